@@ -5,14 +5,20 @@
 # =============================================================================
 
 button-cancel = Abbrechen
+button-continue = Weiter
 button-send = Senden
+button-send-as-one-message = Als eine Nachricht senden
 button-delete = Löschen
 button-connect = Verbinden
 button-save = Speichern
 button-create = Erstellen
 button-edit = Bearbeiten
+button-edit-profile = Profil bearbeiten
 button-update = Aktualisieren
 button-accept-new-certificate = Neues Zertifikat akzeptieren
+button-always-accept-certificate = Immer akzeptieren
+button-accept-rules = Regeln akzeptieren
+button-disconnect = Trennen
 button-close = Schließen
 button-choose-avatar = Avatar auswählen
 button-clear-avatar = Löschen
@@ -22,6 +28,8 @@ button-clear-avatar = Löschen
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = Mit Server verbinden
 title-add-bookmark = Lesezeichen hinzufügen
 title-edit-server = Server bearbeiten
@@ -34,7 +42,10 @@ title-settings = Einstellungen
 title-bookmarks = Lesezeichen
 title-users = Benutzer
 title-edit-server-info = Server-Info bearbeiten
+title-edit-profile = Profil bearbeiten
 title-fingerprint-mismatch = Zertifikat-Fingerabdruck stimmt nicht überein!
+title-server-rules = Serverregeln
+title-server-banner = Serverhinweis
 title-server-info = Server-Info
 title-user-info = Benutzer-Info
 title-about = Über
@@ -46,39 +57,99 @@ title-about = Über
 placeholder-username = Benutzername
 placeholder-password = Passwort
 placeholder-port = Port
+placeholder-fallback-addresses = Ausweichadressen (optional, kommagetrennt Host:Port)
+placeholder-watched-users = Benachrichtigen, wenn online (optional, Benutzernamen durch Kommas getrennt)
 placeholder-server-address = Serveradresse
 placeholder-server-name = Servername
 placeholder-username-optional = Benutzername (optional)
 placeholder-password-optional = Passwort (optional)
 placeholder-password-keep-current = Passwort (leer lassen um aktuelles zu behalten)
 placeholder-message = Nachricht eingeben...
+placeholder-message-slow-mode = Langsamer Modus: { $seconds }s warten...
+placeholder-message-maintenance = Wartungsmodus: Eingabe deaktiviert
 placeholder-no-permission = Keine Berechtigung
 placeholder-broadcast-message = Rundnachricht eingeben...
 placeholder-server-description = Serverbeschreibung
+placeholder-pronouns = z. B. sie/ihr
+placeholder-bio = Eine kurze Zeile über dich
+placeholder-global-theme = Globales Design verwenden
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = Auto-Verbindung
+label-trust-certificate-changes = Zertifikatsänderungen dieses Servers immer akzeptieren (weniger sicher)
 label-add-bookmark = Lesezeichen
+label-invite-fingerprint = Zertifikat-Fingerabdruck aus dem Einladungslink: { $fingerprint }
 label-admin = Admin
 label-enabled = Aktiviert
 label-permissions = Berechtigungen:
 label-expected-fingerprint = Erwarteter Fingerabdruck:
 label-received-fingerprint = Empfangener Fingerabdruck:
 label-theme = Design
+label-density = Dichte:
+density-comfortable = Komfortabel
+density-compact = Kompakt
+label-show-role-badges = Admin- und Moderator-Abzeichen in der Benutzerliste anzeigen
+badge-admin = Admin
+badge-moderator = Mod
+badge-guest = Gast
+user-list-permissions = Berechtigungen: { $permissions }
+user-list-away = Abwesend
 label-chat-font-size = Schriftgröße:
+label-max-message-tabs = Max. Nachrichten-Tabs:
+label-paste-confirm-lines = Einfügen ab so vielen Zeilen bestätigen (0 = nie):
+label-collapse-message-lines = Nachrichten mit mehr Zeilen als diesen einklappen (0 = nie):
+label-ctrl-enter-sends = Mit Strg+Enter senden (Enter fügt eine neue Zeile ein)
+label-message-format = Nachrichtenformat:
+message-format-preset-default = Standard
+message-format-preset-compact = Kompakt
+message-format-preset-irc = IRC-Stil
+message-format-preset-verbose = Ausführlich (mit Datum)
+message-format-preset-custom = Benutzerdefiniert
 label-show-connection-notifications = Verbindungsbenachrichtigungen anzeigen
+label-hide-repeated-topic = Thema beim erneuten Verbinden nur bei Änderung anzeigen
+label-alert-on-disconnect = Fenster hervorheben, wenn eine Verbindung abbricht
+label-alert-on-mention = Fenster hervorheben, wenn dich jemand erwähnt
+label-focus-new-messages = Zu Privatnachrichten wechseln, die eintreffen, während ich inaktiv bin
+label-quiet-hours = Ruhezeiten (Fenster nicht blinken lassen)
+label-quiet-hours-from = Von
+label-quiet-hours-to = bis
+label-watched-users-ignore-quiet-hours = Auch während der Ruhezeit über beobachtete Benutzer benachrichtigen
+label-auto-away = Bei Inaktivität als abwesend markieren
+label-auto-away-minutes = Minuten ohne Eingabe
+label-quiet-hours-active = Ruhezeit
 label-show-timestamps = Zeitstempel anzeigen
 label-use-24-hour-time = 24-Stunden-Format verwenden
 label-show-seconds = Sekunden anzeigen
+label-timezone = Zeitzone:
+timezone-local = Systemzeit
+label-privacy = Datenschutz
+label-trusted-certificates = Vertrauenswürdige Zertifikate
+label-fingerprint-not-pinned = Noch kein Zertifikat gespeichert
+label-forget-passwords = Passwörter nicht speichern (beim Verbinden fragen)
+label-sync-preferences = Design und Chat-Anzeige mit Servern synchronisieren
+label-check-for-updates = Beim Start nach einem neueren Client suchen
+label-proxy = Proxy
+label-use-socks5-proxy = Über einen SOCKS5-Proxy verbinden (Tor, I2P)
+label-proxy-address = Adresse:
+label-proxy-port = Port:
+label-diagnostics = Diagnose
+label-connection-log-path = Verbindungsprotokoll-Datei:
+placeholder-connection-log-path = Leer lassen, um Ereignisse nur im Speicher zu behalten
 label-server-name = Name:
 label-server-description = Beschreibung:
+label-pronouns = Pronomen:
+label-bio = Bio:
 label-server-version = Version:
+label-server-features = Funktionen:
+label-users-online = Benutzer online:
 label-chat-topic = Chat-Thema:
 label-chat-topic-set-by = Chat-Thema gesetzt von:
 label-max-connections-per-ip = Max. Verbindungen pro IP:
+label-traffic = Datenverkehr:
+server-info-traffic-value = { $sent } gesendet, { $received } empfangen
 label-avatar = Avatar:
 label-details = Technische Details
 label-chat-options = Chat-Optionen
@@ -86,6 +157,14 @@ label-appearance = Erscheinungsbild
 label-image = Bild
 label-general = Allgemein
 label-limits = Limits
+label-broadcast-target = Senden an:
+broadcast-target-everyone = Alle
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = Chat
 
 # =============================================================================
 # Permission Display Names
@@ -97,6 +176,8 @@ permission-chat_send = Chat Senden
 permission-chat_receive = Chat Empfangen
 permission-chat_topic = Chat-Thema
 permission-chat_topic_edit = Chat-Thema Bearbeiten
+permission-chat_pin = Chat Anheften
+permission-chat_moderate = Chat Moderieren
 permission-user_broadcast = Benutzer-Rundnachricht
 permission-user_create = Benutzer Erstellen
 permission-user_delete = Benutzer Löschen
@@ -115,16 +196,25 @@ tooltip-user-edit = Benutzer bearbeiten
 tooltip-server-info = Server-Info
 tooltip-about = Über
 tooltip-settings = Einstellungen
+tooltip-quiet-hours-active = Ruhezeit aktiv, das Fenster blinkt nicht
 tooltip-hide-bookmarks = Lesezeichen ausblenden
 tooltip-show-bookmarks = Lesezeichen anzeigen
 tooltip-hide-user-list = Benutzerliste ausblenden
 tooltip-show-user-list = Benutzerliste anzeigen
 tooltip-disconnect = Trennen
+tooltip-connection-health-unknown = Verbindungsqualität noch nicht gemessen
+tooltip-connection-health-good = Verbindung gut ({ $rtt } ms)
+tooltip-connection-health-fair = Verbindung mäßig ({ $rtt } ms)
+tooltip-connection-health-poor = Verbindung schlecht ({ $rtt } ms)
+tooltip-connection-health-no-response = Keine Antwort vom Server
 tooltip-edit = Bearbeiten
 tooltip-info = Info
 tooltip-message = Nachricht
 tooltip-kick = Rauswerfen
 tooltip-close = Schließen
+tooltip-pop-out = In eigenem Fenster öffnen
+tooltip-unpin = Lösen
+tooltip-pinned-by = Angeheftet von { $username }
 tooltip-add-bookmark = Lesezeichen hinzufügen
 
 # =============================================================================
@@ -141,6 +231,7 @@ empty-no-users = Keine Benutzer online
 # =============================================================================
 
 chat-tab-server = #server
+chat-tab-server-count = #server ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -155,18 +246,47 @@ chat-prefix-system = [SYS]
 chat-prefix-error = [FEH]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [ANKÜNDIGUNG]
+chat-prefix-pinned = [PIN]
+chat-message-edited = (bearbeitet)
+chat-message-deleted = (gelöscht)
+chat-message-removed-by-moderator = (von einem Moderator entfernt)
+chat-show-more = Mehr anzeigen
+chat-show-less = Weniger anzeigen
+chat-message-encrypted = (verschlüsselt)
+chat-message-unreadable = (verschlüsselte Nachricht, die nicht entschlüsselt werden konnte)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = Benutzer erfolgreich rausgeworfen
+msg-session-kicked-success = Sitzung getrennt
+msg-confirm-clear-fingerprint = Gespeichertes Zertifikat für { $name } entfernen? Die nächste Verbindung vertraut jedem Zertifikat, das der Server vorlegt.
 msg-broadcast-sent = Rundnachricht erfolgreich gesendet
 msg-user-created = Benutzer erfolgreich erstellt
 msg-user-deleted = Benutzer erfolgreich gelöscht
 msg-user-updated = Benutzer erfolgreich aktualisiert
 msg-permissions-updated = Ihre Berechtigungen wurden aktualisiert
 msg-topic-updated = Thema erfolgreich aktualisiert
+msg-message-pinned = Nachricht angeheftet
+msg-message-unpinned = Nachricht gelöst
+msg-chat-history-purged = Chatverlauf bereinigt: { $count } Nachrichten entfernt
+msg-maintenance-enabled = Der Server befindet sich jetzt im Wartungsmodus; Änderungen sind deaktiviert
+msg-maintenance-disabled = Der Wartungsmodus ist beendet; Änderungen sind wieder möglich
+msg-new-login = Dein Konto hat sich gerade von { $address } ({ $source }) angemeldet
+msg-seen-online = { $username } ist gerade online
+msg-seen-ago = { $username } wurde zuletzt vor { $duration } gesehen ({ $time })
+msg-seen-never = { $username } war noch nicht online
+msg-maintenance-banner = Wartungsmodus: Der Server ist vorübergehend schreibgeschützt
+msg-admin-notice-reconnect = Admin-Hinweis: Bitte verbinde dich neu mit dem Server
+msg-admin-notice-update = Admin-Hinweis: Ein Client-Update ist verfügbar unter { $url }
+msg-update-available = Nexus BBS { $version } ist verfügbar
+msg-admin-notice = Admin-Hinweis: { $message }
+msg-permissions-refreshed = Berechtigungen aktualisiert
+msg-admin-transferred = { $username } ist jetzt Administrator
+msg-copied-to-clipboard = In die Zwischenablage kopiert
+msg-large-paste-confirm = Diese Nachricht enthält { $count } eingefügte Zeilen. Senden?
 
 # =============================================================================
 # Dynamic Messages (with parameters)
@@ -176,9 +296,19 @@ msg-topic-cleared = Thema gelöscht von { $username }
 msg-topic-set = Thema gesetzt von { $username }: { $topic }
 msg-server-info-updated = Serverkonfiguration aktualisiert
 msg-topic-display = Thema: { $topic }
+msg-connected-via-fallback = Über Ausweichadresse { $address } verbunden
+msg-certificate-change-trusted = Das Serverzertifikat hat sich geändert und wurde akzeptiert, weil dieses Lesezeichen Zertifikatsänderungen vertraut (neuer Fingerabdruck { $fingerprint })
 msg-user-connected = { $username } hat sich verbunden
 msg-user-disconnected = { $username } hat sich getrennt
+msg-watched-user-online = { $username } ist jetzt online
+msg-watched-users-online = Beobachtete Benutzer online: { $usernames }
 msg-disconnected = Getrennt: { $error }
+msg-enter-password-for = Passwort für { $name } eingeben
+connect-step-progress = Schritt { $step } von { $total }: { $description }
+connect-step-tcp = Verbindung zum Server wird hergestellt...
+connect-step-tls = Verbindung wird gesichert...
+connect-step-handshake = Protokoll wird ausgehandelt...
+connect-step-login = Anmeldung läuft...
 msg-connection-cancelled = Verbindung abgebrochen wegen Zertifikat-Nichtübereinstimmung
 
 # =============================================================================
@@ -186,7 +316,9 @@ msg-connection-cancelled = Verbindung abgebrochen wegen Zertifikat-Nichtüberein
 # =============================================================================
 
 err-connection-broken = Verbindungsfehler
+err-account-disabled-while-connected = Dein Konto wurde von einem Administrator deaktiviert. Wende dich an ihn, um es vor dem erneuten Verbinden wieder aktivieren zu lassen.
 err-failed-update-server-info = Serverinfo konnte nicht aktualisiert werden: { $error }
+err-failed-update-profile = Profil konnte nicht aktualisiert werden: { $error }
 err-user-kick-failed = Benutzer konnte nicht rausgeworfen werden
 err-no-shutdown-handle = Verbindungsfehler: Kein Shutdown-Handle
 err-userlist-failed = Benutzerliste konnte nicht aktualisiert werden
@@ -204,6 +336,9 @@ err-could-not-determine-config-dir = Konfigurationsverzeichnis konnte nicht ermi
 err-message-too-long = Nachricht ist zu lang ({ $length } Zeichen, max { $max })
 err-send-failed = Nachricht konnte nicht gesendet werden
 err-no-chat-permission = Sie haben keine Berechtigung, Nachrichten zu senden
+err-private-messages-disabled = Private Nachrichten sind auf diesem Server deaktiviert
+err-popout-command = Befehle können nur im Hauptfenster verwendet werden
+err-multiline-command = Befehle müssen in eine Zeile passen (mit // beginnen, um dies als Nachricht zu senden)
 err-broadcast-too-long = Rundnachricht ist zu lang ({ $length } Zeichen, max { $max })
 err-broadcast-send-failed = Rundnachricht konnte nicht gesendet werden
 err-name-required = Lesezeichenname ist erforderlich
@@ -215,6 +350,7 @@ err-message-required = Nachricht ist erforderlich
 
 # Validation errors
 err-message-empty = Nachricht darf nicht leer sein
+err-slow-mode-wait = Langsamer Modus ist aktiv. Bitte warte { $seconds } Sekunden
 err-message-contains-newlines = Nachricht darf keine Zeilenumbrüche enthalten
 err-message-invalid-characters = Nachricht enthält ungültige Zeichen
 err-username-empty = Benutzername darf nicht leer sein
@@ -232,6 +368,12 @@ err-server-name-invalid-characters = Servername enthält ungültige Zeichen
 err-server-description-too-long = Beschreibung ist zu lang (max { $max } Zeichen)
 err-server-description-contains-newlines = Beschreibung darf keine Zeilenumbrüche enthalten
 err-server-description-invalid-characters = Beschreibung enthält ungültige Zeichen
+err-bio-too-long = Bio ist zu lang (max. { $max } Zeichen)
+err-bio-contains-newlines = Bio darf keine Zeilenumbrüche enthalten
+err-bio-invalid-characters = Bio enthält ungültige Zeichen
+err-pronouns-too-long = Pronomen sind zu lang (max. { $max } Zeichen)
+err-pronouns-contains-newlines = Pronomen dürfen keine Zeilenumbrüche enthalten
+err-pronouns-invalid-characters = Pronomen enthalten ungültige Zeichen
 err-failed-send-update = Aktualisierung konnte nicht gesendet werden: { $error }
 
 # =============================================================================
@@ -239,25 +381,68 @@ err-failed-send-update = Aktualisierung konnte nicht gesendet werden: { $error }
 # =============================================================================
 
 err-failed-save-config = Konfiguration konnte nicht gespeichert werden: { $error }
+err-connection-log-write = Verbindungsprotokoll { $path } konnte nicht geschrieben werden: { $error }
 err-failed-save-settings = Einstellungen konnten nicht gespeichert werden: { $error }
 err-invalid-port-bookmark = Ungültiger Port im Lesezeichen: { $name }
+err-fallback-address-invalid = Ungültige Ausweichadresse: { $address }
 err-failed-send-broadcast = Rundnachricht konnte nicht gesendet werden: { $error }
 err-failed-send-message = Nachricht konnte nicht gesendet werden: { $error }
+err-command-unsupported = Der Server unterstützt /{ $command } nicht
 err-failed-create-user = Benutzer konnte nicht erstellt werden: { $error }
 err-failed-delete-user = Benutzer konnte nicht gelöscht werden: { $error }
 err-failed-update-user = Benutzer konnte nicht aktualisiert werden: { $error }
 err-failed-update-topic = Thema konnte nicht aktualisiert werden: { $error }
+err-failed-pin-message = Nachricht konnte nicht angeheftet werden: { $error }
+err-failed-unpin-message = Nachricht konnte nicht gelöst werden: { $error }
+err-failed-edit-message = Nachricht konnte nicht bearbeitet werden: { $error }
+err-failed-delete-message = Nachricht konnte nicht gelöscht werden: { $error }
+err-failed-purge-history = Chatverlauf konnte nicht bereinigt werden: { $error }
+err-failed-announcement = Ankündigungen konnten nicht aktualisiert werden: { $error }
+err-failed-stats = Verbindungsstatistik konnte nicht abgerufen werden: { $error }
+err-failed-history = Ältere Nachrichten konnten nicht geladen werden: { $error }
+err-art-read = { $path } konnte nicht gelesen werden: { $error }
+err-art-file-too-large = Art-Dateien dürfen höchstens { $max } KiB groß sein
+err-art-empty = Die Art-Datei enthält nichts zum Senden
+err-art-fence = Art darf kein ``` enthalten (es würde den Block beenden)
+err-art-first-line = Schon die erste Zeile passt nicht in das Serverlimit von { $max } Zeichen
+err-failed-page = Seitenanfrage fehlgeschlagen: { $error }
+err-failed-set-maintenance = Wartungsmodus konnte nicht geändert werden: { $error }
+err-failed-send-admin-notice = Hinweis konnte nicht gesendet werden: { $error }
+err-notice-url-invalid = Der Link muss mit http:// oder https:// beginnen und darf keine Leerzeichen enthalten
+err-failed-refresh-permissions = Berechtigungen konnten nicht aktualisiert werden: { $error }
+err-failed-transfer-admin = Administratorrechte konnten nicht übertragen werden: { $error }
+err-failed-sync-preferences = Einstellungen konnten nicht synchronisiert werden: { $error }
+err-failed-accept-rules = Regeln konnten nicht akzeptiert werden: { $error }
 err-message-too-long-details = { $error } ({ $length } Zeichen, max { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = Ungültige Adresse '{ $address }': { $error }
+err-all-addresses-failed = Keine der { $count } Adressen erreichbar (letzter Fehler: { $error })
 err-could-not-resolve = Adresse '{ $address }' konnte nicht aufgelöst werden
 err-connection-timeout = Verbindungszeitüberschreitung nach { $seconds } Sekunden
 err-connection-failed = Verbindung fehlgeschlagen: { $error }
+err-proxy-address-empty = Proxy-Adresse ist erforderlich
+err-proxy-address-invalid = Proxy-Adresse muss ein Hostname oder eine IP-Adresse ohne Leerzeichen sein
+err-proxy-port-invalid = Proxy-Port muss zwischen 1 und 65535 liegen
+err-quiet-hours-start-invalid = Beginn der Ruhezeit muss eine 24-Stunden-Uhrzeit wie 22:00 sein
+err-quiet-hours-end-invalid = Ende der Ruhezeit muss eine 24-Stunden-Uhrzeit wie 08:00 sein
+err-message-format-unclosed-brace = Nachrichtenformat enthält einen nicht geschlossenen Platzhalter (doppelte Klammer für eine literale Klammer)
+err-message-format-unknown-placeholder = Nachrichtenformat enthält den unbekannten Platzhalter { $placeholder } (verfügbar: { $available })
+err-message-format-missing-placeholder = Nachrichtenformat muss { $placeholder } enthalten
+err-message-format-duplicate-placeholder = Nachrichtenformat darf { $placeholder } nur einmal enthalten
+err-invite-not-a-link = Kein nexus://-Einladungslink
+err-invite-invalid-address = Der Einladungslink enthält eine ungültige Serveradresse
+err-invite-invalid-port = Der Port im Einladungslink muss zwischen 1 und 65535 liegen
+err-invite-invalid-name = Der Einladungslink enthält einen ungültigen Servernamen
+err-invite-invalid-fingerprint = Der Einladungslink enthält einen ungültigen Zertifikat-Fingerabdruck
+err-invite-credentials = Einladungslinks dürfen keinen Benutzernamen und kein Passwort enthalten
+err-invite-fingerprint-mismatch = Das Serverzertifikat passt nicht zum Einladungslink (erwartet { $expected }, erhalten { $received })
+err-proxy-connection-failed = Verbindung über Proxy { $proxy } fehlgeschlagen: { $error }
 err-tls-handshake-failed = TLS-Handshake fehlgeschlagen: { $error }
 err-failed-send-handshake = Handshake konnte nicht gesendet werden: { $error }
 err-failed-read-handshake = Handshake-Antwort konnte nicht gelesen werden: { $error }
 err-handshake-failed = Handshake fehlgeschlagen: { $error }
+err-client-too-old = Dieser Server benötigt einen neueren Nexus-Client. Bitte aktualisieren Sie, um sich zu verbinden. ({ $error })
 err-failed-parse-handshake = Handshake-Antwort konnte nicht analysiert werden: { $error }
 err-failed-send-login = Anmeldung konnte nicht gesendet werden: { $error }
 err-failed-read-login = Anmeldeantwort konnte nicht gelesen werden: { $error }
@@ -274,6 +459,7 @@ err-failed-set-config-permissions = Berechtigungen der Konfigurationsdatei konnt
 # =============================================================================
 
 fingerprint-warning = Dies könnte auf ein Sicherheitsproblem (MITM-Angriff) hinweisen oder das Serverzertifikat wurde neu generiert. Akzeptieren Sie nur, wenn Sie dem Serveradministrator vertrauen.
+server-rules-intro = Du musst diese Regeln akzeptieren, bevor du auf diesem Server chatten kannst.
 
 # =============================================================================
 # User Info Display
@@ -283,15 +469,24 @@ user-info-username = Benutzername:
 user-info-role = Rolle:
 user-info-role-admin = admin
 user-info-role-user = benutzer
+user-info-role-guest = gast
+user-info-pronouns = Pronomen:
+user-info-bio = Bio:
 user-info-connected = Verbunden:
 user-info-connected-value = vor { $duration }
 user-info-connected-value-sessions = vor { $duration } ({ $count } Sitzungen)
 user-info-features = Funktionen:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (inaktiv)
 user-info-features-none = Keine
+user-info-permissions = Berechtigungen:
+user-info-permissions-none = Keine
 user-info-locale = Sprache:
 user-info-address = Adresse:
 user-info-addresses = Adressen:
+user-info-sessions = Sitzungen:
+user-info-session = Sitzung { $id }
+user-info-this-session = diese Sitzung
 user-info-created = Erstellt:
 user-info-end = Ende der Benutzerinformationen
 user-info-unknown = Unbekannt
@@ -328,28 +523,72 @@ cmd-help-desc = Verfügbare Befehle anzeigen
 cmd-help-escape-hint = Tipp: Verwenden Sie //, um eine Nachricht zu senden, die mit / beginnt
 cmd-message-desc = Nachricht an Benutzer senden
 cmd-message-usage = Verwendung: /{ $command } <benutzername> <nachricht>
+cmd-query-desc = Nachrichten-Tab mit einem Benutzer öffnen, ohne zu senden
+cmd-query-usage = Verwendung: /{ $command } <benutzername>
+cmd-query-not-online = Benutzer ist nicht online: { $username }
+cmd-query-self = Du kannst dir nicht selbst schreiben
+cmd-reconnect-desc = Verbindung zu diesem Server trennen und neu aufbauen
+cmd-reconnect-usage = Verwendung: /{ $command }
+cmd-reconnect-no-bookmark = /{ $command } benötigt ein Lesezeichen zum Neuverbinden; diese Verbindung wurde nicht über ein Lesezeichen hergestellt
 cmd-userinfo-desc = Informationen über einen Benutzer anzeigen
 cmd-userinfo-usage = Verwendung: /{ $command } <benutzername>
+cmd-invite-desc = Einladungslink für diesen Server kopieren
+cmd-invite-usage = Verwendung: /{ $command }
+cmd-invite-copied = Einladungslink in die Zwischenablage kopiert: { $link }
 cmd-kick-desc = Benutzer vom Server entfernen
 cmd-kick-usage = Verwendung: /{ $command } <benutzername>
+cmd-limits-desc = Längenbegrenzungen für Nachrichten und Thema anzeigen oder festlegen
+cmd-limits-usage = Verwendung: /{ $command } [nachricht <zeichen>|thema <zeichen>]
+cmd-limits-arg-message = nachricht
+cmd-limits-arg-topic = thema
+cmd-limits-current = Grenzen: Nachrichten bis { $message } Zeichen, Thema bis { $topic } Zeichen
 cmd-topic-desc = Chat-Thema anzeigen oder verwalten
 cmd-topic-usage = Verwendung: /{ $command } [set|clear] [thema]
 cmd-topic-set-usage = Verwendung: /{ $command } set <thema>
 cmd-topic-none = Kein Thema gesetzt
+cmd-announce-desc = Rotierende Ankündigungen des Servers anzeigen oder verwalten
+cmd-announce-usage = Verwendung: /{ $command } [hinzufügen <nachricht>|entfernen <nummer>]
+cmd-announce-arg-add = hinzufügen
+cmd-announce-arg-remove = entfernen
+cmd-announce-list = Ankündigungen (alle { $minutes } Minuten):
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = Keine Ankündigungen festgelegt
+cmd-announce-added = Ankündigung hinzugefügt
+cmd-announce-removed = Ankündigung entfernt
+cmd-art-desc = ASCII- oder ANSI-Art aus einer Datei als Block fester Breite senden
+cmd-art-usage = Verwendung: /{ $command } [kürzen] <datei>
+cmd-art-arg-truncate = kürzen
+cmd-art-too-long = Die Grafik hat { $length } Zeichen und überschreitet das Serverlimit von { $max }. Mit /{ $command } { $truncate } <datei> werden die passenden Zeilen gesendet
 cmd-broadcast-desc = Broadcast an alle Benutzer senden
 cmd-broadcast-usage = Verwendung: /{ $command } <nachricht>
 cmd-clear-desc = Chat-Verlauf für aktuellen Tab löschen
 cmd-clear-usage = Verwendung: /{ $command }
+cmd-delete-desc = Ihre letzte Nachricht löschen (Moderatoren: die letzte Nachricht eines Benutzers)
+cmd-delete-usage = Verwendung: /{ $command } [Benutzername]
+cmd-delete-no-message = Keine aktuelle Nachricht von { $username } zum Löschen
+cmd-delete-permission-denied = Sie haben keine Berechtigung, Nachrichten anderer Benutzer zu löschen
+cmd-edit-desc = Ihre letzte Nachricht bearbeiten
+cmd-edit-usage = Verwendung: /{ $command } <Nachricht>
+cmd-edit-no-message = Sie haben keine aktuelle Nachricht zum Bearbeiten
+cmd-events-desc = Lebenszyklus-Ereignisse dieser Verbindung anzeigen
+cmd-events-usage = Verwendung: /{ $command }
+cmd-events-header = Verbindungsereignisse (älteste zuerst):
+cmd-events-empty = Noch keine Verbindungsereignisse aufgezeichnet
+cmd-report-desc = Bereinigten Client- und Verbindungsbericht für Fehlermeldungen kopieren
+cmd-report-usage = Verwendung: /{ $command }
+cmd-report-copied = Fehlerbericht in die Zwischenablage kopiert. Ausgelassen: Passwörter, Serveradresse, Benutzernamen, Zertifikat-Fingerabdruck, Servername und Proxy-Adresse
 cmd-window-desc = Chat-Tabs verwalten
-cmd-window-usage = Verwendung: /{ $command } [weiter|zurück|schließen [benutzername]]
+cmd-window-usage = Verwendung: /{ $command } [weiter|zurück|schließen [benutzername]|abdocken [benutzername]]
 cmd-window-arg-next = weiter
 cmd-window-arg-prev = zurück
 cmd-window-arg-close = schließen
+cmd-window-arg-popout = abdocken
 cmd-window-list = Offene Tabs: { $tabs } ({ $count } { $count ->
     [one] Tab
    *[other] Tabs
 })
 cmd-window-close-server = Server-Tab kann nicht geschlossen werden
+cmd-window-popout-server = Server-Tab kann nicht abgedockt werden
 cmd-window-not-found = Tab nicht gefunden: { $name }
 cmd-focus-desc = Server-Chat oder Nachrichtenfenster eines Benutzers fokussieren
 cmd-focus-usage = Verwendung: /{ $command } [benutzername]
@@ -362,19 +601,85 @@ cmd-list-output = Benutzer online: { $users } ({ $count } { $count ->
     [one] Benutzer
    *[other] Benutzer
 })
+cmd-maintenance-desc = Wartungsmodus (schreibgeschützt) anzeigen oder umschalten
+cmd-maintenance-usage = Verwendung: /{ $command } [an|aus]
+cmd-maintenance-arg-on = an
+cmd-maintenance-arg-off = aus
+cmd-maintenance-on = Wartungsmodus ist an
+cmd-maintenance-off = Wartungsmodus ist aus
+cmd-notice-desc = Allen einen Hinweis als Banner anzeigen
+cmd-notice-usage = Verwendung: /{ $command } reconnect | update <url> | <nachricht>
+cmd-notify-desc = Benutzer auflisten oder verwalten, über deren Anmeldung du benachrichtigt wirst
+cmd-notify-usage = Verwendung: /{ $command } [hinzufügen <benutzername>|entfernen <benutzername>]
+cmd-notify-arg-add = hinzufügen
+cmd-notify-arg-del = entfernen
+cmd-notify-no-bookmark = Die Beobachtungsliste wird im Lesezeichen gespeichert, daher funktioniert /notify nur bei Servern mit Lesezeichen
+cmd-notify-none = Du beobachtest auf diesem Server niemanden
+cmd-notify-list = Beobachtet: { $usernames }
+cmd-notify-added = Du wirst benachrichtigt, wenn { $username } online kommt
+cmd-notify-already = { $username } ist bereits auf deiner Beobachtungsliste
+cmd-notify-removed = { $username } wird nicht mehr beobachtet
+cmd-notify-not-watched = { $username } ist nicht auf deiner Beobachtungsliste
 cmd-list-all-no-permission = Sie benötigen die Berechtigung user_edit oder user_delete, um alle Benutzer aufzulisten
 cmd-list-all-output = Benutzer: { $users } ({ $count } { $count ->
     [one] Benutzer
    *[other] Benutzer
 })
+cmd-whoami-desc = Zeigt deinen Benutzernamen, deine Rolle, Berechtigungen und Funktionen
+cmd-whoami-usage = Verwendung: /{ $command }
 cmd-help-usage = Verwendung: /{ $command } [befehl]
 cmd-topic-arg-set = setzen
 cmd-topic-arg-clear = löschen
 cmd-topic-permission-denied = Sie haben keine Berechtigung, das Thema zu bearbeiten
+cmd-page-desc = Infoseiten des Servers auflisten oder lesen (Admins: setzen oder löschen)
+cmd-page-usage = Verwendung: /{ $command } [<name>|setzen <name> <text>|löschen <name>] (\n beginnt eine neue Zeile)
+cmd-page-arg-set = setzen
+cmd-page-arg-delete = löschen
+cmd-page-list = Seiten: { $pages }
+cmd-page-none = Dieser Server hat keine Seiten
+cmd-page-saved = Seite gespeichert
+cmd-page-deleted = Seite gelöscht
+cmd-pin-desc = Angeheftete Nachrichten anzeigen oder die letzte Nachricht eines Benutzers anheften
+cmd-pin-usage = Verwendung: /{ $command } [Benutzername]
+cmd-pin-list = Angeheftete Nachrichten:
+cmd-pin-list-item = { $number }. { $username }: { $message } (angeheftet von { $pinned_by })
+cmd-pin-none = Keine Nachrichten angeheftet
+cmd-pin-no-message = Keine aktuelle Nachricht von { $username } zum Anheften
+cmd-purge-desc = Chatverlauf außerhalb der Aufbewahrungsrichtlinie des Servers bereinigen
+cmd-purge-usage = Verwendung: /{ $command }
+cmd-refresh-desc = Berechtigungen eines Benutzers erneut aus der Serverdatenbank senden
+cmd-refresh-usage = Verwendung: /{ $command } <benutzername>
+cmd-transfer-desc = Ein anderes Konto zum Administrator machen und selbst zurücktreten, außer mit behalten
+cmd-transfer-usage = Verwendung: /{ $command } <benutzername> [behalten]
+cmd-transfer-arg-keep = behalten
+cmd-seen-desc = Zeigen, wann ein Benutzer zuletzt online war
+cmd-seen-usage = Verwendung: /{ $command } <Benutzername>
+cmd-session-desc = Sitzung und Datenverkehr dieser Verbindung anzeigen
+cmd-session-usage = Verwendung: /{ $command }
+cmd-session-info = Sitzung { $id } verbunden mit { $address }
+cmd-session-traffic = Datenverkehr seit dem Verbinden: { $sent } gesendet, { $received } empfangen
+cmd-unpin-desc = Eine angeheftete Nachricht lösen
+cmd-unpin-usage = Verwendung: /{ $command } <Nummer> (Nummern siehe /pin)
 cmd-serverinfo-desc = Server-Informationen anzeigen
 cmd-serverinfo-usage = Verwendung: /{ $command }
 cmd-serverinfo-header = [server]
 cmd-serverinfo-end = Ende der Server-Informationen
+cmd-slow-desc = Langsamen Chat-Modus anzeigen oder setzen
+cmd-slow-usage = Verwendung: /{ $command } [sekunden]
+cmd-slow-off = Langsamer Modus ist aus
+cmd-slow-current = Langsamer Modus: { $seconds } Sekunden zwischen Nachrichten
+cmd-stats-desc = Verbindungsverlauf des Servers der letzten Stunde anzeigen
+cmd-stats-usage = Verwendung: /{ $command } [zurücksetzen]
+cmd-stats-arg-reset = zurücksetzen
+cmd-stats-current = Derzeit offene Verbindungen: { $count }
+cmd-stats-total = Letzte { $minutes } Minuten: { $connections } Verbindungen, { $refused } abgelehnt, Spitze { $peak } offen ({ $peak_per_ip } von einer Adresse)
+cmd-stats-group = { $time }: { $connections } Verbindungen, { $refused } abgelehnt, Spitze { $peak }
+cmd-stats-none = Noch kein Verbindungsverlauf aufgezeichnet
+cmd-set-desc = Chat-Einstellungen für diesen Server anzeigen oder ändern
+cmd-set-usage = Verwendung: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = Betreten/Verlassen-Meldungen werden auf diesem Server angezeigt
+cmd-set-joinleave-off = Betreten/Verlassen-Meldungen werden auf diesem Server ausgeblendet
+cmd-set-joinleave-default = Betreten/Verlassen-Meldungen folgen auf diesem Server der globalen Einstellung
 
 # =============================================================================
 # About Panel
@@ -384,6 +689,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = Bild auswählen
 button-clear-image = Löschen
+button-clear-theme = Löschen
+button-copy-fingerprint = Kopieren
+button-clear-fingerprint = Entfernen
+button-dismiss = Schließen
+button-reconnect = Neu verbinden
+button-open-link = Link öffnen
 label-server-image = Serverbild:
 err-server-image-too-large = Das Serverbild ist zu groß (maximal 512KB)
 err-server-image-invalid-format = Ungültiges Serverbild-Format (muss eine Data-URI mit Base64-Kodierung sein)
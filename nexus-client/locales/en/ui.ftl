@@ -5,25 +5,39 @@
 # =============================================================================
 
 button-cancel = Cancel
+button-continue = Continue
 button-send = Send
+button-send-as-one-message = Send as One Message
 button-delete = Delete
 button-connect = Connect
 button-save = Save
 button-create = Create
 button-edit = Edit
+button-edit-profile = Edit Profile
 button-update = Update
 button-accept-new-certificate = Accept New Certificate
+button-always-accept-certificate = Always Accept
+button-accept-rules = Accept Rules
+button-disconnect = Disconnect
 button-close = Close
 button-choose-avatar = Choose Avatar
 button-clear-avatar = Clear
 button-choose-image = Choose Image
 button-clear-image = Clear
+button-clear-theme = Clear
+button-copy-fingerprint = Copy
+button-clear-fingerprint = Clear
+button-dismiss = Dismiss
+button-reconnect = Reconnect
+button-open-link = Open Link
 
 # =============================================================================
 # Titles
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = Connect to Server
 title-add-bookmark = Add Bookmark
 title-edit-server = Edit Server
@@ -36,7 +50,10 @@ title-settings = Settings
 title-bookmarks = Bookmarks
 title-users = Users
 title-edit-server-info = Edit Server Info
+title-edit-profile = Edit Profile
 title-fingerprint-mismatch = Certificate Fingerprint Mismatch!
+title-server-rules = Server Rules
+title-server-banner = Server Notice
 title-server-info = Server Info
 title-user-info = User Info
 title-about = About
@@ -48,39 +65,99 @@ title-about = About
 placeholder-username = Username
 placeholder-password = Password
 placeholder-port = Port
+placeholder-fallback-addresses = Fallback addresses (optional, comma-separated host:port)
+placeholder-watched-users = Notify when online (optional, comma-separated usernames)
 placeholder-server-address = Server Address
 placeholder-server-name = Server Name
 placeholder-username-optional = Username (optional)
 placeholder-password-optional = Password (optional)
 placeholder-password-keep-current = Password (leave empty to keep current)
 placeholder-message = Type a message...
+placeholder-message-slow-mode = Slow mode: wait { $seconds }s...
+placeholder-message-maintenance = Maintenance mode: input is disabled
 placeholder-no-permission = No permission
 placeholder-broadcast-message = Enter broadcast message...
 placeholder-server-description = Server description
+placeholder-pronouns = e.g. they/them
+placeholder-bio = A short line about yourself
+placeholder-global-theme = Use global theme
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = Auto-Connect
+label-trust-certificate-changes = Always accept this server's certificate changes (less secure)
 label-add-bookmark = Add Bookmark
+label-invite-fingerprint = Certificate fingerprint from invite link: { $fingerprint }
 label-admin = Admin
 label-enabled = Enabled
 label-permissions = Permissions:
 label-expected-fingerprint = Expected fingerprint:
 label-received-fingerprint = Received fingerprint:
 label-theme = Theme:
+label-density = Density:
+density-comfortable = Comfortable
+density-compact = Compact
+label-show-role-badges = Show admin and moderator badges in the user list
+badge-admin = admin
+badge-moderator = mod
+badge-guest = guest
+user-list-permissions = Permissions: { $permissions }
+user-list-away = Away
 label-chat-font-size = Font Size:
+label-max-message-tabs = Max Message Tabs:
+label-paste-confirm-lines = Confirm pastes of at least this many lines (0 = never):
+label-collapse-message-lines = Collapse messages longer than this many lines (0 = never):
+label-ctrl-enter-sends = Send with Ctrl+Enter (Enter adds a new line)
+label-message-format = Message format:
+message-format-preset-default = Default
+message-format-preset-compact = Compact
+message-format-preset-irc = IRC style
+message-format-preset-verbose = Verbose (with date)
+message-format-preset-custom = Custom
 label-show-connection-notifications = Show connect/disconnect notifications
+label-hide-repeated-topic = Only show the topic on reconnect if it changed
+label-alert-on-disconnect = Flash the window when a connection drops
+label-alert-on-mention = Flash the window when someone mentions you
+label-focus-new-messages = Switch to private messages that arrive while I'm idle
+label-quiet-hours = Quiet hours (don't flash the window)
+label-quiet-hours-from = From
+label-quiet-hours-to = to
+label-watched-users-ignore-quiet-hours = Notify about watched users during quiet hours
+label-auto-away = Mark me away when idle
+label-auto-away-minutes = Minutes without input
+label-quiet-hours-active = Quiet hours
 label-show-timestamps = Show timestamps
 label-use-24-hour-time = Use 24-hour time
 label-show-seconds = Show seconds
+label-timezone = Timezone:
+timezone-local = System Local
+label-privacy = Privacy
+label-trusted-certificates = Trusted Certificates
+label-fingerprint-not-pinned = No certificate pinned yet
+label-forget-passwords = Don't save passwords (ask when connecting)
+label-sync-preferences = Sync theme and chat display settings with servers
+label-check-for-updates = Check for a newer client at startup
+label-proxy = Proxy
+label-use-socks5-proxy = Connect through a SOCKS5 proxy (Tor, I2P)
+label-proxy-address = Address:
+label-proxy-port = Port:
+label-diagnostics = Diagnostics
+label-connection-log-path = Connection log file:
+placeholder-connection-log-path = Leave empty to keep events in memory only
 label-server-name = Name:
 label-server-description = Description:
+label-pronouns = Pronouns:
+label-bio = Bio:
 label-server-version = Version:
+label-server-features = Features:
+label-users-online = Users Online:
 label-chat-topic = Chat Topic:
 label-chat-topic-set-by = Chat Topic Set By:
 label-max-connections-per-ip = Max Connections Per IP:
+label-traffic = Traffic:
+server-info-traffic-value = { $sent } sent, { $received } received
 label-avatar = Avatar:
 label-server-image = Server Image:
 label-details = Technical Details
@@ -89,6 +166,14 @@ label-appearance = Appearance
 label-image = Image
 label-general = General
 label-limits = Limits
+label-broadcast-target = Send to:
+broadcast-target-everyone = Everyone
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = Chat
 
 # =============================================================================
 # Permission Display Names
@@ -100,6 +185,8 @@ permission-chat_send = Chat Send
 permission-chat_receive = Chat Receive
 permission-chat_topic = Chat Topic
 permission-chat_topic_edit = Chat Topic Edit
+permission-chat_pin = Chat Pin
+permission-chat_moderate = Chat Moderate
 permission-user_broadcast = User Broadcast
 permission-user_create = User Create
 permission-user_delete = User Delete
@@ -118,16 +205,25 @@ tooltip-user-edit = User Edit
 tooltip-server-info = Server Info
 tooltip-about = About
 tooltip-settings = Settings
+tooltip-quiet-hours-active = Quiet hours are on, so the window won't flash
 tooltip-hide-bookmarks = Hide Bookmarks
 tooltip-show-bookmarks = Show Bookmarks
 tooltip-hide-user-list = Hide User List
 tooltip-show-user-list = Show User List
 tooltip-disconnect = Disconnect
+tooltip-connection-health-unknown = Connection quality not measured yet
+tooltip-connection-health-good = Connection good ({ $rtt } ms)
+tooltip-connection-health-fair = Connection fair ({ $rtt } ms)
+tooltip-connection-health-poor = Connection poor ({ $rtt } ms)
+tooltip-connection-health-no-response = No response from server
 tooltip-edit = Edit
 tooltip-info = Info
 tooltip-message = Message
 tooltip-kick = Kick
 tooltip-close = Close
+tooltip-pop-out = Pop out into a window
+tooltip-unpin = Unpin
+tooltip-pinned-by = Pinned by { $username }
 tooltip-add-bookmark = Add Bookmark
 
 # =============================================================================
@@ -144,6 +240,7 @@ empty-no-users = No users online
 # =============================================================================
 
 chat-tab-server = #server
+chat-tab-server-count = #server ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -158,18 +255,47 @@ chat-prefix-system = [SYS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [ANNOUNCEMENT]
+chat-prefix-pinned = [PIN]
+chat-message-edited = (edited)
+chat-message-deleted = (deleted)
+chat-message-removed-by-moderator = (removed by moderator)
+chat-show-more = Show more
+chat-show-less = Show less
+chat-message-encrypted = (encrypted)
+chat-message-unreadable = (encrypted message that couldn't be decrypted)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = User kicked successfully
+msg-session-kicked-success = Session disconnected
+msg-confirm-clear-fingerprint = Clear the pinned certificate for { $name }? The next connection will trust whatever certificate the server presents.
 msg-broadcast-sent = Broadcast sent successfully
 msg-user-created = User created successfully
 msg-user-deleted = User deleted successfully
 msg-user-updated = User updated successfully
 msg-permissions-updated = Your permissions have been updated
 msg-topic-updated = Topic updated successfully
+msg-message-pinned = Message pinned
+msg-message-unpinned = Message unpinned
+msg-chat-history-purged = Chat history purged: { $count } messages removed
+msg-maintenance-enabled = The server is now in maintenance mode; changes are disabled
+msg-maintenance-disabled = Maintenance mode is over; changes are enabled again
+msg-new-login = Your account just logged in from { $address } ({ $source })
+msg-seen-online = { $username } is online now
+msg-seen-ago = { $username } was last seen { $duration } ago ({ $time })
+msg-seen-never = { $username } hasn't been seen online yet
+msg-maintenance-banner = Maintenance mode: the server is read-only for now
+msg-admin-notice-reconnect = Admin notice: please reconnect to the server
+msg-admin-notice-update = Admin notice: a client update is available at { $url }
+msg-update-available = Nexus BBS { $version } is available
+msg-admin-notice = Admin notice: { $message }
+msg-permissions-refreshed = Permissions refreshed
+msg-admin-transferred = { $username } is now an admin
+msg-copied-to-clipboard = Copied to clipboard
+msg-large-paste-confirm = This message contains { $count } pasted lines. Send it?
 
 
 
@@ -181,9 +307,19 @@ msg-topic-cleared = Topic cleared by { $username }
 msg-topic-set = Topic set by { $username }: { $topic }
 msg-server-info-updated = Server configuration updated
 msg-topic-display = Topic: { $topic }
+msg-connected-via-fallback = Connected through fallback address { $address }
+msg-certificate-change-trusted = Server certificate changed and was accepted because this bookmark trusts certificate changes (new fingerprint { $fingerprint })
 msg-user-connected = { $username } connected
 msg-user-disconnected = { $username } disconnected
+msg-watched-user-online = { $username } is now online
+msg-watched-users-online = Watched users online: { $usernames }
 msg-disconnected = Disconnected: { $error }
+msg-enter-password-for = Enter the password for { $name }
+connect-step-progress = Step { $step } of { $total }: { $description }
+connect-step-tcp = Connecting to server...
+connect-step-tls = Securing connection...
+connect-step-handshake = Negotiating protocol...
+connect-step-login = Logging in...
 msg-connection-cancelled = Connection cancelled due to certificate mismatch
 
 # =============================================================================
@@ -191,7 +327,9 @@ msg-connection-cancelled = Connection cancelled due to certificate mismatch
 # =============================================================================
 
 err-connection-broken = Connection error
+err-account-disabled-while-connected = Your account was disabled by an administrator. Contact them to have it re-enabled before reconnecting.
 err-failed-update-server-info = Failed to update server info: { $error }
+err-failed-update-profile = Failed to update profile: { $error }
 err-user-kick-failed = Failed to kick user
 err-no-shutdown-handle = Connection error: No shutdown handle
 err-userlist-failed = Failed to refresh user list
@@ -209,6 +347,9 @@ err-could-not-determine-config-dir = Could not determine config directory
 err-message-too-long = Message is too long ({ $length } characters, max { $max })
 err-send-failed = Failed to send message
 err-no-chat-permission = You don't have permission to send messages
+err-private-messages-disabled = Private messages are disabled on this server
+err-popout-command = Commands can only be used in the main window
+err-multiline-command = Commands must fit on one line (start with // to send this as a message)
 err-broadcast-too-long = Broadcast is too long ({ $length } characters, max { $max })
 err-broadcast-send-failed = Failed to send broadcast
 err-name-required = Bookmark name is required
@@ -220,6 +361,7 @@ err-message-required = Message is required
 
 # Validation errors
 err-message-empty = Message cannot be empty
+err-slow-mode-wait = Slow mode is enabled. Please wait { $seconds } seconds
 err-message-contains-newlines = Message cannot contain newlines
 err-message-invalid-characters = Message contains invalid characters
 err-username-empty = Username cannot be empty
@@ -237,6 +379,12 @@ err-server-name-invalid-characters = Server name contains invalid characters
 err-server-description-too-long = Description is too long (max { $max } characters)
 err-server-description-contains-newlines = Description cannot contain newlines
 err-server-description-invalid-characters = Description contains invalid characters
+err-bio-too-long = Bio is too long (max { $max } characters)
+err-bio-contains-newlines = Bio cannot contain newlines
+err-bio-invalid-characters = Bio contains invalid characters
+err-pronouns-too-long = Pronouns are too long (max { $max } characters)
+err-pronouns-contains-newlines = Pronouns cannot contain newlines
+err-pronouns-invalid-characters = Pronouns contain invalid characters
 err-failed-send-update = Failed to send update: { $error }
 err-server-image-too-large = Server image is too large (max 512KB)
 err-server-image-invalid-format = Invalid server image format (must be a data URI with base64 encoding)
@@ -249,25 +397,68 @@ err-failed-read-image = Failed to read image: { $error }
 # =============================================================================
 
 err-failed-save-config = Failed to save config: { $error }
+err-connection-log-write = Failed to write connection log { $path }: { $error }
 err-failed-save-settings = Failed to save settings: { $error }
 err-invalid-port-bookmark = Invalid port in bookmark: { $name }
+err-fallback-address-invalid = Invalid fallback address: { $address }
 err-failed-send-broadcast = Failed to send broadcast: { $error }
 err-failed-send-message = Failed to send message: { $error }
+err-command-unsupported = The server doesn't support /{ $command }
 err-failed-create-user = Failed to create user: { $error }
 err-failed-delete-user = Failed to delete user: { $error }
 err-failed-update-user = Failed to update user: { $error }
 err-failed-update-topic = Failed to update topic: { $error }
+err-failed-pin-message = Failed to pin message: { $error }
+err-failed-unpin-message = Failed to unpin message: { $error }
+err-failed-edit-message = Failed to edit message: { $error }
+err-failed-delete-message = Failed to delete message: { $error }
+err-failed-purge-history = Failed to purge chat history: { $error }
+err-failed-announcement = Failed to update announcements: { $error }
+err-failed-stats = Failed to get connection stats: { $error }
+err-failed-history = Failed to load older messages: { $error }
+err-art-read = Couldn't read { $path }: { $error }
+err-art-file-too-large = Art files can be at most { $max } KiB
+err-art-empty = The art file has nothing to send
+err-art-fence = Art can't contain ``` (it would end the art block)
+err-art-first-line = Not even the first line of the art fits this server's limit of { $max } characters
+err-failed-page = Page request failed: { $error }
+err-failed-set-maintenance = Failed to change maintenance mode: { $error }
+err-failed-send-admin-notice = Failed to send notice: { $error }
+err-notice-url-invalid = Link must start with http:// or https:// and contain no spaces
+err-failed-refresh-permissions = Failed to refresh permissions: { $error }
+err-failed-transfer-admin = Failed to transfer admin: { $error }
+err-failed-sync-preferences = Failed to sync preferences: { $error }
+err-failed-accept-rules = Failed to accept rules: { $error }
 err-message-too-long-details = { $error } ({ $length } characters, max { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = Invalid address '{ $address }': { $error }
+err-all-addresses-failed = Could not reach any of { $count } addresses (last error: { $error })
 err-could-not-resolve = Could not resolve address '{ $address }'
 err-connection-timeout = Connection timed out after { $seconds } seconds
 err-connection-failed = Connection failed: { $error }
+err-proxy-address-empty = Proxy address is required
+err-proxy-address-invalid = Proxy address must be a host name or IP address without spaces
+err-proxy-port-invalid = Proxy port must be between 1 and 65535
+err-quiet-hours-start-invalid = Quiet hours start must be a 24-hour time like 22:00
+err-quiet-hours-end-invalid = Quiet hours end must be a 24-hour time like 08:00
+err-message-format-unclosed-brace = Message format has an unclosed placeholder (double a brace to show it literally)
+err-message-format-unknown-placeholder = Message format has an unknown placeholder { $placeholder } (available: { $available })
+err-message-format-missing-placeholder = Message format must include { $placeholder }
+err-message-format-duplicate-placeholder = Message format may include { $placeholder } only once
+err-invite-not-a-link = Not a nexus:// invite link
+err-invite-invalid-address = Invite link has an invalid server address
+err-invite-invalid-port = Invite link port must be between 1 and 65535
+err-invite-invalid-name = Invite link has an invalid server name
+err-invite-invalid-fingerprint = Invite link has an invalid certificate fingerprint
+err-invite-credentials = Invite links must not contain a username or password
+err-invite-fingerprint-mismatch = Server certificate does not match the invite link (expected { $expected }, received { $received })
+err-proxy-connection-failed = Could not connect through proxy { $proxy }: { $error }
 err-tls-handshake-failed = TLS handshake failed: { $error }
 err-failed-send-handshake = Failed to send handshake: { $error }
 err-failed-read-handshake = Failed to read handshake response: { $error }
 err-handshake-failed = Handshake failed: { $error }
+err-client-too-old = This server needs a newer Nexus client. Please update to connect. ({ $error })
 err-failed-parse-handshake = Failed to parse handshake response: { $error }
 err-failed-send-login = Failed to send login: { $error }
 err-failed-read-login = Failed to read login response: { $error }
@@ -284,6 +475,7 @@ err-failed-set-config-permissions = Failed to set config file permissions: { $er
 # =============================================================================
 
 fingerprint-warning = This could indicate a security issue (MITM attack) or the server's certificate was regenerated. Only accept if you trust the server administrator.
+server-rules-intro = You must accept these rules before chatting on this server.
 
 # =============================================================================
 # User Info Display
@@ -293,15 +485,24 @@ user-info-username = Username:
 user-info-role = Role:
 user-info-role-admin = admin
 user-info-role-user = user
+user-info-role-guest = guest
+user-info-pronouns = Pronouns:
+user-info-bio = Bio:
 user-info-connected = Connected:
 user-info-connected-value = { $duration } ago
 user-info-connected-value-sessions = { $duration } ago ({ $count } sessions)
 user-info-features = Features:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (inactive)
 user-info-features-none = None
+user-info-permissions = Permissions:
+user-info-permissions-none = None
 user-info-locale = Locale:
 user-info-address = Address:
 user-info-addresses = Addresses:
+user-info-sessions = Sessions:
+user-info-session = Session { $id }
+user-info-this-session = this session
 user-info-created = Created:
 user-info-end = End of user info
 user-info-unknown = Unknown
@@ -339,10 +540,25 @@ cmd-help-usage = Usage: /{ $command } [command]
 cmd-help-escape-hint = Tip: Use // to send a message starting with /
 cmd-message-desc = Send a message to a user
 cmd-message-usage = Usage: /{ $command } <username> <message>
+cmd-query-desc = Open a message tab with a user without sending
+cmd-query-usage = Usage: /{ $command } <username>
+cmd-query-not-online = User is not online: { $username }
+cmd-query-self = You cannot message yourself
+cmd-reconnect-desc = Disconnect and reconnect to this server
+cmd-reconnect-usage = Usage: /{ $command }
+cmd-reconnect-no-bookmark = /{ $command } needs a bookmark to reconnect; this connection was not made from one
 cmd-userinfo-desc = Show information about a user
 cmd-userinfo-usage = Usage: /{ $command } <username>
+cmd-invite-desc = Copy an invite link for this server
+cmd-invite-usage = Usage: /{ $command }
+cmd-invite-copied = Invite link copied to clipboard: { $link }
 cmd-kick-desc = Kick a user from the server
 cmd-kick-usage = Usage: /{ $command } <username>
+cmd-limits-desc = View or set the message and topic length limits
+cmd-limits-usage = Usage: /{ $command } [message <characters>|topic <characters>]
+cmd-limits-arg-message = message
+cmd-limits-arg-topic = topic
+cmd-limits-current = Limits: messages up to { $message } characters, topic up to { $topic } characters
 cmd-topic-desc = View or manage the chat topic
 cmd-topic-usage = Usage: /{ $command } [set|clear] [topic]
 cmd-topic-arg-set = set
@@ -350,10 +566,66 @@ cmd-topic-arg-clear = clear
 cmd-topic-set-usage = Usage: /{ $command } set <topic>
 cmd-topic-none = No topic is set
 cmd-topic-permission-denied = You don't have permission to edit the topic
+cmd-page-desc = List or read the server's info pages (admins: set or delete them)
+cmd-page-usage = Usage: /{ $command } [<name>|set <name> <text>|delete <name>] (\n starts a new line)
+cmd-page-arg-set = set
+cmd-page-arg-delete = delete
+cmd-page-list = Pages: { $pages }
+cmd-page-none = This server has no pages
+cmd-page-saved = Page saved
+cmd-page-deleted = Page deleted
+cmd-pin-desc = List pinned messages or pin a user's latest message
+cmd-pin-usage = Usage: /{ $command } [username]
+cmd-pin-list = Pinned messages:
+cmd-pin-list-item = { $number }. { $username }: { $message } (pinned by { $pinned_by })
+cmd-pin-none = No messages are pinned
+cmd-pin-no-message = No recent message from { $username } to pin
+cmd-purge-desc = Purge chat history beyond the server's retention policy
+cmd-purge-usage = Usage: /{ $command }
+cmd-refresh-desc = Re-send a user's permissions from the server database
+cmd-refresh-usage = Usage: /{ $command } <username>
+cmd-transfer-desc = Make another account an admin, stepping down yourself unless you add keep
+cmd-transfer-usage = Usage: /{ $command } <username> [keep]
+cmd-transfer-arg-keep = keep
+cmd-seen-desc = Show when a user was last online
+cmd-seen-usage = Usage: /{ $command } <username>
+cmd-session-desc = Show this connection's session and traffic totals
+cmd-session-usage = Usage: /{ $command }
+cmd-session-info = Session { $id } connected to { $address }
+cmd-session-traffic = Traffic since connecting: { $sent } sent, { $received } received
+cmd-unpin-desc = Remove a pinned message
+cmd-unpin-usage = Usage: /{ $command } <number> (see /pin for numbers)
+cmd-announce-desc = List or manage the server's rotating announcements
+cmd-announce-usage = Usage: /{ $command } [add <message>|remove <number>]
+cmd-announce-arg-add = add
+cmd-announce-arg-remove = remove
+cmd-announce-list = Announcements (every { $minutes } minutes):
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = No announcements are set
+cmd-announce-added = Announcement added
+cmd-announce-removed = Announcement removed
+cmd-art-desc = Send ASCII or ANSI art from a file as a fixed-width block
+cmd-art-usage = Usage: /{ $command } [truncate] <file>
+cmd-art-arg-truncate = truncate
+cmd-art-too-long = The art is { $length } characters, over this server's limit of { $max }. Use /{ $command } { $truncate } <file> to send the lines that fit
 cmd-broadcast-desc = Send a broadcast to all users
 cmd-broadcast-usage = Usage: /{ $command } <message>
 cmd-clear-desc = Clear chat history for current tab
 cmd-clear-usage = Usage: /{ $command }
+cmd-delete-desc = Delete your latest message (moderators: a user's latest message)
+cmd-delete-usage = Usage: /{ $command } [username]
+cmd-delete-no-message = No recent message from { $username } to delete
+cmd-delete-permission-denied = You don't have permission to delete other users' messages
+cmd-edit-desc = Edit your latest message
+cmd-edit-usage = Usage: /{ $command } <message>
+cmd-edit-no-message = You have no recent message to edit
+cmd-events-desc = Show this connection's lifecycle events
+cmd-events-usage = Usage: /{ $command }
+cmd-events-header = Connection events (oldest first):
+cmd-events-empty = No connection events recorded yet
+cmd-report-desc = Copy a sanitized client and connection snapshot for bug reports
+cmd-report-usage = Usage: /{ $command }
+cmd-report-copied = Copied a bug report snapshot to the clipboard. Left out: passwords, server address, usernames, certificate fingerprint, server name and proxy address
 cmd-focus-desc = Focus server chat or a user's message tab
 cmd-focus-usage = Usage: /{ $command } [username]
 cmd-focus-not-found = User not found: { $name }
@@ -365,26 +637,65 @@ cmd-list-output = Users online: { $users } ({ $count } { $count ->
     [one] user
    *[other] users
 })
+cmd-maintenance-desc = View or toggle maintenance (read-only) mode
+cmd-maintenance-usage = Usage: /{ $command } [on|off]
+cmd-maintenance-arg-on = on
+cmd-maintenance-arg-off = off
+cmd-maintenance-on = Maintenance mode is on
+cmd-maintenance-off = Maintenance mode is off
+cmd-notice-desc = Show a notice to everyone as a banner
+cmd-notice-usage = Usage: /{ $command } reconnect | update <url> | <message>
+cmd-notify-desc = List or manage the users you're notified about when they come online
+cmd-notify-usage = Usage: /{ $command } [add <username>|del <username>]
+cmd-notify-arg-add = add
+cmd-notify-arg-del = del
+cmd-notify-no-bookmark = The watch list is saved to a bookmark, so /notify only works on bookmarked servers
+cmd-notify-none = You aren't watching anyone on this server
+cmd-notify-list = Watching: { $usernames }
+cmd-notify-added = You'll be notified when { $username } comes online
+cmd-notify-already = { $username } is already on your watch list
+cmd-notify-removed = No longer watching { $username }
+cmd-notify-not-watched = { $username } isn't on your watch list
 cmd-list-all-no-permission = You need user_edit or user_delete permission to list all users
 cmd-list-all-output = Users: { $users } ({ $count } { $count ->
     [one] user
    *[other] users
 })
+cmd-whoami-desc = Show your username, role, permissions and features
+cmd-whoami-usage = Usage: /{ $command }
 cmd-window-desc = Manage chat tabs
-cmd-window-usage = Usage: /{ $command } [next|prev|close [username]]
+cmd-window-usage = Usage: /{ $command } [next|prev|close [username]|popout [username]]
 cmd-window-arg-next = next
 cmd-window-arg-prev = prev
 cmd-window-arg-close = close
+cmd-window-arg-popout = popout
 cmd-window-list = Open tabs: { $tabs } ({ $count } { $count ->
     [one] tab
    *[other] tabs
 })
 cmd-window-close-server = Cannot close the server tab
+cmd-window-popout-server = Cannot pop out the server tab
 cmd-window-not-found = Tab not found: { $name }
 cmd-serverinfo-desc = Show server information
 cmd-serverinfo-usage = Usage: /{ $command }
 cmd-serverinfo-header = [server]
 cmd-serverinfo-end = End of server info
+cmd-slow-desc = View or set chat slow mode
+cmd-slow-usage = Usage: /{ $command } [seconds]
+cmd-slow-off = Slow mode is off
+cmd-slow-current = Slow mode: { $seconds } seconds between messages
+cmd-stats-desc = Show the server's connection history for the last hour
+cmd-stats-usage = Usage: /{ $command } [reset]
+cmd-stats-arg-reset = reset
+cmd-stats-current = Connections open now: { $count }
+cmd-stats-total = Last { $minutes } minutes: { $connections } connections, { $refused } refused, peak { $peak } open ({ $peak_per_ip } from one address)
+cmd-stats-group = { $time }: { $connections } connections, { $refused } refused, peak { $peak }
+cmd-stats-none = No connection history recorded yet
+cmd-set-desc = View or change chat preferences for this server
+cmd-set-usage = Usage: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = Join/leave messages are shown on this server
+cmd-set-joinleave-off = Join/leave messages are hidden on this server
+cmd-set-joinleave-default = Join/leave messages follow the global setting on this server
 
 # =============================================================================
 # About Panel
@@ -5,15 +5,21 @@
 # =============================================================================
 
 button-cancel = Cancelar
+button-continue = Continuar
 button-send = Enviar
+button-send-as-one-message = Enviar como un solo mensaje
 button-delete = Eliminar
 button-connect = Conectar
 button-save = Guardar
 button-create = Crear
 button-edit = Editar
+button-edit-profile = Editar perfil
 button-update = Actualizar
 
 button-accept-new-certificate = Aceptar Nuevo Certificado
+button-always-accept-certificate = Aceptar Siempre
+button-accept-rules = Aceptar Reglas
+button-disconnect = Desconectar
 button-close = Cerrar
 button-choose-avatar = Elegir Icono
 button-clear-avatar = Borrar
@@ -23,6 +29,8 @@ button-clear-avatar = Borrar
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = Conectar al Servidor
 title-add-bookmark = Añadir Marcador
 title-edit-server = Editar Servidor
@@ -35,7 +43,10 @@ title-settings = Configuración
 title-bookmarks = Marcadores
 title-users = Usuarios
 title-edit-server-info = Editar Info del Servidor
+title-edit-profile = Editar perfil
 title-fingerprint-mismatch = ¡Huella del Certificado No Coincide!
+title-server-rules = Reglas del Servidor
+title-server-banner = Aviso del servidor
 title-server-info = Info del Servidor
 title-user-info = Info del Usuario
 title-about = Acerca de
@@ -47,39 +58,99 @@ title-about = Acerca de
 placeholder-username = Nombre de usuario
 placeholder-password = Contraseña
 placeholder-port = Puerto
+placeholder-fallback-addresses = Direcciones alternativas (opcional, host:puerto separados por comas)
+placeholder-watched-users = Avisar al conectarse (opcional, nombres de usuario separados por comas)
 placeholder-server-address = Dirección del Servidor
 placeholder-server-name = Nombre del Servidor
 placeholder-username-optional = Nombre de usuario (opcional)
 placeholder-password-optional = Contraseña (opcional)
 placeholder-password-keep-current = Contraseña (dejar vacío para mantener actual)
 placeholder-message = Escribe un mensaje...
+placeholder-message-slow-mode = Modo lento: espera { $seconds }s...
+placeholder-message-maintenance = Modo de mantenimiento: la escritura está desactivada
 placeholder-no-permission = Sin permiso
 placeholder-broadcast-message = Escribe un mensaje de difusión...
 placeholder-server-description = Descripción del servidor
+placeholder-pronouns = p. ej. elle
+placeholder-bio = Una línea breve sobre ti
+placeholder-global-theme = Usar tema global
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = Auto-Conectar
+label-trust-certificate-changes = Aceptar siempre los cambios de certificado de este servidor (menos seguro)
 label-add-bookmark = Marcador
+label-invite-fingerprint = Huella del certificado del enlace de invitación: { $fingerprint }
 label-admin = Administrador
 label-enabled = Habilitado
 label-permissions = Permisos:
 label-expected-fingerprint = Huella esperada:
 label-received-fingerprint = Huella recibida:
 label-theme = Tema
+label-density = Densidad:
+density-comfortable = Cómoda
+density-compact = Compacta
+label-show-role-badges = Mostrar insignias de administrador y moderador en la lista de usuarios
+badge-admin = admin
+badge-moderator = mod
+badge-guest = invitado
+user-list-permissions = Permisos: { $permissions }
+user-list-away = Ausente
 label-chat-font-size = Tamaño de fuente:
+label-max-message-tabs = Máx. pestañas de mensajes:
+label-paste-confirm-lines = Confirmar pegados de al menos estas líneas (0 = nunca):
+label-collapse-message-lines = Contraer mensajes de más de estas líneas (0 = nunca):
+label-ctrl-enter-sends = Enviar con Ctrl+Intro (Intro añade una nueva línea)
+label-message-format = Formato de mensaje:
+message-format-preset-default = Predeterminado
+message-format-preset-compact = Compacto
+message-format-preset-irc = Estilo IRC
+message-format-preset-verbose = Detallado (con fecha)
+message-format-preset-custom = Personalizado
 label-show-connection-notifications = Mostrar notificaciones de conexión
+label-hide-repeated-topic = Mostrar el tema al reconectar solo si ha cambiado
+label-alert-on-disconnect = Hacer parpadear la ventana cuando se pierda una conexión
+label-alert-on-mention = Hacer parpadear la ventana cuando alguien te mencione
+label-focus-new-messages = Cambiar a los mensajes privados que lleguen mientras estoy inactivo
+label-quiet-hours = Horas de silencio (no hacer parpadear la ventana)
+label-quiet-hours-from = Desde
+label-quiet-hours-to = hasta
+label-watched-users-ignore-quiet-hours = Avisar de usuarios vigilados durante las horas de silencio
+label-auto-away = Marcarme como ausente por inactividad
+label-auto-away-minutes = Minutos sin actividad
+label-quiet-hours-active = Horas de silencio
 label-show-timestamps = Mostrar marcas de tiempo
 label-use-24-hour-time = Usar formato de 24 horas
 label-show-seconds = Mostrar segundos
+label-timezone = Zona horaria:
+timezone-local = Local del sistema
+label-privacy = Privacidad
+label-trusted-certificates = Certificados de confianza
+label-fingerprint-not-pinned = Aún no hay certificado fijado
+label-forget-passwords = No guardar contraseñas (preguntar al conectar)
+label-sync-preferences = Sincronizar tema y ajustes de visualización del chat con los servidores
+label-check-for-updates = Buscar un cliente más reciente al iniciar
+label-proxy = Proxy
+label-use-socks5-proxy = Conectar a través de un proxy SOCKS5 (Tor, I2P)
+label-proxy-address = Dirección:
+label-proxy-port = Puerto:
+label-diagnostics = Diagnóstico
+label-connection-log-path = Archivo de registro de conexión:
+placeholder-connection-log-path = Déjalo vacío para guardar los eventos solo en memoria
 label-server-name = Nombre:
 label-server-description = Descripción:
+label-pronouns = Pronombres:
+label-bio = Biografía:
 label-server-version = Versión:
+label-server-features = Funciones:
+label-users-online = Usuarios en línea:
 label-chat-topic = Tema del Chat:
 label-chat-topic-set-by = Tema Establecido Por:
 label-max-connections-per-ip = Máx. Conexiones Por IP:
+label-traffic = Tráfico:
+server-info-traffic-value = { $sent } enviados, { $received } recibidos
 label-avatar = Icono:
 label-details = Detalles técnicos
 label-chat-options = Opciones de chat
@@ -87,6 +158,14 @@ label-appearance = Apariencia
 label-image = Imagen
 label-general = General
 label-limits = Límites
+label-broadcast-target = Enviar a:
+broadcast-target-everyone = Todos
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = Chat
 
 # =============================================================================
 # Permission Display Names
@@ -98,6 +177,8 @@ permission-chat_send = Enviar Chat
 permission-chat_receive = Recibir Chat
 permission-chat_topic = Tema del Chat
 permission-chat_topic_edit = Editar Tema del Chat
+permission-chat_pin = Fijar en Chat
+permission-chat_moderate = Moderar Chat
 permission-user_broadcast = Difusión de Usuario
 permission-user_create = Crear Usuario
 permission-user_delete = Eliminar Usuario
@@ -116,16 +197,25 @@ tooltip-user-edit = Editar Usuario
 tooltip-server-info = Info del Servidor
 tooltip-about = Acerca de
 tooltip-settings = Configuración
+tooltip-quiet-hours-active = Horas de silencio activas: la ventana no parpadeará
 tooltip-hide-bookmarks = Ocultar Marcadores
 tooltip-show-bookmarks = Mostrar Marcadores
 tooltip-hide-user-list = Ocultar Lista de Usuarios
 tooltip-show-user-list = Mostrar Lista de Usuarios
 tooltip-disconnect = Desconectar
+tooltip-connection-health-unknown = Calidad de conexión aún no medida
+tooltip-connection-health-good = Conexión buena ({ $rtt } ms)
+tooltip-connection-health-fair = Conexión regular ({ $rtt } ms)
+tooltip-connection-health-poor = Conexión mala ({ $rtt } ms)
+tooltip-connection-health-no-response = Sin respuesta del servidor
 tooltip-edit = Editar
 tooltip-info = Info
 tooltip-message = Mensaje
 tooltip-kick = Expulsar
 tooltip-close = Cerrar
+tooltip-pop-out = Abrir en una ventana
+tooltip-unpin = Desfijar
+tooltip-pinned-by = Fijado por { $username }
 tooltip-add-bookmark = Añadir Marcador
 
 # =============================================================================
@@ -142,6 +232,7 @@ empty-no-users = No hay usuarios conectados
 # =============================================================================
 
 chat-tab-server = #servidor
+chat-tab-server-count = #servidor ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -156,18 +247,47 @@ chat-prefix-system = [SIS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [ANUNCIO]
+chat-prefix-pinned = [FIJADO]
+chat-message-edited = (editado)
+chat-message-deleted = (eliminado)
+chat-message-removed-by-moderator = (eliminado por un moderador)
+chat-show-more = Mostrar más
+chat-show-less = Mostrar menos
+chat-message-encrypted = (cifrado)
+chat-message-unreadable = (mensaje cifrado que no se pudo descifrar)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = Usuario expulsado exitosamente
+msg-session-kicked-success = Sesión desconectada
+msg-confirm-clear-fingerprint = ¿Borrar el certificado fijado de { $name }? La próxima conexión confiará en el certificado que presente el servidor.
 msg-broadcast-sent = Difusión enviada exitosamente
 msg-user-created = Usuario creado exitosamente
 msg-user-deleted = Usuario eliminado exitosamente
 msg-user-updated = Usuario actualizado exitosamente
 msg-permissions-updated = Tus permisos han sido actualizados
 msg-topic-updated = Tema actualizado exitosamente
+msg-message-pinned = Mensaje fijado
+msg-message-unpinned = Mensaje desfijado
+msg-chat-history-purged = Historial de chat depurado: { $count } mensajes eliminados
+msg-maintenance-enabled = El servidor está ahora en modo de mantenimiento; los cambios están desactivados
+msg-maintenance-disabled = El modo de mantenimiento ha terminado; los cambios vuelven a estar activados
+msg-new-login = Tu cuenta acaba de iniciar sesión desde { $address } ({ $source })
+msg-seen-online = { $username } está en línea ahora
+msg-seen-ago = { $username } fue visto por última vez hace { $duration } ({ $time })
+msg-seen-never = { $username } aún no se ha conectado
+msg-maintenance-banner = Modo de mantenimiento: el servidor es de solo lectura por ahora
+msg-admin-notice-reconnect = Aviso del administrador: vuelve a conectarte al servidor
+msg-admin-notice-update = Aviso del administrador: hay una actualización del cliente en { $url }
+msg-update-available = Nexus BBS { $version } está disponible
+msg-admin-notice = Aviso del administrador: { $message }
+msg-permissions-refreshed = Permisos actualizados
+msg-admin-transferred = { $username } ahora es administrador
+msg-copied-to-clipboard = Copiado al portapapeles
+msg-large-paste-confirm = Este mensaje contiene { $count } líneas pegadas. ¿Enviarlo?
 
 
 
@@ -179,9 +299,19 @@ msg-topic-cleared = Tema borrado por { $username }
 msg-topic-set = Tema establecido por { $username }: { $topic }
 msg-server-info-updated = Configuración del servidor actualizada
 msg-topic-display = Tema: { $topic }
+msg-connected-via-fallback = Conectado mediante la dirección alternativa { $address }
+msg-certificate-change-trusted = El certificado del servidor cambió y se aceptó porque este marcador confía en los cambios de certificado (nueva huella { $fingerprint })
 msg-user-connected = { $username } se conectó
 msg-user-disconnected = { $username } se desconectó
+msg-watched-user-online = { $username } se ha conectado
+msg-watched-users-online = Usuarios vigilados conectados: { $usernames }
 msg-disconnected = Desconectado: { $error }
+msg-enter-password-for = Introduce la contraseña para { $name }
+connect-step-progress = Paso { $step } de { $total }: { $description }
+connect-step-tcp = Conectando al servidor...
+connect-step-tls = Asegurando la conexión...
+connect-step-handshake = Negociando el protocolo...
+connect-step-login = Iniciando sesión...
 msg-connection-cancelled = Conexión cancelada debido a certificado no coincidente
 
 # =============================================================================
@@ -189,12 +319,17 @@ msg-connection-cancelled = Conexión cancelada debido a certificado no coinciden
 # =============================================================================
 
 err-connection-broken = Error de conexión
+err-account-disabled-while-connected = Un administrador ha desactivado tu cuenta. Contacta con él para que la reactive antes de volver a conectarte.
 err-failed-update-server-info = Error al actualizar información del servidor: { $error }
+err-failed-update-profile = No se pudo actualizar el perfil: { $error }
 err-user-kick-failed = Error al expulsar usuario
 err-no-shutdown-handle = Error de conexión: Sin manejador de cierre
 err-userlist-failed = Error al actualizar lista de usuarios
 err-port-invalid = El puerto debe ser un número válido (1-65535)
 err-no-chat-permission = No tienes permiso para enviar mensajes
+err-private-messages-disabled = Los mensajes privados están desactivados en este servidor
+err-popout-command = Los comandos solo se pueden usar en la ventana principal
+err-multiline-command = Los comandos deben caber en una línea (empieza con // para enviarlo como mensaje)
 
 # Network connection errors
 err-no-peer-certificates = No se encontraron certificados del servidor
@@ -218,6 +353,7 @@ err-message-required = El mensaje es requerido
 
 # Validation errors
 err-message-empty = El mensaje no puede estar vacío
+err-slow-mode-wait = El modo lento está activado. Espera { $seconds } segundos
 err-message-contains-newlines = El mensaje no puede contener saltos de línea
 err-message-invalid-characters = El mensaje contiene caracteres inválidos
 err-username-empty = El nombre de usuario no puede estar vacío
@@ -235,6 +371,12 @@ err-server-name-invalid-characters = El nombre del servidor contiene caracteres
 err-server-description-too-long = La descripción es demasiado larga (máx { $max } caracteres)
 err-server-description-contains-newlines = La descripción no puede contener saltos de línea
 err-server-description-invalid-characters = La descripción contiene caracteres inválidos
+err-bio-too-long = La biografía es demasiado larga (máx. { $max } caracteres)
+err-bio-contains-newlines = La biografía no puede contener saltos de línea
+err-bio-invalid-characters = La biografía contiene caracteres no válidos
+err-pronouns-too-long = Los pronombres son demasiado largos (máx. { $max } caracteres)
+err-pronouns-contains-newlines = Los pronombres no pueden contener saltos de línea
+err-pronouns-invalid-characters = Los pronombres contienen caracteres no válidos
 err-failed-send-update = Error al enviar actualización: { $error }
 
 # =============================================================================
@@ -242,25 +384,68 @@ err-failed-send-update = Error al enviar actualización: { $error }
 # =============================================================================
 
 err-failed-save-config = Error al guardar configuración: { $error }
+err-connection-log-write = No se pudo escribir el registro de conexión { $path }: { $error }
 err-failed-save-settings = Error al guardar configuración: { $error }
 err-invalid-port-bookmark = Puerto inválido en marcador: { $name }
+err-fallback-address-invalid = Dirección alternativa inválida: { $address }
 err-failed-send-broadcast = Error al enviar difusión: { $error }
 err-failed-send-message = Error al enviar mensaje: { $error }
+err-command-unsupported = El servidor no admite /{ $command }
 err-failed-create-user = Error al crear usuario: { $error }
 err-failed-delete-user = Error al eliminar usuario: { $error }
 err-failed-update-user = Error al actualizar usuario: { $error }
 err-failed-update-topic = Error al actualizar tema: { $error }
+err-failed-pin-message = No se pudo fijar el mensaje: { $error }
+err-failed-unpin-message = No se pudo desfijar el mensaje: { $error }
+err-failed-edit-message = Error al editar el mensaje: { $error }
+err-failed-delete-message = Error al eliminar el mensaje: { $error }
+err-failed-purge-history = No se pudo depurar el historial de chat: { $error }
+err-failed-announcement = No se pudieron actualizar los anuncios: { $error }
+err-failed-stats = No se pudieron obtener las estadísticas de conexión: { $error }
+err-failed-history = No se pudieron cargar los mensajes anteriores: { $error }
+err-art-read = No se pudo leer { $path }: { $error }
+err-art-file-too-large = Los archivos de arte pueden tener como máximo { $max } KiB
+err-art-empty = El archivo de arte no tiene nada que enviar
+err-art-fence = El arte no puede contener ``` (cerraría el bloque)
+err-art-first-line = Ni siquiera la primera línea cabe en el límite del servidor de { $max } caracteres
+err-failed-page = La solicitud de página falló: { $error }
+err-failed-set-maintenance = No se pudo cambiar el modo de mantenimiento: { $error }
+err-failed-send-admin-notice = No se pudo enviar el aviso: { $error }
+err-notice-url-invalid = El enlace debe empezar por http:// o https:// y no contener espacios
+err-failed-refresh-permissions = No se pudieron actualizar los permisos: { $error }
+err-failed-transfer-admin = Error al transferir la administración: { $error }
+err-failed-sync-preferences = No se pudieron sincronizar las preferencias: { $error }
+err-failed-accept-rules = Error al aceptar las reglas: { $error }
 err-message-too-long-details = { $error } ({ $length } caracteres, máx { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = Dirección inválida '{ $address }': { $error }
+err-all-addresses-failed = No se pudo conectar a ninguna de las { $count } direcciones (último error: { $error })
 err-could-not-resolve = No se pudo resolver la dirección '{ $address }'
 err-connection-timeout = Tiempo de conexión agotado después de { $seconds } segundos
 err-connection-failed = Error de conexión: { $error }
+err-proxy-address-empty = La dirección del proxy es obligatoria
+err-proxy-address-invalid = La dirección del proxy debe ser un nombre de host o una dirección IP sin espacios
+err-proxy-port-invalid = El puerto del proxy debe estar entre 1 y 65535
+err-quiet-hours-start-invalid = El inicio de las horas de silencio debe ser una hora de 24 horas como 22:00
+err-quiet-hours-end-invalid = El fin de las horas de silencio debe ser una hora de 24 horas como 08:00
+err-message-format-unclosed-brace = El formato de mensaje tiene un marcador sin cerrar (duplica la llave para mostrarla literalmente)
+err-message-format-unknown-placeholder = El formato de mensaje tiene un marcador desconocido { $placeholder } (disponibles: { $available })
+err-message-format-missing-placeholder = El formato de mensaje debe incluir { $placeholder }
+err-message-format-duplicate-placeholder = El formato de mensaje solo puede incluir { $placeholder } una vez
+err-invite-not-a-link = No es un enlace de invitación nexus://
+err-invite-invalid-address = El enlace de invitación tiene una dirección de servidor no válida
+err-invite-invalid-port = El puerto del enlace de invitación debe estar entre 1 y 65535
+err-invite-invalid-name = El enlace de invitación tiene un nombre de servidor no válido
+err-invite-invalid-fingerprint = El enlace de invitación tiene una huella de certificado no válida
+err-invite-credentials = Los enlaces de invitación no deben contener usuario ni contraseña
+err-invite-fingerprint-mismatch = El certificado del servidor no coincide con el enlace de invitación (se esperaba { $expected }, se recibió { $received })
+err-proxy-connection-failed = No se pudo conectar a través del proxy { $proxy }: { $error }
 err-tls-handshake-failed = Error en el handshake TLS: { $error }
 err-failed-send-handshake = Error al enviar handshake: { $error }
 err-failed-read-handshake = Error al leer respuesta del handshake: { $error }
 err-handshake-failed = Error en el handshake: { $error }
+err-client-too-old = Este servidor necesita un cliente de Nexus más reciente. Actualiza para conectarte. ({ $error })
 err-failed-parse-handshake = Error al analizar respuesta del handshake: { $error }
 err-failed-send-login = Error al enviar inicio de sesión: { $error }
 err-failed-read-login = Error al leer respuesta de inicio de sesión: { $error }
@@ -277,6 +462,7 @@ err-failed-set-config-permissions = Error al establecer permisos del archivo de
 # =============================================================================
 
 fingerprint-warning = Esto podría indicar un problema de seguridad (ataque MITM) o que el certificado del servidor fue regenerado. Solo acepta si confías en el administrador del servidor.
+server-rules-intro = Debes aceptar estas reglas antes de chatear en este servidor.
 
 # =============================================================================
 # User Info Display
@@ -286,15 +472,24 @@ user-info-username = Usuario:
 user-info-role = Rol:
 user-info-role-admin = admin
 user-info-role-user = usuario
+user-info-role-guest = invitado
+user-info-pronouns = Pronombres:
+user-info-bio = Biografía:
 user-info-connected = Conectado:
 user-info-connected-value = hace { $duration }
 user-info-connected-value-sessions = hace { $duration } ({ $count } sesiones)
 user-info-features = Características:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (inactiva)
 user-info-features-none = Ninguna
+user-info-permissions = Permisos:
+user-info-permissions-none = Ninguno
 user-info-locale = Idioma:
 user-info-address = Dirección:
 user-info-addresses = Direcciones:
+user-info-sessions = Sesiones:
+user-info-session = Sesión { $id }
+user-info-this-session = esta sesión
 user-info-created = Creado:
 user-info-end = Fin de información del usuario
 user-info-unknown = Desconocido
@@ -331,18 +526,60 @@ cmd-help-desc = Mostrar comandos disponibles
 cmd-help-escape-hint = Consejo: Usa // para enviar un mensaje que comience con /
 cmd-message-desc = Enviar un mensaje a un usuario
 cmd-message-usage = Uso: /{ $command } <usuario> <mensaje>
+cmd-query-desc = Abrir una pestaña de mensajes con un usuario sin enviar
+cmd-query-usage = Uso: /{ $command } <usuario>
+cmd-query-not-online = El usuario no está en línea: { $username }
+cmd-query-self = No puedes enviarte mensajes a ti mismo
+cmd-reconnect-desc = Desconectar y volver a conectar a este servidor
+cmd-reconnect-usage = Uso: /{ $command }
+cmd-reconnect-no-bookmark = /{ $command } necesita un marcador para reconectar; esta conexión no se creó desde uno
 cmd-userinfo-desc = Mostrar información sobre un usuario
 cmd-userinfo-usage = Uso: /{ $command } <usuario>
+cmd-invite-desc = Copiar un enlace de invitación a este servidor
+cmd-invite-usage = Uso: /{ $command }
+cmd-invite-copied = Enlace de invitación copiado al portapapeles: { $link }
 cmd-kick-desc = Expulsar a un usuario del servidor
 cmd-kick-usage = Uso: /{ $command } <usuario>
+cmd-limits-desc = Ver o cambiar los límites de longitud de mensajes y tema
+cmd-limits-usage = Uso: /{ $command } [mensaje <caracteres>|tema <caracteres>]
+cmd-limits-arg-message = mensaje
+cmd-limits-arg-topic = tema
+cmd-limits-current = Límites: mensajes de hasta { $message } caracteres, tema de hasta { $topic } caracteres
 cmd-topic-desc = Ver o gestionar el tema del chat
 cmd-topic-usage = Uso: /{ $command } [set|clear] [tema]
 cmd-topic-set-usage = Uso: /{ $command } set <tema>
 cmd-topic-none = No hay tema establecido
+cmd-announce-desc = Ver o gestionar los anuncios rotativos del servidor
+cmd-announce-usage = Uso: /{ $command } [añadir <mensaje>|quitar <número>]
+cmd-announce-arg-add = añadir
+cmd-announce-arg-remove = quitar
+cmd-announce-list = Anuncios (cada { $minutes } minutos):
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = No hay anuncios configurados
+cmd-announce-added = Anuncio añadido
+cmd-announce-removed = Anuncio eliminado
+cmd-art-desc = Enviar arte ASCII o ANSI desde un archivo como bloque de ancho fijo
+cmd-art-usage = Uso: /{ $command } [recortar] <archivo>
+cmd-art-arg-truncate = recortar
+cmd-art-too-long = El arte tiene { $length } caracteres, más que el límite del servidor de { $max }. Usa /{ $command } { $truncate } <archivo> para enviar las líneas que caben
 cmd-broadcast-desc = Enviar un mensaje a todos los usuarios
 cmd-broadcast-usage = Uso: /{ $command } <mensaje>
 cmd-clear-desc = Limpiar historial de chat de la pestaña actual
 cmd-clear-usage = Uso: /{ $command }
+cmd-delete-desc = Eliminar tu último mensaje (moderadores: el último mensaje de un usuario)
+cmd-delete-usage = Uso: /{ $command } [usuario]
+cmd-delete-no-message = No hay ningún mensaje reciente de { $username } para eliminar
+cmd-delete-permission-denied = No tienes permiso para eliminar mensajes de otros usuarios
+cmd-edit-desc = Editar tu último mensaje
+cmd-edit-usage = Uso: /{ $command } <mensaje>
+cmd-edit-no-message = No tienes ningún mensaje reciente para editar
+cmd-events-desc = Mostrar los eventos del ciclo de vida de esta conexión
+cmd-events-usage = Uso: /{ $command }
+cmd-events-header = Eventos de conexión (los más antiguos primero):
+cmd-events-empty = Aún no se han registrado eventos de conexión
+cmd-report-desc = Copiar un resumen depurado del cliente y la conexión para informes de errores
+cmd-report-usage = Uso: /{ $command }
+cmd-report-copied = Informe de error copiado al portapapeles. Omitido: contraseñas, dirección del servidor, nombres de usuario, huella del certificado, nombre del servidor y dirección del proxy
 cmd-focus-desc = Enfocar chat del servidor o ventana de mensajes de un usuario
 cmd-focus-usage = Uso: /{ $command } [usuario]
 cmd-focus-not-found = Usuario no encontrado: { $name }
@@ -354,30 +591,98 @@ cmd-list-output = Usuarios en línea: { $users } ({ $count } { $count ->
     [one] usuario
    *[other] usuarios
 })
+cmd-maintenance-desc = Ver o alternar el modo de mantenimiento (solo lectura)
+cmd-maintenance-usage = Uso: /{ $command } [activar|desactivar]
+cmd-maintenance-arg-on = activar
+cmd-maintenance-arg-off = desactivar
+cmd-maintenance-on = El modo de mantenimiento está activado
+cmd-maintenance-off = El modo de mantenimiento está desactivado
+cmd-notice-desc = Mostrar un aviso a todos como banner
+cmd-notice-usage = Uso: /{ $command } reconnect | update <url> | <mensaje>
+cmd-notify-desc = Ver o gestionar los usuarios de los que recibes aviso al conectarse
+cmd-notify-usage = Uso: /{ $command } [añadir <usuario>|quitar <usuario>]
+cmd-notify-arg-add = añadir
+cmd-notify-arg-del = quitar
+cmd-notify-no-bookmark = La lista de vigilancia se guarda en un marcador, así que /notify solo funciona en servidores con marcador
+cmd-notify-none = No vigilas a nadie en este servidor
+cmd-notify-list = Vigilando: { $usernames }
+cmd-notify-added = Recibirás un aviso cuando { $username } se conecte
+cmd-notify-already = { $username } ya está en tu lista de vigilancia
+cmd-notify-removed = Ya no vigilas a { $username }
+cmd-notify-not-watched = { $username } no está en tu lista de vigilancia
 cmd-list-all-no-permission = Necesitas permiso user_edit o user_delete para listar todos los usuarios
 cmd-list-all-output = Usuarios: { $users } ({ $count } { $count ->
     [one] usuario
    *[other] usuarios
 })
+cmd-whoami-desc = Muestra tu nombre de usuario, rol, permisos y características
+cmd-whoami-usage = Uso: /{ $command }
 cmd-help-usage = Uso: /{ $command } [comando]
 cmd-topic-arg-set = establecer
 cmd-topic-arg-clear = limpiar
 cmd-topic-permission-denied = No tienes permiso para editar el tema
+cmd-page-desc = Lista o lee las páginas informativas del servidor (administradores: crearlas o eliminarlas)
+cmd-page-usage = Uso: /{ $command } [<nombre>|establecer <nombre> <texto>|eliminar <nombre>] (\n empieza una línea nueva)
+cmd-page-arg-set = establecer
+cmd-page-arg-delete = eliminar
+cmd-page-list = Páginas: { $pages }
+cmd-page-none = Este servidor no tiene páginas
+cmd-page-saved = Página guardada
+cmd-page-deleted = Página eliminada
+cmd-pin-desc = Listar mensajes fijados o fijar el último mensaje de un usuario
+cmd-pin-usage = Uso: /{ $command } [usuario]
+cmd-pin-list = Mensajes fijados:
+cmd-pin-list-item = { $number }. { $username }: { $message } (fijado por { $pinned_by })
+cmd-pin-none = No hay mensajes fijados
+cmd-pin-no-message = No hay un mensaje reciente de { $username } para fijar
+cmd-purge-desc = Depurar el historial de chat fuera de la política de retención del servidor
+cmd-purge-usage = Uso: /{ $command }
+cmd-refresh-desc = Reenviar los permisos de un usuario desde la base de datos del servidor
+cmd-refresh-usage = Uso: /{ $command } <usuario>
+cmd-transfer-desc = Hacer administrador a otra cuenta y dejar de serlo tú, salvo que añadas mantener
+cmd-transfer-usage = Uso: /{ $command } <usuario> [mantener]
+cmd-transfer-arg-keep = mantener
+cmd-seen-desc = Mostrar cuándo estuvo un usuario en línea por última vez
+cmd-seen-usage = Uso: /{ $command } <usuario>
+cmd-session-desc = Mostrar la sesión y el tráfico de esta conexión
+cmd-session-usage = Uso: /{ $command }
+cmd-session-info = Sesión { $id } conectada a { $address }
+cmd-session-traffic = Tráfico desde la conexión: { $sent } enviados, { $received } recibidos
+cmd-unpin-desc = Quitar un mensaje fijado
+cmd-unpin-usage = Uso: /{ $command } <número> (consulta /pin para ver los números)
 cmd-window-desc = Gestionar pestañas de chat
-cmd-window-usage = Uso: /{ $command } [siguiente|anterior|cerrar [usuario]]
+cmd-window-usage = Uso: /{ $command } [siguiente|anterior|cerrar [usuario]|separar [usuario]]
 cmd-window-arg-next = siguiente
 cmd-window-arg-prev = anterior
 cmd-window-arg-close = cerrar
+cmd-window-arg-popout = separar
 cmd-window-list = Pestañas abiertas: { $tabs } ({ $count } { $count ->
     [one] pestaña
    *[other] pestañas
 })
 cmd-window-close-server = No se puede cerrar la pestaña del servidor
+cmd-window-popout-server = No se puede separar la pestaña del servidor
 cmd-window-not-found = Pestaña no encontrada: { $name }
 cmd-serverinfo-desc = Mostrar información del servidor
 cmd-serverinfo-usage = Uso: /{ $command }
 cmd-serverinfo-header = [servidor]
 cmd-serverinfo-end = Fin de información del servidor
+cmd-slow-desc = Ver o configurar el modo lento del chat
+cmd-slow-usage = Uso: /{ $command } [segundos]
+cmd-slow-off = El modo lento está desactivado
+cmd-slow-current = Modo lento: { $seconds } segundos entre mensajes
+cmd-stats-desc = Mostrar el historial de conexiones del servidor de la última hora
+cmd-stats-usage = Uso: /{ $command } [reiniciar]
+cmd-stats-arg-reset = reiniciar
+cmd-stats-current = Conexiones abiertas ahora: { $count }
+cmd-stats-total = Últimos { $minutes } minutos: { $connections } conexiones, { $refused } rechazadas, máximo { $peak } abiertas ({ $peak_per_ip } desde una dirección)
+cmd-stats-group = { $time }: { $connections } conexiones, { $refused } rechazadas, máximo { $peak }
+cmd-stats-none = Aún no hay historial de conexiones
+cmd-set-desc = Ver o cambiar las preferencias de chat de este servidor
+cmd-set-usage = Uso: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = Los mensajes de entrada/salida se muestran en este servidor
+cmd-set-joinleave-off = Los mensajes de entrada/salida están ocultos en este servidor
+cmd-set-joinleave-default = Los mensajes de entrada/salida siguen la configuración global en este servidor
 
 # =============================================================================
 # About Panel
@@ -387,6 +692,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = Elegir imagen
 button-clear-image = Borrar
+button-clear-theme = Borrar
+button-copy-fingerprint = Copiar
+button-clear-fingerprint = Borrar
+button-dismiss = Descartar
+button-reconnect = Reconectar
+button-open-link = Abrir enlace
 label-server-image = Imagen del servidor:
 err-server-image-too-large = La imagen del servidor es demasiado grande (máx. 512KB)
 err-server-image-invalid-format = Formato de imagen del servidor inválido (debe ser una URI de datos con codificación base64)
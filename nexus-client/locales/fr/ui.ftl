@@ -5,15 +5,21 @@
 # =============================================================================
 
 button-cancel = Annuler
+button-continue = Continuer
 button-send = Envoyer
+button-send-as-one-message = Envoyer en un seul message
 button-delete = Supprimer
 button-connect = Connecter
 button-save = Enregistrer
 button-create = Créer
 button-edit = Modifier
+button-edit-profile = Modifier le profil
 button-update = Mettre à jour
 
 button-accept-new-certificate = Accepter le Nouveau Certificat
+button-always-accept-certificate = Toujours Accepter
+button-accept-rules = Accepter les Règles
+button-disconnect = Déconnecter
 button-close = Fermer
 button-choose-avatar = Choisir une Icône
 button-clear-avatar = Effacer
@@ -23,6 +29,8 @@ button-clear-avatar = Effacer
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = Connexion au serveur
 title-add-bookmark = Ajouter un signet
 title-edit-server = Modifier le serveur
@@ -35,7 +43,10 @@ title-settings = Paramètres
 title-bookmarks = Signets
 title-users = Utilisateurs
 title-edit-server-info = Modifier les infos serveur
+title-edit-profile = Modifier le profil
 title-fingerprint-mismatch = Empreinte du certificat non concordante !
+title-server-rules = Règles du Serveur
+title-server-banner = Avis du serveur
 title-server-info = Infos Serveur
 title-user-info = Infos Utilisateur
 title-about = À propos
@@ -47,39 +58,99 @@ title-about = À propos
 placeholder-username = Nom d'utilisateur
 placeholder-password = Mot de passe
 placeholder-port = Port
+placeholder-fallback-addresses = Adresses de secours (facultatif, hôte:port séparés par des virgules)
+placeholder-watched-users = Prévenir à la connexion (facultatif, noms d'utilisateur séparés par des virgules)
 placeholder-server-address = Adresse du serveur
 placeholder-server-name = Nom du serveur
 placeholder-username-optional = Nom d'utilisateur (optionnel)
 placeholder-password-optional = Mot de passe (optionnel)
 placeholder-password-keep-current = Mot de passe (laisser vide pour conserver l'actuel)
 placeholder-message = Tapez un message...
+placeholder-message-slow-mode = Mode lent : attendez { $seconds } s...
+placeholder-message-maintenance = Mode maintenance : saisie désactivée
 placeholder-no-permission = Pas de permission
 placeholder-broadcast-message = Entrez le message de diffusion...
 placeholder-server-description = Description du serveur
+placeholder-pronouns = ex. iel
+placeholder-bio = Une courte ligne sur vous
+placeholder-global-theme = Utiliser le thème global
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = Connexion auto
+label-trust-certificate-changes = Toujours accepter les changements de certificat de ce serveur (moins sûr)
 label-add-bookmark = Ajouter un favori
+label-invite-fingerprint = Empreinte du certificat du lien d'invitation : { $fingerprint }
 label-admin = Administrateur
 label-enabled = Activé
 label-permissions = Permissions :
 label-expected-fingerprint = Empreinte attendue :
 label-received-fingerprint = Empreinte reçue :
 label-theme = Thème
+label-density = Densité :
+density-comfortable = Confortable
+density-compact = Compacte
+label-show-role-badges = Afficher les badges administrateur et modérateur dans la liste des utilisateurs
+badge-admin = admin
+badge-moderator = modo
+badge-guest = invité
+user-list-permissions = Permissions : { $permissions }
+user-list-away = Absent
 label-chat-font-size = Taille de police :
+label-max-message-tabs = Onglets de messages max :
+label-paste-confirm-lines = Confirmer les collages d'au moins ce nombre de lignes (0 = jamais) :
+label-collapse-message-lines = Replier les messages de plus de ce nombre de lignes (0 = jamais) :
+label-ctrl-enter-sends = Envoyer avec Ctrl+Entrée (Entrée ajoute une nouvelle ligne)
+label-message-format = Format des messages :
+message-format-preset-default = Par défaut
+message-format-preset-compact = Compact
+message-format-preset-irc = Style IRC
+message-format-preset-verbose = Détaillé (avec la date)
+message-format-preset-custom = Personnalisé
 label-show-connection-notifications = Afficher les notifications de connexion
+label-hide-repeated-topic = N'afficher le sujet à la reconnexion que s'il a changé
+label-alert-on-disconnect = Faire clignoter la fenêtre quand une connexion est perdue
+label-alert-on-mention = Faire clignoter la fenêtre quand quelqu'un vous mentionne
+label-focus-new-messages = Basculer vers les messages privés reçus pendant mon inactivité
+label-quiet-hours = Heures calmes (ne pas faire clignoter la fenêtre)
+label-quiet-hours-from = De
+label-quiet-hours-to = à
+label-watched-users-ignore-quiet-hours = Prévenir pour les utilisateurs suivis pendant les heures calmes
+label-auto-away = M'indiquer absent en cas d'inactivité
+label-auto-away-minutes = Minutes sans activité
+label-quiet-hours-active = Heures calmes
 label-show-timestamps = Afficher les horodatages
 label-use-24-hour-time = Utiliser le format 24 heures
 label-show-seconds = Afficher les secondes
+label-timezone = Fuseau horaire :
+timezone-local = Local du système
+label-privacy = Confidentialité
+label-trusted-certificates = Certificats de confiance
+label-fingerprint-not-pinned = Aucun certificat épinglé pour l'instant
+label-forget-passwords = Ne pas enregistrer les mots de passe (demander à la connexion)
+label-sync-preferences = Synchroniser le thème et l'affichage du chat avec les serveurs
+label-check-for-updates = Rechercher un client plus récent au démarrage
+label-proxy = Proxy
+label-use-socks5-proxy = Se connecter via un proxy SOCKS5 (Tor, I2P)
+label-proxy-address = Adresse :
+label-proxy-port = Port :
+label-diagnostics = Diagnostic
+label-connection-log-path = Fichier journal des connexions :
+placeholder-connection-log-path = Laisser vide pour garder les événements en mémoire uniquement
 label-server-name = Nom :
 label-server-description = Description :
+label-pronouns = Pronoms :
+label-bio = Bio :
 label-server-version = Version :
+label-server-features = Fonctionnalités :
+label-users-online = Utilisateurs en ligne :
 label-chat-topic = Sujet du Chat :
 label-chat-topic-set-by = Sujet Défini Par :
 label-max-connections-per-ip = Max. Connexions Par IP :
+label-traffic = Trafic :
+server-info-traffic-value = { $sent } envoyés, { $received } reçus
 label-avatar = Icône :
 label-details = Détails techniques
 label-chat-options = Options de chat
@@ -87,6 +158,14 @@ label-appearance = Apparence
 label-image = Image
 label-general = Général
 label-limits = Limites
+label-broadcast-target = Envoyer à :
+broadcast-target-everyone = Tout le monde
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = Chat
 
 # =============================================================================
 # Permission Display Names
@@ -98,6 +177,8 @@ permission-chat_send = Envoyer Chat
 permission-chat_receive = Recevoir Chat
 permission-chat_topic = Sujet du Chat
 permission-chat_topic_edit = Modifier Sujet du Chat
+permission-chat_pin = Épingler dans le Chat
+permission-chat_moderate = Modérer le Chat
 permission-user_broadcast = Diffusion Utilisateur
 permission-user_create = Créer Utilisateur
 permission-user_delete = Supprimer Utilisateur
@@ -116,16 +197,25 @@ tooltip-user-edit = Modifier Utilisateur
 tooltip-server-info = Infos Serveur
 tooltip-about = À propos
 tooltip-settings = Paramètres
+tooltip-quiet-hours-active = Heures calmes actives : la fenêtre ne clignotera pas
 tooltip-hide-bookmarks = Masquer les signets
 tooltip-show-bookmarks = Afficher les signets
 tooltip-hide-user-list = Masquer la liste des utilisateurs
 tooltip-show-user-list = Afficher la liste des utilisateurs
 tooltip-disconnect = Déconnecter
+tooltip-connection-health-unknown = Qualité de connexion pas encore mesurée
+tooltip-connection-health-good = Connexion bonne ({ $rtt } ms)
+tooltip-connection-health-fair = Connexion moyenne ({ $rtt } ms)
+tooltip-connection-health-poor = Connexion mauvaise ({ $rtt } ms)
+tooltip-connection-health-no-response = Aucune réponse du serveur
 tooltip-edit = Modifier
 tooltip-info = Info
 tooltip-message = Message
 tooltip-kick = Expulser
 tooltip-close = Fermer
+tooltip-pop-out = Ouvrir dans une fenêtre
+tooltip-unpin = Désépingler
+tooltip-pinned-by = Épinglé par { $username }
 tooltip-add-bookmark = Ajouter un favori
 
 # =============================================================================
@@ -142,6 +232,7 @@ empty-no-users = Aucun utilisateur en ligne
 # =============================================================================
 
 chat-tab-server = #serveur
+chat-tab-server-count = #serveur ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -156,18 +247,47 @@ chat-prefix-system = [SYS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [ANNONCE]
+chat-prefix-pinned = [ÉPINGLÉ]
+chat-message-edited = (modifié)
+chat-message-deleted = (supprimé)
+chat-message-removed-by-moderator = (supprimé par un modérateur)
+chat-show-more = Afficher plus
+chat-show-less = Afficher moins
+chat-message-encrypted = (chiffré)
+chat-message-unreadable = (message chiffré qui n'a pas pu être déchiffré)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = Utilisateur expulsé avec succès
+msg-session-kicked-success = Session déconnectée
+msg-confirm-clear-fingerprint = Effacer le certificat épinglé de { $name } ? La prochaine connexion fera confiance au certificat présenté par le serveur.
 msg-broadcast-sent = Diffusion envoyée avec succès
 msg-user-created = Utilisateur créé avec succès
 msg-user-deleted = Utilisateur supprimé avec succès
 msg-user-updated = Utilisateur mis à jour avec succès
 msg-permissions-updated = Vos permissions ont été mises à jour
 msg-topic-updated = Sujet mis à jour avec succès
+msg-message-pinned = Message épinglé
+msg-message-unpinned = Message désépinglé
+msg-chat-history-purged = Historique du chat purgé : { $count } messages supprimés
+msg-maintenance-enabled = Le serveur est maintenant en mode maintenance ; les modifications sont désactivées
+msg-maintenance-disabled = Le mode maintenance est terminé ; les modifications sont de nouveau possibles
+msg-new-login = Votre compte vient de se connecter depuis { $address } ({ $source })
+msg-seen-online = { $username } est en ligne
+msg-seen-ago = { $username } a été vu pour la dernière fois il y a { $duration } ({ $time })
+msg-seen-never = { $username } ne s'est encore jamais connecté
+msg-maintenance-banner = Mode maintenance : le serveur est en lecture seule pour le moment
+msg-admin-notice-reconnect = Avis de l'administrateur : veuillez vous reconnecter au serveur
+msg-admin-notice-update = Avis de l'administrateur : une mise à jour du client est disponible sur { $url }
+msg-update-available = Nexus BBS { $version } est disponible
+msg-admin-notice = Avis de l'administrateur : { $message }
+msg-permissions-refreshed = Permissions actualisées
+msg-admin-transferred = { $username } est maintenant administrateur
+msg-copied-to-clipboard = Copié dans le presse-papiers
+msg-large-paste-confirm = Ce message contient { $count } lignes collées. L'envoyer ?
 
 # =============================================================================
 # Dynamic Messages (with parameters)
@@ -177,9 +297,19 @@ msg-topic-cleared = Sujet effacé par { $username }
 msg-topic-set = Sujet défini par { $username } : { $topic }
 msg-server-info-updated = Configuration du serveur mise à jour
 msg-topic-display = Sujet : { $topic }
+msg-connected-via-fallback = Connecté via l'adresse de secours { $address }
+msg-certificate-change-trusted = Le certificat du serveur a changé et a été accepté car ce favori fait confiance aux changements de certificat (nouvelle empreinte { $fingerprint })
 msg-user-connected = { $username } s'est connecté
 msg-user-disconnected = { $username } s'est déconnecté
+msg-watched-user-online = { $username } est maintenant en ligne
+msg-watched-users-online = Utilisateurs suivis en ligne : { $usernames }
 msg-disconnected = Déconnecté : { $error }
+msg-enter-password-for = Saisissez le mot de passe pour { $name }
+connect-step-progress = Étape { $step } sur { $total } : { $description }
+connect-step-tcp = Connexion au serveur...
+connect-step-tls = Sécurisation de la connexion...
+connect-step-handshake = Négociation du protocole...
+connect-step-login = Connexion en cours...
 msg-connection-cancelled = Connexion annulée en raison d'un certificat non concordant
 
 # =============================================================================
@@ -187,12 +317,17 @@ msg-connection-cancelled = Connexion annulée en raison d'un certificat non conc
 # =============================================================================
 
 err-connection-broken = Erreur de connexion
+err-account-disabled-while-connected = Votre compte a été désactivé par un administrateur. Contactez-le pour le faire réactiver avant de vous reconnecter.
 err-failed-update-server-info = Échec de la mise à jour des informations du serveur : { $error }
+err-failed-update-profile = Échec de la mise à jour du profil : { $error }
 err-user-kick-failed = Échec de l'expulsion de l'utilisateur
 err-no-shutdown-handle = Erreur de connexion : Pas de gestionnaire d'arrêt
 err-userlist-failed = Échec de l'actualisation de la liste des utilisateurs
 err-port-invalid = Le port doit être un numéro valide (1-65535)
 err-no-chat-permission = Vous n'avez pas la permission d'envoyer des messages
+err-private-messages-disabled = Les messages privés sont désactivés sur ce serveur
+err-popout-command = Les commandes ne peuvent être utilisées que dans la fenêtre principale
+err-multiline-command = Les commandes doivent tenir sur une seule ligne (commencez par // pour l'envoyer comme message)
 
 # Network connection errors
 err-no-peer-certificates = Aucun certificat de serveur trouvé
@@ -216,6 +351,7 @@ err-message-required = Le message est requis
 
 # Validation errors
 err-message-empty = Le message ne peut pas être vide
+err-slow-mode-wait = Le mode lent est activé. Veuillez attendre { $seconds } secondes
 err-message-contains-newlines = Le message ne peut pas contenir de sauts de ligne
 err-message-invalid-characters = Le message contient des caractères invalides
 err-username-empty = Le nom d'utilisateur ne peut pas être vide
@@ -233,6 +369,12 @@ err-server-name-invalid-characters = Le nom du serveur contient des caractères
 err-server-description-too-long = La description est trop longue (max { $max } caractères)
 err-server-description-contains-newlines = La description ne peut pas contenir de sauts de ligne
 err-server-description-invalid-characters = La description contient des caractères invalides
+err-bio-too-long = La bio est trop longue (max { $max } caractères)
+err-bio-contains-newlines = La bio ne peut pas contenir de retours à la ligne
+err-bio-invalid-characters = La bio contient des caractères invalides
+err-pronouns-too-long = Les pronoms sont trop longs (max { $max } caractères)
+err-pronouns-contains-newlines = Les pronoms ne peuvent pas contenir de retours à la ligne
+err-pronouns-invalid-characters = Les pronoms contiennent des caractères invalides
 err-failed-send-update = Échec de l'envoi de la mise à jour : { $error }
 
 # =============================================================================
@@ -240,25 +382,68 @@ err-failed-send-update = Échec de l'envoi de la mise à jour : { $error }
 # =============================================================================
 
 err-failed-save-config = Échec de l'enregistrement de la configuration : { $error }
+err-connection-log-write = Impossible d'écrire le journal de connexion { $path } : { $error }
 err-failed-save-settings = Échec de l'enregistrement des paramètres : { $error }
 err-invalid-port-bookmark = Port invalide dans le signet : { $name }
+err-fallback-address-invalid = Adresse de secours invalide : { $address }
 err-failed-send-broadcast = Échec de l'envoi de la diffusion : { $error }
 err-failed-send-message = Échec de l'envoi du message : { $error }
+err-command-unsupported = Le serveur ne prend pas en charge /{ $command }
 err-failed-create-user = Échec de la création de l'utilisateur : { $error }
 err-failed-delete-user = Échec de la suppression de l'utilisateur : { $error }
 err-failed-update-user = Échec de la mise à jour de l'utilisateur : { $error }
 err-failed-update-topic = Échec de la mise à jour du sujet : { $error }
+err-failed-pin-message = Impossible d'épingler le message : { $error }
+err-failed-unpin-message = Impossible de désépingler le message : { $error }
+err-failed-edit-message = Échec de la modification du message : { $error }
+err-failed-delete-message = Échec de la suppression du message : { $error }
+err-failed-purge-history = Échec de la purge de l'historique du chat : { $error }
+err-failed-announcement = Échec de la mise à jour des annonces : { $error }
+err-failed-stats = Échec de la récupération des statistiques de connexion : { $error }
+err-failed-history = Échec du chargement des messages plus anciens : { $error }
+err-art-read = Impossible de lire { $path } : { $error }
+err-art-file-too-large = Les fichiers d'art font au plus { $max } Kio
+err-art-empty = Le fichier d'art n'a rien à envoyer
+err-art-fence = L'art ne peut pas contenir ``` (cela fermerait le bloc)
+err-art-first-line = Même la première ligne ne tient pas dans la limite de { $max } caractères du serveur
+err-failed-page = Échec de la requête de page : { $error }
+err-failed-set-maintenance = Impossible de changer le mode maintenance : { $error }
+err-failed-send-admin-notice = Impossible d'envoyer l'avis : { $error }
+err-notice-url-invalid = Le lien doit commencer par http:// ou https:// et ne contenir aucun espace
+err-failed-refresh-permissions = Échec de l'actualisation des permissions : { $error }
+err-failed-transfer-admin = Échec du transfert de l'administration : { $error }
+err-failed-sync-preferences = Échec de la synchronisation des préférences : { $error }
+err-failed-accept-rules = Échec de l'acceptation des règles : { $error }
 err-message-too-long-details = { $error } ({ $length } caractères, max { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = Adresse invalide '{ $address }' : { $error }
+err-all-addresses-failed = Aucune des { $count } adresses n'est joignable (dernière erreur : { $error })
 err-could-not-resolve = Impossible de résoudre l'adresse '{ $address }'
 err-connection-timeout = Délai de connexion dépassé après { $seconds } secondes
 err-connection-failed = Échec de la connexion : { $error }
+err-proxy-address-empty = L'adresse du proxy est requise
+err-proxy-address-invalid = L'adresse du proxy doit être un nom d'hôte ou une adresse IP sans espaces
+err-proxy-port-invalid = Le port du proxy doit être compris entre 1 et 65535
+err-quiet-hours-start-invalid = Le début des heures calmes doit être une heure au format 24 h comme 22:00
+err-quiet-hours-end-invalid = La fin des heures calmes doit être une heure au format 24 h comme 08:00
+err-message-format-unclosed-brace = Le format des messages contient un espace réservé non fermé (doublez l'accolade pour l'afficher telle quelle)
+err-message-format-unknown-placeholder = Le format des messages contient un espace réservé inconnu { $placeholder } (disponibles : { $available })
+err-message-format-missing-placeholder = Le format des messages doit contenir { $placeholder }
+err-message-format-duplicate-placeholder = Le format des messages ne peut contenir { $placeholder } qu'une seule fois
+err-invite-not-a-link = Ce n'est pas un lien d'invitation nexus://
+err-invite-invalid-address = Le lien d'invitation contient une adresse de serveur invalide
+err-invite-invalid-port = Le port du lien d'invitation doit être compris entre 1 et 65535
+err-invite-invalid-name = Le lien d'invitation contient un nom de serveur invalide
+err-invite-invalid-fingerprint = Le lien d'invitation contient une empreinte de certificat invalide
+err-invite-credentials = Les liens d'invitation ne doivent pas contenir de nom d'utilisateur ni de mot de passe
+err-invite-fingerprint-mismatch = Le certificat du serveur ne correspond pas au lien d'invitation (attendu : { $expected }, reçu : { $received })
+err-proxy-connection-failed = Impossible de se connecter via le proxy { $proxy } : { $error }
 err-tls-handshake-failed = Échec du handshake TLS : { $error }
 err-failed-send-handshake = Échec de l'envoi du handshake : { $error }
 err-failed-read-handshake = Échec de la lecture de la réponse du handshake : { $error }
 err-handshake-failed = Échec du handshake : { $error }
+err-client-too-old = Ce serveur nécessite un client Nexus plus récent. Veuillez le mettre à jour pour vous connecter. ({ $error })
 err-failed-parse-handshake = Échec de l'analyse de la réponse du handshake : { $error }
 err-failed-send-login = Échec de l'envoi de la connexion : { $error }
 err-failed-read-login = Échec de la lecture de la réponse de connexion : { $error }
@@ -275,6 +460,7 @@ err-failed-set-config-permissions = Échec de la définition des permissions du
 # =============================================================================
 
 fingerprint-warning = Cela pourrait indiquer un problème de sécurité (attaque MITM) ou que le certificat du serveur a été régénéré. N'acceptez que si vous faites confiance à l'administrateur du serveur.
+server-rules-intro = Vous devez accepter ces règles avant de discuter sur ce serveur.
 
 # =============================================================================
 # User Info Display
@@ -284,15 +470,24 @@ user-info-username = Nom d'utilisateur :
 user-info-role = Rôle :
 user-info-role-admin = admin
 user-info-role-user = utilisateur
+user-info-role-guest = invité
+user-info-pronouns = Pronoms :
+user-info-bio = Bio :
 user-info-connected = Connecté :
 user-info-connected-value = il y a { $duration }
 user-info-connected-value-sessions = il y a { $duration } ({ $count } sessions)
 user-info-features = Fonctionnalités :
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (inactive)
 user-info-features-none = Aucune
+user-info-permissions = Permissions :
+user-info-permissions-none = Aucune
 user-info-locale = Langue :
 user-info-address = Adresse :
 user-info-addresses = Adresses :
+user-info-sessions = Sessions :
+user-info-session = Session { $id }
+user-info-this-session = cette session
 user-info-created = Créé :
 user-info-end = Fin des informations utilisateur
 user-info-unknown = Inconnu
@@ -329,18 +524,60 @@ cmd-help-desc = Afficher les commandes disponibles
 cmd-help-escape-hint = Astuce : Utilisez // pour envoyer un message commençant par /
 cmd-message-desc = Envoyer un message à un utilisateur
 cmd-message-usage = Utilisation : /{ $command } <utilisateur> <message>
+cmd-query-desc = Ouvrir un onglet de message avec un utilisateur sans envoyer
+cmd-query-usage = Utilisation : /{ $command } <utilisateur>
+cmd-query-not-online = L'utilisateur n'est pas en ligne : { $username }
+cmd-query-self = Vous ne pouvez pas vous envoyer de message
+cmd-reconnect-desc = Se déconnecter puis se reconnecter à ce serveur
+cmd-reconnect-usage = Utilisation : /{ $command }
+cmd-reconnect-no-bookmark = /{ $command } nécessite un favori pour se reconnecter ; cette connexion n'a pas été établie depuis un favori
 cmd-userinfo-desc = Afficher les informations sur un utilisateur
 cmd-userinfo-usage = Utilisation : /{ $command } <utilisateur>
+cmd-invite-desc = Copier un lien d'invitation vers ce serveur
+cmd-invite-usage = Utilisation : /{ $command }
+cmd-invite-copied = Lien d'invitation copié dans le presse-papiers : { $link }
 cmd-kick-desc = Expulser un utilisateur du serveur
 cmd-kick-usage = Utilisation : /{ $command } <utilisateur>
+cmd-limits-desc = Afficher ou définir les limites de longueur des messages et du sujet
+cmd-limits-usage = Utilisation : /{ $command } [message <caractères>|sujet <caractères>]
+cmd-limits-arg-message = message
+cmd-limits-arg-topic = sujet
+cmd-limits-current = Limites : messages jusqu'à { $message } caractères, sujet jusqu'à { $topic } caractères
 cmd-topic-desc = Afficher ou gérer le sujet du chat
 cmd-topic-usage = Utilisation : /{ $command } [set|clear] [sujet]
 cmd-topic-set-usage = Utilisation : /{ $command } set <sujet>
 cmd-topic-none = Aucun sujet défini
+cmd-announce-desc = Afficher ou gérer les annonces tournantes du serveur
+cmd-announce-usage = Utilisation : /{ $command } [ajouter <message>|retirer <numéro>]
+cmd-announce-arg-add = ajouter
+cmd-announce-arg-remove = retirer
+cmd-announce-list = Annonces (toutes les { $minutes } minutes) :
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = Aucune annonce n'est définie
+cmd-announce-added = Annonce ajoutée
+cmd-announce-removed = Annonce retirée
+cmd-art-desc = Envoyer de l'art ASCII ou ANSI depuis un fichier en bloc à chasse fixe
+cmd-art-usage = Utilisation : /{ $command } [tronquer] <fichier>
+cmd-art-arg-truncate = tronquer
+cmd-art-too-long = L'art fait { $length } caractères, au-delà de la limite de { $max } du serveur. Utilisez /{ $command } { $truncate } <fichier> pour envoyer les lignes qui tiennent
 cmd-broadcast-desc = Envoyer une diffusion à tous les utilisateurs
 cmd-broadcast-usage = Utilisation : /{ $command } <message>
 cmd-clear-desc = Effacer l'historique du chat de l'onglet actuel
 cmd-clear-usage = Utilisation : /{ $command }
+cmd-delete-desc = Supprimer votre dernier message (modérateurs : le dernier message d'un utilisateur)
+cmd-delete-usage = Utilisation : /{ $command } [utilisateur]
+cmd-delete-no-message = Aucun message récent de { $username } à supprimer
+cmd-delete-permission-denied = Vous n'avez pas la permission de supprimer les messages des autres utilisateurs
+cmd-edit-desc = Modifier votre dernier message
+cmd-edit-usage = Utilisation : /{ $command } <message>
+cmd-edit-no-message = Vous n'avez aucun message récent à modifier
+cmd-events-desc = Afficher les événements du cycle de vie de cette connexion
+cmd-events-usage = Utilisation : /{ $command }
+cmd-events-header = Événements de connexion (les plus anciens d'abord) :
+cmd-events-empty = Aucun événement de connexion enregistré pour l'instant
+cmd-report-desc = Copier un instantané épuré du client et de la connexion pour les rapports de bogue
+cmd-report-usage = Utilisation : /{ $command }
+cmd-report-copied = Rapport de bogue copié dans le presse-papiers. Omis : mots de passe, adresse du serveur, noms d'utilisateur, empreinte du certificat, nom du serveur et adresse du proxy
 cmd-focus-desc = Focaliser le chat serveur ou la fenêtre de messages d'un utilisateur
 cmd-focus-usage = Utilisation : /{ $command } [utilisateur]
 cmd-focus-not-found = Utilisateur non trouvé : { $name }
@@ -352,30 +589,98 @@ cmd-list-output = Utilisateurs en ligne : { $users } ({ $count } { $count ->
     [one] utilisateur
    *[other] utilisateurs
 })
+cmd-maintenance-desc = Afficher ou basculer le mode maintenance (lecture seule)
+cmd-maintenance-usage = Utilisation : /{ $command } [activer|désactiver]
+cmd-maintenance-arg-on = activer
+cmd-maintenance-arg-off = désactiver
+cmd-maintenance-on = Le mode maintenance est activé
+cmd-maintenance-off = Le mode maintenance est désactivé
+cmd-notice-desc = Afficher un avis à tout le monde sous forme de bannière
+cmd-notice-usage = Utilisation : /{ $command } reconnect | update <url> | <message>
+cmd-notify-desc = Afficher ou gérer les utilisateurs dont la connexion vous est signalée
+cmd-notify-usage = Utilisation : /{ $command } [ajouter <utilisateur>|retirer <utilisateur>]
+cmd-notify-arg-add = ajouter
+cmd-notify-arg-del = retirer
+cmd-notify-no-bookmark = La liste de suivi est enregistrée dans un favori, /notify ne fonctionne donc que sur les serveurs en favori
+cmd-notify-none = Vous ne suivez personne sur ce serveur
+cmd-notify-list = Suivis : { $usernames }
+cmd-notify-added = Vous serez prévenu quand { $username } se connectera
+cmd-notify-already = { $username } est déjà dans votre liste de suivi
+cmd-notify-removed = { $username } n'est plus suivi
+cmd-notify-not-watched = { $username } n'est pas dans votre liste de suivi
 cmd-list-all-no-permission = Vous avez besoin de la permission user_edit ou user_delete pour lister tous les utilisateurs
 cmd-list-all-output = Utilisateurs : { $users } ({ $count } { $count ->
     [one] utilisateur
    *[other] utilisateurs
 })
+cmd-whoami-desc = Affiche votre nom d'utilisateur, votre rôle, vos permissions et fonctionnalités
+cmd-whoami-usage = Utilisation : /{ $command }
 cmd-help-usage = Utilisation : /{ $command } [commande]
 cmd-topic-arg-set = définir
 cmd-topic-arg-clear = effacer
 cmd-topic-permission-denied = Vous n'avez pas la permission de modifier le sujet
+cmd-page-desc = Lister ou lire les pages d'information du serveur (admins : les définir ou les supprimer)
+cmd-page-usage = Utilisation : /{ $command } [<nom>|définir <nom> <texte>|supprimer <nom>] (\n commence une nouvelle ligne)
+cmd-page-arg-set = définir
+cmd-page-arg-delete = supprimer
+cmd-page-list = Pages : { $pages }
+cmd-page-none = Ce serveur n'a aucune page
+cmd-page-saved = Page enregistrée
+cmd-page-deleted = Page supprimée
+cmd-pin-desc = Lister les messages épinglés ou épingler le dernier message d'un utilisateur
+cmd-pin-usage = Utilisation : /{ $command } [utilisateur]
+cmd-pin-list = Messages épinglés :
+cmd-pin-list-item = { $number }. { $username } : { $message } (épinglé par { $pinned_by })
+cmd-pin-none = Aucun message épinglé
+cmd-pin-no-message = Aucun message récent de { $username } à épingler
+cmd-purge-desc = Purger l'historique du chat hors de la politique de conservation du serveur
+cmd-purge-usage = Utilisation : /{ $command }
+cmd-refresh-desc = Renvoyer les permissions d'un utilisateur depuis la base de données du serveur
+cmd-refresh-usage = Utilisation : /{ $command } <utilisateur>
+cmd-transfer-desc = Rendre un autre compte administrateur et vous retirer, sauf avec garder
+cmd-transfer-usage = Utilisation : /{ $command } <utilisateur> [garder]
+cmd-transfer-arg-keep = garder
+cmd-seen-desc = Afficher quand un utilisateur était en ligne pour la dernière fois
+cmd-seen-usage = Utilisation : /{ $command } <nom d'utilisateur>
+cmd-session-desc = Afficher la session et le trafic de cette connexion
+cmd-session-usage = Utilisation : /{ $command }
+cmd-session-info = Session { $id } connectée à { $address }
+cmd-session-traffic = Trafic depuis la connexion : { $sent } envoyés, { $received } reçus
+cmd-unpin-desc = Retirer un message épinglé
+cmd-unpin-usage = Utilisation : /{ $command } <numéro> (voir /pin pour les numéros)
 cmd-window-desc = Gérer les onglets de chat
-cmd-window-usage = Utilisation : /{ $command } [suivant|précédent|fermer [utilisateur]]
+cmd-window-usage = Utilisation : /{ $command } [suivant|précédent|fermer [utilisateur]|détacher [utilisateur]]
 cmd-window-arg-next = suivant
 cmd-window-arg-prev = précédent
 cmd-window-arg-close = fermer
+cmd-window-arg-popout = détacher
 cmd-window-list = Onglets ouverts : { $tabs } ({ $count } { $count ->
     [one] onglet
    *[other] onglets
 })
 cmd-window-close-server = Impossible de fermer l'onglet serveur
+cmd-window-popout-server = Impossible de détacher l'onglet serveur
 cmd-window-not-found = Onglet non trouvé : { $name }
 cmd-serverinfo-desc = Afficher les informations du serveur
 cmd-serverinfo-usage = Utilisation : /{ $command }
 cmd-serverinfo-header = [serveur]
 cmd-serverinfo-end = Fin des informations du serveur
+cmd-slow-desc = Afficher ou définir le mode lent du chat
+cmd-slow-usage = Utilisation : /{ $command } [secondes]
+cmd-slow-off = Le mode lent est désactivé
+cmd-slow-current = Mode lent : { $seconds } secondes entre les messages
+cmd-stats-desc = Afficher l'historique des connexions du serveur sur la dernière heure
+cmd-stats-usage = Utilisation : /{ $command } [réinitialiser]
+cmd-stats-arg-reset = réinitialiser
+cmd-stats-current = Connexions ouvertes actuellement : { $count }
+cmd-stats-total = { $minutes } dernières minutes : { $connections } connexions, { $refused } refusées, pic de { $peak } ouvertes ({ $peak_per_ip } depuis une adresse)
+cmd-stats-group = { $time } : { $connections } connexions, { $refused } refusées, pic de { $peak }
+cmd-stats-none = Aucun historique de connexions enregistré pour l'instant
+cmd-set-desc = Afficher ou modifier les préférences de chat de ce serveur
+cmd-set-usage = Utilisation : /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = Les messages d'arrivée/départ sont affichés sur ce serveur
+cmd-set-joinleave-off = Les messages d'arrivée/départ sont masqués sur ce serveur
+cmd-set-joinleave-default = Les messages d'arrivée/départ suivent le réglage global sur ce serveur
 
 # =============================================================================
 # About Panel
@@ -385,6 +690,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = Choisir une image
 button-clear-image = Effacer
+button-clear-theme = Effacer
+button-copy-fingerprint = Copier
+button-clear-fingerprint = Effacer
+button-dismiss = Ignorer
+button-reconnect = Se reconnecter
+button-open-link = Ouvrir le lien
 label-server-image = Image du serveur :
 err-server-image-too-large = L'image du serveur est trop grande (maximum 512 Ko)
 err-server-image-invalid-format = Format d'image du serveur invalide (doit être une URI de données avec encodage base64)
@@ -5,14 +5,20 @@
 # =============================================================================
 
 button-cancel = Annulla
+button-continue = Continua
 button-send = Invia
+button-send-as-one-message = Invia come un solo messaggio
 button-delete = Elimina
 button-connect = Connetti
 button-save = Salva
 button-create = Crea
 button-edit = Modifica
+button-edit-profile = Modifica profilo
 button-update = Aggiorna
 button-accept-new-certificate = Accetta Nuovo Certificato
+button-always-accept-certificate = Accetta Sempre
+button-accept-rules = Accetta Regole
+button-disconnect = Disconnetti
 button-close = Chiudi
 button-choose-avatar = Scegli Icona
 button-clear-avatar = Cancella
@@ -22,6 +28,8 @@ button-clear-avatar = Cancella
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = Connetti al server
 title-add-bookmark = Aggiungi segnalibro
 title-edit-server = Modifica server
@@ -34,7 +42,10 @@ title-settings = Impostazioni
 title-bookmarks = Segnalibri
 title-users = Utenti
 title-edit-server-info = Modifica Info Server
+title-edit-profile = Modifica profilo
 title-fingerprint-mismatch = Impronta certificato non corrispondente!
+title-server-rules = Regole del Server
+title-server-banner = Avviso del server
 title-server-info = Info Server
 title-user-info = Info Utente
 title-about = Informazioni
@@ -46,39 +57,99 @@ title-about = Informazioni
 placeholder-username = Nome utente
 placeholder-password = Password
 placeholder-port = Porta
+placeholder-fallback-addresses = Indirizzi di riserva (facoltativo, host:porta separati da virgole)
+placeholder-watched-users = Avvisa quando online (facoltativo, nomi utente separati da virgole)
 placeholder-server-address = Indirizzo del server
 placeholder-server-name = Nome server
 placeholder-username-optional = Nome utente (opzionale)
 placeholder-password-optional = Password (opzionale)
 placeholder-password-keep-current = Password (lascia vuoto per mantenere l'attuale)
 placeholder-message = Scrivi un messaggio...
+placeholder-message-slow-mode = Modalità lenta: attendi { $seconds }s...
+placeholder-message-maintenance = Modalità manutenzione: input disattivato
 placeholder-no-permission = Nessun permesso
 placeholder-broadcast-message = Inserisci messaggio broadcast...
 placeholder-server-description = Descrizione del server
+placeholder-pronouns = es. loro
+placeholder-bio = Una breve riga su di te
+placeholder-global-theme = Usa tema globale
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = Auto-Connessione
+label-trust-certificate-changes = Accetta sempre i cambi di certificato di questo server (meno sicuro)
 label-add-bookmark = Segnalibro
+label-invite-fingerprint = Impronta del certificato dal link di invito: { $fingerprint }
 label-admin = Amministratore
 label-enabled = Abilitato
 label-permissions = Permessi:
 label-expected-fingerprint = Impronta prevista:
 label-received-fingerprint = Impronta ricevuta:
 label-theme = Tema
+label-density = Densità:
+density-comfortable = Comoda
+density-compact = Compatta
+label-show-role-badges = Mostra i badge di amministratore e moderatore nell'elenco utenti
+badge-admin = admin
+badge-moderator = mod
+badge-guest = ospite
+user-list-permissions = Permessi: { $permissions }
+user-list-away = Assente
 label-chat-font-size = Dimensione font:
+label-max-message-tabs = Max schede messaggi:
+label-paste-confirm-lines = Conferma gli incollaggi di almeno queste righe (0 = mai):
+label-collapse-message-lines = Comprimi i messaggi più lunghi di queste righe (0 = mai):
+label-ctrl-enter-sends = Invia con Ctrl+Invio (Invio aggiunge una nuova riga)
+label-message-format = Formato dei messaggi:
+message-format-preset-default = Predefinito
+message-format-preset-compact = Compatto
+message-format-preset-irc = Stile IRC
+message-format-preset-verbose = Dettagliato (con data)
+message-format-preset-custom = Personalizzato
 label-show-connection-notifications = Mostra notifiche di connessione
+label-hide-repeated-topic = Mostra l'argomento alla riconnessione solo se è cambiato
+label-alert-on-disconnect = Fai lampeggiare la finestra quando una connessione cade
+label-alert-on-mention = Fai lampeggiare la finestra quando qualcuno ti menziona
+label-focus-new-messages = Passa ai messaggi privati che arrivano mentre sono inattivo
+label-quiet-hours = Ore di silenzio (non far lampeggiare la finestra)
+label-quiet-hours-from = Dalle
+label-quiet-hours-to = alle
+label-watched-users-ignore-quiet-hours = Avvisa per gli utenti osservati anche nelle ore silenziose
+label-auto-away = Segnalami assente se inattivo
+label-auto-away-minutes = Minuti senza attività
+label-quiet-hours-active = Ore di silenzio
 label-show-timestamps = Mostra timestamp
 label-use-24-hour-time = Usa formato 24 ore
 label-show-seconds = Mostra secondi
+label-timezone = Fuso orario:
+timezone-local = Locale di sistema
+label-privacy = Privacy
+label-trusted-certificates = Certificati attendibili
+label-fingerprint-not-pinned = Nessun certificato fissato
+label-forget-passwords = Non salvare le password (chiedi alla connessione)
+label-sync-preferences = Sincronizza tema e impostazioni di visualizzazione chat con i server
+label-check-for-updates = Cerca un client più recente all'avvio
+label-proxy = Proxy
+label-use-socks5-proxy = Connetti tramite un proxy SOCKS5 (Tor, I2P)
+label-proxy-address = Indirizzo:
+label-proxy-port = Porta:
+label-diagnostics = Diagnostica
+label-connection-log-path = File di log delle connessioni:
+placeholder-connection-log-path = Lascia vuoto per tenere gli eventi solo in memoria
 label-server-name = Nome:
 label-server-description = Descrizione:
+label-pronouns = Pronomi:
+label-bio = Bio:
 label-server-version = Versione:
+label-server-features = Funzionalità:
+label-users-online = Utenti online:
 label-chat-topic = Argomento Chat:
 label-chat-topic-set-by = Argomento Impostato Da:
 label-max-connections-per-ip = Max Connessioni Per IP:
+label-traffic = Traffico:
+server-info-traffic-value = { $sent } inviati, { $received } ricevuti
 label-avatar = Icona:
 label-details = Dettagli tecnici
 label-chat-options = Opzioni chat
@@ -86,6 +157,14 @@ label-appearance = Aspetto
 label-image = Immagine
 label-general = Generale
 label-limits = Limiti
+label-broadcast-target = Invia a:
+broadcast-target-everyone = Tutti
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = Chat
 
 # =============================================================================
 # Permission Display Names
@@ -97,6 +176,8 @@ permission-chat_send = Invia Chat
 permission-chat_receive = Ricevi Chat
 permission-chat_topic = Argomento Chat
 permission-chat_topic_edit = Modifica Argomento Chat
+permission-chat_pin = Fissa nella Chat
+permission-chat_moderate = Modera la Chat
 permission-user_broadcast = Broadcast Utente
 permission-user_create = Crea Utente
 permission-user_delete = Elimina Utente
@@ -115,16 +196,25 @@ tooltip-user-edit = Modifica Utente
 tooltip-server-info = Info Server
 tooltip-about = Informazioni
 tooltip-settings = Impostazioni
+tooltip-quiet-hours-active = Ore di silenzio attive: la finestra non lampeggerà
 tooltip-hide-bookmarks = Nascondi segnalibri
 tooltip-show-bookmarks = Mostra segnalibri
 tooltip-hide-user-list = Nascondi lista utenti
 tooltip-show-user-list = Mostra lista utenti
 tooltip-disconnect = Disconnetti
+tooltip-connection-health-unknown = Qualità della connessione non ancora misurata
+tooltip-connection-health-good = Connessione buona ({ $rtt } ms)
+tooltip-connection-health-fair = Connessione discreta ({ $rtt } ms)
+tooltip-connection-health-poor = Connessione scarsa ({ $rtt } ms)
+tooltip-connection-health-no-response = Nessuna risposta dal server
 tooltip-edit = Modifica
 tooltip-info = Info
 tooltip-message = Messaggio
 tooltip-kick = Espelli
 tooltip-close = Chiudi
+tooltip-pop-out = Apri in una finestra
+tooltip-unpin = Rimuovi
+tooltip-pinned-by = Fissato da { $username }
 tooltip-add-bookmark = Aggiungi Segnalibro
 
 # =============================================================================
@@ -141,6 +231,7 @@ empty-no-users = Nessun utente online
 # =============================================================================
 
 chat-tab-server = #server
+chat-tab-server-count = #server ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -155,18 +246,47 @@ chat-prefix-system = [SIS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [ANNUNCIO]
+chat-prefix-pinned = [FISSATO]
+chat-message-edited = (modificato)
+chat-message-deleted = (eliminato)
+chat-message-removed-by-moderator = (rimosso da un moderatore)
+chat-show-more = Mostra di più
+chat-show-less = Mostra di meno
+chat-message-encrypted = (cifrato)
+chat-message-unreadable = (messaggio cifrato che non è stato possibile decifrare)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = Utente espulso con successo
+msg-session-kicked-success = Sessione disconnessa
+msg-confirm-clear-fingerprint = Cancellare il certificato fissato per { $name }? La prossima connessione si fiderà di qualsiasi certificato presentato dal server.
 msg-broadcast-sent = Broadcast inviato con successo
 msg-user-created = Utente creato con successo
 msg-user-deleted = Utente eliminato con successo
 msg-user-updated = Utente aggiornato con successo
 msg-permissions-updated = I tuoi permessi sono stati aggiornati
 msg-topic-updated = Argomento aggiornato con successo
+msg-message-pinned = Messaggio fissato
+msg-message-unpinned = Messaggio rimosso dai fissati
+msg-chat-history-purged = Cronologia chat ripulita: { $count } messaggi rimossi
+msg-maintenance-enabled = Il server è ora in modalità manutenzione; le modifiche sono disattivate
+msg-maintenance-disabled = La modalità manutenzione è terminata; le modifiche sono di nuovo attive
+msg-new-login = Il tuo account ha appena effettuato l'accesso da { $address } ({ $source })
+msg-seen-online = { $username } è online ora
+msg-seen-ago = { $username } è stato visto l'ultima volta { $duration } fa ({ $time })
+msg-seen-never = { $username } non è ancora stato online
+msg-maintenance-banner = Modalità manutenzione: il server è in sola lettura per ora
+msg-admin-notice-reconnect = Avviso dell'amministratore: riconnettiti al server
+msg-admin-notice-update = Avviso dell'amministratore: un aggiornamento del client è disponibile su { $url }
+msg-update-available = Nexus BBS { $version } è disponibile
+msg-admin-notice = Avviso dell'amministratore: { $message }
+msg-permissions-refreshed = Permessi aggiornati
+msg-admin-transferred = { $username } ora è amministratore
+msg-copied-to-clipboard = Copiato negli appunti
+msg-large-paste-confirm = Questo messaggio contiene { $count } righe incollate. Inviarlo?
 
 
 
@@ -178,9 +298,19 @@ msg-topic-cleared = Argomento cancellato da { $username }
 msg-topic-set = Argomento impostato da { $username }: { $topic }
 msg-server-info-updated = Configurazione del server aggiornata
 msg-topic-display = Argomento: { $topic }
+msg-connected-via-fallback = Connesso tramite l'indirizzo di riserva { $address }
+msg-certificate-change-trusted = Il certificato del server è cambiato ed è stato accettato perché questo segnalibro si fida dei cambi di certificato (nuova impronta { $fingerprint })
 msg-user-connected = { $username } si è connesso
 msg-user-disconnected = { $username } si è disconnesso
+msg-watched-user-online = { $username } è ora online
+msg-watched-users-online = Utenti osservati online: { $usernames }
 msg-disconnected = Disconnesso: { $error }
+msg-enter-password-for = Inserisci la password per { $name }
+connect-step-progress = Passo { $step } di { $total }: { $description }
+connect-step-tcp = Connessione al server...
+connect-step-tls = Protezione della connessione...
+connect-step-handshake = Negoziazione del protocollo...
+connect-step-login = Accesso in corso...
 msg-connection-cancelled = Connessione annullata per certificato non corrispondente
 
 # =============================================================================
@@ -188,7 +318,9 @@ msg-connection-cancelled = Connessione annullata per certificato non corrisponde
 # =============================================================================
 
 err-connection-broken = Errore di connessione
+err-account-disabled-while-connected = Il tuo account è stato disattivato da un amministratore. Contattalo per farlo riattivare prima di riconnetterti.
 err-failed-update-server-info = Impossibile aggiornare le informazioni del server: { $error }
+err-failed-update-profile = Impossibile aggiornare il profilo: { $error }
 err-user-kick-failed = Impossibile espellere l'utente
 err-no-shutdown-handle = Errore di connessione: Nessun handle di chiusura
 err-userlist-failed = Impossibile aggiornare la lista utenti
@@ -206,6 +338,9 @@ err-could-not-determine-config-dir = Impossibile determinare la directory di con
 err-message-too-long = Il messaggio è troppo lungo ({ $length } caratteri, max { $max })
 err-send-failed = Impossibile inviare il messaggio
 err-no-chat-permission = Non hai il permesso di inviare messaggi
+err-private-messages-disabled = I messaggi privati sono disattivati su questo server
+err-popout-command = I comandi possono essere usati solo nella finestra principale
+err-multiline-command = I comandi devono stare su una sola riga (inizia con // per inviarlo come messaggio)
 err-broadcast-too-long = Il broadcast è troppo lungo ({ $length } caratteri, max { $max })
 err-broadcast-send-failed = Impossibile inviare il broadcast
 err-name-required = Il nome del segnalibro è obbligatorio
@@ -217,6 +352,7 @@ err-message-required = Il messaggio è obbligatorio
 
 # Validation errors
 err-message-empty = Il messaggio non può essere vuoto
+err-slow-mode-wait = La modalità lenta è attiva. Attendi { $seconds } secondi
 err-message-contains-newlines = Il messaggio non può contenere interruzioni di riga
 err-message-invalid-characters = Il messaggio contiene caratteri non validi
 err-username-empty = Il nome utente non può essere vuoto
@@ -234,6 +370,12 @@ err-server-name-invalid-characters = Il nome del server contiene caratteri non v
 err-server-description-too-long = La descrizione è troppo lunga (max { $max } caratteri)
 err-server-description-contains-newlines = La descrizione non può contenere interruzioni di riga
 err-server-description-invalid-characters = La descrizione contiene caratteri non validi
+err-bio-too-long = La bio è troppo lunga (max { $max } caratteri)
+err-bio-contains-newlines = La bio non può contenere a capo
+err-bio-invalid-characters = La bio contiene caratteri non validi
+err-pronouns-too-long = I pronomi sono troppo lunghi (max { $max } caratteri)
+err-pronouns-contains-newlines = I pronomi non possono contenere a capo
+err-pronouns-invalid-characters = I pronomi contengono caratteri non validi
 err-failed-send-update = Impossibile inviare l'aggiornamento: { $error }
 
 # =============================================================================
@@ -241,25 +383,68 @@ err-failed-send-update = Impossibile inviare l'aggiornamento: { $error }
 # =============================================================================
 
 err-failed-save-config = Impossibile salvare la configurazione: { $error }
+err-connection-log-write = Impossibile scrivere il registro di connessione { $path }: { $error }
 err-failed-save-settings = Impossibile salvare le impostazioni: { $error }
 err-invalid-port-bookmark = Porta non valida nel segnalibro: { $name }
+err-fallback-address-invalid = Indirizzo di riserva non valido: { $address }
 err-failed-send-broadcast = Impossibile inviare il broadcast: { $error }
 err-failed-send-message = Impossibile inviare il messaggio: { $error }
+err-command-unsupported = Il server non supporta /{ $command }
 err-failed-create-user = Impossibile creare l'utente: { $error }
 err-failed-delete-user = Impossibile eliminare l'utente: { $error }
 err-failed-update-user = Impossibile aggiornare l'utente: { $error }
 err-failed-update-topic = Impossibile aggiornare l'argomento: { $error }
+err-failed-pin-message = Impossibile fissare il messaggio: { $error }
+err-failed-unpin-message = Impossibile rimuovere il messaggio fissato: { $error }
+err-failed-edit-message = Impossibile modificare il messaggio: { $error }
+err-failed-delete-message = Impossibile eliminare il messaggio: { $error }
+err-failed-purge-history = Impossibile ripulire la cronologia chat: { $error }
+err-failed-announcement = Impossibile aggiornare gli annunci: { $error }
+err-failed-stats = Impossibile ottenere le statistiche delle connessioni: { $error }
+err-failed-history = Impossibile caricare i messaggi precedenti: { $error }
+err-art-read = Impossibile leggere { $path }: { $error }
+err-art-file-too-large = I file di arte possono essere al massimo di { $max } KiB
+err-art-empty = Il file di arte non contiene nulla da inviare
+err-art-fence = L'arte non può contenere ``` (chiuderebbe il blocco)
+err-art-first-line = Neanche la prima riga rientra nel limite del server di { $max } caratteri
+err-failed-page = Richiesta della pagina non riuscita: { $error }
+err-failed-set-maintenance = Impossibile cambiare la modalità manutenzione: { $error }
+err-failed-send-admin-notice = Impossibile inviare l'avviso: { $error }
+err-notice-url-invalid = Il link deve iniziare con http:// o https:// e non contenere spazi
+err-failed-refresh-permissions = Impossibile aggiornare i permessi: { $error }
+err-failed-transfer-admin = Impossibile trasferire l'amministrazione: { $error }
+err-failed-sync-preferences = Impossibile sincronizzare le preferenze: { $error }
+err-failed-accept-rules = Impossibile accettare le regole: { $error }
 err-message-too-long-details = { $error } ({ $length } caratteri, max { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = Indirizzo non valido '{ $address }': { $error }
+err-all-addresses-failed = Nessuno dei { $count } indirizzi è raggiungibile (ultimo errore: { $error })
 err-could-not-resolve = Impossibile risolvere l'indirizzo '{ $address }'
 err-connection-timeout = Connessione scaduta dopo { $seconds } secondi
 err-connection-failed = Connessione fallita: { $error }
+err-proxy-address-empty = L'indirizzo del proxy è obbligatorio
+err-proxy-address-invalid = L'indirizzo del proxy deve essere un nome host o un indirizzo IP senza spazi
+err-proxy-port-invalid = La porta del proxy deve essere compresa tra 1 e 65535
+err-quiet-hours-start-invalid = L'inizio delle ore di silenzio deve essere un orario di 24 ore come 22:00
+err-quiet-hours-end-invalid = La fine delle ore di silenzio deve essere un orario di 24 ore come 08:00
+err-message-format-unclosed-brace = Il formato dei messaggi ha un segnaposto non chiuso (raddoppia la graffa per mostrarla letteralmente)
+err-message-format-unknown-placeholder = Il formato dei messaggi ha un segnaposto sconosciuto { $placeholder } (disponibili: { $available })
+err-message-format-missing-placeholder = Il formato dei messaggi deve includere { $placeholder }
+err-message-format-duplicate-placeholder = Il formato dei messaggi può includere { $placeholder } una sola volta
+err-invite-not-a-link = Non è un link di invito nexus://
+err-invite-invalid-address = Il link di invito contiene un indirizzo del server non valido
+err-invite-invalid-port = La porta del link di invito deve essere compresa tra 1 e 65535
+err-invite-invalid-name = Il link di invito contiene un nome del server non valido
+err-invite-invalid-fingerprint = Il link di invito contiene un'impronta del certificato non valida
+err-invite-credentials = I link di invito non devono contenere nome utente o password
+err-invite-fingerprint-mismatch = Il certificato del server non corrisponde al link di invito (atteso { $expected }, ricevuto { $received })
+err-proxy-connection-failed = Impossibile connettersi tramite il proxy { $proxy }: { $error }
 err-tls-handshake-failed = Handshake TLS fallito: { $error }
 err-failed-send-handshake = Impossibile inviare l'handshake: { $error }
 err-failed-read-handshake = Impossibile leggere la risposta dell'handshake: { $error }
 err-handshake-failed = Handshake fallito: { $error }
+err-client-too-old = Questo server richiede un client Nexus più recente. Aggiorna per connetterti. ({ $error })
 err-failed-parse-handshake = Impossibile analizzare la risposta dell'handshake: { $error }
 err-failed-send-login = Impossibile inviare l'accesso: { $error }
 err-failed-read-login = Impossibile leggere la risposta di accesso: { $error }
@@ -276,6 +461,7 @@ err-failed-set-config-permissions = Impossibile impostare i permessi del file di
 # =============================================================================
 
 fingerprint-warning = Questo potrebbe indicare un problema di sicurezza (attacco MITM) o che il certificato del server è stato rigenerato. Accetta solo se ti fidi dell'amministratore del server.
+server-rules-intro = Devi accettare queste regole prima di chattare su questo server.
 
 # =============================================================================
 # User Info Display
@@ -285,15 +471,24 @@ user-info-username = Nome utente:
 user-info-role = Ruolo:
 user-info-role-admin = admin
 user-info-role-user = utente
+user-info-role-guest = ospite
+user-info-pronouns = Pronomi:
+user-info-bio = Bio:
 user-info-connected = Connesso:
 user-info-connected-value = { $duration } fa
 user-info-connected-value-sessions = { $duration } fa ({ $count } sessioni)
 user-info-features = Funzionalità:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (inattiva)
 user-info-features-none = Nessuna
+user-info-permissions = Permessi:
+user-info-permissions-none = Nessuno
 user-info-locale = Lingua:
 user-info-address = Indirizzo:
 user-info-addresses = Indirizzi:
+user-info-sessions = Sessioni:
+user-info-session = Sessione { $id }
+user-info-this-session = questa sessione
 user-info-created = Creato:
 user-info-end = Fine informazioni utente
 user-info-unknown = Sconosciuto
@@ -330,18 +525,60 @@ cmd-help-desc = Mostra i comandi disponibili
 cmd-help-escape-hint = Suggerimento: Usa // per inviare un messaggio che inizia con /
 cmd-message-desc = Invia un messaggio a un utente
 cmd-message-usage = Uso: /{ $command } <utente> <messaggio>
+cmd-query-desc = Apri una scheda messaggi con un utente senza inviare
+cmd-query-usage = Uso: /{ $command } <utente>
+cmd-query-not-online = L'utente non è online: { $username }
+cmd-query-self = Non puoi inviare messaggi a te stesso
+cmd-reconnect-desc = Disconnetti e riconnetti a questo server
+cmd-reconnect-usage = Uso: /{ $command }
+cmd-reconnect-no-bookmark = /{ $command } richiede un segnalibro per riconnettersi; questa connessione non è stata creata da un segnalibro
 cmd-userinfo-desc = Mostra informazioni su un utente
 cmd-userinfo-usage = Uso: /{ $command } <utente>
+cmd-invite-desc = Copia un link di invito per questo server
+cmd-invite-usage = Uso: /{ $command }
+cmd-invite-copied = Link di invito copiato negli appunti: { $link }
 cmd-kick-desc = Espelli un utente dal server
 cmd-kick-usage = Uso: /{ $command } <utente>
+cmd-limits-desc = Mostra o imposta i limiti di lunghezza di messaggi e argomento
+cmd-limits-usage = Uso: /{ $command } [messaggio <caratteri>|argomento <caratteri>]
+cmd-limits-arg-message = messaggio
+cmd-limits-arg-topic = argomento
+cmd-limits-current = Limiti: messaggi fino a { $message } caratteri, argomento fino a { $topic } caratteri
 cmd-topic-desc = Visualizza o gestisci l'argomento della chat
 cmd-topic-usage = Uso: /{ $command } [set|clear] [argomento]
 cmd-topic-set-usage = Uso: /{ $command } set <argomento>
 cmd-topic-none = Nessun argomento impostato
+cmd-announce-desc = Visualizza o gestisci gli annunci a rotazione del server
+cmd-announce-usage = Uso: /{ $command } [aggiungi <messaggio>|rimuovi <numero>]
+cmd-announce-arg-add = aggiungi
+cmd-announce-arg-remove = rimuovi
+cmd-announce-list = Annunci (ogni { $minutes } minuti):
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = Nessun annuncio impostato
+cmd-announce-added = Annuncio aggiunto
+cmd-announce-removed = Annuncio rimosso
+cmd-art-desc = Invia arte ASCII o ANSI da un file come blocco a larghezza fissa
+cmd-art-usage = Uso: /{ $command } [tronca] <file>
+cmd-art-arg-truncate = tronca
+cmd-art-too-long = L'arte è di { $length } caratteri, oltre il limite del server di { $max }. Usa /{ $command } { $truncate } <file> per inviare le righe che rientrano
 cmd-broadcast-desc = Invia un messaggio broadcast a tutti gli utenti
 cmd-broadcast-usage = Uso: /{ $command } <messaggio>
 cmd-clear-desc = Cancella la cronologia chat della scheda corrente
 cmd-clear-usage = Uso: /{ $command }
+cmd-delete-desc = Elimina il tuo ultimo messaggio (moderatori: l'ultimo messaggio di un utente)
+cmd-delete-usage = Uso: /{ $command } [utente]
+cmd-delete-no-message = Nessun messaggio recente di { $username } da eliminare
+cmd-delete-permission-denied = Non hai il permesso di eliminare i messaggi di altri utenti
+cmd-edit-desc = Modifica il tuo ultimo messaggio
+cmd-edit-usage = Uso: /{ $command } <messaggio>
+cmd-edit-no-message = Non hai messaggi recenti da modificare
+cmd-events-desc = Mostra gli eventi del ciclo di vita di questa connessione
+cmd-events-usage = Uso: /{ $command }
+cmd-events-header = Eventi di connessione (dal più vecchio):
+cmd-events-empty = Nessun evento di connessione registrato
+cmd-report-desc = Copia un'istantanea ripulita del client e della connessione per le segnalazioni di bug
+cmd-report-usage = Uso: /{ $command }
+cmd-report-copied = Segnalazione copiata negli appunti. Esclusi: password, indirizzo del server, nomi utente, impronta del certificato, nome del server e indirizzo del proxy
 cmd-focus-desc = Focalizza la chat del server o la finestra messaggi di un utente
 cmd-focus-usage = Uso: /{ $command } [utente]
 cmd-focus-not-found = Utente non trovato: { $name }
@@ -353,30 +590,98 @@ cmd-list-output = Utenti online: { $users } ({ $count } { $count ->
     [one] utente
    *[other] utenti
 })
+cmd-maintenance-desc = Visualizza o attiva/disattiva la modalità manutenzione (sola lettura)
+cmd-maintenance-usage = Uso: /{ $command } [attiva|disattiva]
+cmd-maintenance-arg-on = attiva
+cmd-maintenance-arg-off = disattiva
+cmd-maintenance-on = La modalità manutenzione è attiva
+cmd-maintenance-off = La modalità manutenzione è disattivata
+cmd-notice-desc = Mostra un avviso a tutti come banner
+cmd-notice-usage = Uso: /{ $command } reconnect | update <url> | <messaggio>
+cmd-notify-desc = Mostra o gestisci gli utenti di cui ricevi un avviso quando si connettono
+cmd-notify-usage = Uso: /{ $command } [aggiungi <utente>|rimuovi <utente>]
+cmd-notify-arg-add = aggiungi
+cmd-notify-arg-del = rimuovi
+cmd-notify-no-bookmark = La lista di osservazione è salvata in un segnalibro, quindi /notify funziona solo sui server nei segnalibri
+cmd-notify-none = Non stai osservando nessuno su questo server
+cmd-notify-list = Osservati: { $usernames }
+cmd-notify-added = Riceverai un avviso quando { $username } sarà online
+cmd-notify-already = { $username } è già nella tua lista di osservazione
+cmd-notify-removed = Non stai più osservando { $username }
+cmd-notify-not-watched = { $username } non è nella tua lista di osservazione
 cmd-list-all-no-permission = Hai bisogno del permesso user_edit o user_delete per elencare tutti gli utenti
 cmd-list-all-output = Utenti: { $users } ({ $count } { $count ->
     [one] utente
    *[other] utenti
 })
+cmd-whoami-desc = Mostra il tuo nome utente, ruolo, permessi e funzionalità
+cmd-whoami-usage = Uso: /{ $command }
 cmd-help-usage = Uso: /{ $command } [comando]
 cmd-topic-arg-set = imposta
 cmd-topic-arg-clear = cancella
 cmd-topic-permission-denied = Non hai il permesso di modificare l'argomento
+cmd-page-desc = Elenca o leggi le pagine informative del server (admin: impostarle o eliminarle)
+cmd-page-usage = Uso: /{ $command } [<nome>|imposta <nome> <testo>|elimina <nome>] (\n inizia una nuova riga)
+cmd-page-arg-set = imposta
+cmd-page-arg-delete = elimina
+cmd-page-list = Pagine: { $pages }
+cmd-page-none = Questo server non ha pagine
+cmd-page-saved = Pagina salvata
+cmd-page-deleted = Pagina eliminata
+cmd-pin-desc = Elenca i messaggi fissati o fissa l'ultimo messaggio di un utente
+cmd-pin-usage = Uso: /{ $command } [utente]
+cmd-pin-list = Messaggi fissati:
+cmd-pin-list-item = { $number }. { $username }: { $message } (fissato da { $pinned_by })
+cmd-pin-none = Nessun messaggio fissato
+cmd-pin-no-message = Nessun messaggio recente di { $username } da fissare
+cmd-purge-desc = Ripulisci la cronologia chat oltre la politica di conservazione del server
+cmd-purge-usage = Uso: /{ $command }
+cmd-refresh-desc = Reinvia i permessi di un utente dal database del server
+cmd-refresh-usage = Uso: /{ $command } <utente>
+cmd-transfer-desc = Rendi amministratore un altro account e lascia il ruolo, a meno che non aggiungi mantieni
+cmd-transfer-usage = Uso: /{ $command } <utente> [mantieni]
+cmd-transfer-arg-keep = mantieni
+cmd-seen-desc = Mostra quando un utente è stato online l'ultima volta
+cmd-seen-usage = Uso: /{ $command } <nome utente>
+cmd-session-desc = Mostra la sessione e il traffico di questa connessione
+cmd-session-usage = Uso: /{ $command }
+cmd-session-info = Sessione { $id } connessa a { $address }
+cmd-session-traffic = Traffico dalla connessione: { $sent } inviati, { $received } ricevuti
+cmd-unpin-desc = Rimuovi un messaggio fissato
+cmd-unpin-usage = Uso: /{ $command } <numero> (vedi /pin per i numeri)
 cmd-window-desc = Gestisci le schede chat
-cmd-window-usage = Uso: /{ $command } [prossimo|precedente|chiudi [utente]]
+cmd-window-usage = Uso: /{ $command } [prossimo|precedente|chiudi [utente]|stacca [utente]]
 cmd-window-arg-next = prossimo
 cmd-window-arg-prev = precedente
 cmd-window-arg-close = chiudi
+cmd-window-arg-popout = stacca
 cmd-window-list = Schede aperte: { $tabs } ({ $count } { $count ->
     [one] scheda
    *[other] schede
 })
 cmd-window-close-server = Impossibile chiudere la scheda server
+cmd-window-popout-server = Impossibile staccare la scheda server
 cmd-window-not-found = Scheda non trovata: { $name }
 cmd-serverinfo-desc = Mostra informazioni del server
 cmd-serverinfo-usage = Uso: /{ $command }
 cmd-serverinfo-header = [server]
 cmd-serverinfo-end = Fine informazioni server
+cmd-slow-desc = Visualizza o imposta la modalità lenta della chat
+cmd-slow-usage = Uso: /{ $command } [secondi]
+cmd-slow-off = La modalità lenta è disattivata
+cmd-slow-current = Modalità lenta: { $seconds } secondi tra i messaggi
+cmd-stats-desc = Mostra la cronologia delle connessioni del server dell'ultima ora
+cmd-stats-usage = Uso: /{ $command } [azzera]
+cmd-stats-arg-reset = azzera
+cmd-stats-current = Connessioni aperte ora: { $count }
+cmd-stats-total = Ultimi { $minutes } minuti: { $connections } connessioni, { $refused } rifiutate, picco di { $peak } aperte ({ $peak_per_ip } da un indirizzo)
+cmd-stats-group = { $time }: { $connections } connessioni, { $refused } rifiutate, picco { $peak }
+cmd-stats-none = Nessuna cronologia delle connessioni registrata
+cmd-set-desc = Visualizza o modifica le preferenze di chat per questo server
+cmd-set-usage = Uso: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = I messaggi di entrata/uscita sono mostrati su questo server
+cmd-set-joinleave-off = I messaggi di entrata/uscita sono nascosti su questo server
+cmd-set-joinleave-default = I messaggi di entrata/uscita seguono l'impostazione globale su questo server
 
 # =============================================================================
 # About Panel
@@ -386,6 +691,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = Scegli immagine
 button-clear-image = Cancella
+button-clear-theme = Cancella
+button-copy-fingerprint = Copia
+button-clear-fingerprint = Cancella
+button-dismiss = Ignora
+button-reconnect = Riconnetti
+button-open-link = Apri link
 label-server-image = Immagine del server:
 err-server-image-too-large = L'immagine del server è troppo grande (massimo 512KB)
 err-server-image-invalid-format = Formato immagine del server non valido (deve essere un URI di dati con codifica base64)
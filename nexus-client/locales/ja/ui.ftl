@@ -5,14 +5,20 @@
 # =============================================================================
 
 button-cancel = キャンセル
+button-continue = 続行
 button-send = 送信
+button-send-as-one-message = 1 件のメッセージとして送信
 button-delete = 削除
 button-connect = 接続
 button-save = 保存
 button-create = 作成
 button-edit = 編集
+button-edit-profile = プロフィールを編集
 button-update = 更新
 button-accept-new-certificate = 新しい証明書を受け入れる
+button-always-accept-certificate = 常に受け入れる
+button-accept-rules = ルールに同意する
+button-disconnect = 切断
 button-close = 閉じる
 button-choose-avatar = アバターを選択
 button-clear-avatar = クリア
@@ -22,6 +28,8 @@ button-clear-avatar = クリア
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = サーバーに接続
 title-add-bookmark = ブックマークを追加
 title-edit-server = サーバーを編集
@@ -34,7 +42,10 @@ title-settings = 設定
 title-bookmarks = ブックマーク
 title-users = ユーザー
 title-edit-server-info = サーバー情報を編集
+title-edit-profile = プロフィールを編集
 title-fingerprint-mismatch = 証明書のフィンガープリントが一致しません！
+title-server-rules = サーバーのルール
+title-server-banner = サーバーからのお知らせ
 title-server-info = サーバー情報
 title-user-info = ユーザー情報
 title-about = アプリについて
@@ -46,39 +57,99 @@ title-about = アプリについて
 placeholder-username = ユーザー名
 placeholder-password = パスワード
 placeholder-port = ポート
+placeholder-fallback-addresses = 予備アドレス（任意、host:port をカンマ区切り）
+placeholder-watched-users = オンライン時に通知（任意、ユーザー名をカンマ区切り）
 placeholder-server-address = サーバーアドレス
 placeholder-server-name = サーバー名
 placeholder-username-optional = ユーザー名（任意）
 placeholder-password-optional = パスワード（任意）
 placeholder-password-keep-current = パスワード（現在のまま維持する場合は空白）
 placeholder-message = メッセージを入力...
+placeholder-message-slow-mode = スローモード: { $seconds }秒お待ちください...
+placeholder-message-maintenance = メンテナンスモード: 入力は無効です
 placeholder-no-permission = 権限がありません
 placeholder-broadcast-message = ブロードキャストメッセージを入力...
 placeholder-server-description = サーバーの説明
+placeholder-pronouns = 例: they/them
+placeholder-bio = 自分についての短い一文
+placeholder-global-theme = グローバルテーマを使用
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = 自動接続
+label-trust-certificate-changes = このサーバーの証明書の変更を常に受け入れる (安全性が低下します)
 label-add-bookmark = ブックマークに追加
+label-invite-fingerprint = 招待リンクの証明書フィンガープリント: { $fingerprint }
 label-admin = 管理者
 label-enabled = 有効
 label-permissions = 権限:
 label-expected-fingerprint = 期待されるフィンガープリント:
 label-received-fingerprint = 受信したフィンガープリント:
 label-theme = テーマ
+label-density = 表示密度:
+density-comfortable = 標準
+density-compact = コンパクト
+label-show-role-badges = ユーザーリストに管理者とモデレーターのバッジを表示
+badge-admin = 管理者
+badge-moderator = モデレーター
+badge-guest = ゲスト
+user-list-permissions = 権限: { $permissions }
+user-list-away = 離席中
 label-chat-font-size = フォントサイズ:
+label-max-message-tabs = メッセージタブの上限:
+label-paste-confirm-lines = この行数以上の貼り付けを確認 (0 = 確認しない):
+label-collapse-message-lines = この行数を超えるメッセージを折りたたむ (0 = 折りたたまない):
+label-ctrl-enter-sends = Ctrl+Enter で送信（Enter で改行）
+label-message-format = メッセージの書式:
+message-format-preset-default = デフォルト
+message-format-preset-compact = コンパクト
+message-format-preset-irc = IRC 風
+message-format-preset-verbose = 詳細 (日付付き)
+message-format-preset-custom = カスタム
 label-show-connection-notifications = 接続通知を表示
+label-hide-repeated-topic = 再接続時はトピックが変わった場合のみ表示
+label-alert-on-disconnect = 接続が切れたときにウィンドウを点滅させる
+label-alert-on-mention = メンションされたときにウィンドウを点滅させる
+label-focus-new-messages = 操作していないときに届いたプライベートメッセージに切り替える
+label-quiet-hours = おやすみ時間（ウィンドウを点滅させない）
+label-quiet-hours-from = 開始
+label-quiet-hours-to = 終了
+label-watched-users-ignore-quiet-hours = 通知停止時間中もウォッチ中のユーザーを通知する
+label-auto-away = 操作がないときは離席中にする
+label-auto-away-minutes = 無操作の時間（分）
+label-quiet-hours-active = おやすみ時間
 label-show-timestamps = タイムスタンプを表示
 label-use-24-hour-time = 24時間形式を使用
 label-show-seconds = 秒を表示
+label-timezone = タイムゾーン:
+timezone-local = システムのローカル
+label-privacy = プライバシー
+label-trusted-certificates = 信頼済み証明書
+label-fingerprint-not-pinned = 証明書はまだ固定されていません
+label-forget-passwords = パスワードを保存しない（接続時に確認）
+label-sync-preferences = テーマとチャット表示設定をサーバーと同期
+label-check-for-updates = 起動時に新しいクライアントを確認
+label-proxy = プロキシ
+label-use-socks5-proxy = SOCKS5プロキシ経由で接続 (Tor、I2P)
+label-proxy-address = アドレス:
+label-proxy-port = ポート:
+label-diagnostics = 診断
+label-connection-log-path = 接続ログファイル:
+placeholder-connection-log-path = 空欄の場合、イベントはメモリにのみ保持されます
 label-server-name = 名前:
 label-server-description = 説明:
+label-pronouns = 代名詞:
+label-bio = 自己紹介:
 label-server-version = バージョン:
+label-server-features = 機能:
+label-users-online = オンラインユーザー:
 label-chat-topic = チャットトピック:
 label-chat-topic-set-by = トピック設定者:
 label-max-connections-per-ip = IP毎の最大接続数:
+label-traffic = 通信量:
+server-info-traffic-value = 送信 { $sent }、受信 { $received }
 label-avatar = アバター:
 label-details = 技術詳細
 label-chat-options = チャットオプション
@@ -86,6 +157,14 @@ label-appearance = 外観
 label-image = 画像
 label-general = 一般
 label-limits = 制限
+label-broadcast-target = 送信先:
+broadcast-target-everyone = 全員
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = チャット
 
 # =============================================================================
 # Permission Display Names
@@ -97,6 +176,8 @@ permission-chat_send = チャット送信
 permission-chat_receive = チャット受信
 permission-chat_topic = チャットトピック
 permission-chat_topic_edit = チャットトピック編集
+permission-chat_pin = チャットのピン留め
+permission-chat_moderate = チャットのモデレート
 permission-user_broadcast = ユーザーブロードキャスト
 permission-user_create = ユーザー作成
 permission-user_delete = ユーザー削除
@@ -115,16 +196,25 @@ tooltip-user-edit = ユーザー編集
 tooltip-server-info = サーバー情報
 tooltip-about = アプリについて
 tooltip-settings = 設定
+tooltip-quiet-hours-active = おやすみ時間中のため、ウィンドウは点滅しません
 tooltip-hide-bookmarks = ブックマークを隠す
 tooltip-show-bookmarks = ブックマークを表示
 tooltip-hide-user-list = ユーザーリストを隠す
 tooltip-show-user-list = ユーザーリストを表示
 tooltip-disconnect = 切断
+tooltip-connection-health-unknown = 接続品質はまだ測定されていません
+tooltip-connection-health-good = 接続良好 ({ $rtt } ms)
+tooltip-connection-health-fair = 接続やや不安定 ({ $rtt } ms)
+tooltip-connection-health-poor = 接続不良 ({ $rtt } ms)
+tooltip-connection-health-no-response = サーバーから応答がありません
 tooltip-edit = 編集
 tooltip-info = 情報
 tooltip-message = メッセージ
 tooltip-kick = キック
 tooltip-close = 閉じる
+tooltip-pop-out = ウィンドウで開く
+tooltip-unpin = ピン留めを解除
+tooltip-pinned-by = { $username } がピン留め
 tooltip-add-bookmark = ブックマークを追加
 
 # =============================================================================
@@ -141,6 +231,7 @@ empty-no-users = オンラインユーザーなし
 # =============================================================================
 
 chat-tab-server = #サーバー
+chat-tab-server-count = #サーバー ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -155,18 +246,47 @@ chat-prefix-system = [システム]
 chat-prefix-error = [エラー]
 chat-prefix-info = [情報]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [お知らせ]
+chat-prefix-pinned = [ピン]
+chat-message-edited = (編集済み)
+chat-message-deleted = (削除済み)
+chat-message-removed-by-moderator = (モデレーターにより削除)
+chat-show-more = もっと見る
+chat-show-less = 折りたたむ
+chat-message-encrypted = (暗号化済み)
+chat-message-unreadable = (復号できなかった暗号化メッセージ)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = ユーザーを正常にキックしました
+msg-session-kicked-success = セッションを切断しました
+msg-confirm-clear-fingerprint = { $name } の固定された証明書を消去しますか？次回の接続ではサーバーが提示する証明書をそのまま信頼します。
 msg-broadcast-sent = ブロードキャストを正常に送信しました
 msg-user-created = ユーザーを正常に作成しました
 msg-user-deleted = ユーザーを正常に削除しました
 msg-user-updated = ユーザーを正常に更新しました
 msg-permissions-updated = 権限が更新されました
 msg-topic-updated = トピックが正常に更新されました
+msg-message-pinned = メッセージをピン留めしました
+msg-message-unpinned = メッセージのピン留めを解除しました
+msg-chat-history-purged = チャット履歴を削除しました: { $count } 件のメッセージ
+msg-maintenance-enabled = サーバーはメンテナンスモードになりました。変更は無効です
+msg-maintenance-disabled = メンテナンスモードが終了しました。変更が再び可能です
+msg-new-login = あなたのアカウントが { $address }（{ $source }）からログインしました
+msg-seen-online = { $username } は現在オンラインです
+msg-seen-ago = { $username } の最終オンライン: { $duration } 前 ({ $time })
+msg-seen-never = { $username } はまだオンラインになったことがありません
+msg-maintenance-banner = メンテナンスモード: サーバーは現在読み取り専用です
+msg-admin-notice-reconnect = 管理者からのお知らせ: サーバーに再接続してください
+msg-admin-notice-update = 管理者からのお知らせ: クライアントの更新があります { $url }
+msg-update-available = Nexus BBS { $version } が利用可能です
+msg-admin-notice = 管理者からのお知らせ: { $message }
+msg-permissions-refreshed = 権限を更新しました
+msg-admin-transferred = { $username } が管理者になりました
+msg-copied-to-clipboard = クリップボードにコピーしました
+msg-large-paste-confirm = このメッセージには貼り付けた { $count } 行が含まれています。送信しますか?
 
 # =============================================================================
 # Dynamic Messages (with parameters)
@@ -176,9 +296,19 @@ msg-topic-cleared = { $username } がトピックをクリアしました
 msg-topic-set = { $username } がトピックを設定しました: { $topic }
 msg-server-info-updated = サーバー設定が更新されました
 msg-topic-display = トピック: { $topic }
+msg-connected-via-fallback = 予備アドレス { $address } で接続しました
+msg-certificate-change-trusted = このブックマークは証明書の変更を信頼するため、変更されたサーバー証明書を受け入れました (新しいフィンガープリント { $fingerprint })
 msg-user-connected = { $username } が接続しました
 msg-user-disconnected = { $username } が切断しました
+msg-watched-user-online = { $username } がオンラインになりました
+msg-watched-users-online = オンラインのウォッチ中ユーザー: { $usernames }
 msg-disconnected = 切断されました: { $error }
+msg-enter-password-for = { $name } のパスワードを入力してください
+connect-step-progress = ステップ { $step }/{ $total }: { $description }
+connect-step-tcp = サーバーに接続中...
+connect-step-tls = 接続を保護中...
+connect-step-handshake = プロトコルをネゴシエート中...
+connect-step-login = ログイン中...
 msg-connection-cancelled = 証明書の不一致のため接続がキャンセルされました
 
 # =============================================================================
@@ -186,7 +316,9 @@ msg-connection-cancelled = 証明書の不一致のため接続がキャンセ
 # =============================================================================
 
 err-connection-broken = 接続エラー
+err-account-disabled-while-connected = アカウントが管理者によって無効化されました。再接続する前に、管理者に再有効化を依頼してください。
 err-failed-update-server-info = サーバー情報の更新に失敗しました: { $error }
+err-failed-update-profile = プロフィールの更新に失敗しました: { $error }
 err-user-kick-failed = ユーザーのキックに失敗しました
 err-no-shutdown-handle = 接続エラー: シャットダウンハンドルがありません
 err-userlist-failed = ユーザーリストの更新に失敗しました
@@ -204,6 +336,9 @@ err-could-not-determine-config-dir = 設定ディレクトリを特定できま
 err-message-too-long = メッセージが長すぎます（{ $length }文字、最大{ $max }文字）
 err-send-failed = メッセージの送信に失敗しました
 err-no-chat-permission = メッセージを送信する権限がありません
+err-private-messages-disabled = このサーバーではプライベートメッセージが無効になっています
+err-popout-command = コマンドはメインウィンドウでのみ使用できます
+err-multiline-command = コマンドは1行で入力してください（メッセージとして送信するには // で始めてください）
 err-broadcast-too-long = ブロードキャストが長すぎます（{ $length }文字、最大{ $max }文字）
 err-broadcast-send-failed = ブロードキャストの送信に失敗しました
 err-name-required = ブックマーク名は必須です
@@ -215,6 +350,7 @@ err-message-required = メッセージは必須です
 
 # Validation errors
 err-message-empty = メッセージは空にできません
+err-slow-mode-wait = スローモードが有効です。{ $seconds }秒お待ちください
 err-message-contains-newlines = メッセージに改行を含めることはできません
 err-message-invalid-characters = メッセージに無効な文字が含まれています
 err-username-empty = ユーザー名は空にできません
@@ -232,6 +368,12 @@ err-server-name-invalid-characters = サーバー名に無効な文字が含ま
 err-server-description-too-long = 説明が長すぎます（最大{ $max }文字）
 err-server-description-contains-newlines = 説明に改行を含めることはできません
 err-server-description-invalid-characters = 説明に無効な文字が含まれています
+err-bio-too-long = 自己紹介が長すぎます（最大 { $max } 文字）
+err-bio-contains-newlines = 自己紹介に改行を含めることはできません
+err-bio-invalid-characters = 自己紹介に無効な文字が含まれています
+err-pronouns-too-long = 代名詞が長すぎます（最大 { $max } 文字）
+err-pronouns-contains-newlines = 代名詞に改行を含めることはできません
+err-pronouns-invalid-characters = 代名詞に無効な文字が含まれています
 err-failed-send-update = 更新の送信に失敗しました: { $error }
 
 # =============================================================================
@@ -239,25 +381,68 @@ err-failed-send-update = 更新の送信に失敗しました: { $error }
 # =============================================================================
 
 err-failed-save-config = 設定の保存に失敗しました: { $error }
+err-connection-log-write = 接続ログ { $path } を書き込めませんでした: { $error }
 err-failed-save-settings = 設定の保存に失敗しました: { $error }
 err-invalid-port-bookmark = ブックマークのポートが無効です: { $name }
+err-fallback-address-invalid = 無効な予備アドレス: { $address }
 err-failed-send-broadcast = ブロードキャストの送信に失敗しました: { $error }
 err-failed-send-message = メッセージの送信に失敗しました: { $error }
+err-command-unsupported = サーバーは /{ $command } に対応していません
 err-failed-create-user = ユーザーの作成に失敗しました: { $error }
 err-failed-delete-user = ユーザーの削除に失敗しました: { $error }
 err-failed-update-user = ユーザーの更新に失敗しました: { $error }
 err-failed-update-topic = トピックの更新に失敗しました: { $error }
+err-failed-pin-message = メッセージのピン留めに失敗しました: { $error }
+err-failed-unpin-message = メッセージのピン留め解除に失敗しました: { $error }
+err-failed-edit-message = メッセージの編集に失敗しました: { $error }
+err-failed-delete-message = メッセージの削除に失敗しました: { $error }
+err-failed-purge-history = チャット履歴の削除に失敗しました: { $error }
+err-failed-announcement = お知らせの更新に失敗しました: { $error }
+err-failed-stats = 接続統計の取得に失敗しました: { $error }
+err-failed-history = 以前のメッセージを読み込めませんでした: { $error }
+err-art-read = { $path } を読み込めませんでした: { $error }
+err-art-file-too-large = アートファイルは最大{ $max } KiBです
+err-art-empty = アートファイルに送信する内容がありません
+err-art-fence = アートに ``` は含められません(ブロックが終わってしまいます)
+err-art-first-line = 最初の行さえこのサーバーの上限{ $max }文字に収まりません
+err-failed-page = ページの要求に失敗しました: { $error }
+err-failed-set-maintenance = メンテナンスモードを変更できませんでした: { $error }
+err-failed-send-admin-notice = お知らせを送信できませんでした: { $error }
+err-notice-url-invalid = リンクは http:// または https:// で始まり、空白を含まない必要があります
+err-failed-refresh-permissions = 権限の更新に失敗しました: { $error }
+err-failed-transfer-admin = 管理者権限の譲渡に失敗しました: { $error }
+err-failed-sync-preferences = 設定の同期に失敗しました: { $error }
+err-failed-accept-rules = ルールの同意に失敗しました: { $error }
 err-message-too-long-details = { $error }（{ $length }文字、最大{ $max }）
 
 # Network connection errors (with parameters)
 err-invalid-address = 無効なアドレス '{ $address }': { $error }
+err-all-addresses-failed = { $count } 件のアドレスのいずれにも接続できませんでした（最後のエラー: { $error }）
 err-could-not-resolve = アドレス '{ $address }' を解決できませんでした
 err-connection-timeout = { $seconds }秒後に接続がタイムアウトしました
 err-connection-failed = 接続に失敗しました: { $error }
+err-proxy-address-empty = プロキシアドレスは必須です
+err-proxy-address-invalid = プロキシアドレスは空白を含まないホスト名またはIPアドレスである必要があります
+err-proxy-port-invalid = プロキシポートは1から65535の間である必要があります
+err-quiet-hours-start-invalid = おやすみ時間の開始は 22:00 のような24時間表記で入力してください
+err-quiet-hours-end-invalid = おやすみ時間の終了は 08:00 のような24時間表記で入力してください
+err-message-format-unclosed-brace = メッセージの書式に閉じられていないプレースホルダーがあります（括弧をそのまま表示するには二重にしてください）
+err-message-format-unknown-placeholder = メッセージの書式に不明なプレースホルダー { $placeholder } があります（使用可能: { $available }）
+err-message-format-missing-placeholder = メッセージの書式には { $placeholder } が必要です
+err-message-format-duplicate-placeholder = メッセージの書式に { $placeholder } は一度しか使えません
+err-invite-not-a-link = nexus:// 招待リンクではありません
+err-invite-invalid-address = 招待リンクのサーバーアドレスが無効です
+err-invite-invalid-port = 招待リンクのポートは1から65535の間である必要があります
+err-invite-invalid-name = 招待リンクのサーバー名が無効です
+err-invite-invalid-fingerprint = 招待リンクの証明書フィンガープリントが無効です
+err-invite-credentials = 招待リンクにユーザー名やパスワードを含めることはできません
+err-invite-fingerprint-mismatch = サーバー証明書が招待リンクと一致しません（期待値 { $expected }、受信値 { $received }）
+err-proxy-connection-failed = プロキシ { $proxy } 経由で接続できませんでした: { $error }
 err-tls-handshake-failed = TLSハンドシェイクに失敗しました: { $error }
 err-failed-send-handshake = ハンドシェイクの送信に失敗しました: { $error }
 err-failed-read-handshake = ハンドシェイク応答の読み取りに失敗しました: { $error }
 err-handshake-failed = ハンドシェイクに失敗しました: { $error }
+err-client-too-old = このサーバーには新しいNexusクライアントが必要です。接続するには更新してください。({ $error })
 err-failed-parse-handshake = ハンドシェイク応答の解析に失敗しました: { $error }
 err-failed-send-login = ログインの送信に失敗しました: { $error }
 err-failed-read-login = ログイン応答の読み取りに失敗しました: { $error }
@@ -274,6 +459,7 @@ err-failed-set-config-permissions = 設定ファイルの権限の設定に失
 # =============================================================================
 
 fingerprint-warning = これはセキュリティ上の問題（MITM攻撃）またはサーバーの証明書が再生成されたことを示している可能性があります。サーバー管理者を信頼している場合のみ受け入れてください。
+server-rules-intro = このサーバーでチャットする前に、これらのルールに同意する必要があります。
 
 # =============================================================================
 # User Info Display
@@ -283,15 +469,24 @@ user-info-username = ユーザー名:
 user-info-role = 役割:
 user-info-role-admin = 管理者
 user-info-role-user = ユーザー
+user-info-role-guest = ゲスト
+user-info-pronouns = 代名詞:
+user-info-bio = 自己紹介:
 user-info-connected = 接続:
 user-info-connected-value = { $duration }前
 user-info-connected-value-sessions = { $duration }前（{ $count }セッション）
 user-info-features = 機能:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature }（無効）
 user-info-features-none = なし
+user-info-permissions = 権限:
+user-info-permissions-none = なし
 user-info-locale = ロケール:
 user-info-address = アドレス:
 user-info-addresses = アドレス:
+user-info-sessions = セッション:
+user-info-session = セッション { $id }
+user-info-this-session = このセッション
 user-info-created = 作成日:
 user-info-end = ユーザー情報終了
 user-info-unknown = 不明
@@ -316,20 +511,62 @@ cmd-help-desc = 利用可能なコマンドを表示
 cmd-help-escape-hint = ヒント: / で始まるメッセージを送信するには // を使用してください
 cmd-message-desc = ユーザーにメッセージを送信
 cmd-message-usage = 使用方法: /{ $command } <ユーザー名> <メッセージ>
+cmd-query-desc = 送信せずにユーザーとのメッセージタブを開く
+cmd-query-usage = 使用方法: /{ $command } <ユーザー名>
+cmd-query-not-online = ユーザーはオンラインではありません: { $username }
+cmd-query-self = 自分自身にメッセージを送ることはできません
+cmd-reconnect-desc = このサーバーから切断して再接続
+cmd-reconnect-usage = 使い方: /{ $command }
+cmd-reconnect-no-bookmark = /{ $command } で再接続するにはブックマークが必要です。この接続はブックマークから作成されていません
 cmd-userinfo-desc = ユーザーの情報を表示
 cmd-userinfo-usage = 使用方法: /{ $command } <ユーザー名>
+cmd-invite-desc = このサーバーの招待リンクをコピー
+cmd-invite-usage = 使用方法: /{ $command }
+cmd-invite-copied = 招待リンクをクリップボードにコピーしました: { $link }
 cmd-kick-desc = ユーザーをサーバーからキック
 cmd-kick-usage = 使用方法: /{ $command } <ユーザー名>
+cmd-limits-desc = メッセージとトピックの長さ制限を表示・設定
+cmd-limits-usage = 使用方法: /{ $command } [メッセージ <文字数>|トピック <文字数>]
+cmd-limits-arg-message = メッセージ
+cmd-limits-arg-topic = トピック
+cmd-limits-current = 制限: メッセージは最大 { $message } 文字、トピックは最大 { $topic } 文字
 cmd-topic-desc = チャットトピックを表示または管理
 cmd-topic-usage = 使用方法: /{ $command } [設定|クリア] [トピック]
 cmd-topic-arg-set = 設定
 cmd-topic-arg-clear = クリア
 cmd-topic-set-usage = 使用方法: /{ $command } 設定 <トピック>
 cmd-topic-none = トピックが設定されていません
+cmd-announce-desc = サーバーの定期お知らせを表示または管理
+cmd-announce-usage = 使用方法: /{ $command } [追加 <メッセージ>|削除 <番号>]
+cmd-announce-arg-add = 追加
+cmd-announce-arg-remove = 削除
+cmd-announce-list = お知らせ（{ $minutes } 分ごと）:
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = お知らせは設定されていません
+cmd-announce-added = お知らせを追加しました
+cmd-announce-removed = お知らせを削除しました
+cmd-art-desc = ファイルのASCII/ANSIアートを等幅ブロックで送信
+cmd-art-usage = 使い方: /{ $command } [切り詰め] <ファイル>
+cmd-art-arg-truncate = 切り詰め
+cmd-art-too-long = アートは{ $length }文字で、このサーバーの上限{ $max }文字を超えています。/{ $command } { $truncate } <ファイル> で収まる行だけを送信できます
 cmd-broadcast-desc = 全ユーザーにブロードキャストを送信
 cmd-broadcast-usage = 使用方法: /{ $command } <メッセージ>
 cmd-clear-desc = 現在のタブのチャット履歴をクリア
 cmd-clear-usage = 使用方法: /{ $command }
+cmd-delete-desc = 自分の最新メッセージを削除（モデレーター：ユーザーの最新メッセージ）
+cmd-delete-usage = 使い方: /{ $command } [ユーザー名]
+cmd-delete-no-message = 削除できる { $username } の最近のメッセージはありません
+cmd-delete-permission-denied = 他のユーザーのメッセージを削除する権限がありません
+cmd-edit-desc = 自分の最新メッセージを編集
+cmd-edit-usage = 使い方: /{ $command } <メッセージ>
+cmd-edit-no-message = 編集できる最近のメッセージはありません
+cmd-events-desc = この接続のライフサイクルイベントを表示
+cmd-events-usage = 使い方: /{ $command }
+cmd-events-header = 接続イベント（古い順）:
+cmd-events-empty = 記録された接続イベントはまだありません
+cmd-report-desc = バグ報告用に機密情報を除いたクライアントと接続の情報をコピー
+cmd-report-usage = 使い方: /{ $command }
+cmd-report-copied = バグ報告用の情報をクリップボードにコピーしました。除外: パスワード、サーバーアドレス、ユーザー名、証明書フィンガープリント、サーバー名、プロキシアドレス
 cmd-focus-desc = サーバーチャットまたはユーザーのメッセージウィンドウにフォーカス
 cmd-focus-usage = 使用方法: /{ $command } [ユーザー名]
 cmd-focus-not-found = ユーザーが見つかりません: { $name }
@@ -338,22 +575,90 @@ cmd-list-arg-all = 全て
 cmd-list-usage = 使用方法: /{ $command } [全て]
 cmd-list-empty = 接続中のユーザーはいません
 cmd-list-output = オンラインユーザー: { $users } ({ $count }人)
+cmd-maintenance-desc = メンテナンス（読み取り専用）モードを表示または切り替え
+cmd-maintenance-usage = 使用方法: /{ $command } [オン|オフ]
+cmd-maintenance-arg-on = オン
+cmd-maintenance-arg-off = オフ
+cmd-maintenance-on = メンテナンスモードはオンです
+cmd-maintenance-off = メンテナンスモードはオフです
+cmd-notice-desc = 全員にお知らせをバナーで表示
+cmd-notice-usage = 使い方: /{ $command } reconnect | update <url> | <メッセージ>
+cmd-notify-desc = オンラインになったときに通知するユーザーを表示・管理
+cmd-notify-usage = 使用方法: /{ $command } [追加 <ユーザー名>|削除 <ユーザー名>]
+cmd-notify-arg-add = 追加
+cmd-notify-arg-del = 削除
+cmd-notify-no-bookmark = ウォッチリストはブックマークに保存されるため、/notify はブックマーク済みのサーバーでのみ使用できます
+cmd-notify-none = このサーバーでウォッチ中のユーザーはいません
+cmd-notify-list = ウォッチ中: { $usernames }
+cmd-notify-added = { $username } がオンラインになると通知します
+cmd-notify-already = { $username } はすでにウォッチリストにあります
+cmd-notify-removed = { $username } のウォッチを解除しました
+cmd-notify-not-watched = { $username } はウォッチリストにありません
 cmd-list-all-no-permission = 全ユーザーを表示するにはuser_editまたはuser_delete権限が必要です
 cmd-list-all-output = ユーザー: { $users } ({ $count }人)
+cmd-whoami-desc = 自分のユーザー名、ロール、権限、機能を表示
+cmd-whoami-usage = 使い方: /{ $command }
 cmd-help-usage = 使用方法: /{ $command } [コマンド]
 cmd-topic-permission-denied = トピックを編集する権限がありません
+cmd-page-desc = サーバーの情報ページを一覧表示または閲覧（管理者は設定・削除も可能）
+cmd-page-usage = 使用方法: /{ $command } [<名前>|設定 <名前> <本文>|削除 <名前>] (\n で改行)
+cmd-page-arg-set = 設定
+cmd-page-arg-delete = 削除
+cmd-page-list = ページ: { $pages }
+cmd-page-none = このサーバーにはページがありません
+cmd-page-saved = ページを保存しました
+cmd-page-deleted = ページを削除しました
+cmd-pin-desc = ピン留めされたメッセージを一覧表示、またはユーザーの最新メッセージをピン留め
+cmd-pin-usage = 使い方: /{ $command } [ユーザー名]
+cmd-pin-list = ピン留めされたメッセージ:
+cmd-pin-list-item = { $number }. { $username }: { $message }（{ $pinned_by } がピン留め）
+cmd-pin-none = ピン留めされたメッセージはありません
+cmd-pin-no-message = { $username } のピン留めできる最近のメッセージがありません
+cmd-purge-desc = サーバーの保持ポリシーを超えたチャット履歴を削除
+cmd-purge-usage = 使い方: /{ $command }
+cmd-refresh-desc = サーバーのデータベースからユーザーの権限を再送信
+cmd-refresh-usage = 使用方法: /{ $command } <ユーザー名>
+cmd-transfer-desc = 別のアカウントを管理者にし、「維持」を付けない限り自分は管理者を降ります
+cmd-transfer-usage = 使用方法: /{ $command } <ユーザー名> [維持]
+cmd-transfer-arg-keep = 維持
+cmd-seen-desc = ユーザーが最後にオンラインだった日時を表示
+cmd-seen-usage = 使い方: /{ $command } <ユーザー名>
+cmd-session-desc = この接続のセッションと通信量を表示
+cmd-session-usage = 使い方: /{ $command }
+cmd-session-info = セッション { $id }（接続先: { $address }）
+cmd-session-traffic = 接続後の通信量: 送信 { $sent }、受信 { $received }
+cmd-unpin-desc = ピン留めを解除
+cmd-unpin-usage = 使い方: /{ $command } <番号>（番号は /pin で確認）
 cmd-window-desc = チャットタブを管理
-cmd-window-usage = 使用方法: /{ $command } [次|前|閉じる [ユーザー名]]
+cmd-window-usage = 使用方法: /{ $command } [次|前|閉じる [ユーザー名]|分離 [ユーザー名]]
 cmd-window-arg-next = 次
 cmd-window-arg-prev = 前
 cmd-window-arg-close = 閉じる
+cmd-window-arg-popout = 分離
 cmd-window-list = 開いているタブ: { $tabs } ({ $count }個のタブ)
 cmd-window-close-server = サーバータブは閉じられません
+cmd-window-popout-server = サーバータブは分離できません
 cmd-window-not-found = タブが見つかりません: { $name }
 cmd-serverinfo-desc = サーバー情報を表示
 cmd-serverinfo-usage = 使用方法: /{ $command }
 cmd-serverinfo-header = [サーバー]
 cmd-serverinfo-end = サーバー情報終了
+cmd-slow-desc = チャットのスローモードを表示または設定
+cmd-slow-usage = 使用方法: /{ $command } [秒]
+cmd-slow-off = スローモードはオフです
+cmd-slow-current = スローモード: メッセージ間隔{ $seconds }秒
+cmd-stats-desc = 直近1時間のサーバー接続履歴を表示
+cmd-stats-usage = 使い方: /{ $command } [リセット]
+cmd-stats-arg-reset = リセット
+cmd-stats-current = 現在の接続数: { $count }
+cmd-stats-total = 直近{ $minutes }分: 接続{ $connections }件、拒否{ $refused }件、最大同時接続{ $peak }(1アドレスから最大{ $peak_per_ip })
+cmd-stats-group = { $time }: 接続{ $connections }件、拒否{ $refused }件、最大{ $peak }
+cmd-stats-none = 接続履歴はまだ記録されていません
+cmd-set-desc = このサーバーのチャット設定を表示または変更
+cmd-set-usage = 使い方: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = このサーバーでは入退室メッセージを表示します
+cmd-set-joinleave-off = このサーバーでは入退室メッセージを非表示にします
+cmd-set-joinleave-default = このサーバーでは入退室メッセージは全体の設定に従います
 
 # =============================================================================
 # About Panel
@@ -363,6 +668,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = 画像を選択
 button-clear-image = クリア
+button-clear-theme = クリア
+button-copy-fingerprint = コピー
+button-clear-fingerprint = 消去
+button-dismiss = 閉じる
+button-reconnect = 再接続
+button-open-link = リンクを開く
 label-server-image = サーバー画像:
 err-server-image-too-large = サーバー画像が大きすぎます（最大512KB）
 err-server-image-invalid-format = サーバー画像の形式が無効です（base64エンコードのデータURIである必要があります）
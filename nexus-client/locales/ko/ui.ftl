@@ -5,14 +5,20 @@
 # =============================================================================
 
 button-cancel = 취소
+button-continue = 계속
 button-send = 보내기
+button-send-as-one-message = 메시지 하나로 보내기
 button-delete = 삭제
 button-connect = 연결
 button-save = 저장
 button-create = 생성
 button-edit = 편집
+button-edit-profile = 프로필 편집
 button-update = 업데이트
 button-accept-new-certificate = 새 인증서 수락
+button-always-accept-certificate = 항상 수락
+button-accept-rules = 규칙 동의
+button-disconnect = 연결 해제
 button-close = 닫기
 button-choose-avatar = 아바타 선택
 button-clear-avatar = 지우기
@@ -22,6 +28,8 @@ button-clear-avatar = 지우기
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = 서버에 연결
 title-add-bookmark = 북마크 추가
 title-edit-server = 서버 편집
@@ -34,7 +42,10 @@ title-settings = 설정
 title-bookmarks = 북마크
 title-users = 사용자
 title-edit-server-info = 서버 정보 편집
+title-edit-profile = 프로필 편집
 title-fingerprint-mismatch = 인증서 지문이 일치하지 않습니다!
+title-server-rules = 서버 규칙
+title-server-banner = 서버 공지
 title-server-info = 서버 정보
 title-user-info = 사용자 정보
 title-about = 정보
@@ -46,39 +57,99 @@ title-about = 정보
 placeholder-username = 사용자 이름
 placeholder-password = 비밀번호
 placeholder-port = 포트
+placeholder-fallback-addresses = 대체 주소 (선택, 쉼표로 구분한 host:port)
+placeholder-watched-users = 접속 시 알림 (선택 사항, 쉼표로 구분한 사용자 이름)
 placeholder-server-address = 서버 주소
 placeholder-server-name = 서버 이름
 placeholder-username-optional = 사용자 이름 (선택)
 placeholder-password-optional = 비밀번호 (선택)
 placeholder-password-keep-current = 비밀번호 (현재 유지하려면 비워두세요)
 placeholder-message = 메시지를 입력하세요...
+placeholder-message-slow-mode = 슬로우 모드: { $seconds }초 대기...
+placeholder-message-maintenance = 유지 관리 모드: 입력이 비활성화되었습니다
 placeholder-no-permission = 권한 없음
 placeholder-broadcast-message = 브로드캐스트 메시지를 입력하세요...
 placeholder-server-description = 서버 설명
+placeholder-pronouns = 예: they/them
+placeholder-bio = 자신에 대한 짧은 한 줄
+placeholder-global-theme = 전역 테마 사용
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = 자동 연결
+label-trust-certificate-changes = 이 서버의 인증서 변경을 항상 수락 (보안 약화)
 label-add-bookmark = 북마크 추가
+label-invite-fingerprint = 초대 링크의 인증서 지문: { $fingerprint }
 label-admin = 관리자
 label-enabled = 활성화
 label-permissions = 권한:
 label-expected-fingerprint = 예상 지문:
 label-received-fingerprint = 수신된 지문:
 label-theme = 테마
+label-density = 표시 밀도:
+density-comfortable = 기본
+density-compact = 좁게
+label-show-role-badges = 사용자 목록에 관리자 및 운영자 배지 표시
+badge-admin = 관리자
+badge-moderator = 운영자
+badge-guest = 게스트
+user-list-permissions = 권한: { $permissions }
+user-list-away = 자리 비움
 label-chat-font-size = 글꼴 크기:
+label-max-message-tabs = 최대 메시지 탭 수:
+label-paste-confirm-lines = 이 줄 수 이상 붙여넣기 시 확인 (0 = 안 함):
+label-collapse-message-lines = 이 줄 수보다 긴 메시지 접기 (0 = 안 함):
+label-ctrl-enter-sends = Ctrl+Enter로 보내기 (Enter는 줄 바꿈)
+label-message-format = 메시지 형식:
+message-format-preset-default = 기본
+message-format-preset-compact = 간결
+message-format-preset-irc = IRC 스타일
+message-format-preset-verbose = 자세히 (날짜 포함)
+message-format-preset-custom = 사용자 지정
 label-show-connection-notifications = 연결 알림 표시
+label-hide-repeated-topic = 재연결 시 주제가 바뀐 경우에만 표시
+label-alert-on-disconnect = 연결이 끊기면 창 깜박이기
+label-alert-on-mention = 누군가 나를 멘션하면 창 깜박이기
+label-focus-new-messages = 입력이 없는 동안 도착한 개인 메시지로 전환
+label-quiet-hours = 방해 금지 시간 (창 깜박임 안 함)
+label-quiet-hours-from = 시작
+label-quiet-hours-to = 종료
+label-watched-users-ignore-quiet-hours = 방해 금지 시간에도 관심 사용자 접속 알림
+label-auto-away = 입력이 없으면 자리 비움으로 표시
+label-auto-away-minutes = 입력 없는 시간(분)
+label-quiet-hours-active = 방해 금지 시간
 label-show-timestamps = 타임스탬프 표시
 label-use-24-hour-time = 24시간 형식 사용
 label-show-seconds = 초 표시
+label-timezone = 시간대:
+timezone-local = 시스템 로컬
+label-privacy = 개인정보
+label-trusted-certificates = 신뢰하는 인증서
+label-fingerprint-not-pinned = 아직 고정된 인증서가 없습니다
+label-forget-passwords = 비밀번호 저장 안 함 (연결 시 묻기)
+label-sync-preferences = 테마 및 채팅 표시 설정을 서버와 동기화
+label-check-for-updates = 시작할 때 새 클라이언트 확인
+label-proxy = 프록시
+label-use-socks5-proxy = SOCKS5 프록시를 통해 연결 (Tor, I2P)
+label-proxy-address = 주소:
+label-proxy-port = 포트:
+label-diagnostics = 진단
+label-connection-log-path = 연결 로그 파일:
+placeholder-connection-log-path = 비워 두면 이벤트를 메모리에만 보관합니다
 label-server-name = 이름:
 label-server-description = 설명:
+label-pronouns = 대명사:
+label-bio = 소개:
 label-server-version = 버전:
+label-server-features = 기능:
+label-users-online = 온라인 사용자:
 label-chat-topic = 채팅 주제:
 label-chat-topic-set-by = 주제 설정자:
 label-max-connections-per-ip = IP당 최대 연결 수:
+label-traffic = 트래픽:
+server-info-traffic-value = 보냄 { $sent }, 받음 { $received }
 label-avatar = 아바타:
 label-details = 기술 세부 정보
 label-chat-options = 채팅 옵션
@@ -86,6 +157,14 @@ label-appearance = 외관
 label-image = 이미지
 label-general = 일반
 label-limits = 제한
+label-broadcast-target = 받는 사람:
+broadcast-target-everyone = 모두
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = 채팅
 
 # =============================================================================
 # Permission Display Names
@@ -97,6 +176,8 @@ permission-chat_send = 채팅 전송
 permission-chat_receive = 채팅 수신
 permission-chat_topic = 채팅 주제
 permission-chat_topic_edit = 채팅 주제 편집
+permission-chat_pin = 채팅 고정
+permission-chat_moderate = 채팅 관리
 permission-user_broadcast = 사용자 브로드캐스트
 permission-user_create = 사용자 생성
 permission-user_delete = 사용자 삭제
@@ -115,16 +196,25 @@ tooltip-user-edit = 사용자 편집
 tooltip-server-info = 서버 정보
 tooltip-about = 정보
 tooltip-settings = 설정
+tooltip-quiet-hours-active = 방해 금지 시간이므로 창이 깜박이지 않습니다
 tooltip-hide-bookmarks = 북마크 숨기기
 tooltip-show-bookmarks = 북마크 표시
 tooltip-hide-user-list = 사용자 목록 숨기기
 tooltip-show-user-list = 사용자 목록 표시
 tooltip-disconnect = 연결 해제
+tooltip-connection-health-unknown = 연결 품질이 아직 측정되지 않았습니다
+tooltip-connection-health-good = 연결 양호 ({ $rtt } ms)
+tooltip-connection-health-fair = 연결 보통 ({ $rtt } ms)
+tooltip-connection-health-poor = 연결 불량 ({ $rtt } ms)
+tooltip-connection-health-no-response = 서버에서 응답이 없습니다
 tooltip-edit = 편집
 tooltip-info = 정보
 tooltip-message = 메시지
 tooltip-kick = 추방
 tooltip-close = 닫기
+tooltip-pop-out = 창으로 분리
+tooltip-unpin = 고정 해제
+tooltip-pinned-by = { $username }님이 고정함
 tooltip-add-bookmark = 북마크 추가
 
 # =============================================================================
@@ -141,6 +231,7 @@ empty-no-users = 온라인 사용자 없음
 # =============================================================================
 
 chat-tab-server = #서버
+chat-tab-server-count = #서버 ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -155,18 +246,47 @@ chat-prefix-system = [시스템]
 chat-prefix-error = [오류]
 chat-prefix-info = [정보]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [공지]
+chat-prefix-pinned = [고정]
+chat-message-edited = (수정됨)
+chat-message-deleted = (삭제됨)
+chat-message-removed-by-moderator = (운영자가 삭제함)
+chat-show-more = 더 보기
+chat-show-less = 접기
+chat-message-encrypted = (암호화됨)
+chat-message-unreadable = (복호화할 수 없는 암호화된 메시지)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = 사용자가 성공적으로 추방되었습니다
+msg-session-kicked-success = 세션 연결을 끊었습니다
+msg-confirm-clear-fingerprint = { $name }의 고정된 인증서를 지울까요? 다음 연결에서는 서버가 제시하는 인증서를 그대로 신뢰합니다.
 msg-broadcast-sent = 브로드캐스트가 성공적으로 전송되었습니다
 msg-user-created = 사용자가 성공적으로 생성되었습니다
 msg-user-deleted = 사용자가 성공적으로 삭제되었습니다
 msg-user-updated = 사용자가 성공적으로 업데이트되었습니다
 msg-permissions-updated = 권한이 업데이트되었습니다
 msg-topic-updated = 주제가 성공적으로 업데이트되었습니다
+msg-message-pinned = 메시지를 고정했습니다
+msg-message-unpinned = 메시지 고정을 해제했습니다
+msg-chat-history-purged = 채팅 기록 정리됨: 메시지 { $count }개 삭제
+msg-maintenance-enabled = 서버가 유지 관리 모드로 전환되었습니다. 변경이 비활성화됩니다
+msg-maintenance-disabled = 유지 관리 모드가 끝났습니다. 다시 변경할 수 있습니다
+msg-new-login = 계정이 방금 { $address }({ $source })에서 로그인했습니다
+msg-seen-online = { $username } 님은 지금 온라인입니다
+msg-seen-ago = { $username } 님의 마지막 접속: { $duration } 전 ({ $time })
+msg-seen-never = { $username } 님은 아직 접속한 적이 없습니다
+msg-maintenance-banner = 유지 관리 모드: 현재 서버는 읽기 전용입니다
+msg-admin-notice-reconnect = 관리자 알림: 서버에 다시 연결하세요
+msg-admin-notice-update = 관리자 알림: 클라이언트 업데이트가 있습니다 { $url }
+msg-update-available = Nexus BBS { $version } 버전을 사용할 수 있습니다
+msg-admin-notice = 관리자 알림: { $message }
+msg-permissions-refreshed = 권한을 새로 고쳤습니다
+msg-admin-transferred = { $username }님이 이제 관리자입니다
+msg-copied-to-clipboard = 클립보드에 복사했습니다
+msg-large-paste-confirm = 이 메시지에는 붙여넣은 { $count }줄이 있습니다. 보낼까요?
 
 # =============================================================================
 # Dynamic Messages (with parameters)
@@ -176,9 +296,19 @@ msg-topic-cleared = { $username }님이 주제를 삭제했습니다
 msg-topic-set = { $username }님이 주제를 설정했습니다: { $topic }
 msg-server-info-updated = 서버 설정이 업데이트되었습니다
 msg-topic-display = 주제: { $topic }
+msg-connected-via-fallback = 대체 주소 { $address }(으)로 연결되었습니다
+msg-certificate-change-trusted = 이 북마크는 인증서 변경을 신뢰하므로 변경된 서버 인증서를 수락했습니다 (새 지문 { $fingerprint })
 msg-user-connected = { $username }님이 연결되었습니다
 msg-user-disconnected = { $username }님의 연결이 해제되었습니다
+msg-watched-user-online = { $username } 님이 접속했습니다
+msg-watched-users-online = 접속 중인 관심 사용자: { $usernames }
 msg-disconnected = 연결 해제됨: { $error }
+msg-enter-password-for = { $name }의 비밀번호를 입력하세요
+connect-step-progress = { $total }단계 중 { $step }단계: { $description }
+connect-step-tcp = 서버에 연결하는 중...
+connect-step-tls = 연결을 보호하는 중...
+connect-step-handshake = 프로토콜 협상 중...
+connect-step-login = 로그인하는 중...
 msg-connection-cancelled = 인증서 불일치로 연결이 취소되었습니다
 
 # =============================================================================
@@ -186,7 +316,9 @@ msg-connection-cancelled = 인증서 불일치로 연결이 취소되었습니
 # =============================================================================
 
 err-connection-broken = 연결 오류
+err-account-disabled-while-connected = 관리자가 계정을 비활성화했습니다. 다시 연결하기 전에 관리자에게 계정 활성화를 요청하세요.
 err-failed-update-server-info = 서버 정보 업데이트 실패: { $error }
+err-failed-update-profile = 프로필 업데이트 실패: { $error }
 err-user-kick-failed = 사용자 추방 실패
 err-no-shutdown-handle = 연결 오류: 종료 핸들 없음
 err-userlist-failed = 사용자 목록 새로고침 실패
@@ -204,6 +336,9 @@ err-could-not-determine-config-dir = 설정 디렉토리를 확인할 수 없습
 err-message-too-long = 메시지가 너무 깁니다 ({ $length }자, 최대 { $max }자)
 err-send-failed = 메시지 전송 실패
 err-no-chat-permission = 메시지를 보낼 권한이 없습니다
+err-private-messages-disabled = 이 서버에서는 개인 메시지가 비활성화되어 있습니다
+err-popout-command = 명령은 메인 창에서만 사용할 수 있습니다
+err-multiline-command = 명령은 한 줄로 입력해야 합니다 (메시지로 보내려면 //로 시작하세요)
 err-broadcast-too-long = 브로드캐스트가 너무 깁니다 ({ $length }자, 최대 { $max }자)
 err-broadcast-send-failed = 브로드캐스트 전송 실패
 err-name-required = 북마크 이름은 필수입니다
@@ -215,6 +350,7 @@ err-message-required = 메시지는 필수입니다
 
 # Validation errors
 err-message-empty = 메시지는 비워둘 수 없습니다
+err-slow-mode-wait = 슬로우 모드가 활성화되어 있습니다. { $seconds }초 기다려 주세요
 err-message-contains-newlines = 메시지에 줄바꿈을 포함할 수 없습니다
 err-message-invalid-characters = 메시지에 잘못된 문자가 포함되어 있습니다
 err-username-empty = 사용자 이름은 비워둘 수 없습니다
@@ -232,6 +368,12 @@ err-server-name-invalid-characters = 서버 이름에 잘못된 문자가 포함
 err-server-description-too-long = 설명이 너무 깁니다 (최대 { $max }자)
 err-server-description-contains-newlines = 설명에 줄바꿈을 포함할 수 없습니다
 err-server-description-invalid-characters = 설명에 잘못된 문자가 포함되어 있습니다
+err-bio-too-long = 소개가 너무 깁니다 (최대 { $max }자)
+err-bio-contains-newlines = 소개에는 줄바꿈을 포함할 수 없습니다
+err-bio-invalid-characters = 소개에 잘못된 문자가 포함되어 있습니다
+err-pronouns-too-long = 대명사가 너무 깁니다 (최대 { $max }자)
+err-pronouns-contains-newlines = 대명사에는 줄바꿈을 포함할 수 없습니다
+err-pronouns-invalid-characters = 대명사에 잘못된 문자가 포함되어 있습니다
 err-failed-send-update = 업데이트 전송 실패: { $error }
 
 # =============================================================================
@@ -239,25 +381,68 @@ err-failed-send-update = 업데이트 전송 실패: { $error }
 # =============================================================================
 
 err-failed-save-config = 설정 저장 실패: { $error }
+err-connection-log-write = 연결 로그 { $path }을(를) 쓰지 못했습니다: { $error }
 err-failed-save-settings = 설정 저장 실패: { $error }
 err-invalid-port-bookmark = 북마크의 포트가 잘못되었습니다: { $name }
+err-fallback-address-invalid = 잘못된 대체 주소: { $address }
 err-failed-send-broadcast = 브로드캐스트 전송 실패: { $error }
 err-failed-send-message = 메시지 전송 실패: { $error }
+err-command-unsupported = 서버가 /{ $command }을(를) 지원하지 않습니다
 err-failed-create-user = 사용자 생성 실패: { $error }
 err-failed-delete-user = 사용자 삭제 실패: { $error }
 err-failed-update-user = 사용자 업데이트 실패: { $error }
 err-failed-update-topic = 주제 업데이트 실패: { $error }
+err-failed-pin-message = 메시지 고정 실패: { $error }
+err-failed-unpin-message = 메시지 고정 해제 실패: { $error }
+err-failed-edit-message = 메시지 수정 실패: { $error }
+err-failed-delete-message = 메시지 삭제 실패: { $error }
+err-failed-purge-history = 채팅 기록 정리 실패: { $error }
+err-failed-announcement = 공지 업데이트 실패: { $error }
+err-failed-stats = 연결 통계를 가져오지 못했습니다: { $error }
+err-failed-history = 이전 메시지를 불러오지 못했습니다: { $error }
+err-art-read = { $path }을(를) 읽을 수 없습니다: { $error }
+err-art-file-too-large = 아트 파일은 최대 { $max } KiB입니다
+err-art-empty = 아트 파일에 보낼 내용이 없습니다
+err-art-fence = 아트에는 ```를 넣을 수 없습니다 (블록이 끝나 버립니다)
+err-art-first-line = 첫 줄조차 이 서버의 제한 { $max }자에 들어가지 않습니다
+err-failed-page = 페이지 요청 실패: { $error }
+err-failed-set-maintenance = 유지 관리 모드를 변경하지 못했습니다: { $error }
+err-failed-send-admin-notice = 알림을 보내지 못했습니다: { $error }
+err-notice-url-invalid = 링크는 http:// 또는 https://로 시작해야 하며 공백을 포함할 수 없습니다
+err-failed-refresh-permissions = 권한 새로 고침 실패: { $error }
+err-failed-transfer-admin = 관리자 권한 이전 실패: { $error }
+err-failed-sync-preferences = 환경설정 동기화 실패: { $error }
+err-failed-accept-rules = 규칙 동의 실패: { $error }
 err-message-too-long-details = { $error } ({ $length }자, 최대 { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = 잘못된 주소 '{ $address }': { $error }
+err-all-addresses-failed = { $count }개 주소 중 어느 곳에도 연결할 수 없습니다 (마지막 오류: { $error })
 err-could-not-resolve = 주소 '{ $address }'를 확인할 수 없습니다
 err-connection-timeout = { $seconds }초 후 연결 시간 초과
 err-connection-failed = 연결 실패: { $error }
+err-proxy-address-empty = 프록시 주소가 필요합니다
+err-proxy-address-invalid = 프록시 주소는 공백 없는 호스트 이름 또는 IP 주소여야 합니다
+err-proxy-port-invalid = 프록시 포트는 1에서 65535 사이여야 합니다
+err-quiet-hours-start-invalid = 방해 금지 시작 시간은 22:00 같은 24시간 형식이어야 합니다
+err-quiet-hours-end-invalid = 방해 금지 종료 시간은 08:00 같은 24시간 형식이어야 합니다
+err-message-format-unclosed-brace = 메시지 형식에 닫히지 않은 자리 표시자가 있습니다 (중괄호를 그대로 표시하려면 두 번 입력하세요)
+err-message-format-unknown-placeholder = 메시지 형식에 알 수 없는 자리 표시자 { $placeholder }가 있습니다 (사용 가능: { $available })
+err-message-format-missing-placeholder = 메시지 형식에는 { $placeholder }가 있어야 합니다
+err-message-format-duplicate-placeholder = 메시지 형식에는 { $placeholder }를 한 번만 사용할 수 있습니다
+err-invite-not-a-link = nexus:// 초대 링크가 아닙니다
+err-invite-invalid-address = 초대 링크의 서버 주소가 잘못되었습니다
+err-invite-invalid-port = 초대 링크의 포트는 1에서 65535 사이여야 합니다
+err-invite-invalid-name = 초대 링크의 서버 이름이 잘못되었습니다
+err-invite-invalid-fingerprint = 초대 링크의 인증서 지문이 잘못되었습니다
+err-invite-credentials = 초대 링크에는 사용자명이나 비밀번호를 포함할 수 없습니다
+err-invite-fingerprint-mismatch = 서버 인증서가 초대 링크와 일치하지 않습니다 (예상 { $expected }, 수신 { $received })
+err-proxy-connection-failed = 프록시 { $proxy }를 통해 연결할 수 없습니다: { $error }
 err-tls-handshake-failed = TLS 핸드셰이크 실패: { $error }
 err-failed-send-handshake = 핸드셰이크 전송 실패: { $error }
 err-failed-read-handshake = 핸드셰이크 응답 읽기 실패: { $error }
 err-handshake-failed = 핸드셰이크 실패: { $error }
+err-client-too-old = 이 서버에는 최신 Nexus 클라이언트가 필요합니다. 연결하려면 업데이트하세요. ({ $error })
 err-failed-parse-handshake = 핸드셰이크 응답 구문 분석 실패: { $error }
 err-failed-send-login = 로그인 전송 실패: { $error }
 err-failed-read-login = 로그인 응답 읽기 실패: { $error }
@@ -274,6 +459,7 @@ err-failed-set-config-permissions = 설정 파일 권한 설정 실패: { $error
 # =============================================================================
 
 fingerprint-warning = 이는 보안 문제(MITM 공격)를 나타내거나 서버 인증서가 재생성되었을 수 있습니다. 서버 관리자를 신뢰하는 경우에만 수락하세요.
+server-rules-intro = 이 서버에서 채팅하기 전에 이 규칙에 동의해야 합니다.
 
 # =============================================================================
 # User Info Display
@@ -283,15 +469,24 @@ user-info-username = 사용자명:
 user-info-role = 역할:
 user-info-role-admin = 관리자
 user-info-role-user = 사용자
+user-info-role-guest = 게스트
+user-info-pronouns = 대명사:
+user-info-bio = 소개:
 user-info-connected = 연결됨:
 user-info-connected-value = { $duration } 전
 user-info-connected-value-sessions = { $duration } 전 ({ $count }개 세션)
 user-info-features = 기능:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (비활성)
 user-info-features-none = 없음
+user-info-permissions = 권한:
+user-info-permissions-none = 없음
 user-info-locale = 언어:
 user-info-address = 주소:
 user-info-addresses = 주소:
+user-info-sessions = 세션:
+user-info-session = 세션 { $id }
+user-info-this-session = 이 세션
 user-info-created = 생성일:
 user-info-end = 사용자 정보 끝
 user-info-unknown = 알 수 없음
@@ -316,20 +511,62 @@ cmd-help-desc = 사용 가능한 명령어 표시
 cmd-help-escape-hint = 팁: /로 시작하는 메시지를 보내려면 //를 사용하세요
 cmd-message-desc = 사용자에게 메시지 보내기
 cmd-message-usage = 사용법: /{ $command } <사용자명> <메시지>
+cmd-query-desc = 메시지를 보내지 않고 사용자와의 메시지 탭 열기
+cmd-query-usage = 사용법: /{ $command } <사용자명>
+cmd-query-not-online = 사용자가 온라인이 아닙니다: { $username }
+cmd-query-self = 자기 자신에게 메시지를 보낼 수 없습니다
+cmd-reconnect-desc = 이 서버와의 연결을 끊고 다시 연결
+cmd-reconnect-usage = 사용법: /{ $command }
+cmd-reconnect-no-bookmark = /{ $command }로 다시 연결하려면 북마크가 필요합니다. 이 연결은 북마크에서 만들어지지 않았습니다
 cmd-userinfo-desc = 사용자 정보 표시
 cmd-userinfo-usage = 사용법: /{ $command } <사용자명>
+cmd-invite-desc = 이 서버의 초대 링크 복사
+cmd-invite-usage = 사용법: /{ $command }
+cmd-invite-copied = 초대 링크를 클립보드에 복사했습니다: { $link }
 cmd-kick-desc = 서버에서 사용자 추방
 cmd-kick-usage = 사용법: /{ $command } <사용자명>
+cmd-limits-desc = 메시지와 주제 길이 제한을 보거나 설정합니다
+cmd-limits-usage = 사용법: /{ $command } [메시지 <글자수>|주제 <글자수>]
+cmd-limits-arg-message = 메시지
+cmd-limits-arg-topic = 주제
+cmd-limits-current = 제한: 메시지 최대 { $message }자, 주제 최대 { $topic }자
 cmd-topic-desc = 채팅 주제 보기 또는 관리
 cmd-topic-usage = 사용법: /{ $command } [설정|지우기] [주제]
 cmd-topic-arg-set = 설정
 cmd-topic-arg-clear = 지우기
 cmd-topic-set-usage = 사용법: /{ $command } 설정 <주제>
 cmd-topic-none = 설정된 주제가 없습니다
+cmd-announce-desc = 서버의 순환 공지 보기 또는 관리
+cmd-announce-usage = 사용법: /{ $command } [추가 <메시지>|삭제 <번호>]
+cmd-announce-arg-add = 추가
+cmd-announce-arg-remove = 삭제
+cmd-announce-list = 공지 ({ $minutes }분마다):
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = 설정된 공지가 없습니다
+cmd-announce-added = 공지를 추가했습니다
+cmd-announce-removed = 공지를 삭제했습니다
+cmd-art-desc = 파일의 ASCII 또는 ANSI 아트를 고정폭 블록으로 보내기
+cmd-art-usage = 사용법: /{ $command } [자르기] <파일>
+cmd-art-arg-truncate = 자르기
+cmd-art-too-long = 아트가 { $length }자로 이 서버의 제한 { $max }자를 넘습니다. /{ $command } { $truncate } <파일>로 들어가는 줄만 보낼 수 있습니다
 cmd-broadcast-desc = 모든 사용자에게 공지 보내기
 cmd-broadcast-usage = 사용법: /{ $command } <메시지>
 cmd-clear-desc = 현재 탭의 채팅 기록 지우기
 cmd-clear-usage = 사용법: /{ $command }
+cmd-delete-desc = 내 최근 메시지 삭제 (관리자: 사용자의 최근 메시지)
+cmd-delete-usage = 사용법: /{ $command } [사용자명]
+cmd-delete-no-message = 삭제할 { $username }님의 최근 메시지가 없습니다
+cmd-delete-permission-denied = 다른 사용자의 메시지를 삭제할 권한이 없습니다
+cmd-edit-desc = 내 최근 메시지 수정
+cmd-edit-usage = 사용법: /{ $command } <메시지>
+cmd-edit-no-message = 수정할 최근 메시지가 없습니다
+cmd-events-desc = 이 연결의 수명 주기 이벤트 표시
+cmd-events-usage = 사용법: /{ $command }
+cmd-events-header = 연결 이벤트 (오래된 순):
+cmd-events-empty = 아직 기록된 연결 이벤트가 없습니다
+cmd-report-desc = 버그 보고용으로 민감한 정보를 뺀 클라이언트 및 연결 정보를 복사합니다
+cmd-report-usage = 사용법: /{ $command }
+cmd-report-copied = 버그 보고 정보를 클립보드에 복사했습니다. 제외: 비밀번호, 서버 주소, 사용자 이름, 인증서 지문, 서버 이름, 프록시 주소
 cmd-focus-desc = 서버 채팅 또는 사용자 메시지 창에 포커스
 cmd-focus-usage = 사용법: /{ $command } [사용자명]
 cmd-focus-not-found = 사용자를 찾을 수 없습니다: { $name }
@@ -338,22 +575,90 @@ cmd-list-arg-all = 전체
 cmd-list-usage = 사용법: /{ $command } [전체]
 cmd-list-empty = 접속 중인 사용자가 없습니다
 cmd-list-output = 온라인 사용자: { $users } ({ $count }명)
+cmd-maintenance-desc = 유지 관리(읽기 전용) 모드 보기 또는 전환
+cmd-maintenance-usage = 사용법: /{ $command } [켜기|끄기]
+cmd-maintenance-arg-on = 켜기
+cmd-maintenance-arg-off = 끄기
+cmd-maintenance-on = 유지 관리 모드가 켜져 있습니다
+cmd-maintenance-off = 유지 관리 모드가 꺼져 있습니다
+cmd-notice-desc = 모든 사용자에게 알림을 배너로 표시
+cmd-notice-usage = 사용법: /{ $command } reconnect | update <url> | <메시지>
+cmd-notify-desc = 접속 시 알림을 받을 사용자를 보거나 관리합니다
+cmd-notify-usage = 사용법: /{ $command } [추가 <사용자이름>|삭제 <사용자이름>]
+cmd-notify-arg-add = 추가
+cmd-notify-arg-del = 삭제
+cmd-notify-no-bookmark = 관심 목록은 북마크에 저장되므로 /notify는 북마크된 서버에서만 사용할 수 있습니다
+cmd-notify-none = 이 서버에서 관심 등록한 사용자가 없습니다
+cmd-notify-list = 관심 사용자: { $usernames }
+cmd-notify-added = { $username } 님이 접속하면 알려 드립니다
+cmd-notify-already = { $username } 님은 이미 관심 목록에 있습니다
+cmd-notify-removed = { $username } 님을 관심 목록에서 제거했습니다
+cmd-notify-not-watched = { $username } 님은 관심 목록에 없습니다
 cmd-list-all-no-permission = 전체 사용자를 보려면 user_edit 또는 user_delete 권한이 필요합니다
 cmd-list-all-output = 사용자: { $users } ({ $count }명)
+cmd-whoami-desc = 내 사용자 이름, 역할, 권한 및 기능 표시
+cmd-whoami-usage = 사용법: /{ $command }
 cmd-help-usage = 사용법: /{ $command } [명령어]
 cmd-topic-permission-denied = 주제를 편집할 권한이 없습니다
+cmd-page-desc = 서버 정보 페이지 목록 보기 또는 읽기 (관리자: 설정 또는 삭제)
+cmd-page-usage = 사용법: /{ $command } [<이름>|설정 <이름> <내용>|삭제 <이름>] (\n은 줄바꿈)
+cmd-page-arg-set = 설정
+cmd-page-arg-delete = 삭제
+cmd-page-list = 페이지: { $pages }
+cmd-page-none = 이 서버에는 페이지가 없습니다
+cmd-page-saved = 페이지를 저장했습니다
+cmd-page-deleted = 페이지를 삭제했습니다
+cmd-pin-desc = 고정된 메시지 목록 보기 또는 사용자의 최근 메시지 고정
+cmd-pin-usage = 사용법: /{ $command } [사용자명]
+cmd-pin-list = 고정된 메시지:
+cmd-pin-list-item = { $number }. { $username }: { $message } ({ $pinned_by }님이 고정)
+cmd-pin-none = 고정된 메시지가 없습니다
+cmd-pin-no-message = 고정할 { $username }님의 최근 메시지가 없습니다
+cmd-purge-desc = 서버 보존 정책을 벗어난 채팅 기록 정리
+cmd-purge-usage = 사용법: /{ $command }
+cmd-refresh-desc = 서버 데이터베이스에서 사용자 권한을 다시 보내기
+cmd-refresh-usage = 사용법: /{ $command } <사용자명>
+cmd-transfer-desc = 다른 계정을 관리자로 만들고, 유지를 붙이지 않으면 자신은 관리자에서 물러납니다
+cmd-transfer-usage = 사용법: /{ $command } <사용자명> [유지]
+cmd-transfer-arg-keep = 유지
+cmd-seen-desc = 사용자가 마지막으로 온라인이었던 시간 표시
+cmd-seen-usage = 사용법: /{ $command } <사용자 이름>
+cmd-session-desc = 이 연결의 세션과 트래픽 표시
+cmd-session-usage = 사용법: /{ $command }
+cmd-session-info = 세션 { $id }, { $address }에 연결됨
+cmd-session-traffic = 연결 후 트래픽: 보냄 { $sent }, 받음 { $received }
+cmd-unpin-desc = 고정된 메시지 해제
+cmd-unpin-usage = 사용법: /{ $command } <번호> (번호는 /pin 참조)
 cmd-window-desc = 채팅 탭 관리
-cmd-window-usage = 사용법: /{ $command } [다음|이전|닫기 [사용자명]]
+cmd-window-usage = 사용법: /{ $command } [다음|이전|닫기 [사용자명]|분리 [사용자명]]
 cmd-window-arg-next = 다음
 cmd-window-arg-prev = 이전
 cmd-window-arg-close = 닫기
+cmd-window-arg-popout = 분리
 cmd-window-list = 열린 탭: { $tabs } ({ $count }개 탭)
 cmd-window-close-server = 서버 탭은 닫을 수 없습니다
+cmd-window-popout-server = 서버 탭은 분리할 수 없습니다
 cmd-window-not-found = 탭을 찾을 수 없습니다: { $name }
 cmd-serverinfo-desc = 서버 정보 표시
 cmd-serverinfo-usage = 사용법: /{ $command }
 cmd-serverinfo-header = [서버]
 cmd-serverinfo-end = 서버 정보 끝
+cmd-slow-desc = 채팅 슬로우 모드 보기 또는 설정
+cmd-slow-usage = 사용법: /{ $command } [초]
+cmd-slow-off = 슬로우 모드가 꺼져 있습니다
+cmd-slow-current = 슬로우 모드: 메시지 간격 { $seconds }초
+cmd-stats-desc = 최근 1시간 동안의 서버 연결 기록 표시
+cmd-stats-usage = 사용법: /{ $command } [초기화]
+cmd-stats-arg-reset = 초기화
+cmd-stats-current = 현재 열린 연결: { $count }
+cmd-stats-total = 최근 { $minutes }분: 연결 { $connections }개, 거부 { $refused }개, 최대 동시 연결 { $peak }개 (한 주소에서 { $peak_per_ip }개)
+cmd-stats-group = { $time }: 연결 { $connections }개, 거부 { $refused }개, 최대 { $peak }개
+cmd-stats-none = 아직 기록된 연결 기록이 없습니다
+cmd-set-desc = 이 서버의 채팅 설정 보기 또는 변경
+cmd-set-usage = 사용법: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = 이 서버에서 입장/퇴장 메시지를 표시합니다
+cmd-set-joinleave-off = 이 서버에서 입장/퇴장 메시지를 숨깁니다
+cmd-set-joinleave-default = 이 서버에서 입장/퇴장 메시지는 전체 설정을 따릅니다
 
 # =============================================================================
 # About Panel
@@ -363,6 +668,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = 이미지 선택
 button-clear-image = 지우기
+button-clear-theme = 지우기
+button-copy-fingerprint = 복사
+button-clear-fingerprint = 지우기
+button-dismiss = 닫기
+button-reconnect = 다시 연결
+button-open-link = 링크 열기
 label-server-image = 서버 이미지:
 err-server-image-too-large = 서버 이미지가 너무 큽니다 (최대 512KB)
 err-server-image-invalid-format = 서버 이미지 형식이 잘못되었습니다 (base64 인코딩된 데이터 URI여야 합니다)
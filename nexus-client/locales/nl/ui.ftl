@@ -5,15 +5,21 @@
 # =============================================================================
 
 button-cancel = Annuleren
+button-continue = Doorgaan
 button-send = Verzenden
+button-send-as-one-message = Als één bericht versturen
 button-delete = Verwijderen
 button-connect = Verbinden
 button-save = Opslaan
 button-create = Aanmaken
 button-edit = Bewerken
+button-edit-profile = Profiel bewerken
 button-update = Bijwerken
 
 button-accept-new-certificate = Nieuw Certificaat Accepteren
+button-always-accept-certificate = Altijd Accepteren
+button-accept-rules = Regels Accepteren
+button-disconnect = Verbinding verbreken
 button-close = Sluiten
 button-choose-avatar = Avatar Kiezen
 button-clear-avatar = Wissen
@@ -23,6 +29,8 @@ button-clear-avatar = Wissen
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = Verbinden met server
 title-add-bookmark = Bladwijzer toevoegen
 title-edit-server = Server bewerken
@@ -35,7 +43,10 @@ title-settings = Instellingen
 title-bookmarks = Bladwijzers
 title-users = Gebruikers
 title-edit-server-info = Server Info bewerken
+title-edit-profile = Profiel bewerken
 title-fingerprint-mismatch = Certificaatvingerafdruk komt niet overeen!
+title-server-rules = Serverregels
+title-server-banner = Servermelding
 title-server-info = Server Info
 title-user-info = Gebruiker Info
 title-about = Over
@@ -47,39 +58,99 @@ title-about = Over
 placeholder-username = Gebruikersnaam
 placeholder-password = Wachtwoord
 placeholder-port = Poort
+placeholder-fallback-addresses = Uitwijkadressen (optioneel, host:poort gescheiden door komma's)
+placeholder-watched-users = Melden wanneer online (optioneel, gebruikersnamen gescheiden door komma's)
 placeholder-server-address = Serveradres
 placeholder-server-name = Servernaam
 placeholder-username-optional = Gebruikersnaam (optioneel)
 placeholder-password-optional = Wachtwoord (optioneel)
 placeholder-password-keep-current = Wachtwoord (leeg laten om huidige te behouden)
 placeholder-message = Typ een bericht...
+placeholder-message-slow-mode = Langzame modus: wacht { $seconds }s...
+placeholder-message-maintenance = Onderhoudsmodus: invoer is uitgeschakeld
 placeholder-no-permission = Geen toestemming
 placeholder-broadcast-message = Voer broadcastbericht in...
 placeholder-server-description = Serverbeschrijving
+placeholder-pronouns = bijv. hen/die
+placeholder-bio = Een korte regel over jezelf
+placeholder-global-theme = Globaal thema gebruiken
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = Auto-Verbinden
+label-trust-certificate-changes = Certificaatwijzigingen van deze server altijd accepteren (minder veilig)
 label-add-bookmark = Bladwijzer
+label-invite-fingerprint = Certificaatvingerafdruk uit de uitnodigingslink: { $fingerprint }
 label-admin = Beheerder
 label-enabled = Ingeschakeld
 label-permissions = Machtigingen:
 label-expected-fingerprint = Verwachte vingerafdruk:
 label-received-fingerprint = Ontvangen vingerafdruk:
 label-theme = Thema
+label-density = Dichtheid:
+density-comfortable = Ruim
+density-compact = Compact
+label-show-role-badges = Beheerders- en moderatorbadges tonen in de gebruikerslijst
+badge-admin = beheerder
+badge-moderator = mod
+badge-guest = gast
+user-list-permissions = Rechten: { $permissions }
+user-list-away = Afwezig
 label-chat-font-size = Lettergrootte:
+label-max-message-tabs = Max. berichttabbladen:
+label-paste-confirm-lines = Plakken vanaf zoveel regels bevestigen (0 = nooit):
+label-collapse-message-lines = Berichten langer dan zoveel regels inklappen (0 = nooit):
+label-ctrl-enter-sends = Verzenden met Ctrl+Enter (Enter voegt een nieuwe regel toe)
+label-message-format = Berichtopmaak:
+message-format-preset-default = Standaard
+message-format-preset-compact = Compact
+message-format-preset-irc = IRC-stijl
+message-format-preset-verbose = Uitgebreid (met datum)
+message-format-preset-custom = Aangepast
 label-show-connection-notifications = Verbindingsmeldingen weergeven
+label-hide-repeated-topic = Onderwerp bij opnieuw verbinden alleen tonen als het is gewijzigd
+label-alert-on-disconnect = Venster laten knipperen wanneer een verbinding wegvalt
+label-alert-on-mention = Venster laten knipperen wanneer iemand je noemt
+label-focus-new-messages = Overschakelen naar privéberichten die binnenkomen terwijl ik inactief ben
+label-quiet-hours = Stille uren (venster niet laten knipperen)
+label-quiet-hours-from = Van
+label-quiet-hours-to = tot
+label-watched-users-ignore-quiet-hours = Ook tijdens stille uren melden over gevolgde gebruikers
+label-auto-away = Markeer mij als afwezig bij inactiviteit
+label-auto-away-minutes = Minuten zonder invoer
+label-quiet-hours-active = Stille uren
 label-show-timestamps = Tijdstempels weergeven
 label-use-24-hour-time = 24-uursformaat gebruiken
 label-show-seconds = Seconden weergeven
+label-timezone = Tijdzone:
+timezone-local = Systeem lokaal
+label-privacy = Privacy
+label-trusted-certificates = Vertrouwde certificaten
+label-fingerprint-not-pinned = Nog geen certificaat vastgelegd
+label-forget-passwords = Wachtwoorden niet opslaan (vragen bij verbinden)
+label-sync-preferences = Thema en chatweergave synchroniseren met servers
+label-check-for-updates = Bij het opstarten naar een nieuwere client zoeken
+label-proxy = Proxy
+label-use-socks5-proxy = Verbinden via een SOCKS5-proxy (Tor, I2P)
+label-proxy-address = Adres:
+label-proxy-port = Poort:
+label-diagnostics = Diagnostiek
+label-connection-log-path = Verbindingslogbestand:
+placeholder-connection-log-path = Leeg laten om gebeurtenissen alleen in het geheugen te bewaren
 label-server-name = Naam:
 label-server-description = Beschrijving:
+label-pronouns = Voornaamwoorden:
+label-bio = Bio:
 label-server-version = Versie:
+label-server-features = Functies:
+label-users-online = Gebruikers online:
 label-chat-topic = Chat Onderwerp:
 label-chat-topic-set-by = Onderwerp Ingesteld Door:
 label-max-connections-per-ip = Max Verbindingen Per IP:
+label-traffic = Dataverkeer:
+server-info-traffic-value = { $sent } verzonden, { $received } ontvangen
 label-avatar = Avatar:
 label-details = Technische details
 label-chat-options = Chatopties
@@ -87,6 +158,14 @@ label-appearance = Uiterlijk
 label-image = Afbeelding
 label-general = Algemeen
 label-limits = Limieten
+label-broadcast-target = Versturen naar:
+broadcast-target-everyone = Iedereen
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = Chat
 
 # =============================================================================
 # Permission Display Names
@@ -98,6 +177,8 @@ permission-chat_send = Chat Verzenden
 permission-chat_receive = Chat Ontvangen
 permission-chat_topic = Chat Onderwerp
 permission-chat_topic_edit = Chat Onderwerp Bewerken
+permission-chat_pin = Chat Vastmaken
+permission-chat_moderate = Chat Modereren
 permission-user_broadcast = Gebruiker Broadcast
 permission-user_create = Gebruiker Aanmaken
 permission-user_delete = Gebruiker Verwijderen
@@ -116,17 +197,26 @@ tooltip-user-edit = Gebruiker bewerken
 tooltip-server-info = Server Info
 tooltip-about = Over
 tooltip-settings = Instellingen
+tooltip-quiet-hours-active = Stille uren actief: het venster knippert niet
 tooltip-hide-bookmarks = Bladwijzers verbergen
 tooltip-show-bookmarks = Bladwijzers tonen
 tooltip-hide-user-list = Gebruikerslijst verbergen
 tooltip-show-user-list = Gebruikerslijst tonen
 tooltip-disconnect = Verbinding verbreken
+tooltip-connection-health-unknown = Verbindingskwaliteit nog niet gemeten
+tooltip-connection-health-good = Verbinding goed ({ $rtt } ms)
+tooltip-connection-health-fair = Verbinding matig ({ $rtt } ms)
+tooltip-connection-health-poor = Verbinding slecht ({ $rtt } ms)
+tooltip-connection-health-no-response = Geen antwoord van de server
 tooltip-edit = Bewerken
 tooltip-info = Info
 tooltip-message = Bericht
 tooltip-kick = Verwijderen
 tooltip-add-bookmark = Bladwijzer Toevoegen
 tooltip-close = Sluiten
+tooltip-pop-out = In een venster openen
+tooltip-unpin = Losmaken
+tooltip-pinned-by = Vastgemaakt door { $username }
 
 # =============================================================================
 # Empty States
@@ -142,6 +232,7 @@ empty-no-users = Geen gebruikers online
 # =============================================================================
 
 chat-tab-server = #server
+chat-tab-server-count = #server ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -156,18 +247,47 @@ chat-prefix-system = [SYS]
 chat-prefix-error = [FOUT]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [AANKONDIGING]
+chat-prefix-pinned = [VAST]
+chat-message-edited = (bewerkt)
+chat-message-deleted = (verwijderd)
+chat-message-removed-by-moderator = (verwijderd door moderator)
+chat-show-more = Meer tonen
+chat-show-less = Minder tonen
+chat-message-encrypted = (versleuteld)
+chat-message-unreadable = (versleuteld bericht dat niet kon worden ontsleuteld)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = Gebruiker succesvol verwijderd
+msg-session-kicked-success = Sessie verbroken
+msg-confirm-clear-fingerprint = Vastgelegd certificaat voor { $name } wissen? De volgende verbinding vertrouwt elk certificaat dat de server aanbiedt.
 msg-broadcast-sent = Broadcast succesvol verzonden
 msg-user-created = Gebruiker succesvol aangemaakt
 msg-user-deleted = Gebruiker succesvol verwijderd
 msg-user-updated = Gebruiker succesvol bijgewerkt
 msg-permissions-updated = Je machtigingen zijn bijgewerkt
 msg-topic-updated = Onderwerp succesvol bijgewerkt
+msg-message-pinned = Bericht vastgemaakt
+msg-message-unpinned = Bericht losgemaakt
+msg-chat-history-purged = Chatgeschiedenis opgeschoond: { $count } berichten verwijderd
+msg-maintenance-enabled = De server is nu in onderhoudsmodus; wijzigingen zijn uitgeschakeld
+msg-maintenance-disabled = Onderhoudsmodus is voorbij; wijzigingen zijn weer mogelijk
+msg-new-login = Je account is zojuist ingelogd vanaf { $address } ({ $source })
+msg-seen-online = { $username } is nu online
+msg-seen-ago = { $username } is { $duration } geleden voor het laatst gezien ({ $time })
+msg-seen-never = { $username } is nog niet online geweest
+msg-maintenance-banner = Onderhoudsmodus: de server is voorlopig alleen-lezen
+msg-admin-notice-reconnect = Beheerdersmelding: maak opnieuw verbinding met de server
+msg-admin-notice-update = Beheerdersmelding: er is een clientupdate beschikbaar op { $url }
+msg-update-available = Nexus BBS { $version } is beschikbaar
+msg-admin-notice = Beheerdersmelding: { $message }
+msg-permissions-refreshed = Rechten vernieuwd
+msg-admin-transferred = { $username } is nu beheerder
+msg-copied-to-clipboard = Gekopieerd naar klembord
+msg-large-paste-confirm = Dit bericht bevat { $count } geplakte regels. Versturen?
 
 # =============================================================================
 # Dynamic Messages (with parameters)
@@ -177,9 +297,19 @@ msg-topic-cleared = Onderwerp gewist door { $username }
 msg-topic-set = Onderwerp ingesteld door { $username }: { $topic }
 msg-server-info-updated = Serverconfiguratie bijgewerkt
 msg-topic-display = Onderwerp: { $topic }
+msg-connected-via-fallback = Verbonden via uitwijkadres { $address }
+msg-certificate-change-trusted = Het servercertificaat is gewijzigd en geaccepteerd omdat deze bladwijzer certificaatwijzigingen vertrouwt (nieuwe vingerafdruk { $fingerprint })
 msg-user-connected = { $username } is verbonden
 msg-user-disconnected = { $username } is losgekoppeld
+msg-watched-user-online = { $username } is nu online
+msg-watched-users-online = Gevolgde gebruikers online: { $usernames }
 msg-disconnected = Verbinding verbroken: { $error }
+msg-enter-password-for = Voer het wachtwoord in voor { $name }
+connect-step-progress = Stap { $step } van { $total }: { $description }
+connect-step-tcp = Verbinden met server...
+connect-step-tls = Verbinding beveiligen...
+connect-step-handshake = Protocol onderhandelen...
+connect-step-login = Inloggen...
 msg-connection-cancelled = Verbinding geannuleerd vanwege niet-overeenkomend certificaat
 
 # =============================================================================
@@ -187,7 +317,9 @@ msg-connection-cancelled = Verbinding geannuleerd vanwege niet-overeenkomend cer
 # =============================================================================
 
 err-connection-broken = Verbindingsfout
+err-account-disabled-while-connected = Je account is uitgeschakeld door een beheerder. Neem contact met hem op om het weer in te schakelen voordat je opnieuw verbindt.
 err-failed-update-server-info = Kan serverinformatie niet bijwerken: { $error }
+err-failed-update-profile = Profiel bijwerken mislukt: { $error }
 err-user-kick-failed = Kan gebruiker niet verwijderen
 err-no-shutdown-handle = Verbindingsfout: Geen afsluithandle
 err-userlist-failed = Kan gebruikerslijst niet vernieuwen
@@ -205,6 +337,9 @@ err-could-not-determine-config-dir = Kan configuratiemap niet bepalen
 err-message-too-long = Bericht is te lang ({ $length } tekens, max { $max })
 err-send-failed = Kan bericht niet verzenden
 err-no-chat-permission = Je hebt geen toestemming om berichten te verzenden
+err-private-messages-disabled = Privéberichten zijn uitgeschakeld op deze server
+err-popout-command = Commando's kunnen alleen in het hoofdvenster worden gebruikt
+err-multiline-command = Commando's moeten op één regel passen (begin met // om dit als bericht te versturen)
 err-broadcast-too-long = Broadcast is te lang ({ $length } tekens, max { $max })
 err-broadcast-send-failed = Kan broadcast niet verzenden
 err-name-required = Bladwijzernaam is vereist
@@ -216,6 +351,7 @@ err-message-required = Bericht is vereist
 
 # Validation errors
 err-message-empty = Bericht mag niet leeg zijn
+err-slow-mode-wait = Langzame modus is ingeschakeld. Wacht { $seconds } seconden
 err-message-contains-newlines = Bericht mag geen regeleinden bevatten
 err-message-invalid-characters = Bericht bevat ongeldige tekens
 err-username-empty = Gebruikersnaam mag niet leeg zijn
@@ -233,6 +369,12 @@ err-server-name-invalid-characters = Servernaam bevat ongeldige tekens
 err-server-description-too-long = Beschrijving is te lang (max { $max } tekens)
 err-server-description-contains-newlines = Beschrijving mag geen regeleinden bevatten
 err-server-description-invalid-characters = Beschrijving bevat ongeldige tekens
+err-bio-too-long = Bio is te lang (max { $max } tekens)
+err-bio-contains-newlines = Bio mag geen regeleinden bevatten
+err-bio-invalid-characters = Bio bevat ongeldige tekens
+err-pronouns-too-long = Voornaamwoorden zijn te lang (max { $max } tekens)
+err-pronouns-contains-newlines = Voornaamwoorden mogen geen regeleinden bevatten
+err-pronouns-invalid-characters = Voornaamwoorden bevatten ongeldige tekens
 err-failed-send-update = Kan update niet verzenden: { $error }
 
 # =============================================================================
@@ -240,25 +382,68 @@ err-failed-send-update = Kan update niet verzenden: { $error }
 # =============================================================================
 
 err-failed-save-config = Kan configuratie niet opslaan: { $error }
+err-connection-log-write = Kan verbindingslogboek { $path } niet schrijven: { $error }
 err-failed-save-settings = Kan instellingen niet opslaan: { $error }
 err-invalid-port-bookmark = Ongeldige poort in bladwijzer: { $name }
+err-fallback-address-invalid = Ongeldig uitwijkadres: { $address }
 err-failed-send-broadcast = Kan broadcast niet verzenden: { $error }
 err-failed-send-message = Kan bericht niet verzenden: { $error }
+err-command-unsupported = De server ondersteunt /{ $command } niet
 err-failed-create-user = Kan gebruiker niet aanmaken: { $error }
 err-failed-delete-user = Kan gebruiker niet verwijderen: { $error }
 err-failed-update-user = Kan gebruiker niet bijwerken: { $error }
 err-failed-update-topic = Kan onderwerp niet bijwerken: { $error }
+err-failed-pin-message = Bericht vastmaken mislukt: { $error }
+err-failed-unpin-message = Bericht losmaken mislukt: { $error }
+err-failed-edit-message = Bericht bewerken mislukt: { $error }
+err-failed-delete-message = Bericht verwijderen mislukt: { $error }
+err-failed-purge-history = Chatgeschiedenis opschonen mislukt: { $error }
+err-failed-announcement = Aankondigingen bijwerken mislukt: { $error }
+err-failed-stats = Verbindingsstatistieken ophalen mislukt: { $error }
+err-failed-history = Oudere berichten laden mislukt: { $error }
+err-art-read = { $path } kon niet worden gelezen: { $error }
+err-art-file-too-large = Art-bestanden mogen hoogstens { $max } KiB zijn
+err-art-empty = Het art-bestand bevat niets om te versturen
+err-art-fence = Art mag geen ``` bevatten (dat zou het blok beëindigen)
+err-art-first-line = Zelfs de eerste regel past niet binnen de serverlimiet van { $max } tekens
+err-failed-page = Paginaverzoek mislukt: { $error }
+err-failed-set-maintenance = Kan onderhoudsmodus niet wijzigen: { $error }
+err-failed-send-admin-notice = Melding verzenden mislukt: { $error }
+err-notice-url-invalid = De link moet beginnen met http:// of https:// en mag geen spaties bevatten
+err-failed-refresh-permissions = Rechten vernieuwen mislukt: { $error }
+err-failed-transfer-admin = Beheerdersrechten overdragen mislukt: { $error }
+err-failed-sync-preferences = Voorkeuren synchroniseren mislukt: { $error }
+err-failed-accept-rules = Regels accepteren mislukt: { $error }
 err-message-too-long-details = { $error } ({ $length } tekens, max { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = Ongeldig adres '{ $address }': { $error }
+err-all-addresses-failed = Geen van de { $count } adressen bereikbaar (laatste fout: { $error })
 err-could-not-resolve = Kan adres '{ $address }' niet oplossen
 err-connection-timeout = Verbinding verlopen na { $seconds } seconden
 err-connection-failed = Verbinding mislukt: { $error }
+err-proxy-address-empty = Proxyadres is vereist
+err-proxy-address-invalid = Proxyadres moet een hostnaam of IP-adres zonder spaties zijn
+err-proxy-port-invalid = Proxypoort moet tussen 1 en 65535 liggen
+err-quiet-hours-start-invalid = Begin van stille uren moet een 24-uurs tijd zijn, zoals 22:00
+err-quiet-hours-end-invalid = Einde van stille uren moet een 24-uurs tijd zijn, zoals 08:00
+err-message-format-unclosed-brace = Berichtopmaak bevat een niet-gesloten plaatshouder (verdubbel de accolade om hem letterlijk te tonen)
+err-message-format-unknown-placeholder = Berichtopmaak bevat een onbekende plaatshouder { $placeholder } (beschikbaar: { $available })
+err-message-format-missing-placeholder = Berichtopmaak moet { $placeholder } bevatten
+err-message-format-duplicate-placeholder = Berichtopmaak mag { $placeholder } maar één keer bevatten
+err-invite-not-a-link = Geen nexus://-uitnodigingslink
+err-invite-invalid-address = Uitnodigingslink bevat een ongeldig serveradres
+err-invite-invalid-port = Poort in de uitnodigingslink moet tussen 1 en 65535 liggen
+err-invite-invalid-name = Uitnodigingslink bevat een ongeldige servernaam
+err-invite-invalid-fingerprint = Uitnodigingslink bevat een ongeldige certificaatvingerafdruk
+err-invite-credentials = Uitnodigingslinks mogen geen gebruikersnaam of wachtwoord bevatten
+err-invite-fingerprint-mismatch = Servercertificaat komt niet overeen met de uitnodigingslink (verwacht { $expected }, ontvangen { $received })
+err-proxy-connection-failed = Kon niet verbinden via proxy { $proxy }: { $error }
 err-tls-handshake-failed = TLS-handshake mislukt: { $error }
 err-failed-send-handshake = Kan handshake niet verzenden: { $error }
 err-failed-read-handshake = Kan handshake-respons niet lezen: { $error }
 err-handshake-failed = Handshake mislukt: { $error }
+err-client-too-old = Deze server vereist een nieuwere Nexus-client. Werk bij om verbinding te maken. ({ $error })
 err-failed-parse-handshake = Kan handshake-respons niet verwerken: { $error }
 err-failed-send-login = Kan aanmelding niet verzenden: { $error }
 err-failed-read-login = Kan aanmeldrespons niet lezen: { $error }
@@ -275,6 +460,7 @@ err-failed-set-config-permissions = Kan machtigingen van configuratiebestand nie
 # =============================================================================
 
 fingerprint-warning = Dit kan wijzen op een beveiligingsprobleem (MITM-aanval) of het servercertificaat is opnieuw gegenereerd. Accepteer alleen als je de serverbeheerder vertrouwt.
+server-rules-intro = Je moet deze regels accepteren voordat je op deze server kunt chatten.
 
 # =============================================================================
 # User Info Display
@@ -284,15 +470,24 @@ user-info-username = Gebruikersnaam:
 user-info-role = Rol:
 user-info-role-admin = admin
 user-info-role-user = gebruiker
+user-info-role-guest = gast
+user-info-pronouns = Voornaamwoorden:
+user-info-bio = Bio:
 user-info-connected = Verbonden:
 user-info-connected-value = { $duration } geleden
 user-info-connected-value-sessions = { $duration } geleden ({ $count } sessies)
 user-info-features = Functies:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (inactief)
 user-info-features-none = Geen
+user-info-permissions = Rechten:
+user-info-permissions-none = Geen
 user-info-locale = Taal:
 user-info-address = Adres:
 user-info-addresses = Adressen:
+user-info-sessions = Sessies:
+user-info-session = Sessie { $id }
+user-info-this-session = deze sessie
 user-info-created = Aangemaakt:
 user-info-end = Einde gebruikersinformatie
 user-info-unknown = Onbekend
@@ -329,18 +524,60 @@ cmd-help-desc = Beschikbare commando's weergeven
 cmd-help-escape-hint = Tip: Gebruik // om een bericht te sturen dat begint met /
 cmd-message-desc = Stuur een bericht naar een gebruiker
 cmd-message-usage = Gebruik: /{ $command } <gebruikersnaam> <bericht>
+cmd-query-desc = Een berichttabblad met een gebruiker openen zonder te versturen
+cmd-query-usage = Gebruik: /{ $command } <gebruikersnaam>
+cmd-query-not-online = Gebruiker is niet online: { $username }
+cmd-query-self = Je kunt jezelf geen bericht sturen
+cmd-reconnect-desc = Verbinding met deze server verbreken en opnieuw verbinden
+cmd-reconnect-usage = Gebruik: /{ $command }
+cmd-reconnect-no-bookmark = /{ $command } heeft een bladwijzer nodig om opnieuw te verbinden; deze verbinding is niet via een bladwijzer gemaakt
 cmd-userinfo-desc = Toon informatie over een gebruiker
 cmd-userinfo-usage = Gebruik: /{ $command } <gebruikersnaam>
+cmd-invite-desc = Uitnodigingslink voor deze server kopiëren
+cmd-invite-usage = Gebruik: /{ $command }
+cmd-invite-copied = Uitnodigingslink naar klembord gekopieerd: { $link }
 cmd-kick-desc = Verwijder een gebruiker van de server
 cmd-kick-usage = Gebruik: /{ $command } <gebruikersnaam>
+cmd-limits-desc = Lengtelimieten voor berichten en onderwerp bekijken of instellen
+cmd-limits-usage = Gebruik: /{ $command } [bericht <tekens>|onderwerp <tekens>]
+cmd-limits-arg-message = bericht
+cmd-limits-arg-topic = onderwerp
+cmd-limits-current = Limieten: berichten tot { $message } tekens, onderwerp tot { $topic } tekens
 cmd-topic-desc = Bekijk of beheer het chatonderwerp
 cmd-topic-usage = Gebruik: /{ $command } [set|clear] [onderwerp]
 cmd-topic-set-usage = Gebruik: /{ $command } set <onderwerp>
 cmd-topic-none = Er is geen onderwerp ingesteld
+cmd-announce-desc = Roterende aankondigingen van de server bekijken of beheren
+cmd-announce-usage = Gebruik: /{ $command } [toevoegen <bericht>|verwijderen <nummer>]
+cmd-announce-arg-add = toevoegen
+cmd-announce-arg-remove = verwijderen
+cmd-announce-list = Aankondigingen (elke { $minutes } minuten):
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = Er zijn geen aankondigingen ingesteld
+cmd-announce-added = Aankondiging toegevoegd
+cmd-announce-removed = Aankondiging verwijderd
+cmd-art-desc = ASCII- of ANSI-art uit een bestand als blok met vaste breedte versturen
+cmd-art-usage = Gebruik: /{ $command } [inkorten] <bestand>
+cmd-art-arg-truncate = inkorten
+cmd-art-too-long = De art is { $length } tekens, meer dan de serverlimiet van { $max }. Gebruik /{ $command } { $truncate } <bestand> om de regels te versturen die passen
 cmd-broadcast-desc = Stuur een broadcast naar alle gebruikers
 cmd-broadcast-usage = Gebruik: /{ $command } <bericht>
 cmd-clear-desc = Chatgeschiedenis van huidige tab wissen
 cmd-clear-usage = Gebruik: /{ $command }
+cmd-delete-desc = Je laatste bericht verwijderen (moderators: het laatste bericht van een gebruiker)
+cmd-delete-usage = Gebruik: /{ $command } [gebruikersnaam]
+cmd-delete-no-message = Geen recent bericht van { $username } om te verwijderen
+cmd-delete-permission-denied = Je hebt geen toestemming om berichten van andere gebruikers te verwijderen
+cmd-edit-desc = Je laatste bericht bewerken
+cmd-edit-usage = Gebruik: /{ $command } <bericht>
+cmd-edit-no-message = Je hebt geen recent bericht om te bewerken
+cmd-events-desc = Levenscyclusgebeurtenissen van deze verbinding tonen
+cmd-events-usage = Gebruik: /{ $command }
+cmd-events-header = Verbindingsgebeurtenissen (oudste eerst):
+cmd-events-empty = Nog geen verbindingsgebeurtenissen vastgelegd
+cmd-report-desc = Een opgeschoond overzicht van client en verbinding kopiëren voor bugrapporten
+cmd-report-usage = Gebruik: /{ $command }
+cmd-report-copied = Bugrapport naar het klembord gekopieerd. Weggelaten: wachtwoorden, serveradres, gebruikersnamen, certificaatvingerafdruk, servernaam en proxyadres
 cmd-focus-desc = Focus op serverchat of berichtenvenster van een gebruiker
 cmd-focus-usage = Gebruik: /{ $command } [gebruikersnaam]
 cmd-focus-not-found = Gebruiker niet gevonden: { $name }
@@ -352,30 +589,98 @@ cmd-list-output = Gebruikers online: { $users } ({ $count } { $count ->
     [one] gebruiker
    *[other] gebruikers
 })
+cmd-maintenance-desc = Onderhoudsmodus (alleen-lezen) bekijken of omschakelen
+cmd-maintenance-usage = Gebruik: /{ $command } [aan|uit]
+cmd-maintenance-arg-on = aan
+cmd-maintenance-arg-off = uit
+cmd-maintenance-on = Onderhoudsmodus staat aan
+cmd-maintenance-off = Onderhoudsmodus staat uit
+cmd-notice-desc = Iedereen een melding als banner tonen
+cmd-notice-usage = Gebruik: /{ $command } reconnect | update <url> | <bericht>
+cmd-notify-desc = Gebruikers tonen of beheren over wie je een melding krijgt als ze online komen
+cmd-notify-usage = Gebruik: /{ $command } [toevoegen <gebruikersnaam>|verwijderen <gebruikersnaam>]
+cmd-notify-arg-add = toevoegen
+cmd-notify-arg-del = verwijderen
+cmd-notify-no-bookmark = De volglijst wordt in een bladwijzer opgeslagen, dus /notify werkt alleen op servers met een bladwijzer
+cmd-notify-none = Je volgt niemand op deze server
+cmd-notify-list = Gevolgd: { $usernames }
+cmd-notify-added = Je krijgt een melding wanneer { $username } online komt
+cmd-notify-already = { $username } staat al op je volglijst
+cmd-notify-removed = { $username } wordt niet meer gevolgd
+cmd-notify-not-watched = { $username } staat niet op je volglijst
 cmd-list-all-no-permission = Je hebt user_edit of user_delete toestemming nodig om alle gebruikers te bekijken
 cmd-list-all-output = Gebruikers: { $users } ({ $count } { $count ->
     [one] gebruiker
    *[other] gebruikers
 })
+cmd-whoami-desc = Toon je gebruikersnaam, rol, rechten en functies
+cmd-whoami-usage = Gebruik: /{ $command }
 cmd-help-usage = Gebruik: /{ $command } [commando]
 cmd-topic-arg-set = instellen
 cmd-topic-arg-clear = wissen
 cmd-topic-permission-denied = Je hebt geen toestemming om het onderwerp te bewerken
+cmd-page-desc = Infopagina's van de server weergeven of lezen (beheerders: instellen of verwijderen)
+cmd-page-usage = Gebruik: /{ $command } [<naam>|instellen <naam> <tekst>|verwijderen <naam>] (\n begint een nieuwe regel)
+cmd-page-arg-set = instellen
+cmd-page-arg-delete = verwijderen
+cmd-page-list = Pagina's: { $pages }
+cmd-page-none = Deze server heeft geen pagina's
+cmd-page-saved = Pagina opgeslagen
+cmd-page-deleted = Pagina verwijderd
+cmd-pin-desc = Vastgemaakte berichten tonen of het laatste bericht van een gebruiker vastmaken
+cmd-pin-usage = Gebruik: /{ $command } [gebruikersnaam]
+cmd-pin-list = Vastgemaakte berichten:
+cmd-pin-list-item = { $number }. { $username }: { $message } (vastgemaakt door { $pinned_by })
+cmd-pin-none = Er zijn geen berichten vastgemaakt
+cmd-pin-no-message = Geen recent bericht van { $username } om vast te maken
+cmd-purge-desc = Chatgeschiedenis buiten het bewaarbeleid van de server opschonen
+cmd-purge-usage = Gebruik: /{ $command }
+cmd-refresh-desc = Rechten van een gebruiker opnieuw verzenden vanuit de serverdatabase
+cmd-refresh-usage = Gebruik: /{ $command } <gebruikersnaam>
+cmd-transfer-desc = Maak een ander account beheerder en treed zelf af, tenzij je behouden toevoegt
+cmd-transfer-usage = Gebruik: /{ $command } <gebruikersnaam> [behouden]
+cmd-transfer-arg-keep = behouden
+cmd-seen-desc = Toon wanneer een gebruiker voor het laatst online was
+cmd-seen-usage = Gebruik: /{ $command } <gebruikersnaam>
+cmd-session-desc = Sessie en dataverkeer van deze verbinding tonen
+cmd-session-usage = Gebruik: /{ $command }
+cmd-session-info = Sessie { $id } verbonden met { $address }
+cmd-session-traffic = Dataverkeer sinds verbinden: { $sent } verzonden, { $received } ontvangen
+cmd-unpin-desc = Een vastgemaakt bericht losmaken
+cmd-unpin-usage = Gebruik: /{ $command } <nummer> (zie /pin voor nummers)
 cmd-window-desc = Beheer chat-tabbladen
-cmd-window-usage = Gebruik: /{ $command } [volgende|vorige|sluiten [gebruikersnaam]]
+cmd-window-usage = Gebruik: /{ $command } [volgende|vorige|sluiten [gebruikersnaam]|losmaken [gebruikersnaam]]
 cmd-window-arg-next = volgende
 cmd-window-arg-prev = vorige
 cmd-window-arg-close = sluiten
+cmd-window-arg-popout = losmaken
 cmd-window-list = Open tabbladen: { $tabs } ({ $count } { $count ->
     [one] tabblad
    *[other] tabbladen
 })
 cmd-window-close-server = Kan het server-tabblad niet sluiten
+cmd-window-popout-server = Kan het server-tabblad niet losmaken
 cmd-window-not-found = Tabblad niet gevonden: { $name }
 cmd-serverinfo-desc = Serverinformatie weergeven
 cmd-serverinfo-usage = Gebruik: /{ $command }
 cmd-serverinfo-header = [server]
 cmd-serverinfo-end = Einde serverinformatie
+cmd-slow-desc = Langzame chatmodus bekijken of instellen
+cmd-slow-usage = Gebruik: /{ $command } [seconden]
+cmd-slow-off = Langzame modus staat uit
+cmd-slow-current = Langzame modus: { $seconds } seconden tussen berichten
+cmd-stats-desc = Verbindingsgeschiedenis van de server van het afgelopen uur tonen
+cmd-stats-usage = Gebruik: /{ $command } [resetten]
+cmd-stats-arg-reset = resetten
+cmd-stats-current = Nu open verbindingen: { $count }
+cmd-stats-total = Afgelopen { $minutes } minuten: { $connections } verbindingen, { $refused } geweigerd, piek { $peak } open ({ $peak_per_ip } van één adres)
+cmd-stats-group = { $time }: { $connections } verbindingen, { $refused } geweigerd, piek { $peak }
+cmd-stats-none = Nog geen verbindingsgeschiedenis vastgelegd
+cmd-set-desc = Chatvoorkeuren voor deze server bekijken of wijzigen
+cmd-set-usage = Gebruik: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = Berichten over binnenkomen/vertrekken worden op deze server getoond
+cmd-set-joinleave-off = Berichten over binnenkomen/vertrekken zijn op deze server verborgen
+cmd-set-joinleave-default = Berichten over binnenkomen/vertrekken volgen op deze server de algemene instelling
 
 # =============================================================================
 # About Panel
@@ -385,6 +690,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = Afbeelding kiezen
 button-clear-image = Wissen
+button-clear-theme = Wissen
+button-copy-fingerprint = Kopiëren
+button-clear-fingerprint = Wissen
+button-dismiss = Sluiten
+button-reconnect = Opnieuw verbinden
+button-open-link = Link openen
 label-server-image = Serverafbeelding:
 err-server-image-too-large = De serverafbeelding is te groot (maximaal 512KB)
 err-server-image-invalid-format = Ongeldig serverafbeeldingsformaat (moet een data-URI met base64-codering zijn)
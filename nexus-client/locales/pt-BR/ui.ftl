@@ -5,14 +5,20 @@
 # =============================================================================
 
 button-cancel = Cancelar
+button-continue = Continuar
 button-send = Enviar
+button-send-as-one-message = Enviar como uma só mensagem
 button-delete = Excluir
 button-connect = Conectar
 button-save = Salvar
 button-create = Criar
 button-edit = Editar
+button-edit-profile = Editar perfil
 button-update = Atualizar
 button-accept-new-certificate = Aceitar Novo Certificado
+button-always-accept-certificate = Sempre Aceitar
+button-accept-rules = Aceitar Regras
+button-disconnect = Desconectar
 button-close = Fechar
 button-choose-avatar = Escolher Ícone
 button-clear-avatar = Limpar
@@ -22,6 +28,8 @@ button-clear-avatar = Limpar
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = Conectar ao Servidor
 title-add-bookmark = Adicionar Favorito
 title-edit-server = Editar Servidor
@@ -34,7 +42,10 @@ title-settings = Configurações
 title-bookmarks = Favoritos
 title-users = Usuários
 title-edit-server-info = Editar Info do Servidor
+title-edit-profile = Editar perfil
 title-fingerprint-mismatch = Impressão Digital do Certificado Não Corresponde!
+title-server-rules = Regras do Servidor
+title-server-banner = Aviso do servidor
 title-server-info = Info do Servidor
 title-user-info = Info do Usuário
 title-about = Sobre
@@ -46,39 +57,99 @@ title-about = Sobre
 placeholder-username = Nome de usuário
 placeholder-password = Senha
 placeholder-port = Porta
+placeholder-fallback-addresses = Endereços alternativos (opcional, host:porta separados por vírgula)
+placeholder-watched-users = Avisar quando ficar online (opcional, nomes de usuário separados por vírgula)
 placeholder-server-address = Endereço do Servidor
 placeholder-server-name = Nome do Servidor
 placeholder-username-optional = Nome de usuário (opcional)
 placeholder-password-optional = Senha (opcional)
 placeholder-password-keep-current = Senha (deixe vazio para manter a atual)
 placeholder-message = Digite uma mensagem...
+placeholder-message-slow-mode = Modo lento: aguarde { $seconds }s...
+placeholder-message-maintenance = Modo de manutenção: entrada desativada
 placeholder-no-permission = Sem permissão
 placeholder-broadcast-message = Digite a mensagem de difusão...
 placeholder-server-description = Descrição do servidor
+placeholder-pronouns = ex.: elu/delu
+placeholder-bio = Uma linha curta sobre você
+placeholder-global-theme = Usar tema global
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = Auto-Conectar
+label-trust-certificate-changes = Sempre aceitar mudanças de certificado deste servidor (menos seguro)
 label-add-bookmark = Favorito
+label-invite-fingerprint = Impressão digital do certificado do link de convite: { $fingerprint }
 label-admin = Admin
 label-enabled = Habilitado
 label-permissions = Permissões:
 label-expected-fingerprint = Impressão digital esperada:
 label-received-fingerprint = Impressão digital recebida:
 label-theme = Tema
+label-density = Densidade:
+density-comfortable = Confortável
+density-compact = Compacta
+label-show-role-badges = Mostrar selos de administrador e moderador na lista de usuários
+badge-admin = admin
+badge-moderator = mod
+badge-guest = convidado
+user-list-permissions = Permissões: { $permissions }
+user-list-away = Ausente
 label-chat-font-size = Tamanho da fonte:
+label-max-message-tabs = Máx. de abas de mensagens:
+label-paste-confirm-lines = Confirmar colagens de pelo menos estas linhas (0 = nunca):
+label-collapse-message-lines = Recolher mensagens com mais destas linhas (0 = nunca):
+label-ctrl-enter-sends = Enviar com Ctrl+Enter (Enter adiciona uma nova linha)
+label-message-format = Formato das mensagens:
+message-format-preset-default = Padrão
+message-format-preset-compact = Compacto
+message-format-preset-irc = Estilo IRC
+message-format-preset-verbose = Detalhado (com data)
+message-format-preset-custom = Personalizado
 label-show-connection-notifications = Mostrar notificações de conexão
+label-hide-repeated-topic = Mostrar o tópico ao reconectar só se ele mudou
+label-alert-on-disconnect = Piscar a janela quando uma conexão cair
+label-alert-on-mention = Piscar a janela quando alguém mencionar você
+label-focus-new-messages = Mudar para mensagens privadas que chegarem enquanto estou inativo
+label-quiet-hours = Horário silencioso (não piscar a janela)
+label-quiet-hours-from = De
+label-quiet-hours-to = até
+label-watched-users-ignore-quiet-hours = Avisar sobre usuários observados durante o horário silencioso
+label-auto-away = Marcar-me como ausente quando inativo
+label-auto-away-minutes = Minutos sem atividade
+label-quiet-hours-active = Horário silencioso
 label-show-timestamps = Mostrar horários
 label-use-24-hour-time = Usar formato de 24 horas
 label-show-seconds = Mostrar segundos
+label-timezone = Fuso horário:
+timezone-local = Local do sistema
+label-privacy = Privacidade
+label-trusted-certificates = Certificados confiáveis
+label-fingerprint-not-pinned = Nenhum certificado fixado ainda
+label-forget-passwords = Não salvar senhas (perguntar ao conectar)
+label-sync-preferences = Sincronizar tema e exibição do chat com os servidores
+label-check-for-updates = Procurar um cliente mais recente ao iniciar
+label-proxy = Proxy
+label-use-socks5-proxy = Conectar por meio de um proxy SOCKS5 (Tor, I2P)
+label-proxy-address = Endereço:
+label-proxy-port = Porta:
+label-diagnostics = Diagnóstico
+label-connection-log-path = Arquivo de log de conexão:
+placeholder-connection-log-path = Deixe vazio para manter os eventos apenas na memória
 label-server-name = Nome:
 label-server-description = Descrição:
+label-pronouns = Pronomes:
+label-bio = Bio:
 label-server-version = Versão:
+label-server-features = Recursos:
+label-users-online = Usuários online:
 label-chat-topic = Tópico do Chat:
 label-chat-topic-set-by = Tópico Definido Por:
 label-max-connections-per-ip = Máx. Conexões Por IP:
+label-traffic = Tráfego:
+server-info-traffic-value = { $sent } enviados, { $received } recebidos
 label-avatar = Ícone:
 label-details = Detalhes técnicos
 label-chat-options = Opções de chat
@@ -86,6 +157,14 @@ label-appearance = Aparência
 label-image = Imagem
 label-general = Geral
 label-limits = Limites
+label-broadcast-target = Enviar para:
+broadcast-target-everyone = Todos
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = Chat
 
 # =============================================================================
 # Permission Display Names
@@ -97,6 +176,8 @@ permission-chat_send = Enviar Chat
 permission-chat_receive = Receber Chat
 permission-chat_topic = Tópico do Chat
 permission-chat_topic_edit = Editar Tópico do Chat
+permission-chat_pin = Fixar no Chat
+permission-chat_moderate = Moderar Chat
 permission-user_broadcast = Difusão de Usuário
 permission-user_create = Criar Usuário
 permission-user_delete = Excluir Usuário
@@ -115,16 +196,25 @@ tooltip-user-edit = Editar Usuário
 tooltip-server-info = Info do Servidor
 tooltip-about = Sobre
 tooltip-settings = Configurações
+tooltip-quiet-hours-active = Horário silencioso ativo: a janela não vai piscar
 tooltip-hide-bookmarks = Ocultar Favoritos
 tooltip-show-bookmarks = Mostrar Favoritos
 tooltip-hide-user-list = Ocultar Lista de Usuários
 tooltip-show-user-list = Mostrar Lista de Usuários
 tooltip-disconnect = Desconectar
+tooltip-connection-health-unknown = Qualidade da conexão ainda não medida
+tooltip-connection-health-good = Conexão boa ({ $rtt } ms)
+tooltip-connection-health-fair = Conexão razoável ({ $rtt } ms)
+tooltip-connection-health-poor = Conexão ruim ({ $rtt } ms)
+tooltip-connection-health-no-response = Sem resposta do servidor
 tooltip-edit = Editar
 tooltip-info = Info
 tooltip-message = Mensagem
 tooltip-kick = Expulsar
 tooltip-close = Fechar
+tooltip-pop-out = Abrir em uma janela
+tooltip-unpin = Desafixar
+tooltip-pinned-by = Fixado por { $username }
 tooltip-add-bookmark = Adicionar Favorito
 
 # =============================================================================
@@ -141,6 +231,7 @@ empty-no-users = Nenhum usuário online
 # =============================================================================
 
 chat-tab-server = #servidor
+chat-tab-server-count = #servidor ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -155,18 +246,47 @@ chat-prefix-system = [SIS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [ANÚNCIO]
+chat-prefix-pinned = [FIXADO]
+chat-message-edited = (editado)
+chat-message-deleted = (excluído)
+chat-message-removed-by-moderator = (removido por um moderador)
+chat-show-more = Mostrar mais
+chat-show-less = Mostrar menos
+chat-message-encrypted = (criptografada)
+chat-message-unreadable = (mensagem criptografada que não pôde ser descriptografada)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = Usuário expulso com sucesso
+msg-session-kicked-success = Sessão desconectada
+msg-confirm-clear-fingerprint = Limpar o certificado fixado de { $name }? A próxima conexão confiará em qualquer certificado que o servidor apresentar.
 msg-broadcast-sent = Difusão enviada com sucesso
 msg-user-created = Usuário criado com sucesso
 msg-user-deleted = Usuário excluído com sucesso
 msg-user-updated = Usuário atualizado com sucesso
 msg-permissions-updated = Suas permissões foram atualizadas
 msg-topic-updated = Tópico atualizado com sucesso
+msg-message-pinned = Mensagem fixada
+msg-message-unpinned = Mensagem desafixada
+msg-chat-history-purged = Histórico do chat limpo: { $count } mensagens removidas
+msg-maintenance-enabled = O servidor está agora em modo de manutenção; alterações estão desativadas
+msg-maintenance-disabled = O modo de manutenção terminou; alterações estão ativadas novamente
+msg-new-login = Sua conta acabou de entrar a partir de { $address } ({ $source })
+msg-seen-online = { $username } está online agora
+msg-seen-ago = { $username } foi visto pela última vez há { $duration } ({ $time })
+msg-seen-never = { $username } ainda não esteve online
+msg-maintenance-banner = Modo de manutenção: o servidor está somente leitura por enquanto
+msg-admin-notice-reconnect = Aviso do administrador: reconecte-se ao servidor
+msg-admin-notice-update = Aviso do administrador: há uma atualização do cliente em { $url }
+msg-update-available = Nexus BBS { $version } está disponível
+msg-admin-notice = Aviso do administrador: { $message }
+msg-permissions-refreshed = Permissões atualizadas
+msg-admin-transferred = { $username } agora é administrador
+msg-copied-to-clipboard = Copiado para a área de transferência
+msg-large-paste-confirm = Esta mensagem contém { $count } linhas coladas. Enviar?
 
 # =============================================================================
 # Dynamic Messages (with parameters)
@@ -176,9 +296,19 @@ msg-topic-cleared = Tópico limpo por { $username }
 msg-topic-set = Tópico definido por { $username }: { $topic }
 msg-server-info-updated = Configuração do servidor atualizada
 msg-topic-display = Tópico: { $topic }
+msg-connected-via-fallback = Conectado pelo endereço alternativo { $address }
+msg-certificate-change-trusted = O certificado do servidor mudou e foi aceito porque este favorito confia em mudanças de certificado (nova impressão digital { $fingerprint })
 msg-user-connected = { $username } conectou
 msg-user-disconnected = { $username } desconectou
+msg-watched-user-online = { $username } está online agora
+msg-watched-users-online = Usuários observados online: { $usernames }
 msg-disconnected = Desconectado: { $error }
+msg-enter-password-for = Digite a senha para { $name }
+connect-step-progress = Etapa { $step } de { $total }: { $description }
+connect-step-tcp = Conectando ao servidor...
+connect-step-tls = Protegendo a conexão...
+connect-step-handshake = Negociando o protocolo...
+connect-step-login = Entrando...
 msg-connection-cancelled = Conexão cancelada devido a certificado não correspondente
 
 # =============================================================================
@@ -186,7 +316,9 @@ msg-connection-cancelled = Conexão cancelada devido a certificado não correspo
 # =============================================================================
 
 err-connection-broken = Erro de conexão
+err-account-disabled-while-connected = Sua conta foi desativada por um administrador. Entre em contato com ele para reativá-la antes de se reconectar.
 err-failed-update-server-info = Falha ao atualizar informações do servidor: { $error }
+err-failed-update-profile = Falha ao atualizar o perfil: { $error }
 err-user-kick-failed = Falha ao expulsar usuário
 err-no-shutdown-handle = Erro de conexão: Sem handle de desligamento
 err-userlist-failed = Falha ao atualizar lista de usuários
@@ -204,6 +336,9 @@ err-could-not-determine-config-dir = Não foi possível determinar o diretório
 err-message-too-long = A mensagem é muito longa ({ $length } caracteres, máx { $max })
 err-send-failed = Falha ao enviar mensagem
 err-no-chat-permission = Você não tem permissão para enviar mensagens
+err-private-messages-disabled = Mensagens privadas estão desativadas neste servidor
+err-popout-command = Comandos só podem ser usados na janela principal
+err-multiline-command = Comandos devem caber em uma linha (comece com // para enviar isto como mensagem)
 err-broadcast-too-long = A difusão é muito longa ({ $length } caracteres, máx { $max })
 err-broadcast-send-failed = Falha ao enviar difusão
 err-name-required = O nome do favorito é obrigatório
@@ -215,6 +350,7 @@ err-message-required = A mensagem é obrigatória
 
 # Validation errors
 err-message-empty = A mensagem não pode estar vazia
+err-slow-mode-wait = O modo lento está ativado. Aguarde { $seconds } segundos
 err-message-contains-newlines = A mensagem não pode conter quebras de linha
 err-message-invalid-characters = A mensagem contém caracteres inválidos
 err-username-empty = O nome de usuário não pode estar vazio
@@ -232,6 +368,12 @@ err-server-name-invalid-characters = O nome do servidor contém caracteres invá
 err-server-description-too-long = A descrição é muito longa (máx { $max } caracteres)
 err-server-description-contains-newlines = A descrição não pode conter quebras de linha
 err-server-description-invalid-characters = A descrição contém caracteres inválidos
+err-bio-too-long = A bio é muito longa (máx. { $max } caracteres)
+err-bio-contains-newlines = A bio não pode conter quebras de linha
+err-bio-invalid-characters = A bio contém caracteres inválidos
+err-pronouns-too-long = Os pronomes são muito longos (máx. { $max } caracteres)
+err-pronouns-contains-newlines = Os pronomes não podem conter quebras de linha
+err-pronouns-invalid-characters = Os pronomes contêm caracteres inválidos
 err-failed-send-update = Falha ao enviar atualização: { $error }
 
 # =============================================================================
@@ -239,25 +381,68 @@ err-failed-send-update = Falha ao enviar atualização: { $error }
 # =============================================================================
 
 err-failed-save-config = Falha ao salvar configuração: { $error }
+err-connection-log-write = Falha ao gravar o log de conexão { $path }: { $error }
 err-failed-save-settings = Falha ao salvar configurações: { $error }
 err-invalid-port-bookmark = Porta inválida no favorito: { $name }
+err-fallback-address-invalid = Endereço alternativo inválido: { $address }
 err-failed-send-broadcast = Falha ao enviar difusão: { $error }
 err-failed-send-message = Falha ao enviar mensagem: { $error }
+err-command-unsupported = O servidor não suporta /{ $command }
 err-failed-create-user = Falha ao criar usuário: { $error }
 err-failed-delete-user = Falha ao excluir usuário: { $error }
 err-failed-update-user = Falha ao atualizar usuário: { $error }
 err-failed-update-topic = Falha ao atualizar tópico: { $error }
+err-failed-pin-message = Falha ao fixar mensagem: { $error }
+err-failed-unpin-message = Falha ao desafixar mensagem: { $error }
+err-failed-edit-message = Falha ao editar mensagem: { $error }
+err-failed-delete-message = Falha ao excluir mensagem: { $error }
+err-failed-purge-history = Falha ao limpar o histórico do chat: { $error }
+err-failed-announcement = Falha ao atualizar os anúncios: { $error }
+err-failed-stats = Falha ao obter estatísticas de conexão: { $error }
+err-failed-history = Falha ao carregar mensagens anteriores: { $error }
+err-art-read = Não foi possível ler { $path }: { $error }
+err-art-file-too-large = Arquivos de arte podem ter no máximo { $max } KiB
+err-art-empty = O arquivo de arte não tem nada para enviar
+err-art-fence = A arte não pode conter ``` (isso encerraria o bloco)
+err-art-first-line = Nem a primeira linha cabe no limite do servidor de { $max } caracteres
+err-failed-page = Falha na solicitação da página: { $error }
+err-failed-set-maintenance = Falha ao alterar o modo de manutenção: { $error }
+err-failed-send-admin-notice = Falha ao enviar o aviso: { $error }
+err-notice-url-invalid = O link deve começar com http:// ou https:// e não conter espaços
+err-failed-refresh-permissions = Falha ao atualizar as permissões: { $error }
+err-failed-transfer-admin = Falha ao transferir a administração: { $error }
+err-failed-sync-preferences = Falha ao sincronizar preferências: { $error }
+err-failed-accept-rules = Falha ao aceitar as regras: { $error }
 err-message-too-long-details = { $error } ({ $length } caracteres, máx { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = Endereço inválido '{ $address }': { $error }
+err-all-addresses-failed = Não foi possível alcançar nenhum dos { $count } endereços (último erro: { $error })
 err-could-not-resolve = Não foi possível resolver o endereço '{ $address }'
 err-connection-timeout = Tempo de conexão esgotado após { $seconds } segundos
 err-connection-failed = Falha na conexão: { $error }
+err-proxy-address-empty = O endereço do proxy é obrigatório
+err-proxy-address-invalid = O endereço do proxy deve ser um nome de host ou endereço IP sem espaços
+err-proxy-port-invalid = A porta do proxy deve estar entre 1 e 65535
+err-quiet-hours-start-invalid = O início do horário silencioso deve ser um horário de 24 horas como 22:00
+err-quiet-hours-end-invalid = O fim do horário silencioso deve ser um horário de 24 horas como 08:00
+err-message-format-unclosed-brace = O formato das mensagens tem um marcador não fechado (duplique a chave para exibi-la literalmente)
+err-message-format-unknown-placeholder = O formato das mensagens tem um marcador desconhecido { $placeholder } (disponíveis: { $available })
+err-message-format-missing-placeholder = O formato das mensagens deve incluir { $placeholder }
+err-message-format-duplicate-placeholder = O formato das mensagens só pode incluir { $placeholder } uma vez
+err-invite-not-a-link = Não é um link de convite nexus://
+err-invite-invalid-address = O link de convite tem um endereço de servidor inválido
+err-invite-invalid-port = A porta do link de convite deve estar entre 1 e 65535
+err-invite-invalid-name = O link de convite tem um nome de servidor inválido
+err-invite-invalid-fingerprint = O link de convite tem uma impressão digital de certificado inválida
+err-invite-credentials = Links de convite não podem conter usuário ou senha
+err-invite-fingerprint-mismatch = O certificado do servidor não corresponde ao link de convite (esperado { $expected }, recebido { $received })
+err-proxy-connection-failed = Não foi possível conectar pelo proxy { $proxy }: { $error }
 err-tls-handshake-failed = Falha no handshake TLS: { $error }
 err-failed-send-handshake = Falha ao enviar handshake: { $error }
 err-failed-read-handshake = Falha ao ler resposta do handshake: { $error }
 err-handshake-failed = Falha no handshake: { $error }
+err-client-too-old = Este servidor precisa de um cliente Nexus mais recente. Atualize para se conectar. ({ $error })
 err-failed-parse-handshake = Falha ao analisar resposta do handshake: { $error }
 err-failed-send-login = Falha ao enviar login: { $error }
 err-failed-read-login = Falha ao ler resposta de login: { $error }
@@ -274,6 +459,7 @@ err-failed-set-config-permissions = Falha ao definir permissões do arquivo de c
 # =============================================================================
 
 fingerprint-warning = Isso pode indicar um problema de segurança (ataque MITM) ou que o certificado do servidor foi regenerado. Aceite apenas se você confiar no administrador do servidor.
+server-rules-intro = Você deve aceitar estas regras antes de conversar neste servidor.
 
 # =============================================================================
 # User Info Display
@@ -283,15 +469,24 @@ user-info-username = Nome de usuário:
 user-info-role = Função:
 user-info-role-admin = admin
 user-info-role-user = usuário
+user-info-role-guest = convidado
+user-info-pronouns = Pronomes:
+user-info-bio = Bio:
 user-info-connected = Conectado:
 user-info-connected-value = há { $duration }
 user-info-connected-value-sessions = há { $duration } ({ $count } sessões)
 user-info-features = Recursos:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (inativo)
 user-info-features-none = Nenhum
+user-info-permissions = Permissões:
+user-info-permissions-none = Nenhuma
 user-info-locale = Idioma:
 user-info-address = Endereço:
 user-info-addresses = Endereços:
+user-info-sessions = Sessões:
+user-info-session = Sessão { $id }
+user-info-this-session = esta sessão
 user-info-created = Criado:
 user-info-end = Fim das informações do usuário
 user-info-unknown = Desconhecido
@@ -328,20 +523,62 @@ cmd-help-desc = Mostrar comandos disponíveis
 cmd-help-escape-hint = Dica: Use // para enviar uma mensagem que começa com /
 cmd-message-desc = Enviar uma mensagem a um usuário
 cmd-message-usage = Uso: /{ $command } <usuário> <mensagem>
+cmd-query-desc = Abrir uma aba de mensagens com um usuário sem enviar
+cmd-query-usage = Uso: /{ $command } <usuário>
+cmd-query-not-online = O usuário não está online: { $username }
+cmd-query-self = Você não pode enviar mensagens para si mesmo
+cmd-reconnect-desc = Desconectar e reconectar a este servidor
+cmd-reconnect-usage = Uso: /{ $command }
+cmd-reconnect-no-bookmark = /{ $command } precisa de um favorito para reconectar; esta conexão não foi feita a partir de um
 cmd-userinfo-desc = Mostrar informações sobre um usuário
 cmd-userinfo-usage = Uso: /{ $command } <usuário>
+cmd-invite-desc = Copiar um link de convite para este servidor
+cmd-invite-usage = Uso: /{ $command }
+cmd-invite-copied = Link de convite copiado para a área de transferência: { $link }
 cmd-kick-desc = Expulsar um usuário do servidor
 cmd-kick-usage = Uso: /{ $command } <usuário>
+cmd-limits-desc = Ver ou definir os limites de tamanho de mensagens e tópico
+cmd-limits-usage = Uso: /{ $command } [mensagem <caracteres>|tópico <caracteres>]
+cmd-limits-arg-message = mensagem
+cmd-limits-arg-topic = tópico
+cmd-limits-current = Limites: mensagens de até { $message } caracteres, tópico de até { $topic } caracteres
 cmd-topic-desc = Ver ou gerenciar o tópico do chat
 cmd-topic-usage = Uso: /{ $command } [definir|limpar] [tópico]
 cmd-topic-arg-set = definir
 cmd-topic-arg-clear = limpar
 cmd-topic-set-usage = Uso: /{ $command } definir <tópico>
 cmd-topic-none = Nenhum tópico definido
+cmd-announce-desc = Ver ou gerenciar os anúncios rotativos do servidor
+cmd-announce-usage = Uso: /{ $command } [adicionar <mensagem>|remover <número>]
+cmd-announce-arg-add = adicionar
+cmd-announce-arg-remove = remover
+cmd-announce-list = Anúncios (a cada { $minutes } minutos):
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = Nenhum anúncio definido
+cmd-announce-added = Anúncio adicionado
+cmd-announce-removed = Anúncio removido
+cmd-art-desc = Enviar arte ASCII ou ANSI de um arquivo como bloco de largura fixa
+cmd-art-usage = Uso: /{ $command } [cortar] <arquivo>
+cmd-art-arg-truncate = cortar
+cmd-art-too-long = A arte tem { $length } caracteres, acima do limite do servidor de { $max }. Use /{ $command } { $truncate } <arquivo> para enviar as linhas que cabem
 cmd-broadcast-desc = Enviar uma mensagem para todos os usuários
 cmd-broadcast-usage = Uso: /{ $command } <mensagem>
 cmd-clear-desc = Limpar histórico de chat da aba atual
 cmd-clear-usage = Uso: /{ $command }
+cmd-delete-desc = Excluir sua última mensagem (moderadores: a última mensagem de um usuário)
+cmd-delete-usage = Uso: /{ $command } [usuário]
+cmd-delete-no-message = Nenhuma mensagem recente de { $username } para excluir
+cmd-delete-permission-denied = Você não tem permissão para excluir mensagens de outros usuários
+cmd-edit-desc = Editar sua última mensagem
+cmd-edit-usage = Uso: /{ $command } <mensagem>
+cmd-edit-no-message = Você não tem nenhuma mensagem recente para editar
+cmd-events-desc = Mostrar os eventos do ciclo de vida desta conexão
+cmd-events-usage = Uso: /{ $command }
+cmd-events-header = Eventos de conexão (mais antigos primeiro):
+cmd-events-empty = Nenhum evento de conexão registrado ainda
+cmd-report-desc = Copiar um resumo sem dados sensíveis do cliente e da conexão para relatórios de bugs
+cmd-report-usage = Uso: /{ $command }
+cmd-report-copied = Relatório de bug copiado para a área de transferência. Omitidos: senhas, endereço do servidor, nomes de usuário, impressão digital do certificado, nome do servidor e endereço do proxy
 cmd-focus-desc = Focar no chat do servidor ou janela de mensagens de um usuário
 cmd-focus-usage = Uso: /{ $command } [usuário]
 cmd-focus-not-found = Usuário não encontrado: { $name }
@@ -353,28 +590,96 @@ cmd-list-output = Usuários online: { $users } ({ $count } { $count ->
     [one] usuário
    *[other] usuários
 })
+cmd-maintenance-desc = Ver ou alternar o modo de manutenção (somente leitura)
+cmd-maintenance-usage = Uso: /{ $command } [ativar|desativar]
+cmd-maintenance-arg-on = ativar
+cmd-maintenance-arg-off = desativar
+cmd-maintenance-on = O modo de manutenção está ativado
+cmd-maintenance-off = O modo de manutenção está desativado
+cmd-notice-desc = Mostrar um aviso a todos como banner
+cmd-notice-usage = Uso: /{ $command } reconnect | update <url> | <mensagem>
+cmd-notify-desc = Listar ou gerenciar os usuários sobre os quais você é avisado ao ficarem online
+cmd-notify-usage = Uso: /{ $command } [adicionar <usuário>|remover <usuário>]
+cmd-notify-arg-add = adicionar
+cmd-notify-arg-del = remover
+cmd-notify-no-bookmark = A lista de observação é salva em um favorito, então /notify só funciona em servidores favoritos
+cmd-notify-none = Você não está observando ninguém neste servidor
+cmd-notify-list = Observando: { $usernames }
+cmd-notify-added = Você será avisado quando { $username } ficar online
+cmd-notify-already = { $username } já está na sua lista de observação
+cmd-notify-removed = Você não está mais observando { $username }
+cmd-notify-not-watched = { $username } não está na sua lista de observação
 cmd-list-all-no-permission = Você precisa da permissão user_edit ou user_delete para listar todos os usuários
 cmd-list-all-output = Usuários: { $users } ({ $count } { $count ->
     [one] usuário
    *[other] usuários
 })
+cmd-whoami-desc = Mostra seu nome de usuário, função, permissões e recursos
+cmd-whoami-usage = Uso: /{ $command }
 cmd-help-usage = Uso: /{ $command } [comando]
 cmd-topic-permission-denied = Você não tem permissão para editar o tópico
+cmd-page-desc = Lista ou lê as páginas informativas do servidor (administradores: defini-las ou excluí-las)
+cmd-page-usage = Uso: /{ $command } [<nome>|definir <nome> <texto>|excluir <nome>] (\n inicia uma nova linha)
+cmd-page-arg-set = definir
+cmd-page-arg-delete = excluir
+cmd-page-list = Páginas: { $pages }
+cmd-page-none = Este servidor não tem páginas
+cmd-page-saved = Página salva
+cmd-page-deleted = Página excluída
+cmd-pin-desc = Listar mensagens fixadas ou fixar a última mensagem de um usuário
+cmd-pin-usage = Uso: /{ $command } [usuário]
+cmd-pin-list = Mensagens fixadas:
+cmd-pin-list-item = { $number }. { $username }: { $message } (fixada por { $pinned_by })
+cmd-pin-none = Nenhuma mensagem fixada
+cmd-pin-no-message = Nenhuma mensagem recente de { $username } para fixar
+cmd-purge-desc = Limpar o histórico do chat além da política de retenção do servidor
+cmd-purge-usage = Uso: /{ $command }
+cmd-refresh-desc = Reenviar as permissões de um usuário a partir do banco de dados do servidor
+cmd-refresh-usage = Uso: /{ $command } <usuário>
+cmd-transfer-desc = Tornar outra conta administradora e deixar de sê-lo, a menos que adicione manter
+cmd-transfer-usage = Uso: /{ $command } <usuário> [manter]
+cmd-transfer-arg-keep = manter
+cmd-seen-desc = Mostrar quando um usuário esteve online pela última vez
+cmd-seen-usage = Uso: /{ $command } <usuário>
+cmd-session-desc = Mostrar a sessão e o tráfego desta conexão
+cmd-session-usage = Uso: /{ $command }
+cmd-session-info = Sessão { $id } conectada a { $address }
+cmd-session-traffic = Tráfego desde a conexão: { $sent } enviados, { $received } recebidos
+cmd-unpin-desc = Remover uma mensagem fixada
+cmd-unpin-usage = Uso: /{ $command } <número> (veja /pin para os números)
 cmd-window-desc = Gerenciar abas de chat
-cmd-window-usage = Uso: /{ $command } [próximo|anterior|fechar [usuário]]
+cmd-window-usage = Uso: /{ $command } [próximo|anterior|fechar [usuário]|destacar [usuário]]
 cmd-window-arg-next = próximo
 cmd-window-arg-prev = anterior
 cmd-window-arg-close = fechar
+cmd-window-arg-popout = destacar
 cmd-window-list = Abas abertas: { $tabs } ({ $count } { $count ->
     [one] aba
    *[other] abas
 })
 cmd-window-close-server = Não é possível fechar a aba do servidor
+cmd-window-popout-server = Não é possível destacar a aba do servidor
 cmd-window-not-found = Aba não encontrada: { $name }
 cmd-serverinfo-desc = Mostrar informações do servidor
 cmd-serverinfo-usage = Uso: /{ $command }
 cmd-serverinfo-header = [servidor]
 cmd-serverinfo-end = Fim das informações do servidor
+cmd-slow-desc = Ver ou definir o modo lento do chat
+cmd-slow-usage = Uso: /{ $command } [segundos]
+cmd-slow-off = O modo lento está desativado
+cmd-slow-current = Modo lento: { $seconds } segundos entre mensagens
+cmd-stats-desc = Mostrar o histórico de conexões do servidor na última hora
+cmd-stats-usage = Uso: /{ $command } [redefinir]
+cmd-stats-arg-reset = redefinir
+cmd-stats-current = Conexões abertas agora: { $count }
+cmd-stats-total = Últimos { $minutes } minutos: { $connections } conexões, { $refused } recusadas, pico de { $peak } abertas ({ $peak_per_ip } de um endereço)
+cmd-stats-group = { $time }: { $connections } conexões, { $refused } recusadas, pico { $peak }
+cmd-stats-none = Nenhum histórico de conexões registrado ainda
+cmd-set-desc = Ver ou alterar as preferências de chat deste servidor
+cmd-set-usage = Uso: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = Mensagens de entrada/saída são exibidas neste servidor
+cmd-set-joinleave-off = Mensagens de entrada/saída estão ocultas neste servidor
+cmd-set-joinleave-default = Mensagens de entrada/saída seguem a configuração global neste servidor
 
 # =============================================================================
 # About Panel
@@ -384,6 +689,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = Escolher imagem
 button-clear-image = Limpar
+button-clear-theme = Limpar
+button-copy-fingerprint = Copiar
+button-clear-fingerprint = Limpar
+button-dismiss = Dispensar
+button-reconnect = Reconectar
+button-open-link = Abrir link
 label-server-image = Imagem do servidor:
 err-server-image-too-large = A imagem do servidor é muito grande (máximo 512KB)
 err-server-image-invalid-format = Formato de imagem do servidor inválido (deve ser uma URI de dados com codificação base64)
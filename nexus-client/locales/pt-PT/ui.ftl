@@ -5,14 +5,20 @@
 # =============================================================================
 
 button-cancel = Cancelar
+button-continue = Continuar
 button-send = Enviar
+button-send-as-one-message = Enviar como uma só mensagem
 button-delete = Eliminar
 button-connect = Ligar
 button-save = Guardar
 button-create = Criar
 button-edit = Editar
+button-edit-profile = Editar perfil
 button-update = Atualizar
 button-accept-new-certificate = Aceitar Novo Certificado
+button-always-accept-certificate = Aceitar Sempre
+button-accept-rules = Aceitar Regras
+button-disconnect = Desligar
 button-close = Fechar
 button-choose-avatar = Escolher Ícone
 button-clear-avatar = Limpar
@@ -22,6 +28,8 @@ button-clear-avatar = Limpar
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = Ligar ao Servidor
 title-add-bookmark = Adicionar Marcador
 title-edit-server = Editar Servidor
@@ -34,7 +42,10 @@ title-settings = Definições
 title-bookmarks = Marcadores
 title-users = Utilizadores
 title-edit-server-info = Editar Info do Servidor
+title-edit-profile = Editar perfil
 title-fingerprint-mismatch = Impressão Digital do Certificado Não Corresponde!
+title-server-rules = Regras do Servidor
+title-server-banner = Aviso do servidor
 title-server-info = Info do Servidor
 title-user-info = Info do Utilizador
 title-about = Sobre
@@ -46,39 +57,99 @@ title-about = Sobre
 placeholder-username = Nome de utilizador
 placeholder-password = Palavra-passe
 placeholder-port = Porta
+placeholder-fallback-addresses = Endereços alternativos (opcional, anfitrião:porta separados por vírgula)
+placeholder-watched-users = Avisar quando ficar online (opcional, nomes de utilizador separados por vírgula)
 placeholder-server-address = Endereço do Servidor
 placeholder-server-name = Nome do Servidor
 placeholder-username-optional = Nome de utilizador (opcional)
 placeholder-password-optional = Palavra-passe (opcional)
 placeholder-password-keep-current = Palavra-passe (deixe vazio para manter a actual)
 placeholder-message = Escreva uma mensagem...
+placeholder-message-slow-mode = Modo lento: aguarde { $seconds }s...
+placeholder-message-maintenance = Modo de manutenção: introdução desativada
 placeholder-no-permission = Sem permissão
 placeholder-broadcast-message = Escreva a mensagem de difusão...
 placeholder-server-description = Descrição do servidor
+placeholder-pronouns = ex.: elu/delu
+placeholder-bio = Uma linha curta sobre si
+placeholder-global-theme = Usar tema global
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = Auto-Ligar
+label-trust-certificate-changes = Aceitar sempre alterações de certificado deste servidor (menos seguro)
 label-add-bookmark = Marcador
+label-invite-fingerprint = Impressão digital do certificado da ligação de convite: { $fingerprint }
 label-admin = Administrador
 label-enabled = Activo
 label-permissions = Permissões:
 label-expected-fingerprint = Impressão digital esperada:
 label-received-fingerprint = Impressão digital recebida:
 label-theme = Tema
+label-density = Densidade:
+density-comfortable = Confortável
+density-compact = Compacta
+label-show-role-badges = Mostrar distintivos de administrador e moderador na lista de utilizadores
+badge-admin = admin
+badge-moderator = mod
+badge-guest = convidado
+user-list-permissions = Permissões: { $permissions }
+user-list-away = Ausente
 label-chat-font-size = Tamanho da fonte:
+label-max-message-tabs = Máx. de separadores de mensagens:
+label-paste-confirm-lines = Confirmar colagens de pelo menos estas linhas (0 = nunca):
+label-collapse-message-lines = Recolher mensagens com mais destas linhas (0 = nunca):
+label-ctrl-enter-sends = Enviar com Ctrl+Enter (Enter adiciona uma nova linha)
+label-message-format = Formato das mensagens:
+message-format-preset-default = Predefinido
+message-format-preset-compact = Compacto
+message-format-preset-irc = Estilo IRC
+message-format-preset-verbose = Detalhado (com data)
+message-format-preset-custom = Personalizado
 label-show-connection-notifications = Mostrar notificações de ligação
+label-hide-repeated-topic = Mostrar o tópico ao voltar a ligar só se tiver mudado
+label-alert-on-disconnect = Fazer piscar a janela quando uma ligação cair
+label-alert-on-mention = Fazer piscar a janela quando alguém o mencionar
+label-focus-new-messages = Mudar para mensagens privadas que cheguem enquanto estou inativo
+label-quiet-hours = Horas de silêncio (não fazer piscar a janela)
+label-quiet-hours-from = Das
+label-quiet-hours-to = às
+label-watched-users-ignore-quiet-hours = Avisar sobre utilizadores observados durante o horário silencioso
+label-auto-away = Marcar-me como ausente quando inativo
+label-auto-away-minutes = Minutos sem atividade
+label-quiet-hours-active = Horas de silêncio
 label-show-timestamps = Mostrar carimbos de data/hora
 label-use-24-hour-time = Usar formato de 24 horas
 label-show-seconds = Mostrar segundos
+label-timezone = Fuso horário:
+timezone-local = Local do sistema
+label-privacy = Privacidade
+label-trusted-certificates = Certificados fidedignos
+label-fingerprint-not-pinned = Ainda sem certificado fixado
+label-forget-passwords = Não guardar palavras-passe (perguntar ao ligar)
+label-sync-preferences = Sincronizar tema e apresentação do chat com os servidores
+label-check-for-updates = Procurar um cliente mais recente ao iniciar
+label-proxy = Proxy
+label-use-socks5-proxy = Ligar através de um proxy SOCKS5 (Tor, I2P)
+label-proxy-address = Endereço:
+label-proxy-port = Porta:
+label-diagnostics = Diagnóstico
+label-connection-log-path = Ficheiro de registo de ligações:
+placeholder-connection-log-path = Deixe vazio para manter os eventos apenas em memória
 label-server-name = Nome:
 label-server-description = Descrição:
+label-pronouns = Pronomes:
+label-bio = Bio:
 label-server-version = Versão:
+label-server-features = Funcionalidades:
+label-users-online = Utilizadores online:
 label-chat-topic = Tópico do Chat:
 label-chat-topic-set-by = Tópico Definido Por:
 label-max-connections-per-ip = Máx. Ligações Por IP:
+label-traffic = Tráfego:
+server-info-traffic-value = { $sent } enviados, { $received } recebidos
 label-avatar = Ícone:
 label-details = Detalhes técnicos
 label-chat-options = Opções de chat
@@ -86,6 +157,14 @@ label-appearance = Aparência
 label-image = Imagem
 label-general = Geral
 label-limits = Limites
+label-broadcast-target = Enviar para:
+broadcast-target-everyone = Todos
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = Chat
 
 # =============================================================================
 # Permission Display Names
@@ -97,6 +176,8 @@ permission-chat_send = Enviar Chat
 permission-chat_receive = Receber Chat
 permission-chat_topic = Tópico do Chat
 permission-chat_topic_edit = Editar Tópico do Chat
+permission-chat_pin = Afixar no Chat
+permission-chat_moderate = Moderar Chat
 permission-user_broadcast = Difusão de Utilizador
 permission-user_create = Criar Utilizador
 permission-user_delete = Eliminar Utilizador
@@ -115,16 +196,25 @@ tooltip-user-edit = Editar Utilizador
 tooltip-server-info = Info do Servidor
 tooltip-about = Sobre
 tooltip-settings = Definições
+tooltip-quiet-hours-active = Horas de silêncio ativas: a janela não vai piscar
 tooltip-hide-bookmarks = Ocultar Marcadores
 tooltip-show-bookmarks = Mostrar Marcadores
 tooltip-hide-user-list = Ocultar Lista de Utilizadores
 tooltip-show-user-list = Mostrar Lista de Utilizadores
 tooltip-disconnect = Desligar
+tooltip-connection-health-unknown = Qualidade da ligação ainda não medida
+tooltip-connection-health-good = Ligação boa ({ $rtt } ms)
+tooltip-connection-health-fair = Ligação razoável ({ $rtt } ms)
+tooltip-connection-health-poor = Ligação fraca ({ $rtt } ms)
+tooltip-connection-health-no-response = Sem resposta do servidor
 tooltip-edit = Editar
 tooltip-info = Info
 tooltip-message = Mensagem
 tooltip-kick = Expulsar
 tooltip-close = Fechar
+tooltip-pop-out = Abrir numa janela
+tooltip-unpin = Desafixar
+tooltip-pinned-by = Afixado por { $username }
 tooltip-add-bookmark = Adicionar Marcador
 
 # =============================================================================
@@ -141,6 +231,7 @@ empty-no-users = Nenhum utilizador online
 # =============================================================================
 
 chat-tab-server = #servidor
+chat-tab-server-count = #servidor ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -155,18 +246,47 @@ chat-prefix-system = [SIS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [ANÚNCIO]
+chat-prefix-pinned = [AFIXADO]
+chat-message-edited = (editado)
+chat-message-deleted = (eliminado)
+chat-message-removed-by-moderator = (removido por um moderador)
+chat-show-more = Mostrar mais
+chat-show-less = Mostrar menos
+chat-message-encrypted = (encriptada)
+chat-message-unreadable = (mensagem encriptada que não foi possível desencriptar)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = Utilizador expulso com sucesso
+msg-session-kicked-success = Sessão desligada
+msg-confirm-clear-fingerprint = Limpar o certificado fixado de { $name }? A próxima ligação confiará em qualquer certificado que o servidor apresentar.
 msg-broadcast-sent = Difusão enviada com sucesso
 msg-user-created = Utilizador criado com sucesso
 msg-user-deleted = Utilizador eliminado com sucesso
 msg-user-updated = Utilizador actualizado com sucesso
 msg-permissions-updated = As suas permissões foram actualizadas
 msg-topic-updated = Tópico atualizado com sucesso
+msg-message-pinned = Mensagem afixada
+msg-message-unpinned = Mensagem desafixada
+msg-chat-history-purged = Histórico do chat limpo: { $count } mensagens removidas
+msg-maintenance-enabled = O servidor está agora em modo de manutenção; as alterações estão desativadas
+msg-maintenance-disabled = O modo de manutenção terminou; as alterações estão novamente ativas
+msg-new-login = A sua conta acabou de iniciar sessão a partir de { $address } ({ $source })
+msg-seen-online = { $username } está online agora
+msg-seen-ago = { $username } foi visto pela última vez há { $duration } ({ $time })
+msg-seen-never = { $username } ainda não esteve online
+msg-maintenance-banner = Modo de manutenção: o servidor está só de leitura por agora
+msg-admin-notice-reconnect = Aviso do administrador: volte a ligar-se ao servidor
+msg-admin-notice-update = Aviso do administrador: está disponível uma atualização do cliente em { $url }
+msg-update-available = Nexus BBS { $version } está disponível
+msg-admin-notice = Aviso do administrador: { $message }
+msg-permissions-refreshed = Permissões atualizadas
+msg-admin-transferred = { $username } é agora administrador
+msg-copied-to-clipboard = Copiado para a área de transferência
+msg-large-paste-confirm = Esta mensagem contém { $count } linhas coladas. Enviar?
 
 
 # =============================================================================
@@ -177,9 +297,19 @@ msg-topic-cleared = Tópico limpo por { $username }
 msg-topic-set = Tópico definido por { $username }: { $topic }
 msg-server-info-updated = Configuração do servidor atualizada
 msg-topic-display = Tópico: { $topic }
+msg-connected-via-fallback = Ligado através do endereço alternativo { $address }
+msg-certificate-change-trusted = O certificado do servidor mudou e foi aceite porque este marcador confia em alterações de certificado (nova impressão digital { $fingerprint })
 msg-user-connected = { $username } ligou-se
 msg-user-disconnected = { $username } desligou-se
+msg-watched-user-online = { $username } está agora online
+msg-watched-users-online = Utilizadores observados online: { $usernames }
 msg-disconnected = Desligado: { $error }
+msg-enter-password-for = Introduza a palavra-passe para { $name }
+connect-step-progress = Passo { $step } de { $total }: { $description }
+connect-step-tcp = A ligar ao servidor...
+connect-step-tls = A proteger a ligação...
+connect-step-handshake = A negociar o protocolo...
+connect-step-login = A iniciar sessão...
 msg-connection-cancelled = Ligação cancelada devido a certificado não correspondente
 
 # =============================================================================
@@ -187,7 +317,9 @@ msg-connection-cancelled = Ligação cancelada devido a certificado não corresp
 # =============================================================================
 
 err-connection-broken = Erro de ligação
+err-account-disabled-while-connected = A sua conta foi desativada por um administrador. Contacte-o para a reativar antes de voltar a ligar-se.
 err-failed-update-server-info = Falha ao atualizar informações do servidor: { $error }
+err-failed-update-profile = Falha ao atualizar o perfil: { $error }
 err-user-kick-failed = Falha ao expulsar utilizador
 err-no-shutdown-handle = Erro de ligação: Sem handle de encerramento
 err-userlist-failed = Falha ao actualizar lista de utilizadores
@@ -205,6 +337,9 @@ err-could-not-determine-config-dir = Não foi possível determinar o directório
 err-message-too-long = A mensagem é demasiado longa ({ $length } caracteres, máx { $max })
 err-send-failed = Falha ao enviar mensagem
 err-no-chat-permission = Não tem permissão para enviar mensagens
+err-private-messages-disabled = As mensagens privadas estão desativadas neste servidor
+err-popout-command = Os comandos só podem ser usados na janela principal
+err-multiline-command = Os comandos têm de caber numa linha (comece com // para enviar isto como mensagem)
 err-broadcast-too-long = A difusão é demasiado longa ({ $length } caracteres, máx { $max })
 err-broadcast-send-failed = Falha ao enviar difusão
 err-name-required = O nome do marcador é obrigatório
@@ -216,6 +351,7 @@ err-message-required = A mensagem é obrigatória
 
 # Validation errors
 err-message-empty = A mensagem não pode estar vazia
+err-slow-mode-wait = O modo lento está ativado. Aguarde { $seconds } segundos
 err-message-contains-newlines = A mensagem não pode conter quebras de linha
 err-message-invalid-characters = A mensagem contém caracteres inválidos
 err-username-empty = O nome de utilizador não pode estar vazio
@@ -233,6 +369,12 @@ err-server-name-invalid-characters = O nome do servidor contém caracteres invá
 err-server-description-too-long = A descrição é demasiado longa (máx { $max } caracteres)
 err-server-description-contains-newlines = A descrição não pode conter quebras de linha
 err-server-description-invalid-characters = A descrição contém caracteres inválidos
+err-bio-too-long = A bio é demasiado longa (máx. { $max } caracteres)
+err-bio-contains-newlines = A bio não pode conter quebras de linha
+err-bio-invalid-characters = A bio contém caracteres inválidos
+err-pronouns-too-long = Os pronomes são demasiado longos (máx. { $max } caracteres)
+err-pronouns-contains-newlines = Os pronomes não podem conter quebras de linha
+err-pronouns-invalid-characters = Os pronomes contêm caracteres inválidos
 err-failed-send-update = Falha ao enviar atualização: { $error }
 
 # =============================================================================
@@ -240,25 +382,68 @@ err-failed-send-update = Falha ao enviar atualização: { $error }
 # =============================================================================
 
 err-failed-save-config = Falha ao guardar configuração: { $error }
+err-connection-log-write = Falha ao escrever o registo de ligação { $path }: { $error }
 err-failed-save-settings = Falha ao guardar definições: { $error }
 err-invalid-port-bookmark = Porta inválida no marcador: { $name }
+err-fallback-address-invalid = Endereço alternativo inválido: { $address }
 err-failed-send-broadcast = Falha ao enviar difusão: { $error }
 err-failed-send-message = Falha ao enviar mensagem: { $error }
+err-command-unsupported = O servidor não suporta /{ $command }
 err-failed-create-user = Falha ao criar utilizador: { $error }
 err-failed-delete-user = Falha ao eliminar utilizador: { $error }
 err-failed-update-user = Falha ao actualizar utilizador: { $error }
 err-failed-update-topic = Falha ao actualizar tópico: { $error }
+err-failed-pin-message = Falha ao afixar mensagem: { $error }
+err-failed-unpin-message = Falha ao desafixar mensagem: { $error }
+err-failed-edit-message = Falha ao editar mensagem: { $error }
+err-failed-delete-message = Falha ao eliminar mensagem: { $error }
+err-failed-purge-history = Falha ao limpar o histórico do chat: { $error }
+err-failed-announcement = Falha ao atualizar os anúncios: { $error }
+err-failed-stats = Falha ao obter estatísticas de ligação: { $error }
+err-failed-history = Falha ao carregar mensagens anteriores: { $error }
+err-art-read = Não foi possível ler { $path }: { $error }
+err-art-file-too-large = Os ficheiros de arte podem ter no máximo { $max } KiB
+err-art-empty = O ficheiro de arte não tem nada para enviar
+err-art-fence = A arte não pode conter ``` (isso terminaria o bloco)
+err-art-first-line = Nem a primeira linha cabe no limite do servidor de { $max } caracteres
+err-failed-page = Falha no pedido da página: { $error }
+err-failed-set-maintenance = Falha ao alterar o modo de manutenção: { $error }
+err-failed-send-admin-notice = Falha ao enviar o aviso: { $error }
+err-notice-url-invalid = A ligação deve começar por http:// ou https:// e não conter espaços
+err-failed-refresh-permissions = Falha ao atualizar as permissões: { $error }
+err-failed-transfer-admin = Falha ao transferir a administração: { $error }
+err-failed-sync-preferences = Falha ao sincronizar preferências: { $error }
+err-failed-accept-rules = Falha ao aceitar as regras: { $error }
 err-message-too-long-details = { $error } ({ $length } caracteres, máx { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = Endereço inválido '{ $address }': { $error }
+err-all-addresses-failed = Não foi possível alcançar nenhum dos { $count } endereços (último erro: { $error })
 err-could-not-resolve = Não foi possível resolver o endereço '{ $address }'
 err-connection-timeout = Tempo de ligação esgotado após { $seconds } segundos
 err-connection-failed = Falha na ligação: { $error }
+err-proxy-address-empty = O endereço do proxy é obrigatório
+err-proxy-address-invalid = O endereço do proxy deve ser um nome de anfitrião ou endereço IP sem espaços
+err-proxy-port-invalid = A porta do proxy deve estar entre 1 e 65535
+err-quiet-hours-start-invalid = O início das horas de silêncio deve ser uma hora no formato de 24 horas como 22:00
+err-quiet-hours-end-invalid = O fim das horas de silêncio deve ser uma hora no formato de 24 horas como 08:00
+err-message-format-unclosed-brace = O formato das mensagens tem um marcador não fechado (duplique a chaveta para a mostrar literalmente)
+err-message-format-unknown-placeholder = O formato das mensagens tem um marcador desconhecido { $placeholder } (disponíveis: { $available })
+err-message-format-missing-placeholder = O formato das mensagens tem de incluir { $placeholder }
+err-message-format-duplicate-placeholder = O formato das mensagens só pode incluir { $placeholder } uma vez
+err-invite-not-a-link = Não é uma ligação de convite nexus://
+err-invite-invalid-address = A ligação de convite tem um endereço de servidor inválido
+err-invite-invalid-port = A porta da ligação de convite deve estar entre 1 e 65535
+err-invite-invalid-name = A ligação de convite tem um nome de servidor inválido
+err-invite-invalid-fingerprint = A ligação de convite tem uma impressão digital de certificado inválida
+err-invite-credentials = As ligações de convite não podem conter utilizador nem palavra-passe
+err-invite-fingerprint-mismatch = O certificado do servidor não corresponde à ligação de convite (esperado { $expected }, recebido { $received })
+err-proxy-connection-failed = Não foi possível ligar através do proxy { $proxy }: { $error }
 err-tls-handshake-failed = Falha no handshake TLS: { $error }
 err-failed-send-handshake = Falha ao enviar handshake: { $error }
 err-failed-read-handshake = Falha ao ler resposta do handshake: { $error }
 err-handshake-failed = Falha no handshake: { $error }
+err-client-too-old = Este servidor precisa de um cliente Nexus mais recente. Atualize para se ligar. ({ $error })
 err-failed-parse-handshake = Falha ao analisar resposta do handshake: { $error }
 err-failed-send-login = Falha ao enviar autenticação: { $error }
 err-failed-read-login = Falha ao ler resposta de autenticação: { $error }
@@ -275,6 +460,7 @@ err-failed-set-config-permissions = Falha ao definir permissões do ficheiro de
 # =============================================================================
 
 fingerprint-warning = Isto pode indicar um problema de segurança (ataque MITM) ou que o certificado do servidor foi regenerado. Aceite apenas se confiar no administrador do servidor.
+server-rules-intro = Tem de aceitar estas regras antes de conversar neste servidor.
 
 # =============================================================================
 # User Info Display
@@ -284,15 +470,24 @@ user-info-username = Nome de utilizador:
 user-info-role = Função:
 user-info-role-admin = admin
 user-info-role-user = utilizador
+user-info-role-guest = convidado
+user-info-pronouns = Pronomes:
+user-info-bio = Bio:
 user-info-connected = Ligado:
 user-info-connected-value = há { $duration }
 user-info-connected-value-sessions = há { $duration } ({ $count } sessões)
 user-info-features = Funcionalidades:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (inativa)
 user-info-features-none = Nenhuma
+user-info-permissions = Permissões:
+user-info-permissions-none = Nenhuma
 user-info-locale = Idioma:
 user-info-address = Endereço:
 user-info-addresses = Endereços:
+user-info-sessions = Sessões:
+user-info-session = Sessão { $id }
+user-info-this-session = esta sessão
 user-info-created = Criado:
 user-info-end = Fim das informações do utilizador
 user-info-unknown = Desconhecido
@@ -329,20 +524,62 @@ cmd-help-desc = Mostrar comandos disponíveis
 cmd-help-escape-hint = Dica: Use // para enviar uma mensagem que comece com /
 cmd-message-desc = Enviar uma mensagem a um utilizador
 cmd-message-usage = Uso: /{ $command } <utilizador> <mensagem>
+cmd-query-desc = Abrir um separador de mensagens com um utilizador sem enviar
+cmd-query-usage = Uso: /{ $command } <utilizador>
+cmd-query-not-online = O utilizador não está online: { $username }
+cmd-query-self = Não pode enviar mensagens a si próprio
+cmd-reconnect-desc = Desligar e voltar a ligar a este servidor
+cmd-reconnect-usage = Utilização: /{ $command }
+cmd-reconnect-no-bookmark = /{ $command } precisa de um marcador para voltar a ligar; esta ligação não foi feita a partir de um
 cmd-userinfo-desc = Mostrar informações sobre um utilizador
 cmd-userinfo-usage = Uso: /{ $command } <utilizador>
+cmd-invite-desc = Copiar uma ligação de convite para este servidor
+cmd-invite-usage = Uso: /{ $command }
+cmd-invite-copied = Ligação de convite copiada para a área de transferência: { $link }
 cmd-kick-desc = Expulsar um utilizador do servidor
 cmd-kick-usage = Uso: /{ $command } <utilizador>
+cmd-limits-desc = Ver ou definir os limites de tamanho das mensagens e do tópico
+cmd-limits-usage = Uso: /{ $command } [mensagem <caracteres>|tópico <caracteres>]
+cmd-limits-arg-message = mensagem
+cmd-limits-arg-topic = tópico
+cmd-limits-current = Limites: mensagens até { $message } caracteres, tópico até { $topic } caracteres
 cmd-topic-desc = Ver ou gerir o tópico do chat
 cmd-topic-usage = Uso: /{ $command } [definir|limpar] [tópico]
 cmd-topic-arg-set = definir
 cmd-topic-arg-clear = limpar
 cmd-topic-set-usage = Uso: /{ $command } definir <tópico>
 cmd-topic-none = Nenhum tópico definido
+cmd-announce-desc = Ver ou gerir os anúncios rotativos do servidor
+cmd-announce-usage = Uso: /{ $command } [adicionar <mensagem>|remover <número>]
+cmd-announce-arg-add = adicionar
+cmd-announce-arg-remove = remover
+cmd-announce-list = Anúncios (a cada { $minutes } minutos):
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = Nenhum anúncio definido
+cmd-announce-added = Anúncio adicionado
+cmd-announce-removed = Anúncio removido
+cmd-art-desc = Enviar arte ASCII ou ANSI de um ficheiro como bloco de largura fixa
+cmd-art-usage = Utilização: /{ $command } [cortar] <ficheiro>
+cmd-art-arg-truncate = cortar
+cmd-art-too-long = A arte tem { $length } caracteres, acima do limite do servidor de { $max }. Utilize /{ $command } { $truncate } <ficheiro> para enviar as linhas que cabem
 cmd-broadcast-desc = Enviar uma difusão para todos os utilizadores
 cmd-broadcast-usage = Uso: /{ $command } <mensagem>
 cmd-clear-desc = Limpar histórico de chat do separador atual
 cmd-clear-usage = Uso: /{ $command }
+cmd-delete-desc = Eliminar a sua última mensagem (moderadores: a última mensagem de um utilizador)
+cmd-delete-usage = Utilização: /{ $command } [utilizador]
+cmd-delete-no-message = Nenhuma mensagem recente de { $username } para eliminar
+cmd-delete-permission-denied = Não tem permissão para eliminar mensagens de outros utilizadores
+cmd-edit-desc = Editar a sua última mensagem
+cmd-edit-usage = Utilização: /{ $command } <mensagem>
+cmd-edit-no-message = Não tem nenhuma mensagem recente para editar
+cmd-events-desc = Mostrar os eventos do ciclo de vida desta ligação
+cmd-events-usage = Utilização: /{ $command }
+cmd-events-header = Eventos de ligação (mais antigos primeiro):
+cmd-events-empty = Ainda não foram registados eventos de ligação
+cmd-report-desc = Copiar um resumo sem dados sensíveis do cliente e da ligação para relatórios de erros
+cmd-report-usage = Utilização: /{ $command }
+cmd-report-copied = Relatório de erro copiado para a área de transferência. Omitidos: palavras-passe, endereço do servidor, nomes de utilizador, impressão digital do certificado, nome do servidor e endereço do proxy
 cmd-focus-desc = Focar no chat do servidor ou janela de mensagens de um utilizador
 cmd-focus-usage = Uso: /{ $command } [utilizador]
 cmd-focus-not-found = Utilizador não encontrado: { $name }
@@ -354,28 +591,96 @@ cmd-list-output = Utilizadores online: { $users } ({ $count } { $count ->
     [one] utilizador
    *[other] utilizadores
 })
+cmd-maintenance-desc = Ver ou alternar o modo de manutenção (só de leitura)
+cmd-maintenance-usage = Uso: /{ $command } [ativar|desativar]
+cmd-maintenance-arg-on = ativar
+cmd-maintenance-arg-off = desativar
+cmd-maintenance-on = O modo de manutenção está ativado
+cmd-maintenance-off = O modo de manutenção está desativado
+cmd-notice-desc = Mostrar um aviso a todos como faixa
+cmd-notice-usage = Utilização: /{ $command } reconnect | update <url> | <mensagem>
+cmd-notify-desc = Listar ou gerir os utilizadores sobre os quais é avisado quando ficam online
+cmd-notify-usage = Uso: /{ $command } [adicionar <utilizador>|remover <utilizador>]
+cmd-notify-arg-add = adicionar
+cmd-notify-arg-del = remover
+cmd-notify-no-bookmark = A lista de observação é guardada num marcador, por isso /notify só funciona em servidores com marcador
+cmd-notify-none = Não está a observar ninguém neste servidor
+cmd-notify-list = A observar: { $usernames }
+cmd-notify-added = Será avisado quando { $username } ficar online
+cmd-notify-already = { $username } já está na sua lista de observação
+cmd-notify-removed = Deixou de observar { $username }
+cmd-notify-not-watched = { $username } não está na sua lista de observação
 cmd-list-all-no-permission = Precisa da permissão user_edit ou user_delete para listar todos os utilizadores
 cmd-list-all-output = Utilizadores: { $users } ({ $count } { $count ->
     [one] utilizador
    *[other] utilizadores
 })
+cmd-whoami-desc = Mostra o seu nome de utilizador, função, permissões e funcionalidades
+cmd-whoami-usage = Utilização: /{ $command }
 cmd-help-usage = Uso: /{ $command } [comando]
 cmd-topic-permission-denied = Não tem permissão para editar o tópico
+cmd-page-desc = Lista ou lê as páginas informativas do servidor (administradores: defini-las ou eliminá-las)
+cmd-page-usage = Uso: /{ $command } [<nome>|definir <nome> <texto>|eliminar <nome>] (\n inicia uma nova linha)
+cmd-page-arg-set = definir
+cmd-page-arg-delete = eliminar
+cmd-page-list = Páginas: { $pages }
+cmd-page-none = Este servidor não tem páginas
+cmd-page-saved = Página guardada
+cmd-page-deleted = Página eliminada
+cmd-pin-desc = Listar mensagens afixadas ou afixar a última mensagem de um utilizador
+cmd-pin-usage = Utilização: /{ $command } [utilizador]
+cmd-pin-list = Mensagens afixadas:
+cmd-pin-list-item = { $number }. { $username }: { $message } (afixada por { $pinned_by })
+cmd-pin-none = Nenhuma mensagem afixada
+cmd-pin-no-message = Nenhuma mensagem recente de { $username } para afixar
+cmd-purge-desc = Limpar o histórico do chat além da política de retenção do servidor
+cmd-purge-usage = Utilização: /{ $command }
+cmd-refresh-desc = Reenviar as permissões de um utilizador a partir da base de dados do servidor
+cmd-refresh-usage = Uso: /{ $command } <utilizador>
+cmd-transfer-desc = Tornar outra conta administradora e deixar de o ser, a menos que adicione manter
+cmd-transfer-usage = Utilização: /{ $command } <utilizador> [manter]
+cmd-transfer-arg-keep = manter
+cmd-seen-desc = Mostrar quando um utilizador esteve online pela última vez
+cmd-seen-usage = Utilização: /{ $command } <utilizador>
+cmd-session-desc = Mostrar a sessão e o tráfego desta ligação
+cmd-session-usage = Utilização: /{ $command }
+cmd-session-info = Sessão { $id } ligada a { $address }
+cmd-session-traffic = Tráfego desde a ligação: { $sent } enviados, { $received } recebidos
+cmd-unpin-desc = Remover uma mensagem afixada
+cmd-unpin-usage = Utilização: /{ $command } <número> (veja /pin para os números)
 cmd-window-desc = Gerir separadores de chat
-cmd-window-usage = Uso: /{ $command } [próximo|anterior|fechar [utilizador]]
+cmd-window-usage = Uso: /{ $command } [próximo|anterior|fechar [utilizador]|destacar [utilizador]]
 cmd-window-arg-next = próximo
 cmd-window-arg-prev = anterior
 cmd-window-arg-close = fechar
+cmd-window-arg-popout = destacar
 cmd-window-list = Separadores abertos: { $tabs } ({ $count } { $count ->
     [one] separador
    *[other] separadores
 })
 cmd-window-close-server = Não é possível fechar o separador do servidor
+cmd-window-popout-server = Não é possível destacar o separador do servidor
 cmd-window-not-found = Separador não encontrado: { $name }
 cmd-serverinfo-desc = Mostrar informações do servidor
 cmd-serverinfo-usage = Utilização: /{ $command }
 cmd-serverinfo-header = [servidor]
 cmd-serverinfo-end = Fim das informações do servidor
+cmd-slow-desc = Ver ou definir o modo lento do chat
+cmd-slow-usage = Uso: /{ $command } [segundos]
+cmd-slow-off = O modo lento está desativado
+cmd-slow-current = Modo lento: { $seconds } segundos entre mensagens
+cmd-stats-desc = Mostrar o histórico de ligações do servidor na última hora
+cmd-stats-usage = Utilização: /{ $command } [repor]
+cmd-stats-arg-reset = repor
+cmd-stats-current = Ligações abertas agora: { $count }
+cmd-stats-total = Últimos { $minutes } minutos: { $connections } ligações, { $refused } recusadas, pico de { $peak } abertas ({ $peak_per_ip } de um endereço)
+cmd-stats-group = { $time }: { $connections } ligações, { $refused } recusadas, pico { $peak }
+cmd-stats-none = Ainda não há histórico de ligações registado
+cmd-set-desc = Ver ou alterar as preferências de chat deste servidor
+cmd-set-usage = Utilização: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = As mensagens de entrada/saída são mostradas neste servidor
+cmd-set-joinleave-off = As mensagens de entrada/saída estão ocultas neste servidor
+cmd-set-joinleave-default = As mensagens de entrada/saída seguem a definição global neste servidor
 
 # =============================================================================
 # About Panel
@@ -385,6 +690,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = Escolher imagem
 button-clear-image = Limpar
+button-clear-theme = Limpar
+button-copy-fingerprint = Copiar
+button-clear-fingerprint = Limpar
+button-dismiss = Dispensar
+button-reconnect = Voltar a ligar
+button-open-link = Abrir ligação
 label-server-image = Imagem do servidor:
 err-server-image-too-large = A imagem do servidor é demasiado grande (máximo 512KB)
 err-server-image-invalid-format = Formato de imagem do servidor inválido (deve ser um URI de dados com codificação base64)
@@ -5,14 +5,20 @@
 # =============================================================================
 
 button-cancel = Отмена
+button-continue = Продолжить
 button-send = Отправить
+button-send-as-one-message = Отправить одним сообщением
 button-delete = Удалить
 button-connect = Подключиться
 button-save = Сохранить
 button-create = Создать
 button-edit = Редактировать
+button-edit-profile = Редактировать профиль
 button-update = Обновить
 button-accept-new-certificate = Принять новый сертификат
+button-always-accept-certificate = Всегда принимать
+button-accept-rules = Принять правила
+button-disconnect = Отключиться
 button-close = Закрыть
 button-choose-avatar = Выбрать аватар
 button-clear-avatar = Очистить
@@ -22,6 +28,8 @@ button-clear-avatar = Очистить
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = Подключение к серверу
 title-add-bookmark = Добавить закладку
 title-edit-server = Редактировать сервер
@@ -34,7 +42,10 @@ title-settings = Настройки
 title-bookmarks = Закладки
 title-users = Пользователи
 title-edit-server-info = Редактировать информацию о сервере
+title-edit-profile = Редактировать профиль
 title-fingerprint-mismatch = Отпечаток сертификата не совпадает!
+title-server-rules = Правила сервера
+title-server-banner = Уведомление сервера
 title-server-info = Информация о сервере
 title-user-info = Информация о пользователе
 title-about = О программе
@@ -46,39 +57,99 @@ title-about = О программе
 placeholder-username = Имя пользователя
 placeholder-password = Пароль
 placeholder-port = Порт
+placeholder-fallback-addresses = Резервные адреса (необязательно, host:port через запятую)
+placeholder-watched-users = Уведомлять о входе (необязательно, имена пользователей через запятую)
 placeholder-server-address = Адрес сервера
 placeholder-server-name = Имя сервера
 placeholder-username-optional = Имя пользователя (необязательно)
 placeholder-password-optional = Пароль (необязательно)
 placeholder-password-keep-current = Пароль (оставьте пустым для сохранения текущего)
 placeholder-message = Введите сообщение...
+placeholder-message-slow-mode = Медленный режим: подождите { $seconds } с...
+placeholder-message-maintenance = Режим обслуживания: ввод отключён
 placeholder-no-permission = Нет разрешения
 placeholder-broadcast-message = Введите сообщение рассылки...
 placeholder-server-description = Описание сервера
+placeholder-pronouns = напр. они/их
+placeholder-bio = Коротко о себе
+placeholder-global-theme = Использовать общую тему
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = Автоподключение
+label-trust-certificate-changes = Всегда принимать смену сертификата этого сервера (менее безопасно)
 label-add-bookmark = Добавить закладку
+label-invite-fingerprint = Отпечаток сертификата из ссылки-приглашения: { $fingerprint }
 label-admin = Администратор
 label-enabled = Включён
 label-permissions = Разрешения:
 label-expected-fingerprint = Ожидаемый отпечаток:
 label-received-fingerprint = Полученный отпечаток:
 label-theme = Тема
+label-density = Плотность:
+density-comfortable = Обычная
+density-compact = Компактная
+label-show-role-badges = Показывать значки администраторов и модераторов в списке пользователей
+badge-admin = админ
+badge-moderator = мод
+badge-guest = гость
+user-list-permissions = Права: { $permissions }
+user-list-away = Отошёл
 label-chat-font-size = Размер шрифта:
+label-max-message-tabs = Макс. вкладок сообщений:
+label-paste-confirm-lines = Подтверждать вставку от стольких строк (0 = никогда):
+label-collapse-message-lines = Сворачивать сообщения длиннее стольких строк (0 = никогда):
+label-ctrl-enter-sends = Отправлять по Ctrl+Enter (Enter добавляет новую строку)
+label-message-format = Формат сообщений:
+message-format-preset-default = По умолчанию
+message-format-preset-compact = Компактный
+message-format-preset-irc = В стиле IRC
+message-format-preset-verbose = Подробный (с датой)
+message-format-preset-custom = Свой
 label-show-connection-notifications = Показывать уведомления о подключении
+label-hide-repeated-topic = Показывать тему при переподключении, только если она изменилась
+label-alert-on-disconnect = Мигать окном при обрыве соединения
+label-alert-on-mention = Мигать окном, когда вас упоминают
+label-focus-new-messages = Переключаться на личные сообщения, пришедшие, пока я неактивен
+label-quiet-hours = Тихие часы (не мигать окном)
+label-quiet-hours-from = С
+label-quiet-hours-to = до
+label-watched-users-ignore-quiet-hours = Уведомлять об отслеживаемых пользователях в тихие часы
+label-auto-away = Отмечать меня отошедшим при бездействии
+label-auto-away-minutes = Минут без активности
+label-quiet-hours-active = Тихие часы
 label-show-timestamps = Показывать время
 label-use-24-hour-time = Использовать 24-часовой формат
 label-show-seconds = Показывать секунды
+label-timezone = Часовой пояс:
+timezone-local = Системный локальный
+label-privacy = Конфиденциальность
+label-trusted-certificates = Доверенные сертификаты
+label-fingerprint-not-pinned = Сертификат ещё не закреплён
+label-forget-passwords = Не сохранять пароли (спрашивать при подключении)
+label-sync-preferences = Синхронизировать тему и настройки отображения чата с серверами
+label-check-for-updates = Проверять наличие новой версии клиента при запуске
+label-proxy = Прокси
+label-use-socks5-proxy = Подключаться через SOCKS5-прокси (Tor, I2P)
+label-proxy-address = Адрес:
+label-proxy-port = Порт:
+label-diagnostics = Диагностика
+label-connection-log-path = Файл журнала подключений:
+placeholder-connection-log-path = Оставьте пустым, чтобы хранить события только в памяти
 label-server-name = Название:
 label-server-description = Описание:
+label-pronouns = Местоимения:
+label-bio = О себе:
 label-server-version = Версия:
+label-server-features = Функции:
+label-users-online = Пользователей онлайн:
 label-chat-topic = Тема чата:
 label-chat-topic-set-by = Тема установлена:
 label-max-connections-per-ip = Макс. подключений на IP:
+label-traffic = Трафик:
+server-info-traffic-value = отправлено { $sent }, получено { $received }
 label-avatar = Аватар:
 label-details = Технические детали
 label-chat-options = Настройки чата
@@ -86,6 +157,14 @@ label-appearance = Внешний вид
 label-image = Изображение
 label-general = Общие
 label-limits = Ограничения
+label-broadcast-target = Кому:
+broadcast-target-everyone = Всем
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = Чат
 
 # =============================================================================
 # Permission Display Names
@@ -97,6 +176,8 @@ permission-chat_send = Отправка Чата
 permission-chat_receive = Получение Чата
 permission-chat_topic = Тема Чата
 permission-chat_topic_edit = Редактирование Темы Чата
+permission-chat_pin = Закрепление в чате
+permission-chat_moderate = Модерация чата
 permission-user_broadcast = Рассылка Пользователя
 permission-user_create = Создание Пользователя
 permission-user_delete = Удаление Пользователя
@@ -115,16 +196,25 @@ tooltip-user-edit = Редактировать пользователя
 tooltip-server-info = Информация о сервере
 tooltip-about = О программе
 tooltip-settings = Настройки
+tooltip-quiet-hours-active = Тихие часы: окно не будет мигать
 tooltip-hide-bookmarks = Скрыть закладки
 tooltip-show-bookmarks = Показать закладки
 tooltip-hide-user-list = Скрыть список пользователей
 tooltip-show-user-list = Показать список пользователей
 tooltip-disconnect = Отключиться
+tooltip-connection-health-unknown = Качество соединения ещё не измерено
+tooltip-connection-health-good = Соединение хорошее ({ $rtt } мс)
+tooltip-connection-health-fair = Соединение среднее ({ $rtt } мс)
+tooltip-connection-health-poor = Соединение плохое ({ $rtt } мс)
+tooltip-connection-health-no-response = Нет ответа от сервера
 tooltip-edit = Редактировать
 tooltip-info = Инфо
 tooltip-message = Сообщение
 tooltip-kick = Выгнать
 tooltip-close = Закрыть
+tooltip-pop-out = Открыть в отдельном окне
+tooltip-unpin = Открепить
+tooltip-pinned-by = Закрепил { $username }
 tooltip-add-bookmark = Добавить закладку
 
 # =============================================================================
@@ -141,6 +231,7 @@ empty-no-users = Нет пользователей онлайн
 # =============================================================================
 
 chat-tab-server = #сервер
+chat-tab-server-count = #сервер ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -155,18 +246,47 @@ chat-prefix-system = [СИС]
 chat-prefix-error = [ОШБ]
 chat-prefix-info = [ИНФ]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [ОБЪЯВЛЕНИЕ]
+chat-prefix-pinned = [ЗАКРЕП]
+chat-message-edited = (изменено)
+chat-message-deleted = (удалено)
+chat-message-removed-by-moderator = (удалено модератором)
+chat-show-more = Показать больше
+chat-show-less = Свернуть
+chat-message-encrypted = (зашифровано)
+chat-message-unreadable = (зашифрованное сообщение, которое не удалось расшифровать)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = Пользователь успешно выгнан
+msg-session-kicked-success = Сеанс отключён
+msg-confirm-clear-fingerprint = Сбросить закреплённый сертификат для { $name }? При следующем подключении будет принят любой сертификат сервера.
 msg-broadcast-sent = Рассылка успешно отправлена
 msg-user-created = Пользователь успешно создан
 msg-user-deleted = Пользователь успешно удалён
 msg-user-updated = Пользователь успешно обновлён
 msg-permissions-updated = Ваши разрешения были обновлены
 msg-topic-updated = Тема успешно обновлена
+msg-message-pinned = Сообщение закреплено
+msg-message-unpinned = Сообщение откреплено
+msg-chat-history-purged = История чата очищена: удалено сообщений: { $count }
+msg-maintenance-enabled = Сервер перешёл в режим обслуживания; изменения отключены
+msg-maintenance-disabled = Режим обслуживания завершён; изменения снова доступны
+msg-new-login = В вашу учётную запись только что выполнен вход с { $address } ({ $source })
+msg-seen-online = { $username } сейчас в сети
+msg-seen-ago = { $username } был в сети { $duration } назад ({ $time })
+msg-seen-never = { $username } ещё ни разу не был в сети
+msg-maintenance-banner = Режим обслуживания: сервер временно доступен только для чтения
+msg-admin-notice-reconnect = Уведомление администратора: переподключитесь к серверу
+msg-admin-notice-update = Уведомление администратора: доступно обновление клиента { $url }
+msg-update-available = Доступна версия Nexus BBS { $version }
+msg-admin-notice = Уведомление администратора: { $message }
+msg-permissions-refreshed = Права обновлены
+msg-admin-transferred = { $username } теперь администратор
+msg-copied-to-clipboard = Скопировано в буфер обмена
+msg-large-paste-confirm = Вставлено строк в сообщении: { $count }. Отправить?
 
 # =============================================================================
 # Dynamic Messages (with parameters)
@@ -176,9 +296,19 @@ msg-topic-cleared = Тема очищена пользователем { $userna
 msg-topic-set = Тема установлена пользователем { $username }: { $topic }
 msg-server-info-updated = Конфигурация сервера обновлена
 msg-topic-display = Тема: { $topic }
+msg-connected-via-fallback = Подключено через резервный адрес { $address }
+msg-certificate-change-trusted = Сертификат сервера изменился и был принят, так как эта закладка доверяет смене сертификата (новый отпечаток { $fingerprint })
 msg-user-connected = { $username } подключился
 msg-user-disconnected = { $username } отключился
+msg-watched-user-online = { $username } теперь в сети
+msg-watched-users-online = Отслеживаемые пользователи в сети: { $usernames }
 msg-disconnected = Отключено: { $error }
+msg-enter-password-for = Введите пароль для { $name }
+connect-step-progress = Шаг { $step } из { $total }: { $description }
+connect-step-tcp = Подключение к серверу...
+connect-step-tls = Защита соединения...
+connect-step-handshake = Согласование протокола...
+connect-step-login = Вход в систему...
 msg-connection-cancelled = Подключение отменено из-за несоответствия сертификата
 
 # =============================================================================
@@ -186,7 +316,9 @@ msg-connection-cancelled = Подключение отменено из-за н
 # =============================================================================
 
 err-connection-broken = Ошибка подключения
+err-account-disabled-while-connected = Ваша учётная запись отключена администратором. Обратитесь к нему, чтобы её снова включили, прежде чем переподключаться.
 err-failed-update-server-info = Не удалось обновить информацию о сервере: { $error }
+err-failed-update-profile = Не удалось обновить профиль: { $error }
 err-user-kick-failed = Не удалось выгнать пользователя
 err-no-shutdown-handle = Ошибка подключения: Нет дескриптора завершения
 err-userlist-failed = Не удалось обновить список пользователей
@@ -204,6 +336,9 @@ err-could-not-determine-config-dir = Не удалось определить к
 err-message-too-long = Сообщение слишком длинное ({ $length } символов, макс { $max })
 err-send-failed = Не удалось отправить сообщение
 err-no-chat-permission = У вас нет разрешения на отправку сообщений
+err-private-messages-disabled = Личные сообщения на этом сервере отключены
+err-popout-command = Команды можно использовать только в главном окне
+err-multiline-command = Команда должна умещаться в одной строке (начните с //, чтобы отправить это как сообщение)
 err-broadcast-too-long = Рассылка слишком длинная ({ $length } символов, макс { $max })
 err-broadcast-send-failed = Не удалось отправить рассылку
 err-name-required = Требуется имя закладки
@@ -215,6 +350,7 @@ err-message-required = Требуется сообщение
 
 # Validation errors
 err-message-empty = Сообщение не может быть пустым
+err-slow-mode-wait = Включён медленный режим. Подождите { $seconds } сек.
 err-message-contains-newlines = Сообщение не может содержать переносы строк
 err-message-invalid-characters = Сообщение содержит недопустимые символы
 err-username-empty = Имя пользователя не может быть пустым
@@ -232,6 +368,12 @@ err-server-name-invalid-characters = Имя сервера содержит не
 err-server-description-too-long = Описание слишком длинное (макс { $max } символов)
 err-server-description-contains-newlines = Описание не может содержать переносы строк
 err-server-description-invalid-characters = Описание содержит недопустимые символы
+err-bio-too-long = Описание слишком длинное (макс. { $max } символов)
+err-bio-contains-newlines = Описание не может содержать переносы строк
+err-bio-invalid-characters = Описание содержит недопустимые символы
+err-pronouns-too-long = Местоимения слишком длинные (макс. { $max } символов)
+err-pronouns-contains-newlines = Местоимения не могут содержать переносы строк
+err-pronouns-invalid-characters = Местоимения содержат недопустимые символы
 err-failed-send-update = Не удалось отправить обновление: { $error }
 
 # =============================================================================
@@ -239,25 +381,68 @@ err-failed-send-update = Не удалось отправить обновлен
 # =============================================================================
 
 err-failed-save-config = Не удалось сохранить конфигурацию: { $error }
+err-connection-log-write = Не удалось записать журнал подключений { $path }: { $error }
 err-failed-save-settings = Не удалось сохранить настройки: { $error }
 err-invalid-port-bookmark = Недопустимый порт в закладке: { $name }
+err-fallback-address-invalid = Недопустимый резервный адрес: { $address }
 err-failed-send-broadcast = Не удалось отправить рассылку: { $error }
 err-failed-send-message = Не удалось отправить сообщение: { $error }
+err-command-unsupported = Сервер не поддерживает /{ $command }
 err-failed-create-user = Не удалось создать пользователя: { $error }
 err-failed-delete-user = Не удалось удалить пользователя: { $error }
 err-failed-update-user = Не удалось обновить пользователя: { $error }
 err-failed-update-topic = Не удалось обновить тему: { $error }
+err-failed-pin-message = Не удалось закрепить сообщение: { $error }
+err-failed-unpin-message = Не удалось открепить сообщение: { $error }
+err-failed-edit-message = Не удалось изменить сообщение: { $error }
+err-failed-delete-message = Не удалось удалить сообщение: { $error }
+err-failed-purge-history = Не удалось очистить историю чата: { $error }
+err-failed-announcement = Не удалось обновить объявления: { $error }
+err-failed-stats = Не удалось получить статистику подключений: { $error }
+err-failed-history = Не удалось загрузить более ранние сообщения: { $error }
+err-art-read = Не удалось прочитать { $path }: { $error }
+err-art-file-too-large = Файл с артом может быть не больше { $max } КиБ
+err-art-empty = В файле с артом нечего отправить
+err-art-fence = Арт не может содержать ``` (это закроет блок)
+err-art-first-line = Даже первая строка не помещается в лимит сервера в { $max } символов
+err-failed-page = Не удалось выполнить запрос страницы: { $error }
+err-failed-set-maintenance = Не удалось изменить режим обслуживания: { $error }
+err-failed-send-admin-notice = Не удалось отправить уведомление: { $error }
+err-notice-url-invalid = Ссылка должна начинаться с http:// или https:// и не содержать пробелов
+err-failed-refresh-permissions = Не удалось обновить права: { $error }
+err-failed-transfer-admin = Не удалось передать права администратора: { $error }
+err-failed-sync-preferences = Не удалось синхронизировать настройки: { $error }
+err-failed-accept-rules = Не удалось принять правила: { $error }
 err-message-too-long-details = { $error } ({ $length } символов, макс { $max })
 
 # Network connection errors (with parameters)
 err-invalid-address = Недопустимый адрес '{ $address }': { $error }
+err-all-addresses-failed = Не удалось подключиться ни к одному из адресов ({ $count }) (последняя ошибка: { $error })
 err-could-not-resolve = Не удалось разрешить адрес '{ $address }'
 err-connection-timeout = Время ожидания подключения истекло через { $seconds } секунд
 err-connection-failed = Ошибка подключения: { $error }
+err-proxy-address-empty = Требуется адрес прокси
+err-proxy-address-invalid = Адрес прокси должен быть именем хоста или IP-адресом без пробелов
+err-proxy-port-invalid = Порт прокси должен быть от 1 до 65535
+err-quiet-hours-start-invalid = Начало тихих часов должно быть временем в 24-часовом формате, например 22:00
+err-quiet-hours-end-invalid = Конец тихих часов должен быть временем в 24-часовом формате, например 08:00
+err-message-format-unclosed-brace = В формате сообщений есть незакрытый заполнитель (удвойте скобку, чтобы вывести её как есть)
+err-message-format-unknown-placeholder = В формате сообщений неизвестный заполнитель { $placeholder } (доступны: { $available })
+err-message-format-missing-placeholder = Формат сообщений должен содержать { $placeholder }
+err-message-format-duplicate-placeholder = Формат сообщений может содержать { $placeholder } только один раз
+err-invite-not-a-link = Это не ссылка-приглашение nexus://
+err-invite-invalid-address = В ссылке-приглашении неверный адрес сервера
+err-invite-invalid-port = Порт в ссылке-приглашении должен быть от 1 до 65535
+err-invite-invalid-name = В ссылке-приглашении неверное имя сервера
+err-invite-invalid-fingerprint = В ссылке-приглашении неверный отпечаток сертификата
+err-invite-credentials = Ссылки-приглашения не должны содержать имя пользователя или пароль
+err-invite-fingerprint-mismatch = Сертификат сервера не совпадает со ссылкой-приглашением (ожидался { $expected }, получен { $received })
+err-proxy-connection-failed = Не удалось подключиться через прокси { $proxy }: { $error }
 err-tls-handshake-failed = Ошибка TLS-рукопожатия: { $error }
 err-failed-send-handshake = Не удалось отправить рукопожатие: { $error }
 err-failed-read-handshake = Не удалось прочитать ответ рукопожатия: { $error }
 err-handshake-failed = Ошибка рукопожатия: { $error }
+err-client-too-old = Этому серверу нужен более новый клиент Nexus. Обновите его, чтобы подключиться. ({ $error })
 err-failed-parse-handshake = Не удалось разобрать ответ рукопожатия: { $error }
 err-failed-send-login = Не удалось отправить данные для входа: { $error }
 err-failed-read-login = Не удалось прочитать ответ при входе: { $error }
@@ -274,6 +459,7 @@ err-failed-set-config-permissions = Не удалось установить п
 # =============================================================================
 
 fingerprint-warning = Это может указывать на проблему безопасности (атака MITM) или на то, что сертификат сервера был перегенерирован. Принимайте только если доверяете администратору сервера.
+server-rules-intro = Вы должны принять эти правила, прежде чем общаться в чате на этом сервере.
 
 # =============================================================================
 # User Info Display
@@ -283,15 +469,24 @@ user-info-username = Имя пользователя:
 user-info-role = Роль:
 user-info-role-admin = админ
 user-info-role-user = пользователь
+user-info-role-guest = гость
+user-info-pronouns = Местоимения:
+user-info-bio = О себе:
 user-info-connected = Подключён:
 user-info-connected-value = { $duration } назад
 user-info-connected-value-sessions = { $duration } назад ({ $count } сеансов)
 user-info-features = Возможности:
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature } (неактивна)
 user-info-features-none = Нет
+user-info-permissions = Права:
+user-info-permissions-none = Нет
 user-info-locale = Язык:
 user-info-address = Адрес:
 user-info-addresses = Адреса:
+user-info-sessions = Сеансы:
+user-info-session = Сеанс { $id }
+user-info-this-session = этот сеанс
 user-info-created = Создан:
 user-info-end = Конец информации о пользователе
 user-info-unknown = Неизвестно
@@ -332,20 +527,62 @@ cmd-help-desc = Показать доступные команды
 cmd-help-escape-hint = Подсказка: Используйте // для отправки сообщения, начинающегося с /
 cmd-message-desc = Отправить сообщение пользователю
 cmd-message-usage = Использование: /{ $command } <имя_пользователя> <сообщение>
+cmd-query-desc = Открыть вкладку сообщений с пользователем без отправки
+cmd-query-usage = Использование: /{ $command } <имя>
+cmd-query-not-online = Пользователь не в сети: { $username }
+cmd-query-self = Нельзя отправить сообщение самому себе
+cmd-reconnect-desc = Отключиться и заново подключиться к этому серверу
+cmd-reconnect-usage = Использование: /{ $command }
+cmd-reconnect-no-bookmark = Для /{ $command } нужна закладка; это подключение создано не из закладки
 cmd-userinfo-desc = Показать информацию о пользователе
 cmd-userinfo-usage = Использование: /{ $command } <имя_пользователя>
+cmd-invite-desc = Скопировать ссылку-приглашение на этот сервер
+cmd-invite-usage = Использование: /{ $command }
+cmd-invite-copied = Ссылка-приглашение скопирована в буфер обмена: { $link }
 cmd-kick-desc = Отключить пользователя от сервера
 cmd-kick-usage = Использование: /{ $command } <имя_пользователя>
+cmd-limits-desc = Показать или задать ограничения длины сообщений и темы
+cmd-limits-usage = Использование: /{ $command } [сообщение <символы>|тема <символы>]
+cmd-limits-arg-message = сообщение
+cmd-limits-arg-topic = тема
+cmd-limits-current = Ограничения: сообщения до { $message } символов, тема до { $topic } символов
 cmd-topic-desc = Просмотр или управление темой чата
 cmd-topic-usage = Использование: /{ $command } [установить|очистить] [тема]
 cmd-topic-arg-set = установить
 cmd-topic-arg-clear = очистить
 cmd-topic-set-usage = Использование: /{ $command } установить <тема>
 cmd-topic-none = Тема не установлена
+cmd-announce-desc = Показать или изменить повторяющиеся объявления сервера
+cmd-announce-usage = Использование: /{ $command } [добавить <сообщение>|удалить <номер>]
+cmd-announce-arg-add = добавить
+cmd-announce-arg-remove = удалить
+cmd-announce-list = Объявления (каждые { $minutes } мин.):
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = Объявления не заданы
+cmd-announce-added = Объявление добавлено
+cmd-announce-removed = Объявление удалено
+cmd-art-desc = Отправить ASCII- или ANSI-арт из файла моноширинным блоком
+cmd-art-usage = Использование: /{ $command } [обрезать] <файл>
+cmd-art-arg-truncate = обрезать
+cmd-art-too-long = Арт занимает { $length } символов, больше лимита сервера в { $max }. Используйте /{ $command } { $truncate } <файл>, чтобы отправить помещающиеся строки
 cmd-broadcast-desc = Отправить сообщение всем пользователям
 cmd-broadcast-usage = Использование: /{ $command } <сообщение>
 cmd-clear-desc = Очистить историю чата текущей вкладки
 cmd-clear-usage = Использование: /{ $command }
+cmd-delete-desc = Удалить своё последнее сообщение (модераторы: последнее сообщение пользователя)
+cmd-delete-usage = Использование: /{ $command } [имя пользователя]
+cmd-delete-no-message = Нет недавних сообщений от { $username } для удаления
+cmd-delete-permission-denied = У вас нет прав на удаление сообщений других пользователей
+cmd-edit-desc = Изменить своё последнее сообщение
+cmd-edit-usage = Использование: /{ $command } <сообщение>
+cmd-edit-no-message = У вас нет недавних сообщений для изменения
+cmd-events-desc = Показать события жизненного цикла этого подключения
+cmd-events-usage = Использование: /{ $command }
+cmd-events-header = События подключения (сначала старые):
+cmd-events-empty = События подключения ещё не записаны
+cmd-report-desc = Скопировать очищенный от личных данных снимок клиента и подключения для отчёта об ошибке
+cmd-report-usage = Использование: /{ $command }
+cmd-report-copied = Отчёт об ошибке скопирован в буфер обмена. Исключены: пароли, адрес сервера, имена пользователей, отпечаток сертификата, имя сервера и адрес прокси
 cmd-focus-desc = Переключиться на чат сервера или окно сообщений пользователя
 cmd-focus-usage = Использование: /{ $command } [имя_пользователя]
 cmd-focus-not-found = Пользователь не найден: { $name }
@@ -358,30 +595,98 @@ cmd-list-output = Пользователи онлайн: { $users } ({ $count }
     [few] пользователя
    *[other] пользователей
 })
+cmd-maintenance-desc = Показать или переключить режим обслуживания (только чтение)
+cmd-maintenance-usage = Использование: /{ $command } [вкл|выкл]
+cmd-maintenance-arg-on = вкл
+cmd-maintenance-arg-off = выкл
+cmd-maintenance-on = Режим обслуживания включён
+cmd-maintenance-off = Режим обслуживания выключен
+cmd-notice-desc = Показать всем уведомление в виде баннера
+cmd-notice-usage = Использование: /{ $command } reconnect | update <url> | <сообщение>
+cmd-notify-desc = Показать или изменить список пользователей, о входе которых вы получаете уведомления
+cmd-notify-usage = Использование: /{ $command } [добавить <имя>|удалить <имя>]
+cmd-notify-arg-add = добавить
+cmd-notify-arg-del = удалить
+cmd-notify-no-bookmark = Список отслеживания хранится в закладке, поэтому /notify работает только на серверах из закладок
+cmd-notify-none = Вы никого не отслеживаете на этом сервере
+cmd-notify-list = Отслеживаются: { $usernames }
+cmd-notify-added = Вы получите уведомление, когда { $username } войдёт в сеть
+cmd-notify-already = { $username } уже в вашем списке отслеживания
+cmd-notify-removed = { $username } больше не отслеживается
+cmd-notify-not-watched = { $username } нет в вашем списке отслеживания
 cmd-list-all-no-permission = Для просмотра всех пользователей требуется разрешение user_edit или user_delete
 cmd-list-all-output = Пользователи: { $users } ({ $count } { $count ->
     [one] пользователь
     [few] пользователя
    *[other] пользователей
 })
+cmd-whoami-desc = Показать ваше имя пользователя, роль, права и возможности
+cmd-whoami-usage = Использование: /{ $command }
 cmd-help-usage = Использование: /{ $command } [команда]
 cmd-topic-permission-denied = У вас нет разрешения на редактирование темы
+cmd-page-desc = Показать список или прочитать информационные страницы сервера (администраторы: задать или удалить)
+cmd-page-usage = Использование: /{ $command } [<имя>|установить <имя> <текст>|удалить <имя>] (\n начинает новую строку)
+cmd-page-arg-set = установить
+cmd-page-arg-delete = удалить
+cmd-page-list = Страницы: { $pages }
+cmd-page-none = На этом сервере нет страниц
+cmd-page-saved = Страница сохранена
+cmd-page-deleted = Страница удалена
+cmd-pin-desc = Показать закреплённые сообщения или закрепить последнее сообщение пользователя
+cmd-pin-usage = Использование: /{ $command } [имя пользователя]
+cmd-pin-list = Закреплённые сообщения:
+cmd-pin-list-item = { $number }. { $username }: { $message } (закрепил { $pinned_by })
+cmd-pin-none = Нет закреплённых сообщений
+cmd-pin-no-message = Нет недавних сообщений от { $username } для закрепления
+cmd-purge-desc = Очистить историю чата сверх политики хранения сервера
+cmd-purge-usage = Использование: /{ $command }
+cmd-refresh-desc = Повторно отправить права пользователя из базы данных сервера
+cmd-refresh-usage = Использование: /{ $command } <имя_пользователя>
+cmd-transfer-desc = Сделать другую учётную запись администратором и сложить свои права, если не добавить оставить
+cmd-transfer-usage = Использование: /{ $command } <имя_пользователя> [оставить]
+cmd-transfer-arg-keep = оставить
+cmd-seen-desc = Показать, когда пользователь был в сети в последний раз
+cmd-seen-usage = Использование: /{ $command } <имя пользователя>
+cmd-session-desc = Показать сеанс и трафик этого подключения
+cmd-session-usage = Использование: /{ $command }
+cmd-session-info = Сеанс { $id }, подключение к { $address }
+cmd-session-traffic = Трафик с момента подключения: отправлено { $sent }, получено { $received }
+cmd-unpin-desc = Открепить сообщение
+cmd-unpin-usage = Использование: /{ $command } <номер> (номера см. в /pin)
 cmd-window-desc = Управление вкладками чата
-cmd-window-usage = Использование: /{ $command } [следующая|предыдущая|закрыть [имя_пользователя]]
+cmd-window-usage = Использование: /{ $command } [следующая|предыдущая|закрыть [имя_пользователя]|отделить [имя_пользователя]]
 cmd-window-arg-next = следующая
 cmd-window-arg-prev = предыдущая
 cmd-window-arg-close = закрыть
+cmd-window-arg-popout = отделить
 cmd-window-list = Открытые вкладки: { $tabs } ({ $count } { $count ->
     [one] вкладка
     [few] вкладки
    *[other] вкладок
 })
 cmd-window-close-server = Невозможно закрыть вкладку сервера
+cmd-window-popout-server = Невозможно отделить вкладку сервера
 cmd-window-not-found = Вкладка не найдена: { $name }
 cmd-serverinfo-desc = Показать информацию о сервере
 cmd-serverinfo-usage = Использование: /{ $command }
 cmd-serverinfo-header = [сервер]
 cmd-serverinfo-end = Конец информации о сервере
+cmd-slow-desc = Показать или задать медленный режим чата
+cmd-slow-usage = Использование: /{ $command } [секунды]
+cmd-slow-off = Медленный режим выключен
+cmd-slow-current = Медленный режим: { $seconds } сек. между сообщениями
+cmd-stats-desc = Показать историю подключений к серверу за последний час
+cmd-stats-usage = Использование: /{ $command } [сброс]
+cmd-stats-arg-reset = сброс
+cmd-stats-current = Открытых подключений сейчас: { $count }
+cmd-stats-total = Последние { $minutes } мин.: подключений { $connections }, отклонено { $refused }, пик { $peak } открытых ({ $peak_per_ip } с одного адреса)
+cmd-stats-group = { $time }: подключений { $connections }, отклонено { $refused }, пик { $peak }
+cmd-stats-none = История подключений пока не записана
+cmd-set-desc = Просмотр или изменение настроек чата для этого сервера
+cmd-set-usage = Использование: /{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = Сообщения о входе/выходе показываются на этом сервере
+cmd-set-joinleave-off = Сообщения о входе/выходе скрыты на этом сервере
+cmd-set-joinleave-default = Сообщения о входе/выходе на этом сервере следуют общей настройке
 
 # =============================================================================
 # About Panel
@@ -391,6 +696,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = Выбрать изображение
 button-clear-image = Очистить
+button-clear-theme = Очистить
+button-copy-fingerprint = Копировать
+button-clear-fingerprint = Сбросить
+button-dismiss = Скрыть
+button-reconnect = Переподключиться
+button-open-link = Открыть ссылку
 label-server-image = Изображение сервера:
 err-server-image-too-large = Изображение сервера слишком большое (максимум 512КБ)
 err-server-image-invalid-format = Недопустимый формат изображения сервера (должен быть data URI с кодировкой base64)
@@ -5,14 +5,20 @@
 # =============================================================================
 
 button-cancel = 取消
+button-continue = 继续
 button-send = 发送
+button-send-as-one-message = 作为一条消息发送
 button-delete = 删除
 button-connect = 连接
 button-save = 保存
 button-create = 创建
 button-edit = 编辑
+button-edit-profile = 编辑个人资料
 button-update = 更新
 button-accept-new-certificate = 接受新证书
+button-always-accept-certificate = 始终接受
+button-accept-rules = 接受规则
+button-disconnect = 断开连接
 button-close = 关闭
 button-choose-avatar = 选择头像
 button-clear-avatar = 清除
@@ -22,6 +28,8 @@ button-clear-avatar = 清除
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = 连接到服务器
 title-add-bookmark = 添加书签
 title-edit-server = 编辑服务器
@@ -34,7 +42,10 @@ title-settings = 设置
 title-bookmarks = 书签
 title-users = 用户
 title-edit-server-info = 编辑服务器信息
+title-edit-profile = 编辑个人资料
 title-fingerprint-mismatch = 证书指纹不匹配！
+title-server-rules = 服务器规则
+title-server-banner = 服务器通知
 title-server-info = 服务器信息
 title-user-info = 用户信息
 title-about = 关于
@@ -47,39 +58,99 @@ title-about = 关于
 placeholder-username = 用户名
 placeholder-password = 密码
 placeholder-port = 端口
+placeholder-fallback-addresses = 备用地址（可选，以逗号分隔的 host:port）
+placeholder-watched-users = 上线时通知（可选，用逗号分隔用户名）
 placeholder-server-address = 服务器地址
 placeholder-server-name = 服务器名称
 placeholder-username-optional = 用户名（可选）
 placeholder-password-optional = 密码（可选）
 placeholder-password-keep-current = 密码（留空保持当前密码）
 placeholder-message = 输入消息...
+placeholder-message-slow-mode = 慢速模式：请等待{ $seconds }秒...
+placeholder-message-maintenance = 维护模式：输入已禁用
 placeholder-no-permission = 无权限
 placeholder-broadcast-message = 输入广播消息...
 placeholder-server-description = 服务器描述
+placeholder-pronouns = 例如 they/them
+placeholder-bio = 一句简短的自我介绍
+placeholder-global-theme = 使用全局主题
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = 自动连接
+label-trust-certificate-changes = 始终接受此服务器的证书变更（安全性较低）
 label-add-bookmark = 书签
+label-invite-fingerprint = 邀请链接中的证书指纹：{ $fingerprint }
 label-admin = 管理员
 label-enabled = 已启用
 label-permissions = 权限：
 label-expected-fingerprint = 预期指纹：
 label-received-fingerprint = 收到的指纹：
 label-theme = 主题
+label-density = 显示密度：
+density-comfortable = 舒适
+density-compact = 紧凑
+label-show-role-badges = 在用户列表中显示管理员和版主徽章
+badge-admin = 管理员
+badge-moderator = 版主
+badge-guest = 访客
+user-list-permissions = 权限：{ $permissions }
+user-list-away = 离开
 label-chat-font-size = 字体大小：
+label-max-message-tabs = 消息标签页上限：
+label-paste-confirm-lines = 粘贴达到此行数时确认（0 = 从不）：
+label-collapse-message-lines = 折叠超过此行数的消息（0 = 从不）：
+label-ctrl-enter-sends = 使用 Ctrl+Enter 发送（Enter 换行）
+label-message-format = 消息格式：
+message-format-preset-default = 默认
+message-format-preset-compact = 紧凑
+message-format-preset-irc = IRC 风格
+message-format-preset-verbose = 详细（含日期）
+message-format-preset-custom = 自定义
 label-show-connection-notifications = 显示连接通知
+label-hide-repeated-topic = 重新连接时仅在主题变化后显示
+label-alert-on-disconnect = 连接断开时闪烁窗口
+label-alert-on-mention = 有人提及你时闪烁窗口
+label-focus-new-messages = 空闲时收到私信自动切换到该标签页
+label-quiet-hours = 免打扰时段（不闪烁窗口）
+label-quiet-hours-from = 从
+label-quiet-hours-to = 到
+label-watched-users-ignore-quiet-hours = 免打扰时段仍通知关注的用户上线
+label-auto-away = 空闲时将我标记为离开
+label-auto-away-minutes = 无操作分钟数
+label-quiet-hours-active = 免打扰
 label-show-timestamps = 显示时间戳
 label-use-24-hour-time = 使用24小时制
 label-show-seconds = 显示秒
+label-timezone = 时区:
+timezone-local = 系统本地
+label-privacy = 隐私
+label-trusted-certificates = 受信任的证书
+label-fingerprint-not-pinned = 尚未固定证书
+label-forget-passwords = 不保存密码（连接时询问）
+label-sync-preferences = 与服务器同步主题和聊天显示设置
+label-check-for-updates = 启动时检查新版客户端
+label-proxy = 代理
+label-use-socks5-proxy = 通过 SOCKS5 代理连接（Tor、I2P）
+label-proxy-address = 地址：
+label-proxy-port = 端口：
+label-diagnostics = 诊断
+label-connection-log-path = 连接日志文件：
+placeholder-connection-log-path = 留空则仅在内存中保留事件
 label-server-name = 名称：
 label-server-description = 描述：
+label-pronouns = 代词：
+label-bio = 简介：
 label-server-version = 版本：
+label-server-features = 功能:
+label-users-online = 在线用户：
 label-chat-topic = 聊天主题：
 label-chat-topic-set-by = 主题设置者：
 label-max-connections-per-ip = 每IP最大连接数：
+label-traffic = 流量：
+server-info-traffic-value = 已发送 { $sent }，已接收 { $received }
 label-avatar = 头像：
 label-details = 技术详情
 label-chat-options = 聊天选项
@@ -87,6 +158,14 @@ label-appearance = 外观
 label-image = 图片
 label-general = 常规
 label-limits = 限制
+label-broadcast-target = 发送给:
+broadcast-target-everyone = 所有人
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = 聊天
 
 # =============================================================================
 # Permission Display Names
@@ -98,6 +177,8 @@ permission-chat_send = 发送聊天
 permission-chat_receive = 接收聊天
 permission-chat_topic = 聊天主题
 permission-chat_topic_edit = 编辑聊天主题
+permission-chat_pin = 聊天置顶
+permission-chat_moderate = 聊天管理
 permission-user_broadcast = 用户广播
 permission-user_create = 创建用户
 permission-user_delete = 删除用户
@@ -116,16 +197,25 @@ tooltip-user-edit = 编辑用户
 tooltip-server-info = 服务器信息
 tooltip-about = 关于
 tooltip-settings = 设置
+tooltip-quiet-hours-active = 免打扰时段中，窗口不会闪烁
 tooltip-hide-bookmarks = 隐藏书签
 tooltip-show-bookmarks = 显示书签
 tooltip-hide-user-list = 隐藏用户列表
 tooltip-show-user-list = 显示用户列表
 tooltip-disconnect = 断开连接
+tooltip-connection-health-unknown = 连接质量尚未测量
+tooltip-connection-health-good = 连接良好（{ $rtt } 毫秒）
+tooltip-connection-health-fair = 连接一般（{ $rtt } 毫秒）
+tooltip-connection-health-poor = 连接较差（{ $rtt } 毫秒）
+tooltip-connection-health-no-response = 服务器无响应
 tooltip-edit = 编辑
 tooltip-info = 信息
 tooltip-message = 消息
 tooltip-kick = 踢出
 tooltip-close = 关闭
+tooltip-pop-out = 在窗口中打开
+tooltip-unpin = 取消置顶
+tooltip-pinned-by = 由 { $username } 置顶
 tooltip-add-bookmark = 添加书签
 
 # =============================================================================
@@ -142,6 +232,7 @@ empty-no-users = 无在线用户
 # =============================================================================
 
 chat-tab-server = #服务器
+chat-tab-server-count = #服务器 ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -156,18 +247,47 @@ chat-prefix-system = [系统]
 chat-prefix-error = [错误]
 chat-prefix-info = [信息]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [公告]
+chat-prefix-pinned = [置顶]
+chat-message-edited = (已编辑)
+chat-message-deleted = (已删除)
+chat-message-removed-by-moderator = (已被管理员删除)
+chat-show-more = 显示更多
+chat-show-less = 收起
+chat-message-encrypted = (已加密)
+chat-message-unreadable = (无法解密的加密消息)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = 用户已成功踢出
+msg-session-kicked-success = 会话已断开
+msg-confirm-clear-fingerprint = 清除 { $name } 的固定证书？下次连接将信任服务器提供的任何证书。
 msg-broadcast-sent = 广播已成功发送
 msg-user-created = 用户已成功创建
 msg-user-deleted = 用户已成功删除
 msg-user-updated = 用户已成功更新
 msg-permissions-updated = 您的权限已更新
 msg-topic-updated = 主题更新成功
+msg-message-pinned = 消息已置顶
+msg-message-unpinned = 已取消置顶消息
+msg-chat-history-purged = 聊天记录已清理: 删除了 { $count } 条消息
+msg-maintenance-enabled = 服务器已进入维护模式，更改已禁用
+msg-maintenance-disabled = 维护模式已结束，可以再次进行更改
+msg-new-login = 你的账户刚刚从 { $address }（{ $source }）登录
+msg-seen-online = { $username } 当前在线
+msg-seen-ago = { $username } 上次在线于 { $duration } 前（{ $time }）
+msg-seen-never = { $username } 尚未上线过
+msg-maintenance-banner = 维护模式：服务器暂时为只读
+msg-admin-notice-reconnect = 管理员通知：请重新连接服务器
+msg-admin-notice-update = 管理员通知：客户端更新可在 { $url } 获取
+msg-update-available = Nexus BBS { $version } 已发布
+msg-admin-notice = 管理员通知：{ $message }
+msg-permissions-refreshed = 权限已刷新
+msg-admin-transferred = { $username } 现在是管理员
+msg-copied-to-clipboard = 已复制到剪贴板
+msg-large-paste-confirm = 此消息包含 { $count } 行粘贴的内容。要发送吗？
 
 # =============================================================================
 # Dynamic Messages (with parameters)
@@ -177,9 +297,19 @@ msg-topic-cleared = { $username } 清除了主题
 msg-topic-set = { $username } 设置了主题：{ $topic }
 msg-server-info-updated = 服务器配置已更新
 msg-topic-display = 主题：{ $topic }
+msg-connected-via-fallback = 已通过备用地址 { $address } 连接
+msg-certificate-change-trusted = 服务器证书已变更，由于此书签信任证书变更，已自动接受（新指纹 { $fingerprint }）
 msg-user-connected = { $username } 已连接
 msg-user-disconnected = { $username } 已断开连接
+msg-watched-user-online = { $username } 已上线
+msg-watched-users-online = 在线的关注用户：{ $usernames }
 msg-disconnected = 已断开连接：{ $error }
+msg-enter-password-for = 请输入 { $name } 的密码
+connect-step-progress = 第 { $step }/{ $total } 步：{ $description }
+connect-step-tcp = 正在连接服务器...
+connect-step-tls = 正在加密连接...
+connect-step-handshake = 正在协商协议...
+connect-step-login = 正在登录...
 msg-connection-cancelled = 由于证书不匹配，连接已取消
 
 # =============================================================================
@@ -187,7 +317,9 @@ msg-connection-cancelled = 由于证书不匹配，连接已取消
 # =============================================================================
 
 err-connection-broken = 连接错误
+err-account-disabled-while-connected = 您的账户已被管理员禁用。重新连接前，请联系管理员重新启用。
 err-failed-update-server-info = 更新服务器信息失败：{ $error }
+err-failed-update-profile = 更新个人资料失败：{ $error }
 err-user-kick-failed = 踢出用户失败
 err-no-shutdown-handle = 连接错误：无关闭句柄
 err-userlist-failed = 刷新用户列表失败
@@ -205,6 +337,9 @@ err-could-not-determine-config-dir = 无法确定配置目录
 err-message-too-long = 消息过长（{ $length }个字符，最多{ $max }个字符）
 err-send-failed = 发送消息失败
 err-no-chat-permission = 您没有发送消息的权限
+err-private-messages-disabled = 此服务器已禁用私信
+err-popout-command = 命令只能在主窗口中使用
+err-multiline-command = 命令必须在一行内（以 // 开头可作为消息发送）
 err-broadcast-too-long = 广播过长（{ $length }个字符，最多{ $max }个字符）
 err-broadcast-send-failed = 发送广播失败
 err-name-required = 书签名称为必填项
@@ -216,6 +351,7 @@ err-message-required = 消息为必填项
 
 # Validation errors
 err-message-empty = 消息不能为空
+err-slow-mode-wait = 慢速模式已启用。请等待{ $seconds }秒
 err-message-contains-newlines = 消息不能包含换行符
 err-message-invalid-characters = 消息包含无效字符
 err-username-empty = 用户名不能为空
@@ -233,6 +369,12 @@ err-server-name-invalid-characters = 服务器名称包含无效字符
 err-server-description-too-long = 描述过长（最多{ $max }个字符）
 err-server-description-contains-newlines = 描述不能包含换行符
 err-server-description-invalid-characters = 描述包含无效字符
+err-bio-too-long = 简介过长（最多 { $max } 个字符）
+err-bio-contains-newlines = 简介不能包含换行符
+err-bio-invalid-characters = 简介包含无效字符
+err-pronouns-too-long = 代词过长（最多 { $max } 个字符）
+err-pronouns-contains-newlines = 代词不能包含换行符
+err-pronouns-invalid-characters = 代词包含无效字符
 err-failed-send-update = 发送更新失败：{ $error }
 
 # =============================================================================
@@ -240,25 +382,68 @@ err-failed-send-update = 发送更新失败：{ $error }
 # =============================================================================
 
 err-failed-save-config = 保存配置失败：{ $error }
+err-connection-log-write = 无法写入连接日志 { $path }：{ $error }
 err-failed-save-settings = 保存设置失败：{ $error }
 err-invalid-port-bookmark = 书签中的端口无效：{ $name }
+err-fallback-address-invalid = 无效的备用地址：{ $address }
 err-failed-send-broadcast = 发送广播失败：{ $error }
 err-failed-send-message = 发送消息失败：{ $error }
+err-command-unsupported = 服务器不支持 /{ $command }
 err-failed-create-user = 创建用户失败：{ $error }
 err-failed-delete-user = 删除用户失败：{ $error }
 err-failed-update-user = 更新用户失败：{ $error }
 err-failed-update-topic = 更新主题失败：{ $error }
+err-failed-pin-message = 置顶消息失败：{ $error }
+err-failed-unpin-message = 取消置顶消息失败：{ $error }
+err-failed-edit-message = 编辑消息失败：{ $error }
+err-failed-delete-message = 删除消息失败：{ $error }
+err-failed-purge-history = 清理聊天记录失败: { $error }
+err-failed-announcement = 更新公告失败: { $error }
+err-failed-stats = 获取连接统计失败: { $error }
+err-failed-history = 加载更早的消息失败: { $error }
+err-art-read = 无法读取 { $path }: { $error }
+err-art-file-too-large = 艺术文件最大为 { $max } KiB
+err-art-empty = 艺术文件中没有可发送的内容
+err-art-fence = 艺术中不能包含 ```(会提前结束区块)
+err-art-first-line = 连第一行都超出了此服务器 { $max } 个字符的限制
+err-failed-page = 页面请求失败: { $error }
+err-failed-set-maintenance = 无法更改维护模式：{ $error }
+err-failed-send-admin-notice = 发送通知失败：{ $error }
+err-notice-url-invalid = 链接必须以 http:// 或 https:// 开头且不能包含空格
+err-failed-refresh-permissions = 刷新权限失败：{ $error }
+err-failed-transfer-admin = 转移管理员失败：{ $error }
+err-failed-sync-preferences = 同步偏好设置失败: { $error }
+err-failed-accept-rules = 接受规则失败：{ $error }
 err-message-too-long-details = { $error }（{ $length }字符，最大{ $max }）
 
 # Network connection errors (with parameters)
 err-invalid-address = 无效地址 '{ $address }'：{ $error }
+err-all-addresses-failed = 无法连接到 { $count } 个地址中的任何一个（最后的错误：{ $error }）
 err-could-not-resolve = 无法解析地址 '{ $address }'
 err-connection-timeout = 连接在 { $seconds } 秒后超时
 err-connection-failed = 连接失败：{ $error }
+err-proxy-address-empty = 代理地址为必填项
+err-proxy-address-invalid = 代理地址必须是不含空格的主机名或 IP 地址
+err-proxy-port-invalid = 代理端口必须介于 1 到 65535 之间
+err-quiet-hours-start-invalid = 免打扰开始时间必须是 24 小时制时间，例如 22:00
+err-quiet-hours-end-invalid = 免打扰结束时间必须是 24 小时制时间，例如 08:00
+err-message-format-unclosed-brace = 消息格式中有未闭合的占位符（要显示花括号本身，请输入两个）
+err-message-format-unknown-placeholder = 消息格式中有未知的占位符 { $placeholder }（可用：{ $available }）
+err-message-format-missing-placeholder = 消息格式必须包含 { $placeholder }
+err-message-format-duplicate-placeholder = 消息格式中 { $placeholder } 只能出现一次
+err-invite-not-a-link = 不是 nexus:// 邀请链接
+err-invite-invalid-address = 邀请链接中的服务器地址无效
+err-invite-invalid-port = 邀请链接中的端口必须介于 1 到 65535 之间
+err-invite-invalid-name = 邀请链接中的服务器名称无效
+err-invite-invalid-fingerprint = 邀请链接中的证书指纹无效
+err-invite-credentials = 邀请链接不能包含用户名或密码
+err-invite-fingerprint-mismatch = 服务器证书与邀请链接不匹配（预期：{ $expected }，收到：{ $received }）
+err-proxy-connection-failed = 无法通过代理 { $proxy } 连接：{ $error }
 err-tls-handshake-failed = TLS握手失败：{ $error }
 err-failed-send-handshake = 发送握手失败：{ $error }
 err-failed-read-handshake = 读取握手响应失败：{ $error }
 err-handshake-failed = 握手失败：{ $error }
+err-client-too-old = 此服务器需要更新版本的 Nexus 客户端。请更新后再连接。（{ $error }）
 err-failed-parse-handshake = 解析握手响应失败：{ $error }
 err-failed-send-login = 发送登录失败：{ $error }
 err-failed-read-login = 读取登录响应失败：{ $error }
@@ -275,6 +460,7 @@ err-failed-set-config-permissions = 设置配置文件权限失败：{ $error }
 # =============================================================================
 
 fingerprint-warning = 这可能表示存在安全问题（中间人攻击）或服务器证书已重新生成。仅在信任服务器管理员时才接受。
+server-rules-intro = 在此服务器上聊天前，您必须接受这些规则。
 
 # =============================================================================
 # User Info Display
@@ -284,15 +470,24 @@ user-info-username = 用户名：
 user-info-role = 角色：
 user-info-role-admin = 管理员
 user-info-role-user = 用户
+user-info-role-guest = 访客
+user-info-pronouns = 代词：
+user-info-bio = 简介：
 user-info-connected = 已连接：
 user-info-connected-value = { $duration }前
 user-info-connected-value-sessions = { $duration }前（{ $count }个会话）
 user-info-features = 功能：
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature }（未启用）
 user-info-features-none = 无
+user-info-permissions = 权限：
+user-info-permissions-none = 无
 user-info-locale = 语言：
 user-info-address = 地址：
 user-info-addresses = 地址：
+user-info-sessions = 会话：
+user-info-session = 会话 { $id }
+user-info-this-session = 当前会话
 user-info-created = 创建时间：
 user-info-end = 用户信息结束
 user-info-unknown = 未知
@@ -317,20 +512,62 @@ cmd-help-desc = 显示可用命令
 cmd-help-escape-hint = 提示：使用 // 发送以 / 开头的消息
 cmd-message-desc = 向用户发送消息
 cmd-message-usage = 用法：/{ $command } <用户名> <消息>
+cmd-query-desc = 打开与用户的消息标签页而不发送
+cmd-query-usage = 用法：/{ $command } <用户名>
+cmd-query-not-online = 用户不在线：{ $username }
+cmd-query-self = 不能给自己发送消息
+cmd-reconnect-desc = 断开并重新连接到此服务器
+cmd-reconnect-usage = 用法：/{ $command }
+cmd-reconnect-no-bookmark = /{ $command } 需要书签才能重新连接；此连接不是通过书签建立的
 cmd-userinfo-desc = 显示用户信息
 cmd-userinfo-usage = 用法：/{ $command } <用户名>
+cmd-invite-desc = 复制此服务器的邀请链接
+cmd-invite-usage = 用法：/{ $command }
+cmd-invite-copied = 邀请链接已复制到剪贴板：{ $link }
 cmd-kick-desc = 将用户踢出服务器
 cmd-kick-usage = 用法：/{ $command } <用户名>
+cmd-limits-desc = 查看或设置消息和主题的长度限制
+cmd-limits-usage = 用法：/{ $command } [消息 <字符数>|主题 <字符数>]
+cmd-limits-arg-message = 消息
+cmd-limits-arg-topic = 主题
+cmd-limits-current = 限制：消息最多 { $message } 个字符，主题最多 { $topic } 个字符
 cmd-topic-desc = 查看或管理聊天主题
 cmd-topic-usage = 用法：/{ $command } [设置|清除] [主题]
 cmd-topic-arg-set = 设置
 cmd-topic-arg-clear = 清除
 cmd-topic-set-usage = 用法：/{ $command } 设置 <主题>
 cmd-topic-none = 未设置主题
+cmd-announce-desc = 查看或管理服务器的轮播公告
+cmd-announce-usage = 用法：/{ $command } [添加 <消息>|删除 <编号>]
+cmd-announce-arg-add = 添加
+cmd-announce-arg-remove = 删除
+cmd-announce-list = 公告（每 { $minutes } 分钟）：
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = 未设置公告
+cmd-announce-added = 已添加公告
+cmd-announce-removed = 已删除公告
+cmd-art-desc = 将文件中的 ASCII 或 ANSI 艺术作为等宽块发送
+cmd-art-usage = 用法: /{ $command } [截断] <文件>
+cmd-art-arg-truncate = 截断
+cmd-art-too-long = 该艺术有 { $length } 个字符,超过了此服务器 { $max } 的限制。使用 /{ $command } { $truncate } <文件> 发送能容纳的行
 cmd-broadcast-desc = 向所有用户发送广播
 cmd-broadcast-usage = 用法：/{ $command } <消息>
 cmd-clear-desc = 清除当前标签页的聊天记录
 cmd-clear-usage = 用法：/{ $command }
+cmd-delete-desc = 删除您的最新消息（版主：某个用户的最新消息）
+cmd-delete-usage = 用法：/{ $command } [用户名]
+cmd-delete-no-message = 没有 { $username } 的最近消息可删除
+cmd-delete-permission-denied = 您没有删除其他用户消息的权限
+cmd-edit-desc = 编辑您的最新消息
+cmd-edit-usage = 用法：/{ $command } <消息>
+cmd-edit-no-message = 您没有可编辑的最近消息
+cmd-events-desc = 显示此连接的生命周期事件
+cmd-events-usage = 用法：/{ $command }
+cmd-events-header = 连接事件（从旧到新）：
+cmd-events-empty = 尚未记录任何连接事件
+cmd-report-desc = 复制已脱敏的客户端和连接信息，用于错误报告
+cmd-report-usage = 用法：/{ $command }
+cmd-report-copied = 已将错误报告信息复制到剪贴板。已排除：密码、服务器地址、用户名、证书指纹、服务器名称和代理地址
 cmd-focus-desc = 聚焦到服务器聊天或用户消息窗口
 cmd-focus-usage = 用法：/{ $command } [用户名]
 cmd-focus-not-found = 未找到用户：{ $name }
@@ -339,22 +576,90 @@ cmd-list-arg-all = 所有
 cmd-list-usage = 用法：/{ $command } [所有]
 cmd-list-empty = 没有已连接的用户
 cmd-list-output = 在线用户：{ $users }（{ $count }位用户）
+cmd-maintenance-desc = 查看或切换维护（只读）模式
+cmd-maintenance-usage = 用法：/{ $command } [开启|关闭]
+cmd-maintenance-arg-on = 开启
+cmd-maintenance-arg-off = 关闭
+cmd-maintenance-on = 维护模式已开启
+cmd-maintenance-off = 维护模式已关闭
+cmd-notice-desc = 以横幅向所有人显示通知
+cmd-notice-usage = 用法：/{ $command } reconnect | update <url> | <消息>
+cmd-notify-desc = 查看或管理上线时通知你的用户
+cmd-notify-usage = 用法：/{ $command } [添加 <用户名>|删除 <用户名>]
+cmd-notify-arg-add = 添加
+cmd-notify-arg-del = 删除
+cmd-notify-no-bookmark = 关注列表保存在书签中，因此 /notify 仅适用于已加入书签的服务器
+cmd-notify-none = 你在此服务器上没有关注任何人
+cmd-notify-list = 已关注：{ $usernames }
+cmd-notify-added = { $username } 上线时会通知你
+cmd-notify-already = { $username } 已在你的关注列表中
+cmd-notify-removed = 已取消关注 { $username }
+cmd-notify-not-watched = { $username } 不在你的关注列表中
 cmd-list-all-no-permission = 您需要 user_edit 或 user_delete 权限才能列出所有用户
 cmd-list-all-output = 用户：{ $users }（{ $count }位用户）
+cmd-whoami-desc = 显示你的用户名、角色、权限和功能
+cmd-whoami-usage = 用法：/{ $command }
 cmd-help-usage = 用法：/{ $command } [命令]
 cmd-topic-permission-denied = 您没有编辑主题的权限
+cmd-page-desc = 列出或阅读服务器的信息页面（管理员：设置或删除）
+cmd-page-usage = 用法：/{ $command } [<名称>|设置 <名称> <文本>|删除 <名称>]（\n 表示换行）
+cmd-page-arg-set = 设置
+cmd-page-arg-delete = 删除
+cmd-page-list = 页面：{ $pages }
+cmd-page-none = 此服务器没有页面
+cmd-page-saved = 页面已保存
+cmd-page-deleted = 页面已删除
+cmd-pin-desc = 列出置顶消息或置顶某用户的最新消息
+cmd-pin-usage = 用法：/{ $command } [用户名]
+cmd-pin-list = 置顶消息：
+cmd-pin-list-item = { $number }. { $username }：{ $message }（由 { $pinned_by } 置顶）
+cmd-pin-none = 没有置顶消息
+cmd-pin-no-message = 没有 { $username } 的近期消息可置顶
+cmd-purge-desc = 清理超出服务器保留策略的聊天记录
+cmd-purge-usage = 用法：/{ $command }
+cmd-refresh-desc = 从服务器数据库重新发送用户权限
+cmd-refresh-usage = 用法：/{ $command } <用户名>
+cmd-transfer-desc = 将另一个账户设为管理员，除非加上“保留”，否则自己卸任
+cmd-transfer-usage = 用法：/{ $command } <用户名> [保留]
+cmd-transfer-arg-keep = 保留
+cmd-seen-desc = 显示用户上次在线的时间
+cmd-seen-usage = 用法：/{ $command } <用户名>
+cmd-session-desc = 显示此连接的会话和流量
+cmd-session-usage = 用法：/{ $command }
+cmd-session-info = 会话 { $id }，已连接到 { $address }
+cmd-session-traffic = 连接以来的流量：已发送 { $sent }，已接收 { $received }
+cmd-unpin-desc = 取消置顶消息
+cmd-unpin-usage = 用法：/{ $command } <编号>（编号见 /pin）
 cmd-window-desc = 管理聊天标签页
-cmd-window-usage = 用法：/{ $command } [下一个|上一个|关闭 [用户名]]
+cmd-window-usage = 用法：/{ $command } [下一个|上一个|关闭 [用户名]|弹出 [用户名]]
 cmd-window-arg-next = 下一个
 cmd-window-arg-prev = 上一个
 cmd-window-arg-close = 关闭
+cmd-window-arg-popout = 弹出
 cmd-window-list = 打开的标签页：{ $tabs }（{ $count }个标签页）
 cmd-window-close-server = 无法关闭服务器标签页
+cmd-window-popout-server = 无法弹出服务器标签页
 cmd-window-not-found = 未找到标签页：{ $name }
 cmd-serverinfo-desc = 显示服务器信息
 cmd-serverinfo-usage = 用法：/{ $command }
 cmd-serverinfo-header = [服务器]
 cmd-serverinfo-end = 服务器信息结束
+cmd-slow-desc = 查看或设置聊天慢速模式
+cmd-slow-usage = 用法：/{ $command } [秒]
+cmd-slow-off = 慢速模式已关闭
+cmd-slow-current = 慢速模式：消息间隔{ $seconds }秒
+cmd-stats-desc = 显示服务器最近一小时的连接历史
+cmd-stats-usage = 用法: /{ $command } [重置]
+cmd-stats-arg-reset = 重置
+cmd-stats-current = 当前打开的连接: { $count }
+cmd-stats-total = 最近 { $minutes } 分钟: { $connections } 个连接,{ $refused } 个被拒绝,峰值 { $peak } 个打开(单个地址 { $peak_per_ip } 个)
+cmd-stats-group = { $time }: { $connections } 个连接,{ $refused } 个被拒绝,峰值 { $peak }
+cmd-stats-none = 尚未记录连接历史
+cmd-set-desc = 查看或更改此服务器的聊天偏好
+cmd-set-usage = 用法：/{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = 此服务器显示进入/离开消息
+cmd-set-joinleave-off = 此服务器隐藏进入/离开消息
+cmd-set-joinleave-default = 此服务器上的进出消息遵循全局设置
 
 # =============================================================================
 # About Panel
@@ -364,6 +669,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = 选择图片
 button-clear-image = 清除
+button-clear-theme = 清除
+button-copy-fingerprint = 复制
+button-clear-fingerprint = 清除
+button-dismiss = 关闭
+button-reconnect = 重新连接
+button-open-link = 打开链接
 label-server-image = 服务器图片:
 err-server-image-too-large = 服务器图片太大（最大512KB）
 err-server-image-invalid-format = 服务器图片格式无效（必须是base64编码的数据URI）
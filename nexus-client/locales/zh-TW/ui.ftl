@@ -5,14 +5,20 @@
 # =============================================================================
 
 button-cancel = 取消
+button-continue = 繼續
 button-send = 傳送
+button-send-as-one-message = 作為一則訊息傳送
 button-delete = 刪除
 button-connect = 連線
 button-save = 儲存
 button-create = 建立
 button-edit = 編輯
+button-edit-profile = 編輯個人資料
 button-update = 更新
 button-accept-new-certificate = 接受新憑證
+button-always-accept-certificate = 一律接受
+button-accept-rules = 接受規則
+button-disconnect = 中斷連線
 button-close = 關閉
 button-choose-avatar = 選擇頭像
 button-clear-avatar = 清除
@@ -22,6 +28,8 @@ button-clear-avatar = 清除
 # =============================================================================
 
 title-nexus-bbs = Nexus BBS
+popout-title = { $username } - { $server }
+popout-title-unread = * { $username } - { $server }
 title-connect-to-server = 連線至伺服器
 title-add-bookmark = 新增書籤
 title-edit-server = 編輯伺服器
@@ -34,7 +42,10 @@ title-settings = 設定
 title-bookmarks = 書籤
 title-users = 使用者
 title-edit-server-info = 編輯伺服器資訊
+title-edit-profile = 編輯個人資料
 title-fingerprint-mismatch = 憑證指紋不符！
+title-server-rules = 伺服器規則
+title-server-banner = 伺服器通知
 title-server-info = 伺服器資訊
 title-user-info = 使用者資訊
 title-about = 關於
@@ -46,39 +57,99 @@ title-about = 關於
 placeholder-username = 使用者名稱
 placeholder-password = 密碼
 placeholder-port = 連接埠
+placeholder-fallback-addresses = 備用位址（選填，以逗號分隔的 host:port）
+placeholder-watched-users = 上線時通知（選填，以逗號分隔使用者名稱）
 placeholder-server-address = 伺服器位址
 placeholder-server-name = 伺服器名稱
 placeholder-username-optional = 使用者名稱（選填）
 placeholder-password-optional = 密碼（選填）
 placeholder-password-keep-current = 密碼（留空保持目前密碼）
 placeholder-message = 輸入訊息...
+placeholder-message-slow-mode = 慢速模式：請等待{ $seconds }秒...
+placeholder-message-maintenance = 維護模式：輸入已停用
 placeholder-no-permission = 無權限
 placeholder-broadcast-message = 輸入廣播訊息...
 placeholder-server-description = 伺服器描述
+placeholder-pronouns = 例如 they/them
+placeholder-bio = 一句簡短的自我介紹
+placeholder-global-theme = 使用全域主題
 
 # =============================================================================
 # Labels
 # =============================================================================
 
 label-auto-connect = 自動連線
+label-trust-certificate-changes = 一律接受此伺服器的憑證變更（安全性較低）
 label-add-bookmark = 新增書籤
+label-invite-fingerprint = 邀請連結中的憑證指紋：{ $fingerprint }
 label-admin = 管理員
 label-enabled = 啟用
 label-permissions = 權限：
 label-expected-fingerprint = 預期指紋：
 label-received-fingerprint = 收到的指紋：
 label-theme = 主題
+label-density = 顯示密度：
+density-comfortable = 舒適
+density-compact = 緊湊
+label-show-role-badges = 在使用者清單中顯示管理員和版主徽章
+badge-admin = 管理員
+badge-moderator = 版主
+badge-guest = 訪客
+user-list-permissions = 權限：{ $permissions }
+user-list-away = 離開
 label-chat-font-size = 字型大小：
+label-max-message-tabs = 訊息分頁上限：
+label-paste-confirm-lines = 貼上達到此行數時確認（0 = 從不）：
+label-collapse-message-lines = 摺疊超過此行數的訊息（0 = 從不）：
+label-ctrl-enter-sends = 使用 Ctrl+Enter 傳送（Enter 換行）
+label-message-format = 訊息格式：
+message-format-preset-default = 預設
+message-format-preset-compact = 精簡
+message-format-preset-irc = IRC 風格
+message-format-preset-verbose = 詳細（含日期）
+message-format-preset-custom = 自訂
 label-show-connection-notifications = 顯示連線通知
+label-hide-repeated-topic = 重新連線時僅在主題變更後顯示
+label-alert-on-disconnect = 連線中斷時閃爍視窗
+label-alert-on-mention = 有人提及你時閃爍視窗
+label-focus-new-messages = 閒置時收到私訊自動切換到該分頁
+label-quiet-hours = 勿擾時段（不閃爍視窗）
+label-quiet-hours-from = 從
+label-quiet-hours-to = 到
+label-watched-users-ignore-quiet-hours = 勿擾時段仍通知關注的使用者上線
+label-auto-away = 閒置時將我標記為離開
+label-auto-away-minutes = 無操作分鐘數
+label-quiet-hours-active = 勿擾
 label-show-timestamps = 顯示時間戳記
 label-use-24-hour-time = 使用24小時制
 label-show-seconds = 顯示秒數
+label-timezone = 時區:
+timezone-local = 系統本地
+label-privacy = 隱私
+label-trusted-certificates = 受信任的憑證
+label-fingerprint-not-pinned = 尚未固定憑證
+label-forget-passwords = 不儲存密碼（連線時詢問）
+label-sync-preferences = 與伺服器同步主題和聊天顯示設定
+label-check-for-updates = 啟動時檢查新版用戶端
+label-proxy = 代理
+label-use-socks5-proxy = 透過 SOCKS5 代理連線（Tor、I2P）
+label-proxy-address = 位址：
+label-proxy-port = 連接埠：
+label-diagnostics = 診斷
+label-connection-log-path = 連線記錄檔：
+placeholder-connection-log-path = 留空則僅在記憶體中保留事件
 label-server-name = 名稱：
 label-server-description = 描述：
+label-pronouns = 代名詞：
+label-bio = 簡介：
 label-server-version = 版本：
+label-server-features = 功能:
+label-users-online = 線上使用者：
 label-chat-topic = 聊天主題：
 label-chat-topic-set-by = 主題設定者：
 label-max-connections-per-ip = 每IP最大連線數：
+label-traffic = 流量：
+server-info-traffic-value = 已傳送 { $sent }，已接收 { $received }
 label-avatar = 頭像：
 label-details = 技術詳情
 label-chat-options = 聊天選項
@@ -86,6 +157,14 @@ label-appearance = 外觀
 label-image = 圖片
 label-general = 一般
 label-limits = 限制
+label-broadcast-target = 傳送給:
+broadcast-target-everyone = 所有人
+
+# =============================================================================
+# Feature Display Names
+# =============================================================================
+
+feature-chat = 聊天
 
 # =============================================================================
 # Permission Display Names
@@ -97,6 +176,8 @@ permission-chat_send = 傳送聊天
 permission-chat_receive = 接收聊天
 permission-chat_topic = 聊天主題
 permission-chat_topic_edit = 編輯聊天主題
+permission-chat_pin = 聊天置頂
+permission-chat_moderate = 聊天管理
 permission-user_broadcast = 使用者廣播
 permission-user_create = 建立使用者
 permission-user_delete = 刪除使用者
@@ -115,16 +196,25 @@ tooltip-user-edit = 編輯用戶
 tooltip-server-info = 伺服器資訊
 tooltip-about = 關於
 tooltip-settings = 設定
+tooltip-quiet-hours-active = 勿擾時段中，視窗不會閃爍
 tooltip-hide-bookmarks = 隱藏書籤
 tooltip-show-bookmarks = 顯示書籤
 tooltip-hide-user-list = 隱藏使用者清單
 tooltip-show-user-list = 顯示使用者清單
 tooltip-disconnect = 中斷連線
+tooltip-connection-health-unknown = 連線品質尚未測量
+tooltip-connection-health-good = 連線良好（{ $rtt } 毫秒）
+tooltip-connection-health-fair = 連線普通（{ $rtt } 毫秒）
+tooltip-connection-health-poor = 連線不佳（{ $rtt } 毫秒）
+tooltip-connection-health-no-response = 伺服器無回應
 tooltip-edit = 編輯
 tooltip-info = 資訊
 tooltip-message = 訊息
 tooltip-kick = 踢出
 tooltip-close = 關閉
+tooltip-pop-out = 在視窗中開啟
+tooltip-unpin = 取消置頂
+tooltip-pinned-by = 由 { $username } 置頂
 tooltip-add-bookmark = 新增書籤
 
 # =============================================================================
@@ -141,6 +231,7 @@ empty-no-users = 無線上使用者
 # =============================================================================
 
 chat-tab-server = #伺服器
+chat-tab-server-count = #伺服器 ({ $count })
 
 # =============================================================================
 # System Message Usernames
@@ -155,18 +246,47 @@ chat-prefix-system = [系統]
 chat-prefix-error = [錯誤]
 chat-prefix-info = [資訊]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-announcement = [公告]
+chat-prefix-pinned = [置頂]
+chat-message-edited = (已編輯)
+chat-message-deleted = (已刪除)
+chat-message-removed-by-moderator = (已被管理員刪除)
+chat-show-more = 顯示更多
+chat-show-less = 收合
+chat-message-encrypted = (已加密)
+chat-message-unreadable = (無法解密的加密訊息)
 
 # =============================================================================
 # Success Messages
 # =============================================================================
 
 msg-user-kicked-success = 使用者已成功踢除
+msg-session-kicked-success = 工作階段已中斷
+msg-confirm-clear-fingerprint = 清除 { $name } 的固定憑證？下次連線將信任伺服器提供的任何憑證。
 msg-broadcast-sent = 廣播已成功傳送
 msg-user-created = 使用者已成功建立
 msg-user-deleted = 使用者已成功刪除
 msg-user-updated = 使用者更新成功
 msg-permissions-updated = 您的權限已更新
 msg-topic-updated = 主題更新成功
+msg-message-pinned = 訊息已置頂
+msg-message-unpinned = 已取消置頂訊息
+msg-chat-history-purged = 聊天記錄已清理: 刪除了 { $count } 則訊息
+msg-maintenance-enabled = 伺服器已進入維護模式，變更已停用
+msg-maintenance-disabled = 維護模式已結束，可以再次進行變更
+msg-new-login = 你的帳號剛剛從 { $address }（{ $source }）登入
+msg-seen-online = { $username } 目前在線上
+msg-seen-ago = { $username } 上次上線於 { $duration } 前（{ $time }）
+msg-seen-never = { $username } 尚未上線過
+msg-maintenance-banner = 維護模式：伺服器暫時為唯讀
+msg-admin-notice-reconnect = 管理員通知：請重新連線伺服器
+msg-admin-notice-update = 管理員通知：用戶端更新可在 { $url } 取得
+msg-update-available = Nexus BBS { $version } 已發布
+msg-admin-notice = 管理員通知：{ $message }
+msg-permissions-refreshed = 權限已重新整理
+msg-admin-transferred = { $username } 現在是管理員
+msg-copied-to-clipboard = 已複製到剪貼簿
+msg-large-paste-confirm = 此訊息包含 { $count } 行貼上的內容。要傳送嗎？
 
 # =============================================================================
 # Dynamic Messages (with parameters)
@@ -176,9 +296,19 @@ msg-topic-cleared = { $username } 清除了主題
 msg-topic-set = { $username } 設定了主題：{ $topic }
 msg-server-info-updated = 伺服器設定已更新
 msg-topic-display = 主題：{ $topic }
+msg-connected-via-fallback = 已透過備用位址 { $address } 連線
+msg-certificate-change-trusted = 伺服器憑證已變更，由於此書籤信任憑證變更，已自動接受（新指紋 { $fingerprint }）
 msg-user-connected = { $username } 已連線
 msg-user-disconnected = { $username } 已中斷連線
+msg-watched-user-online = { $username } 已上線
+msg-watched-users-online = 在線的關注使用者：{ $usernames }
 msg-disconnected = 已中斷連線：{ $error }
+msg-enter-password-for = 請輸入 { $name } 的密碼
+connect-step-progress = 第 { $step }/{ $total } 步：{ $description }
+connect-step-tcp = 正在連線至伺服器...
+connect-step-tls = 正在加密連線...
+connect-step-handshake = 正在協商協定...
+connect-step-login = 正在登入...
 msg-connection-cancelled = 由於憑證不符，連線已取消
 
 # =============================================================================
@@ -186,7 +316,9 @@ msg-connection-cancelled = 由於憑證不符，連線已取消
 # =============================================================================
 
 err-connection-broken = 連線錯誤
+err-account-disabled-while-connected = 您的帳號已被管理員停用。重新連線前，請聯絡管理員重新啟用。
 err-failed-update-server-info = 更新伺服器資訊失敗：{ $error }
+err-failed-update-profile = 更新個人資料失敗：{ $error }
 err-user-kick-failed = 踢除使用者失敗
 err-no-shutdown-handle = 連線錯誤：無關閉控制代碼
 err-userlist-failed = 重新整理使用者清單失敗
@@ -204,6 +336,9 @@ err-could-not-determine-config-dir = 無法確定設定目錄
 err-message-too-long = 訊息過長（{ $length }個字元，最多{ $max }個字元）
 err-send-failed = 傳送訊息失敗
 err-no-chat-permission = 您沒有傳送訊息的權限
+err-private-messages-disabled = 此伺服器已停用私人訊息
+err-popout-command = 指令只能在主視窗中使用
+err-multiline-command = 指令必須在一行內（以 // 開頭可作為訊息傳送）
 err-broadcast-too-long = 廣播過長（{ $length }個字元，最多{ $max }個字元）
 err-broadcast-send-failed = 傳送廣播失敗
 err-name-required = 書籤名稱為必填
@@ -215,6 +350,7 @@ err-message-required = 訊息為必填
 
 # Validation errors
 err-message-empty = 訊息不能為空
+err-slow-mode-wait = 慢速模式已啟用。請等待{ $seconds }秒
 err-message-contains-newlines = 訊息不能包含換行符
 err-message-invalid-characters = 訊息包含無效字元
 err-username-empty = 使用者名稱不能為空
@@ -232,6 +368,12 @@ err-server-name-invalid-characters = 伺服器名稱包含無效字元
 err-server-description-too-long = 描述過長（最多{ $max }個字元）
 err-server-description-contains-newlines = 描述不能包含換行符
 err-server-description-invalid-characters = 描述包含無效字元
+err-bio-too-long = 簡介過長（最多 { $max } 個字元）
+err-bio-contains-newlines = 簡介不能包含換行符號
+err-bio-invalid-characters = 簡介包含無效字元
+err-pronouns-too-long = 代名詞過長（最多 { $max } 個字元）
+err-pronouns-contains-newlines = 代名詞不能包含換行符號
+err-pronouns-invalid-characters = 代名詞包含無效字元
 err-failed-send-update = 傳送更新失敗：{ $error }
 
 # =============================================================================
@@ -239,25 +381,68 @@ err-failed-send-update = 傳送更新失敗：{ $error }
 # =============================================================================
 
 err-failed-save-config = 儲存設定失敗：{ $error }
+err-connection-log-write = 無法寫入連線記錄 { $path }：{ $error }
 err-failed-save-settings = 儲存設定失敗：{ $error }
 err-invalid-port-bookmark = 書籤中的連接埠無效：{ $name }
+err-fallback-address-invalid = 無效的備用位址：{ $address }
 err-failed-send-broadcast = 傳送廣播失敗：{ $error }
 err-failed-send-message = 傳送訊息失敗：{ $error }
+err-command-unsupported = 伺服器不支援 /{ $command }
 err-failed-create-user = 建立使用者失敗：{ $error }
 err-failed-delete-user = 刪除使用者失敗：{ $error }
 err-failed-update-user = 更新使用者失敗：{ $error }
 err-failed-update-topic = 更新主題失敗：{ $error }
+err-failed-pin-message = 置頂訊息失敗：{ $error }
+err-failed-unpin-message = 取消置頂訊息失敗：{ $error }
+err-failed-edit-message = 編輯訊息失敗：{ $error }
+err-failed-delete-message = 刪除訊息失敗：{ $error }
+err-failed-purge-history = 清理聊天記錄失敗: { $error }
+err-failed-announcement = 更新公告失敗: { $error }
+err-failed-stats = 取得連線統計失敗: { $error }
+err-failed-history = 載入較早的訊息失敗: { $error }
+err-art-read = 無法讀取 { $path }: { $error }
+err-art-file-too-large = 藝術檔案最大為 { $max } KiB
+err-art-empty = 藝術檔案中沒有可傳送的內容
+err-art-fence = 藝術中不能包含 ```(會提前結束區塊)
+err-art-first-line = 連第一行都超出此伺服器 { $max } 個字元的限制
+err-failed-page = 頁面請求失敗: { $error }
+err-failed-set-maintenance = 無法變更維護模式：{ $error }
+err-failed-send-admin-notice = 傳送通知失敗：{ $error }
+err-notice-url-invalid = 連結必須以 http:// 或 https:// 開頭且不能包含空格
+err-failed-refresh-permissions = 重新整理權限失敗：{ $error }
+err-failed-transfer-admin = 轉移管理員失敗：{ $error }
+err-failed-sync-preferences = 同步偏好設定失敗: { $error }
+err-failed-accept-rules = 接受規則失敗：{ $error }
 err-message-too-long-details = { $error }（{ $length }字元，最大{ $max }）
 
 # Network connection errors (with parameters)
 err-invalid-address = 無效位址 '{ $address }'：{ $error }
+err-all-addresses-failed = 無法連線到 { $count } 個位址中的任何一個（最後的錯誤：{ $error }）
 err-could-not-resolve = 無法解析位址 '{ $address }'
 err-connection-timeout = 連線在 { $seconds } 秒後逾時
 err-connection-failed = 連線失敗：{ $error }
+err-proxy-address-empty = 代理位址為必填
+err-proxy-address-invalid = 代理位址必須是不含空格的主機名稱或 IP 位址
+err-proxy-port-invalid = 代理連接埠必須介於 1 到 65535 之間
+err-quiet-hours-start-invalid = 勿擾開始時間必須是 24 小時制時間，例如 22:00
+err-quiet-hours-end-invalid = 勿擾結束時間必須是 24 小時制時間，例如 08:00
+err-message-format-unclosed-brace = 訊息格式中有未關閉的預留位置（要顯示大括號本身，請輸入兩個）
+err-message-format-unknown-placeholder = 訊息格式中有未知的預留位置 { $placeholder }（可用：{ $available }）
+err-message-format-missing-placeholder = 訊息格式必須包含 { $placeholder }
+err-message-format-duplicate-placeholder = 訊息格式中 { $placeholder } 只能出現一次
+err-invite-not-a-link = 不是 nexus:// 邀請連結
+err-invite-invalid-address = 邀請連結中的伺服器位址無效
+err-invite-invalid-port = 邀請連結中的連接埠必須介於 1 到 65535 之間
+err-invite-invalid-name = 邀請連結中的伺服器名稱無效
+err-invite-invalid-fingerprint = 邀請連結中的憑證指紋無效
+err-invite-credentials = 邀請連結不能包含用戶名或密碼
+err-invite-fingerprint-mismatch = 伺服器憑證與邀請連結不符（預期：{ $expected }，收到：{ $received }）
+err-proxy-connection-failed = 無法透過代理 { $proxy } 連線：{ $error }
 err-tls-handshake-failed = TLS握手失敗：{ $error }
 err-failed-send-handshake = 傳送握手失敗：{ $error }
 err-failed-read-handshake = 讀取握手回應失敗：{ $error }
 err-handshake-failed = 握手失敗：{ $error }
+err-client-too-old = 此伺服器需要較新版本的 Nexus 用戶端。請更新後再連線。（{ $error }）
 err-failed-parse-handshake = 解析握手回應失敗：{ $error }
 err-failed-send-login = 傳送登入失敗：{ $error }
 err-failed-read-login = 讀取登入回應失敗：{ $error }
@@ -274,6 +459,7 @@ err-failed-set-config-permissions = 設定設定檔權限失敗：{ $error }
 # =============================================================================
 
 fingerprint-warning = 這可能表示存在安全問題（中間人攻擊）或伺服器憑證已重新產生。僅在信任伺服器管理員時才接受。
+server-rules-intro = 在此伺服器上聊天前，您必須接受這些規則。
 
 # =============================================================================
 # User Info Display
@@ -283,15 +469,24 @@ user-info-username = 使用者名稱：
 user-info-role = 角色：
 user-info-role-admin = 管理員
 user-info-role-user = 使用者
+user-info-role-guest = 訪客
+user-info-pronouns = 代名詞：
+user-info-bio = 簡介：
 user-info-connected = 已連線：
 user-info-connected-value = { $duration }前
 user-info-connected-value-sessions = { $duration }前（{ $count }個工作階段）
 user-info-features = 功能：
 user-info-features-value = { $features }
+user-info-feature-inactive = { $feature }（未啟用）
 user-info-features-none = 無
+user-info-permissions = 權限：
+user-info-permissions-none = 無
 user-info-locale = 語言：
 user-info-address = 位址：
 user-info-addresses = 位址：
+user-info-sessions = 工作階段：
+user-info-session = 工作階段 { $id }
+user-info-this-session = 目前工作階段
 user-info-created = 建立時間：
 user-info-end = 使用者資訊結束
 user-info-unknown = 未知
@@ -316,20 +511,62 @@ cmd-help-desc = 顯示可用指令
 cmd-help-escape-hint = 提示：使用 // 發送以 / 開頭的訊息
 cmd-message-desc = 發送訊息給用戶
 cmd-message-usage = 用法：/{ $command } <用戶名> <訊息>
+cmd-query-desc = 開啟與使用者的訊息分頁而不傳送
+cmd-query-usage = 用法：/{ $command } <使用者名稱>
+cmd-query-not-online = 使用者不在線上：{ $username }
+cmd-query-self = 不能傳送訊息給自己
+cmd-reconnect-desc = 中斷並重新連線到此伺服器
+cmd-reconnect-usage = 用法：/{ $command }
+cmd-reconnect-no-bookmark = /{ $command } 需要書籤才能重新連線；此連線不是透過書籤建立的
 cmd-userinfo-desc = 顯示用戶資訊
 cmd-userinfo-usage = 用法：/{ $command } <用戶名>
+cmd-invite-desc = 複製此伺服器的邀請連結
+cmd-invite-usage = 用法：/{ $command }
+cmd-invite-copied = 邀請連結已複製到剪貼簿：{ $link }
 cmd-kick-desc = 將用戶踢出伺服器
 cmd-kick-usage = 用法：/{ $command } <用戶名>
+cmd-limits-desc = 檢視或設定訊息與主題的長度限制
+cmd-limits-usage = 用法：/{ $command } [訊息 <字元數>|主題 <字元數>]
+cmd-limits-arg-message = 訊息
+cmd-limits-arg-topic = 主題
+cmd-limits-current = 限制：訊息最多 { $message } 個字元，主題最多 { $topic } 個字元
 cmd-topic-desc = 查看或管理聊天主題
 cmd-topic-usage = 用法：/{ $command } [設定|清除] [主題]
 cmd-topic-arg-set = 設定
 cmd-topic-arg-clear = 清除
 cmd-topic-set-usage = 用法：/{ $command } 設定 <主題>
 cmd-topic-none = 未設定主題
+cmd-announce-desc = 檢視或管理伺服器的輪播公告
+cmd-announce-usage = 用法：/{ $command } [新增 <訊息>|刪除 <編號>]
+cmd-announce-arg-add = 新增
+cmd-announce-arg-remove = 刪除
+cmd-announce-list = 公告（每 { $minutes } 分鐘）：
+cmd-announce-list-item = { $number }. { $message }
+cmd-announce-none = 未設定公告
+cmd-announce-added = 已新增公告
+cmd-announce-removed = 已刪除公告
+cmd-art-desc = 將檔案中的 ASCII 或 ANSI 藝術以等寬區塊傳送
+cmd-art-usage = 用法: /{ $command } [截斷] <檔案>
+cmd-art-arg-truncate = 截斷
+cmd-art-too-long = 該藝術有 { $length } 個字元,超過此伺服器 { $max } 的限制。使用 /{ $command } { $truncate } <檔案> 傳送能容納的行
 cmd-broadcast-desc = 向所有用戶發送廣播
 cmd-broadcast-usage = 用法：/{ $command } <訊息>
 cmd-clear-desc = 清除當前分頁的聊天記錄
 cmd-clear-usage = 用法：/{ $command }
+cmd-delete-desc = 刪除您的最新訊息（版主：某位使用者的最新訊息）
+cmd-delete-usage = 用法：/{ $command } [使用者名稱]
+cmd-delete-no-message = 沒有 { $username } 的最近訊息可刪除
+cmd-delete-permission-denied = 您沒有刪除其他使用者訊息的權限
+cmd-edit-desc = 編輯您的最新訊息
+cmd-edit-usage = 用法：/{ $command } <訊息>
+cmd-edit-no-message = 您沒有可編輯的最近訊息
+cmd-events-desc = 顯示此連線的生命週期事件
+cmd-events-usage = 用法：/{ $command }
+cmd-events-header = 連線事件（由舊到新）：
+cmd-events-empty = 尚未記錄任何連線事件
+cmd-report-desc = 複製已去除敏感資訊的用戶端與連線資訊，用於錯誤回報
+cmd-report-usage = 用法：/{ $command }
+cmd-report-copied = 已將錯誤回報資訊複製到剪貼簿。已排除：密碼、伺服器位址、使用者名稱、憑證指紋、伺服器名稱與代理位址
 cmd-focus-desc = 聚焦到伺服器聊天或用戶訊息視窗
 cmd-focus-usage = 用法：/{ $command } [用戶名]
 cmd-focus-not-found = 找不到用戶：{ $name }
@@ -338,22 +575,90 @@ cmd-list-arg-all = 所有
 cmd-list-usage = 用法：/{ $command } [所有]
 cmd-list-empty = 沒有已連線的用戶
 cmd-list-output = 線上用戶：{ $users }（{ $count }位用戶）
+cmd-maintenance-desc = 檢視或切換維護（唯讀）模式
+cmd-maintenance-usage = 用法：/{ $command } [開啟|關閉]
+cmd-maintenance-arg-on = 開啟
+cmd-maintenance-arg-off = 關閉
+cmd-maintenance-on = 維護模式已開啟
+cmd-maintenance-off = 維護模式已關閉
+cmd-notice-desc = 以橫幅向所有人顯示通知
+cmd-notice-usage = 用法：/{ $command } reconnect | update <url> | <訊息>
+cmd-notify-desc = 檢視或管理上線時通知你的使用者
+cmd-notify-usage = 用法：/{ $command } [新增 <使用者名稱>|刪除 <使用者名稱>]
+cmd-notify-arg-add = 新增
+cmd-notify-arg-del = 刪除
+cmd-notify-no-bookmark = 關注清單儲存在書籤中，因此 /notify 僅適用於已加入書籤的伺服器
+cmd-notify-none = 你在此伺服器上沒有關注任何人
+cmd-notify-list = 已關注：{ $usernames }
+cmd-notify-added = { $username } 上線時會通知你
+cmd-notify-already = { $username } 已在你的關注清單中
+cmd-notify-removed = 已取消關注 { $username }
+cmd-notify-not-watched = { $username } 不在你的關注清單中
 cmd-list-all-no-permission = 您需要 user_edit 或 user_delete 權限才能列出所有用戶
 cmd-list-all-output = 用戶：{ $users }（{ $count }位用戶）
+cmd-whoami-desc = 顯示你的使用者名稱、角色、權限和功能
+cmd-whoami-usage = 用法：/{ $command }
 cmd-help-usage = 用法：/{ $command } [指令]
 cmd-topic-permission-denied = 您沒有編輯主題的權限
+cmd-page-desc = 列出或閱讀伺服器的資訊頁面（管理員：設定或刪除）
+cmd-page-usage = 用法：/{ $command } [<名稱>|設定 <名稱> <文字>|刪除 <名稱>]（\n 表示換行）
+cmd-page-arg-set = 設定
+cmd-page-arg-delete = 刪除
+cmd-page-list = 頁面：{ $pages }
+cmd-page-none = 此伺服器沒有頁面
+cmd-page-saved = 頁面已儲存
+cmd-page-deleted = 頁面已刪除
+cmd-pin-desc = 列出置頂訊息或置頂某使用者的最新訊息
+cmd-pin-usage = 用法：/{ $command } [使用者名稱]
+cmd-pin-list = 置頂訊息：
+cmd-pin-list-item = { $number }. { $username }：{ $message }（由 { $pinned_by } 置頂）
+cmd-pin-none = 沒有置頂訊息
+cmd-pin-no-message = 沒有 { $username } 的近期訊息可置頂
+cmd-purge-desc = 清理超出伺服器保留政策的聊天記錄
+cmd-purge-usage = 用法：/{ $command }
+cmd-refresh-desc = 從伺服器資料庫重新傳送使用者權限
+cmd-refresh-usage = 用法：/{ $command } <用戶名>
+cmd-transfer-desc = 將另一個帳號設為管理員，除非加上「保留」，否則自己卸任
+cmd-transfer-usage = 用法：/{ $command } <使用者名稱> [保留]
+cmd-transfer-arg-keep = 保留
+cmd-seen-desc = 顯示使用者上次上線的時間
+cmd-seen-usage = 用法：/{ $command } <使用者名稱>
+cmd-session-desc = 顯示此連線的工作階段和流量
+cmd-session-usage = 用法：/{ $command }
+cmd-session-info = 工作階段 { $id }，已連線到 { $address }
+cmd-session-traffic = 連線以來的流量：已傳送 { $sent }，已接收 { $received }
+cmd-unpin-desc = 取消置頂訊息
+cmd-unpin-usage = 用法：/{ $command } <編號>（編號見 /pin）
 cmd-window-desc = 管理聊天分頁
-cmd-window-usage = 用法：/{ $command } [下一個|上一個|關閉 [用戶名]]
+cmd-window-usage = 用法：/{ $command } [下一個|上一個|關閉 [用戶名]|彈出 [用戶名]]
 cmd-window-arg-next = 下一個
 cmd-window-arg-prev = 上一個
 cmd-window-arg-close = 關閉
+cmd-window-arg-popout = 彈出
 cmd-window-list = 開啟的分頁：{ $tabs }（{ $count }個分頁）
 cmd-window-close-server = 無法關閉伺服器分頁
+cmd-window-popout-server = 無法彈出伺服器分頁
 cmd-window-not-found = 找不到分頁：{ $name }
 cmd-serverinfo-desc = 顯示伺服器資訊
 cmd-serverinfo-usage = 用法：/{ $command }
 cmd-serverinfo-header = [伺服器]
 cmd-serverinfo-end = 伺服器資訊結束
+cmd-slow-desc = 檢視或設定聊天慢速模式
+cmd-slow-usage = 用法：/{ $command } [秒]
+cmd-slow-off = 慢速模式已關閉
+cmd-slow-current = 慢速模式：訊息間隔{ $seconds }秒
+cmd-stats-desc = 顯示伺服器最近一小時的連線歷史
+cmd-stats-usage = 用法: /{ $command } [重設]
+cmd-stats-arg-reset = 重設
+cmd-stats-current = 目前開啟的連線: { $count }
+cmd-stats-total = 最近 { $minutes } 分鐘: { $connections } 個連線,{ $refused } 個被拒絕,峰值 { $peak } 個開啟(單一位址 { $peak_per_ip } 個)
+cmd-stats-group = { $time }: { $connections } 個連線,{ $refused } 個被拒絕,峰值 { $peak }
+cmd-stats-none = 尚未記錄連線歷史
+cmd-set-desc = 檢視或變更此伺服器的聊天偏好
+cmd-set-usage = 用法：/{ $command } joinleave [on|off|default]
+cmd-set-joinleave-on = 此伺服器顯示進入/離開訊息
+cmd-set-joinleave-off = 此伺服器隱藏進入/離開訊息
+cmd-set-joinleave-default = 此伺服器上的進出訊息遵循全域設定
 
 # =============================================================================
 # About Panel
@@ -363,6 +668,12 @@ about-app-name = Nexus BBS
 about-copyright = © 2025 Nexus BBS Project
 button-choose-image = 選擇圖片
 button-clear-image = 清除
+button-clear-theme = 清除
+button-copy-fingerprint = 複製
+button-clear-fingerprint = 清除
+button-dismiss = 關閉
+button-reconnect = 重新連線
+button-open-link = 開啟連結
 label-server-image = 伺服器圖片:
 err-server-image-too-large = 伺服器圖片太大（最大512KB）
 err-server-image-invalid-format = 伺服器圖片格式無效（必須是base64編碼的資料URI）
@@ -3,7 +3,7 @@
 use iced::Task;
 
 use crate::config::Config;
-use crate::types::Message;
+use crate::types::{Invite, Message};
 
 /// Generate connection tasks for bookmarks with auto_connect enabled
 ///
@@ -19,6 +19,38 @@ pub fn generate_auto_connect_tasks(config: &Config) -> Vec<Task<Message>> {
         .collect()
 }
 
+/// Find a `nexus://` link among the command-line arguments
+///
+/// OS URL handlers pass the link as an argument; other arguments are ignored.
+pub fn invite_link_argument() -> Option<String> {
+    std::env::args()
+        .skip(1)
+        .find(|arg| Invite::is_invite_link(arg))
+}
+
+/// Find a bookmark an invite link can connect through without asking
+///
+/// The bookmark must match the link's address and port, have a username, and
+/// already trust a certificate. When the link names a fingerprint it must be
+/// the one the bookmark trusts, so a link can never redirect trust silently.
+pub fn bookmark_for_invite(config: &Config, invite: &Invite) -> Option<usize> {
+    let port = invite.port.to_string();
+    config.bookmarks.iter().position(|bookmark| {
+        bookmark.address.eq_ignore_ascii_case(&invite.address)
+            && bookmark.port == port
+            && !bookmark.username.is_empty()
+            && bookmark
+                .certificate_fingerprint
+                .as_ref()
+                .is_some_and(|trusted| {
+                    invite
+                        .fingerprint
+                        .as_ref()
+                        .is_none_or(|expected| expected == trusted)
+                })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +100,78 @@ mod tests {
         let tasks = generate_auto_connect_tasks(&config);
         assert_eq!(tasks.len(), 2);
     }
+
+    /// Helper to create a bookmark with credentials and a trusted certificate
+    fn trusted_bookmark(address: &str, fingerprint: &str) -> ServerBookmark {
+        ServerBookmark {
+            name: address.to_string(),
+            address: address.to_string(),
+            username: "alice".to_string(),
+            password: "secret".to_string(),
+            certificate_fingerprint: Some(fingerprint.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn invite(address: &str, fingerprint: Option<&str>) -> Invite {
+        Invite {
+            address: address.to_string(),
+            port: nexus_common::DEFAULT_PORT,
+            name: None,
+            fingerprint: fingerprint.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_invite_matches_trusted_bookmark() {
+        let mut config = Config::default();
+        config.add_bookmark(bookmark("Other", false));
+        config.add_bookmark(trusted_bookmark("chat.example.com", "AA:BB"));
+
+        assert_eq!(
+            bookmark_for_invite(&config, &invite("Chat.Example.com", None)),
+            Some(1)
+        );
+        assert_eq!(
+            bookmark_for_invite(&config, &invite("chat.example.com", Some("AA:BB"))),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_invite_skips_untrusted_or_mismatched_bookmarks() {
+        let mut config = Config::default();
+        config.add_bookmark(ServerBookmark {
+            certificate_fingerprint: None,
+            ..trusted_bookmark("new.example.com", "")
+        });
+        config.add_bookmark(ServerBookmark {
+            username: String::new(),
+            ..trusted_bookmark("anon.example.com", "AA:BB")
+        });
+        config.add_bookmark(trusted_bookmark("chat.example.com", "AA:BB"));
+
+        assert_eq!(
+            bookmark_for_invite(&config, &invite("new.example.com", None)),
+            None
+        );
+        assert_eq!(
+            bookmark_for_invite(&config, &invite("anon.example.com", None)),
+            None
+        );
+        assert_eq!(
+            bookmark_for_invite(&config, &invite("chat.example.com", Some("CC:DD"))),
+            None
+        );
+        assert_eq!(
+            bookmark_for_invite(
+                &config,
+                &Invite {
+                    port: 8000,
+                    ..invite("chat.example.com", None)
+                }
+            ),
+            None
+        );
+    }
 }
@@ -0,0 +1,69 @@
+//! /announce command implementation - manage the server's rotating announcements
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::io::client_message_type;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /announce command
+///
+/// Usage:
+/// - `/announce` - List the announcements with their numbers and the interval
+/// - `/announce add <message>` - Add a message to the end of the rotation
+/// - `/announce remove <number>` - Remove an announcement by its number
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let msg = match parse_args(args) {
+        Some(msg) => msg,
+        None => {
+            let error_msg = t_args("cmd-announce-usage", &[("command", invoked_name)]);
+            return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    if let Some(task) = unsupported(app, connection_id, invoked_name, client_message_type(&msg)) {
+        return task;
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
+
+/// Turn the command arguments into a request, or None if they are invalid
+fn parse_args(args: &[String]) -> Option<ClientMessage> {
+    let Some((subcommand, rest)) = args.split_first() else {
+        return Some(ClientMessage::AnnouncementList);
+    };
+
+    // Get translated subcommand keywords
+    let add_keyword = t("cmd-announce-arg-add").to_lowercase();
+    let remove_keyword = t("cmd-announce-arg-remove").to_lowercase();
+    let subcommand = subcommand.to_lowercase();
+
+    if subcommand == add_keyword && !rest.is_empty() {
+        Some(ClientMessage::AnnouncementAdd {
+            message: rest.join(" "),
+        })
+    } else if subcommand == remove_keyword && rest.len() == 1 {
+        // Numbers shown by /announce start at 1
+        let number = rest[0].parse::<u32>().ok().filter(|&n| n > 0)?;
+        Some(ClientMessage::AnnouncementRemove { index: number - 1 })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,285 @@
+//! /art command implementation - send ASCII or ANSI art from a file
+
+use std::fs;
+use std::path::Path;
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ART_TAG, ChatMessage, FENCE, Message};
+use iced::Task;
+use iced::widget::text_editor;
+
+/// Largest art file read, in bytes
+///
+/// Generous next to the message limit, since ANSI files spend most of their
+/// bytes on escape sequences that are stripped.
+const MAX_ART_FILE_SIZE: u64 = 64 * 1024;
+
+/// Escape character that starts ANSI sequences
+const ESC: char = '\u{1b}';
+
+/// End-of-file marker before a SAUCE metadata record
+const SAUCE_EOF: u8 = 0x1a;
+
+/// Code page 437 characters for bytes 0x80 to 0xFF (0xFF, a no-break space,
+/// is shown as a plain space)
+const CP437_HIGH: &str = "ÇüéâäàåçêëèïîìÄÅÉæÆôöòûùÿÖÜ¢£¥₧ƒáíóúñÑªº¿⌐¬½¼¡«»\
+    ░▒▓│┤╡╢╖╕╣║╗╝╜╛┐└┴┬├─┼╞╟╚╔╩╦╠═╬╧╨╤╥╙╘╒╓╫╪┘┌█▄▌▐▀\
+    αßΓπΣσµτΦΘΩδ∞φε∩≡±≥≤⌠⌡÷≈°∙·√ⁿ²■ ";
+
+/// Execute the /art command
+///
+/// Sends the file as one message in an `art` block, which clients show in a
+/// fixed-width font without wrapping. The message goes to the active tab
+/// like typed text, so permissions, slow mode and the length limit apply.
+///
+/// Usage:
+/// - `/art <file>` - Send the art, or explain how to truncate it if too long
+/// - `/art truncate <file>` - Send as many whole lines as fit the limit
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let truncate_keyword = t("cmd-art-arg-truncate");
+    let (truncate, path_args) = match args.split_first() {
+        Some((first, rest)) if first.to_lowercase() == truncate_keyword.to_lowercase() => {
+            (true, rest)
+        }
+        _ => (false, args),
+    };
+    if path_args.is_empty() {
+        let error_msg = t_args("cmd-art-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+    let path = path_args.join(" ");
+
+    let art = match read_art(Path::new(&path)) {
+        Ok(art) => art,
+        Err(error_msg) => {
+            return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+    let max_length = conn.max_message_length;
+
+    let message = wrap_art(&art);
+    let length = message.chars().count();
+    let message = if length <= max_length {
+        message
+    } else if !truncate {
+        let error_msg = t_args(
+            "cmd-art-too-long",
+            &[
+                ("length", &length.to_string()),
+                ("max", &max_length.to_string()),
+                ("command", invoked_name),
+                ("truncate", &truncate_keyword),
+            ],
+        );
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    } else if let Some(truncated) = truncate_art(&art, max_length) {
+        wrap_art(truncated)
+    } else {
+        let error_msg = t_args("err-art-first-line", &[("max", &max_length.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    // Send through the input so the art gets the same checks as typed text
+    // and stays in the input if it can't go out yet (e.g. slow mode)
+    let Some(conn) = app.connections.get_mut(&connection_id) else {
+        return Task::none();
+    };
+    conn.message_input = text_editor::Content::with_text(&message);
+    conn.large_paste = false;
+    conn.paste_confirmation = None;
+    app.handle_send_message_pressed()
+}
+
+/// Read and clean up an art file, or a translated error
+fn read_art(path: &Path) -> Result<String, String> {
+    let read_error = |e: &dyn std::fmt::Display| {
+        t_args(
+            "err-art-read",
+            &[
+                ("path", &path.display().to_string()),
+                ("error", &e.to_string()),
+            ],
+        )
+    };
+
+    let size = fs::metadata(path).map_err(|e| read_error(&e))?.len();
+    if size > MAX_ART_FILE_SIZE {
+        return Err(t_args(
+            "err-art-file-too-large",
+            &[("max", &(MAX_ART_FILE_SIZE / 1024).to_string())],
+        ));
+    }
+
+    let bytes = fs::read(path).map_err(|e| read_error(&e))?;
+    let art = clean_art(&decode_art(&bytes));
+    if art.is_empty() {
+        return Err(t("err-art-empty"));
+    }
+    // A fence inside the art would end its block early
+    if art.contains(FENCE) {
+        return Err(t("err-art-fence"));
+    }
+    Ok(art)
+}
+
+/// Decode an art file as UTF-8, or as code page 437 (classic `.ans` and
+/// `.asc` files) if it isn't valid UTF-8
+///
+/// Anything after a SAUCE end-of-file marker is metadata and is dropped.
+fn decode_art(bytes: &[u8]) -> String {
+    let bytes = match bytes.iter().position(|&b| b == SAUCE_EOF) {
+        Some(end) => &bytes[..end],
+        None => bytes,
+    };
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes
+            .iter()
+            .map(|&b| match b {
+                0x00..=0x7f => b as char,
+                _ => CP437_HIGH.chars().nth(usize::from(b - 0x80)).unwrap_or(' '),
+            })
+            .collect(),
+    }
+}
+
+/// Turn decoded art into text the server accepts, keeping its layout
+///
+/// ANSI escape sequences are removed, except that cursor-forward moves
+/// become the spaces they skip. Other control characters are dropped,
+/// trailing whitespace is trimmed from each line, and blank lines around
+/// the art are removed.
+fn clean_art(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            ESC => {
+                if chars.next_if_eq(&'[').is_none() {
+                    // Two-character escape; drop the character after ESC
+                    chars.next();
+                    continue;
+                }
+                let mut params = String::new();
+                while let Some(c) = chars.next_if(|c| ('\u{20}'..='\u{3f}').contains(c)) {
+                    params.push(c);
+                }
+                // Cursor forward ("ESC[nC") is how ANSI art draws spacing
+                if chars.next() == Some('C') {
+                    let count = params.parse::<usize>().unwrap_or(1).min(80);
+                    out.extend(std::iter::repeat_n(' ', count));
+                }
+            }
+            '\r' => {
+                if chars.peek() != Some(&'\n') {
+                    out.push('\n');
+                }
+            }
+            '\n' | '\t' => out.push(ch),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+
+    let lines: Vec<&str> = out.lines().map(str::trim_end).collect();
+    let start = lines
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(start, |i| i + 1);
+    lines[start..end].join("\n")
+}
+
+/// Wrap art in a fenced `art` block
+fn wrap_art(art: &str) -> String {
+    format!("{FENCE}{ART_TAG}\n{art}\n{FENCE}")
+}
+
+/// The longest run of whole leading lines whose wrapped message fits
+/// `max_length` characters, or None if not even the first line fits
+fn truncate_art(art: &str, max_length: usize) -> Option<&str> {
+    let overhead = wrap_art("").chars().count();
+    let mut length = overhead;
+    let mut end = None;
+
+    for (offset, line) in line_offsets(art) {
+        // Every line after the first also needs its newline
+        length += line.chars().count() + usize::from(end.is_some());
+        if length > max_length {
+            break;
+        }
+        end = Some(offset + line.len());
+    }
+
+    end.map(|end| &art[..end])
+}
+
+/// Each line with its byte offset in `text`
+fn line_offsets(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len() + 1;
+        Some((start, line))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_art_keeps_spacing() {
+        assert_eq!(
+            clean_art("\n  /\\_/\\  \r\n ( o.o )\n\n"),
+            "  /\\_/\\\n ( o.o )"
+        );
+    }
+
+    #[test]
+    fn test_clean_art_strips_ansi() {
+        // Colors are dropped; cursor-forward becomes spaces
+        assert_eq!(
+            clean_art("\u{1b}[1;31mA\u{1b}[3CB\u{1b}[0m\u{1b}[CC"),
+            "A   B C"
+        );
+    }
+
+    #[test]
+    fn test_decode_cp437_and_sauce() {
+        let bytes = [
+            b'x', 0xdb, 0xdb, 0xb1, SAUCE_EOF, b'S', b'A', b'U', b'C', b'E',
+        ];
+        assert_eq!(decode_art(&bytes), "x██▒");
+        assert_eq!(CP437_HIGH.chars().count(), 128);
+    }
+
+    #[test]
+    fn test_decode_utf8() {
+        assert_eq!(decode_art("█▒ ok".as_bytes()), "█▒ ok");
+    }
+
+    #[test]
+    fn test_truncate_keeps_whole_lines() {
+        let overhead = wrap_art("").chars().count();
+        let art = "aaa\nbbb\nccc";
+
+        assert_eq!(truncate_art(art, overhead + 11), Some(art));
+        assert_eq!(truncate_art(art, overhead + 10), Some("aaa\nbbb"));
+        assert_eq!(truncate_art(art, overhead + 3), Some("aaa"));
+        assert_eq!(truncate_art(art, overhead + 2), None);
+    }
+}
@@ -29,23 +29,25 @@ pub fn execute(
     let message = args.join(" ");
 
     // Validate message content
-    if let Err(e) = validators::validate_message(&message) {
+    if let Err(e) = validators::validate_message(&message, conn.max_message_length) {
         let error_msg = match e {
             MessageError::Empty => t("err-message-empty"),
             MessageError::TooLong => t_args(
                 "err-message-too-long",
                 &[
-                    ("length", &message.len().to_string()),
-                    ("max", &validators::MAX_MESSAGE_LENGTH.to_string()),
+                    ("length", &message.chars().count().to_string()),
+                    ("max", &conn.max_message_length.to_string()),
                 ],
             ),
-            MessageError::ContainsNewlines => t("err-message-contains-newlines"),
             MessageError::InvalidCharacters => t("err-message-invalid-characters"),
         };
         return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
     }
 
-    let msg = ClientMessage::UserBroadcast { message };
+    let msg = ClientMessage::UserBroadcast {
+        message,
+        target_permission: None,
+    };
 
     if let Err(e) = conn.send(msg) {
         let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
@@ -0,0 +1,59 @@
+//! /delete command implementation - delete a recent #server chat message
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use crate::views::constants::PERMISSION_CHAT_MODERATE;
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /delete command
+///
+/// Usage:
+/// - `/delete` - Delete your most recent #server message
+/// - `/delete <username>` - Delete a user's most recent #server message
+///   (the server only allows this for admins and moderators)
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "ChatDelete") {
+        return task;
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let username = match args {
+        [] => conn.username.clone(),
+        [username] => username.clone(),
+        _ => {
+            let error_msg = t_args("cmd-delete-usage", &[("command", invoked_name)]);
+            return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    let can_moderate = conn.has_permission(PERMISSION_CHAT_MODERATE);
+    if !can_moderate && !username.eq_ignore_ascii_case(&conn.username) {
+        return app.add_chat_message(
+            connection_id,
+            ChatMessage::error(t("cmd-delete-permission-denied")),
+        );
+    }
+
+    let Some(message_id) = conn.latest_chat_message_id(&username) else {
+        let error_msg = t_args("cmd-delete-no-message", &[("username", &username)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    if let Err(e) = conn.send(ClientMessage::ChatDelete { message_id }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
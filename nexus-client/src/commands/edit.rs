@@ -0,0 +1,66 @@
+//! /edit command implementation - edit your latest #server chat message
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, MessageError};
+
+/// Execute the /edit command
+///
+/// Replaces the text of your most recent #server message. The server only
+/// allows this for a limited time after sending.
+/// Usage: /edit <message>
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "ChatEdit") {
+        return task;
+    }
+
+    if args.is_empty() {
+        let error_msg = t_args("cmd-edit-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let Some(message_id) = conn.latest_chat_message_id(&conn.username) else {
+        return app.add_chat_message(connection_id, ChatMessage::error(t("cmd-edit-no-message")));
+    };
+
+    let message = args.join(" ");
+
+    // Validate message content
+    if let Err(e) = validators::validate_message(&message, conn.max_message_length) {
+        let error_msg = match e {
+            MessageError::Empty => t("err-message-empty"),
+            MessageError::TooLong => t_args(
+                "err-message-too-long",
+                &[
+                    ("length", &message.chars().count().to_string()),
+                    ("max", &conn.max_message_length.to_string()),
+                ],
+            ),
+            MessageError::InvalidCharacters => t("err-message-invalid-characters"),
+        };
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    if let Err(e) = conn.send(ClientMessage::ChatEdit {
+        message_id,
+        message,
+    }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -0,0 +1,40 @@
+//! /events command implementation - show this connection's lifecycle timeline
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+
+/// Execute the /events command
+///
+/// Prints the connection's recorded events (connect, login, errors, kicks),
+/// oldest first, for pasting into a bug report.
+/// Usage: /events
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    // /events takes no arguments
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-events-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if conn.event_log.is_empty() {
+        return app.add_chat_message(connection_id, ChatMessage::info(t("cmd-events-empty")));
+    }
+
+    let lines: Vec<String> = conn.event_log.lines().collect();
+    let mut tasks =
+        vec![app.add_chat_message(connection_id, ChatMessage::info(t("cmd-events-header")))];
+    for line in lines {
+        tasks.push(app.add_chat_message(connection_id, ChatMessage::info(line)));
+    }
+    Task::batch(tasks)
+}
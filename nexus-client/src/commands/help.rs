@@ -1,7 +1,7 @@
 //! /help command implementation
 
 use crate::NexusApp;
-use crate::commands::{command_list_for_user, get_command_info};
+use crate::commands::{command_list_for_user, get_command_info, is_available};
 use crate::i18n::{t, t_args};
 use crate::types::{ChatMessage, Message};
 use iced::Task;
@@ -18,15 +18,28 @@ pub fn execute(
     args: &[String],
 ) -> Task<Message> {
     // Get user's permissions for filtering
-    let (is_admin, permissions) = app
+    let (is_admin, permissions, disabled) = app
         .connections
         .get(&connection_id)
-        .map(|conn| (conn.is_admin, conn.permissions.clone()))
-        .unwrap_or((false, Vec::new()));
+        .map(|conn| {
+            (
+                conn.is_admin,
+                conn.permissions.clone(),
+                conn.disabled_permissions.clone(),
+            )
+        })
+        .unwrap_or((false, Vec::new(), Vec::new()));
 
     // If a command name is provided, show help for that specific command
     if args.len() == 1 {
-        return show_command_help(app, connection_id, &args[0], is_admin, &permissions);
+        return show_command_help(
+            app,
+            connection_id,
+            &args[0],
+            is_admin,
+            &permissions,
+            &disabled,
+        );
     }
 
     // Too many arguments - show usage
@@ -42,7 +55,7 @@ pub fn execute(
     tasks.push(app.add_chat_message(connection_id, ChatMessage::info(t("cmd-help-header"))));
 
     // List available commands (filtered by permission)
-    for cmd in command_list_for_user(is_admin, &permissions) {
+    for cmd in command_list_for_user(is_admin, &permissions, &disabled) {
         let aliases = if cmd.aliases.is_empty() {
             String::new()
         } else {
@@ -67,6 +80,7 @@ fn show_command_help(
     command_name: &str,
     is_admin: bool,
     permissions: &[String],
+    disabled: &[String],
 ) -> Task<Message> {
     // Look up the command
     let Some(cmd) = get_command_info(command_name) else {
@@ -75,14 +89,7 @@ fn show_command_help(
     };
 
     // Check if user has permission to use this command
-    let has_permission = cmd.permissions.is_empty()
-        || is_admin
-        || cmd
-            .permissions
-            .iter()
-            .any(|req| permissions.iter().any(|p| p == *req));
-
-    if !has_permission {
+    if !is_available(cmd, is_admin, permissions, disabled) {
         let error_msg = t_args("cmd-unknown", &[("command", command_name)]);
         return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
     }
@@ -0,0 +1,45 @@
+//! /invite command implementation - copy an invite link for this server
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Invite, Message};
+use iced::Task;
+
+/// Execute the /invite command
+///
+/// Copies a `nexus://` link with the server address, port, name, and
+/// certificate fingerprint to the clipboard. Credentials are never included.
+/// Usage: /invite
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    // /invite takes no arguments
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-invite-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let invite = Invite {
+        address: conn.server_address.clone(),
+        port: conn.server_port,
+        name: conn
+            .server_name
+            .clone()
+            .filter(|name| !name.trim().is_empty()),
+        fingerprint: Some(conn.certificate_fingerprint.clone()),
+    };
+    let link = invite.to_url();
+
+    let message = t_args("cmd-invite-copied", &[("link", &link)]);
+    Task::batch([
+        iced::clipboard::write(link),
+        app.add_chat_message(connection_id, ChatMessage::info(message)),
+    ])
+}
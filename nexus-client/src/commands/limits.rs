@@ -0,0 +1,83 @@
+//! /limits command implementation - view and set chat length limits
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /limits command
+///
+/// Connected users pick up new limits as soon as the server applies them.
+///
+/// Usage:
+/// - `/limits` - Show the message and topic length limits
+/// - `/limits message <characters>` - Set the maximum message length
+/// - `/limits topic <characters>` - Set the maximum topic length
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    // /limits - show current limits
+    if args.is_empty() {
+        let message = t_args(
+            "cmd-limits-current",
+            &[
+                ("message", &conn.max_message_length.to_string()),
+                ("topic", &conn.max_topic_length.to_string()),
+            ],
+        );
+        return app.add_chat_message(connection_id, ChatMessage::info(message));
+    }
+
+    let Some((max_message_length, max_topic_length)) = parse_args(args) else {
+        let error_msg = t_args("cmd-limits-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    let msg = ClientMessage::ServerInfoUpdate {
+        name: None,
+        description: None,
+        max_connections_per_ip: None,
+        image: None,
+        chat_slow_mode: None,
+        max_message_length,
+        max_topic_length,
+    };
+
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
+
+/// Turn the arguments into (message length, topic length), or None if invalid
+///
+/// Range checks are left to the server, which knows its ceilings.
+fn parse_args(args: &[String]) -> Option<(Option<u32>, Option<u32>)> {
+    let [limit, length] = args else {
+        return None;
+    };
+    let length = length.parse::<u32>().ok()?;
+
+    // Get translated subcommand keywords
+    let message_keyword = t("cmd-limits-arg-message").to_lowercase();
+    let topic_keyword = t("cmd-limits-arg-topic").to_lowercase();
+    let limit = limit.to_lowercase();
+
+    if limit == message_keyword {
+        Some((Some(length), None))
+    } else if limit == topic_keyword {
+        Some((None, Some(length)))
+    } else {
+        None
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
-use crate::types::{ChatMessage, Message, PendingRequests, ResponseRouting};
+use crate::types::{ChatMessage, Message, ResponseRouting};
 use crate::views::constants::{PERMISSION_USER_DELETE, PERMISSION_USER_EDIT};
 use iced::Task;
 use nexus_common::protocol::ClientMessage;
@@ -38,9 +38,8 @@ pub fn execute(
         };
 
         // Check if user has user_edit OR user_delete permission
-        let has_edit = conn.is_admin || conn.permissions.iter().any(|p| p == PERMISSION_USER_EDIT);
-        let has_delete =
-            conn.is_admin || conn.permissions.iter().any(|p| p == PERMISSION_USER_DELETE);
+        let has_edit = conn.has_permission(PERMISSION_USER_EDIT);
+        let has_delete = conn.has_permission(PERMISSION_USER_DELETE);
 
         if !has_edit && !has_delete {
             let error_msg = t("cmd-list-all-no-permission");
@@ -62,8 +61,7 @@ pub fn execute(
             .connections
             .get_mut(&connection_id)
             .expect("connection exists");
-        conn.pending_requests
-            .track(message_id, ResponseRouting::DisplayListInChat);
+        conn.track_request(message_id, ResponseRouting::DisplayListInChat);
 
         return Task::none();
     }
@@ -0,0 +1,59 @@
+//! /maintenance command implementation - view and toggle maintenance mode
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /maintenance command
+///
+/// Usage:
+/// - `/maintenance` - Show whether maintenance mode is on
+/// - `/maintenance on|off` - Turn maintenance mode on or off
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "SetMaintenance") {
+        return task;
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    // /maintenance - show current state
+    if args.is_empty() {
+        let message = if conn.maintenance {
+            t("cmd-maintenance-on")
+        } else {
+            t("cmd-maintenance-off")
+        };
+        return app.add_chat_message(connection_id, ChatMessage::info(message));
+    }
+
+    // /maintenance on|off - takes exactly one argument, translated or English
+    let on_keyword = t("cmd-maintenance-arg-on").to_lowercase();
+    let off_keyword = t("cmd-maintenance-arg-off").to_lowercase();
+    let arg = args[0].to_lowercase();
+
+    let enabled = if args.len() == 1 && (arg == on_keyword || arg == "on") {
+        true
+    } else if args.len() == 1 && (arg == off_keyword || arg == "off") {
+        false
+    } else {
+        let error_msg = t_args("cmd-maintenance-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    if let Err(e) = conn.send(ClientMessage::SetMaintenance { enabled }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -2,9 +2,8 @@
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
-use crate::types::{ChatMessage, Message, PendingRequests, ResponseRouting};
+use crate::types::{ChatMessage, Message, ResponseRouting};
 use iced::Task;
-use nexus_common::protocol::ClientMessage;
 use nexus_common::validators::{self, MessageError, UsernameError};
 
 /// Execute the /message command
@@ -45,41 +44,30 @@ pub fn execute(
     }
 
     // Validate message content
-    if let Err(e) = validators::validate_message(&message) {
+    if let Err(e) = validators::validate_message(&message, conn.max_message_length) {
         let error_msg = match e {
             MessageError::Empty => t("err-message-empty"),
             MessageError::TooLong => t_args(
                 "err-message-too-long",
                 &[
-                    ("length", &message.len().to_string()),
-                    ("max", &validators::MAX_MESSAGE_LENGTH.to_string()),
+                    ("length", &message.chars().count().to_string()),
+                    ("max", &conn.max_message_length.to_string()),
                 ],
             ),
-            MessageError::ContainsNewlines => t("err-message-contains-newlines"),
             MessageError::InvalidCharacters => t("err-message-invalid-characters"),
         };
         return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
     }
 
-    let msg = ClientMessage::UserMessage {
-        to_username: username.clone(),
-        message,
-    };
-
-    let message_id = match conn.send(msg) {
-        Ok(id) => id,
-        Err(e) => {
-            let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
-            return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
-        }
+    let Some(conn) = app.connections.get_mut(&connection_id) else {
+        return Task::none();
     };
 
     // Track this request so we can switch to the user's tab on successful delivery
-    if let Some(conn) = app.connections.get_mut(&connection_id) {
-        conn.pending_requests.track(
-            message_id,
-            ResponseRouting::OpenMessageTab(username.clone()),
-        );
+    let routing = ResponseRouting::OpenMessageTab(username.clone());
+    if let Err(e) = conn.send_private_message(username, message, Some(routing)) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
     }
 
     Task::none()
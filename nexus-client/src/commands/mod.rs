@@ -6,23 +6,49 @@
 //!
 //! | Command | Aliases | Permission | Description |
 //! |---------|---------|------------|-------------|
+//! | `/announce` | | *admin* | List or manage the server's rotating announcements |
+//! | `/art` | | *none* | Send ASCII or ANSI art from a file as a fixed-width block |
 //! | `/broadcast` | `/b` | `user_broadcast` | Send a broadcast to all users |
 //! | `/clear` | | *none* | Clear chat history for current tab |
+//! | `/delete` | | *none* | Delete your latest message (moderators: a user's latest message) |
+//! | `/edit` | | `chat_send` | Edit your latest message |
+//! | `/events` | | *none* | Show this connection's lifecycle events |
 //! | `/focus` | `/f` | *none* | Focus server chat or a user's PM tab |
 //! | `/help` | `/h`, `/?` | *none* | Show available commands |
 //! | `/info` | `/i`, `/userinfo`, `/whois` | `user_info` | Show information about a user |
+//! | `/invite` | | *none* | Copy a `nexus://` invite link for this server |
 //! | `/kick` | `/k`, `/userkick` | `user_kick` | Kick a user from the server |
+//! | `/limits` | | *admin* | View or set the message and topic length limits |
 //! | `/list` | `/l`, `/userlist` | `user_list` | Show connected users |
+//! | `/maintenance` | | *admin* | View or toggle maintenance (read-only) mode |
 //! | `/message` | `/m`, `/msg` | `user_message` | Send a message to a user |
+//! | `/notice` | | *admin* | Show a notice (or a reconnect or update request) to everyone as a banner |
+//! | `/notify` | | *none* | List or manage the users you're notified about when they come online |
+//! | `/page` | | *none* | List or read the server's info pages (admins: set or delete them) |
+//! | `/pin` | | `chat_pin` | List pins or pin a user's latest message |
+//! | `/purge` | | *admin* | Purge chat history beyond the server's retention policy |
+//! | `/query` | `/q` | `user_message` | Open a message tab without sending |
+//! | `/reconnect` | | *none* | Drop and re-establish the current connection |
+//! | `/refresh` | | *admin* | Re-send a user's permissions from the server database |
+//! | `/report` | | *none* | Copy a sanitized client and connection snapshot for bug reports |
+//! | `/seen` | | `user_info` | Show when a user was last online |
+//! | `/session` | | *none* | Show this connection's session and traffic totals |
+//! | `/set` | | *none* | View or change per-server chat preferences |
 //! | `/sinfo` | `/s`, `/serverinfo` | *none* | Show server information |
+//! | `/slow` | | *admin* | View or set chat slow mode |
+//! | `/stats` | | *admin* | Show the server's connection history for the last hour |
 //! | `/topic` | `/t`, `/chattopic` | `chat_topic` or `chat_topic_edit` | View or manage the chat topic |
-//! | `/window` | `/w` | *none* | Manage chat tabs (list, close) |
+//! | `/transfer` | | *admin* | Make another account an admin, stepping down by default |
+//! | `/unpin` | | `chat_pin` | Remove a pinned message |
+//! | `/whoami` | | *none* | Show your username, role, permissions and features |
+//! | `/window` | `/w` | *none* | Manage chat tabs (list, close, pop out) |
 //!
 //! ## Special Syntax
 //!
 //! - `/` alone is a shortcut for `/help`
 //! - `//text` - Escape sequence, sends `/text` as a regular message
 //! - ` /command` - Leading space prevents command parsing
+//! - Multi-line input starting with `/` is refused rather than sent as chat
 //!
 //! ## Permissions
 //!
@@ -31,16 +57,41 @@
 //!
 //! Unknown commands display an error in chat and are never sent to the server.
 
+mod announce;
+mod art;
 mod broadcast;
 mod clear;
+mod delete;
+mod edit;
+mod events;
 mod focus;
 mod help;
+mod invite;
+mod limits;
 mod list;
+mod maintenance;
 mod message;
+mod notice;
+mod notify;
+mod page;
+mod pin;
+mod purge;
+mod query;
+mod reconnect;
+mod refresh;
+mod report;
+mod seen;
 mod server_info;
+mod session;
+mod set;
+mod slow;
+mod stats;
 mod topic;
+mod transfer;
+mod unpin;
 mod user_info;
 mod user_kick;
+mod whoami;
 mod window;
 
 use std::collections::HashMap;
@@ -50,8 +101,9 @@ use crate::NexusApp;
 use crate::i18n::t_args;
 use crate::types::{ChatMessage, Message};
 use crate::views::constants::{
-    PERMISSION_CHAT_TOPIC, PERMISSION_CHAT_TOPIC_EDIT, PERMISSION_USER_BROADCAST,
-    PERMISSION_USER_INFO, PERMISSION_USER_KICK, PERMISSION_USER_LIST, PERMISSION_USER_MESSAGE,
+    PERMISSION_CHAT_PIN, PERMISSION_CHAT_SEND, PERMISSION_CHAT_TOPIC, PERMISSION_CHAT_TOPIC_EDIT,
+    PERMISSION_USER_BROADCAST, PERMISSION_USER_INFO, PERMISSION_USER_KICK, PERMISSION_USER_LIST,
+    PERMISSION_USER_MESSAGE,
 };
 use iced::Task;
 
@@ -71,6 +123,8 @@ pub struct CommandInfo {
     pub usage_key: &'static str,
     /// Required permissions (any of these grants access, empty = always available)
     pub permissions: &'static [&'static str],
+    /// Only available to admins (checked in addition to permissions)
+    pub admin_only: bool,
 }
 
 /// Command registration entry - links metadata to handler
@@ -81,6 +135,28 @@ struct CommandRegistration {
 
 /// All registered commands (alphabetical order)
 static COMMANDS: &[CommandRegistration] = &[
+    CommandRegistration {
+        info: CommandInfo {
+            name: "announce",
+            aliases: &[],
+            description_key: "cmd-announce-desc",
+            usage_key: "cmd-announce-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: announce::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "art",
+            aliases: &[],
+            description_key: "cmd-art-desc",
+            usage_key: "cmd-art-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: art::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "broadcast",
@@ -88,6 +164,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-broadcast-desc",
             usage_key: "cmd-broadcast-usage",
             permissions: &[PERMISSION_USER_BROADCAST],
+            admin_only: false,
         },
         handler: broadcast::execute,
     },
@@ -98,9 +175,43 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-clear-desc",
             usage_key: "cmd-clear-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: clear::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "delete",
+            aliases: &[],
+            description_key: "cmd-delete-desc",
+            usage_key: "cmd-delete-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: delete::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "edit",
+            aliases: &[],
+            description_key: "cmd-edit-desc",
+            usage_key: "cmd-edit-usage",
+            permissions: &[PERMISSION_CHAT_SEND],
+            admin_only: false,
+        },
+        handler: edit::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "events",
+            aliases: &[],
+            description_key: "cmd-events-desc",
+            usage_key: "cmd-events-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: events::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "focus",
@@ -108,6 +219,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-focus-desc",
             usage_key: "cmd-focus-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: focus::execute,
     },
@@ -118,6 +230,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-help-desc",
             usage_key: "cmd-help-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: help::execute,
     },
@@ -128,9 +241,21 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-userinfo-desc",
             usage_key: "cmd-userinfo-usage",
             permissions: &[PERMISSION_USER_INFO],
+            admin_only: false,
         },
         handler: user_info::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "invite",
+            aliases: &[],
+            description_key: "cmd-invite-desc",
+            usage_key: "cmd-invite-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: invite::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "kick",
@@ -138,9 +263,21 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-kick-desc",
             usage_key: "cmd-kick-usage",
             permissions: &[PERMISSION_USER_KICK],
+            admin_only: false,
         },
         handler: user_kick::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "limits",
+            aliases: &[],
+            description_key: "cmd-limits-desc",
+            usage_key: "cmd-limits-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: limits::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "list",
@@ -148,9 +285,21 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-list-desc",
             usage_key: "cmd-list-usage",
             permissions: &[PERMISSION_USER_LIST],
+            admin_only: false,
         },
         handler: list::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "maintenance",
+            aliases: &[],
+            description_key: "cmd-maintenance-desc",
+            usage_key: "cmd-maintenance-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: maintenance::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "message",
@@ -158,9 +307,142 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-message-desc",
             usage_key: "cmd-message-usage",
             permissions: &[PERMISSION_USER_MESSAGE],
+            admin_only: false,
         },
         handler: message::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "notice",
+            aliases: &[],
+            description_key: "cmd-notice-desc",
+            usage_key: "cmd-notice-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: notice::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "notify",
+            aliases: &[],
+            description_key: "cmd-notify-desc",
+            usage_key: "cmd-notify-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: notify::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "page",
+            aliases: &[],
+            description_key: "cmd-page-desc",
+            usage_key: "cmd-page-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: page::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "pin",
+            aliases: &[],
+            description_key: "cmd-pin-desc",
+            usage_key: "cmd-pin-usage",
+            permissions: &[PERMISSION_CHAT_PIN],
+            admin_only: false,
+        },
+        handler: pin::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "purge",
+            aliases: &[],
+            description_key: "cmd-purge-desc",
+            usage_key: "cmd-purge-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: purge::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "query",
+            aliases: &["q"],
+            description_key: "cmd-query-desc",
+            usage_key: "cmd-query-usage",
+            permissions: &[PERMISSION_USER_MESSAGE],
+            admin_only: false,
+        },
+        handler: query::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "reconnect",
+            aliases: &[],
+            description_key: "cmd-reconnect-desc",
+            usage_key: "cmd-reconnect-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: reconnect::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "refresh",
+            aliases: &[],
+            description_key: "cmd-refresh-desc",
+            usage_key: "cmd-refresh-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: refresh::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "report",
+            aliases: &[],
+            description_key: "cmd-report-desc",
+            usage_key: "cmd-report-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: report::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "seen",
+            aliases: &[],
+            description_key: "cmd-seen-desc",
+            usage_key: "cmd-seen-usage",
+            permissions: &[PERMISSION_USER_INFO],
+            admin_only: false,
+        },
+        handler: seen::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "session",
+            aliases: &[],
+            description_key: "cmd-session-desc",
+            usage_key: "cmd-session-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: session::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "set",
+            aliases: &[],
+            description_key: "cmd-set-desc",
+            usage_key: "cmd-set-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: set::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "sinfo",
@@ -168,9 +450,32 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-serverinfo-desc",
             usage_key: "cmd-serverinfo-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: server_info::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "slow",
+            aliases: &[],
+            description_key: "cmd-slow-desc",
+            usage_key: "cmd-slow-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: slow::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "stats",
+            aliases: &[],
+            description_key: "cmd-stats-desc",
+            usage_key: "cmd-stats-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: stats::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "topic",
@@ -178,9 +483,43 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-topic-desc",
             usage_key: "cmd-topic-usage",
             permissions: &[PERMISSION_CHAT_TOPIC, PERMISSION_CHAT_TOPIC_EDIT],
+            admin_only: false,
         },
         handler: topic::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "transfer",
+            aliases: &[],
+            description_key: "cmd-transfer-desc",
+            usage_key: "cmd-transfer-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: transfer::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "unpin",
+            aliases: &[],
+            description_key: "cmd-unpin-desc",
+            usage_key: "cmd-unpin-usage",
+            permissions: &[PERMISSION_CHAT_PIN],
+            admin_only: false,
+        },
+        handler: unpin::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "whoami",
+            aliases: &[],
+            description_key: "cmd-whoami-desc",
+            usage_key: "cmd-whoami-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: whoami::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "window",
@@ -188,6 +527,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-window-desc",
             usage_key: "cmd-window-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: window::execute,
     },
@@ -207,22 +547,37 @@ static COMMAND_MAP: LazyLock<HashMap<&'static str, usize>> = LazyLock::new(|| {
     map
 });
 
+/// Check if a command is available to the user (admin-only flag and permissions)
+///
+/// `disabled` lists permissions the server has switched off for this user.
+pub fn is_available(
+    info: &CommandInfo,
+    is_admin: bool,
+    user_permissions: &[String],
+    disabled: &[String],
+) -> bool {
+    (!info.admin_only || is_admin)
+        && has_permission(is_admin, user_permissions, disabled, info.permissions)
+}
+
 /// Check if user has any of the required permissions for a command
-fn has_permission(is_admin: bool, user_permissions: &[String], required: &[&str]) -> bool {
+fn has_permission(
+    is_admin: bool,
+    user_permissions: &[String],
+    disabled: &[String],
+    required: &[&str],
+) -> bool {
     // Empty permissions = always allowed
     if required.is_empty() {
         return true;
     }
 
-    // Admins have all permissions
-    if is_admin {
-        return true;
-    }
-
-    // Check if user has any of the required permissions
-    required
-        .iter()
-        .any(|req| user_permissions.iter().any(|p| p == *req))
+    // Check if user has any of the required permissions the server hasn't
+    // disabled (admins have all permissions)
+    required.iter().any(|req| {
+        !disabled.iter().any(|p| p == *req)
+            && (is_admin || user_permissions.iter().any(|p| p == *req))
+    })
 }
 
 /// Get command info by name or alias (for /help <command>)
@@ -233,12 +588,13 @@ pub fn get_command_info(name: &str) -> Option<&'static CommandInfo> {
 }
 
 /// Get list of commands the user has permission to use (for /help display)
-pub fn command_list_for_user(
+pub fn command_list_for_user<'a>(
     is_admin: bool,
-    permissions: &[String],
-) -> impl Iterator<Item = &'static CommandInfo> {
+    permissions: &'a [String],
+    disabled: &'a [String],
+) -> impl Iterator<Item = &'static CommandInfo> + 'a {
     COMMANDS.iter().filter_map(move |reg| {
-        if has_permission(is_admin, permissions, reg.info.permissions) {
+        if is_available(&reg.info, is_admin, permissions, disabled) {
             Some(&reg.info)
         } else {
             None
@@ -252,6 +608,9 @@ pub enum ParseResult {
     Command(CommandInvocation),
     /// Input is a regular message that should be sent to the server
     Message(String),
+    /// Input starts like a command but spans several lines (never sent, so a
+    /// mistyped command can't leak into chat)
+    MultilineCommand,
     /// Input is empty (should be ignored)
     Empty,
 }
@@ -270,6 +629,7 @@ pub struct CommandInvocation {
 /// - `/command args` → Command { name: "command", args: ["args"] }
 /// - `//text` → Message("/text") (escape sequence, preserves rest of input)
 /// - ` /command` → Message(" /command") (leading space prevents command parsing)
+/// - `/command\nmore` → MultilineCommand (commands are single-line and never sent as chat)
 /// - `regular text` → Message("regular text")
 /// - `` or whitespace only → Empty
 pub fn parse_input(input: &str) -> ParseResult {
@@ -285,6 +645,11 @@ pub fn parse_input(input: &str) -> ParseResult {
             return ParseResult::Message(rest.to_string());
         }
 
+        // Commands are single-line; a trailing newline doesn't count as a second line
+        if input.trim_end().contains('\n') {
+            return ParseResult::MultilineCommand;
+        }
+
         // Parse as command
         let parts: Vec<&str> = rest.split_whitespace().collect();
 
@@ -322,13 +687,19 @@ pub fn execute_command(
         let reg = &COMMANDS[index];
 
         // Check permissions
-        let (is_admin, permissions) = app
+        let (is_admin, permissions, disabled) = app
             .connections
             .get(&connection_id)
-            .map(|conn| (conn.is_admin, conn.permissions.clone()))
-            .unwrap_or((false, Vec::new()));
+            .map(|conn| {
+                (
+                    conn.is_admin,
+                    conn.permissions.clone(),
+                    conn.disabled_permissions.clone(),
+                )
+            })
+            .unwrap_or((false, Vec::new(), Vec::new()));
 
-        if has_permission(is_admin, &permissions, reg.info.permissions) {
+        if is_available(&reg.info, is_admin, &permissions, &disabled) {
             return (reg.handler)(app, connection_id, &command.name, &command.args);
         }
     }
@@ -338,6 +709,25 @@ pub fn execute_command(
     app.add_chat_message(connection_id, ChatMessage::error(error_msg))
 }
 
+/// Report that the server doesn't understand a command's request
+///
+/// Returns `None` when the server supports `message_type`. Servers disconnect
+/// clients that send message types they don't know, so commands check before
+/// sending anything an older server may lack.
+fn unsupported(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    message_type: &str,
+) -> Option<Task<Message>> {
+    let conn = app.connections.get(&connection_id)?;
+    if conn.supports_message_type(message_type) {
+        return None;
+    }
+    let error_msg = t_args("err-command-unsupported", &[("command", invoked_name)]);
+    Some(app.add_chat_message(connection_id, ChatMessage::error(error_msg)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,6 +831,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_multi_line_command_is_refused() {
+        assert!(matches!(
+            parse_input("/help\nme please"),
+            ParseResult::MultilineCommand
+        ));
+        assert!(matches!(
+            parse_input("/msg bob secret\nmore"),
+            ParseResult::MultilineCommand
+        ));
+        match parse_input("//shrug\nok") {
+            ParseResult::Message(msg) => assert_eq!(msg, "/shrug\nok"),
+            _ => panic!("Expected Message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_command_with_trailing_newline() {
+        match parse_input("/test arg1\n") {
+            ParseResult::Command(cmd) => {
+                assert_eq!(cmd.name, "test");
+                assert_eq!(cmd.args, vec!["arg1"]);
+            }
+            _ => panic!("Expected Command"),
+        }
+    }
+
     #[test]
     fn test_command_map_contains_all_aliases() {
         // Verify all commands and aliases are in COMMAND_MAP
@@ -465,29 +882,54 @@ mod tests {
 
     #[test]
     fn test_has_permission_empty_always_allowed() {
-        assert!(has_permission(false, &[], &[]));
-        assert!(has_permission(true, &[], &[]));
+        assert!(has_permission(false, &[], &[], &[]));
+        assert!(has_permission(true, &[], &[], &[]));
     }
 
     #[test]
     fn test_has_permission_admin_always_allowed() {
-        assert!(has_permission(true, &[], &["user_list"]));
-        assert!(has_permission(true, &[], &["user_list", "user_info"]));
+        assert!(has_permission(true, &[], &[], &["user_list"]));
+        assert!(has_permission(true, &[], &[], &["user_list", "user_info"]));
     }
 
     #[test]
     fn test_has_permission_user_with_permission() {
         let perms = vec!["user_list".to_string(), "chat_send".to_string()];
-        assert!(has_permission(false, &perms, &["user_list"]));
-        assert!(has_permission(false, &perms, &["chat_send"]));
-        assert!(has_permission(false, &perms, &["user_list", "user_info"])); // any match
+        assert!(has_permission(false, &perms, &[], &["user_list"]));
+        assert!(has_permission(false, &perms, &[], &["chat_send"]));
+        // Any match is enough
+        assert!(has_permission(
+            false,
+            &perms,
+            &[],
+            &["user_list", "user_info"]
+        ));
     }
 
     #[test]
     fn test_has_permission_user_without_permission() {
         let perms = vec!["chat_send".to_string()];
-        assert!(!has_permission(false, &perms, &["user_list"]));
-        assert!(!has_permission(false, &perms, &["user_list", "user_info"]));
+        assert!(!has_permission(false, &perms, &[], &["user_list"]));
+        assert!(!has_permission(
+            false,
+            &perms,
+            &[],
+            &["user_list", "user_info"]
+        ));
+    }
+
+    #[test]
+    fn test_has_permission_disabled_by_server() {
+        let perms = vec!["user_broadcast".to_string()];
+        let disabled = vec!["user_broadcast".to_string()];
+        assert!(!has_permission(
+            false,
+            &perms,
+            &disabled,
+            &["user_broadcast"]
+        ));
+        assert!(!has_permission(true, &[], &disabled, &["user_broadcast"]));
+        assert!(has_permission(true, &[], &disabled, &["user_list"]));
     }
 
     #[test]
@@ -521,28 +963,79 @@ mod tests {
 
     #[test]
     fn test_command_list_for_user_admin_sees_all() {
-        let commands: Vec<_> = command_list_for_user(true, &[]).collect();
+        let commands: Vec<_> = command_list_for_user(true, &[], &[]).collect();
         assert_eq!(commands.len(), COMMANDS.len());
     }
 
     #[test]
     fn test_command_list_for_user_no_perms_sees_public() {
-        let commands: Vec<_> = command_list_for_user(false, &[]).collect();
+        let commands: Vec<_> = command_list_for_user(false, &[], &[]).collect();
         // Should see help and clear (no permissions required)
         assert!(commands.iter().any(|c| c.name == "help"));
         assert!(commands.iter().any(|c| c.name == "clear"));
         // Should not see permission-gated commands
         assert!(!commands.iter().any(|c| c.name == "kick"));
         assert!(!commands.iter().any(|c| c.name == "broadcast"));
+        assert!(!commands.iter().any(|c| c.name == "slow"));
     }
 
     #[test]
     fn test_command_list_for_user_with_permission() {
         let perms = vec!["user_list".to_string()];
-        let commands: Vec<_> = command_list_for_user(false, &perms).collect();
+        let commands: Vec<_> = command_list_for_user(false, &perms, &[]).collect();
         // Should see list command now
         assert!(commands.iter().any(|c| c.name == "list"));
         // Still shouldn't see kick
         assert!(!commands.iter().any(|c| c.name == "kick"));
     }
+
+    #[test]
+    fn test_admin_only_command_requires_admin() {
+        for name in [
+            "limits",
+            "maintenance",
+            "notice",
+            "purge",
+            "slow",
+            "stats",
+            "transfer",
+        ] {
+            let info = get_command_info(name).expect("admin command should exist");
+            assert!(info.admin_only);
+            assert!(is_available(info, true, &[], &[]));
+            assert!(!is_available(info, false, &[], &[]));
+        }
+    }
+
+    #[test]
+    fn test_query_command_requires_user_message_permission() {
+        let info = get_command_info("q").expect("q alias should exist");
+        assert_eq!(info.name, "query");
+        assert!(!is_available(info, false, &[], &[]));
+        assert!(is_available(
+            info,
+            false,
+            &["user_message".to_string()],
+            &[]
+        ));
+    }
+
+    #[test]
+    fn test_pin_commands_require_chat_pin_permission() {
+        for name in ["pin", "unpin"] {
+            let info = get_command_info(name).expect("pin commands should exist");
+            assert!(!is_available(info, false, &[], &[]));
+            assert!(is_available(info, false, &["chat_pin".to_string()], &[]));
+        }
+    }
+
+    #[test]
+    fn test_edit_requires_chat_send_and_delete_is_public() {
+        let edit = get_command_info("edit").expect("edit command should exist");
+        assert!(!is_available(edit, false, &[], &[]));
+        assert!(is_available(edit, false, &["chat_send".to_string()], &[]));
+
+        let delete = get_command_info("delete").expect("delete command should exist");
+        assert!(is_available(delete, false, &[], &[]));
+    }
 }
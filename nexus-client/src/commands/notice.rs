@@ -0,0 +1,74 @@
+//! /notice command implementation - show an admin notice to everyone
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::{AdminNoticeAction, ClientMessage};
+use nexus_common::validators::{self, MessageError};
+
+/// Execute the /notice command
+///
+/// Every connected user sees the notice as a banner above the chat until
+/// they dismiss it.
+///
+/// Usage:
+/// - `/notice reconnect` - Ask everyone to reconnect
+/// - `/notice update <url>` - Tell everyone a client update is available at `url`
+/// - `/notice <message>` - Show a message
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "AdminNotice") {
+        return task;
+    }
+
+    let usage = || t_args("cmd-notice-usage", &[("command", invoked_name)]);
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let action = match args {
+        [] => return app.add_chat_message(connection_id, ChatMessage::error(usage())),
+        [keyword] if keyword.eq_ignore_ascii_case("reconnect") => AdminNoticeAction::Reconnect,
+        [keyword, url] if keyword.eq_ignore_ascii_case("update") => {
+            if validators::validate_notice_url(url).is_err() {
+                return app.add_chat_message(
+                    connection_id,
+                    ChatMessage::error(t("err-notice-url-invalid")),
+                );
+            }
+            AdminNoticeAction::Update { url: url.clone() }
+        }
+        _ => {
+            let message = args.join(" ");
+            if let Err(e) = validators::validate_message(&message, conn.max_message_length) {
+                let error_msg = match e {
+                    MessageError::Empty => t("err-message-empty"),
+                    MessageError::TooLong => t_args(
+                        "err-message-too-long",
+                        &[
+                            ("length", &message.chars().count().to_string()),
+                            ("max", &conn.max_message_length.to_string()),
+                        ],
+                    ),
+                    MessageError::InvalidCharacters => t("err-message-invalid-characters"),
+                };
+                return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+            }
+            AdminNoticeAction::Notice { message }
+        }
+    };
+
+    if let Err(e) = conn.send(ClientMessage::AdminNotice { action }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
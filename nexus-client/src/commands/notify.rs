@@ -0,0 +1,81 @@
+//! /notify command implementation - manage the watch list for this server
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+
+/// Execute the /notify command
+///
+/// The watch list is saved to the connection's bookmark, so it needs one.
+///
+/// Usage:
+/// - `/notify` - List the users you're notified about
+/// - `/notify add <username>` - Notify when a user comes online
+/// - `/notify del <username>` - Stop notifying about a user
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let Some(bookmark) = conn
+        .bookmark_index
+        .and_then(|idx| app.config.bookmarks.get_mut(idx))
+    else {
+        return app.add_chat_message(
+            connection_id,
+            ChatMessage::error(t("cmd-notify-no-bookmark")),
+        );
+    };
+
+    // /notify - list the watched users
+    let Some((subcommand, rest)) = args.split_first() else {
+        let message = if bookmark.watched_users.is_empty() {
+            t("cmd-notify-none")
+        } else {
+            t_args(
+                "cmd-notify-list",
+                &[("usernames", &bookmark.watched_users.join(", "))],
+            )
+        };
+        return app.add_chat_message(connection_id, ChatMessage::info(message));
+    };
+
+    // Get translated subcommand keywords
+    let add_keyword = t("cmd-notify-arg-add").to_lowercase();
+    let del_keyword = t("cmd-notify-arg-del").to_lowercase();
+    let subcommand = subcommand.to_lowercase();
+
+    let message = match rest {
+        [username] if subcommand == add_keyword => {
+            if bookmark.watch_user(username) {
+                t_args("cmd-notify-added", &[("username", username.as_str())])
+            } else {
+                t_args("cmd-notify-already", &[("username", username.as_str())])
+            }
+        }
+        [username] if subcommand == del_keyword => {
+            if bookmark.unwatch_user(username) {
+                t_args("cmd-notify-removed", &[("username", username.as_str())])
+            } else {
+                t_args("cmd-notify-not-watched", &[("username", username.as_str())])
+            }
+        }
+        _ => {
+            let error_msg = t_args("cmd-notify-usage", &[("command", invoked_name)]);
+            return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    if let Err(e) = app.config.save() {
+        let error_msg = t_args("err-failed-save-config", &[("error", &e)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    app.add_chat_message(connection_id, ChatMessage::info(message))
+}
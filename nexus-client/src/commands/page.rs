@@ -0,0 +1,68 @@
+//! /page command implementation - read and manage the server's info pages
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::io::client_message_type;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /page command
+///
+/// Usage:
+/// - `/page` - List the server's pages
+/// - `/page <name>` - Open a page in the page viewer
+/// - `/page set <name> <text>` - Create or replace a page (admin; `\n` starts a new line)
+/// - `/page delete <name>` - Delete a page (admin)
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let Some(msg) = parse_args(args) else {
+        let error_msg = t_args("cmd-page-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    if let Some(task) = unsupported(app, connection_id, invoked_name, client_message_type(&msg)) {
+        return task;
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
+
+/// Turn the command arguments into a request, or None if they are invalid
+fn parse_args(args: &[String]) -> Option<ClientMessage> {
+    // Get translated subcommand keywords
+    let set_keyword = t("cmd-page-arg-set").to_lowercase();
+    let delete_keyword = t("cmd-page-arg-delete").to_lowercase();
+
+    match args {
+        [] => Some(ClientMessage::PageList),
+        [name] => Some(ClientMessage::PageGet { name: name.clone() }),
+        [subcommand, name] if subcommand.to_lowercase() == delete_keyword => {
+            Some(ClientMessage::PageDelete { name: name.clone() })
+        }
+        [subcommand, name, text @ ..]
+            if subcommand.to_lowercase() == set_keyword && !text.is_empty() =>
+        {
+            // Chat input words lose their line breaks, so `\n` stands in for one
+            Some(ClientMessage::PageSet {
+                name: name.clone(),
+                content: text.join(" ").replace("\\n", "\n"),
+            })
+        }
+        _ => None,
+    }
+}
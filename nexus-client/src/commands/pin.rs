@@ -0,0 +1,91 @@
+//! /pin command implementation - pin a chat message to the top of #server
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /pin command
+///
+/// Usage:
+/// - `/pin` - List the pinned messages with their numbers (for /unpin)
+/// - `/pin <username>` - Pin the most recent #server message from a user
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    match args {
+        [] => list_pins(app, connection_id),
+        [username] => pin_latest_from(app, connection_id, invoked_name, username),
+        _ => {
+            let error_msg = t_args("cmd-pin-usage", &[("command", invoked_name)]);
+            app.add_chat_message(connection_id, ChatMessage::error(error_msg))
+        }
+    }
+}
+
+/// List the currently pinned messages
+fn list_pins(app: &mut NexusApp, connection_id: usize) -> Task<Message> {
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if conn.pinned_messages.is_empty() {
+        return app.add_chat_message(connection_id, ChatMessage::info(t("cmd-pin-none")));
+    }
+
+    let lines: Vec<String> = conn
+        .pinned_messages
+        .iter()
+        .enumerate()
+        .map(|(i, pin)| {
+            t_args(
+                "cmd-pin-list-item",
+                &[
+                    ("number", &(i + 1).to_string()),
+                    ("username", &pin.username),
+                    ("message", &pin.message),
+                    ("pinned_by", &pin.pinned_by),
+                ],
+            )
+        })
+        .collect();
+
+    let mut tasks = vec![app.add_chat_message(connection_id, ChatMessage::info(t("cmd-pin-list")))];
+    for line in lines {
+        tasks.push(app.add_chat_message(connection_id, ChatMessage::info(line)));
+    }
+    Task::batch(tasks)
+}
+
+/// Pin the most recent #server chat message sent by `username`
+fn pin_latest_from(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    username: &str,
+) -> Task<Message> {
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "PinMessage") {
+        return task;
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let Some(message_id) = conn.latest_chat_message_id(username) else {
+        let error_msg = t_args("cmd-pin-no-message", &[("username", username)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    if let Err(e) = conn.send(ClientMessage::PinMessage { message_id }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -0,0 +1,41 @@
+//! /purge command implementation - apply the chat retention policy now
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /purge command
+///
+/// Asks the server to delete chat history outside its retention policy
+/// immediately; the response reports how many messages were removed.
+/// Usage: /purge
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "ChatHistoryPurge") {
+        return task;
+    }
+
+    // /purge takes no arguments
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-purge-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(ClientMessage::ChatHistoryPurge) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -0,0 +1,59 @@
+//! /query command implementation - open a message tab without sending
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, ChatTab, Message};
+use iced::Task;
+
+/// Execute the /query command
+///
+/// Opens (and focuses) a PM tab for an online user without sending anything.
+/// Usage: /query <username>
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    // /query takes exactly one argument
+    if args.len() != 1 {
+        let error_msg = t_args("cmd-query-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let target = &args[0];
+    let target_lower = target.to_lowercase();
+
+    if conn.username.to_lowercase() == target_lower {
+        return app.add_chat_message(connection_id, ChatMessage::error(t("cmd-query-self")));
+    }
+
+    // Reuse an existing tab even if the user has since gone offline
+    let existing_tab = conn
+        .user_messages
+        .keys()
+        .find(|username| username.to_lowercase() == target_lower)
+        .cloned();
+
+    if let Some(username) = existing_tab {
+        return Task::done(Message::SwitchChatTab(ChatTab::UserMessage(username)));
+    }
+
+    // Only open new tabs for online users (use their canonical username casing)
+    let online_user = conn
+        .online_users
+        .iter()
+        .find(|user| user.username.to_lowercase() == target_lower)
+        .map(|user| user.username.clone());
+
+    if let Some(username) = online_user {
+        return Task::done(Message::UserMessageIconClicked(username));
+    }
+
+    let error_msg = t_args("cmd-query-not-online", &[("username", target.as_str())]);
+    app.add_chat_message(connection_id, ChatMessage::error(error_msg))
+}
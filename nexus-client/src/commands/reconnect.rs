@@ -0,0 +1,39 @@
+//! /reconnect command implementation - drop and re-establish the current connection
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+
+/// Execute the /reconnect command
+///
+/// Disconnects the current connection and reconnects using its bookmark,
+/// returning to the same chat tab once the user list confirms it.
+/// Usage: /reconnect
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    // /reconnect takes no arguments
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-reconnect-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    // Ad-hoc connections don't keep their credentials around
+    let Some(bookmark_index) = conn
+        .bookmark_index
+        .filter(|&idx| app.config.get_bookmark(idx).is_some())
+    else {
+        let error_msg = t_args("cmd-reconnect-no-bookmark", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    app.reconnect_bookmark(connection_id, bookmark_index)
+}
@@ -0,0 +1,202 @@
+//! /report command implementation - copy a sanitized snapshot for bug reports
+
+use std::fmt::Write;
+use std::net::IpAddr;
+
+use crate::NexusApp;
+use crate::config::settings::Settings;
+use crate::i18n::{get_locale, t, t_args};
+use crate::network::format_bytes;
+use crate::types::{ChatMessage, Message, REDACTED, ServerConnection};
+use iced::Task;
+use nexus_common::PROTOCOL_VERSION;
+
+/// What the report leaves out, listed in the report itself
+const REDACTED_FIELDS: &str = "passwords, server address, usernames, certificate fingerprint, \
+     server name, proxy address, event messages";
+
+/// Execute the /report command
+///
+/// Copies client, connection and settings details to the clipboard for
+/// pasting into a bug report. Nothing is sent to the server. The report is
+/// untranslated so maintainers can read every one the same way.
+/// Usage: /report
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    // /report takes no arguments
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-report-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let report = build_report(conn, &app.config.settings);
+
+    Task::batch([
+        iced::clipboard::write(report),
+        app.add_chat_message(connection_id, ChatMessage::info(t("cmd-report-copied"))),
+    ])
+}
+
+/// Plain-text snapshot of the client, this connection and the settings
+fn build_report(conn: &ServerConnection, settings: &Settings) -> String {
+    let mut report = String::new();
+    let yes_no = |value: bool| if value { "yes" } else { "no" };
+
+    // Writing to a String can't fail
+    let _ = writeln!(report, "Nexus BBS client report");
+    let _ = writeln!(report, "Redacted: {REDACTED_FIELDS}");
+
+    let _ = writeln!(report, "\n[client]");
+    let _ = writeln!(report, "version: {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(report, "protocol: {PROTOCOL_VERSION}");
+    let _ = writeln!(
+        report,
+        "os: {} ({})",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    let _ = writeln!(report, "locale: {}", get_locale());
+
+    let _ = writeln!(report, "\n[connection]");
+    let _ = writeln!(
+        report,
+        "address: {REDACTED} ({})",
+        address_kind(&conn.server_address)
+    );
+    let _ = writeln!(report, "port: {}", conn.server_port);
+    let _ = writeln!(
+        report,
+        "server version: {}",
+        conn.server_version.as_deref().unwrap_or("unknown")
+    );
+    let _ = writeln!(report, "admin: {}", yes_no(conn.is_admin));
+    let _ = writeln!(report, "permissions: {}", list_or_none(&conn.permissions));
+    let _ = writeln!(
+        report,
+        "disabled permissions: {}",
+        list_or_none(&conn.disabled_permissions)
+    );
+    let _ = writeln!(report, "health: {:?}", conn.keepalive.health());
+    let rtt = conn.keepalive.average_rtt().map_or_else(
+        || "unknown".to_string(),
+        |rtt| format!("{} ms", rtt.as_millis()),
+    );
+    let _ = writeln!(report, "average round trip: {rtt}");
+    let traffic = conn.traffic.totals();
+    let _ = writeln!(
+        report,
+        "traffic: {} sent, {} received",
+        format_bytes(traffic.sent),
+        format_bytes(traffic.received)
+    );
+    let _ = writeln!(report, "maintenance: {}", yes_no(conn.maintenance));
+    let _ = writeln!(
+        report,
+        "limits: message {}, topic {}, slow mode {}s",
+        conn.max_message_length, conn.max_topic_length, conn.chat_slow_mode
+    );
+    match &conn.capabilities {
+        Some(capabilities) => {
+            let _ = writeln!(
+                report,
+                "server features: {}",
+                list_or_none(&capabilities.features)
+            );
+            let _ = writeln!(
+                report,
+                "server message types: {}",
+                capabilities.message_types.len()
+            );
+        }
+        None => {
+            let _ = writeln!(report, "server features: unknown");
+        }
+    }
+
+    let _ = writeln!(report, "\n[settings]");
+    let _ = writeln!(report, "theme: {}", settings.theme);
+    let _ = writeln!(report, "density: {:?}", settings.ui_density);
+    let _ = writeln!(report, "chat font size: {}", settings.chat_font_size);
+    let _ = writeln!(
+        report,
+        "ctrl+enter sends: {}",
+        yes_no(settings.ctrl_enter_sends)
+    );
+    let _ = writeln!(report, "proxy: {}", yes_no(settings.proxy.enabled));
+    let _ = writeln!(
+        report,
+        "forget passwords: {}",
+        yes_no(settings.forget_passwords)
+    );
+    let _ = writeln!(
+        report,
+        "sync preferences: {}",
+        yes_no(settings.sync_preferences)
+    );
+    let _ = writeln!(
+        report,
+        "check for updates: {}",
+        yes_no(settings.check_for_updates)
+    );
+    let _ = writeln!(
+        report,
+        "connection log: {}",
+        yes_no(settings.connection_log_path.is_some())
+    );
+
+    let _ = writeln!(report, "\n[events]");
+    if conn.event_log.is_empty() {
+        let _ = writeln!(report, "none");
+    }
+    for line in conn.event_log.redacted_lines() {
+        let _ = writeln!(report, "{line}");
+    }
+
+    report
+}
+
+/// Describe a server address without revealing it
+fn address_kind(address: &str) -> &'static str {
+    let host = address.trim_start_matches('[').trim_end_matches(']');
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(_)) => "IPv4",
+        Ok(IpAddr::V6(_)) => "IPv6",
+        Err(_) if host.to_lowercase().ends_with(".onion") => "onion",
+        Err(_) => "hostname",
+    }
+}
+
+/// Comma-separated values, or "none" when empty
+fn list_or_none(values: &[String]) -> String {
+    if values.is_empty() {
+        "none".to_string()
+    } else {
+        values.join(", ")
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_kind() {
+        assert_eq!(address_kind("192.0.2.1"), "IPv4");
+        assert_eq!(address_kind("[2001:db8::1]"), "IPv6");
+        assert_eq!(address_kind("2001:db8::1"), "IPv6");
+        assert_eq!(address_kind("example.onion"), "onion");
+        assert_eq!(address_kind("bbs.example.com"), "hostname");
+    }
+}
@@ -35,6 +35,7 @@ pub fn execute(
     let server_description = conn.server_description.clone();
     let server_version = conn.server_version.clone();
     let max_connections_per_ip = conn.max_connections_per_ip;
+    let user_count = conn.user_count;
 
     // Build multi-line output similar to user info
     let mut lines = Vec::new();
@@ -62,6 +63,12 @@ pub fn execute(
         lines.push(format!("{INFO_INDENT}{label} {version}"));
     }
 
+    // Online user count (only sent to users who can see the user list)
+    if let Some(count) = user_count {
+        let label = t("label-users-online").to_lowercase();
+        lines.push(format!("{INFO_INDENT}{label} {count}"));
+    }
+
     // Max connections per IP (admin only)
     if let Some(max_conn) = max_connections_per_ip {
         let label = t("label-max-connections-per-ip").to_lowercase();
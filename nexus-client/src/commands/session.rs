@@ -0,0 +1,55 @@
+//! /session command implementation - show this connection's session and traffic
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::network::format_bytes;
+use crate::types::{ChatMessage, Message, format_endpoint};
+use iced::Task;
+
+/// Execute the /session command
+///
+/// Prints the session ID, the address actually connected to, and the bytes
+/// sent and received since this connection was made (reconnecting starts
+/// again from zero).
+/// Usage: /session
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    // /session takes no arguments
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-session-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get_mut(&connection_id) else {
+        return Task::none();
+    };
+
+    // Read the counters directly so the numbers are current
+    conn.traffic_totals = conn.traffic.totals();
+    let info = t_args(
+        "cmd-session-info",
+        &[
+            ("id", &conn.session_id.to_string()),
+            (
+                "address",
+                &format_endpoint(&conn.server_address, conn.server_port),
+            ),
+        ],
+    );
+    let traffic = t_args(
+        "cmd-session-traffic",
+        &[
+            ("sent", &format_bytes(conn.traffic_totals.sent)),
+            ("received", &format_bytes(conn.traffic_totals.received)),
+        ],
+    );
+
+    Task::batch([
+        app.add_chat_message(connection_id, ChatMessage::info(info)),
+        app.add_chat_message(connection_id, ChatMessage::info(traffic)),
+    ])
+}
@@ -0,0 +1,74 @@
+//! /set command implementation - view and change per-server chat preferences
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+
+/// Execute the /set command
+///
+/// Preferences apply to the current connection and are saved to its bookmark.
+///
+/// Usage:
+/// - `/set joinleave` - Show whether join/leave messages are shown
+/// - `/set joinleave on|off` - Show or hide join/leave messages
+/// - `/set joinleave default` - Follow the global connection notification setting
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let usage = || t_args("cmd-set-usage", &[("command", invoked_name)]);
+
+    let (option, value) = match args {
+        [option] => (option, None),
+        [option, value] => (option, Some(value)),
+        _ => return app.add_chat_message(connection_id, ChatMessage::error(usage())),
+    };
+
+    if !option.eq_ignore_ascii_case("joinleave") {
+        return app.add_chat_message(connection_id, ChatMessage::error(usage()));
+    }
+
+    let Some(conn) = app.connections.get_mut(&connection_id) else {
+        return Task::none();
+    };
+
+    // /set joinleave - show current setting
+    let Some(value) = value else {
+        let message = joinleave_message(conn.connection_notifications);
+        return app.add_chat_message(connection_id, ChatMessage::info(message));
+    };
+
+    let enabled = match value.to_lowercase().as_str() {
+        "on" => Some(true),
+        "off" => Some(false),
+        "default" => None,
+        _ => return app.add_chat_message(connection_id, ChatMessage::error(usage())),
+    };
+    conn.connection_notifications = enabled;
+
+    // Remember the preference for this server if it's bookmarked
+    if let Some(bookmark) = conn
+        .bookmark_index
+        .and_then(|idx| app.config.bookmarks.get_mut(idx))
+    {
+        bookmark.connection_notifications = enabled;
+        if let Err(e) = app.config.save() {
+            let error_msg = t_args("err-failed-save-config", &[("error", &e)]);
+            return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+        }
+    }
+
+    app.add_chat_message(connection_id, ChatMessage::info(joinleave_message(enabled)))
+}
+
+/// Describe a connection's join/leave override
+fn joinleave_message(enabled: Option<bool>) -> String {
+    match enabled {
+        Some(true) => t("cmd-set-joinleave-on"),
+        Some(false) => t("cmd-set-joinleave-off"),
+        None => t("cmd-set-joinleave-default"),
+    }
+}
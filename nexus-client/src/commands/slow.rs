@@ -0,0 +1,62 @@
+//! /slow command implementation - view and set chat slow mode
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /slow command
+///
+/// Usage:
+/// - `/slow` - Show the current slow mode interval
+/// - `/slow <seconds>` - Set the slow mode interval (0 disables slow mode)
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    // /slow - show current setting
+    if args.is_empty() {
+        let message = if conn.chat_slow_mode == 0 {
+            t("cmd-slow-off")
+        } else {
+            t_args(
+                "cmd-slow-current",
+                &[("seconds", &conn.chat_slow_mode.to_string())],
+            )
+        };
+        return app.add_chat_message(connection_id, ChatMessage::info(message));
+    }
+
+    // /slow <seconds> - takes exactly one numeric argument
+    let seconds = match (args.len(), args[0].parse::<u32>()) {
+        (1, Ok(seconds)) => seconds,
+        _ => {
+            let error_msg = t_args("cmd-slow-usage", &[("command", invoked_name)]);
+            return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    let msg = ClientMessage::ServerInfoUpdate {
+        name: None,
+        description: None,
+        max_connections_per_ip: None,
+        image: None,
+        chat_slow_mode: Some(seconds),
+        max_message_length: None,
+        max_topic_length: None,
+    };
+
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
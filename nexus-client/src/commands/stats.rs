@@ -0,0 +1,44 @@
+//! /stats command implementation - show the server's recent connection history
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /stats command
+///
+/// Usage:
+/// - `/stats` - Show connections open now and the last hour of history
+/// - `/stats reset` - Show the history, then clear it on the server
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "ConnectionStats") {
+        return task;
+    }
+
+    let reset = match args {
+        [] => false,
+        [arg] if arg.to_lowercase() == t("cmd-stats-arg-reset").to_lowercase() => true,
+        _ => {
+            let error_msg = t_args("cmd-stats-usage", &[("command", invoked_name)]);
+            return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(ClientMessage::ConnectionStats { reset }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -45,14 +45,20 @@ pub fn execute(
         }
         let topic = args[1..].join(" ");
 
-        // Validate topic content
-        if let Err(e) = validators::validate_chat_topic(&topic) {
+        // Validate topic content against the server's limit
+        let max_length = app
+            .connections
+            .get(&connection_id)
+            .map_or(validators::MAX_CHAT_TOPIC_LENGTH, |conn| {
+                conn.max_topic_length
+            });
+        if let Err(e) = validators::validate_chat_topic(&topic, max_length) {
             let error_msg = match e {
                 ChatTopicError::TooLong => t_args(
                     "err-topic-too-long",
                     &[
-                        ("length", &topic.len().to_string()),
-                        ("max", &validators::MAX_CHAT_TOPIC_LENGTH.to_string()),
+                        ("length", &topic.chars().count().to_string()),
+                        ("max", &max_length.to_string()),
                     ],
                 ),
                 ChatTopicError::ContainsNewlines => t("err-message-contains-newlines"),
@@ -87,13 +93,9 @@ pub fn execute(
 
 /// Check if user has chat_topic_edit permission
 fn has_topic_edit_permission(app: &NexusApp, connection_id: usize) -> bool {
-    app.connections.get(&connection_id).is_some_and(|conn| {
-        conn.is_admin
-            || conn
-                .permissions
-                .iter()
-                .any(|p| p == PERMISSION_CHAT_TOPIC_EDIT)
-    })
+    app.connections
+        .get(&connection_id)
+        .is_some_and(|conn| conn.has_permission(PERMISSION_CHAT_TOPIC_EDIT))
 }
 
 /// Show the current topic
@@ -0,0 +1,64 @@
+//! /transfer command implementation - hand admin over to another account
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, UsernameError};
+
+/// Execute the /transfer command
+///
+/// Makes another account an admin. By default the caller steps down in the
+/// same server transaction; `keep` leaves the caller an admin too.
+/// Usage: /transfer <username> [keep]
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "TransferAdmin") {
+        return task;
+    }
+
+    let demote_self = match args {
+        [_] => true,
+        [_, keep] if keep.to_lowercase() == t("cmd-transfer-arg-keep").to_lowercase() => false,
+        _ => {
+            let error_msg = t_args("cmd-transfer-usage", &[("command", invoked_name)]);
+            return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let username = &args[0];
+
+    if let Err(e) = validators::validate_username(username) {
+        let error_msg = match e {
+            UsernameError::Empty => t("err-username-empty"),
+            UsernameError::TooLong => t_args(
+                "err-username-too-long",
+                &[("max", &validators::MAX_USERNAME_LENGTH.to_string())],
+            ),
+            UsernameError::InvalidCharacters => t("err-username-invalid"),
+        };
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let msg = ClientMessage::TransferAdmin {
+        to_username: username.clone(),
+        demote_self,
+    };
+
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
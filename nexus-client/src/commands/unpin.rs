@@ -0,0 +1,51 @@
+//! /unpin command implementation - remove a pinned chat message
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /unpin command
+///
+/// Usage:
+/// - `/unpin <number>` - Unpin the message with this number (as listed by /pin)
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "UnpinMessage") {
+        return task;
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let pin = match args {
+        [number] => number
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|index| conn.pinned_messages.get(index)),
+        _ => None,
+    };
+
+    let Some(pin) = pin else {
+        let error_msg = t_args("cmd-unpin-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    let msg = ClientMessage::UnpinMessage {
+        message_id: pin.message_id,
+    };
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
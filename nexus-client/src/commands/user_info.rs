@@ -2,7 +2,7 @@
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
-use crate::types::{ChatMessage, Message, PendingRequests, ResponseRouting};
+use crate::types::{ChatMessage, Message, ResponseRouting};
 use iced::Task;
 use nexus_common::protocol::ClientMessage;
 use nexus_common::validators::{self, UsernameError};
@@ -56,8 +56,7 @@ pub fn execute(
 
     // Track this request so the response goes to chat, not the panel
     if let Some(conn) = app.connections.get_mut(&connection_id) {
-        conn.pending_requests
-            .track(message_id, ResponseRouting::DisplayUserInfoInChat);
+        conn.track_request(message_id, ResponseRouting::DisplayUserInfoInChat);
     }
 
     Task::none()
@@ -0,0 +1,41 @@
+//! /whoami command implementation - show your own account as the server sees it
+
+use crate::NexusApp;
+use crate::commands::unsupported;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+/// Execute the /whoami command
+///
+/// Asks the server for this session's username, admin status, effective
+/// permissions and features, read fresh rather than from the login response.
+/// Usage: /whoami
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    // /whoami takes no arguments
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-whoami-usage", &[("command", invoked_name)]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    if let Some(task) = unsupported(app, connection_id, invoked_name, "WhoAmI") {
+        return task;
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(ClientMessage::WhoAmI) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_chat_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -6,11 +6,12 @@ use crate::types::{ChatMessage, ChatTab, Message};
 use iced::Task;
 
 /// Get translated subcommand keywords
-fn get_keywords() -> (String, String, String) {
+fn get_keywords() -> (String, String, String, String) {
     (
         t("cmd-window-arg-next").to_lowercase(),
         t("cmd-window-arg-prev").to_lowercase(),
         t("cmd-window-arg-close").to_lowercase(),
+        t("cmd-window-arg-popout").to_lowercase(),
     )
 }
 
@@ -23,6 +24,8 @@ fn get_keywords() -> (String, String, String) {
 /// - `/window close <username>` or `/w close <username>` - Close specific user's PM tab
 /// - `/window next` or `/w next` - Switch to next tab
 /// - `/window prev` or `/w prev` - Switch to previous tab
+/// - `/window popout` or `/w popout` - Pop the current PM tab out into its own window
+/// - `/window popout <username>` or `/w popout <username>` - Pop a specific user's PM tab out
 pub fn execute(
     app: &mut NexusApp,
     connection_id: usize,
@@ -38,7 +41,7 @@ pub fn execute(
         return list_tabs(app, connection_id);
     }
 
-    let (next_keyword, prev_keyword, close_keyword) = get_keywords();
+    let (next_keyword, prev_keyword, close_keyword, popout_keyword) = get_keywords();
     let arg = args[0].to_lowercase();
 
     if arg == next_keyword {
@@ -79,6 +82,32 @@ pub fn execute(
             let error_msg = t_args("cmd-window-usage", &[("command", invoked_name)]);
             app.add_chat_message(connection_id, ChatMessage::error(error_msg))
         }
+    } else if arg == popout_keyword {
+        if args.len() == 1 {
+            // /window popout - pop out current tab
+            pop_out_current_tab(app, connection_id)
+        } else if args.len() == 2 {
+            // /window popout <username> - pop out specific tab
+            let target = &args[1];
+            let target_lower = target.to_lowercase();
+
+            // Find matching tab (case-insensitive)
+            let matching_user = conn
+                .user_messages
+                .keys()
+                .find(|username| username.to_lowercase() == target_lower)
+                .cloned();
+
+            if let Some(username) = matching_user {
+                Task::done(Message::PopOutMessageTab(username))
+            } else {
+                let error_msg = t_args("cmd-window-not-found", &[("name", target.as_str())]);
+                app.add_chat_message(connection_id, ChatMessage::error(error_msg))
+            }
+        } else {
+            let error_msg = t_args("cmd-window-usage", &[("command", invoked_name)]);
+            app.add_chat_message(connection_id, ChatMessage::error(error_msg))
+        }
     } else {
         let error_msg = t_args("cmd-window-usage", &[("command", invoked_name)]);
         app.add_chat_message(connection_id, ChatMessage::error(error_msg))
@@ -125,3 +154,18 @@ fn close_current_tab(app: &mut NexusApp, connection_id: usize) -> Task<Message>
         }
     }
 }
+
+/// Pop the current PM tab out into its own window
+fn pop_out_current_tab(app: &mut NexusApp, connection_id: usize) -> Task<Message> {
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    match &conn.active_chat_tab {
+        ChatTab::Server => app.add_chat_message(
+            connection_id,
+            ChatMessage::error(t("cmd-window-popout-server")),
+        ),
+        ChatTab::UserMessage(username) => Task::done(Message::PopOutMessageTab(username.clone())),
+    }
+}
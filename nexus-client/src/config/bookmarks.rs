@@ -26,6 +26,13 @@ impl Config {
         self.bookmarks.get(index)
     }
 
+    /// Clear the saved password of every bookmark
+    pub fn clear_bookmark_passwords(&mut self) {
+        for bookmark in &mut self.bookmarks {
+            bookmark.password.clear();
+        }
+    }
+
     /// Update an existing bookmark at the given index
     ///
     /// Does nothing if the index is out of bounds.
@@ -145,4 +152,24 @@ mod tests {
         assert_eq!(config.bookmarks.len(), 1);
         assert_eq!(config.bookmarks[0].name, "Server 1");
     }
+
+    #[test]
+    fn test_clear_bookmark_passwords() {
+        let mut config = Config::default();
+        for name in ["Server 1", "Server 2"] {
+            config.add_bookmark(ServerBookmark {
+                name: name.to_string(),
+                username: "alice".to_string(),
+                password: "secret".to_string(),
+                ..Default::default()
+            });
+        }
+
+        config.clear_bookmark_passwords();
+
+        assert!(config.bookmarks.iter().all(|b| b.password.is_empty()));
+        // Other fields are left alone
+        assert_eq!(config.bookmarks[1].name, "Server 2");
+        assert_eq!(config.bookmarks[1].username, "alice");
+    }
 }
@@ -0,0 +1,73 @@
+//! Interface density preference configuration
+//!
+//! Compact density tightens the spacing and padding of the chat, server list
+//! and user list so more fits on screen. Icon buttons keep their size in both
+//! modes so they stay easy to hit.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::t;
+use crate::style::{COMFORTABLE_DENSITY, COMPACT_DENSITY, Density};
+
+/// How tightly chat and list views are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UiDensity {
+    /// Standard spacing
+    #[default]
+    Comfortable,
+    /// Reduced spacing and padding
+    Compact,
+}
+
+impl UiDensity {
+    /// All densities, in picker order
+    pub const ALL: [Self; 2] = [Self::Comfortable, Self::Compact];
+
+    /// Spacing and padding used by views at this density
+    pub fn metrics(self) -> Density {
+        match self {
+            Self::Comfortable => COMFORTABLE_DENSITY,
+            Self::Compact => COMPACT_DENSITY,
+        }
+    }
+}
+
+impl std::fmt::Display for UiDensity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let key = match self {
+            Self::Comfortable => "density-comfortable",
+            Self::Compact => "density-compact",
+        };
+        write!(f, "{}", t(key))
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialization() {
+        assert_eq!(
+            serde_json::to_string(&UiDensity::Compact).unwrap(),
+            "\"compact\""
+        );
+        let parsed: UiDensity = serde_json::from_str("\"comfortable\"").unwrap();
+        assert_eq!(parsed, UiDensity::Comfortable);
+    }
+
+    #[test]
+    fn test_compact_is_tighter() {
+        let comfortable = UiDensity::Comfortable.metrics();
+        let compact = UiDensity::Compact.metrics();
+        assert!(compact.chat_spacing <= comfortable.chat_spacing);
+        assert!(compact.chat_padding < comfortable.chat_padding);
+        assert!(compact.list_padding < comfortable.list_padding);
+        assert!(compact.list_item_padding < comfortable.list_item_padding);
+    }
+}
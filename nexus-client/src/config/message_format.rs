@@ -0,0 +1,446 @@
+//! Chat message format templates
+//!
+//! A template such as `[{time}] <{nick}> {message}` controls how chat
+//! messages are laid out. Placeholders are `{time}`, `{date}`, `{nick}` and
+//! `{message}`; `{nick}` and `{message}` must each appear exactly once.
+//! `{{` and `}}` stand for literal braces.
+//!
+//! While timestamps are hidden, every whitespace-separated word containing
+//! `{time}` or `{date}` is left out along with the whitespace after it, so
+//! `[{time}] {nick}: {message}` renders as `alice: hello`.
+
+use crate::i18n::t;
+
+/// Template used when none is set or the stored one doesn't parse
+pub const DEFAULT_MESSAGE_FORMAT: &str = "[{time}] {nick}: {message}";
+
+/// A placeholder or a run of literal text in a template
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Time,
+    Date,
+    Nick,
+    Message,
+}
+
+/// A whitespace-separated word of a template, including the whitespace after it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Word {
+    parts: Vec<Part>,
+}
+
+impl Word {
+    /// Whether the word contains `{time}` or `{date}`
+    fn has_timestamp(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|part| matches!(part, Part::Time | Part::Date))
+    }
+
+    /// Whether the word already ends in whitespace (so the next word begins)
+    fn ends_with_whitespace(&self) -> bool {
+        matches!(
+            self.parts.last(),
+            Some(Part::Literal(text)) if text.ends_with(char::is_whitespace)
+        )
+    }
+
+    /// Append a literal character, merging it with a preceding literal
+    fn push_char(&mut self, c: char) {
+        match self.parts.last_mut() {
+            Some(Part::Literal(text)) => text.push(c),
+            _ => self.parts.push(Part::Literal(c.to_string())),
+        }
+    }
+}
+
+/// How a piece of a formatted message is styled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanKind {
+    /// A word containing the time or date
+    Timestamp,
+    /// The nickname and any other template text
+    Nick,
+    /// The message itself
+    Message,
+}
+
+/// Why a message format template was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageFormatError {
+    /// A `{` has no matching `}`
+    UnclosedBrace,
+    /// A placeholder name isn't one of time, date, nick or message
+    UnknownPlaceholder(String),
+    /// A required placeholder (nick or message) is missing
+    MissingPlaceholder(&'static str),
+    /// A placeholder that may only appear once (nick or message) is repeated
+    DuplicatePlaceholder(&'static str),
+}
+
+/// A parsed chat message format template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageFormat {
+    /// Words before `{message}`
+    before: Vec<Word>,
+    /// Words after `{message}`
+    after: Vec<Word>,
+}
+
+impl Default for MessageFormat {
+    fn default() -> Self {
+        Self::parse(DEFAULT_MESSAGE_FORMAT).expect("default message format is valid")
+    }
+}
+
+impl MessageFormat {
+    /// Parse a template, checking its placeholders
+    pub fn parse(template: &str) -> Result<Self, MessageFormatError> {
+        let parts = parse_parts(template)?;
+
+        for (required, name) in [(Part::Nick, "nick"), (Part::Message, "message")] {
+            match parts.iter().filter(|part| **part == required).count() {
+                0 => return Err(MessageFormatError::MissingPlaceholder(name)),
+                1 => {}
+                _ => return Err(MessageFormatError::DuplicatePlaceholder(name)),
+            }
+        }
+
+        let split = parts
+            .iter()
+            .position(|part| *part == Part::Message)
+            .expect("message placeholder was checked above");
+        Ok(Self {
+            before: split_words(&parts[..split]),
+            after: split_words(&parts[split + 1..]),
+        })
+    }
+
+    /// Parse a template, falling back to the default format if it is invalid
+    pub fn parse_or_default(template: &str) -> Self {
+        Self::parse(template).unwrap_or_default()
+    }
+
+    /// Lay out one line of a message as styled spans
+    ///
+    /// `time` and `date` are `None` while timestamps are hidden, which drops
+    /// the words containing them. Adjacent spans of the same kind are merged.
+    pub fn render(
+        &self,
+        time: Option<&str>,
+        date: Option<&str>,
+        nick: &str,
+        message: &str,
+    ) -> Vec<(SpanKind, String)> {
+        let mut spans = Vec::new();
+        push_words(&mut spans, &self.before, time, date, nick);
+        push_span(&mut spans, SpanKind::Message, message);
+        push_words(&mut spans, &self.after, time, date, nick);
+        spans
+    }
+}
+
+/// Split a template into literal text and placeholders
+fn parse_parts(template: &str) -> Result<Vec<Part>, MessageFormatError> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some('{') | None => return Err(MessageFormatError::UnclosedBrace),
+                        Some(c) => name.push(c),
+                    }
+                }
+                let part = match name.as_str() {
+                    "time" => Part::Time,
+                    "date" => Part::Date,
+                    "nick" => Part::Nick,
+                    "message" => Part::Message,
+                    _ => return Err(MessageFormatError::UnknownPlaceholder(name)),
+                };
+                if !literal.is_empty() {
+                    parts.push(Part::Literal(std::mem::take(&mut literal)));
+                }
+                parts.push(part);
+            }
+            _ => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        parts.push(Part::Literal(literal));
+    }
+    Ok(parts)
+}
+
+/// Group template parts into whitespace-separated words
+fn split_words(parts: &[Part]) -> Vec<Word> {
+    let mut words = vec![Word::default()];
+
+    for part in parts {
+        if let Part::Literal(text) = part {
+            for c in text.chars() {
+                let word = words.last_mut().expect("words is never empty");
+                if !c.is_whitespace() && word.ends_with_whitespace() {
+                    words.push(Word::default());
+                }
+                words.last_mut().expect("words is never empty").push_char(c);
+            }
+        } else {
+            if words.last().is_some_and(|word| word.ends_with_whitespace()) {
+                words.push(Word::default());
+            }
+            words
+                .last_mut()
+                .expect("words is never empty")
+                .parts
+                .push(part.clone());
+        }
+    }
+
+    words.retain(|word| !word.parts.is_empty());
+    words
+}
+
+/// Append the text of template words, skipping those whose timestamp is hidden
+fn push_words(
+    spans: &mut Vec<(SpanKind, String)>,
+    words: &[Word],
+    time: Option<&str>,
+    date: Option<&str>,
+    nick: &str,
+) {
+    for word in words {
+        let kind = if word.has_timestamp() {
+            SpanKind::Timestamp
+        } else {
+            SpanKind::Nick
+        };
+
+        let mut text = String::new();
+        for part in &word.parts {
+            let value = match part {
+                Part::Literal(literal) => Some(literal.as_str()),
+                Part::Time => time,
+                Part::Date => date,
+                Part::Nick => Some(nick),
+                Part::Message => Some(""),
+            };
+            let Some(value) = value else {
+                text.clear();
+                break;
+            };
+            text.push_str(value);
+        }
+        push_span(spans, kind, &text);
+    }
+}
+
+/// Append text to the spans, merging it into the last span of the same kind
+fn push_span(spans: &mut Vec<(SpanKind, String)>, kind: SpanKind, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    match spans.last_mut() {
+        Some((last_kind, last_text)) if *last_kind == kind => last_text.push_str(text),
+        _ => spans.push((kind, text.to_string())),
+    }
+}
+
+// =============================================================================
+// Presets
+// =============================================================================
+
+/// Built-in message format templates offered in the settings panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormatPreset {
+    /// `[12:30:00] alice: hello`
+    Default,
+    /// `12:30:00 alice: hello`
+    Compact,
+    /// `[12:30:00] <alice> hello`
+    Irc,
+    /// `[2025-01-15 12:30:00] alice: hello`
+    Verbose,
+}
+
+impl MessageFormatPreset {
+    /// All presets, in picker order
+    pub const ALL: [Self; 4] = [Self::Default, Self::Compact, Self::Irc, Self::Verbose];
+
+    /// Template for this preset
+    pub fn template(self) -> &'static str {
+        match self {
+            Self::Default => DEFAULT_MESSAGE_FORMAT,
+            Self::Compact => "{time} {nick}: {message}",
+            Self::Irc => "[{time}] <{nick}> {message}",
+            Self::Verbose => "[{date} {time}] {nick}: {message}",
+        }
+    }
+
+    /// Preset with exactly this template, or `None` for a custom template
+    pub fn from_template(template: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.template() == template)
+    }
+}
+
+impl std::fmt::Display for MessageFormatPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let key = match self {
+            Self::Default => "message-format-preset-default",
+            Self::Compact => "message-format-preset-compact",
+            Self::Irc => "message-format-preset-irc",
+            Self::Verbose => "message-format-preset-verbose",
+        };
+        write!(f, "{}", t(key))
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Render a line and join the spans into plain text
+    fn render_text(template: &str, time: Option<&str>, date: Option<&str>) -> String {
+        MessageFormat::parse(template)
+            .expect("valid template")
+            .render(time, date, "alice", "hello")
+            .into_iter()
+            .map(|(_, text)| text)
+            .collect()
+    }
+
+    #[test]
+    fn test_presets_are_valid() {
+        for preset in MessageFormatPreset::ALL {
+            assert!(MessageFormat::parse(preset.template()).is_ok());
+            assert_eq!(
+                MessageFormatPreset::from_template(preset.template()),
+                Some(preset)
+            );
+        }
+        assert_eq!(MessageFormatPreset::from_template("{nick} {message}"), None);
+    }
+
+    #[test]
+    fn test_render_presets() {
+        let time = Some("12:30");
+        let date = Some("2025-01-15");
+        assert_eq!(
+            render_text(DEFAULT_MESSAGE_FORMAT, time, date),
+            "[12:30] alice: hello"
+        );
+        assert_eq!(
+            render_text(MessageFormatPreset::Irc.template(), time, date),
+            "[12:30] <alice> hello"
+        );
+        assert_eq!(
+            render_text(MessageFormatPreset::Verbose.template(), time, date),
+            "[2025-01-15 12:30] alice: hello"
+        );
+    }
+
+    #[test]
+    fn test_hidden_timestamps_drop_their_words() {
+        assert_eq!(
+            render_text(DEFAULT_MESSAGE_FORMAT, None, None),
+            "alice: hello"
+        );
+        assert_eq!(
+            render_text(MessageFormatPreset::Verbose.template(), None, None),
+            "alice: hello"
+        );
+        assert_eq!(
+            render_text("{nick} {time} {message}", None, None),
+            "alice hello"
+        );
+    }
+
+    #[test]
+    fn test_span_kinds() {
+        let spans = MessageFormat::parse(MessageFormatPreset::Irc.template())
+            .expect("valid template")
+            .render(Some("12:30"), None, "alice", "hello");
+        assert_eq!(
+            spans,
+            vec![
+                (SpanKind::Timestamp, "[12:30] ".to_string()),
+                (SpanKind::Nick, "<alice> ".to_string()),
+                (SpanKind::Message, "hello".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_after_message() {
+        assert_eq!(
+            render_text("{nick}: {message} ({time})", Some("12:30"), None),
+            "alice: hello (12:30)"
+        );
+    }
+
+    #[test]
+    fn test_escaped_braces() {
+        assert_eq!(
+            render_text("{{{nick}}} {message}", None, None),
+            "{alice} hello"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            MessageFormat::parse("[{time] {nick}: {message}"),
+            Err(MessageFormatError::UnclosedBrace)
+        );
+        assert_eq!(
+            MessageFormat::parse("{nick}: {message"),
+            Err(MessageFormatError::UnclosedBrace)
+        );
+        assert_eq!(
+            MessageFormat::parse("{user}: {message}"),
+            Err(MessageFormatError::UnknownPlaceholder("user".to_string()))
+        );
+        assert_eq!(
+            MessageFormat::parse("[{time}] {message}"),
+            Err(MessageFormatError::MissingPlaceholder("nick"))
+        );
+        assert_eq!(
+            MessageFormat::parse("{nick}"),
+            Err(MessageFormatError::MissingPlaceholder("message"))
+        );
+        assert_eq!(
+            MessageFormat::parse("{nick}: {message} {message}"),
+            Err(MessageFormatError::DuplicatePlaceholder("message"))
+        );
+    }
+
+    #[test]
+    fn test_parse_or_default_falls_back() {
+        assert_eq!(
+            MessageFormat::parse_or_default("{bogus}"),
+            MessageFormat::default()
+        );
+    }
+}
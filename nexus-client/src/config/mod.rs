@@ -5,9 +5,13 @@
 //! - `bookmarks` - Server bookmarks for quick connect
 
 mod bookmarks;
+pub mod density;
+pub mod message_format;
 mod persistence;
+pub mod quiet_hours;
 pub mod settings;
 pub mod theme;
+pub mod timezone;
 
 use crate::types::ServerBookmark;
 use settings::Settings;
@@ -45,4 +49,32 @@ mod tests {
         // Only test Config-level defaults; Settings defaults are tested in settings.rs
         assert_eq!(config.bookmarks.len(), 0);
     }
+
+    #[test]
+    fn test_bookmark_without_theme_uses_global() {
+        // Bookmarks saved before per-server themes existed have no theme field
+        let json = r#"{"bookmarks":[{"name":"Test","address":"::1","port":"7500","username":"","password":""}]}"#;
+        let config: Config = serde_json::from_str(json).expect("deserialize");
+        assert!(config.bookmarks[0].theme.is_none());
+    }
+
+    #[test]
+    fn test_bookmark_theme_serialization_roundtrip() {
+        let mut config = Config::default();
+        config.bookmarks.push(ServerBookmark {
+            name: "Test".to_string(),
+            theme: Some(iced::Theme::Nord.into()),
+            ..Default::default()
+        });
+
+        let json = serde_json::to_string(&config).expect("serialize");
+        let deserialized: Config = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(
+            deserialized.bookmarks[0]
+                .theme
+                .as_ref()
+                .map(|t| t.0.clone()),
+            Some(iced::Theme::Nord)
+        );
+    }
 }
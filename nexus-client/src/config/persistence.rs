@@ -54,9 +54,15 @@ impl Config {
             })?;
         }
 
-        // Serialize config to pretty JSON
-        let json = serde_json::to_string_pretty(self)
-            .map_err(|e| t_args("err-failed-serialize-config", &[("error", &e.to_string())]))?;
+        // Serialize config to pretty JSON, leaving out passwords if the user opted out
+        let json = if self.settings.forget_passwords {
+            let mut config = self.clone();
+            config.clear_bookmark_passwords();
+            serde_json::to_string_pretty(&config)
+        } else {
+            serde_json::to_string_pretty(self)
+        }
+        .map_err(|e| t_args("err-failed-serialize-config", &[("error", &e.to_string())]))?;
 
         // Write to disk
         fs::write(&path, json)
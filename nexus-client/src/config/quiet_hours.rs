@@ -0,0 +1,183 @@
+//! Quiet hours schedule for notifications
+//!
+//! During quiet hours the client doesn't request attention (taskbar flash or
+//! dock bounce). Messages still arrive and mark tabs unread as usual. The
+//! schedule is checked against the local clock, and a range whose start is
+//! later than its end crosses midnight (e.g. 22:00–08:00).
+
+use chrono::{Local, NaiveTime};
+
+/// Default start of quiet hours
+pub const QUIET_HOURS_START_DEFAULT: &str = "22:00";
+
+/// Default end of quiet hours
+pub const QUIET_HOURS_END_DEFAULT: &str = "08:00";
+
+/// Format of quiet hours times (24-hour "HH:MM")
+const TIME_FORMAT: &str = "%H:%M";
+
+/// Daily time range during which notifications are suppressed
+///
+/// Times are kept as typed so the fields can be edited freely; they are
+/// validated when settings are saved.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct QuietHours {
+    /// Suppress notifications during the range
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Start of the range ("HH:MM", inclusive)
+    #[serde(default = "default_start")]
+    pub start: String,
+
+    /// End of the range ("HH:MM", exclusive)
+    #[serde(default = "default_end")]
+    pub end: String,
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start: default_start(),
+            end: default_end(),
+        }
+    }
+}
+
+/// Why a quiet hours setting was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuietHoursError {
+    /// Start time isn't a valid "HH:MM" time
+    InvalidStart,
+    /// End time isn't a valid "HH:MM" time
+    InvalidEnd,
+}
+
+impl QuietHours {
+    /// Check the start and end times (disabled quiet hours are always valid)
+    pub fn validate(&self) -> Result<(), QuietHoursError> {
+        if !self.enabled {
+            return Ok(());
+        }
+        parse_time(&self.start).ok_or(QuietHoursError::InvalidStart)?;
+        parse_time(&self.end).ok_or(QuietHoursError::InvalidEnd)?;
+        Ok(())
+    }
+
+    /// Whether quiet hours are in effect right now on the local clock
+    pub fn is_active(&self) -> bool {
+        self.is_active_at(Local::now().time())
+    }
+
+    /// Whether quiet hours are in effect at the given time of day
+    ///
+    /// Disabled or invalid schedules are never active, and equal start and
+    /// end times make an empty range.
+    pub fn is_active_at(&self, now: NaiveTime) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let (Some(start), Some(end)) = (parse_time(&self.start), parse_time(&self.end)) else {
+            return false;
+        };
+        if start <= end {
+            start <= now && now < end
+        } else {
+            // Crosses midnight
+            now >= start || now < end
+        }
+    }
+}
+
+/// Parse an "HH:MM" time, ignoring surrounding whitespace
+fn parse_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), TIME_FORMAT).ok()
+}
+
+fn default_start() -> String {
+    QUIET_HOURS_START_DEFAULT.to_string()
+}
+
+fn default_end() -> String {
+    QUIET_HOURS_END_DEFAULT.to_string()
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn quiet_hours(start: &str, end: &str) -> QuietHours {
+        QuietHours {
+            enabled: true,
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_default_is_disabled() {
+        let quiet = QuietHours::default();
+        assert!(!quiet.enabled);
+        assert_eq!(quiet.start, QUIET_HOURS_START_DEFAULT);
+        assert_eq!(quiet.end, QUIET_HOURS_END_DEFAULT);
+        assert!(!quiet.is_active_at(at(23, 0)));
+    }
+
+    #[test]
+    fn test_same_day_range() {
+        let quiet = quiet_hours("09:00", "17:30");
+        assert!(!quiet.is_active_at(at(8, 59)));
+        assert!(quiet.is_active_at(at(9, 0)));
+        assert!(quiet.is_active_at(at(17, 29)));
+        assert!(!quiet.is_active_at(at(17, 30)));
+    }
+
+    #[test]
+    fn test_range_crossing_midnight() {
+        let quiet = quiet_hours("22:00", "08:00");
+        assert!(!quiet.is_active_at(at(21, 59)));
+        assert!(quiet.is_active_at(at(22, 0)));
+        assert!(quiet.is_active_at(at(23, 59)));
+        assert!(quiet.is_active_at(at(0, 0)));
+        assert!(quiet.is_active_at(at(7, 59)));
+        assert!(!quiet.is_active_at(at(8, 0)));
+        assert!(!quiet.is_active_at(at(12, 0)));
+    }
+
+    #[test]
+    fn test_empty_range_is_never_active() {
+        let quiet = quiet_hours("08:00", "08:00");
+        assert!(!quiet.is_active_at(at(8, 0)));
+        assert!(!quiet.is_active_at(at(20, 0)));
+    }
+
+    #[test]
+    fn test_invalid_times() {
+        assert_eq!(
+            quiet_hours("25:00", "08:00").validate(),
+            Err(QuietHoursError::InvalidStart)
+        );
+        assert_eq!(
+            quiet_hours("22:00", "8pm").validate(),
+            Err(QuietHoursError::InvalidEnd)
+        );
+        assert!(quiet_hours(" 22:00 ", "8:05").validate().is_ok());
+        assert!(!quiet_hours("", "08:00").is_active_at(at(3, 0)));
+
+        // A disabled schedule isn't checked
+        let disabled = QuietHours {
+            enabled: false,
+            ..quiet_hours("bad", "bad")
+        };
+        assert!(disabled.validate().is_ok());
+    }
+}
@@ -1,8 +1,13 @@
 //! User preference settings
 
+use crate::network::ProxyConfig;
 use crate::style::{WINDOW_HEIGHT, WINDOW_WIDTH};
 
+use super::density::UiDensity;
+use super::message_format::{DEFAULT_MESSAGE_FORMAT, MessageFormat};
+use super::quiet_hours::QuietHours;
 use super::theme::ThemePreference;
+use super::timezone::TimezonePreference;
 
 // =============================================================================
 // Constants
@@ -26,6 +31,45 @@ pub const CHAT_FONT_SIZE_DEFAULT: u8 = 13;
 /// All valid chat font sizes for the picker
 pub const CHAT_FONT_SIZES: &[u8] = &[9, 10, 11, 12, 13, 14, 15, 16];
 
+/// Minimum allowed limit on open private message tabs per server
+pub const MAX_MESSAGE_TABS_MIN: usize = 1;
+
+/// Maximum allowed limit on open private message tabs per server
+pub const MAX_MESSAGE_TABS_MAX: usize = 500;
+
+/// Default limit on open private message tabs per server
+pub const MAX_MESSAGE_TABS_DEFAULT: usize = 50;
+
+/// Maximum allowed line threshold for confirming a large paste
+pub const PASTE_CONFIRM_LINES_MAX: usize = 1000;
+
+/// Default line threshold for confirming a large paste (0 never asks)
+pub const PASTE_CONFIRM_LINES_DEFAULT: usize = 10;
+
+/// Maximum allowed line count before a chat message is collapsed
+pub const COLLAPSE_MESSAGE_LINES_MAX: usize = 1000;
+
+/// Default line count before a chat message is collapsed (0 never collapses)
+pub const COLLAPSE_MESSAGE_LINES_DEFAULT: usize = 20;
+
+/// Minimum allowed idle time before going away, in minutes
+pub const AUTO_AWAY_MINUTES_MIN: u32 = 1;
+
+/// Maximum allowed idle time before going away, in minutes
+pub const AUTO_AWAY_MINUTES_MAX: u32 = 720;
+
+/// Default idle time before going away, in minutes
+pub const AUTO_AWAY_MINUTES_DEFAULT: u32 = 10;
+
+/// Default SOCKS5 proxy address (a local Tor daemon)
+pub const PROXY_ADDRESS_DEFAULT: &str = "127.0.0.1";
+
+/// Default SOCKS5 proxy port (Tor's SOCKS port)
+pub const PROXY_PORT_DEFAULT: u16 = 9050;
+
+/// Maximum proxy address length (longest DNS name)
+pub const PROXY_ADDRESS_MAX_LENGTH: usize = 253;
+
 // =============================================================================
 // Settings
 // =============================================================================
@@ -37,14 +81,83 @@ pub struct Settings {
     #[serde(default)]
     pub theme: ThemePreference,
 
+    /// Spacing density of chat and list views
+    #[serde(default)]
+    pub ui_density: UiDensity,
+
+    /// Show admin and moderator badges next to names in the user list
+    #[serde(default = "default_true")]
+    pub show_role_badges: bool,
+
     /// Font size for chat messages (9-16)
     #[serde(default = "default_chat_font_size")]
     pub chat_font_size: u8,
 
-    /// Show user connect/disconnect notifications in chat
+    /// Maximum open private message tabs per server
+    ///
+    /// Opening one more closes the least recently active tab; its history is
+    /// kept and comes back if that user messages again.
+    #[serde(default = "default_max_message_tabs")]
+    pub max_message_tabs: usize,
+
+    /// Send chat messages with Ctrl+Enter (Cmd+Enter on macOS), so that a
+    /// plain Enter inserts a newline instead
+    #[serde(default)]
+    pub ctrl_enter_sends: bool,
+
+    /// Ask before sending a chat message containing a paste of at least this
+    /// many lines (0 never asks)
+    #[serde(default = "default_paste_confirm_lines")]
+    pub paste_confirm_lines: usize,
+
+    /// Show chat messages longer than this many lines collapsed, with a
+    /// "show more" toggle (0 never collapses)
+    #[serde(default = "default_collapse_message_lines")]
+    pub collapse_message_lines: usize,
+
+    /// Template for laying out chat messages (e.g., "[{time}] <{nick}> {message}")
+    ///
+    /// An invalid template is shown with the default format instead.
+    #[serde(default = "default_chat_message_format")]
+    pub chat_message_format: String,
+
+    /// Show user connect/disconnect notifications in chat (bookmarks can override)
     #[serde(default = "default_true")]
     pub show_connection_notifications: bool,
 
+    /// Show the chat topic on reconnect only when it changed since it was
+    /// last seen for that bookmark (topic changes are always shown)
+    #[serde(default = "default_true")]
+    pub hide_repeated_topic: bool,
+
+    /// Request attention (taskbar flash or dock bounce) when a connection
+    /// drops unexpectedly, unless the window is focused on that connection
+    #[serde(default)]
+    pub alert_on_disconnect: bool,
+
+    /// Request attention when someone `@mentions` the user in #server,
+    /// unless the window is focused on that connection
+    #[serde(default = "default_true")]
+    pub alert_on_mention: bool,
+
+    /// Switch to a private message tab when a message arrives in it, unless
+    /// quiet hours are on or the user has interacted with the client recently
+    #[serde(default)]
+    pub focus_new_messages: bool,
+
+    /// Daily schedule during which attention requests are suppressed
+    #[serde(default)]
+    pub quiet_hours: QuietHours,
+
+    /// Still request attention for watched users coming online during quiet
+    /// hours (the watch list is kept per bookmark)
+    #[serde(default = "default_true")]
+    pub watched_users_ignore_quiet_hours: bool,
+
+    /// Mark the user away on servers that support it after a period of inactivity
+    #[serde(default)]
+    pub auto_away: AutoAwaySettings,
+
     /// Show timestamps in chat messages
     #[serde(default = "default_true")]
     pub show_timestamps: bool,
@@ -57,6 +170,36 @@ pub struct Settings {
     #[serde(default = "default_true")]
     pub show_seconds: bool,
 
+    /// Timezone for displaying timestamps (system local, UTC, or a named zone)
+    #[serde(default)]
+    pub timestamp_timezone: TimezonePreference,
+
+    /// Never save bookmark passwords to disk, prompting for them on connect
+    #[serde(default)]
+    pub forget_passwords: bool,
+
+    /// Store theme and chat display preferences on servers that support it,
+    /// applying them at login so they follow the user across devices
+    #[serde(default)]
+    pub sync_preferences: bool,
+
+    /// Look for a newer client release at startup and mention it in a banner
+    /// (off by default, since it contacts the release host; nothing is downloaded)
+    #[serde(default)]
+    pub check_for_updates: bool,
+
+    /// Release URL queried by the update check (None = the project's releases)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_url: Option<String>,
+
+    /// SOCKS5 proxy that all server connections are routed through
+    #[serde(default)]
+    pub proxy: ProxySettings,
+
+    /// File that connection lifecycle events are appended to (None = memory only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_log_path: Option<String>,
+
     /// User avatar as data URI (e.g., "data:image/png;base64,...")
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub avatar: Option<String>,
@@ -82,11 +225,32 @@ impl Default for Settings {
     fn default() -> Self {
         Self {
             theme: ThemePreference::default(),
+            ui_density: UiDensity::default(),
+            show_role_badges: default_true(),
             chat_font_size: default_chat_font_size(),
+            max_message_tabs: default_max_message_tabs(),
+            ctrl_enter_sends: false,
+            paste_confirm_lines: default_paste_confirm_lines(),
+            collapse_message_lines: default_collapse_message_lines(),
+            chat_message_format: default_chat_message_format(),
             show_connection_notifications: default_true(),
+            hide_repeated_topic: default_true(),
+            alert_on_disconnect: false,
+            alert_on_mention: default_true(),
+            focus_new_messages: false,
+            quiet_hours: QuietHours::default(),
+            watched_users_ignore_quiet_hours: default_true(),
+            auto_away: AutoAwaySettings::default(),
             show_timestamps: default_true(),
             use_24_hour_time: false,
             show_seconds: default_true(),
+            timestamp_timezone: TimezonePreference::default(),
+            forget_passwords: false,
+            sync_preferences: false,
+            check_for_updates: false,
+            update_url: None,
+            proxy: ProxySettings::default(),
+            connection_log_path: None,
             avatar: None,
             window_width: default_window_width(),
             window_height: default_window_height(),
@@ -96,6 +260,190 @@ impl Default for Settings {
     }
 }
 
+impl Settings {
+    /// Preferences to store on the server when `sync_preferences` is enabled
+    pub fn synced_preferences(&self) -> serde_json::Value {
+        let synced = SyncedPreferences {
+            theme: Some(self.theme.clone()),
+            chat_message_format: Some(self.chat_message_format.clone()),
+            show_connection_notifications: Some(self.show_connection_notifications),
+            show_timestamps: Some(self.show_timestamps),
+            use_24_hour_time: Some(self.use_24_hour_time),
+            show_seconds: Some(self.show_seconds),
+        };
+        serde_json::to_value(synced).unwrap_or_default()
+    }
+
+    /// Merge preferences stored on the server over the local settings
+    ///
+    /// Keys the server doesn't have keep their local values, and anything this
+    /// client doesn't understand is ignored.
+    pub fn apply_synced_preferences(&mut self, preferences: &serde_json::Value) {
+        let Ok(synced) = serde_json::from_value::<SyncedPreferences>(preferences.clone()) else {
+            return;
+        };
+
+        if let Some(theme) = synced.theme {
+            self.theme = theme;
+        }
+        if let Some(format) = synced.chat_message_format
+            && MessageFormat::parse(&format).is_ok()
+        {
+            self.chat_message_format = format;
+        }
+        if let Some(enabled) = synced.show_connection_notifications {
+            self.show_connection_notifications = enabled;
+        }
+        if let Some(enabled) = synced.show_timestamps {
+            self.show_timestamps = enabled;
+        }
+        if let Some(enabled) = synced.use_24_hour_time {
+            self.use_24_hour_time = enabled;
+        }
+        if let Some(enabled) = synced.show_seconds {
+            self.show_seconds = enabled;
+        }
+    }
+}
+
+// =============================================================================
+// Auto-Away Settings
+// =============================================================================
+
+/// Automatic away status after a period without input
+///
+/// The timeout is kept while auto-away is disabled so toggling it back on
+/// doesn't lose it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AutoAwaySettings {
+    /// Go away automatically when idle
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Minutes without input before going away
+    #[serde(default = "default_auto_away_minutes")]
+    pub minutes: u32,
+}
+
+impl Default for AutoAwaySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            minutes: default_auto_away_minutes(),
+        }
+    }
+}
+
+impl AutoAwaySettings {
+    /// Idle time before going away, or `None` when auto-away is disabled
+    pub fn timeout(&self) -> Option<std::time::Duration> {
+        self.enabled.then(|| {
+            let minutes = self
+                .minutes
+                .clamp(AUTO_AWAY_MINUTES_MIN, AUTO_AWAY_MINUTES_MAX);
+            std::time::Duration::from_secs(u64::from(minutes) * 60)
+        })
+    }
+}
+
+// =============================================================================
+// Proxy Settings
+// =============================================================================
+
+/// SOCKS5 proxy configuration (e.g., Tor or I2P)
+///
+/// The address and port are kept while the proxy is disabled so toggling it
+/// back on doesn't lose them.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProxySettings {
+    /// Route server connections through the proxy
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Proxy host name or IP address
+    #[serde(default = "default_proxy_address")]
+    pub address: String,
+
+    /// Proxy port
+    #[serde(default = "default_proxy_port")]
+    pub port: u16,
+}
+
+impl Default for ProxySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            address: default_proxy_address(),
+            port: default_proxy_port(),
+        }
+    }
+}
+
+/// Why a proxy setting was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyError {
+    /// Address is empty
+    EmptyAddress,
+    /// Address is too long or contains whitespace or control characters
+    InvalidAddress,
+    /// Port is zero
+    InvalidPort,
+}
+
+impl ProxySettings {
+    /// Proxy to connect through, or `None` when the proxy is disabled
+    pub fn active(&self) -> Option<ProxyConfig> {
+        self.enabled.then(|| ProxyConfig {
+            address: self.address.trim().to_string(),
+            port: self.port,
+        })
+    }
+
+    /// Check the address and port (a disabled proxy is always valid)
+    pub fn validate(&self) -> Result<(), ProxyError> {
+        if !self.enabled {
+            return Ok(());
+        }
+        let address = self.address.trim();
+        if address.is_empty() {
+            return Err(ProxyError::EmptyAddress);
+        }
+        if address.len() > PROXY_ADDRESS_MAX_LENGTH
+            || address.chars().any(|c| c.is_whitespace() || c.is_control())
+        {
+            return Err(ProxyError::InvalidAddress);
+        }
+        if self.port == 0 {
+            return Err(ProxyError::InvalidPort);
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Synced Preferences
+// =============================================================================
+
+/// The subset of settings shared across devices through the server
+///
+/// Every field is optional so blobs written by other client versions merge
+/// cleanly.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SyncedPreferences {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    theme: Option<ThemePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    chat_message_format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    show_connection_notifications: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    show_timestamps: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    use_24_hour_time: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    show_seconds: Option<bool>,
+}
+
 // =============================================================================
 // Default Functions (for serde)
 // =============================================================================
@@ -105,14 +453,34 @@ impl std::fmt::Debug for Settings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Settings")
             .field("theme", &self.theme)
+            .field("ui_density", &self.ui_density)
+            .field("show_role_badges", &self.show_role_badges)
             .field("chat_font_size", &self.chat_font_size)
+            .field("max_message_tabs", &self.max_message_tabs)
+            .field("paste_confirm_lines", &self.paste_confirm_lines)
+            .field("collapse_message_lines", &self.collapse_message_lines)
+            .field("chat_message_format", &self.chat_message_format)
             .field(
                 "show_connection_notifications",
                 &self.show_connection_notifications,
             )
+            .field("hide_repeated_topic", &self.hide_repeated_topic)
+            .field("quiet_hours", &self.quiet_hours)
+            .field(
+                "watched_users_ignore_quiet_hours",
+                &self.watched_users_ignore_quiet_hours,
+            )
+            .field("auto_away", &self.auto_away)
             .field("show_timestamps", &self.show_timestamps)
             .field("use_24_hour_time", &self.use_24_hour_time)
             .field("show_seconds", &self.show_seconds)
+            .field("timestamp_timezone", &self.timestamp_timezone)
+            .field("forget_passwords", &self.forget_passwords)
+            .field("sync_preferences", &self.sync_preferences)
+            .field("check_for_updates", &self.check_for_updates)
+            .field("update_url", &self.update_url)
+            .field("proxy", &self.proxy)
+            .field("connection_log_path", &self.connection_log_path)
             .field(
                 "avatar",
                 &self.avatar.as_ref().map(|a| format!("<{} bytes>", a.len())),
@@ -125,10 +493,38 @@ fn default_chat_font_size() -> u8 {
     CHAT_FONT_SIZE_DEFAULT
 }
 
+fn default_chat_message_format() -> String {
+    DEFAULT_MESSAGE_FORMAT.to_string()
+}
+
+fn default_max_message_tabs() -> usize {
+    MAX_MESSAGE_TABS_DEFAULT
+}
+
+fn default_paste_confirm_lines() -> usize {
+    PASTE_CONFIRM_LINES_DEFAULT
+}
+
+fn default_collapse_message_lines() -> usize {
+    COLLAPSE_MESSAGE_LINES_DEFAULT
+}
+
+fn default_auto_away_minutes() -> u32 {
+    AUTO_AWAY_MINUTES_DEFAULT
+}
+
 fn default_true() -> bool {
     true
 }
 
+fn default_proxy_address() -> String {
+    PROXY_ADDRESS_DEFAULT.to_string()
+}
+
+fn default_proxy_port() -> u16 {
+    PROXY_PORT_DEFAULT
+}
+
 fn default_window_width() -> f32 {
     WINDOW_WIDTH
 }
@@ -149,11 +545,33 @@ mod tests {
     fn test_default_settings() {
         let settings = Settings::default();
         assert_eq!(settings.theme, ThemePreference::default());
+        assert!(settings.show_role_badges);
         assert_eq!(settings.chat_font_size, CHAT_FONT_SIZE_DEFAULT);
+        assert_eq!(settings.max_message_tabs, MAX_MESSAGE_TABS_DEFAULT);
+        assert_eq!(settings.paste_confirm_lines, PASTE_CONFIRM_LINES_DEFAULT);
+        assert_eq!(
+            settings.collapse_message_lines,
+            COLLAPSE_MESSAGE_LINES_DEFAULT
+        );
+        assert_eq!(settings.chat_message_format, DEFAULT_MESSAGE_FORMAT);
         assert!(settings.show_connection_notifications);
+        assert!(settings.hide_repeated_topic);
+        assert!(settings.alert_on_mention);
+        assert!(!settings.focus_new_messages);
+        assert!(!settings.quiet_hours.enabled);
+        assert!(settings.watched_users_ignore_quiet_hours);
+        assert!(!settings.auto_away.enabled);
+        assert_eq!(settings.auto_away.minutes, AUTO_AWAY_MINUTES_DEFAULT);
         assert!(settings.show_timestamps);
         assert!(!settings.use_24_hour_time);
         assert!(settings.show_seconds);
+        assert_eq!(settings.timestamp_timezone, TimezonePreference::Local);
+        assert!(!settings.forget_passwords);
+        assert!(!settings.sync_preferences);
+        assert!(!settings.check_for_updates);
+        assert!(settings.update_url.is_none());
+        assert!(!settings.proxy.enabled);
+        assert!(settings.connection_log_path.is_none());
         assert!(settings.avatar.is_none());
         assert_eq!(settings.window_width, WINDOW_WIDTH);
         assert_eq!(settings.window_height, WINDOW_HEIGHT);
@@ -176,6 +594,7 @@ mod tests {
 
         assert_eq!(settings.theme.0, deserialized.theme.0);
         assert_eq!(settings.chat_font_size, deserialized.chat_font_size);
+        assert_eq!(settings.max_message_tabs, deserialized.max_message_tabs);
         assert_eq!(
             settings.show_connection_notifications,
             deserialized.show_connection_notifications
@@ -183,9 +602,19 @@ mod tests {
         assert_eq!(settings.show_timestamps, deserialized.show_timestamps);
         assert_eq!(settings.use_24_hour_time, deserialized.use_24_hour_time);
         assert_eq!(settings.show_seconds, deserialized.show_seconds);
+        assert_eq!(settings.timestamp_timezone, deserialized.timestamp_timezone);
+        assert_eq!(settings.forget_passwords, deserialized.forget_passwords);
         assert_eq!(settings.avatar, deserialized.avatar);
     }
 
+    #[test]
+    fn test_settings_without_timezone_defaults_to_local() {
+        // Settings saved before the timezone option existed
+        let json = r#"{"show_timestamps":true}"#;
+        let settings: Settings = serde_json::from_str(json).expect("deserialize");
+        assert_eq!(settings.timestamp_timezone, TimezonePreference::Local);
+    }
+
     #[test]
     fn test_settings_with_avatar_serialization_roundtrip() {
         let settings = Settings {
@@ -198,4 +627,119 @@ mod tests {
 
         assert_eq!(settings.avatar, deserialized.avatar);
     }
+
+    #[test]
+    fn test_proxy_settings_roundtrip() {
+        let settings = Settings {
+            proxy: ProxySettings {
+                enabled: true,
+                address: "localhost".to_string(),
+                port: 4447,
+            },
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&settings).expect("serialize");
+        let deserialized: Settings = serde_json::from_str(&json).expect("deserialize");
+
+        assert_eq!(settings.proxy, deserialized.proxy);
+    }
+
+    #[test]
+    fn test_proxy_validation() {
+        let mut proxy = ProxySettings {
+            enabled: true,
+            ..Default::default()
+        };
+        assert_eq!(proxy.validate(), Ok(()));
+        assert_eq!(
+            proxy.active().map(|p| (p.address, p.port)),
+            Some((PROXY_ADDRESS_DEFAULT.to_string(), PROXY_PORT_DEFAULT))
+        );
+
+        proxy.address = "  ".to_string();
+        assert_eq!(proxy.validate(), Err(ProxyError::EmptyAddress));
+
+        proxy.address = "tor proxy".to_string();
+        assert_eq!(proxy.validate(), Err(ProxyError::InvalidAddress));
+
+        proxy.address = "a".repeat(PROXY_ADDRESS_MAX_LENGTH + 1);
+        assert_eq!(proxy.validate(), Err(ProxyError::InvalidAddress));
+
+        proxy.address = "::1".to_string();
+        proxy.port = 0;
+        assert_eq!(proxy.validate(), Err(ProxyError::InvalidPort));
+
+        // Disabled proxies are never validated or used
+        proxy.enabled = false;
+        assert_eq!(proxy.validate(), Ok(()));
+        assert!(proxy.active().is_none());
+    }
+
+    #[test]
+    fn test_auto_away_timeout() {
+        let mut auto_away = AutoAwaySettings {
+            enabled: true,
+            minutes: 5,
+        };
+        assert_eq!(
+            auto_away.timeout(),
+            Some(std::time::Duration::from_secs(300))
+        );
+
+        // Out-of-range values from a hand-edited file are clamped
+        auto_away.minutes = 0;
+        assert_eq!(
+            auto_away.timeout(),
+            Some(std::time::Duration::from_secs(60))
+        );
+
+        auto_away.enabled = false;
+        assert!(auto_away.timeout().is_none());
+    }
+
+    #[test]
+    fn test_synced_preferences_roundtrip() {
+        let source = Settings {
+            theme: ThemePreference(iced::Theme::Light),
+            show_seconds: false,
+            use_24_hour_time: true,
+            chat_message_format: "<{nick}> {message}".to_string(),
+            ..Default::default()
+        };
+
+        let mut target = Settings::default();
+        target.apply_synced_preferences(&source.synced_preferences());
+
+        assert_eq!(target.theme, source.theme);
+        assert_eq!(target.chat_message_format, source.chat_message_format);
+        assert!(!target.show_seconds);
+        assert!(target.use_24_hour_time);
+    }
+
+    #[test]
+    fn test_apply_synced_preferences_partial() {
+        let mut settings = Settings::default();
+        let preferences = serde_json::json!({
+            "show_timestamps": false,
+            "unknown_future_option": 42
+        });
+
+        settings.apply_synced_preferences(&preferences);
+
+        assert!(!settings.show_timestamps);
+        // Keys the server didn't send keep their local values
+        assert!(settings.show_seconds);
+        assert_eq!(settings.theme, ThemePreference::default());
+    }
+
+    #[test]
+    fn test_apply_synced_preferences_ignores_invalid_format() {
+        let mut settings = Settings::default();
+        let preferences = serde_json::json!({ "chat_message_format": "{nick}" });
+
+        settings.apply_synced_preferences(&preferences);
+
+        assert_eq!(settings.chat_message_format, DEFAULT_MESSAGE_FORMAT);
+    }
 }
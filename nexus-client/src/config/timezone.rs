@@ -0,0 +1,177 @@
+//! Timestamp timezone preference configuration
+//!
+//! Chat timestamps are stored as local time and converted to the preferred
+//! timezone at render time. Named zones use the IANA database from chrono-tz.
+
+use chrono::{DateTime, Local, Utc};
+use chrono_tz::{TZ_VARIANTS, Tz};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::i18n::t;
+
+/// Serialized name for the system local timezone
+const LOCAL_NAME: &str = "Local";
+
+/// Serialized name for UTC
+const UTC_NAME: &str = "UTC";
+
+/// Timezone used when displaying chat timestamps
+///
+/// Serializes as "Local", "UTC", or an IANA zone name (e.g., "Europe/Berlin").
+/// Defaults to system local time, falls back to Local on unknown zone names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimezonePreference {
+    /// System local timezone
+    #[default]
+    Local,
+    /// Coordinated Universal Time
+    Utc,
+    /// Named IANA timezone
+    Named(Tz),
+}
+
+impl TimezonePreference {
+    /// Parse a timezone preference from its serialized name
+    ///
+    /// Returns `None` if the name is not "Local", "UTC", or a valid IANA zone.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            LOCAL_NAME => Some(Self::Local),
+            UTC_NAME => Some(Self::Utc),
+            _ => name.parse::<Tz>().ok().map(Self::Named),
+        }
+    }
+
+    /// Get the serialized name for this preference
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Local => LOCAL_NAME,
+            Self::Utc => UTC_NAME,
+            Self::Named(tz) => tz.name(),
+        }
+    }
+
+    /// Format a timestamp in this timezone using a strftime-style format string
+    pub fn format(&self, timestamp: &DateTime<Local>, format: &str) -> String {
+        match self {
+            Self::Local => timestamp.format(format).to_string(),
+            Self::Utc => timestamp.with_timezone(&Utc).format(format).to_string(),
+            Self::Named(tz) => timestamp.with_timezone(tz).format(format).to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for TimezonePreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Local => write!(f, "{}", t("timezone-local")),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+impl Serialize for TimezonePreference {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for TimezonePreference {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(Self::from_name(&name).unwrap_or_default())
+    }
+}
+
+// =============================================================================
+// All Available Timezones
+// =============================================================================
+
+/// Get all timezone choices for the picker (Local, UTC, then IANA zones)
+pub fn all_timezones() -> Vec<TimezonePreference> {
+    let mut timezones = vec![TimezonePreference::Local, TimezonePreference::Utc];
+    // The named "UTC" zone is already covered by the Utc choice
+    timezones.extend(
+        TZ_VARIANTS
+            .iter()
+            .filter(|tz| **tz != Tz::UTC)
+            .copied()
+            .map(TimezonePreference::Named),
+    );
+    timezones
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_default_is_local() {
+        assert_eq!(TimezonePreference::default(), TimezonePreference::Local);
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(
+            TimezonePreference::from_name("Local"),
+            Some(TimezonePreference::Local)
+        );
+        assert_eq!(
+            TimezonePreference::from_name("UTC"),
+            Some(TimezonePreference::Utc)
+        );
+        assert_eq!(
+            TimezonePreference::from_name("Europe/Berlin"),
+            Some(TimezonePreference::Named(Tz::Europe__Berlin))
+        );
+        assert_eq!(TimezonePreference::from_name("Mars/Olympus_Mons"), None);
+        assert_eq!(TimezonePreference::from_name(""), None);
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        for tz in [
+            TimezonePreference::Local,
+            TimezonePreference::Utc,
+            TimezonePreference::Named(Tz::Asia__Tokyo),
+        ] {
+            let json = serde_json::to_string(&tz).expect("serialize");
+            let deserialized: TimezonePreference =
+                serde_json::from_str(&json).expect("deserialize");
+            assert_eq!(tz, deserialized);
+        }
+    }
+
+    #[test]
+    fn test_invalid_name_falls_back_to_local() {
+        let tz: TimezonePreference = serde_json::from_str("\"Not/A_Zone\"").expect("deserialize");
+        assert_eq!(tz, TimezonePreference::Local);
+    }
+
+    #[test]
+    fn test_format_converts_timezone() {
+        let timestamp = Utc
+            .with_ymd_and_hms(2025, 1, 15, 12, 30, 0)
+            .unwrap()
+            .with_timezone(&Local);
+
+        assert_eq!(TimezonePreference::Utc.format(&timestamp, "%H:%M"), "12:30");
+        assert_eq!(
+            TimezonePreference::Named(Tz::Asia__Tokyo).format(&timestamp, "%H:%M"),
+            "21:30"
+        );
+    }
+
+    #[test]
+    fn test_all_timezones_starts_with_local_and_utc() {
+        let timezones = all_timezones();
+        assert_eq!(timezones[0], TimezonePreference::Local);
+        assert_eq!(timezones[1], TimezonePreference::Utc);
+        assert_eq!(timezones.len(), TZ_VARIANTS.len() + 1);
+    }
+}
@@ -0,0 +1,190 @@
+//! End-to-end encryption for private messages
+//!
+//! Each connection makes an X25519 key pair that lives only in memory and
+//! announces the public half to the server. To send a private message, the
+//! client seals one envelope for every session of both accounts:
+//!
+//! 1. Make a one-time X25519 key pair and agree a secret with the session's key
+//! 2. Derive a ChaCha20-Poly1305 key with HKDF-SHA256, salted with both public
+//!    keys
+//! 3. Seal the message text under that key (the key is never reused, so the
+//!    nonce is fixed at zero)
+//!
+//! The server relays envelopes it can't open, but it also hands out the keys,
+//! so this protects against a server that logs or leaks messages, not one
+//! that swaps keys to read them.
+
+use std::fmt;
+use std::sync::Arc;
+
+use aws_lc_rs::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use aws_lc_rs::agreement::{PrivateKey, UnparsedPublicKey, X25519, agree};
+use aws_lc_rs::hkdf::{HKDF_SHA256, Salt};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use nexus_common::protocol::{E2eeEnvelope, E2eeSessionKey};
+
+/// HKDF info string, so keys derived here are never used for anything else
+const KEY_INFO: &[u8] = b"nexus-e2ee-pm-v1";
+
+/// This connection's key pair for end-to-end encrypted private messages
+#[derive(Clone)]
+pub struct E2eeIdentity {
+    private_key: Arc<PrivateKey>,
+    /// Public key (base64), as announced to the server
+    public_key: String,
+}
+
+impl fmt::Debug for E2eeIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("E2eeIdentity")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl E2eeIdentity {
+    /// Make a new key pair (`None` if the system can't provide randomness)
+    pub fn generate() -> Option<Self> {
+        let private_key = PrivateKey::generate(&X25519).ok()?;
+        let public_key = STANDARD.encode(private_key.compute_public_key().ok()?.as_ref());
+        Some(Self {
+            private_key: Arc::new(private_key),
+            public_key,
+        })
+    }
+
+    /// Public key (base64) to announce
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    /// Open an envelope sealed for this connection
+    ///
+    /// Returns `None` if it wasn't sealed for this key or was tampered with.
+    pub fn open(&self, envelope: &E2eeEnvelope) -> Option<String> {
+        let ephemeral_key = STANDARD.decode(&envelope.ephemeral_key).ok()?;
+        let own_key = STANDARD.decode(&self.public_key).ok()?;
+        let key = derive_key(&self.private_key, &ephemeral_key, &ephemeral_key, &own_key)?;
+
+        let mut sealed = STANDARD.decode(&envelope.ciphertext).ok()?;
+        let plaintext = key
+            .open_in_place(zero_nonce(), Aad::empty(), &mut sealed)
+            .ok()?;
+        String::from_utf8(plaintext.to_vec()).ok()
+    }
+}
+
+/// Seal a message for one session
+///
+/// Returns `None` if the session's key is malformed.
+pub fn seal(message: &str, session: &E2eeSessionKey) -> Option<E2eeEnvelope> {
+    let recipient_key = STANDARD.decode(&session.public_key).ok()?;
+    let ephemeral = PrivateKey::generate(&X25519).ok()?;
+    let ephemeral_public = ephemeral.compute_public_key().ok()?;
+    let key = derive_key(
+        &ephemeral,
+        &recipient_key,
+        ephemeral_public.as_ref(),
+        &recipient_key,
+    )?;
+
+    let mut sealed = message.as_bytes().to_vec();
+    key.seal_in_place_append_tag(zero_nonce(), Aad::empty(), &mut sealed)
+        .ok()?;
+    Some(E2eeEnvelope {
+        session_id: session.session_id,
+        ephemeral_key: STANDARD.encode(ephemeral_public.as_ref()),
+        ciphertext: STANDARD.encode(sealed),
+    })
+}
+
+/// Agree a secret with `peer_key` and derive the message key from it
+///
+/// `ephemeral_key` and `recipient_key` salt the derivation, so both ends
+/// pass them in the same order.
+fn derive_key(
+    private_key: &PrivateKey,
+    peer_key: &[u8],
+    ephemeral_key: &[u8],
+    recipient_key: &[u8],
+) -> Option<LessSafeKey> {
+    let salt = Salt::new(HKDF_SHA256, &[ephemeral_key, recipient_key].concat());
+    let mut key_bytes = [0u8; 32];
+    agree(
+        private_key,
+        UnparsedPublicKey::new(&X25519, peer_key),
+        (),
+        |secret| {
+            salt.extract(secret)
+                .expand(&[KEY_INFO], &CHACHA20_POLY1305)
+                .and_then(|okm| okm.fill(&mut key_bytes))
+                .map_err(|_| ())
+        },
+    )
+    .ok()?;
+    let key = UnboundKey::new(&CHACHA20_POLY1305, &key_bytes).ok()?;
+    Some(LessSafeKey::new(key))
+}
+
+/// Nonce for a key that seals exactly one message
+fn zero_nonce() -> Nonce {
+    Nonce::assume_unique_for_key([0u8; NONCE_LEN])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_key(identity: &E2eeIdentity, session_id: u32) -> E2eeSessionKey {
+        E2eeSessionKey {
+            session_id,
+            public_key: identity.public_key().to_string(),
+        }
+    }
+
+    #[test]
+    fn test_seal_and_open() {
+        let recipient = E2eeIdentity::generate().unwrap();
+        let envelope = seal("hello 😀", &session_key(&recipient, 7)).unwrap();
+
+        assert_eq!(envelope.session_id, 7);
+        assert!(!envelope.ciphertext.contains("hello"));
+        assert_eq!(recipient.open(&envelope).as_deref(), Some("hello 😀"));
+    }
+
+    #[test]
+    fn test_public_key_is_valid() {
+        let identity = E2eeIdentity::generate().unwrap();
+        assert!(nexus_common::validators::validate_e2ee_public_key(identity.public_key()).is_ok());
+    }
+
+    #[test]
+    fn test_other_key_cannot_open() {
+        let recipient = E2eeIdentity::generate().unwrap();
+        let other = E2eeIdentity::generate().unwrap();
+        let envelope = seal("secret", &session_key(&recipient, 1)).unwrap();
+
+        assert_eq!(other.open(&envelope), None);
+    }
+
+    #[test]
+    fn test_tampered_envelope_rejected() {
+        let recipient = E2eeIdentity::generate().unwrap();
+        let mut envelope = seal("secret", &session_key(&recipient, 1)).unwrap();
+
+        let mut sealed = STANDARD.decode(&envelope.ciphertext).unwrap();
+        sealed[0] ^= 1;
+        envelope.ciphertext = STANDARD.encode(sealed);
+        assert_eq!(recipient.open(&envelope), None);
+    }
+
+    #[test]
+    fn test_malformed_key_rejected() {
+        let session = E2eeSessionKey {
+            session_id: 1,
+            public_key: "not base64!".to_string(),
+        };
+        assert!(seal("secret", &session).is_none());
+    }
+}
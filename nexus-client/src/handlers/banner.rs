@@ -0,0 +1,75 @@
+//! Pre-login banner handlers
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::{Message, PendingBanner};
+use iced::Task;
+
+impl NexusApp {
+    /// Queue a pre-login banner for the user to read
+    ///
+    /// The connection attempt has ended without logging in, so the connecting
+    /// state is cleared as if it had failed.
+    pub fn handle_banner_received(
+        &mut self,
+        bookmark_index: Option<usize>,
+        banner: String,
+    ) -> Task<Message> {
+        let display_name = match bookmark_index {
+            Some(idx) => {
+                self.connecting_bookmarks.remove(&idx);
+                self.bookmark_steps.remove(&idx);
+                self.config
+                    .get_bookmark(idx)
+                    .map(|b| b.name.clone())
+                    .unwrap_or_default()
+            }
+            None => {
+                self.connection_form.is_connecting = false;
+                self.connection_form.step = None;
+                self.get_display_name(None)
+            }
+        };
+
+        self.pending_banners.push_back(PendingBanner {
+            bookmark_index,
+            display_name,
+            banner,
+        });
+        Task::none()
+    }
+
+    /// Accept the banner and reconnect (remembered per bookmark)
+    pub fn handle_accept_banner(&mut self) -> Task<Message> {
+        let Some(pending) = self.pending_banners.pop_front() else {
+            return Task::none();
+        };
+
+        match pending.bookmark_index {
+            Some(idx) => {
+                // Handle case where bookmark was deleted while the dialog was open
+                let Some(bookmark) = self.config.bookmarks.get_mut(idx) else {
+                    return Task::none();
+                };
+                bookmark.accepted_banner = Some(pending.banner);
+                let _ = self.config.save();
+                self.handle_connect_to_bookmark(idx)
+            }
+            None => {
+                self.connection_form.accepted_banner = Some(pending.banner);
+                self.handle_connect_pressed()
+            }
+        }
+    }
+
+    /// Decline the banner (the connection was never logged in)
+    pub fn handle_cancel_banner(&mut self) -> Task<Message> {
+        self.pending_banners.pop_front();
+
+        if self.pending_banners.is_empty() {
+            self.connection_form.error = Some(t("msg-connection-cancelled"));
+        }
+
+        Task::none()
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::NexusApp;
 use crate::i18n::{get_locale, t, t_args};
-use crate::types::{BookmarkEditMode, BookmarkEditState, InputId, Message};
+use crate::types::{BookmarkEditMode, BookmarkEditState, ConnectionStep, InputId, Message};
 use iced::Task;
 use iced::widget::{Id, operation};
 use std::collections::HashMap;
@@ -18,12 +18,36 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle bookmark fallback addresses field change
+    ///
+    /// The field is comma-separated. Entries are kept as typed (splitting and
+    /// joining on commas round-trips) and trimmed when connecting.
+    pub fn handle_bookmark_addresses_changed(&mut self, addresses: String) -> Task<Message> {
+        self.bookmark_edit.bookmark.addresses = if addresses.is_empty() {
+            Vec::new()
+        } else {
+            addresses.split(',').map(str::to_string).collect()
+        };
+        self.bookmark_edit.error = None;
+        self.focused_field = InputId::BookmarkAddresses;
+        Task::none()
+    }
+
     /// Handle bookmark auto-connect toggle
     pub fn handle_bookmark_auto_connect_toggled(&mut self, enabled: bool) -> Task<Message> {
         self.bookmark_edit.bookmark.auto_connect = enabled;
         Task::none()
     }
 
+    /// Handle bookmark trust certificate changes toggle
+    pub fn handle_bookmark_trust_certificate_changes_toggled(
+        &mut self,
+        enabled: bool,
+    ) -> Task<Message> {
+        self.bookmark_edit.bookmark.trust_certificate_changes = enabled;
+        Task::none()
+    }
+
     /// Handle bookmark name field change
     pub fn handle_bookmark_name_changed(&mut self, name: String) -> Task<Message> {
         self.bookmark_edit.bookmark.name = name;
@@ -48,6 +72,18 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle bookmark theme cleared (fall back to the global theme)
+    pub fn handle_bookmark_theme_cleared(&mut self) -> Task<Message> {
+        self.bookmark_edit.bookmark.theme = None;
+        Task::none()
+    }
+
+    /// Handle bookmark theme selection from the picker
+    pub fn handle_bookmark_theme_selected(&mut self, theme: iced::Theme) -> Task<Message> {
+        self.bookmark_edit.bookmark.theme = Some(theme.into());
+        Task::none()
+    }
+
     /// Handle bookmark username field change
     pub fn handle_bookmark_username_changed(&mut self, username: String) -> Task<Message> {
         self.bookmark_edit.bookmark.username = username;
@@ -56,6 +92,19 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle bookmark watched users field change
+    ///
+    /// Comma-separated like the fallback addresses, and cleaned up on save.
+    pub fn handle_bookmark_watched_users_changed(&mut self, usernames: String) -> Task<Message> {
+        self.bookmark_edit.bookmark.watched_users = if usernames.is_empty() {
+            Vec::new()
+        } else {
+            usernames.split(',').map(str::to_string).collect()
+        };
+        self.focused_field = InputId::BookmarkWatchedUsers;
+        Task::none()
+    }
+
     // ==================== Dialog Actions ====================
 
     /// Cancel bookmark editing and close the dialog
@@ -71,7 +120,21 @@ impl NexusApp {
             return Task::none();
         }
 
-        let bookmark = self.bookmark_edit.bookmark.clone();
+        let mut bookmark = self.bookmark_edit.bookmark.clone();
+        bookmark.addresses = bookmark
+            .addresses
+            .iter()
+            .map(|entry| entry.trim())
+            .filter(|entry| !entry.is_empty())
+            .map(str::to_string)
+            .collect();
+        // Re-add the watched users so blanks and duplicates are dropped
+        for username in std::mem::take(&mut bookmark.watched_users) {
+            let username = username.trim();
+            if !username.is_empty() {
+                bookmark.watch_user(username);
+            }
+        }
 
         match self.bookmark_edit.mode {
             BookmarkEditMode::Add => {
@@ -127,53 +190,80 @@ impl NexusApp {
         }
 
         if let Some(bookmark) = self.config.get_bookmark(index) {
+            // Passwords aren't remembered, so ask for one via the connection form
+            if self.config.settings.forget_passwords && bookmark.password.is_empty() {
+                return self.prompt_bookmark_password(index);
+            }
+
             self.connecting_bookmarks.insert(index);
 
             let connection_id = self.next_connection_id;
             self.next_connection_id += 1;
 
-            let port: u16 = match bookmark.port.parse() {
-                Ok(p) => p,
-                Err(_) => {
+            let endpoints = match bookmark.endpoints() {
+                Ok(endpoints) => endpoints,
+                Err(entry) => {
                     self.connecting_bookmarks.remove(&index);
-                    self.connection_form.error = Some(t_args(
-                        "err-invalid-port-bookmark",
-                        &[("name", &bookmark.name)],
-                    ));
+                    self.connection_form.error = Some(if entry == bookmark.port {
+                        t_args("err-invalid-port-bookmark", &[("name", &bookmark.name)])
+                    } else {
+                        t_args("err-fallback-address-invalid", &[("address", &entry)])
+                    });
                     return Task::none();
                 }
             };
 
-            let server_address = bookmark.address.clone();
             let username = bookmark.username.clone();
             let password = bookmark.password.clone();
             let locale = get_locale().to_string();
             let avatar = self.config.settings.avatar.clone();
+            let proxy = self.config.settings.proxy.active();
             let display_name = bookmark.name.clone();
+            let accepted_banner = bookmark.accepted_banner.clone();
 
-            return Task::perform(
-                async move {
-                    crate::network::connect_to_server(
-                        server_address,
-                        port,
-                        username,
-                        password,
-                        locale,
-                        avatar,
-                        connection_id,
-                    )
-                    .await
-                },
-                move |result| Message::BookmarkConnectionResult {
-                    result,
-                    bookmark_index: Some(index),
-                    display_name: display_name.clone(),
-                },
+            return Task::run(
+                crate::network::connect_with_progress(
+                    endpoints,
+                    username,
+                    password,
+                    locale,
+                    avatar,
+                    proxy,
+                    connection_id,
+                    accepted_banner,
+                    None,
+                    move |step| Message::BookmarkConnectionStepChanged {
+                        bookmark_index: index,
+                        step,
+                    },
+                    move |banner| Message::BannerReceived {
+                        bookmark_index: Some(index),
+                        banner,
+                    },
+                    move |result| Message::BookmarkConnectionResult {
+                        result,
+                        bookmark_index: Some(index),
+                        display_name,
+                    },
+                ),
+                |message| message,
             );
         }
         Task::none()
     }
 
+    /// Handle a connection step reported while connecting to a bookmark
+    pub fn handle_bookmark_connection_step_changed(
+        &mut self,
+        bookmark_index: usize,
+        step: ConnectionStep,
+    ) -> Task<Message> {
+        if self.connecting_bookmarks.contains(&bookmark_index) {
+            self.bookmark_steps.insert(bookmark_index, step);
+        }
+        Task::none()
+    }
+
     /// Delete a bookmark by index
     pub fn handle_delete_bookmark(&mut self, index: usize) -> Task<Message> {
         self.config.delete_bookmark(index);
@@ -184,21 +274,107 @@ impl NexusApp {
             ));
         }
 
-        // Clean up bookmark_errors: remove the deleted index and shift higher indices down
-        self.bookmark_errors.remove(&index);
-        let shifted: HashMap<usize, String> = self
-            .bookmark_errors
+        // Index-keyed state: drop the deleted index and shift higher indices down
+        shift_bookmark_keys(&mut self.bookmark_errors, index);
+        shift_bookmark_keys(&mut self.restore_chat_tabs, index);
+        shift_bookmark_keys(&mut self.restore_drafts, index);
+        shift_bookmark_keys(&mut self.bookmark_steps, index);
+        self.connecting_bookmarks = self
+            .connecting_bookmarks
             .drain()
-            .map(|(i, err)| if i > index { (i - 1, err) } else { (i, err) })
+            .filter(|&i| i != index)
+            .map(|i| if i > index { i - 1 } else { i })
             .collect();
-        self.bookmark_errors = shifted;
+
+        self.password_prompts = self
+            .password_prompts
+            .drain(..)
+            .filter(|&i| i != index)
+            .map(|i| if i > index { i - 1 } else { i })
+            .collect();
+
+        // Open connections to the deleted bookmark become ad-hoc connections
+        for conn in self.connections.values_mut() {
+            conn.bookmark_index = match conn.bookmark_index {
+                Some(i) if i == index => None,
+                Some(i) if i > index => Some(i - 1),
+                other => other,
+            };
+        }
 
         self.bookmark_edit = BookmarkEditState::default();
         Task::none()
     }
 
+    /// Drop a bookmark's in-memory password if passwords aren't remembered
+    ///
+    /// Called when a connection closes so the next connect prompts again.
+    pub fn forget_bookmark_password(&mut self, bookmark_index: Option<usize>) {
+        if self.config.settings.forget_passwords
+            && let Some(bookmark) =
+                bookmark_index.and_then(|idx| self.config.bookmarks.get_mut(idx))
+        {
+            bookmark.password.clear();
+        }
+    }
+
+    /// Show the next queued password prompt once the current one connected
+    ///
+    /// Returns `None` if the connection wasn't for the prompted bookmark or
+    /// no other prompt is waiting.
+    pub fn next_password_prompt(&mut self, bookmark_index: Option<usize>) -> Option<Task<Message>> {
+        if bookmark_index.is_none() || self.password_prompts.front() != bookmark_index.as_ref() {
+            return None;
+        }
+        self.password_prompts.pop_front();
+        let next = *self.password_prompts.front()?;
+        Some(self.show_password_prompt(next))
+    }
+
     // ==================== Private Helpers ====================
 
+    /// Queue a password prompt for a bookmark, showing it if none is pending
+    ///
+    /// Several bookmarks can need a password at once (e.g. auto-connect at
+    /// startup), so they are asked for one at a time.
+    fn prompt_bookmark_password(&mut self, index: usize) -> Task<Message> {
+        if !self.password_prompts.contains(&index) {
+            self.password_prompts.push_back(index);
+        }
+        if self.password_prompts.front() != Some(&index) {
+            return Task::none();
+        }
+        self.show_password_prompt(index)
+    }
+
+    /// Fill the connection form from a bookmark and focus the password field
+    ///
+    /// Used instead of connecting directly when passwords aren't saved. The
+    /// connection result is matched back to the bookmark by address, port, and
+    /// username, so fingerprint checks still apply.
+    fn show_password_prompt(&mut self, index: usize) -> Task<Message> {
+        let Some(bookmark) = self.config.get_bookmark(index) else {
+            return Task::none();
+        };
+
+        self.connection_form.server_name = bookmark.name.clone();
+        self.connection_form.server_address = bookmark.address.clone();
+        self.connection_form.port = bookmark.port.clone();
+        self.connection_form.username = bookmark.username.clone();
+        self.connection_form.password.clear();
+        self.connection_form.add_bookmark = false;
+        self.connection_form.error = Some(t_args(
+            "msg-enter-password-for",
+            &[("name", &bookmark.name)],
+        ));
+
+        // Show the connection form
+        self.bookmark_edit = BookmarkEditState::default();
+        self.active_connection = None;
+        self.focused_field = InputId::Password;
+        operation::focus(Id::from(InputId::Password))
+    }
+
     /// Validate bookmark fields
     fn validate_bookmark(&self) -> Option<String> {
         if self.bookmark_edit.bookmark.name.trim().is_empty() {
@@ -213,6 +389,39 @@ impl NexusApp {
         if self.bookmark_edit.bookmark.port.parse::<u16>().is_err() {
             return Some(t("err-port-invalid"));
         }
+        if let Err(entry) = self.bookmark_edit.bookmark.endpoints() {
+            return Some(t_args(
+                "err-fallback-address-invalid",
+                &[("address", &entry)],
+            ));
+        }
         None
     }
 }
+
+/// Re-key a bookmark-indexed map after the bookmark at `index` was deleted
+fn shift_bookmark_keys<V>(map: &mut HashMap<usize, V>, index: usize) {
+    map.remove(&index);
+    *map = map
+        .drain()
+        .map(|(i, value)| {
+            if i > index {
+                (i - 1, value)
+            } else {
+                (i, value)
+            }
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_bookmark_keys() {
+        let mut map: HashMap<usize, &str> = [(0, "a"), (1, "b"), (2, "c")].into();
+        shift_bookmark_keys(&mut map, 1);
+        assert_eq!(map, [(0, "a"), (1, "c")].into());
+    }
+}
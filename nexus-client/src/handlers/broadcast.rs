@@ -2,7 +2,7 @@
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
-use crate::types::{ActivePanel, ChatMessage, InputId, Message};
+use crate::types::{ActivePanel, BroadcastTarget, ChatMessage, InputId, Message};
 use iced::Task;
 use iced::widget::{Id, operation};
 use nexus_common::protocol::ClientMessage;
@@ -57,6 +57,16 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle broadcast audience selection
+    pub fn handle_broadcast_target_selected(&mut self, target: BroadcastTarget) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.broadcast_target = target;
+        }
+        Task::none()
+    }
+
     /// Handle validation of broadcast form (called on Enter when message empty)
     pub fn handle_validate_broadcast(&mut self) -> Task<Message> {
         let Some(conn_id) = self.active_connection else {
@@ -66,7 +76,9 @@ impl NexusApp {
             return Task::none();
         };
 
-        if let Err(MessageError::Empty) = validators::validate_message(&conn.broadcast_message) {
+        if let Err(MessageError::Empty) =
+            validators::validate_message(&conn.broadcast_message, conn.max_message_length)
+        {
             conn.broadcast_error = Some(t("err-message-required"));
         }
         Task::none()
@@ -86,23 +98,25 @@ impl NexusApp {
         let message = conn.broadcast_message.trim().to_string();
 
         // Validate message content using shared validators
-        if let Err(e) = validators::validate_message(&message) {
+        if let Err(e) = validators::validate_message(&message, conn.max_message_length) {
             let error_msg = match e {
                 MessageError::Empty => return Task::none(),
                 MessageError::TooLong => t_args(
                     "err-broadcast-too-long",
                     &[
-                        ("length", &message.len().to_string()),
-                        ("max", &validators::MAX_MESSAGE_LENGTH.to_string()),
+                        ("length", &message.chars().count().to_string()),
+                        ("max", &conn.max_message_length.to_string()),
                     ],
                 ),
-                MessageError::ContainsNewlines => t("err-message-contains-newlines"),
                 MessageError::InvalidCharacters => t("err-message-invalid-characters"),
             };
             return self.add_broadcast_error(conn_id, error_msg);
         }
 
-        let msg = ClientMessage::UserBroadcast { message };
+        let msg = ClientMessage::UserBroadcast {
+            message,
+            target_permission: conn.broadcast_target.permission(),
+        };
 
         if let Err(e) = conn.send(msg) {
             let error_msg = format!("{}: {}", t("err-broadcast-send-failed"), e);
@@ -1,18 +1,32 @@
 //! Connection and chat message handlers
 
+use std::time::Instant;
+
 use crate::commands::{self, ParseResult};
+use crate::config::message_format::MessageFormat;
+use crate::handlers::popout::close_popouts;
 use crate::i18n::{get_locale, t, t_args};
-use crate::types::{ActivePanel, ChatMessage, ChatTab, InputId, Message, ScrollableId};
+use crate::types::{
+    ActivePanel, COPY_CONFIRMATION_DURATION, ChatMessage, ChatTab, ConnectionEvent, ConnectionStep,
+    InputId, Invite, InviteError, Message, MessageType, SEND_DEBOUNCE, ScrollableId,
+};
 use crate::views::constants::{PERMISSION_CHAT_SEND, PERMISSION_USER_MESSAGE};
-use crate::{NexusApp, network};
+use crate::views::{TimestampSettings, message_copy_text};
+use crate::{NexusApp, autostart, network};
 use iced::Task;
-use iced::widget::{Id, operation, scrollable};
+use iced::widget::{Id, operation, scrollable, text_editor};
 use nexus_common::protocol::ClientMessage;
 use nexus_common::validators::{self, MessageError};
 
 /// Threshold for considering scroll position "at bottom" (0.0 = top, 1.0 = bottom)
 const SCROLL_BOTTOM_THRESHOLD: f32 = 0.99;
 
+/// Scroll offset at or below which older chat history is requested
+const SCROLL_TOP_THRESHOLD: f32 = 0.01;
+
+/// Maximum number of characters of the original message included in a quote
+const QUOTE_SNIPPET_LENGTH: usize = 50;
+
 impl NexusApp {
     // ==================== Connection Form Fields ====================
 
@@ -33,6 +47,8 @@ impl NexusApp {
     /// Handle port field change
     pub fn handle_port_changed(&mut self, port: String) -> Task<Message> {
         self.connection_form.port = port;
+        self.connection_form.expected_fingerprint = None;
+        self.connection_form.accepted_banner = None;
         self.connection_form.error = None;
         self.focused_field = InputId::Port;
         Task::none()
@@ -41,6 +57,8 @@ impl NexusApp {
     /// Handle server address field change
     pub fn handle_server_address_changed(&mut self, addr: String) -> Task<Message> {
         self.connection_form.server_address = addr;
+        self.connection_form.expected_fingerprint = None;
+        self.connection_form.accepted_banner = None;
         self.connection_form.error = None;
         self.focused_field = InputId::ServerAddress;
         Task::none()
@@ -62,6 +80,36 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Open a `nexus://` invite link
+    ///
+    /// The link is validated first; a rejected link leaves the form empty and
+    /// shows why. A bookmark with stored credentials that already trusts the
+    /// server is connected directly. Otherwise the connection form is pre-filled
+    /// (showing the link's fingerprint) and focus moves to the username field,
+    /// since credentials are never part of a link.
+    pub fn handle_invite_link(&mut self, link: &str) -> Task<Message> {
+        let invite = match Invite::parse(link) {
+            Ok(invite) => invite,
+            Err(error) => {
+                self.connection_form.error = Some(invite_error_message(error));
+                return operation::focus(Id::from(InputId::ServerName));
+            }
+        };
+
+        if let Some(index) = autostart::bookmark_for_invite(&self.config, &invite) {
+            return self.handle_connect_to_bookmark(index);
+        }
+
+        self.connection_form.clear();
+        self.connection_form.server_name = invite.name.unwrap_or_default();
+        self.connection_form.server_address = invite.address;
+        self.connection_form.port = invite.port.to_string();
+        self.connection_form.expected_fingerprint = invite.fingerprint;
+        self.connection_form.error = None;
+        self.focused_field = InputId::Username;
+        operation::focus(Id::from(InputId::Username))
+    }
+
     // ==================== Connection Actions ====================
 
     /// Handle connect button press
@@ -81,34 +129,54 @@ impl NexusApp {
         };
 
         self.connection_form.is_connecting = true;
+        self.connection_form.step = None;
 
         let server_address = self.connection_form.server_address.clone();
         let username = self.connection_form.username.clone();
         let password = self.connection_form.password.clone();
         let locale = get_locale().to_string();
         let avatar = self.config.settings.avatar.clone();
+        let proxy = self.config.settings.proxy.active();
         let connection_id = self.next_connection_id;
         self.next_connection_id += 1;
 
-        Task::perform(
-            async move {
-                network::connect_to_server(
-                    server_address,
-                    port,
-                    username,
-                    password,
-                    locale,
-                    avatar,
-                    connection_id,
-                )
-                .await
-            },
-            Message::ConnectionResult,
+        Task::run(
+            network::connect_with_progress(
+                vec![(server_address, port)],
+                username,
+                password,
+                locale,
+                avatar,
+                proxy,
+                connection_id,
+                self.connection_form.accepted_banner.clone(),
+                self.connection_form.expected_fingerprint.clone(),
+                Message::ConnectionStepChanged,
+                |banner| Message::BannerReceived {
+                    bookmark_index: None,
+                    banner,
+                },
+                Message::ConnectionResult,
+            ),
+            |message| message,
         )
     }
 
+    /// Handle a connection step reported by the connect task
+    pub fn handle_connection_step_changed(&mut self, step: ConnectionStep) -> Task<Message> {
+        if self.connection_form.is_connecting {
+            self.connection_form.step = Some(step);
+        }
+        Task::none()
+    }
+
     /// Disconnect from a server and clean up resources
     pub fn handle_disconnect_from_server(&mut self, connection_id: usize) -> Task<Message> {
+        self.record_connection_event(
+            connection_id,
+            ConnectionEvent::Disconnected { reason: None },
+        );
+
         if let Some(conn) = self.connections.remove(&connection_id) {
             let shutdown_arc = conn.shutdown_handle.clone();
             tokio::spawn(async move {
@@ -128,10 +196,83 @@ impl NexusApp {
             if self.active_connection == Some(connection_id) {
                 self.active_connection = None;
             }
+
+            // A deliberate disconnect starts the next connection on the server tab
+            if let Some(idx) = conn.bookmark_index {
+                self.restore_chat_tabs.remove(&idx);
+                self.restore_drafts.remove(&idx);
+            }
+
+            self.forget_bookmark_password(conn.bookmark_index);
+
+            // Popped-out tabs have nothing left to show
+            return close_popouts(&conn);
         }
         Task::none()
     }
 
+    /// Hide a connection's admin notice banner
+    pub fn handle_dismiss_admin_notice(&mut self, connection_id: usize) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.admin_notice = None;
+        }
+        Task::none()
+    }
+
+    /// Reconnect as an admin notice asked
+    ///
+    /// The banner only offers this for bookmarked connections.
+    pub fn handle_admin_notice_reconnect(&mut self, connection_id: usize) -> Task<Message> {
+        let Some(bookmark_index) = self
+            .connections
+            .get(&connection_id)
+            .and_then(|conn| conn.bookmark_index)
+            .filter(|&idx| self.config.get_bookmark(idx).is_some())
+        else {
+            return Task::none();
+        };
+        self.reconnect_bookmark(connection_id, bookmark_index)
+    }
+
+    /// Drop a bookmarked connection and connect to its bookmark again
+    ///
+    /// Returns to the same chat tab once the user list confirms it, and keeps
+    /// unsent drafts.
+    pub fn reconnect_bookmark(
+        &mut self,
+        connection_id: usize,
+        bookmark_index: usize,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let active_tab = conn.active_chat_tab.clone();
+        let drafts = conn.take_drafts();
+
+        // Disconnecting forgets the password when passwords aren't saved, but the
+        // session is being resumed, so keep using the one it connected with
+        let password = self
+            .config
+            .get_bookmark(bookmark_index)
+            .map(|b| b.password.clone())
+            .unwrap_or_default();
+
+        let disconnect_task = self.handle_disconnect_from_server(connection_id);
+
+        if let Some(bookmark) = self.config.bookmarks.get_mut(bookmark_index) {
+            bookmark.password = password;
+        }
+        if active_tab != ChatTab::Server {
+            self.restore_chat_tabs.insert(bookmark_index, active_tab);
+        }
+        self.restore_drafts.insert(bookmark_index, drafts);
+
+        Task::batch([
+            disconnect_task,
+            self.handle_connect_to_bookmark(bookmark_index),
+        ])
+    }
+
     /// Switch active view to a different connection
     pub fn handle_switch_to_connection(&mut self, connection_id: usize) -> Task<Message> {
         if !self.connections.contains_key(&connection_id) {
@@ -144,6 +285,61 @@ impl NexusApp {
         self.scroll_chat_if_visible(true)
     }
 
+    /// Send a keepalive ping to every connection whose server supports it
+    ///
+    /// A ping still awaiting its pong when the next one goes out counts as missed.
+    pub fn handle_keepalive_tick(&mut self) -> Task<Message> {
+        let now = Instant::now();
+        for conn in self.connections.values_mut() {
+            // Only ping servers that have confirmed support (older servers would disconnect us)
+            let supported = conn
+                .capabilities
+                .as_ref()
+                .is_some_and(|caps| caps.message_types.contains("Ping"));
+            if !supported {
+                continue;
+            }
+            if let Ok(message_id) = conn.send(ClientMessage::Ping) {
+                conn.keepalive.ping_sent(message_id, now);
+            }
+        }
+        Task::none()
+    }
+
+    /// Copy each connection's traffic counters into its displayed totals
+    pub fn refresh_traffic_totals(&mut self) {
+        for conn in self.connections.values_mut() {
+            conn.traffic_totals = conn.traffic.totals();
+        }
+    }
+
+    /// Handle user activity, ending an automatic away period
+    pub fn handle_user_activity(&mut self) -> Task<Message> {
+        if self.idle.activity(Instant::now()) {
+            self.send_away(false);
+        }
+        Task::none()
+    }
+
+    /// Periodic idle check, going away once the timeout has passed
+    pub fn check_auto_away(&mut self) {
+        if let Some(timeout) = self.config.settings.auto_away.timeout()
+            && self.idle.check(Instant::now(), timeout)
+        {
+            self.send_away(true);
+        }
+    }
+
+    /// Tell every server that supports it whether the user is away
+    pub fn send_away(&self, away: bool) {
+        for conn in self.connections.values() {
+            // Older servers would disconnect us for an unknown message
+            if conn.supports_message_type("SetAway") {
+                let _ = conn.send(ClientMessage::SetAway { away });
+            }
+        }
+    }
+
     // ==================== Chat Helpers ====================
 
     /// Scroll chat if chat view is visible (no panel overlay).
@@ -205,9 +401,14 @@ impl NexusApp {
             && let Some(valid_offset) = Self::get_valid_scroll_offset(&viewport)
         {
             let tab = conn.active_chat_tab.clone();
-            let scroll_state = conn.scroll_states.entry(tab).or_default();
+            let scroll_state = conn.scroll_states.entry(tab.clone()).or_default();
             scroll_state.offset = valid_offset;
             scroll_state.auto_scroll = valid_offset >= SCROLL_BOTTOM_THRESHOLD;
+
+            // Load older #server history as the user reaches the top
+            if tab == ChatTab::Server && valid_offset <= SCROLL_TOP_THRESHOLD {
+                return self.request_older_history(conn_id);
+            }
         }
         Task::none()
     }
@@ -229,31 +430,171 @@ impl NexusApp {
         Some(viewport.relative_offset().y)
     }
 
-    /// Close a user message tab
+    /// Close a user message tab (and its window, if popped out)
     pub fn handle_close_user_message_tab(&mut self, username: String) -> Task<Message> {
         if let Some(conn_id) = self.active_connection
             && let Some(conn) = self.connections.get_mut(&conn_id)
         {
+            let popout = conn.popout_window(&username);
+            if let Some(window_id) = popout {
+                conn.popouts.remove(&window_id);
+            }
+
             conn.user_messages.remove(&username);
+            conn.message_tabs.forget(&username);
 
             let tab = ChatTab::UserMessage(username);
             conn.unread_tabs.remove(&tab);
             conn.scroll_states.remove(&tab);
+            conn.drafts.remove(&tab);
 
             if conn.active_chat_tab == tab {
-                conn.active_chat_tab = ChatTab::Server;
+                // Unsent input goes with the closed tab
+                conn.message_input = text_editor::Content::new();
+                conn.set_active_chat_tab(ChatTab::Server);
                 return self.handle_show_chat_view();
             }
+            if let Some(window_id) = popout {
+                return iced::window::close(window_id);
+            }
         }
         Task::none()
     }
 
-    /// Handle chat message input change
-    pub fn handle_message_input_changed(&mut self, input: String) -> Task<Message> {
+    /// Copy a chat message from the active tab to the clipboard
+    ///
+    /// The text matches what is displayed, including the timestamp (when
+    /// enabled) and username prefix. A confirmation shows briefly afterwards.
+    pub fn handle_copy_chat_line(&mut self, index: usize) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let Some(msg) = conn.active_messages().get(index) else {
+            return Task::none();
+        };
+
+        let settings = &self.config.settings;
+        let text = message_copy_text(
+            msg,
+            TimestampSettings {
+                show_timestamps: settings.show_timestamps,
+                use_24_hour_time: settings.use_24_hour_time,
+                show_seconds: settings.show_seconds,
+                timezone: settings.timestamp_timezone,
+            },
+            &MessageFormat::parse_or_default(&settings.chat_message_format),
+        );
+        conn.last_copied = Some(Instant::now());
+
+        // Redraw once the confirmation has timed out so it disappears
+        Task::batch([
+            iced::clipboard::write(text),
+            Task::perform(tokio::time::sleep(COPY_CONFIRMATION_DURATION), |_| {
+                Message::CopyConfirmationExpired
+            }),
+        ])
+    }
+
+    /// Handle the copy confirmation timing out
+    ///
+    /// No state changes needed; the message only triggers a redraw so the
+    /// confirmation is hidden once `COPY_CONFIRMATION_DURATION` has elapsed.
+    pub fn handle_copy_confirmation_expired(&mut self) -> Task<Message> {
+        Task::none()
+    }
+
+    /// Quote a chat message from the active tab in the message input
+    ///
+    /// Prefills the input with `> username: "snippet" ` ahead of anything
+    /// already typed. The quote is plain text, so it is sent as a normal
+    /// message. Only user messages (chat and broadcasts) can be quoted.
+    pub fn handle_reply_to(&mut self, index: usize) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let Some(msg) = conn.active_messages().get(index) else {
+            return Task::none();
+        };
+        if !matches!(msg.message_type, MessageType::Chat | MessageType::Broadcast) || msg.deleted {
+            return Task::none();
+        }
+
+        // Quote only the start of the first line to keep the reply readable
+        let first_line = msg.message.lines().next().unwrap_or_default();
+        let mut snippet: String = first_line.chars().take(QUOTE_SNIPPET_LENGTH).collect();
+        if snippet.len() < msg.message.len() {
+            snippet.push('…');
+        }
+
+        let quote = format!("> {}: \"{}\" ", msg.username, snippet);
+        let input = format!("{}{}", quote, conn.message_input.text().trim_start());
+        conn.message_input = text_editor::Content::with_text(&input);
+        conn.message_input
+            .perform(text_editor::Action::Move(text_editor::Motion::DocumentEnd));
+        self.focused_field = InputId::ChatInput;
+
+        operation::focus(Id::from(InputId::ChatInput))
+    }
+
+    /// Expand a collapsed message in the active tab, or collapse it again
+    ///
+    /// The state lives on the message itself, so it goes away with the
+    /// message when history is trimmed or the tab is closed.
+    pub fn handle_toggle_message_expanded(&mut self, index: usize) -> Task<Message> {
         if let Some(conn_id) = self.active_connection
             && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(msg) = conn
+                .active_messages_mut()
+                .and_then(|messages| messages.get_mut(index))
         {
-            conn.message_input = input;
+            msg.expanded = !msg.expanded;
+        }
+        Task::none()
+    }
+
+    /// Handle unpin button press in the pinned messages header
+    pub fn handle_unpin_message_pressed(&mut self, message_id: u64) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get(&conn_id) else {
+            return Task::none();
+        };
+
+        if let Err(e) = conn.send(ClientMessage::UnpinMessage { message_id }) {
+            let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+            return self.add_chat_error(conn_id, error_msg);
+        }
+
+        Task::none()
+    }
+
+    /// Handle an edit in the chat message input
+    ///
+    /// A paste of `paste_confirm_lines` or more lines is remembered so that
+    /// sending the input asks for confirmation first.
+    pub fn handle_message_input_action(&mut self, action: text_editor::Action) -> Task<Message> {
+        let paste_confirm_lines = self.config.settings.paste_confirm_lines;
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            if let text_editor::Action::Edit(text_editor::Edit::Paste(text)) = &action
+                && is_large_paste(text, paste_confirm_lines)
+            {
+                conn.large_paste = true;
+            }
+            // A pending confirmation no longer describes an edited input
+            if action.is_edit() {
+                conn.paste_confirmation = None;
+            }
+            conn.message_input.perform(action);
         }
         self.focused_field = InputId::ChatInput;
         Task::none()
@@ -273,7 +614,8 @@ impl NexusApp {
             return Task::none();
         };
 
-        let input = conn.message_input.clone();
+        // Pasted text may use CRLF line endings; the server accepts \n only
+        let input = conn.message_input.text().replace("\r\n", "\n");
 
         // Parse input for commands
         match commands::parse_input(&input) {
@@ -281,74 +623,145 @@ impl NexusApp {
             ParseResult::Command(command) => {
                 // Clear input and execute command
                 if let Some(conn) = self.connections.get_mut(&conn_id) {
-                    conn.message_input.clear();
+                    conn.message_input = text_editor::Content::new();
+                    conn.large_paste = false;
+                    conn.paste_confirmation = None;
                 }
                 commands::execute_command(self, conn_id, command)
             }
+            // Kept in the input so it can be fixed or escaped with `//`
+            ParseResult::MultilineCommand => {
+                self.add_chat_error(conn_id, t("err-multiline-command"))
+            }
             ParseResult::Message(message) => {
+                if matches!(conn.active_chat_tab, ChatTab::UserMessage(_))
+                    && conn.permission_disabled(PERMISSION_USER_MESSAGE)
+                {
+                    return self.add_chat_error(conn_id, t("err-private-messages-disabled"));
+                }
+
                 // Check permission before sending
                 let has_permission = match &conn.active_chat_tab {
-                    ChatTab::Server => {
-                        conn.is_admin || conn.permissions.iter().any(|p| p == PERMISSION_CHAT_SEND)
-                    }
-                    ChatTab::UserMessage(_) => {
-                        conn.is_admin
-                            || conn
-                                .permissions
-                                .iter()
-                                .any(|p| p == PERMISSION_USER_MESSAGE)
-                    }
+                    ChatTab::Server => conn.has_permission(PERMISSION_CHAT_SEND),
+                    ChatTab::UserMessage(_) => conn.has_permission(PERMISSION_USER_MESSAGE),
                 };
 
                 if !has_permission {
                     return self.add_chat_error(conn_id, t("err-no-chat-permission"));
                 }
 
+                // An identical message right after sending is an accidental repeat
+                if conn
+                    .send_debounce
+                    .is_repeat(&conn.active_chat_tab, &message, Instant::now())
+                {
+                    if let Some(conn) = self.connections.get_mut(&conn_id) {
+                        conn.message_input = text_editor::Content::new();
+                        conn.large_paste = false;
+                        conn.paste_confirmation = None;
+                    }
+                    return Task::none();
+                }
+
+                // Slow mode only applies to server chat (keep input so it can be resent)
+                if conn.active_chat_tab == ChatTab::Server
+                    && let Some(remaining) = conn.slow_mode_remaining()
+                {
+                    let error_msg =
+                        t_args("err-slow-mode-wait", &[("seconds", &remaining.to_string())]);
+                    return self.add_chat_error(conn_id, error_msg);
+                }
+
                 // Validate message content using shared validators
-                if let Err(e) = validators::validate_message(&message) {
-                    let error_msg = match e {
-                        MessageError::Empty => t("err-message-empty"),
-                        MessageError::TooLong => t_args(
-                            "err-message-too-long",
-                            &[
-                                ("length", &message.len().to_string()),
-                                ("max", &validators::MAX_MESSAGE_LENGTH.to_string()),
-                            ],
-                        ),
-                        MessageError::ContainsNewlines => t("err-message-contains-newlines"),
-                        MessageError::InvalidCharacters => t("err-message-invalid-characters"),
-                    };
+                if let Err(e) = validators::validate_message(&message, conn.max_message_length) {
+                    let error_msg = message_error_text(e, &message, conn.max_message_length);
                     return self.add_chat_error(conn_id, error_msg);
                 }
 
-                // Re-borrow conn after potential mutable borrow above
-                let Some(conn) = self.connections.get(&conn_id) else {
+                // A large paste goes out as one message, but only once confirmed
+                if conn.large_paste
+                    && is_large_paste(&message, self.config.settings.paste_confirm_lines)
+                {
+                    let lines = message.lines().count();
+                    if let Some(conn) = self.connections.get_mut(&conn_id) {
+                        conn.paste_confirmation = Some(lines);
+                    }
+                    return Task::none();
+                }
+
+                // Re-borrow conn mutably (private messages may wait for encryption keys)
+                let Some(conn) = self.connections.get_mut(&conn_id) else {
                     return Task::none();
                 };
 
-                let msg = match &conn.active_chat_tab {
-                    ChatTab::Server => ClientMessage::ChatSend { message },
-                    ChatTab::UserMessage(username) => ClientMessage::UserMessage {
-                        to_username: username.clone(),
-                        message,
-                    },
+                let result = match conn.active_chat_tab.clone() {
+                    ChatTab::Server => conn
+                        .send(ClientMessage::ChatSend {
+                            message: message.clone(),
+                        })
+                        .map(|_| ()),
+                    ChatTab::UserMessage(username) => {
+                        conn.send_private_message(&username, message.clone(), None)
+                    }
                 };
 
-                if let Err(e) = conn.send(msg) {
+                if let Err(e) = result {
                     let error_msg = format!("{}: {}", t("err-send-failed"), e);
                     return self.add_chat_error(conn_id, error_msg);
                 }
 
                 if let Some(conn) = self.connections.get_mut(&conn_id) {
-                    conn.message_input.clear();
+                    conn.message_input = text_editor::Content::new();
+                    conn.large_paste = false;
+                    conn.paste_confirmation = None;
+                    let now = Instant::now();
+                    if conn.active_chat_tab == ChatTab::Server {
+                        conn.last_chat_sent = Some(now);
+                    }
+                    conn.send_debounce
+                        .record(conn.active_chat_tab.clone(), message, now);
                 }
 
-                Task::none()
+                // Redraw once the debounce has ended so the send button is re-enabled
+                Task::perform(tokio::time::sleep(SEND_DEBOUNCE), |_| {
+                    Message::SendDebounceExpired
+                })
             }
         }
     }
 
+    /// Handle the send debounce window ending
+    ///
+    /// No state changes needed; the message only triggers a redraw so the
+    /// send button is enabled again once `SEND_DEBOUNCE` has elapsed.
+    pub fn handle_send_debounce_expired(&mut self) -> Task<Message> {
+        Task::none()
+    }
+
+    /// Send the input after the user confirmed a large paste
+    pub fn handle_confirm_large_paste(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.large_paste = false;
+            conn.paste_confirmation = None;
+        }
+        self.handle_send_message_pressed()
+    }
+
+    /// Dismiss the large paste confirmation, keeping the input for editing
+    pub fn handle_cancel_large_paste(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.paste_confirmation = None;
+        }
+        operation::focus(Id::from(InputId::ChatInput))
+    }
+
     /// Switch to a different chat tab (Server or UserMessage)
+    ///
+    /// A popped-out tab has its window focused instead.
     pub fn handle_switch_chat_tab(&mut self, tab: ChatTab) -> Task<Message> {
         let Some(conn_id) = self.active_connection else {
             return Task::none();
@@ -357,8 +770,17 @@ impl NexusApp {
             return Task::none();
         };
 
+        if let ChatTab::UserMessage(username) = &tab
+            && let Some(window_id) = conn.popout_window(username)
+        {
+            return iced::window::gain_focus(window_id);
+        }
+
         conn.unread_tabs.remove(&tab);
-        conn.active_chat_tab = tab;
+        if let ChatTab::UserMessage(username) = &tab {
+            conn.message_tabs.touch(username);
+        }
+        conn.set_active_chat_tab(tab);
 
         self.handle_show_chat_view()
     }
@@ -370,3 +792,62 @@ impl NexusApp {
         self.add_chat_message(connection_id, ChatMessage::error(message))
     }
 }
+
+/// Whether pasted text has enough lines to ask before sending it
+///
+/// A `threshold` of 0 never asks.
+fn is_large_paste(text: &str, threshold: usize) -> bool {
+    threshold > 0 && text.lines().count() >= threshold
+}
+
+/// Translated message for a chat message that failed validation
+pub(super) fn message_error_text(error: MessageError, message: &str, max_length: usize) -> String {
+    match error {
+        MessageError::Empty => t("err-message-empty"),
+        MessageError::TooLong => t_args(
+            "err-message-too-long",
+            &[
+                ("length", &message.chars().count().to_string()),
+                ("max", &max_length.to_string()),
+            ],
+        ),
+        MessageError::InvalidCharacters => t("err-message-invalid-characters"),
+    }
+}
+
+/// Translated message for a rejected invite link
+fn invite_error_message(error: InviteError) -> String {
+    match error {
+        InviteError::NotAnInvite => t("err-invite-not-a-link"),
+        InviteError::InvalidAddress => t("err-invite-invalid-address"),
+        InviteError::InvalidPort => t("err-invite-invalid-port"),
+        InviteError::InvalidName => t("err-invite-invalid-name"),
+        InviteError::InvalidFingerprint => t("err-invite-invalid-fingerprint"),
+        InviteError::ContainsCredentials => t("err-invite-credentials"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_large_paste_threshold() {
+        assert!(!is_large_paste("one\ntwo", 3));
+        assert!(is_large_paste("one\ntwo\nthree", 3));
+        assert!(is_large_paste("one\ntwo\nthree\nfour", 3));
+    }
+
+    #[test]
+    fn test_is_large_paste_trailing_newline() {
+        // A trailing newline doesn't start another line
+        assert!(!is_large_paste("one\ntwo\n", 3));
+        assert!(is_large_paste("one\r\ntwo\r\nthree\r\n", 3));
+    }
+
+    #[test]
+    fn test_is_large_paste_zero_is_off() {
+        assert!(!is_large_paste("", 0));
+        assert!(!is_large_paste(&"line\n".repeat(1000), 0));
+    }
+}
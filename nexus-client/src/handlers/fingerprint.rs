@@ -25,6 +25,19 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Accept new certificate fingerprint and trust this bookmark's future changes
+    ///
+    /// Remembers the choice on the bookmark, so later certificate changes are
+    /// accepted without this dialog.
+    pub fn handle_always_accept_new_fingerprint(&mut self) -> Task<Message> {
+        if let Some(mismatch) = self.fingerprint_mismatch_queue.front()
+            && let Some(bookmark) = self.config.bookmarks.get_mut(mismatch.bookmark_index)
+        {
+            bookmark.trust_certificate_changes = true;
+        }
+        self.handle_accept_new_fingerprint()
+    }
+
     /// Reject new certificate fingerprint (cancel connection)
     pub fn handle_cancel_fingerprint_mismatch(&mut self) -> Task<Message> {
         self.fingerprint_mismatch_queue.pop_front();
@@ -7,8 +7,22 @@ use iced::widget::{Id, operation};
 use iced::{Event, Task};
 
 impl NexusApp {
-    /// Handle keyboard events (Tab, Enter, Escape)
+    /// Handle keyboard events (Tab, Enter, Escape, Ctrl+R)
     pub fn handle_keyboard_event(&mut self, event: Event) -> Task<Message> {
+        // Handle Cmd/Ctrl+R to reply to the last private message
+        // (to_latin keeps the shortcut on the R key with non-Latin layouts)
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key,
+            physical_key,
+            modifiers,
+            ..
+        }) = &event
+            && modifiers.command()
+            && !modifiers.shift()
+            && key.to_latin(*physical_key) == Some('r')
+        {
+            return self.update(Message::ReplyToLast);
+        }
         // Handle Cmd/Ctrl+Shift+Tab for previous chat tab (must be before plain Tab check)
         if let Event::Keyboard(keyboard::Event::KeyPressed {
             key: keyboard::Key::Named(key::Named::Tab),
@@ -49,6 +63,16 @@ impl NexusApp {
             ..
         }) = event
         {
+            // The page viewer sits above any panel
+            if let Some(conn_id) = self.active_connection
+                && self
+                    .connections
+                    .get(&conn_id)
+                    .is_some_and(|conn| conn.open_page.is_some())
+            {
+                return self.update(Message::ClosePage(conn_id));
+            }
+
             if self.bookmark_edit.mode != BookmarkEditMode::None {
                 // On bookmark edit screen, try to save
                 let can_save = !self.bookmark_edit.bookmark.name.trim().is_empty()
@@ -112,7 +136,13 @@ impl NexusApp {
                 }
                 return self.update(Message::CloseServerInfo);
             } else if self.active_panel() == ActivePanel::UserInfo {
-                // On user info screen, close the panel
+                // On user info screen, save profile if editing, otherwise close
+                if let Some(conn_id) = self.active_connection
+                    && let Some(conn) = self.connections.get(&conn_id)
+                    && conn.profile_edit.is_some()
+                {
+                    return self.update(Message::UpdateProfilePressed);
+                }
                 return self.update(Message::CloseUserInfo);
             } else if self.active_connection.is_none() {
                 // On connection screen, try to connect
@@ -152,7 +182,16 @@ impl NexusApp {
                         }
                         return self.update(Message::CloseServerInfo);
                     }
-                    ActivePanel::UserInfo => return self.update(Message::CloseUserInfo),
+                    ActivePanel::UserInfo => {
+                        // If editing our profile, cancel edit; otherwise close panel
+                        if let Some(conn_id) = self.active_connection
+                            && let Some(conn) = self.connections.get(&conn_id)
+                            && conn.profile_edit.is_some()
+                        {
+                            return self.update(Message::CancelEditProfile);
+                        }
+                        return self.update(Message::CloseUserInfo);
+                    }
                     ActivePanel::None => {}
                 }
             }
@@ -169,9 +208,14 @@ impl NexusApp {
             return Task::none();
         };
 
-        // Build ordered list of tabs: Server first, then PMs alphabetically
+        // Build ordered list of tabs: Server first, then inline PMs alphabetically
         let mut tabs = vec![ChatTab::Server];
-        let mut pm_usernames: Vec<String> = conn.user_messages.keys().cloned().collect();
+        let mut pm_usernames: Vec<String> = conn
+            .user_messages
+            .keys()
+            .filter(|username| conn.popout_window(username).is_none())
+            .cloned()
+            .collect();
         pm_usernames.sort();
         for username in pm_usernames {
             tabs.push(ChatTab::UserMessage(username));
@@ -197,9 +241,14 @@ impl NexusApp {
             return Task::none();
         };
 
-        // Build ordered list of tabs: Server first, then PMs alphabetically
+        // Build ordered list of tabs: Server first, then inline PMs alphabetically
         let mut tabs = vec![ChatTab::Server];
-        let mut pm_usernames: Vec<String> = conn.user_messages.keys().cloned().collect();
+        let mut pm_usernames: Vec<String> = conn
+            .user_messages
+            .keys()
+            .filter(|username| conn.popout_window(username).is_none())
+            .cloned()
+            .collect();
         pm_usernames.sort();
         for username in pm_usernames {
             tabs.push(ChatTab::UserMessage(username));
@@ -220,6 +269,43 @@ impl NexusApp {
         self.update(Message::SwitchChatTab(prev_tab))
     }
 
+    /// Open the message tab of whoever last sent us a private message
+    ///
+    /// Falls back to refocusing the input when no one has messaged us yet but
+    /// a message tab is already open, so the shortcut always means "reply".
+    pub fn handle_reply_to_last(&mut self) -> Task<Message> {
+        let max_message_tabs = self.config.settings.max_message_tabs;
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        if let Some(username) = conn.last_message_from.clone() {
+            // Reopens the tab if it was closed since; a popped-out tab just
+            // gets its window focused
+            let popped_out = conn.popout_window(&username).is_some();
+            conn.open_user_message_tab(&username, max_message_tabs);
+            let switch = self.update(Message::SwitchChatTab(ChatTab::UserMessage(username)));
+            if popped_out {
+                return switch;
+            }
+            return Task::batch([switch, self.focus_chat_input()]);
+        }
+        if matches!(conn.active_chat_tab, ChatTab::UserMessage(_)) {
+            let show = self.handle_show_chat_view();
+            return Task::batch([show, self.focus_chat_input()]);
+        }
+        Task::none()
+    }
+
+    /// Move keyboard focus to the chat input
+    fn focus_chat_input(&mut self) -> Task<Message> {
+        self.focused_field = InputId::ChatInput;
+        operation::focus(Id::from(InputId::ChatInput))
+    }
+
     /// Handle Tab key navigation across different screens
     pub fn handle_tab_navigation(&mut self) -> Task<Message> {
         if self.bookmark_edit.mode != BookmarkEditMode::None {
@@ -227,9 +313,11 @@ impl NexusApp {
             let next_field = match self.focused_field {
                 InputId::BookmarkName => InputId::BookmarkAddress,
                 InputId::BookmarkAddress => InputId::BookmarkPort,
-                InputId::BookmarkPort => InputId::BookmarkUsername,
+                InputId::BookmarkPort => InputId::BookmarkAddresses,
+                InputId::BookmarkAddresses => InputId::BookmarkUsername,
                 InputId::BookmarkUsername => InputId::BookmarkPassword,
-                InputId::BookmarkPassword => InputId::BookmarkName,
+                InputId::BookmarkPassword => InputId::BookmarkWatchedUsers,
+                InputId::BookmarkWatchedUsers => InputId::BookmarkName,
                 _ => InputId::BookmarkName,
             };
             self.focused_field = next_field;
@@ -281,6 +369,20 @@ impl NexusApp {
                 self.focused_field = next_field;
                 return operation::focus(Id::from(next_field));
             }
+        } else if self.active_panel() == ActivePanel::UserInfo {
+            // Profile edit: cycle through pronouns and bio fields
+            if let Some(conn_id) = self.active_connection
+                && let Some(conn) = self.connections.get(&conn_id)
+                && conn.profile_edit.is_some()
+            {
+                let next_field = match self.focused_field {
+                    InputId::EditProfilePronouns => InputId::EditProfileBio,
+                    InputId::EditProfileBio => InputId::EditProfilePronouns,
+                    _ => InputId::EditProfilePronouns,
+                };
+                self.focused_field = next_field;
+                return operation::focus(Id::from(next_field));
+            }
         } else if self.active_panel() == ActivePanel::Broadcast {
             // Broadcast screen only has one field, so focus stays
             self.focused_field = InputId::BroadcastMessage;
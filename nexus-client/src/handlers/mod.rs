@@ -1,11 +1,16 @@
 //! Message handlers organized by category
 
+mod banner;
 mod bookmarks;
 mod broadcast;
 mod connection;
 mod fingerprint;
 mod keyboard;
 pub(crate) mod network;
+mod pages;
+mod popout;
+mod profile;
+mod rules;
 mod server_info;
 mod settings;
 mod ui;
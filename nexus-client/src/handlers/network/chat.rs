@@ -36,14 +36,19 @@ impl NexusApp {
     }
 
     /// Add chat topic message if present and not empty
+    ///
+    /// With `hide_repeated_topic` enabled, a topic identical to the one last
+    /// shown for this bookmark is skipped, so reconnects don't repeat it.
     pub fn add_topic_message(
         &mut self,
         connection_id: usize,
         chat_topic: Option<String>,
         chat_topic_set_by: Option<String>,
     ) {
+        let chat_topic = chat_topic.filter(|topic| !topic.is_empty());
+        let changed = self.remember_topic(connection_id, chat_topic.as_deref());
         if let Some(topic) = chat_topic
-            && !topic.is_empty()
+            && (changed || !self.config.settings.hide_repeated_topic)
         {
             let message = match chat_topic_set_by {
                 Some(ref username) if !username.is_empty() => t_args(
@@ -55,4 +60,40 @@ impl NexusApp {
             let _ = self.add_chat_message(connection_id, ChatMessage::system(message));
         }
     }
+
+    /// Record the topic as last seen on the connection's bookmark
+    ///
+    /// Returns whether it changed since it was last seen. Connections without
+    /// a bookmark have nothing to compare against, so their topic always counts
+    /// as changed.
+    pub fn remember_topic(&mut self, connection_id: usize, topic: Option<&str>) -> bool {
+        let Some(index) = self
+            .connections
+            .get(&connection_id)
+            .and_then(|conn| conn.bookmark_index)
+        else {
+            return true;
+        };
+        let Some(bookmark) = self.config.bookmarks.get_mut(index) else {
+            return true;
+        };
+
+        let changed = bookmark.see_topic(topic);
+        if changed {
+            // With the settings panel open the config holds unsaved edits, so
+            // the topic goes into the saved copy and only that is written
+            match &mut self.settings_form {
+                Some(form) => {
+                    if let Some(saved) = form.original_config.bookmarks.get_mut(index) {
+                        saved.see_topic(topic);
+                    }
+                    let _ = form.original_config.save();
+                }
+                None => {
+                    let _ = self.config.save();
+                }
+            }
+        }
+        changed
+    }
 }
@@ -1,17 +1,22 @@
 //! Connection result handlers
 
 use crate::NexusApp;
+use crate::handlers::popout::close_popouts;
 use crate::i18n::{t, t_args};
 use crate::image::decode_data_uri_max_width;
 use crate::style::SERVER_IMAGE_MAX_CACHE_WIDTH;
 
 use crate::types::{
-    ActivePanel, InputId, Message, NetworkConnection, ServerBookmark, ServerConnection,
+    ActivePanel, ChatMessage, ChatTab, ConnectionEvent, InputId, Message, NetworkConnection,
+    ServerBookmark, ServerConnection, format_endpoint, format_event_line,
 };
 use crate::views::constants::PERMISSION_USER_LIST;
+use chrono::Local;
 use iced::Task;
 use iced::widget::{Id, operation};
+use iced::window::UserAttention;
 use nexus_common::protocol::ClientMessage;
+use std::path::PathBuf;
 
 /// Result of creating and registering a connection
 struct ConnectionRegistration {
@@ -31,6 +36,7 @@ impl NexusApp {
         result: Result<NetworkConnection, String>,
     ) -> Task<Message> {
         self.connection_form.is_connecting = false;
+        self.connection_form.step = None;
 
         match result {
             Ok(conn) => {
@@ -44,12 +50,17 @@ impl NexusApp {
                 });
 
                 // Verify and save certificate fingerprint
-                if let Err(mismatch_details) =
-                    self.verify_and_save_fingerprint(bookmark_index, &conn.certificate_fingerprint)
-                {
-                    let display_name = self.get_display_name(bookmark_index);
-                    return self.handle_fingerprint_mismatch(*mismatch_details, conn, display_name);
-                }
+                let trusted_change = match self.verify_and_save_fingerprint(bookmark_index, &conn) {
+                    Ok(trusted_change) => trusted_change,
+                    Err(mismatch_details) => {
+                        let display_name = self.get_display_name(bookmark_index);
+                        return self.handle_fingerprint_mismatch(
+                            *mismatch_details,
+                            conn,
+                            display_name,
+                        );
+                    }
+                };
 
                 let connection_id = conn.connection_id;
                 let display_name = self.get_display_name(bookmark_index);
@@ -67,9 +78,10 @@ impl NexusApp {
                     return Task::none();
                 };
 
-                // Request user list if we have permission
-                if let Err(error_msg) =
-                    self.request_initial_userlist(connection_id, reg.should_request_userlist)
+                // Request user list if we have permission, then server capabilities
+                if let Err(error_msg) = self
+                    .request_initial_userlist(connection_id, reg.should_request_userlist)
+                    .and_then(|()| self.request_capabilities(connection_id))
                 {
                     self.connection_form.error = Some(error_msg);
                     self.connections.remove(&connection_id);
@@ -79,6 +91,7 @@ impl NexusApp {
 
                 // Add chat topic message if present
                 self.add_topic_message(connection_id, reg.chat_topic, reg.chat_topic_set_by);
+                self.add_trusted_certificate_change_message(connection_id, trusted_change);
 
                 // Save as bookmark if checkbox was enabled (and not already a bookmark)
                 if self.connection_form.add_bookmark && bookmark_index.is_none() {
@@ -88,6 +101,10 @@ impl NexusApp {
                 // Clear connection form
                 self.connection_form.clear();
 
+                if let Some(task) = self.next_password_prompt(bookmark_index) {
+                    return task;
+                }
+
                 operation::focus(Id::from(InputId::ChatInput))
             }
             Err(error) => {
@@ -114,15 +131,21 @@ impl NexusApp {
                 // Clear the connecting lock and any previous error for this bookmark
                 if let Some(idx) = bookmark_index {
                     self.connecting_bookmarks.remove(&idx);
+                    self.bookmark_steps.remove(&idx);
                     self.bookmark_errors.remove(&idx);
                 }
 
                 // Verify and save certificate fingerprint
-                if let Err(mismatch_details) =
-                    self.verify_and_save_fingerprint(bookmark_index, &conn.certificate_fingerprint)
-                {
-                    return self.handle_fingerprint_mismatch(*mismatch_details, conn, display_name);
-                }
+                let trusted_change = match self.verify_and_save_fingerprint(bookmark_index, &conn) {
+                    Ok(trusted_change) => trusted_change,
+                    Err(mismatch_details) => {
+                        return self.handle_fingerprint_mismatch(
+                            *mismatch_details,
+                            conn,
+                            display_name,
+                        );
+                    }
+                };
 
                 // Extract username from bookmark
                 let username = bookmark_index
@@ -130,6 +153,15 @@ impl NexusApp {
                     .map(|b| b.username.clone())
                     .unwrap_or_default();
 
+                // Note when a fallback address answered instead of the primary one
+                let fallback_endpoint = bookmark_index
+                    .and_then(|idx| self.config.get_bookmark(idx))
+                    .filter(|b| {
+                        b.address.trim() != conn.server_address
+                            || b.port.trim() != conn.server_port.to_string()
+                    })
+                    .map(|_| format_endpoint(&conn.server_address, conn.server_port));
+
                 // Create and register connection
                 let Some(reg) = self.create_and_register_connection(
                     conn,
@@ -144,9 +176,10 @@ impl NexusApp {
                     return Task::none();
                 };
 
-                // Request initial user list
-                if let Err(error_msg) =
-                    self.request_initial_userlist(connection_id, reg.should_request_userlist)
+                // Request initial user list, then server capabilities
+                if let Err(error_msg) = self
+                    .request_initial_userlist(connection_id, reg.should_request_userlist)
+                    .and_then(|()| self.request_capabilities(connection_id))
                 {
                     self.connections.remove(&connection_id);
                     self.active_connection = None;
@@ -158,12 +191,19 @@ impl NexusApp {
 
                 // Add chat topic message if present
                 self.add_topic_message(connection_id, reg.chat_topic, reg.chat_topic_set_by);
+                self.add_trusted_certificate_change_message(connection_id, trusted_change);
+
+                if let Some(endpoint) = fallback_endpoint {
+                    let message = t_args("msg-connected-via-fallback", &[("address", &endpoint)]);
+                    let _ = self.add_chat_message(connection_id, ChatMessage::system(message));
+                }
 
                 operation::focus(Id::from(InputId::ChatInput))
             }
             Err(error) => {
                 if let Some(idx) = bookmark_index {
                     self.connecting_bookmarks.remove(&idx);
+                    self.bookmark_steps.remove(&idx);
                     self.bookmark_errors.insert(idx, error);
                 }
                 Task::none()
@@ -172,8 +212,18 @@ impl NexusApp {
     }
 
     /// Handle network error or connection closure
+    ///
+    /// Deliberate disconnects remove the connection first, so only unexpected
+    /// drops get here with the connection still registered.
     pub fn handle_network_error(&mut self, connection_id: usize, error: String) -> Task<Message> {
-        if let Some(conn) = self.connections.remove(&connection_id) {
+        self.record_connection_event(
+            connection_id,
+            ConnectionEvent::Disconnected {
+                reason: Some(error.clone()),
+            },
+        );
+
+        if let Some(mut conn) = self.connections.remove(&connection_id) {
             // Clean up the receiver from the global registry
             let registry = crate::network::NETWORK_RECEIVERS.clone();
             tokio::spawn(async move {
@@ -190,11 +240,55 @@ impl NexusApp {
                 }
             });
 
+            self.forget_bookmark_password(conn.bookmark_index);
+
+            // Remember the active tab so reconnecting to this bookmark can restore it
+            if let Some(idx) = conn.bookmark_index
+                && conn.active_chat_tab != ChatTab::Server
+            {
+                self.restore_chat_tabs
+                    .insert(idx, conn.active_chat_tab.clone());
+            }
+
+            // Keep unsent input for the reconnect too
+            if let Some(idx) = conn.bookmark_index {
+                self.restore_drafts.insert(idx, conn.take_drafts());
+            }
+
+            // A disabled account can't log back in, so say so instead of
+            // showing a generic disconnect the user might retry
+            let error_msg = if conn.account_disabled {
+                let error_msg = t("err-account-disabled-while-connected");
+                if let Some(idx) = conn.bookmark_index {
+                    self.bookmark_errors.insert(idx, error_msg.clone());
+                }
+                error_msg
+            } else {
+                t_args("msg-disconnected", &[("error", &error)])
+            };
+
             // If this was the active connection, clear it
-            if self.active_connection == Some(connection_id) {
+            let was_active = self.active_connection == Some(connection_id);
+            if was_active {
                 self.active_connection = None;
-                self.connection_form.error = Some(t_args("msg-disconnected", &[("error", &error)]));
+                self.connection_form.error = Some(error_msg);
             }
+
+            // Popped-out tabs have nothing left to show
+            let close_popouts = close_popouts(&conn);
+
+            // Alert unless the user is already looking at this connection or
+            // it's quiet hours
+            if self.config.settings.alert_on_disconnect
+                && !(was_active && self.main_window_focused())
+                && !self.config.settings.quiet_hours.is_active()
+            {
+                let alert = iced::window::oldest().and_then(|id| {
+                    iced::window::request_user_attention(id, Some(UserAttention::Critical))
+                });
+                return Task::batch([close_popouts, alert]);
+            }
+            return close_popouts;
         }
         Task::none()
     }
@@ -217,8 +311,6 @@ impl NexusApp {
         let shutdown_handle = conn.shutdown?;
         let chat_topic = conn.chat_topic.clone();
         let chat_topic_set_by = conn.chat_topic_set_by.clone();
-        let should_request_userlist =
-            conn.is_admin || conn.permissions.iter().any(|p| p == PERMISSION_USER_LIST);
 
         let cached_server_image = if conn.server_image.is_empty() {
             None
@@ -226,7 +318,7 @@ impl NexusApp {
             decode_data_uri_max_width(&conn.server_image, SERVER_IMAGE_MAX_CACHE_WIDTH)
         };
 
-        let server_conn = ServerConnection::new(
+        let mut server_conn = ServerConnection::new(
             bookmark_index,
             conn.session_id,
             username,
@@ -243,13 +335,60 @@ impl NexusApp {
             chat_topic.clone(),
             chat_topic_set_by.clone(),
             conn.max_connections_per_ip,
+            conn.chat_slow_mode,
+            conn.user_count,
             conn.tx,
             shutdown_handle,
         );
 
+        server_conn.server_address = conn.server_address;
+        server_conn.server_port = conn.server_port;
+        server_conn.certificate_fingerprint = conn.certificate_fingerprint;
+        server_conn.max_message_length = conn.max_message_length;
+        server_conn.max_topic_length = conn.max_topic_length;
+        server_conn.pinned_messages = conn.pinned_messages;
+        server_conn.pending_rules = conn.rules;
+        server_conn.maintenance = conn.maintenance;
+        server_conn.disabled_permissions = conn.disabled_permissions;
+        server_conn.traffic_totals = conn.traffic.totals();
+        server_conn.traffic = conn.traffic;
+        server_conn.extended_protocol = conn.extended_protocol;
+        let should_request_userlist = server_conn.has_permission(PERMISSION_USER_LIST);
+
+        // Preferences synced from the user's other devices win over local ones
+        if self.config.settings.sync_preferences
+            && let Some(preferences) = &conn.preferences
+        {
+            self.config.settings.apply_synced_preferences(preferences);
+            let _ = self.config.save();
+        }
+
+        // Apply per-bookmark chat preferences
+        server_conn.connection_notifications = bookmark_index
+            .and_then(|idx| self.config.get_bookmark(idx))
+            .and_then(|b| b.connection_notifications);
+
+        // Restore the tab from a dropped connection to this bookmark. PM targets
+        // are validated against the initial user list, so without one we stay
+        // on the server tab.
+        let restore_tab = bookmark_index.and_then(|idx| self.restore_chat_tabs.remove(&idx));
+        if should_request_userlist {
+            server_conn.pending_tab_restore = restore_tab;
+            server_conn.pending_watch_check = true;
+        }
+        if let Some(drafts) = bookmark_index.and_then(|idx| self.restore_drafts.remove(&idx)) {
+            server_conn.restore_drafts(drafts);
+        }
+
+        let endpoint = format_endpoint(&server_conn.server_address, server_conn.server_port);
+        let username = server_conn.username.clone();
         self.connections.insert(conn.connection_id, server_conn);
         self.active_connection = Some(conn.connection_id);
 
+        // The handshake and login completed together
+        self.record_connection_event(conn.connection_id, ConnectionEvent::Connected { endpoint });
+        self.record_connection_event(conn.connection_id, ConnectionEvent::LoggedIn { username });
+
         // Always start on chat screen - close any app-wide panels (Settings/About)
         self.ui_state.active_panel = ActivePanel::None;
 
@@ -260,8 +399,37 @@ impl NexusApp {
         })
     }
 
+    /// Add an event to a connection's timeline
+    ///
+    /// Also queues the event for the connection log file when one is set in
+    /// settings. Events for connections that are already gone are dropped.
+    pub fn record_connection_event(&mut self, connection_id: usize, event: ConnectionEvent) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+        let now = Local::now();
+
+        if let Some(log) = &self.connection_log
+            && let Some(path) = self
+                .config
+                .settings
+                .connection_log_path
+                .as_deref()
+                .map(str::trim)
+                .filter(|path| !path.is_empty())
+        {
+            let line = format_event_line(now, &event);
+            log.append(
+                PathBuf::from(path),
+                format!("[{}] {}", conn.display_name, line),
+            );
+        }
+
+        conn.event_log.record(now, event);
+    }
+
     /// Get display name from connection form or bookmark
-    fn get_display_name(&self, bookmark_index: Option<usize>) -> String {
+    pub fn get_display_name(&self, bookmark_index: Option<usize>) -> String {
         if !self.connection_form.server_name.trim().is_empty() {
             self.connection_form.server_name.clone()
         } else if let Some(name) = bookmark_index
@@ -292,16 +460,40 @@ impl NexusApp {
         Ok(())
     }
 
+    /// Ask the server which message types and features it supports
+    ///
+    /// Servers older than the extended protocol have no Capabilities message
+    /// and would disconnect on it.
+    fn request_capabilities(&self, connection_id: usize) -> Result<(), String> {
+        if let Some(conn) = self.connections.get(&connection_id)
+            && conn.extended_protocol
+            && let Err(e) = conn.send(ClientMessage::Capabilities)
+        {
+            return Err(format!("{}: {}", t("err-connection-broken"), e));
+        }
+        Ok(())
+    }
+
     /// Save a new bookmark from the current connection form
     fn save_new_bookmark(&mut self, connection_id: usize, certificate_fingerprint: String) {
         let new_bookmark = ServerBookmark {
             name: self.connection_form.server_name.clone(),
             address: self.connection_form.server_address.clone(),
             port: self.connection_form.port.clone(),
+            addresses: Vec::new(),
             username: self.connection_form.username.clone(),
             password: self.connection_form.password.clone(),
             auto_connect: false,
             certificate_fingerprint: Some(certificate_fingerprint),
+            trust_certificate_changes: false,
+            accepted_banner: self.connection_form.accepted_banner.clone(),
+            theme: None,
+            connection_notifications: None,
+            last_seen_topic: self
+                .connections
+                .get(&connection_id)
+                .and_then(|conn| conn.chat_topic.clone()),
+            ..ServerBookmark::default()
         };
         self.config.add_bookmark(new_bookmark);
         let _ = self.config.save();
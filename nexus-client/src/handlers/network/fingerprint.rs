@@ -1,24 +1,37 @@
 //! Certificate fingerprint verification and handling
 
 use crate::NexusApp;
-use crate::types::{FingerprintMismatch, FingerprintMismatchDetails, Message, NetworkConnection};
+use crate::i18n::t_args;
+use crate::types::{
+    ChatMessage, FingerprintMismatch, FingerprintMismatchDetails, Message, NetworkConnection,
+};
 use iced::Task;
 
 impl NexusApp {
     /// Verify certificate fingerprint matches stored value, or save on first connection (TOFU)
+    ///
+    /// A bookmark's fallback addresses share its fingerprint, so whichever
+    /// endpoint connected is checked against it, and a mismatch names that
+    /// endpoint rather than the primary address.
+    ///
+    /// Bookmarks that trust certificate changes accept a new fingerprint
+    /// without asking; the new fingerprint is returned so the caller can note
+    /// the change in chat.
     pub fn verify_and_save_fingerprint(
         &mut self,
         bookmark_index: Option<usize>,
-        fingerprint: &str,
-    ) -> Result<(), Box<FingerprintMismatchDetails>> {
+        conn: &NetworkConnection,
+    ) -> Result<Option<String>, Box<FingerprintMismatchDetails>> {
+        let fingerprint = conn.certificate_fingerprint.as_str();
+
         let Some(idx) = bookmark_index else {
             // No bookmark - nothing to verify
-            return Ok(());
+            return Ok(None);
         };
 
         let Some(bookmark) = self.config.bookmarks.get_mut(idx) else {
             // Invalid bookmark index - nothing to verify
-            return Ok(());
+            return Ok(None);
         };
 
         match &bookmark.certificate_fingerprint {
@@ -26,26 +39,46 @@ impl NexusApp {
                 // First connection - save fingerprint (Trust On First Use)
                 bookmark.certificate_fingerprint = Some(fingerprint.to_string());
                 let _ = self.config.save();
-                Ok(())
+                Ok(None)
             }
             Some(stored) => {
                 // Verify fingerprint matches
                 if stored == fingerprint {
-                    Ok(())
+                    Ok(None)
+                } else if bookmark.trust_certificate_changes {
+                    // Opted out of the dialog - pin the new certificate instead
+                    bookmark.certificate_fingerprint = Some(fingerprint.to_string());
+                    let _ = self.config.save();
+                    Ok(Some(fingerprint.to_string()))
                 } else {
                     Err(Box::new(FingerprintMismatchDetails {
                         bookmark_index: idx,
                         expected: stored.clone(),
                         received: fingerprint.to_string(),
                         bookmark_name: bookmark.name.clone(),
-                        server_address: bookmark.address.clone(),
-                        server_port: bookmark.port.clone(),
+                        server_address: conn.server_address.clone(),
+                        server_port: conn.server_port.to_string(),
                     }))
                 }
             }
         }
     }
 
+    /// Note a certificate change that was accepted without asking
+    pub fn add_trusted_certificate_change_message(
+        &mut self,
+        connection_id: usize,
+        new_fingerprint: Option<String>,
+    ) {
+        if let Some(fingerprint) = new_fingerprint {
+            let message = t_args(
+                "msg-certificate-change-trusted",
+                &[("fingerprint", &fingerprint)],
+            );
+            let _ = self.add_chat_message(connection_id, ChatMessage::system(message));
+        }
+    }
+
     /// Handle fingerprint mismatch by queuing it for user verification
     pub fn handle_fingerprint_mismatch(
         &mut self,
@@ -66,6 +99,7 @@ impl NexusApp {
             });
 
         self.connection_form.is_connecting = false;
+        self.connection_form.step = None;
         Task::none()
     }
 }
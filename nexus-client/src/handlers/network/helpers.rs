@@ -4,7 +4,7 @@ use crate::types::UserInfo;
 
 /// Helper function to sort user list alphabetically by username (case-insensitive)
 pub fn sort_user_list(users: &mut [UserInfo]) {
-    users.sort_by(|a, b| a.username.to_lowercase().cmp(&b.username.to_lowercase()));
+    users.sort_by_key(|user| user.username.to_lowercase());
 }
 
 /// Format session duration in human-readable form
@@ -17,3 +17,28 @@ pub fn format_duration(seconds: u64) -> String {
         format!("{}h {}m", seconds / 3600, (seconds % 3600) / 60)
     }
 }
+
+/// Format time elapsed since an event, e.g. "3d 4h" (under a day, as `format_duration`)
+pub fn format_elapsed(seconds: u64) -> String {
+    if seconds < 86400 {
+        format_duration(seconds)
+    } else {
+        format!("{}d {}h", seconds / 86400, (seconds % 86400) / 3600)
+    }
+}
+
+/// Pair each session address with its source tag, e.g. "10.0.0.5:4242 (lan)"
+///
+/// Older servers don't send sources, in which case the addresses are returned as-is.
+pub fn format_session_addresses(addresses: &[String], sources: Option<&[String]>) -> Vec<String> {
+    addresses
+        .iter()
+        .enumerate()
+        .map(
+            |(index, address)| match sources.and_then(|sources| sources.get(index)) {
+                Some(source) => format!("{} ({})", address, source),
+                None => address.clone(),
+            },
+        )
+        .collect()
+}
@@ -0,0 +1,87 @@
+//! Rotating announcement handlers
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use iced::Task;
+
+impl NexusApp {
+    /// Handle an announcement broadcast by the server's ticker
+    pub fn handle_server_announcement(
+        &mut self,
+        connection_id: usize,
+        message: String,
+    ) -> Task<Message> {
+        self.add_chat_message(connection_id, ChatMessage::announcement(message))
+    }
+
+    /// Handle announcement add response
+    pub fn handle_announcement_add_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t("cmd-announce-added"))
+        } else {
+            announcement_error(error)
+        };
+        self.add_chat_message(connection_id, message)
+    }
+
+    /// Handle announcement list response (numbered for /announce remove)
+    pub fn handle_announcement_list_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        announcements: Vec<String>,
+        interval_minutes: u32,
+    ) -> Task<Message> {
+        if !success {
+            return self.add_chat_message(connection_id, announcement_error(error));
+        }
+
+        if announcements.is_empty() {
+            return self.add_chat_message(connection_id, ChatMessage::info(t("cmd-announce-none")));
+        }
+
+        let header = t_args(
+            "cmd-announce-list",
+            &[("minutes", &interval_minutes.to_string())],
+        );
+        let mut tasks = vec![self.add_chat_message(connection_id, ChatMessage::info(header))];
+        for (i, announcement) in announcements.iter().enumerate() {
+            let line = t_args(
+                "cmd-announce-list-item",
+                &[("number", &(i + 1).to_string()), ("message", announcement)],
+            );
+            tasks.push(self.add_chat_message(connection_id, ChatMessage::info(line)));
+        }
+        Task::batch(tasks)
+    }
+
+    /// Handle announcement remove response
+    pub fn handle_announcement_remove_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t("cmd-announce-removed"))
+        } else {
+            announcement_error(error)
+        };
+        self.add_chat_message(connection_id, message)
+    }
+}
+
+/// Build the chat error shown when an announcement request fails
+fn announcement_error(error: Option<String>) -> ChatMessage {
+    ChatMessage::error(t_args(
+        "err-failed-announcement",
+        &[("error", &error.unwrap_or_default())],
+    ))
+}
@@ -1,10 +1,49 @@
 //! Broadcast message handlers
 
 use crate::NexusApp;
+use crate::i18n::t_args;
 use crate::types::{ChatMessage, Message};
 use iced::Task;
+use nexus_common::protocol::AdminNoticeAction;
 
 impl NexusApp {
+    /// Handle an admin notice
+    ///
+    /// The notice replaces any earlier one and stays as a banner above the
+    /// chat until dismissed.
+    pub fn handle_admin_notice(
+        &mut self,
+        connection_id: usize,
+        action: AdminNoticeAction,
+    ) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.admin_notice = Some(action);
+        }
+        Task::none()
+    }
+
+    /// Handle admin notice response
+    ///
+    /// Success is shown by the `AdminNotice` that follows, which the sender
+    /// receives too.
+    pub fn handle_admin_notice_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+        self.add_chat_message(
+            connection_id,
+            ChatMessage::error(t_args(
+                "err-failed-send-admin-notice",
+                &[("error", &error.unwrap_or_default())],
+            )),
+        )
+    }
+
     /// Handle incoming server broadcast message
     pub fn handle_server_broadcast(
         &mut self,
@@ -0,0 +1,39 @@
+//! Server capabilities response handler
+
+use crate::NexusApp;
+use crate::types::{Message, ServerCapabilities};
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+impl NexusApp {
+    /// Handle capabilities response (cached per connection)
+    ///
+    /// A server connected while the user is away hears about it straight away,
+    /// a server that relays encrypted private messages is sent this
+    /// connection's key (held back during maintenance, which refuses it), and
+    /// the newest page of #server history is loaded once the server is known
+    /// to provide it.
+    pub fn handle_capabilities_response(
+        &mut self,
+        connection_id: usize,
+        message_types: Vec<String>,
+        features: Vec<String>,
+    ) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.capabilities = Some(ServerCapabilities {
+                message_types: message_types.into_iter().collect(),
+                features,
+            });
+            if self.idle.is_away() && conn.supports_message_type("SetAway") {
+                let _ = conn.send(ClientMessage::SetAway { away: true });
+            }
+            if conn.supports_message_type("EncryptedUserMessage")
+                && let Some(public_key) = conn.e2ee.enable(&conn.username)
+                && (!conn.maintenance || conn.is_admin)
+            {
+                let _ = conn.send(ClientMessage::E2eeKeyAnnounce { public_key });
+            }
+        }
+        self.request_older_history(connection_id)
+    }
+}
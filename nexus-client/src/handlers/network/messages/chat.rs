@@ -7,17 +7,123 @@ use crate::NexusApp;
 use crate::i18n::{t, t_args};
 use crate::types::{ChatMessage, Message};
 use iced::Task;
-use nexus_common::protocol::ServerInfo;
+use iced::window::UserAttention;
+use nexus_common::protocol::{ClientMessage, PinnedMessage, ServerInfo};
 
 impl NexusApp {
     /// Handle incoming chat message
+    ///
+    /// Requests attention when someone else mentions us, unless the window is
+    /// focused on this connection or it's quiet hours.
     pub fn handle_chat_message(
         &mut self,
         connection_id: usize,
         username: String,
         message: String,
+        message_id: Option<u64>,
+        mentions: Option<Vec<String>>,
     ) -> Task<Message> {
-        self.add_chat_message(connection_id, ChatMessage::new(username, message))
+        let message = ChatMessage::new(username, message)
+            .with_message_id(message_id)
+            .with_mentions(mentions);
+        let mentions_us = self.connections.get(&connection_id).is_some_and(|conn| {
+            message.mentions_user(&conn.username)
+                && !message.username.eq_ignore_ascii_case(&conn.username)
+        });
+
+        let task = self.add_chat_message(connection_id, message);
+
+        if mentions_us
+            && self.config.settings.alert_on_mention
+            && !(self.active_connection == Some(connection_id) && self.main_window_focused())
+            && !self.config.settings.quiet_hours.is_active()
+        {
+            let alert = iced::window::oldest().and_then(|id| {
+                iced::window::request_user_attention(id, Some(UserAttention::Informational))
+            });
+            return Task::batch([task, alert]);
+        }
+        task
+    }
+
+    /// Handle chat message edited notification (updates the message in place)
+    pub fn handle_chat_message_edited(
+        &mut self,
+        connection_id: usize,
+        message_id: u64,
+        message: String,
+        mentions: Option<Vec<String>>,
+    ) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id)
+            && let Some(msg) = conn.chat_message_mut(message_id)
+        {
+            msg.message = message;
+            msg.mentions = mentions.unwrap_or_default();
+            msg.edited = true;
+        }
+        Task::none()
+    }
+
+    /// Handle chat message deleted notification
+    ///
+    /// The message stays in place but its text is cleared and its id dropped,
+    /// so it can no longer be pinned, edited, or deleted. A moderator's
+    /// removal is labelled as such to keep the conversation's context.
+    pub fn handle_chat_message_deleted(
+        &mut self,
+        connection_id: usize,
+        message_id: u64,
+        by_moderator: bool,
+    ) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id)
+            && let Some(msg) = conn.chat_message_mut(message_id)
+        {
+            msg.message.clear();
+            msg.message_id = None;
+            msg.deleted = true;
+            msg.removed_by_moderator = by_moderator;
+        }
+        Task::none()
+    }
+
+    /// Handle chat edit response (only failures are shown; the edit itself
+    /// arrives as a broadcast)
+    pub fn handle_chat_edit_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+        self.add_chat_message(
+            connection_id,
+            ChatMessage::error(t_args(
+                "err-failed-edit-message",
+                &[("error", &error.unwrap_or_default())],
+            )),
+        )
+    }
+
+    /// Handle chat delete response (only failures are shown; the deletion
+    /// itself arrives as a broadcast)
+    pub fn handle_chat_delete_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+        self.add_chat_message(
+            connection_id,
+            ChatMessage::error(t_args(
+                "err-failed-delete-message",
+                &[("error", &error.unwrap_or_default())],
+            )),
+        )
     }
 
     /// Handle chat topic change notification
@@ -43,6 +149,9 @@ impl NexusApp {
             Some(username.clone())
         };
 
+        // Live changes are always shown; remember them so a reconnect doesn't repeat them
+        self.remember_topic(connection_id, (!topic.is_empty()).then_some(topic.as_str()));
+
         // Build message after releasing mutable borrow
         let message = if topic.is_empty() {
             t_args("msg-topic-cleared", &[("username", &username)])
@@ -73,6 +182,168 @@ impl NexusApp {
         self.add_chat_message(connection_id, message)
     }
 
+    /// Handle pinned messages changed notification
+    pub fn handle_pinned_messages_updated(
+        &mut self,
+        connection_id: usize,
+        pinned_messages: Vec<PinnedMessage>,
+    ) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.pinned_messages = pinned_messages;
+        }
+        Task::none()
+    }
+
+    /// Handle pin message response
+    pub fn handle_pin_message_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t("msg-message-pinned"))
+        } else {
+            ChatMessage::error(t_args(
+                "err-failed-pin-message",
+                &[("error", &error.unwrap_or_default())],
+            ))
+        };
+        self.add_chat_message(connection_id, message)
+    }
+
+    /// Handle unpin message response
+    pub fn handle_unpin_message_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t("msg-message-unpinned"))
+        } else {
+            ChatMessage::error(t_args(
+                "err-failed-unpin-message",
+                &[("error", &error.unwrap_or_default())],
+            ))
+        };
+        self.add_chat_message(connection_id, message)
+    }
+
+    /// Handle chat history purge response
+    pub fn handle_chat_history_purge_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        removed: Option<u64>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t_args(
+                "msg-chat-history-purged",
+                &[("count", &removed.unwrap_or_default().to_string())],
+            ))
+        } else {
+            ChatMessage::error(t_args(
+                "err-failed-purge-history",
+                &[("error", &error.unwrap_or_default())],
+            ))
+        };
+        self.add_chat_message(connection_id, message)
+    }
+
+    /// Handle accept rules response
+    ///
+    /// The rules dialog stays open on failure so the user can try again.
+    pub fn handle_accept_rules_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            if let Some(conn) = self.connections.get_mut(&connection_id) {
+                conn.pending_rules = None;
+            }
+            return Task::none();
+        }
+        self.add_chat_message(
+            connection_id,
+            ChatMessage::error(t_args(
+                "err-failed-accept-rules",
+                &[("error", &error.unwrap_or_default())],
+            )),
+        )
+    }
+
+    /// Handle set preferences response (only failures are shown)
+    pub fn handle_set_preferences_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+        self.add_chat_message(
+            connection_id,
+            ChatMessage::error(t_args(
+                "err-failed-sync-preferences",
+                &[("error", &error.unwrap_or_default())],
+            )),
+        )
+    }
+
+    /// Handle maintenance mode turning on or off
+    pub fn handle_maintenance_changed(
+        &mut self,
+        connection_id: usize,
+        enabled: bool,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        conn.maintenance = enabled;
+
+        // The server refuses key announcements during maintenance, so announce
+        // again in case one was held back (repeating it is harmless)
+        if !enabled
+            && !conn.is_admin
+            && let Some(public_key) = conn.e2ee.public_key()
+        {
+            let _ = conn.send(ClientMessage::E2eeKeyAnnounce { public_key });
+        }
+
+        let message = if enabled {
+            t("msg-maintenance-enabled")
+        } else {
+            t("msg-maintenance-disabled")
+        };
+        self.add_chat_message(connection_id, ChatMessage::system(message))
+    }
+
+    /// Handle set maintenance response
+    ///
+    /// Success is reported by the `MaintenanceChanged` broadcast that follows.
+    pub fn handle_set_maintenance_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+        self.add_chat_message(
+            connection_id,
+            ChatMessage::error(t_args(
+                "err-failed-set-maintenance",
+                &[("error", &error.unwrap_or_default())],
+            )),
+        )
+    }
+
     /// Handle server info updated notification
     pub fn handle_server_info_updated(
         &mut self,
@@ -100,6 +371,15 @@ impl NexusApp {
         if server_info.max_connections_per_ip.is_some() {
             conn.max_connections_per_ip = server_info.max_connections_per_ip;
         }
+        if let Some(chat_slow_mode) = server_info.chat_slow_mode {
+            conn.chat_slow_mode = chat_slow_mode;
+        }
+        if let Some(max_message_length) = server_info.max_message_length {
+            conn.max_message_length = max_message_length as usize;
+        }
+        if let Some(max_topic_length) = server_info.max_topic_length {
+            conn.max_topic_length = max_topic_length as usize;
+        }
         // Update server image and cached version if provided
         if let Some(image) = server_info.image {
             conn.server_image = image.clone();
@@ -113,6 +393,26 @@ impl NexusApp {
         self.add_chat_message(connection_id, ChatMessage::system(system_message))
     }
 
+    /// Handle chat limits changed at runtime
+    ///
+    /// Replaces the limits from login so the character counters and slow mode
+    /// countdown follow the new values. The accompanying server info update
+    /// already announces the change in chat.
+    pub fn handle_limits_updated(
+        &mut self,
+        connection_id: usize,
+        max_message_length: u32,
+        max_topic_length: u32,
+        chat_slow_mode: u32,
+    ) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.max_message_length = max_message_length as usize;
+            conn.max_topic_length = max_topic_length as usize;
+            conn.chat_slow_mode = chat_slow_mode;
+        }
+        Task::none()
+    }
+
     /// Handle server info update response
     pub fn handle_server_info_update_response(
         &mut self,
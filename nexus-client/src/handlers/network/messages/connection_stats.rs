@@ -0,0 +1,97 @@
+//! Connection history handlers
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use chrono::{DateTime, Local};
+use iced::Task;
+use nexus_common::protocol::ConnectionStatsBucket;
+
+/// Length of the groups the per-minute history is summarized in
+const GROUP_SECS: i64 = 10 * 60;
+
+impl NexusApp {
+    /// Handle connection stats response
+    ///
+    /// Shows the connections open now, totals for the whole history, and a
+    /// breakdown in ten-minute groups so an hour fits in a few lines.
+    pub fn handle_connection_stats_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        current: Option<u32>,
+        buckets: Option<Vec<ConnectionStatsBucket>>,
+    ) -> Task<Message> {
+        if !success {
+            let message = ChatMessage::error(t_args(
+                "err-failed-stats",
+                &[("error", &error.unwrap_or_default())],
+            ));
+            return self.add_chat_message(connection_id, message);
+        }
+
+        let buckets = buckets.unwrap_or_default();
+        let mut lines = vec![t_args(
+            "cmd-stats-current",
+            &[("count", &current.unwrap_or_default().to_string())],
+        )];
+
+        if let Some(total) = summarize(&buckets) {
+            let minutes = total.seconds / 60;
+            lines.push(t_args(
+                "cmd-stats-total",
+                &[
+                    ("minutes", &minutes.to_string()),
+                    ("connections", &total.connections.to_string()),
+                    ("refused", &total.refused.to_string()),
+                    ("peak", &total.peak.to_string()),
+                    ("peak_per_ip", &total.peak_per_ip.to_string()),
+                ],
+            ));
+
+            for group in buckets.chunk_by(|a, b| a.start / GROUP_SECS == b.start / GROUP_SECS) {
+                let Some(summary) = summarize(group) else {
+                    continue;
+                };
+                let time = DateTime::from_timestamp(summary.start, 0)
+                    .map(|dt| dt.with_timezone(&Local).format("%H:%M").to_string())
+                    .unwrap_or_default();
+                lines.push(t_args(
+                    "cmd-stats-group",
+                    &[
+                        ("time", &time),
+                        ("connections", &summary.connections.to_string()),
+                        ("refused", &summary.refused.to_string()),
+                        ("peak", &summary.peak.to_string()),
+                    ],
+                ));
+            }
+        } else {
+            lines.push(t("cmd-stats-none"));
+        }
+
+        let mut tasks = Vec::with_capacity(lines.len());
+        for line in lines {
+            tasks.push(self.add_chat_message(connection_id, ChatMessage::info(line)));
+        }
+        Task::batch(tasks)
+    }
+}
+
+/// Combine consecutive buckets into one, or None if there are none
+fn summarize(buckets: &[ConnectionStatsBucket]) -> Option<ConnectionStatsBucket> {
+    let first = *buckets.first()?;
+    Some(
+        buckets[1..]
+            .iter()
+            .fold(first, |acc, b| ConnectionStatsBucket {
+                start: acc.start,
+                seconds: acc.seconds + b.seconds,
+                connections: acc.connections.saturating_add(b.connections),
+                refused: acc.refused.saturating_add(b.refused),
+                peak: acc.peak.max(b.peak),
+                peak_per_ip: acc.peak_per_ip.max(b.peak_per_ip),
+            }),
+    )
+}
@@ -1,8 +1,9 @@
 //! Error message handler
 
 use crate::NexusApp;
-use crate::types::{ActivePanel, ChatMessage, Message};
+use crate::types::{ActivePanel, ChatMessage, ConnectionEvent, Message};
 use iced::Task;
+use nexus_common::protocol::{ERROR_CODE_ACCOUNT_DISABLED, ERROR_CODE_KICKED};
 
 // Protocol command names (must match server exactly)
 const CMD_USER_EDIT: &str = "UserEdit";
@@ -16,7 +17,24 @@ impl NexusApp {
         connection_id: usize,
         message: String,
         command: Option<String>,
+        code: Option<String>,
     ) -> Task<Message> {
+        if code.as_deref() == Some(ERROR_CODE_ACCOUNT_DISABLED) {
+            self.mark_account_disabled(connection_id);
+        }
+
+        let event = if code.as_deref() == Some(ERROR_CODE_KICKED) {
+            ConnectionEvent::Kicked {
+                message: message.clone(),
+            }
+        } else {
+            ConnectionEvent::Error {
+                message: message.clone(),
+                code: code.clone(),
+            }
+        };
+        self.record_connection_event(connection_id, event);
+
         // Show error in edit user form if it's for user management commands
         if self.is_user_edit_error(&command, connection_id) {
             let Some(conn) = self.connections.get_mut(&connection_id) else {
@@ -41,6 +59,28 @@ impl NexusApp {
         self.add_chat_message(connection_id, ChatMessage::error(message))
     }
 
+    /// Remember that an admin disabled this account
+    ///
+    /// The server drops the connection right after, so the disconnect is
+    /// reported as non-retryable. Auto-connect is switched off for the
+    /// bookmark so the next launch doesn't immediately try again; the user
+    /// can turn it back on in the bookmark editor.
+    fn mark_account_disabled(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+        conn.account_disabled = true;
+
+        if let Some(bookmark) = conn
+            .bookmark_index
+            .and_then(|idx| self.config.bookmarks.get_mut(idx))
+            && bookmark.auto_connect
+        {
+            bookmark.auto_connect = false;
+            let _ = self.config.save();
+        }
+    }
+
     /// Check if error should be shown in user edit form
     fn is_user_edit_error(&self, command: &Option<String>, connection_id: usize) -> bool {
         let Some(cmd) = command else {
@@ -0,0 +1,97 @@
+//! Chat history paging handlers
+
+use std::collections::HashSet;
+use std::time::Instant;
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, HISTORY_PAGE_SIZE, Message};
+use crate::views::constants::PERMISSION_CHAT_RECEIVE;
+use chrono::{DateTime, Local};
+use iced::Task;
+use nexus_common::protocol::{ClientMessage, HistoryMessage};
+
+impl NexusApp {
+    /// Ask the server for the page of #server history before the oldest
+    /// message shown
+    ///
+    /// Does nothing while a request is outstanding, once the start of the
+    /// history has been reached, or if the server or the user's permissions
+    /// don't allow it.
+    pub fn request_older_history(&mut self, connection_id: usize) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let now = Instant::now();
+        if !conn.history.can_request(now)
+            || !conn.supports_message_type("HistoryRequest")
+            || !conn.has_permission(PERMISSION_CHAT_RECEIVE)
+        {
+            return Task::none();
+        }
+
+        let before_message_id = conn.chat_messages.iter().find_map(|msg| msg.message_id);
+        let request = ClientMessage::HistoryRequest {
+            before_message_id,
+            limit: HISTORY_PAGE_SIZE,
+        };
+        if conn.send(request).is_ok() {
+            conn.history.loading = true;
+            conn.history.last_request = Some(now);
+        }
+        Task::none()
+    }
+
+    /// Handle history response
+    ///
+    /// Older messages are prepended to #server chat, skipping any already
+    /// shown (such as live messages that arrived before the page).
+    pub fn handle_history_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        messages: Option<Vec<HistoryMessage>>,
+        has_more: Option<bool>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        conn.history.loading = false;
+
+        if !success {
+            let message = ChatMessage::error(t_args(
+                "err-failed-history",
+                &[("error", &error.unwrap_or_default())],
+            ));
+            return self.add_chat_message(connection_id, message);
+        }
+
+        conn.history.complete = !has_more.unwrap_or_default();
+
+        let shown: HashSet<u64> = conn
+            .chat_messages
+            .iter()
+            .filter_map(|msg| msg.message_id)
+            .collect();
+        let older: Vec<ChatMessage> = messages
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|msg| !shown.contains(&msg.message_id))
+            .map(history_chat_message)
+            .collect();
+        conn.chat_messages.splice(0..0, older);
+        Task::none()
+    }
+}
+
+/// Turn a history entry into a #server chat message
+fn history_chat_message(msg: HistoryMessage) -> ChatMessage {
+    let timestamp = DateTime::from_timestamp(msg.timestamp, 0)
+        .map_or_else(Local::now, |dt| dt.with_timezone(&Local));
+    let mut chat_message =
+        ChatMessage::with_timestamp_and_admin(msg.username, msg.message, timestamp, false);
+    chat_message.message_id = Some(msg.message_id);
+    chat_message
+}
@@ -0,0 +1,18 @@
+//! Keepalive pong handler
+
+use std::time::Instant;
+
+use crate::NexusApp;
+use crate::types::Message;
+use iced::Task;
+use nexus_common::framing::MessageId;
+
+impl NexusApp {
+    /// Handle pong (records the round-trip time for the health indicator)
+    pub fn handle_pong(&mut self, connection_id: usize, message_id: MessageId) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.keepalive.pong_received(message_id, Instant::now());
+        }
+        Task::none()
+    }
+}
@@ -2,9 +2,15 @@
 //!
 //! This module contains handlers for all server messages, organized by category.
 
+mod announcements;
 mod broadcast;
+mod capabilities;
 mod chat;
+mod connection_stats;
 mod error;
+mod history;
+mod keepalive;
+mod pages;
 mod permissions;
 mod user_admin;
 mod user_connection;
@@ -15,7 +21,7 @@ mod user_message;
 pub use user_admin::UserEditResponseData;
 
 use crate::NexusApp;
-use crate::types::Message;
+use crate::types::{InfoPage, Message};
 use iced::Task;
 use nexus_common::framing::MessageId;
 use nexus_common::protocol::ServerMessage;
@@ -47,11 +53,64 @@ impl NexusApp {
         msg: ServerMessage,
     ) -> Task<Message> {
         match msg {
+            ServerMessage::AnnouncementAddResponse { success, error } => {
+                self.handle_announcement_add_response(connection_id, success, error)
+            }
+
+            ServerMessage::AnnouncementListResponse {
+                success,
+                error,
+                announcements,
+                interval_minutes,
+            } => self.handle_announcement_list_response(
+                connection_id,
+                success,
+                error,
+                announcements.unwrap_or_default(),
+                interval_minutes.unwrap_or_default(),
+            ),
+
+            ServerMessage::AnnouncementRemoveResponse { success, error } => {
+                self.handle_announcement_remove_response(connection_id, success, error)
+            }
+
+            ServerMessage::CapabilitiesResponse {
+                message_types,
+                features,
+            } => self.handle_capabilities_response(connection_id, message_types, features),
+
+            ServerMessage::ChatHistoryPurgeResponse {
+                success,
+                error,
+                removed,
+            } => self.handle_chat_history_purge_response(connection_id, success, error, removed),
+
             ServerMessage::ChatMessage {
                 session_id: _,
                 username,
                 message,
-            } => self.handle_chat_message(connection_id, username, message),
+                message_id,
+                mentions,
+            } => self.handle_chat_message(connection_id, username, message, message_id, mentions),
+
+            ServerMessage::ChatMessageEdited {
+                message_id,
+                message,
+                mentions,
+            } => self.handle_chat_message_edited(connection_id, message_id, message, mentions),
+
+            ServerMessage::ChatMessageDeleted {
+                message_id,
+                by_moderator,
+            } => self.handle_chat_message_deleted(connection_id, message_id, by_moderator),
+
+            ServerMessage::ChatEditResponse { success, error } => {
+                self.handle_chat_edit_response(connection_id, success, error)
+            }
+
+            ServerMessage::ChatDeleteResponse { success, error } => {
+                self.handle_chat_delete_response(connection_id, success, error)
+            }
 
             ServerMessage::ChatTopicUpdated { topic, username } => {
                 self.handle_chat_topic(connection_id, topic, username)
@@ -61,10 +120,51 @@ impl NexusApp {
                 self.handle_chat_topic_update_response(connection_id, success, error)
             }
 
-            ServerMessage::Error { message, command } => {
-                self.handle_error(connection_id, message, command)
+            ServerMessage::ConnectionStatsResponse {
+                success,
+                error,
+                current,
+                buckets,
+            } => self.handle_connection_stats_response(
+                connection_id,
+                success,
+                error,
+                current,
+                buckets,
+            ),
+
+            ServerMessage::Error {
+                message,
+                command,
+                code,
+            } => self.handle_error(connection_id, message, command, code),
+
+            ServerMessage::HistoryResponse {
+                success,
+                error,
+                messages,
+                has_more,
+            } => self.handle_history_response(connection_id, success, error, messages, has_more),
+
+            ServerMessage::LimitsUpdated {
+                max_message_length,
+                max_topic_length,
+                chat_slow_mode,
+            } => self.handle_limits_updated(
+                connection_id,
+                max_message_length,
+                max_topic_length,
+                chat_slow_mode,
+            ),
+
+            ServerMessage::MaintenanceChanged { enabled } => {
+                self.handle_maintenance_changed(connection_id, enabled)
             }
 
+            ServerMessage::NewLogin {
+                address, source, ..
+            } => self.handle_new_login(connection_id, address, source),
+
             ServerMessage::PermissionsUpdated {
                 is_admin,
                 permissions,
@@ -78,6 +178,72 @@ impl NexusApp {
                 chat_info,
             ),
 
+            ServerMessage::PageDeleteResponse { success, error } => {
+                self.handle_page_delete_response(connection_id, success, error)
+            }
+
+            ServerMessage::PageGetResponse {
+                success,
+                error,
+                name,
+                content,
+            } => {
+                let page = name
+                    .zip(content)
+                    .map(|(name, content)| InfoPage { name, content });
+                self.handle_page_get_response(connection_id, success, error, page)
+            }
+
+            ServerMessage::PageListResponse {
+                success,
+                error,
+                pages,
+            } => self.handle_page_list_response(
+                connection_id,
+                success,
+                error,
+                pages.unwrap_or_default(),
+            ),
+
+            ServerMessage::PageSetResponse { success, error } => {
+                self.handle_page_set_response(connection_id, success, error)
+            }
+
+            ServerMessage::PinMessageResponse { success, error } => {
+                self.handle_pin_message_response(connection_id, success, error)
+            }
+
+            ServerMessage::PinnedMessagesUpdated { pinned_messages } => {
+                self.handle_pinned_messages_updated(connection_id, pinned_messages)
+            }
+
+            ServerMessage::AcceptRulesResponse { success, error } => {
+                self.handle_accept_rules_response(connection_id, success, error)
+            }
+            ServerMessage::AdminNoticeResponse { success, error } => {
+                self.handle_admin_notice_response(connection_id, success, error)
+            }
+            ServerMessage::SetMaintenanceResponse { success, error } => {
+                self.handle_set_maintenance_response(connection_id, success, error)
+            }
+            ServerMessage::SetPreferencesResponse { success, error } => {
+                self.handle_set_preferences_response(connection_id, success, error)
+            }
+
+            ServerMessage::UnpinMessageResponse { success, error } => {
+                self.handle_unpin_message_response(connection_id, success, error)
+            }
+
+            ServerMessage::Pong => self.handle_pong(connection_id, message_id),
+
+            ServerMessage::ServerAnnouncement { message } => {
+                self.handle_server_announcement(connection_id, message)
+            }
+
+            ServerMessage::AdminNotice { action } => {
+                self.handle_admin_notice(connection_id, action)
+            }
+
             ServerMessage::ServerBroadcast {
                 session_id: _,
                 username,
@@ -92,9 +258,11 @@ impl NexusApp {
                 self.handle_user_connected(connection_id, user)
             }
 
-            ServerMessage::UserCreateResponse { success, error } => {
-                self.handle_user_create_response(connection_id, success, error)
-            }
+            ServerMessage::UserCreateResponse {
+                success,
+                error,
+                warning,
+            } => self.handle_user_create_response(connection_id, success, error, warning),
 
             ServerMessage::UserDeleteResponse { success, error } => {
                 self.handle_user_delete_response(connection_id, success, error)
@@ -130,9 +298,50 @@ impl NexusApp {
                 user,
             } => self.handle_user_info_response(connection_id, message_id, success, error, user),
 
+            ServerMessage::RefreshUserPermissionsResponse { success, error } => {
+                self.handle_refresh_user_permissions_response(connection_id, success, error)
+            }
+            ServerMessage::TransferAdminResponse {
+                success,
+                error,
+                username,
+            } => self.handle_transfer_admin_response(connection_id, success, error, username),
+            ServerMessage::SeenResponse {
+                success,
+                error,
+                username,
+                online,
+                last_seen,
+            } => self.handle_seen_response(
+                connection_id,
+                success,
+                error,
+                username,
+                online,
+                last_seen,
+            ),
+            ServerMessage::WhoAmIResponse {
+                success,
+                error,
+                username,
+                is_admin,
+                permissions,
+                features,
+            } => self.handle_who_am_i_response(
+                connection_id,
+                success,
+                error,
+                username,
+                is_admin,
+                permissions,
+                features,
+            ),
             ServerMessage::UserKickResponse { success, error } => {
                 self.handle_user_kick_response(connection_id, success, error)
             }
+            ServerMessage::KickSessionResponse { success, error } => {
+                self.handle_kick_session_response(connection_id, success, error)
+            }
 
             ServerMessage::UserListResponse {
                 success,
@@ -145,14 +354,35 @@ impl NexusApp {
                 from_admin,
                 to_username,
                 message,
+                message_id,
             } => self.handle_user_message(
                 connection_id,
                 from_username,
                 from_admin,
                 to_username,
                 message,
+                message_id,
+            ),
+
+            ServerMessage::EncryptedUserMessage {
+                from_username,
+                from_admin,
+                to_username,
+                envelope,
+                message_id,
+            } => self.handle_encrypted_user_message(
+                connection_id,
+                from_username,
+                from_admin,
+                to_username,
+                envelope,
+                message_id,
             ),
 
+            ServerMessage::E2eeKeys { username, keys } => {
+                self.handle_e2ee_keys(connection_id, username, keys)
+            }
+
             ServerMessage::UserMessageResponse { success, error } => {
                 self.handle_user_message_response(connection_id, message_id, success, error)
             }
@@ -162,9 +392,11 @@ impl NexusApp {
                 user,
             } => self.handle_user_updated(connection_id, previous_username, user),
 
-            ServerMessage::UserUpdateResponse { success, error } => {
-                self.handle_user_update_response(connection_id, success, error)
-            }
+            ServerMessage::UserUpdateResponse {
+                success,
+                error,
+                warning,
+            } => self.handle_user_update_response(connection_id, success, error, warning),
 
             ServerMessage::ServerInfoUpdated { server_info } => {
                 self.handle_server_info_updated(connection_id, server_info)
@@ -174,6 +406,10 @@ impl NexusApp {
                 self.handle_server_info_update_response(connection_id, success, error)
             }
 
+            ServerMessage::SetProfileResponse { success, error } => {
+                self.handle_set_profile_response(connection_id, success, error)
+            }
+
             // Catch-all for any unhandled message types
             _ => Task::none(),
         }
@@ -0,0 +1,83 @@
+//! Info page response handlers
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, InfoPage, Message};
+use iced::Task;
+
+impl NexusApp {
+    /// Handle page get response (opens the page viewer)
+    pub fn handle_page_get_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        page: Option<InfoPage>,
+    ) -> Task<Message> {
+        match page {
+            Some(page) if success => {
+                if let Some(conn) = self.connections.get_mut(&connection_id) {
+                    conn.open_page = Some(page);
+                }
+                Task::none()
+            }
+            _ => self.add_chat_message(connection_id, page_error(error)),
+        }
+    }
+
+    /// Handle page list response
+    pub fn handle_page_list_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        pages: Vec<String>,
+    ) -> Task<Message> {
+        let message = if !success {
+            page_error(error)
+        } else if pages.is_empty() {
+            ChatMessage::info(t("cmd-page-none"))
+        } else {
+            ChatMessage::info(t_args("cmd-page-list", &[("pages", &pages.join(", "))]))
+        };
+        self.add_chat_message(connection_id, message)
+    }
+
+    /// Handle page set response
+    pub fn handle_page_set_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t("cmd-page-saved"))
+        } else {
+            page_error(error)
+        };
+        self.add_chat_message(connection_id, message)
+    }
+
+    /// Handle page delete response
+    pub fn handle_page_delete_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t("cmd-page-deleted"))
+        } else {
+            page_error(error)
+        };
+        self.add_chat_message(connection_id, message)
+    }
+}
+
+/// Build the chat error shown when a page request fails
+fn page_error(error: Option<String>) -> ChatMessage {
+    ChatMessage::error(t_args(
+        "err-failed-page",
+        &[("error", &error.unwrap_or_default())],
+    ))
+}
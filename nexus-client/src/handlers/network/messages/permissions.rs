@@ -1,7 +1,7 @@
 //! Permissions update handler
 
 use crate::NexusApp;
-use crate::i18n::t;
+use crate::i18n::{t, t_args};
 use crate::image::decode_data_uri_max_width;
 use crate::style::SERVER_IMAGE_MAX_CACHE_WIDTH;
 use crate::types::{ChatMessage, Message};
@@ -23,14 +23,13 @@ impl NexusApp {
             return Task::none();
         };
 
-        let had_user_list =
-            conn.is_admin || conn.permissions.iter().any(|p| p == PERMISSION_USER_LIST);
-
-        let has_user_list = is_admin || permissions.iter().any(|p| p == PERMISSION_USER_LIST);
+        let had_user_list = conn.has_permission(PERMISSION_USER_LIST);
 
         conn.is_admin = is_admin;
         conn.permissions = permissions;
 
+        let has_user_list = conn.has_permission(PERMISSION_USER_LIST);
+
         // Update only the server info fields that were provided
         // (PermissionsUpdated only sends fields that change with permissions, like max_connections_per_ip)
         if let Some(info) = server_info {
@@ -92,4 +91,47 @@ impl NexusApp {
             ChatMessage::system(t("msg-permissions-updated")),
         )
     }
+
+    /// Handle refresh user permissions response
+    pub fn handle_refresh_user_permissions_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t("msg-permissions-refreshed"))
+        } else {
+            ChatMessage::error(t_args(
+                "err-failed-refresh-permissions",
+                &[("error", &error.unwrap_or_default())],
+            ))
+        };
+        self.add_chat_message(connection_id, message)
+    }
+
+    /// Handle the response to an admin transfer
+    ///
+    /// Only reports the outcome; the `PermissionsUpdated` that follows a
+    /// successful transfer updates this session's admin status.
+    pub fn handle_transfer_admin_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        username: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t_args(
+                "msg-admin-transferred",
+                &[("username", &username.unwrap_or_default())],
+            ))
+        } else {
+            ChatMessage::error(t_args(
+                "err-failed-transfer-admin",
+                &[("error", &error.unwrap_or_default())],
+            ))
+        };
+        self.add_chat_message(connection_id, message)
+    }
 }
@@ -22,6 +22,7 @@ impl NexusApp {
         connection_id: usize,
         success: bool,
         error: Option<String>,
+        warning: Option<String>,
     ) -> Task<Message> {
         if success {
             // Close add user panel on success
@@ -33,8 +34,16 @@ impl NexusApp {
             }
 
             // add_chat_message handles scrolling when this is the active connection
-            return self
-                .add_chat_message(connection_id, ChatMessage::system(t("msg-user-created")));
+            let task =
+                self.add_chat_message(connection_id, ChatMessage::system(t("msg-user-created")));
+            // The server notes permissions that won't work without others
+            return match warning {
+                Some(warning) => Task::batch([
+                    task,
+                    self.add_chat_message(connection_id, ChatMessage::info(warning)),
+                ]),
+                None => task,
+            };
         }
 
         // On error, keep panel open and show error in form
@@ -106,6 +115,7 @@ impl NexusApp {
         connection_id: usize,
         success: bool,
         error: Option<String>,
+        warning: Option<String>,
     ) -> Task<Message> {
         if success {
             // Close edit panel on success
@@ -117,8 +127,16 @@ impl NexusApp {
             }
 
             // add_chat_message handles scrolling when this is the active connection
-            return self
-                .add_chat_message(connection_id, ChatMessage::system(t("msg-user-updated")));
+            let task =
+                self.add_chat_message(connection_id, ChatMessage::system(t("msg-user-updated")));
+            // The server notes permissions that won't work without others
+            return match warning {
+                Some(warning) => Task::batch([
+                    task,
+                    self.add_chat_message(connection_id, ChatMessage::info(warning)),
+                ]),
+                None => task,
+            };
         }
 
         // On error, keep panel open and show error in form
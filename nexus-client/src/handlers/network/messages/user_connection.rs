@@ -6,10 +6,14 @@ use crate::handlers::network::helpers::sort_user_list;
 use crate::i18n::t_args;
 use crate::types::{ChatMessage, Message, UserInfo as ClientUserInfo};
 use iced::Task;
+use iced::window::UserAttention;
 use nexus_common::protocol::UserInfo as ProtocolUserInfo;
 
 impl NexusApp {
     /// Handle user connected notification
+    ///
+    /// Users on the bookmark's watch list get their own notice and an
+    /// attention request, shown even when join/leave messages are hidden.
     pub fn handle_user_connected(
         &mut self,
         connection_id: usize,
@@ -35,6 +39,9 @@ impl NexusApp {
                 }
             }
 
+            // The new session isn't idle, so the user is back
+            existing_user.away = user.away;
+
             // Update avatar if it changed (latest login wins, including clearing avatar)
             if existing_user.avatar_hash != new_avatar_hash {
                 existing_user.avatar_hash = new_avatar_hash;
@@ -53,11 +60,19 @@ impl NexusApp {
             conn.online_users.push(ClientUserInfo {
                 username: user.username.clone(),
                 is_admin: user.is_admin,
+                is_moderator: user.is_moderator,
+                away: user.away,
+                is_guest: user.is_guest,
+                permissions: user.permissions.clone().unwrap_or_default(),
                 session_ids: user.session_ids.clone(),
                 avatar_hash: new_avatar_hash,
             });
             sort_user_list(&mut conn.online_users);
 
+            if let Some(count) = conn.user_count.as_mut() {
+                *count += 1;
+            }
+
             // Pre-populate avatar cache for new user
             get_or_create_avatar(
                 &mut conn.avatar_cache,
@@ -68,8 +83,19 @@ impl NexusApp {
             true
         };
 
+        if is_new_user && self.is_watched(connection_id, &user.username) {
+            let task = self.add_chat_message(
+                connection_id,
+                ChatMessage::info(t_args(
+                    "msg-watched-user-online",
+                    &[("username", &user.username)],
+                )),
+            );
+            return Task::batch([task, self.watched_user_alert(connection_id)]);
+        }
+
         // Only announce if this is their first session (new user) and notifications are enabled
-        if is_new_user && self.config.settings.show_connection_notifications {
+        if is_new_user && self.join_leave_visible(connection_id) {
             self.add_chat_message(
                 connection_id,
                 ChatMessage::system(t_args(
@@ -107,6 +133,10 @@ impl NexusApp {
                 conn.online_users.retain(|u| u.username != username);
                 is_last_session = true;
 
+                if let Some(count) = conn.user_count.as_mut() {
+                    *count = count.saturating_sub(1);
+                }
+
                 // Clear expanded_user if the disconnected user was expanded
                 if conn.expanded_user.as_ref() == Some(&username) {
                     conn.expanded_user = None;
@@ -118,7 +148,7 @@ impl NexusApp {
         }
 
         // Only announce if this was their last session (fully offline) and notifications are enabled
-        if is_last_session && self.config.settings.show_connection_notifications {
+        if is_last_session && self.join_leave_visible(connection_id) {
             self.add_chat_message(
                 connection_id,
                 ChatMessage::system(t_args("msg-user-disconnected", &[("username", &username)])),
@@ -127,4 +157,59 @@ impl NexusApp {
             Task::none()
         }
     }
+
+    /// Handle a notice that our account logged in from another session
+    ///
+    /// Shown regardless of the join/leave setting so an unexpected login
+    /// doesn't go unnoticed.
+    pub fn handle_new_login(
+        &mut self,
+        connection_id: usize,
+        address: String,
+        source: String,
+    ) -> Task<Message> {
+        self.add_chat_message(
+            connection_id,
+            ChatMessage::system(t_args(
+                "msg-new-login",
+                &[("address", &address), ("source", &source)],
+            )),
+        )
+    }
+
+    /// Whether `username` is on the watch list of the connection's bookmark
+    pub fn is_watched(&self, connection_id: usize, username: &str) -> bool {
+        self.connections
+            .get(&connection_id)
+            .and_then(|conn| conn.bookmark_index)
+            .and_then(|idx| self.config.get_bookmark(idx))
+            .is_some_and(|bookmark| bookmark.is_watching(username))
+    }
+
+    /// Request attention for a watched user coming online
+    ///
+    /// Skipped when the window is focused on this connection, and during
+    /// quiet hours unless watched users are set to ignore them.
+    pub fn watched_user_alert(&self, connection_id: usize) -> Task<Message> {
+        let settings = &self.config.settings;
+        if (self.active_connection == Some(connection_id) && self.main_window_focused())
+            || (settings.quiet_hours.is_active() && !settings.watched_users_ignore_quiet_hours)
+        {
+            return Task::none();
+        }
+        iced::window::oldest().and_then(|id| {
+            iced::window::request_user_attention(id, Some(UserAttention::Informational))
+        })
+    }
+
+    /// Whether join/leave messages should be shown in chat for a connection
+    ///
+    /// Follows the connection's override if it has one, otherwise the global
+    /// notification setting.
+    fn join_leave_visible(&self, connection_id: usize) -> bool {
+        self.connections
+            .get(&connection_id)
+            .and_then(|conn| conn.connection_notifications)
+            .unwrap_or(self.config.settings.show_connection_notifications)
+    }
 }
@@ -3,7 +3,9 @@
 use crate::NexusApp;
 use crate::avatar::{compute_avatar_hash, get_or_create_avatar};
 use crate::handlers::network::constants::DATETIME_FORMAT;
-use crate::handlers::network::helpers::{format_duration, sort_user_list};
+use crate::handlers::network::helpers::{
+    format_duration, format_elapsed, format_session_addresses, sort_user_list,
+};
 use crate::i18n::{t, t_args};
 use crate::types::{
     ActivePanel, ChatMessage, ChatTab, Message, ResponseRouting, UserInfo as ClientUserInfo,
@@ -103,8 +105,14 @@ impl NexusApp {
         // Username header
         lines.push(format!("[{}]", user.username));
 
-        // Role (only visible to admins)
-        if let Some(is_admin) = user.is_admin {
+        // Role (only visible to admins, or for guests)
+        if user.is_guest {
+            lines.push(format!(
+                "{INFO_INDENT}{} {}",
+                t("user-info-role").to_lowercase(),
+                t("user-info-role-guest")
+            ));
+        } else if let Some(is_admin) = user.is_admin {
             let role_value = if is_admin {
                 t("user-info-role-admin")
             } else {
@@ -117,6 +125,22 @@ impl NexusApp {
             ));
         }
 
+        // Profile (only shown when set)
+        if let Some(pronouns) = &user.pronouns {
+            lines.push(format!(
+                "{INFO_INDENT}{} {}",
+                t("user-info-pronouns").to_lowercase(),
+                pronouns
+            ));
+        }
+        if let Some(bio) = &user.bio {
+            lines.push(format!(
+                "{INFO_INDENT}{} {}",
+                t("user-info-bio").to_lowercase(),
+                bio
+            ));
+        }
+
         // Sessions
         let session_count = user.session_ids.len();
         let connected_value = if session_count == 1 {
@@ -158,10 +182,11 @@ impl NexusApp {
             user.locale
         ));
 
-        // IP Addresses (only visible to admins)
-        if let Some(addresses) = user.addresses
+        // IP Addresses with source tags (only visible to admins)
+        if let Some(addresses) = &user.addresses
             && !addresses.is_empty()
         {
+            let addresses = format_session_addresses(addresses, user.sources.as_deref());
             if addresses.len() == 1 {
                 lines.push(format!(
                     "{INFO_INDENT}{} {}",
@@ -179,16 +204,135 @@ impl NexusApp {
             }
         }
 
-        // Account created (last field)
+        // Account created (last field; guests have no account)
+        if !user.is_guest {
+            lines.push(format!(
+                "{INFO_INDENT}{} {}",
+                t("user-info-created").to_lowercase(),
+                created
+            ));
+        }
+
+        lines.push(format!("{INFO_INDENT}{}", t("user-info-end")));
+
+        // Add each line as a separate chat message with shared timestamp
+        let timestamp = Local::now();
+        let mut task = Task::none();
+        for line in lines {
+            task = self.add_chat_message(
+                connection_id,
+                ChatMessage::info_with_timestamp(line, timestamp),
+            );
+        }
+        // Last add_chat_message will handle auto-scroll
+        task
+    }
+
+    /// Handle set profile response
+    ///
+    /// On success, closes the edit form and shows the new values in the open
+    /// user info panel.
+    pub fn handle_set_profile_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        if success {
+            if let Some(edit_state) = conn.profile_edit.take()
+                && let Some(Ok(user)) = &mut conn.user_info_data
+            {
+                let bio = edit_state.bio.trim();
+                let pronouns = edit_state.pronouns.trim();
+                user.bio = (!bio.is_empty()).then(|| bio.to_string());
+                user.pronouns = (!pronouns.is_empty()).then(|| pronouns.to_string());
+            }
+            return Task::none();
+        }
+
+        // Show error in the edit form if still open, otherwise show in chat
+        let error_msg = t_args(
+            "err-failed-update-profile",
+            &[("error", &error.unwrap_or_default())],
+        );
+        if let Some(edit_state) = &mut conn.profile_edit {
+            edit_state.error = Some(error_msg);
+            Task::none()
+        } else {
+            self.add_chat_message(connection_id, ChatMessage::error(error_msg))
+        }
+    }
+
+    /// Handle WhoAmI response (from `/whoami` command)
+    ///
+    /// Shows the same WHOIS-style layout as `/info`, listing the effective
+    /// permissions instead of session details.
+    #[allow(clippy::too_many_arguments)]
+    pub fn handle_who_am_i_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        username: Option<String>,
+        is_admin: Option<bool>,
+        permissions: Option<Vec<String>>,
+        features: Option<Vec<String>>,
+    ) -> Task<Message> {
+        if !success {
+            return self
+                .add_chat_message(connection_id, ChatMessage::error(error.unwrap_or_default()));
+        }
+
+        let Some(username) = username else {
+            return Task::none();
+        };
+
+        let mut lines = vec![format!("[{}]", username)];
+
+        let role_value = if is_admin.unwrap_or_default() {
+            t("user-info-role-admin")
+        } else {
+            t("user-info-role-user")
+        };
         lines.push(format!(
             "{INFO_INDENT}{} {}",
-            t("user-info-created").to_lowercase(),
-            created
+            t("user-info-role").to_lowercase(),
+            role_value
+        ));
+
+        let permissions = permissions.unwrap_or_default();
+        let permissions_value = if permissions.is_empty() {
+            t("user-info-permissions-none").to_lowercase()
+        } else {
+            permissions.join(", ")
+        };
+        lines.push(format!(
+            "{INFO_INDENT}{} {}",
+            t("user-info-permissions").to_lowercase(),
+            permissions_value
+        ));
+
+        let features = features.unwrap_or_default();
+        let features_value = if features.is_empty() {
+            t("user-info-features-none").to_lowercase()
+        } else {
+            t_args(
+                "user-info-features-value",
+                &[("features", &features.join(", "))],
+            )
+        };
+        lines.push(format!(
+            "{INFO_INDENT}{} {}",
+            t("user-info-features").to_lowercase(),
+            features_value
         ));
 
         lines.push(format!("{INFO_INDENT}{}", t("user-info-end")));
 
-        // Add each line as a separate chat message with shared timestamp
         let timestamp = Local::now();
         let mut task = Task::none();
         for line in lines {
@@ -197,10 +341,52 @@ impl NexusApp {
                 ChatMessage::info_with_timestamp(line, timestamp),
             );
         }
-        // Last add_chat_message will handle auto-scroll
         task
     }
 
+    /// Handle Seen response (from `/seen` command)
+    pub fn handle_seen_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        username: Option<String>,
+        online: Option<bool>,
+        last_seen: Option<i64>,
+    ) -> Task<Message> {
+        if !success {
+            return self
+                .add_chat_message(connection_id, ChatMessage::error(error.unwrap_or_default()));
+        }
+
+        let Some(username) = username else {
+            return Task::none();
+        };
+
+        let message = if online.unwrap_or_default() {
+            t_args("msg-seen-online", &[("username", &username)])
+        } else if let Some(seen) = last_seen.and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+        {
+            // Clock skew between client and server can put the time slightly ahead
+            let elapsed = chrono::Utc::now()
+                .signed_duration_since(seen)
+                .num_seconds()
+                .max(0) as u64;
+            t_args(
+                "msg-seen-ago",
+                &[
+                    ("username", &username),
+                    ("duration", &format_elapsed(elapsed)),
+                    ("time", &seen.format(DATETIME_FORMAT).to_string()),
+                ],
+            )
+        } else {
+            t_args("msg-seen-never", &[("username", &username)])
+        };
+
+        self.add_chat_message(connection_id, ChatMessage::info(message))
+    }
+
     /// Handle user list response
     pub fn handle_user_list_response(
         &mut self,
@@ -209,6 +395,7 @@ impl NexusApp {
         success: bool,
         users: Option<Vec<ProtocolUserInfo>>,
     ) -> Task<Message> {
+        let max_message_tabs = self.config.settings.max_message_tabs;
         let Some(conn) = self.connections.get_mut(&connection_id) else {
             return Task::none();
         };
@@ -241,6 +428,10 @@ impl NexusApp {
                 ClientUserInfo {
                     username: u.username,
                     is_admin: u.is_admin,
+                    is_moderator: u.is_moderator,
+                    away: u.away,
+                    is_guest: u.is_guest,
+                    permissions: u.permissions.unwrap_or_default(),
                     session_ids: u.session_ids,
                     avatar_hash,
                 }
@@ -249,6 +440,7 @@ impl NexusApp {
 
         conn.online_users = user_list;
         sort_user_list(&mut conn.online_users);
+        conn.user_count = Some(conn.online_users.len() as u32);
 
         // Clear expanded_user if the user is no longer in the list
         if let Some(expanded) = &conn.expanded_user
@@ -256,6 +448,40 @@ impl NexusApp {
         {
             conn.expanded_user = None;
         }
+
+        // Restore the tab from before a dropped connection if its user is still online,
+        // otherwise stay on the server tab
+        if let Some(username) =
+            restorable_message_tab(conn.pending_tab_restore.take(), &conn.online_users)
+        {
+            conn.open_user_message_tab(&username, max_message_tabs);
+            conn.set_active_chat_tab(ChatTab::UserMessage(username));
+        }
+
+        // Watched users who were already online when we connected
+        if std::mem::take(&mut conn.pending_watch_check)
+            && let Some(bookmark) = conn
+                .bookmark_index
+                .and_then(|idx| self.config.bookmarks.get(idx))
+        {
+            let watched: Vec<&str> = conn
+                .online_users
+                .iter()
+                .map(|u| u.username.as_str())
+                .filter(|username| bookmark.is_watching(username))
+                .collect();
+            if !watched.is_empty() {
+                let usernames = watched.join(", ");
+                let task = self.add_chat_message(
+                    connection_id,
+                    ChatMessage::info(t_args(
+                        "msg-watched-users-online",
+                        &[("usernames", &usernames)],
+                    )),
+                );
+                return Task::batch([task, self.watched_user_alert(connection_id)]);
+            }
+        }
         Task::none()
     }
 
@@ -319,6 +545,10 @@ impl NexusApp {
 
             existing_user.username = new_username.clone();
             existing_user.is_admin = user.is_admin;
+            existing_user.is_moderator = user.is_moderator;
+            existing_user.away = user.away;
+            existing_user.is_guest = user.is_guest;
+            existing_user.permissions = user.permissions.unwrap_or_default();
             existing_user.session_ids = user.session_ids;
             existing_user.avatar_hash = new_avatar_hash;
 
@@ -352,6 +582,7 @@ impl NexusApp {
             if let Some(messages) = conn.user_messages.remove(&previous_username) {
                 conn.user_messages.insert(new_username.clone(), messages);
             }
+            conn.message_tabs.rename(&previous_username, &new_username);
 
             // Rename the scroll_states entry
             let old_tab = ChatTab::UserMessage(previous_username.clone());
@@ -364,12 +595,25 @@ impl NexusApp {
             if conn.unread_tabs.remove(&old_tab) {
                 conn.unread_tabs.insert(new_tab.clone());
             }
+            conn.drafts.rename(&old_tab, new_tab.clone());
+
+            // Update the popout window showing this user's tab
+            if let Some(window_id) = conn.popout_window(&previous_username)
+                && let Some(popout) = conn.popouts.get_mut(&window_id)
+            {
+                popout.username = new_username.clone();
+            }
 
             // Update active_chat_tab if it's for this user
             if conn.active_chat_tab == old_tab {
                 conn.active_chat_tab = new_tab;
             }
 
+            // Keep replying to the last message sender under their new name
+            if conn.last_message_from.as_ref() == Some(&previous_username) {
+                conn.last_message_from = Some(new_username.clone());
+            }
+
             // Update expanded_user if it was set to the old username
             if conn.expanded_user.as_ref() == Some(&previous_username) {
                 conn.expanded_user = Some(new_username);
@@ -379,3 +623,77 @@ impl NexusApp {
         Task::none()
     }
 }
+
+/// Username of the message tab to restore after reconnecting, if any
+///
+/// Only a message tab whose user is in the initial user list comes back. No
+/// saved tab, a saved server tab, or a user who is gone all leave the
+/// connection on the server tab.
+fn restorable_message_tab(
+    saved: Option<ChatTab>,
+    online_users: &[ClientUserInfo],
+) -> Option<String> {
+    match saved {
+        Some(ChatTab::UserMessage(username))
+            if online_users.iter().any(|u| u.username == username) =>
+        {
+            Some(username)
+        }
+        _ => None,
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn online(username: &str) -> ClientUserInfo {
+        ClientUserInfo {
+            username: username.to_string(),
+            is_admin: false,
+            is_moderator: false,
+            away: false,
+            is_guest: false,
+            permissions: Vec::new(),
+            session_ids: vec![1],
+            avatar_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_restores_tab_for_online_user() {
+        let users = vec![online("alice"), online("bob")];
+        let saved = Some(ChatTab::UserMessage("bob".to_string()));
+        assert_eq!(
+            restorable_message_tab(saved, &users),
+            Some("bob".to_string())
+        );
+    }
+
+    #[test]
+    fn test_nothing_saved_stays_on_server_tab() {
+        let users = vec![online("alice")];
+        assert_eq!(restorable_message_tab(None, &users), None);
+        assert_eq!(restorable_message_tab(Some(ChatTab::Server), &users), None);
+    }
+
+    #[test]
+    fn test_stale_saved_tab_stays_on_server_tab() {
+        // The user went offline while we were disconnected
+        let users = vec![online("alice")];
+        let saved = Some(ChatTab::UserMessage("bob".to_string()));
+        assert_eq!(restorable_message_tab(saved, &users), None);
+
+        // A saved tab that names no one matches nobody
+        let saved = Some(ChatTab::UserMessage(String::new()));
+        assert_eq!(restorable_message_tab(saved, &users), None);
+
+        // No user list at all
+        let saved = Some(ChatTab::UserMessage("alice".to_string()));
+        assert_eq!(restorable_message_tab(saved, &[]), None);
+    }
+}
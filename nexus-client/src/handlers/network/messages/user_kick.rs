@@ -1,8 +1,8 @@
-//! User kick response handler
+//! User kick and session kick response handlers
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
-use crate::types::{ChatMessage, Message};
+use crate::types::{ActivePanel, ChatMessage, Message};
 use iced::Task;
 
 impl NexusApp {
@@ -23,4 +23,43 @@ impl NexusApp {
         };
         self.add_chat_message(connection_id, message)
     }
+
+    /// Handle kick session response
+    ///
+    /// On success the open user info panel is refreshed so the disconnected
+    /// session drops out of its list.
+    pub fn handle_kick_session_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if !success {
+            let message = ChatMessage::error(t_args(
+                "err-failed-send-message",
+                &[("error", &error.unwrap_or_default())],
+            ));
+            return self.add_chat_message(connection_id, message);
+        }
+
+        let task = self.add_chat_message(
+            connection_id,
+            ChatMessage::info(t("msg-session-kicked-success")),
+        );
+
+        let open_user_info = self
+            .connections
+            .get(&connection_id)
+            .filter(|conn| conn.active_panel == ActivePanel::UserInfo)
+            .and_then(|conn| match &conn.user_info_data {
+                Some(Ok(user)) => Some(user.username.clone()),
+                _ => None,
+            });
+        match open_user_info {
+            Some(username) if self.active_connection == Some(connection_id) => {
+                Task::batch([task, self.handle_user_info_icon_clicked(username)])
+            }
+            _ => task,
+        }
+    }
 }
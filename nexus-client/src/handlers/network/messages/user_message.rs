@@ -1,11 +1,15 @@
 //! User message handlers
 
 use crate::NexusApp;
-use crate::i18n::t_args;
-use crate::types::{ChatMessage, ChatTab, Message, ResponseRouting};
+use crate::i18n::{t, t_args};
+use crate::types::{
+    ActivePanel, ChatMessage, ChatTab, FOCUS_MESSAGE_IDLE, Message, ResponseRouting,
+};
 use chrono::Local;
 use iced::Task;
 use nexus_common::framing::MessageId;
+use nexus_common::protocol::{E2eeEnvelope, E2eeSessionKey};
+use std::time::Instant;
 
 impl NexusApp {
     /// Handle incoming private message
@@ -16,34 +20,124 @@ impl NexusApp {
         from_admin: bool,
         to_username: String,
         message: String,
+        message_id: Option<u64>,
+    ) -> Task<Message> {
+        let chat_msg =
+            ChatMessage::with_timestamp_and_admin(from_username, message, Local::now(), from_admin)
+                .with_message_id(message_id);
+        self.show_user_message(connection_id, to_username, chat_msg)
+    }
+
+    /// Handle incoming end-to-end encrypted private message
+    ///
+    /// The envelope was sealed for this connection's key. One that can't be
+    /// opened (e.g. sealed for a key from before a reconnect) is shown as
+    /// unreadable rather than dropped.
+    pub fn handle_encrypted_user_message(
+        &mut self,
+        connection_id: usize,
+        from_username: String,
+        from_admin: bool,
+        to_username: String,
+        envelope: E2eeEnvelope,
+        message_id: Option<u64>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return Task::none();
+        };
+        let message = conn
+            .e2ee
+            .open(&envelope)
+            .unwrap_or_else(|| t("chat-message-unreadable"));
+        let chat_msg =
+            ChatMessage::with_timestamp_and_admin(from_username, message, Local::now(), from_admin)
+                .with_message_id(message_id)
+                .with_encrypted(true);
+        self.show_user_message(connection_id, to_username, chat_msg)
+    }
+
+    /// Handle a user's end-to-end encryption keys
+    ///
+    /// Private messages waiting for them are sent now.
+    pub fn handle_e2ee_keys(
+        &mut self,
+        connection_id: usize,
+        username: String,
+        keys: Vec<E2eeSessionKey>,
     ) -> Task<Message> {
         let Some(conn) = self.connections.get_mut(&connection_id) else {
             return Task::none();
         };
+        let tasks: Vec<_> = conn
+            .receive_e2ee_keys(&username, keys)
+            .into_iter()
+            .map(|e| {
+                let error_msg = t_args("err-failed-send-message", &[("error", &e)]);
+                self.add_chat_message(connection_id, ChatMessage::error(error_msg))
+            })
+            .collect();
+        Task::batch(tasks)
+    }
+
+    /// Add a private message to its tab, opening the tab if needed
+    fn show_user_message(
+        &mut self,
+        connection_id: usize,
+        to_username: String,
+        chat_msg: ChatMessage,
+    ) -> Task<Message> {
+        let max_message_tabs = self.config.settings.max_message_tabs;
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
 
         // Determine which user we're chatting with (the other person)
-        let other_user = if from_username == conn.username {
-            to_username
+        let incoming = chat_msg.username != conn.username;
+        let other_user = if incoming {
+            chat_msg.username.clone()
         } else {
-            from_username.clone()
+            to_username
         };
+        if incoming {
+            conn.last_message_from = Some(other_user.clone());
+        }
 
-        // Add message to PM tab history (creates entry if doesn't exist)
-        let chat_msg =
-            ChatMessage::with_timestamp_and_admin(from_username, message, Local::now(), from_admin);
-        conn.user_messages
-            .entry(other_user.clone())
-            .or_default()
+        // Add message to PM tab history (opens or reopens the tab if needed)
+        conn.open_user_message_tab(&other_user, max_message_tabs)
             .push(chat_msg);
 
+        // A popped-out tab is being viewed while its window has focus
+        if let Some(window_id) = conn.popout_window(&other_user) {
+            if self.focused_window != Some(window_id) {
+                conn.unread_tabs.insert(ChatTab::UserMessage(other_user));
+            }
+            return Task::none();
+        }
+
         // Mark as unread if not currently viewing this tab
         let pm_tab = ChatTab::UserMessage(other_user);
-        if conn.active_chat_tab != pm_tab {
-            conn.unread_tabs.insert(pm_tab);
-            Task::none()
-        } else {
-            self.scroll_chat_if_visible(true)
+        if conn.active_chat_tab == pm_tab {
+            return self.scroll_chat_if_visible(true);
         }
+        conn.unread_tabs.insert(pm_tab.clone());
+
+        if incoming && self.should_focus_message_tab(connection_id) {
+            return Task::done(Message::SwitchChatTab(pm_tab));
+        }
+        Task::none()
+    }
+
+    /// Whether an incoming private message should switch to its tab
+    ///
+    /// Only when the setting is on, the message is for the connection being
+    /// viewed with no panel open, quiet hours are off, and the user hasn't
+    /// touched the client for a while, so focus never moves mid-action.
+    fn should_focus_message_tab(&self, connection_id: usize) -> bool {
+        self.config.settings.focus_new_messages
+            && self.active_connection == Some(connection_id)
+            && self.active_panel() == ActivePanel::None
+            && !self.config.settings.quiet_hours.is_active()
+            && self.idle.idle_for(Instant::now()) >= FOCUS_MESSAGE_IDLE
     }
 
     /// Handle user message response (success/failure of sending a message)
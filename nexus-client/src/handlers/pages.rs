@@ -0,0 +1,15 @@
+//! Info page viewer handlers
+
+use crate::NexusApp;
+use crate::types::Message;
+use iced::Task;
+
+impl NexusApp {
+    /// Close the page viewer for a connection
+    pub fn handle_close_page(&mut self, connection_id: usize) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.open_page = None;
+        }
+        Task::none()
+    }
+}
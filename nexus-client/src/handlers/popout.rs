@@ -0,0 +1,256 @@
+//! Window focus tracking and private message tabs popped out into their own windows
+
+use std::time::Instant;
+
+use chrono::Local;
+use iced::widget::text_editor;
+use iced::{Size, Task, window};
+
+use super::connection::message_error_text;
+use crate::NexusApp;
+use crate::commands::{self, ParseResult};
+use crate::i18n::{t, t_args};
+use crate::style::{
+    POPOUT_WINDOW_HEIGHT, POPOUT_WINDOW_HEIGHT_MIN, POPOUT_WINDOW_WIDTH, POPOUT_WINDOW_WIDTH_MIN,
+};
+use crate::types::{ChatMessage, ChatTab, Message, MessageDraft, PopoutWindow, ServerConnection};
+use crate::views::constants::PERMISSION_USER_MESSAGE;
+use nexus_common::validators;
+
+impl NexusApp {
+    // ==================== Lookup Helpers ====================
+
+    /// Connection owning the popout window `window_id`
+    pub fn popout_connection(&self, window_id: window::Id) -> Option<usize> {
+        self.connections
+            .iter()
+            .find(|(_, conn)| conn.popouts.contains_key(&window_id))
+            .map(|(id, _)| *id)
+    }
+
+    /// Whether the main window currently has input focus
+    pub fn main_window_focused(&self) -> bool {
+        self.focused_window.is_some() && self.focused_window == self.main_window
+    }
+
+    /// Whether a popout window currently has input focus
+    ///
+    /// Keyboard shortcuts target the main window, so they are ignored meanwhile.
+    pub fn popout_has_focus(&self) -> bool {
+        self.focused_window
+            .is_some_and(|id| self.popout_connection(id).is_some())
+    }
+
+    /// Title for the popout window `window_id`
+    ///
+    /// Marked while the tab has messages that arrived without the window focused.
+    pub fn popout_title(&self, window_id: window::Id) -> Option<String> {
+        let conn = self.connections.get(&self.popout_connection(window_id)?)?;
+        let popout = conn.popouts.get(&window_id)?;
+        let tab = ChatTab::UserMessage(popout.username.clone());
+        let key = if conn.unread_tabs.contains(&tab) {
+            "popout-title-unread"
+        } else {
+            "popout-title"
+        };
+        Some(t_args(
+            key,
+            &[
+                ("username", &popout.username),
+                ("server", &conn.display_name),
+            ],
+        ))
+    }
+
+    // ==================== Handlers ====================
+
+    /// Pop a user message tab on the active connection out into its own window
+    ///
+    /// A tab that is already popped out has its window focused instead.
+    pub fn handle_pop_out_message_tab(&mut self, username: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        if let Some(window_id) = conn.popout_window(&username) {
+            return window::gain_focus(window_id);
+        }
+        if !conn.user_messages.contains_key(&username) {
+            return Task::none();
+        }
+
+        let tab = ChatTab::UserMessage(username.clone());
+        conn.unread_tabs.remove(&tab);
+
+        let (window_id, open) = window::open(window::Settings {
+            size: Size::new(POPOUT_WINDOW_WIDTH, POPOUT_WINDOW_HEIGHT),
+            min_size: Some(Size::new(POPOUT_WINDOW_WIDTH_MIN, POPOUT_WINDOW_HEIGHT_MIN)),
+            exit_on_close_request: false,
+            ..Default::default()
+        });
+
+        // The main window falls back to #server while the tab is away, and
+        // the tab's unsent input moves to the new window
+        let was_active = conn.active_chat_tab == tab;
+        if was_active {
+            conn.set_active_chat_tab(ChatTab::Server);
+        }
+        let mut popout = PopoutWindow::new(username);
+        popout.message_input = text_editor::Content::with_text(&conn.drafts.take(&tab).text);
+        conn.popouts.insert(window_id, popout);
+
+        if was_active {
+            return Task::batch([open.discard(), self.handle_show_chat_view()]);
+        }
+        open.discard()
+    }
+
+    /// Close a popout window, returning its tab to the main window's tab bar
+    pub fn handle_popout_closed(&mut self, window_id: window::Id) -> Task<Message> {
+        if let Some(conn_id) = self.popout_connection(window_id)
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(popout) = conn.popouts.remove(&window_id)
+        {
+            // Unsent input waits in the tab it returns to
+            let draft = MessageDraft {
+                text: popout.message_input.text(),
+                large_paste: false,
+            };
+            conn.drafts
+                .store(ChatTab::UserMessage(popout.username), draft);
+        }
+        if self.focused_window == Some(window_id) {
+            self.focused_window = None;
+        }
+        window::close(window_id)
+    }
+
+    /// Handle an edit to a popout window's message input
+    pub fn handle_popout_input_action(
+        &mut self,
+        window_id: window::Id,
+        action: text_editor::Action,
+    ) -> Task<Message> {
+        if let Some(conn_id) = self.popout_connection(window_id)
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(popout) = conn.popouts.get_mut(&window_id)
+        {
+            popout.message_input.perform(action);
+        }
+        Task::none()
+    }
+
+    /// Send a popout window's input as a private message
+    ///
+    /// Commands act on the main window's active tab, so they are only
+    /// accepted there; `//text` still sends `/text`.
+    pub fn handle_popout_send_pressed(&mut self, window_id: window::Id) -> Task<Message> {
+        let Some(conn_id) = self.popout_connection(window_id) else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get(&conn_id) else {
+            return Task::none();
+        };
+        let Some(popout) = conn.popouts.get(&window_id) else {
+            return Task::none();
+        };
+        let username = popout.username.clone();
+
+        // Pasted text may use CRLF line endings; the server accepts \n only
+        let input = popout.message_input.text().replace("\r\n", "\n");
+        let message = match commands::parse_input(&input) {
+            ParseResult::Empty => return Task::none(),
+            ParseResult::Command(_) | ParseResult::MultilineCommand => {
+                return self.add_popout_error(conn_id, &username, t("err-popout-command"));
+            }
+            ParseResult::Message(message) => message,
+        };
+
+        if conn.permission_disabled(PERMISSION_USER_MESSAGE) {
+            return self.add_popout_error(conn_id, &username, t("err-private-messages-disabled"));
+        }
+        if !conn.has_permission(PERMISSION_USER_MESSAGE) {
+            return self.add_popout_error(conn_id, &username, t("err-no-chat-permission"));
+        }
+        if let Err(e) = validators::validate_message(&message, conn.max_message_length) {
+            let error_msg = message_error_text(e, &message, conn.max_message_length);
+            return self.add_popout_error(conn_id, &username, error_msg);
+        }
+
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        if let Err(e) = conn.send_private_message(&username, message, None) {
+            let error_msg = format!("{}: {}", t("err-send-failed"), e);
+            return self.add_popout_error(conn_id, &username, error_msg);
+        }
+
+        if let Some(popout) = conn.popouts.get_mut(&window_id) {
+            popout.message_input = text_editor::Content::new();
+        }
+        Task::none()
+    }
+
+    /// Track which window has input focus
+    ///
+    /// Focusing a window counts as activity (ending an automatic away
+    /// period), and focusing a popout marks its tab as read, like switching
+    /// to it inline.
+    pub fn handle_window_focus_changed(
+        &mut self,
+        window_id: window::Id,
+        focused: bool,
+    ) -> Task<Message> {
+        if !focused {
+            if self.focused_window == Some(window_id) {
+                self.focused_window = None;
+            }
+            return Task::none();
+        }
+
+        self.focused_window = Some(window_id);
+        if self.idle.activity(Instant::now()) {
+            self.send_away(false);
+        }
+        if let Some(conn_id) = self.popout_connection(window_id)
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(popout) = conn.popouts.get(&window_id)
+        {
+            let tab = ChatTab::UserMessage(popout.username.clone());
+            conn.unread_tabs.remove(&tab);
+        }
+        Task::none()
+    }
+
+    // ==================== Private Helpers ====================
+
+    /// Add an error message to a popped-out tab's history
+    fn add_popout_error(
+        &mut self,
+        connection_id: usize,
+        username: &str,
+        message: String,
+    ) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            let mut error = ChatMessage::error(message);
+            error.timestamp = Some(Local::now());
+            conn.user_messages
+                .entry(username.to_string())
+                .or_default()
+                .push(error);
+        }
+        Task::none()
+    }
+}
+
+/// Close every popout window belonging to a connection that is going away
+pub fn close_popouts(conn: &ServerConnection) -> Task<Message> {
+    Task::batch(
+        conn.popouts
+            .keys()
+            .map(|window_id| window::close(*window_id)),
+    )
+}
@@ -0,0 +1,133 @@
+//! Own profile (bio and pronouns) edit handlers
+
+use iced::Task;
+use iced::widget::{Id, operation};
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{
+    self, BioError, MAX_BIO_LENGTH, MAX_PRONOUNS_LENGTH, PronounsError,
+};
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{InputId, Message, ProfileEditState};
+
+impl NexusApp {
+    // ==================== Panel Actions ====================
+
+    /// Enter profile edit mode
+    ///
+    /// Only available while the user info panel shows our own account, whose
+    /// current bio and pronouns prefill the form.
+    pub fn handle_edit_profile_pressed(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let Some(Ok(user)) = &conn.user_info_data else {
+            return Task::none();
+        };
+        if !user.username.eq_ignore_ascii_case(&conn.username) {
+            return Task::none();
+        }
+
+        conn.profile_edit = Some(ProfileEditState::new(
+            user.bio.as_deref(),
+            user.pronouns.as_deref(),
+        ));
+
+        self.focused_field = InputId::EditProfilePronouns;
+        operation::focus(Id::from(InputId::EditProfilePronouns))
+    }
+
+    /// Cancel profile edit mode
+    pub fn handle_cancel_edit_profile(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.profile_edit = None;
+        }
+        Task::none()
+    }
+
+    /// Save profile changes
+    ///
+    /// Validates the form and sends `SetProfile` to the server.
+    pub fn handle_update_profile_pressed(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let Some(edit_state) = &mut conn.profile_edit else {
+            return Task::none();
+        };
+
+        let bio = edit_state.bio.trim().to_string();
+        let pronouns = edit_state.pronouns.trim().to_string();
+
+        if let Err(e) = validators::validate_pronouns(&pronouns) {
+            edit_state.error = Some(match e {
+                PronounsError::TooLong => t_args(
+                    "err-pronouns-too-long",
+                    &[("max", &MAX_PRONOUNS_LENGTH.to_string())],
+                ),
+                PronounsError::ContainsNewlines => t("err-pronouns-contains-newlines"),
+                PronounsError::InvalidCharacters => t("err-pronouns-invalid-characters"),
+            });
+            return Task::none();
+        }
+
+        if let Err(e) = validators::validate_bio(&bio) {
+            edit_state.error = Some(match e {
+                BioError::TooLong => {
+                    t_args("err-bio-too-long", &[("max", &MAX_BIO_LENGTH.to_string())])
+                }
+                BioError::ContainsNewlines => t("err-bio-contains-newlines"),
+                BioError::InvalidCharacters => t("err-bio-invalid-characters"),
+            });
+            return Task::none();
+        }
+
+        if let Err(e) = conn.send(ClientMessage::SetProfile { bio, pronouns }) {
+            if let Some(edit) = &mut conn.profile_edit {
+                edit.error = Some(t_args(
+                    "err-failed-send-update",
+                    &[("error", &e.to_string())],
+                ));
+            }
+            return Task::none();
+        }
+
+        // Keep edit mode open until we get a response
+        // The response handler will close it on success
+        Task::none()
+    }
+
+    // ==================== Form Field Handlers ====================
+
+    /// Handle profile pronouns field change
+    pub fn handle_edit_profile_pronouns_changed(&mut self, pronouns: String) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(edit_state) = &mut conn.profile_edit
+        {
+            edit_state.pronouns = pronouns;
+        }
+        Task::none()
+    }
+
+    /// Handle profile bio field change
+    pub fn handle_edit_profile_bio_changed(&mut self, bio: String) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(edit_state) = &mut conn.profile_edit
+        {
+            edit_state.bio = bio;
+        }
+        Task::none()
+    }
+}
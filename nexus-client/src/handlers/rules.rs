@@ -0,0 +1,18 @@
+//! Server rules acknowledgement handlers
+
+use crate::NexusApp;
+use crate::types::Message;
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+impl NexusApp {
+    /// Accept the server rules for a connection
+    ///
+    /// The dialog closes once the server confirms with `AcceptRulesResponse`.
+    pub fn handle_accept_rules(&mut self, connection_id: usize) -> Task<Message> {
+        if let Some(conn) = self.connections.get(&connection_id) {
+            let _ = conn.send(ClientMessage::AcceptRules);
+        }
+        Task::none()
+    }
+}
@@ -175,6 +175,9 @@ impl NexusApp {
             description,
             max_connections_per_ip,
             image,
+            chat_slow_mode: None,
+            max_message_length: None,
+            max_topic_length: None,
         };
 
         if let Err(e) = conn.send(msg) {
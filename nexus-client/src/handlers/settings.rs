@@ -1,12 +1,21 @@
 //! Settings panel handlers
 
 use crate::NexusApp;
-use crate::config::settings::{AVATAR_MAX_SIZE, CHAT_FONT_SIZE_MAX, CHAT_FONT_SIZE_MIN};
+use crate::config::density::UiDensity;
+use crate::config::message_format::{MessageFormat, MessageFormatError, MessageFormatPreset};
+use crate::config::quiet_hours::QuietHoursError;
+use crate::config::settings::{
+    AUTO_AWAY_MINUTES_MAX, AUTO_AWAY_MINUTES_MIN, AVATAR_MAX_SIZE, CHAT_FONT_SIZE_MAX,
+    CHAT_FONT_SIZE_MIN, COLLAPSE_MESSAGE_LINES_MAX, MAX_MESSAGE_TABS_MAX, MAX_MESSAGE_TABS_MIN,
+    PASTE_CONFIRM_LINES_MAX, ProxyError,
+};
+use crate::config::timezone::TimezonePreference;
 use crate::i18n::{t, t_args};
 use crate::image::{ImagePickerError, decode_data_uri_square};
 use crate::style::AVATAR_MAX_CACHE_SIZE;
-use crate::types::{ActivePanel, Message, SettingsFormState};
+use crate::types::{ActivePanel, ChatMessage, Message, SettingsFormState};
 use iced::Task;
+use nexus_common::protocol::ClientMessage;
 use rfd::AsyncFileDialog;
 
 impl NexusApp {
@@ -37,7 +46,29 @@ impl NexusApp {
     }
 
     /// Save settings to disk and close panel
+    ///
+    /// An invalid message format, quiet hours time, or proxy setting keeps the
+    /// panel open with an error instead.
     pub fn handle_save_settings(&mut self) -> Task<Message> {
+        if let Err(e) = MessageFormat::parse(&self.config.settings.chat_message_format) {
+            if let Some(form) = &mut self.settings_form {
+                form.error = Some(message_format_error_message(e));
+            }
+            return Task::none();
+        }
+        if let Err(e) = self.config.settings.quiet_hours.validate() {
+            if let Some(form) = &mut self.settings_form {
+                form.error = Some(quiet_hours_error_message(e));
+            }
+            return Task::none();
+        }
+        if let Err(e) = self.config.settings.proxy.validate() {
+            if let Some(form) = &mut self.settings_form {
+                form.error = Some(proxy_error_message(e));
+            }
+            return Task::none();
+        }
+
         // Clear the snapshot (no need to restore)
         self.settings_form = None;
 
@@ -49,9 +80,33 @@ impl NexusApp {
             ));
         }
 
+        if self.config.settings.sync_preferences {
+            self.upload_synced_preferences();
+        }
+
+        // A lowered limit closes the least recently active tabs right away
+        let max_message_tabs = self.config.settings.max_message_tabs;
+        for conn in self.connections.values_mut() {
+            conn.enforce_message_tab_limit(max_message_tabs, None);
+        }
+
         self.handle_show_chat_view()
     }
 
+    /// Store the synced preferences on every connected server that supports it
+    ///
+    /// Send failures are ignored; the connection's disconnect handling reports them.
+    fn upload_synced_preferences(&self) {
+        let preferences = self.config.settings.synced_preferences();
+        for conn in self.connections.values() {
+            if conn.supports_message_type("SetPreferences") {
+                let _ = conn.send(ClientMessage::SetPreferences {
+                    preferences: preferences.clone(),
+                });
+            }
+        }
+    }
+
     // ==================== Theme & Display ====================
 
     /// Handle theme selection from the picker (live preview)
@@ -63,18 +118,141 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle interface density selection from the picker (live preview)
+    pub fn handle_ui_density_selected(&mut self, density: UiDensity) -> Task<Message> {
+        self.config.settings.ui_density = density;
+        Task::none()
+    }
+
+    /// Handle show role badges toggle (live preview in the user list)
+    pub fn handle_show_role_badges_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.show_role_badges = enabled;
+        Task::none()
+    }
+
     /// Handle connection notifications toggle
     pub fn handle_connection_notifications_toggled(&mut self, enabled: bool) -> Task<Message> {
         self.config.settings.show_connection_notifications = enabled;
         Task::none()
     }
 
+    /// Handle hide repeated topic toggle
+    pub fn handle_hide_repeated_topic_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.hide_repeated_topic = enabled;
+        Task::none()
+    }
+
+    /// Handle alert on unexpected disconnect toggle
+    pub fn handle_alert_on_disconnect_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.alert_on_disconnect = enabled;
+        Task::none()
+    }
+
+    /// Handle alert on mention toggle
+    pub fn handle_alert_on_mention_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.alert_on_mention = enabled;
+        Task::none()
+    }
+
+    /// Handle focus new private messages toggle
+    pub fn handle_focus_new_messages_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.focus_new_messages = enabled;
+        Task::none()
+    }
+
+    /// Handle quiet hours toggle
+    pub fn handle_quiet_hours_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.quiet_hours.enabled = enabled;
+        Task::none()
+    }
+
+    /// Handle quiet hours start time field change
+    pub fn handle_quiet_hours_start_changed(&mut self, start: String) -> Task<Message> {
+        self.config.settings.quiet_hours.start = start;
+        Task::none()
+    }
+
+    /// Handle quiet hours end time field change
+    pub fn handle_quiet_hours_end_changed(&mut self, end: String) -> Task<Message> {
+        self.config.settings.quiet_hours.end = end;
+        Task::none()
+    }
+
+    /// Handle notify about watched users during quiet hours toggle
+    pub fn handle_watched_users_ignore_quiet_hours_toggled(
+        &mut self,
+        enabled: bool,
+    ) -> Task<Message> {
+        self.config.settings.watched_users_ignore_quiet_hours = enabled;
+        Task::none()
+    }
+
     /// Handle chat font size selection from the picker (live preview)
     pub fn handle_chat_font_size_selected(&mut self, size: u8) -> Task<Message> {
         self.config.settings.chat_font_size = size.clamp(CHAT_FONT_SIZE_MIN, CHAT_FONT_SIZE_MAX);
         Task::none()
     }
 
+    /// Handle chat message format template edit (live preview)
+    ///
+    /// An invalid template previews with the default format and is rejected on save.
+    pub fn handle_chat_message_format_changed(&mut self, format: String) -> Task<Message> {
+        self.config.settings.chat_message_format = format;
+        Task::none()
+    }
+
+    /// Handle chat message format preset selection (live preview)
+    pub fn handle_chat_message_format_preset_selected(
+        &mut self,
+        preset: MessageFormatPreset,
+    ) -> Task<Message> {
+        self.config.settings.chat_message_format = preset.template().to_string();
+        Task::none()
+    }
+
+    /// Handle auto-away toggle
+    ///
+    /// Turning it off while away counts as coming back.
+    pub fn handle_auto_away_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.auto_away.enabled = enabled;
+        if enabled {
+            return Task::none();
+        }
+        self.handle_user_activity()
+    }
+
+    /// Handle auto-away idle minutes change
+    pub fn handle_auto_away_minutes_changed(&mut self, minutes: u32) -> Task<Message> {
+        self.config.settings.auto_away.minutes =
+            minutes.clamp(AUTO_AWAY_MINUTES_MIN, AUTO_AWAY_MINUTES_MAX);
+        Task::none()
+    }
+
+    /// Handle maximum message tabs change (applied to open tabs on save)
+    pub fn handle_max_message_tabs_changed(&mut self, max: usize) -> Task<Message> {
+        self.config.settings.max_message_tabs =
+            max.clamp(MAX_MESSAGE_TABS_MIN, MAX_MESSAGE_TABS_MAX);
+        Task::none()
+    }
+
+    /// Handle large paste confirmation threshold change (0 never asks)
+    pub fn handle_paste_confirm_lines_changed(&mut self, lines: usize) -> Task<Message> {
+        self.config.settings.paste_confirm_lines = lines.min(PASTE_CONFIRM_LINES_MAX);
+        Task::none()
+    }
+
+    /// Handle long message collapse threshold change (0 never collapses)
+    pub fn handle_collapse_message_lines_changed(&mut self, lines: usize) -> Task<Message> {
+        self.config.settings.collapse_message_lines = lines.min(COLLAPSE_MESSAGE_LINES_MAX);
+        Task::none()
+    }
+
+    /// Handle Ctrl+Enter sends messages toggle
+    pub fn handle_ctrl_enter_sends_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.ctrl_enter_sends = enabled;
+        Task::none()
+    }
+
     // ==================== Timestamps ====================
 
     /// Handle show timestamps toggle
@@ -95,6 +273,87 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle timestamp timezone selection from the picker (live preview)
+    pub fn handle_timestamp_timezone_selected(
+        &mut self,
+        timezone: TimezonePreference,
+    ) -> Task<Message> {
+        self.config.settings.timestamp_timezone = timezone;
+        Task::none()
+    }
+
+    // ==================== Privacy ====================
+
+    /// Handle don't save passwords toggle
+    ///
+    /// Saved passwords are removed from disk on the next save; passwords already
+    /// in memory are kept until their connection is closed.
+    pub fn handle_forget_passwords_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.forget_passwords = enabled;
+        Task::none()
+    }
+
+    /// Handle sync preferences with server toggle
+    pub fn handle_sync_preferences_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.sync_preferences = enabled;
+        Task::none()
+    }
+
+    /// Handle check for updates at startup toggle (takes effect next launch)
+    pub fn handle_check_for_updates_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.check_for_updates = enabled;
+        Task::none()
+    }
+
+    // ==================== Proxy ====================
+
+    /// Handle use SOCKS5 proxy toggle (applies to new connections)
+    pub fn handle_proxy_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.proxy.enabled = enabled;
+        Task::none()
+    }
+
+    /// Handle proxy address field change
+    pub fn handle_proxy_address_changed(&mut self, address: String) -> Task<Message> {
+        self.config.settings.proxy.address = address;
+        Task::none()
+    }
+
+    /// Handle proxy port change
+    pub fn handle_proxy_port_changed(&mut self, port: u16) -> Task<Message> {
+        self.config.settings.proxy.port = port;
+        Task::none()
+    }
+
+    // ==================== Diagnostics ====================
+
+    /// Handle connection event log file field change (empty turns file logging off)
+    pub fn handle_connection_log_path_changed(&mut self, path: String) -> Task<Message> {
+        self.config.settings.connection_log_path = (!path.is_empty()).then_some(path);
+        Task::none()
+    }
+
+    /// Show a failed connection log write in the active chat (or the connection form)
+    pub fn handle_connection_log_write_failed(
+        &mut self,
+        path: String,
+        error: String,
+    ) -> Task<Message> {
+        let message = t_args(
+            "err-connection-log-write",
+            &[("path", &path), ("error", &error)],
+        );
+        match self.active_connection {
+            Some(connection_id) => {
+                self.add_chat_message(connection_id, ChatMessage::error(message))
+            }
+            None => {
+                self.connection_form.error = Some(message);
+                Task::none()
+            }
+        }
+    }
+
     // ==================== Avatar ====================
 
     /// Handle pick avatar button pressed - opens file dialog
@@ -204,4 +463,95 @@ impl NexusApp {
         self.config.settings.avatar = None;
         Task::none()
     }
+
+    // ==================== Trusted Certificates ====================
+
+    /// Copy a bookmark's pinned certificate fingerprint to the clipboard
+    pub fn handle_copy_fingerprint_pressed(&mut self, index: usize) -> Task<Message> {
+        match self
+            .config
+            .get_bookmark(index)
+            .and_then(|bookmark| bookmark.certificate_fingerprint.clone())
+        {
+            Some(fingerprint) => iced::clipboard::write(fingerprint),
+            None => Task::none(),
+        }
+    }
+
+    /// Ask to confirm clearing a bookmark's pinned certificate
+    pub fn handle_clear_fingerprint_pressed(&mut self, index: usize) -> Task<Message> {
+        if let Some(form) = &mut self.settings_form {
+            form.confirm_clear_fingerprint = Some(index);
+        }
+        Task::none()
+    }
+
+    /// Keep the pinned certificate
+    pub fn handle_cancel_clear_fingerprint(&mut self) -> Task<Message> {
+        if let Some(form) = &mut self.settings_form {
+            form.confirm_clear_fingerprint = None;
+        }
+        Task::none()
+    }
+
+    /// Clear the pinned certificate awaiting confirmation
+    ///
+    /// The next connection to that bookmark trusts whatever certificate the
+    /// server presents (Trust On First Use). Like other settings, the change
+    /// is written on Save and undone by Cancel.
+    pub fn handle_confirm_clear_fingerprint(&mut self) -> Task<Message> {
+        let Some(index) = self
+            .settings_form
+            .as_mut()
+            .and_then(|form| form.confirm_clear_fingerprint.take())
+        else {
+            return Task::none();
+        };
+
+        if let Some(bookmark) = self.config.bookmarks.get_mut(index) {
+            bookmark.certificate_fingerprint = None;
+        }
+        Task::none()
+    }
+}
+
+/// Translated message for an invalid quiet hours time
+fn quiet_hours_error_message(error: QuietHoursError) -> String {
+    match error {
+        QuietHoursError::InvalidStart => t("err-quiet-hours-start-invalid"),
+        QuietHoursError::InvalidEnd => t("err-quiet-hours-end-invalid"),
+    }
+}
+
+/// Translated message for an invalid proxy setting
+fn proxy_error_message(error: ProxyError) -> String {
+    match error {
+        ProxyError::EmptyAddress => t("err-proxy-address-empty"),
+        ProxyError::InvalidAddress => t("err-proxy-address-invalid"),
+        ProxyError::InvalidPort => t("err-proxy-port-invalid"),
+    }
+}
+
+/// Translated message for an invalid chat message format template
+///
+/// Placeholders are shown with their braces, as typed in the template.
+fn message_format_error_message(error: MessageFormatError) -> String {
+    match error {
+        MessageFormatError::UnclosedBrace => t("err-message-format-unclosed-brace"),
+        MessageFormatError::UnknownPlaceholder(name) => t_args(
+            "err-message-format-unknown-placeholder",
+            &[
+                ("placeholder", &format!("{{{}}}", name)),
+                ("available", "{time}, {date}, {nick}, {message}"),
+            ],
+        ),
+        MessageFormatError::MissingPlaceholder(name) => t_args(
+            "err-message-format-missing-placeholder",
+            &[("placeholder", &format!("{{{}}}", name))],
+        ),
+        MessageFormatError::DuplicatePlaceholder(name) => t_args(
+            "err-message-format-duplicate-placeholder",
+            &[("placeholder", &format!("{{{}}}", name))],
+        ),
+    }
 }
@@ -1,11 +1,71 @@
 //! UI panel management and toggles
 
 use crate::NexusApp;
-use crate::types::{ActivePanel, InputId, Message};
+use crate::network::{self, DEFAULT_RELEASE_URL, TRAFFIC_REFRESH_INTERVAL, UpdateInfo};
+use crate::types::{AUTO_AWAY_CHECK_INTERVAL, ActivePanel, InputId, Message, tick_interval};
 use iced::Task;
 use iced::widget::{Id, markdown, operation};
+use std::time::{Duration, Instant};
 
 impl NexusApp {
+    // ==================== Clock ====================
+
+    /// Whether any transient state is showing a spinner
+    ///
+    /// New animated states add their condition here rather than a timer of
+    /// their own.
+    pub fn is_animating(&self) -> bool {
+        self.connection_form.is_connecting || !self.connecting_bookmarks.is_empty()
+    }
+
+    /// How often the shared clock should tick, or `None` when nothing needs it
+    ///
+    /// The clock subscription only runs while a periodic job or animation
+    /// needs it. New periodic jobs add their condition here and their work to
+    /// `handle_clock_tick` rather than a timer of their own.
+    pub fn clock_interval(&self) -> Option<Duration> {
+        let animating = self.is_animating();
+        let needed = animating
+            || self.active_panel() == ActivePanel::ServerInfo
+            || self.auto_away_active()
+            || self
+                .connections
+                .values()
+                .any(|conn| conn.slow_mode_remaining().is_some());
+        needed.then(|| tick_interval(animating))
+    }
+
+    /// Advance the shared clock and run whichever periodic jobs are due
+    ///
+    /// A running slow mode countdown needs no work of its own: the redraw
+    /// after every tick shows the updated seconds in the chat input. Spinners
+    /// move to their next frame on every tick while animating.
+    pub fn handle_clock_tick(&mut self) -> Task<Message> {
+        let animating = self.is_animating();
+        self.clock.tick_at(Instant::now(), tick_interval(animating));
+
+        if animating {
+            self.animation.advance();
+        }
+
+        if self.active_panel() == ActivePanel::ServerInfo
+            && self.clock.is_due(TRAFFIC_REFRESH_INTERVAL)
+        {
+            self.refresh_traffic_totals();
+        }
+
+        if self.auto_away_active() && self.clock.is_due(AUTO_AWAY_CHECK_INTERVAL) {
+            self.check_auto_away();
+        }
+
+        Task::none()
+    }
+
+    /// Whether auto-away is on and there is a server to report it to
+    fn auto_away_active(&self) -> bool {
+        self.config.settings.auto_away.enabled && !self.connections.is_empty()
+    }
+
     // ==================== Active Panel Helpers ====================
 
     /// Get the effective active panel.
@@ -57,6 +117,40 @@ impl NexusApp {
         Task::none()
     }
 
+    // ==================== Update Check ====================
+
+    /// Task checking the configured release URL for a newer client
+    ///
+    /// Goes through the SOCKS5 proxy when one is enabled, like server connections.
+    pub fn update_check_task(&self) -> Task<Message> {
+        let release_url = self
+            .config
+            .settings
+            .update_url
+            .clone()
+            .unwrap_or_else(|| DEFAULT_RELEASE_URL.to_string());
+        let proxy = self.config.settings.proxy.active();
+        Task::perform(
+            network::check_for_update(release_url, proxy),
+            Message::UpdateCheckCompleted,
+        )
+    }
+
+    /// Show the update banner if the check found a newer release
+    ///
+    /// A failed check reports nothing, so it is indistinguishable from being
+    /// up to date.
+    pub fn handle_update_check_completed(&mut self, update: Option<UpdateInfo>) -> Task<Message> {
+        self.update_available = update;
+        Task::none()
+    }
+
+    /// Hide the update banner until the next launch
+    pub fn handle_dismiss_update_banner(&mut self) -> Task<Message> {
+        self.update_available = None;
+        Task::none()
+    }
+
     // ==================== Server Info ====================
 
     /// Show Server Info panel
@@ -66,6 +160,8 @@ impl NexusApp {
         }
 
         self.set_active_panel(ActivePanel::ServerInfo);
+        // Show current traffic rather than waiting for the first refresh
+        self.refresh_traffic_totals();
         Task::none()
     }
 
@@ -85,7 +181,14 @@ impl NexusApp {
     // ==================== User Info ====================
 
     /// Close User Info panel
+    ///
+    /// Also clears any active profile edit state.
     pub fn handle_close_user_info(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.profile_edit = None;
+        }
         self.handle_show_chat_view()
     }
 
@@ -3,8 +3,7 @@
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
 use crate::types::{
-    ActivePanel, ChatMessage, ChatTab, InputId, Message, PendingRequests, ResponseRouting,
-    UserEditState,
+    ActivePanel, ChatMessage, ChatTab, InputId, Message, ResponseRouting, UserEditState,
 };
 use crate::views::constants::PERMISSION_USER_INFO;
 use iced::Task;
@@ -508,11 +507,12 @@ impl NexusApp {
 
     /// Handle user message icon click (create/switch to PM tab)
     pub fn handle_user_message_icon_clicked(&mut self, username: String) -> Task<Message> {
+        let max_message_tabs = self.config.settings.max_message_tabs;
         if let Some(conn_id) = self.active_connection
             && let Some(conn) = self.connections.get_mut(&conn_id)
         {
             // Create PM tab entry if it doesn't exist
-            conn.user_messages.entry(username.clone()).or_default();
+            conn.open_user_message_tab(&username, max_message_tabs);
 
             // Switch to the PM tab
             let tab = ChatTab::UserMessage(username);
@@ -537,6 +537,18 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle the disconnect button for a single session in the user info panel
+    pub fn handle_kick_session_pressed(&mut self, session_id: u32) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Err(e) = conn.send(ClientMessage::KickSession { session_id })
+        {
+            let error_msg = format!("{}: {}", t("err-send-failed"), e);
+            return self.add_chat_message(conn_id, ChatMessage::error(error_msg));
+        }
+        Task::none()
+    }
+
     /// Handle user list item click (expand/collapse accordion)
     pub fn handle_user_list_item_clicked(&mut self, username: String) -> Task<Message> {
         if let Some(conn_id) = self.active_connection
@@ -562,14 +574,14 @@ impl NexusApp {
             && let Some(conn) = self.connections.get_mut(&conn_id)
         {
             // Check permission (admins always have access)
-            let has_permission =
-                conn.is_admin || conn.permissions.iter().any(|p| p == PERMISSION_USER_INFO);
+            let has_permission = conn.has_permission(PERMISSION_USER_INFO);
             if !has_permission {
                 return Task::none();
             }
 
             // Clear previous data and open the panel (shows loading state)
             conn.user_info_data = None;
+            conn.profile_edit = None;
             conn.active_panel = ActivePanel::UserInfo;
 
             // Send UserInfo request to server and track it
@@ -577,8 +589,10 @@ impl NexusApp {
                 username: username.clone(),
             }) {
                 Ok(message_id) => {
-                    conn.pending_requests
-                        .track(message_id, ResponseRouting::PopulateUserInfoPanel(username));
+                    conn.track_request(
+                        message_id,
+                        ResponseRouting::PopulateUserInfoPanel(username),
+                    );
                 }
                 Err(e) => {
                     let error_msg = format!("{}: {}", t("err-send-failed"), e);
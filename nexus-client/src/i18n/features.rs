@@ -0,0 +1,55 @@
+//! Feature name translation
+
+use super::bundle::get_bundle;
+use super::locale::get_locale;
+
+/// Translate a feature name to a user-friendly display string
+///
+/// Looks up the translation key "feature-{feature_name}" and returns the
+/// localized string. Unknown features are returned unchanged so that
+/// features added by newer clients still show up.
+///
+/// # Arguments
+/// * `feature` - The feature name (e.g., "chat")
+///
+/// # Returns
+/// The translated feature name, or the original name if no translation is found
+///
+/// # Example
+/// ```ignore
+/// let display = translate_feature("chat"); // "Chat" in English
+/// ```
+pub fn translate_feature(feature: &str) -> String {
+    translate_feature_for_locale(feature, get_locale())
+}
+
+fn translate_feature_for_locale(feature: &str, locale: &str) -> String {
+    let key = format!("feature-{}", feature);
+
+    let bundle = get_bundle(locale);
+    if let Some(msg) = bundle.get_message(&key).and_then(|m| m.value()) {
+        let mut errors = vec![];
+        let value = bundle.format_pattern(msg, None, &mut errors);
+        return value.to_string();
+    }
+
+    // Fallback: show the raw feature name
+    feature.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate_feature() {
+        let result = translate_feature_for_locale("chat", "en");
+        assert_eq!(result, "Chat");
+    }
+
+    #[test]
+    fn test_translate_feature_unknown() {
+        let result = translate_feature_for_locale("some_new_feature", "en");
+        assert_eq!(result, "some_new_feature");
+    }
+}
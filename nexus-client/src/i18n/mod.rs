@@ -19,14 +19,21 @@
 //! Permission names (like "user_list", "chat_send") are translated using the
 //! `translate_permission()` function, which looks up the corresponding
 //! "permission-{name}" key in the translation files.
+//!
+//! ## Feature Translation
+//!
+//! Feature names (like "chat") are translated using `translate_feature()`,
+//! which looks up "feature-{name}". Unknown features are shown unchanged.
 
 mod bundle;
 mod constants;
+mod features;
 mod locale;
 mod permissions;
 mod translate;
 
 pub use constants::DEFAULT_LOCALE;
+pub use features::translate_feature;
 pub use locale::get_locale;
 pub use permissions::translate_permission;
 pub use translate::{t, t_args};
@@ -5,6 +5,7 @@ mod autostart;
 mod avatar;
 mod commands;
 mod config;
+mod e2ee;
 mod fonts;
 mod handlers;
 mod i18n;
@@ -18,40 +19,26 @@ mod views;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 use iced::widget::{Id, operation};
-use iced::{Element, Subscription, Task, Theme};
+use iced::{Element, Subscription, Task, Theme, window};
 
 use style::{WINDOW_HEIGHT_MIN, WINDOW_TITLE, WINDOW_WIDTH_MIN};
 use types::{
-    BookmarkEditState, ConnectionFormState, FingerprintMismatch, InputId, Message,
-    ServerConnection, SettingsFormState, UiState, ViewConfig,
+    AnimationFrame, BookmarkEditState, ChatTab, ClockTick, ConnectionFormState,
+    ConnectionLogSender, ConnectionStep, FingerprintMismatch, IdleTracker, InputId,
+    KEEPALIVE_INTERVAL, Message, MessageDrafts, PendingBanner, ServerConnection, SettingsFormState,
+    UiState, ViewConfig, connection_log_writer,
 };
 
 /// Application entry point
 ///
-/// Configures the Iced application with window settings, fonts, and theme,
-/// then starts the event loop.
+/// Configures the Iced application with fonts and theme, then starts the
+/// event loop. Runs as a daemon so private message tabs can be popped out
+/// into windows of their own; the main window is opened by `NexusApp::new`.
 pub fn main() -> iced::Result {
-    // Load config early to get saved window position/size
-    let config = config::Config::load();
-    let window_size = iced::Size::new(config.settings.window_width, config.settings.window_height);
-    let window_position = match (config.settings.window_x, config.settings.window_y) {
-        (Some(x), Some(y)) => {
-            iced::window::Position::Specific(iced::Point::new(x as f32, y as f32))
-        }
-        _ => iced::window::Position::default(),
-    };
-
-    iced::application(NexusApp::new, NexusApp::update, NexusApp::view)
-        .title(WINDOW_TITLE)
+    iced::daemon(NexusApp::new, NexusApp::update, NexusApp::view)
+        .title(NexusApp::title)
         .theme(NexusApp::theme)
         .subscription(NexusApp::subscription)
-        .window(iced::window::Settings {
-            size: window_size,
-            min_size: Some(iced::Size::new(WINDOW_WIDTH_MIN, WINDOW_HEIGHT_MIN)),
-            position: window_position,
-            exit_on_close_request: false,
-            ..Default::default()
-        })
         .font(fonts::SAUCECODE_PRO_MONO)
         .font(fonts::SAUCECODE_PRO_MONO_BOLD)
         .font(fonts::SAUCECODE_PRO_MONO_ITALIC)
@@ -61,6 +48,23 @@ pub fn main() -> iced::Result {
         .run()
 }
 
+/// Settings for the main window, restoring its saved size and position
+fn main_window_settings(config: &config::Config) -> window::Settings {
+    let size = iced::Size::new(config.settings.window_width, config.settings.window_height);
+    let position = match (config.settings.window_x, config.settings.window_y) {
+        (Some(x), Some(y)) => window::Position::Specific(iced::Point::new(x as f32, y as f32)),
+        _ => window::Position::default(),
+    };
+
+    window::Settings {
+        size,
+        min_size: Some(iced::Size::new(WINDOW_WIDTH_MIN, WINDOW_HEIGHT_MIN)),
+        position,
+        exit_on_close_request: false,
+        ..Default::default()
+    }
+}
+
 /// Main application state for the Nexus BBS client
 struct NexusApp {
     // -------------------------------------------------------------------------
@@ -80,6 +84,8 @@ struct NexusApp {
     next_connection_id: usize,
     /// Set of bookmark indices currently connecting (prevents duplicate attempts)
     connecting_bookmarks: HashSet<usize>,
+    /// Latest connection step for each bookmark currently connecting
+    bookmark_steps: HashMap<usize, ConnectionStep>,
 
     // -------------------------------------------------------------------------
     // Forms
@@ -98,14 +104,36 @@ struct NexusApp {
     ui_state: UiState,
     /// Settings panel form state (present when settings panel is open)
     settings_form: Option<SettingsFormState>,
+    /// Main window (popped-out message tabs get windows of their own)
+    main_window: Option<window::Id>,
+    /// Window with input focus, if any
+    focused_window: Option<window::Id>,
 
     // -------------------------------------------------------------------------
     // Async / Transient
     // -------------------------------------------------------------------------
     /// Certificate fingerprint mismatch queue (for handling multiple mismatches)
     fingerprint_mismatch_queue: VecDeque<FingerprintMismatch>,
+    /// Pre-login banners waiting to be read (first one is shown)
+    pending_banners: VecDeque<PendingBanner>,
+    /// Bookmarks waiting for a password (first one is shown in the connection form)
+    password_prompts: VecDeque<usize>,
     /// Transient per-bookmark connection errors (not persisted to disk)
     bookmark_errors: HashMap<usize, String>,
+    /// Chat tab per bookmark to restore after reconnecting from a dropped connection
+    restore_chat_tabs: HashMap<usize, ChatTab>,
+    /// Unsent input per bookmark to restore after reconnecting
+    restore_drafts: HashMap<usize, MessageDrafts>,
+    /// Shared frame for spinners (advanced only while `is_animating`)
+    animation: AnimationFrame,
+    /// Shared clock driving periodic jobs (runs only while `clock_interval` is set)
+    clock: ClockTick,
+    /// Last user activity, for automatic away status
+    idle: IdleTracker,
+    /// Newer client release found by the update check (until dismissed)
+    update_available: Option<network::UpdateInfo>,
+    /// Queue for the connection log file writer (started in `new`)
+    connection_log: Option<ConnectionLogSender>,
 }
 
 impl Default for NexusApp {
@@ -119,6 +147,7 @@ impl Default for NexusApp {
             active_connection: None,
             next_connection_id: 0,
             connecting_bookmarks: HashSet::new(),
+            bookmark_steps: HashMap::new(),
             // Forms
             connection_form: ConnectionFormState::default(),
             bookmark_edit: BookmarkEditState::default(),
@@ -126,9 +155,20 @@ impl Default for NexusApp {
             // UI State
             ui_state: UiState::default(),
             settings_form: None,
+            main_window: None,
+            focused_window: None,
             // Async / Transient
             fingerprint_mismatch_queue: VecDeque::new(),
+            pending_banners: VecDeque::new(),
+            password_prompts: VecDeque::new(),
             bookmark_errors: HashMap::new(),
+            restore_chat_tabs: HashMap::new(),
+            restore_drafts: HashMap::new(),
+            animation: AnimationFrame::default(),
+            clock: ClockTick::default(),
+            idle: IdleTracker::new(std::time::Instant::now()),
+            update_available: None,
+            connection_log: None,
         }
     }
 }
@@ -136,18 +176,42 @@ impl Default for NexusApp {
 impl NexusApp {
     /// Initialize the application with default state and auto-connect tasks
     ///
-    /// Called once at startup to set up initial state and generate tasks for
-    /// focusing the input field and auto-connecting to bookmarks.
+    /// Called once at startup to open the main window and generate tasks for
+    /// focusing the input field and auto-connecting to bookmarks. A `nexus://` link
+    /// among the arguments connects through a trusted bookmark or pre-fills the
+    /// connection form.
     fn new() -> (Self, Task<Message>) {
-        let app = Self::default();
+        let mut app = Self::default();
+
+        let (main_window, open_main_window) = window::open(main_window_settings(&app.config));
+        app.main_window = Some(main_window);
+        app.focused_window = Some(main_window);
 
         // Generate auto-connect tasks for bookmarks
         let auto_connect_tasks = autostart::generate_auto_connect_tasks(&app.config);
 
-        // Combine focus task with auto-connect tasks
-        let mut tasks = vec![operation::focus(Id::from(InputId::ServerName))];
+        // A nexus:// link passed on the command line or by the OS URL handler
+        let startup_task = match autostart::invite_link_argument() {
+            Some(link) => app.handle_invite_link(&link),
+            None => operation::focus(Id::from(InputId::ServerName)),
+        };
+
+        // Combine startup task with auto-connect tasks
+        let mut tasks = vec![open_main_window.discard(), startup_task];
         tasks.extend(auto_connect_tasks);
 
+        // Single writer for the connection log file, running on the app's runtime
+        let (connection_log, writer) = connection_log_writer();
+        app.connection_log = Some(connection_log);
+        tasks.push(Task::run(writer, |(path, error)| {
+            Message::ConnectionLogWriteFailed { path, error }
+        }));
+
+        // Opt-in check for a newer client release
+        if app.config.settings.check_for_updates {
+            tasks.push(app.update_check_task());
+        }
+
         (app, Task::batch(tasks))
     }
 
@@ -156,16 +220,19 @@ impl NexusApp {
     /// Central message dispatcher that routes messages to their handlers.
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
-            // Keyboard and window events
+            // Keyboard and window events (shortcuts only apply to the main window)
+            Message::Event(_) if self.popout_has_focus() => Task::none(),
             Message::Event(event) => self.handle_keyboard_event(event),
             Message::NextChatTab => self.handle_next_chat_tab(),
             Message::PrevChatTab => self.handle_prev_chat_tab(),
             Message::TabPressed => self.handle_tab_navigation(),
+            Message::WindowCloseRequested(id) if Some(id) != self.main_window => {
+                self.handle_popout_closed(id)
+            }
             Message::WindowCloseRequested(id) => {
-                // Query window size and position, then save and close
-                iced::window::size(id).then(move |size| {
-                    iced::window::position(id).map(move |point| Message::WindowSaveAndClose {
-                        id,
+                // Query window size and position, then save and exit
+                window::size(id).then(move |size| {
+                    window::position(id).map(move |point| Message::WindowSaveAndClose {
                         width: size.width,
                         height: size.height,
                         x: point.map(|p| p.x as i32),
@@ -173,8 +240,10 @@ impl NexusApp {
                     })
                 })
             }
+            Message::WindowFocusChanged(id, focused) => {
+                self.handle_window_focus_changed(id, focused)
+            }
             Message::WindowSaveAndClose {
-                id,
                 width,
                 height,
                 x,
@@ -186,7 +255,8 @@ impl NexusApp {
                 self.config.settings.window_x = x;
                 self.config.settings.window_y = y;
                 let _ = self.config.save();
-                iced::window::close(id)
+                // Closing the main window quits, taking any popouts with it
+                iced::exit()
             }
 
             // Connection management
@@ -195,6 +265,14 @@ impl NexusApp {
             Message::DisconnectFromServer(connection_id) => {
                 self.handle_disconnect_from_server(connection_id)
             }
+            Message::AdminNoticeReconnect(connection_id) => {
+                self.handle_admin_notice_reconnect(connection_id)
+            }
+            Message::DismissAdminNotice(connection_id) => {
+                self.handle_dismiss_admin_notice(connection_id)
+            }
+            Message::KeepaliveTick => self.handle_keepalive_tick(),
+            Message::UserActivity => self.handle_user_activity(),
             Message::SwitchToConnection(connection_id) => {
                 self.handle_switch_to_connection(connection_id)
             }
@@ -209,6 +287,9 @@ impl NexusApp {
 
             // Bookmark management
             Message::BookmarkAddressChanged(addr) => self.handle_bookmark_address_changed(addr),
+            Message::BookmarkAddressesChanged(addresses) => {
+                self.handle_bookmark_addresses_changed(addresses)
+            }
             Message::BookmarkAutoConnectToggled(enabled) => {
                 self.handle_bookmark_auto_connect_toggled(enabled)
             }
@@ -217,9 +298,17 @@ impl NexusApp {
                 self.handle_bookmark_password_changed(password)
             }
             Message::BookmarkPortChanged(port) => self.handle_bookmark_port_changed(port),
+            Message::BookmarkThemeCleared => self.handle_bookmark_theme_cleared(),
+            Message::BookmarkThemeSelected(theme) => self.handle_bookmark_theme_selected(theme),
+            Message::BookmarkTrustCertificateChangesToggled(enabled) => {
+                self.handle_bookmark_trust_certificate_changes_toggled(enabled)
+            }
             Message::BookmarkUsernameChanged(username) => {
                 self.handle_bookmark_username_changed(username)
             }
+            Message::BookmarkWatchedUsersChanged(usernames) => {
+                self.handle_bookmark_watched_users_changed(usernames)
+            }
             Message::CancelBookmarkEdit => self.handle_cancel_bookmark_edit(),
             Message::DeleteBookmark(index) => self.handle_delete_bookmark(index),
             Message::SaveBookmark => self.handle_save_bookmark(),
@@ -228,18 +317,44 @@ impl NexusApp {
 
             // Certificate fingerprint
             Message::AcceptNewFingerprint => self.handle_accept_new_fingerprint(),
+            Message::AlwaysAcceptNewFingerprint => self.handle_always_accept_new_fingerprint(),
             Message::CancelFingerprintMismatch => self.handle_cancel_fingerprint_mismatch(),
 
+            // Pre-login banner
+            Message::AcceptBanner => self.handle_accept_banner(),
+            Message::CancelBanner => self.handle_cancel_banner(),
+
+            // Server rules
+            Message::AcceptRules(connection_id) => self.handle_accept_rules(connection_id),
+
+            // Info pages
+            Message::ClosePage(connection_id) => self.handle_close_page(connection_id),
+
             // Chat
-            Message::ChatInputChanged(input) => self.handle_message_input_changed(input),
+            Message::CancelLargePaste => self.handle_cancel_large_paste(),
+            Message::ChatInputAction(action) => self.handle_message_input_action(action),
+            Message::ConfirmLargePaste => self.handle_confirm_large_paste(),
             Message::ChatScrolled(viewport) => self.handle_chat_scrolled(viewport),
             Message::CloseUserMessageTab(username) => self.handle_close_user_message_tab(username),
+            Message::CopyChatLine(index) => self.handle_copy_chat_line(index),
+            Message::CopyConfirmationExpired => self.handle_copy_confirmation_expired(),
+            Message::PopOutMessageTab(username) => self.handle_pop_out_message_tab(username),
+            Message::PopoutInputAction(id, action) => self.handle_popout_input_action(id, action),
+            Message::PopoutSendPressed(id) => self.handle_popout_send_pressed(id),
+            Message::ReplyToLast => self.handle_reply_to_last(),
+            Message::ReplyTo(index) => self.handle_reply_to(index),
+            Message::ToggleMessageExpanded(index) => self.handle_toggle_message_expanded(index),
+            Message::SendDebounceExpired => self.handle_send_debounce_expired(),
             Message::SendMessagePressed => self.handle_send_message_pressed(),
             Message::SwitchChatTab(tab) => self.handle_switch_chat_tab(tab),
+            Message::UnpinMessagePressed(message_id) => {
+                self.handle_unpin_message_pressed(message_id)
+            }
 
             // User list interactions
             Message::UserInfoIconClicked(username) => self.handle_user_info_icon_clicked(username),
             Message::UserKickIconClicked(username) => self.handle_user_kick_icon_clicked(username),
+            Message::KickSessionPressed(session_id) => self.handle_kick_session_pressed(session_id),
             Message::UserListItemClicked(username) => self.handle_user_list_item_clicked(username),
             Message::UserMessageIconClicked(username) => {
                 self.handle_user_message_icon_clicked(username)
@@ -278,11 +393,15 @@ impl NexusApp {
 
             // Broadcast
             Message::BroadcastMessageChanged(input) => self.handle_broadcast_message_changed(input),
+            Message::BroadcastTargetSelected(target) => {
+                self.handle_broadcast_target_selected(target)
+            }
             Message::CancelBroadcast => self.handle_cancel_broadcast(),
             Message::SendBroadcastPressed => self.handle_send_broadcast_pressed(),
             Message::ValidateBroadcast => self.handle_validate_broadcast(),
 
             // UI toggles
+            Message::ClockTick => self.handle_clock_tick(),
             Message::ShowChatView => self.handle_show_chat_view(),
             Message::ToggleAddUser => self.handle_toggle_add_user(),
             Message::ToggleBookmarks => self.handle_toggle_bookmarks(),
@@ -291,19 +410,86 @@ impl NexusApp {
             Message::ToggleUserList => self.handle_toggle_user_list(),
 
             // Settings
+            Message::AlertOnDisconnectToggled(enabled) => {
+                self.handle_alert_on_disconnect_toggled(enabled)
+            }
+            Message::AlertOnMentionToggled(enabled) => {
+                self.handle_alert_on_mention_toggled(enabled)
+            }
+            Message::FocusNewMessagesToggled(enabled) => {
+                self.handle_focus_new_messages_toggled(enabled)
+            }
             Message::CancelSettings => self.handle_cancel_settings(),
             Message::ChatFontSizeSelected(size) => self.handle_chat_font_size_selected(size),
+            Message::ChatMessageFormatChanged(format) => {
+                self.handle_chat_message_format_changed(format)
+            }
+            Message::ChatMessageFormatPresetSelected(preset) => {
+                self.handle_chat_message_format_preset_selected(preset)
+            }
+            Message::CancelClearFingerprint => self.handle_cancel_clear_fingerprint(),
             Message::ClearAvatarPressed => self.handle_clear_avatar_pressed(),
+            Message::ClearFingerprintPressed(index) => self.handle_clear_fingerprint_pressed(index),
+            Message::ConfirmClearFingerprint => self.handle_confirm_clear_fingerprint(),
+            Message::CopyFingerprintPressed(index) => self.handle_copy_fingerprint_pressed(index),
+            Message::CtrlEnterSendsToggled(enabled) => {
+                self.handle_ctrl_enter_sends_toggled(enabled)
+            }
+            Message::ConnectionLogPathChanged(path) => {
+                self.handle_connection_log_path_changed(path)
+            }
+            Message::ConnectionLogWriteFailed { path, error } => {
+                self.handle_connection_log_write_failed(path, error)
+            }
             Message::ConnectionNotificationsToggled(enabled) => {
                 self.handle_connection_notifications_toggled(enabled)
             }
+            Message::HideRepeatedTopicToggled(enabled) => {
+                self.handle_hide_repeated_topic_toggled(enabled)
+            }
+            Message::AutoAwayMinutesChanged(minutes) => {
+                self.handle_auto_away_minutes_changed(minutes)
+            }
+            Message::AutoAwayToggled(enabled) => self.handle_auto_away_toggled(enabled),
             Message::AvatarLoaded(result) => self.handle_avatar_loaded(result),
+            Message::MaxMessageTabsChanged(max) => self.handle_max_message_tabs_changed(max),
+            Message::PasteConfirmLinesChanged(lines) => {
+                self.handle_paste_confirm_lines_changed(lines)
+            }
+            Message::CollapseMessageLinesChanged(lines) => {
+                self.handle_collapse_message_lines_changed(lines)
+            }
             Message::PickAvatarPressed => self.handle_pick_avatar_pressed(),
             Message::SaveSettings => self.handle_save_settings(),
+            Message::ShowRoleBadgesToggled(enabled) => {
+                self.handle_show_role_badges_toggled(enabled)
+            }
             Message::ShowSecondsToggled(enabled) => self.handle_show_seconds_toggled(enabled),
             Message::ShowTimestampsToggled(enabled) => self.handle_show_timestamps_toggled(enabled),
             Message::ThemeSelected(theme) => self.handle_theme_selected(theme),
+            Message::ForgetPasswordsToggled(enabled) => {
+                self.handle_forget_passwords_toggled(enabled)
+            }
+            Message::SyncPreferencesToggled(enabled) => {
+                self.handle_sync_preferences_toggled(enabled)
+            }
+            Message::CheckForUpdatesToggled(enabled) => {
+                self.handle_check_for_updates_toggled(enabled)
+            }
+            Message::ProxyAddressChanged(address) => self.handle_proxy_address_changed(address),
+            Message::ProxyPortChanged(port) => self.handle_proxy_port_changed(port),
+            Message::ProxyToggled(enabled) => self.handle_proxy_toggled(enabled),
+            Message::QuietHoursEndChanged(end) => self.handle_quiet_hours_end_changed(end),
+            Message::QuietHoursStartChanged(start) => self.handle_quiet_hours_start_changed(start),
+            Message::QuietHoursToggled(enabled) => self.handle_quiet_hours_toggled(enabled),
+            Message::WatchedUsersIgnoreQuietHoursToggled(enabled) => {
+                self.handle_watched_users_ignore_quiet_hours_toggled(enabled)
+            }
+            Message::TimestampTimezoneSelected(timezone) => {
+                self.handle_timestamp_timezone_selected(timezone)
+            }
             Message::ToggleSettings => self.handle_toggle_settings(),
+            Message::UiDensitySelected(density) => self.handle_ui_density_selected(density),
             Message::Use24HourTimeToggled(enabled) => self.handle_use_24_hour_time_toggled(enabled),
 
             // About
@@ -311,6 +497,10 @@ impl NexusApp {
             Message::OpenUrl(url) => self.handle_open_url(url),
             Message::ShowAbout => self.handle_show_about(),
 
+            // Update check
+            Message::DismissUpdateBanner => self.handle_dismiss_update_banner(),
+            Message::UpdateCheckCompleted(update) => self.handle_update_check_completed(update),
+
             // Server info
             Message::CancelEditServerInfo => self.handle_cancel_edit_server_info(),
             Message::ClearServerImagePressed => self.handle_clear_server_image_pressed(),
@@ -333,7 +523,14 @@ impl NexusApp {
             Message::UpdateServerInfoPressed => self.handle_update_server_info_pressed(),
 
             // User info
+            Message::CancelEditProfile => self.handle_cancel_edit_profile(),
             Message::CloseUserInfo => self.handle_close_user_info(),
+            Message::EditProfileBioChanged(bio) => self.handle_edit_profile_bio_changed(bio),
+            Message::EditProfilePressed => self.handle_edit_profile_pressed(),
+            Message::EditProfilePronounsChanged(pronouns) => {
+                self.handle_edit_profile_pronouns_changed(pronouns)
+            }
+            Message::UpdateProfilePressed => self.handle_update_profile_pressed(),
 
             // Network events (async results)
             Message::BookmarkConnectionResult {
@@ -341,7 +538,16 @@ impl NexusApp {
                 bookmark_index,
                 display_name,
             } => self.handle_bookmark_connection_result(result, bookmark_index, display_name),
+            Message::BookmarkConnectionStepChanged {
+                bookmark_index,
+                step,
+            } => self.handle_bookmark_connection_step_changed(bookmark_index, step),
+            Message::BannerReceived {
+                bookmark_index,
+                banner,
+            } => self.handle_banner_received(bookmark_index, banner),
             Message::ConnectionResult(result) => self.handle_connection_result(result),
+            Message::ConnectionStepChanged(step) => self.handle_connection_step_changed(step),
             Message::NetworkError(connection_id, error) => {
                 self.handle_network_error(connection_id, error)
             }
@@ -360,7 +566,17 @@ impl NexusApp {
             // Keyboard and general events
             iced::event::listen().map(Message::Event),
             // Window close requests (we handle saving before exit)
-            iced::window::close_requests().map(Message::WindowCloseRequested),
+            window::close_requests().map(Message::WindowCloseRequested),
+            // Window focus changes (disconnect alerts are skipped while focused)
+            iced::event::listen_with(|event, _status, id| match event {
+                iced::Event::Window(window::Event::Focused) => {
+                    Some(Message::WindowFocusChanged(id, true))
+                }
+                iced::Event::Window(window::Event::Unfocused) => {
+                    Some(Message::WindowFocusChanged(id, false))
+                }
+                _ => None,
+            }),
         ];
 
         // Subscribe to all active connections
@@ -371,53 +587,177 @@ impl NexusApp {
             ));
         }
 
+        // Periodically ping connected servers to measure connection health
+        if !self.connections.is_empty() {
+            subscriptions
+                .push(iced::time::every(KEEPALIVE_INTERVAL).map(|_| Message::KeepaliveTick));
+        }
+
+        // Watch for user activity while auto-away or focusing new messages is on
+        let watch_activity =
+            self.config.settings.auto_away.enabled || self.config.settings.focus_new_messages;
+        if watch_activity && !self.connections.is_empty() {
+            subscriptions.push(iced::event::listen_with(
+                |event, _status, _id| match event {
+                    iced::Event::Keyboard(iced::keyboard::Event::KeyPressed { .. })
+                    | iced::Event::Mouse(iced::mouse::Event::ButtonPressed(_))
+                    | iced::Event::Mouse(iced::mouse::Event::WheelScrolled { .. }) => {
+                        Some(Message::UserActivity)
+                    }
+                    _ => None,
+                },
+            ));
+        }
+
+        // One shared clock drives spinners, traffic refresh, slow mode and idle checks
+        if let Some(interval) = self.clock_interval() {
+            subscriptions.push(iced::time::every(interval).map(|_| Message::ClockTick));
+        }
+
         Subscription::batch(subscriptions)
     }
 
-    /// Render the current application state to the UI
+    /// Render a window
     ///
-    /// Delegates to `views::main_layout()` for all rendering logic.
-    fn view(&self) -> Element<'_, Message> {
+    /// Popout windows show their message tab; the main window delegates to
+    /// `views::main_layout()`.
+    fn view(&self, window_id: window::Id) -> Element<'_, Message> {
+        if let Some(conn) = self
+            .popout_connection(window_id)
+            .and_then(|id| self.connections.get(&id))
+            && let Some(popout) = conn.popouts.get(&window_id)
+        {
+            let config = self.view_config(self.connection_theme(conn.connection_id));
+            return views::popout_view(window_id, conn, popout, &config);
+        }
+
+        self.main_view()
+    }
+
+    /// Render the main window
+    fn main_view(&self) -> Element<'_, Message> {
+        let main_view = views::main_layout(self.view_config(self.main_theme()));
+
+        // Overlay fingerprint mismatch dialog if present (show first in queue)
+        if let Some(mismatch) = self.fingerprint_mismatch_queue.front() {
+            return views::fingerprint_mismatch_dialog(mismatch);
+        }
+
+        // Overlay pre-login banner dialog if present (show first in queue)
+        if let Some(pending) = self.pending_banners.front() {
+            return views::banner_dialog(pending);
+        }
+
+        // Overlay server rules dialog until the active connection accepts them
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get(&conn_id)
+            && let Some(rules) = &conn.pending_rules
+        {
+            return views::server_rules_dialog(conn_id, rules);
+        }
+
+        // Overlay the page viewer while the active connection has a page open
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get(&conn_id)
+            && let Some(page) = &conn.open_page
+        {
+            return views::page_dialog(conn_id, page);
+        }
+
+        main_view
+    }
+
+    /// Settings and state shared by the window views, styled with `theme`
+    fn view_config(&self, theme: Theme) -> ViewConfig<'_> {
         // Get current connection state
         let active_conn = self
             .active_connection
             .and_then(|id| self.connections.get(&id));
-        let message_input = active_conn.map(|c| c.message_input.as_str()).unwrap_or("");
         let user_management = active_conn.map(|c| &c.user_management);
 
-        // Build view configuration
-        let config = ViewConfig {
-            theme: self.theme(),
+        ViewConfig {
+            theme,
+            ui_density: self.config.settings.ui_density,
+            show_role_badges: self.config.settings.show_role_badges,
             show_connection_notifications: self.config.settings.show_connection_notifications,
+            hide_repeated_topic: self.config.settings.hide_repeated_topic,
+            alert_on_disconnect: self.config.settings.alert_on_disconnect,
+            alert_on_mention: self.config.settings.alert_on_mention,
+            focus_new_messages: self.config.settings.focus_new_messages,
+            quiet_hours: &self.config.settings.quiet_hours,
+            watched_users_ignore_quiet_hours: self.config.settings.watched_users_ignore_quiet_hours,
+            auto_away: self.config.settings.auto_away,
             chat_font_size: self.config.settings.chat_font_size,
+            max_message_tabs: self.config.settings.max_message_tabs,
+            paste_confirm_lines: self.config.settings.paste_confirm_lines,
+            collapse_message_lines: self.config.settings.collapse_message_lines,
+            ctrl_enter_sends: self.config.settings.ctrl_enter_sends,
+            chat_message_format: &self.config.settings.chat_message_format,
             show_timestamps: self.config.settings.show_timestamps,
             use_24_hour_time: self.config.settings.use_24_hour_time,
             show_seconds: self.config.settings.show_seconds,
+            timestamp_timezone: self.config.settings.timestamp_timezone,
+            forget_passwords: self.config.settings.forget_passwords,
+            sync_preferences: self.config.settings.sync_preferences,
+            check_for_updates: self.config.settings.check_for_updates,
+            proxy: &self.config.settings.proxy,
+            connection_log_path: self
+                .config
+                .settings
+                .connection_log_path
+                .as_deref()
+                .unwrap_or_default(),
             settings_form: self.settings_form.as_ref(),
             connections: &self.connections,
             active_connection: self.active_connection,
             bookmarks: &self.config.bookmarks,
             bookmark_errors: &self.bookmark_errors,
+            bookmark_steps: &self.bookmark_steps,
+            animation: self.animation,
             connection_form: &self.connection_form,
             bookmark_edit: &self.bookmark_edit,
-            message_input,
             user_management,
             ui_state: &self.ui_state,
             active_panel: self.active_panel(),
-        };
+            update_available: self.update_available.as_ref(),
+        }
+    }
 
-        let main_view = views::main_layout(config);
+    /// Title of a window
+    fn title(&self, window_id: window::Id) -> String {
+        self.popout_title(window_id)
+            .unwrap_or_else(|| WINDOW_TITLE.to_string())
+    }
 
-        // Overlay fingerprint mismatch dialog if present (show first in queue)
-        if let Some(mismatch) = self.fingerprint_mismatch_queue.front() {
-            return views::fingerprint_mismatch_dialog(mismatch);
+    /// Theme of a window
+    ///
+    /// Popouts follow the theme of the connection they belong to.
+    fn theme(&self, window_id: window::Id) -> Theme {
+        match self.popout_connection(window_id) {
+            Some(connection_id) => self.connection_theme(connection_id),
+            None => self.main_theme(),
         }
+    }
 
-        main_view
+    /// Theme of the main window, following the active connection
+    fn main_theme(&self) -> Theme {
+        match self.active_connection {
+            Some(connection_id) => self.connection_theme(connection_id),
+            None => self.config.settings.theme.to_iced_theme(),
+        }
     }
 
-    /// Get the current theme based on configuration
-    fn theme(&self) -> Theme {
-        self.config.settings.theme.to_iced_theme()
+    /// Get a connection's theme based on configuration
+    ///
+    /// Uses the connection's bookmark theme override when one is set,
+    /// otherwise falls back to the global theme from settings.
+    fn connection_theme(&self, connection_id: usize) -> Theme {
+        self.connections
+            .get(&connection_id)
+            .and_then(|conn| conn.bookmark_index)
+            .and_then(|index| self.config.get_bookmark(index))
+            .and_then(|bookmark| bookmark.theme.as_ref())
+            .unwrap_or(&self.config.settings.theme)
+            .to_iced_theme()
     }
 }
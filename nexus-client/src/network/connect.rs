@@ -1,44 +1,149 @@
 //! Server connection, handshake, and login
 
+use iced::futures::{SinkExt, Stream};
+use iced::stream;
 use tokio::io::BufReader;
 
 use nexus_common::PROTOCOL_VERSION;
 use nexus_common::framing::{FrameReader, FrameWriter};
 use nexus_common::io::{read_server_message, send_client_message};
-use nexus_common::protocol::{ClientMessage, ServerMessage};
+use nexus_common::protocol::{ClientMessage, ERROR_CODE_CLIENT_TOO_OLD, ServerMessage};
+use nexus_common::validators::{MAX_CHAT_TOPIC_LENGTH, MAX_MESSAGE_LENGTH};
+use nexus_common::version::{Version, supports_extended_protocol};
 
 use crate::i18n::{DEFAULT_LOCALE, t, t_args};
-use crate::types::NetworkConnection;
+use crate::types::{ConnectionStep, Message, NetworkConnection};
 
-use super::constants::DEFAULT_FEATURES;
+use super::constants::{DEFAULT_FEATURES, STREAM_CHANNEL_SIZE};
 use super::stream::setup_communication_channels;
 use super::tls::establish_connection;
-use super::types::{LoginInfo, Reader, Writer};
+use super::types::{ConnectionDetails, LoginInfo, ProxyConfig, Reader, TlsStream, Writer};
+
+/// Why a connection attempt stopped before a session was established
+enum Interrupted {
+    /// The attempt failed (translated error message)
+    Failed(String),
+    /// The server shows a banner the user has not accepted yet
+    Banner(String),
+}
+
+impl From<String> for Interrupted {
+    fn from(error: String) -> Self {
+        Self::Failed(error)
+    }
+}
+
+/// Connect to server as an Iced stream that reports progress
+///
+/// `endpoints` are `(address, port)` pairs tried in order until one connects.
+/// Emits `on_step` for each connection step as it begins, then a final
+/// `on_result` message with the outcome of [`connect_to_server`]. If the server
+/// sends a pre-login banner other than `accepted_banner`, the attempt stops
+/// before logging in and `on_banner` is emitted instead. With
+/// `expected_fingerprint` set, a server presenting any other certificate is
+/// dropped before the handshake.
+#[allow(clippy::too_many_arguments)]
+pub fn connect_with_progress(
+    endpoints: Vec<(String, u16)>,
+    username: String,
+    password: String,
+    locale: String,
+    avatar: Option<String>,
+    proxy: Option<ProxyConfig>,
+    connection_id: usize,
+    accepted_banner: Option<String>,
+    expected_fingerprint: Option<String>,
+    on_step: impl Fn(ConnectionStep) -> Message + Send + 'static,
+    on_banner: impl FnOnce(String) -> Message + Send + 'static,
+    on_result: impl FnOnce(Result<NetworkConnection, String>) -> Message + Send + 'static,
+) -> impl Stream<Item = Message> {
+    stream::channel(
+        STREAM_CHANNEL_SIZE,
+        move |mut output: iced::futures::channel::mpsc::Sender<Message>| async move {
+            let result = connect_to_server(
+                endpoints,
+                username,
+                password,
+                locale,
+                avatar,
+                proxy,
+                connection_id,
+                accepted_banner,
+                expected_fingerprint,
+                |step| {
+                    // Progress is best-effort; never stall the connection on the UI
+                    let _ = output.try_send(on_step(step));
+                },
+            )
+            .await;
+            let message = match result {
+                Ok(conn) => on_result(Ok(conn)),
+                Err(Interrupted::Failed(error)) => on_result(Err(error)),
+                Err(Interrupted::Banner(banner)) => on_banner(banner),
+            };
+            let _ = output.send(message).await;
+        },
+    )
+}
 
 /// Connect to server, perform handshake and login
 ///
-/// Establishes a TCP connection, performs protocol handshake and authentication,
-/// then sets up bidirectional communication channels. Returns a NetworkConnection
-/// handle for sending messages to the server.
-pub async fn connect_to_server(
-    server_address: String,
-    port: u16,
+/// Establishes a TCP connection (through `proxy` when given) to the first of
+/// `endpoints` that accepts it and completes TLS, performs protocol handshake
+/// and authentication, then sets up bidirectional communication channels.
+/// Returns a NetworkConnection handle for sending messages to the server. Each
+/// step is reported through `progress` as it begins.
+///
+/// Only connection failures fall through to the next endpoint; once a server
+/// answers, its handshake or login result is final.
+///
+/// A pre-login banner that differs from `accepted_banner` ends the attempt
+/// before logging in, so the user can read it without racing the server's
+/// login deadline; the caller reconnects once it is accepted.
+///
+/// A certificate other than `expected_fingerprint` (the one named by an
+/// invite link) ends the attempt right after TLS, before any credentials
+/// are sent.
+#[allow(clippy::too_many_arguments)]
+async fn connect_to_server(
+    endpoints: Vec<(String, u16)>,
     username: String,
     password: String,
     locale: String,
     avatar: Option<String>,
+    proxy: Option<ProxyConfig>,
     connection_id: usize,
-) -> Result<NetworkConnection, String> {
+    accepted_banner: Option<String>,
+    expected_fingerprint: Option<String>,
+    mut progress: impl FnMut(ConnectionStep),
+) -> Result<NetworkConnection, Interrupted> {
     // Establish TCP connection and get certificate fingerprint
-    let (tls_stream, fingerprint) = establish_connection(&server_address, port).await?;
+    let (server_address, port, tls_stream, fingerprint) =
+        establish_first_connection(endpoints, proxy.as_ref(), &mut progress).await?;
+    if let Some(expected) = expected_fingerprint
+        && expected != fingerprint
+    {
+        return Err(Interrupted::Failed(t_args(
+            "err-invite-fingerprint-mismatch",
+            &[("expected", &expected), ("received", &fingerprint)],
+        )));
+    }
 
+    let traffic = tls_stream.get_ref().0.counters();
     let (reader, writer) = tokio::io::split(tls_stream);
     let buf_reader = BufReader::new(reader);
     let mut frame_reader = FrameReader::new(buf_reader);
     let mut frame_writer = FrameWriter::new(writer);
 
     // Perform handshake and login
-    perform_handshake(&mut frame_reader, &mut frame_writer).await?;
+    progress(ConnectionStep::Handshake);
+    let handshake = perform_handshake(&mut frame_reader, &mut frame_writer).await?;
+    if let Some(banner) = handshake.banner
+        && accepted_banner.as_deref() != Some(banner.as_str())
+    {
+        return Err(Interrupted::Banner(banner));
+    }
+    progress(ConnectionStep::Login);
     let login_info = perform_login(
         &mut frame_reader,
         &mut frame_writer,
@@ -50,18 +155,66 @@ pub async fn connect_to_server(
     .await?;
 
     // Set up bidirectional communication
-    setup_communication_channels(
+    let conn = setup_communication_channels(
         frame_reader,
         frame_writer,
         login_info,
-        connection_id,
-        fingerprint,
+        ConnectionDetails {
+            connection_id,
+            server_address,
+            server_port: port,
+            fingerprint,
+            traffic,
+            extended_protocol: handshake.extended_protocol,
+        },
     )
-    .await
+    .await?;
+    Ok(conn)
+}
+
+/// Establish a TLS connection to the first reachable endpoint
+///
+/// Returns the endpoint that connected along with the stream and certificate
+/// fingerprint. When every endpoint fails, the error names how many were
+/// tried and the last failure.
+async fn establish_first_connection(
+    endpoints: Vec<(String, u16)>,
+    proxy: Option<&ProxyConfig>,
+    progress: &mut impl FnMut(ConnectionStep),
+) -> Result<(String, u16, TlsStream, String), String> {
+    let count = endpoints.len();
+    let mut last_error = None;
+    for (address, port) in endpoints {
+        match establish_connection(&address, port, proxy, &mut *progress).await {
+            Ok((tls_stream, fingerprint)) => return Ok((address, port, tls_stream, fingerprint)),
+            Err(error) => last_error = Some(error),
+        }
+    }
+
+    let error = last_error.unwrap_or_else(|| t("err-address-required"));
+    if count > 1 {
+        Err(t_args(
+            "err-all-addresses-failed",
+            &[("count", &count.to_string()), ("error", &error)],
+        ))
+    } else {
+        Err(error)
+    }
+}
+
+/// What the server reported in a successful handshake
+struct HandshakeInfo {
+    /// Pre-login banner, if the server has one
+    banner: Option<String>,
+    /// Whether the server speaks the extended protocol
+    extended_protocol: bool,
 }
 
 /// Perform protocol handshake with the server
-async fn perform_handshake(reader: &mut Reader, writer: &mut Writer) -> Result<(), String> {
+async fn perform_handshake(
+    reader: &mut Reader,
+    writer: &mut Writer,
+) -> Result<HandshakeInfo, String> {
     let handshake = ClientMessage::Handshake {
         version: PROTOCOL_VERSION.to_string(),
     };
@@ -75,15 +228,31 @@ async fn perform_handshake(reader: &mut Reader, writer: &mut Writer) -> Result<(
         .ok_or_else(|| t("err-connection-closed"))?;
 
     match received.message {
-        ServerMessage::HandshakeResponse { success: true, .. } => Ok(()),
+        ServerMessage::HandshakeResponse {
+            success: true,
+            version,
+            banner,
+            ..
+        } => Ok(HandshakeInfo {
+            banner: banner.filter(|banner| !banner.trim().is_empty()),
+            extended_protocol: version
+                .and_then(|version| version.parse::<Version>().ok())
+                .is_some_and(|version| supports_extended_protocol(&version)),
+        }),
         ServerMessage::HandshakeResponse {
             success: false,
             error,
+            code,
             ..
-        } => Err(t_args(
-            "err-handshake-failed",
-            &[("error", &error.unwrap_or_default())],
-        )),
+        } => {
+            // Servers can require a newer client; point the user at the fix
+            let key = if code.as_deref() == Some(ERROR_CODE_CLIENT_TOO_OLD) {
+                "err-client-too-old"
+            } else {
+                "err-handshake-failed"
+            };
+            Err(t_args(key, &[("error", &error.unwrap_or_default())]))
+        }
         _ => Err(t("err-unexpected-handshake-response")),
     }
 }
@@ -122,6 +291,11 @@ async fn perform_login(
             server_info,
             chat_info,
             locale,
+            pinned_messages,
+            preferences,
+            rules,
+            maintenance,
+            disabled_permissions,
             ..
         } => Ok(LoginInfo {
             session_id: id,
@@ -138,7 +312,26 @@ async fn perform_login(
                 .unwrap_or_default(),
             chat_topic: chat_info.as_ref().map(|info| info.topic.clone()),
             chat_topic_set_by: chat_info.as_ref().map(|info| info.topic_set_by.clone()),
-            max_connections_per_ip: server_info.and_then(|info| info.max_connections_per_ip),
+            max_connections_per_ip: server_info
+                .as_ref()
+                .and_then(|info| info.max_connections_per_ip),
+            chat_slow_mode: server_info
+                .as_ref()
+                .and_then(|info| info.chat_slow_mode)
+                .unwrap_or_default(),
+            user_count: server_info.as_ref().and_then(|info| info.user_count),
+            max_message_length: server_info
+                .as_ref()
+                .and_then(|info| info.max_message_length)
+                .map_or(MAX_MESSAGE_LENGTH, |len| len as usize),
+            max_topic_length: server_info
+                .and_then(|info| info.max_topic_length)
+                .map_or(MAX_CHAT_TOPIC_LENGTH, |len| len as usize),
+            pinned_messages: pinned_messages.unwrap_or_default(),
+            preferences,
+            rules,
+            maintenance: maintenance.unwrap_or_default(),
+            disabled_permissions: disabled_permissions.unwrap_or_default(),
             locale: locale.unwrap_or_else(|| DEFAULT_LOCALE.to_string()),
         }),
         ServerMessage::LoginResponse {
@@ -10,3 +10,18 @@ pub const STREAM_CHANNEL_SIZE: usize = 100;
 
 /// Default features to request during login
 pub const DEFAULT_FEATURES: &[&str] = &["chat"];
+
+/// Release checked by the update check when no release URL is configured
+pub const DEFAULT_RELEASE_URL: &str = "https://api.github.com/repos/zquestz/nexus/releases/latest";
+
+/// Page linked for a newer release that doesn't give a link of its own
+pub const RELEASES_PAGE_URL: &str = "https://github.com/zquestz/nexus/releases";
+
+/// Time allowed for the whole update check (10 seconds)
+pub const UPDATE_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest release response read by the update check (256 KiB)
+pub const UPDATE_CHECK_MAX_RESPONSE_SIZE: u64 = 256 * 1024;
+
+/// User agent sent by the update check (some release hosts require one)
+pub const UPDATE_CHECK_USER_AGENT: &str = concat!("Nexus-BBS/", env!("CARGO_PKG_VERSION"));
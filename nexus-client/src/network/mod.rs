@@ -4,7 +4,13 @@ mod connect;
 mod constants;
 mod stream;
 mod tls;
+mod traffic;
 mod types;
+mod update;
 
-pub use connect::connect_to_server;
+pub use connect::connect_with_progress;
+pub use constants::DEFAULT_RELEASE_URL;
 pub use stream::{NETWORK_RECEIVERS, ShutdownHandle, network_stream};
+pub use traffic::{TRAFFIC_REFRESH_INTERVAL, TrafficCounters, TrafficTotals, format_bytes};
+pub use types::ProxyConfig;
+pub use update::{UpdateInfo, check_for_update};
@@ -18,7 +18,7 @@ use crate::types::connection::CommandSender;
 use crate::types::{Message, NetworkConnection};
 
 use super::constants::STREAM_CHANNEL_SIZE;
-use super::types::{LoginInfo, Reader, Writer};
+use super::types::{ConnectionDetails, LoginInfo, Reader, Writer};
 
 /// Type alias for the connection registry
 type ConnectionRegistry =
@@ -54,9 +54,17 @@ pub(super) async fn setup_communication_channels(
     reader: Reader,
     writer: Writer,
     login_info: LoginInfo,
-    connection_id: usize,
-    fingerprint: String,
+    details: ConnectionDetails,
 ) -> Result<NetworkConnection, String> {
+    let ConnectionDetails {
+        connection_id,
+        server_address,
+        server_port,
+        fingerprint,
+        traffic,
+        extended_protocol,
+    } = details;
+
     // Create channels for bidirectional communication
     // Command channel includes MessageId for request-response correlation
     let (cmd_tx, cmd_rx): (CommandSender, CommandReceiver) = mpsc::unbounded_channel();
@@ -74,6 +82,8 @@ pub(super) async fn setup_communication_channels(
         session_id: login_info.session_id,
         connection_id,
         shutdown: Some(Arc::new(Mutex::new(Some(ShutdownHandle::new(shutdown_tx))))),
+        server_address,
+        server_port,
         is_admin: login_info.is_admin,
         permissions: login_info.permissions,
         server_name: login_info.server_name,
@@ -83,8 +93,19 @@ pub(super) async fn setup_communication_channels(
         chat_topic: login_info.chat_topic,
         chat_topic_set_by: login_info.chat_topic_set_by,
         max_connections_per_ip: login_info.max_connections_per_ip,
+        chat_slow_mode: login_info.chat_slow_mode,
+        user_count: login_info.user_count,
+        max_message_length: login_info.max_message_length,
+        max_topic_length: login_info.max_topic_length,
+        pinned_messages: login_info.pinned_messages,
+        preferences: login_info.preferences,
+        rules: login_info.rules,
+        maintenance: login_info.maintenance,
+        disabled_permissions: login_info.disabled_permissions,
         certificate_fingerprint: fingerprint,
         locale: login_info.locale,
+        traffic,
+        extended_protocol,
     })
 }
 
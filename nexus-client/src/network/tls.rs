@@ -9,11 +9,14 @@ use tokio::net::TcpStream;
 use tokio_rustls::TlsConnector;
 use tokio_rustls::rustls::ClientConfig;
 use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_socks::tcp::Socks5Stream;
 
 use crate::i18n::{t, t_args};
+use crate::types::ConnectionStep;
 
 use super::constants::CONNECTION_TIMEOUT;
-use super::types::TlsStream;
+use super::traffic::CountingStream;
+use super::types::{ProxyConfig, TlsStream};
 
 /// Global TLS connector (accepts any certificate, no hostname verification)
 pub(super) static TLS_CONNECTOR: Lazy<TlsConnector> = Lazy::new(|| {
@@ -89,10 +92,47 @@ impl tokio_rustls::rustls::client::danger::ServerCertVerifier for NoVerifier {
 }
 
 /// Establish TLS connection to the server and return certificate fingerprint
+///
+/// When `proxy` is given the TCP connection is made through that SOCKS5 proxy,
+/// which also resolves the server address (needed for `.onion` and `.i2p`
+/// names). TLS and the certificate fingerprint are unaffected. Reports the TCP
+/// and TLS steps through `progress` as they begin. Traffic is counted from
+/// the TLS handshake on.
 pub(super) async fn establish_connection(
     address: &str,
     port: u16,
+    proxy: Option<&ProxyConfig>,
+    progress: &mut impl FnMut(ConnectionStep),
 ) -> Result<(TlsStream, String), String> {
+    // Establish TCP connection
+    progress(ConnectionStep::Tcp);
+    let tcp_stream = match proxy {
+        Some(proxy) => connect_through_proxy(address, port, proxy).await?,
+        None => connect_direct(address, port).await?,
+    };
+
+    // Perform TLS handshake (hostname doesn't matter, we accept any cert)
+    progress(ConnectionStep::Tls);
+    let server_name = ServerName::try_from("localhost").map_err(|e| {
+        t_args(
+            "err-failed-create-server-name",
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let tls_stream = TLS_CONNECTOR
+        .connect(server_name, CountingStream::new(tcp_stream))
+        .await
+        .map_err(|e| t_args("err-tls-handshake-failed", &[("error", &e.to_string())]))?;
+
+    // Calculate certificate fingerprint for TOFU verification
+    let fingerprint = calculate_certificate_fingerprint(&tls_stream)?;
+
+    Ok((tls_stream, fingerprint))
+}
+
+/// Open a TCP connection directly to the server
+pub(super) async fn connect_direct(address: &str, port: u16) -> Result<TcpStream, String> {
     // Use to_socket_addrs to support IPv6 zone identifiers (e.g., "fe80::1%eth0")
     let mut addrs = (address, port).to_socket_addrs().map_err(|e| {
         t_args(
@@ -105,34 +145,46 @@ pub(super) async fn establish_connection(
         .next()
         .ok_or_else(|| t_args("err-could-not-resolve", &[("address", address)]))?;
 
-    // Establish TCP connection
-    let tcp_stream = tokio::time::timeout(CONNECTION_TIMEOUT, TcpStream::connect(socket_addr))
+    tokio::time::timeout(CONNECTION_TIMEOUT, TcpStream::connect(socket_addr))
         .await
-        .map_err(|_| {
-            t_args(
-                "err-connection-timeout",
-                &[("seconds", &CONNECTION_TIMEOUT.as_secs().to_string())],
-            )
-        })?
-        .map_err(|e| t_args("err-connection-failed", &[("error", &e.to_string())]))?;
+        .map_err(|_| connection_timeout_error())?
+        .map_err(|e| t_args("err-connection-failed", &[("error", &e.to_string())]))
+}
 
-    // Perform TLS handshake (hostname doesn't matter, we accept any cert)
-    let server_name = ServerName::try_from("localhost").map_err(|e| {
+/// Open a TCP connection to the server through a SOCKS5 proxy
+///
+/// The server address is passed to the proxy unresolved so hidden-service
+/// names work and no DNS lookup leaks outside the proxy.
+pub(super) async fn connect_through_proxy(
+    address: &str,
+    port: u16,
+    proxy: &ProxyConfig,
+) -> Result<TcpStream, String> {
+    let stream = tokio::time::timeout(
+        CONNECTION_TIMEOUT,
+        Socks5Stream::connect((proxy.address.as_str(), proxy.port), (address, port)),
+    )
+    .await
+    .map_err(|_| connection_timeout_error())?
+    .map_err(|e| {
         t_args(
-            "err-failed-create-server-name",
-            &[("error", &e.to_string())],
+            "err-proxy-connection-failed",
+            &[
+                ("proxy", &format!("{}:{}", proxy.address, proxy.port)),
+                ("error", &e.to_string()),
+            ],
         )
     })?;
 
-    let tls_stream = TLS_CONNECTOR
-        .connect(server_name, tcp_stream)
-        .await
-        .map_err(|e| t_args("err-tls-handshake-failed", &[("error", &e.to_string())]))?;
-
-    // Calculate certificate fingerprint for TOFU verification
-    let fingerprint = calculate_certificate_fingerprint(&tls_stream)?;
+    Ok(stream.into_inner())
+}
 
-    Ok((tls_stream, fingerprint))
+/// Error shown when the TCP connection doesn't complete in time
+fn connection_timeout_error() -> String {
+    t_args(
+        "err-connection-timeout",
+        &[("seconds", &CONNECTION_TIMEOUT.as_secs().to_string())],
+    )
 }
 
 /// Calculate SHA-256 fingerprint of the server's certificate
@@ -0,0 +1,168 @@
+//! Per-connection traffic accounting
+//!
+//! The TCP stream under TLS is wrapped in a `CountingStream`, so the totals
+//! are bytes on the wire, handshake and TLS overhead included. That is what
+//! metered and overlay networks (Tor, Yggdrasil) charge for. Counters are
+//! created with each connection, so reconnecting starts again from zero.
+
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// How often displayed traffic totals are refreshed while they are shown
+pub const TRAFFIC_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Bytes sent and received over one connection, updated by its stream
+#[derive(Debug, Default)]
+pub struct TrafficCounters {
+    sent: AtomicU64,
+    received: AtomicU64,
+}
+
+impl TrafficCounters {
+    /// Current totals
+    pub fn totals(&self) -> TrafficTotals {
+        TrafficTotals {
+            sent: self.sent.load(Ordering::Relaxed),
+            received: self.received.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Snapshot of a connection's traffic
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrafficTotals {
+    /// Bytes written to the server
+    pub sent: u64,
+    /// Bytes read from the server
+    pub received: u64,
+}
+
+/// Stream wrapper that counts the bytes passing through it
+#[derive(Debug)]
+pub struct CountingStream<S> {
+    inner: S,
+    counters: Arc<TrafficCounters>,
+}
+
+impl<S> CountingStream<S> {
+    /// Wrap `inner` with fresh counters
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            counters: Arc::new(TrafficCounters::default()),
+        }
+    }
+
+    /// Counters shared with the UI
+    pub fn counters(&self) -> Arc<TrafficCounters> {
+        Arc::clone(&self.counters)
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for CountingStream<S> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before = buf.filled().len();
+        let result = Pin::new(&mut self.inner).poll_read(cx, buf);
+        let read = (buf.filled().len() - before) as u64;
+        if read > 0 {
+            self.counters.received.fetch_add(read, Ordering::Relaxed);
+        }
+        result
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for CountingStream<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let result = Pin::new(&mut self.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(written)) = result {
+            self.counters
+                .sent
+                .fetch_add(written as u64, Ordering::Relaxed);
+        }
+        result
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+/// Format a byte count for display, e.g. "512 B" or "1.5 MiB"
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn test_counts_both_directions() {
+        let (client, mut server) = tokio::io::duplex(64);
+        let mut stream = CountingStream::new(client);
+        let counters = stream.counters();
+
+        stream.write_all(b"hello").await.unwrap();
+        let mut buf = [0u8; 5];
+        server.read_exact(&mut buf).await.unwrap();
+
+        server.write_all(b"hi there").await.unwrap();
+        let mut buf = [0u8; 8];
+        stream.read_exact(&mut buf).await.unwrap();
+
+        assert_eq!(
+            counters.totals(),
+            TrafficTotals {
+                sent: 5,
+                received: 8
+            }
+        );
+    }
+
+    #[test]
+    fn test_new_stream_starts_at_zero() {
+        let stream = CountingStream::new(tokio::io::empty());
+        assert_eq!(stream.counters().totals(), TrafficTotals::default());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.0 KiB");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GiB");
+        assert_eq!(format_bytes(u64::MAX), "16777216.0 TiB");
+    }
+}
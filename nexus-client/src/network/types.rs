@@ -1,12 +1,17 @@
 //! Network module type aliases and internal types
 
+use std::sync::Arc;
+
 use tokio::io::BufReader;
 use tokio::net::TcpStream;
 
 use nexus_common::framing::{FrameReader, FrameWriter};
+use nexus_common::protocol::PinnedMessage;
+
+use super::traffic::{CountingStream, TrafficCounters};
 
-/// Type alias for TLS stream
-pub type TlsStream = tokio_rustls::client::TlsStream<TcpStream>;
+/// Type alias for TLS stream (over a TCP stream that counts traffic)
+pub type TlsStream = tokio_rustls::client::TlsStream<CountingStream<TcpStream>>;
 
 /// Type alias for TLS stream read half with buffering and framing
 pub type Reader = FrameReader<BufReader<tokio::io::ReadHalf<TlsStream>>>;
@@ -14,6 +19,26 @@ pub type Reader = FrameReader<BufReader<tokio::io::ReadHalf<TlsStream>>>;
 /// Type alias for TLS stream write half with framing
 pub type Writer = FrameWriter<tokio::io::WriteHalf<TlsStream>>;
 
+/// SOCKS5 proxy to route the TCP connection through before TLS
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    /// Proxy host name or IP address
+    pub address: String,
+    /// Proxy port
+    pub port: u16,
+}
+
+/// Where a connection was made, alongside what login returned
+pub struct ConnectionDetails {
+    pub connection_id: usize,
+    pub server_address: String,
+    pub server_port: u16,
+    pub fingerprint: String,
+    pub traffic: Arc<TrafficCounters>,
+    /// Whether the server speaks the extended protocol (see `EXTENDED_PROTOCOL_VERSION`)
+    pub extended_protocol: bool,
+}
+
 /// Login information returned from the server
 pub struct LoginInfo {
     pub session_id: u32,
@@ -26,5 +51,14 @@ pub struct LoginInfo {
     pub chat_topic: Option<String>,
     pub chat_topic_set_by: Option<String>,
     pub max_connections_per_ip: Option<u32>,
+    pub chat_slow_mode: u32,
+    pub user_count: Option<u32>,
+    pub max_message_length: usize,
+    pub max_topic_length: usize,
+    pub pinned_messages: Vec<PinnedMessage>,
+    pub preferences: Option<serde_json::Value>,
+    pub rules: Option<String>,
+    pub maintenance: bool,
+    pub disabled_permissions: Vec<String>,
     pub locale: String,
 }
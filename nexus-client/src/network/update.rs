@@ -0,0 +1,277 @@
+//! Opt-in check for a newer client release
+//!
+//! Fetches a small JSON release description over HTTPS and compares its
+//! version with this build. Nothing is ever downloaded or run; a newer release
+//! is only reported so the user can follow its link. Any failure along the
+//! way (network, TLS, HTTP status, malformed body) just means no update is
+//! reported.
+
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+
+use nexus_common::validators;
+use nexus_common::version::Version;
+
+use super::constants::{
+    RELEASES_PAGE_URL, UPDATE_CHECK_MAX_RESPONSE_SIZE, UPDATE_CHECK_TIMEOUT,
+    UPDATE_CHECK_USER_AGENT,
+};
+use super::tls::{connect_direct, connect_through_proxy};
+use super::types::ProxyConfig;
+
+/// Default HTTPS port for release URLs without one
+const HTTPS_PORT: u16 = 443;
+
+/// TLS connector for release checks
+///
+/// Unlike BBS servers (pinned on first use), release hosts are public sites,
+/// so certificates and host names are verified against the bundled web roots.
+static VERIFIED_TLS_CONNECTOR: Lazy<TlsConnector> = Lazy::new(|| {
+    let mut roots = RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let config = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    TlsConnector::from(Arc::new(config))
+});
+
+/// A client release newer than this build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateInfo {
+    /// Version of the release (without any leading `v`)
+    pub version: String,
+    /// Page to open for the release
+    pub url: String,
+}
+
+/// Release description returned by the release URL
+///
+/// Accepts both a plain `{"version", "url"}` document and the fields of a
+/// GitHub "latest release" response.
+#[derive(Deserialize)]
+struct ReleaseDocument {
+    #[serde(alias = "tag_name")]
+    version: String,
+    #[serde(default, alias = "html_url")]
+    url: Option<String>,
+}
+
+/// Check `release_url` for a client newer than this build
+///
+/// Returns `None` when the check fails for any reason or the release isn't
+/// newer. The request goes through `proxy` when one is set, like server
+/// connections.
+pub async fn check_for_update(
+    release_url: String,
+    proxy: Option<ProxyConfig>,
+) -> Option<UpdateInfo> {
+    let current = Version::parse(env!("CARGO_PKG_VERSION")).ok()?;
+    let body = tokio::time::timeout(
+        UPDATE_CHECK_TIMEOUT,
+        fetch_release(&release_url, proxy.as_ref()),
+    )
+    .await
+    .ok()??;
+    newer_release(&body, &current)
+}
+
+/// GET `release_url` and return the body of a successful response
+async fn fetch_release(release_url: &str, proxy: Option<&ProxyConfig>) -> Option<Vec<u8>> {
+    let (host, port, path) = parse_release_url(release_url)?;
+
+    let tcp_stream = match proxy {
+        Some(proxy) => connect_through_proxy(host, port, proxy).await.ok()?,
+        None => connect_direct(host, port).await.ok()?,
+    };
+    let server_name = ServerName::try_from(host.to_string()).ok()?;
+    let mut stream = VERIFIED_TLS_CONNECTOR
+        .connect(server_name, tcp_stream)
+        .await
+        .ok()?;
+
+    // HTTP/1.0 keeps the body unchunked and the connection closed after it
+    let request = format!(
+        "GET {path} HTTP/1.0\r\nHost: {host}\r\nUser-Agent: {UPDATE_CHECK_USER_AGENT}\r\nAccept: application/json\r\nConnection: close\r\n\r\n"
+    );
+    stream.write_all(request.as_bytes()).await.ok()?;
+
+    let mut response = Vec::new();
+    (&mut stream)
+        .take(UPDATE_CHECK_MAX_RESPONSE_SIZE)
+        .read_to_end(&mut response)
+        .await
+        .ok()?;
+
+    response_body(&response).map(<[u8]>::to_vec)
+}
+
+/// Split an `https://host[:port][/path]` URL into its parts
+///
+/// Plain `http://` is refused so the release can't be altered in transit.
+fn parse_release_url(url: &str) -> Option<(&str, u16, String)> {
+    validators::validate_notice_url(url).ok()?;
+    let scheme_len = "https://".len();
+    if !url.get(..scheme_len)?.eq_ignore_ascii_case("https://") {
+        return None;
+    }
+    let rest = &url[scheme_len..];
+
+    let rest = rest.split('#').next().unwrap_or_default();
+    let (authority, path) = match rest.find(['/', '?']) {
+        Some(index) if rest[index..].starts_with('/') => {
+            (&rest[..index], rest[index..].to_string())
+        }
+        Some(index) => (&rest[..index], format!("/{}", &rest[index..])),
+        None => (rest, "/".to_string()),
+    };
+
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, port.parse().ok()?),
+        None => (authority, HTTPS_PORT),
+    };
+    if host.is_empty() || host.contains(['@', ':', '[', ']']) {
+        return None;
+    }
+    Some((host, port, path))
+}
+
+/// Body of a `200` HTTP response, or `None` for any other response
+fn response_body(response: &[u8]) -> Option<&[u8]> {
+    let header_end = response.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let status_line = response[..header_end].split(|&b| b == b'\r').next()?;
+    let status = std::str::from_utf8(status_line).ok()?.split(' ').nth(1)?;
+    (status == "200").then(|| &response[header_end + 4..])
+}
+
+/// Parse a release document and report it if it's newer than `current`
+///
+/// A missing or unusable release link falls back to the project's releases
+/// page, so the banner only ever opens an `http(s)` page.
+fn newer_release(body: &[u8], current: &Version) -> Option<UpdateInfo> {
+    let document: ReleaseDocument = serde_json::from_slice(body).ok()?;
+    let version_text = document.version.trim();
+    let version_text = version_text
+        .strip_prefix(['v', 'V'])
+        .unwrap_or(version_text);
+    let version = Version::parse(version_text).ok()?;
+    if version <= *current {
+        return None;
+    }
+
+    let url = document
+        .url
+        .filter(|url| validators::validate_notice_url(url).is_ok())
+        .unwrap_or_else(|| RELEASES_PAGE_URL.to_string());
+    Some(UpdateInfo {
+        version: version.to_string(),
+        url,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current() -> Version {
+        Version::parse("0.5.0").unwrap()
+    }
+
+    #[test]
+    fn test_parse_release_url() {
+        assert_eq!(
+            parse_release_url("https://api.github.com/repos/zquestz/nexus/releases/latest"),
+            Some((
+                "api.github.com",
+                443,
+                "/repos/zquestz/nexus/releases/latest".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_release_url("HTTPS://example.com:8443?channel=stable"),
+            Some(("example.com", 8443, "/?channel=stable".to_string()))
+        );
+        assert_eq!(
+            parse_release_url("https://example.com/latest.json#top"),
+            Some(("example.com", 443, "/latest.json".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_release_url_rejects() {
+        assert_eq!(parse_release_url("http://example.com/latest.json"), None);
+        assert_eq!(parse_release_url("https://example.com:port/"), None);
+        assert_eq!(parse_release_url("https://user@example.com/"), None);
+        assert_eq!(parse_release_url("https://[::1]/"), None);
+        assert_eq!(parse_release_url("ftp://example.com"), None);
+    }
+
+    #[test]
+    fn test_response_body() {
+        let response = b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{}";
+        assert_eq!(response_body(response), Some(&b"{}"[..]));
+
+        let not_found = b"HTTP/1.1 404 Not Found\r\n\r\n{}";
+        assert_eq!(response_body(not_found), None);
+        assert_eq!(response_body(b"HTTP/1.1 200 OK\r\n"), None);
+    }
+
+    #[test]
+    fn test_newer_release() {
+        let body = br#"{"version":"0.6.0","url":"https://example.com/nexus"}"#;
+        assert_eq!(
+            newer_release(body, &current()),
+            Some(UpdateInfo {
+                version: "0.6.0".to_string(),
+                url: "https://example.com/nexus".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_newer_release_github_format() {
+        let body = br#"{"tag_name":"v0.5.1","html_url":"https://github.com/zquestz/nexus/releases/tag/v0.5.1","assets":[]}"#;
+        let update = newer_release(body, &current()).unwrap();
+        assert_eq!(update.version, "0.5.1");
+        assert_eq!(
+            update.url,
+            "https://github.com/zquestz/nexus/releases/tag/v0.5.1"
+        );
+    }
+
+    #[test]
+    fn test_same_or_older_release_ignored() {
+        assert_eq!(newer_release(br#"{"version":"0.5.0"}"#, &current()), None);
+        assert_eq!(newer_release(br#"{"version":"0.4.9"}"#, &current()), None);
+        assert_eq!(
+            newer_release(br#"{"version":"0.5.0-rc.1"}"#, &current()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unusable_link_falls_back() {
+        let body = br#"{"version":"1.0.0","url":"javascript:alert(1)"}"#;
+        assert_eq!(
+            newer_release(body, &current()).unwrap().url,
+            RELEASES_PAGE_URL
+        );
+        let body = br#"{"version":"1.0.0"}"#;
+        assert_eq!(
+            newer_release(body, &current()).unwrap().url,
+            RELEASES_PAGE_URL
+        );
+    }
+
+    #[test]
+    fn test_malformed_release_ignored() {
+        assert_eq!(newer_release(b"not json", &current()), None);
+        assert_eq!(newer_release(br#"{"version":"latest"}"#, &current()), None);
+        assert_eq!(newer_release(br#"{"url":"https://x.y"}"#, &current()), None);
+    }
+}
@@ -82,6 +82,13 @@ pub fn system(theme: &Theme) -> Color {
     for_theme(theme, SYSTEM_LIGHT, SYSTEM_DARK)
 }
 
+/// Mention color (`@username` in chat)
+///
+/// Uses the theme's warning color so mentions stand out from links.
+pub fn mention(theme: &Theme) -> Color {
+    theme.palette().warning
+}
+
 /// Info message color ([INFO])
 ///
 /// Uses the theme's primary color for informational messages and command responses.
@@ -95,6 +95,9 @@ pub const SPACER_SIZE_MEDIUM: f32 = 10.0;
 /// Small vertical spacer (between related items)
 pub const SPACER_SIZE_SMALL: f32 = 5.0;
 
+/// Height of the connection progress bar
+pub const CONNECTION_PROGRESS_HEIGHT: f32 = 4.0;
+
 /// Toolbar spacing between sections
 pub const TOOLBAR_SPACING: f32 = 20.0;
 
@@ -113,11 +116,49 @@ pub const SERVER_LIST_ITEM_SPACING: f32 = 3.0;
 /// No spacing between button and icon (flush)
 pub const NO_SPACING: f32 = 0.0;
 
-/// User list item spacing
-pub const USER_LIST_ITEM_SPACING: f32 = 3.0;
+// ============================================================================
+// Density
+// ============================================================================
+
+/// Spacing and padding that change with the interface density setting
+///
+/// Icon buttons and list button heights are not part of this, so touch
+/// targets stay the same size in compact mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Density {
+    /// Spacing between chat messages and between lines of one message
+    pub chat_spacing: f32,
+    /// Padding around the chat message list
+    pub chat_padding: f32,
+    /// Spacing between server list and user list rows
+    pub list_item_spacing: f32,
+    /// Spacing between a sidebar section title and its rows
+    pub list_section_spacing: f32,
+    /// Padding around sidebar sections
+    pub list_padding: f32,
+    /// Padding inside user list rows
+    pub list_item_padding: f32,
+}
+
+/// Default density
+pub const COMFORTABLE_DENSITY: Density = Density {
+    chat_spacing: CHAT_SPACING,
+    chat_padding: INPUT_PADDING,
+    list_item_spacing: SERVER_LIST_ITEM_SPACING,
+    list_section_spacing: SERVER_LIST_SECTION_SPACING,
+    list_padding: FORM_PADDING,
+    list_item_padding: INPUT_PADDING,
+};
 
-/// User list column spacing (matches server list section spacing)
-pub const USER_LIST_SPACING: f32 = 5.0;
+/// Compact density (fits more messages and users on screen)
+pub const COMPACT_DENSITY: Density = Density {
+    chat_spacing: 0.0,
+    chat_padding: 4.0,
+    list_item_spacing: 0.0,
+    list_section_spacing: 3.0,
+    list_padding: 10.0,
+    list_item_padding: 4.0,
+};
 
 // ============================================================================
 // Dimensions
@@ -144,6 +185,9 @@ pub const BORDER_WIDTH: f32 = 1.0;
 /// User list panel width
 pub const USER_LIST_PANEL_WIDTH: f32 = 180.0;
 
+/// Width of the quiet hours time inputs in settings panel
+pub const QUIET_HOURS_INPUT_WIDTH: f32 = 70.0;
+
 /// Avatar preview size in settings panel
 pub const AVATAR_PREVIEW_SIZE: f32 = 48.0;
 
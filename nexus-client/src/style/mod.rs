@@ -49,9 +49,10 @@ pub use layout::*;
 pub use shaping::{shaped_text, shaped_text_wrapped};
 pub use widgets::{
     alternating_row_style, chat_tab_active_style, close_button_on_primary_style,
-    content_background_style, danger_icon_button_style, disabled_icon_button_style,
-    error_text_style, icon_button_with_hover_style, list_item_button_style, modal_overlay_style,
-    muted_text_style, separator_style, sidebar_panel_style, subheading_text_style,
+    connection_health_style, content_background_style, danger_icon_button_style,
+    disabled_icon_button_style, error_text_style, icon_button_with_hover_style,
+    list_item_button_style, modal_overlay_style, muted_text_style, pinned_messages_style,
+    role_badge_style, separator_style, sidebar_panel_style, subheading_text_style,
     toolbar_background_style, toolbar_button_style, tooltip_container_style,
     transparent_icon_button_style, user_list_item_button_style, user_toolbar_separator_style,
 };
@@ -101,6 +101,15 @@ pub fn icon_disabled_color(theme: &Theme) -> Color {
     adjust_brightness(text, 0.6, !is_dark)
 }
 
+// ============================================================================
+// Role Colors
+// ============================================================================
+
+/// Moderator badge color (kept apart from the red admin color)
+pub fn moderator_color(theme: &Theme) -> Color {
+    theme.palette().success
+}
+
 // ============================================================================
 // Danger Colors
 // ============================================================================
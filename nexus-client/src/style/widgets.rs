@@ -4,7 +4,8 @@
 //! All styles derive from Iced's theme palette for compatibility with
 //! all 30 themes (22 built-in Iced + 8 custom Celestial).
 
-use super::ui;
+use super::{chat, ui};
+use crate::types::ConnectionHealth;
 use iced::widget::{button, container, text};
 use iced::{Background, Border, Color, Theme};
 
@@ -199,6 +200,19 @@ pub fn user_toolbar_separator_style(theme: &Theme) -> container::Style {
     }
 }
 
+/// Pinned messages header style (alternate row background with border)
+pub fn pinned_messages_style(theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(ui::alt_row_color(theme))),
+        border: Border {
+            color: ui::sidebar_border(theme),
+            width: 1.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
 /// Separator line style
 pub fn separator_style(theme: &Theme) -> container::Style {
     container::Style {
@@ -244,6 +258,32 @@ pub fn error_text_style(theme: &Theme) -> text::Style {
     }
 }
 
+/// User list role badge style - admin red or moderator color
+pub fn role_badge_style(is_admin: bool) -> impl Fn(&Theme) -> text::Style {
+    move |theme| text::Style {
+        color: Some(if is_admin {
+            chat::admin(theme)
+        } else {
+            ui::moderator_color(theme)
+        }),
+    }
+}
+
+/// Connection health indicator style - success, warning or danger by rating
+pub fn connection_health_style(health: ConnectionHealth) -> impl Fn(&Theme) -> text::Style {
+    move |theme| {
+        let palette = theme.palette();
+        text::Style {
+            color: Some(match health {
+                ConnectionHealth::Unknown => ui::muted_text_color(theme),
+                ConnectionHealth::Good => palette.success,
+                ConnectionHealth::Fair => palette.warning,
+                ConnectionHealth::Poor => palette.danger,
+            }),
+        }
+    }
+}
+
 /// Muted text style - for section titles and secondary info
 pub fn muted_text_style(theme: &Theme) -> text::Style {
     text::Style {
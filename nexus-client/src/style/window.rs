@@ -18,5 +18,17 @@ pub const WINDOW_WIDTH_MIN: f32 = 800.0;
 /// Minimum window height
 pub const WINDOW_HEIGHT_MIN: f32 = 500.0;
 
-/// Window title (static, not localized - Iced requires &'static str or closure)
+/// Default width of a popped-out message tab window
+pub const POPOUT_WINDOW_WIDTH: f32 = 480.0;
+
+/// Default height of a popped-out message tab window
+pub const POPOUT_WINDOW_HEIGHT: f32 = 600.0;
+
+/// Minimum width of a popped-out message tab window
+pub const POPOUT_WINDOW_WIDTH_MIN: f32 = 320.0;
+
+/// Minimum height of a popped-out message tab window
+pub const POPOUT_WINDOW_HEIGHT_MIN: f32 = 240.0;
+
+/// Main window title (not localized, matching the product name)
 pub const WINDOW_TITLE: &str = "Nexus BBS";
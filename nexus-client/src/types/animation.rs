@@ -0,0 +1,54 @@
+//! Shared frame counter for "working" animations
+//!
+//! Spinners for transient states (a connection attempt in progress, a
+//! bookmark connecting in the background) all draw from one frame counter,
+//! advanced by the shared clock (see [`super::clock`]) instead of a timer per
+//! widget. The clock only ticks this fast while `NexusApp::is_animating`
+//! reports something to animate.
+
+use std::time::Duration;
+
+/// Time between animation frames (4 frames per second keeps redraws cheap)
+pub const ANIMATION_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Spinner frames: a dot bouncing across three positions
+const SPINNER_FRAMES: [&str; 4] = ["•··", "·•·", "··•", "·•·"];
+
+/// Current frame of the shared animation ticker
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnimationFrame(usize);
+
+impl AnimationFrame {
+    /// Move to the next frame
+    pub fn advance(&mut self) {
+        self.0 = (self.0 + 1) % SPINNER_FRAMES.len();
+    }
+
+    /// Spinner text for this frame
+    pub fn spinner(self) -> &'static str {
+        SPINNER_FRAMES[self.0]
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spinner_cycles_through_frames() {
+        let mut frame = AnimationFrame::default();
+        let mut seen = Vec::new();
+        for _ in 0..SPINNER_FRAMES.len() {
+            seen.push(frame.spinner());
+            frame.advance();
+        }
+        assert_eq!(seen, SPINNER_FRAMES);
+
+        // Wraps back to the first frame
+        assert_eq!(frame, AnimationFrame::default());
+    }
+}
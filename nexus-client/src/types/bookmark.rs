@@ -1,7 +1,11 @@
 //! Server bookmark types
 
+use std::net::Ipv6Addr;
+
 use nexus_common::DEFAULT_PORT_STR;
 
+use crate::config::theme::ThemePreference;
+
 /// Server bookmark configuration
 ///
 /// Stores connection details for a server that can be saved and reused.
@@ -14,6 +18,12 @@ pub struct ServerBookmark {
     pub address: String,
     /// Server port number
     pub port: String,
+    /// Fallback endpoints tried in order when `address` can't be reached
+    ///
+    /// Each entry is `host`, `host:port`, or `[ipv6]:port`; without a port the
+    /// bookmark's port is used. Older configs without the field load as empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub addresses: Vec<String>,
     /// Optional username for quick connect
     pub username: String,
     /// Optional password for quick connect
@@ -24,6 +34,28 @@ pub struct ServerBookmark {
     /// Certificate fingerprint (SHA-256) for Trust On First Use
     #[serde(default)]
     pub certificate_fingerprint: Option<String>,
+    /// Accept certificate changes without asking, replacing the pinned fingerprint
+    ///
+    /// Opt-in for servers that rotate certificates often. This gives up the
+    /// protection pinning offers, so a changed certificate is only noted in chat.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub trust_certificate_changes: bool,
+    /// Pre-login banner the user last accepted (shown again when it changes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepted_banner: Option<String>,
+    /// Theme override for this server (None = use the global theme)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemePreference>,
+    /// Show user connect/disconnect messages in chat for this server
+    /// (None = use the global setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_notifications: Option<bool>,
+    /// Usernames to notify about when they come online on this server
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub watched_users: Vec<String>,
+    /// Chat topic last shown for this server (None if it had no topic)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen_topic: Option<String>,
 }
 
 impl Default for ServerBookmark {
@@ -32,14 +64,117 @@ impl Default for ServerBookmark {
             name: String::new(),
             address: String::new(),
             port: DEFAULT_PORT_STR.to_string(),
+            addresses: Vec::new(),
             username: String::new(),
             password: String::new(),
             auto_connect: false,
             certificate_fingerprint: None,
+            trust_certificate_changes: false,
+            accepted_banner: None,
+            theme: None,
+            connection_notifications: None,
+            watched_users: Vec::new(),
+            last_seen_topic: None,
         }
     }
 }
 
+impl ServerBookmark {
+    /// Record `topic` as the last seen topic
+    ///
+    /// Returns whether it differs from the topic recorded before.
+    pub fn see_topic(&mut self, topic: Option<&str>) -> bool {
+        if self.last_seen_topic.as_deref() == topic {
+            return false;
+        }
+        self.last_seen_topic = topic.map(str::to_string);
+        true
+    }
+
+    /// Whether `username` is on the watch list (usernames are case-insensitive)
+    pub fn is_watching(&self, username: &str) -> bool {
+        self.watched_users
+            .iter()
+            .any(|watched| watched.eq_ignore_ascii_case(username))
+    }
+
+    /// Add `username` to the watch list
+    ///
+    /// Returns false if it was already there.
+    pub fn watch_user(&mut self, username: &str) -> bool {
+        if self.is_watching(username) {
+            return false;
+        }
+        self.watched_users.push(username.to_string());
+        true
+    }
+
+    /// Remove `username` from the watch list
+    ///
+    /// Returns false if it wasn't there.
+    pub fn unwatch_user(&mut self, username: &str) -> bool {
+        let before = self.watched_users.len();
+        self.watched_users
+            .retain(|watched| !watched.eq_ignore_ascii_case(username));
+        self.watched_users.len() != before
+    }
+
+    /// Endpoints to try in order: the primary address, then each fallback
+    ///
+    /// Blank fallback entries are skipped. Returns the first entry that
+    /// doesn't parse as an error.
+    pub fn endpoints(&self) -> Result<Vec<(String, u16)>, String> {
+        let port: u16 = self.port.trim().parse().map_err(|_| self.port.clone())?;
+        let mut endpoints = vec![(self.address.trim().to_string(), port)];
+        for entry in self.addresses.iter().map(|entry| entry.trim()) {
+            if entry.is_empty() {
+                continue;
+            }
+            endpoints.push(parse_endpoint(entry, port).ok_or_else(|| entry.to_string())?);
+        }
+        Ok(endpoints)
+    }
+}
+
+/// Parse `host`, `host:port`, `[ipv6]`, or `[ipv6]:port`
+///
+/// A bare IPv6 address (more than one colon, no brackets) is taken whole.
+/// Entries without a port use `default_port`.
+fn parse_endpoint(entry: &str, default_port: u16) -> Option<(String, u16)> {
+    let parse_port = |port: &str| port.parse::<u16>().ok().filter(|port| *port != 0);
+
+    let (host, port) = if let Some(bracketed) = entry.strip_prefix('[') {
+        let (host, after) = bracketed.split_once(']')?;
+        host.parse::<Ipv6Addr>().ok()?;
+        let port = match after {
+            "" => default_port,
+            after => parse_port(after.strip_prefix(':')?)?,
+        };
+        (host, port)
+    } else if entry.matches(':').count() > 1 {
+        (entry, default_port)
+    } else {
+        match entry.split_once(':') {
+            Some((host, port)) => (host, parse_port(port)?),
+            None => (entry, default_port),
+        }
+    };
+
+    if host.is_empty() || host.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((host.to_string(), port))
+}
+
+/// Format an endpoint as `host:port`, bracketing IPv6 addresses
+pub fn format_endpoint(address: &str, port: u16) -> String {
+    if address.contains(':') {
+        format!("[{}]:{}", address, port)
+    } else {
+        format!("{}:{}", address, port)
+    }
+}
+
 /// State for bookmark editing dialog
 ///
 /// Wraps a ServerBookmark with an editing mode to track whether
@@ -74,3 +209,162 @@ pub enum BookmarkEditMode {
     /// Editing bookmark at this index
     Edit(usize),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_endpoint() {
+        assert_eq!(
+            parse_endpoint("example.com", 7500),
+            Some(("example.com".to_string(), 7500))
+        );
+        assert_eq!(
+            parse_endpoint("example.com:7600", 7500),
+            Some(("example.com".to_string(), 7600))
+        );
+        assert_eq!(
+            parse_endpoint("[200:1234::1]:7600", 7500),
+            Some(("200:1234::1".to_string(), 7600))
+        );
+        assert_eq!(
+            parse_endpoint("[::1]", 7500),
+            Some(("::1".to_string(), 7500))
+        );
+        assert_eq!(
+            parse_endpoint("fe80::1%eth0", 7500),
+            Some(("fe80::1%eth0".to_string(), 7500))
+        );
+    }
+
+    #[test]
+    fn test_parse_endpoint_invalid() {
+        assert_eq!(parse_endpoint("example.com:0", 7500), None);
+        assert_eq!(parse_endpoint("example.com:port", 7500), None);
+        assert_eq!(parse_endpoint("[not-ipv6]:7500", 7500), None);
+        assert_eq!(parse_endpoint("[::1]7500", 7500), None);
+        assert_eq!(parse_endpoint(":7500", 7500), None);
+        assert_eq!(parse_endpoint("two words", 7500), None);
+    }
+
+    #[test]
+    fn test_endpoints_primary_first() {
+        let bookmark = ServerBookmark {
+            address: "example.com".to_string(),
+            port: "7500".to_string(),
+            addresses: vec![
+                "abc.onion".to_string(),
+                " ".to_string(),
+                "[200::1]:7600".to_string(),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            bookmark.endpoints(),
+            Ok(vec![
+                ("example.com".to_string(), 7500),
+                ("abc.onion".to_string(), 7500),
+                ("200::1".to_string(), 7600),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_endpoints_reports_bad_entry() {
+        let bookmark = ServerBookmark {
+            address: "example.com".to_string(),
+            addresses: vec!["backup:99999".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(bookmark.endpoints(), Err("backup:99999".to_string()));
+    }
+
+    #[test]
+    fn test_connection_notifications_override() {
+        let bookmark: ServerBookmark = serde_json::from_str(
+            r#"{"name":"a","address":"b","port":"7500","username":"","password":""}"#,
+        )
+        .unwrap();
+        assert_eq!(bookmark.connection_notifications, None);
+        let json = serde_json::to_string(&bookmark).unwrap();
+        assert!(!json.contains("connection_notifications"));
+
+        let bookmark = ServerBookmark {
+            connection_notifications: Some(false),
+            ..bookmark
+        };
+        let json = serde_json::to_string(&bookmark).unwrap();
+        assert!(json.contains(r#""connection_notifications":false"#));
+    }
+
+    #[test]
+    fn test_see_topic() {
+        let mut bookmark = ServerBookmark::default();
+
+        assert!(bookmark.see_topic(Some("Welcome")));
+        assert!(!bookmark.see_topic(Some("Welcome")));
+        assert!(bookmark.see_topic(Some("Release day")));
+        assert_eq!(bookmark.last_seen_topic.as_deref(), Some("Release day"));
+
+        // Clearing the topic is a change, and the next topic shows again
+        assert!(bookmark.see_topic(None));
+        assert!(!bookmark.see_topic(None));
+        assert!(bookmark.see_topic(Some("Release day")));
+    }
+
+    #[test]
+    fn test_watch_list() {
+        let mut bookmark = ServerBookmark::default();
+        assert!(!bookmark.is_watching("alice"));
+
+        assert!(bookmark.watch_user("alice"));
+        assert!(!bookmark.watch_user("Alice"));
+        assert!(bookmark.is_watching("ALICE"));
+        assert_eq!(bookmark.watched_users, vec!["alice".to_string()]);
+
+        assert!(!bookmark.unwatch_user("bob"));
+        assert!(bookmark.unwatch_user("Alice"));
+        assert!(bookmark.watched_users.is_empty());
+
+        // An empty list isn't written out
+        let json = serde_json::to_string(&bookmark).unwrap();
+        assert!(!json.contains("watched_users"));
+    }
+
+    #[test]
+    fn test_old_config_has_no_fallbacks() {
+        let json =
+            r#"{"name":"Old","address":"example.com","port":"7500","username":"","password":""}"#;
+        let bookmark: ServerBookmark = serde_json::from_str(json).unwrap();
+
+        assert!(bookmark.addresses.is_empty());
+        assert!(
+            !serde_json::to_string(&bookmark)
+                .unwrap()
+                .contains("addresses")
+        );
+    }
+
+    #[test]
+    fn test_old_config_checks_certificate_changes() {
+        let json =
+            r#"{"name":"Old","address":"example.com","port":"7500","username":"","password":""}"#;
+        let bookmark: ServerBookmark = serde_json::from_str(json).unwrap();
+
+        assert!(!bookmark.trust_certificate_changes);
+        assert!(
+            !serde_json::to_string(&bookmark)
+                .unwrap()
+                .contains("trust_certificate_changes")
+        );
+    }
+
+    #[test]
+    fn test_format_endpoint() {
+        assert_eq!(format_endpoint("example.com", 7500), "example.com:7500");
+        assert_eq!(format_endpoint("200::1", 7500), "[200::1]:7500");
+    }
+}
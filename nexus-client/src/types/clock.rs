@@ -0,0 +1,138 @@
+//! Shared clock for periodic housekeeping and animation
+//!
+//! Refreshing traffic totals, counting down slow mode, checking for idle
+//! time and advancing spinners all run off a single ticker instead of a timer
+//! each. The ticker runs once a second, or every [`ANIMATION_INTERVAL`] while
+//! something is animating. Every tick adds the time since the last one to
+//! this clock, and each job runs on the ticks that cross its own interval.
+//! The ticker only runs while `NexusApp::clock_interval` reports a job that
+//! wants it.
+
+use std::time::{Duration, Instant};
+
+use super::ANIMATION_INTERVAL;
+
+/// Time between clock ticks when nothing is animating
+pub const CLOCK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Time counted by the shared clock
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClockTick {
+    /// Milliseconds counted so far
+    elapsed: u64,
+    /// Milliseconds added by the latest tick
+    step: u64,
+    /// When the latest tick arrived
+    last_tick: Option<Instant>,
+}
+
+impl ClockTick {
+    /// Count a tick that arrived at `now`
+    ///
+    /// The clock advances by the real time since the previous tick, so late
+    /// or dropped ticks don't hold jobs back. The first tick has nothing to
+    /// measure against and counts as `first_step`.
+    pub fn tick_at(&mut self, now: Instant, first_step: Duration) {
+        let step = self
+            .last_tick
+            .map_or(first_step, |last| now.saturating_duration_since(last));
+        self.last_tick = Some(now);
+        self.advance(step);
+    }
+
+    /// Count one more tick, `step` after the previous one
+    pub fn advance(&mut self, step: Duration) {
+        self.step = step.as_millis() as u64;
+        self.elapsed += self.step;
+    }
+
+    /// Whether a job that runs every `interval` is due on this tick
+    ///
+    /// A job is due on the tick that crosses a multiple of its interval, so
+    /// anything no longer than a tick runs on every one.
+    pub fn is_due(self, interval: Duration) -> bool {
+        let every = (interval.as_millis() as u64).max(1);
+        self.elapsed / every != (self.elapsed - self.step) / every
+    }
+}
+
+/// Interval the shared clock ticks at while something is animating
+pub const fn tick_interval(animating: bool) -> Duration {
+    if animating {
+        ANIMATION_INTERVAL
+    } else {
+        CLOCK_INTERVAL
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jobs_run_on_their_own_interval() {
+        let mut tick = ClockTick::default();
+        let mut every_second = 0;
+        let mut every_fifteen = 0;
+        for _ in 0..30 {
+            tick.advance(CLOCK_INTERVAL);
+            if tick.is_due(CLOCK_INTERVAL) {
+                every_second += 1;
+            }
+            if tick.is_due(Duration::from_secs(15)) {
+                every_fifteen += 1;
+            }
+        }
+        assert_eq!(every_second, 30);
+        assert_eq!(every_fifteen, 2);
+    }
+
+    #[test]
+    fn test_ticks_count_real_time() {
+        let start = Instant::now();
+        let mut tick = ClockTick::default();
+        tick.tick_at(start, CLOCK_INTERVAL);
+        assert!(tick.is_due(CLOCK_INTERVAL));
+
+        // A tick that arrives late still covers the time that passed
+        tick.tick_at(start + Duration::from_millis(1_400), CLOCK_INTERVAL);
+        assert!(!tick.is_due(Duration::from_secs(5)));
+        tick.tick_at(start + Duration::from_secs(5), CLOCK_INTERVAL);
+        assert!(tick.is_due(Duration::from_secs(5)));
+
+        // Animation ticks measure the real gap too, not the nominal interval
+        tick.tick_at(start + Duration::from_millis(5_100), ANIMATION_INTERVAL);
+        assert!(!tick.is_due(CLOCK_INTERVAL));
+        tick.tick_at(start + Duration::from_millis(6_050), ANIMATION_INTERVAL);
+        assert!(tick.is_due(CLOCK_INTERVAL));
+    }
+
+    #[test]
+    fn test_short_interval_runs_every_tick() {
+        let mut tick = ClockTick::default();
+        tick.advance(CLOCK_INTERVAL);
+        assert!(tick.is_due(Duration::from_millis(250)));
+        assert!(tick.is_due(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_animation_ticks_keep_slower_jobs_on_time() {
+        let mut tick = ClockTick::default();
+        let mut every_second = 0;
+        for _ in 0..8 {
+            tick.advance(tick_interval(true));
+            if tick.is_due(CLOCK_INTERVAL) {
+                every_second += 1;
+            }
+        }
+        assert_eq!(every_second, 2);
+
+        // Switching back to the slow ticker stays on the same schedule
+        tick.advance(tick_interval(false));
+        assert!(tick.is_due(CLOCK_INTERVAL));
+    }
+}
@@ -1,15 +1,67 @@
 //! Server connection types
 
-use nexus_common::framing::MessageId;
-use nexus_common::protocol::{ClientMessage, UserInfoDetailed};
+use iced::widget::text_editor;
+use nexus_common::DEFAULT_PORT;
+use nexus_common::framing::{MessageId, MessageIdGenerator, legacy_max_payload_for_type};
+use nexus_common::protocol::{
+    AdminNoticeAction, ClientMessage, E2eeSessionKey, PinnedMessage, UserInfoDetailed,
+};
+use nexus_common::validators::{MAX_CHAT_TOPIC_LENGTH, MAX_MESSAGE_LENGTH};
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
 use super::{
-    ActivePanel, ChatMessage, ChatTab, ResponseRouting, ScrollState, ServerInfoEditState, UserInfo,
-    UserManagementState,
+    ActivePanel, BroadcastTarget, ChatMessage, ChatTab, ConnectionEventLog, E2eeState, InfoPage,
+    Keepalive, MessageDraft, MessageDrafts, MessageTabs, MessageType, PendingRequests,
+    PopoutWindow, PrivateMessageRoute, ProfileEditState, QueuedPrivateMessage, ResponseRouting,
+    ScrollState, SendDebounce, ServerInfoEditState, UserInfo, UserManagementState,
 };
 use crate::image::CachedImage;
+use crate::network::{TrafficCounters, TrafficTotals};
+
+/// How long the "copied to clipboard" confirmation stays visible
+pub const COPY_CONFIRMATION_DURATION: Duration = Duration::from_secs(2);
+
+/// Shortest interval between requests for older #server history
+///
+/// Matches the server's own limit, so scrolling never trips it.
+pub const HISTORY_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Messages asked for in each history request
+pub const HISTORY_PAGE_SIZE: u32 = 50;
+
+/// Progress loading older #server history from the server
+#[derive(Debug, Clone, Default)]
+pub struct HistoryPaging {
+    /// A request is waiting for its response
+    pub loading: bool,
+    /// The server has no older messages
+    pub complete: bool,
+    /// When the last request was sent
+    pub last_request: Option<Instant>,
+}
+
+impl HistoryPaging {
+    /// Whether another page may be requested at `now`
+    pub fn can_request(&self, now: Instant) -> bool {
+        !self.loading
+            && !self.complete
+            && self
+                .last_request
+                .is_none_or(|last| now.duration_since(last) >= HISTORY_REQUEST_INTERVAL)
+    }
+}
+
+/// Message types and features reported by the server's Capabilities response
+#[derive(Debug, Clone, Default)]
+pub struct ServerCapabilities {
+    /// Every message type the server understands
+    pub message_types: HashSet<String>,
+    /// Optional features the server supports
+    pub features: Vec<String>,
+}
 
 /// Type alias for the wrapped shutdown handle (Arc<Mutex<Option<...>>>)
 type WrappedShutdownHandle =
@@ -26,12 +78,17 @@ pub struct ServerConnection {
     /// Bookmark index or None for ad-hoc connections
     pub bookmark_index: Option<usize>,
     /// Session ID assigned by server
-    #[allow(dead_code)]
     pub session_id: u32,
     /// Authenticated username (used for PM routing)
     pub username: String,
     /// Display name (bookmark name or address:port)
     pub display_name: String,
+    /// Server address as entered when connecting
+    pub server_address: String,
+    /// Server port
+    pub server_port: u16,
+    /// Certificate fingerprint (SHA-256) the server presented
+    pub certificate_fingerprint: String,
     /// Unique connection identifier
     pub connection_id: usize,
     /// Whether user is admin on this server
@@ -60,30 +117,100 @@ pub struct ServerConnection {
     pub chat_topic_set_by: Option<String>,
     /// Max connections per IP (admin only, from ServerInfo)
     pub max_connections_per_ip: Option<u32>,
+    /// Chat slow mode interval in seconds (0 = disabled, from ServerInfo)
+    pub chat_slow_mode: u32,
+    /// When the last server chat message was sent (for slow mode countdown)
+    pub last_chat_sent: Option<Instant>,
+    /// When a chat message was last copied to the clipboard (for the confirmation)
+    pub last_copied: Option<Instant>,
+    /// Last message sent from the chat input (drops accidental double sends)
+    pub send_debounce: SendDebounce,
+    /// Number of unique users online (None if not provided by the server)
+    pub user_count: Option<u32>,
+    /// Maximum message length accepted by the server (defaults for older servers)
+    pub max_message_length: usize,
+    /// Maximum chat topic length accepted by the server (defaults for older servers)
+    pub max_topic_length: usize,
+    /// Whether an admin disabled this account while connected (disconnect is not retryable)
+    pub account_disabled: bool,
+    /// Whether the server speaks the extended protocol (0.6 or newer)
+    ///
+    /// Older servers disconnect on message types they don't know, so only the
+    /// 0.5 message types are sent to them.
+    pub extended_protocol: bool,
+    /// Server capabilities (None until the Capabilities response arrives)
+    pub capabilities: Option<ServerCapabilities>,
+    /// Messages pinned to the top of #server (oldest pin first)
+    pub pinned_messages: Vec<PinnedMessage>,
+    /// Server rules awaiting acceptance (shown as a dialog until accepted)
+    pub pending_rules: Option<String>,
+    /// Info page shown in the page viewer (from `/page <name>`)
+    pub open_page: Option<InfoPage>,
+    /// Whether the server is in maintenance (read-only) mode
+    pub maintenance: bool,
+    /// Latest admin notice, shown as a banner until dismissed
+    pub admin_notice: Option<AdminNoticeAction>,
+    /// Permissions the server has switched off for this user, whatever their grants
+    pub disabled_permissions: Vec<String>,
+    /// Keepalive round-trip tracking for the connection health indicator
+    pub keepalive: Keepalive,
+    /// Lifecycle events (connect, login, errors, kicks) for `/events`
+    pub event_log: ConnectionEventLog,
+    /// Bytes sent and received, counted by the network task
+    pub traffic: Arc<TrafficCounters>,
+    /// Traffic as last refreshed for display
+    pub traffic_totals: TrafficTotals,
     /// Active chat tab
     pub active_chat_tab: ChatTab,
+    /// Show user connect/disconnect messages in chat, overriding the global
+    /// setting (from bookmark or /set; None = use the global setting)
+    pub connection_notifications: Option<bool>,
+    /// Tab to switch to once the initial user list confirms it is still valid
+    pub pending_tab_restore: Option<ChatTab>,
+    /// Announce watched users found in the initial user list
+    pub pending_watch_check: bool,
     /// Chat message history for server chat
     pub chat_messages: Vec<ChatMessage>,
-    /// User message history per user
+    /// Loading of older server chat history ("load more" on scrolling up)
+    pub history: HistoryPaging,
+    /// User message history per user (one entry per open message tab)
     pub user_messages: HashMap<String, Vec<ChatMessage>>,
+    /// Message tab recency and history of tabs closed by the tab limit
+    pub message_tabs: MessageTabs,
     /// Tabs with unread messages (for bold indicator)
     pub unread_tabs: HashSet<ChatTab>,
+    /// Sender of the most recent private message received (for Ctrl+R)
+    pub last_message_from: Option<String>,
     /// Currently online users
     pub online_users: Vec<UserInfo>,
     /// Username of expanded user in user list (None if no user expanded)
     pub expanded_user: Option<String>,
     /// Channel for sending commands to server
     tx: CommandSender,
+    /// Generator for unique outgoing message IDs
+    message_ids: MessageIdGenerator,
     /// Handle for graceful shutdown
     pub shutdown_handle: WrappedShutdownHandle,
-    /// Current chat message input
-    pub message_input: String,
+    /// Current chat message input for the active tab (may span several lines)
+    pub message_input: text_editor::Content,
+    /// Unsent input of the other chat tabs, restored on switching back
+    pub drafts: MessageDrafts,
+    /// Whether a large paste went into the message input since it was last sent
+    pub large_paste: bool,
+    /// Line count of a large paste waiting for the user to confirm sending it
+    pub paste_confirmation: Option<usize>,
+    /// Private message tabs popped out into their own windows
+    pub popouts: HashMap<iced::window::Id, PopoutWindow>,
     /// Current broadcast message input
     pub broadcast_message: String,
+    /// Audience selected in the broadcast panel
+    pub broadcast_target: BroadcastTarget,
     /// Scroll state per chat tab (offset and auto-scroll flag)
     pub scroll_states: HashMap<ChatTab, ScrollState>,
     /// Pending requests that need response routing
     pub pending_requests: HashMap<MessageId, ResponseRouting>,
+    /// Keys and queued messages for end-to-end encrypted private messages
+    pub e2ee: E2eeState,
     /// Error message for broadcast operations
     pub broadcast_error: Option<String>,
     /// User management panel state
@@ -94,6 +221,8 @@ pub struct ServerConnection {
     pub avatar_cache: HashMap<String, CachedImage>,
     /// Server info edit state (Some when editing, None otherwise)
     pub server_info_edit: Option<ServerInfoEditState>,
+    /// Own profile edit state in the user info panel (Some when editing)
+    pub profile_edit: Option<ProfileEditState>,
     /// Currently active panel in the main content area (per-connection)
     pub active_panel: ActivePanel,
 }
@@ -104,13 +233,288 @@ impl ServerConnection {
     /// Generates a new message ID and sends the message through the channel.
     /// Returns the message ID on success for optional tracking.
     pub fn send(&self, message: ClientMessage) -> Result<MessageId, String> {
-        let message_id = MessageId::new();
+        let message_id = self.message_ids.next_id();
         self.tx
             .send((message_id, message))
             .map_err(|e| e.to_string())?;
         Ok(message_id)
     }
 
+    /// Send a private message, end-to-end encrypted when both accounts can
+    /// receive it
+    ///
+    /// While either account's keys are still being fetched the message waits
+    /// and goes out when they arrive. A user known to have no keys is asked
+    /// about again, as they may have logged back in since. `routing` is tracked for the response
+    /// whenever the message is sent.
+    pub fn send_private_message(
+        &mut self,
+        to_username: &str,
+        message: String,
+        routing: Option<ResponseRouting>,
+    ) -> Result<(), String> {
+        let queued = QueuedPrivateMessage {
+            to_username: to_username.to_string(),
+            message,
+            routing,
+        };
+        self.e2ee.forget_empty_keys(&self.username, to_username);
+        let route = self
+            .e2ee
+            .route(&self.username, to_username, &queued.message);
+        match route {
+            PrivateMessageRoute::Wait => {
+                if self.e2ee.mark_requested(to_username) {
+                    self.send(ClientMessage::E2eeKeysRequest {
+                        username: to_username.to_string(),
+                    })?;
+                }
+                self.e2ee.queue(queued);
+                Ok(())
+            }
+            route => self.send_routed_private_message(queued, route),
+        }
+    }
+
+    /// Store a user's end-to-end encryption keys and send the private
+    /// messages that were waiting for them
+    ///
+    /// Returns the errors of messages that couldn't be sent.
+    pub fn receive_e2ee_keys(&mut self, username: &str, keys: Vec<E2eeSessionKey>) -> Vec<String> {
+        self.e2ee.set_keys(username, keys);
+        let ready = self.e2ee.take_ready(&self.username);
+        ready
+            .into_iter()
+            .filter_map(|(queued, route)| self.send_routed_private_message(queued, route).err())
+            .collect()
+    }
+
+    /// Send a private message the way `route` says
+    fn send_routed_private_message(
+        &mut self,
+        queued: QueuedPrivateMessage,
+        route: PrivateMessageRoute,
+    ) -> Result<(), String> {
+        let message = match route {
+            PrivateMessageRoute::Encrypted(envelopes) => ClientMessage::EncryptedUserMessage {
+                to_username: queued.to_username,
+                envelopes,
+            },
+            _ => ClientMessage::UserMessage {
+                to_username: queued.to_username,
+                message: queued.message,
+            },
+        };
+        let message_id = self.send(message)?;
+        if let Some(routing) = queued.routing {
+            self.track_request(message_id, routing);
+        }
+        Ok(())
+    }
+
+    /// Remember how to route the response to a request
+    ///
+    /// A message ID that is still outstanding has its old routing replaced, so
+    /// the earlier request's target never receives the newer response.
+    pub fn track_request(&mut self, message_id: MessageId, routing: ResponseRouting) {
+        let _ = self.pending_requests.track(message_id, routing);
+    }
+
+    /// Whether this user may use a permission-gated feature
+    ///
+    /// Admins have every permission, but nothing the server has disabled.
+    pub fn has_permission(&self, permission: &str) -> bool {
+        !self.permission_disabled(permission)
+            && (self.is_admin || self.permissions.iter().any(|p| p == permission))
+    }
+
+    /// Whether the server has switched this permission off for this user
+    pub fn permission_disabled(&self, permission: &str) -> bool {
+        self.disabled_permissions.iter().any(|p| p == permission)
+    }
+
+    /// Seconds remaining before another server chat message may be sent
+    ///
+    /// Returns `None` when slow mode is disabled, the user is an admin (exempt),
+    /// or the interval has already elapsed.
+    pub fn slow_mode_remaining(&self) -> Option<u64> {
+        if self.is_admin || self.chat_slow_mode == 0 {
+            return None;
+        }
+
+        let elapsed = self.last_chat_sent?.elapsed();
+        let interval = Duration::from_secs(u64::from(self.chat_slow_mode));
+        if elapsed >= interval {
+            return None;
+        }
+
+        let remaining = interval - elapsed;
+        Some(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0))
+    }
+
+    /// Message history of the active chat tab
+    pub fn active_messages(&self) -> &[ChatMessage] {
+        match &self.active_chat_tab {
+            ChatTab::Server => &self.chat_messages,
+            ChatTab::UserMessage(username) => self
+                .user_messages
+                .get(username)
+                .map(|v| v.as_slice())
+                .unwrap_or(&[]),
+        }
+    }
+
+    /// Mutable messages of the active chat tab (None if its history is gone)
+    pub fn active_messages_mut(&mut self) -> Option<&mut Vec<ChatMessage>> {
+        match &self.active_chat_tab {
+            ChatTab::Server => Some(&mut self.chat_messages),
+            ChatTab::UserMessage(username) => self.user_messages.get_mut(username),
+        }
+    }
+
+    /// Window the private message tab with `username` is popped out into
+    pub fn popout_window(&self, username: &str) -> Option<iced::window::Id> {
+        self.popouts
+            .iter()
+            .find(|(_, popout)| popout.username == username)
+            .map(|(id, _)| *id)
+    }
+
+    /// Whether the server understands a message type
+    ///
+    /// Servers older than the extended protocol only know the 0.5 message
+    /// types. Newer servers are assumed to support a type until they have
+    /// reported their capabilities.
+    pub fn supports_message_type(&self, message_type: &str) -> bool {
+        if !self.extended_protocol {
+            return legacy_max_payload_for_type(message_type).is_some();
+        }
+        self.capabilities
+            .as_ref()
+            .is_none_or(|caps| caps.message_types.contains(message_type))
+    }
+
+    /// Find a #server chat message by its server-assigned id
+    ///
+    /// Ids increase with every message, so the search starts from the newest.
+    pub fn chat_message_mut(&mut self, message_id: u64) -> Option<&mut ChatMessage> {
+        self.chat_messages
+            .iter_mut()
+            .rev()
+            .find(|msg| msg.message_id == Some(message_id))
+    }
+
+    /// Id of the most recent #server chat message sent by `username`
+    pub fn latest_chat_message_id(&self, username: &str) -> Option<u64> {
+        self.chat_messages
+            .iter()
+            .rev()
+            .filter(|msg| msg.message_type == MessageType::Chat)
+            .filter(|msg| msg.username.eq_ignore_ascii_case(username))
+            .find_map(|msg| msg.message_id)
+    }
+
+    /// Open a message tab (or mark an open one active) and return its history
+    ///
+    /// A tab previously closed by the limit gets its history back. If this
+    /// makes more than `max_tabs` tabs, the least recently active ones are
+    /// closed, never this tab or the active one.
+    pub fn open_user_message_tab(
+        &mut self,
+        username: &str,
+        max_tabs: usize,
+    ) -> &mut Vec<ChatMessage> {
+        if !self.user_messages.contains_key(username) {
+            let history = self.message_tabs.restore(username).unwrap_or_default();
+            self.user_messages.insert(username.to_string(), history);
+        }
+        self.message_tabs.touch(username);
+        self.enforce_message_tab_limit(max_tabs, Some(username));
+
+        // Never evicted above, so this only looks up the entry
+        self.user_messages.entry(username.to_string()).or_default()
+    }
+
+    /// Close the least recently active message tabs beyond `max_tabs`
+    ///
+    /// The active tab, popped-out tabs, and `keep` are never closed. Closed
+    /// tabs keep their history for when they are reopened.
+    pub fn enforce_message_tab_limit(&mut self, max_tabs: usize, keep: Option<&str>) {
+        while self.user_messages.len() > max_tabs {
+            let candidates = self.user_messages.keys().filter(|username| {
+                Some(username.as_str()) != keep
+                    && self.popout_window(username).is_none()
+                    && !matches!(&self.active_chat_tab, ChatTab::UserMessage(active) if active == *username)
+            });
+            let Some(username) = self.message_tabs.least_recent(candidates).cloned() else {
+                break;
+            };
+
+            if let Some(messages) = self.user_messages.remove(&username) {
+                self.message_tabs.evict(username.clone(), messages);
+            }
+            let tab = ChatTab::UserMessage(username);
+            self.unread_tabs.remove(&tab);
+            self.scroll_states.remove(&tab);
+        }
+    }
+
+    /// Switch the main window's chat tab, keeping each tab's unsent input
+    ///
+    /// The input is set aside as the old tab's draft and the new tab's draft,
+    /// if any, takes its place.
+    pub fn set_active_chat_tab(&mut self, tab: ChatTab) {
+        if self.active_chat_tab == tab {
+            return;
+        }
+        let old_tab = std::mem::replace(&mut self.active_chat_tab, tab);
+        let draft = MessageDraft {
+            text: self.message_input.text(),
+            large_paste: self.large_paste,
+        };
+        self.drafts.store(old_tab, draft);
+        self.load_draft();
+    }
+
+    /// Collect every unsent input, including popped-out tabs, to carry over
+    /// to the next connection to the same server
+    pub fn take_drafts(&mut self) -> MessageDrafts {
+        let draft = MessageDraft {
+            text: self.message_input.text(),
+            large_paste: self.large_paste,
+        };
+        self.drafts.store(self.active_chat_tab.clone(), draft);
+        for popout in self.popouts.values() {
+            let draft = MessageDraft {
+                text: popout.message_input.text(),
+                large_paste: false,
+            };
+            self.drafts
+                .store(ChatTab::UserMessage(popout.username.clone()), draft);
+        }
+        std::mem::take(&mut self.drafts)
+    }
+
+    /// Use drafts carried over from an earlier connection
+    pub fn restore_drafts(&mut self, drafts: MessageDrafts) {
+        self.drafts = drafts;
+        self.load_draft();
+    }
+
+    /// Replace the message input with the active tab's draft
+    fn load_draft(&mut self) {
+        let draft = self.drafts.take(&self.active_chat_tab);
+        self.message_input = text_editor::Content::with_text(&draft.text);
+        self.large_paste = draft.large_paste;
+        self.paste_confirmation = None;
+    }
+
+    /// Whether the "copied to clipboard" confirmation should be shown
+    pub fn copy_confirmation_visible(&self) -> bool {
+        self.last_copied
+            .is_some_and(|copied| copied.elapsed() < COPY_CONFIRMATION_DURATION)
+    }
+
     /// Create a new ServerConnection with the given parameters
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -130,6 +534,8 @@ impl ServerConnection {
         chat_topic: Option<String>,
         chat_topic_set_by: Option<String>,
         max_connections_per_ip: Option<u32>,
+        chat_slow_mode: u32,
+        user_count: Option<u32>,
         tx: CommandSender,
         shutdown_handle: WrappedShutdownHandle,
     ) -> Self {
@@ -138,6 +544,9 @@ impl ServerConnection {
             session_id,
             username,
             display_name,
+            server_address: String::new(),
+            server_port: DEFAULT_PORT,
+            certificate_fingerprint: String::new(),
             connection_id,
             is_admin,
             permissions,
@@ -150,28 +559,102 @@ impl ServerConnection {
             chat_topic,
             chat_topic_set_by,
             max_connections_per_ip,
+            chat_slow_mode,
+            last_chat_sent: None,
+            last_copied: None,
+            send_debounce: SendDebounce::default(),
+            user_count,
+            max_message_length: MAX_MESSAGE_LENGTH,
+            max_topic_length: MAX_CHAT_TOPIC_LENGTH,
+            account_disabled: false,
+            extended_protocol: true,
+            capabilities: None,
+            pinned_messages: Vec::new(),
+            pending_rules: None,
+            open_page: None,
+            maintenance: false,
+            admin_notice: None,
+            disabled_permissions: Vec::new(),
+            keepalive: Keepalive::default(),
+            event_log: ConnectionEventLog::default(),
+            traffic: Arc::default(),
+            traffic_totals: TrafficTotals::default(),
             active_chat_tab: ChatTab::Server,
+            pending_tab_restore: None,
+            pending_watch_check: false,
+            connection_notifications: None,
             chat_messages: Vec::new(),
+            history: HistoryPaging::default(),
             user_messages: HashMap::new(),
+            message_tabs: MessageTabs::default(),
             unread_tabs: HashSet::new(),
+            last_message_from: None,
             online_users: Vec::new(),
             expanded_user: None,
             tx,
+            message_ids: MessageIdGenerator::new(),
             shutdown_handle,
-            message_input: String::new(),
+            message_input: text_editor::Content::new(),
+            drafts: MessageDrafts::default(),
+            large_paste: false,
+            paste_confirmation: None,
+            popouts: HashMap::new(),
             broadcast_message: String::new(),
+            broadcast_target: BroadcastTarget::default(),
             scroll_states: HashMap::new(),
             pending_requests: HashMap::new(),
+            e2ee: E2eeState::default(),
             broadcast_error: None,
             user_management: UserManagementState::default(),
             user_info_data: None,
             avatar_cache: HashMap::new(),
             server_info_edit: None,
+            profile_edit: None,
             active_panel: ActivePanel::None,
         }
     }
 }
 
+/// Stage reached while connecting to a server
+///
+/// Reported by the connect task so the UI can show where a connection stalls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionStep {
+    /// Opening the TCP connection
+    Tcp,
+    /// TCP connected, performing the TLS handshake
+    Tls,
+    /// TLS established, performing the protocol handshake
+    Handshake,
+    /// Handshake complete, waiting for login
+    Login,
+}
+
+impl ConnectionStep {
+    /// Number of steps in a connection attempt
+    pub const COUNT: usize = 4;
+
+    /// One-based position of this step (for progress display)
+    pub fn number(self) -> usize {
+        match self {
+            Self::Tcp => 1,
+            Self::Tls => 2,
+            Self::Handshake => 3,
+            Self::Login => 4,
+        }
+    }
+
+    /// Translation key describing this step
+    pub fn translation_key(self) -> &'static str {
+        match self {
+            Self::Tcp => "connect-step-tcp",
+            Self::Tls => "connect-step-tls",
+            Self::Handshake => "connect-step-handshake",
+            Self::Login => "connect-step-login",
+        }
+    }
+}
+
 /// Network connection handle returned by connect_to_server()
 #[derive(Debug, Clone)]
 pub struct NetworkConnection {
@@ -183,6 +666,10 @@ pub struct NetworkConnection {
     pub connection_id: usize,
     /// Optional shutdown handle
     pub shutdown: Option<WrappedShutdownHandle>,
+    /// Server address as entered when connecting
+    pub server_address: String,
+    /// Server port
+    pub server_port: u16,
     /// Whether user is admin
     pub is_admin: bool,
     /// User's permissions
@@ -201,8 +688,30 @@ pub struct NetworkConnection {
     pub chat_topic_set_by: Option<String>,
     /// Max connections per IP (admin only)
     pub max_connections_per_ip: Option<u32>,
+    /// Chat slow mode interval in seconds (0 = disabled)
+    pub chat_slow_mode: u32,
+    /// Number of unique users online (requires user_list permission)
+    pub user_count: Option<u32>,
+    /// Maximum message length accepted by the server
+    pub max_message_length: usize,
+    /// Maximum chat topic length accepted by the server
+    pub max_topic_length: usize,
+    /// Currently pinned chat messages (requires chat_receive permission)
+    pub pinned_messages: Vec<PinnedMessage>,
+    /// Preferences stored on the server for this account (if any)
+    pub preferences: Option<serde_json::Value>,
+    /// Server rules the user must accept before chatting (None if already accepted)
+    pub rules: Option<String>,
+    /// Whether the server is in maintenance (read-only) mode
+    pub maintenance: bool,
+    /// Permissions the server has switched off for this user
+    pub disabled_permissions: Vec<String>,
     /// Certificate fingerprint (SHA-256) for TOFU verification
     pub certificate_fingerprint: String,
     /// Locale accepted by the server
     pub locale: String,
+    /// Bytes sent and received, counted by the network task
+    pub traffic: Arc<TrafficCounters>,
+    /// Whether the server speaks the extended protocol (0.6 or newer)
+    pub extended_protocol: bool,
 }
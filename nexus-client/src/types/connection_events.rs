@@ -0,0 +1,358 @@
+//! Connection lifecycle event log
+//!
+//! Each connection keeps a timeline of what happened to it (connected,
+//! logged in, errors, kicked, disconnected) so a user reporting a bug can
+//! show `/events` output instead of reconstructing it from memory. The log
+//! holds a fixed number of entries per connection. When a log file is set in
+//! settings, every event is also appended there by a background task, so
+//! the UI never waits on disk.
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use iced::futures::{SinkExt, Stream};
+use iced::stream;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+/// Maximum events kept in memory per connection (oldest are dropped first)
+pub const CONNECTION_EVENT_LOG_CAPACITY: usize = 200;
+
+/// Something that happened over a connection's lifetime
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// Transport and TLS established with the server
+    Connected { endpoint: String },
+    /// Server accepted the login
+    LoggedIn { username: String },
+    /// Server reported an error
+    Error {
+        message: String,
+        code: Option<String>,
+    },
+    /// A moderator kicked this session
+    Kicked { message: String },
+    /// Connection closed (`None` when the user disconnected)
+    Disconnected { reason: Option<String> },
+}
+
+/// Stand-in for values left out of shared reports
+pub const REDACTED: &str = "[redacted]";
+
+impl ConnectionEvent {
+    /// Copy of the event without the server endpoint, username, or any
+    /// free text (server errors, kick and disconnect reasons can name users
+    /// or addresses); error codes are kept
+    pub fn redacted(&self) -> Self {
+        match self {
+            Self::Connected { .. } => Self::Connected {
+                endpoint: REDACTED.to_string(),
+            },
+            Self::LoggedIn { .. } => Self::LoggedIn {
+                username: REDACTED.to_string(),
+            },
+            Self::Error { code, .. } => Self::Error {
+                message: REDACTED.to_string(),
+                code: code.clone(),
+            },
+            Self::Kicked { .. } => Self::Kicked {
+                message: REDACTED.to_string(),
+            },
+            Self::Disconnected { reason } => Self::Disconnected {
+                reason: reason.as_ref().map(|_| REDACTED.to_string()),
+            },
+        }
+    }
+}
+
+/// Events are logged untranslated so timelines read the same in every bug report
+impl fmt::Display for ConnectionEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Connected { endpoint } => write!(f, "connected to {endpoint}"),
+            Self::LoggedIn { username } => write!(f, "logged in as {username}"),
+            Self::Error {
+                message,
+                code: Some(code),
+            } => write!(f, "error [{code}]: {message}"),
+            Self::Error {
+                message,
+                code: None,
+            } => write!(f, "error: {message}"),
+            Self::Kicked { message } => write!(f, "kicked: {message}"),
+            Self::Disconnected {
+                reason: Some(reason),
+            } => write!(f, "disconnected: {reason}"),
+            Self::Disconnected { reason: None } => write!(f, "disconnected by user"),
+        }
+    }
+}
+
+/// Bounded timeline of events for one connection
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionEventLog {
+    entries: VecDeque<(DateTime<Local>, ConnectionEvent)>,
+}
+
+impl ConnectionEventLog {
+    /// Append an event, dropping the oldest once the log is full
+    pub fn record(&mut self, at: DateTime<Local>, event: ConnectionEvent) {
+        if self.entries.len() == CONNECTION_EVENT_LOG_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((at, event));
+    }
+
+    /// Timeline lines, oldest first
+    pub fn lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.entries
+            .iter()
+            .map(|(at, event)| format_event_line(*at, event))
+    }
+
+    /// Timeline lines with endpoints, usernames, and messages redacted, oldest first
+    pub fn redacted_lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.entries
+            .iter()
+            .map(|(at, event)| format_event_line(*at, &event.redacted()))
+    }
+
+    /// Whether nothing has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// One timeline line: RFC 3339 timestamp followed by the event
+pub fn format_event_line(at: DateTime<Local>, event: &ConnectionEvent) -> String {
+    format!(
+        "{} {event}",
+        at.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+    )
+}
+
+/// Capacity of the channel reporting failed log writes to the app
+const LOG_FAILURE_CHANNEL_SIZE: usize = 16;
+
+/// Lines waiting for the log writer (further lines are dropped while it's full)
+const LOG_QUEUE_SIZE: usize = 256;
+
+/// Queue for the task that appends lines to log files
+///
+/// One task writes every line in the order it was queued, so events from
+/// several connections never interleave mid-line.
+#[derive(Debug, Clone)]
+pub struct ConnectionLogSender(mpsc::Sender<(PathBuf, String)>);
+
+impl ConnectionLogSender {
+    /// Queue a line to be appended to a log file in the background
+    ///
+    /// The line is dropped if the writer has fallen behind (e.g. on a stalled
+    /// disk); it stays in the in-memory log either way.
+    pub fn append(&self, path: PathBuf, line: String) {
+        let _ = self.0.try_send((path, line));
+    }
+}
+
+/// Log files whose last write failed, so a broken path is reported only once
+#[derive(Debug, Default)]
+struct FailedLogPaths(HashSet<PathBuf>);
+
+impl FailedLogPaths {
+    /// Note the outcome of a write, returning whether to report it
+    ///
+    /// A successful write clears the path, so failing again later is reported.
+    fn should_report(&mut self, path: &Path, written: bool) -> bool {
+        if written {
+            self.0.remove(path);
+            false
+        } else {
+            self.0.insert(path.to_path_buf())
+        }
+    }
+}
+
+/// Create the log queue and the writer that drains it
+///
+/// The writer does nothing until it is run as a task on the app's runtime
+/// (see `NexusApp::new`). It yields the path and error when writing to a
+/// path first fails.
+pub fn connection_log_writer() -> (
+    ConnectionLogSender,
+    impl Stream<Item = (String, String)> + Send + 'static,
+) {
+    let (tx, mut rx) = mpsc::channel::<(PathBuf, String)>(LOG_QUEUE_SIZE);
+    let writer = stream::channel(
+        LOG_FAILURE_CHANNEL_SIZE,
+        move |mut output: iced::futures::channel::mpsc::Sender<(String, String)>| async move {
+            let mut failed = FailedLogPaths::default();
+            while let Some((path, line)) = rx.recv().await {
+                let result = append_line(&path, &line).await;
+                if failed.should_report(&path, result.is_ok())
+                    && let Err(e) = result
+                {
+                    let _ = output
+                        .send((path.display().to_string(), e.to_string()))
+                        .await;
+                }
+            }
+        },
+    );
+    (ConnectionLogSender(tx), writer)
+}
+
+/// Append one line to a file, creating it if needed
+async fn append_line(path: &Path, line: &str) -> std::io::Result<()> {
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(format!("{line}\n").as_bytes()).await
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_log_path_reported_once() {
+        let mut failed = FailedLogPaths::default();
+        let path = Path::new("/nonexistent/events.log");
+        let other = Path::new("/nonexistent/other.log");
+
+        assert!(failed.should_report(path, false));
+        assert!(!failed.should_report(path, false));
+        assert!(failed.should_report(other, false));
+
+        // Recovering and failing again is worth another report
+        assert!(!failed.should_report(path, true));
+        assert!(failed.should_report(path, false));
+    }
+
+    #[test]
+    fn test_log_is_bounded() {
+        let mut log = ConnectionEventLog::default();
+        let now = Local::now();
+        for i in 0..CONNECTION_EVENT_LOG_CAPACITY + 5 {
+            log.record(
+                now,
+                ConnectionEvent::Error {
+                    message: i.to_string(),
+                    code: None,
+                },
+            );
+        }
+
+        let lines: Vec<String> = log.lines().collect();
+        assert_eq!(lines.len(), CONNECTION_EVENT_LOG_CAPACITY);
+        assert!(lines[0].ends_with("error: 5"));
+        assert!(
+            lines
+                .last()
+                .unwrap()
+                .ends_with(&format!("error: {}", CONNECTION_EVENT_LOG_CAPACITY + 4))
+        );
+    }
+
+    #[test]
+    fn test_redacted_lines() {
+        let mut log = ConnectionEventLog::default();
+        let now = Local::now();
+        log.record(
+            now,
+            ConnectionEvent::Connected {
+                endpoint: "192.0.2.1:7500".to_string(),
+            },
+        );
+        log.record(
+            now,
+            ConnectionEvent::LoggedIn {
+                username: "alice".to_string(),
+            },
+        );
+        log.record(
+            now,
+            ConnectionEvent::Kicked {
+                message: "Bye alice".to_string(),
+            },
+        );
+        log.record(
+            now,
+            ConnectionEvent::Error {
+                message: "User alice is not online".to_string(),
+                code: Some("not_found".to_string()),
+            },
+        );
+        log.record(
+            now,
+            ConnectionEvent::Disconnected {
+                reason: Some("Connection to 192.0.2.1 reset".to_string()),
+            },
+        );
+        log.record(now, ConnectionEvent::Disconnected { reason: None });
+
+        let lines: Vec<String> = log.redacted_lines().collect();
+        assert!(lines[0].ends_with("connected to [redacted]"));
+        assert!(lines[1].ends_with("logged in as [redacted]"));
+        assert!(lines[2].ends_with("kicked: [redacted]"));
+        assert!(lines[3].ends_with("error [not_found]: [redacted]"));
+        assert!(lines[4].ends_with("disconnected: [redacted]"));
+        assert!(lines[5].ends_with("disconnected by user"));
+    }
+
+    #[test]
+    fn test_event_lines() {
+        let at = DateTime::parse_from_rfc3339("2025-01-02T03:04:05+00:00")
+            .unwrap()
+            .with_timezone(&Local);
+        let stamp = at.to_rfc3339_opts(chrono::SecondsFormat::Secs, false);
+
+        let cases = [
+            (
+                ConnectionEvent::Connected {
+                    endpoint: "example.com:7500".to_string(),
+                },
+                "connected to example.com:7500",
+            ),
+            (
+                ConnectionEvent::LoggedIn {
+                    username: "alice".to_string(),
+                },
+                "logged in as alice",
+            ),
+            (
+                ConnectionEvent::Error {
+                    message: "Nope".to_string(),
+                    code: Some("account_disabled".to_string()),
+                },
+                "error [account_disabled]: Nope",
+            ),
+            (
+                ConnectionEvent::Kicked {
+                    message: "Bye".to_string(),
+                },
+                "kicked: Bye",
+            ),
+            (
+                ConnectionEvent::Disconnected {
+                    reason: Some("reset".to_string()),
+                },
+                "disconnected: reset",
+            ),
+            (
+                ConnectionEvent::Disconnected { reason: None },
+                "disconnected by user",
+            ),
+        ];
+        for (event, expected) in cases {
+            assert_eq!(format_event_line(at, &event), format!("{stamp} {expected}"));
+        }
+    }
+}
@@ -44,6 +44,8 @@ pub enum MessageType {
     Info,
     /// Broadcast message from a user
     Broadcast,
+    /// Rotating announcement from the server
+    Announcement,
 }
 
 /// Chat message for display
@@ -59,6 +61,21 @@ pub struct ChatMessage {
     pub timestamp: Option<DateTime<Local>>,
     /// Whether the sender is an admin (for username coloring)
     pub is_admin: bool,
+    /// Server-assigned id for chat and private messages (#server ids are used
+    /// by /pin, /edit and /delete)
+    pub message_id: Option<u64>,
+    /// Whether the sender edited the message after sending it
+    pub edited: bool,
+    /// Whether the message was deleted (its text is cleared)
+    pub deleted: bool,
+    /// Whether a moderator deleted the message rather than its sender
+    pub removed_by_moderator: bool,
+    /// Users the server recognized as `@mentioned` in a #server message
+    pub mentions: Vec<String>,
+    /// Whether the private message arrived end-to-end encrypted
+    pub encrypted: bool,
+    /// Whether a long message has been expanded past the collapse threshold
+    pub expanded: bool,
 }
 
 impl ChatMessage {
@@ -70,6 +87,13 @@ impl ChatMessage {
             message_type: MessageType::Chat,
             timestamp: None,
             is_admin: false,
+            message_id: None,
+            edited: false,
+            deleted: false,
+            removed_by_moderator: false,
+            mentions: Vec::new(),
+            encrypted: false,
+            expanded: false,
         }
     }
 
@@ -86,6 +110,13 @@ impl ChatMessage {
             message_type: MessageType::Chat,
             timestamp: Some(timestamp),
             is_admin,
+            message_id: None,
+            edited: false,
+            deleted: false,
+            removed_by_moderator: false,
+            mentions: Vec::new(),
+            encrypted: false,
+            expanded: false,
         }
     }
 
@@ -97,6 +128,13 @@ impl ChatMessage {
             message_type: MessageType::System,
             timestamp: None,
             is_admin: false,
+            message_id: None,
+            edited: false,
+            deleted: false,
+            removed_by_moderator: false,
+            mentions: Vec::new(),
+            encrypted: false,
+            expanded: false,
         }
     }
 
@@ -108,6 +146,13 @@ impl ChatMessage {
             message_type: MessageType::Error,
             timestamp: None,
             is_admin: false,
+            message_id: None,
+            edited: false,
+            deleted: false,
+            removed_by_moderator: false,
+            mentions: Vec::new(),
+            encrypted: false,
+            expanded: false,
         }
     }
 
@@ -119,6 +164,13 @@ impl ChatMessage {
             message_type: MessageType::Info,
             timestamp: None,
             is_admin: false,
+            message_id: None,
+            edited: false,
+            deleted: false,
+            removed_by_moderator: false,
+            mentions: Vec::new(),
+            encrypted: false,
+            expanded: false,
         }
     }
 
@@ -130,6 +182,13 @@ impl ChatMessage {
             message_type: MessageType::Info,
             timestamp: Some(timestamp),
             is_admin: false,
+            message_id: None,
+            edited: false,
+            deleted: false,
+            removed_by_moderator: false,
+            mentions: Vec::new(),
+            encrypted: false,
+            expanded: false,
         }
     }
 
@@ -141,9 +200,60 @@ impl ChatMessage {
             message_type: MessageType::Broadcast,
             timestamp: None,
             is_admin: false,
+            message_id: None,
+            edited: false,
+            deleted: false,
+            removed_by_moderator: false,
+            mentions: Vec::new(),
+            encrypted: false,
+            expanded: false,
         }
     }
 
+    /// Create a server announcement
+    pub fn announcement(message: impl Into<String>) -> Self {
+        Self {
+            username: String::new(),
+            message: message.into(),
+            message_type: MessageType::Announcement,
+            timestamp: None,
+            is_admin: false,
+            message_id: None,
+            edited: false,
+            deleted: false,
+            removed_by_moderator: false,
+            mentions: Vec::new(),
+            encrypted: false,
+            expanded: false,
+        }
+    }
+
+    /// Attach the server-assigned message id
+    pub fn with_message_id(mut self, message_id: Option<u64>) -> Self {
+        self.message_id = message_id;
+        self
+    }
+
+    /// Mark the message as having arrived end-to-end encrypted
+    pub fn with_encrypted(mut self, encrypted: bool) -> Self {
+        self.encrypted = encrypted;
+        self
+    }
+
+    /// Attach the users the server flagged as mentioned
+    pub fn with_mentions(mut self, mentions: Option<Vec<String>>) -> Self {
+        self.mentions = mentions.unwrap_or_default();
+        self
+    }
+
+    /// Whether `username` is among the mentioned users (case-insensitive)
+    pub fn mentions_user(&self, username: &str) -> bool {
+        let username = username.to_lowercase();
+        self.mentions
+            .iter()
+            .any(|mentioned| mentioned.to_lowercase() == username)
+    }
+
     /// Get the timestamp, using current time if not set
     pub fn get_timestamp(&self) -> DateTime<Local> {
         self.timestamp.unwrap_or_else(Local::now)
@@ -157,6 +267,14 @@ pub struct UserInfo {
     pub username: String,
     /// Whether user is admin
     pub is_admin: bool,
+    /// Whether user moderates chat without being an admin
+    pub is_moderator: bool,
+    /// Whether every one of the user's sessions is idle
+    pub away: bool,
+    /// Whether the user is logged in as a guest, without an account
+    pub is_guest: bool,
+    /// Notable permissions held (only sent to admins and `user_info` holders)
+    pub permissions: Vec<String>,
     /// All active session IDs for this user
     pub session_ids: Vec<u32>,
     /// SHA-256 hash of the avatar data URI for change detection (None = no avatar/identicon)
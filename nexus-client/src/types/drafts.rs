@@ -0,0 +1,98 @@
+//! Unsent message input kept per chat tab
+//!
+//! The main window has a single message input showing the active tab's text.
+//! Switching tabs sets that text aside as the old tab's draft and brings back
+//! the new tab's, so a half-written message waits where it was left.
+
+use std::collections::HashMap;
+
+use super::ChatTab;
+
+/// Unsent text for a tab that isn't being shown
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageDraft {
+    /// Text as typed (may span several lines)
+    pub text: String,
+    /// Whether the text includes a large paste (asks before sending)
+    pub large_paste: bool,
+}
+
+/// Drafts of every chat tab on a connection except the one being shown
+#[derive(Debug, Clone, Default)]
+pub struct MessageDrafts {
+    drafts: HashMap<ChatTab, MessageDraft>,
+}
+
+impl MessageDrafts {
+    /// Set aside a tab's draft (blank text leaves no draft)
+    pub fn store(&mut self, tab: ChatTab, draft: MessageDraft) {
+        if draft.text.trim().is_empty() {
+            self.drafts.remove(&tab);
+        } else {
+            self.drafts.insert(tab, draft);
+        }
+    }
+
+    /// Take a tab's draft back, leaving none behind
+    pub fn take(&mut self, tab: &ChatTab) -> MessageDraft {
+        self.drafts.remove(tab).unwrap_or_default()
+    }
+
+    /// Discard a tab's draft (e.g. when the tab is closed)
+    pub fn remove(&mut self, tab: &ChatTab) {
+        self.drafts.remove(tab);
+    }
+
+    /// Move a draft to the tab of a user who was renamed
+    pub fn rename(&mut self, old: &ChatTab, new: ChatTab) {
+        if let Some(draft) = self.drafts.remove(old) {
+            self.drafts.insert(new, draft);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draft(text: &str) -> MessageDraft {
+        MessageDraft {
+            text: text.to_string(),
+            large_paste: false,
+        }
+    }
+
+    #[test]
+    fn test_store_and_take() {
+        let mut drafts = MessageDrafts::default();
+        let tab = ChatTab::UserMessage("alice".to_string());
+
+        drafts.store(tab.clone(), draft("half a thought"));
+        assert_eq!(drafts.take(&ChatTab::Server), MessageDraft::default());
+        assert_eq!(drafts.take(&tab), draft("half a thought"));
+
+        // Taking leaves nothing behind
+        assert_eq!(drafts.take(&tab), MessageDraft::default());
+    }
+
+    #[test]
+    fn test_blank_text_clears_draft() {
+        let mut drafts = MessageDrafts::default();
+
+        drafts.store(ChatTab::Server, draft("hello"));
+        drafts.store(ChatTab::Server, draft(" \n "));
+        assert_eq!(drafts.take(&ChatTab::Server), MessageDraft::default());
+    }
+
+    #[test]
+    fn test_rename_moves_draft() {
+        let mut drafts = MessageDrafts::default();
+        let old = ChatTab::UserMessage("alice".to_string());
+        let new = ChatTab::UserMessage("alicia".to_string());
+
+        drafts.store(old.clone(), draft("hi"));
+        drafts.rename(&old, new.clone());
+        assert_eq!(drafts.take(&old), MessageDraft::default());
+        assert_eq!(drafts.take(&new), draft("hi"));
+    }
+}
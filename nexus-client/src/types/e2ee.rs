@@ -0,0 +1,282 @@
+//! End-to-end encryption state for a connection
+//!
+//! Once the server supports encrypted private messages, the connection
+//! announces a key and fetches the keys of everyone it messages. A message is
+//! encrypted when both accounts can receive encrypted messages and sent as
+//! plain text otherwise; while either account's keys are still on their way,
+//! it waits here.
+
+use std::collections::{HashMap, HashSet};
+
+use nexus_common::protocol::{E2eeEnvelope, E2eeSessionKey};
+use nexus_common::validators::MAX_E2EE_ENVELOPES;
+
+use super::ResponseRouting;
+use crate::e2ee::{E2eeIdentity, seal};
+
+/// A private message waiting for keys
+#[derive(Debug, Clone)]
+pub struct QueuedPrivateMessage {
+    /// Recipient as entered
+    pub to_username: String,
+    /// Message text
+    pub message: String,
+    /// Routing to track for the response once the message is sent
+    pub routing: Option<ResponseRouting>,
+}
+
+/// How to send a private message
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrivateMessageRoute {
+    /// As plain text (encryption is off or one account can't receive it)
+    Plain,
+    /// As these envelopes, one per session of both accounts
+    Encrypted(Vec<E2eeEnvelope>),
+    /// Not yet; the keys of one account haven't arrived
+    Wait,
+}
+
+/// Keys and queued messages for end-to-end encrypted private messages
+#[derive(Debug, Clone, Default)]
+pub struct E2eeState {
+    /// This connection's key pair (None until the server supports encryption)
+    identity: Option<E2eeIdentity>,
+    /// Latest keys sent by the server, by lowercase username
+    keys: HashMap<String, Vec<E2eeSessionKey>>,
+    /// Lowercase usernames whose keys have been asked for
+    requested: HashSet<String>,
+    /// Messages waiting for keys, oldest first
+    queued: Vec<QueuedPrivateMessage>,
+}
+
+impl E2eeState {
+    /// Make this connection's key pair
+    ///
+    /// Returns the public key to announce. The server sends the keys of the
+    /// user's own account once it has the announcement, so they are never
+    /// asked for.
+    pub fn enable(&mut self, own_username: &str) -> Option<String> {
+        let identity = E2eeIdentity::generate()?;
+        let public_key = identity.public_key().to_string();
+        self.identity = Some(identity);
+        self.requested.insert(own_username.to_lowercase());
+        Some(public_key)
+    }
+
+    /// This connection's public key, once encryption is enabled
+    pub fn public_key(&self) -> Option<String> {
+        self.identity
+            .as_ref()
+            .map(|identity| identity.public_key().to_string())
+    }
+
+    /// Decide how to send `message` from `own_username` to `to_username`
+    pub fn route(
+        &self,
+        own_username: &str,
+        to_username: &str,
+        message: &str,
+    ) -> PrivateMessageRoute {
+        if self.identity.is_none() {
+            return PrivateMessageRoute::Plain;
+        }
+        let (Some(own_keys), Some(their_keys)) = (
+            self.keys.get(&own_username.to_lowercase()),
+            self.keys.get(&to_username.to_lowercase()),
+        ) else {
+            return PrivateMessageRoute::Wait;
+        };
+        if own_keys.is_empty()
+            || their_keys.is_empty()
+            || own_keys.len() + their_keys.len() > MAX_E2EE_ENVELOPES
+        {
+            return PrivateMessageRoute::Plain;
+        }
+
+        let envelopes: Option<Vec<_>> = own_keys
+            .iter()
+            .chain(their_keys)
+            .map(|key| seal(message, key))
+            .collect();
+        envelopes.map_or(PrivateMessageRoute::Plain, PrivateMessageRoute::Encrypted)
+    }
+
+    /// Mark a user's keys as asked for
+    ///
+    /// Returns `true` if they still need to be, i.e. they haven't arrived and
+    /// no request is outstanding.
+    pub fn mark_requested(&mut self, username: &str) -> bool {
+        let username = username.to_lowercase();
+        !self.keys.contains_key(&username) && self.requested.insert(username)
+    }
+
+    /// Forget a user's keys if there are none, so they are asked for again
+    ///
+    /// The server stops sending a user's keys once they go offline, and sends
+    /// none when it can't keep this connection updated, so an empty set may
+    /// be out of date by the next message. The user's own account is always
+    /// kept up to date.
+    pub fn forget_empty_keys(&mut self, own_username: &str, username: &str) {
+        let username = username.to_lowercase();
+        if username != own_username.to_lowercase()
+            && self.keys.get(&username).is_some_and(Vec::is_empty)
+        {
+            self.keys.remove(&username);
+            self.requested.remove(&username);
+        }
+    }
+
+    /// Keep a message until the keys it needs arrive
+    pub fn queue(&mut self, message: QueuedPrivateMessage) {
+        self.queued.push(message);
+    }
+
+    /// Store a user's latest keys (the server sends them again on every change)
+    pub fn set_keys(&mut self, username: &str, keys: Vec<E2eeSessionKey>) {
+        self.keys.insert(username.to_lowercase(), keys);
+    }
+
+    /// Remove the queued messages that can now be sent, with their routes
+    pub fn take_ready(
+        &mut self,
+        own_username: &str,
+    ) -> Vec<(QueuedPrivateMessage, PrivateMessageRoute)> {
+        let mut ready = Vec::new();
+        let mut waiting = Vec::new();
+        for queued in std::mem::take(&mut self.queued) {
+            match self.route(own_username, &queued.to_username, &queued.message) {
+                PrivateMessageRoute::Wait => waiting.push(queued),
+                route => ready.push((queued, route)),
+            }
+        }
+        self.queued = waiting;
+        ready
+    }
+
+    /// Decrypt an envelope sealed for this connection
+    pub fn open(&self, envelope: &E2eeEnvelope) -> Option<String> {
+        self.identity.as_ref()?.open(envelope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys_of(state: &E2eeState) -> Vec<E2eeSessionKey> {
+        vec![E2eeSessionKey {
+            session_id: 1,
+            public_key: state.identity.as_ref().unwrap().public_key().to_string(),
+        }]
+    }
+
+    fn queued(to_username: &str, message: &str) -> QueuedPrivateMessage {
+        QueuedPrivateMessage {
+            to_username: to_username.to_string(),
+            message: message.to_string(),
+            routing: None,
+        }
+    }
+
+    #[test]
+    fn test_plain_until_enabled() {
+        let state = E2eeState::default();
+        assert_eq!(
+            state.route("alice", "bob", "hi"),
+            PrivateMessageRoute::Plain
+        );
+    }
+
+    #[test]
+    fn test_encrypts_once_keys_arrive() {
+        let mut alice = E2eeState::default();
+        alice.enable("alice").unwrap();
+        let mut bob = E2eeState::default();
+        bob.enable("bob").unwrap();
+
+        assert_eq!(alice.route("alice", "bob", "hi"), PrivateMessageRoute::Wait);
+
+        alice.set_keys("alice", keys_of(&alice));
+        let mut bob_keys = keys_of(&bob);
+        bob_keys[0].session_id = 2;
+        alice.set_keys("Bob", bob_keys);
+
+        let PrivateMessageRoute::Encrypted(envelopes) = alice.route("alice", "BOB", "hi") else {
+            panic!("Expected encrypted route");
+        };
+        assert_eq!(envelopes.len(), 2);
+        assert_eq!(alice.open(&envelopes[0]).as_deref(), Some("hi"));
+        assert_eq!(bob.open(&envelopes[1]).as_deref(), Some("hi"));
+        assert_eq!(bob.open(&envelopes[0]), None);
+    }
+
+    #[test]
+    fn test_plain_when_recipient_cannot_decrypt() {
+        let mut state = E2eeState::default();
+        state.enable("alice").unwrap();
+        state.set_keys("alice", keys_of(&state));
+        state.set_keys("bob", Vec::new());
+
+        assert_eq!(
+            state.route("alice", "bob", "hi"),
+            PrivateMessageRoute::Plain
+        );
+    }
+
+    #[test]
+    fn test_requests_each_user_once() {
+        let mut state = E2eeState::default();
+        state.enable("alice").unwrap();
+
+        assert!(!state.mark_requested("Alice"));
+        assert!(state.mark_requested("bob"));
+        assert!(!state.mark_requested("BOB"));
+
+        state.set_keys("carol", Vec::new());
+        assert!(!state.mark_requested("carol"));
+    }
+
+    #[test]
+    fn test_empty_keys_are_asked_for_again() {
+        let mut state = E2eeState::default();
+        state.enable("alice").unwrap();
+        assert!(state.mark_requested("bob"));
+        state.set_keys("alice", Vec::new());
+        state.set_keys("bob", Vec::new());
+
+        state.forget_empty_keys("alice", "Alice");
+        state.forget_empty_keys("alice", "Bob");
+        assert!(!state.mark_requested("alice"));
+        assert_eq!(state.route("alice", "bob", "hi"), PrivateMessageRoute::Wait);
+        assert!(state.mark_requested("bob"));
+    }
+
+    #[test]
+    fn test_take_ready_keeps_waiting_messages() {
+        let mut state = E2eeState::default();
+        state.enable("alice").unwrap();
+        state.queue(queued("bob", "first"));
+        state.queue(queued("carol", "second"));
+        state.queue(queued("bob", "third"));
+
+        state.set_keys("alice", keys_of(&state));
+        state.set_keys("bob", Vec::new());
+
+        let ready: Vec<_> = state
+            .take_ready("alice")
+            .into_iter()
+            .map(|(queued, route)| (queued.message, route))
+            .collect();
+        assert_eq!(
+            ready,
+            vec![
+                ("first".to_string(), PrivateMessageRoute::Plain),
+                ("third".to_string(), PrivateMessageRoute::Plain),
+            ]
+        );
+
+        state.set_keys("carol", Vec::new());
+        assert_eq!(state.take_ready("alice").len(), 1);
+        assert!(state.take_ready("alice").is_empty());
+    }
+}
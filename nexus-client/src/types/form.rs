@@ -20,8 +20,10 @@ const DEFAULT_USER_PERMISSIONS: &[&str] = &[
     "user_message",
 ];
 use crate::avatar::generate_identicon;
+use crate::i18n::{t, translate_permission};
 use crate::image::{CachedImage, decode_data_uri_max_width, decode_data_uri_square};
 use crate::style::{AVATAR_MAX_CACHE_SIZE, SERVER_IMAGE_MAX_CACHE_WIDTH};
+use crate::types::ConnectionStep;
 use nexus_common::{ALL_PERMISSIONS, DEFAULT_PORT_STR};
 
 /// User edit flow state (two-stage process)
@@ -48,6 +50,46 @@ pub enum UserEditState {
     },
 }
 
+/// Audience for a broadcast message
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum BroadcastTarget {
+    /// Every connected user
+    #[default]
+    Everyone,
+    /// Only users holding this permission (and admins)
+    Permission(String),
+}
+
+impl BroadcastTarget {
+    /// All selectable audiences, starting with everyone
+    pub fn all() -> Vec<Self> {
+        std::iter::once(Self::Everyone)
+            .chain(
+                ALL_PERMISSIONS
+                    .iter()
+                    .map(|perm| Self::Permission(perm.to_string())),
+            )
+            .collect()
+    }
+
+    /// Permission name to send to the server (`None` broadcasts to everyone)
+    pub fn permission(&self) -> Option<String> {
+        match self {
+            Self::Everyone => None,
+            Self::Permission(perm) => Some(perm.clone()),
+        }
+    }
+}
+
+impl std::fmt::Display for BroadcastTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Everyone => write!(f, "{}", t("broadcast-target-everyone")),
+            Self::Permission(perm) => write!(f, "{}", translate_permission(perm)),
+        }
+    }
+}
+
 /// Connection form state (not persisted)
 #[derive(Debug, Clone)]
 pub struct ConnectionFormState {
@@ -65,8 +107,14 @@ pub struct ConnectionFormState {
     pub error: Option<String>,
     /// Whether a connection attempt is currently in progress
     pub is_connecting: bool,
+    /// Latest step reached by the connection attempt in progress
+    pub step: Option<ConnectionStep>,
     /// Whether to save this connection as a bookmark on successful connect
     pub add_bookmark: bool,
+    /// Certificate fingerprint the server must present (from an invite link)
+    pub expected_fingerprint: Option<String>,
+    /// Pre-login banner accepted for the server in this form
+    pub accepted_banner: Option<String>,
 }
 
 impl Default for ConnectionFormState {
@@ -79,7 +127,10 @@ impl Default for ConnectionFormState {
             password: String::new(),
             error: None,
             is_connecting: false,
+            step: None,
             add_bookmark: false,
+            expected_fingerprint: None,
+            accepted_banner: None,
         }
     }
 }
@@ -92,6 +143,8 @@ impl ConnectionFormState {
         self.port = DEFAULT_PORT_STR.to_string();
         self.username.clear();
         self.password.clear();
+        self.expected_fingerprint = None;
+        self.accepted_banner = None;
     }
 }
 
@@ -210,6 +263,8 @@ pub struct SettingsFormState {
     pub cached_avatar: Option<CachedImage>,
     /// Default avatar for settings preview when no custom avatar is set
     pub default_avatar: CachedImage,
+    /// Bookmark whose pinned certificate is awaiting clear confirmation
+    pub confirm_clear_fingerprint: Option<usize>,
 }
 
 // Manual Debug implementation because CachedImage doesn't implement Debug
@@ -223,10 +278,37 @@ impl std::fmt::Debug for SettingsFormState {
                 &self.cached_avatar.as_ref().map(|_| "<cached>"),
             )
             .field("default_avatar", &"<cached>")
+            .field("confirm_clear_fingerprint", &self.confirm_clear_fingerprint)
             .finish()
     }
 }
 
+// =============================================================================
+// Profile Edit State
+// =============================================================================
+
+/// Own profile edit form state (shown in the user info panel)
+#[derive(Debug, Clone, Default)]
+pub struct ProfileEditState {
+    /// Bio (editable, empty clears it)
+    pub bio: String,
+    /// Pronouns (editable, empty clears them)
+    pub pronouns: String,
+    /// Error message to display
+    pub error: Option<String>,
+}
+
+impl ProfileEditState {
+    /// Create a new profile edit state with the current values
+    pub fn new(bio: Option<&str>, pronouns: Option<&str>) -> Self {
+        Self {
+            bio: bio.unwrap_or("").to_string(),
+            pronouns: pronouns.unwrap_or("").to_string(),
+            error: None,
+        }
+    }
+}
+
 // =============================================================================
 // Server Info Edit State
 // =============================================================================
@@ -326,6 +408,7 @@ impl SettingsFormState {
             error: None,
             cached_avatar,
             default_avatar,
+            confirm_clear_fingerprint: None,
         }
     }
 }
@@ -0,0 +1,121 @@
+//! Inactivity tracking for automatic away status
+//!
+//! Key presses, clicks, scrolling and window focus count as activity. Once
+//! none has happened for the configured timeout the user is marked away, and
+//! the next bit of activity brings them back. Typing counts too, so nobody
+//! goes away halfway through writing a message.
+//!
+//! The same record keeps incoming private messages from pulling focus to
+//! their tab while the user is in the middle of something.
+
+use std::time::{Duration, Instant};
+
+/// How often the idle timeout is checked while auto-away is enabled
+pub const AUTO_AWAY_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long the user must leave the client alone before an incoming private
+/// message may switch to its tab
+pub const FOCUS_MESSAGE_IDLE: Duration = Duration::from_secs(30);
+
+/// Time of the last user activity and whether that makes the user away
+#[derive(Debug, Clone, Copy)]
+pub struct IdleTracker {
+    last_activity: Instant,
+    away: bool,
+}
+
+impl IdleTracker {
+    /// Start tracking with activity at `now`
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_activity: now,
+            away: false,
+        }
+    }
+
+    /// Whether the user is currently away
+    pub fn is_away(&self) -> bool {
+        self.away
+    }
+
+    /// Time since the last activity
+    pub fn idle_for(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.last_activity)
+    }
+
+    /// Record activity, returning true if it ended an away period
+    pub fn activity(&mut self, now: Instant) -> bool {
+        self.last_activity = now;
+        std::mem::replace(&mut self.away, false)
+    }
+
+    /// Mark the user away if idle for `timeout`, returning true if that just happened
+    pub fn check(&mut self, now: Instant, timeout: Duration) -> bool {
+        if self.away || now.duration_since(self.last_activity) < timeout {
+            return false;
+        }
+        self.away = true;
+        true
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_away_after_timeout() {
+        let start = Instant::now();
+        let timeout = Duration::from_secs(600);
+        let mut idle = IdleTracker::new(start);
+
+        assert!(!idle.check(start + timeout - Duration::from_secs(1), timeout));
+        assert!(!idle.is_away());
+
+        assert!(idle.check(start + timeout, timeout));
+        assert!(idle.is_away());
+
+        // Only the transition is reported
+        assert!(!idle.check(start + timeout * 2, timeout));
+    }
+
+    #[test]
+    fn test_activity_resets_timer_and_ends_away() {
+        let start = Instant::now();
+        let timeout = Duration::from_secs(600);
+        let mut idle = IdleTracker::new(start);
+
+        // Activity before the timeout pushes it back
+        let typed = start + Duration::from_secs(500);
+        assert!(!idle.activity(typed));
+        assert!(!idle.check(start + timeout, timeout));
+
+        assert!(idle.check(typed + timeout, timeout));
+        assert!(idle.activity(typed + timeout + Duration::from_secs(1)));
+        assert!(!idle.is_away());
+    }
+
+    #[test]
+    fn test_idle_for_counts_from_last_activity() {
+        let start = Instant::now();
+        let mut idle = IdleTracker::new(start);
+        assert_eq!(
+            idle.idle_for(start + FOCUS_MESSAGE_IDLE),
+            FOCUS_MESSAGE_IDLE
+        );
+
+        let clicked = start + Duration::from_secs(45);
+        idle.activity(clicked);
+        assert_eq!(
+            idle.idle_for(clicked + Duration::from_secs(5)),
+            Duration::from_secs(5)
+        );
+
+        // A clock that reads earlier than the last activity is not idle at all
+        assert_eq!(idle.idle_for(start), Duration::ZERO);
+    }
+}
@@ -0,0 +1,175 @@
+//! Keepalive round-trip tracking for connection health
+//!
+//! The client periodically sends a `Ping` to each connected server and times
+//! how long the matching `Pong` takes to arrive. A short rolling window of
+//! those round-trip times, plus a count of unanswered pings, is turned into a
+//! coarse health rating for the server list.
+
+use nexus_common::framing::MessageId;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// How often a ping is sent to each connected server
+pub const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Number of recent round-trip samples averaged into the health rating
+const RTT_SAMPLE_COUNT: usize = 5;
+
+/// Average round-trip time below which the connection is rated good
+const RTT_GOOD_THRESHOLD: Duration = Duration::from_millis(250);
+
+/// Average round-trip time below which the connection is rated fair
+const RTT_FAIR_THRESHOLD: Duration = Duration::from_millis(1000);
+
+/// Consecutive unanswered pings after which the connection is rated poor
+const MISSED_PONGS_POOR: u32 = 2;
+
+/// Coarse connection quality shown beside each connected server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionHealth {
+    /// No round-trip has been measured yet
+    Unknown,
+    /// Low latency, no missed pings
+    Good,
+    /// Noticeable latency or a single missed ping
+    Fair,
+    /// High latency or several missed pings in a row
+    Poor,
+}
+
+/// Outstanding ping and recent round-trip samples for one connection
+#[derive(Debug, Clone, Default)]
+pub struct Keepalive {
+    /// The ping awaiting a pong, with the time it was sent
+    pending: Option<(MessageId, Instant)>,
+    /// Most recent round-trip times, oldest first
+    samples: VecDeque<Duration>,
+    /// Pings that went unanswered in a row
+    missed: u32,
+}
+
+impl Keepalive {
+    /// Record that a ping was sent
+    ///
+    /// If the previous ping is still unanswered it is counted as missed.
+    pub fn ping_sent(&mut self, message_id: MessageId, at: Instant) {
+        if self.pending.is_some() {
+            self.missed = self.missed.saturating_add(1);
+        }
+        self.pending = Some((message_id, at));
+    }
+
+    /// Record a pong, returning `false` if it doesn't answer the outstanding ping
+    pub fn pong_received(&mut self, message_id: MessageId, at: Instant) -> bool {
+        match self.pending {
+            Some((id, sent)) if id == message_id => {
+                if self.samples.len() == RTT_SAMPLE_COUNT {
+                    self.samples.pop_front();
+                }
+                self.samples.push_back(at.saturating_duration_since(sent));
+                self.pending = None;
+                self.missed = 0;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Average of the recent round-trip samples
+    pub fn average_rtt(&self) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let total: Duration = self.samples.iter().sum();
+        Some(total / self.samples.len() as u32)
+    }
+
+    /// Health rating from the recent samples and missed pings
+    pub fn health(&self) -> ConnectionHealth {
+        if self.missed >= MISSED_PONGS_POOR {
+            return ConnectionHealth::Poor;
+        }
+        let Some(rtt) = self.average_rtt() else {
+            return if self.missed > 0 {
+                ConnectionHealth::Fair
+            } else {
+                ConnectionHealth::Unknown
+            };
+        };
+        let health = if rtt < RTT_GOOD_THRESHOLD {
+            ConnectionHealth::Good
+        } else if rtt < RTT_FAIR_THRESHOLD {
+            ConnectionHealth::Fair
+        } else {
+            ConnectionHealth::Poor
+        };
+        if self.missed > 0 && health == ConnectionHealth::Good {
+            ConnectionHealth::Fair
+        } else {
+            health
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(keepalive: &mut Keepalive, rtt: Duration) {
+        let id = MessageId::new();
+        let sent = Instant::now();
+        keepalive.ping_sent(id, sent);
+        assert!(keepalive.pong_received(id, sent + rtt));
+    }
+
+    #[test]
+    fn test_unknown_without_samples() {
+        assert_eq!(Keepalive::default().health(), ConnectionHealth::Unknown);
+    }
+
+    #[test]
+    fn test_health_from_average_rtt() {
+        let mut keepalive = Keepalive::default();
+        sample(&mut keepalive, Duration::from_millis(50));
+        assert_eq!(keepalive.health(), ConnectionHealth::Good);
+
+        let mut keepalive = Keepalive::default();
+        sample(&mut keepalive, Duration::from_millis(500));
+        assert_eq!(keepalive.health(), ConnectionHealth::Fair);
+
+        let mut keepalive = Keepalive::default();
+        sample(&mut keepalive, Duration::from_millis(1500));
+        assert_eq!(keepalive.health(), ConnectionHealth::Poor);
+    }
+
+    #[test]
+    fn test_samples_are_capped() {
+        let mut keepalive = Keepalive::default();
+        sample(&mut keepalive, Duration::from_millis(5000));
+        for _ in 0..RTT_SAMPLE_COUNT {
+            sample(&mut keepalive, Duration::from_millis(100));
+        }
+        assert_eq!(keepalive.average_rtt(), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_missed_pongs_degrade_health() {
+        let mut keepalive = Keepalive::default();
+        sample(&mut keepalive, Duration::from_millis(50));
+        let now = Instant::now();
+        keepalive.ping_sent(MessageId::new(), now);
+        keepalive.ping_sent(MessageId::new(), now);
+        assert_eq!(keepalive.health(), ConnectionHealth::Fair);
+        keepalive.ping_sent(MessageId::new(), now);
+        assert_eq!(keepalive.health(), ConnectionHealth::Poor);
+    }
+
+    #[test]
+    fn test_stale_pong_ignored() {
+        let mut keepalive = Keepalive::default();
+        let now = Instant::now();
+        keepalive.ping_sent(MessageId::new(), now);
+        assert!(!keepalive.pong_received(MessageId::new(), now));
+        assert_eq!(keepalive.average_rtt(), None);
+    }
+}
@@ -1,22 +1,32 @@
 //! Message types for the Elm-style architecture
 
 use iced::Theme;
-use iced::widget::markdown;
+use iced::widget::{markdown, text_editor};
 
 use nexus_common::framing::MessageId;
 
-use super::{ChatTab, NetworkConnection, ServerMessage};
+use super::{BroadcastTarget, ChatTab, ConnectionStep, NetworkConnection, ServerMessage};
+use crate::config::density::UiDensity;
+use crate::config::message_format::MessageFormatPreset;
+use crate::config::timezone::TimezonePreference;
 use crate::image::ImagePickerError;
+use crate::network::UpdateInfo;
 
 /// Messages that drive the application state machine
 #[derive(Debug, Clone)]
 pub enum Message {
+    /// Pre-login banner: Continue button pressed (reconnect and log in)
+    AcceptBanner,
     /// Fingerprint mismatch: Accept button pressed (update stored fingerprint)
     AcceptNewFingerprint,
+    /// Server rules dialog: Accept button pressed (by connection_id)
+    AcceptRules(usize),
     /// Connection form: Add bookmark checkbox toggled
     AddBookmarkToggled(bool),
     /// Admin panel: Enabled checkbox toggled
     AdminEnabledToggled(bool),
+    /// Chat: Reconnect pressed on an admin notice banner (by connection_id)
+    AdminNoticeReconnect(usize),
     /// Admin panel: Is Admin checkbox toggled
     AdminIsAdminToggled(bool),
     /// Admin panel: Password field changed
@@ -25,8 +35,19 @@ pub enum Message {
     AdminPermissionToggled(String, bool),
     /// Admin panel: Username field changed
     AdminUsernameChanged(String),
+    /// Fingerprint mismatch: Always Accept button pressed (also trust future changes)
+    AlwaysAcceptNewFingerprint,
+    /// Shared clock advanced (only runs while a periodic job or animation needs it)
+    ClockTick,
+    /// Network: Server sent a pre-login banner the user has not accepted yet
+    BannerReceived {
+        bookmark_index: Option<usize>,
+        banner: String,
+    },
     /// Bookmark editor: Address field changed
     BookmarkAddressChanged(String),
+    /// Bookmark editor: Fallback addresses field changed
+    BookmarkAddressesChanged(String),
     /// Bookmark editor: Auto-connect checkbox toggled
     BookmarkAutoConnectToggled(bool),
     /// Network: Bookmark connection attempt completed (with display name)
@@ -35,16 +56,31 @@ pub enum Message {
         bookmark_index: Option<usize>,
         display_name: String,
     },
+    /// Network: Bookmark connection attempt reached a new step
+    BookmarkConnectionStepChanged {
+        bookmark_index: usize,
+        step: ConnectionStep,
+    },
     /// Bookmark editor: Name field changed
     BookmarkNameChanged(String),
     /// Bookmark editor: Password field changed
     BookmarkPasswordChanged(String),
     /// Bookmark editor: Port field changed
     BookmarkPortChanged(String),
+    /// Bookmark editor: Clear theme button pressed (use global theme)
+    BookmarkThemeCleared,
+    /// Bookmark editor: Theme selected from picker
+    BookmarkThemeSelected(Theme),
+    /// Bookmark editor: Trust certificate changes checkbox toggled
+    BookmarkTrustCertificateChangesToggled(bool),
     /// Bookmark editor: Username field changed
     BookmarkUsernameChanged(String),
+    /// Bookmark editor: Watched users field changed
+    BookmarkWatchedUsersChanged(String),
     /// Broadcast: Message input changed
     BroadcastMessageChanged(String),
+    /// Broadcast: Audience selected from picker
+    BroadcastTargetSelected(BroadcastTarget),
     /// User add panel: Cancel button pressed
     CancelAddUser,
     /// Bookmark editor: Cancel button pressed
@@ -53,22 +89,36 @@ pub enum Message {
     CancelBroadcast,
     /// Server info edit: Cancel button pressed (exit edit mode)
     CancelEditServerInfo,
+    /// Profile edit: Cancel button pressed (exit edit mode)
+    CancelEditProfile,
+    /// Pre-login banner: Cancel button pressed (don't log in)
+    CancelBanner,
     /// User edit panel: Cancel button pressed
     CancelEditUser,
     /// Fingerprint mismatch: Cancel button pressed (reject new certificate)
     CancelFingerprintMismatch,
-    /// Chat: Message input field changed
-    ChatInputChanged(String),
+    /// Chat: Cancel pressed on the large paste confirmation (keep editing)
+    CancelLargePaste,
+    /// Chat: Message input edited (typing, cursor movement, paste)
+    ChatInputAction(text_editor::Action),
+    /// Chat: Send pressed on the large paste confirmation
+    ConfirmLargePaste,
     /// Chat scrollable: scroll position changed
     ChatScrolled(iced::widget::scrollable::Viewport),
     /// Close a user message tab
     CloseUserMessageTab(String),
     /// Connection form: Connect button pressed
     ConnectPressed,
+    /// Chat: Message right-clicked to copy it (index into the active tab's messages)
+    CopyChatLine(usize),
+    /// Chat: "Copied to clipboard" confirmation timed out
+    CopyConfirmationExpired,
     /// Connect to a bookmark by index
     ConnectToBookmark(usize),
     /// Network: Connection attempt completed
     ConnectionResult(Result<NetworkConnection, String>),
+    /// Network: Connection attempt reached a new step
+    ConnectionStepChanged(ConnectionStep),
     /// Admin panel: Create User button pressed
     CreateUserPressed,
     /// Delete a bookmark by index
@@ -77,6 +127,8 @@ pub enum Message {
     DeleteUserPressed(String),
     /// Disconnect from server by connection_id
     DisconnectFromServer(usize),
+    /// Chat: Dismiss pressed on an admin notice banner (by connection_id)
+    DismissAdminNotice(usize),
     /// User edit panel: Enabled checkbox toggled
     EditEnabledToggled(bool),
     /// User edit panel: Is Admin checkbox toggled
@@ -91,6 +143,12 @@ pub enum Message {
     EditUsernameChanged(String),
     /// User edit panel: Edit button pressed (stage 1)
     EditUserPressed,
+    /// Profile edit: Bio field changed
+    EditProfileBioChanged(String),
+    /// User info panel: Edit Profile button pressed (enter edit mode)
+    EditProfilePressed,
+    /// Profile edit: Pronouns field changed
+    EditProfilePronounsChanged(String),
     /// Server info edit: Description field changed
     EditServerInfoDescriptionChanged(String),
     /// Server info edit: Image loaded from file picker (data URI or error)
@@ -107,20 +165,38 @@ pub enum Message {
     ClearServerImagePressed,
     /// Keyboard or mouse event
     Event(iced::Event),
+    /// Network: Time to send a keepalive ping to each connected server
+    KeepaliveTick,
+    /// Auto-away: Key press, click or scroll in any window
+    UserActivity,
+    /// User info: Disconnect button pressed for one of the user's sessions
+    KickSessionPressed(u32),
     /// Keyboard: Navigate to next chat tab (Ctrl+Tab)
     NextChatTab,
     /// Network: Error occurred on connection
     NetworkError(usize, String),
     /// Connection form: Password field changed
     PasswordChanged(String),
+    /// Chat: Pop a user message tab out into its own window
+    PopOutMessageTab(String),
+    /// Popout window: Message input edited (typing, cursor movement, paste)
+    PopoutInputAction(iced::window::Id, text_editor::Action),
+    /// Popout window: Send button pressed
+    PopoutSendPressed(iced::window::Id),
     /// Connection form: Port field changed
     PortChanged(String),
     /// Keyboard: Navigate to previous chat tab (Ctrl+Shift+Tab)
     PrevChatTab,
+    /// Chat: Message double-clicked to quote it (index into the active tab's messages)
+    ReplyTo(usize),
+    /// Keyboard: Open the message tab of the last user who messaged us (Ctrl+R)
+    ReplyToLast,
     /// Bookmark editor: Save button pressed
     SaveBookmark,
     /// Broadcast panel: Send button pressed
     SendBroadcastPressed,
+    /// Chat: Send debounce window ended (re-enables the send button)
+    SendDebounceExpired,
     /// Chat: Send message button pressed
     SendMessagePressed,
     /// Connection form: Server address field changed
@@ -149,16 +225,56 @@ pub enum Message {
     ToggleBroadcast,
     /// Toolbar: Toggle Edit User panel (optionally pre-populate username)
     ToggleEditUser(Option<String>),
+    /// Chat: Show more/less pressed on a collapsed message (index into the active tab's messages)
+    ToggleMessageExpanded(usize),
+    /// Chat: Unpin button pressed on a pinned message (message_id)
+    UnpinMessagePressed(u64),
+    /// Settings panel: Alert on unexpected disconnect checkbox toggled
+    AlertOnDisconnectToggled(bool),
+    /// Settings panel: Alert on mention checkbox toggled
+    AlertOnMentionToggled(bool),
+    /// Settings panel: Focus new private messages checkbox toggled
+    FocusNewMessagesToggled(bool),
     /// Settings panel: Cancel button pressed (restore original settings)
     CancelSettings,
     /// Settings panel: Chat font size selected from picker
     ChatFontSizeSelected(u8),
+    /// Settings panel: Auto-away idle minutes changed
+    AutoAwayMinutesChanged(u32),
+    /// Settings panel: Auto-away checkbox toggled
+    AutoAwayToggled(bool),
+    /// Settings panel: Chat message format template field changed
+    ChatMessageFormatChanged(String),
+    /// Settings panel: Chat message format preset selected from picker
+    ChatMessageFormatPresetSelected(MessageFormatPreset),
     /// Settings panel: Clear avatar button pressed
     ClearAvatarPressed,
+    /// Settings panel: Clear pressed on a bookmark's pinned certificate (asks to confirm)
+    ClearFingerprintPressed(usize),
+    /// Settings panel: Cancel pressed on the clear certificate confirmation
+    CancelClearFingerprint,
+    /// Settings panel: Clear confirmed for the pending bookmark's pinned certificate
+    ConfirmClearFingerprint,
+    /// Settings panel: Copy pressed on a bookmark's pinned certificate
+    CopyFingerprintPressed(usize),
+    /// Settings panel: Ctrl+Enter sends messages checkbox toggled
+    CtrlEnterSendsToggled(bool),
+    /// Settings panel: Connection event log file field changed
+    ConnectionLogPathChanged(String),
+    /// Connection event log file couldn't be written
+    ConnectionLogWriteFailed { path: String, error: String },
     /// Settings panel: Connection notifications checkbox toggled
     ConnectionNotificationsToggled(bool),
+    /// Settings panel: Hide repeated topic on reconnect checkbox toggled
+    HideRepeatedTopicToggled(bool),
     /// Settings panel: Avatar loaded from file picker (data URI or error)
     AvatarLoaded(Result<String, ImagePickerError>),
+    /// Settings panel: Maximum open message tabs changed
+    MaxMessageTabsChanged(usize),
+    /// Settings panel: Large paste confirmation line threshold changed
+    PasteConfirmLinesChanged(usize),
+    /// Settings panel: Long message collapse line threshold changed
+    CollapseMessageLinesChanged(usize),
     /// Settings panel: Pick avatar button pressed
     PickAvatarPressed,
     /// Settings panel: Save button pressed (persist to disk)
@@ -171,18 +287,50 @@ pub enum Message {
     ToggleSettings,
     /// Settings panel: Theme selected from picker
     ThemeSelected(Theme),
+    /// Settings panel: Don't save passwords checkbox toggled
+    ForgetPasswordsToggled(bool),
+    /// Settings panel: Sync preferences with server checkbox toggled
+    SyncPreferencesToggled(bool),
+    /// Settings panel: Check for updates at startup checkbox toggled
+    CheckForUpdatesToggled(bool),
+    /// Settings panel: SOCKS5 proxy address field changed
+    ProxyAddressChanged(String),
+    /// Settings panel: SOCKS5 proxy port changed
+    ProxyPortChanged(u16),
+    /// Settings panel: Use SOCKS5 proxy checkbox toggled
+    ProxyToggled(bool),
+    /// Settings panel: Quiet hours end time field changed
+    QuietHoursEndChanged(String),
+    /// Settings panel: Quiet hours start time field changed
+    QuietHoursStartChanged(String),
+    /// Settings panel: Quiet hours checkbox toggled
+    QuietHoursToggled(bool),
+    /// Settings panel: Notify about watched users during quiet hours checkbox toggled
+    WatchedUsersIgnoreQuietHoursToggled(bool),
+    /// Settings panel: Timestamp timezone selected from picker
+    TimestampTimezoneSelected(TimezonePreference),
     /// About panel: URL link clicked
     OpenUrl(markdown::Uri),
+    /// Update banner: Dismiss button pressed
+    DismissUpdateBanner,
+    /// Update check finished (a newer release, if one was found)
+    UpdateCheckCompleted(Option<UpdateInfo>),
     /// About panel: Close button pressed
     CloseAbout,
+    /// Page viewer: Close button pressed (by connection_id)
+    ClosePage(usize),
     /// Server info panel: Close button pressed
     CloseServerInfo,
     /// User info panel: Close button pressed
     CloseUserInfo,
     /// Toolbar: Show About panel
     ShowAbout,
+    /// Settings panel: Show role badges in the user list toggled
+    ShowRoleBadgesToggled(bool),
     /// Toolbar: Show Server Info panel
     ShowServerInfo,
+    /// Settings panel: Interface density selected from picker
+    UiDensitySelected(UiDensity),
     /// Settings panel: Use 24-hour time format toggled
     Use24HourTimeToggled(bool),
     /// Toolbar: Toggle user list sidebar
@@ -191,6 +339,8 @@ pub enum Message {
     UpdateUserPressed,
     /// Server info edit: Update button pressed (save changes)
     UpdateServerInfoPressed,
+    /// Profile edit: Save button pressed
+    UpdateProfilePressed,
     /// User list: Info icon clicked on expanded user
     UserInfoIconClicked(String),
     /// User list: Kick icon clicked on expanded user (disconnect - future)
@@ -209,9 +359,10 @@ pub enum Message {
     ValidateEditUser,
     /// Window: Close requested - query size and position
     WindowCloseRequested(iced::window::Id),
-    /// Window: Save settings and close (internal - after querying size and position)
+    /// Window: Input focus gained (true) or lost (false) by a window
+    WindowFocusChanged(iced::window::Id, bool),
+    /// Window: Save settings and exit (internal - after querying main window size and position)
     WindowSaveAndClose {
-        id: iced::window::Id,
         width: f32,
         height: f32,
         x: Option<i32>,
@@ -0,0 +1,238 @@
+//! Splitting chat messages into plain text and code blocks
+//!
+//! Text between triple backticks is shown as a pre-formatted block with its
+//! whitespace preserved. An optional language tag after the opening fence
+//! (as in "```rust") is dropped. A fence without a closing partner is left
+//! as plain text.
+//!
+//! The `art` tag marks BBS art (see `/art`), shown like a code block but
+//! never wrapped, so wide pieces scroll instead of being reflowed.
+//!
+//! Long messages can be collapsed to their first lines; code and art blocks
+//! are cut line by line like text, so pasted logs and art collapse too.
+
+/// Code block fence
+pub const FENCE: &str = "```";
+
+/// Language tag marking a code block as art
+pub const ART_TAG: &str = "art";
+
+/// A run of message text to render one way
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageBlock<'a> {
+    /// Plain text (may contain newlines)
+    Text(&'a str),
+    /// Pre-formatted text from inside a fenced code block
+    Code(&'a str),
+    /// Pre-formatted text from inside a fenced block tagged `art`
+    Art(&'a str),
+}
+
+/// Split a message into plain text and fenced code blocks
+///
+/// The newline right after an opening fence and right before a closing fence
+/// belongs to the fence, not to the surrounding blocks. Text blocks left
+/// empty by that are omitted.
+pub fn split_message_blocks(message: &str) -> Vec<MessageBlock<'_>> {
+    let mut blocks = Vec::new();
+    let mut rest = message;
+
+    while let Some(open) = rest.find(FENCE) {
+        let after_open = &rest[open + FENCE.len()..];
+        let Some(close) = after_open.find(FENCE) else {
+            break;
+        };
+
+        push_text(
+            &mut blocks,
+            rest[..open].strip_suffix('\n').unwrap_or(&rest[..open]),
+        );
+        blocks.push(code_block(&after_open[..close]));
+
+        rest = &after_open[close + FENCE.len()..];
+        rest = rest.strip_prefix('\n').unwrap_or(rest);
+    }
+
+    push_text(&mut blocks, rest);
+    blocks
+}
+
+/// Add a text block unless it is empty
+fn push_text<'a>(blocks: &mut Vec<MessageBlock<'a>>, text: &'a str) {
+    if !text.is_empty() {
+        blocks.push(MessageBlock::Text(text));
+    }
+}
+
+/// Build the block for a fenced block's contents
+///
+/// The language tag and the fence newlines are stripped.
+fn code_block(inner: &str) -> MessageBlock<'_> {
+    let (tag, body) = match inner.split_once('\n') {
+        // First line is a language tag (a single word) or empty
+        Some((first, body)) if !first.contains(char::is_whitespace) => (first, body),
+        _ => ("", inner),
+    };
+    let body = body.strip_suffix('\n').unwrap_or(body);
+    if tag == ART_TAG {
+        MessageBlock::Art(body)
+    } else {
+        MessageBlock::Code(body)
+    }
+}
+
+/// Number of lines a message's blocks take up
+pub fn message_line_count(blocks: &[MessageBlock<'_>]) -> usize {
+    blocks
+        .iter()
+        .map(|block| block.contents().split('\n').count())
+        .sum()
+}
+
+/// Keep only the first `max_lines` lines of a message's blocks
+///
+/// A block straddling the limit is cut short; blocks after it are dropped.
+pub fn collapse_message_blocks<'a>(
+    blocks: &[MessageBlock<'a>],
+    max_lines: usize,
+) -> Vec<MessageBlock<'a>> {
+    let mut collapsed = Vec::new();
+    let mut remaining = max_lines;
+
+    for block in blocks {
+        if remaining == 0 {
+            break;
+        }
+        let contents = block.contents();
+        let kept = match contents.match_indices('\n').nth(remaining - 1) {
+            Some((end, _)) => &contents[..end],
+            None => contents,
+        };
+        remaining -= kept.split('\n').count();
+        collapsed.push(block.with_contents(kept));
+    }
+
+    collapsed
+}
+
+impl<'a> MessageBlock<'a> {
+    /// Text of the block, whatever its kind
+    fn contents(&self) -> &'a str {
+        match *self {
+            MessageBlock::Text(text) | MessageBlock::Code(text) | MessageBlock::Art(text) => text,
+        }
+    }
+
+    /// Block of the same kind holding `contents`
+    fn with_contents(&self, contents: &'a str) -> Self {
+        match self {
+            MessageBlock::Text(_) => MessageBlock::Text(contents),
+            MessageBlock::Code(_) => MessageBlock::Code(contents),
+            MessageBlock::Art(_) => MessageBlock::Art(contents),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text_is_one_block() {
+        assert_eq!(
+            split_message_blocks("hello\nworld"),
+            vec![MessageBlock::Text("hello\nworld")]
+        );
+        assert!(split_message_blocks("").is_empty());
+    }
+
+    #[test]
+    fn test_code_block_between_text() {
+        assert_eq!(
+            split_message_blocks("look:\n```\nfn main() {}\n```\nthanks"),
+            vec![
+                MessageBlock::Text("look:"),
+                MessageBlock::Code("fn main() {}"),
+                MessageBlock::Text("thanks"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_language_tag_is_dropped() {
+        assert_eq!(
+            split_message_blocks("```rust\nlet x = 1;\n    x + 1\n```"),
+            vec![MessageBlock::Code("let x = 1;\n    x + 1")]
+        );
+    }
+
+    #[test]
+    fn test_art_tag_keeps_spacing() {
+        assert_eq!(
+            split_message_blocks("```art\n  /\\_/\\  \n ( o.o ) \n```"),
+            vec![MessageBlock::Art("  /\\_/\\  \n ( o.o ) ")]
+        );
+    }
+
+    #[test]
+    fn test_inline_fence_keeps_contents() {
+        assert_eq!(
+            split_message_blocks("run ```cargo test``` first"),
+            vec![
+                MessageBlock::Text("run "),
+                MessageBlock::Code("cargo test"),
+                MessageBlock::Text(" first"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unclosed_fence_is_plain_text() {
+        assert_eq!(
+            split_message_blocks("```\nnot closed"),
+            vec![MessageBlock::Text("```\nnot closed")]
+        );
+    }
+
+    #[test]
+    fn test_multiple_code_blocks() {
+        assert_eq!(
+            split_message_blocks("```\na\n```\n```\nb\n```"),
+            vec![MessageBlock::Code("a"), MessageBlock::Code("b")]
+        );
+    }
+
+    #[test]
+    fn test_message_line_count() {
+        let blocks = split_message_blocks("one\ntwo\n```\na\nb\nc\n```\nend");
+        assert_eq!(message_line_count(&blocks), 6);
+        assert_eq!(message_line_count(&[]), 0);
+    }
+
+    #[test]
+    fn test_collapse_cuts_text() {
+        let blocks = split_message_blocks("1\n2\n3\n4");
+        assert_eq!(
+            collapse_message_blocks(&blocks, 2),
+            vec![MessageBlock::Text("1\n2")]
+        );
+        assert_eq!(collapse_message_blocks(&blocks, 4), blocks);
+    }
+
+    #[test]
+    fn test_collapse_cuts_code_and_art() {
+        let blocks = split_message_blocks("log:\n```\na\nb\nc\n```\n```art\nx\ny\n```");
+        assert_eq!(
+            collapse_message_blocks(&blocks, 3),
+            vec![MessageBlock::Text("log:"), MessageBlock::Code("a\nb")]
+        );
+        assert_eq!(
+            collapse_message_blocks(&blocks, 5),
+            vec![
+                MessageBlock::Text("log:"),
+                MessageBlock::Code("a\nb\nc"),
+                MessageBlock::Art("x"),
+            ]
+        );
+    }
+}
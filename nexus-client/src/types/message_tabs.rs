@@ -0,0 +1,191 @@
+//! Recency tracking for private message tabs
+//!
+//! The number of open private message tabs per server is capped so a flood
+//! of messages from many accounts can't grow the tab bar without bound. When
+//! a new tab would exceed the cap, the least recently active tab is closed.
+//! Its history is set aside rather than discarded, and comes back if the
+//! tab is opened again (for example when that user sends another message).
+//! The set-aside history is bounded too: only the newest messages of each
+//! tab are kept, and the longest-evicted tabs are forgotten first.
+
+use std::collections::HashMap;
+
+use super::ChatMessage;
+
+/// Maximum evicted tabs whose history is kept (the longest evicted go first)
+const MAX_EVICTED_TABS: usize = 100;
+
+/// Maximum messages kept from each evicted tab (the oldest are dropped)
+const MAX_EVICTED_MESSAGES: usize = 200;
+
+/// History set aside when a tab was closed by the limit
+#[derive(Debug, Clone)]
+struct EvictedTab {
+    /// Value of `clock` when the tab was evicted
+    evicted_at: u64,
+    messages: Vec<ChatMessage>,
+}
+
+/// Last activity of each open message tab, plus history of evicted tabs
+#[derive(Debug, Clone, Default)]
+pub struct MessageTabs {
+    /// Value of `clock` when each open tab last had activity
+    last_active: HashMap<String, u64>,
+    /// Monotonic activity counter (avoids ties between tabs touched at once)
+    clock: u64,
+    /// History of tabs closed by the limit, keyed by username
+    evicted: HashMap<String, EvictedTab>,
+}
+
+impl MessageTabs {
+    /// Record activity (a message or a switch to the tab) on a tab
+    pub fn touch(&mut self, username: &str) {
+        self.clock += 1;
+        self.last_active.insert(username.to_string(), self.clock);
+    }
+
+    /// The least recently active of `candidates` (tabs never touched come first)
+    pub fn least_recent<'a>(
+        &self,
+        candidates: impl Iterator<Item = &'a String>,
+    ) -> Option<&'a String> {
+        candidates.min_by_key(|username| self.last_active.get(*username).copied().unwrap_or(0))
+    }
+
+    /// Set aside the history of a tab closed by the limit
+    pub fn evict(&mut self, username: String, mut messages: Vec<ChatMessage>) {
+        self.last_active.remove(&username);
+        if messages.len() > MAX_EVICTED_MESSAGES {
+            messages.drain(..messages.len() - MAX_EVICTED_MESSAGES);
+        }
+        self.clock += 1;
+        self.evicted.insert(
+            username,
+            EvictedTab {
+                evicted_at: self.clock,
+                messages,
+            },
+        );
+
+        if self.evicted.len() > MAX_EVICTED_TABS
+            && let Some(oldest) = self
+                .evicted
+                .iter()
+                .min_by_key(|(_, tab)| tab.evicted_at)
+                .map(|(username, _)| username.clone())
+        {
+            self.evicted.remove(&oldest);
+        }
+    }
+
+    /// Take back the history of an evicted tab so it can be reopened
+    pub fn restore(&mut self, username: &str) -> Option<Vec<ChatMessage>> {
+        self.evicted.remove(username).map(|tab| tab.messages)
+    }
+
+    /// Forget a tab the user closed themselves
+    pub fn forget(&mut self, username: &str) {
+        self.last_active.remove(username);
+        self.evicted.remove(username);
+    }
+
+    /// Follow a username change
+    pub fn rename(&mut self, previous: &str, new: &str) {
+        if let Some(active) = self.last_active.remove(previous) {
+            self.last_active.insert(new.to_string(), active);
+        }
+        if let Some(tab) = self.evicted.remove(previous) {
+            self.evicted.insert(new.to_string(), tab);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_least_recent_prefers_untouched_then_oldest() {
+        let mut tabs = MessageTabs::default();
+        let open = names(&["alice", "bob", "carol"]);
+
+        tabs.touch("alice");
+        tabs.touch("bob");
+        tabs.touch("carol");
+        assert_eq!(tabs.least_recent(open.iter()).unwrap(), "alice");
+
+        tabs.touch("alice");
+        assert_eq!(tabs.least_recent(open.iter()).unwrap(), "bob");
+
+        let with_new = names(&["alice", "bob", "dave"]);
+        assert_eq!(tabs.least_recent(with_new.iter()).unwrap(), "dave");
+    }
+
+    #[test]
+    fn test_evicted_history_is_restored_once() {
+        let mut tabs = MessageTabs::default();
+        tabs.touch("alice");
+        tabs.evict("alice".to_string(), vec![ChatMessage::new("alice", "hi")]);
+
+        let restored = tabs.restore("alice").unwrap();
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[0].message, "hi");
+        assert!(tabs.restore("alice").is_none());
+    }
+
+    #[test]
+    fn test_evicted_history_is_capped() {
+        let mut tabs = MessageTabs::default();
+        let messages = (0..MAX_EVICTED_MESSAGES + 5)
+            .map(|i| ChatMessage::new("alice", i.to_string()))
+            .collect();
+        tabs.evict("alice".to_string(), messages);
+        for i in 0..MAX_EVICTED_TABS {
+            tabs.evict(format!("user{i}"), vec![ChatMessage::new("user", "hi")]);
+        }
+
+        // The longest-evicted tab is forgotten once there are too many
+        assert!(tabs.restore("alice").is_none());
+        assert_eq!(tabs.evicted.len(), MAX_EVICTED_TABS);
+        tabs.evict("bob".to_string(), vec![ChatMessage::new("bob", "hi")]);
+        assert!(tabs.restore("user0").is_none());
+        assert!(tabs.restore("user1").is_some());
+        assert!(tabs.restore("bob").is_some());
+
+        // Only the newest messages of a tab are kept
+        let messages = (0..MAX_EVICTED_MESSAGES + 5)
+            .map(|i| ChatMessage::new("carol", i.to_string()))
+            .collect();
+        tabs.evict("carol".to_string(), messages);
+        let restored = tabs.restore("carol").unwrap();
+        assert_eq!(restored.len(), MAX_EVICTED_MESSAGES);
+        assert_eq!(restored[0].message, "5");
+    }
+
+    #[test]
+    fn test_forget_discards_evicted_history() {
+        let mut tabs = MessageTabs::default();
+        tabs.evict("alice".to_string(), vec![ChatMessage::new("alice", "hi")]);
+        tabs.forget("alice");
+        assert!(tabs.restore("alice").is_none());
+    }
+
+    #[test]
+    fn test_rename_moves_activity_and_history() {
+        let mut tabs = MessageTabs::default();
+        tabs.evict("alice".to_string(), vec![ChatMessage::new("alice", "hi")]);
+        tabs.rename("alice", "alicia");
+        assert!(tabs.restore("alice").is_none());
+        assert!(tabs.restore("alicia").is_some());
+
+        tabs.touch("bob");
+        tabs.touch("carol");
+        tabs.rename("bob", "robert");
+        let open = names(&["robert", "carol"]);
+        assert_eq!(tabs.least_recent(open.iter()).unwrap(), "robert");
+    }
+}
@@ -1,25 +1,59 @@
 //! Type definitions for the Nexus client
 
+mod animation;
 mod bookmark;
+mod clock;
 pub mod connection;
+mod connection_events;
 mod display;
+mod drafts;
+mod e2ee;
 mod form;
+mod idle;
+mod invite;
+mod keepalive;
 mod message;
+mod message_blocks;
+mod message_tabs;
 mod pending;
+mod popout;
+mod send_debounce;
 mod ui;
 mod view_config;
 
 // Re-export types for convenience
-pub use bookmark::{BookmarkEditMode, BookmarkEditState, ServerBookmark};
-pub use connection::{NetworkConnection, ServerConnection};
+pub use animation::{ANIMATION_INTERVAL, AnimationFrame};
+pub use bookmark::{BookmarkEditMode, BookmarkEditState, ServerBookmark, format_endpoint};
+pub use clock::{ClockTick, tick_interval};
+pub use connection::{
+    COPY_CONFIRMATION_DURATION, ConnectionStep, HISTORY_PAGE_SIZE, NetworkConnection,
+    ServerCapabilities, ServerConnection,
+};
+pub use connection_events::{
+    ConnectionEvent, ConnectionEventLog, ConnectionLogSender, REDACTED, connection_log_writer,
+    format_event_line,
+};
 pub use display::{ChatMessage, ChatTab, MessageType, ScrollState, UserInfo};
+pub use drafts::{MessageDraft, MessageDrafts};
+pub use e2ee::{E2eeState, PrivateMessageRoute, QueuedPrivateMessage};
 pub use form::{
-    ConnectionFormState, ServerInfoEditState, SettingsFormState, UserEditState, UserManagementState,
+    BroadcastTarget, ConnectionFormState, ProfileEditState, ServerInfoEditState, SettingsFormState,
+    UserEditState, UserManagementState,
 };
+pub use idle::{AUTO_AWAY_CHECK_INTERVAL, FOCUS_MESSAGE_IDLE, IdleTracker};
+pub use invite::{Invite, InviteError};
+pub use keepalive::{ConnectionHealth, KEEPALIVE_INTERVAL, Keepalive};
 pub use message::Message;
+pub use message_blocks::{
+    ART_TAG, FENCE, MessageBlock, collapse_message_blocks, message_line_count, split_message_blocks,
+};
+pub use message_tabs::MessageTabs;
 pub use nexus_common::protocol::ServerMessage;
 pub use pending::{PendingRequests, ResponseRouting};
+pub use popout::PopoutWindow;
+pub use send_debounce::{SEND_DEBOUNCE, SendDebounce};
 pub use ui::{
-    ActivePanel, FingerprintMismatch, FingerprintMismatchDetails, InputId, ScrollableId, UiState,
+    ActivePanel, FingerprintMismatch, FingerprintMismatchDetails, InfoPage, InputId, PendingBanner,
+    ScrollableId, UiState,
 };
 pub use view_config::{ToolbarState, ViewConfig};
@@ -30,12 +30,16 @@ pub enum ResponseRouting {
 /// a convenient method for tracking requests.
 pub trait PendingRequests {
     /// Track a pending request for response routing
-    fn track(&mut self, message_id: MessageId, routing: ResponseRouting);
+    ///
+    /// Returns `false` if the message ID was already outstanding, in which case
+    /// the previous routing is replaced.
+    #[must_use]
+    fn track(&mut self, message_id: MessageId, routing: ResponseRouting) -> bool;
 }
 
 impl PendingRequests for std::collections::HashMap<MessageId, ResponseRouting> {
-    fn track(&mut self, message_id: MessageId, routing: ResponseRouting) {
-        self.insert(message_id, routing);
+    fn track(&mut self, message_id: MessageId, routing: ResponseRouting) -> bool {
+        self.insert(message_id, routing).is_none()
     }
 }
 
@@ -48,7 +52,7 @@ mod tests {
     fn test_track_display_list_in_chat() {
         let mut pending: HashMap<MessageId, ResponseRouting> = HashMap::new();
         let id = MessageId::new();
-        pending.track(id, ResponseRouting::DisplayListInChat);
+        assert!(pending.track(id, ResponseRouting::DisplayListInChat));
         assert!(matches!(
             pending.get(&id),
             Some(ResponseRouting::DisplayListInChat)
@@ -59,7 +63,7 @@ mod tests {
     fn test_track_open_message_tab() {
         let mut pending: HashMap<MessageId, ResponseRouting> = HashMap::new();
         let id = MessageId::new();
-        pending.track(id, ResponseRouting::OpenMessageTab("alice".to_string()));
+        assert!(pending.track(id, ResponseRouting::OpenMessageTab("alice".to_string())));
         assert!(matches!(
             pending.get(&id),
             Some(ResponseRouting::OpenMessageTab(name)) if name == "alice"
@@ -70,7 +74,7 @@ mod tests {
     fn test_track_display_user_info_in_chat() {
         let mut pending: HashMap<MessageId, ResponseRouting> = HashMap::new();
         let id = MessageId::new();
-        pending.track(id, ResponseRouting::DisplayUserInfoInChat);
+        assert!(pending.track(id, ResponseRouting::DisplayUserInfoInChat));
         assert!(matches!(
             pending.get(&id),
             Some(ResponseRouting::DisplayUserInfoInChat)
@@ -81,10 +85,10 @@ mod tests {
     fn test_track_populate_user_info_panel() {
         let mut pending: HashMap<MessageId, ResponseRouting> = HashMap::new();
         let id = MessageId::new();
-        pending.track(
+        assert!(pending.track(
             id,
             ResponseRouting::PopulateUserInfoPanel("bob".to_string()),
-        );
+        ));
         assert!(matches!(
             pending.get(&id),
             Some(ResponseRouting::PopulateUserInfoPanel(name)) if name == "bob"
@@ -95,7 +99,7 @@ mod tests {
     fn test_remove_returns_tracked_routing() {
         let mut pending: HashMap<MessageId, ResponseRouting> = HashMap::new();
         let id = MessageId::new();
-        pending.track(id, ResponseRouting::DisplayListInChat);
+        assert!(pending.track(id, ResponseRouting::DisplayListInChat));
         let removed = pending.remove(&id);
         assert!(matches!(removed, Some(ResponseRouting::DisplayListInChat)));
         assert!(pending.is_empty());
@@ -106,8 +110,8 @@ mod tests {
         let mut pending: HashMap<MessageId, ResponseRouting> = HashMap::new();
         let id1 = MessageId::new();
         let id2 = MessageId::new();
-        pending.track(id1, ResponseRouting::DisplayListInChat);
-        pending.track(id2, ResponseRouting::OpenMessageTab("bob".to_string()));
+        assert!(pending.track(id1, ResponseRouting::DisplayListInChat));
+        assert!(pending.track(id2, ResponseRouting::OpenMessageTab("bob".to_string())));
         assert_eq!(pending.len(), 2);
         assert!(matches!(
             pending.get(&id1),
@@ -123,12 +127,31 @@ mod tests {
     fn test_track_overwrites_existing() {
         let mut pending: HashMap<MessageId, ResponseRouting> = HashMap::new();
         let id = MessageId::new();
-        pending.track(id, ResponseRouting::DisplayListInChat);
-        pending.track(id, ResponseRouting::OpenMessageTab("alice".to_string()));
+        assert!(pending.track(id, ResponseRouting::DisplayListInChat));
+        // Reusing an outstanding ID is reported
+        assert!(!pending.track(id, ResponseRouting::OpenMessageTab("alice".to_string())));
         assert_eq!(pending.len(), 1);
         assert!(matches!(
             pending.get(&id),
             Some(ResponseRouting::OpenMessageTab(name)) if name == "alice"
         ));
     }
+
+    #[test]
+    fn test_reused_id_does_not_route_to_old_target() {
+        let mut pending: HashMap<MessageId, ResponseRouting> = HashMap::new();
+        let id = MessageId::new();
+        assert!(pending.track(
+            id,
+            ResponseRouting::PopulateUserInfoPanel("bob".to_string())
+        ));
+        assert!(!pending.track(id, ResponseRouting::DisplayUserInfoInChat));
+
+        // The response goes to the newer request only
+        assert!(matches!(
+            pending.remove(&id),
+            Some(ResponseRouting::DisplayUserInfoInChat)
+        ));
+        assert!(pending.remove(&id).is_none());
+    }
 }
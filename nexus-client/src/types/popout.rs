@@ -0,0 +1,25 @@
+//! Private message tabs popped out into their own OS windows
+
+use iced::widget::text_editor;
+
+/// A private message tab shown in its own window
+///
+/// The tab's history stays on the connection; the popout only owns its
+/// message input so typing here does not disturb the main window's input.
+#[derive(Debug, Clone)]
+pub struct PopoutWindow {
+    /// User the private message tab is with
+    pub username: String,
+    /// Message input for this window (may span several lines)
+    pub message_input: text_editor::Content,
+}
+
+impl PopoutWindow {
+    /// Create a popout for the private message tab with `username`
+    pub fn new(username: String) -> Self {
+        Self {
+            username,
+            message_input: text_editor::Content::new(),
+        }
+    }
+}
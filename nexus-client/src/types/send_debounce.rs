@@ -0,0 +1,99 @@
+//! Debounce for the chat send action
+//!
+//! A repeated Enter press on a slow connection can send the same message
+//! twice. The client remembers the last message sent and drops an identical
+//! one to the same tab within a short window. The send button is also
+//! disabled for that window. The window is short enough that fast typists
+//! sending different messages are never held back.
+
+use std::time::{Duration, Instant};
+
+use super::ChatTab;
+
+/// How long after sending that an identical message is dropped
+pub const SEND_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// The last message sent from the chat input
+#[derive(Debug, Clone, Default)]
+pub struct SendDebounce {
+    /// Tab, content, and send time of the last message
+    last: Option<(ChatTab, String, Instant)>,
+}
+
+impl SendDebounce {
+    /// Record that a message was sent to a tab
+    pub fn record(&mut self, tab: ChatTab, message: String, at: Instant) {
+        self.last = Some((tab, message, at));
+    }
+
+    /// Whether sending this message now would repeat the last one within the window
+    pub fn is_repeat(&self, tab: &ChatTab, message: &str, at: Instant) -> bool {
+        self.last
+            .as_ref()
+            .is_some_and(|(last_tab, last_message, sent)| {
+                last_tab == tab && last_message == message && within_window(*sent, at)
+            })
+    }
+
+    /// Whether a message was sent within the window (the send button stays disabled)
+    pub fn is_active(&self, at: Instant) -> bool {
+        self.last
+            .as_ref()
+            .is_some_and(|(_, _, sent)| within_window(*sent, at))
+    }
+}
+
+/// Whether `at` falls inside the debounce window that began at `sent`
+fn within_window(sent: Instant, at: Instant) -> bool {
+    at.saturating_duration_since(sent) < SEND_DEBOUNCE
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nothing_sent_is_inactive() {
+        let debounce = SendDebounce::default();
+        let now = Instant::now();
+        assert!(!debounce.is_active(now));
+        assert!(!debounce.is_repeat(&ChatTab::Server, "hello", now));
+    }
+
+    #[test]
+    fn test_identical_message_within_window_is_repeat() {
+        let mut debounce = SendDebounce::default();
+        let sent = Instant::now();
+        debounce.record(ChatTab::Server, "hello".to_string(), sent);
+
+        let soon = sent + SEND_DEBOUNCE / 2;
+        assert!(debounce.is_active(soon));
+        assert!(debounce.is_repeat(&ChatTab::Server, "hello", soon));
+    }
+
+    #[test]
+    fn test_different_message_or_tab_is_not_repeat() {
+        let mut debounce = SendDebounce::default();
+        let sent = Instant::now();
+        debounce.record(ChatTab::Server, "hello".to_string(), sent);
+
+        let soon = sent + Duration::from_millis(10);
+        assert!(!debounce.is_repeat(&ChatTab::Server, "hello!", soon));
+        assert!(!debounce.is_repeat(&ChatTab::UserMessage("alice".to_string()), "hello", soon));
+    }
+
+    #[test]
+    fn test_window_expires() {
+        let mut debounce = SendDebounce::default();
+        let sent = Instant::now();
+        debounce.record(ChatTab::Server, "hello".to_string(), sent);
+
+        let later = sent + SEND_DEBOUNCE;
+        assert!(!debounce.is_active(later));
+        assert!(!debounce.is_repeat(&ChatTab::Server, "hello", later));
+    }
+}
@@ -89,6 +89,28 @@ pub struct FingerprintMismatch {
     pub display_name: String,
 }
 
+/// Pre-login banner awaiting the user's acceptance
+///
+/// The connection attempt has already ended; accepting the banner reconnects.
+#[derive(Debug, Clone)]
+pub struct PendingBanner {
+    /// Bookmark that was connecting (None for the connection form)
+    pub bookmark_index: Option<usize>,
+    /// Display name of the server
+    pub display_name: String,
+    /// Banner text sent by the server
+    pub banner: String,
+}
+
+/// Server info page open in the page viewer
+#[derive(Debug, Clone)]
+pub struct InfoPage {
+    /// Page name as the server stores it
+    pub name: String,
+    /// Page text
+    pub content: String,
+}
+
 /// Text input IDs for focus management
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum InputId {
@@ -108,10 +130,14 @@ pub enum InputId {
     BookmarkAddress,
     /// Bookmark editor: Port input
     BookmarkPort,
+    /// Bookmark editor: Fallback addresses input
+    BookmarkAddresses,
     /// Bookmark editor: Username input
     BookmarkUsername,
     /// Bookmark editor: Password input
     BookmarkPassword,
+    /// Bookmark editor: Watched users input
+    BookmarkWatchedUsers,
     /// Admin panel: Username input
     AdminUsername,
     /// Admin panel: Password input
@@ -126,6 +152,10 @@ pub enum InputId {
     EditServerInfoName,
     /// Server info edit: Description input
     EditServerInfoDescription,
+    /// Profile edit: Pronouns input
+    EditProfilePronouns,
+    /// Profile edit: Bio input
+    EditProfileBio,
     /// Broadcast panel: Message input
     BroadcastMessage,
     /// Chat: Message input
@@ -143,8 +173,10 @@ impl From<InputId> for Id {
             InputId::BookmarkName => "InputId::BookmarkName",
             InputId::BookmarkAddress => "InputId::BookmarkAddress",
             InputId::BookmarkPort => "InputId::BookmarkPort",
+            InputId::BookmarkAddresses => "InputId::BookmarkAddresses",
             InputId::BookmarkUsername => "InputId::BookmarkUsername",
             InputId::BookmarkPassword => "InputId::BookmarkPassword",
+            InputId::BookmarkWatchedUsers => "InputId::BookmarkWatchedUsers",
             InputId::AdminUsername => "InputId::AdminUsername",
             InputId::AdminPassword => "InputId::AdminPassword",
             InputId::EditUsername => "InputId::EditUsername",
@@ -152,6 +184,8 @@ impl From<InputId> for Id {
             InputId::EditNewPassword => "InputId::EditNewPassword",
             InputId::EditServerInfoName => "InputId::EditServerInfoName",
             InputId::EditServerInfoDescription => "InputId::EditServerInfoDescription",
+            InputId::EditProfilePronouns => "InputId::EditProfilePronouns",
+            InputId::EditProfileBio => "InputId::EditProfileBio",
             InputId::BroadcastMessage => "InputId::BroadcastMessage",
             InputId::ChatInput => "InputId::ChatInput",
         })
@@ -1,8 +1,13 @@
 //! View configuration struct for passing state to view rendering
 
+use crate::config::density::UiDensity;
+use crate::config::quiet_hours::QuietHours;
+use crate::config::settings::{AutoAwaySettings, ProxySettings};
+use crate::config::timezone::TimezonePreference;
+use crate::network::UpdateInfo;
 use crate::types::{
-    ActivePanel, BookmarkEditState, ConnectionFormState, ServerBookmark, ServerConnection,
-    SettingsFormState, UiState, UserManagementState,
+    ActivePanel, AnimationFrame, BookmarkEditState, ConnectionFormState, ConnectionStep,
+    ServerBookmark, ServerConnection, SettingsFormState, UiState, UserManagementState,
 };
 use iced::Theme;
 use std::collections::HashMap;
@@ -15,12 +20,54 @@ pub struct ViewConfig<'a> {
     /// Current theme for styling
     pub theme: Theme,
 
+    /// Spacing density of chat and list views
+    pub ui_density: UiDensity,
+
+    /// Show admin and moderator badges in the user list
+    pub show_role_badges: bool,
+
     /// Show user connect/disconnect notifications in chat
     pub show_connection_notifications: bool,
 
+    /// Skip the reconnect topic message when the topic hasn't changed
+    pub hide_repeated_topic: bool,
+
+    /// Alert when a connection drops unexpectedly
+    pub alert_on_disconnect: bool,
+
+    /// Alert when someone mentions the user in chat
+    pub alert_on_mention: bool,
+
+    /// Switch to private message tabs when messages arrive while idle
+    pub focus_new_messages: bool,
+
+    /// Daily schedule during which attention requests are suppressed
+    pub quiet_hours: &'a QuietHours,
+
+    /// Still alert for watched users coming online during quiet hours
+    pub watched_users_ignore_quiet_hours: bool,
+
+    /// Idle timeout after which the user is marked away
+    pub auto_away: AutoAwaySettings,
+
     /// Font size for chat messages
     pub chat_font_size: u8,
 
+    /// Maximum private message tabs kept open per server
+    pub max_message_tabs: usize,
+
+    /// Line threshold for confirming a large paste (0 never asks)
+    pub paste_confirm_lines: usize,
+
+    /// Line count above which chat messages are collapsed (0 never collapses)
+    pub collapse_message_lines: usize,
+
+    /// Send chat messages with Ctrl+Enter instead of Enter
+    pub ctrl_enter_sends: bool,
+
+    /// Template for laying out chat messages
+    pub chat_message_format: &'a str,
+
     /// Show timestamps in chat messages
     pub show_timestamps: bool,
 
@@ -30,6 +77,24 @@ pub struct ViewConfig<'a> {
     /// Show seconds in timestamps
     pub show_seconds: bool,
 
+    /// Timezone for displaying timestamps
+    pub timestamp_timezone: TimezonePreference,
+
+    /// Never save bookmark passwords to disk
+    pub forget_passwords: bool,
+
+    /// Store theme and chat display preferences on the server
+    pub sync_preferences: bool,
+
+    /// Look for a newer client release at startup
+    pub check_for_updates: bool,
+
+    /// SOCKS5 proxy used for server connections
+    pub proxy: &'a ProxySettings,
+
+    /// File that connection events are appended to (empty = memory only)
+    pub connection_log_path: &'a str,
+
     /// Settings form state (present when settings panel is open)
     pub settings_form: Option<&'a SettingsFormState>,
 
@@ -45,15 +110,18 @@ pub struct ViewConfig<'a> {
     /// Per-bookmark connection errors (transient)
     pub bookmark_errors: &'a HashMap<usize, String>,
 
+    /// Latest connection step per connecting bookmark (transient)
+    pub bookmark_steps: &'a HashMap<usize, ConnectionStep>,
+
+    /// Current frame of the shared "working" animation
+    pub animation: AnimationFrame,
+
     /// Connection form state
     pub connection_form: &'a ConnectionFormState,
 
     /// Bookmark add/edit dialog state
     pub bookmark_edit: &'a BookmarkEditState,
 
-    /// Chat message input (from active connection or empty)
-    pub message_input: &'a str,
-
     /// User management state (only present when connected)
     pub user_management: Option<&'a UserManagementState>,
 
@@ -62,6 +130,9 @@ pub struct ViewConfig<'a> {
 
     /// Effective active panel (computed from connection or ui_state)
     pub active_panel: ActivePanel,
+
+    /// Newer client release to mention in a banner (until dismissed)
+    pub update_available: Option<&'a UpdateInfo>,
 }
 
 /// Toolbar state configuration
@@ -72,9 +143,12 @@ pub struct ToolbarState<'a> {
     pub show_user_list: bool,
     pub active_panel: ActivePanel,
     pub is_connected: bool,
-    pub is_admin: bool,
-    pub permissions: &'a [String],
+    pub can_broadcast: bool,
+    pub can_create_user: bool,
+    pub can_edit_user: bool,
     pub can_view_user_list: bool,
+    /// Quiet hours are in effect, so attention requests are suppressed
+    pub quiet_hours_active: bool,
     /// Server name to display in toolbar (None = show "Nexus BBS")
     pub server_name: Option<&'a str>,
 }
@@ -101,9 +175,11 @@ mod tests {
             show_user_list: false,
             active_panel: ActivePanel::None,
             is_connected: false,
-            is_admin: false,
-            permissions: &[],
+            can_broadcast: false,
+            can_create_user: false,
+            can_edit_user: false,
             can_view_user_list: false,
+            quiet_hours_active: false,
             server_name: None,
         }
     }
@@ -0,0 +1,66 @@
+//! Pre-login banner dialog view
+
+use super::layout::scrollable_modal;
+use crate::i18n::t;
+use crate::style::{
+    BUTTON_PADDING, ELEMENT_SPACING, FINGERPRINT_DIALOG_MAX_WIDTH, FINGERPRINT_SPACE_AFTER_TITLE,
+    FINGERPRINT_SPACE_BEFORE_BUTTONS, FORM_PADDING, TEXT_SIZE, TITLE_SIZE, shaped_text,
+    shaped_text_wrapped,
+};
+use crate::types::{Message, PendingBanner};
+use iced::widget::button as btn;
+use iced::widget::{Space, button, column, row};
+use iced::{Element, Length};
+
+/// Create the dialog showing a server's pre-login banner
+///
+/// Nothing has been sent to the server yet; continuing reconnects and logs in.
+pub fn banner_dialog(pending: &PendingBanner) -> Element<'_, Message> {
+    let title = shaped_text(t("title-server-banner"))
+        .size(TITLE_SIZE)
+        .width(Length::Fill)
+        .center();
+
+    let server_line = shaped_text(&pending.display_name).size(TEXT_SIZE);
+    let banner_text = shaped_text_wrapped(&pending.banner).size(TEXT_SIZE);
+
+    let continue_button = button(
+        shaped_text(t("button-continue"))
+            .size(TEXT_SIZE)
+            .width(Length::Fill)
+            .center(),
+    )
+    .on_press(Message::AcceptBanner)
+    .padding(BUTTON_PADDING);
+
+    let cancel_button = button(
+        shaped_text(t("button-cancel"))
+            .size(TEXT_SIZE)
+            .width(Length::Fill)
+            .center(),
+    )
+    .on_press(Message::CancelBanner)
+    .padding(BUTTON_PADDING)
+    .style(btn::secondary);
+
+    let button_row = row![
+        Space::new().width(Length::Fill),
+        cancel_button,
+        continue_button
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    let dialog = column![
+        title,
+        Space::new().height(FINGERPRINT_SPACE_AFTER_TITLE),
+        server_line,
+        banner_text,
+        Space::new().height(FINGERPRINT_SPACE_BEFORE_BUTTONS),
+        button_row,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .padding(FORM_PADDING)
+    .max_width(FINGERPRINT_DIALOG_MAX_WIDTH);
+
+    scrollable_modal(dialog)
+}
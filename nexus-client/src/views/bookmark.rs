@@ -1,6 +1,7 @@
 //! Bookmark add/edit form
 
 use super::layout::scrollable_panel;
+use crate::config::theme::all_themes;
 use crate::i18n::t;
 use crate::style::{
     BUTTON_PADDING, ELEMENT_SPACING, FORM_MAX_WIDTH, FORM_PADDING, INPUT_PADDING,
@@ -9,7 +10,7 @@ use crate::style::{
 };
 use crate::types::{BookmarkEditMode, BookmarkEditState, InputId, Message};
 use iced::widget::button as btn;
-use iced::widget::{Id, Space, button, checkbox, column, row, text, text_input};
+use iced::widget::{Id, Space, button, checkbox, column, pick_list, row, text, text_input};
 use iced::{Center, Element, Fill};
 
 // ============================================================================
@@ -19,7 +20,7 @@ use iced::{Center, Element, Fill};
 /// Displays form for adding or editing a server bookmark
 ///
 /// Shows validated input fields for server connection details with optional
-/// username/password/locale fields and auto-connect checkbox. Validates that required
+/// fallback addresses, username/password/locale fields, watched users, auto-connect checkbox, and theme override. Validates that required
 /// fields (name, address, port) are non-empty before enabling save button.
 pub fn bookmark_edit_view(state: &BookmarkEditState) -> Element<'_, Message> {
     let dialog_title = match state.mode {
@@ -41,6 +42,32 @@ pub fn bookmark_edit_view(state: &BookmarkEditState) -> Element<'_, Message> {
         Message::BookmarkNameChanged(String::new())
     };
 
+    // Theme override picker (placeholder shown when using the global theme)
+    let selected_theme = state
+        .bookmark
+        .theme
+        .as_ref()
+        .map(|theme| theme.to_iced_theme());
+    let clear_theme_button = if selected_theme.is_some() {
+        button(shaped_text(t("button-clear-theme")).size(TEXT_SIZE))
+            .on_press(Message::BookmarkThemeCleared)
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary)
+    } else {
+        button(shaped_text(t("button-clear-theme")).size(TEXT_SIZE))
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary)
+    };
+    let theme_row = row![
+        shaped_text(t("label-theme")).size(TEXT_SIZE),
+        pick_list(all_themes(), selected_theme, Message::BookmarkThemeSelected)
+            .placeholder(t("placeholder-global-theme"))
+            .text_size(TEXT_SIZE),
+        clear_theme_button,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
     let mut column_items: Vec<Element<'_, Message>> = vec![
         shaped_text(&dialog_title)
             .size(TITLE_SIZE)
@@ -86,6 +113,16 @@ pub fn bookmark_edit_view(state: &BookmarkEditState) -> Element<'_, Message> {
             .padding(INPUT_PADDING)
             .size(TEXT_SIZE)
             .into(),
+        text_input(
+            &t("placeholder-fallback-addresses"),
+            &state.bookmark.addresses.join(","),
+        )
+        .on_input(Message::BookmarkAddressesChanged)
+        .on_submit(submit_action.clone())
+        .id(Id::from(InputId::BookmarkAddresses))
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE)
+        .into(),
         text_input(
             &t("placeholder-username-optional"),
             &state.bookmark.username,
@@ -101,12 +138,22 @@ pub fn bookmark_edit_view(state: &BookmarkEditState) -> Element<'_, Message> {
             &state.bookmark.password,
         )
         .on_input(Message::BookmarkPasswordChanged)
-        .on_submit(submit_action)
+        .on_submit(submit_action.clone())
         .id(Id::from(InputId::BookmarkPassword))
         .secure(true)
         .padding(INPUT_PADDING)
         .size(TEXT_SIZE)
         .into(),
+        text_input(
+            &t("placeholder-watched-users"),
+            &state.bookmark.watched_users.join(","),
+        )
+        .on_input(Message::BookmarkWatchedUsersChanged)
+        .on_submit(submit_action)
+        .id(Id::from(InputId::BookmarkWatchedUsers))
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE)
+        .into(),
         Space::new().height(SPACER_SIZE_SMALL).into(),
         checkbox(state.bookmark.auto_connect)
             .label(t("label-auto-connect"))
@@ -114,6 +161,13 @@ pub fn bookmark_edit_view(state: &BookmarkEditState) -> Element<'_, Message> {
             .size(TEXT_SIZE)
             .text_shaping(text::Shaping::Advanced)
             .into(),
+        checkbox(state.bookmark.trust_certificate_changes)
+            .label(t("label-trust-certificate-changes"))
+            .on_toggle(Message::BookmarkTrustCertificateChangesToggled)
+            .size(TEXT_SIZE)
+            .text_shaping(text::Shaping::Advanced)
+            .into(),
+        theme_row.into(),
         Space::new().height(SPACER_SIZE_MEDIUM).into(),
         {
             let mut buttons: Vec<Element<'_, Message>> = vec![
@@ -7,9 +7,9 @@ use crate::style::{
     SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, TITLE_SIZE, error_text_style, shaped_text,
     shaped_text_wrapped,
 };
-use crate::types::{InputId, Message, ServerConnection};
+use crate::types::{BroadcastTarget, InputId, Message, ServerConnection};
 use iced::widget::button as btn;
-use iced::widget::{Id, Space, button, row, text_input};
+use iced::widget::{Id, Space, button, pick_list, row, text_input};
 use iced::{Center, Element, Fill};
 
 // ============================================================================
@@ -18,7 +18,8 @@ use iced::{Center, Element, Fill};
 
 /// Render the broadcast panel
 ///
-/// Shows a form for composing and sending broadcast messages to all connected users.
+/// Shows a form for composing and sending broadcast messages to all connected users,
+/// or only to users holding a chosen permission.
 pub fn broadcast_view(conn: &ServerConnection) -> Element<'_, Message> {
     let title = shaped_text(t("title-broadcast-message"))
         .size(TITLE_SIZE)
@@ -42,6 +43,18 @@ pub fn broadcast_view(conn: &ServerConnection) -> Element<'_, Message> {
         .size(TEXT_SIZE)
         .font(MONOSPACE_FONT);
 
+    // Audience picker (everyone, or only users with a given permission)
+    let target_label = shaped_text(t("label-broadcast-target")).size(TEXT_SIZE);
+    let target_picker = pick_list(
+        BroadcastTarget::all(),
+        Some(conn.broadcast_target.clone()),
+        Message::BroadcastTargetSelected,
+    )
+    .text_size(TEXT_SIZE);
+    let target_row = row![target_label, target_picker]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
     let buttons = row![
         Space::new().width(Fill),
         button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
@@ -77,6 +90,7 @@ pub fn broadcast_view(conn: &ServerConnection) -> Element<'_, Message> {
 
     form_items.extend([
         message_input.into(),
+        target_row.into(),
         Space::new().height(SPACER_SIZE_MEDIUM).into(),
         buttons.into(),
     ]);
@@ -1,22 +1,36 @@
 //! Chat interface for active server connections
 
-use crate::i18n::t;
+use std::time::Instant;
+
+use crate::config::message_format::{MessageFormat, SpanKind};
+use crate::config::timezone::TimezonePreference;
+use crate::i18n::{t, t_args};
 use crate::style::{
-    BOLD_FONT, CHAT_LINE_HEIGHT, CHAT_MESSAGE_SIZE, CHAT_SPACING, CLOSE_BUTTON_PADDING,
-    INPUT_PADDING, MONOSPACE_FONT, SMALL_PADDING, SMALL_SPACING, TAB_CONTENT_PADDING,
+    BOLD_FONT, CHAT_INPUT_MAX_LINES, CHAT_LINE_HEIGHT, CHAT_MESSAGE_SIZE, CLOSE_BUTTON_PADDING,
+    Density, INPUT_PADDING, MONOSPACE_FONT, SMALL_PADDING, SMALL_SPACING, TAB_CONTENT_PADDING,
     TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE, chat,
-    chat_tab_active_style, close_button_on_primary_style, content_background_style, shaped_text,
-    tooltip_container_style,
+    chat_tab_active_style, close_button_on_primary_style, content_background_style,
+    error_text_style, muted_text_style, pinned_messages_style, shaped_text, shaped_text_wrapped,
+    tooltip_container_style, transparent_icon_button_style,
+};
+use crate::types::{
+    ChatMessage, ChatTab, InputId, Message, MessageBlock, MessageType, PopoutWindow, ScrollableId,
+    ServerConnection, ViewConfig, collapse_message_blocks, message_line_count,
+    split_message_blocks,
 };
-use crate::types::{ChatTab, InputId, Message, MessageType, ScrollableId, ServerConnection};
+use crate::views::constants::PERMISSION_CHAT_PIN;
+use iced::keyboard::{self, key};
 use iced::widget::scrollable::{Direction, Scrollbar};
 use iced::widget::text::Wrapping;
+use iced::widget::text_editor::{Binding, KeyPress};
 use iced::widget::{
-    Column, Id, button, column, container, rich_text, row, scrollable, span, text::Rich,
-    text_input, tooltip,
+    Column, Id, button, column, container, mouse_area, rich_text, row, scrollable, span,
+    text::Rich, text_editor, tooltip,
 };
 use iced::{Color, Element, Fill, Font, Theme};
 use linkify::{LinkFinder, LinkKind};
+use nexus_common::mentions::find_mentions;
+use nexus_common::protocol::AdminNoticeAction;
 use once_cell::sync::Lazy;
 
 // ============================================================================
@@ -32,6 +46,19 @@ pub struct TimestampSettings {
     pub use_24_hour_time: bool,
     /// Show seconds in the timestamp
     pub show_seconds: bool,
+    /// Timezone to display the timestamp in
+    pub timezone: TimezonePreference,
+}
+
+impl From<&ViewConfig<'_>> for TimestampSettings {
+    fn from(config: &ViewConfig<'_>) -> Self {
+        Self {
+            show_timestamps: config.show_timestamps,
+            use_24_hour_time: config.use_24_hour_time,
+            show_seconds: config.show_seconds,
+            timezone: config.timestamp_timezone,
+        }
+    }
 }
 
 impl TimestampSettings {
@@ -48,7 +75,13 @@ impl TimestampSettings {
             (false, false) => "%I:%M",
         };
 
-        Some(timestamp.format(format).to_string())
+        Some(self.timezone.format(timestamp, format))
+    }
+
+    /// Format the date of a timestamp (for the `{date}` message placeholder)
+    pub fn format_date(&self, timestamp: &chrono::DateTime<chrono::Local>) -> Option<String> {
+        self.show_timestamps
+            .then(|| self.timezone.format(timestamp, "%Y-%m-%d"))
     }
 }
 
@@ -108,6 +141,7 @@ struct MessageStyle {
     prefix_color: Color,
     content_color: Color,
     link_color: Color,
+    mention_color: Color,
     font_size: f32,
 }
 
@@ -129,12 +163,42 @@ fn styled_message<'a>(
     // Add prefix (username, [SYS], etc.)
     spans.push(span(prefix).color(style.prefix_color));
 
-    // Add content with link detection
+    push_content_spans(&mut spans, content, style, &[]);
+    rich_message(spans, style)
+}
+
+/// Build a chat message laid out by the user's message format template
+fn formatted_message<'a>(
+    parts: Vec<(SpanKind, String)>,
+    style: &MessageStyle,
+    mentions: &[String],
+) -> Element<'a, Message> {
+    let mut spans: Vec<iced::widget::text::Span<'a, String, Font>> = Vec::new();
+
+    for (kind, text) in parts {
+        match kind {
+            SpanKind::Timestamp => spans.push(span(text).color(style.timestamp_color)),
+            SpanKind::Nick => spans.push(span(text).color(style.prefix_color)),
+            SpanKind::Message => push_content_spans(&mut spans, &text, style, mentions),
+        }
+    }
+
+    rich_message(spans, style)
+}
+
+/// Add message content to a span list with link and mention detection
+///
+/// Only mentions of users in `mentions` (those the server flagged) are
+/// highlighted; anything else that looks like one stays plain text.
+fn push_content_spans<'a>(
+    spans: &mut Vec<iced::widget::text::Span<'a, String, Font>>,
+    content: &str,
+    style: &MessageStyle,
+    mentions: &[String],
+) {
     for segment in split_into_segments(content) {
         match segment {
-            TextSegment::Text(text) => {
-                spans.push(span(text.to_string()).color(style.content_color));
-            }
+            TextSegment::Text(text) => push_text_spans(spans, text, style, mentions),
             TextSegment::Link(url) => {
                 let openable_url = make_openable_url(url);
                 spans.push(
@@ -145,7 +209,45 @@ fn styled_message<'a>(
             }
         }
     }
+}
 
+/// Add plain text to a span list, highlighting flagged mentions
+fn push_text_spans<'a>(
+    spans: &mut Vec<iced::widget::text::Span<'a, String, Font>>,
+    text: &str,
+    style: &MessageStyle,
+    mentions: &[String],
+) {
+    let mut plain_start = 0;
+    if !mentions.is_empty() {
+        let found = find_mentions(text, |name| {
+            let name = name.to_lowercase();
+            mentions
+                .iter()
+                .find(|mentioned| mentioned.to_lowercase() == name)
+                .cloned()
+        });
+        for mention in found {
+            if mention.range.start > plain_start {
+                spans.push(
+                    span(text[plain_start..mention.range.start].to_string())
+                        .color(style.content_color),
+                );
+            }
+            spans.push(span(text[mention.range.clone()].to_string()).color(style.mention_color));
+            plain_start = mention.range.end;
+        }
+    }
+    if plain_start < text.len() {
+        spans.push(span(text[plain_start..].to_string()).color(style.content_color));
+    }
+}
+
+/// Wrap message spans in a monospace rich text widget with clickable links
+fn rich_message<'a>(
+    spans: Vec<iced::widget::text::Span<'a, String, Font>>,
+    style: &MessageStyle,
+) -> Element<'a, Message> {
     let text_widget: Rich<'a, String, Message> = rich_text(spans)
         .on_link_click(Message::OpenUrl)
         .size(style.font_size)
@@ -185,11 +287,24 @@ fn create_tab_button(
     }
 }
 
-/// Create an active tab button (with close button for PM tabs)
+/// Create an active tab button (with pop-out and close buttons for PM tabs)
 fn create_active_tab_button(tab: ChatTab, label: String) -> Element<'static, Message> {
-    // Active PM tabs include a close button
+    // Active PM tabs include pop-out and close buttons
     if let ChatTab::UserMessage(ref username) = tab {
         let username_clone = username.clone();
+        let pop_out_button = tooltip(
+            button(crate::icon::expand_right().size(CHAT_MESSAGE_SIZE))
+                .on_press(Message::PopOutMessageTab(username.clone()))
+                .padding(CLOSE_BUTTON_PADDING)
+                .style(close_button_on_primary_style()),
+            container(shaped_text(t("tooltip-pop-out")).size(TOOLTIP_TEXT_SIZE))
+                .padding(TOOLTIP_BACKGROUND_PADDING)
+                .style(tooltip_container_style),
+            tooltip::Position::Bottom,
+        )
+        .gap(TOOLTIP_GAP)
+        .padding(TOOLTIP_PADDING);
+
         let close_button = tooltip(
             button(crate::icon::close().size(CHAT_MESSAGE_SIZE))
                 .on_press(Message::CloseUserMessageTab(username_clone))
@@ -209,6 +324,7 @@ fn create_active_tab_button(tab: ChatTab, label: String) -> Element<'static, Mes
             iced::widget::text(label)
                 .size(CHAT_MESSAGE_SIZE)
                 .shaping(iced::widget::text::Shaping::Advanced),
+            pop_out_button,
             close_button
         ]
         .spacing(SMALL_SPACING)
@@ -254,17 +370,25 @@ fn create_inactive_tab_button(
 // ============================================================================
 
 /// Build a rich text element for a single message line
+///
+/// Chat messages are laid out by `message_format`; system, error, info,
+/// broadcast and announcement lines keep their fixed `[time] [TAG]` layout.
+#[allow(clippy::too_many_arguments)]
 fn render_message_line<'a>(
     time_str: Option<&str>,
+    date_str: Option<&str>,
     username: &str,
     line: &str,
+    mentions: &[String],
     message_type: MessageType,
     theme: &Theme,
     username_is_admin: bool,
     font_size: f32,
+    message_format: &MessageFormat,
 ) -> Element<'a, Message> {
     let timestamp_color = chat::timestamp(theme);
     let link_color = theme.palette().primary;
+    let mention_color = chat::mention(theme);
 
     match message_type {
         MessageType::System => {
@@ -274,6 +398,7 @@ fn render_message_line<'a>(
                 prefix_color: color,
                 content_color: color,
                 link_color,
+                mention_color,
                 font_size,
             };
             styled_message(
@@ -290,6 +415,7 @@ fn render_message_line<'a>(
                 prefix_color: color,
                 content_color: color,
                 link_color,
+                mention_color,
                 font_size,
             };
             styled_message(
@@ -306,6 +432,7 @@ fn render_message_line<'a>(
                 prefix_color: color,
                 content_color: color,
                 link_color,
+                mention_color,
                 font_size,
             };
             styled_message(
@@ -322,6 +449,7 @@ fn render_message_line<'a>(
                 prefix_color: color,
                 content_color: color,
                 link_color,
+                mention_color,
                 font_size,
             };
             styled_message(
@@ -331,6 +459,23 @@ fn render_message_line<'a>(
                 &style,
             )
         }
+        MessageType::Announcement => {
+            let color = chat::broadcast(theme);
+            let style = MessageStyle {
+                timestamp_color,
+                prefix_color: color,
+                content_color: color,
+                link_color,
+                mention_color,
+                font_size,
+            };
+            styled_message(
+                time_str,
+                format!("{} ", t("chat-prefix-announcement")),
+                line,
+                &style,
+            )
+        }
         MessageType::Chat => {
             let username_color = if username_is_admin {
                 chat::admin(theme)
@@ -343,37 +488,135 @@ fn render_message_line<'a>(
                 prefix_color: username_color,
                 content_color: text_color,
                 link_color,
+                mention_color,
                 font_size,
             };
-            styled_message(time_str, format!("{}: ", username), line, &style)
+            formatted_message(
+                message_format.render(time_str, date_str, username, line),
+                &style,
+                mentions,
+            )
         }
     }
 }
 
+// ============================================================================
+// Pinned Messages
+// ============================================================================
+
+/// Build the sticky header listing pinned messages for #server
+///
+/// Returns `None` when nothing is pinned. An unpin button is shown on each
+/// row when the user is an admin or has the chat_pin permission.
+fn build_pinned_header<'a>(
+    conn: &'a ServerConnection,
+    theme: &Theme,
+    font_size: f32,
+    density: Density,
+) -> Option<Element<'a, Message>> {
+    if conn.pinned_messages.is_empty() {
+        return None;
+    }
+
+    let can_unpin =
+        (conn.has_permission(PERMISSION_CHAT_PIN)) && conn.supports_message_type("UnpinMessage");
+    let prefix_label = t("chat-prefix-pinned");
+
+    let mut pinned_column = Column::new().spacing(density.chat_spacing);
+    for pin in &conn.pinned_messages {
+        let username_color = if is_admin_username(conn, &pin.username) {
+            chat::admin(theme)
+        } else {
+            chat::text(theme)
+        };
+        let style = MessageStyle {
+            timestamp_color: chat::timestamp(theme),
+            prefix_color: username_color,
+            content_color: chat::text(theme),
+            link_color: theme.palette().primary,
+            mention_color: chat::mention(theme),
+            font_size,
+        };
+        let line = tooltip(
+            styled_message(
+                None,
+                format!("{} {}: ", prefix_label, pin.username),
+                &pin.message,
+                &style,
+            ),
+            container(
+                shaped_text(t_args("tooltip-pinned-by", &[("username", &pin.pinned_by)]))
+                    .size(TOOLTIP_TEXT_SIZE),
+            )
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+            tooltip::Position::Bottom,
+        )
+        .gap(TOOLTIP_GAP)
+        .padding(TOOLTIP_PADDING);
+
+        let pinned_row = if can_unpin {
+            let unpin_button = tooltip(
+                button(crate::icon::close().size(font_size))
+                    .on_press(Message::UnpinMessagePressed(pin.message_id))
+                    .padding(CLOSE_BUTTON_PADDING)
+                    .style(transparent_icon_button_style),
+                container(shaped_text(t("tooltip-unpin")).size(TOOLTIP_TEXT_SIZE))
+                    .padding(TOOLTIP_BACKGROUND_PADDING)
+                    .style(tooltip_container_style),
+                tooltip::Position::Left,
+            )
+            .gap(TOOLTIP_GAP)
+            .padding(TOOLTIP_PADDING);
+
+            row![line, unpin_button]
+                .spacing(SMALL_SPACING)
+                .align_y(iced::Alignment::Center)
+        } else {
+            row![line]
+        };
+
+        pinned_column = pinned_column.push(pinned_row);
+    }
+
+    Some(
+        container(pinned_column)
+            .padding(SMALL_PADDING)
+            .width(Fill)
+            .style(pinned_messages_style)
+            .into(),
+    )
+}
+
 // ============================================================================
 // Message List
 // ============================================================================
 
-/// Build the message list column for the active chat tab
+/// Build the message list column for a chat tab's messages
+///
+/// With `interactive`, right-click copies and double-click quotes a message,
+/// and messages longer than `collapse_lines` lines (0 = never) are collapsed
+/// behind a show more/less toggle. All of these act on the main window's
+/// active tab, so popouts render without them.
+#[allow(clippy::too_many_arguments)]
 fn build_message_list<'a>(
     conn: &'a ServerConnection,
+    messages: &'a [ChatMessage],
     theme: &Theme,
     font_size: f32,
     timestamp_settings: TimestampSettings,
+    message_format: &MessageFormat,
+    density: Density,
+    interactive: bool,
+    collapse_lines: usize,
 ) -> Column<'a, Message> {
-    let messages = match &conn.active_chat_tab {
-        ChatTab::Server => conn.chat_messages.as_slice(),
-        ChatTab::UserMessage(username) => conn
-            .user_messages
-            .get(username)
-            .map(|v| v.as_slice())
-            .unwrap_or(&[]),
-    };
+    let mut chat_column = Column::new()
+        .spacing(density.chat_spacing)
+        .padding(density.chat_padding);
 
-    let mut chat_column = Column::new().spacing(CHAT_SPACING).padding(INPUT_PADDING);
-
-    for msg in messages {
+    for (index, msg) in messages.iter().enumerate() {
         let time_str = timestamp_settings.format(&msg.get_timestamp());
+        let date_str = timestamp_settings.format_date(&msg.get_timestamp());
         // For private messages, use the stored is_admin flag.
         // For server chat, fall back to looking up in online users.
         let username_is_admin = if msg.is_admin {
@@ -383,46 +626,402 @@ fn build_message_list<'a>(
         };
 
         // Split message into lines to prevent spoofing via embedded newlines
-        // Each line is displayed with the same timestamp/username prefix
-        for line in msg.message.split('\n') {
-            let display = render_message_line(
-                time_str.as_deref(),
-                &msg.username,
-                line,
-                msg.message_type,
-                theme,
-                username_is_admin,
-                font_size,
+        // Each line is displayed with the same timestamp/username prefix;
+        // fenced code blocks are boxed below it with whitespace preserved
+        let display_text = message_display_text(msg);
+        let mut message_column = Column::new().spacing(density.chat_spacing);
+        let mut has_prefix_line = false;
+        let mut blocks = split_message_blocks(&display_text);
+        let collapsible =
+            interactive && collapse_lines > 0 && message_line_count(&blocks) > collapse_lines;
+        if collapsible && !msg.expanded {
+            blocks = collapse_message_blocks(&blocks, collapse_lines);
+        }
+        for block in blocks {
+            let lines: Vec<&str> = match block {
+                MessageBlock::Text(text) => text.split('\n').collect(),
+                // A message that opens with code still gets its prefix line
+                MessageBlock::Code(_) | MessageBlock::Art(_) if !has_prefix_line => vec![""],
+                MessageBlock::Code(_) | MessageBlock::Art(_) => Vec::new(),
+            };
+            for line in lines {
+                let display = render_message_line(
+                    time_str.as_deref(),
+                    date_str.as_deref(),
+                    &msg.username,
+                    line,
+                    &msg.mentions,
+                    msg.message_type,
+                    theme,
+                    username_is_admin,
+                    font_size,
+                    message_format,
+                );
+                message_column = message_column.push(display);
+                has_prefix_line = true;
+            }
+            match block {
+                MessageBlock::Code(code) => {
+                    message_column = message_column.push(build_code_block(code, theme, font_size));
+                }
+                MessageBlock::Art(art) => {
+                    message_column = message_column.push(build_art_block(art, theme, font_size));
+                }
+                MessageBlock::Text(_) => {}
+            }
+        }
+        if collapsible {
+            let label = if msg.expanded {
+                t("chat-show-less")
+            } else {
+                t("chat-show-more")
+            };
+            message_column = message_column.push(
+                button(shaped_text(label).size(font_size).style(muted_text_style))
+                    .on_press(Message::ToggleMessageExpanded(index))
+                    .padding(0)
+                    .style(transparent_icon_button_style),
             );
-            chat_column = chat_column.push(display);
+        }
+
+        // Right-click copies and double-click quotes the whole message,
+        // not just the clicked line
+        if interactive {
+            chat_column = chat_column.push(
+                mouse_area(message_column)
+                    .on_right_press(Message::CopyChatLine(index))
+                    .on_double_click(Message::ReplyTo(index)),
+            );
+        } else {
+            chat_column = chat_column.push(message_column);
         }
     }
 
     chat_column
 }
 
+/// Build a boxed, monospace block for the contents of a fenced code block
+fn build_code_block<'a>(code: &str, theme: &Theme, font_size: f32) -> Element<'a, Message> {
+    container(
+        iced::widget::text(code.to_string())
+            .size(font_size)
+            .line_height(CHAT_LINE_HEIGHT)
+            .font(MONOSPACE_FONT)
+            .color(chat::text(theme))
+            .wrapping(Wrapping::Glyph)
+            .width(Fill),
+    )
+    .padding(SMALL_PADDING)
+    .width(Fill)
+    .style(pinned_messages_style)
+    .into()
+}
+
+/// Build a boxed, monospace block for BBS art
+///
+/// Unlike a code block, art is never wrapped: lines wider than the chat
+/// scroll sideways so the picture keeps its shape.
+fn build_art_block<'a>(art: &str, theme: &Theme, font_size: f32) -> Element<'a, Message> {
+    container(
+        scrollable(
+            iced::widget::text(art.to_string())
+                .size(font_size)
+                .line_height(CHAT_LINE_HEIGHT)
+                .font(MONOSPACE_FONT)
+                .color(chat::text(theme))
+                .wrapping(Wrapping::None),
+        )
+        .direction(Direction::Horizontal(Scrollbar::default())),
+    )
+    .padding(SMALL_PADDING)
+    .width(Fill)
+    .style(pinned_messages_style)
+    .into()
+}
+
+/// Text shown for a chat message, with a marker if it was edited, deleted or
+/// end-to-end encrypted
+fn message_display_text(msg: &ChatMessage) -> String {
+    if msg.removed_by_moderator {
+        t("chat-message-removed-by-moderator")
+    } else if msg.deleted {
+        t("chat-message-deleted")
+    } else if msg.edited {
+        format!("{} {}", msg.message, t("chat-message-edited"))
+    } else if msg.encrypted {
+        format!("{} {}", msg.message, t("chat-message-encrypted"))
+    } else {
+        msg.message.clone()
+    }
+}
+
+/// Format a chat message as plain text for the clipboard
+///
+/// Matches what the chat view shows: each line carries the timestamp (when
+/// enabled) and the same prefix as the rendered message, and chat messages
+/// follow `message_format`.
+pub fn message_copy_text(
+    msg: &ChatMessage,
+    timestamp_settings: TimestampSettings,
+    message_format: &MessageFormat,
+) -> String {
+    let time_str = timestamp_settings.format(&msg.get_timestamp());
+    let display_text = message_display_text(msg);
+
+    let prefix = match msg.message_type {
+        MessageType::System => format!("{} ", t("chat-prefix-system")),
+        MessageType::Error => format!("{} ", t("chat-prefix-error")),
+        MessageType::Info => format!("{} ", t("chat-prefix-info")),
+        MessageType::Broadcast => format!("{} {}: ", t("chat-prefix-broadcast"), msg.username),
+        MessageType::Announcement => format!("{} ", t("chat-prefix-announcement")),
+        MessageType::Chat => {
+            let date_str = timestamp_settings.format_date(&msg.get_timestamp());
+            return display_text
+                .split('\n')
+                .map(|line| {
+                    message_format
+                        .render(
+                            time_str.as_deref(),
+                            date_str.as_deref(),
+                            &msg.username,
+                            line,
+                        )
+                        .into_iter()
+                        .map(|(_, text)| text)
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+    };
+
+    let timestamp = time_str.map(|ts| format!("[{}] ", ts)).unwrap_or_default();
+    display_text
+        .split('\n')
+        .map(|line| format!("{}{}{}", timestamp, prefix, line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 // ============================================================================
 // Input Row
 // ============================================================================
 
 /// Build the message input row with text field and send button
-fn build_input_row<'a>(message_input: &'a str, font_size: f32) -> iced::widget::Row<'a, Message> {
-    let text_field = text_input(&t("placeholder-message"), message_input)
-        .on_input(Message::ChatInputChanged)
-        .on_submit(Message::SendMessagePressed)
+///
+/// Enter sends the message and Shift+Enter starts a new line, or with
+/// `ctrl_enter_sends` Ctrl+Enter sends and Enter starts a new line; the field
+/// grows with its contents up to a few lines, then scrolls.
+/// When slow mode is counting down, the placeholder shows the seconds remaining.
+/// A short confirmation appears beside the send button after copying a message.
+/// The field and send button are disabled while maintenance mode blocks input.
+fn build_input_row<'a>(
+    message_input: &'a text_editor::Content,
+    font_size: f32,
+    slow_mode_remaining: Option<u64>,
+    show_copied: bool,
+    input_disabled: bool,
+    send_debounced: bool,
+    ctrl_enter_sends: bool,
+) -> iced::widget::Row<'a, Message> {
+    let placeholder = if input_disabled {
+        t("placeholder-message-maintenance")
+    } else if let Some(seconds) = slow_mode_remaining {
+        t_args(
+            "placeholder-message-slow-mode",
+            &[("seconds", &seconds.to_string())],
+        )
+    } else {
+        t("placeholder-message")
+    };
+
+    let text_field = text_editor(message_input)
+        .placeholder(placeholder)
+        .key_binding(move |key_press| {
+            chat_input_key_binding(key_press, ctrl_enter_sends, Message::SendMessagePressed)
+        })
         .id(Id::from(InputId::ChatInput))
         .padding(INPUT_PADDING)
         .size(font_size)
+        .line_height(CHAT_LINE_HEIGHT)
+        .max_height(font_size * CHAT_LINE_HEIGHT * CHAT_INPUT_MAX_LINES)
         .font(MONOSPACE_FONT)
-        .width(Fill);
+        .wrapping(Wrapping::WordOrGlyph);
+    // Without an action handler the editor is disabled
+    let text_field = if input_disabled {
+        text_field
+    } else {
+        text_field.on_action(Message::ChatInputAction)
+    };
 
     let send_button = button(shaped_text(t("button-send")).size(font_size))
-        .on_press(Message::SendMessagePressed)
+        .on_press_maybe((!input_disabled && !send_debounced).then_some(Message::SendMessagePressed))
         .padding(INPUT_PADDING);
 
-    row![text_field, send_button]
+    let copied_label = show_copied.then(|| {
+        shaped_text(t("msg-copied-to-clipboard"))
+            .size(font_size)
+            .style(muted_text_style)
+    });
+
+    row![text_field]
+        .push(copied_label)
+        .push(send_button)
         .spacing(SMALL_SPACING)
+        .align_y(iced::Alignment::Center)
+        .width(Fill)
+}
+
+/// Build the maintenance banner shown while the server is read-only
+///
+/// Admins see it too, as a reminder that everyone else is blocked.
+fn build_maintenance_banner<'a>(
+    conn: &ServerConnection,
+    font_size: f32,
+) -> Option<Element<'a, Message>> {
+    if !conn.maintenance {
+        return None;
+    }
+
+    Some(
+        container(
+            shaped_text(t("msg-maintenance-banner"))
+                .size(font_size)
+                .style(error_text_style),
+        )
+        .padding(SMALL_PADDING)
+        .width(Fill)
+        .style(pinned_messages_style)
+        .into(),
+    )
+}
+
+/// Build the banner showing the latest admin notice
+///
+/// Update notices offer to open the link, and reconnect notices offer to
+/// reconnect when the connection has a bookmark to reconnect with.
+fn build_admin_notice_banner<'a>(
+    conn: &ServerConnection,
+    font_size: f32,
+) -> Option<Element<'a, Message>> {
+    let notice = conn.admin_notice.as_ref()?;
+    let connection_id = conn.connection_id;
+
+    let action_button = |label: String, message: Message| {
+        button(shaped_text(label).size(font_size))
+            .on_press(message)
+            .padding(INPUT_PADDING)
+    };
+    let (text, action) = match notice {
+        AdminNoticeAction::Reconnect => (
+            t("msg-admin-notice-reconnect"),
+            conn.bookmark_index.map(|_| {
+                action_button(
+                    t("button-reconnect"),
+                    Message::AdminNoticeReconnect(connection_id),
+                )
+            }),
+        ),
+        AdminNoticeAction::Update { url } => (
+            t_args("msg-admin-notice-update", &[("url", url)]),
+            Some(action_button(
+                t("button-open-link"),
+                Message::OpenUrl(url.clone()),
+            )),
+        ),
+        AdminNoticeAction::Notice { message } => {
+            (t_args("msg-admin-notice", &[("message", message)]), None)
+        }
+    };
+
+    let dismiss_button = button(shaped_text(t("button-dismiss")).size(font_size))
+        .on_press(Message::DismissAdminNotice(connection_id))
+        .padding(INPUT_PADDING)
+        .style(iced::widget::button::secondary);
+
+    Some(
+        container(
+            row![
+                shaped_text_wrapped(text)
+                    .size(font_size)
+                    .font(BOLD_FONT)
+                    .width(Fill)
+            ]
+            .push(action)
+            .push(dismiss_button)
+            .spacing(SMALL_SPACING)
+            .align_y(iced::Alignment::Center),
+        )
+        .padding(SMALL_PADDING)
+        .width(Fill)
+        .style(pinned_messages_style)
+        .into(),
+    )
+}
+
+/// Build the prompt asking whether to send a large paste
+///
+/// Shown above the input after sending a message with many pasted lines;
+/// the paste goes out as a single multi-line message once confirmed.
+fn build_paste_confirmation<'a>(
+    conn: &ServerConnection,
+    font_size: f32,
+) -> Option<Element<'a, Message>> {
+    let lines = conn.paste_confirmation?;
+
+    let prompt = shaped_text(t_args(
+        "msg-large-paste-confirm",
+        &[("count", &lines.to_string())],
+    ))
+    .size(font_size)
+    .width(Fill);
+    let send_button = button(shaped_text(t("button-send-as-one-message")).size(font_size))
+        .on_press(Message::ConfirmLargePaste)
+        .padding(INPUT_PADDING);
+    let cancel_button = button(shaped_text(t("button-cancel")).size(font_size))
+        .on_press(Message::CancelLargePaste)
+        .padding(INPUT_PADDING)
+        .style(iced::widget::button::secondary);
+
+    Some(
+        container(
+            row![prompt, send_button, cancel_button]
+                .spacing(SMALL_SPACING)
+                .align_y(iced::Alignment::Center),
+        )
+        .padding(SMALL_PADDING)
         .width(Fill)
+        .style(pinned_messages_style)
+        .into(),
+    )
+}
+
+/// Key bindings for the chat input
+///
+/// By default Enter sends and Shift+Enter inserts a newline. With
+/// `ctrl_enter_sends`, Ctrl+Enter (Cmd+Enter on macOS) sends and any other
+/// Enter inserts a newline. Sending produces `send`.
+fn chat_input_key_binding(
+    key_press: KeyPress,
+    ctrl_enter_sends: bool,
+    send: Message,
+) -> Option<Binding<Message>> {
+    let send_modifier = if ctrl_enter_sends {
+        key_press.modifiers.command()
+    } else {
+        !key_press.modifiers.shift()
+    };
+    let is_send = matches!(
+        key_press.key.as_ref(),
+        keyboard::Key::Named(key::Named::Enter)
+    ) && send_modifier
+        && matches!(key_press.status, text_editor::Status::Focused { .. });
+
+    if is_send {
+        Some(Binding::Custom(send))
+    } else {
+        Binding::from_key_press(key_press)
+    }
 }
 
 // ============================================================================
@@ -436,16 +1035,25 @@ fn build_tab_bar(conn: &ServerConnection) -> (iced::widget::Row<'static, Message
     // Server tab (always present)
     let is_server_active = conn.active_chat_tab == ChatTab::Server;
     let server_has_unread = conn.unread_tabs.contains(&ChatTab::Server);
+    let server_label = match conn.user_count {
+        Some(count) => t_args("chat-tab-server-count", &[("count", &count.to_string())]),
+        None => t("chat-tab-server"),
+    };
     let server_tab_button = create_tab_button(
         ChatTab::Server,
-        t("chat-tab-server"),
+        server_label,
         is_server_active,
         server_has_unread,
     );
     tab_row = tab_row.push(server_tab_button);
 
-    // PM tabs (sorted alphabetically)
-    let mut pm_usernames: Vec<String> = conn.user_messages.keys().cloned().collect();
+    // PM tabs (sorted alphabetically), except those popped out into their own window
+    let mut pm_usernames: Vec<String> = conn
+        .user_messages
+        .keys()
+        .filter(|username| conn.popout_window(username).is_none())
+        .cloned()
+        .collect();
     pm_usernames.sort();
 
     let has_pm_tabs = !pm_usernames.is_empty();
@@ -475,21 +1083,28 @@ fn build_tab_bar(conn: &ServerConnection) -> (iced::widget::Row<'static, Message
 /// - Chat messages (server enforces chat_receive permission)
 ///
 /// The send input is only enabled with chat_send permission.
-pub fn chat_view<'a>(
-    conn: &'a ServerConnection,
-    message_input: &'a str,
-    theme: Theme,
-    chat_font_size: u8,
-    timestamp_settings: TimestampSettings,
-) -> Element<'a, Message> {
-    let font_size = chat_font_size as f32;
+pub fn chat_view<'a>(conn: &'a ServerConnection, config: &ViewConfig<'a>) -> Element<'a, Message> {
+    let theme = config.theme.clone();
+    let font_size = config.chat_font_size as f32;
+    let message_format = MessageFormat::parse_or_default(config.chat_message_format);
+    let density = config.ui_density.metrics();
 
     // Build tab bar
     let (tab_row, has_pm_tabs) = build_tab_bar(conn);
     let tab_bar = tab_row.wrap();
 
     // Build message list
-    let chat_column = build_message_list(conn, &theme, font_size, timestamp_settings);
+    let chat_column = build_message_list(
+        conn,
+        conn.active_messages(),
+        &theme,
+        font_size,
+        TimestampSettings::from(config),
+        &message_format,
+        density,
+        true,
+        config.collapse_message_lines,
+    );
 
     let chat_scrollable = scrollable(chat_column)
         .id(ScrollableId::ChatMessages)
@@ -499,11 +1114,42 @@ pub fn chat_view<'a>(
         .height(Fill);
 
     // Build input row (always enabled - permission checked on send)
-    let input_row = build_input_row(message_input, font_size);
+    // Slow mode countdown only applies to server chat
+    let slow_mode_remaining = if conn.active_chat_tab == ChatTab::Server {
+        conn.slow_mode_remaining()
+    } else {
+        None
+    };
+    let input_row = build_input_row(
+        &conn.message_input,
+        font_size,
+        slow_mode_remaining,
+        conn.copy_confirmation_visible(),
+        conn.maintenance && !conn.is_admin,
+        conn.send_debounce.is_active(Instant::now()),
+        config.ctrl_enter_sends,
+    );
+
+    let maintenance_banner = build_maintenance_banner(conn, font_size);
+    let admin_notice_banner = build_admin_notice_banner(conn, font_size);
+    let paste_confirmation = build_paste_confirmation(conn, font_size);
+
+    // Pinned messages stay above the scrollable on the #server tab
+    let pinned_header = if conn.active_chat_tab == ChatTab::Server {
+        build_pinned_header(conn, &theme, font_size, density)
+    } else {
+        None
+    };
 
     // Chat content with background
     let chat_content = container(
-        column![chat_scrollable, input_row]
+        Column::new()
+            .push(maintenance_banner)
+            .push(admin_notice_banner)
+            .push(pinned_header)
+            .push(chat_scrollable)
+            .push(paste_confirmation)
+            .push(input_row)
             .spacing(SMALL_SPACING)
             .padding(SMALL_PADDING),
     )
@@ -524,3 +1170,94 @@ pub fn chat_view<'a>(
         chat_content.into()
     }
 }
+
+// ============================================================================
+// Popout Window
+// ============================================================================
+
+/// Displays a private message tab popped out into its own window
+///
+/// Shares the tab's history with the main window but has its own input.
+/// The list stays pinned to the newest message while scrolled to the bottom.
+pub fn popout_view<'a>(
+    window_id: iced::window::Id,
+    conn: &'a ServerConnection,
+    popout: &'a PopoutWindow,
+    config: &ViewConfig<'a>,
+) -> Element<'a, Message> {
+    let theme = config.theme.clone();
+    let font_size = config.chat_font_size as f32;
+    let message_format = MessageFormat::parse_or_default(config.chat_message_format);
+    let density = config.ui_density.metrics();
+    let ctrl_enter_sends = config.ctrl_enter_sends;
+    let messages = conn
+        .user_messages
+        .get(&popout.username)
+        .map(|v| v.as_slice())
+        .unwrap_or(&[]);
+
+    let chat_column = build_message_list(
+        conn,
+        messages,
+        &theme,
+        font_size,
+        TimestampSettings::from(config),
+        &message_format,
+        density,
+        false,
+        0,
+    );
+    let chat_scrollable = scrollable(chat_column)
+        .direction(Direction::Vertical(Scrollbar::default()))
+        .anchor_bottom()
+        .width(Fill)
+        .height(Fill);
+
+    let input_disabled = conn.maintenance && !conn.is_admin;
+    let placeholder = if input_disabled {
+        t("placeholder-message-maintenance")
+    } else {
+        t("placeholder-message")
+    };
+    let text_field = text_editor(&popout.message_input)
+        .placeholder(placeholder)
+        .key_binding(move |key_press| {
+            chat_input_key_binding(
+                key_press,
+                ctrl_enter_sends,
+                Message::PopoutSendPressed(window_id),
+            )
+        })
+        .padding(INPUT_PADDING)
+        .size(font_size)
+        .line_height(CHAT_LINE_HEIGHT)
+        .max_height(font_size * CHAT_LINE_HEIGHT * CHAT_INPUT_MAX_LINES)
+        .font(MONOSPACE_FONT)
+        .wrapping(Wrapping::WordOrGlyph);
+    // Without an action handler the editor is disabled
+    let text_field = if input_disabled {
+        text_field
+    } else {
+        text_field.on_action(move |action| Message::PopoutInputAction(window_id, action))
+    };
+    let send_button = button(shaped_text(t("button-send")).size(font_size))
+        .on_press_maybe((!input_disabled).then_some(Message::PopoutSendPressed(window_id)))
+        .padding(INPUT_PADDING);
+    let input_row = row![text_field, send_button]
+        .spacing(SMALL_SPACING)
+        .align_y(iced::Alignment::Center)
+        .width(Fill);
+
+    container(
+        Column::new()
+            .push(build_maintenance_banner(conn, font_size))
+            .push(chat_scrollable)
+            .push(input_row)
+            .spacing(SMALL_SPACING)
+            .padding(SMALL_PADDING),
+    )
+    .width(Fill)
+    .height(Fill)
+    .style(content_background_style)
+    .into()
+}
@@ -1,14 +1,14 @@
 //! Connection form for new server connections
 
 use super::layout::scrollable_panel;
-use crate::i18n::t;
+use crate::i18n::{t, t_args};
 use crate::style::{
-    BUTTON_PADDING, ELEMENT_SPACING, FORM_MAX_WIDTH, FORM_PADDING, INPUT_PADDING,
-    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, TITLE_SIZE, error_text_style, shaped_text,
-    shaped_text_wrapped,
+    BUTTON_PADDING, CONNECTION_PROGRESS_HEIGHT, ELEMENT_SPACING, FORM_MAX_WIDTH, FORM_PADDING,
+    INPUT_PADDING, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, TITLE_SIZE, error_text_style,
+    muted_text_style, shaped_text, shaped_text_wrapped,
 };
-use crate::types::{ConnectionFormState, InputId, Message};
-use iced::widget::{Id, Space, button, checkbox, column, row, text, text_input};
+use crate::types::{AnimationFrame, ConnectionFormState, ConnectionStep, InputId, Message};
+use iced::widget::{Id, Space, button, checkbox, column, progress_bar, row, text, text_input};
 use iced::{Center, Element, Fill};
 
 // ============================================================================
@@ -20,7 +20,10 @@ use iced::{Center, Element, Fill};
 /// Shows validated input fields for connecting to a new server. Server name is
 /// optional, but address, port, and username are required. Password can be empty
 /// for servers that don't require authentication.
-pub fn connection_form_view(form: &ConnectionFormState) -> Element<'_, Message> {
+pub fn connection_form_view(
+    form: &ConnectionFormState,
+    animation: AnimationFrame,
+) -> Element<'_, Message> {
     // Validate required fields (username and password are optional)
     let can_connect = !form.server_name.trim().is_empty()
         && !form.server_address.trim().is_empty()
@@ -105,6 +108,22 @@ pub fn connection_form_view(form: &ConnectionFormState) -> Element<'_, Message>
         port_input.into(),
         username_input.into(),
         password_input.into(),
+    ]);
+
+    // An invite link pins the certificate; show it before the user connects
+    if let Some(fingerprint) = &form.expected_fingerprint {
+        column_items.push(
+            shaped_text_wrapped(t_args(
+                "label-invite-fingerprint",
+                &[("fingerprint", fingerprint)],
+            ))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into(),
+        );
+    }
+
+    column_items.extend([
         Space::new().height(SPACER_SIZE_SMALL).into(),
         checkbox(form.add_bookmark)
             .label(t("label-add-bookmark"))
@@ -113,10 +132,20 @@ pub fn connection_form_view(form: &ConnectionFormState) -> Element<'_, Message>
             .text_shaping(text::Shaping::Advanced)
             .into(),
         Space::new().height(SPACER_SIZE_MEDIUM).into(),
+    ]);
+
+    // Show how far the connection attempt has progressed
+    if form.is_connecting
+        && let Some(step) = form.step
+    {
+        column_items.push(connection_progress(step, animation));
+    }
+
+    column_items.push(
         row![Space::new().width(Fill), connect_button]
             .spacing(ELEMENT_SPACING)
             .into(),
-    ]);
+    );
 
     let content = column(column_items)
         .spacing(ELEMENT_SPACING)
@@ -125,3 +154,32 @@ pub fn connection_form_view(form: &ConnectionFormState) -> Element<'_, Message>
 
     scrollable_panel(content)
 }
+
+/// Progress bar, spinner, and label for the current connection step
+fn connection_progress(
+    step: ConnectionStep,
+    animation: AnimationFrame,
+) -> Element<'static, Message> {
+    let label = t_args(
+        "connect-step-progress",
+        &[
+            ("step", &step.number().to_string()),
+            ("total", &ConnectionStep::COUNT.to_string()),
+            ("description", &t(step.translation_key())),
+        ],
+    );
+
+    column![
+        progress_bar(0.0..=ConnectionStep::COUNT as f32, step.number() as f32)
+            .girth(CONNECTION_PROGRESS_HEIGHT),
+        row![
+            shaped_text(animation.spinner())
+                .size(TEXT_SIZE)
+                .style(muted_text_style),
+            shaped_text(label).size(TEXT_SIZE).style(muted_text_style),
+        ]
+        .spacing(SPACER_SIZE_SMALL),
+    ]
+    .spacing(SPACER_SIZE_SMALL)
+    .into()
+}
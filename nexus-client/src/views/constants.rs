@@ -18,6 +18,9 @@ pub(crate) const PERMISSION_USER_INFO: &str = "user_info";
 /// Permission to send chat messages
 pub(crate) const PERMISSION_CHAT_SEND: &str = "chat_send";
 
+/// Permission to receive chat messages (and load older chat history)
+pub(crate) const PERMISSION_CHAT_RECEIVE: &str = "chat_receive";
+
 /// Permission to broadcast messages to all users
 pub(crate) const PERMISSION_USER_BROADCAST: &str = "user_broadcast";
 
@@ -41,3 +44,9 @@ pub(crate) const PERMISSION_CHAT_TOPIC: &str = "chat_topic";
 
 /// Permission to edit chat topic
 pub(crate) const PERMISSION_CHAT_TOPIC_EDIT: &str = "chat_topic_edit";
+
+/// Permission to pin and unpin chat messages
+pub(crate) const PERMISSION_CHAT_PIN: &str = "chat_pin";
+
+/// Permission to delete other users' chat messages
+pub(crate) const PERMISSION_CHAT_MODERATE: &str = "chat_moderate";
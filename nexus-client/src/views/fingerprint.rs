@@ -19,7 +19,7 @@ use iced::{Element, Length};
 // ============================================================================
 
 /// Format a colon-separated fingerprint into two lines for readability
-fn format_fingerprint_multiline(fingerprint: &str) -> String {
+pub(super) fn format_fingerprint_multiline(fingerprint: &str) -> String {
     let parts: Vec<&str> = fingerprint.split(':').collect();
     let mid = parts.len() / 2;
     format!("{}\n{}", parts[..mid].join(":"), parts[mid..].join(":"))
@@ -64,6 +64,16 @@ pub fn fingerprint_mismatch_dialog<'a>(mismatch: &'a FingerprintMismatch) -> Ele
     .padding(BUTTON_PADDING)
     .style(btn::danger);
 
+    let always_accept_button = button(
+        shaped_text(t("button-always-accept-certificate"))
+            .size(TEXT_SIZE)
+            .width(Length::Fill)
+            .center(),
+    )
+    .on_press(Message::AlwaysAcceptNewFingerprint)
+    .padding(BUTTON_PADDING)
+    .style(btn::danger);
+
     let cancel_button = button(
         shaped_text(t("button-cancel"))
             .size(TEXT_SIZE)
@@ -77,6 +87,7 @@ pub fn fingerprint_mismatch_dialog<'a>(mismatch: &'a FingerprintMismatch) -> Ele
     let button_row = row![
         Space::new().width(Length::Fill),
         cancel_button,
+        always_accept_button,
         accept_button
     ]
     .spacing(ELEMENT_SPACING);
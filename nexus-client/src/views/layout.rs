@@ -1,19 +1,22 @@
 //! Main application layout and toolbar
 
 use super::constants::{
-    PERMISSION_USER_BROADCAST, PERMISSION_USER_CREATE, PERMISSION_USER_EDIT, PERMISSION_USER_LIST,
+    PERMISSION_USER_BROADCAST, PERMISSION_USER_CREATE, PERMISSION_USER_EDIT, PERMISSION_USER_KICK,
+    PERMISSION_USER_LIST,
 };
 use super::server_info::{ServerInfoData, server_info_view};
 use super::user_info::user_info_view;
-use crate::i18n::t;
+use crate::i18n::{t, t_args};
 use crate::icon;
+use crate::network::UpdateInfo;
 use crate::style::{
-    BORDER_WIDTH, EMPTY_VIEW_SIZE, PANEL_SPACING, TOOLBAR_ICON_SIZE, TOOLBAR_ICON_SPACING,
-    TOOLBAR_PADDING_HORIZONTAL, TOOLBAR_PADDING_VERTICAL, TOOLBAR_SPACING, TOOLBAR_TITLE_SIZE,
-    TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE,
-    content_background_style, disabled_icon_button_style, modal_overlay_style, muted_text_style,
-    separator_style, shaped_text, toolbar_background_style, toolbar_button_style,
-    tooltip_container_style, transparent_icon_button_style,
+    BORDER_WIDTH, EMPTY_VIEW_SIZE, INPUT_PADDING, PANEL_SPACING, SMALL_PADDING, SMALL_SPACING,
+    TEXT_SIZE, TOOLBAR_ICON_SIZE, TOOLBAR_ICON_SPACING, TOOLBAR_PADDING_HORIZONTAL,
+    TOOLBAR_PADDING_VERTICAL, TOOLBAR_SPACING, TOOLBAR_TITLE_SIZE, TOOLTIP_BACKGROUND_PADDING,
+    TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE, content_background_style,
+    disabled_icon_button_style, modal_overlay_style, muted_text_style, pinned_messages_style,
+    separator_style, shaped_text, shaped_text_wrapped, toolbar_background_style,
+    toolbar_button_style, tooltip_container_style, transparent_icon_button_style,
 };
 use crate::types::{
     ActivePanel, BookmarkEditMode, Message, ServerConnection, ToolbarState, UserManagementState,
@@ -102,15 +105,14 @@ fn hidden_panel<'a>() -> Element<'a, Message> {
 /// - Connection form when no server is connected
 /// - Server content (chat/user management/broadcast) when connected
 pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
-    // Get permissions and admin status from active connection
-    let (is_admin, permissions) = config
-        .active_connection
-        .and_then(|id| config.connections.get(&id))
-        .map(|conn| (conn.is_admin, conn.permissions.as_slice()))
-        .unwrap_or((false, &[]));
-
-    // Check if user has permission to view user list
-    let can_view_user_list = is_admin || permissions.iter().any(|p| p == PERMISSION_USER_LIST);
+    // Check permissions on the active connection (none when disconnected)
+    let has_permission = |permission: &str| {
+        config
+            .active_connection
+            .and_then(|id| config.connections.get(&id))
+            .is_some_and(|conn| conn.has_permission(permission))
+    };
+    let can_view_user_list = has_permission(PERMISSION_USER_LIST);
 
     // Get server name from active connection
     let server_name = config
@@ -124,9 +126,11 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
         show_user_list: config.ui_state.show_user_list,
         active_panel: config.active_panel,
         is_connected: config.active_connection.is_some(),
-        is_admin,
-        permissions,
+        can_broadcast: has_permission(PERMISSION_USER_BROADCAST),
+        can_create_user: has_permission(PERMISSION_USER_CREATE),
+        can_edit_user: has_permission(PERMISSION_USER_EDIT),
         can_view_user_list,
+        quiet_hours_active: config.quiet_hours.is_active(),
         server_name,
     });
 
@@ -137,6 +141,9 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
             config.connections,
             config.active_connection,
             config.bookmark_errors,
+            config.bookmark_steps,
+            config.animation,
+            config.ui_density.metrics(),
         )
     } else {
         hidden_panel()
@@ -151,39 +158,41 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
             && let Some(conn) = config.connections.get(&conn_id)
             && let Some(user_mgmt) = config.user_management
         {
-            server_content_view(
-                conn,
-                config.message_input,
-                user_mgmt,
-                config.active_panel,
-                config.theme.clone(),
-                config.show_connection_notifications,
-                config.chat_font_size,
-                TimestampSettings {
-                    show_timestamps: config.show_timestamps,
-                    use_24_hour_time: config.use_24_hour_time,
-                    show_seconds: config.show_seconds,
-                },
-                config.settings_form,
-            )
+            server_content_view(conn, user_mgmt, &config)
         } else if config.active_connection.is_some() {
             // Connection exists but couldn't get all required state
             empty_content_view()
         } else {
             // Not connected - show connection form, with Settings/About overlay if active
-            let conn_form = connection_form_view(config.connection_form);
+            let conn_form = connection_form_view(config.connection_form, config.animation);
             match config.active_panel {
                 ActivePanel::Settings => stack![
                     conn_form,
                     settings_view(
                         config.theme.clone(),
+                        config.ui_density,
+                        config.show_role_badges,
                         config.show_connection_notifications,
+                        config.hide_repeated_topic,
+                        config.alert_on_disconnect,
+                        config.alert_on_mention,
+                        config.focus_new_messages,
+                        config.quiet_hours.clone(),
+                        config.watched_users_ignore_quiet_hours,
+                        config.auto_away,
                         config.chat_font_size,
-                        TimestampSettings {
-                            show_timestamps: config.show_timestamps,
-                            use_24_hour_time: config.use_24_hour_time,
-                            show_seconds: config.show_seconds,
-                        },
+                        config.max_message_tabs,
+                        config.paste_confirm_lines,
+                        config.collapse_message_lines,
+                        config.ctrl_enter_sends,
+                        config.chat_message_format,
+                        TimestampSettings::from(&config),
+                        config.forget_passwords,
+                        config.sync_preferences,
+                        config.check_for_updates,
+                        config.proxy.clone(),
+                        config.connection_log_path,
+                        config.bookmarks,
                         config.settings_form,
                     )
                 ]
@@ -209,7 +218,14 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
         config
             .active_connection
             .and_then(|conn_id| config.connections.get(&conn_id))
-            .map(|conn| user_list_panel(conn, &config.theme))
+            .map(|conn| {
+                user_list_panel(
+                    conn,
+                    &config.theme,
+                    config.ui_density.metrics(),
+                    config.show_role_badges,
+                )
+            })
             .unwrap_or_else(hidden_panel)
     } else {
         hidden_panel()
@@ -220,7 +236,39 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
         .spacing(PANEL_SPACING)
         .height(Fill);
 
-    column![toolbar, content].into()
+    column![toolbar]
+        .push(config.update_available.map(update_banner))
+        .push(content)
+        .into()
+}
+
+/// Build the banner mentioning a newer client release
+///
+/// Only links to the release; the user downloads it themselves.
+fn update_banner<'a>(update: &UpdateInfo) -> Element<'a, Message> {
+    let text = shaped_text_wrapped(t_args(
+        "msg-update-available",
+        &[("version", &update.version)],
+    ))
+    .size(TEXT_SIZE)
+    .width(Fill);
+    let open_button = button(shaped_text(t("button-open-link")).size(TEXT_SIZE))
+        .on_press(Message::OpenUrl(update.url.clone()))
+        .padding(INPUT_PADDING);
+    let dismiss_button = button(shaped_text(t("button-dismiss")).size(TEXT_SIZE))
+        .on_press(Message::DismissUpdateBanner)
+        .padding(INPUT_PADDING)
+        .style(button::secondary);
+
+    container(
+        row![text, open_button, dismiss_button]
+            .spacing(SMALL_SPACING)
+            .align_y(Center),
+    )
+    .padding(SMALL_PADDING)
+    .width(Fill)
+    .style(pinned_messages_style)
+    .into()
 }
 
 /// Build the top toolbar with buttons and toggles
@@ -232,19 +280,9 @@ fn build_toolbar(state: ToolbarState) -> Element<'static, Message> {
     // Need to capture this for the closures
     let active_panel = state.active_panel;
 
-    // Check permissions (avoid string allocations)
-    let has_broadcast = state.is_admin
-        || state
-            .permissions
-            .iter()
-            .any(|p| p == PERMISSION_USER_BROADCAST);
-    let has_user_create = state.is_admin
-        || state
-            .permissions
-            .iter()
-            .any(|p| p == PERMISSION_USER_CREATE);
-    let has_user_edit =
-        state.is_admin || state.permissions.iter().any(|p| p == PERMISSION_USER_EDIT);
+    let has_broadcast = state.can_broadcast;
+    let has_user_create = state.can_create_user;
+    let has_user_edit = state.can_edit_user;
 
     let toolbar = container(
         row![
@@ -383,6 +421,8 @@ fn build_toolbar(state: ToolbarState) -> Element<'static, Message> {
             .spacing(TOOLBAR_ICON_SPACING),
             // Spacer to push collapse buttons to the right
             container(shaped_text("")).width(Fill),
+            // Quiet hours indicator
+            quiet_hours_indicator(state.quiet_hours_active),
             // Collapse buttons group (with theme toggle)
             row![
                 // About button
@@ -494,34 +534,40 @@ fn build_toolbar(state: ToolbarState) -> Element<'static, Message> {
     toolbar.into()
 }
 
+/// Subtle label shown in the toolbar while quiet hours are in effect
+fn quiet_hours_indicator(active: bool) -> Element<'static, Message> {
+    if !active {
+        return Space::new().into();
+    }
+    tooltip(
+        shaped_text(t("label-quiet-hours-active"))
+            .size(TOOLTIP_TEXT_SIZE)
+            .style(muted_text_style),
+        container(shaped_text(t("tooltip-quiet-hours-active")).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Bottom,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING)
+    .into()
+}
+
 /// Dispatches to appropriate content view based on active panels
 ///
 /// Always renders chat view at the bottom layer to preserve scroll position,
 /// then overlays broadcast or user management panels on top when active.
-#[allow(clippy::too_many_arguments)]
 fn server_content_view<'a>(
     conn: &'a ServerConnection,
-    message_input: &'a str,
     user_management: &'a UserManagementState,
-    active_panel: ActivePanel,
-    theme: iced::Theme,
-    show_connection_notifications: bool,
-    chat_font_size: u8,
-    timestamp_settings: TimestampSettings,
-    settings_form: Option<&'a crate::types::SettingsFormState>,
+    config: &ViewConfig<'a>,
 ) -> Element<'a, Message> {
     // Always render chat view as the base layer to preserve scroll position
-    let chat = chat_view(
-        conn,
-        message_input,
-        theme.clone(),
-        chat_font_size,
-        timestamp_settings,
-    );
+    let chat = chat_view(conn, config);
 
     // Overlay panels on top when active
-    match active_panel {
-        ActivePanel::About => stack![chat, about_view(theme)]
+    match config.active_panel {
+        ActivePanel::About => stack![chat, about_view(config.theme.clone())]
             .width(Fill)
             .height(Fill)
             .into(),
@@ -530,7 +576,7 @@ fn server_content_view<'a>(
             .height(Fill)
             .into(),
         ActivePanel::AddUser | ActivePanel::EditUser => {
-            stack![chat, users_view(conn, user_management, active_panel)]
+            stack![chat, users_view(conn, user_management, config.active_panel)]
                 .width(Fill)
                 .height(Fill)
                 .into()
@@ -538,11 +584,31 @@ fn server_content_view<'a>(
         ActivePanel::Settings => stack![
             chat,
             settings_view(
-                theme.clone(),
-                show_connection_notifications,
-                chat_font_size,
-                timestamp_settings,
-                settings_form,
+                config.theme.clone(),
+                config.ui_density,
+                config.show_role_badges,
+                config.show_connection_notifications,
+                config.hide_repeated_topic,
+                config.alert_on_disconnect,
+                config.alert_on_mention,
+                config.focus_new_messages,
+                config.quiet_hours.clone(),
+                config.watched_users_ignore_quiet_hours,
+                config.auto_away,
+                config.chat_font_size,
+                config.max_message_tabs,
+                config.paste_confirm_lines,
+                config.collapse_message_lines,
+                config.ctrl_enter_sends,
+                config.chat_message_format,
+                TimestampSettings::from(config),
+                config.forget_passwords,
+                config.sync_preferences,
+                config.check_for_updates,
+                config.proxy.clone(),
+                config.connection_log_path,
+                config.bookmarks,
+                config.settings_form,
             )
         ]
         .width(Fill)
@@ -553,7 +619,12 @@ fn server_content_view<'a>(
                 name: conn.server_name.clone(),
                 description: conn.server_description.clone(),
                 version: conn.server_version.clone(),
+                features: conn
+                    .capabilities
+                    .as_ref()
+                    .map(|caps| caps.features.as_slice()),
                 max_connections_per_ip: conn.max_connections_per_ip,
+                traffic: conn.traffic_totals,
                 cached_server_image: conn.cached_server_image.as_ref(),
                 is_admin: conn.is_admin,
                 edit_state: conn.server_info_edit.as_ref(),
@@ -567,11 +638,18 @@ fn server_content_view<'a>(
             chat,
             user_info_view(
                 &conn.user_info_data,
-                theme,
-                conn.is_admin,
-                &conn.permissions,
+                config.theme.clone(),
+                conn.has_permission(PERMISSION_USER_EDIT),
                 &conn.username,
-                &conn.avatar_cache
+                &conn.avatar_cache,
+                conn.capabilities
+                    .as_ref()
+                    .map(|caps| caps.features.as_slice()),
+                (conn.has_permission(PERMISSION_USER_KICK)
+                    && conn.supports_message_type("KickSession"))
+                .then_some(conn.session_id),
+                conn.profile_edit.as_ref(),
+                conn.supports_message_type("SetProfile"),
             )
         ]
         .width(Fill)
@@ -1,6 +1,7 @@
 //! UI view rendering components
 
 mod about;
+mod banner;
 mod bookmark;
 mod broadcast;
 mod chat;
@@ -8,6 +9,8 @@ mod connection;
 pub(crate) mod constants;
 mod fingerprint;
 mod layout;
+mod page;
+mod rules;
 mod server_info;
 mod server_list;
 mod settings;
@@ -16,5 +19,9 @@ mod user_list;
 mod users;
 
 // Re-export the main layout function and fingerprint dialog (public API)
+pub use banner::banner_dialog;
+pub use chat::{TimestampSettings, message_copy_text, popout_view};
 pub use fingerprint::fingerprint_mismatch_dialog;
 pub use layout::main_layout;
+pub use page::page_dialog;
+pub use rules::server_rules_dialog;
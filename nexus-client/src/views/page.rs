@@ -0,0 +1,46 @@
+//! Info page viewer dialog
+
+use super::layout::scrollable_modal;
+use crate::i18n::t;
+use crate::style::{
+    BUTTON_PADDING, ELEMENT_SPACING, FINGERPRINT_DIALOG_MAX_WIDTH, FINGERPRINT_SPACE_AFTER_TITLE,
+    FINGERPRINT_SPACE_BEFORE_BUTTONS, FORM_PADDING, TEXT_SIZE, TITLE_SIZE, shaped_text,
+    shaped_text_wrapped,
+};
+use crate::types::{InfoPage, Message};
+use iced::widget::{Space, button, column, row};
+use iced::{Element, Length};
+
+/// Create the dialog showing one of the server's info pages
+pub fn page_dialog(connection_id: usize, page: &InfoPage) -> Element<'_, Message> {
+    let title = shaped_text(&page.name)
+        .size(TITLE_SIZE)
+        .width(Length::Fill)
+        .center();
+
+    let content = shaped_text_wrapped(&page.content).size(TEXT_SIZE);
+
+    let close_button = button(
+        shaped_text(t("button-close"))
+            .size(TEXT_SIZE)
+            .width(Length::Fill)
+            .center(),
+    )
+    .on_press(Message::ClosePage(connection_id))
+    .padding(BUTTON_PADDING);
+
+    let button_row = row![Space::new().width(Length::Fill), close_button].spacing(ELEMENT_SPACING);
+
+    let dialog = column![
+        title,
+        Space::new().height(FINGERPRINT_SPACE_AFTER_TITLE),
+        content,
+        Space::new().height(FINGERPRINT_SPACE_BEFORE_BUTTONS),
+        button_row,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .padding(FORM_PADDING)
+    .max_width(FINGERPRINT_DIALOG_MAX_WIDTH);
+
+    scrollable_modal(dialog)
+}
@@ -0,0 +1,66 @@
+//! Server rules acknowledgement dialog view
+
+use super::layout::scrollable_modal;
+use crate::i18n::t;
+use crate::style::{
+    BUTTON_PADDING, ELEMENT_SPACING, FINGERPRINT_DIALOG_MAX_WIDTH, FINGERPRINT_SPACE_AFTER_TITLE,
+    FINGERPRINT_SPACE_BEFORE_BUTTONS, FORM_PADDING, TEXT_SIZE, TITLE_SIZE, shaped_text,
+    shaped_text_wrapped,
+};
+use crate::types::Message;
+use iced::widget::button as btn;
+use iced::widget::{Space, button, column, row};
+use iced::{Element, Length};
+
+/// Create the dialog asking the user to accept the server rules
+///
+/// Declining disconnects from the server.
+pub fn server_rules_dialog(connection_id: usize, rules: &str) -> Element<'_, Message> {
+    let title = shaped_text(t("title-server-rules"))
+        .size(TITLE_SIZE)
+        .width(Length::Fill)
+        .center();
+
+    let intro = shaped_text_wrapped(t("server-rules-intro")).size(TEXT_SIZE);
+    let rules_text = shaped_text_wrapped(rules).size(TEXT_SIZE);
+
+    let accept_button = button(
+        shaped_text(t("button-accept-rules"))
+            .size(TEXT_SIZE)
+            .width(Length::Fill)
+            .center(),
+    )
+    .on_press(Message::AcceptRules(connection_id))
+    .padding(BUTTON_PADDING);
+
+    let decline_button = button(
+        shaped_text(t("button-disconnect"))
+            .size(TEXT_SIZE)
+            .width(Length::Fill)
+            .center(),
+    )
+    .on_press(Message::DisconnectFromServer(connection_id))
+    .padding(BUTTON_PADDING)
+    .style(btn::secondary);
+
+    let button_row = row![
+        Space::new().width(Length::Fill),
+        decline_button,
+        accept_button
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    let dialog = column![
+        title,
+        Space::new().height(FINGERPRINT_SPACE_AFTER_TITLE),
+        intro,
+        rules_text,
+        Space::new().height(FINGERPRINT_SPACE_BEFORE_BUTTONS),
+        button_row,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .padding(FORM_PADDING)
+    .max_width(FINGERPRINT_DIALOG_MAX_WIDTH);
+
+    scrollable_modal(dialog)
+}
@@ -1,8 +1,9 @@
 //! Server info panel view
 
 use super::layout::scrollable_panel;
-use crate::i18n::t;
+use crate::i18n::{t, t_args, translate_feature};
 use crate::image::CachedImage;
+use crate::network::{TrafficTotals, format_bytes};
 use crate::style::{
     BUTTON_PADDING, ELEMENT_SPACING, FORM_MAX_WIDTH, FORM_PADDING, INPUT_PADDING,
     SERVER_IMAGE_PREVIEW_SIZE, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, SUBHEADING_SIZE, TEXT_SIZE,
@@ -22,8 +23,12 @@ pub struct ServerInfoData<'a> {
     pub description: Option<String>,
     /// Server version (if provided)
     pub version: Option<String>,
+    /// Features reported by the server's capabilities (None until received)
+    pub features: Option<&'a [String]>,
     /// Max connections per IP (admin only)
     pub max_connections_per_ip: Option<u32>,
+    /// Bytes sent and received over this connection
+    pub traffic: TrafficTotals,
     /// Cached server image for display (None if no image set)
     pub cached_server_image: Option<&'a CachedImage>,
     /// Whether the current user is an admin
@@ -95,6 +100,19 @@ fn server_info_display_view(data: &ServerInfoData<'_>) -> Element<'static, Messa
             .into()
     });
 
+    // Supported features (from the capabilities response)
+    let features_row: Option<Element<'static, Message>> = data
+        .features
+        .filter(|features| !features.is_empty())
+        .map(|features| {
+            let label = shaped_text(t("label-server-features")).size(TEXT_SIZE);
+            let names: Vec<String> = features.iter().map(|f| translate_feature(f)).collect();
+            let value = shaped_text(names.join(", ")).size(TEXT_SIZE);
+            row![label, Space::new().width(ELEMENT_SPACING), value]
+                .align_y(Center)
+                .into()
+        });
+
     // Max connections per IP (admin only)
     let max_conn_row: Option<Element<'static, Message>> =
         data.max_connections_per_ip.map(|max_conn| {
@@ -105,6 +123,22 @@ fn server_info_display_view(data: &ServerInfoData<'_>) -> Element<'static, Messa
                 .into()
         });
 
+    // Traffic over this connection (refreshed while the panel is open)
+    let traffic_row: Element<'static, Message> = {
+        let label = shaped_text(t("label-traffic")).size(TEXT_SIZE);
+        let value = shaped_text(t_args(
+            "server-info-traffic-value",
+            &[
+                ("sent", &format_bytes(data.traffic.sent)),
+                ("received", &format_bytes(data.traffic.received)),
+            ],
+        ))
+        .size(TEXT_SIZE);
+        row![label, Space::new().width(ELEMENT_SPACING), value]
+            .align_y(Center)
+            .into()
+    };
+
     // Buttons: Edit (admin only, secondary) and Close (primary)
     let buttons = if data.is_admin {
         row![
@@ -143,9 +177,13 @@ fn server_info_display_view(data: &ServerInfoData<'_>) -> Element<'static, Messa
     if let Some(ver) = version_row {
         items.push(ver);
     }
+    if let Some(features) = features_row {
+        items.push(features);
+    }
     if let Some(conn) = max_conn_row {
         items.push(conn);
     }
+    items.push(traffic_row);
     items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
     items.push(buttons.into());
 
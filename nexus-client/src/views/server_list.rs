@@ -1,18 +1,19 @@
 //! Server list panel (left sidebar)
 
-use crate::i18n::t;
+use crate::i18n::{t, t_args};
 use crate::icon;
 use crate::style::{
-    FORM_PADDING, ICON_BUTTON_PADDING, INPUT_PADDING, NO_SPACING, PANEL_SPACING,
-    SECTION_TITLE_SIZE, SEPARATOR_HEIGHT, SERVER_LIST_BUTTON_HEIGHT,
-    SERVER_LIST_DISCONNECT_ICON_SIZE, SERVER_LIST_ITEM_SPACING, SERVER_LIST_PANEL_WIDTH,
-    SERVER_LIST_SECTION_SPACING, SERVER_LIST_SMALL_TEXT_SIZE, SERVER_LIST_TEXT_SIZE,
+    Density, ICON_BUTTON_PADDING, INPUT_PADDING, NO_SPACING, PANEL_SPACING, SECTION_TITLE_SIZE,
+    SEPARATOR_HEIGHT, SERVER_LIST_BUTTON_HEIGHT, SERVER_LIST_DISCONNECT_ICON_SIZE,
+    SERVER_LIST_PANEL_WIDTH, SERVER_LIST_SMALL_TEXT_SIZE, SERVER_LIST_TEXT_SIZE,
     SIDEBAR_ACTION_ICON_SIZE, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING,
-    TOOLTIP_TEXT_SIZE, alternating_row_style, danger_icon_button_style, list_item_button_style,
-    muted_text_style, separator_style, shaped_text, sidebar_panel_style, tooltip_container_style,
-    transparent_icon_button_style,
+    TOOLTIP_TEXT_SIZE, alternating_row_style, connection_health_style, danger_icon_button_style,
+    list_item_button_style, muted_text_style, separator_style, shaped_text, sidebar_panel_style,
+    tooltip_container_style, transparent_icon_button_style,
+};
+use crate::types::{
+    AnimationFrame, ConnectionHealth, ConnectionStep, Message, ServerBookmark, ServerConnection,
 };
-use crate::types::{Message, ServerBookmark, ServerConnection};
 use iced::widget::{Column, Space, button, column, container, row, scrollable, tooltip};
 use iced::{Element, Fill, alignment};
 use std::collections::HashMap;
@@ -45,6 +46,36 @@ fn transparent_edit_button(
         .style(transparent_icon_button_style)
 }
 
+/// Colored dot showing keepalive health, with the round-trip time in a tooltip
+fn health_indicator<'a>(conn: &ServerConnection) -> Element<'a, Message> {
+    let health = conn.keepalive.health();
+    let label = match (health, conn.keepalive.average_rtt()) {
+        (ConnectionHealth::Unknown, _) => t("tooltip-connection-health-unknown"),
+        (_, None) => t("tooltip-connection-health-no-response"),
+        (health, Some(rtt)) => {
+            let key = match health {
+                ConnectionHealth::Good => "tooltip-connection-health-good",
+                ConnectionHealth::Fair => "tooltip-connection-health-fair",
+                _ => "tooltip-connection-health-poor",
+            };
+            t_args(key, &[("rtt", &rtt.as_millis().to_string())])
+        }
+    };
+
+    tooltip(
+        shaped_text("●")
+            .size(SERVER_LIST_SMALL_TEXT_SIZE)
+            .style(connection_health_style(health)),
+        container(shaped_text(label).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Right,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING)
+    .into()
+}
+
 /// Create a horizontal separator line
 fn separator<'a>() -> Element<'a, Message> {
     container(Space::new().width(Fill).height(SEPARATOR_HEIGHT))
@@ -62,12 +93,13 @@ fn separator<'a>() -> Element<'a, Message> {
 fn connected_servers_section<'a>(
     connections: &'a HashMap<usize, ServerConnection>,
     active_connection: Option<usize>,
+    density: Density,
 ) -> Column<'a, Message> {
     let connected_title = shaped_text(t("title-connected"))
         .size(SECTION_TITLE_SIZE)
         .style(muted_text_style);
 
-    let mut connected_column = Column::new().spacing(SERVER_LIST_ITEM_SPACING);
+    let mut connected_column = Column::new().spacing(density.list_item_spacing);
 
     if connections.is_empty() {
         connected_column = connected_column.push(
@@ -102,7 +134,7 @@ fn connected_servers_section<'a>(
             .gap(TOOLTIP_GAP)
             .padding(TOOLTIP_PADDING);
 
-            let server_row = row![btn, disconnect_btn]
+            let server_row = row![btn, health_indicator(conn), disconnect_btn]
                 .spacing(NO_SPACING)
                 .align_y(alignment::Vertical::Center);
 
@@ -117,8 +149,8 @@ fn connected_servers_section<'a>(
     }
 
     column![connected_title, connected_column]
-        .spacing(SERVER_LIST_SECTION_SPACING)
-        .padding(FORM_PADDING)
+        .spacing(density.list_section_spacing)
+        .padding(density.list_padding)
 }
 
 // ============================================================================
@@ -130,12 +162,15 @@ fn bookmarks_section<'a>(
     bookmarks: &'a [ServerBookmark],
     connections: &'a HashMap<usize, ServerConnection>,
     bookmark_errors: &'a HashMap<usize, String>,
+    bookmark_steps: &'a HashMap<usize, ConnectionStep>,
+    animation: AnimationFrame,
+    density: Density,
 ) -> Column<'a, Message> {
     let bookmarks_title = shaped_text(t("title-bookmarks"))
         .size(SECTION_TITLE_SIZE)
         .style(muted_text_style);
 
-    let mut bookmarks_column = Column::new().spacing(SERVER_LIST_ITEM_SPACING);
+    let mut bookmarks_column = Column::new().spacing(density.list_item_spacing);
 
     if bookmarks.is_empty() {
         bookmarks_column = bookmarks_column.push(
@@ -166,9 +201,30 @@ fn bookmarks_section<'a>(
                 Message::ConnectToBookmark(index)
             };
 
+            // Show a spinner and connection progress (e.g., "2/4") beside the name while connecting
+            let label: Element<'a, Message> = match bookmark_steps.get(&index) {
+                Some(step) => row![
+                    shaped_text(&bookmark.name).size(SERVER_LIST_TEXT_SIZE),
+                    Space::new().width(Fill),
+                    shaped_text(format!(
+                        "{} {}/{}",
+                        animation.spinner(),
+                        step.number(),
+                        ConnectionStep::COUNT
+                    ))
+                    .size(SERVER_LIST_SMALL_TEXT_SIZE)
+                    .style(muted_text_style),
+                ]
+                .align_y(alignment::Vertical::Center)
+                .into(),
+                None => shaped_text(&bookmark.name)
+                    .size(SERVER_LIST_TEXT_SIZE)
+                    .into(),
+            };
+
             // Transparent button with hover effect
             // Show in danger color if there's an error, primary color if connected, normal otherwise
-            let btn = button(shaped_text(&bookmark.name).size(SERVER_LIST_TEXT_SIZE))
+            let btn = button(label)
                 .width(Fill)
                 .height(SERVER_LIST_BUTTON_HEIGHT)
                 .padding(INPUT_PADDING)
@@ -225,8 +281,8 @@ fn bookmarks_section<'a>(
         scrollable(bookmarks_column).height(Fill),
         Element::from(add_btn),
     ]
-    .spacing(SERVER_LIST_SECTION_SPACING)
-    .padding(FORM_PADDING)
+    .spacing(density.list_section_spacing)
+    .padding(density.list_padding)
 }
 
 // ============================================================================
@@ -243,11 +299,21 @@ pub fn server_list_panel<'a>(
     connections: &'a HashMap<usize, ServerConnection>,
     active_connection: Option<usize>,
     bookmark_errors: &'a HashMap<usize, String>,
+    bookmark_steps: &'a HashMap<usize, ConnectionStep>,
+    animation: AnimationFrame,
+    density: Density,
 ) -> Element<'a, Message> {
     let main_column = column![
-        connected_servers_section(connections, active_connection),
+        connected_servers_section(connections, active_connection, density),
         separator(),
-        bookmarks_section(bookmarks, connections, bookmark_errors),
+        bookmarks_section(
+            bookmarks,
+            connections,
+            bookmark_errors,
+            bookmark_steps,
+            animation,
+            density,
+        ),
     ]
     .spacing(PANEL_SPACING);
 
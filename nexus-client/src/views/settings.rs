@@ -1,19 +1,30 @@
 //! Settings panel view
 
 use super::chat::TimestampSettings;
+use super::fingerprint::format_fingerprint_multiline;
 use super::layout::scrollable_panel;
-use crate::config::settings::CHAT_FONT_SIZES;
+use crate::config::density::UiDensity;
+use crate::config::message_format::{DEFAULT_MESSAGE_FORMAT, MessageFormatPreset};
+use crate::config::quiet_hours::{QUIET_HOURS_END_DEFAULT, QUIET_HOURS_START_DEFAULT, QuietHours};
+use crate::config::settings::{
+    AUTO_AWAY_MINUTES_MAX, AUTO_AWAY_MINUTES_MIN, AutoAwaySettings, CHAT_FONT_SIZES,
+    COLLAPSE_MESSAGE_LINES_MAX, MAX_MESSAGE_TABS_MAX, MAX_MESSAGE_TABS_MIN,
+    PASTE_CONFIRM_LINES_MAX, PROXY_ADDRESS_DEFAULT, ProxySettings,
+};
 use crate::config::theme::all_themes;
-use crate::i18n::t;
+use crate::config::timezone::all_timezones;
+use crate::i18n::{t, t_args};
 use crate::style::{
     AVATAR_PREVIEW_SIZE, BUTTON_PADDING, ELEMENT_SPACING, FORM_MAX_WIDTH, FORM_PADDING,
-    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, SUBHEADING_SIZE, TEXT_SIZE, TITLE_SIZE,
-    error_text_style, shaped_text, shaped_text_wrapped, subheading_text_style,
+    INPUT_PADDING, MONOSPACE_FONT, QUIET_HOURS_INPUT_WIDTH, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL,
+    SUBHEADING_SIZE, TEXT_SIZE, TITLE_SIZE, error_text_style, muted_text_style, shaped_text,
+    shaped_text_wrapped, subheading_text_style,
 };
-use crate::types::{Message, SettingsFormState};
+use crate::types::{Message, ServerBookmark, SettingsFormState};
 use iced::widget::button as btn;
-use iced::widget::{Column, Space, button, checkbox, pick_list, row};
+use iced::widget::{Column, Space, button, checkbox, column, pick_list, row, text_input};
 use iced::{Center, Element, Fill, Theme};
+use iced_aw::NumberInput;
 
 // ============================================================================
 // Settings View
@@ -23,11 +34,32 @@ use iced::{Center, Element, Fill, Theme};
 ///
 /// Shows application settings that can be modified and saved to disk.
 /// Cancel restores original settings, Save persists changes.
+#[allow(clippy::too_many_arguments)]
 pub fn settings_view(
     current_theme: Theme,
+    ui_density: UiDensity,
+    show_role_badges: bool,
     show_connection_notifications: bool,
+    hide_repeated_topic: bool,
+    alert_on_disconnect: bool,
+    alert_on_mention: bool,
+    focus_new_messages: bool,
+    quiet_hours: QuietHours,
+    watched_users_ignore_quiet_hours: bool,
+    auto_away: AutoAwaySettings,
     chat_font_size: u8,
+    max_message_tabs: usize,
+    paste_confirm_lines: usize,
+    collapse_message_lines: usize,
+    ctrl_enter_sends: bool,
+    chat_message_format: &str,
     timestamp_settings: TimestampSettings,
+    forget_passwords: bool,
+    sync_preferences: bool,
+    check_for_updates: bool,
+    proxy: ProxySettings,
+    connection_log_path: &str,
+    bookmarks: &[ServerBookmark],
     settings_form: Option<&SettingsFormState>,
 ) -> Element<'static, Message> {
     // Extract avatar state from settings form (only present when panel is open)
@@ -40,6 +72,7 @@ pub fn settings_view(
             )
         })
         .unwrap_or((None, None, None));
+    let confirm_clear_fingerprint = settings_form.and_then(|f| f.confirm_clear_fingerprint);
 
     let title = shaped_text(t("title-settings"))
         .size(TITLE_SIZE)
@@ -54,6 +87,14 @@ pub fn settings_view(
         .spacing(ELEMENT_SPACING)
         .align_y(Center);
 
+    // Density picker row
+    let density_label = shaped_text(t("label-density")).size(TEXT_SIZE);
+    let density_picker = pick_list(UiDensity::ALL, Some(ui_density), Message::UiDensitySelected)
+        .text_size(TEXT_SIZE);
+    let density_row = row![density_label, density_picker]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
     // Chat font size picker row
     let font_size_label = shaped_text(t("label-chat-font-size")).size(TEXT_SIZE);
     let font_size_picker = pick_list(
@@ -66,12 +107,165 @@ pub fn settings_view(
         .spacing(ELEMENT_SPACING)
         .align_y(Center);
 
+    // Maximum open message tabs row
+    let max_tabs_label = shaped_text(t("label-max-message-tabs")).size(TEXT_SIZE);
+    let max_tabs_input: Element<'static, Message> = NumberInput::new(
+        &max_message_tabs,
+        MAX_MESSAGE_TABS_MIN..=MAX_MESSAGE_TABS_MAX,
+        Message::MaxMessageTabsChanged,
+    )
+    .padding(INPUT_PADDING)
+    .into();
+    let max_tabs_row = row![max_tabs_label, max_tabs_input]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
+    // Large paste confirmation threshold row (0 never asks)
+    let paste_confirm_label = shaped_text(t("label-paste-confirm-lines")).size(TEXT_SIZE);
+    let paste_confirm_input: Element<'static, Message> = NumberInput::new(
+        &paste_confirm_lines,
+        0..=PASTE_CONFIRM_LINES_MAX,
+        Message::PasteConfirmLinesChanged,
+    )
+    .padding(INPUT_PADDING)
+    .into();
+    let paste_confirm_row = row![paste_confirm_label, paste_confirm_input]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
+    // Long message collapse threshold row (0 never collapses)
+    let collapse_lines_label = shaped_text(t("label-collapse-message-lines")).size(TEXT_SIZE);
+    let collapse_lines_input: Element<'static, Message> = NumberInput::new(
+        &collapse_message_lines,
+        0..=COLLAPSE_MESSAGE_LINES_MAX,
+        Message::CollapseMessageLinesChanged,
+    )
+    .padding(INPUT_PADDING)
+    .into();
+    let collapse_lines_row = row![collapse_lines_label, collapse_lines_input]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
+    // Send key checkbox
+    let ctrl_enter_sends_checkbox = checkbox(ctrl_enter_sends)
+        .label(t("label-ctrl-enter-sends"))
+        .on_toggle(Message::CtrlEnterSendsToggled)
+        .text_size(TEXT_SIZE);
+
+    // Message format preset picker (shows "Custom" for any other template)
+    let message_format_label = shaped_text(t("label-message-format")).size(TEXT_SIZE);
+    let message_format_picker = pick_list(
+        MessageFormatPreset::ALL,
+        MessageFormatPreset::from_template(chat_message_format),
+        Message::ChatMessageFormatPresetSelected,
+    )
+    .placeholder(t("message-format-preset-custom"))
+    .text_size(TEXT_SIZE);
+    let message_format_row = row![message_format_label, message_format_picker]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
+    // Message format template, editable for a custom layout
+    let message_format_input = text_input(DEFAULT_MESSAGE_FORMAT, chat_message_format)
+        .on_input(Message::ChatMessageFormatChanged)
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE);
+    let message_format_input_row = row![Space::new().width(20), message_format_input]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
+    // Role badges checkbox
+    let role_badges_checkbox = checkbox(show_role_badges)
+        .label(t("label-show-role-badges"))
+        .on_toggle(Message::ShowRoleBadgesToggled)
+        .text_size(TEXT_SIZE);
+
     // Connection notifications checkbox
     let notifications_checkbox = checkbox(show_connection_notifications)
         .label(t("label-show-connection-notifications"))
         .on_toggle(Message::ConnectionNotificationsToggled)
         .text_size(TEXT_SIZE);
 
+    // Repeated topic checkbox
+    let hide_repeated_topic_checkbox = checkbox(hide_repeated_topic)
+        .label(t("label-hide-repeated-topic"))
+        .on_toggle(Message::HideRepeatedTopicToggled)
+        .text_size(TEXT_SIZE);
+
+    // Disconnect alert checkbox
+    let alert_on_disconnect_checkbox = checkbox(alert_on_disconnect)
+        .label(t("label-alert-on-disconnect"))
+        .on_toggle(Message::AlertOnDisconnectToggled)
+        .text_size(TEXT_SIZE);
+
+    // Mention alert checkbox
+    let alert_on_mention_checkbox = checkbox(alert_on_mention)
+        .label(t("label-alert-on-mention"))
+        .on_toggle(Message::AlertOnMentionToggled)
+        .text_size(TEXT_SIZE);
+
+    // Focus new private messages checkbox
+    let focus_new_messages_checkbox = checkbox(focus_new_messages)
+        .label(t("label-focus-new-messages"))
+        .on_toggle(Message::FocusNewMessagesToggled)
+        .text_size(TEXT_SIZE);
+
+    // Quiet hours (start and end are only editable while quiet hours are enabled)
+    let quiet_hours_checkbox = checkbox(quiet_hours.enabled)
+        .label(t("label-quiet-hours"))
+        .on_toggle(Message::QuietHoursToggled)
+        .text_size(TEXT_SIZE);
+    let quiet_hours_start_input = text_input(QUIET_HOURS_START_DEFAULT, &quiet_hours.start)
+        .on_input_maybe(
+            quiet_hours
+                .enabled
+                .then_some(Message::QuietHoursStartChanged),
+        )
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE)
+        .width(QUIET_HOURS_INPUT_WIDTH);
+    let quiet_hours_end_input = text_input(QUIET_HOURS_END_DEFAULT, &quiet_hours.end)
+        .on_input_maybe(quiet_hours.enabled.then_some(Message::QuietHoursEndChanged))
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE)
+        .width(QUIET_HOURS_INPUT_WIDTH);
+    let quiet_hours_row = row![
+        Space::new().width(20),
+        shaped_text(t("label-quiet-hours-from")).size(TEXT_SIZE),
+        quiet_hours_start_input,
+        shaped_text(t("label-quiet-hours-to")).size(TEXT_SIZE),
+        quiet_hours_end_input
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
+    // Watched users alert during quiet hours checkbox
+    let watched_users_checkbox = checkbox(watched_users_ignore_quiet_hours)
+        .label(t("label-watched-users-ignore-quiet-hours"))
+        .on_toggle(Message::WatchedUsersIgnoreQuietHoursToggled)
+        .text_size(TEXT_SIZE);
+
+    // Auto-away (the idle minutes are only editable while auto-away is enabled)
+    let auto_away_checkbox = checkbox(auto_away.enabled)
+        .label(t("label-auto-away"))
+        .on_toggle(Message::AutoAwayToggled)
+        .text_size(TEXT_SIZE);
+    let auto_away_minutes_input: Element<'static, Message> = NumberInput::new(
+        &auto_away.minutes,
+        AUTO_AWAY_MINUTES_MIN..=AUTO_AWAY_MINUTES_MAX,
+        Message::AutoAwayMinutesChanged,
+    )
+    .on_input_maybe(auto_away.enabled.then_some(Message::AutoAwayMinutesChanged))
+    .padding(INPUT_PADDING)
+    .into();
+    let auto_away_row = row![
+        Space::new().width(20),
+        shaped_text(t("label-auto-away-minutes")).size(TEXT_SIZE),
+        auto_away_minutes_input
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
     // Timestamp settings
     let timestamps_checkbox = checkbox(timestamp_settings.show_timestamps)
         .label(t("label-show-timestamps"))
@@ -102,9 +296,73 @@ pub fn settings_view(
             .text_size(TEXT_SIZE)
     };
 
+    // Timezone picker
+    let timezone_label = shaped_text(t("label-timezone")).size(TEXT_SIZE);
+    let timezone_picker = pick_list(
+        all_timezones(),
+        Some(timestamp_settings.timezone),
+        Message::TimestampTimezoneSelected,
+    )
+    .text_size(TEXT_SIZE);
+
     // Indent the dependent timestamp options
     let time_format_row = row![Space::new().width(20), time_format_checkbox];
     let seconds_row = row![Space::new().width(20), seconds_checkbox];
+    let timezone_row = row![Space::new().width(20), timezone_label, timezone_picker]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
+    // Privacy options
+    let forget_passwords_checkbox = checkbox(forget_passwords)
+        .label(t("label-forget-passwords"))
+        .on_toggle(Message::ForgetPasswordsToggled)
+        .text_size(TEXT_SIZE);
+    let sync_preferences_checkbox = checkbox(sync_preferences)
+        .label(t("label-sync-preferences"))
+        .on_toggle(Message::SyncPreferencesToggled)
+        .text_size(TEXT_SIZE);
+    let check_for_updates_checkbox = checkbox(check_for_updates)
+        .label(t("label-check-for-updates"))
+        .on_toggle(Message::CheckForUpdatesToggled)
+        .text_size(TEXT_SIZE);
+
+    // Proxy options (address and port are only editable while the proxy is enabled)
+    let proxy_checkbox = checkbox(proxy.enabled)
+        .label(t("label-use-socks5-proxy"))
+        .on_toggle(Message::ProxyToggled)
+        .text_size(TEXT_SIZE);
+    let proxy_address_label = shaped_text(t("label-proxy-address")).size(TEXT_SIZE);
+    let proxy_port_label = shaped_text(t("label-proxy-port")).size(TEXT_SIZE);
+    let proxy_address_input = text_input(PROXY_ADDRESS_DEFAULT, &proxy.address)
+        .on_input_maybe(proxy.enabled.then_some(Message::ProxyAddressChanged))
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE);
+    let proxy_port_input: Element<'static, Message> =
+        NumberInput::new(&proxy.port, 1..=u16::MAX, Message::ProxyPortChanged)
+            .on_input_maybe(proxy.enabled.then_some(Message::ProxyPortChanged))
+            .padding(INPUT_PADDING)
+            .into();
+    let proxy_address_row = row![
+        Space::new().width(20),
+        proxy_address_label,
+        proxy_address_input
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+    let proxy_port_row = row![Space::new().width(20), proxy_port_label, proxy_port_input]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
+    // Connection event log file
+    let connection_log_label = shaped_text(t("label-connection-log-path")).size(TEXT_SIZE);
+    let connection_log_input =
+        text_input(&t("placeholder-connection-log-path"), connection_log_path)
+            .on_input(Message::ConnectionLogPathChanged)
+            .padding(INPUT_PADDING)
+            .size(TEXT_SIZE);
+    let connection_log_row = row![connection_log_label, connection_log_input]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
 
     // Avatar section
     let avatar_preview: Element<'static, Message> = if let Some(av) = avatar {
@@ -178,6 +436,26 @@ pub fn settings_view(
         .size(SUBHEADING_SIZE)
         .style(subheading_text_style);
 
+    // Privacy subheading
+    let privacy_heading = shaped_text(t("label-privacy"))
+        .size(SUBHEADING_SIZE)
+        .style(subheading_text_style);
+
+    // Trusted certificates subheading
+    let certificates_heading = shaped_text(t("label-trusted-certificates"))
+        .size(SUBHEADING_SIZE)
+        .style(subheading_text_style);
+
+    // Proxy subheading
+    let proxy_heading = shaped_text(t("label-proxy"))
+        .size(SUBHEADING_SIZE)
+        .style(subheading_text_style);
+
+    // Diagnostics subheading
+    let diagnostics_heading = shaped_text(t("label-diagnostics"))
+        .size(SUBHEADING_SIZE)
+        .style(subheading_text_style);
+
     // Avatar subheading
     let avatar_heading = shaped_text(t("label-avatar"))
         .size(SUBHEADING_SIZE)
@@ -186,16 +464,55 @@ pub fn settings_view(
     form_items.extend([
         appearance_heading.into(),
         theme_row.into(),
+        density_row.into(),
+        role_badges_checkbox.into(),
         Space::new().height(SPACER_SIZE_SMALL).into(),
         avatar_heading.into(),
         avatar_row.into(),
         Space::new().height(SPACER_SIZE_SMALL).into(),
         chat_heading.into(),
         font_size_row.into(),
+        max_tabs_row.into(),
+        paste_confirm_row.into(),
+        collapse_lines_row.into(),
+        ctrl_enter_sends_checkbox.into(),
+        message_format_row.into(),
+        message_format_input_row.into(),
         notifications_checkbox.into(),
+        hide_repeated_topic_checkbox.into(),
+        alert_on_disconnect_checkbox.into(),
+        alert_on_mention_checkbox.into(),
+        focus_new_messages_checkbox.into(),
+        quiet_hours_checkbox.into(),
+        quiet_hours_row.into(),
+        watched_users_checkbox.into(),
+        auto_away_checkbox.into(),
+        auto_away_row.into(),
         timestamps_checkbox.into(),
         time_format_row.into(),
         seconds_row.into(),
+        timezone_row.into(),
+        Space::new().height(SPACER_SIZE_SMALL).into(),
+        privacy_heading.into(),
+        forget_passwords_checkbox.into(),
+        sync_preferences_checkbox.into(),
+        check_for_updates_checkbox.into(),
+        Space::new().height(SPACER_SIZE_SMALL).into(),
+        certificates_heading.into(),
+    ]);
+    form_items.extend(trusted_certificate_rows(
+        bookmarks,
+        confirm_clear_fingerprint,
+    ));
+    form_items.extend([
+        Space::new().height(SPACER_SIZE_SMALL).into(),
+        proxy_heading.into(),
+        proxy_checkbox.into(),
+        proxy_address_row.into(),
+        proxy_port_row.into(),
+        Space::new().height(SPACER_SIZE_SMALL).into(),
+        diagnostics_heading.into(),
+        connection_log_row.into(),
         Space::new().height(SPACER_SIZE_MEDIUM).into(),
         buttons.into(),
     ]);
@@ -207,3 +524,85 @@ pub fn settings_view(
 
     scrollable_panel(form)
 }
+
+/// Rows for the trusted certificates section
+///
+/// Lists every bookmark with its pinned certificate fingerprint, or a note
+/// that none is pinned yet. The bookmark awaiting clear confirmation shows
+/// the warning with confirm buttons in place of Copy and Clear.
+fn trusted_certificate_rows(
+    bookmarks: &[ServerBookmark],
+    confirm_clear: Option<usize>,
+) -> Vec<Element<'static, Message>> {
+    if bookmarks.is_empty() {
+        return vec![
+            shaped_text(t("empty-no-bookmarks"))
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .into(),
+        ];
+    }
+
+    bookmarks
+        .iter()
+        .enumerate()
+        .map(|(index, bookmark)| {
+            let name = shaped_text(bookmark.name.clone()).size(TEXT_SIZE);
+
+            let Some(fingerprint) = &bookmark.certificate_fingerprint else {
+                let not_pinned = shaped_text(t("label-fingerprint-not-pinned"))
+                    .size(TEXT_SIZE)
+                    .style(muted_text_style);
+                return column![name, row![Space::new().width(20), not_pinned]].into();
+            };
+
+            let value = shaped_text(format_fingerprint_multiline(fingerprint))
+                .size(TEXT_SIZE)
+                .font(MONOSPACE_FONT);
+
+            let actions: Element<'static, Message> = if confirm_clear == Some(index) {
+                let warning = shaped_text_wrapped(t_args(
+                    "msg-confirm-clear-fingerprint",
+                    &[("name", &bookmark.name)],
+                ))
+                .size(TEXT_SIZE)
+                .style(error_text_style);
+                let buttons = row![
+                    button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
+                        .on_press(Message::CancelClearFingerprint)
+                        .padding(BUTTON_PADDING)
+                        .style(btn::secondary),
+                    button(shaped_text(t("button-clear-fingerprint")).size(TEXT_SIZE))
+                        .on_press(Message::ConfirmClearFingerprint)
+                        .padding(BUTTON_PADDING)
+                        .style(btn::danger),
+                ]
+                .spacing(ELEMENT_SPACING);
+                column![warning, buttons].spacing(ELEMENT_SPACING).into()
+            } else {
+                row![
+                    button(shaped_text(t("button-copy-fingerprint")).size(TEXT_SIZE))
+                        .on_press(Message::CopyFingerprintPressed(index))
+                        .padding(BUTTON_PADDING)
+                        .style(btn::secondary),
+                    button(shaped_text(t("button-clear-fingerprint")).size(TEXT_SIZE))
+                        .on_press(Message::ClearFingerprintPressed(index))
+                        .padding(BUTTON_PADDING)
+                        .style(btn::danger),
+                ]
+                .spacing(ELEMENT_SPACING)
+                .into()
+            };
+
+            column![
+                name,
+                row![
+                    Space::new().width(20),
+                    column![value, actions].spacing(ELEMENT_SPACING)
+                ],
+            ]
+            .spacing(ELEMENT_SPACING)
+            .into()
+        })
+        .collect()
+}
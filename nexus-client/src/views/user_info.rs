@@ -5,34 +5,45 @@ use std::collections::HashMap;
 use super::layout::scrollable_panel;
 use crate::avatar::generate_identicon;
 use crate::handlers::network::constants::DATETIME_FORMAT;
-use crate::handlers::network::helpers::format_duration;
-use crate::i18n::{t, t_args};
+use crate::handlers::network::helpers::{format_duration, format_session_addresses};
+use crate::i18n::{t, t_args, translate_feature};
 use crate::image::CachedImage;
 use crate::style::{
-    BUTTON_PADDING, ELEMENT_SPACING, FORM_MAX_WIDTH, FORM_PADDING, SPACER_SIZE_MEDIUM, TEXT_SIZE,
-    TITLE_SIZE, USER_INFO_AVATAR_SIZE, USER_INFO_AVATAR_SPACING, chat, shaped_text,
+    BUTTON_PADDING, ELEMENT_SPACING, FORM_MAX_WIDTH, FORM_PADDING, INPUT_PADDING, SMALL_PADDING,
+    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, TITLE_SIZE, USER_INFO_AVATAR_SIZE,
+    USER_INFO_AVATAR_SPACING, chat, error_text_style, shaped_text, shaped_text_wrapped,
 };
-use crate::types::Message;
+use crate::types::{InputId, Message, ProfileEditState};
 use iced::widget::button as btn;
-use iced::widget::{Space, button, column, row};
+use iced::widget::{Id, Space, button, column, row, text_input};
 use iced::{Center, Color, Element, Fill, Theme};
 use nexus_common::protocol::UserInfoDetailed;
 
-use super::constants::PERMISSION_USER_EDIT;
-
 /// Render the user info panel
 ///
 /// Displays user information received from the server.
 /// Shows loading state, error state, or user details depending on data.
+/// `server_features` is the server's supported feature list (None until the
+/// capabilities response arrives) and is used to mark inactive features.
+/// `session_kick` is the viewer's own session ID when they may disconnect
+/// individual sessions (None hides the per-session buttons).
+/// `profile_edit` replaces the panel with the profile edit form, and
+/// `can_edit_profile` shows an Edit Profile button when viewing self.
+#[allow(clippy::too_many_arguments)]
 pub fn user_info_view<'a>(
     data: &Option<Result<UserInfoDetailed, String>>,
     theme: Theme,
-    is_admin: bool,
-    permissions: &[String],
+    has_edit_permission: bool,
     current_username: &str,
     avatar_cache: &'a HashMap<String, CachedImage>,
+    server_features: Option<&[String]>,
+    session_kick: Option<u32>,
+    profile_edit: Option<&ProfileEditState>,
+    can_edit_profile: bool,
 ) -> Element<'a, Message> {
-    let has_edit_permission = is_admin || permissions.iter().any(|p| p == PERMISSION_USER_EDIT);
+    if let Some(edit_state) = profile_edit {
+        return profile_edit_view(edit_state);
+    }
 
     let mut content = column![].spacing(ELEMENT_SPACING);
 
@@ -55,24 +66,42 @@ pub fn user_info_view<'a>(
         }
         Some(Ok(user)) => {
             // User info display with avatar + username header
-            content = build_user_info_content(content, user, &theme, avatar_cache);
+            content = build_user_info_content(
+                content,
+                user,
+                &theme,
+                avatar_cache,
+                server_features,
+                session_kick,
+            );
         }
     }
 
     // Build buttons row - spacer, then Edit (if permitted) + Close on the right
     let mut buttons = row![Space::new().width(Fill)].spacing(ELEMENT_SPACING);
 
-    // Add edit button if user has permission, data loaded, and not viewing self
-    if has_edit_permission
-        && let Some(Ok(user)) = data
-        && user.username.to_lowercase() != current_username.to_lowercase()
-    {
-        buttons = buttons.push(
-            button(shaped_text(t("button-edit")).size(TEXT_SIZE))
-                .on_press(Message::ToggleEditUser(Some(user.username.clone())))
-                .padding(BUTTON_PADDING)
-                .style(btn::secondary),
-        );
+    if let Some(Ok(user)) = data {
+        let viewing_self = user.username.to_lowercase() == current_username.to_lowercase();
+
+        // Add edit profile button when viewing self and the server supports it
+        if viewing_self && can_edit_profile {
+            buttons = buttons.push(
+                button(shaped_text(t("button-edit-profile")).size(TEXT_SIZE))
+                    .on_press(Message::EditProfilePressed)
+                    .padding(BUTTON_PADDING)
+                    .style(btn::secondary),
+            );
+        }
+
+        // Add edit button if user has permission and not viewing self
+        if has_edit_permission && !viewing_self {
+            buttons = buttons.push(
+                button(shaped_text(t("button-edit")).size(TEXT_SIZE))
+                    .on_press(Message::ToggleEditUser(Some(user.username.clone())))
+                    .padding(BUTTON_PADDING)
+                    .style(btn::secondary),
+            );
+        }
     }
 
     // Close button (primary)
@@ -90,6 +119,90 @@ pub fn user_info_view<'a>(
     scrollable_panel(form)
 }
 
+/// Render the profile edit form (own bio and pronouns)
+fn profile_edit_view(edit_state: &ProfileEditState) -> Element<'static, Message> {
+    let title = shaped_text(t("title-edit-profile"))
+        .size(TITLE_SIZE)
+        .width(Fill)
+        .align_x(Center);
+
+    let mut content = column![title].spacing(ELEMENT_SPACING);
+
+    // Show error if present
+    if let Some(error) = &edit_state.error {
+        content = content.push(
+            shaped_text_wrapped(error.clone())
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(error_text_style),
+        );
+        content = content.push(Space::new().height(SPACER_SIZE_SMALL));
+    } else {
+        content = content.push(Space::new().height(SPACER_SIZE_MEDIUM));
+    }
+
+    // Pronouns input
+    let pronouns_input = text_input(&t("placeholder-pronouns"), &edit_state.pronouns)
+        .on_input(Message::EditProfilePronounsChanged)
+        .on_submit(Message::UpdateProfilePressed)
+        .id(Id::from(InputId::EditProfilePronouns))
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE);
+    content = content.push(shaped_text(t("label-pronouns")).size(TEXT_SIZE));
+    content = content.push(pronouns_input);
+
+    // Bio input
+    let bio_input = text_input(&t("placeholder-bio"), &edit_state.bio)
+        .on_input(Message::EditProfileBioChanged)
+        .on_submit(Message::UpdateProfilePressed)
+        .id(Id::from(InputId::EditProfileBio))
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE);
+    content = content.push(shaped_text(t("label-bio")).size(TEXT_SIZE));
+    content = content.push(bio_input);
+
+    content = content.push(Space::new().height(SPACER_SIZE_MEDIUM));
+
+    // Buttons: Cancel (secondary) and Save (primary)
+    let buttons = row![
+        Space::new().width(Fill),
+        button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
+            .on_press(Message::CancelEditProfile)
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary),
+        button(shaped_text(t("button-save")).size(TEXT_SIZE))
+            .on_press(Message::UpdateProfilePressed)
+            .padding(BUTTON_PADDING),
+    ]
+    .spacing(ELEMENT_SPACING);
+    content = content.push(buttons);
+
+    let form = content.padding(FORM_PADDING).max_width(FORM_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
+/// Join a user's features into a display string
+///
+/// Known features are translated. Features the server does not support are
+/// marked inactive once its capabilities are known.
+fn format_features(features: &[String], server_features: Option<&[String]>) -> String {
+    features
+        .iter()
+        .map(|feature| {
+            let name = translate_feature(feature);
+            match server_features {
+                Some(supported) if !supported.contains(feature) => {
+                    t_args("user-info-feature-inactive", &[("feature", &name)])
+                }
+                _ => name,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Create a label: value row for the user info panel
 fn info_row<'a>(
     label: String,
@@ -116,6 +229,8 @@ fn build_user_info_content<'a>(
     user: &UserInfoDetailed,
     theme: &Theme,
     avatar_cache: &'a HashMap<String, CachedImage>,
+    server_features: Option<&[String]>,
+    session_kick: Option<u32>,
 ) -> iced::widget::Column<'a, Message> {
     // Header row: Avatar + Username (title-sized, red for admins)
     let is_admin = user.is_admin.unwrap_or(false);
@@ -143,9 +258,11 @@ fn build_user_info_content<'a>(
     content = content.push(header_row);
     content = content.push(Space::new().height(SPACER_SIZE_MEDIUM));
 
-    // Role (only shown if is_admin field is present)
-    if user.is_admin.is_some() {
-        let role_value = if is_admin {
+    // Role (only shown if is_admin field is present, or for guests)
+    if user.is_admin.is_some() || user.is_guest {
+        let role_value = if user.is_guest {
+            t("user-info-role-guest")
+        } else if is_admin {
             t("user-info-role-admin")
         } else {
             t("user-info-role-user")
@@ -153,6 +270,18 @@ fn build_user_info_content<'a>(
         content = content.push(info_row(t("user-info-role"), role_value, None));
     }
 
+    // Profile (only shown when set)
+    if let Some(pronouns) = &user.pronouns {
+        content = content.push(info_row(t("user-info-pronouns"), pronouns.clone(), None));
+    }
+    if let Some(bio) = &user.bio {
+        content = content.push(row![
+            shaped_text(t("user-info-bio")).size(TEXT_SIZE),
+            Space::new().width(ELEMENT_SPACING),
+            shaped_text_wrapped(bio.clone()).size(TEXT_SIZE).width(Fill),
+        ]);
+    }
+
     // Session duration
     let now = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -181,7 +310,10 @@ fn build_user_info_content<'a>(
     } else {
         t_args(
             "user-info-features-value",
-            &[("features", &user.features.join(", "))],
+            &[(
+                "features",
+                &format_features(&user.features, server_features),
+            )],
         )
     };
     content = content.push(info_row(t("user-info-features"), features_value, None));
@@ -189,26 +321,81 @@ fn build_user_info_content<'a>(
     // Locale
     content = content.push(info_row(t("user-info-locale"), user.locale.clone(), None));
 
-    // IP Addresses (only shown if field is present - admin viewers only)
-    if let Some(addresses) = &user.addresses
+    // Sessions with disconnect buttons when the viewer may kick them,
+    // otherwise IP addresses with source tags (admin viewers only)
+    if let Some(own_session_id) = session_kick {
+        content = push_session_rows(content, user, own_session_id);
+    } else if let Some(addresses) = &user.addresses
         && !addresses.is_empty()
     {
+        let addresses = format_session_addresses(addresses, user.sources.as_deref());
         if addresses.len() == 1 {
             content = content.push(info_row(t("user-info-address"), addresses[0].clone(), None));
         } else {
             // Multiple addresses - show label then list
             content = content.push(info_row(t("user-info-addresses"), String::new(), None));
             for addr in addresses {
-                content = content.push(info_row(String::from("  "), addr.clone(), None));
+                content = content.push(info_row(String::from("  "), addr, None));
             }
         }
     }
 
-    // Account created
-    let created = chrono::DateTime::from_timestamp(user.created_at, 0)
-        .map(|dt| dt.format(DATETIME_FORMAT).to_string())
-        .unwrap_or_else(|| t("user-info-unknown"));
-    content = content.push(info_row(t("user-info-created"), created, None));
+    // Account created (guests have no account)
+    if !user.is_guest {
+        let created = chrono::DateTime::from_timestamp(user.created_at, 0)
+            .map(|dt| dt.format(DATETIME_FORMAT).to_string())
+            .unwrap_or_else(|| t("user-info-unknown"));
+        content = content.push(info_row(t("user-info-created"), created, None));
+    }
+
+    content
+}
+
+/// Push one row per session, each with a Disconnect button
+///
+/// Sessions are labelled with their address when the server sent addresses
+/// (same order as `session_ids`). The viewer's own session can't be kicked
+/// and is marked instead.
+fn push_session_rows<'a>(
+    mut content: iced::widget::Column<'a, Message>,
+    user: &UserInfoDetailed,
+    own_session_id: u32,
+) -> iced::widget::Column<'a, Message> {
+    let addresses = user
+        .addresses
+        .as_deref()
+        .map(|addresses| format_session_addresses(addresses, user.sources.as_deref()))
+        .unwrap_or_default();
+
+    content = content.push(info_row(t("user-info-sessions"), String::new(), None));
+    for (index, &session_id) in user.session_ids.iter().enumerate() {
+        let label = addresses
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| t_args("user-info-session", &[("id", &session_id.to_string())]));
+
+        let action: Element<'a, Message> = if session_id == own_session_id {
+            shaped_text(t("user-info-this-session"))
+                .size(TEXT_SIZE)
+                .into()
+        } else {
+            button(shaped_text(t("button-disconnect")).size(TEXT_SIZE))
+                .on_press(Message::KickSessionPressed(session_id))
+                .padding(SMALL_PADDING)
+                .style(btn::danger)
+                .into()
+        };
+
+        content = content.push(
+            row![
+                Space::new().width(ELEMENT_SPACING),
+                shaped_text(label).size(TEXT_SIZE),
+                Space::new().width(ELEMENT_SPACING),
+                action,
+            ]
+            .align_y(Center),
+        );
+    }
 
     content
 }
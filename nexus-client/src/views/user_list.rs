@@ -2,17 +2,16 @@
 
 use super::constants::{PERMISSION_USER_INFO, PERMISSION_USER_KICK, PERMISSION_USER_MESSAGE};
 use crate::avatar::generate_identicon;
-use crate::i18n::t;
+use crate::i18n::{t, t_args};
 use crate::icon;
 use crate::style::{
-    FORM_PADDING, ICON_BUTTON_PADDING, INPUT_PADDING, NO_SPACING, SEPARATOR_HEIGHT,
-    SIDEBAR_ACTION_ICON_SIZE, TOOLBAR_CONTAINER_PADDING, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP,
-    TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE, USER_LIST_AVATAR_SIZE, USER_LIST_AVATAR_SPACING,
-    USER_LIST_ITEM_SPACING, USER_LIST_PANEL_WIDTH, USER_LIST_SMALL_TEXT_SIZE, USER_LIST_SPACING,
-    USER_LIST_TEXT_SIZE, USER_LIST_TITLE_SIZE, alternating_row_style, chat,
-    disabled_icon_button_style, icon_button_with_hover_style, muted_text_style, shaped_text,
-    sidebar_panel_style, tooltip_container_style, ui, user_list_item_button_style,
-    user_toolbar_separator_style,
+    Density, ICON_BUTTON_PADDING, NO_SPACING, SEPARATOR_HEIGHT, SIDEBAR_ACTION_ICON_SIZE,
+    TOOLBAR_CONTAINER_PADDING, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING,
+    TOOLTIP_TEXT_SIZE, USER_LIST_AVATAR_SIZE, USER_LIST_AVATAR_SPACING, USER_LIST_PANEL_WIDTH,
+    USER_LIST_SMALL_TEXT_SIZE, USER_LIST_TEXT_SIZE, USER_LIST_TITLE_SIZE, alternating_row_style,
+    chat, disabled_icon_button_style, icon_button_with_hover_style, muted_text_style,
+    role_badge_style, shaped_text, sidebar_panel_style, tooltip_container_style, ui,
+    user_list_item_button_style, user_toolbar_separator_style,
 };
 use crate::types::{Message, ServerConnection};
 use iced::widget::{Column, Row, Space, button, column, container, row, scrollable, tooltip};
@@ -22,6 +21,17 @@ use iced::{Center, Color, Element, Fill, Theme};
 // Helper Functions
 // ============================================================================
 
+/// Translation key of a user's role badge (None for regular users)
+fn role_badge_key(is_admin: bool, is_moderator: bool) -> Option<&'static str> {
+    if is_admin {
+        Some("badge-admin")
+    } else if is_moderator {
+        Some("badge-moderator")
+    } else {
+        None
+    }
+}
+
 /// Create an icon container with consistent sizing and alignment
 fn icon_container(icon: iced::widget::Text<'_>) -> iced::widget::Container<'_, Message> {
     container(icon.size(SIDEBAR_ACTION_ICON_SIZE))
@@ -84,20 +94,16 @@ fn create_user_toolbar<'a>(
     username: &'a str,
     current_username: &'a str,
     target_is_admin: bool,
-    current_user_is_admin: bool,
-    permissions: &[String],
+    conn: &ServerConnection,
     theme: &Theme,
 ) -> Row<'a, Message> {
     let username_owned = username.to_string();
     let is_self = username == current_username;
 
-    // Check permissions (admins have all permissions)
-    let has_user_info_permission =
-        current_user_is_admin || permissions.iter().any(|p| p == PERMISSION_USER_INFO);
-    let has_user_message_permission =
-        current_user_is_admin || permissions.iter().any(|p| p == PERMISSION_USER_MESSAGE);
-    let has_user_kick_permission =
-        current_user_is_admin || permissions.iter().any(|p| p == PERMISSION_USER_KICK);
+    // Check permissions (admins have all permissions the server hasn't disabled)
+    let has_user_info_permission = conn.has_permission(PERMISSION_USER_INFO);
+    let has_user_message_permission = conn.has_permission(PERMISSION_USER_MESSAGE);
+    let has_user_kick_permission = conn.has_permission(PERMISSION_USER_KICK);
 
     // Build toolbar row
     let mut toolbar_row = row![].spacing(NO_SPACING).width(Fill);
@@ -121,8 +127,8 @@ fn create_user_toolbar<'a>(
     };
     toolbar_row = toolbar_row.push(with_tooltip(info_button, t("tooltip-info")));
 
-    // Message button (only show if not self)
-    if !is_self {
+    // Message button (only show if not self and the server allows private messages)
+    if !is_self && !conn.permission_disabled(PERMISSION_USER_MESSAGE) {
         let message_icon = icon_container(icon::message());
         let message_button = if has_user_message_permission {
             let username_for_message = username_owned.clone();
@@ -164,20 +170,24 @@ fn create_user_toolbar<'a>(
 ///
 /// Shows a list of currently connected users. Clicking a username expands it
 /// to show an action toolbar underneath. Only one user can be expanded at a time.
-/// Admin users are shown in red (using the chat admin color).
+/// Admin users are shown in red (using the chat admin color). With
+/// `show_role_badges`, admins and moderators also get a small role badge.
 ///
 /// Note: This panel is only shown when the user has `user_list` permission.
 /// Permission checking is done at the layout level.
-pub fn user_list_panel<'a>(conn: &'a ServerConnection, theme: &Theme) -> Element<'a, Message> {
+pub fn user_list_panel<'a>(
+    conn: &'a ServerConnection,
+    theme: &Theme,
+    density: Density,
+    show_role_badges: bool,
+) -> Element<'a, Message> {
     let current_username = &conn.username;
-    let is_admin = conn.is_admin;
-    let permissions = &conn.permissions;
 
     let title = shaped_text(t("title-users"))
         .size(USER_LIST_TITLE_SIZE)
         .style(muted_text_style);
 
-    let mut users_column = Column::new().spacing(USER_LIST_ITEM_SPACING);
+    let mut users_column = Column::new().spacing(density.list_item_spacing);
 
     if conn.online_users.is_empty() {
         users_column = users_column.push(
@@ -203,27 +213,62 @@ pub fn user_list_panel<'a>(conn: &'a ServerConnection, theme: &Theme) -> Element
                     generate_identicon(&user.username).render(USER_LIST_AVATAR_SIZE)
                 };
 
-            // Row with avatar and username
-            let user_row = row![
-                avatar_element,
-                shaped_text(&user.username).size(USER_LIST_TEXT_SIZE),
-            ]
-            .spacing(USER_LIST_AVATAR_SPACING)
-            .align_y(Center);
+            // Away users are dimmed
+            let username_text = shaped_text(&user.username).size(USER_LIST_TEXT_SIZE);
+            let username_text = if user.away {
+                username_text.style(muted_text_style)
+            } else {
+                username_text
+            };
+
+            // Row with avatar, username and optional role badge
+            let mut user_row = row![avatar_element, username_text]
+                .spacing(USER_LIST_AVATAR_SPACING)
+                .align_y(Center);
+            // Guests are always marked, whatever the role badge setting
+            let badge_key = if user.is_guest {
+                Some("badge-guest")
+            } else if show_role_badges {
+                role_badge_key(user.is_admin, user.is_moderator)
+            } else {
+                None
+            };
+            if let Some(badge_key) = badge_key {
+                user_row = user_row.push(
+                    shaped_text(t(badge_key))
+                        .size(USER_LIST_SMALL_TEXT_SIZE)
+                        .style(role_badge_style(user.is_admin)),
+                );
+            }
 
             let user_button = button(container(user_row).width(Fill))
                 .on_press(Message::UserListItemClicked(username_clone))
                 .width(Fill)
-                .padding(INPUT_PADDING)
+                .padding(density.list_item_padding)
                 .style(user_list_item_button_style(
                     user_is_admin,
                     chat::admin(theme),
                 ));
 
-            // Wrap button in tooltip showing full username (useful when truncated)
+            // Tooltip shows the full username (useful when truncated), whether
+            // they're away, and any notable permissions the server shared with us
+            let mut tooltip_column = column![shaped_text(&user.username).size(TOOLTIP_TEXT_SIZE)];
+            if user.away {
+                tooltip_column =
+                    tooltip_column.push(shaped_text(t("user-list-away")).size(TOOLTIP_TEXT_SIZE));
+            }
+            if !user.permissions.is_empty() {
+                tooltip_column = tooltip_column.push(
+                    shaped_text(t_args(
+                        "user-list-permissions",
+                        &[("permissions", &user.permissions.join(", "))],
+                    ))
+                    .size(TOOLTIP_TEXT_SIZE),
+                );
+            }
             let user_button_with_tooltip = tooltip(
                 user_button,
-                container(shaped_text(&user.username).size(TOOLTIP_TEXT_SIZE))
+                container(tooltip_column)
                     .padding(TOOLTIP_BACKGROUND_PADDING)
                     .style(tooltip_container_style),
                 tooltip::Position::Left,
@@ -247,8 +292,7 @@ pub fn user_list_panel<'a>(conn: &'a ServerConnection, theme: &Theme) -> Element
                     &user.username,
                     current_username,
                     user.is_admin,
-                    is_admin,
-                    permissions,
+                    conn,
                     theme,
                 );
                 let toolbar_row = container(toolbar)
@@ -267,8 +311,8 @@ pub fn user_list_panel<'a>(conn: &'a ServerConnection, theme: &Theme) -> Element
     }
 
     let panel = column![title, scrollable(users_column).height(Fill),]
-        .spacing(USER_LIST_SPACING)
-        .padding(FORM_PADDING)
+        .spacing(density.list_section_spacing)
+        .padding(density.list_padding)
         .width(USER_LIST_PANEL_WIDTH);
 
     container(panel)
@@ -210,8 +210,7 @@ fn select_user_view<'a>(
         .align_x(Center);
 
     let can_edit = !username.trim().is_empty();
-    let can_delete = !username.trim().is_empty()
-        && (conn.is_admin || conn.permissions.iter().any(|p| p == PERMISSION_USER_DELETE));
+    let can_delete = !username.trim().is_empty() && (conn.has_permission(PERMISSION_USER_DELETE));
 
     // Helper for on_submit
     let submit_action = if can_edit {
@@ -3,6 +3,7 @@
 use std::fmt;
 use std::io;
 
+use super::message_id::MessageId;
 use super::{
     MAX_PAYLOAD_LENGTH, MAX_PAYLOAD_LENGTH_DIGITS, MAX_TYPE_LENGTH, MAX_TYPE_LENGTH_DIGITS,
 };
@@ -32,6 +33,16 @@ pub enum FrameError {
         length: u64,
         max: u64,
     },
+    /// Payload exceeded the per-type maximum but was small enough to skip
+    ///
+    /// The payload has been discarded and the stream is positioned at the next
+    /// frame, so the connection can carry on.
+    OversizedPayloadSkipped {
+        message_id: MessageId,
+        message_type: String,
+        length: u64,
+        max: u64,
+    },
     /// Missing delimiter where expected
     MissingDelimiter,
     /// Missing terminator (newline)
@@ -85,6 +96,17 @@ impl fmt::Display for FrameError {
                     "payload length {length} exceeds maximum {max} for message type '{message_type}'"
                 )
             }
+            FrameError::OversizedPayloadSkipped {
+                message_type,
+                length,
+                max,
+                ..
+            } => {
+                write!(
+                    f,
+                    "skipped payload of length {length} over maximum {max} for message type '{message_type}'"
+                )
+            }
             FrameError::MissingDelimiter => write!(f, "missing delimiter '|'"),
             FrameError::MissingTerminator => write!(f, "missing terminator '\\n'"),
             FrameError::UnknownMessageType(t) => write!(f, "unknown message type: '{t}'"),
@@ -96,6 +118,17 @@ impl fmt::Display for FrameError {
     }
 }
 
+impl FrameError {
+    /// Whether the stream is still usable after this error
+    ///
+    /// Only a skipped oversized payload leaves the reader at a frame boundary;
+    /// every other error means the stream can't be trusted any more.
+    #[must_use]
+    pub fn is_recoverable(&self) -> bool {
+        matches!(self, FrameError::OversizedPayloadSkipped { .. })
+    }
+}
+
 impl std::error::Error for FrameError {}
 
 impl From<io::Error> for FrameError {
@@ -153,6 +186,15 @@ mod tests {
                 },
                 "payload length 5000 exceeds maximum 2048 for message type 'ChatSend'",
             ),
+            (
+                FrameError::OversizedPayloadSkipped {
+                    message_id: MessageId::from_bytes(b"a1b2c3d4e5f6").unwrap(),
+                    message_type: "ChatSend".to_string(),
+                    length: 5000,
+                    max: 2048,
+                },
+                "skipped payload of length 5000 over maximum 2048 for message type 'ChatSend'",
+            ),
             (FrameError::MissingDelimiter, "missing delimiter '|'"),
             (FrameError::MissingTerminator, "missing terminator '\\n'"),
             (
@@ -176,6 +218,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_frame_error_is_recoverable() {
+        let skipped = FrameError::OversizedPayloadSkipped {
+            message_id: MessageId::new(),
+            message_type: "ChatSend".to_string(),
+            length: 5000,
+            max: 2048,
+        };
+        assert!(skipped.is_recoverable());
+
+        let hostile = FrameError::PayloadLengthExceedsTypeMax {
+            message_type: "ChatSend".to_string(),
+            length: 5_000_000,
+            max: 2048,
+        };
+        assert!(!hostile.is_recoverable());
+        assert!(!FrameError::InvalidMagic.is_recoverable());
+    }
+
     #[test]
     fn test_frame_error_from_io_error() {
         // UnexpectedEof maps to ConnectionClosed
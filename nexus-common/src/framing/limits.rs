@@ -18,6 +18,123 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
     let mut m = HashMap::new();
 
     // Client messages (limits match actual max size from validators)
+    // Message and topic sizes use the configurable ceilings, not the defaults
+    m.insert("AcceptRules", 22);
+    m.insert("AdminNotice", 16446); // shared type: client and server carry the same action
+    m.insert("AnnouncementAdd", 16423);
+    m.insert("AnnouncementList", 27);
+    m.insert("AnnouncementRemove", 48);
+    m.insert("Capabilities", 23);
+    m.insert("ChatDelete", 55);
+    m.insert("ChatEdit", 16450);
+    m.insert("ChatHistoryPurge", 27);
+    m.insert("ChatSend", 16416);
+    m.insert("ChatTopicUpdate", 4133);
+    m.insert("ConnectionStats", 40);
+    m.insert("E2eeKeyAnnounce", 86);
+    m.insert("E2eeKeysRequest", 168);
+    m.insert("EncryptedUserMessage", 351758); // shared type: client (351758) > server (22360)
+    m.insert("Handshake", 65);
+    m.insert("HistoryRequest", 85);
+    m.insert("KickSession", 46);
+    m.insert("Login", 177041);
+    m.insert("PageDelete", 63);
+    m.insert("PageGet", 60);
+    m.insert("PageList", 19);
+    m.insert("PageSet", 32841);
+    m.insert("Ping", 15);
+    m.insert("PinMessage", 55);
+    m.insert("UnpinMessage", 57);
+    m.insert("RefreshUserPermissions", 175);
+    m.insert("SetAway", 31);
+    m.insert("SetMaintenance", 41);
+    m.insert("SetPreferences", 4136);
+    m.insert("SeenRequest", 164);
+    m.insert("SetProfile", 1196);
+    m.insert("TransferAdmin", 189);
+    m.insert("UserBroadcast", 16476);
+    m.insert("UserCreate", 1040);
+    m.insert("UserDelete", 163);
+    m.insert("UserEdit", 161);
+    m.insert("UserInfo", 161);
+    m.insert("UserKick", 161);
+    m.insert("UserList", 31);
+    m.insert("UserUpdate", 1232);
+    m.insert("WhoAmI", 17);
+    m.insert("ServerInfoUpdate", 701471); // includes image field (700000 + overhead)
+
+    // Server messages (limits match actual max size from validators)
+    // ServerInfo now includes image field (up to 700000 chars), adding ~700011 bytes
+    m.insert("AcceptRulesResponse", 569);
+    m.insert("AdminNoticeResponse", 569);
+    m.insert("AnnouncementAddResponse", 573);
+    m.insert("AnnouncementListResponse", 164491);
+    m.insert("AnnouncementRemoveResponse", 576);
+    m.insert("CapabilitiesResponse", 0); // unlimited (server-trusted)
+    m.insert("ChatDeleteResponse", 568);
+    m.insert("ChatEditResponse", 566);
+    m.insert("ChatHistoryPurgeResponse", 605);
+    m.insert("ChatMessage", 17942);
+    m.insert("ChatMessageDeleted", 83);
+    m.insert("ChatMessageEdited", 17782);
+    m.insert("ChatTopicUpdated", 4276);
+    m.insert("ChatTopicUpdateResponse", 573);
+    m.insert("ConnectionStatsResponse", 9066);
+    m.insert("E2eeKeys", 1546);
+    m.insert("Error", 2196);
+    m.insert("HandshakeResponse", 8602); // includes pre-login banner
+    m.insert("HistoryResponse", 830995);
+    m.insert("KickSessionResponse", 569);
+    m.insert("LoginResponse", 777764); // includes ServerInfo with image, pinned messages, preferences, rules, maintenance, and disabled permissions
+    m.insert("PermissionsUpdated", 706417); // includes ServerInfo with image
+    m.insert("LimitsUpdated", 114);
+    m.insert("MaintenanceChanged", 45);
+    m.insert("NewLogin", 145);
+    m.insert("PageDeleteResponse", 568);
+    m.insert("PageGetResponse", 33388);
+    m.insert("PageListResponse", 2326);
+    m.insert("PageSetResponse", 565);
+    m.insert("Pong", 15);
+    m.insert("PinMessageResponse", 568);
+    m.insert("PinnedMessagesUpdated", 50206);
+    m.insert("ServerAnnouncement", 16426);
+    m.insert("ServerBroadcast", 16589);
+    m.insert("ServerInfoUpdated", 701557); // includes ServerInfo with image
+    m.insert("ServerInfoUpdateResponse", 574);
+    m.insert("UserConnected", 176531);
+    m.insert("UserCreateResponse", 1093);
+    m.insert("UserDeleteResponse", 568);
+    m.insert("UserDisconnected", 193);
+    m.insert("UserEditResponse", 791);
+    m.insert("UserBroadcastResponse", 571);
+    m.insert("UserInfoResponse", 179062);
+    m.insert("UserKickResponse", 566);
+    m.insert("UnpinMessageResponse", 570);
+    m.insert("RefreshUserPermissionsResponse", 580);
+    m.insert("SeenResponse", 752);
+    m.insert("TransferAdminResponse", 713);
+    m.insert("SetMaintenanceResponse", 572);
+    m.insert("WhoAmIResponse", 1348);
+    m.insert("SetPreferencesResponse", 572);
+    m.insert("SetProfileResponse", 568);
+    m.insert("UserListResponse", 0); // unlimited (server-trusted)
+    m.insert("UserMessage", 16763); // shared type: server (16763) > client (16564)
+    m.insert("UserMessageResponse", 569);
+    m.insert("UserUpdated", 176680);
+    m.insert("UserUpdateResponse", 1093);
+
+    m
+});
+
+/// Payload limits of protocol 0.5, for peers older than the extended protocol
+///
+/// Only the message types 0.5 knew are listed; those peers disconnect on any
+/// other type and reject frames over these sizes (see
+/// [`crate::version::EXTENDED_PROTOCOL_VERSION`]).
+static LEGACY_MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new(|| {
+    let mut m = HashMap::new();
+
+    // Client messages
     m.insert("ChatSend", 1056);
     m.insert("ChatTopicUpdate", 293);
     m.insert("Handshake", 65);
@@ -30,19 +147,18 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
     m.insert("UserKick", 65);
     m.insert("UserList", 31);
     m.insert("UserUpdate", 1040);
-    m.insert("ServerInfoUpdate", 700421); // includes image field (700000 + overhead)
+    m.insert("ServerInfoUpdate", 700421);
 
-    // Server messages (limits match actual max size from validators)
-    // ServerInfo now includes image field (up to 700000 chars), adding ~700011 bytes
+    // Server messages
     m.insert("ChatMessage", 1129);
     m.insert("ChatTopicUpdated", 340);
     m.insert("ChatTopicUpdateResponse", 573);
     m.insert("Error", 2154);
     m.insert("HandshakeResponse", 356);
-    m.insert("LoginResponse", 701469); // includes ServerInfo with image
-    m.insert("PermissionsUpdated", 701407); // includes ServerInfo with image
+    m.insert("LoginResponse", 701469);
+    m.insert("PermissionsUpdated", 701407);
     m.insert("ServerBroadcast", 1133);
-    m.insert("ServerInfoUpdated", 700483); // includes ServerInfo with image
+    m.insert("ServerInfoUpdated", 700483);
     m.insert("ServerInfoUpdateResponse", 574);
     m.insert("UserConnected", 176294);
     m.insert("UserCreateResponse", 568);
@@ -53,7 +169,7 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
     m.insert("UserInfoResponse", 177412);
     m.insert("UserKickResponse", 566);
     m.insert("UserListResponse", 0); // unlimited (server-trusted)
-    m.insert("UserMessage", 1177); // shared type: server (1177) > client (1108)
+    m.insert("UserMessage", 1177);
     m.insert("UserMessageResponse", 569);
     m.insert("UserUpdated", 176347);
     m.insert("UserUpdateResponse", 568);
@@ -81,6 +197,15 @@ pub fn is_known_message_type(message_type: &str) -> bool {
     MESSAGE_TYPE_LIMITS.contains_key(message_type)
 }
 
+/// Get the maximum payload size a 0.5 peer accepts for a message type
+///
+/// Returns `None` for message types 0.5 peers don't know. A limit of `0`
+/// means unlimited, as in [`max_payload_for_type`].
+#[must_use]
+pub fn legacy_max_payload_for_type(message_type: &str) -> Option<u64> {
+    LEGACY_MESSAGE_TYPE_LIMITS.get(message_type).copied()
+}
+
 /// Get all known message type names
 #[must_use]
 pub fn known_message_types() -> Vec<&'static str> {
@@ -90,14 +215,23 @@ pub fn known_message_types() -> Vec<&'static str> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::NOTABLE_PERMISSIONS;
+    use crate::mentions::MAX_MENTIONS;
     use crate::protocol::{
-        ChatInfo, ClientMessage, ServerInfo, ServerMessage, UserInfo, UserInfoDetailed,
+        AdminNoticeAction, ChatInfo, ClientMessage, ConnectionStatsBucket, E2eeEnvelope,
+        E2eeSessionKey, HistoryMessage, MAX_ANNOUNCEMENTS, MAX_CONNECTION_STATS_BUCKETS,
+        MAX_ERROR_CODE_LENGTH, MAX_HISTORY_PAGE_SIZE, MAX_PAGES, MAX_PINNED_MESSAGES,
+        PinnedMessage, ServerInfo, ServerMessage, UserInfo, UserInfoDetailed,
     };
     use crate::validators::{
-        MAX_AVATAR_DATA_URI_LENGTH, MAX_CHAT_TOPIC_LENGTH, MAX_FEATURE_LENGTH, MAX_FEATURES_COUNT,
-        MAX_LOCALE_LENGTH, MAX_MESSAGE_LENGTH, MAX_PASSWORD_LENGTH, MAX_PERMISSION_LENGTH,
-        MAX_PERMISSIONS_COUNT, MAX_SERVER_DESCRIPTION_LENGTH, MAX_SERVER_IMAGE_DATA_URI_LENGTH,
-        MAX_SERVER_NAME_LENGTH, MAX_USERNAME_LENGTH, MAX_VERSION_LENGTH,
+        E2EE_PUBLIC_KEY_LENGTH, MAX_AVATAR_DATA_URI_LENGTH, MAX_BIO_LENGTH,
+        MAX_CHAT_TOPIC_LENGTH_CEILING, MAX_E2EE_CIPHERTEXT_LENGTH, MAX_E2EE_ENVELOPES,
+        MAX_FEATURE_LENGTH, MAX_FEATURES_COUNT, MAX_LOCALE_LENGTH, MAX_MESSAGE_LENGTH_CEILING,
+        MAX_NOTICE_URL_LENGTH, MAX_PAGE_CONTENT_LENGTH, MAX_PAGE_NAME_LENGTH, MAX_PASSWORD_LENGTH,
+        MAX_PERMISSION_LENGTH, MAX_PERMISSIONS_COUNT, MAX_PREFERENCES_LENGTH, MAX_PRONOUNS_LENGTH,
+        MAX_SERVER_BANNER_LENGTH, MAX_SERVER_DESCRIPTION_LENGTH, MAX_SERVER_IMAGE_DATA_URI_LENGTH,
+        MAX_SERVER_NAME_LENGTH, MAX_SERVER_RULES_LENGTH, MAX_SOURCE_TAG_LENGTH,
+        MAX_USERNAME_LENGTH, MAX_VERSION_LENGTH,
     };
 
     /// Helper to get serialized JSON size of a message
@@ -110,6 +244,41 @@ mod tests {
         "x".repeat(len)
     }
 
+    /// Helper to create user-facing text of given length in characters
+    ///
+    /// Text validators count characters, so the widest case is a 4-byte
+    /// character in every position.
+    fn text_of_len(len: usize) -> String {
+        "😀".repeat(len)
+    }
+
+    /// Helper to create the largest possible set of pinned messages
+    fn max_pinned_messages() -> Vec<PinnedMessage> {
+        (0..MAX_PINNED_MESSAGES)
+            .map(|_| PinnedMessage {
+                message_id: u64::MAX,
+                username: text_of_len(MAX_USERNAME_LENGTH),
+                message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+                pinned_by: text_of_len(MAX_USERNAME_LENGTH),
+            })
+            .collect()
+    }
+
+    /// Helper to create the largest possible encrypted message envelope
+    fn max_e2ee_envelope() -> E2eeEnvelope {
+        E2eeEnvelope {
+            session_id: u32::MAX,
+            ephemeral_key: str_of_len(E2EE_PUBLIC_KEY_LENGTH),
+            ciphertext: str_of_len(MAX_E2EE_CIPHERTEXT_LENGTH),
+        }
+    }
+
+    /// Helper to create a preferences object at exactly the size limit
+    fn max_preferences() -> serde_json::Value {
+        // `{"k":""}` is 8 bytes of overhead
+        serde_json::json!({ "k": str_of_len(MAX_PREFERENCES_LENGTH - 8) })
+    }
+
     #[test]
     #[should_panic(expected = "unknown message types should be rejected")]
     fn test_max_payload_for_type_unknown_panics() {
@@ -123,6 +292,23 @@ mod tests {
         assert!(!is_known_message_type("FakeMessage"));
     }
 
+    #[test]
+    fn test_legacy_limits() {
+        assert_eq!(legacy_max_payload_for_type("ChatMessage"), Some(1129));
+        assert_eq!(legacy_max_payload_for_type("HandshakeResponse"), Some(356));
+        assert_eq!(legacy_max_payload_for_type("Capabilities"), None);
+        assert_eq!(legacy_max_payload_for_type("ServerAnnouncement"), None);
+
+        // Every 0.5 type is still part of the protocol, and never got smaller
+        for (message_type, limit) in LEGACY_MESSAGE_TYPE_LIMITS.iter() {
+            let current = max_payload_for_type(message_type);
+            assert!(
+                current == 0 || (*limit != 0 && *limit <= current),
+                "{message_type}: legacy {limit} > current {current}"
+            );
+        }
+    }
+
     #[test]
     fn test_all_protocol_types_have_limits() {
         // This test verifies that MESSAGE_TYPE_LIMITS has the expected number of entries.
@@ -134,11 +320,11 @@ mod tests {
         // will cause a compile error if you add a variant there, reminding you to
         // also add the limit here.
         //
-        // Note: UserMessage is shared between client and server (same type name),
-        // so it's only counted once in the HashMap.
-        const CLIENT_MESSAGE_COUNT: usize = 14;
-        const SERVER_MESSAGE_COUNT: usize = 23;
-        const SHARED_MESSAGE_COUNT: usize = 1; // UserMessage
+        // Note: AdminNotice, UserMessage, and EncryptedUserMessage are shared between
+        // client and server (same type name), so they're only counted once in the HashMap.
+        const CLIENT_MESSAGE_COUNT: usize = 44;
+        const SERVER_MESSAGE_COUNT: usize = 59;
+        const SHARED_MESSAGE_COUNT: usize = 3; // AdminNotice, UserMessage, EncryptedUserMessage
         const TOTAL_MESSAGE_COUNT: usize =
             CLIENT_MESSAGE_COUNT + SERVER_MESSAGE_COUNT - SHARED_MESSAGE_COUNT;
 
@@ -160,10 +346,102 @@ mod tests {
     // Client message size tests - verify limits match actual max sizes
     // =========================================================================
 
+    #[test]
+    fn test_limit_accept_rules() {
+        let msg = ClientMessage::AcceptRules;
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("AcceptRules") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_admin_notice() {
+        // The notice text is the largest action, and the server relays it unchanged
+        let notice = AdminNoticeAction::Notice {
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+        };
+        let update = AdminNoticeAction::Update {
+            url: str_of_len(MAX_NOTICE_URL_LENGTH),
+        };
+        assert_eq!(
+            json_size(&ClientMessage::AdminNotice { action: notice }),
+            max_payload_for_type("AdminNotice") as usize
+        );
+        assert!(
+            json_size(&ServerMessage::AdminNotice { action: update })
+                < max_payload_for_type("AdminNotice") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_announcement_add() {
+        let msg = ClientMessage::AnnouncementAdd {
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("AnnouncementAdd") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_announcement_list() {
+        let msg = ClientMessage::AnnouncementList;
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("AnnouncementList") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_announcement_remove() {
+        let msg = ClientMessage::AnnouncementRemove { index: u32::MAX };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("AnnouncementRemove") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_capabilities() {
+        let msg = ClientMessage::Capabilities;
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("Capabilities") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_chat_delete() {
+        let msg = ClientMessage::ChatDelete {
+            message_id: u64::MAX,
+        };
+        assert_eq!(json_size(&msg), max_payload_for_type("ChatDelete") as usize);
+    }
+
+    #[test]
+    fn test_limit_chat_edit() {
+        let msg = ClientMessage::ChatEdit {
+            message_id: u64::MAX,
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+        };
+        assert_eq!(json_size(&msg), max_payload_for_type("ChatEdit") as usize);
+    }
+
+    #[test]
+    fn test_limit_chat_history_purge() {
+        let msg = ClientMessage::ChatHistoryPurge;
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("ChatHistoryPurge") as usize
+        );
+    }
+
     #[test]
     fn test_limit_chat_send() {
         let msg = ClientMessage::ChatSend {
-            message: str_of_len(MAX_MESSAGE_LENGTH),
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
         };
         assert_eq!(json_size(&msg), max_payload_for_type("ChatSend") as usize);
     }
@@ -171,7 +449,7 @@ mod tests {
     #[test]
     fn test_limit_chat_topic_update() {
         let msg = ClientMessage::ChatTopicUpdate {
-            topic: str_of_len(MAX_CHAT_TOPIC_LENGTH),
+            topic: text_of_len(MAX_CHAT_TOPIC_LENGTH_CEILING),
         };
         assert_eq!(
             json_size(&msg),
@@ -179,6 +457,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_connection_stats() {
+        let msg = ClientMessage::ConnectionStats { reset: false };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("ConnectionStats") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_e2ee_key_announce() {
+        let msg = ClientMessage::E2eeKeyAnnounce {
+            public_key: str_of_len(E2EE_PUBLIC_KEY_LENGTH),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("E2eeKeyAnnounce") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_e2ee_keys_request() {
+        let msg = ClientMessage::E2eeKeysRequest {
+            username: text_of_len(MAX_USERNAME_LENGTH),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("E2eeKeysRequest") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_encrypted_user_message_client() {
+        let msg = ClientMessage::EncryptedUserMessage {
+            to_username: text_of_len(MAX_USERNAME_LENGTH),
+            envelopes: (0..MAX_E2EE_ENVELOPES)
+                .map(|_| max_e2ee_envelope())
+                .collect(),
+        };
+        // Client variant defines the limit since it carries every envelope
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("EncryptedUserMessage") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_history_request() {
+        let msg = ClientMessage::HistoryRequest {
+            before_message_id: Some(u64::MAX),
+            limit: u32::MAX,
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("HistoryRequest") as usize
+        );
+    }
+
     #[test]
     fn test_limit_handshake() {
         let msg = ClientMessage::Handshake {
@@ -187,10 +523,21 @@ mod tests {
         assert_eq!(json_size(&msg), max_payload_for_type("Handshake") as usize);
     }
 
+    #[test]
+    fn test_limit_kick_session() {
+        let msg = ClientMessage::KickSession {
+            session_id: u32::MAX,
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("KickSession") as usize
+        );
+    }
+
     #[test]
     fn test_limit_login() {
         let msg = ClientMessage::Login {
-            username: str_of_len(MAX_USERNAME_LENGTH),
+            username: text_of_len(MAX_USERNAME_LENGTH),
             password: str_of_len(MAX_PASSWORD_LENGTH),
             features: (0..MAX_FEATURES_COUNT)
                 .map(|_| str_of_len(MAX_FEATURE_LENGTH))
@@ -201,10 +548,87 @@ mod tests {
         assert_eq!(json_size(&msg), max_payload_for_type("Login") as usize);
     }
 
+    #[test]
+    fn test_limit_page_delete() {
+        let msg = ClientMessage::PageDelete {
+            name: str_of_len(MAX_PAGE_NAME_LENGTH),
+        };
+        assert_eq!(json_size(&msg), max_payload_for_type("PageDelete") as usize);
+    }
+
+    #[test]
+    fn test_limit_page_get() {
+        let msg = ClientMessage::PageGet {
+            name: str_of_len(MAX_PAGE_NAME_LENGTH),
+        };
+        assert_eq!(json_size(&msg), max_payload_for_type("PageGet") as usize);
+    }
+
+    #[test]
+    fn test_limit_page_list() {
+        let msg = ClientMessage::PageList;
+        assert_eq!(json_size(&msg), max_payload_for_type("PageList") as usize);
+    }
+
+    #[test]
+    fn test_limit_page_set() {
+        let msg = ClientMessage::PageSet {
+            name: str_of_len(MAX_PAGE_NAME_LENGTH),
+            content: text_of_len(MAX_PAGE_CONTENT_LENGTH),
+        };
+        assert_eq!(json_size(&msg), max_payload_for_type("PageSet") as usize);
+    }
+
+    #[test]
+    fn test_limit_ping() {
+        let msg = ClientMessage::Ping;
+        assert_eq!(json_size(&msg), max_payload_for_type("Ping") as usize);
+    }
+
+    #[test]
+    fn test_limit_pin_message() {
+        let msg = ClientMessage::PinMessage {
+            message_id: u64::MAX,
+        };
+        assert_eq!(json_size(&msg), max_payload_for_type("PinMessage") as usize);
+    }
+
+    #[test]
+    fn test_limit_set_preferences() {
+        let msg = ClientMessage::SetPreferences {
+            preferences: max_preferences(),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("SetPreferences") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_set_profile() {
+        let msg = ClientMessage::SetProfile {
+            bio: text_of_len(MAX_BIO_LENGTH),
+            pronouns: text_of_len(MAX_PRONOUNS_LENGTH),
+        };
+        assert_eq!(json_size(&msg), max_payload_for_type("SetProfile") as usize);
+    }
+
+    #[test]
+    fn test_limit_unpin_message() {
+        let msg = ClientMessage::UnpinMessage {
+            message_id: u64::MAX,
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("UnpinMessage") as usize
+        );
+    }
+
     #[test]
     fn test_limit_user_broadcast() {
         let msg = ClientMessage::UserBroadcast {
-            message: str_of_len(MAX_MESSAGE_LENGTH),
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+            target_permission: Some(str_of_len(MAX_PERMISSION_LENGTH)),
         };
         assert_eq!(
             json_size(&msg),
@@ -215,7 +639,7 @@ mod tests {
     #[test]
     fn test_limit_user_create() {
         let msg = ClientMessage::UserCreate {
-            username: str_of_len(MAX_USERNAME_LENGTH),
+            username: text_of_len(MAX_USERNAME_LENGTH),
             password: str_of_len(MAX_PASSWORD_LENGTH),
             is_admin: true,
             enabled: true,
@@ -229,7 +653,7 @@ mod tests {
     #[test]
     fn test_limit_user_delete() {
         let msg = ClientMessage::UserDelete {
-            username: str_of_len(MAX_USERNAME_LENGTH),
+            username: text_of_len(MAX_USERNAME_LENGTH),
         };
         assert_eq!(json_size(&msg), max_payload_for_type("UserDelete") as usize);
     }
@@ -237,7 +661,7 @@ mod tests {
     #[test]
     fn test_limit_user_edit() {
         let msg = ClientMessage::UserEdit {
-            username: str_of_len(MAX_USERNAME_LENGTH),
+            username: text_of_len(MAX_USERNAME_LENGTH),
         };
         assert_eq!(json_size(&msg), max_payload_for_type("UserEdit") as usize);
     }
@@ -245,15 +669,70 @@ mod tests {
     #[test]
     fn test_limit_user_info() {
         let msg = ClientMessage::UserInfo {
-            username: str_of_len(MAX_USERNAME_LENGTH),
+            username: text_of_len(MAX_USERNAME_LENGTH),
         };
         assert_eq!(json_size(&msg), max_payload_for_type("UserInfo") as usize);
     }
 
+    #[test]
+    fn test_limit_transfer_admin() {
+        let msg = ClientMessage::TransferAdmin {
+            to_username: text_of_len(MAX_USERNAME_LENGTH),
+            demote_self: false,
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("TransferAdmin") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_seen_request() {
+        let msg = ClientMessage::SeenRequest {
+            username: text_of_len(MAX_USERNAME_LENGTH),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("SeenRequest") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_set_away() {
+        let msg = ClientMessage::SetAway { away: false };
+        assert_eq!(json_size(&msg), max_payload_for_type("SetAway") as usize);
+    }
+
+    #[test]
+    fn test_limit_set_maintenance() {
+        let msg = ClientMessage::SetMaintenance { enabled: false };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("SetMaintenance") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_refresh_user_permissions() {
+        let msg = ClientMessage::RefreshUserPermissions {
+            username: text_of_len(MAX_USERNAME_LENGTH),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("RefreshUserPermissions") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_who_am_i() {
+        let msg = ClientMessage::WhoAmI;
+        assert_eq!(json_size(&msg), max_payload_for_type("WhoAmI") as usize);
+    }
+
     #[test]
     fn test_limit_user_kick() {
         let msg = ClientMessage::UserKick {
-            username: str_of_len(MAX_USERNAME_LENGTH),
+            username: text_of_len(MAX_USERNAME_LENGTH),
         };
         assert_eq!(json_size(&msg), max_payload_for_type("UserKick") as usize);
     }
@@ -268,8 +747,8 @@ mod tests {
     #[test]
     fn test_limit_user_message_client() {
         let msg = ClientMessage::UserMessage {
-            to_username: str_of_len(MAX_USERNAME_LENGTH),
-            message: str_of_len(MAX_MESSAGE_LENGTH),
+            to_username: text_of_len(MAX_USERNAME_LENGTH),
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
         };
         // Client variant is smaller than server variant, so it fits within the limit
         assert!(json_size(&msg) <= max_payload_for_type("UserMessage") as usize);
@@ -278,8 +757,8 @@ mod tests {
     #[test]
     fn test_limit_user_update() {
         let msg = ClientMessage::UserUpdate {
-            username: str_of_len(MAX_USERNAME_LENGTH),
-            requested_username: Some(str_of_len(MAX_USERNAME_LENGTH)),
+            username: text_of_len(MAX_USERNAME_LENGTH),
+            requested_username: Some(text_of_len(MAX_USERNAME_LENGTH)),
             requested_password: Some(str_of_len(MAX_PASSWORD_LENGTH)),
             requested_is_admin: Some(true),
             requested_enabled: Some(true),
@@ -295,10 +774,13 @@ mod tests {
     #[test]
     fn test_limit_server_info_update() {
         let msg = ClientMessage::ServerInfoUpdate {
-            name: Some(str_of_len(MAX_SERVER_NAME_LENGTH)),
-            description: Some(str_of_len(MAX_SERVER_DESCRIPTION_LENGTH)),
+            name: Some(text_of_len(MAX_SERVER_NAME_LENGTH)),
+            description: Some(text_of_len(MAX_SERVER_DESCRIPTION_LENGTH)),
             max_connections_per_ip: Some(u32::MAX),
             image: Some(str_of_len(MAX_SERVER_IMAGE_DATA_URI_LENGTH)),
+            chat_slow_mode: Some(u32::MAX),
+            max_message_length: Some(u32::MAX),
+            max_topic_length: Some(u32::MAX),
         };
         assert_eq!(
             json_size(&msg),
@@ -310,12 +792,121 @@ mod tests {
     // Server message size tests - verify limits match actual max sizes
     // =========================================================================
 
+    #[test]
+    fn test_limit_accept_rules_response() {
+        let msg = ServerMessage::AcceptRulesResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("AcceptRulesResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_admin_notice_response() {
+        let msg = ServerMessage::AdminNoticeResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("AdminNoticeResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_announcement_add_response() {
+        let msg = ServerMessage::AnnouncementAddResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("AnnouncementAddResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_announcement_list_response() {
+        let msg = ServerMessage::AnnouncementListResponse {
+            success: true,
+            error: Some(str_of_len(512)),
+            announcements: Some(
+                (0..MAX_ANNOUNCEMENTS)
+                    .map(|_| text_of_len(MAX_MESSAGE_LENGTH_CEILING))
+                    .collect(),
+            ),
+            interval_minutes: Some(u32::MAX),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("AnnouncementListResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_announcement_remove_response() {
+        let msg = ServerMessage::AnnouncementRemoveResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("AnnouncementRemoveResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_chat_delete_response() {
+        let msg = ServerMessage::ChatDeleteResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("ChatDeleteResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_chat_edit_response() {
+        let msg = ServerMessage::ChatEditResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("ChatEditResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_chat_history_purge_response() {
+        let msg = ServerMessage::ChatHistoryPurgeResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+            removed: Some(u64::MAX),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("ChatHistoryPurgeResponse") as usize
+        );
+    }
+
     #[test]
     fn test_limit_chat_message() {
         let msg = ServerMessage::ChatMessage {
             session_id: u32::MAX,
-            username: str_of_len(MAX_USERNAME_LENGTH),
-            message: str_of_len(MAX_MESSAGE_LENGTH),
+            username: text_of_len(MAX_USERNAME_LENGTH),
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+            message_id: Some(u64::MAX),
+            mentions: Some(
+                (0..MAX_MENTIONS)
+                    .map(|_| text_of_len(MAX_USERNAME_LENGTH))
+                    .collect(),
+            ),
         };
         assert_eq!(
             json_size(&msg),
@@ -323,11 +914,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_chat_message_deleted() {
+        let msg = ServerMessage::ChatMessageDeleted {
+            message_id: u64::MAX,
+            by_moderator: true,
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("ChatMessageDeleted") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_chat_message_edited() {
+        let msg = ServerMessage::ChatMessageEdited {
+            message_id: u64::MAX,
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+            mentions: Some(
+                (0..MAX_MENTIONS)
+                    .map(|_| text_of_len(MAX_USERNAME_LENGTH))
+                    .collect(),
+            ),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("ChatMessageEdited") as usize
+        );
+    }
+
     #[test]
     fn test_limit_chat_topic_updated() {
         let msg = ServerMessage::ChatTopicUpdated {
-            topic: str_of_len(MAX_CHAT_TOPIC_LENGTH),
-            username: str_of_len(MAX_USERNAME_LENGTH),
+            topic: text_of_len(MAX_CHAT_TOPIC_LENGTH_CEILING),
+            username: text_of_len(MAX_USERNAME_LENGTH),
         };
         assert_eq!(
             json_size(&msg),
@@ -347,11 +967,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_connection_stats_response() {
+        let bucket = ConnectionStatsBucket {
+            start: i64::MIN,
+            seconds: u32::MAX,
+            connections: u32::MAX,
+            refused: u32::MAX,
+            peak: u32::MAX,
+            peak_per_ip: u32::MAX,
+        };
+        let msg = ServerMessage::ConnectionStatsResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+            current: Some(u32::MAX),
+            buckets: Some(vec![bucket; MAX_CONNECTION_STATS_BUCKETS]),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("ConnectionStatsResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_e2ee_keys() {
+        let msg = ServerMessage::E2eeKeys {
+            username: text_of_len(MAX_USERNAME_LENGTH),
+            keys: (0..MAX_E2EE_ENVELOPES)
+                .map(|_| E2eeSessionKey {
+                    session_id: u32::MAX,
+                    public_key: str_of_len(E2EE_PUBLIC_KEY_LENGTH),
+                })
+                .collect(),
+        };
+        assert_eq!(json_size(&msg), max_payload_for_type("E2eeKeys") as usize);
+    }
+
+    #[test]
+    fn test_limit_encrypted_user_message_server() {
+        let msg = ServerMessage::EncryptedUserMessage {
+            from_username: text_of_len(MAX_USERNAME_LENGTH),
+            from_admin: false,
+            to_username: text_of_len(MAX_USERNAME_LENGTH),
+            envelope: max_e2ee_envelope(),
+            message_id: Some(u64::MAX),
+        };
+        // Server variant carries one envelope, so it fits within the limit
+        assert!(json_size(&msg) <= max_payload_for_type("EncryptedUserMessage") as usize);
+    }
+
     #[test]
     fn test_limit_error() {
         let msg = ServerMessage::Error {
             message: str_of_len(2048),
             command: Some(str_of_len(64)),
+            code: Some(str_of_len(MAX_ERROR_CODE_LENGTH)),
         };
         assert_eq!(json_size(&msg), max_payload_for_type("Error") as usize);
     }
@@ -362,6 +1032,8 @@ mod tests {
             success: false,
             version: Some(str_of_len(MAX_VERSION_LENGTH)),
             error: Some(str_of_len(256)),
+            banner: Some(text_of_len(MAX_SERVER_BANNER_LENGTH)),
+            code: Some(str_of_len(MAX_ERROR_CODE_LENGTH)),
         };
         assert_eq!(
             json_size(&msg),
@@ -369,6 +1041,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_history_response() {
+        let message = HistoryMessage {
+            message_id: u64::MAX,
+            username: text_of_len(MAX_USERNAME_LENGTH),
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+            timestamp: i64::MIN,
+        };
+        let msg = ServerMessage::HistoryResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+            messages: Some(vec![message; MAX_HISTORY_PAGE_SIZE]),
+            has_more: Some(false),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("HistoryResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_kick_session_response() {
+        let msg = ServerMessage::KickSessionResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("KickSessionResponse") as usize
+        );
+    }
+
     #[test]
     fn test_limit_login_response() {
         let msg = ServerMessage::LoginResponse {
@@ -382,17 +1086,30 @@ mod tests {
                     .collect(),
             ),
             server_info: Some(ServerInfo {
-                name: Some(str_of_len(MAX_SERVER_NAME_LENGTH)),
-                description: Some(str_of_len(MAX_SERVER_DESCRIPTION_LENGTH)),
+                name: Some(text_of_len(MAX_SERVER_NAME_LENGTH)),
+                description: Some(text_of_len(MAX_SERVER_DESCRIPTION_LENGTH)),
                 version: Some(str_of_len(MAX_VERSION_LENGTH)),
                 max_connections_per_ip: Some(u32::MAX),
                 image: Some(str_of_len(MAX_SERVER_IMAGE_DATA_URI_LENGTH)),
+                chat_slow_mode: Some(u32::MAX),
+                user_count: Some(u32::MAX),
+                max_message_length: Some(u32::MAX),
+                max_topic_length: Some(u32::MAX),
             }),
             chat_info: Some(ChatInfo {
-                topic: str_of_len(MAX_CHAT_TOPIC_LENGTH),
-                topic_set_by: str_of_len(MAX_USERNAME_LENGTH),
+                topic: text_of_len(MAX_CHAT_TOPIC_LENGTH_CEILING),
+                topic_set_by: text_of_len(MAX_USERNAME_LENGTH),
             }),
             locale: Some(str_of_len(MAX_LOCALE_LENGTH)),
+            pinned_messages: Some(max_pinned_messages()),
+            preferences: Some(max_preferences()),
+            rules: Some(text_of_len(MAX_SERVER_RULES_LENGTH)),
+            maintenance: Some(false),
+            disabled_permissions: Some(
+                (0..MAX_PERMISSIONS_COUNT)
+                    .map(|_| str_of_len(MAX_PERMISSION_LENGTH))
+                    .collect(),
+            ),
         };
         assert_eq!(
             json_size(&msg),
@@ -400,6 +1117,212 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_page_delete_response() {
+        let msg = ServerMessage::PageDeleteResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("PageDeleteResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_page_get_response() {
+        let msg = ServerMessage::PageGetResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+            name: Some(str_of_len(MAX_PAGE_NAME_LENGTH)),
+            content: Some(text_of_len(MAX_PAGE_CONTENT_LENGTH)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("PageGetResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_page_list_response() {
+        let msg = ServerMessage::PageListResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+            pages: Some(
+                (0..MAX_PAGES)
+                    .map(|_| str_of_len(MAX_PAGE_NAME_LENGTH))
+                    .collect(),
+            ),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("PageListResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_page_set_response() {
+        let msg = ServerMessage::PageSetResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("PageSetResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_pong() {
+        let msg = ServerMessage::Pong;
+        assert_eq!(json_size(&msg), max_payload_for_type("Pong") as usize);
+    }
+
+    #[test]
+    fn test_limit_pin_message_response() {
+        let msg = ServerMessage::PinMessageResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("PinMessageResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_pinned_messages_updated() {
+        let msg = ServerMessage::PinnedMessagesUpdated {
+            pinned_messages: max_pinned_messages(),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("PinnedMessagesUpdated") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_refresh_user_permissions_response() {
+        let msg = ServerMessage::RefreshUserPermissionsResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("RefreshUserPermissionsResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_limits_updated() {
+        let msg = ServerMessage::LimitsUpdated {
+            max_message_length: u32::MAX,
+            max_topic_length: u32::MAX,
+            chat_slow_mode: u32::MAX,
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("LimitsUpdated") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_maintenance_changed() {
+        let msg = ServerMessage::MaintenanceChanged { enabled: false };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("MaintenanceChanged") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_new_login() {
+        let msg = ServerMessage::NewLogin {
+            session_id: u32::MAX,
+            address: str_of_len(45),
+            source: str_of_len(MAX_SOURCE_TAG_LENGTH),
+        };
+        assert_eq!(json_size(&msg), max_payload_for_type("NewLogin") as usize);
+    }
+
+    #[test]
+    fn test_limit_transfer_admin_response() {
+        let msg = ServerMessage::TransferAdminResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+            username: Some(text_of_len(MAX_USERNAME_LENGTH)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("TransferAdminResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_seen_response() {
+        // Every field at its largest, even though a real response never has
+        // both an error and a result
+        let msg = ServerMessage::SeenResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+            username: Some(text_of_len(MAX_USERNAME_LENGTH)),
+            online: Some(false),
+            last_seen: Some(i64::MIN),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("SeenResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_set_maintenance_response() {
+        let msg = ServerMessage::SetMaintenanceResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("SetMaintenanceResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_set_profile_response() {
+        let msg = ServerMessage::SetProfileResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("SetProfileResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_set_preferences_response() {
+        let msg = ServerMessage::SetPreferencesResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("SetPreferencesResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_unpin_message_response() {
+        let msg = ServerMessage::UnpinMessageResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("UnpinMessageResponse") as usize
+        );
+    }
+
     #[test]
     fn test_limit_permissions_updated() {
         let msg = ServerMessage::PermissionsUpdated {
@@ -408,15 +1331,19 @@ mod tests {
                 .map(|_| str_of_len(MAX_PERMISSION_LENGTH))
                 .collect(),
             server_info: Some(ServerInfo {
-                name: Some(str_of_len(MAX_SERVER_NAME_LENGTH)),
-                description: Some(str_of_len(MAX_SERVER_DESCRIPTION_LENGTH)),
+                name: Some(text_of_len(MAX_SERVER_NAME_LENGTH)),
+                description: Some(text_of_len(MAX_SERVER_DESCRIPTION_LENGTH)),
                 version: Some(str_of_len(MAX_VERSION_LENGTH)),
                 max_connections_per_ip: Some(u32::MAX),
                 image: Some(str_of_len(MAX_SERVER_IMAGE_DATA_URI_LENGTH)),
+                chat_slow_mode: Some(u32::MAX),
+                user_count: Some(u32::MAX),
+                max_message_length: Some(u32::MAX),
+                max_topic_length: Some(u32::MAX),
             }),
             chat_info: Some(ChatInfo {
-                topic: str_of_len(MAX_CHAT_TOPIC_LENGTH),
-                topic_set_by: str_of_len(MAX_USERNAME_LENGTH),
+                topic: text_of_len(MAX_CHAT_TOPIC_LENGTH_CEILING),
+                topic_set_by: text_of_len(MAX_USERNAME_LENGTH),
             }),
         };
         assert_eq!(
@@ -429,11 +1356,15 @@ mod tests {
     fn test_limit_server_info_updated() {
         let msg = ServerMessage::ServerInfoUpdated {
             server_info: ServerInfo {
-                name: Some(str_of_len(MAX_SERVER_NAME_LENGTH)),
-                description: Some(str_of_len(MAX_SERVER_DESCRIPTION_LENGTH)),
+                name: Some(text_of_len(MAX_SERVER_NAME_LENGTH)),
+                description: Some(text_of_len(MAX_SERVER_DESCRIPTION_LENGTH)),
                 version: Some(str_of_len(MAX_VERSION_LENGTH)),
                 max_connections_per_ip: Some(u32::MAX),
                 image: Some(str_of_len(MAX_SERVER_IMAGE_DATA_URI_LENGTH)),
+                chat_slow_mode: Some(u32::MAX),
+                user_count: Some(u32::MAX),
+                max_message_length: Some(u32::MAX),
+                max_topic_length: Some(u32::MAX),
             },
         };
         assert_eq!(
@@ -454,12 +1385,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_server_announcement() {
+        let msg = ServerMessage::ServerAnnouncement {
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("ServerAnnouncement") as usize
+        );
+    }
+
     #[test]
     fn test_limit_server_broadcast() {
         let msg = ServerMessage::ServerBroadcast {
             session_id: u32::MAX,
-            username: str_of_len(MAX_USERNAME_LENGTH),
-            message: str_of_len(MAX_MESSAGE_LENGTH),
+            username: text_of_len(MAX_USERNAME_LENGTH),
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
         };
         assert_eq!(
             json_size(&msg),
@@ -471,12 +1413,16 @@ mod tests {
     fn test_limit_user_connected() {
         let msg = ServerMessage::UserConnected {
             user: UserInfo {
-                username: str_of_len(MAX_USERNAME_LENGTH),
+                username: text_of_len(MAX_USERNAME_LENGTH),
                 login_time: i64::MAX,
                 is_admin: true,
+                is_moderator: false,
+                away: false,
+                is_guest: false,
                 session_ids: vec![u32::MAX; 10],
                 locale: str_of_len(MAX_LOCALE_LENGTH),
                 avatar: Some(str_of_len(MAX_AVATAR_DATA_URI_LENGTH)),
+                permissions: Some(NOTABLE_PERMISSIONS.iter().map(|p| p.to_string()).collect()),
             },
         };
         assert_eq!(
@@ -490,6 +1436,7 @@ mod tests {
         let msg = ServerMessage::UserCreateResponse {
             success: false,
             error: Some(str_of_len(512)),
+            warning: Some(str_of_len(512)),
         };
         assert_eq!(
             json_size(&msg),
@@ -513,7 +1460,7 @@ mod tests {
     fn test_limit_user_disconnected() {
         let msg = ServerMessage::UserDisconnected {
             session_id: u32::MAX,
-            username: str_of_len(MAX_USERNAME_LENGTH),
+            username: text_of_len(MAX_USERNAME_LENGTH),
         };
         assert_eq!(
             json_size(&msg),
@@ -526,7 +1473,7 @@ mod tests {
         let msg = ServerMessage::UserEditResponse {
             success: true,
             error: None,
-            username: Some(str_of_len(MAX_USERNAME_LENGTH)),
+            username: Some(text_of_len(MAX_USERNAME_LENGTH)),
             is_admin: Some(true),
             enabled: Some(true),
             permissions: Some(
@@ -541,6 +1488,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_who_am_i_response() {
+        let msg = ServerMessage::WhoAmIResponse {
+            success: true,
+            error: None,
+            username: Some(text_of_len(MAX_USERNAME_LENGTH)),
+            is_admin: Some(false),
+            permissions: Some(
+                (0..MAX_PERMISSIONS_COUNT)
+                    .map(|_| str_of_len(MAX_PERMISSION_LENGTH))
+                    .collect(),
+            ),
+            features: Some(
+                (0..MAX_FEATURES_COUNT)
+                    .map(|_| str_of_len(MAX_FEATURE_LENGTH))
+                    .collect(),
+            ),
+        };
+        assert_eq!(
+            json_size(&msg),
+            max_payload_for_type("WhoAmIResponse") as usize
+        );
+    }
+
     #[test]
     fn test_limit_user_broadcast_response() {
         let msg = ServerMessage::UserBroadcastResponse {
@@ -559,17 +1530,21 @@ mod tests {
             success: true,
             error: None,
             user: Some(UserInfoDetailed {
-                username: str_of_len(MAX_USERNAME_LENGTH),
+                username: text_of_len(MAX_USERNAME_LENGTH),
                 login_time: i64::MAX,
                 session_ids: vec![u32::MAX; 10],
                 features: (0..MAX_FEATURES_COUNT)
                     .map(|_| str_of_len(MAX_FEATURE_LENGTH))
                     .collect(),
                 created_at: i64::MAX,
+                is_guest: false,
                 locale: str_of_len(MAX_LOCALE_LENGTH),
                 avatar: Some(str_of_len(MAX_AVATAR_DATA_URI_LENGTH)),
+                pronouns: Some(text_of_len(MAX_PRONOUNS_LENGTH)),
+                bio: Some(text_of_len(MAX_BIO_LENGTH)),
                 is_admin: Some(true),
                 addresses: Some(vec![str_of_len(45); 10]),
+                sources: Some(vec![str_of_len(MAX_SOURCE_TAG_LENGTH); 10]),
             }),
         };
         assert_eq!(
@@ -601,10 +1576,11 @@ mod tests {
     #[test]
     fn test_limit_user_message_server() {
         let msg = ServerMessage::UserMessage {
-            from_username: str_of_len(MAX_USERNAME_LENGTH),
+            from_username: text_of_len(MAX_USERNAME_LENGTH),
             from_admin: true,
-            to_username: str_of_len(MAX_USERNAME_LENGTH),
-            message: str_of_len(MAX_MESSAGE_LENGTH),
+            to_username: text_of_len(MAX_USERNAME_LENGTH),
+            message: text_of_len(MAX_MESSAGE_LENGTH_CEILING),
+            message_id: Some(u64::MAX),
         };
         // Server variant defines the limit since it's larger
         assert_eq!(
@@ -628,14 +1604,18 @@ mod tests {
     #[test]
     fn test_limit_user_updated() {
         let msg = ServerMessage::UserUpdated {
-            previous_username: str_of_len(MAX_USERNAME_LENGTH),
+            previous_username: text_of_len(MAX_USERNAME_LENGTH),
             user: UserInfo {
-                username: str_of_len(MAX_USERNAME_LENGTH),
+                username: text_of_len(MAX_USERNAME_LENGTH),
                 login_time: i64::MAX,
                 is_admin: true,
+                is_moderator: false,
+                away: false,
+                is_guest: false,
                 session_ids: vec![u32::MAX; 10],
                 locale: str_of_len(MAX_LOCALE_LENGTH),
                 avatar: Some(str_of_len(MAX_AVATAR_DATA_URI_LENGTH)),
+                permissions: Some(NOTABLE_PERMISSIONS.iter().map(|p| p.to_string()).collect()),
             },
         };
         assert_eq!(
@@ -649,6 +1629,7 @@ mod tests {
         let msg = ServerMessage::UserUpdateResponse {
             success: false,
             error: Some(str_of_len(512)),
+            warning: Some(str_of_len(512)),
         };
         assert_eq!(
             json_size(&msg),
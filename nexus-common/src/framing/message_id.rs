@@ -1,12 +1,17 @@
 //! Message ID for request-response correlation
 
 use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use rand::Rng;
 
 use super::MSG_ID_LENGTH;
 use super::error::FrameError;
 
+/// Bit mask for the 48-bit numeric value of a message ID
+const MSG_ID_MASK: u64 = (1 << (MSG_ID_LENGTH * 4)) - 1;
+
 /// A 12-character hex message ID for request-response correlation
 ///
 /// Message IDs provide 48 bits of entropy (12 hex characters × 4 bits each),
@@ -16,6 +21,9 @@ use super::error::FrameError;
 /// For a BBS application with per-connection ID scopes, this is more than sufficient.
 /// Message IDs are used for request-response correlation only, not for security
 /// purposes, so collisions have no security implications.
+///
+/// Connections that track outstanding requests should use [`MessageIdGenerator`],
+/// which guarantees no repeats within a connection instead of relying on chance.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MessageId([u8; MSG_ID_LENGTH]);
 
@@ -54,6 +62,33 @@ impl MessageId {
         Ok(Self(id))
     }
 
+    /// Create a message ID from the low 48 bits of a number
+    ///
+    /// The value is encoded as 12 zero-padded lowercase hex characters.
+    #[must_use]
+    pub fn from_u64(value: u64) -> Self {
+        const HEX_CHARS: &[u8] = b"0123456789abcdef";
+        let value = value & MSG_ID_MASK;
+        let mut bytes = [0u8; MSG_ID_LENGTH];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            let shift = (MSG_ID_LENGTH - 1 - i) * 4;
+            *byte = HEX_CHARS[((value >> shift) & 0xf) as usize];
+        }
+        Self(bytes)
+    }
+
+    /// Get the 48-bit numeric value of this message ID
+    #[must_use]
+    pub fn to_u64(&self) -> u64 {
+        self.0.iter().fold(0, |acc, &b| {
+            // Validated as hex on construction
+            let digit = (b as char)
+                .to_digit(16)
+                .expect("MessageId contains hex digits");
+            (acc << 4) | u64::from(digit)
+        })
+    }
+
     /// Get the message ID as a byte slice
     #[must_use]
     pub fn as_bytes(&self) -> &[u8] {
@@ -86,6 +121,46 @@ impl fmt::Display for MessageId {
     }
 }
 
+/// Per-connection message ID generator
+///
+/// Starts at a random 48-bit value and counts upward, so IDs never repeat
+/// within a connection until all 2^48 values have been used, while separate
+/// connections start at unrelated points. Clones share the same counter.
+#[derive(Debug, Clone)]
+pub struct MessageIdGenerator {
+    next: Arc<AtomicU64>,
+}
+
+impl MessageIdGenerator {
+    /// Create a generator starting at a random offset
+    #[must_use]
+    pub fn new() -> Self {
+        Self::starting_at(rand::rng().random::<u64>())
+    }
+
+    /// Create a generator starting at a specific value (low 48 bits are used)
+    #[must_use]
+    pub fn starting_at(start: u64) -> Self {
+        Self {
+            next: Arc::new(AtomicU64::new(start & MSG_ID_MASK)),
+        }
+    }
+
+    /// Generate the next message ID
+    ///
+    /// IDs increase by one per call and wrap around after `ffffffffffff`.
+    #[must_use]
+    pub fn next_id(&self) -> MessageId {
+        MessageId::from_u64(self.next.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for MessageIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +222,64 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_message_id_from_u64() {
+        assert_eq!(MessageId::from_u64(0).as_str(), "000000000000");
+        assert_eq!(MessageId::from_u64(0xa1b2c3d4e5f6).as_str(), "a1b2c3d4e5f6");
+        assert_eq!(MessageId::from_u64(MSG_ID_MASK).as_str(), "ffffffffffff");
+        // Bits above 48 are discarded
+        assert_eq!(MessageId::from_u64(1 << 48).as_str(), "000000000000");
+    }
+
+    #[test]
+    fn test_message_id_u64_roundtrip() {
+        for value in [0, 1, 0xabc, 0xa1b2c3d4e5f6, MSG_ID_MASK] {
+            let id = MessageId::from_u64(value);
+            assert_eq!(id.to_u64(), value);
+            assert_eq!(MessageId::from_bytes(id.as_bytes()).unwrap(), id);
+        }
+    }
+
+    #[test]
+    fn test_message_id_to_u64_uppercase() {
+        // Uppercase hex from a peer parses to the same value
+        let id = MessageId::from_bytes(b"A1B2C3D4E5F6").unwrap();
+        assert_eq!(id.to_u64(), 0xa1b2c3d4e5f6);
+    }
+
+    #[test]
+    fn test_generator_is_monotonic() {
+        let generator = MessageIdGenerator::starting_at(41);
+        assert_eq!(generator.next_id().to_u64(), 41);
+        assert_eq!(generator.next_id().to_u64(), 42);
+        assert_eq!(generator.next_id().to_u64(), 43);
+    }
+
+    #[test]
+    fn test_generator_wraps_around() {
+        let generator = MessageIdGenerator::starting_at(MSG_ID_MASK);
+        assert_eq!(generator.next_id().as_str(), "ffffffffffff");
+        assert_eq!(generator.next_id().as_str(), "000000000000");
+    }
+
+    #[test]
+    fn test_generator_uniqueness() {
+        let generator = MessageIdGenerator::new();
+        let mut ids = std::collections::HashSet::new();
+        for _ in 0..100_000 {
+            let id = generator.next_id();
+            assert!(id.as_bytes().iter().all(u8::is_ascii_hexdigit));
+            assert!(ids.insert(id), "Duplicate MessageId generated");
+        }
+    }
+
+    #[test]
+    fn test_generator_clones_share_counter() {
+        let generator = MessageIdGenerator::starting_at(0);
+        let clone = generator.clone();
+        assert_eq!(generator.next_id().to_u64(), 0);
+        assert_eq!(clone.next_id().to_u64(), 1);
+        assert_eq!(generator.next_id().to_u64(), 2);
+    }
 }
@@ -38,8 +38,10 @@ mod writer;
 // Re-export public types
 pub use error::FrameError;
 pub use frame::RawFrame;
-pub use limits::{is_known_message_type, known_message_types, max_payload_for_type};
-pub use message_id::MessageId;
+pub use limits::{
+    is_known_message_type, known_message_types, legacy_max_payload_for_type, max_payload_for_type,
+};
+pub use message_id::{MessageId, MessageIdGenerator};
 pub use reader::{DEFAULT_FRAME_TIMEOUT, FrameReader};
 pub use writer::FrameWriter;
 
@@ -71,6 +73,13 @@ pub const MAX_PAYLOAD_LENGTH_DIGITS: usize = 10;
 /// Maximum payload length (sanity check, per-type limits are enforced separately)
 pub const MAX_PAYLOAD_LENGTH: u64 = 9_999_999_999;
 
+/// Largest over-limit payload the reader skips instead of failing the stream
+///
+/// A payload over its type's limit but within this size is read and discarded
+/// so the sender can be told and the connection kept. Anything larger is
+/// treated as hostile and fails before a byte of it is read.
+pub const MAX_SKIPPABLE_PAYLOAD_LENGTH: u64 = 1024 * 1024;
+
 #[cfg(test)]
 mod tests {
     use super::*;
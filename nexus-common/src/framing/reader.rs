@@ -11,13 +11,16 @@ use super::frame::RawFrame;
 use super::limits::{is_known_message_type, max_payload_for_type};
 use super::message_id::MessageId;
 use super::{
-    DELIMITER, MAGIC, MAX_PAYLOAD_LENGTH, MAX_PAYLOAD_LENGTH_DIGITS, MAX_TYPE_LENGTH,
-    MAX_TYPE_LENGTH_DIGITS, MSG_ID_LENGTH, TERMINATOR,
+    DELIMITER, MAGIC, MAX_PAYLOAD_LENGTH, MAX_PAYLOAD_LENGTH_DIGITS, MAX_SKIPPABLE_PAYLOAD_LENGTH,
+    MAX_TYPE_LENGTH, MAX_TYPE_LENGTH_DIGITS, MSG_ID_LENGTH, TERMINATOR,
 };
 
 /// Default timeout for completing a frame once the first byte is received
 pub const DEFAULT_FRAME_TIMEOUT: Duration = Duration::from_secs(60);
 
+/// Maximum bytes to preallocate for a payload before any of it has arrived
+const PAYLOAD_PREALLOCATE_MAX: u64 = 64 * 1024;
+
 /// Reads protocol frames from an async reader
 pub struct FrameReader<R> {
     reader: R,
@@ -172,9 +175,20 @@ impl<R: AsyncReadExt + Unpin> FrameReader<R> {
         }
 
         // Step 9: Validate payload length against per-type maximum (0 = unlimited)
+        // Modest overruns are skipped so the sender can be told; hostile sizes
+        // fail without reading the payload
         let max_for_type = max_payload_for_type(&message_type);
         if max_for_type > 0 && payload_length > max_for_type {
-            return Err(FrameError::PayloadLengthExceedsTypeMax {
+            if payload_length > MAX_SKIPPABLE_PAYLOAD_LENGTH {
+                return Err(FrameError::PayloadLengthExceedsTypeMax {
+                    message_type,
+                    length: payload_length,
+                    max: max_for_type,
+                });
+            }
+            self.skip_payload(payload_length).await?;
+            return Err(FrameError::OversizedPayloadSkipped {
+                message_id,
                 message_type,
                 length: payload_length,
                 max: max_for_type,
@@ -182,8 +196,16 @@ impl<R: AsyncReadExt + Unpin> FrameReader<R> {
         }
 
         // Step 10: Read exactly M bytes — JSON payload
-        let mut payload = vec![0u8; payload_length as usize];
-        self.reader.read_exact(&mut payload).await?;
+        // The buffer grows as data arrives instead of trusting the declared length,
+        // so a bogus header can't force a huge allocation up front.
+        let mut payload = Vec::with_capacity(payload_length.min(PAYLOAD_PREALLOCATE_MAX) as usize);
+        let read = (&mut self.reader)
+            .take(payload_length)
+            .read_to_end(&mut payload)
+            .await?;
+        if (read as u64) < payload_length {
+            return Err(FrameError::ConnectionClosed);
+        }
 
         // Step 11: Read exactly 1 byte — must be "\n"
         let terminator = self.read_byte().await?;
@@ -194,6 +216,24 @@ impl<R: AsyncReadExt + Unpin> FrameReader<R> {
         Ok(Some(RawFrame::new(message_id, message_type, payload)))
     }
 
+    /// Discard a payload of the given length and its terminator
+    async fn skip_payload(&mut self, payload_length: u64) -> Result<(), FrameError> {
+        let skipped = tokio::io::copy(
+            &mut (&mut self.reader).take(payload_length),
+            &mut tokio::io::sink(),
+        )
+        .await?;
+        if skipped < payload_length {
+            return Err(FrameError::ConnectionClosed);
+        }
+
+        let terminator = self.read_byte().await?;
+        if terminator != TERMINATOR {
+            return Err(FrameError::MissingTerminator);
+        }
+        Ok(())
+    }
+
     /// Read a single byte, returning None on clean EOF
     async fn read_byte_allow_eof(&mut self) -> Result<Option<u8>, FrameError> {
         let mut buf = [0u8; 1];
@@ -395,9 +435,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_frame_reader_payload_exceeds_type_max() {
-        // ChatSend has a limit of 1056 bytes, try to send more
-        // Create a payload that claims to be 2000 bytes
-        let data = b"NX|8|ChatSend|a1b2c3d4e5f6|2000|";
+        // ChatSend has a limit of 16416 bytes; a payload past the skippable cap
+        // is rejected from the header alone
+        let data = b"NX|8|ChatSend|a1b2c3d4e5f6|2000000|";
         let cursor = Cursor::new(data.as_slice());
         let buf_reader = BufReader::new(cursor);
         let mut reader = FrameReader::new(buf_reader);
@@ -407,12 +447,78 @@ mod tests {
             result,
             Err(FrameError::PayloadLengthExceedsTypeMax {
                 message_type,
-                length: 2000,
-                max: 1056
+                length: 2000000,
+                max: 16416
             }) if message_type == "ChatSend"
         ));
     }
 
+    #[tokio::test]
+    async fn test_frame_reader_skips_oversized_payload_within_cap() {
+        // 20000 bytes is over ChatSend's limit but under the skippable cap, so
+        // the payload is discarded and the next frame still reads
+        let mut data = b"NX|8|ChatSend|a1b2c3d4e5f6|20000|".to_vec();
+        data.extend(std::iter::repeat_n(b'x', 20000));
+        data.extend_from_slice(b"\nNX|8|UserList|b2c3d4e5f6a1|2|{}\n");
+        let cursor = Cursor::new(data);
+        let buf_reader = BufReader::new(cursor);
+        let mut reader = FrameReader::new(buf_reader);
+
+        let result = reader.read_frame().await;
+        assert!(matches!(
+            result,
+            Err(FrameError::OversizedPayloadSkipped {
+                message_id,
+                ref message_type,
+                length: 20000,
+                max: 16416
+            }) if message_type == "ChatSend"
+                && message_id == MessageId::from_bytes(b"a1b2c3d4e5f6").unwrap()
+        ));
+        assert!(result.unwrap_err().is_recoverable());
+
+        let frame = reader.read_frame().await.unwrap().unwrap();
+        assert_eq!(frame.message_type, "UserList");
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_skippable_cap_boundary() {
+        // Exactly at the cap is skipped
+        let mut data =
+            format!("NX|8|ChatSend|a1b2c3d4e5f6|{MAX_SKIPPABLE_PAYLOAD_LENGTH}|").into_bytes();
+        data.extend(std::iter::repeat_n(
+            b'x',
+            MAX_SKIPPABLE_PAYLOAD_LENGTH as usize,
+        ));
+        data.push(b'\n');
+        let (result, _) = read_raw(&data).await;
+        assert!(matches!(
+            result,
+            Err(FrameError::OversizedPayloadSkipped { length, .. })
+                if length == MAX_SKIPPABLE_PAYLOAD_LENGTH
+        ));
+
+        // One byte over the cap is hostile
+        let over = MAX_SKIPPABLE_PAYLOAD_LENGTH + 1;
+        let data = format!("NX|8|ChatSend|a1b2c3d4e5f6|{over}|");
+        let (result, _) = read_raw(data.as_bytes()).await;
+        assert!(matches!(
+            result,
+            Err(FrameError::PayloadLengthExceedsTypeMax { length, .. }) if length == over
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_skipped_payload_still_needs_terminator() {
+        let data = format!("NX|9|Handshake|a1b2c3d4e5f6|66|{}X", "x".repeat(66));
+        let (result, _) = read_raw(data.as_bytes()).await;
+        assert_eq!(result, Err(FrameError::MissingTerminator));
+
+        let data = b"NX|9|Handshake|a1b2c3d4e5f6|66|short";
+        let (result, _) = read_raw(data).await;
+        assert_eq!(result, Err(FrameError::ConnectionClosed));
+    }
+
     #[tokio::test]
     async fn test_frame_reader_zero_length_payload() {
         let data = b"NX|8|UserList|a1b2c3d4e5f6|0|\n";
@@ -467,10 +573,11 @@ mod tests {
         let result = reader.read_frame().await;
         assert!(matches!(
             result,
-            Err(FrameError::PayloadLengthExceedsTypeMax {
+            Err(FrameError::OversizedPayloadSkipped {
                 message_type,
                 length: 66,
-                max: 65
+                max: 65,
+                ..
             }) if message_type == "Handshake"
         ));
     }
@@ -633,4 +740,200 @@ mod tests {
             .unwrap();
         assert_eq!(frame.message_type, "ChatSend");
     }
+
+    // =========================================================================
+    // Malformed header tests
+    // =========================================================================
+
+    /// Read a single frame from raw bytes without buffering, returning the
+    /// result along with the bytes left unread in the stream
+    async fn read_raw(data: &[u8]) -> (Result<Option<RawFrame>, FrameError>, Vec<u8>) {
+        let mut reader = FrameReader::new(Cursor::new(data.to_vec()));
+        let result = reader.read_frame().await;
+        let cursor = reader.into_inner();
+        let pos = cursor.position() as usize;
+        (result, cursor.into_inner()[pos..].to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_type_length_non_numeric() {
+        let (result, rest) = read_raw(b"NX|8a|ChatSend|a1b2c3d4e5f6|2|{}\n").await;
+        assert_eq!(result, Err(FrameError::InvalidTypeLength));
+        // Stops at the offending byte without consuming the rest of the frame
+        assert_eq!(rest, b"|ChatSend|a1b2c3d4e5f6|2|{}\n");
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_type_length_empty() {
+        let (result, _) = read_raw(b"NX||ChatSend|a1b2c3d4e5f6|2|{}\n").await;
+        assert_eq!(result, Err(FrameError::InvalidTypeLength));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_type_length_signed() {
+        let (result, _) = read_raw(b"NX|+8|ChatSend|a1b2c3d4e5f6|2|{}\n").await;
+        assert_eq!(result, Err(FrameError::InvalidTypeLength));
+
+        let (result, _) = read_raw(b"NX|-8|ChatSend|a1b2c3d4e5f6|2|{}\n").await;
+        assert_eq!(result, Err(FrameError::InvalidTypeLength));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_type_length_all_zeros() {
+        let (result, _) = read_raw(b"NX|000|ChatSend|a1b2c3d4e5f6|2|{}\n").await;
+        assert_eq!(result, Err(FrameError::TypeLengthOutOfRange));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_type_length_too_many_digits_stops_reading() {
+        let (result, rest) = read_raw(b"NX|9999999|X").await;
+        assert_eq!(result, Err(FrameError::TypeLengthTooManyDigits));
+        // Only MAX_TYPE_LENGTH_DIGITS + 1 bytes of the field are consumed
+        assert_eq!(rest, b"999|X");
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_type_length_at_maximum() {
+        // A 999-byte type is read in full and then rejected as unknown
+        let mut data = format!("NX|{MAX_TYPE_LENGTH}|").into_bytes();
+        data.extend(std::iter::repeat_n(b'A', MAX_TYPE_LENGTH));
+        data.extend_from_slice(b"|a1b2c3d4e5f6|2|{}\n");
+
+        let (result, rest) = read_raw(&data).await;
+        assert!(matches!(
+            result,
+            Err(FrameError::UnknownMessageType(t)) if t.len() == MAX_TYPE_LENGTH
+        ));
+        assert_eq!(rest, b"|a1b2c3d4e5f6|2|{}\n");
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_type_invalid_utf8() {
+        let (result, _) = read_raw(b"NX|2|\xff\xfe|a1b2c3d4e5f6|2|{}\n").await;
+        assert!(matches!(result, Err(FrameError::UnknownMessageType(_))));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_missing_delimiter_after_type() {
+        let (result, _) = read_raw(b"NX|8|ChatSend:a1b2c3d4e5f6|2|{}\n").await;
+        assert_eq!(result, Err(FrameError::MissingDelimiter));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_missing_delimiter_after_message_id() {
+        let (result, _) = read_raw(b"NX|8|ChatSend|a1b2c3d4e5f6:2|{}\n").await;
+        assert_eq!(result, Err(FrameError::MissingDelimiter));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_payload_length_non_numeric() {
+        let (result, rest) = read_raw(b"NX|8|ChatSend|a1b2c3d4e5f6|2x|{}\n").await;
+        assert_eq!(result, Err(FrameError::InvalidPayloadLength));
+        assert_eq!(rest, b"|{}\n");
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_payload_length_empty() {
+        let (result, _) = read_raw(b"NX|8|ChatSend|a1b2c3d4e5f6||{}\n").await;
+        assert_eq!(result, Err(FrameError::InvalidPayloadLength));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_payload_length_signed() {
+        let (result, _) = read_raw(b"NX|8|ChatSend|a1b2c3d4e5f6|-2|{}\n").await;
+        assert_eq!(result, Err(FrameError::InvalidPayloadLength));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_payload_length_too_many_digits_stops_reading() {
+        let (result, rest) = read_raw(b"NX|8|ChatSend|a1b2c3d4e5f6|123456789012|{}\n").await;
+        assert_eq!(result, Err(FrameError::PayloadLengthTooManyDigits));
+        assert_eq!(rest, b"2|{}\n");
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_huge_declared_payload_does_not_preallocate() {
+        // An unlimited type claiming the maximum payload length must fail cleanly
+        // when the stream ends, rather than allocating the declared size up front
+        let data = format!("NX|16|UserListResponse|a1b2c3d4e5f6|{MAX_PAYLOAD_LENGTH}|{{}}");
+        let (result, _) = read_raw(data.as_bytes()).await;
+        assert_eq!(result, Err(FrameError::ConnectionClosed));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_truncated_frames() {
+        let data = b"NX|8|ChatSend|a1b2c3d4e5f6|20|{\"message\":\"Hello!\"}\n";
+
+        // Every strict prefix is either a clean disconnect (empty) or a closed connection
+        for len in 0..data.len() {
+            let (result, _) = read_raw(&data[..len]).await;
+            if len == 0 {
+                assert_eq!(result, Ok(None));
+            } else {
+                assert_eq!(
+                    result,
+                    Err(FrameError::ConnectionClosed),
+                    "prefix of {len} bytes"
+                );
+            }
+        }
+    }
+
+    /// Drain a stream of frames, asserting it terminates promptly
+    async fn drain_frames(data: Vec<u8>) {
+        let mut reader = FrameReader::new(Cursor::new(data));
+        let drained = timeout(Duration::from_secs(5), async {
+            // Each successful frame consumes at least one byte, so this terminates
+            while let Ok(Some(_)) = reader.read_frame().await {}
+        })
+        .await;
+        assert!(drained.is_ok(), "frame reader hung on input");
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_fuzz_random_bytes() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0x4e58);
+        for _ in 0..2000 {
+            let len = rng.random_range(0..256);
+            let data: Vec<u8> = (0..len).map(|_| rng.random()).collect();
+            drain_frames(data).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_fuzz_after_magic() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        // Bias the garbage toward header characters to reach the deeper parsing steps
+        const ALPHABET: &[u8] = b"0123456789|abcdefNX\n";
+        let mut rng = StdRng::seed_from_u64(0x7c7c);
+        for _ in 0..2000 {
+            let len = rng.random_range(0..128);
+            let mut data = MAGIC.to_vec();
+            data.extend((0..len).map(|_| ALPHABET[rng.random_range(0..ALPHABET.len())]));
+            drain_frames(data).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_fuzz_corrupted_bytes() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let valid = b"NX|8|ChatSend|a1b2c3d4e5f6|20|{\"message\":\"Hello!\"}\n";
+        let mut rng = StdRng::seed_from_u64(0xbeef);
+
+        // Corrupt each position with several random bytes
+        for pos in 0..valid.len() {
+            for _ in 0..16 {
+                let mut data = valid.to_vec();
+                data[pos] = rng.random();
+                drain_frames(data).await;
+            }
+        }
+    }
 }
@@ -239,10 +239,39 @@ where
 #[must_use]
 pub fn client_message_type(message: &ClientMessage) -> &'static str {
     match message {
+        ClientMessage::AcceptRules => "AcceptRules",
+        ClientMessage::AdminNotice { .. } => "AdminNotice",
+        ClientMessage::AnnouncementAdd { .. } => "AnnouncementAdd",
+        ClientMessage::AnnouncementList => "AnnouncementList",
+        ClientMessage::AnnouncementRemove { .. } => "AnnouncementRemove",
+        ClientMessage::Capabilities => "Capabilities",
+        ClientMessage::ChatDelete { .. } => "ChatDelete",
+        ClientMessage::ChatEdit { .. } => "ChatEdit",
+        ClientMessage::ChatHistoryPurge => "ChatHistoryPurge",
         ClientMessage::ChatSend { .. } => "ChatSend",
         ClientMessage::ChatTopicUpdate { .. } => "ChatTopicUpdate",
+        ClientMessage::ConnectionStats { .. } => "ConnectionStats",
+        ClientMessage::E2eeKeyAnnounce { .. } => "E2eeKeyAnnounce",
+        ClientMessage::E2eeKeysRequest { .. } => "E2eeKeysRequest",
+        ClientMessage::EncryptedUserMessage { .. } => "EncryptedUserMessage",
         ClientMessage::Handshake { .. } => "Handshake",
+        ClientMessage::HistoryRequest { .. } => "HistoryRequest",
+        ClientMessage::KickSession { .. } => "KickSession",
         ClientMessage::Login { .. } => "Login",
+        ClientMessage::PageDelete { .. } => "PageDelete",
+        ClientMessage::PageGet { .. } => "PageGet",
+        ClientMessage::PageList => "PageList",
+        ClientMessage::PageSet { .. } => "PageSet",
+        ClientMessage::Ping => "Ping",
+        ClientMessage::PinMessage { .. } => "PinMessage",
+        ClientMessage::UnpinMessage { .. } => "UnpinMessage",
+        ClientMessage::RefreshUserPermissions { .. } => "RefreshUserPermissions",
+        ClientMessage::SetAway { .. } => "SetAway",
+        ClientMessage::SetMaintenance { .. } => "SetMaintenance",
+        ClientMessage::SetPreferences { .. } => "SetPreferences",
+        ClientMessage::SeenRequest { .. } => "SeenRequest",
+        ClientMessage::SetProfile { .. } => "SetProfile",
+        ClientMessage::TransferAdmin { .. } => "TransferAdmin",
         ClientMessage::UserBroadcast { .. } => "UserBroadcast",
         ClientMessage::UserCreate { .. } => "UserCreate",
         ClientMessage::UserDelete { .. } => "UserDelete",
@@ -252,6 +281,7 @@ pub fn client_message_type(message: &ClientMessage) -> &'static str {
         ClientMessage::UserList { .. } => "UserList",
         ClientMessage::UserMessage { .. } => "UserMessage",
         ClientMessage::UserUpdate { .. } => "UserUpdate",
+        ClientMessage::WhoAmI => "WhoAmI",
         ClientMessage::ServerInfoUpdate { .. } => "ServerInfoUpdate",
     }
 }
@@ -260,13 +290,41 @@ pub fn client_message_type(message: &ClientMessage) -> &'static str {
 #[must_use]
 pub fn server_message_type(message: &ServerMessage) -> &'static str {
     match message {
+        ServerMessage::AcceptRulesResponse { .. } => "AcceptRulesResponse",
+        ServerMessage::AdminNotice { .. } => "AdminNotice",
+        ServerMessage::AdminNoticeResponse { .. } => "AdminNoticeResponse",
+        ServerMessage::AnnouncementAddResponse { .. } => "AnnouncementAddResponse",
+        ServerMessage::AnnouncementListResponse { .. } => "AnnouncementListResponse",
+        ServerMessage::AnnouncementRemoveResponse { .. } => "AnnouncementRemoveResponse",
+        ServerMessage::CapabilitiesResponse { .. } => "CapabilitiesResponse",
+        ServerMessage::ChatDeleteResponse { .. } => "ChatDeleteResponse",
+        ServerMessage::ChatEditResponse { .. } => "ChatEditResponse",
+        ServerMessage::ChatHistoryPurgeResponse { .. } => "ChatHistoryPurgeResponse",
         ServerMessage::ChatMessage { .. } => "ChatMessage",
+        ServerMessage::ChatMessageDeleted { .. } => "ChatMessageDeleted",
+        ServerMessage::ChatMessageEdited { .. } => "ChatMessageEdited",
         ServerMessage::ChatTopicUpdated { .. } => "ChatTopicUpdated",
         ServerMessage::ChatTopicUpdateResponse { .. } => "ChatTopicUpdateResponse",
+        ServerMessage::ConnectionStatsResponse { .. } => "ConnectionStatsResponse",
+        ServerMessage::E2eeKeys { .. } => "E2eeKeys",
+        ServerMessage::EncryptedUserMessage { .. } => "EncryptedUserMessage",
         ServerMessage::Error { .. } => "Error",
         ServerMessage::HandshakeResponse { .. } => "HandshakeResponse",
+        ServerMessage::HistoryResponse { .. } => "HistoryResponse",
+        ServerMessage::KickSessionResponse { .. } => "KickSessionResponse",
         ServerMessage::LoginResponse { .. } => "LoginResponse",
         ServerMessage::PermissionsUpdated { .. } => "PermissionsUpdated",
+        ServerMessage::LimitsUpdated { .. } => "LimitsUpdated",
+        ServerMessage::MaintenanceChanged { .. } => "MaintenanceChanged",
+        ServerMessage::NewLogin { .. } => "NewLogin",
+        ServerMessage::PageDeleteResponse { .. } => "PageDeleteResponse",
+        ServerMessage::PageGetResponse { .. } => "PageGetResponse",
+        ServerMessage::PageListResponse { .. } => "PageListResponse",
+        ServerMessage::PageSetResponse { .. } => "PageSetResponse",
+        ServerMessage::Pong => "Pong",
+        ServerMessage::PinMessageResponse { .. } => "PinMessageResponse",
+        ServerMessage::PinnedMessagesUpdated { .. } => "PinnedMessagesUpdated",
+        ServerMessage::ServerAnnouncement { .. } => "ServerAnnouncement",
         ServerMessage::ServerBroadcast { .. } => "ServerBroadcast",
         ServerMessage::UserBroadcastResponse { .. } => "UserBroadcastResponse",
         ServerMessage::UserConnected { .. } => "UserConnected",
@@ -276,6 +334,14 @@ pub fn server_message_type(message: &ServerMessage) -> &'static str {
         ServerMessage::UserEditResponse { .. } => "UserEditResponse",
         ServerMessage::UserInfoResponse { .. } => "UserInfoResponse",
         ServerMessage::UserKickResponse { .. } => "UserKickResponse",
+        ServerMessage::UnpinMessageResponse { .. } => "UnpinMessageResponse",
+        ServerMessage::RefreshUserPermissionsResponse { .. } => "RefreshUserPermissionsResponse",
+        ServerMessage::SeenResponse { .. } => "SeenResponse",
+        ServerMessage::TransferAdminResponse { .. } => "TransferAdminResponse",
+        ServerMessage::SetMaintenanceResponse { .. } => "SetMaintenanceResponse",
+        ServerMessage::WhoAmIResponse { .. } => "WhoAmIResponse",
+        ServerMessage::SetPreferencesResponse { .. } => "SetPreferencesResponse",
+        ServerMessage::SetProfileResponse { .. } => "SetProfileResponse",
         ServerMessage::UserListResponse { .. } => "UserListResponse",
         ServerMessage::UserMessage { .. } => "UserMessage",
         ServerMessage::UserMessageResponse { .. } => "UserMessageResponse",
@@ -323,6 +389,8 @@ mod tests {
                 session_id: 1,
                 username: "test".to_string(),
                 message: "hi".to_string(),
+                message_id: None,
+                mentions: None,
             }),
             "ChatMessage"
         );
@@ -330,6 +398,7 @@ mod tests {
             server_message_type(&ServerMessage::Error {
                 message: "error".to_string(),
                 command: None,
+                code: None,
             }),
             "Error"
         );
@@ -369,6 +438,8 @@ mod tests {
             session_id: 42,
             username: "alice".to_string(),
             message: "Hi there!".to_string(),
+            message_id: None,
+            mentions: None,
         };
 
         // Write the message
@@ -390,6 +461,7 @@ mod tests {
                 session_id,
                 username,
                 message,
+                ..
             } => {
                 assert_eq!(session_id, 42);
                 assert_eq!(username, "alice");
@@ -429,6 +501,8 @@ mod tests {
             success: true,
             version: Some("0.4.0".to_string()),
             error: None,
+            banner: None,
+            code: None,
         };
         let specific_id = MessageId::new();
 
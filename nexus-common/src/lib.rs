@@ -4,12 +4,13 @@
 
 pub mod framing;
 pub mod io;
+pub mod mentions;
 pub mod protocol;
 pub mod validators;
 pub mod version;
 
 /// Version information for the Nexus protocol
-pub const PROTOCOL_VERSION: &str = "0.5.0";
+pub const PROTOCOL_VERSION: &str = "0.6.0";
 
 /// Default port for Nexus BBS connections
 pub const DEFAULT_PORT: u16 = 7500;
@@ -26,6 +27,8 @@ pub const DEFAULT_PORT_STR: &str = "7500";
 /// user access control. The list is maintained in alphabetical order.
 ///
 /// Permission meanings:
+/// - `chat_moderate`: Delete any recent chat message in #server
+/// - `chat_pin`: Pin and unpin chat messages in #server
 /// - `chat_receive`: Receive chat messages from #server
 /// - `chat_send`: Send chat messages to #server
 /// - `chat_topic`: View the server topic
@@ -39,6 +42,8 @@ pub const DEFAULT_PORT_STR: &str = "7500";
 /// - `user_list`: View the list of connected users
 /// - `user_message`: Send private messages to users
 pub const ALL_PERMISSIONS: &[&str] = &[
+    "chat_moderate",
+    "chat_pin",
     "chat_receive",
     "chat_send",
     "chat_topic",
@@ -53,6 +58,17 @@ pub const ALL_PERMISSIONS: &[&str] = &[
     "user_message",
 ];
 
+/// Permissions worth showing at a glance in user lists (moderation powers)
+///
+/// Sent per user in listings to admins and users with `user_info`.
+pub const NOTABLE_PERMISSIONS: &[&str] = &[
+    "chat_moderate",
+    "chat_pin",
+    "chat_topic_edit",
+    "user_broadcast",
+    "user_kick",
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,8 +95,8 @@ mod tests {
 
     #[test]
     fn test_all_permissions_count() {
-        // Verify we have the expected number of permissions (12)
-        assert_eq!(ALL_PERMISSIONS.len(), 12);
+        // Verify we have the expected number of permissions (14)
+        assert_eq!(ALL_PERMISSIONS.len(), 14);
     }
 
     #[test]
@@ -91,6 +107,20 @@ mod tests {
         assert_eq!(ALL_PERMISSIONS, sorted.as_slice());
     }
 
+    #[test]
+    fn test_notable_permissions_are_known_and_sorted() {
+        for perm in NOTABLE_PERMISSIONS {
+            assert!(
+                ALL_PERMISSIONS.contains(perm),
+                "Unknown permission: {}",
+                perm
+            );
+        }
+        let mut sorted = NOTABLE_PERMISSIONS.to_vec();
+        sorted.sort();
+        assert_eq!(NOTABLE_PERMISSIONS, sorted.as_slice());
+    }
+
     #[test]
     fn test_all_permissions_no_duplicates() {
         // Verify no duplicate permissions
@@ -0,0 +1,163 @@
+//! `@username` mention parsing
+//!
+//! The server uses this to work out who a chat message mentions, and the
+//! client to find those mentions again when rendering the message.
+//!
+//! A mention is an `@` at the start of the text or after anything other than
+//! a letter, digit, or `_` (so `bob@example.com` is not one), followed by the
+//! name up to the next whitespace. Usernames may contain punctuation, so the
+//! full name is tried first and trailing punctuation (`@alice:`, `@bob!`) is
+//! only dropped when that doesn't match anyone.
+
+use std::ops::Range;
+
+use crate::validators::MAX_USERNAME_LENGTH;
+
+/// Maximum number of distinct users flagged as mentioned in one message
+pub const MAX_MENTIONS: usize = 10;
+
+/// A mention found in a message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mention {
+    /// Byte range of the mention in the text, including the `@`
+    pub range: Range<usize>,
+    /// The mentioned user, as returned by the lookup
+    pub username: String,
+}
+
+/// Find the mentions in `text`, in order of appearance
+///
+/// `lookup` maps a candidate name to the user it refers to, or `None` if
+/// there is no such user. Candidates that don't match anyone are skipped, so
+/// a mention of an unknown user is just text. A user mentioned more than once
+/// appears more than once.
+pub fn find_mentions(text: &str, mut lookup: impl FnMut(&str) -> Option<String>) -> Vec<Mention> {
+    let mut mentions = Vec::new();
+    let mut prev: Option<char> = None;
+    let mut resume_at = 0;
+
+    for (start, ch) in text.char_indices() {
+        let at_boundary = prev.is_none_or(|p| !p.is_alphanumeric() && p != '_');
+        prev = Some(ch);
+        if ch != '@' || !at_boundary || start < resume_at {
+            continue;
+        }
+
+        let Some(name) = candidate_name(&text[start + 1..]) else {
+            continue;
+        };
+        if let Some((len, username)) = match_name(name, &mut lookup) {
+            let end = start + 1 + len;
+            mentions.push(Mention {
+                range: start..end,
+                username,
+            });
+            resume_at = end;
+        }
+    }
+
+    mentions
+}
+
+/// Name following an `@`, up to whitespace or the username length limit
+///
+/// Returns `None` when the name runs past the limit without punctuation that
+/// could end it, since no username can match then.
+fn candidate_name(rest: &str) -> Option<&str> {
+    let mut end = rest.len();
+    for (count, (idx, ch)) in rest.char_indices().enumerate() {
+        if ch.is_whitespace() || count == MAX_USERNAME_LENGTH {
+            end = idx;
+            break;
+        }
+    }
+
+    match rest[end..].chars().next() {
+        Some(next) if !next.is_whitespace() && !next.is_ascii_punctuation() => None,
+        _ => Some(&rest[..end]),
+    }
+}
+
+/// Look up `name`, dropping trailing punctuation until something matches
+///
+/// Returns the length in bytes of the matched name and the user it names.
+fn match_name(
+    mut name: &str,
+    lookup: &mut impl FnMut(&str) -> Option<String>,
+) -> Option<(usize, String)> {
+    while !name.is_empty() {
+        if let Some(username) = lookup(name) {
+            return Some((name.len(), username));
+        }
+        name = name.strip_suffix(|ch: char| ch.is_ascii_punctuation())?;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USERS: &[&str] = &["alice", "Bob", "bob.jr", "用户"];
+
+    fn lookup(name: &str) -> Option<String> {
+        USERS
+            .iter()
+            .find(|user| user.to_lowercase() == name.to_lowercase())
+            .map(|user| user.to_string())
+    }
+
+    fn mentioned(text: &str) -> Vec<String> {
+        find_mentions(text, lookup)
+            .into_iter()
+            .map(|mention| mention.username)
+            .collect()
+    }
+
+    #[test]
+    fn test_mentions_at_word_boundaries() {
+        assert_eq!(mentioned("@alice hi"), vec!["alice"]);
+        assert_eq!(mentioned("hi @BOB and (@alice)"), vec!["Bob", "alice"]);
+        assert_eq!(mentioned("ping @用户"), vec!["用户"]);
+        // Part of a word or an email address
+        assert!(mentioned("mail alice@example.com").is_empty());
+        assert!(mentioned("x@alice").is_empty());
+    }
+
+    #[test]
+    fn test_trailing_punctuation() {
+        assert_eq!(mentioned("@alice: look"), vec!["alice"]);
+        assert_eq!(mentioned("thanks @bob!!"), vec!["Bob"]);
+        // Punctuation that is part of a username wins over trimming
+        assert_eq!(mentioned("@bob.jr, hi"), vec!["bob.jr"]);
+        assert_eq!(mentioned("@bob. hi"), vec!["Bob"]);
+    }
+
+    #[test]
+    fn test_unknown_users_are_skipped() {
+        assert!(mentioned("@carol hi").is_empty());
+        assert!(mentioned("@ alice").is_empty());
+        assert!(mentioned("@alicex").is_empty());
+        assert_eq!(mentioned("@carol @alice @alice"), vec!["alice", "alice"]);
+    }
+
+    #[test]
+    fn test_mention_ranges() {
+        let text = "hey @Alice, and @bob";
+        let mentions = find_mentions(text, lookup);
+        assert_eq!(mentions.len(), 2);
+        assert_eq!(&text[mentions[0].range.clone()], "@Alice");
+        assert_eq!(&text[mentions[1].range.clone()], "@bob");
+    }
+
+    #[test]
+    fn test_long_names() {
+        let name = "a".repeat(MAX_USERNAME_LENGTH);
+        let is_long = |candidate: &str| (candidate == name).then(|| candidate.to_string());
+
+        assert_eq!(find_mentions(&format!("@{name}!!"), is_long).len(), 1);
+        assert!(find_mentions(&format!("@{name}a"), is_long).is_empty());
+        // Runs of @ and punctuation stay cheap and match nothing
+        assert!(mentioned(&"@".repeat(4096)).is_empty());
+    }
+}
@@ -20,12 +20,59 @@ fn default_locale() -> String {
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum ClientMessage {
+    /// Accept the server rules sent in `LoginResponse`
+    AcceptRules,
+    /// Send a prominent notice to every connected user (admin only)
+    AdminNotice { action: AdminNoticeAction },
+    /// Add a message to the end of the announcement rotation (admin only)
+    AnnouncementAdd { message: String },
+    /// List the announcement rotation (admin only)
+    AnnouncementList,
+    /// Remove an announcement by its position in the rotation (admin only)
+    AnnouncementRemove { index: u32 },
+    /// Request the message types and features the server supports
+    Capabilities,
+    /// Delete a recent #server chat message (own messages within the edit
+    /// window; moderators can delete any recent message)
+    ChatDelete { message_id: u64 },
+    /// Replace the text of one of the user's own recent #server chat messages
+    ChatEdit { message_id: u64, message: String },
+    /// Purge chat history beyond the configured retention now (admin only)
+    ChatHistoryPurge,
     /// Send a chat message to #server
     ChatSend { message: String },
     /// Update the chat topic
     ChatTopicUpdate { topic: String },
+    /// Request the recent connection history, optionally clearing it
+    /// afterwards (admin only)
+    ConnectionStats {
+        #[serde(default)]
+        reset: bool,
+    },
+    /// Publish this session's public key for end-to-end encrypted private
+    /// messages (the session then receives `E2eeKeys` for its own account)
+    E2eeKeyAnnounce { public_key: String },
+    /// Ask for a user's end-to-end encryption keys; the server answers with
+    /// `E2eeKeys` and sends that user's keys again whenever they change
+    E2eeKeysRequest { username: String },
+    /// Send an end-to-end encrypted private message, with one envelope for
+    /// every session of both the sender and the recipient
+    EncryptedUserMessage {
+        to_username: String,
+        envelopes: Vec<E2eeEnvelope>,
+    },
     /// Handshake - must be sent first
     Handshake { version: String },
+    /// Request a page of persisted #server chat history, newest first from
+    /// just before `before_message_id` (or from the newest message if omitted)
+    HistoryRequest {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        before_message_id: Option<u64>,
+        /// Messages wanted; the server caps this at `MAX_HISTORY_PAGE_SIZE`
+        limit: u32,
+    },
+    /// Disconnect one session (device) of a user, leaving their other sessions online
+    KickSession { session_id: u32 },
     /// Login request
     Login {
         username: String,
@@ -37,8 +84,48 @@ pub enum ClientMessage {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         avatar: Option<String>,
     },
+    /// Delete an info page (admin only)
+    PageDelete { name: String },
+    /// Fetch an info page by name (case-insensitive)
+    PageGet { name: String },
+    /// List the names of the server's info pages
+    PageList,
+    /// Create an info page or replace its content (admin only)
+    PageSet { name: String, content: String },
+    /// Keepalive probe; the server answers with `Pong` echoing the message ID
+    Ping,
+    /// Pin a recent chat message to the top of #server
+    PinMessage { message_id: u64 },
+    /// Remove a pinned chat message
+    UnpinMessage { message_id: u64 },
+    /// Re-read a user's permissions from the database and push them to their
+    /// online sessions (admin only)
+    RefreshUserPermissions { username: String },
+    /// Mark this session away or back (sent by the client's idle detection)
+    SetAway { away: bool },
+    /// Turn maintenance (read-only) mode on or off (admin only)
+    SetMaintenance { enabled: bool },
+    /// Store the user's synced preferences on the server (replaces any stored value)
+    SetPreferences { preferences: serde_json::Value },
+    /// Ask when a user was last online
+    SeenRequest { username: String },
+    /// Set the user's public profile (empty fields clear them)
+    SetProfile { bio: String, pronouns: String },
+    /// Make another account an admin and, with `demote_self`, step down in the
+    /// same transaction (admin only)
+    TransferAdmin {
+        to_username: String,
+        demote_self: bool,
+    },
     /// Broadcast a message to all connected users
-    UserBroadcast { message: String },
+    ///
+    /// When `target_permission` is set, only users holding that permission
+    /// (and admins) receive it.
+    UserBroadcast {
+        message: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_permission: Option<String>,
+    },
     /// Create a new user account
     UserCreate {
         username: String,
@@ -67,6 +154,9 @@ pub enum ClientMessage {
         to_username: String,
         message: String,
     },
+    /// Request the current session's account name, admin status, effective
+    /// permissions and features (always allowed once logged in)
+    WhoAmI,
     /// Update a user account
     UserUpdate {
         username: String,
@@ -92,6 +182,15 @@ pub enum ClientMessage {
         /// Server image (logo/banner) as base64-encoded data URI
         #[serde(skip_serializing_if = "Option::is_none")]
         image: Option<String>,
+        /// Minimum seconds between chat messages per user (0 = disabled)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        chat_slow_mode: Option<u32>,
+        /// Maximum message length in characters (chat, broadcast, private messages)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_message_length: Option<u32>,
+        /// Maximum chat topic length in characters
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_topic_length: Option<u32>,
     },
 }
 
@@ -99,11 +198,97 @@ pub enum ClientMessage {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum ServerMessage {
+    /// Accept rules response
+    AcceptRulesResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Notice from an admin (sent to every connected user), shown apart from chat
+    AdminNotice { action: AdminNoticeAction },
+    /// Admin notice response
+    AdminNoticeResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Announcement add response
+    AnnouncementAddResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Announcement list response (in rotation order)
+    AnnouncementListResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        announcements: Option<Vec<String>>,
+        /// Minutes between announcements
+        #[serde(skip_serializing_if = "Option::is_none")]
+        interval_minutes: Option<u32>,
+    },
+    /// Announcement remove response
+    AnnouncementRemoveResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Capabilities response (message types the server understands and its features)
+    CapabilitiesResponse {
+        message_types: Vec<String>,
+        features: Vec<String>,
+    },
+    /// Chat delete response
+    ChatDeleteResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Chat edit response
+    ChatEditResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Chat history purge response (`removed` is the number of rows deleted)
+    ChatHistoryPurgeResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        removed: Option<u64>,
+    },
     /// Chat message
     ChatMessage {
         session_id: u32,
         username: String,
         message: String,
+        /// Server-assigned id used to edit, delete, or pin the message; ids
+        /// are unique and increasing (older servers never send this)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message_id: Option<u64>,
+        /// Online users mentioned with `@username`, as the server spells them
+        /// (see [`crate::mentions`])
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        mentions: Option<Vec<String>>,
+    },
+    /// Chat message deleted broadcast (sent to users who can receive chat)
+    ChatMessageDeleted {
+        message_id: u64,
+        /// A moderator removed someone else's message (older servers never
+        /// send this)
+        #[serde(default)]
+        by_moderator: bool,
+    },
+    /// Chat message edited broadcast (sent to users who can receive chat)
+    ChatMessageEdited {
+        message_id: u64,
+        message: String,
+        /// Online users mentioned in the new text, as in `ChatMessage`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        mentions: Option<Vec<String>>,
     },
     /// Chat topic updated broadcast (sent to users with ChatTopic permission when topic changes)
     ChatTopicUpdated { topic: String, username: String },
@@ -113,11 +298,57 @@ pub enum ServerMessage {
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
     },
+    /// Connection stats response: connections open now and the recent
+    /// history, oldest bucket first
+    ConnectionStatsResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        current: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        buckets: Option<Vec<ConnectionStatsBucket>>,
+    },
+    /// A user's end-to-end encryption keys, one per session
+    ///
+    /// Sent in answer to `E2eeKeysRequest` and again whenever the user's
+    /// sessions change. `keys` is empty when the user can't receive encrypted
+    /// messages (offline, or a session without a key).
+    E2eeKeys {
+        username: String,
+        keys: Vec<E2eeSessionKey>,
+    },
+    /// An end-to-end encrypted private message, carrying only the envelope
+    /// for the receiving session (the server can't read it)
+    EncryptedUserMessage {
+        from_username: String,
+        from_admin: bool,
+        to_username: String,
+        envelope: E2eeEnvelope,
+        /// Server-assigned id, drawn from the same sequence as chat message ids
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message_id: Option<u64>,
+    },
     /// Error message
     Error {
         message: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         command: Option<String>,
+        /// Machine-readable reason (e.g. `ERROR_CODE_ACCOUNT_DISABLED`), so clients
+        /// don't have to match on translated text
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<String>,
+    },
+    /// History page response, oldest message first (`has_more` is false once
+    /// the start of the persisted history is reached)
+    HistoryResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        messages: Option<Vec<HistoryMessage>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        has_more: Option<bool>,
     },
     /// Handshake response
     HandshakeResponse {
@@ -126,6 +357,18 @@ pub enum ServerMessage {
         version: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
+        /// Notice to show before logging in (only sent when the server has one)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        banner: Option<String>,
+        /// Machine-readable failure reason (e.g. `ERROR_CODE_CLIENT_TOO_OLD`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<String>,
+    },
+    /// Kick session response
+    KickSessionResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
     },
     /// Login response
     LoginResponse {
@@ -144,7 +387,159 @@ pub enum ServerMessage {
         chat_info: Option<ChatInfo>,
         #[serde(skip_serializing_if = "Option::is_none")]
         locale: Option<String>,
+        /// Currently pinned chat messages (only sent to users who can receive chat)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pinned_messages: Option<Vec<PinnedMessage>>,
+        /// Preferences previously stored with `SetPreferences` (absent if never set)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        preferences: Option<serde_json::Value>,
+        /// Server rules the user must accept (with `AcceptRules`) before chatting
+        ///
+        /// Only sent when the server has rules and this account hasn't accepted them.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rules: Option<String>,
+        /// Whether the server is in maintenance (read-only) mode
+        ///
+        /// Absent from older servers, which have no maintenance mode.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        maintenance: Option<bool>,
+        /// Permissions the server has disabled for this user, regardless of grants
+        ///
+        /// Only sent when something is disabled; clients hide the matching UI.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        disabled_permissions: Option<Vec<String>>,
+    },
+    /// Maintenance mode turned on or off (sent to every connected user)
+    MaintenanceChanged { enabled: bool },
+    /// Chat limits changed at runtime (sent to every connected user)
+    ///
+    /// Carries every limit, so clients replace the values they got at login.
+    LimitsUpdated {
+        /// Maximum message length in characters (chat, broadcast, private messages)
+        max_message_length: u32,
+        /// Maximum chat topic length in characters
+        max_topic_length: u32,
+        /// Minimum seconds between chat messages per user (0 = disabled)
+        chat_slow_mode: u32,
+    },
+    /// The account logged in again (sent only to the account's other sessions)
+    NewLogin {
+        session_id: u32,
+        /// IP address of the new session
+        address: String,
+        /// Source tag of the new session (e.g. "lan", "internet")
+        source: String,
+    },
+    /// Page delete response
+    PageDeleteResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Page get response
+    PageGetResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Page name as the admin saved it
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        content: Option<String>,
+    },
+    /// Page list response (names in alphabetical order)
+    PageListResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pages: Option<Vec<String>>,
+    },
+    /// Page set response
+    PageSetResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Keepalive reply to `Ping`
+    Pong,
+    /// Pin message response
+    PinMessageResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Pinned messages changed broadcast (sent to users who can receive chat)
+    PinnedMessagesUpdated { pinned_messages: Vec<PinnedMessage> },
+    /// Refresh user permissions response
+    RefreshUserPermissionsResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// WhoAmI response (permissions are re-read from the database)
+    WhoAmIResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        is_admin: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        permissions: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        features: Option<Vec<String>>,
     },
+    /// Seen response: whether the user is online, or when they were last seen
+    SeenResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Username as stored on the server
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        online: Option<bool>,
+        /// Unix timestamp of the last login or logout (absent if never logged in)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        last_seen: Option<i64>,
+    },
+    /// Transfer admin response
+    TransferAdminResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// New admin's username as stored on the server
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+    },
+    /// Set maintenance response
+    SetMaintenanceResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Set preferences response
+    SetPreferencesResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Set profile response
+    SetProfileResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Unpin message response
+    UnpinMessageResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Rotating announcement from the server (sent to every connected user)
+    ServerAnnouncement { message: String },
     /// Broadcast message from another user
     ServerBroadcast {
         session_id: u32,
@@ -154,10 +549,15 @@ pub enum ServerMessage {
     /// User connected event
     UserConnected { user: UserInfo },
     /// User create response
+    ///
+    /// `warning` names permission prerequisites the server added or found
+    /// missing, for the admin to review.
     UserCreateResponse {
         success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        warning: Option<String>,
     },
     /// User delete response
     UserDeleteResponse {
@@ -232,6 +632,10 @@ pub enum ServerMessage {
         from_admin: bool,
         to_username: String,
         message: String,
+        /// Server-assigned id, drawn from the same sequence as chat message
+        /// ids (older servers never send this)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message_id: Option<u64>,
     },
     /// User message response
     UserMessageResponse {
@@ -245,10 +649,15 @@ pub enum ServerMessage {
         user: UserInfo,
     },
     /// User update response
+    ///
+    /// `warning` names permission prerequisites the server added or found
+    /// missing, for the admin to review.
     UserUpdateResponse {
         success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        warning: Option<String>,
     },
 }
 
@@ -270,6 +679,26 @@ pub struct ServerInfo {
     /// Server image (logo/banner) as base64-encoded data URI
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub image: Option<String>,
+    /// Minimum seconds between chat messages per user (0 = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chat_slow_mode: Option<u32>,
+    /// Number of unique users currently online
+    ///
+    /// Only sent to users who can already see the user list (`user_list`
+    /// permission or admin), so servers that hide the list don't leak the count.
+    /// Older servers never send this field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u32>,
+    /// Maximum message length in characters (chat, broadcast, private messages)
+    ///
+    /// Older servers never send this field; clients fall back to the default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_message_length: Option<u32>,
+    /// Maximum chat topic length in characters
+    ///
+    /// Older servers never send this field; clients fall back to the default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_topic_length: Option<u32>,
 }
 
 /// Chat room information (topic, etc.)
@@ -281,17 +710,148 @@ pub struct ChatInfo {
     pub topic_set_by: String,
 }
 
+/// Maximum length of an error code in `ServerMessage::Error`
+pub const MAX_ERROR_CODE_LENGTH: usize = 32;
+
+/// Error code sent when an admin disables the account of a connected user
+pub const ERROR_CODE_ACCOUNT_DISABLED: &str = "account_disabled";
+
+/// Error code sent to a session just before a moderator kicks it
+pub const ERROR_CODE_KICKED: &str = "kicked";
+
+/// Error code sent to a session just before a newer login on the same account
+/// replaces it (the server caps sessions per account)
+pub const ERROR_CODE_SESSION_REPLACED: &str = "session_replaced";
+
+/// Error code sent when a user must accept the server rules before chatting
+pub const ERROR_CODE_RULES_NOT_ACCEPTED: &str = "rules_not_accepted";
+
+/// Handshake error code sent when the server requires a newer client than
+/// the one connecting, so clients can ask the user to upgrade
+pub const ERROR_CODE_CLIENT_TOO_OLD: &str = "client_too_old";
+
+/// Error code sent when a request's payload was over its type's size limit
+/// and skipped (the connection stays open)
+pub const ERROR_CODE_PAYLOAD_TOO_LARGE: &str = "payload_too_large";
+
+/// Maximum number of chat messages that can be pinned at once
+pub const MAX_PINNED_MESSAGES: usize = 3;
+
+/// Maximum number of announcements in the rotation
+pub const MAX_ANNOUNCEMENTS: usize = 10;
+
+/// Maximum number of info pages a server can store
+pub const MAX_PAGES: usize = 50;
+
+/// Maximum number of buckets in a connection stats response
+pub const MAX_CONNECTION_STATS_BUCKETS: usize = 60;
+
+/// Maximum number of messages in one history page
+pub const MAX_HISTORY_PAGE_SIZE: usize = 50;
+
+/// A persisted #server chat message returned by `HistoryRequest`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryMessage {
+    /// Server-assigned id of the chat message
+    pub message_id: u64,
+    /// Username who sent the message
+    pub username: String,
+    /// The message text (as last edited)
+    pub message: String,
+    /// When the message was sent (Unix timestamp in seconds)
+    pub timestamp: i64,
+}
+
+/// Connection counts for one interval of the server's recent history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionStatsBucket {
+    /// Start of the interval (Unix timestamp in seconds)
+    pub start: i64,
+    /// Length of the interval in seconds
+    pub seconds: u32,
+    /// Connections accepted
+    pub connections: u32,
+    /// Connections turned away (per-address limit or a temporary block)
+    pub refused: u32,
+    /// Most connections open at once
+    pub peak: u32,
+    /// Most connections open at once from a single address
+    pub peak_per_ip: u32,
+}
+
+/// What an admin notice asks of users
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AdminNoticeAction {
+    /// Reconnect to the server (e.g. after maintenance)
+    Reconnect,
+    /// Update the client from `url`
+    Update { url: String },
+    /// Read a message
+    Notice { message: String },
+}
+
+/// One session's copy of an end-to-end encrypted private message
+///
+/// The sender derives a key from a fresh X25519 key pair and the session's
+/// public key, and seals the message with ChaCha20-Poly1305.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct E2eeEnvelope {
+    /// Session the envelope is for
+    pub session_id: u32,
+    /// Sender's one-time X25519 public key (base64)
+    pub ephemeral_key: String,
+    /// Sealed message text with its authentication tag (base64)
+    pub ciphertext: String,
+}
+
+/// A session's public key for end-to-end encrypted private messages
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct E2eeSessionKey {
+    pub session_id: u32,
+    /// X25519 public key (base64)
+    pub public_key: String,
+}
+
+/// A chat message pinned to the top of #server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PinnedMessage {
+    /// Server-assigned id of the original chat message
+    pub message_id: u64,
+    /// Username who sent the original message
+    pub username: String,
+    /// The message text
+    pub message: String,
+    /// Username who pinned the message
+    pub pinned_by: String,
+}
+
 /// Information about a connected user (basic info for lists)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserInfo {
     pub username: String,
     pub login_time: i64,
     pub is_admin: bool,
+    /// Holds `chat_moderate` without being an admin (always false for offline users)
+    #[serde(default)]
+    pub is_moderator: bool,
+    /// Every online session is away (always false for offline users)
+    #[serde(default)]
+    pub away: bool,
+    /// Logged in as a guest, without an account (always false for offline users)
+    #[serde(default)]
+    pub is_guest: bool,
     pub session_ids: Vec<u32>,
     pub locale: String,
     /// User's avatar as a data URI (ephemeral, from most recent login)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub avatar: Option<String>,
+    /// Which of `NOTABLE_PERMISSIONS` the user holds
+    ///
+    /// Only sent to admins and users with `user_info`, and only for online
+    /// non-admin users (admins hold them all).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<String>>,
 }
 
 /// Detailed information about a user (for UserInfo command)
@@ -301,25 +861,54 @@ pub struct UserInfoDetailed {
     pub login_time: i64,
     pub session_ids: Vec<u32>,
     pub features: Vec<String>,
-    /// When the account was created (Unix timestamp)
+    /// When the account was created (Unix timestamp; a guest's login time)
     pub created_at: i64,
+    /// Logged in as a guest, without an account
+    #[serde(default)]
+    pub is_guest: bool,
     /// User's preferred locale
     pub locale: String,
     /// User's avatar as a data URI (ephemeral, from most recent login)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub avatar: Option<String>,
+    /// User's pronouns from their profile (absent if not set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<String>,
+    /// User's bio from their profile (absent if not set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
     /// Only included for admins viewing the info
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_admin: Option<bool>,
     /// Only included for admins viewing the info (one per session)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub addresses: Option<Vec<String>>,
+    /// Connection source tag per session, in the same order as `addresses`
+    /// (only included for admins viewing the info)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<String>>,
 }
 
 // Custom Debug implementation that redacts passwords
 impl std::fmt::Debug for ClientMessage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            ClientMessage::AcceptRules => f.debug_struct("AcceptRules").finish(),
+            ClientMessage::AdminNotice { action } => f
+                .debug_struct("AdminNotice")
+                .field("action", action)
+                .finish(),
+            ClientMessage::AnnouncementAdd { message } => f
+                .debug_struct("AnnouncementAdd")
+                .field("message", message)
+                .finish(),
+            ClientMessage::AnnouncementList => f.debug_struct("AnnouncementList").finish(),
+            ClientMessage::AnnouncementRemove { index } => f
+                .debug_struct("AnnouncementRemove")
+                .field("index", index)
+                .finish(),
+            ClientMessage::Capabilities => f.debug_struct("Capabilities").finish(),
+            ClientMessage::ChatHistoryPurge => f.debug_struct("ChatHistoryPurge").finish(),
             ClientMessage::ChatSend { message } => f
                 .debug_struct("ChatSend")
                 .field("message", message)
@@ -328,6 +917,34 @@ impl std::fmt::Debug for ClientMessage {
                 .debug_struct("ChatTopicUpdate")
                 .field("topic", topic)
                 .finish(),
+            ClientMessage::ConnectionStats { reset } => f
+                .debug_struct("ConnectionStats")
+                .field("reset", reset)
+                .finish(),
+            ClientMessage::E2eeKeyAnnounce { public_key } => f
+                .debug_struct("E2eeKeyAnnounce")
+                .field("public_key", public_key)
+                .finish(),
+            ClientMessage::E2eeKeysRequest { username } => f
+                .debug_struct("E2eeKeysRequest")
+                .field("username", username)
+                .finish(),
+            ClientMessage::EncryptedUserMessage {
+                to_username,
+                envelopes,
+            } => f
+                .debug_struct("EncryptedUserMessage")
+                .field("to_username", to_username)
+                .field("envelopes", &format!("<{} envelopes>", envelopes.len()))
+                .finish(),
+            ClientMessage::HistoryRequest {
+                before_message_id,
+                limit,
+            } => f
+                .debug_struct("HistoryRequest")
+                .field("before_message_id", before_message_id)
+                .field("limit", limit)
+                .finish(),
             ClientMessage::Handshake { version } => f
                 .debug_struct("Handshake")
                 .field("version", version)
@@ -355,9 +972,78 @@ impl std::fmt::Debug for ClientMessage {
                     }),
                 )
                 .finish(),
-            ClientMessage::UserBroadcast { message } => f
+            ClientMessage::ChatDelete { message_id } => f
+                .debug_struct("ChatDelete")
+                .field("message_id", message_id)
+                .finish(),
+            ClientMessage::ChatEdit {
+                message_id,
+                message,
+            } => f
+                .debug_struct("ChatEdit")
+                .field("message_id", message_id)
+                .field("message", message)
+                .finish(),
+            ClientMessage::KickSession { session_id } => f
+                .debug_struct("KickSession")
+                .field("session_id", session_id)
+                .finish(),
+            ClientMessage::PageDelete { name } => {
+                f.debug_struct("PageDelete").field("name", name).finish()
+            }
+            ClientMessage::PageGet { name } => {
+                f.debug_struct("PageGet").field("name", name).finish()
+            }
+            ClientMessage::PageList => f.debug_struct("PageList").finish(),
+            ClientMessage::PageSet { name, content } => f
+                .debug_struct("PageSet")
+                .field("name", name)
+                .field("content", content)
+                .finish(),
+            ClientMessage::Ping => f.debug_struct("Ping").finish(),
+            ClientMessage::PinMessage { message_id } => f
+                .debug_struct("PinMessage")
+                .field("message_id", message_id)
+                .finish(),
+            ClientMessage::UnpinMessage { message_id } => f
+                .debug_struct("UnpinMessage")
+                .field("message_id", message_id)
+                .finish(),
+            ClientMessage::SetAway { away } => {
+                f.debug_struct("SetAway").field("away", away).finish()
+            }
+            ClientMessage::SetMaintenance { enabled } => f
+                .debug_struct("SetMaintenance")
+                .field("enabled", enabled)
+                .finish(),
+            ClientMessage::SetPreferences { preferences } => f
+                .debug_struct("SetPreferences")
+                .field("preferences", preferences)
+                .finish(),
+            ClientMessage::SeenRequest { username } => f
+                .debug_struct("SeenRequest")
+                .field("username", username)
+                .finish(),
+            ClientMessage::TransferAdmin {
+                to_username,
+                demote_self,
+            } => f
+                .debug_struct("TransferAdmin")
+                .field("to_username", to_username)
+                .field("demote_self", demote_self)
+                .finish(),
+            ClientMessage::SetProfile { bio, pronouns } => f
+                .debug_struct("SetProfile")
+                .field("bio", bio)
+                .field("pronouns", pronouns)
+                .finish(),
+            ClientMessage::UserBroadcast {
+                message,
+                target_permission,
+            } => f
                 .debug_struct("UserBroadcast")
                 .field("message", message)
+                .field("target_permission", target_permission)
                 .finish(),
             ClientMessage::UserCreate {
                 username,
@@ -383,6 +1069,10 @@ impl std::fmt::Debug for ClientMessage {
                 .debug_struct("UserInfo")
                 .field("username", username)
                 .finish(),
+            ClientMessage::RefreshUserPermissions { username } => f
+                .debug_struct("RefreshUserPermissions")
+                .field("username", username)
+                .finish(),
             ClientMessage::UserKick { username } => f
                 .debug_struct("UserKick")
                 .field("username", username)
@@ -398,6 +1088,7 @@ impl std::fmt::Debug for ClientMessage {
                 .field("to_username", to_username)
                 .field("message", message)
                 .finish(),
+            ClientMessage::WhoAmI => f.debug_struct("WhoAmI").finish(),
             ClientMessage::UserUpdate {
                 username,
                 requested_username,
@@ -419,6 +1110,9 @@ impl std::fmt::Debug for ClientMessage {
                 description,
                 max_connections_per_ip,
                 image,
+                chat_slow_mode,
+                max_message_length,
+                max_topic_length,
             } => {
                 let mut s = f.debug_struct("ServerInfoUpdate");
                 s.field("name", name)
@@ -437,6 +1131,9 @@ impl std::fmt::Debug for ClientMessage {
                 } else {
                     s.field("image", &None::<String>);
                 }
+                s.field("chat_slow_mode", chat_slow_mode)
+                    .field("max_message_length", max_message_length)
+                    .field("max_topic_length", max_topic_length);
                 s.finish()
             }
         }
@@ -520,6 +1217,11 @@ mod tests {
             chat_info: None,
             locale: Some("en".to_string()),
             error: None,
+            pinned_messages: None,
+            preferences: None,
+            rules: None,
+            maintenance: None,
+            disabled_permissions: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"LoginResponse\""));
@@ -538,6 +1240,11 @@ mod tests {
             chat_info: None,
             locale: None,
             error: Some("Invalid credentials".to_string()),
+            pinned_messages: None,
+            preferences: None,
+            rules: None,
+            maintenance: None,
+            disabled_permissions: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"success\":false"));
@@ -555,6 +1262,11 @@ mod tests {
             chat_info: None,
             locale: Some("en".to_string()),
             error: None,
+            pinned_messages: None,
+            preferences: None,
+            rules: None,
+            maintenance: None,
+            disabled_permissions: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"LoginResponse\""));
@@ -574,6 +1286,11 @@ mod tests {
             chat_info: None,
             locale: Some("en".to_string()),
             error: None,
+            pinned_messages: None,
+            preferences: None,
+            rules: None,
+            maintenance: None,
+            disabled_permissions: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"LoginResponse\""));
@@ -621,24 +1338,42 @@ mod tests {
             username: "alice".to_string(),
             login_time: 1234567890,
             is_admin: false,
+            is_moderator: false,
+            away: false,
+            is_guest: false,
             session_ids: vec![1],
             locale: "en".to_string(),
             avatar: Some(avatar_data.clone()),
+            permissions: None,
         };
         let json = serde_json::to_string(&user_info).unwrap();
         assert!(json.contains("\"avatar\""));
         assert!(json.contains(&avatar_data));
     }
 
+    #[test]
+    fn test_deserialize_user_info_without_moderator_or_away() {
+        // Older servers send neither the moderator, away, nor guest flag
+        let json = r#"{"username":"alice","login_time":1,"is_admin":false,"session_ids":[1],"locale":"en"}"#;
+        let user_info: UserInfo = serde_json::from_str(json).unwrap();
+        assert!(!user_info.is_moderator);
+        assert!(!user_info.away);
+        assert!(!user_info.is_guest);
+    }
+
     #[test]
     fn test_serialize_user_info_without_avatar() {
         let user_info = UserInfo {
             username: "alice".to_string(),
             login_time: 1234567890,
             is_admin: false,
+            is_moderator: false,
+            away: false,
+            is_guest: false,
             session_ids: vec![1],
             locale: "en".to_string(),
             avatar: None,
+            permissions: None,
         };
         let json = serde_json::to_string(&user_info).unwrap();
         // avatar should not be in JSON when None (skip_serializing_if)
@@ -654,14 +1389,57 @@ mod tests {
             session_ids: vec![1, 2],
             features: vec!["chat".to_string()],
             created_at: 1234567800,
+            is_guest: false,
             locale: "en".to_string(),
             avatar: Some(avatar_data.clone()),
+            pronouns: None,
+            bio: None,
             is_admin: Some(false),
             addresses: None,
+            sources: None,
         };
         let json = serde_json::to_string(&user_info).unwrap();
         assert!(json.contains("\"avatar\""));
         assert!(json.contains(&avatar_data));
+        // Unset profile fields are omitted
+        assert!(!json.contains("\"pronouns\""));
+        assert!(!json.contains("\"bio\""));
+    }
+
+    #[test]
+    fn test_user_info_detailed_profile_roundtrip() {
+        let user_info = UserInfoDetailed {
+            username: "alice".to_string(),
+            login_time: 1234567890,
+            session_ids: vec![1],
+            features: vec![],
+            created_at: 1234567800,
+            is_guest: false,
+            locale: "en".to_string(),
+            avatar: None,
+            pronouns: Some("she/her".to_string()),
+            bio: Some("Retro computing fan".to_string()),
+            is_admin: Some(false),
+            addresses: None,
+            sources: None,
+        };
+        let json = serde_json::to_string(&user_info).unwrap();
+        let parsed: UserInfoDetailed = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.pronouns.as_deref(), Some("she/her"));
+        assert_eq!(parsed.bio.as_deref(), Some("Retro computing fan"));
+    }
+
+    #[test]
+    fn test_serialize_set_profile() {
+        let msg = ClientMessage::SetProfile {
+            bio: "Hello".to_string(),
+            pronouns: "they/them".to_string(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"SetProfile","bio":"Hello","pronouns":"they/them"}"#
+        );
     }
 
     #[test]
@@ -683,4 +1461,298 @@ mod tests {
         // Should NOT contain the full avatar
         assert!(!debug_output.contains(&large_avatar));
     }
+
+    #[test]
+    fn test_deserialize_chat_message_without_message_id() {
+        // Older servers don't assign message ids
+        let json = r#"{"type":"ChatMessage","session_id":1,"username":"alice","message":"hi"}"#;
+        let msg: ServerMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ServerMessage::ChatMessage {
+                message_id,
+                mentions,
+                ..
+            } => {
+                assert_eq!(message_id, None);
+                assert_eq!(mentions, None);
+            }
+            _ => panic!("Expected ChatMessage"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_user_message_without_message_id() {
+        // Older servers don't assign message ids to private messages
+        let json = r#"{"type":"UserMessage","from_username":"alice","from_admin":false,"to_username":"bob","message":"hi"}"#;
+        let msg: ServerMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ServerMessage::UserMessage { message_id, .. } => assert_eq!(message_id, None),
+            _ => panic!("Expected UserMessage"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_handshake_response_without_banner() {
+        // Older servers have no pre-login banner
+        let json = r#"{"type":"HandshakeResponse","success":true,"version":"0.5.0"}"#;
+        let msg: ServerMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ServerMessage::HandshakeResponse { banner, .. } => assert_eq!(banner, None),
+            _ => panic!("Expected HandshakeResponse"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_pinned_messages_updated() {
+        let msg = ServerMessage::PinnedMessagesUpdated {
+            pinned_messages: vec![PinnedMessage {
+                message_id: 7,
+                username: "alice".to_string(),
+                message: "Maintenance at 10pm".to_string(),
+                pinned_by: "admin".to_string(),
+            }],
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"PinnedMessagesUpdated\""));
+        assert!(json.contains("\"message_id\":7"));
+        assert!(json.contains("\"pinned_by\":\"admin\""));
+    }
+
+    #[test]
+    fn test_deserialize_user_broadcast_without_target() {
+        // Older clients always broadcast to everyone
+        let json = r#"{"type":"UserBroadcast","message":"hi"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ClientMessage::UserBroadcast {
+                target_permission, ..
+            } => assert_eq!(target_permission, None),
+            _ => panic!("Expected UserBroadcast"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_admin_notice() {
+        let msg = ServerMessage::AdminNotice {
+            action: AdminNoticeAction::Update {
+                url: "https://example.com".to_string(),
+            },
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"AdminNotice","action":{"kind":"update","url":"https://example.com"}}"#
+        );
+
+        let json = r#"{"type":"AdminNotice","action":{"kind":"reconnect"}}"#;
+        match serde_json::from_str::<ClientMessage>(json).unwrap() {
+            ClientMessage::AdminNotice { action } => {
+                assert_eq!(action, AdminNoticeAction::Reconnect)
+            }
+            _ => panic!("Expected AdminNotice"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_capabilities() {
+        let json = serde_json::to_string(&ClientMessage::Capabilities).unwrap();
+        assert_eq!(json, r#"{"type":"Capabilities"}"#);
+
+        let msg: ClientMessage = serde_json::from_str(&json).unwrap();
+        assert!(matches!(msg, ClientMessage::Capabilities));
+    }
+
+    #[test]
+    fn test_serialize_page_set() {
+        let msg = ClientMessage::PageSet {
+            name: "news".to_string(),
+            content: "Line one\nLine two".to_string(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"PageSet","name":"news","content":"Line one\nLine two"}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_page_get_response_failure() {
+        let json = r#"{"type":"PageGetResponse","success":false,"error":"Not found"}"#;
+        let msg: ServerMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ServerMessage::PageGetResponse {
+                success,
+                error,
+                name,
+                content,
+            } => {
+                assert!(!success);
+                assert_eq!(error.as_deref(), Some("Not found"));
+                assert!(name.is_none());
+                assert!(content.is_none());
+            }
+            _ => panic!("Expected PageGetResponse"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_connection_stats_without_reset() {
+        let json = r#"{"type":"ConnectionStats"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            ClientMessage::ConnectionStats { reset: false }
+        ));
+    }
+
+    #[test]
+    fn test_serialize_connection_stats_response() {
+        let msg = ServerMessage::ConnectionStatsResponse {
+            success: true,
+            error: None,
+            current: Some(3),
+            buckets: Some(vec![ConnectionStatsBucket {
+                start: 1_700_000_000,
+                seconds: 60,
+                connections: 5,
+                refused: 1,
+                peak: 4,
+                peak_per_ip: 2,
+            }]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"ConnectionStatsResponse","success":true,"current":3,"buckets":[{"start":1700000000,"seconds":60,"connections":5,"refused":1,"peak":4,"peak_per_ip":2}]}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_history_request() {
+        let msg = ClientMessage::HistoryRequest {
+            before_message_id: None,
+            limit: 50,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(json, r#"{"type":"HistoryRequest","limit":50}"#);
+
+        let msg = ClientMessage::HistoryRequest {
+            before_message_id: Some(42),
+            limit: 10,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"HistoryRequest","before_message_id":42,"limit":10}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_history_response() {
+        let msg = ServerMessage::HistoryResponse {
+            success: true,
+            error: None,
+            messages: Some(vec![HistoryMessage {
+                message_id: 7,
+                username: "alice".to_string(),
+                message: "hi".to_string(),
+                timestamp: 1_700_000_000,
+            }]),
+            has_more: Some(false),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"HistoryResponse","success":true,"messages":[{"message_id":7,"username":"alice","message":"hi","timestamp":1700000000}],"has_more":false}"#
+        );
+    }
+
+    #[test]
+    fn test_user_update_response_warning_is_optional() {
+        let msg = ServerMessage::UserUpdateResponse {
+            success: true,
+            error: None,
+            warning: Some("Also granted: chat_receive".to_string()),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"UserUpdateResponse","success":true,"warning":"Also granted: chat_receive"}"#
+        );
+
+        // Older servers never send a warning
+        let parsed: ServerMessage =
+            serde_json::from_str(r#"{"type":"UserUpdateResponse","success":true}"#).unwrap();
+        assert!(matches!(
+            parsed,
+            ServerMessage::UserUpdateResponse { warning: None, .. }
+        ));
+    }
+
+    #[test]
+    fn test_serialize_set_away() {
+        let msg = ClientMessage::SetAway { away: true };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(json, r#"{"type":"SetAway","away":true}"#);
+    }
+
+    #[test]
+    fn test_serialize_transfer_admin() {
+        let msg = ClientMessage::TransferAdmin {
+            to_username: "bob".to_string(),
+            demote_self: true,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"TransferAdmin","to_username":"bob","demote_self":true}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_seen_response() {
+        let msg = ServerMessage::SeenResponse {
+            success: true,
+            error: None,
+            username: Some("alice".to_string()),
+            online: Some(false),
+            last_seen: None,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"SeenResponse","success":true,"username":"alice","online":false}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_who_am_i_response() {
+        let msg = ServerMessage::WhoAmIResponse {
+            success: true,
+            error: None,
+            username: Some("alice".to_string()),
+            is_admin: Some(false),
+            permissions: Some(vec!["chat_send".to_string()]),
+            features: Some(vec![]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"WhoAmIResponse","success":true,"username":"alice","is_admin":false,"permissions":["chat_send"],"features":[]}"#
+        );
+
+        let json = serde_json::to_string(&ClientMessage::WhoAmI).unwrap();
+        assert_eq!(json, r#"{"type":"WhoAmI"}"#);
+    }
+
+    #[test]
+    fn test_deserialize_error_without_code() {
+        // Older servers only send the translated message
+        let json = r#"{"type":"Error","message":"Account disabled"}"#;
+        let msg: ServerMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ServerMessage::Error { code, .. } => assert_eq!(code, None),
+            _ => panic!("Expected Error"),
+        }
+    }
 }
@@ -0,0 +1,121 @@
+//! Bio validation
+//!
+//! Validates user profile bios.
+
+/// Maximum length for a bio in characters
+pub const MAX_BIO_LENGTH: usize = 256;
+
+/// Validation error for a bio
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BioError {
+    /// Bio exceeds maximum length
+    TooLong,
+    /// Bio contains newline characters
+    ContainsNewlines,
+    /// Bio contains invalid characters
+    InvalidCharacters,
+}
+
+/// Validate a bio
+///
+/// Checks:
+/// - Does not exceed maximum length (256 characters)
+/// - No control characters (newlines reported separately)
+///
+/// Note: An empty bio is allowed (to clear it).
+///
+/// # Errors
+///
+/// Returns a `BioError` variant describing the validation failure.
+pub fn validate_bio(bio: &str) -> Result<(), BioError> {
+    if bio.chars().count() > MAX_BIO_LENGTH {
+        return Err(BioError::TooLong);
+    }
+    for ch in bio.chars() {
+        if ch.is_control() {
+            if ch == '\n' || ch == '\r' {
+                return Err(BioError::ContainsNewlines);
+            }
+            return Err(BioError::InvalidCharacters);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_bio() {
+        assert!(validate_bio("Retro computing fan and BBS sysop").is_ok());
+        assert!(validate_bio(&"a".repeat(MAX_BIO_LENGTH)).is_ok());
+        // Unicode
+        assert!(validate_bio("日本語の自己紹介").is_ok());
+        assert!(validate_bio("Люблю старые компьютеры").is_ok());
+        // Emoji
+        assert!(validate_bio("Hello! 🎉").is_ok());
+    }
+
+    #[test]
+    fn test_empty_allowed() {
+        assert!(validate_bio("").is_ok());
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_bio(&"a".repeat(MAX_BIO_LENGTH + 1)),
+            Err(BioError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_length_counts_characters() {
+        let emoji = "😀".repeat(MAX_BIO_LENGTH);
+        assert!(validate_bio(&emoji).is_ok());
+        assert_eq!(
+            validate_bio(&"語".repeat(MAX_BIO_LENGTH + 1)),
+            Err(BioError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_newlines() {
+        assert_eq!(
+            validate_bio("Line1\nLine2"),
+            Err(BioError::ContainsNewlines)
+        );
+        assert_eq!(
+            validate_bio("Line1\rLine2"),
+            Err(BioError::ContainsNewlines)
+        );
+        assert_eq!(
+            validate_bio("Line1\r\nLine2"),
+            Err(BioError::ContainsNewlines)
+        );
+    }
+
+    #[test]
+    fn test_control_characters() {
+        // Null byte
+        assert_eq!(
+            validate_bio("Hello\0World"),
+            Err(BioError::InvalidCharacters)
+        );
+        // Tab
+        assert_eq!(
+            validate_bio("Hello\tWorld"),
+            Err(BioError::InvalidCharacters)
+        );
+        // Other control characters
+        assert_eq!(
+            validate_bio("Test\x01Control"),
+            Err(BioError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_bio("Test\x7FDelete"),
+            Err(BioError::InvalidCharacters)
+        );
+    }
+}
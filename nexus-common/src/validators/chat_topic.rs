@@ -2,9 +2,17 @@
 //!
 //! Validates chat topic strings.
 
-/// Maximum length for topics in characters
+/// Default maximum length for topics in characters
+///
+/// Servers may configure a different limit, up to `MAX_CHAT_TOPIC_LENGTH_CEILING`.
 pub const MAX_CHAT_TOPIC_LENGTH: usize = 256;
 
+/// Largest topic length a server may configure
+///
+/// Frame size limits are derived from this value, so any configured limit
+/// up to it fits on the wire.
+pub const MAX_CHAT_TOPIC_LENGTH_CEILING: usize = 1024;
+
 /// Validation error for topics
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChatTopicError {
@@ -19,7 +27,7 @@ pub enum ChatTopicError {
 /// Validate a chat topic
 ///
 /// Checks:
-/// - Does not exceed maximum length (256 characters)
+/// - Does not exceed `max_length` characters (usually the server's configured limit)
 /// - No control characters (newlines reported separately)
 ///
 /// Note: Empty topics are allowed (to clear the topic).
@@ -27,8 +35,8 @@ pub enum ChatTopicError {
 /// # Errors
 ///
 /// Returns a `ChatTopicError` variant describing the validation failure.
-pub fn validate_chat_topic(topic: &str) -> Result<(), ChatTopicError> {
-    if topic.len() > MAX_CHAT_TOPIC_LENGTH {
+pub fn validate_chat_topic(topic: &str, max_length: usize) -> Result<(), ChatTopicError> {
+    if topic.chars().count() > max_length {
         return Err(ChatTopicError::TooLong);
     }
     for ch in topic.chars() {
@@ -48,40 +56,76 @@ mod tests {
 
     #[test]
     fn test_valid_topics() {
-        assert!(validate_chat_topic("Welcome to the server!").is_ok());
-        assert!(validate_chat_topic(&"a".repeat(MAX_CHAT_TOPIC_LENGTH)).is_ok());
+        assert!(validate_chat_topic("Welcome to the server!", MAX_CHAT_TOPIC_LENGTH).is_ok());
+        assert!(
+            validate_chat_topic(&"a".repeat(MAX_CHAT_TOPIC_LENGTH), MAX_CHAT_TOPIC_LENGTH).is_ok()
+        );
         // Unicode
-        assert!(validate_chat_topic("日本語のトピック").is_ok());
-        assert!(validate_chat_topic("Тема чата").is_ok());
+        assert!(validate_chat_topic("日本語のトピック", MAX_CHAT_TOPIC_LENGTH).is_ok());
+        assert!(validate_chat_topic("Тема чата", MAX_CHAT_TOPIC_LENGTH).is_ok());
         // Emoji
-        assert!(validate_chat_topic("Welcome! 🎉").is_ok());
+        assert!(validate_chat_topic("Welcome! 🎉", MAX_CHAT_TOPIC_LENGTH).is_ok());
     }
 
     #[test]
     fn test_empty_allowed() {
-        assert!(validate_chat_topic("").is_ok());
+        assert!(validate_chat_topic("", MAX_CHAT_TOPIC_LENGTH).is_ok());
     }
 
     #[test]
     fn test_too_long() {
         assert_eq!(
-            validate_chat_topic(&"a".repeat(MAX_CHAT_TOPIC_LENGTH + 1)),
+            validate_chat_topic(
+                &"a".repeat(MAX_CHAT_TOPIC_LENGTH + 1),
+                MAX_CHAT_TOPIC_LENGTH
+            ),
+            Err(ChatTopicError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_length_counts_characters() {
+        let emoji = "😀".repeat(MAX_CHAT_TOPIC_LENGTH);
+        assert!(validate_chat_topic(&emoji, MAX_CHAT_TOPIC_LENGTH).is_ok());
+        assert_eq!(
+            validate_chat_topic(
+                &"語".repeat(MAX_CHAT_TOPIC_LENGTH + 1),
+                MAX_CHAT_TOPIC_LENGTH
+            ),
+            Err(ChatTopicError::TooLong)
+        );
+        assert_eq!(
+            validate_chat_topic("e\u{301}", 1),
+            Err(ChatTopicError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_custom_max_length() {
+        // Longer limit accepts topics past the default
+        let long = "a".repeat(MAX_CHAT_TOPIC_LENGTH + 1);
+        assert!(validate_chat_topic(&long, MAX_CHAT_TOPIC_LENGTH_CEILING).is_ok());
+
+        // Shorter limit rejects topics the default would accept
+        assert_eq!(
+            validate_chat_topic("topic", 4),
             Err(ChatTopicError::TooLong)
         );
+        assert!(validate_chat_topic("", 0).is_ok());
     }
 
     #[test]
     fn test_newlines() {
         assert_eq!(
-            validate_chat_topic("Line1\nLine2"),
+            validate_chat_topic("Line1\nLine2", MAX_CHAT_TOPIC_LENGTH),
             Err(ChatTopicError::ContainsNewlines)
         );
         assert_eq!(
-            validate_chat_topic("Line1\rLine2"),
+            validate_chat_topic("Line1\rLine2", MAX_CHAT_TOPIC_LENGTH),
             Err(ChatTopicError::ContainsNewlines)
         );
         assert_eq!(
-            validate_chat_topic("Line1\r\nLine2"),
+            validate_chat_topic("Line1\r\nLine2", MAX_CHAT_TOPIC_LENGTH),
             Err(ChatTopicError::ContainsNewlines)
         );
     }
@@ -90,21 +134,21 @@ mod tests {
     fn test_control_characters() {
         // Null byte
         assert_eq!(
-            validate_chat_topic("Hello\0World"),
+            validate_chat_topic("Hello\0World", MAX_CHAT_TOPIC_LENGTH),
             Err(ChatTopicError::InvalidCharacters)
         );
         // Tab
         assert_eq!(
-            validate_chat_topic("Hello\tWorld"),
+            validate_chat_topic("Hello\tWorld", MAX_CHAT_TOPIC_LENGTH),
             Err(ChatTopicError::InvalidCharacters)
         );
         // Other control characters
         assert_eq!(
-            validate_chat_topic("Test\x01Control"),
+            validate_chat_topic("Test\x01Control", MAX_CHAT_TOPIC_LENGTH),
             Err(ChatTopicError::InvalidCharacters)
         );
         assert_eq!(
-            validate_chat_topic("Test\x7FDelete"),
+            validate_chat_topic("Test\x7FDelete", MAX_CHAT_TOPIC_LENGTH),
             Err(ChatTopicError::InvalidCharacters)
         );
     }
@@ -0,0 +1,184 @@
+//! End-to-end encrypted private message validation
+//!
+//! Validates the public keys sessions announce and the per-session envelopes
+//! of an encrypted private message. The server can't read what it relays, so
+//! these checks only bound the sizes and encodings.
+
+use std::collections::HashSet;
+
+use super::MAX_MESSAGE_LENGTH_CEILING;
+use crate::protocol::E2eeEnvelope;
+
+/// Length of an X25519 public key in standard base64 (32 bytes, padded)
+pub const E2EE_PUBLIC_KEY_LENGTH: usize = 44;
+
+/// Maximum envelopes in one message (every session of both accounts)
+pub const MAX_E2EE_ENVELOPES: usize = 16;
+
+/// Maximum length of an envelope's ciphertext in standard base64
+///
+/// The largest message (4 bytes per character at the length ceiling) plus
+/// the 16-byte authentication tag.
+pub const MAX_E2EE_CIPHERTEXT_LENGTH: usize = (MAX_MESSAGE_LENGTH_CEILING * 4 + 16).div_ceil(3) * 4;
+
+/// Validation error for end-to-end encryption fields
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum E2eeError {
+    /// A public key isn't a base64 X25519 key
+    InvalidKey,
+    /// No envelopes, or more than the maximum
+    EnvelopeCount,
+    /// Two envelopes are for the same session
+    DuplicateSession,
+    /// A ciphertext is empty, too long, or not base64
+    InvalidCiphertext,
+}
+
+/// Validate an announced X25519 public key
+///
+/// Checks:
+/// - Exactly 44 characters
+/// - Only standard base64 characters, ending in the single `=` of padding
+///
+/// # Errors
+///
+/// Returns `E2eeError::InvalidKey` if the key is malformed.
+pub fn validate_e2ee_public_key(public_key: &str) -> Result<(), E2eeError> {
+    if public_key.len() != E2EE_PUBLIC_KEY_LENGTH || !is_base64(public_key) {
+        return Err(E2eeError::InvalidKey);
+    }
+    Ok(())
+}
+
+/// Validate the envelopes of an encrypted private message
+///
+/// Checks:
+/// - Between 1 and 16 envelopes, each for a different session
+/// - Every ephemeral key is a valid public key
+/// - Every ciphertext is non-empty base64 within the maximum length
+///
+/// # Errors
+///
+/// Returns an `E2eeError` variant describing the validation failure.
+pub fn validate_e2ee_envelopes(envelopes: &[E2eeEnvelope]) -> Result<(), E2eeError> {
+    if envelopes.is_empty() || envelopes.len() > MAX_E2EE_ENVELOPES {
+        return Err(E2eeError::EnvelopeCount);
+    }
+    let mut sessions = HashSet::new();
+    for envelope in envelopes {
+        if !sessions.insert(envelope.session_id) {
+            return Err(E2eeError::DuplicateSession);
+        }
+        validate_e2ee_public_key(&envelope.ephemeral_key)?;
+        if envelope.ciphertext.is_empty()
+            || envelope.ciphertext.len() > MAX_E2EE_CIPHERTEXT_LENGTH
+            || !is_base64(&envelope.ciphertext)
+        {
+            return Err(E2eeError::InvalidCiphertext);
+        }
+    }
+    Ok(())
+}
+
+/// Whether `value` is padded standard base64
+fn is_base64(value: &str) -> bool {
+    if !value.len().is_multiple_of(4) {
+        return false;
+    }
+    let data = value.trim_end_matches('=');
+    value.len() - data.len() <= 2
+        && data
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '+' || ch == '/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key() -> String {
+        format!("{}=", "A".repeat(E2EE_PUBLIC_KEY_LENGTH - 1))
+    }
+
+    fn envelope(session_id: u32) -> E2eeEnvelope {
+        E2eeEnvelope {
+            session_id,
+            ephemeral_key: key(),
+            ciphertext: "c2VjcmV0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_valid_public_key() {
+        assert!(validate_e2ee_public_key(&key()).is_ok());
+        assert!(validate_e2ee_public_key("q83vEjRWeJq83vEjRWeJq83vEjRWeJq83vEjRWeJq80=").is_ok());
+    }
+
+    #[test]
+    fn test_invalid_public_key() {
+        assert_eq!(validate_e2ee_public_key(""), Err(E2eeError::InvalidKey));
+        assert_eq!(
+            validate_e2ee_public_key(&"A".repeat(E2EE_PUBLIC_KEY_LENGTH + 4)),
+            Err(E2eeError::InvalidKey)
+        );
+        assert_eq!(
+            validate_e2ee_public_key(&format!("{}=", "-".repeat(E2EE_PUBLIC_KEY_LENGTH - 1))),
+            Err(E2eeError::InvalidKey)
+        );
+        assert_eq!(
+            validate_e2ee_public_key(&format!("{}===", "A".repeat(E2EE_PUBLIC_KEY_LENGTH - 3))),
+            Err(E2eeError::InvalidKey)
+        );
+    }
+
+    #[test]
+    fn test_valid_envelopes() {
+        assert!(validate_e2ee_envelopes(&[envelope(1), envelope(2)]).is_ok());
+
+        let mut largest = envelope(1);
+        largest.ciphertext = "A".repeat(MAX_E2EE_CIPHERTEXT_LENGTH);
+        assert!(validate_e2ee_envelopes(&[largest]).is_ok());
+    }
+
+    #[test]
+    fn test_envelope_count() {
+        assert_eq!(validate_e2ee_envelopes(&[]), Err(E2eeError::EnvelopeCount));
+
+        let too_many: Vec<_> = (0..=MAX_E2EE_ENVELOPES as u32).map(envelope).collect();
+        assert_eq!(
+            validate_e2ee_envelopes(&too_many),
+            Err(E2eeError::EnvelopeCount)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_session() {
+        assert_eq!(
+            validate_e2ee_envelopes(&[envelope(1), envelope(1)]),
+            Err(E2eeError::DuplicateSession)
+        );
+    }
+
+    #[test]
+    fn test_invalid_envelope_fields() {
+        let mut bad_key = envelope(1);
+        bad_key.ephemeral_key = "short".to_string();
+        assert_eq!(
+            validate_e2ee_envelopes(&[bad_key]),
+            Err(E2eeError::InvalidKey)
+        );
+
+        for ciphertext in [
+            String::new(),
+            "not base64!".to_string(),
+            "A".repeat(MAX_E2EE_CIPHERTEXT_LENGTH + 4),
+        ] {
+            let mut bad = envelope(1);
+            bad.ciphertext = ciphertext;
+            assert_eq!(
+                validate_e2ee_envelopes(&[bad]),
+                Err(E2eeError::InvalidCiphertext)
+            );
+        }
+    }
+}
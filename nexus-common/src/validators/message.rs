@@ -1,10 +1,22 @@
 //! Message validation
 //!
 //! Validates chat messages, broadcasts, and private messages.
+//!
+//! Messages may span several lines: newlines and tabs are allowed so users
+//! can share logs and code snippets. Lines are separated by `\n` only;
+//! clients normalize `\r\n` before sending.
 
-/// Maximum length for messages (chat, broadcast, private messages) in characters
+/// Default maximum length for messages (chat, broadcast, private messages) in characters
+///
+/// Servers may configure a different limit, up to `MAX_MESSAGE_LENGTH_CEILING`.
 pub const MAX_MESSAGE_LENGTH: usize = 1024;
 
+/// Largest message length a server may configure
+///
+/// Frame size limits are derived from this value, so any configured limit
+/// up to it fits on the wire.
+pub const MAX_MESSAGE_LENGTH_CEILING: usize = 4096;
+
 /// Validation error for messages
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MessageError {
@@ -12,8 +24,6 @@ pub enum MessageError {
     Empty,
     /// Message exceeds maximum length
     TooLong,
-    /// Message contains newline characters
-    ContainsNewlines,
     /// Message contains invalid characters
     InvalidCharacters,
 }
@@ -22,26 +32,24 @@ pub enum MessageError {
 ///
 /// Checks:
 /// - Not empty or whitespace-only
-/// - Does not exceed maximum length (1024 characters)
-/// - No control characters (newlines reported separately)
+/// - Does not exceed `max_length` characters (usually the server's configured limit)
+/// - No control characters other than newline (`\n`) and tab
 ///
 /// # Errors
 ///
 /// Returns a `MessageError` variant describing the validation failure.
-pub fn validate_message(message: &str) -> Result<(), MessageError> {
+pub fn validate_message(message: &str, max_length: usize) -> Result<(), MessageError> {
     if message.trim().is_empty() {
         return Err(MessageError::Empty);
     }
-    if message.len() > MAX_MESSAGE_LENGTH {
+    if message.chars().count() > max_length {
         return Err(MessageError::TooLong);
     }
-    for ch in message.chars() {
-        if ch.is_control() {
-            if ch == '\n' || ch == '\r' {
-                return Err(MessageError::ContainsNewlines);
-            }
-            return Err(MessageError::InvalidCharacters);
-        }
+    if message
+        .chars()
+        .any(|ch| ch.is_control() && ch != '\n' && ch != '\t')
+    {
+        return Err(MessageError::InvalidCharacters);
     }
     Ok(())
 }
@@ -52,82 +60,129 @@ mod tests {
 
     #[test]
     fn test_valid_messages() {
-        assert!(validate_message("Hello, world!").is_ok());
-        assert!(validate_message("a").is_ok());
-        assert!(validate_message(&"a".repeat(MAX_MESSAGE_LENGTH)).is_ok());
+        assert!(validate_message("Hello, world!", MAX_MESSAGE_LENGTH).is_ok());
+        assert!(validate_message("a", MAX_MESSAGE_LENGTH).is_ok());
+        assert!(validate_message(&"a".repeat(MAX_MESSAGE_LENGTH), MAX_MESSAGE_LENGTH).is_ok());
     }
 
     #[test]
     fn test_empty_messages() {
-        assert_eq!(validate_message(""), Err(MessageError::Empty));
-        assert_eq!(validate_message("   "), Err(MessageError::Empty));
-        assert_eq!(validate_message("\t"), Err(MessageError::Empty));
+        assert_eq!(
+            validate_message("", MAX_MESSAGE_LENGTH),
+            Err(MessageError::Empty)
+        );
+        assert_eq!(
+            validate_message("   ", MAX_MESSAGE_LENGTH),
+            Err(MessageError::Empty)
+        );
+        assert_eq!(
+            validate_message("\t", MAX_MESSAGE_LENGTH),
+            Err(MessageError::Empty)
+        );
     }
 
     #[test]
     fn test_too_long() {
         assert_eq!(
-            validate_message(&"a".repeat(MAX_MESSAGE_LENGTH + 1)),
+            validate_message(&"a".repeat(MAX_MESSAGE_LENGTH + 1), MAX_MESSAGE_LENGTH),
+            Err(MessageError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_length_counts_characters() {
+        // Multibyte characters count once each, not per byte
+        let emoji = "😀".repeat(MAX_MESSAGE_LENGTH);
+        assert!(validate_message(&emoji, MAX_MESSAGE_LENGTH).is_ok());
+        let cjk = "語".repeat(MAX_MESSAGE_LENGTH);
+        assert!(validate_message(&cjk, MAX_MESSAGE_LENGTH).is_ok());
+        assert_eq!(
+            validate_message(&"😀".repeat(MAX_MESSAGE_LENGTH + 1), MAX_MESSAGE_LENGTH),
             Err(MessageError::TooLong)
         );
+
+        // Combining marks are separate characters ("e" + U+0301 is two)
+        assert!(validate_message("e\u{301}", 2).is_ok());
+        assert_eq!(validate_message("e\u{301}", 1), Err(MessageError::TooLong));
+    }
+
+    #[test]
+    fn test_custom_max_length() {
+        // Longer limit accepts messages past the default
+        let long = "a".repeat(MAX_MESSAGE_LENGTH + 1);
+        assert!(validate_message(&long, MAX_MESSAGE_LENGTH_CEILING).is_ok());
+
+        // Shorter limit rejects messages the default would accept
+        assert_eq!(validate_message("hello", 4), Err(MessageError::TooLong));
+        assert!(validate_message("hell", 4).is_ok());
     }
 
     #[test]
-    fn test_newlines() {
+    fn test_multiline_messages() {
+        assert!(validate_message("Hello\nWorld", MAX_MESSAGE_LENGTH).is_ok());
+        assert!(
+            validate_message(
+                "```\nfn main() {\n\tprintln!();\n}\n```",
+                MAX_MESSAGE_LENGTH
+            )
+            .is_ok()
+        );
+
+        // Only newlines is still empty
         assert_eq!(
-            validate_message("Hello\nWorld"),
-            Err(MessageError::ContainsNewlines)
+            validate_message("\n\n", MAX_MESSAGE_LENGTH),
+            Err(MessageError::Empty)
         );
+
+        // Newlines count toward the length limit
+        assert_eq!(validate_message("ab\ncd", 4), Err(MessageError::TooLong));
+
+        // Carriage returns are not line separators
         assert_eq!(
-            validate_message("Hello\rWorld"),
-            Err(MessageError::ContainsNewlines)
+            validate_message("Hello\rWorld", MAX_MESSAGE_LENGTH),
+            Err(MessageError::InvalidCharacters)
         );
         assert_eq!(
-            validate_message("Hello\r\nWorld"),
-            Err(MessageError::ContainsNewlines)
+            validate_message("Hello\r\nWorld", MAX_MESSAGE_LENGTH),
+            Err(MessageError::InvalidCharacters)
         );
     }
 
     #[test]
     fn test_valid_characters() {
         // ASCII graphic characters
-        assert!(validate_message("Hello, world!").is_ok());
-        assert!(validate_message("Test @#$%^&*()").is_ok());
+        assert!(validate_message("Hello, world!", MAX_MESSAGE_LENGTH).is_ok());
+        assert!(validate_message("Test @#$%^&*()", MAX_MESSAGE_LENGTH).is_ok());
         // Unicode letters
-        assert!(validate_message("日本語").is_ok());
-        assert!(validate_message("Привет").is_ok());
-        assert!(validate_message("مرحبا").is_ok());
+        assert!(validate_message("日本語", MAX_MESSAGE_LENGTH).is_ok());
+        assert!(validate_message("Привет", MAX_MESSAGE_LENGTH).is_ok());
+        assert!(validate_message("مرحبا", MAX_MESSAGE_LENGTH).is_ok());
         // Spaces
-        assert!(validate_message("Multiple   spaces").is_ok());
+        assert!(validate_message("Multiple   spaces", MAX_MESSAGE_LENGTH).is_ok());
         // Emoji and other unicode
-        assert!(validate_message("Hello 👋 World").is_ok());
-        assert!(validate_message("Math: ∑∏∫").is_ok());
+        assert!(validate_message("Hello 👋 World", MAX_MESSAGE_LENGTH).is_ok());
+        assert!(validate_message("Math: ∑∏∫", MAX_MESSAGE_LENGTH).is_ok());
     }
 
     #[test]
     fn test_control_characters() {
         // Null byte
         assert_eq!(
-            validate_message("Hello\0World"),
-            Err(MessageError::InvalidCharacters)
-        );
-        // Tab
-        assert_eq!(
-            validate_message("Hello\tWorld"),
+            validate_message("Hello\0World", MAX_MESSAGE_LENGTH),
             Err(MessageError::InvalidCharacters)
         );
         // Other control characters
         assert_eq!(
-            validate_message("Hello\x01World"),
+            validate_message("Hello\x01World", MAX_MESSAGE_LENGTH),
             Err(MessageError::InvalidCharacters)
         );
         assert_eq!(
-            validate_message("Test\x7FDelete"),
+            validate_message("Test\x7FDelete", MAX_MESSAGE_LENGTH),
             Err(MessageError::InvalidCharacters)
         );
         // Escape character
         assert_eq!(
-            validate_message("Test\x1BEscape"),
+            validate_message("Test\x1BEscape", MAX_MESSAGE_LENGTH),
             Err(MessageError::InvalidCharacters)
         );
     }
@@ -3,35 +3,68 @@
 //! Reusable validators for common input types. These validators are shared
 //! between client and server - clients can use them for pre-validation,
 //! servers use them for enforcement.
+//!
+//! Lengths of user-facing text (messages, topics, usernames, server name,
+//! description, rules, banner, bio, pronouns, and info pages) are counted in characters
+//! (Unicode scalar values), so emoji and CJK input get the same budget as
+//! ASCII. Combining marks count as separate characters. Passwords and machine-readable fields (locales,
+//! versions, data URIs, preferences JSON) are limited in bytes. Frame size
+//! limits allow for 4 bytes per character.
 
 mod avatar;
+mod bio;
 mod chat_topic;
 mod data_uri;
+mod e2ee;
 mod features;
 mod locale;
 mod message;
+mod notice_url;
+mod page;
 mod password;
 mod permissions;
+mod preferences;
+mod pronouns;
+mod server_banner;
 mod server_description;
 mod server_image;
 mod server_name;
+mod server_rules;
+mod source_tag;
 mod username;
 mod version;
 
 pub use avatar::{AvatarError, MAX_AVATAR_DATA_URI_LENGTH, validate_avatar};
-pub use chat_topic::{ChatTopicError, MAX_CHAT_TOPIC_LENGTH, validate_chat_topic};
+pub use bio::{BioError, MAX_BIO_LENGTH, validate_bio};
+pub use chat_topic::{
+    ChatTopicError, MAX_CHAT_TOPIC_LENGTH, MAX_CHAT_TOPIC_LENGTH_CEILING, validate_chat_topic,
+};
 pub use data_uri::{ALLOWED_IMAGE_MIME_TYPES, DataUriError, validate_image_data_uri};
+pub use e2ee::{
+    E2EE_PUBLIC_KEY_LENGTH, E2eeError, MAX_E2EE_CIPHERTEXT_LENGTH, MAX_E2EE_ENVELOPES,
+    validate_e2ee_envelopes, validate_e2ee_public_key,
+};
 pub use features::{FeaturesError, MAX_FEATURE_LENGTH, MAX_FEATURES_COUNT, validate_features};
 pub use locale::{LocaleError, MAX_LOCALE_LENGTH, validate_locale};
-pub use message::{MAX_MESSAGE_LENGTH, MessageError, validate_message};
+pub use message::{MAX_MESSAGE_LENGTH, MAX_MESSAGE_LENGTH_CEILING, MessageError, validate_message};
+pub use notice_url::{MAX_NOTICE_URL_LENGTH, NoticeUrlError, validate_notice_url};
+pub use page::{
+    MAX_PAGE_CONTENT_LENGTH, MAX_PAGE_NAME_LENGTH, PageContentError, PageNameError,
+    validate_page_content, validate_page_name,
+};
 pub use password::{MAX_PASSWORD_LENGTH, PasswordError, validate_password};
 pub use permissions::{
     MAX_PERMISSION_LENGTH, MAX_PERMISSIONS_COUNT, PermissionsError, validate_permissions,
 };
+pub use preferences::{MAX_PREFERENCES_LENGTH, PreferencesError, validate_preferences};
+pub use pronouns::{MAX_PRONOUNS_LENGTH, PronounsError, validate_pronouns};
+pub use server_banner::{MAX_SERVER_BANNER_LENGTH, ServerBannerError, validate_server_banner};
 pub use server_description::{
     MAX_SERVER_DESCRIPTION_LENGTH, ServerDescriptionError, validate_server_description,
 };
 pub use server_image::{MAX_SERVER_IMAGE_DATA_URI_LENGTH, ServerImageError, validate_server_image};
 pub use server_name::{MAX_SERVER_NAME_LENGTH, ServerNameError, validate_server_name};
+pub use server_rules::{MAX_SERVER_RULES_LENGTH, ServerRulesError, validate_server_rules};
+pub use source_tag::{MAX_SOURCE_TAG_LENGTH, SourceTagError, validate_source_tag};
 pub use username::{MAX_USERNAME_LENGTH, UsernameError, validate_username};
 pub use version::{MAX_VERSION_LENGTH, VersionError, validate_version};
@@ -0,0 +1,115 @@
+//! Admin notice URL validation
+//!
+//! Validates the download link in an "update available" admin notice.
+
+/// Maximum length for an admin notice URL in bytes
+pub const MAX_NOTICE_URL_LENGTH: usize = 512;
+
+/// Validation error for admin notice URLs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoticeUrlError {
+    /// URL is empty
+    Empty,
+    /// URL exceeds maximum length
+    TooLong,
+    /// URL does not start with `http://` or `https://`
+    InvalidScheme,
+    /// URL contains whitespace, control, or non-ASCII characters
+    InvalidCharacters,
+}
+
+/// Validate an admin notice URL
+///
+/// Checks:
+/// - Not empty
+/// - Does not exceed maximum length (512 bytes)
+/// - Starts with `http://` or `https://` (case-insensitive), followed by a host
+/// - Printable ASCII only, so clients can show it exactly as it will open
+///
+/// # Errors
+///
+/// Returns a `NoticeUrlError` variant describing the validation failure.
+pub fn validate_notice_url(url: &str) -> Result<(), NoticeUrlError> {
+    if url.is_empty() {
+        return Err(NoticeUrlError::Empty);
+    }
+    if url.len() > MAX_NOTICE_URL_LENGTH {
+        return Err(NoticeUrlError::TooLong);
+    }
+    if !url.chars().all(|ch| ch.is_ascii_graphic()) {
+        return Err(NoticeUrlError::InvalidCharacters);
+    }
+    let lower = url.to_ascii_lowercase();
+    let rest = lower
+        .strip_prefix("https://")
+        .or_else(|| lower.strip_prefix("http://"));
+    match rest {
+        Some(rest) if !rest.is_empty() && !rest.starts_with('/') => Ok(()),
+        _ => Err(NoticeUrlError::InvalidScheme),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_urls() {
+        assert!(validate_notice_url("https://example.com/download").is_ok());
+        assert!(validate_notice_url("http://example.com").is_ok());
+        assert!(validate_notice_url("HTTPS://Example.com/Nexus?v=1.2#top").is_ok());
+        let long = format!("https://{}", "a".repeat(MAX_NOTICE_URL_LENGTH - 8));
+        assert!(validate_notice_url(&long).is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(validate_notice_url(""), Err(NoticeUrlError::Empty));
+    }
+
+    #[test]
+    fn test_too_long() {
+        let long = format!("https://{}", "a".repeat(MAX_NOTICE_URL_LENGTH - 7));
+        assert_eq!(validate_notice_url(&long), Err(NoticeUrlError::TooLong));
+    }
+
+    #[test]
+    fn test_invalid_scheme() {
+        assert_eq!(
+            validate_notice_url("ftp://example.com"),
+            Err(NoticeUrlError::InvalidScheme)
+        );
+        assert_eq!(
+            validate_notice_url("javascript:alert(1)"),
+            Err(NoticeUrlError::InvalidScheme)
+        );
+        assert_eq!(
+            validate_notice_url("example.com"),
+            Err(NoticeUrlError::InvalidScheme)
+        );
+        assert_eq!(
+            validate_notice_url("https://"),
+            Err(NoticeUrlError::InvalidScheme)
+        );
+        assert_eq!(
+            validate_notice_url("https:///path"),
+            Err(NoticeUrlError::InvalidScheme)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_notice_url("https://example.com/a b"),
+            Err(NoticeUrlError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_notice_url("https://example.com/\n"),
+            Err(NoticeUrlError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_notice_url("https://exämple.com"),
+            Err(NoticeUrlError::InvalidCharacters)
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Info page validation
+//!
+//! Validates the names and text of the server's info pages (help, news, and
+//! so on).
+
+/// Maximum length for a page name in characters
+pub const MAX_PAGE_NAME_LENGTH: usize = 32;
+
+/// Maximum length for page content in characters
+pub const MAX_PAGE_CONTENT_LENGTH: usize = 8192;
+
+/// Validation error for page names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageNameError {
+    /// Page name is empty
+    Empty,
+    /// Page name exceeds maximum length
+    TooLong,
+    /// Page name contains characters other than ASCII letters, digits, `-` and `_`
+    InvalidCharacters,
+}
+
+/// Validation error for page content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageContentError {
+    /// Page content is empty or whitespace only
+    Empty,
+    /// Page content exceeds maximum length
+    TooLong,
+    /// Page content contains invalid characters
+    InvalidCharacters,
+}
+
+/// Validate a page name
+///
+/// Checks:
+/// - Not empty
+/// - Does not exceed maximum length (32 characters)
+/// - Only ASCII letters, digits, `-` and `_` (so names are easy to type after `/page`)
+///
+/// # Errors
+///
+/// Returns a `PageNameError` variant describing the validation failure.
+pub fn validate_page_name(name: &str) -> Result<(), PageNameError> {
+    if name.is_empty() {
+        return Err(PageNameError::Empty);
+    }
+    if name.len() > MAX_PAGE_NAME_LENGTH {
+        return Err(PageNameError::TooLong);
+    }
+    if !name
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_'))
+    {
+        return Err(PageNameError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+/// Validate page content
+///
+/// Checks:
+/// - Not empty or whitespace only (delete the page instead)
+/// - Does not exceed maximum length (8192 characters)
+/// - No control characters other than newlines and tabs
+///
+/// # Errors
+///
+/// Returns a `PageContentError` variant describing the validation failure.
+pub fn validate_page_content(content: &str) -> Result<(), PageContentError> {
+    if content.trim().is_empty() {
+        return Err(PageContentError::Empty);
+    }
+    if content.chars().count() > MAX_PAGE_CONTENT_LENGTH {
+        return Err(PageContentError::TooLong);
+    }
+    for ch in content.chars() {
+        if ch.is_control() && !matches!(ch, '\n' | '\r' | '\t') {
+            return Err(PageContentError::InvalidCharacters);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_page_names() {
+        assert!(validate_page_name("help").is_ok());
+        assert!(validate_page_name("News_2025-01").is_ok());
+        assert!(validate_page_name(&"a".repeat(MAX_PAGE_NAME_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_page_names() {
+        assert_eq!(validate_page_name(""), Err(PageNameError::Empty));
+        assert_eq!(
+            validate_page_name(&"a".repeat(MAX_PAGE_NAME_LENGTH + 1)),
+            Err(PageNameError::TooLong)
+        );
+        for name in ["two words", "café", "../etc", "tab\t"] {
+            assert_eq!(
+                validate_page_name(name),
+                Err(PageNameError::InvalidCharacters),
+                "{name:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_valid_page_content() {
+        assert!(validate_page_content("Welcome!\n\n\t- Be nice\r\n").is_ok());
+        assert!(validate_page_content(&"語".repeat(MAX_PAGE_CONTENT_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_page_content() {
+        assert_eq!(validate_page_content(""), Err(PageContentError::Empty));
+        assert_eq!(validate_page_content(" \n\t"), Err(PageContentError::Empty));
+        assert_eq!(
+            validate_page_content(&"a".repeat(MAX_PAGE_CONTENT_LENGTH + 1)),
+            Err(PageContentError::TooLong)
+        );
+        assert_eq!(
+            validate_page_content("News\x1b[31m"),
+            Err(PageContentError::InvalidCharacters)
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Preferences validation
+//!
+//! Validates the per-user preferences blob stored by the server.
+
+use serde_json::Value;
+
+/// Maximum serialized size of a preferences object in bytes (compact JSON)
+pub const MAX_PREFERENCES_LENGTH: usize = 4096;
+
+/// Validation error for preferences
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreferencesError {
+    /// Preferences are not a JSON object
+    NotObject,
+    /// Serialized preferences exceed maximum size
+    TooLarge,
+}
+
+/// Validate a preferences value
+///
+/// Checks:
+/// - Is a JSON object (keys are preference names)
+/// - Compact serialization does not exceed maximum size (4096 bytes)
+///
+/// # Errors
+///
+/// Returns a `PreferencesError` variant describing the validation failure.
+pub fn validate_preferences(preferences: &Value) -> Result<(), PreferencesError> {
+    if !preferences.is_object() {
+        return Err(PreferencesError::NotObject);
+    }
+    // Serializing a Value can't fail (all keys are strings)
+    let length = serde_json::to_vec(preferences).map_or(usize::MAX, |json| json.len());
+    if length > MAX_PREFERENCES_LENGTH {
+        return Err(PreferencesError::TooLarge);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_valid_preferences() {
+        assert!(validate_preferences(&json!({})).is_ok());
+        assert!(validate_preferences(&json!({"theme": "Dark", "show_seconds": false})).is_ok());
+    }
+
+    #[test]
+    fn test_not_object() {
+        assert_eq!(
+            validate_preferences(&json!([1, 2, 3])),
+            Err(PreferencesError::NotObject)
+        );
+        assert_eq!(
+            validate_preferences(&json!("theme")),
+            Err(PreferencesError::NotObject)
+        );
+        assert_eq!(
+            validate_preferences(&Value::Null),
+            Err(PreferencesError::NotObject)
+        );
+    }
+
+    #[test]
+    fn test_size_limit() {
+        // `{"k":""}` is 8 bytes of overhead
+        let at_limit = json!({"k": "x".repeat(MAX_PREFERENCES_LENGTH - 8)});
+        assert!(validate_preferences(&at_limit).is_ok());
+
+        let over_limit = json!({"k": "x".repeat(MAX_PREFERENCES_LENGTH - 7)});
+        assert_eq!(
+            validate_preferences(&over_limit),
+            Err(PreferencesError::TooLarge)
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Pronouns validation
+//!
+//! Validates user profile pronouns.
+
+/// Maximum length for pronouns in characters
+pub const MAX_PRONOUNS_LENGTH: usize = 32;
+
+/// Validation error for pronouns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PronounsError {
+    /// Pronouns exceed maximum length
+    TooLong,
+    /// Pronouns contain newline characters
+    ContainsNewlines,
+    /// Pronouns contain invalid characters
+    InvalidCharacters,
+}
+
+/// Validate pronouns
+///
+/// Checks:
+/// - Does not exceed maximum length (32 characters)
+/// - No control characters (newlines reported separately)
+///
+/// Note: Empty pronouns are allowed (to clear them).
+///
+/// # Errors
+///
+/// Returns a `PronounsError` variant describing the validation failure.
+pub fn validate_pronouns(pronouns: &str) -> Result<(), PronounsError> {
+    if pronouns.chars().count() > MAX_PRONOUNS_LENGTH {
+        return Err(PronounsError::TooLong);
+    }
+    for ch in pronouns.chars() {
+        if ch.is_control() {
+            if ch == '\n' || ch == '\r' {
+                return Err(PronounsError::ContainsNewlines);
+            }
+            return Err(PronounsError::InvalidCharacters);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_pronouns() {
+        assert!(validate_pronouns("they/them").is_ok());
+        assert!(validate_pronouns(&"a".repeat(MAX_PRONOUNS_LENGTH)).is_ok());
+        // Unicode
+        assert!(validate_pronouns("彼/彼女").is_ok());
+        assert!(validate_pronouns("он/его").is_ok());
+        // Emoji
+        assert!(validate_pronouns("she/her 🌸").is_ok());
+    }
+
+    #[test]
+    fn test_empty_allowed() {
+        assert!(validate_pronouns("").is_ok());
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_pronouns(&"a".repeat(MAX_PRONOUNS_LENGTH + 1)),
+            Err(PronounsError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_length_counts_characters() {
+        let emoji = "😀".repeat(MAX_PRONOUNS_LENGTH);
+        assert!(validate_pronouns(&emoji).is_ok());
+        assert_eq!(
+            validate_pronouns(&"語".repeat(MAX_PRONOUNS_LENGTH + 1)),
+            Err(PronounsError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_newlines() {
+        assert_eq!(
+            validate_pronouns("Line1\nLine2"),
+            Err(PronounsError::ContainsNewlines)
+        );
+        assert_eq!(
+            validate_pronouns("Line1\rLine2"),
+            Err(PronounsError::ContainsNewlines)
+        );
+        assert_eq!(
+            validate_pronouns("Line1\r\nLine2"),
+            Err(PronounsError::ContainsNewlines)
+        );
+    }
+
+    #[test]
+    fn test_control_characters() {
+        // Null byte
+        assert_eq!(
+            validate_pronouns("Hello\0World"),
+            Err(PronounsError::InvalidCharacters)
+        );
+        // Tab
+        assert_eq!(
+            validate_pronouns("Hello\tWorld"),
+            Err(PronounsError::InvalidCharacters)
+        );
+        // Other control characters
+        assert_eq!(
+            validate_pronouns("Test\x01Control"),
+            Err(PronounsError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_pronouns("Test\x7FDelete"),
+            Err(PronounsError::InvalidCharacters)
+        );
+    }
+}
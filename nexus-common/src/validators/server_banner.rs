@@ -0,0 +1,71 @@
+//! Server banner validation
+//!
+//! Validates the notice shown to clients before they log in.
+
+/// Maximum length for the server banner in characters
+pub const MAX_SERVER_BANNER_LENGTH: usize = 2048;
+
+/// Validation error for the server banner
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerBannerError {
+    /// Server banner exceeds maximum length
+    TooLong,
+    /// Server banner contains invalid characters
+    InvalidCharacters,
+}
+
+/// Validate server banner text
+///
+/// Checks:
+/// - Does not exceed maximum length (2048 characters)
+/// - No control characters other than newlines and tabs
+///
+/// Note: An empty banner is allowed (disables the pre-login notice).
+///
+/// # Errors
+///
+/// Returns a `ServerBannerError` variant describing the validation failure.
+pub fn validate_server_banner(banner: &str) -> Result<(), ServerBannerError> {
+    if banner.chars().count() > MAX_SERVER_BANNER_LENGTH {
+        return Err(ServerBannerError::TooLong);
+    }
+    for ch in banner.chars() {
+        if ch.is_control() && !matches!(ch, '\n' | '\r' | '\t') {
+            return Err(ServerBannerError::InvalidCharacters);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_banner() {
+        assert!(validate_server_banner("").is_ok());
+        assert!(validate_server_banner("Authorized use only.\r\n\tActivity is logged.").is_ok());
+        assert!(validate_server_banner(&"a".repeat(MAX_SERVER_BANNER_LENGTH)).is_ok());
+        assert!(validate_server_banner(&"許".repeat(MAX_SERVER_BANNER_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_server_banner(&"a".repeat(MAX_SERVER_BANNER_LENGTH + 1)),
+            Err(ServerBannerError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_server_banner("Banner\0"),
+            Err(ServerBannerError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_server_banner("Banner\x1b[31m"),
+            Err(ServerBannerError::InvalidCharacters)
+        );
+    }
+}
@@ -28,7 +28,7 @@ pub enum ServerDescriptionError {
 ///
 /// Returns a `ServerDescriptionError` variant describing the validation failure.
 pub fn validate_server_description(description: &str) -> Result<(), ServerDescriptionError> {
-    if description.len() > MAX_SERVER_DESCRIPTION_LENGTH {
+    if description.chars().count() > MAX_SERVER_DESCRIPTION_LENGTH {
         return Err(ServerDescriptionError::TooLong);
     }
     for ch in description.chars() {
@@ -70,6 +70,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_length_counts_characters() {
+        let emoji = "😀".repeat(MAX_SERVER_DESCRIPTION_LENGTH);
+        assert!(validate_server_description(&emoji).is_ok());
+        assert_eq!(
+            validate_server_description(&"語".repeat(MAX_SERVER_DESCRIPTION_LENGTH + 1)),
+            Err(ServerDescriptionError::TooLong)
+        );
+    }
+
     #[test]
     fn test_newlines() {
         assert_eq!(
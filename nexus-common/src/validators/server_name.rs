@@ -32,7 +32,7 @@ pub fn validate_server_name(name: &str) -> Result<(), ServerNameError> {
     if name.trim().is_empty() {
         return Err(ServerNameError::Empty);
     }
-    if name.len() > MAX_SERVER_NAME_LENGTH {
+    if name.chars().count() > MAX_SERVER_NAME_LENGTH {
         return Err(ServerNameError::TooLong);
     }
     for ch in name.chars() {
@@ -77,6 +77,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_length_counts_characters() {
+        assert!(validate_server_name(&"😀".repeat(MAX_SERVER_NAME_LENGTH)).is_ok());
+        assert!(validate_server_name(&"語".repeat(MAX_SERVER_NAME_LENGTH)).is_ok());
+        assert_eq!(
+            validate_server_name(&"語".repeat(MAX_SERVER_NAME_LENGTH + 1)),
+            Err(ServerNameError::TooLong)
+        );
+    }
+
     #[test]
     fn test_newlines() {
         assert_eq!(
@@ -0,0 +1,80 @@
+//! Server rules validation
+//!
+//! Validates the rules text users must accept before chatting.
+
+/// Maximum length for server rules in characters
+pub const MAX_SERVER_RULES_LENGTH: usize = 4096;
+
+/// Validation error for server rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerRulesError {
+    /// Server rules exceed maximum length
+    TooLong,
+    /// Server rules contain invalid characters
+    InvalidCharacters,
+}
+
+/// Validate server rules text
+///
+/// Checks:
+/// - Does not exceed maximum length (4096 characters)
+/// - No control characters other than newlines and tabs
+///
+/// Note: Empty rules are allowed (disables the acknowledgement gate).
+///
+/// # Errors
+///
+/// Returns a `ServerRulesError` variant describing the validation failure.
+pub fn validate_server_rules(rules: &str) -> Result<(), ServerRulesError> {
+    if rules.chars().count() > MAX_SERVER_RULES_LENGTH {
+        return Err(ServerRulesError::TooLong);
+    }
+    for ch in rules.chars() {
+        if ch.is_control() && !matches!(ch, '\n' | '\r' | '\t') {
+            return Err(ServerRulesError::InvalidCharacters);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_rules() {
+        assert!(validate_server_rules("").is_ok());
+        assert!(validate_server_rules("1. Be nice\n2. No spam\r\n\t- really").is_ok());
+        assert!(validate_server_rules(&"a".repeat(MAX_SERVER_RULES_LENGTH)).is_ok());
+        assert!(validate_server_rules("ルールを守ってください").is_ok());
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_server_rules(&"a".repeat(MAX_SERVER_RULES_LENGTH + 1)),
+            Err(ServerRulesError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_length_counts_characters() {
+        assert!(validate_server_rules(&"語".repeat(MAX_SERVER_RULES_LENGTH)).is_ok());
+        assert_eq!(
+            validate_server_rules(&"😀".repeat(MAX_SERVER_RULES_LENGTH + 1)),
+            Err(ServerRulesError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_server_rules("Rules\0"),
+            Err(ServerRulesError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_server_rules("Rules\x1b[31m"),
+            Err(ServerRulesError::InvalidCharacters)
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! Connection source tag validation
+//!
+//! Validates the short labels servers attach to connections based on where
+//! they come from (e.g. "loopback", "lan", "tor").
+
+/// Maximum length for source tags in bytes
+pub const MAX_SOURCE_TAG_LENGTH: usize = 32;
+
+/// Validation error for source tags
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceTagError {
+    /// Source tag is empty
+    Empty,
+    /// Source tag exceeds maximum length
+    TooLong,
+    /// Source tag contains characters other than ASCII letters, digits, '-' or '_'
+    InvalidCharacters,
+}
+
+/// Validate a source tag
+///
+/// Checks:
+/// - Not empty
+/// - Does not exceed maximum length (32 bytes)
+/// - Only ASCII letters, digits, '-' and '_'
+///
+/// # Errors
+///
+/// Returns a `SourceTagError` variant describing the validation failure.
+pub fn validate_source_tag(tag: &str) -> Result<(), SourceTagError> {
+    if tag.is_empty() {
+        return Err(SourceTagError::Empty);
+    }
+    if tag.len() > MAX_SOURCE_TAG_LENGTH {
+        return Err(SourceTagError::TooLong);
+    }
+    if !tag
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+    {
+        return Err(SourceTagError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_source_tags() {
+        assert!(validate_source_tag("lan").is_ok());
+        assert!(validate_source_tag("tor").is_ok());
+        assert!(validate_source_tag("office-vpn").is_ok());
+        assert!(validate_source_tag("site_2").is_ok());
+        assert!(validate_source_tag(&"a".repeat(MAX_SOURCE_TAG_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(validate_source_tag(""), Err(SourceTagError::Empty));
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_source_tag(&"a".repeat(MAX_SOURCE_TAG_LENGTH + 1)),
+            Err(SourceTagError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_source_tag("my lan"),
+            Err(SourceTagError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_source_tag("lan=tor"),
+            Err(SourceTagError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_source_tag("réseau"),
+            Err(SourceTagError::InvalidCharacters)
+        );
+    }
+}
@@ -77,6 +77,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_length_counts_characters() {
+        assert!(validate_username(&"語".repeat(MAX_USERNAME_LENGTH)).is_ok());
+        assert_eq!(
+            validate_username(&"語".repeat(MAX_USERNAME_LENGTH + 1)),
+            Err(UsernameError::TooLong)
+        );
+    }
+
     #[test]
     fn test_invalid_characters() {
         // Spaces not allowed
@@ -44,6 +44,24 @@ pub fn protocol_version() -> Version {
         .expect("PROTOCOL_VERSION must be valid semver")
 }
 
+/// First protocol version with the message types added after 0.5
+///
+/// Older peers disconnect on message types they don't know and reject frames
+/// over the 0.5 size limits, so they are only sent what 0.5 understood.
+pub const EXTENDED_PROTOCOL_VERSION: Version = Version::new(0, 6, 0);
+
+/// Whether a peer speaking `version` understands the extended protocol
+///
+/// Patch and pre-release parts are ignored, as in [`check_compatibility`].
+#[must_use]
+pub fn supports_extended_protocol(version: &Version) -> bool {
+    (version.major, version.minor)
+        >= (
+            EXTENDED_PROTOCOL_VERSION.major,
+            EXTENDED_PROTOCOL_VERSION.minor,
+        )
+}
+
 /// Check if a client version is compatible with the server's protocol version.
 ///
 /// Compatibility rules:
@@ -154,6 +172,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_supports_extended_protocol() {
+        assert!(supports_extended_protocol(&protocol_version()));
+        assert!(supports_extended_protocol(&Version::new(0, 6, 0)));
+        assert!(supports_extended_protocol(
+            &"0.6.0-alpha".parse::<Version>().unwrap()
+        ));
+        assert!(supports_extended_protocol(&Version::new(1, 0, 0)));
+        assert!(!supports_extended_protocol(&Version::new(0, 5, 9)));
+        assert!(!supports_extended_protocol(&Version::new(0, 4, 0)));
+    }
+
     #[test]
     fn test_prerelease_versions() {
         let server = protocol_version();
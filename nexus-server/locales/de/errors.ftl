@@ -5,6 +5,9 @@ err-not-logged-in = Nicht angemeldet
 err-avatar-invalid-format = Ungültiges Avatar-Format (muss eine Data-URI mit Base64-Kodierung sein)
 err-avatar-too-large = Avatar ist zu groß (max. { $max_length } Zeichen)
 err-avatar-unsupported-type = Nicht unterstützter Avatar-Typ (nur PNG, WebP oder SVG)
+err-avatar-undecodable = Avatar-Bild konnte nicht dekodiert werden
+err-avatar-dimensions-too-small = Avatar-Bild ist zu klein (min. { $min_dimension } Pixel pro Seite)
+err-avatar-dimensions-too-large = Avatar-Bild ist zu groß (max. { $max_dimension } Pixel pro Seite)
 err-authentication = Authentifizierungsfehler
 err-invalid-credentials = Ungültiger Benutzername oder Passwort
 err-handshake-required = Handshake erforderlich
@@ -12,6 +15,9 @@ err-already-logged-in = Bereits angemeldet
 err-handshake-already-completed = Handshake bereits abgeschlossen
 err-account-deleted = Ihr Konto wurde gelöscht
 err-account-disabled-by-admin = Konto vom Administrator deaktiviert
+err-guest-name-in-use = Der Gastname '{ $username }' wird bereits verwendet
+err-guest-name-reserved = Namen, die mit '{ $prefix }' beginnen, sind für Gäste reserviert
+err-guest-not-allowed = Gäste können das nicht; melde dich mit einem Konto an
 
 # Berechtigungs- und Zugriffsfehler
 err-permission-denied = Zugriff verweigert
@@ -24,6 +30,7 @@ err-database = Datenbankfehler
 
 # Nachrichtenformatfehler
 err-invalid-message-format = Ungültiges Nachrichtenformat
+err-payload-too-large = Anfrage zu groß (maximal { $max_length } Bytes)
 
 # Benutzerverwaltungsfehler
 err-cannot-delete-last-admin = Der letzte Administrator kann nicht gelöscht werden
@@ -33,7 +40,16 @@ err-cannot-edit-self = Sie können sich nicht selbst bearbeiten
 err-cannot-create-admin = Nur Administratoren können Administrator-Benutzer erstellen
 err-cannot-kick-self = Sie können sich nicht selbst hinauswerfen
 err-cannot-kick-admin = Administrator-Benutzer können nicht hinausgeworfen werden
+err-cannot-kick-own-session = Sie können die Sitzung, die Sie gerade verwenden, nicht trennen
+err-session-not-found = Keine Sitzung mit der ID { $session_id } ist online
+err-session-limit = Dieses Konto hat bereits die maximale Anzahl von { $max_sessions } Sitzungen online; melde dich zuerst auf einem anderen Gerät ab
+err-session-replaced = Getrennt, weil sich dieses Konto auf einem anderen Gerät angemeldet hat
 err-cannot-message-self = Sie können sich nicht selbst eine Nachricht senden
+err-e2ee-invalid-key = Ungültiger Verschlüsselungsschlüssel
+err-e2ee-invalid-message = Ungültige verschlüsselte Nachricht
+err-e2ee-keys-changed = Die Verschlüsselungsschlüssel von „{ $username }" haben sich geändert; sende die Nachricht erneut
+err-server-maintenance = Der Server befindet sich im Wartungsmodus; Änderungen sind vorübergehend deaktiviert
+err-feature-disabled = Diese Funktion ist auf diesem Server deaktiviert
 err-cannot-disable-last-admin = Der letzte Administrator kann nicht deaktiviert werden
 
 # Chat-Themenfehler
@@ -46,6 +62,7 @@ err-version-too-long = Die Version ist zu lang (maximal { $max_length } Zeichen)
 err-version-invalid-semver = Die Version muss im Semver-Format vorliegen (MAJOR.MINOR.PATCH)
 err-version-major-mismatch = Inkompatible Protokollversion: Server ist Version { $server_major }.x, Client ist Version { $client_major }.x
 err-version-client-too-new = Die Client-Version { $client_version } ist neuer als die Server-Version { $server_version }. Bitte aktualisieren Sie den Server oder verwenden Sie einen älteren Client.
+err-version-client-too-old = Die Client-Version { $client_version } ist zu alt; dieser Server erfordert { $min_version } oder neuer. Bitte aktualisieren Sie Ihren Client.
 
 # Passwortvalidierungsfehler
 err-password-empty = Das Passwort darf nicht leer sein
@@ -63,7 +80,6 @@ err-features-invalid-characters = Der Feature-Name enthält ungültige Zeichen
 
 # Nachrichtenvalidierungsfehler
 err-message-empty = Die Nachricht darf nicht leer sein
-err-message-contains-newlines = Die Nachricht darf keine Zeilenumbrüche enthalten
 err-message-invalid-characters = Die Nachricht enthält ungültige Zeichen
 
 # Benutzernamen-Validierungsfehler
@@ -74,8 +90,35 @@ err-username-invalid = Der Benutzername enthält ungültige Zeichen (Buchstaben,
 err-unknown-permission = Unbekannte Berechtigung: '{ $permission }'
 
 # Dynamische Fehlermeldungen (mit Parametern)
+err-announcement-limit-reached = Es sind höchstens { $max_count } Ankündigungen möglich. Entfernen Sie zuerst eine
+err-announcement-not-found = Keine Ankündigung mit der Nummer { $number }
+err-page-content-empty = Der Seiteninhalt darf nicht leer sein
+err-page-content-invalid-characters = Der Seiteninhalt enthält ungültige Zeichen
+err-page-content-too-long = Seiteninhalt zu lang (max. { $max_length } Zeichen)
+err-page-limit-reached = Es sind höchstens { $max_count } Seiten möglich. Löschen Sie zuerst eine
+err-page-name-invalid = Seitennamen dürfen nur Buchstaben, Ziffern, „-“ und „_“ enthalten
+err-page-name-too-long = Seitenname zu lang (max. { $max_length } Zeichen)
+err-page-not-found = Keine Seite namens „{ $name }“
 err-broadcast-too-long = Nachricht zu lang (maximal { $max_length } Zeichen)
 err-chat-too-long = Nachricht zu lang (maximal { $max_length } Zeichen)
+err-chat-slow-mode = Langsamer Modus ist aktiv. Bitte warte { $seconds } Sekunden, bevor du eine weitere Nachricht sendest
+err-command-cooldown = Bitte warte { $seconds } Sekunden, bevor du diese Anfrage wiederholst
+err-chat-edit-window-expired = Nachrichten können nur innerhalb von { $minutes } Minuten nach dem Senden geändert werden
+err-chat-message-not-found = Nachricht nicht gefunden oder zu alt zum Ändern
+err-chat-not-own-message = Du kannst nur deine eigenen Nachrichten ändern
+err-pin-already-pinned = Nachricht ist bereits angeheftet
+err-pin-limit-reached = Es können höchstens { $max_count } Nachrichten angeheftet werden. Löse zuerst eine
+err-pin-message-not-found = Nachricht nicht gefunden oder zu alt zum Anheften
+err-pin-not-pinned = Nachricht ist nicht angeheftet
+err-preferences-not-object = Einstellungen müssen ein JSON-Objekt sein
+err-preferences-too-large = Einstellungen sind zu groß (max. { $max_length } Bytes)
+err-bio-too-long = Bio ist zu lang (max. { $max_length } Zeichen)
+err-bio-contains-newlines = Bio darf keine Zeilenumbrüche enthalten
+err-bio-invalid-characters = Bio enthält ungültige Zeichen
+err-pronouns-too-long = Pronomen sind zu lang (max. { $max_length } Zeichen)
+err-pronouns-contains-newlines = Pronomen dürfen keine Zeilenumbrüche enthalten
+err-pronouns-invalid-characters = Pronomen enthalten ungültige Zeichen
+err-rules-not-accepted = Du musst die Serverregeln akzeptieren, bevor du chatten kannst
 err-topic-too-long = Das Thema darf { $max_length } Zeichen nicht überschreiten
 err-kicked-by = Sie wurden von { $username } hinausgeworfen
 err-username-exists = Der Benutzername „{ $username }" existiert bereits
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = Der Berechtigungsname enthält ungültige Z
 
 # Server-Update-Fehler
 err-admin-required = Administratorrechte erforderlich
+err-cannot-transfer-admin-to-self = Sie können Administratorrechte nicht an sich selbst übertragen
+err-already-admin = „{ $username }" ist bereits Administrator
+err-notice-url-invalid = Der Link muss mit http:// oder https:// beginnen und darf keine Leerzeichen enthalten
+err-notice-url-too-long = Der Link ist zu lang (max. { $max_length } Zeichen)
 err-server-name-empty = Der Servername darf nicht leer sein
 err-server-name-too-long = Der Servername ist zu lang (maximal { $max_length } Zeichen)
 err-server-name-contains-newlines = Der Servername darf keine Zeilenumbrüche enthalten
@@ -102,8 +149,14 @@ err-server-description-too-long = Die Serverbeschreibung ist zu lang (maximal {
 err-server-description-contains-newlines = Die Serverbeschreibung darf keine Zeilenumbrüche enthalten
 err-server-description-invalid-characters = Die Serverbeschreibung enthält ungültige Zeichen
 err-max-connections-per-ip-invalid = Maximale Verbindungen pro IP muss größer als 0 sein
+err-max-message-length-invalid = Die maximale Nachrichtenlänge muss zwischen 1 und { $max_length } liegen
+err-max-topic-length-invalid = Die maximale Themenlänge muss zwischen 1 und { $max_length } liegen
 err-no-fields-to-update = Keine Felder zum Aktualisieren
 
 err-server-image-too-large = Das Serverbild ist zu groß (maximal 512KB)
 err-server-image-invalid-format = Ungültiges Serverbild-Format (muss eine Data-URI mit Base64-Kodierung sein)
 err-server-image-unsupported-type = Nicht unterstützter Serverbild-Typ (nur PNG, WebP, JPEG oder SVG)
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = Zusätzlich erforderliche Berechtigungen erteilt: { $permissions }
+warn-permission-prerequisites-missing = Einige erteilte Berechtigungen funktionieren nicht ohne: { $permissions }
@@ -5,6 +5,9 @@ err-not-logged-in = Not logged in
 err-avatar-invalid-format = Invalid avatar format (must be a data URI with base64 encoding)
 err-avatar-too-large = Avatar is too large (max { $max_length } characters)
 err-avatar-unsupported-type = Unsupported avatar type (PNG, WebP, or SVG only)
+err-avatar-undecodable = Avatar image could not be decoded
+err-avatar-dimensions-too-small = Avatar image is too small (min { $min_dimension } pixels per side)
+err-avatar-dimensions-too-large = Avatar image is too large (max { $max_dimension } pixels per side)
 err-authentication = Authentication error
 err-invalid-credentials = Invalid username or password
 err-handshake-required = Handshake required
@@ -12,6 +15,9 @@ err-already-logged-in = Already logged in
 err-handshake-already-completed = Handshake already completed
 err-account-deleted = Your account has been deleted
 err-account-disabled-by-admin = Account disabled by admin
+err-guest-name-in-use = The guest name '{ $username }' is already in use
+err-guest-name-reserved = Names starting with '{ $prefix }' are reserved for guests
+err-guest-not-allowed = Guests can't do that; log in with an account
 
 # Permission & Access Errors
 err-permission-denied = Permission denied
@@ -24,6 +30,7 @@ err-database = Database error
 
 # Message Format Errors
 err-invalid-message-format = Invalid message format
+err-payload-too-large = Request too large (max { $max_length } bytes)
 
 # User Management Errors
 err-cannot-delete-last-admin = Cannot delete the last admin
@@ -33,7 +40,16 @@ err-cannot-edit-self = You cannot edit yourself
 err-cannot-create-admin = Only admins can create admin users
 err-cannot-kick-self = You cannot kick yourself
 err-cannot-kick-admin = Cannot kick admin users
+err-cannot-kick-own-session = You cannot disconnect the session you are using
+err-session-not-found = No session with ID { $session_id } is online
+err-session-limit = This account already has the maximum of { $max_sessions } sessions online; log out on another device first
+err-session-replaced = Disconnected because this account logged in on another device
 err-cannot-message-self = You cannot message yourself
+err-e2ee-invalid-key = Invalid encryption key
+err-e2ee-invalid-message = Invalid encrypted message
+err-e2ee-keys-changed = The encryption keys for '{ $username }' changed; send the message again
+err-server-maintenance = The server is in maintenance mode; changes are disabled for now
+err-feature-disabled = This feature is disabled on this server
 err-cannot-disable-last-admin = Cannot disable the last admin
 
 # Chat Topic Errors
@@ -68,7 +84,6 @@ err-permissions-invalid-characters = Permission name contains invalid characters
 
 # Message Validation Errors
 err-message-empty = Message cannot be empty
-err-message-contains-newlines = Message cannot contain newlines
 err-message-invalid-characters = Message contains invalid characters
 
 # Username Validation Errors
@@ -79,11 +94,39 @@ err-username-invalid = Username contains invalid characters (letters, numbers, a
 err-unknown-permission = Unknown permission: '{ $permission }'
 
 # Dynamic Error Messages (with parameters)
+err-announcement-limit-reached = Cannot have more than { $max_count } announcements. Remove one first
+err-announcement-not-found = No announcement number { $number }
+err-page-content-empty = Page content cannot be empty
+err-page-content-invalid-characters = Page content contains invalid characters
+err-page-content-too-long = Page content too long (max { $max_length } characters)
+err-page-limit-reached = Cannot have more than { $max_count } pages. Delete one first
+err-page-name-invalid = Page names may only use letters, numbers, '-' and '_'
+err-page-name-too-long = Page name too long (max { $max_length } characters)
+err-page-not-found = No page named '{ $name }'
 err-broadcast-too-long = Message too long (max { $max_length } characters)
 err-chat-too-long = Message too long (max { $max_length } characters)
+err-chat-slow-mode = Slow mode is enabled. Please wait { $seconds } seconds before sending another message
+err-command-cooldown = Please wait { $seconds } seconds before repeating this request
+err-chat-edit-window-expired = Messages can only be changed within { $minutes } minutes of sending
+err-chat-message-not-found = Message not found or too old to change
+err-chat-not-own-message = You can only change your own messages
+err-pin-already-pinned = Message is already pinned
+err-pin-limit-reached = Cannot pin more than { $max_count } messages. Unpin one first
+err-pin-message-not-found = Message not found or too old to pin
+err-pin-not-pinned = Message is not pinned
+err-preferences-not-object = Preferences must be a JSON object
+err-preferences-too-large = Preferences are too large (max { $max_length } bytes)
+err-bio-too-long = Bio is too long (max { $max_length } characters)
+err-bio-contains-newlines = Bio cannot contain newlines
+err-bio-invalid-characters = Bio contains invalid characters
+err-pronouns-too-long = Pronouns are too long (max { $max_length } characters)
+err-pronouns-contains-newlines = Pronouns cannot contain newlines
+err-pronouns-invalid-characters = Pronouns contain invalid characters
+err-rules-not-accepted = You must accept the server rules before chatting
 err-topic-too-long = Topic cannot exceed { $max_length } characters
 err-version-major-mismatch = Incompatible protocol version: server is version { $server_major }.x, client is version { $client_major }.x
 err-version-client-too-new = Client version { $client_version } is newer than server version { $server_version }. Please update the server or use an older client.
+err-version-client-too-old = Client version { $client_version } is too old; this server requires { $min_version } or newer. Please upgrade your client.
 err-kicked-by = You have been kicked by { $username }
 err-username-exists = Username '{ $username }' already exists
 err-user-not-found = User '{ $username }' not found
@@ -95,6 +138,10 @@ err-username-too-long = Username is too long (max { $max_length } characters)
 
 # Server Update Errors
 err-admin-required = Admin privileges required
+err-cannot-transfer-admin-to-self = You cannot transfer admin to yourself
+err-already-admin = '{ $username }' is already an admin
+err-notice-url-invalid = Link must start with http:// or https:// and contain no spaces
+err-notice-url-too-long = Link is too long (max { $max_length } characters)
 err-server-name-empty = Server name cannot be empty
 err-server-name-too-long = Server name is too long (max { $max_length } characters)
 err-server-name-contains-newlines = Server name cannot contain newlines
@@ -106,4 +153,10 @@ err-server-image-too-large = Server image is too large (max 512KB)
 err-server-image-invalid-format = Invalid server image format (must be a data URI with base64 encoding)
 err-server-image-unsupported-type = Unsupported server image type (PNG, WebP, JPEG, or SVG only)
 err-max-connections-per-ip-invalid = Max connections per IP must be greater than 0
-err-no-fields-to-update = No fields to update
\ No newline at end of file
+err-max-message-length-invalid = Max message length must be between 1 and { $max_length }
+err-max-topic-length-invalid = Max topic length must be between 1 and { $max_length }
+err-no-fields-to-update = No fields to update
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = Also granted prerequisite permissions: { $permissions }
+warn-permission-prerequisites-missing = Some granted permissions won't work without: { $permissions }
\ No newline at end of file
@@ -5,6 +5,9 @@ err-not-logged-in = No has iniciado sesión
 err-avatar-invalid-format = Formato de avatar no válido (debe ser una URI de datos con codificación base64)
 err-avatar-too-large = El avatar es demasiado grande (máx. { $max_length } caracteres)
 err-avatar-unsupported-type = Tipo de avatar no compatible (solo PNG, WebP o SVG)
+err-avatar-undecodable = No se pudo decodificar la imagen del avatar
+err-avatar-dimensions-too-small = La imagen del avatar es demasiado pequeña (mín. { $min_dimension } píxeles por lado)
+err-avatar-dimensions-too-large = La imagen del avatar es demasiado grande (máx. { $max_dimension } píxeles por lado)
 err-authentication = Error de autenticación
 err-invalid-credentials = Usuario o contraseña inválidos
 err-handshake-required = Se requiere handshake
@@ -12,6 +15,9 @@ err-already-logged-in = Ya ha iniciado sesión
 err-handshake-already-completed = Handshake ya completado
 err-account-deleted = Su cuenta ha sido eliminada
 err-account-disabled-by-admin = Cuenta deshabilitada por el administrador
+err-guest-name-in-use = El nombre de invitado '{ $username }' ya está en uso
+err-guest-name-reserved = Los nombres que empiezan por '{ $prefix }' están reservados para invitados
+err-guest-not-allowed = Los invitados no pueden hacer eso; inicia sesión con una cuenta
 
 # Permission & Access Errors
 # Errores de permisos y acceso
@@ -25,6 +31,7 @@ err-database = Error de base de datos
 
 # Errores de formato de mensaje
 err-invalid-message-format = Formato de mensaje inválido
+err-payload-too-large = Solicitud demasiado grande (máx. { $max_length } bytes)
 
 # Errores de gestión de usuarios
 err-cannot-delete-last-admin = No se puede eliminar el último administrador
@@ -34,7 +41,16 @@ err-cannot-edit-self = No puedes editarte a ti mismo
 err-cannot-create-admin = Solo los administradores pueden crear usuarios administradores
 err-cannot-kick-self = No puedes expulsarte a ti mismo
 err-cannot-kick-admin = No se puede expulsar a usuarios administradores
+err-cannot-kick-own-session = No puedes desconectar la sesión que estás usando
+err-session-not-found = No hay ninguna sesión en línea con el ID { $session_id }
+err-session-limit = Esta cuenta ya tiene el máximo de { $max_sessions } sesiones en línea; cierra sesión primero en otro dispositivo
+err-session-replaced = Desconectado porque esta cuenta inició sesión en otro dispositivo
 err-cannot-message-self = No puedes enviarte mensajes a ti mismo
+err-e2ee-invalid-key = Clave de cifrado no válida
+err-e2ee-invalid-message = Mensaje cifrado no válido
+err-e2ee-keys-changed = Las claves de cifrado de '{ $username }' han cambiado; envía el mensaje de nuevo
+err-server-maintenance = El servidor está en modo de mantenimiento; los cambios están desactivados por ahora
+err-feature-disabled = Esta función está desactivada en este servidor
 err-cannot-disable-last-admin = No se puede deshabilitar al último administrador
 
 # Errores de tema de chat
@@ -62,7 +78,6 @@ err-features-invalid-characters = El nombre de la característica contiene carac
 
 # Errores de validación de mensajes
 err-message-empty = El mensaje no puede estar vacío
-err-message-contains-newlines = El mensaje no puede contener saltos de línea
 err-message-invalid-characters = El mensaje contiene caracteres inválidos
 
 # Errores de validación de nombre de usuario
@@ -73,11 +88,39 @@ err-username-invalid = El nombre de usuario contiene caracteres inválidos (se p
 err-unknown-permission = Permiso desconocido: '{ $permission }'
 
 # Mensajes de error dinámicos (con parámetros)
+err-announcement-limit-reached = No se pueden tener más de { $max_count } anuncios. Elimina uno primero
+err-announcement-not-found = No existe el anuncio número { $number }
+err-page-content-empty = El contenido de la página no puede estar vacío
+err-page-content-invalid-characters = El contenido de la página contiene caracteres no válidos
+err-page-content-too-long = Contenido de la página demasiado largo (máx. { $max_length } caracteres)
+err-page-limit-reached = No puede haber más de { $max_count } páginas. Elimina una primero
+err-page-name-invalid = Los nombres de página solo pueden usar letras, números, '-' y '_'
+err-page-name-too-long = Nombre de página demasiado largo (máx. { $max_length } caracteres)
+err-page-not-found = No hay ninguna página llamada '{ $name }'
 err-broadcast-too-long = Mensaje demasiado largo (máx. { $max_length } caracteres)
 err-chat-too-long = Mensaje demasiado largo (máx. { $max_length } caracteres)
+err-chat-slow-mode = El modo lento está activado. Espera { $seconds } segundos antes de enviar otro mensaje
+err-command-cooldown = Espera { $seconds } segundos antes de repetir esta solicitud
+err-chat-edit-window-expired = Los mensajes solo se pueden cambiar dentro de los { $minutes } minutos posteriores al envío
+err-chat-message-not-found = Mensaje no encontrado o demasiado antiguo para cambiarlo
+err-chat-not-own-message = Solo puedes cambiar tus propios mensajes
+err-pin-already-pinned = El mensaje ya está fijado
+err-pin-limit-reached = No se pueden fijar más de { $max_count } mensajes. Desfija uno primero
+err-pin-message-not-found = Mensaje no encontrado o demasiado antiguo para fijarlo
+err-pin-not-pinned = El mensaje no está fijado
+err-preferences-not-object = Las preferencias deben ser un objeto JSON
+err-preferences-too-large = Las preferencias son demasiado grandes (máx. { $max_length } bytes)
+err-bio-too-long = La biografía es demasiado larga (máx. { $max_length } caracteres)
+err-bio-contains-newlines = La biografía no puede contener saltos de línea
+err-bio-invalid-characters = La biografía contiene caracteres no válidos
+err-pronouns-too-long = Los pronombres son demasiado largos (máx. { $max_length } caracteres)
+err-pronouns-contains-newlines = Los pronombres no pueden contener saltos de línea
+err-pronouns-invalid-characters = Los pronombres contienen caracteres no válidos
+err-rules-not-accepted = Debes aceptar las reglas del servidor antes de chatear
 err-topic-too-long = El tema no puede exceder { $max_length } caracteres
 err-version-major-mismatch = Versión de protocolo incompatible: el servidor es versión { $server_major }.x, el cliente es versión { $client_major }.x
 err-version-client-too-new = La versión del cliente { $client_version } es más nueva que la versión del servidor { $server_version }. Por favor actualice el servidor o use un cliente más antiguo.
+err-version-client-too-old = La versión del cliente { $client_version } es demasiado antigua; este servidor requiere { $min_version } o posterior. Actualiza tu cliente.
 err-kicked-by = Has sido expulsado por { $username }
 err-username-exists = El nombre de usuario '{ $username }' ya existe
 err-user-not-found = Usuario '{ $username }' no encontrado
@@ -95,6 +138,10 @@ err-permissions-invalid-characters = El nombre del permiso contiene caracteres i
 
 # Errores de actualización del servidor
 err-admin-required = Se requieren privilegios de administrador
+err-cannot-transfer-admin-to-self = No puedes transferirte la administración a ti mismo
+err-already-admin = '{ $username }' ya es administrador
+err-notice-url-invalid = El enlace debe empezar por http:// o https:// y no contener espacios
+err-notice-url-too-long = El enlace es demasiado largo (máx. { $max_length } caracteres)
 err-server-name-empty = El nombre del servidor no puede estar vacío
 err-server-name-too-long = El nombre del servidor es demasiado largo (máx. { $max_length } caracteres)
 err-server-name-contains-newlines = El nombre del servidor no puede contener saltos de línea
@@ -103,8 +150,14 @@ err-server-description-too-long = La descripción del servidor es demasiado larg
 err-server-description-contains-newlines = La descripción del servidor no puede contener saltos de línea
 err-server-description-invalid-characters = La descripción del servidor contiene caracteres inválidos
 err-max-connections-per-ip-invalid = Las conexiones máximas por IP deben ser mayores que 0
+err-max-message-length-invalid = La longitud máxima de mensaje debe estar entre 1 y { $max_length }
+err-max-topic-length-invalid = La longitud máxima del tema debe estar entre 1 y { $max_length }
 err-no-fields-to-update = No hay campos para actualizar
 
 err-server-image-too-large = La imagen del servidor es demasiado grande (máx. 512KB)
 err-server-image-invalid-format = Formato de imagen del servidor inválido (debe ser una URI de datos con codificación base64)
 err-server-image-unsupported-type = Tipo de imagen del servidor no compatible (solo PNG, WebP, JPEG o SVG)
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = También se concedieron los permisos necesarios: { $permissions }
+warn-permission-prerequisites-missing = Algunos permisos concedidos no funcionarán sin: { $permissions }
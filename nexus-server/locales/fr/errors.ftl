@@ -5,6 +5,9 @@ err-not-logged-in = Non connecté
 err-avatar-invalid-format = Format d'avatar invalide (doit être une URI de données avec encodage base64)
 err-avatar-too-large = L'avatar est trop volumineux (max. { $max_length } caractères)
 err-avatar-unsupported-type = Type d'avatar non pris en charge (PNG, WebP ou SVG uniquement)
+err-avatar-undecodable = Impossible de décoder l'image de l'avatar
+err-avatar-dimensions-too-small = L'image de l'avatar est trop petite (min. { $min_dimension } pixels par côté)
+err-avatar-dimensions-too-large = L'image de l'avatar est trop grande (max. { $max_dimension } pixels par côté)
 err-authentication = Erreur d'authentification
 err-invalid-credentials = Nom d'utilisateur ou mot de passe invalide
 err-handshake-required = Handshake requis
@@ -12,6 +15,9 @@ err-already-logged-in = Déjà connecté
 err-handshake-already-completed = Handshake déjà effectué
 err-account-deleted = Votre compte a été supprimé
 err-account-disabled-by-admin = Compte désactivé par l'administrateur
+err-guest-name-in-use = Le nom d'invité '{ $username }' est déjà utilisé
+err-guest-name-reserved = Les noms commençant par '{ $prefix }' sont réservés aux invités
+err-guest-not-allowed = Les invités ne peuvent pas faire cela ; connectez-vous avec un compte
 
 # Erreurs de permission et d'accès
 err-permission-denied = Permission refusée
@@ -24,6 +30,7 @@ err-database = Erreur de base de données
 
 # Erreurs de format de message
 err-invalid-message-format = Format de message invalide
+err-payload-too-large = Requête trop volumineuse (maximum { $max_length } octets)
 
 # Erreurs de gestion des utilisateurs
 err-cannot-delete-last-admin = Impossible de supprimer le dernier administrateur
@@ -33,7 +40,16 @@ err-cannot-edit-self = Vous ne pouvez pas vous modifier vous-même
 err-cannot-create-admin = Seuls les administrateurs peuvent créer des utilisateurs administrateurs
 err-cannot-kick-self = Vous ne pouvez pas vous expulser vous-même
 err-cannot-kick-admin = Impossible d'expulser les utilisateurs administrateurs
+err-cannot-kick-own-session = Vous ne pouvez pas déconnecter la session que vous utilisez
+err-session-not-found = Aucune session avec l'ID { $session_id } n'est en ligne
+err-session-limit = Ce compte a déjà le maximum de { $max_sessions } sessions en ligne ; déconnectez-vous d'abord sur un autre appareil
+err-session-replaced = Déconnecté car ce compte s'est connecté sur un autre appareil
 err-cannot-message-self = Vous ne pouvez pas vous envoyer de message
+err-e2ee-invalid-key = Clé de chiffrement invalide
+err-e2ee-invalid-message = Message chiffré invalide
+err-e2ee-keys-changed = Les clés de chiffrement de « { $username } » ont changé ; envoyez à nouveau le message
+err-server-maintenance = Le serveur est en mode maintenance ; les modifications sont désactivées pour le moment
+err-feature-disabled = Cette fonctionnalité est désactivée sur ce serveur
 err-cannot-disable-last-admin = Impossible de désactiver le dernier administrateur
 
 # Erreurs de sujet de discussion
@@ -61,7 +77,6 @@ err-features-invalid-characters = Le nom de la fonctionnalité contient des cara
 
 # Erreurs de validation de message
 err-message-empty = Le message ne peut pas être vide
-err-message-contains-newlines = Le message ne peut pas contenir de sauts de ligne
 err-message-invalid-characters = Le message contient des caractères invalides
 
 # Erreurs de validation du nom d'utilisateur
@@ -72,11 +87,39 @@ err-username-invalid = Le nom d'utilisateur contient des caractères invalides (
 err-unknown-permission = Permission inconnue : '{ $permission }'
 
 # Messages d'erreur dynamiques (avec paramètres)
+err-announcement-limit-reached = Impossible d'avoir plus de { $max_count } annonces. Supprimez-en une d'abord
+err-announcement-not-found = Aucune annonce numéro { $number }
+err-page-content-empty = Le contenu de la page ne peut pas être vide
+err-page-content-invalid-characters = Le contenu de la page contient des caractères invalides
+err-page-content-too-long = Contenu de la page trop long (max { $max_length } caractères)
+err-page-limit-reached = Impossible d'avoir plus de { $max_count } pages. Supprimez-en une d'abord
+err-page-name-invalid = Les noms de page ne peuvent contenir que des lettres, des chiffres, « - » et « _ »
+err-page-name-too-long = Nom de page trop long (max { $max_length } caractères)
+err-page-not-found = Aucune page nommée « { $name } »
 err-broadcast-too-long = Message trop long (maximum { $max_length } caractères)
 err-chat-too-long = Message trop long (maximum { $max_length } caractères)
+err-chat-slow-mode = Le mode lent est activé. Veuillez attendre { $seconds } secondes avant d'envoyer un autre message
+err-command-cooldown = Veuillez patienter { $seconds } secondes avant de répéter cette requête
+err-chat-edit-window-expired = Les messages ne peuvent être modifiés que dans les { $minutes } minutes suivant leur envoi
+err-chat-message-not-found = Message introuvable ou trop ancien pour être modifié
+err-chat-not-own-message = Vous ne pouvez modifier que vos propres messages
+err-pin-already-pinned = Le message est déjà épinglé
+err-pin-limit-reached = Impossible d'épingler plus de { $max_count } messages. Désépinglez-en un d'abord
+err-pin-message-not-found = Message introuvable ou trop ancien pour être épinglé
+err-pin-not-pinned = Le message n'est pas épinglé
+err-preferences-not-object = Les préférences doivent être un objet JSON
+err-preferences-too-large = Les préférences sont trop volumineuses (max. { $max_length } octets)
+err-bio-too-long = La bio est trop longue (max { $max_length } caractères)
+err-bio-contains-newlines = La bio ne peut pas contenir de retours à la ligne
+err-bio-invalid-characters = La bio contient des caractères invalides
+err-pronouns-too-long = Les pronoms sont trop longs (max { $max_length } caractères)
+err-pronouns-contains-newlines = Les pronoms ne peuvent pas contenir de retours à la ligne
+err-pronouns-invalid-characters = Les pronoms contiennent des caractères invalides
+err-rules-not-accepted = Vous devez accepter les règles du serveur avant de discuter
 err-topic-too-long = Le sujet ne peut pas dépasser { $max_length } caractères
 err-version-major-mismatch = Version de protocole incompatible : le serveur est en version { $server_major }.x, le client est en version { $client_major }.x
 err-version-client-too-new = La version du client { $client_version } est plus récente que la version du serveur { $server_version }. Veuillez mettre à jour le serveur ou utiliser un client plus ancien.
+err-version-client-too-old = La version du client { $client_version } est trop ancienne ; ce serveur exige { $min_version } ou plus récente. Veuillez mettre à jour votre client.
 err-kicked-by = Vous avez été expulsé par { $username }
 err-username-exists = Le nom d'utilisateur « { $username } » existe déjà
 err-user-not-found = Utilisateur « { $username } » introuvable
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = Le nom de la permission contient des caract
 
 # Erreurs de mise à jour du serveur
 err-admin-required = Privilèges d'administrateur requis
+err-cannot-transfer-admin-to-self = Vous ne pouvez pas vous transférer l'administration à vous-même
+err-already-admin = « { $username } » est déjà administrateur
+err-notice-url-invalid = Le lien doit commencer par http:// ou https:// et ne contenir aucun espace
+err-notice-url-too-long = Le lien est trop long (max { $max_length } caractères)
 err-server-name-empty = Le nom du serveur ne peut pas être vide
 err-server-name-too-long = Le nom du serveur est trop long (maximum { $max_length } caractères)
 err-server-name-contains-newlines = Le nom du serveur ne peut pas contenir de sauts de ligne
@@ -102,8 +149,14 @@ err-server-description-too-long = La description du serveur est trop longue (max
 err-server-description-contains-newlines = La description du serveur ne peut pas contenir de sauts de ligne
 err-server-description-invalid-characters = La description du serveur contient des caractères invalides
 err-max-connections-per-ip-invalid = Les connexions maximales par IP doivent être supérieures à 0
+err-max-message-length-invalid = La longueur maximale des messages doit être comprise entre 1 et { $max_length }
+err-max-topic-length-invalid = La longueur maximale du sujet doit être comprise entre 1 et { $max_length }
 err-no-fields-to-update = Aucun champ à mettre à jour
 
 err-server-image-too-large = L'image du serveur est trop grande (maximum 512 Ko)
 err-server-image-invalid-format = Format d'image du serveur invalide (doit être une URI de données avec encodage base64)
 err-server-image-unsupported-type = Type d'image du serveur non pris en charge (PNG, WebP, JPEG ou SVG uniquement)
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = Permissions requises également accordées : { $permissions }
+warn-permission-prerequisites-missing = Certaines permissions accordées ne fonctionneront pas sans : { $permissions }
@@ -5,6 +5,9 @@ err-not-logged-in = Non connesso
 err-avatar-invalid-format = Formato avatar non valido (deve essere un URI di dati con codifica base64)
 err-avatar-too-large = L'avatar è troppo grande (max { $max_length } caratteri)
 err-avatar-unsupported-type = Tipo di avatar non supportato (solo PNG, WebP o SVG)
+err-avatar-undecodable = Impossibile decodificare l'immagine dell'avatar
+err-avatar-dimensions-too-small = L'immagine dell'avatar è troppo piccola (min. { $min_dimension } pixel per lato)
+err-avatar-dimensions-too-large = L'immagine dell'avatar è troppo grande (max. { $max_dimension } pixel per lato)
 err-authentication = Errore di autenticazione
 err-invalid-credentials = Nome utente o password non validi
 err-handshake-required = Handshake richiesto
@@ -12,6 +15,9 @@ err-already-logged-in = Già connesso
 err-handshake-already-completed = Handshake già completato
 err-account-deleted = Il tuo account è stato eliminato
 err-account-disabled-by-admin = Account disabilitato dall'amministratore
+err-guest-name-in-use = Il nome ospite '{ $username }' è già in uso
+err-guest-name-reserved = I nomi che iniziano con '{ $prefix }' sono riservati agli ospiti
+err-guest-not-allowed = Gli ospiti non possono farlo; accedi con un account
 
 # Errori di permesso e accesso
 err-permission-denied = Permesso negato
@@ -24,6 +30,7 @@ err-database = Errore del database
 
 # Errori di formato messaggio
 err-invalid-message-format = Formato messaggio non valido
+err-payload-too-large = Richiesta troppo grande (massimo { $max_length } byte)
 
 # Errori di gestione utenti
 err-cannot-delete-last-admin = Impossibile eliminare l'ultimo amministratore
@@ -33,7 +40,16 @@ err-cannot-edit-self = Non puoi modificare te stesso
 err-cannot-create-admin = Solo gli amministratori possono creare utenti amministratori
 err-cannot-kick-self = Non puoi espellere te stesso
 err-cannot-kick-admin = Impossibile espellere utenti amministratori
+err-cannot-kick-own-session = Non puoi disconnettere la sessione che stai usando
+err-session-not-found = Nessuna sessione con ID { $session_id } è online
+err-session-limit = Questo account ha già il massimo di { $max_sessions } sessioni online; disconnettiti prima da un altro dispositivo
+err-session-replaced = Disconnesso perché questo account ha effettuato l'accesso su un altro dispositivo
 err-cannot-message-self = Non puoi inviare messaggi a te stesso
+err-e2ee-invalid-key = Chiave di cifratura non valida
+err-e2ee-invalid-message = Messaggio cifrato non valido
+err-e2ee-keys-changed = Le chiavi di cifratura di "{ $username }" sono cambiate; invia di nuovo il messaggio
+err-server-maintenance = Il server è in modalità manutenzione; le modifiche sono disattivate per ora
+err-feature-disabled = Questa funzione è disattivata su questo server
 err-cannot-disable-last-admin = Impossibile disabilitare l'ultimo amministratore
 
 # Errori argomento chat
@@ -61,7 +77,6 @@ err-features-invalid-characters = Il nome della funzionalità contiene caratteri
 
 # Errori di validazione messaggio
 err-message-empty = Il messaggio non può essere vuoto
-err-message-contains-newlines = Il messaggio non può contenere interruzioni di riga
 err-message-invalid-characters = Il messaggio contiene caratteri non validi
 
 # Errori di validazione nome utente
@@ -72,11 +87,39 @@ err-username-invalid = Il nome utente contiene caratteri non validi (lettere, nu
 err-unknown-permission = Permesso sconosciuto: '{ $permission }'
 
 # Messaggi di errore dinamici (con parametri)
+err-announcement-limit-reached = Non è possibile avere più di { $max_count } annunci. Rimuovine prima uno
+err-announcement-not-found = Nessun annuncio numero { $number }
+err-page-content-empty = Il contenuto della pagina non può essere vuoto
+err-page-content-invalid-characters = Il contenuto della pagina contiene caratteri non validi
+err-page-content-too-long = Contenuto della pagina troppo lungo (max { $max_length } caratteri)
+err-page-limit-reached = Non è possibile avere più di { $max_count } pagine. Eliminane prima una
+err-page-name-invalid = I nomi delle pagine possono contenere solo lettere, numeri, '-' e '_'
+err-page-name-too-long = Nome della pagina troppo lungo (max { $max_length } caratteri)
+err-page-not-found = Nessuna pagina chiamata '{ $name }'
 err-broadcast-too-long = Messaggio troppo lungo (massimo { $max_length } caratteri)
 err-chat-too-long = Messaggio troppo lungo (massimo { $max_length } caratteri)
+err-chat-slow-mode = La modalità lenta è attiva. Attendi { $seconds } secondi prima di inviare un altro messaggio
+err-command-cooldown = Attendi { $seconds } secondi prima di ripetere questa richiesta
+err-chat-edit-window-expired = I messaggi possono essere modificati solo entro { $minutes } minuti dall'invio
+err-chat-message-not-found = Messaggio non trovato o troppo vecchio per essere modificato
+err-chat-not-own-message = Puoi modificare solo i tuoi messaggi
+err-pin-already-pinned = Il messaggio è già fissato
+err-pin-limit-reached = Non è possibile fissare più di { $max_count } messaggi. Rimuovine prima uno
+err-pin-message-not-found = Messaggio non trovato o troppo vecchio per essere fissato
+err-pin-not-pinned = Il messaggio non è fissato
+err-preferences-not-object = Le preferenze devono essere un oggetto JSON
+err-preferences-too-large = Le preferenze sono troppo grandi (max { $max_length } byte)
+err-bio-too-long = La bio è troppo lunga (max { $max_length } caratteri)
+err-bio-contains-newlines = La bio non può contenere a capo
+err-bio-invalid-characters = La bio contiene caratteri non validi
+err-pronouns-too-long = I pronomi sono troppo lunghi (max { $max_length } caratteri)
+err-pronouns-contains-newlines = I pronomi non possono contenere a capo
+err-pronouns-invalid-characters = I pronomi contengono caratteri non validi
+err-rules-not-accepted = Devi accettare le regole del server prima di chattare
 err-topic-too-long = L'argomento non può superare { $max_length } caratteri
 err-version-major-mismatch = Versione del protocollo incompatibile: il server è versione { $server_major }.x, il client è versione { $client_major }.x
 err-version-client-too-new = La versione del client { $client_version } è più recente della versione del server { $server_version }. Aggiorna il server o usa un client più vecchio.
+err-version-client-too-old = La versione del client { $client_version } è troppo vecchia; questo server richiede { $min_version } o successiva. Aggiorna il tuo client.
 err-kicked-by = Sei stato espulso da { $username }
 err-username-exists = Il nome utente "{ $username }" esiste già
 err-user-not-found = Utente "{ $username }" non trovato
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = Il nome del permesso contiene caratteri non
 
 # Errori di aggiornamento del server
 err-admin-required = Privilegi di amministratore richiesti
+err-cannot-transfer-admin-to-self = Non puoi trasferire l'amministrazione a te stesso
+err-already-admin = '{ $username }' è già un amministratore
+err-notice-url-invalid = Il link deve iniziare con http:// o https:// e non contenere spazi
+err-notice-url-too-long = Il link è troppo lungo (max { $max_length } caratteri)
 err-server-name-empty = Il nome del server non può essere vuoto
 err-server-name-too-long = Il nome del server è troppo lungo (massimo { $max_length } caratteri)
 err-server-name-contains-newlines = Il nome del server non può contenere interruzioni di riga
@@ -102,8 +149,14 @@ err-server-description-too-long = La descrizione del server è troppo lunga (mas
 err-server-description-contains-newlines = La descrizione del server non può contenere interruzioni di riga
 err-server-description-invalid-characters = La descrizione del server contiene caratteri non validi
 err-max-connections-per-ip-invalid = Le connessioni massime per IP devono essere maggiori di 0
+err-max-message-length-invalid = La lunghezza massima dei messaggi deve essere compresa tra 1 e { $max_length }
+err-max-topic-length-invalid = La lunghezza massima dell'argomento deve essere compresa tra 1 e { $max_length }
 err-no-fields-to-update = Nessun campo da aggiornare
 
 err-server-image-too-large = L'immagine del server è troppo grande (massimo 512KB)
 err-server-image-invalid-format = Formato immagine del server non valido (deve essere un URI di dati con codifica base64)
 err-server-image-unsupported-type = Tipo di immagine del server non supportato (solo PNG, WebP, JPEG o SVG)
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = Concessi anche i permessi necessari: { $permissions }
+warn-permission-prerequisites-missing = Alcuni permessi concessi non funzioneranno senza: { $permissions }
@@ -5,6 +5,9 @@ err-not-logged-in = ログインしていません
 err-avatar-invalid-format = アバター形式が無効です（base64エンコードのデータURIである必要があります）
 err-avatar-too-large = アバターが大きすぎます（最大{ $max_length }文字）
 err-avatar-unsupported-type = サポートされていないアバタータイプです（PNG、WebP、SVGのみ）
+err-avatar-undecodable = アバター画像をデコードできませんでした
+err-avatar-dimensions-too-small = アバター画像が小さすぎます (各辺最小 { $min_dimension } ピクセル)
+err-avatar-dimensions-too-large = アバター画像が大きすぎます (各辺最大 { $max_dimension } ピクセル)
 err-authentication = 認証エラー
 err-invalid-credentials = ユーザー名またはパスワードが無効です
 err-handshake-required = ハンドシェイクが必要です
@@ -12,6 +15,9 @@ err-already-logged-in = 既にログインしています
 err-handshake-already-completed = ハンドシェイクは既に完了しています
 err-account-deleted = アカウントが削除されました
 err-account-disabled-by-admin = 管理者によってアカウントが無効化されました
+err-guest-name-in-use = ゲスト名 '{ $username }' は既に使用されています
+err-guest-name-reserved = '{ $prefix }' で始まる名前はゲスト用に予約されています
+err-guest-not-allowed = ゲストはこの操作を行えません。アカウントでログインしてください
 
 # 権限とアクセスのエラー
 err-permission-denied = 権限がありません
@@ -24,6 +30,7 @@ err-database = データベースエラー
 
 # メッセージ形式のエラー
 err-invalid-message-format = 無効なメッセージ形式です
+err-payload-too-large = リクエストが大きすぎます（最大{ $max_length }バイト）
 
 # ユーザー管理のエラー
 err-cannot-delete-last-admin = 最後の管理者を削除できません
@@ -33,7 +40,16 @@ err-cannot-edit-self = 自分自身を編集できません
 err-cannot-create-admin = 管理者ユーザーを作成できるのは管理者のみです
 err-cannot-kick-self = 自分自身をキックできません
 err-cannot-kick-admin = 管理者ユーザーをキックできません
+err-cannot-kick-own-session = 使用中のセッションは切断できません
+err-session-not-found = ID { $session_id } のセッションはオンラインではありません
+err-session-limit = このアカウントは既に上限の { $max_sessions } セッションがオンラインです。先に別のデバイスでログアウトしてください
+err-session-replaced = このアカウントが別のデバイスでログインしたため切断されました
 err-cannot-message-self = 自分自身にメッセージを送ることはできません
+err-e2ee-invalid-key = 暗号鍵が無効です
+err-e2ee-invalid-message = 暗号化メッセージが無効です
+err-e2ee-keys-changed = 「{ $username }」の暗号鍵が変わりました。メッセージをもう一度送信してください
+err-server-maintenance = サーバーはメンテナンスモードです。現在、変更は無効になっています
+err-feature-disabled = この機能はこのサーバーでは無効になっています
 err-cannot-disable-last-admin = 最後の管理者を無効化できません
 
 # チャットトピックのエラー
@@ -61,7 +77,6 @@ err-features-invalid-characters = 機能名に無効な文字が含まれてい
 
 # メッセージ検証のエラー
 err-message-empty = メッセージを空にすることはできません
-err-message-contains-newlines = メッセージに改行を含めることはできません
 err-message-invalid-characters = メッセージに無効な文字が含まれています
 
 # ユーザー名検証のエラー
@@ -72,11 +87,39 @@ err-username-invalid = ユーザー名に無効な文字が含まれています
 err-unknown-permission = 不明な権限: '{ $permission }'
 
 # 動的エラーメッセージ（パラメータ付き）
+err-announcement-limit-reached = お知らせは { $max_count } 件までです。先にいずれかを削除してください
+err-announcement-not-found = { $number } 番のお知らせはありません
+err-page-content-empty = ページの内容を空にすることはできません
+err-page-content-invalid-characters = ページの内容に無効な文字が含まれています
+err-page-content-too-long = ページの内容が長すぎます（最大 { $max_length } 文字）
+err-page-limit-reached = ページは { $max_count } 件までです。先にいずれかを削除してください
+err-page-name-invalid = ページ名には英数字、「-」、「_」のみ使用できます
+err-page-name-too-long = ページ名が長すぎます（最大 { $max_length } 文字）
+err-page-not-found = 「{ $name }」という名前のページはありません
 err-broadcast-too-long = メッセージが長すぎます（最大{ $max_length }文字）
 err-chat-too-long = メッセージが長すぎます（最大{ $max_length }文字）
+err-chat-slow-mode = スローモードが有効です。次のメッセージを送信するまで{ $seconds }秒お待ちください
+err-command-cooldown = このリクエストを繰り返すには { $seconds } 秒お待ちください
+err-chat-edit-window-expired = メッセージは送信後{ $minutes }分以内のみ変更できます
+err-chat-message-not-found = メッセージが見つからないか、古すぎて変更できません
+err-chat-not-own-message = 自分のメッセージのみ変更できます
+err-pin-already-pinned = メッセージはすでにピン留めされています
+err-pin-limit-reached = ピン留めできるメッセージは { $max_count } 件までです。先にいずれかを解除してください
+err-pin-message-not-found = メッセージが見つからないか、古すぎてピン留めできません
+err-pin-not-pinned = メッセージはピン留めされていません
+err-preferences-not-object = 設定はJSONオブジェクトである必要があります
+err-preferences-too-large = 設定が大きすぎます（最大{ $max_length }バイト）
+err-bio-too-long = 自己紹介が長すぎます（最大 { $max_length } 文字）
+err-bio-contains-newlines = 自己紹介に改行を含めることはできません
+err-bio-invalid-characters = 自己紹介に無効な文字が含まれています
+err-pronouns-too-long = 代名詞が長すぎます（最大 { $max_length } 文字）
+err-pronouns-contains-newlines = 代名詞に改行を含めることはできません
+err-pronouns-invalid-characters = 代名詞に無効な文字が含まれています
+err-rules-not-accepted = チャットする前にサーバーのルールに同意する必要があります
 err-topic-too-long = トピックは{ $max_length }文字を超えることはできません
 err-version-major-mismatch = 互換性のないプロトコルバージョン：サーバーはバージョン{ $server_major }.x、クライアントはバージョン{ $client_major }.x
 err-version-client-too-new = クライアントバージョン{ $client_version }はサーバーバージョン{ $server_version }より新しいです。サーバーを更新するか、古いクライアントを使用してください。
+err-version-client-too-old = クライアントバージョン{ $client_version }は古すぎます。このサーバーには{ $min_version }以降が必要です。クライアントを更新してください。
 err-kicked-by = { $username }によってキックされました
 err-username-exists = ユーザー名「{ $username }」は既に存在します
 err-user-not-found = ユーザー「{ $username }」が見つかりません
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = 権限名に無効な文字が含まれて
 
 # サーバー更新エラー
 err-admin-required = 管理者権限が必要です
+err-cannot-transfer-admin-to-self = 自分自身に管理者権限を譲渡することはできません
+err-already-admin = 「{ $username }」はすでに管理者です
+err-notice-url-invalid = リンクは http:// または https:// で始まり、空白を含まない必要があります
+err-notice-url-too-long = リンクが長すぎます（最大{ $max_length }文字）
 err-server-name-empty = サーバー名を空にすることはできません
 err-server-name-too-long = サーバー名が長すぎます（最大{ $max_length }文字）
 err-server-name-contains-newlines = サーバー名に改行を含めることはできません
@@ -102,8 +149,14 @@ err-server-description-too-long = サーバーの説明が長すぎます（最
 err-server-description-contains-newlines = サーバーの説明に改行を含めることはできません
 err-server-description-invalid-characters = サーバーの説明に無効な文字が含まれています
 err-max-connections-per-ip-invalid = IPあたりの最大接続数は0より大きくなければなりません
+err-max-message-length-invalid = メッセージの最大長は 1 から { $max_length } の間で指定してください
+err-max-topic-length-invalid = トピックの最大長は 1 から { $max_length } の間で指定してください
 err-no-fields-to-update = 更新するフィールドがありません
 
 err-server-image-too-large = サーバー画像が大きすぎます（最大512KB）
 err-server-image-invalid-format = サーバー画像の形式が無効です（base64エンコードのデータURIである必要があります）
 err-server-image-unsupported-type = サポートされていないサーバー画像タイプです（PNG、WebP、JPEG、SVGのみ）
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = 前提となる権限も付与しました: { $permissions }
+warn-permission-prerequisites-missing = 付与した権限の一部は次の権限がないと機能しません: { $permissions }
@@ -5,6 +5,9 @@ err-not-logged-in = 로그인되지 않음
 err-avatar-invalid-format = 아바타 형식이 잘못되었습니다 (base64 인코딩된 데이터 URI여야 합니다)
 err-avatar-too-large = 아바타가 너무 큽니다 (최대 { $max_length }자)
 err-avatar-unsupported-type = 지원되지 않는 아바타 유형입니다 (PNG, WebP 또는 SVG만 가능)
+err-avatar-undecodable = 아바타 이미지를 디코딩할 수 없습니다
+err-avatar-dimensions-too-small = 아바타 이미지가 너무 작습니다 (각 변 최소 { $min_dimension }픽셀)
+err-avatar-dimensions-too-large = 아바타 이미지가 너무 큽니다 (각 변 최대 { $max_dimension }픽셀)
 err-authentication = 인증 오류
 err-invalid-credentials = 잘못된 사용자 이름 또는 비밀번호
 err-handshake-required = 핸드셰이크 필요
@@ -12,6 +15,9 @@ err-already-logged-in = 이미 로그인됨
 err-handshake-already-completed = 핸드셰이크가 이미 완료됨
 err-account-deleted = 계정이 삭제되었습니다
 err-account-disabled-by-admin = 관리자가 계정을 비활성화했습니다
+err-guest-name-in-use = 게스트 이름 '{ $username }'은(는) 이미 사용 중입니다
+err-guest-name-reserved = '{ $prefix }'(으)로 시작하는 이름은 게스트용으로 예약되어 있습니다
+err-guest-not-allowed = 게스트는 이 작업을 할 수 없습니다. 계정으로 로그인하세요
 
 # 권한 및 액세스 오류
 err-permission-denied = 권한이 거부됨
@@ -24,6 +30,7 @@ err-database = 데이터베이스 오류
 
 # 메시지 형식 오류
 err-invalid-message-format = 잘못된 메시지 형식
+err-payload-too-large = 요청이 너무 큽니다 (최대 { $max_length }바이트)
 
 # 사용자 관리 오류
 err-cannot-delete-last-admin = 마지막 관리자를 삭제할 수 없습니다
@@ -33,7 +40,16 @@ err-cannot-edit-self = 자신을 편집할 수 없습니다
 err-cannot-create-admin = 관리자만 관리자 사용자를 만들 수 있습니다
 err-cannot-kick-self = 자기 자신을 추방할 수 없습니다
 err-cannot-kick-admin = 관리자 사용자를 추방할 수 없습니다
+err-cannot-kick-own-session = 사용 중인 세션은 연결을 끊을 수 없습니다
+err-session-not-found = ID { $session_id }인 세션이 온라인 상태가 아닙니다
+err-session-limit = 이 계정은 이미 최대 { $max_sessions }개의 세션이 온라인 상태입니다. 먼저 다른 기기에서 로그아웃하세요
+err-session-replaced = 이 계정이 다른 기기에서 로그인하여 연결이 끊어졌습니다
 err-cannot-message-self = 자기 자신에게 메시지를 보낼 수 없습니다
+err-e2ee-invalid-key = 잘못된 암호화 키입니다
+err-e2ee-invalid-message = 잘못된 암호화 메시지입니다
+err-e2ee-keys-changed = "{ $username }"의 암호화 키가 변경되었습니다. 메시지를 다시 보내세요
+err-server-maintenance = 서버가 유지 관리 모드입니다. 현재 변경할 수 없습니다
+err-feature-disabled = 이 기능은 이 서버에서 비활성화되어 있습니다
 err-cannot-disable-last-admin = 마지막 관리자를 비활성화할 수 없습니다
 
 # 채팅 주제 오류
@@ -61,7 +77,6 @@ err-features-invalid-characters = 기능 이름에 잘못된 문자가 포함되
 
 # 메시지 검증 오류
 err-message-empty = 메시지는 비어 있을 수 없습니다
-err-message-contains-newlines = 메시지에 줄 바꿈을 포함할 수 없습니다
 err-message-invalid-characters = 메시지에 잘못된 문자가 포함되어 있습니다
 
 # 사용자 이름 검증 오류
@@ -72,11 +87,39 @@ err-username-invalid = 사용자 이름에 잘못된 문자가 포함되어 있
 err-unknown-permission = 알 수 없는 권한: '{ $permission }'
 
 # 동적 오류 메시지 (매개변수 포함)
+err-announcement-limit-reached = 공지는 최대 { $max_count }개까지 등록할 수 있습니다. 먼저 하나를 삭제하세요
+err-announcement-not-found = { $number }번 공지가 없습니다
+err-page-content-empty = 페이지 내용은 비워 둘 수 없습니다
+err-page-content-invalid-characters = 페이지 내용에 잘못된 문자가 포함되어 있습니다
+err-page-content-too-long = 페이지 내용이 너무 깁니다 (최대 { $max_length }자)
+err-page-limit-reached = 페이지는 최대 { $max_count }개까지 만들 수 있습니다. 먼저 하나를 삭제하세요
+err-page-name-invalid = 페이지 이름에는 영문자, 숫자, '-', '_'만 사용할 수 있습니다
+err-page-name-too-long = 페이지 이름이 너무 깁니다 (최대 { $max_length }자)
+err-page-not-found = '{ $name }' 페이지가 없습니다
 err-broadcast-too-long = 메시지가 너무 깁니다 (최대 { $max_length }자)
 err-chat-too-long = 메시지가 너무 깁니다 (최대 { $max_length }자)
+err-chat-slow-mode = 슬로우 모드가 활성화되어 있습니다. 다음 메시지를 보내기 전에 { $seconds }초 기다려 주세요
+err-command-cooldown = 이 요청을 반복하려면 { $seconds }초 기다려 주세요
+err-chat-edit-window-expired = 메시지는 보낸 후 { $minutes }분 이내에만 변경할 수 있습니다
+err-chat-message-not-found = 메시지를 찾을 수 없거나 너무 오래되어 변경할 수 없습니다
+err-chat-not-own-message = 자신의 메시지만 변경할 수 있습니다
+err-pin-already-pinned = 메시지가 이미 고정되어 있습니다
+err-pin-limit-reached = 메시지는 최대 { $max_count }개까지 고정할 수 있습니다. 먼저 하나를 고정 해제하세요
+err-pin-message-not-found = 메시지를 찾을 수 없거나 너무 오래되어 고정할 수 없습니다
+err-pin-not-pinned = 메시지가 고정되어 있지 않습니다
+err-preferences-not-object = 환경설정은 JSON 객체여야 합니다
+err-preferences-too-large = 환경설정이 너무 큽니다 (최대 { $max_length }바이트)
+err-bio-too-long = 소개가 너무 깁니다 (최대 { $max_length }자)
+err-bio-contains-newlines = 소개에는 줄바꿈을 포함할 수 없습니다
+err-bio-invalid-characters = 소개에 잘못된 문자가 포함되어 있습니다
+err-pronouns-too-long = 대명사가 너무 깁니다 (최대 { $max_length }자)
+err-pronouns-contains-newlines = 대명사에는 줄바꿈을 포함할 수 없습니다
+err-pronouns-invalid-characters = 대명사에 잘못된 문자가 포함되어 있습니다
+err-rules-not-accepted = 채팅하기 전에 서버 규칙에 동의해야 합니다
 err-topic-too-long = 주제는 { $max_length }자를 초과할 수 없습니다
 err-version-major-mismatch = 호환되지 않는 프로토콜 버전: 서버는 버전 { $server_major }.x, 클라이언트는 버전 { $client_major }.x입니다
 err-version-client-too-new = 클라이언트 버전 { $client_version }이(가) 서버 버전 { $server_version }보다 최신입니다. 서버를 업데이트하거나 이전 클라이언트를 사용하세요.
+err-version-client-too-old = 클라이언트 버전 { $client_version }이(가) 너무 오래되었습니다. 이 서버는 { $min_version } 이상이 필요합니다. 클라이언트를 업데이트하세요.
 err-kicked-by = { $username }에게 추방당했습니다
 err-username-exists = 사용자 이름 "{ $username }"이(가) 이미 존재합니다
 err-user-not-found = 사용자 "{ $username }"을(를) 찾을 수 없습니다
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = 권한 이름에 잘못된 문자가 포함
 
 # 서버 업데이트 오류
 err-admin-required = 관리자 권한이 필요합니다
+err-cannot-transfer-admin-to-self = 자신에게 관리자 권한을 이전할 수 없습니다
+err-already-admin = '{ $username }'은(는) 이미 관리자입니다
+err-notice-url-invalid = 링크는 http:// 또는 https://로 시작해야 하며 공백을 포함할 수 없습니다
+err-notice-url-too-long = 링크가 너무 깁니다 (최대 { $max_length }자)
 err-server-name-empty = 서버 이름은 비어 있을 수 없습니다
 err-server-name-too-long = 서버 이름이 너무 깁니다 (최대 { $max_length }자)
 err-server-name-contains-newlines = 서버 이름에 줄 바꿈을 포함할 수 없습니다
@@ -102,8 +149,14 @@ err-server-description-too-long = 서버 설명이 너무 깁니다 (최대 { $m
 err-server-description-contains-newlines = 서버 설명에 줄 바꿈을 포함할 수 없습니다
 err-server-description-invalid-characters = 서버 설명에 잘못된 문자가 포함되어 있습니다
 err-max-connections-per-ip-invalid = IP당 최대 연결 수는 0보다 커야 합니다
+err-max-message-length-invalid = 최대 메시지 길이는 1에서 { $max_length } 사이여야 합니다
+err-max-topic-length-invalid = 최대 주제 길이는 1에서 { $max_length } 사이여야 합니다
 err-no-fields-to-update = 업데이트할 필드가 없습니다
 
 err-server-image-too-large = 서버 이미지가 너무 큽니다 (최대 512KB)
 err-server-image-invalid-format = 서버 이미지 형식이 잘못되었습니다 (base64 인코딩된 데이터 URI여야 합니다)
 err-server-image-unsupported-type = 지원되지 않는 서버 이미지 유형입니다 (PNG, WebP, JPEG 또는 SVG만 지원)
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = 필요한 권한도 함께 부여했습니다: { $permissions }
+warn-permission-prerequisites-missing = 일부 부여된 권한은 다음 권한 없이는 작동하지 않습니다: { $permissions }
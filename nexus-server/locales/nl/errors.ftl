@@ -5,6 +5,9 @@ err-not-logged-in = Niet ingelogd
 err-avatar-invalid-format = Ongeldig avatar-formaat (moet een data-URI zijn met base64-codering)
 err-avatar-too-large = Avatar is te groot (max. { $max_length } tekens)
 err-avatar-unsupported-type = Niet-ondersteund avatar-type (alleen PNG, WebP of SVG)
+err-avatar-undecodable = Avatarafbeelding kon niet worden gedecodeerd
+err-avatar-dimensions-too-small = Avatarafbeelding is te klein (min. { $min_dimension } pixels per zijde)
+err-avatar-dimensions-too-large = Avatarafbeelding is te groot (max. { $max_dimension } pixels per zijde)
 err-authentication = Authenticatiefout
 err-invalid-credentials = Ongeldige gebruikersnaam of wachtwoord
 err-handshake-required = Handshake vereist
@@ -12,6 +15,9 @@ err-already-logged-in = Al ingelogd
 err-handshake-already-completed = Handshake al voltooid
 err-account-deleted = Uw account is verwijderd
 err-account-disabled-by-admin = Account uitgeschakeld door beheerder
+err-guest-name-in-use = De gastnaam '{ $username }' is al in gebruik
+err-guest-name-reserved = Namen die beginnen met '{ $prefix }' zijn gereserveerd voor gasten
+err-guest-not-allowed = Gasten kunnen dat niet; log in met een account
 
 # Permissie- en toegangsfouten
 err-permission-denied = Toestemming geweigerd
@@ -24,6 +30,7 @@ err-database = Databasefout
 
 # Berichtformaatfouten
 err-invalid-message-format = Ongeldig berichtformaat
+err-payload-too-large = Verzoek te groot (maximaal { $max_length } bytes)
 
 # Gebruikersbeheersfouten
 err-cannot-delete-last-admin = Kan de laatste beheerder niet verwijderen
@@ -33,7 +40,16 @@ err-cannot-edit-self = U kunt uzelf niet bewerken
 err-cannot-create-admin = Alleen beheerders kunnen beheerdergebruikers aanmaken
 err-cannot-kick-self = U kunt uzelf niet verwijderen
 err-cannot-kick-admin = Kan beheerdergebruikers niet verwijderen
+err-cannot-kick-own-session = U kunt de sessie die u gebruikt niet verbreken
+err-session-not-found = Er is geen sessie met ID { $session_id } online
+err-session-limit = Dit account heeft al het maximum van { $max_sessions } sessies online; meld je eerst af op een ander apparaat
+err-session-replaced = Verbinding verbroken omdat dit account op een ander apparaat is ingelogd
 err-cannot-message-self = U kunt geen berichten naar uzelf sturen
+err-e2ee-invalid-key = Ongeldige versleutelingssleutel
+err-e2ee-invalid-message = Ongeldig versleuteld bericht
+err-e2ee-keys-changed = De versleutelingssleutels van "{ $username }" zijn gewijzigd; verstuur het bericht opnieuw
+err-server-maintenance = De server is in onderhoudsmodus; wijzigingen zijn voorlopig uitgeschakeld
+err-feature-disabled = Deze functie is uitgeschakeld op deze server
 err-cannot-disable-last-admin = Kan de laatste beheerder niet uitschakelen
 
 # Chatonderwerpfouten
@@ -61,7 +77,6 @@ err-features-invalid-characters = De functienaam bevat ongeldige tekens
 
 # Berichtvalidatiefouten
 err-message-empty = Het bericht mag niet leeg zijn
-err-message-contains-newlines = Het bericht mag geen regeleinden bevatten
 err-message-invalid-characters = Het bericht bevat ongeldige tekens
 
 # Gebruikersnaamvalidatiefouten
@@ -72,11 +87,39 @@ err-username-invalid = De gebruikersnaam bevat ongeldige tekens (letters, cijfer
 err-unknown-permission = Onbekende machtiging: '{ $permission }'
 
 # Dynamische foutmeldingen (met parameters)
+err-announcement-limit-reached = Er kunnen niet meer dan { $max_count } aankondigingen zijn. Verwijder er eerst een
+err-announcement-not-found = Geen aankondiging met nummer { $number }
+err-page-content-empty = Pagina-inhoud mag niet leeg zijn
+err-page-content-invalid-characters = Pagina-inhoud bevat ongeldige tekens
+err-page-content-too-long = Pagina-inhoud te lang (max { $max_length } tekens)
+err-page-limit-reached = Er kunnen niet meer dan { $max_count } pagina's zijn. Verwijder er eerst een
+err-page-name-invalid = Paginanamen mogen alleen letters, cijfers, '-' en '_' bevatten
+err-page-name-too-long = Paginanaam te lang (max { $max_length } tekens)
+err-page-not-found = Geen pagina met de naam '{ $name }'
 err-broadcast-too-long = Bericht te lang (maximaal { $max_length } tekens)
 err-chat-too-long = Bericht te lang (maximaal { $max_length } tekens)
+err-chat-slow-mode = Langzame modus is ingeschakeld. Wacht { $seconds } seconden voordat je een nieuw bericht verstuurt
+err-command-cooldown = Wacht { $seconds } seconden voordat je dit verzoek herhaalt
+err-chat-edit-window-expired = Berichten kunnen alleen binnen { $minutes } minuten na verzenden worden gewijzigd
+err-chat-message-not-found = Bericht niet gevonden of te oud om te wijzigen
+err-chat-not-own-message = Je kunt alleen je eigen berichten wijzigen
+err-pin-already-pinned = Bericht is al vastgemaakt
+err-pin-limit-reached = Er kunnen niet meer dan { $max_count } berichten worden vastgemaakt. Maak er eerst een los
+err-pin-message-not-found = Bericht niet gevonden of te oud om vast te maken
+err-pin-not-pinned = Bericht is niet vastgemaakt
+err-preferences-not-object = Voorkeuren moeten een JSON-object zijn
+err-preferences-too-large = Voorkeuren zijn te groot (max. { $max_length } bytes)
+err-bio-too-long = Bio is te lang (max { $max_length } tekens)
+err-bio-contains-newlines = Bio mag geen regeleinden bevatten
+err-bio-invalid-characters = Bio bevat ongeldige tekens
+err-pronouns-too-long = Voornaamwoorden zijn te lang (max { $max_length } tekens)
+err-pronouns-contains-newlines = Voornaamwoorden mogen geen regeleinden bevatten
+err-pronouns-invalid-characters = Voornaamwoorden bevatten ongeldige tekens
+err-rules-not-accepted = Je moet de serverregels accepteren voordat je kunt chatten
 err-topic-too-long = Het onderwerp mag niet meer dan { $max_length } tekens bevatten
 err-version-major-mismatch = Incompatibele protocolversie: server is versie { $server_major }.x, client is versie { $client_major }.x
 err-version-client-too-new = Clientversie { $client_version } is nieuwer dan serverversie { $server_version }. Werk de server bij of gebruik een oudere client.
+err-version-client-too-old = Clientversie { $client_version } is te oud; deze server vereist { $min_version } of nieuwer. Werk je client bij.
 err-kicked-by = U bent verwijderd door { $username }
 err-username-exists = De gebruikersnaam "{ $username }" bestaat al
 err-user-not-found = Gebruiker "{ $username }" niet gevonden
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = De machtigingsnaam bevat ongeldige tekens
 
 # Serverupdatefouten
 err-admin-required = Beheerdersrechten vereist
+err-cannot-transfer-admin-to-self = Je kunt beheerdersrechten niet aan jezelf overdragen
+err-already-admin = '{ $username }' is al beheerder
+err-notice-url-invalid = De link moet beginnen met http:// of https:// en mag geen spaties bevatten
+err-notice-url-too-long = De link is te lang (max. { $max_length } tekens)
 err-server-name-empty = De servernaam mag niet leeg zijn
 err-server-name-too-long = De servernaam is te lang (maximaal { $max_length } tekens)
 err-server-name-contains-newlines = De servernaam mag geen regeleinden bevatten
@@ -102,8 +149,14 @@ err-server-description-too-long = De serverbeschrijving is te lang (maximaal { $
 err-server-description-contains-newlines = De serverbeschrijving mag geen regeleinden bevatten
 err-server-description-invalid-characters = De serverbeschrijving bevat ongeldige tekens
 err-max-connections-per-ip-invalid = Maximale verbindingen per IP moet groter zijn dan 0
+err-max-message-length-invalid = De maximale berichtlengte moet tussen 1 en { $max_length } liggen
+err-max-topic-length-invalid = De maximale onderwerplengte moet tussen 1 en { $max_length } liggen
 err-no-fields-to-update = Geen velden om bij te werken
 
 err-server-image-too-large = De serverafbeelding is te groot (maximaal 512KB)
 err-server-image-invalid-format = Ongeldig serverafbeeldingsformaat (moet een data-URI met base64-codering zijn)
 err-server-image-unsupported-type = Niet-ondersteund serverafbeeldingstype (alleen PNG, WebP, JPEG of SVG)
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = Ook de vereiste rechten toegekend: { $permissions }
+warn-permission-prerequisites-missing = Sommige toegekende rechten werken niet zonder: { $permissions }
@@ -5,6 +5,9 @@ err-not-logged-in = Não conectado
 err-avatar-invalid-format = Formato de avatar inválido (deve ser uma URI de dados com codificação base64)
 err-avatar-too-large = O avatar é muito grande (máx. { $max_length } caracteres)
 err-avatar-unsupported-type = Tipo de avatar não suportado (apenas PNG, WebP ou SVG)
+err-avatar-undecodable = Não foi possível decodificar a imagem do avatar
+err-avatar-dimensions-too-small = A imagem do avatar é pequena demais (mín. { $min_dimension } pixels por lado)
+err-avatar-dimensions-too-large = A imagem do avatar é grande demais (máx. { $max_dimension } pixels por lado)
 err-authentication = Erro de autenticação
 err-invalid-credentials = Nome de usuário ou senha inválidos
 err-handshake-required = Handshake necessário
@@ -12,6 +15,9 @@ err-already-logged-in = Já conectado
 err-handshake-already-completed = Handshake já concluído
 err-account-deleted = Sua conta foi excluída
 err-account-disabled-by-admin = Conta desativada pelo administrador
+err-guest-name-in-use = O nome de convidado '{ $username }' já está em uso
+err-guest-name-reserved = Nomes que começam com '{ $prefix }' são reservados para convidados
+err-guest-not-allowed = Convidados não podem fazer isso; entre com uma conta
 
 # Erros de permissão e acesso
 err-permission-denied = Permissão negada
@@ -24,6 +30,7 @@ err-database = Erro de banco de dados
 
 # Erros de formato de mensagem
 err-invalid-message-format = Formato de mensagem inválido
+err-payload-too-large = Solicitação muito grande (máximo { $max_length } bytes)
 
 # Erros de gerenciamento de usuários
 err-cannot-delete-last-admin = Não é possível excluir o último administrador
@@ -33,7 +40,16 @@ err-cannot-edit-self = Você não pode editar a si mesmo
 err-cannot-create-admin = Apenas administradores podem criar usuários administradores
 err-cannot-kick-self = Você não pode expulsar a si mesmo
 err-cannot-kick-admin = Não é possível expulsar usuários administradores
+err-cannot-kick-own-session = Você não pode desconectar a sessão que está usando
+err-session-not-found = Nenhuma sessão com ID { $session_id } está online
+err-session-limit = Esta conta já tem o máximo de { $max_sessions } sessões online; saia em outro dispositivo primeiro
+err-session-replaced = Desconectado porque esta conta entrou em outro dispositivo
 err-cannot-message-self = Você não pode enviar mensagem para si mesmo
+err-e2ee-invalid-key = Chave de criptografia inválida
+err-e2ee-invalid-message = Mensagem criptografada inválida
+err-e2ee-keys-changed = As chaves de criptografia de "{ $username }" mudaram; envie a mensagem novamente
+err-server-maintenance = O servidor está em modo de manutenção; alterações estão desativadas por enquanto
+err-feature-disabled = Este recurso está desativado neste servidor
 err-cannot-disable-last-admin = Não é possível desabilitar o último administrador
 
 # Erros de tópico de chat
@@ -61,7 +77,6 @@ err-features-invalid-characters = O nome do recurso contém caracteres inválido
 
 # Erros de validação de mensagem
 err-message-empty = A mensagem não pode estar vazia
-err-message-contains-newlines = A mensagem não pode conter quebras de linha
 err-message-invalid-characters = A mensagem contém caracteres inválidos
 
 # Erros de validação de nome de usuário
@@ -72,11 +87,39 @@ err-username-invalid = O nome de usuário contém caracteres inválidos (letras,
 err-unknown-permission = Permissão desconhecida: '{ $permission }'
 
 # Mensagens de erro dinâmicas (com parâmetros)
+err-announcement-limit-reached = Não é possível ter mais de { $max_count } anúncios. Remova um primeiro
+err-announcement-not-found = Não existe o anúncio número { $number }
+err-page-content-empty = O conteúdo da página não pode ficar vazio
+err-page-content-invalid-characters = O conteúdo da página contém caracteres inválidos
+err-page-content-too-long = Conteúdo da página muito longo (máx. { $max_length } caracteres)
+err-page-limit-reached = Não é possível ter mais de { $max_count } páginas. Exclua uma primeiro
+err-page-name-invalid = Nomes de página só podem usar letras, números, '-' e '_'
+err-page-name-too-long = Nome da página muito longo (máx. { $max_length } caracteres)
+err-page-not-found = Nenhuma página chamada '{ $name }'
 err-broadcast-too-long = Mensagem muito longa (máximo { $max_length } caracteres)
 err-chat-too-long = Mensagem muito longa (máximo { $max_length } caracteres)
+err-chat-slow-mode = O modo lento está ativado. Aguarde { $seconds } segundos antes de enviar outra mensagem
+err-command-cooldown = Aguarde { $seconds } segundos antes de repetir esta solicitação
+err-chat-edit-window-expired = As mensagens só podem ser alteradas até { $minutes } minutos após o envio
+err-chat-message-not-found = Mensagem não encontrada ou antiga demais para alterar
+err-chat-not-own-message = Você só pode alterar suas próprias mensagens
+err-pin-already-pinned = A mensagem já está fixada
+err-pin-limit-reached = Não é possível fixar mais de { $max_count } mensagens. Desafixe uma primeiro
+err-pin-message-not-found = Mensagem não encontrada ou antiga demais para fixar
+err-pin-not-pinned = A mensagem não está fixada
+err-preferences-not-object = As preferências devem ser um objeto JSON
+err-preferences-too-large = As preferências são muito grandes (máx. { $max_length } bytes)
+err-bio-too-long = A bio é muito longa (máx. { $max_length } caracteres)
+err-bio-contains-newlines = A bio não pode conter quebras de linha
+err-bio-invalid-characters = A bio contém caracteres inválidos
+err-pronouns-too-long = Os pronomes são muito longos (máx. { $max_length } caracteres)
+err-pronouns-contains-newlines = Os pronomes não podem conter quebras de linha
+err-pronouns-invalid-characters = Os pronomes contêm caracteres inválidos
+err-rules-not-accepted = Você deve aceitar as regras do servidor antes de conversar
 err-topic-too-long = O tópico não pode exceder { $max_length } caracteres
 err-version-major-mismatch = Versão de protocolo incompatível: o servidor é versão { $server_major }.x, o cliente é versão { $client_major }.x
 err-version-client-too-new = A versão do cliente { $client_version } é mais recente que a versão do servidor { $server_version }. Por favor, atualize o servidor ou use um cliente mais antigo.
+err-version-client-too-old = A versão do cliente { $client_version } é muito antiga; este servidor exige { $min_version } ou mais recente. Atualize seu cliente.
 err-kicked-by = Você foi expulso por { $username }
 err-username-exists = O nome de usuário "{ $username }" já existe
 err-user-not-found = Usuário "{ $username }" não encontrado
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = O nome da permissão contém caracteres inv
 
 # Erros de atualização do servidor
 err-admin-required = Privilégios de administrador necessários
+err-cannot-transfer-admin-to-self = Você não pode transferir a administração para si mesmo
+err-already-admin = '{ $username }' já é administrador
+err-notice-url-invalid = O link deve começar com http:// ou https:// e não conter espaços
+err-notice-url-too-long = O link é longo demais (máx. { $max_length } caracteres)
 err-server-name-empty = O nome do servidor não pode estar vazio
 err-server-name-too-long = O nome do servidor é muito longo (máximo { $max_length } caracteres)
 err-server-name-contains-newlines = O nome do servidor não pode conter quebras de linha
@@ -102,8 +149,14 @@ err-server-description-too-long = A descrição do servidor é muito longa (máx
 err-server-description-contains-newlines = A descrição do servidor não pode conter quebras de linha
 err-server-description-invalid-characters = A descrição do servidor contém caracteres inválidos
 err-max-connections-per-ip-invalid = Conexões máximas por IP deve ser maior que 0
+err-max-message-length-invalid = O tamanho máximo da mensagem deve estar entre 1 e { $max_length }
+err-max-topic-length-invalid = O tamanho máximo do tópico deve estar entre 1 e { $max_length }
 err-no-fields-to-update = Nenhum campo para atualizar
 
 err-server-image-too-large = A imagem do servidor é muito grande (máximo 512KB)
 err-server-image-invalid-format = Formato de imagem do servidor inválido (deve ser uma URI de dados com codificação base64)
 err-server-image-unsupported-type = Tipo de imagem do servidor não suportado (apenas PNG, WebP, JPEG ou SVG)
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = Também foram concedidas as permissões necessárias: { $permissions }
+warn-permission-prerequisites-missing = Algumas permissões concedidas não funcionarão sem: { $permissions }
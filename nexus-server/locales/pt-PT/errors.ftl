@@ -5,6 +5,9 @@ err-not-logged-in = Sessão não iniciada
 err-avatar-invalid-format = Formato de avatar inválido (deve ser uma URI de dados com codificação base64)
 err-avatar-too-large = O avatar é demasiado grande (máx. { $max_length } caracteres)
 err-avatar-unsupported-type = Tipo de avatar não suportado (apenas PNG, WebP ou SVG)
+err-avatar-undecodable = Não foi possível descodificar a imagem do avatar
+err-avatar-dimensions-too-small = A imagem do avatar é demasiado pequena (mín. { $min_dimension } píxeis por lado)
+err-avatar-dimensions-too-large = A imagem do avatar é demasiado grande (máx. { $max_dimension } píxeis por lado)
 err-authentication = Erro de autenticação
 err-invalid-credentials = Nome de utilizador ou palavra-passe inválidos
 err-handshake-required = Handshake necessário
@@ -12,6 +15,9 @@ err-already-logged-in = Sessão já iniciada
 err-handshake-already-completed = Handshake já concluído
 err-account-deleted = A sua conta foi eliminada
 err-account-disabled-by-admin = Conta desativada pelo administrador
+err-guest-name-in-use = O nome de convidado '{ $username }' já está em utilização
+err-guest-name-reserved = Os nomes começados por '{ $prefix }' estão reservados para convidados
+err-guest-not-allowed = Os convidados não podem fazer isso; inicie sessão com uma conta
 
 # Erros de permissão e acesso
 err-permission-denied = Permissão negada
@@ -24,6 +30,7 @@ err-database = Erro de base de dados
 
 # Erros de formato de mensagem
 err-invalid-message-format = Formato de mensagem inválido
+err-payload-too-large = Pedido demasiado grande (máximo { $max_length } bytes)
 
 # Erros de gestão de utilizadores
 err-cannot-delete-last-admin = Não é possível eliminar o último administrador
@@ -33,7 +40,16 @@ err-cannot-edit-self = Não pode editar-se a si próprio
 err-cannot-create-admin = Apenas administradores podem criar utilizadores administradores
 err-cannot-kick-self = Não pode expulsar-se a si mesmo
 err-cannot-kick-admin = Não é possível expulsar utilizadores administradores
+err-cannot-kick-own-session = Não pode desligar a sessão que está a utilizar
+err-session-not-found = Nenhuma sessão com o ID { $session_id } está online
+err-session-limit = Esta conta já tem o máximo de { $max_sessions } sessões online; termine a sessão noutro dispositivo primeiro
+err-session-replaced = Desligado porque esta conta iniciou sessão noutro dispositivo
 err-cannot-message-self = Não pode enviar mensagens a si mesmo
+err-e2ee-invalid-key = Chave de encriptação inválida
+err-e2ee-invalid-message = Mensagem encriptada inválida
+err-e2ee-keys-changed = As chaves de encriptação de "{ $username }" mudaram; envie a mensagem novamente
+err-server-maintenance = O servidor está em modo de manutenção; as alterações estão desativadas por agora
+err-feature-disabled = Esta funcionalidade está desativada neste servidor
 err-cannot-disable-last-admin = Não é possível desativar o último administrador
 
 # Erros de tópico de chat
@@ -61,7 +77,6 @@ err-features-invalid-characters = O nome da funcionalidade contém caracteres in
 
 # Erros de validação de mensagem
 err-message-empty = A mensagem não pode estar vazia
-err-message-contains-newlines = A mensagem não pode conter quebras de linha
 err-message-invalid-characters = A mensagem contém caracteres inválidos
 
 # Erros de validação de nome de utilizador
@@ -72,11 +87,39 @@ err-username-invalid = O nome de utilizador contém caracteres inválidos (letra
 err-unknown-permission = Permissão desconhecida: '{ $permission }'
 
 # Mensagens de erro dinâmicas (com parâmetros)
+err-announcement-limit-reached = Não é possível ter mais de { $max_count } anúncios. Remova um primeiro
+err-announcement-not-found = Não existe o anúncio número { $number }
+err-page-content-empty = O conteúdo da página não pode estar vazio
+err-page-content-invalid-characters = O conteúdo da página contém caracteres inválidos
+err-page-content-too-long = Conteúdo da página demasiado longo (máx. { $max_length } caracteres)
+err-page-limit-reached = Não é possível ter mais de { $max_count } páginas. Elimine uma primeiro
+err-page-name-invalid = Os nomes de página só podem usar letras, números, '-' e '_'
+err-page-name-too-long = Nome da página demasiado longo (máx. { $max_length } caracteres)
+err-page-not-found = Nenhuma página chamada '{ $name }'
 err-broadcast-too-long = Mensagem demasiado longa (máximo { $max_length } caracteres)
 err-chat-too-long = Mensagem demasiado longa (máximo { $max_length } caracteres)
+err-chat-slow-mode = O modo lento está ativado. Aguarde { $seconds } segundos antes de enviar outra mensagem
+err-command-cooldown = Aguarde { $seconds } segundos antes de repetir este pedido
+err-chat-edit-window-expired = As mensagens só podem ser alteradas até { $minutes } minutos após o envio
+err-chat-message-not-found = Mensagem não encontrada ou demasiado antiga para alterar
+err-chat-not-own-message = Só pode alterar as suas próprias mensagens
+err-pin-already-pinned = A mensagem já está afixada
+err-pin-limit-reached = Não é possível afixar mais de { $max_count } mensagens. Desafixe uma primeiro
+err-pin-message-not-found = Mensagem não encontrada ou demasiado antiga para afixar
+err-pin-not-pinned = A mensagem não está afixada
+err-preferences-not-object = As preferências devem ser um objeto JSON
+err-preferences-too-large = As preferências são demasiado grandes (máx. { $max_length } bytes)
+err-bio-too-long = A bio é demasiado longa (máx. { $max_length } caracteres)
+err-bio-contains-newlines = A bio não pode conter quebras de linha
+err-bio-invalid-characters = A bio contém caracteres inválidos
+err-pronouns-too-long = Os pronomes são demasiado longos (máx. { $max_length } caracteres)
+err-pronouns-contains-newlines = Os pronomes não podem conter quebras de linha
+err-pronouns-invalid-characters = Os pronomes contêm caracteres inválidos
+err-rules-not-accepted = Tem de aceitar as regras do servidor antes de conversar
 err-topic-too-long = O tópico não pode exceder { $max_length } caracteres
 err-version-major-mismatch = Versão de protocolo incompatível: o servidor é versão { $server_major }.x, o cliente é versão { $client_major }.x
 err-version-client-too-new = A versão do cliente { $client_version } é mais recente que a versão do servidor { $server_version }. Por favor atualize o servidor ou use um cliente mais antigo.
+err-version-client-too-old = A versão do cliente { $client_version } é demasiado antiga; este servidor exige { $min_version } ou mais recente. Atualize o seu cliente.
 err-kicked-by = Foi expulso por { $username }
 err-username-exists = O nome de utilizador "{ $username }" já existe
 err-user-not-found = Utilizador "{ $username }" não encontrado
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = O nome da permissão contém caracteres inv
 
 # Erros de atualização do servidor
 err-admin-required = Privilégios de administrador necessários
+err-cannot-transfer-admin-to-self = Não pode transferir a administração para si próprio
+err-already-admin = '{ $username }' já é administrador
+err-notice-url-invalid = A ligação deve começar por http:// ou https:// e não conter espaços
+err-notice-url-too-long = A ligação é demasiado longa (máx. { $max_length } caracteres)
 err-server-name-empty = O nome do servidor não pode estar vazio
 err-server-name-too-long = O nome do servidor é demasiado longo (máximo { $max_length } caracteres)
 err-server-name-contains-newlines = O nome do servidor não pode conter quebras de linha
@@ -102,8 +149,14 @@ err-server-description-too-long = A descrição do servidor é demasiado longa (
 err-server-description-contains-newlines = A descrição do servidor não pode conter quebras de linha
 err-server-description-invalid-characters = A descrição do servidor contém caracteres inválidos
 err-max-connections-per-ip-invalid = Ligações máximas por IP deve ser maior que 0
+err-max-message-length-invalid = O tamanho máximo da mensagem tem de estar entre 1 e { $max_length }
+err-max-topic-length-invalid = O tamanho máximo do tópico tem de estar entre 1 e { $max_length }
 err-no-fields-to-update = Nenhum campo para atualizar
 
 err-server-image-too-large = A imagem do servidor é demasiado grande (máximo 512KB)
 err-server-image-invalid-format = Formato de imagem do servidor inválido (deve ser um URI de dados com codificação base64)
 err-server-image-unsupported-type = Tipo de imagem do servidor não suportado (apenas PNG, WebP, JPEG ou SVG)
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = Foram também concedidas as permissões necessárias: { $permissions }
+warn-permission-prerequisites-missing = Algumas permissões concedidas não funcionarão sem: { $permissions }
@@ -5,6 +5,9 @@ err-not-logged-in = Не выполнен вход
 err-avatar-invalid-format = Недопустимый формат аватара (должен быть data URI с кодировкой base64)
 err-avatar-too-large = Аватар слишком большой (макс. { $max_length } символов)
 err-avatar-unsupported-type = Неподдерживаемый тип аватара (только PNG, WebP или SVG)
+err-avatar-undecodable = Не удалось декодировать изображение аватара
+err-avatar-dimensions-too-small = Изображение аватара слишком маленькое (мин. { $min_dimension } пикселей по стороне)
+err-avatar-dimensions-too-large = Изображение аватара слишком большое (макс. { $max_dimension } пикселей по стороне)
 err-authentication = Ошибка аутентификации
 err-invalid-credentials = Неверное имя пользователя или пароль
 err-handshake-required = Требуется рукопожатие
@@ -12,6 +15,9 @@ err-already-logged-in = Вы уже вошли в систему
 err-handshake-already-completed = Рукопожатие уже выполнено
 err-account-deleted = Ваша учетная запись удалена
 err-account-disabled-by-admin = Учетная запись отключена администратором
+err-guest-name-in-use = Гостевое имя '{ $username }' уже используется
+err-guest-name-reserved = Имена, начинающиеся с '{ $prefix }', зарезервированы для гостей
+err-guest-not-allowed = Гости не могут этого сделать; войдите с учётной записью
 
 # Ошибки прав доступа
 err-permission-denied = Доступ запрещен
@@ -24,6 +30,7 @@ err-database = Ошибка базы данных
 
 # Ошибки формата сообщения
 err-invalid-message-format = Неверный формат сообщения
+err-payload-too-large = Запрос слишком большой (максимум { $max_length } байт)
 
 # Ошибки управления пользователями
 err-cannot-delete-last-admin = Невозможно удалить последнего администратора
@@ -33,7 +40,16 @@ err-cannot-edit-self = Вы не можете редактировать себ
 err-cannot-create-admin = Только администраторы могут создавать пользователей-администраторов
 err-cannot-kick-self = Вы не можете выгнать себя
 err-cannot-kick-admin = Невозможно выгнать пользователей-администраторов
+err-cannot-kick-own-session = Нельзя отключить сеанс, который вы используете
+err-session-not-found = Сеанс с ID { $session_id } не в сети
+err-session-limit = У этой учётной записи уже максимум сеансов в сети ({ $max_sessions }); сначала выйдите на другом устройстве
+err-session-replaced = Отключено, потому что эта учётная запись вошла с другого устройства
 err-cannot-message-self = Вы не можете отправить сообщение себе
+err-e2ee-invalid-key = Недопустимый ключ шифрования
+err-e2ee-invalid-message = Недопустимое зашифрованное сообщение
+err-e2ee-keys-changed = Ключи шифрования пользователя "{ $username }" изменились; отправьте сообщение ещё раз
+err-server-maintenance = Сервер находится в режиме обслуживания; изменения временно отключены
+err-feature-disabled = Эта функция отключена на этом сервере
 err-cannot-disable-last-admin = Невозможно отключить последнего администратора
 
 # Ошибки темы чата
@@ -61,7 +77,6 @@ err-features-invalid-characters = Название функции содержи
 
 # Ошибки проверки сообщений
 err-message-empty = Сообщение не может быть пустым
-err-message-contains-newlines = Сообщение не может содержать переносы строк
 err-message-invalid-characters = Сообщение содержит недопустимые символы
 
 # Ошибки проверки имени пользователя
@@ -72,11 +87,39 @@ err-username-invalid = Имя пользователя содержит недо
 err-unknown-permission = Неизвестное разрешение: '{ $permission }'
 
 # Динамические сообщения об ошибках (с параметрами)
+err-announcement-limit-reached = Нельзя добавить больше { $max_count } объявлений. Сначала удалите одно
+err-announcement-not-found = Нет объявления с номером { $number }
+err-page-content-empty = Содержимое страницы не может быть пустым
+err-page-content-invalid-characters = Содержимое страницы содержит недопустимые символы
+err-page-content-too-long = Содержимое страницы слишком длинное (макс. { $max_length } символов)
+err-page-limit-reached = Нельзя создать больше { $max_count } страниц. Сначала удалите одну
+err-page-name-invalid = Имя страницы может содержать только латинские буквы, цифры, '-' и '_'
+err-page-name-too-long = Имя страницы слишком длинное (макс. { $max_length } символов)
+err-page-not-found = Страница "{ $name }" не найдена
 err-broadcast-too-long = Сообщение слишком длинное (максимум { $max_length } символов)
 err-chat-too-long = Сообщение слишком длинное (максимум { $max_length } символов)
+err-chat-slow-mode = Включён медленный режим. Подождите { $seconds } сек. перед отправкой следующего сообщения
+err-command-cooldown = Подождите { $seconds } сек., прежде чем повторить этот запрос
+err-chat-edit-window-expired = Сообщения можно изменить только в течение { $minutes } минут после отправки
+err-chat-message-not-found = Сообщение не найдено или слишком старое для изменения
+err-chat-not-own-message = Вы можете изменять только свои сообщения
+err-pin-already-pinned = Сообщение уже закреплено
+err-pin-limit-reached = Нельзя закрепить больше { $max_count } сообщений. Сначала открепите одно
+err-pin-message-not-found = Сообщение не найдено или слишком старое для закрепления
+err-pin-not-pinned = Сообщение не закреплено
+err-preferences-not-object = Настройки должны быть JSON-объектом
+err-preferences-too-large = Настройки слишком большие (макс. { $max_length } байт)
+err-bio-too-long = Описание слишком длинное (макс. { $max_length } символов)
+err-bio-contains-newlines = Описание не может содержать переносы строк
+err-bio-invalid-characters = Описание содержит недопустимые символы
+err-pronouns-too-long = Местоимения слишком длинные (макс. { $max_length } символов)
+err-pronouns-contains-newlines = Местоимения не могут содержать переносы строк
+err-pronouns-invalid-characters = Местоимения содержат недопустимые символы
+err-rules-not-accepted = Вы должны принять правила сервера, прежде чем общаться в чате
 err-topic-too-long = Тема не может превышать { $max_length } символов
 err-version-major-mismatch = Несовместимая версия протокола: сервер версии { $server_major }.x, клиент версии { $client_major }.x
 err-version-client-too-new = Версия клиента { $client_version } новее версии сервера { $server_version }. Пожалуйста, обновите сервер или используйте более старый клиент.
+err-version-client-too-old = Версия клиента { $client_version } устарела; этому серверу нужна { $min_version } или новее. Обновите клиент.
 err-kicked-by = Вы были выгнаны пользователем { $username }
 err-username-exists = Имя пользователя "{ $username }" уже существует
 err-user-not-found = Пользователь "{ $username }" не найден
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = Название разрешения со
 
 # Ошибки обновления сервера
 err-admin-required = Требуются права администратора
+err-cannot-transfer-admin-to-self = Нельзя передать права администратора самому себе
+err-already-admin = "{ $username }" уже является администратором
+err-notice-url-invalid = Ссылка должна начинаться с http:// или https:// и не содержать пробелов
+err-notice-url-too-long = Ссылка слишком длинная (макс. { $max_length } символов)
 err-server-name-empty = Имя сервера не может быть пустым
 err-server-name-too-long = Имя сервера слишком длинное (максимум { $max_length } символов)
 err-server-name-contains-newlines = Имя сервера не может содержать переносы строк
@@ -102,8 +149,14 @@ err-server-description-too-long = Описание сервера слишком
 err-server-description-contains-newlines = Описание сервера не может содержать переносы строк
 err-server-description-invalid-characters = Описание сервера содержит недопустимые символы
 err-max-connections-per-ip-invalid = Максимальное количество подключений на IP должно быть больше 0
+err-max-message-length-invalid = Максимальная длина сообщения должна быть от 1 до { $max_length }
+err-max-topic-length-invalid = Максимальная длина темы должна быть от 1 до { $max_length }
 err-no-fields-to-update = Нет полей для обновления
 
 err-server-image-too-large = Изображение сервера слишком большое (максимум 512КБ)
 err-server-image-invalid-format = Недопустимый формат изображения сервера (должен быть data URI с кодировкой base64)
 err-server-image-unsupported-type = Неподдерживаемый тип изображения сервера (только PNG, WebP, JPEG или SVG)
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = Также выданы необходимые разрешения: { $permissions }
+warn-permission-prerequisites-missing = Некоторые выданные разрешения не будут работать без: { $permissions }
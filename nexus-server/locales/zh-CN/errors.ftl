@@ -5,6 +5,9 @@ err-not-logged-in = 未登录
 err-avatar-invalid-format = 头像格式无效（必须是base64编码的数据URI）
 err-avatar-too-large = 头像太大（最多{ $max_length }个字符）
 err-avatar-unsupported-type = 不支持的头像类型（仅支持PNG、WebP或SVG）
+err-avatar-undecodable = 无法解码头像图片
+err-avatar-dimensions-too-small = 头像图片太小（每边至少 { $min_dimension } 像素）
+err-avatar-dimensions-too-large = 头像图片太大（每边最多 { $max_dimension } 像素）
 err-authentication = 身份验证错误
 err-invalid-credentials = 用户名或密码无效
 err-handshake-required = 需要握手
@@ -12,6 +15,9 @@ err-already-logged-in = 已经登录
 err-handshake-already-completed = 握手已完成
 err-account-deleted = 您的账户已被删除
 err-account-disabled-by-admin = 账户已被管理员禁用
+err-guest-name-in-use = 访客名称 '{ $username }' 已被使用
+err-guest-name-reserved = 以 '{ $prefix }' 开头的名称保留给访客使用
+err-guest-not-allowed = 访客无法执行此操作，请使用账户登录
 
 # 权限和访问错误
 err-permission-denied = 权限被拒绝
@@ -24,6 +30,7 @@ err-database = 数据库错误
 
 # 消息格式错误
 err-invalid-message-format = 无效的消息格式
+err-payload-too-large = 请求过大（最多{ $max_length }字节）
 
 # 用户管理错误
 err-cannot-delete-last-admin = 无法删除最后一个管理员
@@ -33,7 +40,16 @@ err-cannot-edit-self = 您不能编辑自己
 err-cannot-create-admin = 只有管理员才能创建管理员用户
 err-cannot-kick-self = 您无法踢出自己
 err-cannot-kick-admin = 无法踢出管理员用户
+err-cannot-kick-own-session = 无法断开您正在使用的会话
+err-session-not-found = ID 为 { $session_id } 的会话不在线
+err-session-limit = 此账户在线会话已达上限 { $max_sessions } 个；请先在其他设备上退出登录
+err-session-replaced = 由于此账户在其他设备上登录，连接已断开
 err-cannot-message-self = 您无法给自己发消息
+err-e2ee-invalid-key = 加密密钥无效
+err-e2ee-invalid-message = 加密消息无效
+err-e2ee-keys-changed = "{ $username }"的加密密钥已更改，请重新发送消息
+err-server-maintenance = 服务器正处于维护模式，暂时无法进行更改
+err-feature-disabled = 此功能在本服务器上已禁用
 err-cannot-disable-last-admin = 无法禁用最后一个管理员
 
 # 聊天主题错误
@@ -61,7 +77,6 @@ err-features-invalid-characters = 功能名称包含无效字符
 
 # 消息验证错误
 err-message-empty = 消息不能为空
-err-message-contains-newlines = 消息不能包含换行符
 err-message-invalid-characters = 消息包含无效字符
 
 # 用户名验证错误
@@ -72,11 +87,39 @@ err-username-invalid = 用户名包含无效字符（允许字母、数字和符
 err-unknown-permission = 未知权限: '{ $permission }'
 
 # 动态错误消息（带参数）
+err-announcement-limit-reached = 最多只能有 { $max_count } 条公告，请先删除一条
+err-announcement-not-found = 没有编号为 { $number } 的公告
+err-page-content-empty = 页面内容不能为空
+err-page-content-invalid-characters = 页面内容包含无效字符
+err-page-content-too-long = 页面内容过长（最多 { $max_length } 个字符）
+err-page-limit-reached = 最多只能有 { $max_count } 个页面，请先删除一个
+err-page-name-invalid = 页面名称只能包含字母、数字、"-"和"_"
+err-page-name-too-long = 页面名称过长（最多 { $max_length } 个字符）
+err-page-not-found = 没有名为"{ $name }"的页面
 err-broadcast-too-long = 消息太长（最多{ $max_length }个字符）
 err-chat-too-long = 消息太长（最多{ $max_length }个字符）
+err-chat-slow-mode = 慢速模式已启用。请等待{ $seconds }秒后再发送消息
+err-command-cooldown = 请等待 { $seconds } 秒后再重复此请求
+err-chat-edit-window-expired = 消息只能在发送后{ $minutes }分钟内更改
+err-chat-message-not-found = 消息不存在或太旧，无法更改
+err-chat-not-own-message = 您只能更改自己的消息
+err-pin-already-pinned = 消息已置顶
+err-pin-limit-reached = 最多只能置顶 { $max_count } 条消息，请先取消置顶一条
+err-pin-message-not-found = 未找到消息或消息太旧无法置顶
+err-pin-not-pinned = 消息未置顶
+err-preferences-not-object = 偏好设置必须是 JSON 对象
+err-preferences-too-large = 偏好设置太大（最多{ $max_length }字节）
+err-bio-too-long = 简介过长（最多 { $max_length } 个字符）
+err-bio-contains-newlines = 简介不能包含换行符
+err-bio-invalid-characters = 简介包含无效字符
+err-pronouns-too-long = 代词过长（最多 { $max_length } 个字符）
+err-pronouns-contains-newlines = 代词不能包含换行符
+err-pronouns-invalid-characters = 代词包含无效字符
+err-rules-not-accepted = 聊天前必须接受服务器规则
 err-topic-too-long = 主题不能超过{ $max_length }个字符
 err-version-major-mismatch = 不兼容的协议版本：服务器是版本{ $server_major }.x，客户端是版本{ $client_major }.x
 err-version-client-too-new = 客户端版本{ $client_version }比服务器版本{ $server_version }更新。请更新服务器或使用旧版客户端。
+err-version-client-too-old = 客户端版本{ $client_version }过旧；此服务器需要{ $min_version }或更高版本。请升级客户端。
 err-kicked-by = 您已被{ $username }踢出
 err-username-exists = 用户名"{ $username }"已存在
 err-user-not-found = 找不到用户"{ $username }"
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = 权限名称包含无效字符
 
 # 服务器更新错误
 err-admin-required = 需要管理员权限
+err-cannot-transfer-admin-to-self = 不能将管理员权限转移给自己
+err-already-admin = "{ $username }"已经是管理员
+err-notice-url-invalid = 链接必须以 http:// 或 https:// 开头且不能包含空格
+err-notice-url-too-long = 链接太长（最多{ $max_length }个字符）
 err-server-name-empty = 服务器名称不能为空
 err-server-name-too-long = 服务器名称太长（最多{ $max_length }个字符）
 err-server-name-contains-newlines = 服务器名称不能包含换行符
@@ -102,8 +149,14 @@ err-server-description-too-long = 服务器描述太长（最多{ $max_length }
 err-server-description-contains-newlines = 服务器描述不能包含换行符
 err-server-description-invalid-characters = 服务器描述包含无效字符
 err-max-connections-per-ip-invalid = 每个IP的最大连接数必须大于0
+err-max-message-length-invalid = 消息最大长度必须在 1 到 { $max_length } 之间
+err-max-topic-length-invalid = 主题最大长度必须在 1 到 { $max_length } 之间
 err-no-fields-to-update = 没有要更新的字段
 
 err-server-image-too-large = 服务器图片太大（最大512KB）
 err-server-image-invalid-format = 服务器图片格式无效（必须是base64编码的数据URI）
 err-server-image-unsupported-type = 不支持的服务器图片类型（仅支持PNG、WebP、JPEG或SVG）
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = 已同时授予前置权限：{ $permissions }
+warn-permission-prerequisites-missing = 部分已授予的权限缺少以下权限将无法使用：{ $permissions }
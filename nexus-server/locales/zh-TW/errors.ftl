@@ -5,6 +5,9 @@ err-not-logged-in = 未登入
 err-avatar-invalid-format = 頭像格式無效（必須是base64編碼的資料URI）
 err-avatar-too-large = 頭像太大（最多{ $max_length }個字元）
 err-avatar-unsupported-type = 不支援的頭像類型（僅支援PNG、WebP或SVG）
+err-avatar-undecodable = 無法解碼頭像圖片
+err-avatar-dimensions-too-small = 頭像圖片太小（每邊至少 { $min_dimension } 像素）
+err-avatar-dimensions-too-large = 頭像圖片太大（每邊最多 { $max_dimension } 像素）
 err-authentication = 身份驗證錯誤
 err-invalid-credentials = 使用者名稱或密碼無效
 err-handshake-required = 需要握手
@@ -12,6 +15,9 @@ err-already-logged-in = 已經登入
 err-handshake-already-completed = 握手已完成
 err-account-deleted = 您的帳戶已被刪除
 err-account-disabled-by-admin = 帳戶已被管理員停用
+err-guest-name-in-use = 訪客名稱 '{ $username }' 已被使用
+err-guest-name-reserved = 以 '{ $prefix }' 開頭的名稱保留給訪客使用
+err-guest-not-allowed = 訪客無法執行此操作，請使用帳號登入
 
 # 權限和存取錯誤
 err-permission-denied = 權限被拒絕
@@ -24,6 +30,7 @@ err-database = 資料庫錯誤
 
 # 訊息格式錯誤
 err-invalid-message-format = 無效的訊息格式
+err-payload-too-large = 請求過大（最多{ $max_length }位元組）
 
 # 使用者管理錯誤
 err-cannot-delete-last-admin = 無法刪除最後一個管理員
@@ -33,7 +40,16 @@ err-cannot-edit-self = 您不能編輯自己
 err-cannot-create-admin = 只有管理員可以建立管理員使用者
 err-cannot-kick-self = 您不能踢除自己
 err-cannot-kick-admin = 無法踢除管理員用戶
+err-cannot-kick-own-session = 無法中斷您正在使用的工作階段
+err-session-not-found = ID 為 { $session_id } 的工作階段不在線上
+err-session-limit = 此帳號線上工作階段已達上限 { $max_sessions } 個；請先在其他裝置上登出
+err-session-replaced = 由於此帳號在其他裝置上登入，連線已中斷
 err-cannot-message-self = 您不能給自己發送訊息
+err-e2ee-invalid-key = 加密金鑰無效
+err-e2ee-invalid-message = 加密訊息無效
+err-e2ee-keys-changed = 「{ $username }」的加密金鑰已變更，請重新傳送訊息
+err-server-maintenance = 伺服器正處於維護模式，暫時無法進行變更
+err-feature-disabled = 此功能在本伺服器上已停用
 err-cannot-disable-last-admin = 無法停用最後一位管理員
 
 # 聊天主題錯誤
@@ -61,7 +77,6 @@ err-features-invalid-characters = 功能名稱包含無效字元
 
 # 訊息驗證錯誤
 err-message-empty = 訊息不能為空
-err-message-contains-newlines = 訊息不能包含換行符號
 err-message-invalid-characters = 訊息包含無效字元
 
 # 使用者名稱驗證錯誤
@@ -72,11 +87,39 @@ err-username-invalid = 使用者名稱包含無效字元（允許字母、數字
 err-unknown-permission = 未知權限: '{ $permission }'
 
 # 動態錯誤訊息（帶參數）
+err-announcement-limit-reached = 最多只能有 { $max_count } 則公告，請先刪除一則
+err-announcement-not-found = 沒有編號為 { $number } 的公告
+err-page-content-empty = 頁面內容不能為空
+err-page-content-invalid-characters = 頁面內容包含無效字元
+err-page-content-too-long = 頁面內容過長（最多 { $max_length } 個字元）
+err-page-limit-reached = 最多只能有 { $max_count } 個頁面，請先刪除一個
+err-page-name-invalid = 頁面名稱只能包含字母、數字、「-」和「_」
+err-page-name-too-long = 頁面名稱過長（最多 { $max_length } 個字元）
+err-page-not-found = 沒有名為「{ $name }」的頁面
 err-broadcast-too-long = 訊息太長（最多{ $max_length }個字元）
 err-chat-too-long = 訊息太長（最多{ $max_length }個字元）
+err-chat-slow-mode = 慢速模式已啟用。請等待{ $seconds }秒後再傳送訊息
+err-command-cooldown = 請等待 { $seconds } 秒後再重複此請求
+err-chat-edit-window-expired = 訊息只能在傳送後{ $minutes }分鐘內變更
+err-chat-message-not-found = 訊息不存在或太舊，無法變更
+err-chat-not-own-message = 您只能變更自己的訊息
+err-pin-already-pinned = 訊息已置頂
+err-pin-limit-reached = 最多只能置頂 { $max_count } 則訊息，請先取消置頂一則
+err-pin-message-not-found = 找不到訊息或訊息太舊無法置頂
+err-pin-not-pinned = 訊息未置頂
+err-preferences-not-object = 偏好設定必須是 JSON 物件
+err-preferences-too-large = 偏好設定太大（最多{ $max_length }位元組）
+err-bio-too-long = 簡介過長（最多 { $max_length } 個字元）
+err-bio-contains-newlines = 簡介不能包含換行符號
+err-bio-invalid-characters = 簡介包含無效字元
+err-pronouns-too-long = 代名詞過長（最多 { $max_length } 個字元）
+err-pronouns-contains-newlines = 代名詞不能包含換行符號
+err-pronouns-invalid-characters = 代名詞包含無效字元
+err-rules-not-accepted = 聊天前必須接受伺服器規則
 err-topic-too-long = 主題不能超過{ $max_length }個字元
 err-version-major-mismatch = 不相容的協定版本：伺服器是版本{ $server_major }.x，客戶端是版本{ $client_major }.x
 err-version-client-too-new = 客戶端版本{ $client_version }比伺服器版本{ $server_version }更新。請更新伺服器或使用較舊的客戶端。
+err-version-client-too-old = 用戶端版本{ $client_version }過舊；此伺服器需要{ $min_version }或更新版本。請升級用戶端。
 err-kicked-by = 您已被{ $username }踢出
 err-username-exists = 使用者名稱「{ $username }」已存在
 err-user-not-found = 找不到使用者「{ $username }」
@@ -94,6 +137,10 @@ err-permissions-invalid-characters = 權限名稱包含無效字元
 
 # 伺服器更新錯誤
 err-admin-required = 需要管理員權限
+err-cannot-transfer-admin-to-self = 不能將管理員權限轉移給自己
+err-already-admin = 「{ $username }」已經是管理員
+err-notice-url-invalid = 連結必須以 http:// 或 https:// 開頭且不能包含空格
+err-notice-url-too-long = 連結太長（最多{ $max_length }個字元）
 err-server-name-empty = 伺服器名稱不能為空
 err-server-name-too-long = 伺服器名稱太長（最多{ $max_length }個字元）
 err-server-name-contains-newlines = 伺服器名稱不能包含換行符號
@@ -102,8 +149,14 @@ err-server-description-too-long = 伺服器描述太長（最多{ $max_length }
 err-server-description-contains-newlines = 伺服器描述不能包含換行符號
 err-server-description-invalid-characters = 伺服器描述包含無效字元
 err-max-connections-per-ip-invalid = 每個IP的最大連線數必須大於0
+err-max-message-length-invalid = 訊息最大長度必須介於 1 到 { $max_length } 之間
+err-max-topic-length-invalid = 主題最大長度必須介於 1 到 { $max_length } 之間
 err-no-fields-to-update = 沒有要更新的欄位
 
 err-server-image-too-large = 伺服器圖片太大（最大512KB）
 err-server-image-invalid-format = 伺服器圖片格式無效（必須是base64編碼的資料URI）
 err-server-image-unsupported-type = 不支援的伺服器圖片類型（僅支援PNG、WebP、JPEG或SVG）
+
+# Permission Prerequisite Warnings
+warn-permission-prerequisites-added = 已同時授予前置權限：{ $permissions }
+warn-permission-prerequisites-missing = 部分已授予的權限缺少以下權限將無法使用：{ $permissions }
@@ -0,0 +1,4 @@
+-- Add chat slow mode configuration key
+-- Minimum seconds between chat messages per user (0 = disabled)
+
+INSERT INTO config (key, value) VALUES ('chat_slow_mode_seconds', '0');
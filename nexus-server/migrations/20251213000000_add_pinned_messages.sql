@@ -0,0 +1,4 @@
+-- Add pinned chat messages to chat state
+-- Stored as a JSON array of pinned messages (empty = nothing pinned)
+
+INSERT INTO chat_state (key, value) VALUES ('pinned_messages', '[]');
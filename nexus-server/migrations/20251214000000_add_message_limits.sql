@@ -0,0 +1,5 @@
+-- Add configurable message and topic length limits
+-- Defaults match the protocol defaults; values may be raised up to the protocol ceilings
+
+INSERT INTO config (key, value) VALUES ('max_message_length', '1024');
+INSERT INTO config (key, value) VALUES ('max_topic_length', '256');
@@ -0,0 +1,16 @@
+-- Persist #server chat history with a configurable retention policy
+-- Retention keeps at most chat_retention_count messages no older than
+-- chat_retention_days days (0 disables either limit)
+
+CREATE TABLE IF NOT EXISTS chat_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    message_id INTEGER NOT NULL,
+    username TEXT NOT NULL,
+    message TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_chat_history_created_at ON chat_history(created_at);
+
+INSERT INTO config (key, value) VALUES ('chat_retention_count', '1000');
+INSERT INTO config (key, value) VALUES ('chat_retention_days', '30');
@@ -0,0 +1,5 @@
+-- Add server rules that users must accept before chatting
+
+ALTER TABLE users ADD COLUMN rules_accepted BOOLEAN NOT NULL DEFAULT 0;
+
+INSERT INTO config (key, value) VALUES ('server_rules', '');
@@ -0,0 +1,4 @@
+-- Look up chat history rows by message id so edits and deletes of recent
+-- messages don't scan the whole table
+
+CREATE INDEX IF NOT EXISTS idx_chat_history_message_id ON chat_history(message_id);
@@ -0,0 +1,4 @@
+-- Add a server-wide private message policy
+-- enabled: anyone with the user_message permission; admins-only; disabled
+
+INSERT INTO config (key, value) VALUES ('private_messages', 'enabled');
@@ -0,0 +1,4 @@
+-- Add maintenance (read-only) mode, off by default
+-- While on, non-admins stay connected but cannot change server state
+
+INSERT INTO config (key, value) VALUES ('maintenance_mode', 'false');
@@ -0,0 +1,4 @@
+-- Add a banner (legal notice) shown to clients before they log in
+-- Empty disables it
+
+INSERT INTO config (key, value) VALUES ('server_banner', '');
@@ -0,0 +1,5 @@
+-- Add rotating announcements broadcast on a timer
+-- Stored as a JSON array of messages (empty = ticker disabled)
+
+INSERT INTO config (key, value) VALUES ('announcements', '[]');
+INSERT INTO config (key, value) VALUES ('announcement_interval', '30');
@@ -0,0 +1,5 @@
+-- Add server-wide disabled permissions
+-- Stored as a JSON array of permission names (empty = nothing disabled)
+
+INSERT INTO config (key, value) VALUES ('disabled_permissions', '[]');
+INSERT INTO config (key, value) VALUES ('disabled_permissions_admin_exempt', 'false');
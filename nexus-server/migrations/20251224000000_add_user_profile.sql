@@ -0,0 +1,4 @@
+-- Add an optional public profile (bio and pronouns) to user accounts
+
+ALTER TABLE users ADD COLUMN bio TEXT NOT NULL DEFAULT '';
+ALTER TABLE users ADD COLUMN pronouns TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,4 @@
+-- Add new login notices, on by default
+-- While on, a user's other sessions are told when their account logs in again
+
+INSERT INTO config (key, value) VALUES ('login_notices', 'true');
@@ -0,0 +1,4 @@
+-- Add the default locale for server messages
+-- Used for clients that don't name a supported locale; a client's own locale still wins
+
+INSERT INTO config (key, value) VALUES ('default_locale', 'en');
@@ -0,0 +1,6 @@
+-- Track when each account was last seen, for the /seen command
+--
+-- Set to the login time when a session logs in and to the disconnect time
+-- when one of its connections closes. NULL until the account first logs in.
+
+ALTER TABLE users ADD COLUMN last_seen INTEGER;
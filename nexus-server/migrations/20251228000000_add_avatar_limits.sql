@@ -0,0 +1,7 @@
+-- Add pixel bounds for raster avatars
+-- Avatars outside the bounds are rejected at login, unless downscaling is on
+-- and the avatar is merely too large
+
+INSERT INTO config (key, value) VALUES ('avatar_min_dimension', '16');
+INSERT INTO config (key, value) VALUES ('avatar_max_dimension', '1024');
+INSERT INTO config (key, value) VALUES ('avatar_downscale', 'false');
@@ -0,0 +1,4 @@
+-- Add a per-session cooldown for expensive commands (user info, seen and
+-- full user list requests), in seconds; 0 disables it
+
+INSERT INTO config (key, value) VALUES ('command_cooldown_seconds', '0');
@@ -0,0 +1,8 @@
+-- Add admin-editable info pages (help, news, and so on) fetched by name
+-- Names are unique regardless of case; the admin's spelling is kept for display
+
+CREATE TABLE IF NOT EXISTS pages (
+    name TEXT PRIMARY KEY COLLATE NOCASE,
+    content TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);
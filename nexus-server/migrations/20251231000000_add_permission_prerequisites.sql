@@ -0,0 +1,5 @@
+-- Add how missing permission prerequisites (e.g. chat_send without
+-- chat_receive) are handled when accounts are created or updated
+-- off: ignore; warn: tell the requester; enforce: add them
+
+INSERT INTO config (key, value) VALUES ('permission_prerequisites', 'warn');
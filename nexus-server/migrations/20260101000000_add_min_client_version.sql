@@ -0,0 +1,4 @@
+-- Add the oldest client protocol version allowed to connect
+-- Empty accepts every compatible version
+
+INSERT INTO config (key, value) VALUES ('min_client_version', '');
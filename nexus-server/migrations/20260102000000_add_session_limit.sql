@@ -0,0 +1,7 @@
+-- Add the cap on concurrent sessions per account
+-- 0 means unlimited; a login over the cap is refused (reject) or
+-- disconnects the account's oldest sessions (replace-oldest)
+
+INSERT INTO config (key, value) VALUES ('max_sessions_per_user', '0');
+INSERT INTO config (key, value) VALUES ('session_limit_policy', 'reject');
+INSERT INTO config (key, value) VALUES ('session_limit_admin_exempt', 'false');
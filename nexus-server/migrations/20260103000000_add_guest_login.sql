@@ -0,0 +1,6 @@
+-- Add guest login
+-- Guests log in with a guest- name and no account; the optional shared
+-- password is stored as a hash (empty means guests need no password)
+
+INSERT INTO config (key, value) VALUES ('guest_login', 'false');
+INSERT INTO config (key, value) VALUES ('guest_password', '');
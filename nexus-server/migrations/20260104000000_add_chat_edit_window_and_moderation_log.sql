@@ -0,0 +1,18 @@
+-- Make the chat edit window configurable and log moderation actions
+-- Users can edit or delete their own messages for chat_edit_window_minutes
+-- minutes after sending (0 = no limit); moderators can delete any message,
+-- and each such deletion is kept in moderation_log for accountability
+
+INSERT INTO config (key, value) VALUES ('chat_edit_window_minutes', '15');
+
+CREATE TABLE IF NOT EXISTS moderation_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    moderator TEXT NOT NULL,
+    action TEXT NOT NULL,
+    target_username TEXT NOT NULL,
+    message_id INTEGER,
+    message TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_moderation_log_created_at ON moderation_log(created_at);
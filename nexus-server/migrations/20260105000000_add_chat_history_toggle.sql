@@ -0,0 +1,4 @@
+-- Make recording public chat in the history table opt-in
+-- chat_history: true records every #server message (subject to retention)
+
+INSERT INTO config (key, value) VALUES ('chat_history', 'false');
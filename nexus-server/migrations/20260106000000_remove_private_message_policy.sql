@@ -0,0 +1,4 @@
+-- Remove the private message policy
+-- Private messages are now turned off through disabled_permissions (user_message)
+
+DELETE FROM config WHERE key = 'private_messages';
@@ -1,7 +1,26 @@
 //! Command-line argument parsing
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use nexus_common::DEFAULT_PORT;
+use nexus_common::validators::{self, PasswordError};
+use nexus_common::version::{self, Version};
+
+use crate::connection_source::SourceRule;
+use crate::constants::{
+    DEFAULT_DB_MAINTENANCE_INTERVAL_HOURS, DEFAULT_DB_MAINTENANCE_MAX_USERS,
+    DEFAULT_LISTEN_BACKLOG, DEFAULT_LOGIN_TIMEOUT_SECS, DEFAULT_MALFORMED_FRAME_BLOCK_SECS,
+    DEFAULT_MALFORMED_FRAME_LIMIT, DEFAULT_MALFORMED_FRAME_WINDOW_SECS,
+    DEFAULT_MODERATION_LOG_LIMIT, DEFAULT_OUTGOING_QUEUE_CAPACITY, ERR_ANNOUNCEMENT_INTERVAL_RANGE,
+    ERR_CHAT_EDIT_WINDOW_RANGE, ERR_COMMAND_COOLDOWN_RANGE, ERR_DB_MAINTENANCE_INTERVAL_RANGE,
+    ERR_DEFAULT_LOCALE_UNSUPPORTED, ERR_GUEST_PASSWORD_TOO_LONG, ERR_LOGIN_TIMEOUT_RANGE,
+    ERR_MALFORMED_FRAME_SECS_RANGE, ERR_MIN_CLIENT_VERSION_INVALID, ERR_MIN_CLIENT_VERSION_TOO_NEW,
+    ERR_OUTGOING_QUEUE_RANGE, MAX_ANNOUNCEMENT_INTERVAL_MINUTES, MAX_CHAT_EDIT_WINDOW_MINUTES,
+    MAX_COMMAND_COOLDOWN_SECONDS, MAX_DB_MAINTENANCE_INTERVAL_HOURS, MAX_LOGIN_TIMEOUT_SECS,
+    MAX_MALFORMED_FRAME_SECS, MAX_OUTGOING_QUEUE_CAPACITY, SUPPORTED_LOCALES,
+};
+use crate::db::{DisabledPermissions, PermissionPrerequisites, SessionLimitPolicy};
+use crate::i18n;
+use crate::tls_policy::{TlsCipherSuites, TlsMinVersion};
 use std::net::IpAddr;
 use std::path::PathBuf;
 
@@ -21,6 +40,117 @@ fn default_database_help() -> String {
     return "Database file path (overrides platform default)".to_string();
 }
 
+/// Parse the outgoing queue capacity (at least one message, bounded above)
+fn parse_queue_capacity(value: &str) -> Result<usize, String> {
+    let capacity: usize = value.parse().map_err(|e| format!("{}", e))?;
+    if (1..=MAX_OUTGOING_QUEUE_CAPACITY).contains(&capacity) {
+        Ok(capacity)
+    } else {
+        Err(ERR_OUTGOING_QUEUE_RANGE.to_string())
+    }
+}
+
+/// Parse the login deadline in seconds (at least one, bounded above)
+fn parse_login_timeout(value: &str) -> Result<u64, String> {
+    let seconds: u64 = value.parse().map_err(|e| format!("{}", e))?;
+    if (1..=MAX_LOGIN_TIMEOUT_SECS).contains(&seconds) {
+        Ok(seconds)
+    } else {
+        Err(ERR_LOGIN_TIMEOUT_RANGE.to_string())
+    }
+}
+
+/// Parse a malformed frame window or block duration in seconds (at least one, bounded above)
+fn parse_malformed_frame_secs(value: &str) -> Result<u64, String> {
+    let seconds: u64 = value.parse().map_err(|e| format!("{}", e))?;
+    if (1..=MAX_MALFORMED_FRAME_SECS).contains(&seconds) {
+        Ok(seconds)
+    } else {
+        Err(ERR_MALFORMED_FRAME_SECS_RANGE.to_string())
+    }
+}
+
+/// Parse the announcement interval in minutes (at least one, bounded above)
+fn parse_announcement_interval(value: &str) -> Result<u32, String> {
+    let minutes: u32 = value.parse().map_err(|e| format!("{}", e))?;
+    if (1..=MAX_ANNOUNCEMENT_INTERVAL_MINUTES).contains(&minutes) {
+        Ok(minutes)
+    } else {
+        Err(ERR_ANNOUNCEMENT_INTERVAL_RANGE.to_string())
+    }
+}
+
+/// Parse the command cooldown in seconds (0 disables it, bounded above)
+fn parse_command_cooldown(value: &str) -> Result<u32, String> {
+    let seconds: u32 = value.parse().map_err(|e| format!("{}", e))?;
+    if seconds <= MAX_COMMAND_COOLDOWN_SECONDS {
+        Ok(seconds)
+    } else {
+        Err(ERR_COMMAND_COOLDOWN_RANGE.to_string())
+    }
+}
+
+/// Parse the chat edit window in minutes (0 means no limit, bounded above)
+fn parse_chat_edit_window(value: &str) -> Result<u32, String> {
+    let minutes: u32 = value.parse().map_err(|e| format!("{}", e))?;
+    if minutes <= MAX_CHAT_EDIT_WINDOW_MINUTES {
+        Ok(minutes)
+    } else {
+        Err(ERR_CHAT_EDIT_WINDOW_RANGE.to_string())
+    }
+}
+
+/// Parse the database maintenance interval in hours (0 disables it, bounded above)
+fn parse_db_maintenance_interval(value: &str) -> Result<u32, String> {
+    let hours: u32 = value.parse().map_err(|e| format!("{}", e))?;
+    if hours <= MAX_DB_MAINTENANCE_INTERVAL_HOURS {
+        Ok(hours)
+    } else {
+        Err(ERR_DB_MAINTENANCE_INTERVAL_RANGE.to_string())
+    }
+}
+
+/// Parse the minimum client version (empty accepts any, never above the server's)
+fn parse_min_client_version(value: &str) -> Result<String, String> {
+    if value.is_empty() {
+        return Ok(String::new());
+    }
+    let min: Version = value
+        .parse()
+        .map_err(|_| format!("{}{}", ERR_MIN_CLIENT_VERSION_INVALID, value))?;
+    let server = version::protocol_version();
+    if (min.major, min.minor, min.patch) > (server.major, server.minor, server.patch) {
+        return Err(format!("{}{}", ERR_MIN_CLIENT_VERSION_TOO_NEW, server));
+    }
+    Ok(min.to_string())
+}
+
+/// Parse the default locale (must have a translation bundle)
+fn parse_default_locale(value: &str) -> Result<String, String> {
+    if i18n::is_supported_locale(value) {
+        Ok(value.to_string())
+    } else {
+        Err(format!(
+            "{}{} ({})",
+            ERR_DEFAULT_LOCALE_UNSUPPORTED,
+            value,
+            SUPPORTED_LOCALES.join(", ")
+        ))
+    }
+}
+
+/// Parse the guest password (empty lets guests in without one)
+fn parse_guest_password(value: &str) -> Result<String, String> {
+    match validators::validate_password(value) {
+        Err(PasswordError::TooLong) => Err(format!(
+            "{}{} bytes",
+            ERR_GUEST_PASSWORD_TOO_LONG,
+            validators::MAX_PASSWORD_LENGTH
+        )),
+        Ok(()) | Err(PasswordError::Empty) => Ok(value.to_string()),
+    }
+}
+
 /// Nexus BBS Server
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -44,4 +174,194 @@ pub struct Args {
     /// Enable UPnP port forwarding (automatic NAT traversal)
     #[arg(long, default_value = "false")]
     pub upnp: bool,
+
+    /// Maximum number of pending connections queued by the kernel
+    #[arg(long, default_value_t = DEFAULT_LISTEN_BACKLOG)]
+    pub backlog: u32,
+
+    /// Server messages queued per client before a slow client is disconnected
+    #[arg(long, value_name = "MESSAGES", default_value_t = DEFAULT_OUTGOING_QUEUE_CAPACITY, value_parser = parse_queue_capacity)]
+    pub outgoing_queue: usize,
+
+    /// Seconds a new connection has to complete the TLS handshake, protocol handshake, and login
+    #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_LOGIN_TIMEOUT_SECS, value_parser = parse_login_timeout)]
+    pub login_timeout: u64,
+
+    /// Malformed frames from one address before login that get it blocked, 0 to never block
+    #[arg(long, value_name = "FRAMES", default_value_t = DEFAULT_MALFORMED_FRAME_LIMIT)]
+    pub malformed_frame_limit: u32,
+
+    /// Seconds over which malformed frames are counted towards a block
+    #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_MALFORMED_FRAME_WINDOW_SECS, value_parser = parse_malformed_frame_secs)]
+    pub malformed_frame_window: u64,
+
+    /// Seconds an address is refused after sending too many malformed frames
+    #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_MALFORMED_FRAME_BLOCK_SECS, value_parser = parse_malformed_frame_secs)]
+    pub malformed_frame_block: u64,
+
+    /// Disable TCP_NODELAY on client sockets (re-enables Nagle's algorithm)
+    #[arg(long, default_value = "false")]
+    pub no_tcp_nodelay: bool,
+
+    /// Set SO_REUSEADDR on the listening socket (allows quick restarts; ignored on Windows)
+    #[arg(long, default_value = "false")]
+    pub reuse_address: bool,
+
+    /// Lowest TLS version to accept: 1.2 or 1.3
+    #[arg(long, value_name = "VERSION", default_value_t = TlsMinVersion::default())]
+    pub tls_min_version: TlsMinVersion,
+
+    /// Comma-separated TLS cipher suites to allow (default: rustls' default suites)
+    #[arg(long, value_name = "LIST")]
+    pub tls_ciphers: Option<TlsCipherSuites>,
+
+    /// Maximum chat/broadcast/private message length (saved to the database)
+    #[arg(long)]
+    pub max_message_length: Option<usize>,
+
+    /// Maximum chat topic length (saved to the database)
+    #[arg(long)]
+    pub max_topic_length: Option<usize>,
+
+    /// Smallest accepted avatar width or height in pixels (saved to the database)
+    #[arg(long, value_name = "PIXELS")]
+    pub avatar_min_dimension: Option<u32>,
+
+    /// Largest accepted avatar width or height in pixels (saved to the database)
+    #[arg(long, value_name = "PIXELS")]
+    pub avatar_max_dimension: Option<u32>,
+
+    /// Downscale avatars above the largest dimension instead of rejecting them: true or false (saved to the database)
+    #[arg(long, value_name = "BOOL")]
+    pub avatar_downscale: Option<bool>,
+
+    /// Seconds a session must wait between user info, seen and full user list requests, 0 to disable (saved to the database)
+    #[arg(long, value_name = "SECONDS", value_parser = parse_command_cooldown)]
+    pub command_cooldown: Option<u32>,
+
+    /// Minutes users can edit or delete their own chat messages, 0 for no limit (saved to the database)
+    #[arg(long, value_name = "MINUTES", value_parser = parse_chat_edit_window)]
+    pub chat_edit_window: Option<u32>,
+
+    /// Record public chat in the database: true or false (saved to the database)
+    #[arg(long, value_name = "BOOL")]
+    pub chat_history: Option<bool>,
+
+    /// Maximum chat history messages to keep, 0 for unlimited (saved to the database)
+    #[arg(long)]
+    pub chat_retention_count: Option<u32>,
+
+    /// Maximum chat history age in days, 0 for unlimited (saved to the database)
+    #[arg(long)]
+    pub chat_retention_days: Option<u32>,
+
+    /// Oldest client protocol version allowed to connect, empty to accept any (saved to the database)
+    #[arg(long, value_name = "VERSION", value_parser = parse_min_client_version)]
+    pub min_client_version: Option<String>,
+
+    /// Start in maintenance (read-only) mode: true or false (saved to the database)
+    #[arg(long, value_name = "BOOL")]
+    pub maintenance: Option<bool>,
+
+    /// Locale for server messages to clients that don't name a supported one (saved to the database)
+    #[arg(long, value_name = "LOCALE", value_parser = parse_default_locale)]
+    pub default_locale: Option<String>,
+
+    /// Tell a user's other sessions when their account logs in again: true or false (saved to the database)
+    #[arg(long, value_name = "BOOL")]
+    pub login_notices: Option<bool>,
+
+    /// Let people log in as guests with a "guest-" name and no account: true or false (saved to the database)
+    #[arg(long, value_name = "BOOL")]
+    pub guest_login: Option<bool>,
+
+    /// Password guests share, empty to let them in without one (saved to the database, hashed)
+    #[arg(long, value_name = "PASSWORD", value_parser = parse_guest_password)]
+    pub guest_password: Option<String>,
+
+    /// Most concurrent sessions per account, 0 for unlimited (saved to the database)
+    #[arg(long, value_name = "SESSIONS")]
+    pub max_sessions_per_user: Option<u32>,
+
+    /// What a login over the session limit does: reject or replace-oldest (saved to the database)
+    #[arg(long, value_name = "POLICY")]
+    pub session_limit_policy: Option<SessionLimitPolicy>,
+
+    /// Exempt admins from the session limit: true or false (saved to the database)
+    #[arg(long, value_name = "BOOL")]
+    pub session_limit_admin_exempt: Option<bool>,
+
+    /// Comma-separated permissions to switch off for everyone, empty to clear (saved to the database)
+    #[arg(long, value_name = "LIST")]
+    pub disable_permissions: Option<DisabledPermissions>,
+
+    /// Exempt admins from disabled permissions: true or false (saved to the database)
+    #[arg(long, value_name = "BOOL")]
+    pub disabled_permissions_admin_exempt: Option<bool>,
+
+    /// When saved accounts lack permissions others depend on: off, warn, or enforce (saved to the database)
+    #[arg(long, value_name = "MODE")]
+    pub permission_prerequisites: Option<PermissionPrerequisites>,
+
+    /// Text file with rules users must accept before chatting, empty to disable (saved to the database)
+    #[arg(long, value_name = "PATH")]
+    pub rules_file: Option<PathBuf>,
+
+    /// Text file with a notice shown before login, empty to disable (saved to the database)
+    #[arg(long, value_name = "PATH")]
+    pub banner_file: Option<PathBuf>,
+
+    /// Minutes between rotating announcements (saved to the database)
+    #[arg(long, value_name = "MINUTES", value_parser = parse_announcement_interval)]
+    pub announcement_interval: Option<u32>,
+
+    /// Hours between database maintenance runs (PRAGMA optimize), 0 to disable
+    #[arg(long, value_name = "HOURS", default_value_t = DEFAULT_DB_MAINTENANCE_INTERVAL_HOURS, value_parser = parse_db_maintenance_interval)]
+    pub db_maintenance_interval: u32,
+
+    /// Also VACUUM during database maintenance to reclaim free space (rewrites the whole file)
+    #[arg(long, default_value = "false")]
+    pub db_vacuum: bool,
+
+    /// Postpone database maintenance while more than this many users are online
+    #[arg(long, value_name = "USERS", default_value_t = DEFAULT_DB_MAINTENANCE_MAX_USERS)]
+    pub db_maintenance_max_users: usize,
+
+    /// Tag connections from a network, e.g. 172.20.0.2/32=tor (repeatable, shown to admins in user info)
+    #[arg(long = "source-tag", value_name = "CIDR=TAG")]
+    pub source_tags: Vec<SourceRule>,
+
+    /// Run a maintenance command and exit instead of starting the server
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Maintenance commands run against the database
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Manage user accounts
+    User {
+        #[command(subcommand)]
+        command: UserCommand,
+    },
+    /// Print the newest moderation actions, oldest first
+    ModerationLog {
+        /// Number of entries to show
+        #[arg(long, default_value_t = DEFAULT_MODERATION_LOG_LIMIT)]
+        limit: usize,
+    },
+}
+
+/// User account commands
+#[derive(Subcommand, Debug)]
+pub enum UserCommand {
+    /// Create accounts from a CSV or JSON file (username, password, hashed, admin, permissions)
+    Import {
+        /// File to import (.json for a JSON array, otherwise CSV with a header row)
+        file: PathBuf,
+
+        /// Check the file and report what would be created without changing the database
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
@@ -0,0 +1,199 @@
+//! Raster avatar dimension checks
+//!
+//! `validate_avatar` in nexus-common only checks a data URI's length and MIME
+//! type. This module decodes PNG, JPEG and WebP avatars to enforce the
+//! server's pixel bounds, so a 1x10000 strip can't stretch every user list.
+//! Undecodable images are rejected. SVG avatars have no raster dimensions and
+//! are left to the client's renderer.
+
+use std::io::Cursor;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageError, ImageFormat, ImageReader, Limits};
+use nexus_common::validators::MAX_AVATAR_DATA_URI_LENGTH;
+
+use crate::constants::AVATAR_DIMENSION_CEILING;
+use crate::db::AvatarLimits;
+
+/// Raster avatar check failures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarImageError {
+    /// The payload isn't valid base64 or a valid image of its declared type
+    Undecodable,
+    /// Width or height is below the minimum
+    TooSmall,
+    /// Width or height is above the maximum (and downscaling is off or failed)
+    TooLarge,
+}
+
+/// Check a validated avatar data URI against the server's pixel bounds
+///
+/// Returns `Ok(None)` when the avatar can be used as sent, or
+/// `Ok(Some(uri))` with a re-encoded avatar when an oversized image was
+/// downscaled to fit `max_dimension`.
+pub fn check_avatar_image(
+    data_uri: &str,
+    limits: AvatarLimits,
+) -> Result<Option<String>, AvatarImageError> {
+    let Some((mime_type, payload)) = data_uri
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(";base64,"))
+    else {
+        return Err(AvatarImageError::Undecodable);
+    };
+
+    let format = match mime_type {
+        "image/png" => ImageFormat::Png,
+        "image/jpeg" => ImageFormat::Jpeg,
+        "image/webp" => ImageFormat::WebP,
+        _ => return Ok(None),
+    };
+
+    let image = decode(payload, format)?;
+    let (width, height) = (image.width(), image.height());
+    if width.min(height) < limits.min_dimension {
+        return Err(AvatarImageError::TooSmall);
+    }
+    if width.max(height) <= limits.max_dimension {
+        return Ok(None);
+    }
+    if !limits.downscale {
+        return Err(AvatarImageError::TooLarge);
+    }
+
+    // Keep the aspect ratio; a long, thin image may shrink below the minimum
+    let scaled = image.resize(
+        limits.max_dimension,
+        limits.max_dimension,
+        FilterType::Lanczos3,
+    );
+    if scaled.width().min(scaled.height()) < limits.min_dimension {
+        return Err(AvatarImageError::TooSmall);
+    }
+
+    let mut encoded = Vec::new();
+    scaled
+        .write_to(&mut Cursor::new(&mut encoded), format)
+        .map_err(|_| AvatarImageError::Undecodable)?;
+    let uri = format!("data:{};base64,{}", mime_type, STANDARD.encode(encoded));
+    if uri.len() > MAX_AVATAR_DATA_URI_LENGTH {
+        return Err(AvatarImageError::TooLarge);
+    }
+
+    Ok(Some(uri))
+}
+
+/// Decode a base64 image payload, refusing anything above the dimension ceiling
+fn decode(payload: &str, format: ImageFormat) -> Result<DynamicImage, AvatarImageError> {
+    let bytes = STANDARD
+        .decode(payload)
+        .map_err(|_| AvatarImageError::Undecodable)?;
+
+    let mut decode_limits = Limits::default();
+    decode_limits.max_image_width = Some(AVATAR_DIMENSION_CEILING);
+    decode_limits.max_image_height = Some(AVATAR_DIMENSION_CEILING);
+
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    reader.limits(decode_limits);
+    reader.decode().map_err(|e| match e {
+        ImageError::Limits(_) => AvatarImageError::TooLarge,
+        _ => AvatarImageError::Undecodable,
+    })
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::png_data_uri;
+
+    /// Dimensions of an avatar data URI
+    fn dimensions(data_uri: &str) -> (u32, u32) {
+        let (_, payload) = data_uri.split_once(";base64,").unwrap();
+        let image = decode(payload, ImageFormat::Png).unwrap();
+        (image.width(), image.height())
+    }
+
+    #[test]
+    fn test_valid_avatar_is_kept() {
+        let limits = AvatarLimits::default();
+        for (width, height) in [(64, 64), (16, 512), (1024, 100)] {
+            assert_eq!(
+                check_avatar_image(&png_data_uri(width, height), limits),
+                Ok(None)
+            );
+        }
+    }
+
+    #[test]
+    fn test_out_of_bounds_dimensions_rejected() {
+        let limits = AvatarLimits::default();
+        assert_eq!(
+            check_avatar_image(&png_data_uri(1, 500), limits),
+            Err(AvatarImageError::TooSmall)
+        );
+        assert_eq!(
+            check_avatar_image(&png_data_uri(1200, 1200), limits),
+            Err(AvatarImageError::TooLarge)
+        );
+        assert_eq!(
+            check_avatar_image(&png_data_uri(AVATAR_DIMENSION_CEILING + 1, 16), limits),
+            Err(AvatarImageError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn test_oversized_avatar_downscaled() {
+        let limits = AvatarLimits {
+            downscale: true,
+            ..AvatarLimits::default()
+        };
+
+        let scaled = check_avatar_image(&png_data_uri(2048, 1536), limits)
+            .unwrap()
+            .expect("Expected a downscaled avatar");
+        assert!(scaled.starts_with("data:image/png;base64,"));
+        assert_eq!(dimensions(&scaled), (1024, 768));
+
+        // Shrinking a thin strip to fit would leave it below the minimum
+        assert_eq!(
+            check_avatar_image(&png_data_uri(4000, 40), limits),
+            Err(AvatarImageError::TooSmall)
+        );
+    }
+
+    #[test]
+    fn test_corrupt_avatar_rejected() {
+        let limits = AvatarLimits::default();
+        let truncated = &png_data_uri(64, 64)[..60];
+        for uri in [
+            "data:image/png;base64,iVBORw0KGgo=",
+            "data:image/png;base64,",
+            "data:image/jpeg;base64,not base64!",
+            "data:image/webp;base64,UklGRh4=",
+            truncated,
+        ] {
+            assert_eq!(
+                check_avatar_image(uri, limits),
+                Err(AvatarImageError::Undecodable),
+                "{uri}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_svg_avatar_skipped() {
+        assert_eq!(
+            check_avatar_image(
+                "data:image/svg+xml;base64,PHN2Zz4=",
+                AvatarLimits::default()
+            ),
+            Ok(None)
+        );
+    }
+}
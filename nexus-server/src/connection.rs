@@ -2,63 +2,127 @@
 
 use std::io;
 use std::net::SocketAddr;
+use std::time::Duration;
 
 use tokio::io::{AsyncWriteExt, BufReader};
 use tokio::net::TcpStream;
-use tokio::sync::mpsc;
+use tokio::time::{Instant, sleep_until, timeout_at};
 use tokio_rustls::TlsAcceptor;
 
 use nexus_common::framing::{FrameError, FrameReader, FrameWriter, MessageId};
-use nexus_common::io::{read_client_message_with_timeout, send_server_message_with_id};
-use nexus_common::protocol::{ClientMessage, ServerMessage};
+use nexus_common::io::{client_message_type, read_client_message_with_timeout};
+use nexus_common::protocol::{ClientMessage, ERROR_CODE_PAYLOAD_TOO_LARGE, ServerMessage};
+use nexus_common::version::{Version, supports_extended_protocol};
 
+use crate::connection_history::ConnectionHistory;
 use crate::constants::*;
 use crate::db::Database;
-use crate::handlers::{self, HandlerContext, err_invalid_message_format};
+use crate::frame_guard::{MalformedFrameTracker, is_malformed};
+use crate::handlers::{
+    self, HandlerContext, err_invalid_message_format, err_not_logged_in, err_payload_too_large,
+    send_server_message_to_peer,
+};
+use crate::i18n;
 use crate::users::UserManager;
+use crate::users::user::session_channel;
 
 /// Connection state for a single client
 struct ConnectionState {
     session_id: Option<u32>,
-    handshake_complete: bool,
+    /// Account behind the session, kept after the session is removed (e.g.
+    /// by a kick) so the disconnect can still be recorded for /seen
+    db_user_id: Option<i64>,
+    /// Protocol version the client reported, once the handshake succeeded
+    client_version: Option<Version>,
     locale: String,
 }
 
 impl ConnectionState {
-    fn new() -> Self {
+    /// State for a new connection, answered in `locale` until the client logs in
+    fn new(locale: String) -> Self {
         Self {
             session_id: None,
-            handshake_complete: false,
-            locale: "en".to_string(),
+            db_user_id: None,
+            client_version: None,
+            locale,
         }
     }
+
+    /// Whether the client speaks a protocol older than the extended one
+    fn legacy_protocol(&self) -> bool {
+        self.client_version
+            .as_ref()
+            .is_some_and(|version| !supports_extended_protocol(version))
+    }
 }
 
 /// Handle a client connection (always with TLS)
+///
+/// `source` is the connection's source tag (see `connection_source`).
+/// `queue_capacity` bounds the server messages queued for the client.
+/// `login_timeout` is how long the client has, from now, to complete the TLS
+/// handshake, the protocol handshake, and login before it is dropped.
+/// `frame_guard` counts malformed frames towards blocking the peer's address.
+/// `connection_history` is the server's connection history, read by admins.
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_connection(
     socket: TcpStream,
     peer_addr: SocketAddr,
+    source: String,
     user_manager: UserManager,
     db: Database,
     debug: bool,
+    queue_capacity: usize,
+    login_timeout: Duration,
     tls_acceptor: TlsAcceptor,
+    frame_guard: MalformedFrameTracker,
+    connection_history: ConnectionHistory,
 ) -> io::Result<()> {
+    let login_deadline = Instant::now() + login_timeout;
+
     // Perform TLS handshake (mandatory)
-    let tls_stream = tls_acceptor
-        .accept(socket)
-        .await
-        .map_err(|e| io::Error::other(format!("TLS handshake failed: {}", e)))?;
+    let tls_stream = match timeout_at(login_deadline, tls_acceptor.accept(socket)).await {
+        Ok(result) => {
+            result.map_err(|e| io::Error::other(format!("TLS handshake failed: {}", e)))?
+        }
+        Err(_) => {
+            if debug {
+                eprintln!("{}{}", ERR_LOGIN_TIMEOUT, peer_addr);
+            }
+            return Ok(());
+        }
+    };
 
-    handle_connection_inner(tls_stream, peer_addr, user_manager, db, debug).await
+    handle_connection_inner(
+        tls_stream,
+        peer_addr,
+        source,
+        user_manager,
+        db,
+        debug,
+        queue_capacity,
+        login_deadline,
+        frame_guard,
+        connection_history,
+    )
+    .await
 }
 
 /// Inner connection handler that works with any AsyncRead + AsyncWrite stream
+///
+/// The connection is dropped if it hasn't logged in by `login_deadline`.
+#[allow(clippy::too_many_arguments)]
 async fn handle_connection_inner<S>(
     socket: S,
     peer_addr: SocketAddr,
+    source: String,
     user_manager: UserManager,
     db: Database,
     debug: bool,
+    queue_capacity: usize,
+    login_deadline: Instant,
+    frame_guard: MalformedFrameTracker,
+    connection_history: ConnectionHistory,
 ) -> io::Result<()>
 where
     S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + Send + 'static,
@@ -68,11 +132,11 @@ where
     let mut frame_reader = FrameReader::new(buf_reader);
     let mut frame_writer = FrameWriter::new(writer);
 
-    // Create channel for receiving server messages to send to this client
-    let (tx, mut rx) = mpsc::unbounded_channel::<(ServerMessage, Option<MessageId>)>();
+    // Create bounded queue for server messages to send to this client
+    let (tx, mut rx) = session_channel(queue_capacity);
 
     // Connection state
-    let mut conn_state = ConnectionState::new();
+    let mut conn_state = ConnectionState::new(db.config.get_default_locale().await);
 
     // Main loop - handle both incoming messages and outgoing events
     // Uses tokio::select! to handle both reading from client and sending to client concurrently
@@ -89,12 +153,15 @@ where
                         let mut ctx = HandlerContext {
                             writer: &mut frame_writer,
                             peer_addr,
+                            source: &source,
                             user_manager: &user_manager,
                             db: &db,
+                            connection_history: &connection_history,
                             tx: &tx,
                             debug,
                             locale: &locale,
                             message_id: received.message_id,
+                            legacy_protocol: conn_state.legacy_protocol(),
                         };
 
                         if let Err(e) = handle_client_message(
@@ -110,6 +177,44 @@ where
                         // Connection closed cleanly
                         break;
                     }
+                    Err(FrameError::OversizedPayloadSkipped {
+                        message_id,
+                        message_type,
+                        length,
+                        max,
+                    }) => {
+                        // The reader already skipped the payload, so tell the client
+                        // which request was refused and keep the connection
+                        if debug {
+                            eprintln!(
+                                "{}{}: {} ({} > {})",
+                                ERR_PAYLOAD_SKIPPED, peer_addr, message_type, length, max
+                            );
+                        }
+
+                        // Before login it counts towards a block like a malformed frame,
+                        // and a blocked address is disconnected
+                        let blocked = conn_state.session_id.is_none()
+                            && record_malformed_frame(&frame_guard, peer_addr);
+
+                        let error_msg = ServerMessage::Error {
+                            message: err_payload_too_large(&conn_state.locale, max),
+                            command: Some(message_type),
+                            code: Some(ERROR_CODE_PAYLOAD_TOO_LARGE.to_string()),
+                        };
+                        if send_server_message_to_peer(
+                            &mut frame_writer,
+                            &error_msg,
+                            message_id,
+                            conn_state.legacy_protocol(),
+                        )
+                        .await
+                        .is_err()
+                            || blocked
+                        {
+                            break;
+                        }
+                    }
                     Err(e) => {
                         // Invalid magic and timeouts are common (scanners, dropped connections)
                         // Only log in debug mode to reduce noise
@@ -122,15 +227,27 @@ where
                             eprintln!("{}{}: {}", ERR_PARSE_MESSAGE, peer_addr, e);
                         }
 
+                        // Only connections that never logged in count towards a block;
+                        // a logged-in user is no scanner and may share the address
+                        if is_malformed(&e) {
+                            if conn_state.session_id.is_some() {
+                                frame_guard.record_unattributed();
+                            } else {
+                                record_malformed_frame(&frame_guard, peer_addr);
+                            }
+                        }
+
                         // Try to send error before disconnecting
                         let error_msg = ServerMessage::Error {
                             message: err_invalid_message_format(&conn_state.locale),
                             command: None,
+                            code: None,
                         };
-                        let _ = send_server_message_with_id(
+                        let _ = send_server_message_to_peer(
                             &mut frame_writer,
                             &error_msg,
                             MessageId::new(),
+                            conn_state.legacy_protocol(),
                         ).await;
                         break;
                     }
@@ -138,20 +255,63 @@ where
             }
 
             // Handle outgoing server messages/events
-            msg = rx.recv() => {
+            msg = rx.messages.recv() => {
                 match msg {
                     Some((msg, msg_id)) => {
                         // Use provided message ID or generate a new one
                         let id = msg_id.unwrap_or_else(MessageId::new);
-                        if send_server_message_with_id(&mut frame_writer, &msg, id).await.is_err() {
+                        if send_server_message_to_peer(
+                            &mut frame_writer,
+                            &msg,
+                            id,
+                            conn_state.legacy_protocol(),
+                        )
+                        .await
+                        .is_err()
+                        {
                             break;
                         }
                     }
                     None => {
-                        // Channel closed (user was removed from manager) - disconnect
+                        // Every sender is gone, so nothing more can be sent
+                        break;
+                    }
+                }
+            }
+
+            // Scanners and stalled clients that never log in are shed quietly
+            _ = sleep_until(login_deadline), if conn_state.session_id.is_none() => {
+                if debug {
+                    eprintln!("{}{}", ERR_LOGIN_TIMEOUT, peer_addr);
+                }
+                break;
+            }
+
+            // The session was kicked - send what was queued for it (such as
+            // the reason) and disconnect
+            _ = rx.close.notified() => {
+                while let Ok((msg, msg_id)) = rx.messages.try_recv() {
+                    let id = msg_id.unwrap_or_else(MessageId::new);
+                    if send_server_message_to_peer(
+                        &mut frame_writer,
+                        &msg,
+                        id,
+                        conn_state.legacy_protocol(),
+                    )
+                    .await
+                    .is_err()
+                    {
                         break;
                     }
                 }
+                break;
+            }
+
+            // The client stopped reading and its queue filled up - disconnect it
+            // rather than buffer without bound or drop messages
+            _ = rx.overflow.notified() => {
+                eprintln!("{}{}", ERR_SLOW_CLIENT, peer_addr);
+                break;
             }
         }
     }
@@ -159,6 +319,16 @@ where
     // Shutdown the writer gracefully
     let _ = frame_writer.get_mut().shutdown().await;
 
+    // Record the disconnect for /seen
+    if let Some(db_user_id) = conn_state.db_user_id
+        && let Err(e) = db
+            .users
+            .set_last_seen(db_user_id, chrono::Utc::now().timestamp())
+            .await
+    {
+        eprintln!("Database error recording last seen: {}", e);
+    }
+
     // Remove user on disconnect
     if let Some(id) = conn_state.session_id
         && let Some(user) = user_manager.remove_user(id).await
@@ -182,6 +352,35 @@ where
     Ok(())
 }
 
+/// Count a bad frame from a connection that hasn't logged in
+///
+/// Warns and returns `true` if the frame got the address blocked.
+fn record_malformed_frame(frame_guard: &MalformedFrameTracker, peer_addr: SocketAddr) -> bool {
+    let blocked = frame_guard.record(peer_addr.ip());
+    if blocked {
+        eprintln!(
+            "{}{}{}{}{}",
+            WARN_MALFORMED_FRAME_BLOCK,
+            peer_addr.ip(),
+            WARN_MALFORMED_FRAME_BLOCK_FOR,
+            frame_guard.block_duration().as_secs(),
+            WARN_MALFORMED_FRAME_BLOCK_SUFFIX
+        );
+    }
+    blocked
+}
+
+/// Whether a client message can be handled before login
+///
+/// Only the handshake and the login itself; every other command needs a
+/// session.
+fn allowed_before_login(msg: &ClientMessage) -> bool {
+    matches!(
+        msg,
+        ClientMessage::Handshake { .. } | ClientMessage::Login { .. }
+    )
+}
+
 /// Handle a message from the client
 async fn handle_client_message<W>(
     msg: ClientMessage,
@@ -191,15 +390,111 @@ async fn handle_client_message<W>(
 where
     W: tokio::io::AsyncWrite + Unpin,
 {
+    // Enforce the auth gate here for every command, so a handler that forgets
+    // its own session check can't be reached without logging in
+    if conn_state.session_id.is_none() && !allowed_before_login(&msg) {
+        let command = client_message_type(&msg);
+        eprintln!("{} from {} without login", command, ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some(command))
+            .await;
+    }
+
     match msg {
+        ClientMessage::AcceptRules => {
+            handlers::handle_accept_rules(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::AdminNotice { action } => {
+            handlers::handle_admin_notice(action, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::AnnouncementAdd { message } => {
+            handlers::handle_announcement_add(message, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::AnnouncementList => {
+            handlers::handle_announcement_list(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::AnnouncementRemove { index } => {
+            handlers::handle_announcement_remove(index, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::Capabilities => {
+            handlers::handle_capabilities(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::ChatHistoryPurge => {
+            handlers::handle_chat_history_purge(conn_state.session_id, ctx).await?;
+        }
         ClientMessage::ChatSend { message } => {
             handlers::handle_chat_send(message, conn_state.session_id, ctx).await?;
         }
         ClientMessage::ChatTopicUpdate { topic } => {
             handlers::handle_chat_topic_update(topic, conn_state.session_id, ctx).await?;
         }
+        ClientMessage::ConnectionStats { reset } => {
+            handlers::handle_connection_stats(reset, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::PageDelete { name } => {
+            handlers::handle_page_delete(name, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::PageGet { name } => {
+            handlers::handle_page_get(name, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::PageList => {
+            handlers::handle_page_list(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::PageSet { name, content } => {
+            handlers::handle_page_set(name, content, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::Ping => {
+            handlers::handle_ping(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::ChatEdit {
+            message_id,
+            message,
+        } => {
+            handlers::handle_chat_edit(message_id, message, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::ChatDelete { message_id } => {
+            handlers::handle_chat_delete(message_id, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::PinMessage { message_id } => {
+            handlers::handle_pin_message(message_id, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::UnpinMessage { message_id } => {
+            handlers::handle_unpin_message(message_id, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::RefreshUserPermissions { username } => {
+            handlers::handle_refresh_user_permissions(username, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::SetAway { away } => {
+            handlers::handle_set_away(away, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::SetMaintenance { enabled } => {
+            handlers::handle_set_maintenance(enabled, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::SetPreferences { preferences } => {
+            handlers::handle_set_preferences(preferences, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::SeenRequest { username } => {
+            handlers::handle_seen(username, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::SetProfile { bio, pronouns } => {
+            handlers::handle_set_profile(bio, pronouns, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::TransferAdmin {
+            to_username,
+            demote_self,
+        } => {
+            handlers::handle_transfer_admin(to_username, demote_self, conn_state.session_id, ctx)
+                .await?;
+        }
         ClientMessage::Handshake { version } => {
-            handlers::handle_handshake(version, &mut conn_state.handshake_complete, ctx).await?;
+            handlers::handle_handshake(version, &mut conn_state.client_version, ctx).await?;
+        }
+        ClientMessage::HistoryRequest {
+            before_message_id,
+            limit,
+        } => {
+            handlers::handle_history_request(before_message_id, limit, conn_state.session_id, ctx)
+                .await?;
         }
         ClientMessage::Login {
             username,
@@ -214,15 +509,28 @@ where
                 features,
                 locale: locale.clone(),
                 avatar,
-                handshake_complete: conn_state.handshake_complete,
+                handshake_complete: conn_state.client_version.is_some(),
             };
             handlers::handle_login(request, &mut conn_state.session_id, ctx).await?;
+            if let Some(id) = conn_state.session_id {
+                conn_state.db_user_id = ctx
+                    .user_manager
+                    .get_user_by_session_id(id)
+                    .await
+                    .filter(|user| !user.is_guest) // Guests have no account to record
+                    .map(|user| user.db_user_id);
+            }
 
-            // Update connection locale after successful login
-            conn_state.locale = locale;
+            // Update connection locale after successful login (the pre-login
+            // locale is the server default, which login falls back to as well)
+            conn_state.locale = i18n::resolve_locale(&locale, &conn_state.locale);
         }
-        ClientMessage::UserBroadcast { message } => {
-            handlers::handle_user_broadcast(message, conn_state.session_id, ctx).await?;
+        ClientMessage::UserBroadcast {
+            message,
+            target_permission,
+        } => {
+            handlers::handle_user_broadcast(message, target_permission, conn_state.session_id, ctx)
+                .await?;
         }
         ClientMessage::UserCreate {
             username,
@@ -251,6 +559,9 @@ where
         ClientMessage::UserInfo { username } => {
             handlers::handle_user_info(username, conn_state.session_id, ctx).await?;
         }
+        ClientMessage::KickSession { session_id } => {
+            handlers::handle_kick_session(session_id, conn_state.session_id, ctx).await?;
+        }
         ClientMessage::UserKick { username } => {
             handlers::handle_user_kick(username, conn_state.session_id, ctx).await?;
         }
@@ -263,6 +574,24 @@ where
         } => {
             handlers::handle_user_message(to_username, message, conn_state.session_id, ctx).await?;
         }
+        ClientMessage::EncryptedUserMessage {
+            to_username,
+            envelopes,
+        } => {
+            handlers::handle_encrypted_user_message(
+                to_username,
+                envelopes,
+                conn_state.session_id,
+                ctx,
+            )
+            .await?;
+        }
+        ClientMessage::E2eeKeyAnnounce { public_key } => {
+            handlers::handle_e2ee_key_announce(public_key, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::E2eeKeysRequest { username } => {
+            handlers::handle_e2ee_keys_request(username, conn_state.session_id, ctx).await?;
+        }
         ClientMessage::UserUpdate {
             username,
             requested_username,
@@ -282,23 +611,604 @@ where
             };
             handlers::handle_user_update(request, ctx).await?;
         }
+        ClientMessage::WhoAmI => {
+            handlers::handle_who_am_i(conn_state.session_id, ctx).await?;
+        }
         ClientMessage::ServerInfoUpdate {
             name,
             description,
             max_connections_per_ip,
             image,
+            chat_slow_mode,
+            max_message_length,
+            max_topic_length,
         } => {
-            handlers::handle_server_info_update(
+            let request = handlers::ServerInfoUpdateRequest {
                 name,
                 description,
                 max_connections_per_ip,
                 image,
-                conn_state.session_id,
-                ctx,
-            )
-            .await?;
+                chat_slow_mode,
+                max_message_length,
+                max_topic_length,
+                session_id: conn_state.session_id,
+            };
+            handlers::handle_server_info_update(request, ctx).await?;
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::{Permissions, SessionLimitPolicy, hash_password};
+    use nexus_common::io::{read_server_message, send_client_message};
+    use nexus_common::protocol::{ERROR_CODE_KICKED, ERROR_CODE_SESSION_REPLACED};
+    use tokio::io::{AsyncReadExt, DuplexStream, ReadHalf, WriteHalf};
+
+    type TestReader = FrameReader<BufReader<ReadHalf<DuplexStream>>>;
+    type TestWriter = FrameWriter<WriteHalf<DuplexStream>>;
+
+    /// Run a connection over an in-memory pipe and return the client's ends
+    fn spawn_connection(
+        user_manager: &UserManager,
+        db: &Database,
+    ) -> (
+        TestReader,
+        TestWriter,
+        tokio::task::JoinHandle<io::Result<()>>,
+    ) {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let handle = tokio::spawn(handle_connection_inner(
+            server,
+            "127.0.0.1:12345".parse().unwrap(),
+            "loopback".to_string(),
+            user_manager.clone(),
+            db.clone(),
+            false,
+            DEFAULT_OUTGOING_QUEUE_CAPACITY,
+            Instant::now() + Duration::from_secs(5),
+            MalformedFrameTracker::new(
+                DEFAULT_MALFORMED_FRAME_LIMIT,
+                Duration::from_secs(DEFAULT_MALFORMED_FRAME_WINDOW_SECS),
+                Duration::from_secs(DEFAULT_MALFORMED_FRAME_BLOCK_SECS),
+            ),
+            ConnectionHistory::new(),
+        ));
+        let (read_half, write_half) = tokio::io::split(client);
+        (
+            FrameReader::new(BufReader::new(read_half)),
+            FrameWriter::new(write_half),
+            handle,
+        )
+    }
+
+    /// Handshake and log in, skipping anything sent before the login response
+    async fn login(reader: &mut TestReader, writer: &mut TestWriter, username: &str) {
+        send_client_message(
+            writer,
+            &ClientMessage::Handshake {
+                version: nexus_common::PROTOCOL_VERSION.to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        send_client_message(
+            writer,
+            &ClientMessage::Login {
+                username: username.to_string(),
+                password: "password".to_string(),
+                features: Vec::new(),
+                locale: DEFAULT_LOCALE.to_string(),
+                avatar: None,
+            },
+        )
+        .await
+        .unwrap();
+        loop {
+            let received = read_server_message(reader).await.unwrap().unwrap();
+            if let ServerMessage::LoginResponse { success, .. } = received.message {
+                assert!(success, "{username} should be able to log in");
+                return;
+            }
+        }
+    }
+
+    /// Read until the server hangs up, returning the error codes seen on the way
+    async fn error_codes_until_closed(reader: &mut TestReader) -> Vec<Option<String>> {
+        let mut codes = Vec::new();
+        let read_all = async {
+            while let Some(received) = read_server_message(reader).await.unwrap() {
+                if let ServerMessage::Error { code, .. } = received.message {
+                    codes.push(code);
+                }
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(5), read_all)
+            .await
+            .expect("server should close the connection");
+        codes
+    }
+
+    async fn create_user(db: &Database, username: &str, is_admin: bool) {
+        let hashed = hash_password("password").unwrap();
+        db.users
+            .create_user(username, &hashed, is_admin, true, &Permissions::new())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connection_dropped_when_login_deadline_passes() {
+        let (mut client, server) = tokio::io::duplex(1024);
+        let db = Database::new(create_test_db().await);
+
+        // The client connects and never sends anything
+        let handle = tokio::spawn(handle_connection_inner(
+            server,
+            "127.0.0.1:12345".parse().unwrap(),
+            "loopback".to_string(),
+            UserManager::new(),
+            db,
+            false,
+            DEFAULT_OUTGOING_QUEUE_CAPACITY,
+            Instant::now() + Duration::from_millis(50),
+            MalformedFrameTracker::new(
+                DEFAULT_MALFORMED_FRAME_LIMIT,
+                Duration::from_secs(DEFAULT_MALFORMED_FRAME_WINDOW_SECS),
+                Duration::from_secs(DEFAULT_MALFORMED_FRAME_BLOCK_SECS),
+            ),
+            ConnectionHistory::new(),
+        ));
+
+        // Well before the 60s frame timeout, the server hangs up
+        let result = tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("connection should close at the login deadline")
+            .unwrap();
+        assert!(result.is_ok());
+
+        let mut buf = [0u8; 16];
+        assert_eq!(client.read(&mut buf).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_garbage_before_login_counts_towards_block() {
+        let (mut client, server) = tokio::io::duplex(1024);
+        let db = Database::new(create_test_db().await);
+        let peer_addr: SocketAddr = "203.0.113.7:12345".parse().unwrap();
+        let frame_guard = MalformedFrameTracker::new(
+            1,
+            Duration::from_secs(DEFAULT_MALFORMED_FRAME_WINDOW_SECS),
+            Duration::from_secs(DEFAULT_MALFORMED_FRAME_BLOCK_SECS),
+        );
+
+        let handle = tokio::spawn(handle_connection_inner(
+            server,
+            peer_addr,
+            "internet".to_string(),
+            UserManager::new(),
+            db,
+            false,
+            DEFAULT_OUTGOING_QUEUE_CAPACITY,
+            Instant::now() + Duration::from_secs(5),
+            frame_guard.clone(),
+            ConnectionHistory::new(),
+        ));
+
+        client.write_all(b"GET / HTTP/1.1\r\n\r\n").await.unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("connection should close after a malformed frame")
+            .unwrap();
+        assert!(result.is_ok());
+
+        assert!(frame_guard.check_blocked(peer_addr.ip()));
+        assert_eq!(frame_guard.metrics().malformed_frames, 1);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_payload_keeps_connection() {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let db = Database::new(create_test_db().await);
+        let frame_guard = MalformedFrameTracker::new(
+            2,
+            Duration::from_secs(DEFAULT_MALFORMED_FRAME_WINDOW_SECS),
+            Duration::from_secs(DEFAULT_MALFORMED_FRAME_BLOCK_SECS),
+        );
+
+        let handle = tokio::spawn(handle_connection_inner(
+            server,
+            "203.0.113.7:12345".parse().unwrap(),
+            "internet".to_string(),
+            UserManager::new(),
+            db,
+            false,
+            DEFAULT_OUTGOING_QUEUE_CAPACITY,
+            Instant::now() + Duration::from_secs(5),
+            frame_guard.clone(),
+            ConnectionHistory::new(),
+        ));
+
+        let (read_half, mut write_half) = tokio::io::split(client);
+        let mut reader = FrameReader::new(BufReader::new(read_half));
+
+        // A Handshake one byte over its limit is refused but not fatal
+        let oversized = format!("{{\"version\":\"{}\"}}", "x".repeat(66 - 14));
+        let frame = format!(
+            "NX|9|Handshake|a1b2c3d4e5f6|{}|{}\n",
+            oversized.len(),
+            oversized
+        );
+        write_half.write_all(frame.as_bytes()).await.unwrap();
+
+        let received = read_server_message(&mut reader).await.unwrap().unwrap();
+        assert_eq!(
+            received.message_id,
+            MessageId::from_bytes(b"a1b2c3d4e5f6").unwrap()
+        );
+        match received.message {
+            ServerMessage::Error { command, code, .. } => {
+                assert_eq!(command.as_deref(), Some("Handshake"));
+                assert_eq!(code.as_deref(), Some(ERROR_CODE_PAYLOAD_TOO_LARGE));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+
+        // The same connection still handles the next request
+        let handshake = format!(
+            "{{\"type\":\"Handshake\",\"version\":\"{}\"}}",
+            nexus_common::PROTOCOL_VERSION
+        );
+        let frame = format!(
+            "NX|9|Handshake|b2c3d4e5f6a1|{}|{}\n",
+            handshake.len(),
+            handshake
+        );
+        write_half.write_all(frame.as_bytes()).await.unwrap();
+
+        let received = read_server_message(&mut reader).await.unwrap().unwrap();
+        match received.message {
+            ServerMessage::HandshakeResponse { success, .. } => assert!(success),
+            other => panic!("Expected HandshakeResponse, got {:?}", other),
+        }
+        // Not logged in, so the refused frame still counts towards a block
+        assert_eq!(frame_guard.metrics().malformed_frames, 1);
+        assert!(!frame_guard.check_blocked("203.0.113.7".parse().unwrap()));
+
+        write_half.shutdown().await.unwrap();
+        let result = tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("connection should close when the client does")
+            .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_oversized_payloads_before_login_block() {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let db = Database::new(create_test_db().await);
+        let frame_guard = MalformedFrameTracker::new(
+            1,
+            Duration::from_secs(DEFAULT_MALFORMED_FRAME_WINDOW_SECS),
+            Duration::from_secs(DEFAULT_MALFORMED_FRAME_BLOCK_SECS),
+        );
+        let peer_addr: SocketAddr = "203.0.113.7:12345".parse().unwrap();
+
+        let handle = tokio::spawn(handle_connection_inner(
+            server,
+            peer_addr,
+            "internet".to_string(),
+            UserManager::new(),
+            db,
+            false,
+            DEFAULT_OUTGOING_QUEUE_CAPACITY,
+            Instant::now() + Duration::from_secs(5),
+            frame_guard.clone(),
+            ConnectionHistory::new(),
+        ));
+
+        let (read_half, mut write_half) = tokio::io::split(client);
+        let mut reader = FrameReader::new(BufReader::new(read_half));
+
+        let oversized = format!("{{\"version\":\"{}\"}}", "x".repeat(66 - 14));
+        let frame = format!(
+            "NX|9|Handshake|a1b2c3d4e5f6|{}|{}\n",
+            oversized.len(),
+            oversized
+        );
+        write_half.write_all(frame.as_bytes()).await.unwrap();
+
+        // The request is refused, then the now-blocked address is dropped
+        let received = read_server_message(&mut reader).await.unwrap().unwrap();
+        assert!(matches!(
+            received.message,
+            ServerMessage::Error { ref code, .. }
+                if code.as_deref() == Some(ERROR_CODE_PAYLOAD_TOO_LARGE)
+        ));
+        let result = tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("connection should close once the address is blocked")
+            .unwrap();
+        assert!(result.is_ok());
+        assert!(frame_guard.check_blocked(peer_addr.ip()));
+    }
+
+    #[tokio::test]
+    async fn test_legacy_peer_only_gets_what_it_can_read() {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let mut writer = FrameWriter::new(server);
+        let mut reader = FrameReader::new(BufReader::new(client));
+
+        let chat = |message: String| ServerMessage::ChatMessage {
+            session_id: 1,
+            username: "alice".to_string(),
+            message,
+            message_id: Some(1),
+            mentions: None,
+        };
+        let announcement = ServerMessage::ServerAnnouncement {
+            message: "hello".to_string(),
+        };
+
+        // A type 0.5 doesn't know, and a frame over its 0.5 limit, are dropped
+        for message in [announcement.clone(), chat("😀".repeat(1024))] {
+            send_server_message_to_peer(&mut writer, &message, MessageId::new(), true)
+                .await
+                .unwrap();
+        }
+        send_server_message_to_peer(&mut writer, &chat("hi".to_string()), MessageId::new(), true)
+            .await
+            .unwrap();
+
+        // Newer peers get everything
+        send_server_message_to_peer(&mut writer, &announcement, MessageId::new(), false)
+            .await
+            .unwrap();
+
+        let received = read_server_message(&mut reader).await.unwrap().unwrap();
+        assert!(
+            matches!(received.message, ServerMessage::ChatMessage { ref message, .. } if message == "hi")
+        );
+        let received = read_server_message(&mut reader).await.unwrap().unwrap();
+        assert!(matches!(
+            received.message,
+            ServerMessage::ServerAnnouncement { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_hostile_payload_size_disconnects() {
+        let (client, server) = tokio::io::duplex(1024);
+        let db = Database::new(create_test_db().await);
+        let frame_guard = MalformedFrameTracker::new(
+            1,
+            Duration::from_secs(DEFAULT_MALFORMED_FRAME_WINDOW_SECS),
+            Duration::from_secs(DEFAULT_MALFORMED_FRAME_BLOCK_SECS),
+        );
+        let peer_addr: SocketAddr = "203.0.113.7:12345".parse().unwrap();
+
+        let handle = tokio::spawn(handle_connection_inner(
+            server,
+            peer_addr,
+            "internet".to_string(),
+            UserManager::new(),
+            db,
+            false,
+            DEFAULT_OUTGOING_QUEUE_CAPACITY,
+            Instant::now() + Duration::from_secs(5),
+            frame_guard.clone(),
+            ConnectionHistory::new(),
+        ));
+
+        let (read_half, mut write_half) = tokio::io::split(client);
+        let mut reader = FrameReader::new(BufReader::new(read_half));
+
+        // Past the skippable cap the header alone ends the connection
+        let length = nexus_common::framing::MAX_SKIPPABLE_PAYLOAD_LENGTH + 1;
+        let frame = format!("NX|9|Handshake|a1b2c3d4e5f6|{length}|");
+        write_half.write_all(frame.as_bytes()).await.unwrap();
+
+        let received = read_server_message(&mut reader).await.unwrap().unwrap();
+        assert!(matches!(
+            received.message,
+            ServerMessage::Error { code: None, .. }
+        ));
+
+        let result = tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("connection should close after a hostile frame")
+            .unwrap();
+        assert!(result.is_ok());
+        assert!(frame_guard.check_blocked(peer_addr.ip()));
+    }
+
+    #[tokio::test]
+    async fn test_chat_before_login_disconnects() {
+        let (client, server) = tokio::io::duplex(1024);
+        let db = Database::new(create_test_db().await);
+
+        let handle = tokio::spawn(handle_connection_inner(
+            server,
+            "127.0.0.1:12345".parse().unwrap(),
+            "loopback".to_string(),
+            UserManager::new(),
+            db,
+            false,
+            DEFAULT_OUTGOING_QUEUE_CAPACITY,
+            Instant::now() + Duration::from_secs(5),
+            MalformedFrameTracker::new(
+                DEFAULT_MALFORMED_FRAME_LIMIT,
+                Duration::from_secs(DEFAULT_MALFORMED_FRAME_WINDOW_SECS),
+                Duration::from_secs(DEFAULT_MALFORMED_FRAME_BLOCK_SECS),
+            ),
+            ConnectionHistory::new(),
+        ));
+
+        let (read_half, write_half) = tokio::io::split(client);
+        let mut reader = FrameReader::new(BufReader::new(read_half));
+        let mut writer = FrameWriter::new(write_half);
+
+        // A completed handshake is not a login
+        nexus_common::io::send_client_message(
+            &mut writer,
+            &ClientMessage::Handshake {
+                version: nexus_common::PROTOCOL_VERSION.to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let received = read_server_message(&mut reader).await.unwrap().unwrap();
+        assert!(matches!(
+            received.message,
+            ServerMessage::HandshakeResponse { success: true, .. }
+        ));
+
+        nexus_common::io::send_client_message(
+            &mut writer,
+            &ClientMessage::ChatSend {
+                message: "hello".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let received = read_server_message(&mut reader).await.unwrap().unwrap();
+        match received.message {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
+                assert_eq!(message, err_not_logged_in(DEFAULT_LOCALE));
+                assert_eq!(command.as_deref(), Some("ChatSend"));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+
+        // The server hangs up without waiting for the client
+        let result = tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("connection should close after a command before login")
+            .unwrap();
+        assert!(result.is_ok());
+        assert!(read_server_message(&mut reader).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_replaced_session_is_disconnected() {
+        let db = Database::new(create_test_db().await);
+        let user_manager = UserManager::new();
+        create_user(&db, "bob", false).await;
+        db.config.set_max_sessions_per_user(1).await.unwrap();
+        db.config
+            .set_session_limit_policy(SessionLimitPolicy::ReplaceOldest)
+            .await
+            .unwrap();
+
+        let (mut old_reader, mut old_writer, old_handle) = spawn_connection(&user_manager, &db);
+        login(&mut old_reader, &mut old_writer, "bob").await;
+        let (mut new_reader, mut new_writer, _new_handle) = spawn_connection(&user_manager, &db);
+        login(&mut new_reader, &mut new_writer, "bob").await;
+
+        // The old connection is told why, then closed while its client stays
+        let codes = error_codes_until_closed(&mut old_reader).await;
+        assert_eq!(codes, vec![Some(ERROR_CODE_SESSION_REPLACED.to_string())]);
+        assert!(old_handle.await.unwrap().is_ok());
+        assert_eq!(user_manager.get_session_ids_for_user("bob").await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deleted_user_is_disconnected() {
+        let db = Database::new(create_test_db().await);
+        let user_manager = UserManager::new();
+        create_user(&db, "admin", true).await;
+        create_user(&db, "bob", false).await;
+
+        let (mut bob_reader, mut bob_writer, bob_handle) = spawn_connection(&user_manager, &db);
+        login(&mut bob_reader, &mut bob_writer, "bob").await;
+        let (mut admin_reader, mut admin_writer, _admin_handle) =
+            spawn_connection(&user_manager, &db);
+        login(&mut admin_reader, &mut admin_writer, "admin").await;
+
+        send_client_message(
+            &mut admin_writer,
+            &ClientMessage::UserDelete {
+                username: "bob".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        // Bob is told why, then closed while their client stays
+        let codes = error_codes_until_closed(&mut bob_reader).await;
+        assert_eq!(codes, vec![None]);
+        assert!(bob_handle.await.unwrap().is_ok());
+        assert!(
+            user_manager
+                .get_session_ids_for_user("bob")
+                .await
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kicked_user_is_disconnected() {
+        let db = Database::new(create_test_db().await);
+        let user_manager = UserManager::new();
+        create_user(&db, "admin", true).await;
+        create_user(&db, "bob", false).await;
+
+        let (mut bob_reader, mut bob_writer, bob_handle) = spawn_connection(&user_manager, &db);
+        login(&mut bob_reader, &mut bob_writer, "bob").await;
+        let (mut admin_reader, mut admin_writer, _admin_handle) =
+            spawn_connection(&user_manager, &db);
+        login(&mut admin_reader, &mut admin_writer, "admin").await;
+
+        send_client_message(
+            &mut admin_writer,
+            &ClientMessage::UserKick {
+                username: "bob".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let codes = error_codes_until_closed(&mut bob_reader).await;
+        assert_eq!(codes, vec![Some(ERROR_CODE_KICKED.to_string())]);
+        assert!(bob_handle.await.unwrap().is_ok());
+        assert!(
+            user_manager
+                .get_session_ids_for_user("bob")
+                .await
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_only_handshake_and_login_allowed_before_login() {
+        assert!(allowed_before_login(&ClientMessage::Handshake {
+            version: nexus_common::PROTOCOL_VERSION.to_string(),
+        }));
+        assert!(allowed_before_login(&ClientMessage::Login {
+            username: "alice".to_string(),
+            password: "password".to_string(),
+            features: Vec::new(),
+            locale: DEFAULT_LOCALE.to_string(),
+            avatar: None,
+        }));
+        assert!(!allowed_before_login(&ClientMessage::ChatSend {
+            message: "hello".to_string(),
+        }));
+        assert!(!allowed_before_login(&ClientMessage::UserMessage {
+            to_username: "bob".to_string(),
+            message: "hi".to_string(),
+        }));
+        assert!(!allowed_before_login(&ClientMessage::UserBroadcast {
+            message: "hi".to_string(),
+            target_permission: None,
+        }));
+        assert!(!allowed_before_login(&ClientMessage::Ping));
+    }
+}
@@ -0,0 +1,212 @@
+//! Rolling connection history for capacity planning
+//!
+//! The accept loop records every connection it accepts or turns away, and
+//! admins can read the last hour back with `ConnectionStats` without any
+//! external monitoring. Counts are kept in one-minute buckets in a ring
+//! buffer, so memory stays bounded however busy the server is.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use nexus_common::protocol::{ConnectionStatsBucket, MAX_CONNECTION_STATS_BUCKETS};
+
+/// Length of one history bucket in seconds
+const BUCKET_SECS: i64 = 60;
+
+/// Buckets plus the number of connections open right now
+#[derive(Debug, Default)]
+struct HistoryState {
+    buckets: VecDeque<ConnectionStatsBucket>,
+    open: u32,
+}
+
+impl HistoryState {
+    /// Bucket covering `now`, starting new buckets as time moves on
+    ///
+    /// Minutes without any activity get buckets too, with the connections
+    /// that stayed open through them as their peak.
+    fn current(&mut self, now: i64) -> &mut ConnectionStatsBucket {
+        let start = now - now.rem_euclid(BUCKET_SECS);
+        let mut next = match self.buckets.back() {
+            Some(last) if last.start >= start => None,
+            // Skip minutes that would fall out of the history anyway
+            Some(last) => Some(
+                (last.start + BUCKET_SECS)
+                    .max(start - (MAX_CONNECTION_STATS_BUCKETS as i64 - 1) * BUCKET_SECS),
+            ),
+            None => Some(start),
+        };
+
+        while let Some(bucket_start) = next.filter(|s| *s <= start) {
+            if self.buckets.len() == MAX_CONNECTION_STATS_BUCKETS {
+                self.buckets.pop_front();
+            }
+            self.buckets.push_back(ConnectionStatsBucket {
+                start: bucket_start,
+                seconds: BUCKET_SECS as u32,
+                connections: 0,
+                refused: 0,
+                peak: self.open,
+                peak_per_ip: 0,
+            });
+            next = Some(bucket_start + BUCKET_SECS);
+        }
+
+        self.buckets
+            .back_mut()
+            .expect("a bucket was just added or already covers now")
+    }
+}
+
+/// Shared, cloneable handle to the server's connection history
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionHistory {
+    state: Arc<Mutex<HistoryState>>,
+}
+
+impl ConnectionHistory {
+    /// Create an empty history
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an accepted connection, now one of `open_from_ip` from its address
+    pub fn record_opened(&self, open_from_ip: usize) {
+        self.record_opened_at(now(), open_from_ip);
+    }
+
+    /// Record that an accepted connection has closed
+    pub fn record_closed(&self) {
+        let mut state = self.state.lock().expect("connection history lock");
+        state.open = state.open.saturating_sub(1);
+    }
+
+    /// Record a connection turned away before it was accepted
+    pub fn record_refused(&self) {
+        self.record_refused_at(now());
+    }
+
+    /// Connections open now and the recorded buckets, oldest first
+    #[must_use]
+    pub fn snapshot(&self) -> (u32, Vec<ConnectionStatsBucket>) {
+        self.snapshot_at(now())
+    }
+
+    /// Forget the recorded buckets (connections still open are kept)
+    pub fn reset(&self) {
+        let mut state = self.state.lock().expect("connection history lock");
+        state.buckets.clear();
+    }
+
+    fn record_opened_at(&self, now: i64, open_from_ip: usize) {
+        let mut state = self.state.lock().expect("connection history lock");
+        state.open = state.open.saturating_add(1);
+        let open = state.open;
+        let bucket = state.current(now);
+        bucket.connections = bucket.connections.saturating_add(1);
+        bucket.peak = bucket.peak.max(open);
+        bucket.peak_per_ip = bucket.peak_per_ip.max(open_from_ip as u32);
+    }
+
+    fn record_refused_at(&self, now: i64) {
+        let mut state = self.state.lock().expect("connection history lock");
+        let bucket = state.current(now);
+        bucket.refused = bucket.refused.saturating_add(1);
+    }
+
+    fn snapshot_at(&self, now: i64) -> (u32, Vec<ConnectionStatsBucket>) {
+        let mut state = self.state.lock().expect("connection history lock");
+        // Bring the history up to date so quiet minutes show up
+        if !state.buckets.is_empty() {
+            state.current(now);
+        }
+        (state.open, state.buckets.iter().copied().collect())
+    }
+}
+
+/// Current Unix time in seconds
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Start of some minute
+    const T0: i64 = 1_700_000_040;
+
+    #[test]
+    fn test_counts_within_a_minute() {
+        let history = ConnectionHistory::new();
+
+        history.record_opened_at(T0, 1);
+        history.record_opened_at(T0 + 10, 2);
+        history.record_closed();
+        history.record_opened_at(T0 + 20, 1);
+        history.record_refused_at(T0 + 30);
+
+        let (open, buckets) = history.snapshot_at(T0 + 59);
+        assert_eq!(open, 2);
+        assert_eq!(
+            buckets,
+            vec![ConnectionStatsBucket {
+                start: T0,
+                seconds: 60,
+                connections: 3,
+                refused: 1,
+                peak: 2,
+                peak_per_ip: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_quiet_minutes_carry_open_connections() {
+        let history = ConnectionHistory::new();
+        history.record_opened_at(T0, 1);
+
+        let (open, buckets) = history.snapshot_at(T0 + 3 * 60 + 5);
+        assert_eq!(open, 1);
+        assert_eq!(buckets.len(), 4);
+        assert_eq!(buckets[3].start, T0 + 3 * 60);
+        for bucket in &buckets[1..] {
+            assert_eq!(bucket.connections, 0);
+            assert_eq!(bucket.peak, 1);
+        }
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let history = ConnectionHistory::new();
+        for minute in 0..200 {
+            history.record_opened_at(T0 + minute * 60, 1);
+            history.record_closed();
+        }
+
+        let (_, buckets) = history.snapshot_at(T0 + 199 * 60);
+        assert_eq!(buckets.len(), MAX_CONNECTION_STATS_BUCKETS);
+        assert_eq!(buckets[0].start, T0 + 140 * 60);
+
+        // A long idle gap doesn't add more buckets than the history holds
+        let (_, buckets) = history.snapshot_at(T0 + 100_000 * 60);
+        assert_eq!(buckets.len(), MAX_CONNECTION_STATS_BUCKETS);
+        assert!(buckets.iter().all(|b| b.connections == 0));
+    }
+
+    #[test]
+    fn test_reset_keeps_open_connections() {
+        let history = ConnectionHistory::new();
+        history.record_opened_at(T0, 1);
+        history.reset();
+
+        assert_eq!(history.snapshot_at(T0 + 120), (1, Vec::new()));
+
+        history.record_refused_at(T0 + 125);
+        let (_, buckets) = history.snapshot_at(T0 + 130);
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].peak, 1);
+        assert_eq!(buckets[0].refused, 1);
+    }
+}
@@ -0,0 +1,250 @@
+//! Connection source classification
+//!
+//! Tags each connection with where it comes from based on the peer IP, so
+//! admins can see at a glance whether a user is local, on the LAN, or coming
+//! in over the internet. Operators can add their own `CIDR=TAG` rules (e.g.
+//! the address of a Tor container or a VPN subnet), which take precedence
+//! over the built-in ranges.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use nexus_common::validators::{SourceTagError, validate_source_tag};
+
+use crate::constants::{
+    ERR_SOURCE_RULE_FORMAT, ERR_SOURCE_RULE_NETWORK, ERR_SOURCE_RULE_PREFIX, ERR_SOURCE_TAG_INVALID,
+};
+
+/// Tag for loopback connections (127.0.0.0/8, ::1)
+pub const SOURCE_LOOPBACK: &str = "loopback";
+
+/// Tag for private, link-local and unique local ranges
+pub const SOURCE_LAN: &str = "lan";
+
+/// Tag for the Yggdrasil mesh network (200::/7)
+pub const SOURCE_YGGDRASIL: &str = "yggdrasil";
+
+/// Tag for everything else
+pub const SOURCE_INTERNET: &str = "internet";
+
+/// A `CIDR=TAG` mapping supplied by the operator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceRule {
+    network: IpAddr,
+    prefix_len: u8,
+    tag: String,
+}
+
+impl SourceRule {
+    /// Whether `ip` falls inside this rule's network
+    fn matches(&self, ip: IpAddr) -> bool {
+        match (self.network, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                prefix_matches(network.to_bits(), ip.to_bits(), self.prefix_len)
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                prefix_matches(network.to_bits(), ip.to_bits(), self.prefix_len)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for SourceRule {
+    type Err = String;
+
+    /// Parse `CIDR=TAG`, e.g. `10.8.0.0/16=vpn` (a bare address is a single host)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cidr, tag) = s
+            .split_once('=')
+            .ok_or_else(|| format!("{}{}", ERR_SOURCE_RULE_FORMAT, s))?;
+
+        validate_source_tag(tag).map_err(|e| {
+            let reason = match e {
+                SourceTagError::Empty => "empty",
+                SourceTagError::TooLong => "too long",
+                SourceTagError::InvalidCharacters => "invalid characters",
+            };
+            format!("{}{} ({})", ERR_SOURCE_TAG_INVALID, tag, reason)
+        })?;
+
+        let (address, prefix) = match cidr.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (cidr, None),
+        };
+        let network: IpAddr = address
+            .parse()
+            .map_err(|_| format!("{}{}", ERR_SOURCE_RULE_NETWORK, address))?;
+        let max_prefix = if network.is_ipv4() { 32 } else { 128 };
+        let prefix_len = match prefix {
+            Some(prefix) => prefix
+                .parse::<u8>()
+                .ok()
+                .filter(|len| *len <= max_prefix)
+                .ok_or_else(|| format!("{}{}", ERR_SOURCE_RULE_PREFIX, prefix))?,
+            None => max_prefix,
+        };
+
+        Ok(Self {
+            network: network.to_canonical(),
+            prefix_len,
+            tag: tag.to_string(),
+        })
+    }
+}
+
+/// Classifies peer addresses into source tags
+#[derive(Debug, Clone, Default)]
+pub struct SourceClassifier {
+    rules: Vec<SourceRule>,
+}
+
+impl SourceClassifier {
+    /// Create a classifier with operator rules layered over the built-in ranges
+    pub fn new(rules: Vec<SourceRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Tag for a peer address
+    ///
+    /// The most specific matching operator rule wins; otherwise the address is
+    /// classified by the built-in ranges. IPv4-mapped IPv6 addresses are
+    /// treated as IPv4.
+    pub fn classify(&self, ip: IpAddr) -> String {
+        let ip = ip.to_canonical();
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(ip))
+            .max_by_key(|rule| rule.prefix_len)
+            .map(|rule| rule.tag.clone())
+            .unwrap_or_else(|| builtin_source(ip).to_string())
+    }
+}
+
+/// Built-in classification of an address
+fn builtin_source(ip: IpAddr) -> &'static str {
+    match ip {
+        IpAddr::V4(ip) => builtin_source_v4(ip),
+        IpAddr::V6(ip) => builtin_source_v6(ip),
+    }
+}
+
+fn builtin_source_v4(ip: Ipv4Addr) -> &'static str {
+    if ip.is_loopback() {
+        SOURCE_LOOPBACK
+    } else if ip.is_private() || ip.is_link_local() {
+        SOURCE_LAN
+    } else {
+        SOURCE_INTERNET
+    }
+}
+
+fn builtin_source_v6(ip: Ipv6Addr) -> &'static str {
+    let bits = ip.to_bits();
+    if ip.is_loopback() {
+        SOURCE_LOOPBACK
+    } else if prefix_matches(0xfc00 << 112, bits, 7) || prefix_matches(0xfe80 << 112, bits, 10) {
+        // Unique local (fc00::/7) and link-local (fe80::/10)
+        SOURCE_LAN
+    } else if prefix_matches(0x0200 << 112, bits, 7) {
+        SOURCE_YGGDRASIL
+    } else {
+        SOURCE_INTERNET
+    }
+}
+
+/// Whether the top `prefix_len` bits of `network` and `ip` are equal
+fn prefix_matches<T>(network: T, ip: T, prefix_len: u8) -> bool
+where
+    T: Copy + std::ops::Shr<u32, Output = T> + std::ops::BitXor<Output = T> + PartialEq + From<u8>,
+{
+    let bits = (std::mem::size_of::<T>() * 8) as u32;
+    let prefix_len = u32::from(prefix_len);
+    if prefix_len == 0 {
+        return true;
+    }
+    ((network ^ ip) >> (bits - prefix_len)) == T::from(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classify(classifier: &SourceClassifier, ip: &str) -> String {
+        classifier.classify(ip.parse().unwrap())
+    }
+
+    #[test]
+    fn test_builtin_loopback() {
+        let classifier = SourceClassifier::default();
+        assert_eq!(classify(&classifier, "127.0.0.1"), SOURCE_LOOPBACK);
+        assert_eq!(classify(&classifier, "127.10.0.2"), SOURCE_LOOPBACK);
+        assert_eq!(classify(&classifier, "::1"), SOURCE_LOOPBACK);
+        assert_eq!(classify(&classifier, "::ffff:127.0.0.1"), SOURCE_LOOPBACK);
+    }
+
+    #[test]
+    fn test_builtin_lan() {
+        let classifier = SourceClassifier::default();
+        assert_eq!(classify(&classifier, "10.1.2.3"), SOURCE_LAN);
+        assert_eq!(classify(&classifier, "172.16.0.1"), SOURCE_LAN);
+        assert_eq!(classify(&classifier, "172.31.255.255"), SOURCE_LAN);
+        assert_eq!(classify(&classifier, "192.168.1.20"), SOURCE_LAN);
+        assert_eq!(classify(&classifier, "169.254.0.5"), SOURCE_LAN);
+        assert_eq!(classify(&classifier, "fd12:3456::1"), SOURCE_LAN);
+        assert_eq!(classify(&classifier, "fe80::1"), SOURCE_LAN);
+        assert_eq!(classify(&classifier, "::ffff:192.168.0.1"), SOURCE_LAN);
+    }
+
+    #[test]
+    fn test_builtin_yggdrasil() {
+        let classifier = SourceClassifier::default();
+        assert_eq!(classify(&classifier, "200:1234::1"), SOURCE_YGGDRASIL);
+        assert_eq!(classify(&classifier, "300:abcd::1"), SOURCE_YGGDRASIL);
+    }
+
+    #[test]
+    fn test_builtin_internet() {
+        let classifier = SourceClassifier::default();
+        assert_eq!(classify(&classifier, "8.8.8.8"), SOURCE_INTERNET);
+        assert_eq!(classify(&classifier, "172.32.0.1"), SOURCE_INTERNET);
+        assert_eq!(classify(&classifier, "2001:db8::1"), SOURCE_INTERNET);
+        assert_eq!(classify(&classifier, "400::1"), SOURCE_INTERNET);
+    }
+
+    #[test]
+    fn test_rules_override_builtin() {
+        let classifier = SourceClassifier::new(vec![
+            "172.20.0.2=tor".parse().unwrap(),
+            "10.8.0.0/16=vpn".parse().unwrap(),
+            "10.8.5.0/24=office".parse().unwrap(),
+            "2001:db8::/32=partner".parse().unwrap(),
+        ]);
+        assert_eq!(classify(&classifier, "172.20.0.2"), "tor");
+        assert_eq!(classify(&classifier, "172.20.0.3"), SOURCE_LAN);
+        assert_eq!(classify(&classifier, "10.8.1.1"), "vpn");
+        // Most specific rule wins
+        assert_eq!(classify(&classifier, "10.8.5.9"), "office");
+        assert_eq!(classify(&classifier, "10.9.0.1"), SOURCE_LAN);
+        assert_eq!(classify(&classifier, "2001:db8:1::1"), "partner");
+        assert_eq!(classify(&classifier, "::ffff:10.8.1.1"), "vpn");
+    }
+
+    #[test]
+    fn test_catch_all_rule() {
+        let classifier = SourceClassifier::new(vec!["0.0.0.0/0=external".parse().unwrap()]);
+        assert_eq!(classify(&classifier, "8.8.8.8"), "external");
+        assert_eq!(classify(&classifier, "2001:db8::1"), SOURCE_INTERNET);
+    }
+
+    #[test]
+    fn test_parse_rule_errors() {
+        assert!("10.0.0.0/8".parse::<SourceRule>().is_err());
+        assert!("10.0.0.0/8=".parse::<SourceRule>().is_err());
+        assert!("10.0.0.0/8=my lan".parse::<SourceRule>().is_err());
+        assert!("10.0.0.0/33=lan".parse::<SourceRule>().is_err());
+        assert!("::/129=lan".parse::<SourceRule>().is_err());
+        assert!("10.0.0/8=lan".parse::<SourceRule>().is_err());
+        assert!("example.com=lan".parse::<SourceRule>().is_err());
+    }
+}
@@ -1,12 +1,15 @@
 //! Connection tracking for DoS protection
 //!
 //! This module provides connection limiting per IP address to prevent
-//! resource exhaustion attacks.
+//! resource exhaustion attacks. Accepted and refused connections are also
+//! recorded in the server's connection history.
 
 use std::collections::HashMap;
 use std::net::IpAddr;
 use std::sync::{Arc, Mutex};
 
+use crate::connection_history::ConnectionHistory;
+
 /// Tracks active connections per IP address
 ///
 /// This is used to enforce connection limits and prevent a single IP
@@ -17,15 +20,18 @@ pub struct ConnectionTracker {
     connections: Arc<Mutex<HashMap<IpAddr, usize>>>,
     /// Maximum connections allowed per IP
     max_per_ip: usize,
+    /// Rolling history of accepted and refused connections
+    history: ConnectionHistory,
 }
 
 impl ConnectionTracker {
     /// Create a new connection tracker with the specified limit
     #[must_use]
-    pub fn new(max_per_ip: usize) -> Self {
+    pub fn new(max_per_ip: usize, history: ConnectionHistory) -> Self {
         Self {
             connections: Arc::new(Mutex::new(HashMap::new())),
             max_per_ip,
+            history,
         }
     }
 
@@ -40,13 +46,16 @@ impl ConnectionTracker {
         let count = connections.entry(ip).or_insert(0);
 
         if *count >= self.max_per_ip {
+            self.history.record_refused();
             return None;
         }
 
         *count += 1;
+        self.history.record_opened(*count);
         Some(ConnectionGuard {
             ip,
             connections: self.connections.clone(),
+            history: self.history.clone(),
         })
     }
 }
@@ -59,10 +68,12 @@ impl ConnectionTracker {
 pub struct ConnectionGuard {
     ip: IpAddr,
     connections: Arc<Mutex<HashMap<IpAddr, usize>>>,
+    history: ConnectionHistory,
 }
 
 impl Drop for ConnectionGuard {
     fn drop(&mut self) {
+        self.history.record_closed();
         let mut connections = self.connections.lock().expect("connection tracker lock");
         if let Some(count) = connections.get_mut(&self.ip) {
             *count = count.saturating_sub(1);
@@ -94,7 +105,7 @@ mod tests {
 
     #[test]
     fn test_acquire_and_release() {
-        let tracker = ConnectionTracker::new(2);
+        let tracker = ConnectionTracker::new(2, ConnectionHistory::new());
         let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
 
         // Should be able to acquire up to the limit
@@ -122,7 +133,7 @@ mod tests {
 
     #[test]
     fn test_different_ips_independent() {
-        let tracker = ConnectionTracker::new(1);
+        let tracker = ConnectionTracker::new(1, ConnectionHistory::new());
         let ip1 = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
         let ip2 = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2));
 
@@ -146,7 +157,7 @@ mod tests {
 
     #[test]
     fn test_total_connections() {
-        let tracker = ConnectionTracker::new(5);
+        let tracker = ConnectionTracker::new(5, ConnectionHistory::new());
         let ip1 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
         let ip2 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
 
@@ -163,7 +174,7 @@ mod tests {
 
     #[test]
     fn test_cleanup_on_zero() {
-        let tracker = ConnectionTracker::new(2);
+        let tracker = ConnectionTracker::new(2, ConnectionHistory::new());
         let ip = IpAddr::V4(Ipv4Addr::new(172, 16, 0, 1));
 
         let guard = tracker.try_acquire(ip).unwrap();
@@ -176,4 +187,23 @@ mod tests {
         let connections = tracker.connections.lock().expect("connection tracker lock");
         assert!(!connections.contains_key(&ip));
     }
+
+    #[test]
+    fn test_records_history() {
+        let history = ConnectionHistory::new();
+        let tracker = ConnectionTracker::new(1, history.clone());
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3));
+
+        let guard = tracker.try_acquire(ip).unwrap();
+        assert!(tracker.try_acquire(ip).is_none());
+
+        let (open, buckets) = history.snapshot();
+        assert_eq!(open, 1);
+        assert_eq!(buckets.iter().map(|b| b.connections).sum::<u32>(), 1);
+        assert_eq!(buckets.iter().map(|b| b.refused).sum::<u32>(), 1);
+        assert_eq!(buckets.iter().map(|b| b.peak_per_ip).max(), Some(1));
+
+        drop(guard);
+        assert_eq!(history.snapshot().0, 0);
+    }
 }
@@ -13,6 +13,204 @@ pub const CONFIG_KEY_MAX_CONNECTIONS_PER_IP: &str = "max_connections_per_ip";
 /// Default maximum connections per IP address (matches migration default)
 pub const DEFAULT_MAX_CONNECTIONS_PER_IP: usize = 5;
 
+/// Configuration key for the cap on concurrent sessions per account
+pub const CONFIG_KEY_MAX_SESSIONS_PER_USER: &str = "max_sessions_per_user";
+
+/// Default cap on concurrent sessions per account (0 = unlimited, matches migration default)
+pub const DEFAULT_MAX_SESSIONS_PER_USER: u32 = 0;
+
+/// Configuration key for what a login over the session cap does
+pub const CONFIG_KEY_SESSION_LIMIT_POLICY: &str = "session_limit_policy";
+
+/// Configuration key for whether admins are exempt from the session cap
+pub const CONFIG_KEY_SESSION_LIMIT_ADMIN_EXEMPT: &str = "session_limit_admin_exempt";
+
+// =============================================================================
+// Chat Slow Mode
+// =============================================================================
+
+/// Configuration key for chat slow mode interval in the database
+pub const CONFIG_KEY_CHAT_SLOW_MODE: &str = "chat_slow_mode_seconds";
+
+/// Default chat slow mode interval in seconds (0 = disabled, matches migration default)
+pub const DEFAULT_CHAT_SLOW_MODE_SECONDS: u32 = 0;
+
+// =============================================================================
+// Command Cooldowns
+// =============================================================================
+
+/// Configuration key for the per-session cooldown on expensive commands
+pub const CONFIG_KEY_COMMAND_COOLDOWN: &str = "command_cooldown_seconds";
+
+/// Default command cooldown in seconds (0 = disabled, matches migration default)
+pub const DEFAULT_COMMAND_COOLDOWN_SECONDS: u32 = 0;
+
+/// Longest accepted command cooldown in seconds
+pub const MAX_COMMAND_COOLDOWN_SECONDS: u32 = 300;
+
+/// Shortest interval between history requests from one session in seconds
+///
+/// Applies to admins too; the configured command cooldown takes over for
+/// everyone else when it is longer.
+pub const HISTORY_REQUEST_INTERVAL_SECONDS: u32 = 1;
+
+// =============================================================================
+// Message Limits
+// =============================================================================
+
+/// Configuration key for max message length (chat, broadcast, private messages)
+pub const CONFIG_KEY_MAX_MESSAGE_LENGTH: &str = "max_message_length";
+
+/// Configuration key for max chat topic length
+pub const CONFIG_KEY_MAX_TOPIC_LENGTH: &str = "max_topic_length";
+
+// =============================================================================
+// Avatar Dimensions
+// =============================================================================
+
+/// Configuration key for the smallest accepted avatar width or height in pixels
+pub const CONFIG_KEY_AVATAR_MIN_DIMENSION: &str = "avatar_min_dimension";
+
+/// Configuration key for the largest accepted avatar width or height in pixels
+pub const CONFIG_KEY_AVATAR_MAX_DIMENSION: &str = "avatar_max_dimension";
+
+/// Configuration key for downscaling oversized avatars instead of rejecting them
+pub const CONFIG_KEY_AVATAR_DOWNSCALE: &str = "avatar_downscale";
+
+/// Default smallest avatar side in pixels (matches migration default)
+pub const DEFAULT_AVATAR_MIN_DIMENSION: u32 = 16;
+
+/// Default largest avatar side in pixels (matches migration default)
+pub const DEFAULT_AVATAR_MAX_DIMENSION: u32 = 1024;
+
+/// Largest configurable avatar side; bigger images are never decoded
+pub const AVATAR_DIMENSION_CEILING: u32 = 4096;
+
+// =============================================================================
+// Chat History Retention
+// =============================================================================
+
+/// Configuration key for whether public chat is recorded in the history
+pub const CONFIG_KEY_CHAT_HISTORY: &str = "chat_history";
+
+/// Configuration key for the maximum number of chat history messages kept
+pub const CONFIG_KEY_CHAT_RETENTION_COUNT: &str = "chat_retention_count";
+
+/// Configuration key for the maximum age of chat history messages in days
+pub const CONFIG_KEY_CHAT_RETENTION_DAYS: &str = "chat_retention_days";
+
+/// Default chat history message count (0 = unlimited, matches migration default)
+pub const DEFAULT_CHAT_RETENTION_COUNT: u32 = 1000;
+
+/// Default chat history age in days (0 = unlimited, matches migration default)
+pub const DEFAULT_CHAT_RETENTION_DAYS: u32 = 30;
+
+/// Seconds between scheduled chat history purges
+pub const CHAT_HISTORY_PURGE_INTERVAL_SECS: u64 = 3600;
+
+/// Seconds in a day (for age-based retention)
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+// =============================================================================
+// Maintenance Mode
+// =============================================================================
+
+/// Configuration key for maintenance (read-only) mode
+pub const CONFIG_KEY_MAINTENANCE_MODE: &str = "maintenance_mode";
+
+// =============================================================================
+// Disabled Permissions
+// =============================================================================
+
+/// Configuration key for permissions disabled server-wide (JSON array of names)
+pub const CONFIG_KEY_DISABLED_PERMISSIONS: &str = "disabled_permissions";
+
+/// Configuration key for whether admins are exempt from disabled permissions
+pub const CONFIG_KEY_DISABLED_PERMISSIONS_ADMIN_EXEMPT: &str = "disabled_permissions_admin_exempt";
+
+// =============================================================================
+// Permission Prerequisites
+// =============================================================================
+
+/// Configuration key for how missing permission prerequisites are handled
+pub const CONFIG_KEY_PERMISSION_PREREQUISITES: &str = "permission_prerequisites";
+
+// =============================================================================
+// Minimum Client Version
+// =============================================================================
+
+/// Configuration key for the oldest client protocol version allowed to connect
+pub const CONFIG_KEY_MIN_CLIENT_VERSION: &str = "min_client_version";
+
+// =============================================================================
+// Login Notices
+// =============================================================================
+
+/// Configuration key for telling a user's other sessions about a new login
+pub const CONFIG_KEY_LOGIN_NOTICES: &str = "login_notices";
+
+// =============================================================================
+// Guest Login
+// =============================================================================
+
+/// Configuration key for letting people log in as guests without an account
+pub const CONFIG_KEY_GUEST_LOGIN: &str = "guest_login";
+
+/// Configuration key for the password hash guests share (empty = no password)
+pub const CONFIG_KEY_GUEST_PASSWORD: &str = "guest_password";
+
+/// Prefix every guest username must start with
+///
+/// Guests can only use names with this prefix, so they can't pass themselves
+/// off as account holders.
+pub const GUEST_USERNAME_PREFIX: &str = "guest-";
+
+// =============================================================================
+// Announcements
+// =============================================================================
+
+/// Configuration key for the rotating announcements (JSON array of messages)
+pub const CONFIG_KEY_ANNOUNCEMENTS: &str = "announcements";
+
+/// Configuration key for the minutes between announcements
+pub const CONFIG_KEY_ANNOUNCEMENT_INTERVAL: &str = "announcement_interval";
+
+/// Default minutes between announcements (matches migration default)
+pub const DEFAULT_ANNOUNCEMENT_INTERVAL_MINUTES: u32 = 30;
+
+/// Longest accepted announcement interval in minutes (one week)
+pub const MAX_ANNOUNCEMENT_INTERVAL_MINUTES: u32 = 10_080;
+
+/// Seconds in a minute (for the announcement interval)
+pub const SECONDS_PER_MINUTE: u64 = 60;
+
+// =============================================================================
+// Database Maintenance
+// =============================================================================
+
+/// Default hours between database maintenance runs (one week, 0 = off)
+pub const DEFAULT_DB_MAINTENANCE_INTERVAL_HOURS: u32 = 168;
+
+/// Longest accepted database maintenance interval in hours (one year)
+pub const MAX_DB_MAINTENANCE_INTERVAL_HOURS: u32 = 8760;
+
+/// Default most users online for database maintenance to run
+///
+/// VACUUM rewrites the whole file and holds the write lock while it does,
+/// so a due run waits for a quiet moment instead of stalling a busy server.
+pub const DEFAULT_DB_MAINTENANCE_MAX_USERS: usize = 5;
+
+/// Seconds to wait before checking again when maintenance is due but the
+/// server is busy
+pub const DB_MAINTENANCE_RETRY_SECS: u64 = 900;
+
+/// Seconds a maintenance statement waits for other connections to release
+/// the database before giving up
+pub const DB_MAINTENANCE_BUSY_TIMEOUT_SECS: u64 = 30;
+
+/// Seconds in an hour (for the database maintenance interval)
+pub const SECONDS_PER_HOUR: u64 = 3600;
+
 // =============================================================================
 // Database Validation Errors (defense-in-depth, operator-facing)
 // =============================================================================
@@ -20,6 +218,18 @@ pub const DEFAULT_MAX_CONNECTIONS_PER_IP: usize = 5;
 /// Error when max_connections_per_ip is set to zero
 pub const ERR_MAX_CONNECTIONS_ZERO: &str = "max_connections_per_ip must be greater than 0";
 
+/// Error when max_message_length is zero or above the protocol ceiling
+pub const ERR_MAX_MESSAGE_LENGTH_OUT_OF_RANGE: &str =
+    "max_message_length must be between 1 and the protocol ceiling";
+
+/// Error when max_topic_length is zero or above the protocol ceiling
+pub const ERR_MAX_TOPIC_LENGTH_OUT_OF_RANGE: &str =
+    "max_topic_length must be between 1 and the protocol ceiling";
+
+/// Error when avatar dimension bounds are inverted or above the ceiling
+pub const ERR_AVATAR_DIMENSIONS_OUT_OF_RANGE: &str =
+    "avatar dimensions must satisfy 1 <= min <= max <= 4096";
+
 /// Error when server name is empty
 pub const ERR_SERVER_NAME_EMPTY: &str = "Server name cannot be empty";
 
@@ -50,6 +260,18 @@ pub const ERR_SERVER_IMAGE_INVALID_FORMAT: &str = "Server image has invalid form
 /// Error when server image has unsupported type
 pub const ERR_SERVER_IMAGE_UNSUPPORTED_TYPE: &str = "Server image has unsupported type";
 
+/// Error when server rules are too long
+pub const ERR_SERVER_RULES_TOO_LONG: &str = "Server rules are too long";
+
+/// Error when server rules contain invalid characters
+pub const ERR_SERVER_RULES_INVALID_CHARS: &str = "Server rules contain invalid characters";
+
+/// Error when the server banner is too long
+pub const ERR_SERVER_BANNER_TOO_LONG: &str = "Server banner is too long";
+
+/// Error when the server banner contains invalid characters
+pub const ERR_SERVER_BANNER_INVALID_CHARS: &str = "Server banner contains invalid characters";
+
 /// Default server name (matches migration default)
 pub const DEFAULT_SERVER_NAME: &str = "Nexus BBS";
 
@@ -59,6 +281,12 @@ pub const DEFAULT_SERVER_DESCRIPTION: &str = "";
 /// Default server image (matches migration default)
 pub const DEFAULT_SERVER_IMAGE: &str = "";
 
+/// Default server rules (matches migration default; empty disables the gate)
+pub const DEFAULT_SERVER_RULES: &str = "";
+
+/// Default server banner (matches migration default; empty disables it)
+pub const DEFAULT_SERVER_BANNER: &str = "";
+
 // =============================================================================
 // Database Configuration
 // =============================================================================
@@ -69,6 +297,9 @@ pub const DATA_DIR_NAME: &str = "nexusd";
 /// Database file name
 pub const DATABASE_FILENAME: &str = "nexus.db";
 
+/// Extension appended to the database path for the single-instance lock file
+pub const DATABASE_LOCK_EXTENSION: &str = "lock";
+
 /// Database configuration key for server name
 pub const CONFIG_KEY_SERVER_NAME: &str = "server_name";
 
@@ -78,6 +309,12 @@ pub const CONFIG_KEY_SERVER_DESCRIPTION: &str = "server_description";
 /// Database configuration key for server image
 pub const CONFIG_KEY_SERVER_IMAGE: &str = "server_image";
 
+/// Database configuration key for server rules
+pub const CONFIG_KEY_SERVER_RULES: &str = "server_rules";
+
+/// Database configuration key for the pre-login server banner
+pub const CONFIG_KEY_SERVER_BANNER: &str = "server_banner";
+
 // =============================================================================
 // Feature Names
 // =============================================================================
@@ -85,6 +322,9 @@ pub const CONFIG_KEY_SERVER_IMAGE: &str = "server_image";
 /// Feature name for chat functionality
 pub const FEATURE_CHAT: &str = "chat";
 
+/// Features this server supports (reported by the Capabilities request)
+pub const SUPPORTED_FEATURES: &[&str] = &[FEATURE_CHAT];
+
 // =============================================================================
 // Chat State Keys
 // =============================================================================
@@ -95,6 +335,9 @@ pub const CHAT_STATE_KEY_TOPIC: &str = "topic";
 /// Chat state key for username who set the topic
 pub const CHAT_STATE_KEY_TOPIC_SET_BY: &str = "topic_set_by";
 
+/// Chat state key for pinned messages (stored as a JSON array)
+pub const CHAT_STATE_KEY_PINNED_MESSAGES: &str = "pinned_messages";
+
 /// Maximum number of concurrent database connections in the pool
 ///
 /// This value (5) is chosen to balance:
@@ -107,6 +350,106 @@ pub const CHAT_STATE_KEY_TOPIC_SET_BY: &str = "topic_set_by";
 /// keeping resource usage reasonable.
 pub const MAX_DB_CONNECTIONS: u32 = 5;
 
+// =============================================================================
+// Recent Chat Messages
+// =============================================================================
+
+/// Number of recent chat messages kept in memory so they can be pinned by id
+pub const RECENT_CHAT_MESSAGES_CAPACITY: usize = 200;
+
+// =============================================================================
+// E2EE Key Watches
+// =============================================================================
+
+/// Most users a session can watch for key changes (its own account aside)
+pub const MAX_E2EE_WATCHES_PER_SESSION: usize = 256;
+
+// =============================================================================
+// Chat Edit Window
+// =============================================================================
+
+/// Configuration key for how long users can edit or delete their own chat messages
+pub const CONFIG_KEY_CHAT_EDIT_WINDOW: &str = "chat_edit_window_minutes";
+
+/// Default chat edit window in minutes (0 = no limit, matches migration default)
+pub const DEFAULT_CHAT_EDIT_WINDOW_MINUTES: u32 = 15;
+
+/// Longest accepted chat edit window in minutes (one day)
+pub const MAX_CHAT_EDIT_WINDOW_MINUTES: u32 = 24 * 60;
+
+// =============================================================================
+// Moderation Log
+// =============================================================================
+
+/// Moderation log action for a moderator deleting a chat message
+pub const MODERATION_ACTION_DELETE_MESSAGE: &str = "delete_message";
+
+/// Entries shown by `nexusd moderation-log` when no limit is given
+pub const DEFAULT_MODERATION_LOG_LIMIT: usize = 50;
+
+// =============================================================================
+// TCP Socket Options
+// =============================================================================
+
+/// Default listen backlog (pending connections queued by the kernel)
+///
+/// Sized for connection bursts such as many clients reconnecting after a
+/// restart. The kernel may cap this (e.g. `net.core.somaxconn` on Linux).
+pub const DEFAULT_LISTEN_BACKLOG: u32 = 1024;
+
+// =============================================================================
+// Outgoing Message Queue
+// =============================================================================
+
+/// Default number of server messages queued per connection before the client
+/// is considered too slow and disconnected
+///
+/// A healthy client drains its queue almost immediately; this leaves room for
+/// bursts (e.g. many users joining at once) while capping memory held for a
+/// stalled connection.
+pub const DEFAULT_OUTGOING_QUEUE_CAPACITY: usize = 1024;
+
+/// Largest accepted outgoing queue capacity
+pub const MAX_OUTGOING_QUEUE_CAPACITY: usize = 1 << 20;
+
+// =============================================================================
+// Login Deadline
+// =============================================================================
+
+/// Default seconds a new connection has to complete the TLS handshake, the
+/// protocol handshake, and login before it is dropped
+///
+/// Real clients finish in a few round trips, even over Tor. Scanners and
+/// stalled TLS clients that connect and go quiet are shed instead of holding
+/// a connection slot until the frame timeout.
+pub const DEFAULT_LOGIN_TIMEOUT_SECS: u64 = 15;
+
+/// Longest accepted login deadline in seconds
+pub const MAX_LOGIN_TIMEOUT_SECS: u64 = 600;
+
+// =============================================================================
+// Malformed Frame Blocking
+// =============================================================================
+
+/// Default malformed frames from one address, before login, that trigger a block
+///
+/// A real client sends a bad frame only through a bug or version mismatch, and
+/// is disconnected on the first one; it won't reconnect and fail this many
+/// times within the window. Scanners do so within seconds.
+pub const DEFAULT_MALFORMED_FRAME_LIMIT: u32 = 5;
+
+/// Default seconds over which malformed frames are counted
+pub const DEFAULT_MALFORMED_FRAME_WINDOW_SECS: u64 = 60;
+
+/// Default seconds a blocked address is refused
+///
+/// Kept short: the aim is to shed noise, not to ban. Addresses shared by many
+/// users (NAT, Tor exits) recover quickly if one of them misbehaves.
+pub const DEFAULT_MALFORMED_FRAME_BLOCK_SECS: u64 = 300;
+
+/// Longest accepted malformed frame window or block duration in seconds
+pub const MAX_MALFORMED_FRAME_SECS: u64 = 3600;
+
 // =============================================================================
 // TLS Configuration
 // =============================================================================
@@ -136,12 +479,148 @@ pub const MSG_BANNER: &str = "Nexus BBS Server v";
 /// Database path display
 pub const MSG_DATABASE: &str = "Database: ";
 
+/// Message length limit display
+pub const MSG_MESSAGE_LIMITS: &str = "Message limits: max message length ";
+
+/// Topic length limit display (continues MSG_MESSAGE_LIMITS)
+pub const MSG_TOPIC_LIMIT: &str = ", max topic length ";
+
+/// Avatar dimension bounds display
+pub const MSG_AVATAR_LIMITS: &str = "Avatar dimensions: ";
+
+/// Avatar dimension bounds separator (continues MSG_AVATAR_LIMITS)
+pub const MSG_AVATAR_LIMITS_TO: &str = " to ";
+
+/// Avatar dimension unit suffix (continues MSG_AVATAR_LIMITS_TO)
+pub const MSG_AVATAR_LIMITS_SUFFIX: &str = " pixels";
+
+/// Avatar downscaling suffix (continues MSG_AVATAR_LIMITS_SUFFIX)
+pub const MSG_AVATAR_LIMITS_DOWNSCALE: &str = " (larger avatars downscaled)";
+
+/// Command cooldown display
+pub const MSG_COMMAND_COOLDOWN: &str = "Command cooldown: ";
+
+/// Command cooldown unit suffix (continues MSG_COMMAND_COOLDOWN)
+pub const MSG_COMMAND_COOLDOWN_SUFFIX: &str =
+    " seconds for user info, seen and full user list requests";
+
+/// Command cooldown display when disabled
+pub const MSG_COMMAND_COOLDOWN_OFF: &str = "Command cooldown: off";
+
+/// Chat edit window display
+pub const MSG_CHAT_EDIT_WINDOW: &str = "Chat edit window: ";
+
+/// Chat edit window unit suffix (continues MSG_CHAT_EDIT_WINDOW)
+pub const MSG_CHAT_EDIT_WINDOW_SUFFIX: &str = " minutes for users to change their own messages";
+
+/// Chat edit window display when unlimited
+pub const MSG_CHAT_EDIT_WINDOW_UNLIMITED: &str = "Chat edit window: unlimited";
+
+/// Chat retention count display
+pub const MSG_CHAT_RETENTION: &str = "Chat retention: keep ";
+
+/// Chat retention age display (continues MSG_CHAT_RETENTION)
+pub const MSG_CHAT_RETENTION_DAYS: &str = " messages, ";
+
+/// Chat retention unit suffix (continues MSG_CHAT_RETENTION_DAYS)
+pub const MSG_CHAT_RETENTION_DAYS_SUFFIX: &str = " days (0 = unlimited)";
+
+/// Chat history recording display
+pub const MSG_CHAT_HISTORY: &str = "Chat history recording: ";
+
+/// Maintenance mode display
+pub const MSG_MAINTENANCE_MODE: &str = "Maintenance mode: ";
+
+/// Permission prerequisites mode display
+pub const MSG_PERMISSION_PREREQUISITES: &str = "Permission prerequisites: ";
+
+/// Sessions per user display
+pub const MSG_SESSION_LIMIT: &str = "Sessions per user: ";
+
+/// Suffix for the sessions per user display when admins are exempt
+pub const MSG_SESSION_LIMIT_ADMIN_EXEMPT: &str = ", admins exempt";
+
+/// Sessions per user display when accounts may open any number
+pub const MSG_SESSION_LIMIT_UNLIMITED: &str = "Sessions per user: unlimited";
+
+/// Minimum client version display
+pub const MSG_MIN_CLIENT_VERSION: &str = "Minimum client version: ";
+
+/// Minimum client version display when every compatible client is accepted
+pub const MSG_MIN_CLIENT_VERSION_ANY: &str = "Minimum client version: any";
+
+/// Disabled permissions display
+pub const MSG_DISABLED_PERMISSIONS: &str = "Disabled permissions: ";
+
+/// Disabled permissions admin exemption suffix (continues MSG_DISABLED_PERMISSIONS)
+pub const MSG_DISABLED_PERMISSIONS_ADMIN_EXEMPT: &str = " (admins exempt)";
+
+/// Server rules loaded display
+pub const MSG_SERVER_RULES: &str = "Server rules loaded from ";
+
+/// Server rules changed notice (acceptance reset)
+pub const MSG_SERVER_RULES_CHANGED: &str = "Server rules changed; users must accept them again";
+
+/// Server banner loaded display
+pub const MSG_SERVER_BANNER: &str = "Server banner loaded from ";
+
+/// Default locale display
+pub const MSG_DEFAULT_LOCALE: &str = "Default locale: ";
+
+/// New login notices display
+pub const MSG_LOGIN_NOTICES: &str = "New login notices: ";
+
+/// Guest login display
+pub const MSG_GUEST_LOGIN: &str = "Guest login: ";
+
+/// Guest login shared password note (continues MSG_GUEST_LOGIN)
+pub const MSG_GUEST_LOGIN_PASSWORD: &str = " (shared password)";
+
+/// Announcement rotation display
+pub const MSG_ANNOUNCEMENTS: &str = "Announcements: ";
+
+/// Announcement interval display (continues MSG_ANNOUNCEMENTS)
+pub const MSG_ANNOUNCEMENTS_INTERVAL: &str = " in rotation, every ";
+
+/// Announcement interval unit suffix (continues MSG_ANNOUNCEMENTS_INTERVAL)
+pub const MSG_ANNOUNCEMENTS_INTERVAL_SUFFIX: &str = " minutes";
+
+/// Database maintenance schedule display
+pub const MSG_DB_MAINTENANCE: &str = "Database maintenance: every ";
+
+/// Database maintenance interval unit (continues MSG_DB_MAINTENANCE)
+pub const MSG_DB_MAINTENANCE_HOURS: &str = " hours";
+
+/// Database maintenance vacuum note (continues MSG_DB_MAINTENANCE_HOURS)
+pub const MSG_DB_MAINTENANCE_VACUUM: &str = " with VACUUM";
+
+/// Database maintenance load guard (continues the schedule display)
+pub const MSG_DB_MAINTENANCE_MAX_USERS: &str = ", when at most ";
+
+/// Database maintenance load guard unit (continues MSG_DB_MAINTENANCE_MAX_USERS)
+pub const MSG_DB_MAINTENANCE_MAX_USERS_SUFFIX: &str = " users are online";
+
+/// Database maintenance display when disabled
+pub const MSG_DB_MAINTENANCE_OFF: &str = "Database maintenance: off";
+
+/// Database maintenance finished, with the file size before it ran
+pub const MSG_DB_MAINTENANCE_DONE: &str = "Database maintenance done: ";
+
+/// Database file size after maintenance (continues MSG_DB_MAINTENANCE_DONE)
+pub const MSG_DB_MAINTENANCE_SIZE_TO: &str = " bytes -> ";
+
+/// Database file size unit (continues MSG_DB_MAINTENANCE_SIZE_TO)
+pub const MSG_DB_MAINTENANCE_SIZE_SUFFIX: &str = " bytes";
+
 /// Certificates path display
 pub const MSG_CERTIFICATES: &str = "Certificates: ";
 
 /// Listening address display
 pub const MSG_LISTENING: &str = "Listening on ";
 
+/// Effective TLS policy display
+pub const MSG_TLS_POLICY: &str = "TLS policy: ";
+
 /// TLS enabled indicator
 pub const MSG_TLS_ENABLED: &str = " (TLS enabled)";
 
@@ -160,6 +639,15 @@ pub const MSG_KEY_GENERATED: &str = "Private key generated: ";
 /// Shutdown signal received message
 pub const MSG_SHUTDOWN_RECEIVED: &str = "\nShutdown signal received";
 
+/// Malformed frame metrics display at shutdown
+pub const MSG_FRAME_METRICS: &str = "Malformed frames: ";
+
+/// Blocks display (continues MSG_FRAME_METRICS)
+pub const MSG_FRAME_METRICS_BLOCKS: &str = ", IPs blocked: ";
+
+/// Refused connections display (continues MSG_FRAME_METRICS_BLOCKS)
+pub const MSG_FRAME_METRICS_REFUSED: &str = ", connections refused: ";
+
 // =============================================================================
 // Server Error Messages (operator-facing)
 // =============================================================================
@@ -176,9 +664,160 @@ pub const ERR_DB_PATH_NO_PARENT: &str = "Database path should have a parent dire
 /// Database directory creation error
 pub const ERR_CREATE_DB_DIR: &str = "Failed to create directory: ";
 
+/// Database held by another process (usually a second nexusd)
+pub const ERR_DB_LOCKED: &str = "Database is in use by another process (is another nexusd running?). Stop it or pass a different --database path: ";
+
+/// Database file or directory not accessible
+pub const ERR_DB_PERMISSION_DENIED: &str = "Permission denied. Make sure the user running nexusd can read and write the database file and its directory: ";
+
+/// Database could not be opened
+pub const ERR_DB_OPEN: &str = "Failed to open database: ";
+
+/// Schema migration failed
+pub const ERR_DB_MIGRATION: &str = "Database migration failed (the failing migration was rolled back). Restore a backup or report this error: ";
+
+/// Database was migrated by a newer server version
+pub const ERR_DB_NEWER_VERSION: &str = "Database was upgraded by a newer nexusd. Upgrade this server or pass a different --database path. Unknown migration: ";
+
 /// Data directory error
 pub const ERR_NO_DATA_DIR: &str = "Unable to determine data directory for your platform";
 
+/// Message limit configuration error
+pub const ERR_MESSAGE_LIMITS: &str = "Failed to set message limits: ";
+
+/// Avatar limit configuration error
+pub const ERR_AVATAR_LIMITS: &str = "Failed to set avatar limits: ";
+
+/// Chat retention configuration error
+pub const ERR_CHAT_RETENTION: &str = "Failed to set chat retention: ";
+
+/// Chat history recording configuration error
+pub const ERR_CHAT_HISTORY: &str = "Failed to set chat history recording: ";
+
+/// Maintenance mode configuration error
+pub const ERR_MAINTENANCE_MODE: &str = "Failed to set maintenance mode: ";
+
+/// Default locale configuration error
+pub const ERR_DEFAULT_LOCALE: &str = "Failed to set default locale: ";
+
+/// New login notices configuration error
+pub const ERR_LOGIN_NOTICES: &str = "Failed to set new login notices: ";
+
+/// Guest login configuration error
+pub const ERR_GUEST_LOGIN: &str = "Failed to set guest login: ";
+
+/// Guest password on the command line longer than a login password may be
+pub const ERR_GUEST_PASSWORD_TOO_LONG: &str = "Guest password can't be longer than ";
+
+/// Permission prerequisites configuration error
+pub const ERR_PERMISSION_PREREQUISITES: &str = "Failed to set permission prerequisites: ";
+
+/// Session limit configuration error
+pub const ERR_SESSION_LIMIT: &str = "Failed to set session limit: ";
+
+/// Unknown session limit policy on the command line
+pub const ERR_SESSION_LIMIT_POLICY: &str =
+    "Expected reject or replace-oldest for the session limit policy, got ";
+
+/// Minimum client version configuration error
+pub const ERR_MIN_CLIENT_VERSION: &str = "Failed to set minimum client version: ";
+
+/// Minimum client version on the command line that isn't a version
+pub const ERR_MIN_CLIENT_VERSION_INVALID: &str =
+    "Expected a version like 0.5.0 (or empty to accept any), got ";
+
+/// Minimum client version on the command line newer than this server
+pub const ERR_MIN_CLIENT_VERSION_TOO_NEW: &str =
+    "Minimum client version can't be newer than the server's protocol version ";
+
+/// Unknown permission prerequisites mode on the command line
+pub const ERR_PERMISSION_PREREQUISITES_MODE: &str =
+    "Expected off, warn, or enforce for permission prerequisites, got ";
+
+/// Outgoing queue capacity outside the accepted range
+pub const ERR_OUTGOING_QUEUE_RANGE: &str = "must be between 1 and 1048576";
+
+/// Login deadline outside the accepted range
+pub const ERR_LOGIN_TIMEOUT_RANGE: &str = "must be between 1 and 600";
+
+/// Malformed frame window or block duration outside the accepted range
+pub const ERR_MALFORMED_FRAME_SECS_RANGE: &str = "must be between 1 and 3600";
+
+/// Unknown permission in the disabled permissions list
+pub const ERR_DISABLED_PERMISSION_UNKNOWN: &str = "Unknown permission: ";
+
+/// Permission in the disabled permissions list that gates no command
+pub const ERR_DISABLED_PERMISSION_NOT_COMMAND: &str = "Permission does not gate a command: ";
+
+/// Announcement interval outside the accepted range
+pub const ERR_ANNOUNCEMENT_INTERVAL_RANGE: &str = "must be between 1 and 10080";
+
+/// Command cooldown outside the accepted range
+pub const ERR_COMMAND_COOLDOWN_RANGE: &str = "must be between 0 and 300";
+
+/// Chat edit window outside the accepted range
+pub const ERR_CHAT_EDIT_WINDOW_RANGE: &str = "must be between 0 and 1440";
+
+/// Database maintenance interval outside the accepted range
+pub const ERR_DB_MAINTENANCE_INTERVAL_RANGE: &str = "must be between 0 and 8760";
+
+/// Database maintenance run error
+pub const ERR_DB_MAINTENANCE: &str = "Database maintenance failed: ";
+
+/// Connection dropped because it did not log in before the deadline (debug only)
+pub const ERR_LOGIN_TIMEOUT: &str = "Dropping connection that did not log in in time: ";
+
+/// Client disconnected because its outgoing queue filled up
+pub const ERR_SLOW_CLIENT: &str = "Disconnecting slow client (outgoing queue full): ";
+
+/// Chat history purge error
+pub const ERR_CHAT_HISTORY_PURGE: &str = "Failed to purge chat history: ";
+
+/// Chat history recording error
+pub const ERR_CHAT_HISTORY_RECORD: &str = "Failed to record chat history: ";
+
+/// Server rules file read error
+pub const ERR_READ_RULES_FILE: &str = "Failed to read rules file: ";
+
+/// Server rules configuration error
+pub const ERR_SERVER_RULES: &str = "Failed to set server rules: ";
+
+/// Server banner file read error
+pub const ERR_READ_BANNER_FILE: &str = "Failed to read banner file: ";
+
+/// Server banner configuration error
+pub const ERR_SERVER_BANNER: &str = "Failed to set server banner: ";
+
+/// Disabled permissions configuration error
+pub const ERR_DISABLED_PERMISSIONS: &str = "Failed to set disabled permissions: ";
+
+/// Announcement interval configuration error
+pub const ERR_ANNOUNCEMENT_INTERVAL: &str = "Failed to set announcement interval: ";
+
+/// Command cooldown configuration error
+pub const ERR_COMMAND_COOLDOWN: &str = "Failed to set command cooldown: ";
+
+/// Chat edit window configuration error
+pub const ERR_CHAT_EDIT_WINDOW: &str = "Failed to set chat edit window: ";
+
+/// Announcement list database error
+pub const ERR_ANNOUNCEMENTS: &str = "Database error updating announcements: ";
+
+/// Info page database error
+pub const ERR_PAGES: &str = "Database error accessing pages: ";
+
+/// Source tag rule is not of the form CIDR=TAG
+pub const ERR_SOURCE_RULE_FORMAT: &str = "Expected CIDR=TAG, got: ";
+
+/// Source tag rule has an invalid network address
+pub const ERR_SOURCE_RULE_NETWORK: &str = "Invalid network address: ";
+
+/// Source tag rule has an invalid prefix length
+pub const ERR_SOURCE_RULE_PREFIX: &str = "Invalid prefix length: ";
+
+/// Source tag is empty, too long, or has invalid characters
+pub const ERR_SOURCE_TAG_INVALID: &str = "Invalid source tag: ";
+
 /// TLS initialization error
 pub const ERR_TLS_INIT: &str = "Failed to initialize TLS: ";
 
@@ -191,12 +830,31 @@ pub const ERR_CONNECTION: &str = "Error handling connection from ";
 /// Connection accept error
 pub const ERR_ACCEPT: &str = "Failed to accept connection: ";
 
+/// TCP_NODELAY setup error (debug only)
+pub const ERR_SET_NODELAY: &str = "Failed to set TCP_NODELAY for ";
+
 /// Message handling error
 pub const ERR_HANDLING_MESSAGE: &str = "Error handling message: ";
 
 /// Message parsing error
 pub const ERR_PARSE_MESSAGE: &str = "Failed to parse message from ";
 
+/// Oversized request skipped (debug-only logging; the connection stays open)
+pub const ERR_PAYLOAD_SKIPPED: &str = "Skipped oversized request from ";
+
+/// Connection refused because its address is blocked for malformed frames (debug only)
+pub const ERR_MALFORMED_FRAME_BLOCKED: &str = "Refusing connection from blocked IP: ";
+
+/// Address blocked after repeated malformed frames
+pub const WARN_MALFORMED_FRAME_BLOCK: &str =
+    "Warning: Blocking IP after repeated malformed frames: ";
+
+/// Block duration display (continues WARN_MALFORMED_FRAME_BLOCK)
+pub const WARN_MALFORMED_FRAME_BLOCK_FOR: &str = " for ";
+
+/// Block duration unit suffix (continues WARN_MALFORMED_FRAME_BLOCK_FOR)
+pub const WARN_MALFORMED_FRAME_BLOCK_SUFFIX: &str = " seconds";
+
 /// Connection limit exceeded error (debug only)
 pub const ERR_CONNECTION_LIMIT: &str = "Connection limit exceeded for IP: ";
 
@@ -280,6 +938,83 @@ pub const ERR_NO_KEY_FOUND: &str = "No private key found in key file";
 /// TLS configuration creation error
 pub const ERR_CREATE_TLS_CONFIG: &str = "Failed to create TLS configuration: ";
 
+/// TLS policy error (e.g. no listed cipher suite works at the minimum version)
+pub const ERR_TLS_POLICY: &str = "Invalid TLS policy: ";
+
+/// TLS minimum version is below 1.2
+pub const ERR_TLS_VERSION_TOO_OLD: &str = "TLS versions below 1.2 are not supported: ";
+
+/// TLS minimum version is not 1.2 or 1.3
+pub const ERR_TLS_VERSION_UNKNOWN: &str = "Expected TLS version 1.2 or 1.3, got: ";
+
+/// Cipher suite is not one of rustls' default (strong) suites
+pub const ERR_TLS_CIPHER_UNKNOWN: &str = "Unknown or disallowed cipher suite: ";
+
+/// Cipher suite list names no suites
+pub const ERR_TLS_CIPHERS_EMPTY: &str = "Cipher suite list is empty";
+
+/// No listed cipher suite can be negotiated at the minimum version
+pub const ERR_TLS_CIPHERS_UNUSABLE: &str = "No listed cipher suite supports TLS ";
+
+// =============================================================================
+// User Import Messages (operator-facing)
+// =============================================================================
+
+/// Import file could not be read
+pub const ERR_IMPORT_READ: &str = "Failed to read import file: ";
+
+/// Import file is not valid CSV or JSON
+pub const ERR_IMPORT_PARSE: &str = "Failed to parse import file: ";
+
+/// Prefix for a record that was skipped or rejected (followed by its number)
+pub const ERR_IMPORT_RECORD: &str = "Record ";
+
+/// Record username fails validation
+pub const ERR_IMPORT_USERNAME_INVALID: &str = "invalid username: ";
+
+/// Record username is already taken in the database
+pub const ERR_IMPORT_USERNAME_EXISTS: &str = "username already exists, skipping: ";
+
+/// Record username repeats an earlier record
+pub const ERR_IMPORT_USERNAME_REPEATED: &str = "username appears earlier in the file, skipping: ";
+
+/// Record password fails validation (the password itself is never printed)
+pub const ERR_IMPORT_PASSWORD_INVALID: &str = "invalid password";
+
+/// Record marked as hashed doesn't hold an Argon2 hash
+pub const ERR_IMPORT_HASH_INVALID: &str = "password is marked as hashed but is not an Argon2 hash";
+
+/// Record names an unknown permission
+pub const ERR_IMPORT_PERMISSION_UNKNOWN: &str = "unknown permission: ";
+
+/// Password hashing or account creation failed
+pub const ERR_IMPORT_CREATE: &str = "failed to create account: ";
+
+/// Dry run notice
+pub const MSG_IMPORT_DRY_RUN: &str = "Dry run: no accounts will be created";
+
+/// Created accounts display
+pub const MSG_IMPORT_CREATED: &str = "Accounts created: ";
+
+/// Accounts a dry run would create display
+pub const MSG_IMPORT_WOULD_CREATE: &str = "Accounts that would be created: ";
+
+/// Skipped (existing or repeated) accounts display
+pub const MSG_IMPORT_SKIPPED: &str = "Accounts skipped: ";
+
+/// Rejected records display
+pub const MSG_IMPORT_INVALID: &str = "Invalid records: ";
+
+// =============================================================================
+// Moderation Log Messages (operator-facing)
+// =============================================================================
+
+/// Moderation log could not be read
+pub const ERR_MODERATION_LOG_READ: &str = "Failed to read moderation log: ";
+
+/// Empty moderation log display
+pub const MSG_MODERATION_LOG_EMPTY: &str = "No moderation actions recorded";
+
 // =============================================================================
 // UPnP Messages (operator-facing)
 // =============================================================================
@@ -411,6 +1146,31 @@ pub const LOCALE_ITALIAN: &str = "it";
 /// Supported locale: Dutch
 pub const LOCALE_DUTCH: &str = "nl";
 
+/// Locales with a translation bundle (generic "pt" and "zh" map to a regional variant)
+pub const SUPPORTED_LOCALES: &[&str] = &[
+    DEFAULT_LOCALE,
+    LOCALE_SPANISH,
+    LOCALE_JAPANESE,
+    LOCALE_FRENCH,
+    LOCALE_GERMAN,
+    LOCALE_PORTUGUESE,
+    LOCALE_PORTUGUESE_PT,
+    LOCALE_PORTUGUESE_BR,
+    LOCALE_RUSSIAN,
+    LOCALE_CHINESE,
+    LOCALE_CHINESE_CN,
+    LOCALE_CHINESE_TW,
+    LOCALE_KOREAN,
+    LOCALE_ITALIAN,
+    LOCALE_DUTCH,
+];
+
+/// Configuration key for the locale used when a client doesn't name a supported one
+pub const CONFIG_KEY_DEFAULT_LOCALE: &str = "default_locale";
+
+/// Default locale is not one of SUPPORTED_LOCALES (followed by the supported list)
+pub const ERR_DEFAULT_LOCALE_UNSUPPORTED: &str = "Unsupported locale: ";
+
 /// Error when translation key is missing (format: key, locale)
 pub const ERR_I18N_MISSING_KEY: &str = "Missing translation key";
 
@@ -1,7 +1,15 @@
 //! Chat state database operations
 
-use super::sql::{SQL_GET_CHAT_STATE, SQL_SET_CHAT_STATE};
-use crate::constants::{CHAT_STATE_KEY_TOPIC, CHAT_STATE_KEY_TOPIC_SET_BY};
+use super::sql::{
+    SQL_DELETE_CHAT_HISTORY_BEFORE, SQL_DELETE_CHAT_HISTORY_BEYOND_COUNT,
+    SQL_DELETE_CHAT_HISTORY_MESSAGE, SQL_GET_CHAT_STATE, SQL_INSERT_CHAT_HISTORY,
+    SQL_SELECT_CHAT_HISTORY_BEFORE, SQL_SET_CHAT_STATE, SQL_UPDATE_CHAT_HISTORY_MESSAGE,
+};
+use crate::constants::{
+    CHAT_STATE_KEY_PINNED_MESSAGES, CHAT_STATE_KEY_TOPIC, CHAT_STATE_KEY_TOPIC_SET_BY,
+    SECONDS_PER_DAY,
+};
+use nexus_common::protocol::{HistoryMessage, PinnedMessage};
 use nexus_common::validators;
 use sqlx::SqlitePool;
 use std::io;
@@ -48,7 +56,10 @@ impl ChatDb {
     pub async fn set_topic(&self, topic: &str, set_by: &str) -> io::Result<()> {
         // Validate topic format (failsafe - handlers should also validate)
         // If this fails, it indicates a bug or attack bypassing handler validation
-        if let Err(e) = validators::validate_chat_topic(topic) {
+        // Uses the ceiling since the configured limit is enforced by the handler
+        if let Err(e) =
+            validators::validate_chat_topic(topic, validators::MAX_CHAT_TOPIC_LENGTH_CEILING)
+        {
             return Err(io::Error::other(format!("{e:?}")));
         }
 
@@ -73,6 +84,159 @@ impl ChatDb {
 
         Ok(())
     }
+
+    /// Get the currently pinned messages (oldest pin first)
+    pub async fn get_pinned_messages(&self) -> io::Result<Vec<PinnedMessage>> {
+        let json = sqlx::query_scalar::<_, String>(SQL_GET_CHAT_STATE)
+            .bind(CHAT_STATE_KEY_PINNED_MESSAGES)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        serde_json::from_str(&json).map_err(io::Error::other)
+    }
+
+    /// Replace the pinned messages
+    pub async fn set_pinned_messages(&self, pinned: &[PinnedMessage]) -> io::Result<()> {
+        let json = serde_json::to_string(pinned).map_err(io::Error::other)?;
+
+        sqlx::query(SQL_SET_CHAT_STATE)
+            .bind(CHAT_STATE_KEY_PINNED_MESSAGES)
+            .bind(json)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Record a chat message in the persistent history
+    pub async fn record_message(
+        &self,
+        message_id: u64,
+        username: &str,
+        message: &str,
+    ) -> io::Result<()> {
+        sqlx::query(SQL_INSERT_CHAT_HISTORY)
+            .bind(message_id as i64)
+            .bind(username)
+            .bind(message)
+            .bind(chrono::Utc::now().timestamp())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Replace the text of a recorded chat message
+    ///
+    /// Does nothing if the message was never recorded or has been purged.
+    pub async fn edit_message(&self, message_id: u64, message: &str) -> io::Result<()> {
+        sqlx::query(SQL_UPDATE_CHAT_HISTORY_MESSAGE)
+            .bind(message)
+            .bind(message_id as i64)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Remove a recorded chat message from the history
+    ///
+    /// Does nothing if the message was never recorded or has been purged.
+    pub async fn delete_message(&self, message_id: u64) -> io::Result<()> {
+        sqlx::query(SQL_DELETE_CHAT_HISTORY_MESSAGE)
+            .bind(message_id as i64)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get a page of chat history older than `before_message_id` (or the
+    /// newest messages if `None`)
+    ///
+    /// Returns up to `limit` messages, oldest first, and whether older
+    /// messages remain beyond the page.
+    pub async fn history_page(
+        &self,
+        before_message_id: Option<u64>,
+        limit: usize,
+    ) -> io::Result<(Vec<HistoryMessage>, bool)> {
+        let before = before_message_id.map_or(i64::MAX, |id| id.min(i64::MAX as u64) as i64);
+
+        // Fetch one extra row to learn whether another page exists
+        let mut rows =
+            sqlx::query_as::<_, (i64, String, String, i64)>(SQL_SELECT_CHAT_HISTORY_BEFORE)
+                .bind(before)
+                .bind(limit as i64 + 1)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| io::Error::other(e.to_string()))?;
+
+        let has_more = rows.len() > limit;
+        rows.truncate(limit);
+
+        let messages = rows
+            .into_iter()
+            .rev()
+            .map(
+                |(message_id, username, message, created_at)| HistoryMessage {
+                    message_id: message_id as u64,
+                    username,
+                    message,
+                    timestamp: created_at,
+                },
+            )
+            .collect();
+
+        Ok((messages, has_more))
+    }
+
+    /// Delete chat history outside the retention policy
+    ///
+    /// Removes messages older than `max_age_days`, then everything beyond the
+    /// newest `max_count` messages. A limit of 0 disables that rule. Both
+    /// deletes run in a single transaction.
+    ///
+    /// Returns the number of messages removed.
+    pub async fn purge_history(&self, max_count: u32, max_age_days: u32) -> io::Result<u64> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        let mut removed = 0;
+
+        if max_age_days > 0 {
+            let cutoff = chrono::Utc::now().timestamp() - i64::from(max_age_days) * SECONDS_PER_DAY;
+            removed += sqlx::query(SQL_DELETE_CHAT_HISTORY_BEFORE)
+                .bind(cutoff)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| io::Error::other(e.to_string()))?
+                .rows_affected();
+        }
+
+        if max_count > 0 {
+            removed += sqlx::query(SQL_DELETE_CHAT_HISTORY_BEYOND_COUNT)
+                .bind(i64::from(max_count))
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| io::Error::other(e.to_string()))?
+                .rows_affected();
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(removed)
+    }
 }
 
 #[cfg(test)]
@@ -128,4 +292,208 @@ mod tests {
         assert_eq!(retrieved.topic, "");
         assert_eq!(retrieved.set_by, "bob");
     }
+
+    #[tokio::test]
+    async fn test_get_pinned_messages_default() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool);
+
+        let pinned = chat_db.get_pinned_messages().await.unwrap();
+        assert!(pinned.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_set_and_get_pinned_messages() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool);
+
+        let pinned = vec![PinnedMessage {
+            message_id: 42,
+            username: "alice".to_string(),
+            message: "Server maintenance tonight".to_string(),
+            pinned_by: "admin".to_string(),
+        }];
+        chat_db.set_pinned_messages(&pinned).await.unwrap();
+        assert_eq!(chat_db.get_pinned_messages().await.unwrap(), pinned);
+
+        // Clearing removes every pin
+        chat_db.set_pinned_messages(&[]).await.unwrap();
+        assert!(chat_db.get_pinned_messages().await.unwrap().is_empty());
+    }
+
+    // =========================================================================
+    // Chat History Retention Tests
+    // =========================================================================
+
+    /// Insert a history row with an explicit timestamp
+    async fn insert_history_at(pool: &SqlitePool, message_id: u64, created_at: i64) {
+        sqlx::query(SQL_INSERT_CHAT_HISTORY)
+            .bind(message_id as i64)
+            .bind("alice")
+            .bind(format!("message {message_id}"))
+            .bind(created_at)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    /// Message ids remaining in the history, oldest first
+    async fn history_ids(pool: &SqlitePool) -> Vec<i64> {
+        sqlx::query_scalar("SELECT message_id FROM chat_history ORDER BY id")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_record_message() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool.clone());
+
+        chat_db.record_message(1, "alice", "Hello").await.unwrap();
+        chat_db.record_message(2, "bob", "Hi").await.unwrap();
+
+        assert_eq!(history_ids(&pool).await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_edit_and_delete_message() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool.clone());
+
+        chat_db.record_message(1, "alice", "helo").await.unwrap();
+        chat_db.record_message(2, "bob", "Hi").await.unwrap();
+
+        chat_db.edit_message(1, "hello").await.unwrap();
+        let message: String =
+            sqlx::query_scalar("SELECT message FROM chat_history WHERE message_id = 1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(message, "hello");
+
+        chat_db.delete_message(2).await.unwrap();
+        assert_eq!(history_ids(&pool).await, vec![1]);
+    }
+
+    /// Message ids in a history page, oldest first
+    fn page_ids(page: &[HistoryMessage]) -> Vec<u64> {
+        page.iter().map(|m| m.message_id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_history_page_newest_first_page() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool.clone());
+        for id in 1..=5 {
+            insert_history_at(&pool, id, 1_000 + id as i64).await;
+        }
+
+        let (page, has_more) = chat_db.history_page(None, 2).await.unwrap();
+        assert_eq!(page_ids(&page), vec![4, 5]);
+        assert!(has_more);
+        assert_eq!(page[1].username, "alice");
+        assert_eq!(page[1].message, "message 5");
+        assert_eq!(page[1].timestamp, 1_005);
+    }
+
+    #[tokio::test]
+    async fn test_history_page_walks_back_to_start() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool.clone());
+        for id in 1..=5 {
+            insert_history_at(&pool, id, 1_000).await;
+        }
+
+        let (page, has_more) = chat_db.history_page(Some(4), 2).await.unwrap();
+        assert_eq!(page_ids(&page), vec![2, 3]);
+        assert!(has_more);
+
+        // The last page holds exactly the remaining message
+        let (page, has_more) = chat_db.history_page(Some(2), 2).await.unwrap();
+        assert_eq!(page_ids(&page), vec![1]);
+        assert!(!has_more);
+
+        let (page, has_more) = chat_db.history_page(Some(1), 2).await.unwrap();
+        assert!(page.is_empty());
+        assert!(!has_more);
+    }
+
+    #[tokio::test]
+    async fn test_history_page_exact_fit_has_no_more() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool.clone());
+        for id in 1..=3 {
+            insert_history_at(&pool, id, 1_000).await;
+        }
+
+        let (page, has_more) = chat_db.history_page(None, 3).await.unwrap();
+        assert_eq!(page_ids(&page), vec![1, 2, 3]);
+        assert!(!has_more);
+
+        // Ids beyond the signed range still mean "before everything"
+        let (page, _) = chat_db.history_page(Some(u64::MAX), 3).await.unwrap();
+        assert_eq!(page_ids(&page), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_purge_history_by_age() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool.clone());
+        let now = chrono::Utc::now().timestamp();
+
+        insert_history_at(&pool, 1, now - 40 * SECONDS_PER_DAY).await;
+        insert_history_at(&pool, 2, now - 31 * SECONDS_PER_DAY).await;
+        insert_history_at(&pool, 3, now - 29 * SECONDS_PER_DAY).await;
+        insert_history_at(&pool, 4, now).await;
+
+        let removed = chat_db.purge_history(0, 30).await.unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(history_ids(&pool).await, vec![3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_purge_history_by_count() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool.clone());
+        let now = chrono::Utc::now().timestamp();
+
+        for id in 1..=5 {
+            insert_history_at(&pool, id, now).await;
+        }
+
+        let removed = chat_db.purge_history(3, 0).await.unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(history_ids(&pool).await, vec![3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_purge_history_by_age_and_count() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool.clone());
+        let now = chrono::Utc::now().timestamp();
+
+        insert_history_at(&pool, 1, now - 60 * SECONDS_PER_DAY).await;
+        for id in 2..=5 {
+            insert_history_at(&pool, id, now).await;
+        }
+
+        // One row is too old, then one more falls outside the newest three
+        let removed = chat_db.purge_history(3, 30).await.unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(history_ids(&pool).await, vec![3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_purge_history_unlimited() {
+        let pool = create_test_db().await;
+        let chat_db = ChatDb::new(pool.clone());
+
+        insert_history_at(&pool, 1, 0).await;
+        insert_history_at(&pool, 2, chrono::Utc::now().timestamp()).await;
+
+        let removed = chat_db.purge_history(0, 0).await.unwrap();
+        assert_eq!(removed, 0);
+        assert_eq!(history_ids(&pool).await, vec![1, 2]);
+    }
 }
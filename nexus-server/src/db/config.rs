@@ -1,22 +1,236 @@
 //! Server configuration database operations
 
 use nexus_common::validators::{
-    ServerDescriptionError, ServerImageError, ServerNameError, validate_server_description,
-    validate_server_image, validate_server_name,
+    MAX_CHAT_TOPIC_LENGTH, MAX_CHAT_TOPIC_LENGTH_CEILING, MAX_MESSAGE_LENGTH,
+    MAX_MESSAGE_LENGTH_CEILING, ServerBannerError, ServerDescriptionError, ServerImageError,
+    ServerNameError, ServerRulesError, validate_server_banner, validate_server_description,
+    validate_server_image, validate_server_name, validate_server_rules,
 };
+use nexus_common::version::Version;
 
+use super::permissions::Permission;
 use super::sql::{SQL_GET_CONFIG, SQL_SET_CONFIG};
 use crate::constants::{
-    CONFIG_KEY_MAX_CONNECTIONS_PER_IP, CONFIG_KEY_SERVER_DESCRIPTION, CONFIG_KEY_SERVER_IMAGE,
-    CONFIG_KEY_SERVER_NAME, DEFAULT_MAX_CONNECTIONS_PER_IP, DEFAULT_SERVER_DESCRIPTION,
-    DEFAULT_SERVER_IMAGE, DEFAULT_SERVER_NAME, ERR_MAX_CONNECTIONS_ZERO,
-    ERR_SERVER_DESC_INVALID_CHARS, ERR_SERVER_DESC_NEWLINES, ERR_SERVER_DESC_TOO_LONG,
-    ERR_SERVER_IMAGE_INVALID_FORMAT, ERR_SERVER_IMAGE_TOO_LARGE, ERR_SERVER_IMAGE_UNSUPPORTED_TYPE,
-    ERR_SERVER_NAME_EMPTY, ERR_SERVER_NAME_INVALID_CHARS, ERR_SERVER_NAME_NEWLINES,
-    ERR_SERVER_NAME_TOO_LONG,
+    AVATAR_DIMENSION_CEILING, CONFIG_KEY_ANNOUNCEMENT_INTERVAL, CONFIG_KEY_ANNOUNCEMENTS,
+    CONFIG_KEY_AVATAR_DOWNSCALE, CONFIG_KEY_AVATAR_MAX_DIMENSION, CONFIG_KEY_AVATAR_MIN_DIMENSION,
+    CONFIG_KEY_CHAT_EDIT_WINDOW, CONFIG_KEY_CHAT_HISTORY, CONFIG_KEY_CHAT_RETENTION_COUNT,
+    CONFIG_KEY_CHAT_RETENTION_DAYS, CONFIG_KEY_CHAT_SLOW_MODE, CONFIG_KEY_COMMAND_COOLDOWN,
+    CONFIG_KEY_DEFAULT_LOCALE, CONFIG_KEY_DISABLED_PERMISSIONS,
+    CONFIG_KEY_DISABLED_PERMISSIONS_ADMIN_EXEMPT, CONFIG_KEY_GUEST_LOGIN,
+    CONFIG_KEY_GUEST_PASSWORD, CONFIG_KEY_LOGIN_NOTICES, CONFIG_KEY_MAINTENANCE_MODE,
+    CONFIG_KEY_MAX_CONNECTIONS_PER_IP, CONFIG_KEY_MAX_MESSAGE_LENGTH,
+    CONFIG_KEY_MAX_SESSIONS_PER_USER, CONFIG_KEY_MAX_TOPIC_LENGTH, CONFIG_KEY_MIN_CLIENT_VERSION,
+    CONFIG_KEY_PERMISSION_PREREQUISITES, CONFIG_KEY_SERVER_BANNER, CONFIG_KEY_SERVER_DESCRIPTION,
+    CONFIG_KEY_SERVER_IMAGE, CONFIG_KEY_SERVER_NAME, CONFIG_KEY_SERVER_RULES,
+    CONFIG_KEY_SESSION_LIMIT_ADMIN_EXEMPT, CONFIG_KEY_SESSION_LIMIT_POLICY,
+    DEFAULT_ANNOUNCEMENT_INTERVAL_MINUTES, DEFAULT_AVATAR_MAX_DIMENSION,
+    DEFAULT_AVATAR_MIN_DIMENSION, DEFAULT_CHAT_EDIT_WINDOW_MINUTES, DEFAULT_CHAT_RETENTION_COUNT,
+    DEFAULT_CHAT_RETENTION_DAYS, DEFAULT_CHAT_SLOW_MODE_SECONDS, DEFAULT_COMMAND_COOLDOWN_SECONDS,
+    DEFAULT_LOCALE, DEFAULT_MAX_CONNECTIONS_PER_IP, DEFAULT_MAX_SESSIONS_PER_USER,
+    DEFAULT_SERVER_BANNER, DEFAULT_SERVER_DESCRIPTION, DEFAULT_SERVER_IMAGE, DEFAULT_SERVER_NAME,
+    DEFAULT_SERVER_RULES, ERR_AVATAR_DIMENSIONS_OUT_OF_RANGE, ERR_DISABLED_PERMISSION_NOT_COMMAND,
+    ERR_DISABLED_PERMISSION_UNKNOWN, ERR_MAX_CONNECTIONS_ZERO, ERR_MAX_MESSAGE_LENGTH_OUT_OF_RANGE,
+    ERR_MAX_TOPIC_LENGTH_OUT_OF_RANGE, ERR_PERMISSION_PREREQUISITES_MODE,
+    ERR_SERVER_BANNER_INVALID_CHARS, ERR_SERVER_BANNER_TOO_LONG, ERR_SERVER_DESC_INVALID_CHARS,
+    ERR_SERVER_DESC_NEWLINES, ERR_SERVER_DESC_TOO_LONG, ERR_SERVER_IMAGE_INVALID_FORMAT,
+    ERR_SERVER_IMAGE_TOO_LARGE, ERR_SERVER_IMAGE_UNSUPPORTED_TYPE, ERR_SERVER_NAME_EMPTY,
+    ERR_SERVER_NAME_INVALID_CHARS, ERR_SERVER_NAME_NEWLINES, ERR_SERVER_NAME_TOO_LONG,
+    ERR_SERVER_RULES_INVALID_CHARS, ERR_SERVER_RULES_TOO_LONG, ERR_SESSION_LIMIT_POLICY,
 };
+use crate::i18n;
 use sqlx::SqlitePool;
+use std::fmt;
 use std::io;
+use std::str::FromStr;
+
+/// How missing permission prerequisites are handled when accounts are saved
+///
+/// See `PERMISSION_PREREQUISITES` for which permissions need which.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PermissionPrerequisites {
+    /// Save permissions as given
+    Off,
+    /// Save permissions as given and tell the requester what is missing
+    #[default]
+    Warn,
+    /// Add missing prerequisites the requester may grant, and tell them
+    Enforce,
+}
+
+impl PermissionPrerequisites {
+    /// Value stored in the config table and accepted on the command line
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Warn => "warn",
+            Self::Enforce => "enforce",
+        }
+    }
+}
+
+impl fmt::Display for PermissionPrerequisites {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PermissionPrerequisites {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "warn" => Ok(Self::Warn),
+            "enforce" => Ok(Self::Enforce),
+            _ => Err(format!("{}{}", ERR_PERMISSION_PREREQUISITES_MODE, s)),
+        }
+    }
+}
+
+/// What a login does when its account already has the most sessions allowed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SessionLimitPolicy {
+    /// Refuse the new login
+    #[default]
+    Reject,
+    /// Disconnect the account's oldest sessions to make room
+    ReplaceOldest,
+}
+
+impl SessionLimitPolicy {
+    /// Value stored in the config table and accepted on the command line
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Reject => "reject",
+            Self::ReplaceOldest => "replace-oldest",
+        }
+    }
+}
+
+impl fmt::Display for SessionLimitPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SessionLimitPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reject" => Ok(Self::Reject),
+            "replace-oldest" => Ok(Self::ReplaceOldest),
+            _ => Err(format!("{}{}", ERR_SESSION_LIMIT_POLICY, s)),
+        }
+    }
+}
+
+/// Cap on concurrent sessions per account
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionLimit {
+    /// Most sessions one account may have at once (0 = unlimited)
+    pub max_sessions: u32,
+    /// What a login over the cap does
+    pub policy: SessionLimitPolicy,
+    /// Admins may open any number of sessions
+    pub admin_exempt: bool,
+}
+
+impl SessionLimit {
+    /// Whether the cap applies to an account
+    pub fn applies_to(&self, is_admin: bool) -> bool {
+        self.max_sessions > 0 && !(is_admin && self.admin_exempt)
+    }
+}
+
+/// Whether and how people may log in as guests
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuestLogin {
+    /// Guests may log in
+    pub enabled: bool,
+    /// Hash of the password guests share (`None` = no password needed)
+    pub password_hash: Option<String>,
+}
+
+/// Permissions switched off server-wide
+///
+/// Applies regardless of grants, to admins too unless the operator exempts them.
+/// Parsed from a comma-separated list of permission names (empty disables nothing).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisabledPermissions(Vec<Permission>);
+
+impl DisabledPermissions {
+    /// Whether nothing is disabled
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether the given permission is disabled
+    pub fn contains(&self, permission: Permission) -> bool {
+        self.0.contains(&permission)
+    }
+
+    /// Permission names in the order they were configured
+    pub fn names(&self) -> Vec<String> {
+        self.0.iter().map(|p| p.as_str().to_string()).collect()
+    }
+}
+
+impl fmt::Display for DisabledPermissions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("none");
+        }
+        f.write_str(&self.names().join(", "))
+    }
+}
+
+impl FromStr for DisabledPermissions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut permissions = Vec::new();
+        for name in s.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let permission = Permission::parse(name)
+                .ok_or_else(|| format!("{}{}", ERR_DISABLED_PERMISSION_UNKNOWN, name))?;
+            if !permission.gates_command() {
+                return Err(format!("{}{}", ERR_DISABLED_PERMISSION_NOT_COMMAND, name));
+            }
+            if !permissions.contains(&permission) {
+                permissions.push(permission);
+            }
+        }
+        Ok(Self(permissions))
+    }
+}
+
+/// Pixel bounds for raster avatars (SVG avatars have no dimensions to check)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvatarLimits {
+    /// Smallest accepted width or height
+    pub min_dimension: u32,
+    /// Largest accepted width or height
+    pub max_dimension: u32,
+    /// Downscale avatars above `max_dimension` instead of rejecting them
+    pub downscale: bool,
+}
+
+impl AvatarLimits {
+    /// Whether a min/max pair is usable (`1 <= min <= max <= AVATAR_DIMENSION_CEILING`)
+    pub fn dimensions_valid(min: u32, max: u32) -> bool {
+        min > 0 && min <= max && max <= AVATAR_DIMENSION_CEILING
+    }
+}
+
+impl Default for AvatarLimits {
+    fn default() -> Self {
+        Self {
+            min_dimension: DEFAULT_AVATAR_MIN_DIMENSION,
+            max_dimension: DEFAULT_AVATAR_MAX_DIMENSION,
+            downscale: false,
+        }
+    }
+}
 
 /// Database interface for server configuration
 #[derive(Clone)]
@@ -63,6 +277,80 @@ impl ConfigDb {
         Ok(())
     }
 
+    /// Get the cap on concurrent sessions per account
+    ///
+    /// Each part falls back to its default (unlimited, reject, admins not
+    /// exempt) if not found or invalid.
+    pub async fn get_session_limit(&self) -> SessionLimit {
+        let max_sessions = self
+            .get_u32(
+                CONFIG_KEY_MAX_SESSIONS_PER_USER,
+                DEFAULT_MAX_SESSIONS_PER_USER,
+            )
+            .await;
+        let policy = sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_SESSION_LIMIT_POLICY)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let admin_exempt = sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_SESSION_LIMIT_ADMIN_EXEMPT)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(false);
+
+        SessionLimit {
+            max_sessions,
+            policy,
+            admin_exempt,
+        }
+    }
+
+    /// Set the most concurrent sessions per account (0 = unlimited)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_max_sessions_per_user(&self, value: u32) -> io::Result<()> {
+        self.set_u32(CONFIG_KEY_MAX_SESSIONS_PER_USER, value).await
+    }
+
+    /// Set what a login over the session cap does
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_session_limit_policy(&self, policy: SessionLimitPolicy) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(policy.as_str())
+            .bind(CONFIG_KEY_SESSION_LIMIT_POLICY)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Set whether admins are exempt from the session cap
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_session_limit_admin_exempt(&self, exempt: bool) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(exempt.to_string())
+            .bind(CONFIG_KEY_SESSION_LIMIT_ADMIN_EXEMPT)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
     /// Get the server name
     ///
     /// Returns the configured value, or "Nexus BBS" (the default) if not found.
@@ -177,6 +465,735 @@ impl ConfigDb {
 
         Ok(())
     }
+
+    /// Get the server rules users must accept before chatting
+    ///
+    /// Returns the configured value, or "" (empty string, the default, meaning
+    /// no rules) if not found.
+    pub async fn get_server_rules(&self) -> String {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_SERVER_RULES)
+            .fetch_one(&self.pool)
+            .await
+            .unwrap_or_else(|_| DEFAULT_SERVER_RULES.to_string())
+    }
+
+    /// Set the server rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails or if the database update fails.
+    /// An empty string is allowed to disable the rules gate.
+    pub async fn set_server_rules(&self, rules: &str) -> io::Result<()> {
+        // Defense-in-depth validation
+        if let Err(e) = validate_server_rules(rules) {
+            let msg = match e {
+                ServerRulesError::TooLong => ERR_SERVER_RULES_TOO_LONG,
+                ServerRulesError::InvalidCharacters => ERR_SERVER_RULES_INVALID_CHARS,
+            };
+            return Err(io::Error::other(msg));
+        }
+
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(rules)
+            .bind(CONFIG_KEY_SERVER_RULES)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the banner shown to clients before they log in
+    ///
+    /// Returns the configured value, or "" (empty string, the default, meaning
+    /// no banner) if not found.
+    pub async fn get_server_banner(&self) -> String {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_SERVER_BANNER)
+            .fetch_one(&self.pool)
+            .await
+            .unwrap_or_else(|_| DEFAULT_SERVER_BANNER.to_string())
+    }
+
+    /// Set the server banner
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails or if the database update fails.
+    /// An empty string is allowed to disable the banner.
+    pub async fn set_server_banner(&self, banner: &str) -> io::Result<()> {
+        // Defense-in-depth validation
+        if let Err(e) = validate_server_banner(banner) {
+            let msg = match e {
+                ServerBannerError::TooLong => ERR_SERVER_BANNER_TOO_LONG,
+                ServerBannerError::InvalidCharacters => ERR_SERVER_BANNER_INVALID_CHARS,
+            };
+            return Err(io::Error::other(msg));
+        }
+
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(banner)
+            .bind(CONFIG_KEY_SERVER_BANNER)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the rotating announcements in broadcast order
+    ///
+    /// Returns an empty list (the default, which disables the ticker) if not
+    /// found or invalid.
+    pub async fn get_announcements(&self) -> Vec<String> {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_ANNOUNCEMENTS)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| serde_json::from_str(&v).ok())
+            .unwrap_or_default()
+    }
+
+    /// Replace the rotating announcements (callers validate each message)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_announcements(&self, announcements: &[String]) -> io::Result<()> {
+        let json = serde_json::to_string(announcements).map_err(io::Error::other)?;
+
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(json)
+            .bind(CONFIG_KEY_ANNOUNCEMENTS)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the minutes between announcements
+    ///
+    /// Returns the configured value, or 30 (the default) if not found or invalid.
+    pub async fn get_announcement_interval(&self) -> u32 {
+        self.get_u32(
+            CONFIG_KEY_ANNOUNCEMENT_INTERVAL,
+            DEFAULT_ANNOUNCEMENT_INTERVAL_MINUTES,
+        )
+        .await
+        .max(1)
+    }
+
+    /// Set the minutes between announcements (callers validate the range)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_announcement_interval(&self, minutes: u32) -> io::Result<()> {
+        self.set_u32(CONFIG_KEY_ANNOUNCEMENT_INTERVAL, minutes)
+            .await
+    }
+
+    /// Get the chat slow mode interval in seconds
+    ///
+    /// Returns the configured value, or 0 (disabled, the default) if not found or invalid.
+    pub async fn get_chat_slow_mode_seconds(&self) -> u32 {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_CHAT_SLOW_MODE)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_CHAT_SLOW_MODE_SECONDS)
+    }
+
+    /// Set the chat slow mode interval in seconds (0 disables slow mode)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_chat_slow_mode_seconds(&self, seconds: u32) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(seconds.to_string())
+            .bind(CONFIG_KEY_CHAT_SLOW_MODE)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the per-session cooldown for expensive commands in seconds
+    ///
+    /// Returns the configured value, or 0 (disabled, the default) if not found or invalid.
+    pub async fn get_command_cooldown_seconds(&self) -> u32 {
+        self.get_u32(
+            CONFIG_KEY_COMMAND_COOLDOWN,
+            DEFAULT_COMMAND_COOLDOWN_SECONDS,
+        )
+        .await
+    }
+
+    /// Set the per-session cooldown for expensive commands (0 disables it)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_command_cooldown_seconds(&self, seconds: u32) -> io::Result<()> {
+        self.set_u32(CONFIG_KEY_COMMAND_COOLDOWN, seconds).await
+    }
+
+    /// Get how many minutes users can edit or delete their own chat messages
+    ///
+    /// Returns the configured value, or 15 (the default) if not found or invalid.
+    /// 0 means no limit.
+    pub async fn get_chat_edit_window_minutes(&self) -> u32 {
+        self.get_u32(
+            CONFIG_KEY_CHAT_EDIT_WINDOW,
+            DEFAULT_CHAT_EDIT_WINDOW_MINUTES,
+        )
+        .await
+    }
+
+    /// Set how many minutes users can edit or delete their own chat messages
+    /// (0 for no limit, callers validate the range)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_chat_edit_window_minutes(&self, minutes: u32) -> io::Result<()> {
+        self.set_u32(CONFIG_KEY_CHAT_EDIT_WINDOW, minutes).await
+    }
+
+    /// Get the maximum number of chat history messages to keep
+    ///
+    /// Returns the configured value, or 1000 (the default) if not found or invalid.
+    /// 0 means unlimited.
+    pub async fn get_chat_retention_count(&self) -> u32 {
+        self.get_u32(
+            CONFIG_KEY_CHAT_RETENTION_COUNT,
+            DEFAULT_CHAT_RETENTION_COUNT,
+        )
+        .await
+    }
+
+    /// Set the maximum number of chat history messages to keep (0 = unlimited)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_chat_retention_count(&self, count: u32) -> io::Result<()> {
+        self.set_u32(CONFIG_KEY_CHAT_RETENTION_COUNT, count).await
+    }
+
+    /// Get whether public chat is recorded in the history
+    ///
+    /// Returns the configured value, or `false` (the default) if not found or invalid.
+    pub async fn get_chat_history_enabled(&self) -> bool {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_CHAT_HISTORY)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(false)
+    }
+
+    /// Turn recording public chat in the history on or off
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_chat_history_enabled(&self, enabled: bool) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(enabled.to_string())
+            .bind(CONFIG_KEY_CHAT_HISTORY)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the maximum age of chat history messages in days
+    ///
+    /// Returns the configured value, or 30 (the default) if not found or invalid.
+    /// 0 means unlimited.
+    pub async fn get_chat_retention_days(&self) -> u32 {
+        self.get_u32(CONFIG_KEY_CHAT_RETENTION_DAYS, DEFAULT_CHAT_RETENTION_DAYS)
+            .await
+    }
+
+    /// Set the maximum age of chat history messages in days (0 = unlimited)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_chat_retention_days(&self, days: u32) -> io::Result<()> {
+        self.set_u32(CONFIG_KEY_CHAT_RETENTION_DAYS, days).await
+    }
+
+    /// Get how missing permission prerequisites are handled
+    ///
+    /// Returns the configured mode, or `Warn` (the default) if not found or invalid.
+    pub async fn get_permission_prerequisites(&self) -> PermissionPrerequisites {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_PERMISSION_PREREQUISITES)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Set how missing permission prerequisites are handled
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_permission_prerequisites(
+        &self,
+        mode: PermissionPrerequisites,
+    ) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(mode.as_str())
+            .bind(CONFIG_KEY_PERMISSION_PREREQUISITES)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get whether maintenance (read-only) mode is on
+    ///
+    /// Returns the configured value, or `false` (the default) if not found or invalid.
+    pub async fn get_maintenance_mode(&self) -> bool {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_MAINTENANCE_MODE)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(false)
+    }
+
+    /// Turn maintenance (read-only) mode on or off
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_maintenance_mode(&self, enabled: bool) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(enabled.to_string())
+            .bind(CONFIG_KEY_MAINTENANCE_MODE)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the locale used for clients that don't name a supported one
+    ///
+    /// Returns the configured locale, or `DEFAULT_LOCALE` if not found or unsupported.
+    pub async fn get_default_locale(&self) -> String {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_DEFAULT_LOCALE)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .filter(|locale| i18n::is_supported_locale(locale))
+            .unwrap_or_else(|| DEFAULT_LOCALE.to_string())
+    }
+
+    /// Set the locale used for clients that don't name a supported one
+    ///
+    /// Callers validate the locale with `i18n::is_supported_locale`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_default_locale(&self, locale: &str) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(locale)
+            .bind(CONFIG_KEY_DEFAULT_LOCALE)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the oldest client protocol version allowed to connect
+    ///
+    /// Returns `None` (every compatible version) if unset or invalid.
+    pub async fn get_min_client_version(&self) -> Option<Version> {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_MIN_CLIENT_VERSION)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+    }
+
+    /// Set the oldest client protocol version allowed to connect
+    ///
+    /// `None` accepts every compatible version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_min_client_version(&self, version: Option<&Version>) -> io::Result<()> {
+        let value = version.map(Version::to_string).unwrap_or_default();
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(value)
+            .bind(CONFIG_KEY_MIN_CLIENT_VERSION)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get whether a user's other sessions are told about a new login
+    ///
+    /// Returns the configured value, or `true` (the default) if not found or invalid.
+    pub async fn get_login_notices(&self) -> bool {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_LOGIN_NOTICES)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(true)
+    }
+
+    /// Turn new login notices on or off
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_login_notices(&self, enabled: bool) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(enabled.to_string())
+            .bind(CONFIG_KEY_LOGIN_NOTICES)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get whether guests may log in, and the password they share
+    ///
+    /// Guest login is off (the default) if not found or invalid.
+    pub async fn get_guest_login(&self) -> GuestLogin {
+        let enabled = sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_GUEST_LOGIN)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(false);
+        let password_hash = sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_GUEST_PASSWORD)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .filter(|hash| !hash.is_empty());
+
+        GuestLogin {
+            enabled,
+            password_hash,
+        }
+    }
+
+    /// Turn guest login on or off
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_guest_login(&self, enabled: bool) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(enabled.to_string())
+            .bind(CONFIG_KEY_GUEST_LOGIN)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Set the hash of the password guests share
+    ///
+    /// `None` lets guests in without a password.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_guest_password_hash(&self, hash: Option<&str>) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(hash.unwrap_or_default())
+            .bind(CONFIG_KEY_GUEST_PASSWORD)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the pixel bounds and downscaling setting for raster avatars
+    ///
+    /// Falls back to the default bounds if either stored dimension is missing,
+    /// invalid, or the pair is out of range.
+    pub async fn get_avatar_limits(&self) -> AvatarLimits {
+        let defaults = AvatarLimits::default();
+        let min = self
+            .get_u32(CONFIG_KEY_AVATAR_MIN_DIMENSION, defaults.min_dimension)
+            .await;
+        let max = self
+            .get_u32(CONFIG_KEY_AVATAR_MAX_DIMENSION, defaults.max_dimension)
+            .await;
+        let (min_dimension, max_dimension) = if AvatarLimits::dimensions_valid(min, max) {
+            (min, max)
+        } else {
+            (defaults.min_dimension, defaults.max_dimension)
+        };
+
+        let downscale = sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_AVATAR_DOWNSCALE)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(defaults.downscale);
+
+        AvatarLimits {
+            min_dimension,
+            max_dimension,
+            downscale,
+        }
+    }
+
+    /// Set the smallest and largest accepted avatar width or height
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pair is out of range (see
+    /// `AvatarLimits::dimensions_valid`), or if the database update fails.
+    pub async fn set_avatar_dimensions(&self, min: u32, max: u32) -> io::Result<()> {
+        if !AvatarLimits::dimensions_valid(min, max) {
+            return Err(io::Error::other(ERR_AVATAR_DIMENSIONS_OUT_OF_RANGE));
+        }
+
+        self.set_u32(CONFIG_KEY_AVATAR_MIN_DIMENSION, min).await?;
+        self.set_u32(CONFIG_KEY_AVATAR_MAX_DIMENSION, max).await
+    }
+
+    /// Turn downscaling of oversized avatars on or off
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_avatar_downscale(&self, enabled: bool) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(enabled.to_string())
+            .bind(CONFIG_KEY_AVATAR_DOWNSCALE)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the permissions disabled server-wide
+    ///
+    /// Returns an empty set (the default) if not found or invalid. Names this
+    /// server no longer knows are skipped.
+    pub async fn get_disabled_permissions(&self) -> DisabledPermissions {
+        let names: Vec<String> = sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_DISABLED_PERMISSIONS)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| serde_json::from_str(&v).ok())
+            .unwrap_or_default();
+
+        DisabledPermissions(
+            names
+                .iter()
+                .filter_map(|name| Permission::parse(name))
+                .filter(|p| p.gates_command())
+                .collect(),
+        )
+    }
+
+    /// Replace the permissions disabled server-wide
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_disabled_permissions(&self, disabled: &DisabledPermissions) -> io::Result<()> {
+        let json = serde_json::to_string(&disabled.names()).map_err(io::Error::other)?;
+
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(json)
+            .bind(CONFIG_KEY_DISABLED_PERMISSIONS)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get whether admins are exempt from disabled permissions
+    ///
+    /// Returns the configured value, or `false` (the default) if not found or invalid.
+    pub async fn get_disabled_permissions_admin_exempt(&self) -> bool {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_DISABLED_PERMISSIONS_ADMIN_EXEMPT)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(false)
+    }
+
+    /// Set whether admins are exempt from disabled permissions
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_disabled_permissions_admin_exempt(&self, exempt: bool) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(exempt.to_string())
+            .bind(CONFIG_KEY_DISABLED_PERMISSIONS_ADMIN_EXEMPT)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the permissions disabled for a particular user
+    ///
+    /// Empty for admins when the operator has exempted them.
+    pub async fn get_disabled_permissions_for(&self, is_admin: bool) -> DisabledPermissions {
+        let disabled = self.get_disabled_permissions().await;
+        if disabled.is_empty() || (is_admin && self.get_disabled_permissions_admin_exempt().await) {
+            return DisabledPermissions::default();
+        }
+        disabled
+    }
+
+    /// Get the maximum message length (chat, broadcast, private messages)
+    ///
+    /// Returns the configured value, or `MAX_MESSAGE_LENGTH` (the default) if not
+    /// found, invalid, or outside `1..=MAX_MESSAGE_LENGTH_CEILING`.
+    pub async fn get_max_message_length(&self) -> usize {
+        self.get_length_limit(
+            CONFIG_KEY_MAX_MESSAGE_LENGTH,
+            MAX_MESSAGE_LENGTH_CEILING,
+            MAX_MESSAGE_LENGTH,
+        )
+        .await
+    }
+
+    /// Set the maximum message length (chat, broadcast, private messages)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is zero or above `MAX_MESSAGE_LENGTH_CEILING`,
+    /// or if the database update fails.
+    pub async fn set_max_message_length(&self, value: usize) -> io::Result<()> {
+        if value == 0 || value > MAX_MESSAGE_LENGTH_CEILING {
+            return Err(io::Error::other(ERR_MAX_MESSAGE_LENGTH_OUT_OF_RANGE));
+        }
+
+        self.set_length_limit(CONFIG_KEY_MAX_MESSAGE_LENGTH, value)
+            .await
+    }
+
+    /// Get the maximum chat topic length
+    ///
+    /// Returns the configured value, or `MAX_CHAT_TOPIC_LENGTH` (the default) if not
+    /// found, invalid, or outside `1..=MAX_CHAT_TOPIC_LENGTH_CEILING`.
+    pub async fn get_max_topic_length(&self) -> usize {
+        self.get_length_limit(
+            CONFIG_KEY_MAX_TOPIC_LENGTH,
+            MAX_CHAT_TOPIC_LENGTH_CEILING,
+            MAX_CHAT_TOPIC_LENGTH,
+        )
+        .await
+    }
+
+    /// Set the maximum chat topic length
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is zero or above `MAX_CHAT_TOPIC_LENGTH_CEILING`,
+    /// or if the database update fails.
+    pub async fn set_max_topic_length(&self, value: usize) -> io::Result<()> {
+        if value == 0 || value > MAX_CHAT_TOPIC_LENGTH_CEILING {
+            return Err(io::Error::other(ERR_MAX_TOPIC_LENGTH_OUT_OF_RANGE));
+        }
+
+        self.set_length_limit(CONFIG_KEY_MAX_TOPIC_LENGTH, value)
+            .await
+    }
+
+    /// Read a length limit, falling back to `default` if missing or out of range
+    ///
+    /// Operators may edit the config table directly, so values are range-checked
+    /// here rather than trusted.
+    async fn get_length_limit(&self, key: &str, ceiling: usize, default: usize) -> usize {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(key)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|&v| v > 0 && v <= ceiling)
+            .unwrap_or(default)
+    }
+
+    /// Read an unsigned setting, falling back to `default` if missing or invalid
+    async fn get_u32(&self, key: &str, default: u32) -> u32 {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(key)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(default)
+    }
+
+    /// Store an unsigned setting
+    async fn set_u32(&self, key: &str, value: u32) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(value.to_string())
+            .bind(key)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Store a length limit (callers validate the range)
+    async fn set_length_limit(&self, key: &str, value: usize) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(value.to_string())
+            .bind(key)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -391,4 +1408,506 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("too large"));
     }
+
+    // =========================================================================
+    // Server Rules Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_server_rules_round_trip() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration sets default to empty (no rules)
+        assert_eq!(config_db.get_server_rules().await, "");
+
+        let rules = "1. Be nice\n2. No spam";
+        config_db.set_server_rules(rules).await.unwrap();
+        assert_eq!(config_db.get_server_rules().await, rules);
+    }
+
+    #[tokio::test]
+    async fn test_set_server_rules_validation() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        let too_long = "a".repeat(validators::MAX_SERVER_RULES_LENGTH + 1);
+        assert!(config_db.set_server_rules(&too_long).await.is_err());
+        assert!(config_db.set_server_rules("bad\0rules").await.is_err());
+        assert_eq!(config_db.get_server_rules().await, "");
+    }
+
+    #[tokio::test]
+    async fn test_server_banner_round_trip() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration sets default to empty (no banner)
+        assert_eq!(config_db.get_server_banner().await, "");
+
+        let banner = "Authorized use only.\nActivity is logged.";
+        config_db.set_server_banner(banner).await.unwrap();
+        assert_eq!(config_db.get_server_banner().await, banner);
+
+        let too_long = "a".repeat(validators::MAX_SERVER_BANNER_LENGTH + 1);
+        assert!(config_db.set_server_banner(&too_long).await.is_err());
+        assert!(config_db.set_server_banner("bad\0banner").await.is_err());
+        assert_eq!(config_db.get_server_banner().await, banner);
+    }
+
+    // =========================================================================
+    // Announcement Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_announcements_round_trip() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration sets defaults: no announcements, every 30 minutes
+        assert!(config_db.get_announcements().await.is_empty());
+        assert_eq!(config_db.get_announcement_interval().await, 30);
+
+        let announcements = vec![
+            "Read the rules".to_string(),
+            "Game night Friday".to_string(),
+        ];
+        config_db.set_announcements(&announcements).await.unwrap();
+        assert_eq!(config_db.get_announcements().await, announcements);
+
+        config_db.set_announcement_interval(5).await.unwrap();
+        assert_eq!(config_db.get_announcement_interval().await, 5);
+    }
+
+    // =========================================================================
+    // Chat Slow Mode Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_get_chat_slow_mode_seconds_default() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration sets default to 0 (disabled)
+        let seconds = config_db.get_chat_slow_mode_seconds().await;
+        assert_eq!(seconds, 0);
+    }
+
+    #[tokio::test]
+    async fn test_set_chat_slow_mode_seconds() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        config_db.set_chat_slow_mode_seconds(30).await.unwrap();
+        assert_eq!(config_db.get_chat_slow_mode_seconds().await, 30);
+
+        // Setting back to 0 disables slow mode
+        config_db.set_chat_slow_mode_seconds(0).await.unwrap();
+        assert_eq!(config_db.get_chat_slow_mode_seconds().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_command_cooldown_seconds() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        assert_eq!(
+            config_db.get_command_cooldown_seconds().await,
+            DEFAULT_COMMAND_COOLDOWN_SECONDS
+        );
+
+        config_db.set_command_cooldown_seconds(5).await.unwrap();
+        assert_eq!(config_db.get_command_cooldown_seconds().await, 5);
+    }
+
+    // =========================================================================
+    // Chat Retention Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_chat_edit_window_minutes() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        assert_eq!(
+            config_db.get_chat_edit_window_minutes().await,
+            DEFAULT_CHAT_EDIT_WINDOW_MINUTES
+        );
+
+        config_db.set_chat_edit_window_minutes(60).await.unwrap();
+        assert_eq!(config_db.get_chat_edit_window_minutes().await, 60);
+
+        config_db.set_chat_edit_window_minutes(0).await.unwrap();
+        assert_eq!(config_db.get_chat_edit_window_minutes().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_chat_retention_default() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration keeps 1000 messages for 30 days by default
+        assert_eq!(config_db.get_chat_retention_count().await, 1000);
+        assert_eq!(config_db.get_chat_retention_days().await, 30);
+    }
+
+    #[tokio::test]
+    async fn test_set_chat_retention() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        config_db.set_chat_retention_count(50).await.unwrap();
+        config_db.set_chat_retention_days(0).await.unwrap();
+
+        assert_eq!(config_db.get_chat_retention_count().await, 50);
+        assert_eq!(config_db.get_chat_retention_days().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_chat_history_default_and_set() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration leaves recording off
+        assert!(!config_db.get_chat_history_enabled().await);
+
+        config_db.set_chat_history_enabled(true).await.unwrap();
+        assert!(config_db.get_chat_history_enabled().await);
+    }
+
+    // =========================================================================
+    // Permission Prerequisite Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_permission_prerequisites_default_and_set() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        assert_eq!(
+            config_db.get_permission_prerequisites().await,
+            PermissionPrerequisites::Warn
+        );
+        config_db
+            .set_permission_prerequisites(PermissionPrerequisites::Enforce)
+            .await
+            .unwrap();
+        assert_eq!(
+            config_db.get_permission_prerequisites().await,
+            PermissionPrerequisites::Enforce
+        );
+    }
+
+    #[tokio::test]
+    async fn test_session_limit_default_and_set() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        let limit = config_db.get_session_limit().await;
+        assert_eq!(limit, SessionLimit::default());
+        assert!(!limit.applies_to(false));
+
+        config_db.set_max_sessions_per_user(3).await.unwrap();
+        config_db
+            .set_session_limit_policy(SessionLimitPolicy::ReplaceOldest)
+            .await
+            .unwrap();
+        config_db
+            .set_session_limit_admin_exempt(true)
+            .await
+            .unwrap();
+        let limit = config_db.get_session_limit().await;
+        assert_eq!(
+            limit,
+            SessionLimit {
+                max_sessions: 3,
+                policy: SessionLimitPolicy::ReplaceOldest,
+                admin_exempt: true,
+            }
+        );
+        assert!(limit.applies_to(false));
+        assert!(!limit.applies_to(true));
+    }
+
+    #[test]
+    fn test_session_limit_policy_parse() {
+        for policy in [
+            SessionLimitPolicy::Reject,
+            SessionLimitPolicy::ReplaceOldest,
+        ] {
+            assert_eq!(policy.as_str().parse::<SessionLimitPolicy>(), Ok(policy));
+        }
+        assert!("replace".parse::<SessionLimitPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_permission_prerequisites_parse() {
+        for mode in [
+            PermissionPrerequisites::Off,
+            PermissionPrerequisites::Warn,
+            PermissionPrerequisites::Enforce,
+        ] {
+            assert_eq!(mode.as_str().parse::<PermissionPrerequisites>(), Ok(mode));
+        }
+        assert!("add".parse::<PermissionPrerequisites>().is_err());
+    }
+
+    // =========================================================================
+    // Maintenance Mode Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_maintenance_mode_default_and_set() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration leaves maintenance mode off
+        assert!(!config_db.get_maintenance_mode().await);
+
+        config_db.set_maintenance_mode(true).await.unwrap();
+        assert!(config_db.get_maintenance_mode().await);
+
+        config_db.set_maintenance_mode(false).await.unwrap();
+        assert!(!config_db.get_maintenance_mode().await);
+    }
+
+    #[tokio::test]
+    async fn test_default_locale_default_and_set() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        assert_eq!(config_db.get_default_locale().await, DEFAULT_LOCALE);
+
+        config_db.set_default_locale("ja").await.unwrap();
+        assert_eq!(config_db.get_default_locale().await, "ja");
+
+        // An unsupported stored value reads back as the built-in default
+        config_db.set_default_locale("xx").await.unwrap();
+        assert_eq!(config_db.get_default_locale().await, DEFAULT_LOCALE);
+    }
+
+    #[tokio::test]
+    async fn test_min_client_version_default_and_set() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration accepts every compatible version
+        assert_eq!(config_db.get_min_client_version().await, None);
+
+        let version = Version::new(0, 5, 0);
+        config_db
+            .set_min_client_version(Some(&version))
+            .await
+            .unwrap();
+        assert_eq!(config_db.get_min_client_version().await, Some(version));
+
+        config_db.set_min_client_version(None).await.unwrap();
+        assert_eq!(config_db.get_min_client_version().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_login_notices_default_and_set() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration leaves login notices on
+        assert!(config_db.get_login_notices().await);
+
+        config_db.set_login_notices(false).await.unwrap();
+        assert!(!config_db.get_login_notices().await);
+    }
+
+    #[tokio::test]
+    async fn test_guest_login_default_and_set() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration leaves guest login off, without a password
+        assert_eq!(config_db.get_guest_login().await, GuestLogin::default());
+
+        config_db.set_guest_login(true).await.unwrap();
+        config_db
+            .set_guest_password_hash(Some("$argon2id$hash"))
+            .await
+            .unwrap();
+        assert_eq!(
+            config_db.get_guest_login().await,
+            GuestLogin {
+                enabled: true,
+                password_hash: Some("$argon2id$hash".to_string()),
+            }
+        );
+
+        config_db.set_guest_password_hash(None).await.unwrap();
+        assert_eq!(config_db.get_guest_login().await.password_hash, None);
+    }
+
+    #[tokio::test]
+    async fn test_avatar_limits_default_and_set() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        assert_eq!(config_db.get_avatar_limits().await, AvatarLimits::default());
+
+        config_db.set_avatar_dimensions(32, 256).await.unwrap();
+        config_db.set_avatar_downscale(true).await.unwrap();
+        assert_eq!(
+            config_db.get_avatar_limits().await,
+            AvatarLimits {
+                min_dimension: 32,
+                max_dimension: 256,
+                downscale: true,
+            }
+        );
+
+        // Inverted, zero and above-ceiling bounds are rejected
+        for (min, max) in [(0, 256), (300, 256), (16, AVATAR_DIMENSION_CEILING + 1)] {
+            assert!(config_db.set_avatar_dimensions(min, max).await.is_err());
+        }
+
+        // An inverted pair edited into the table reads back as the defaults
+        config_db
+            .set_u32(CONFIG_KEY_AVATAR_MIN_DIMENSION, 1024)
+            .await
+            .unwrap();
+        let limits = config_db.get_avatar_limits().await;
+        assert_eq!(limits.min_dimension, DEFAULT_AVATAR_MIN_DIMENSION);
+        assert_eq!(limits.max_dimension, DEFAULT_AVATAR_MAX_DIMENSION);
+    }
+
+    // =========================================================================
+    // Disabled Permissions Tests
+    // =========================================================================
+
+    #[test]
+    fn test_disabled_permissions_parse() {
+        let disabled: DisabledPermissions =
+            "user_broadcast, user_kick,user_broadcast".parse().unwrap();
+        assert_eq!(disabled.names(), vec!["user_broadcast", "user_kick"]);
+        assert_eq!(disabled.to_string(), "user_broadcast, user_kick");
+
+        let none: DisabledPermissions = "".parse().unwrap();
+        assert!(none.is_empty());
+        assert_eq!(none.to_string(), "none");
+
+        assert!(
+            "user_broadcast,bogus"
+                .parse::<DisabledPermissions>()
+                .is_err()
+        );
+        assert!("chat_receive".parse::<DisabledPermissions>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_disabled_permissions_round_trip() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration disables nothing and doesn't exempt admins
+        assert!(config_db.get_disabled_permissions().await.is_empty());
+        assert!(!config_db.get_disabled_permissions_admin_exempt().await);
+
+        let disabled: DisabledPermissions = "user_broadcast".parse().unwrap();
+        config_db.set_disabled_permissions(&disabled).await.unwrap();
+        assert_eq!(config_db.get_disabled_permissions().await, disabled);
+        assert!(
+            config_db
+                .get_disabled_permissions_for(true)
+                .await
+                .contains(Permission::UserBroadcast)
+        );
+
+        config_db
+            .set_disabled_permissions_admin_exempt(true)
+            .await
+            .unwrap();
+        assert!(
+            config_db
+                .get_disabled_permissions_for(true)
+                .await
+                .is_empty()
+        );
+        assert_eq!(
+            config_db.get_disabled_permissions_for(false).await,
+            disabled
+        );
+    }
+
+    // =========================================================================
+    // Message Limit Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_get_message_limits_default() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration sets defaults matching the protocol defaults
+        assert_eq!(
+            config_db.get_max_message_length().await,
+            validators::MAX_MESSAGE_LENGTH
+        );
+        assert_eq!(
+            config_db.get_max_topic_length().await,
+            validators::MAX_CHAT_TOPIC_LENGTH
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_message_limits() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        config_db
+            .set_max_message_length(validators::MAX_MESSAGE_LENGTH_CEILING)
+            .await
+            .unwrap();
+        config_db.set_max_topic_length(64).await.unwrap();
+
+        assert_eq!(
+            config_db.get_max_message_length().await,
+            validators::MAX_MESSAGE_LENGTH_CEILING
+        );
+        assert_eq!(config_db.get_max_topic_length().await, 64);
+    }
+
+    #[tokio::test]
+    async fn test_set_message_limits_out_of_range_fails() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        assert!(config_db.set_max_message_length(0).await.is_err());
+        assert!(
+            config_db
+                .set_max_message_length(validators::MAX_MESSAGE_LENGTH_CEILING + 1)
+                .await
+                .is_err()
+        );
+        assert!(config_db.set_max_topic_length(0).await.is_err());
+        assert!(
+            config_db
+                .set_max_topic_length(validators::MAX_CHAT_TOPIC_LENGTH_CEILING + 1)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_message_limits_ignores_out_of_range_values() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool.clone());
+
+        // Simulate an operator editing the config table by hand
+        sqlx::query(SQL_SET_CONFIG)
+            .bind("999999")
+            .bind(CONFIG_KEY_MAX_MESSAGE_LENGTH)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            config_db.get_max_message_length().await,
+            validators::MAX_MESSAGE_LENGTH
+        );
+    }
 }
@@ -4,6 +4,9 @@ use crate::constants::*;
 
 pub mod chat;
 pub mod config;
+pub mod maintenance;
+pub mod moderation;
+pub mod pages;
 pub mod password;
 pub mod permissions;
 pub mod sql;
@@ -13,20 +16,37 @@ pub mod users;
 pub mod testing;
 
 pub use chat::ChatDb;
-pub use config::ConfigDb;
-pub use password::{hash_password, verify_password};
-pub use permissions::{Permission, Permissions};
-pub use users::UserDb;
+pub use config::{
+    AvatarLimits, ConfigDb, DisabledPermissions, PermissionPrerequisites, SessionLimitPolicy,
+};
+pub use maintenance::run_maintenance;
+pub use moderation::{ModerationDb, ModerationEntry};
+pub use pages::PageDb;
+pub use password::{hash_password, is_password_hash, verify_password};
+pub use permissions::{GUEST_PERMISSIONS, Permission, Permissions, TOPIC_VIEW_PERMISSIONS};
+pub use users::{AdminTransfer, UserDb, UserProfile};
 
+use sqlx::migrate::{MigrateError, Migrator};
 use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use std::fmt;
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
+/// Schema migrations embedded at build time
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// SQLite primary result code for a busy (locked) database
+const SQLITE_BUSY: i64 = 5;
+
 /// Combined database access for all database operations
 #[derive(Clone)]
 pub struct Database {
     pub users: UserDb,
     pub config: ConfigDb,
     pub chat: ChatDb,
+    pub moderation: ModerationDb,
+    pub pages: PageDb,
 }
 
 impl Database {
@@ -35,9 +55,24 @@ impl Database {
         Self {
             users: UserDb::new(pool.clone()),
             config: ConfigDb::new(pool.clone()),
-            chat: ChatDb::new(pool),
+            chat: ChatDb::new(pool.clone()),
+            moderation: ModerationDb::new(pool.clone()),
+            pages: PageDb::new(pool),
         }
     }
+
+    /// Apply the configured chat retention policy to the chat history
+    ///
+    /// Text kept in the moderation log is cleared at the same age.
+    ///
+    /// Returns the number of messages removed.
+    pub async fn purge_chat_history(&self) -> std::io::Result<u64> {
+        let max_count = self.config.get_chat_retention_count().await;
+        let max_age_days = self.config.get_chat_retention_days().await;
+        let removed = self.chat.purge_history(max_count, max_age_days).await?;
+        self.moderation.clear_text_older_than(max_age_days).await?;
+        Ok(removed)
+    }
 }
 
 /// Get the default database path for the platform
@@ -56,26 +91,234 @@ pub fn default_database_path() -> Result<PathBuf, String> {
     Ok(data_dir.join(DATA_DIR_NAME).join(DATABASE_FILENAME))
 }
 
+/// Why the database could not be opened
+#[derive(Debug)]
+pub enum InitDbError {
+    /// The database directory could not be created
+    CreateDir(io::Error),
+    /// The database file or its directory isn't accessible to this user
+    PermissionDenied(PathBuf),
+    /// Another process holds the database (usually a second nexusd)
+    Locked(PathBuf),
+    /// SQLite could not open the database
+    Open(sqlx::Error),
+    /// A schema migration failed
+    Migration(MigrateError),
+}
+
+impl fmt::Display for InitDbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreateDir(e) => write!(f, "{}{}", ERR_CREATE_DB_DIR, e),
+            Self::PermissionDenied(path) => {
+                write!(f, "{}{}", ERR_DB_PERMISSION_DENIED, path.display())
+            }
+            Self::Locked(path) => write!(f, "{}{}", ERR_DB_LOCKED, path.display()),
+            Self::Open(e) => write!(f, "{}{}", ERR_DB_OPEN, e),
+            Self::Migration(MigrateError::VersionMissing(version)) => {
+                write!(f, "{}{}", ERR_DB_NEWER_VERSION, version)
+            }
+            Self::Migration(e) => write!(f, "{}{}", ERR_DB_MIGRATION, e),
+        }
+    }
+}
+
+impl std::error::Error for InitDbError {}
+
+/// Advisory lock that keeps a second server off the same database
+///
+/// Held for the life of the process; the OS releases it on exit, even after
+/// a crash, so a stale lock file never blocks a restart.
+#[derive(Debug)]
+pub struct DatabaseLock {
+    _file: File,
+}
+
+/// Path of the lock file for a database (`nexus.db` → `nexus.db.lock`)
+fn lock_path(database_path: &Path) -> PathBuf {
+    let mut path = database_path.as_os_str().to_owned();
+    path.push(".");
+    path.push(DATABASE_LOCK_EXTENSION);
+    PathBuf::from(path)
+}
+
+/// Map an I/O error on `path` to an init error
+fn io_error(path: &Path, e: io::Error) -> InitDbError {
+    if e.kind() == io::ErrorKind::PermissionDenied {
+        InitDbError::PermissionDenied(path.to_path_buf())
+    } else {
+        InitDbError::Open(sqlx::Error::Io(e))
+    }
+}
+
+/// Whether SQLite reported the database as busy (locked by another connection)
+fn is_busy(e: &sqlx::Error) -> bool {
+    let sqlx::Error::Database(db_err) = e else {
+        return false;
+    };
+    db_err
+        .code()
+        .and_then(|code| code.parse::<i64>().ok())
+        .is_some_and(|code| code & 0xff == SQLITE_BUSY)
+}
+
+/// Take the single-instance lock for a database
+///
+/// The lock file records the PID of the server holding it.
+///
+/// # Errors
+///
+/// Returns `Locked` if another process holds the lock.
+pub fn lock_database(database_path: &Path) -> Result<DatabaseLock, InitDbError> {
+    let path = lock_path(database_path);
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&path)
+        .map_err(|e| io_error(&path, e))?;
+
+    match file.try_lock() {
+        Ok(()) => {}
+        Err(TryLockError::WouldBlock) => {
+            return Err(InitDbError::Locked(database_path.to_path_buf()));
+        }
+        Err(TryLockError::Error(e)) => return Err(io_error(&path, e)),
+    }
+
+    // Best effort: the PID only helps operators find the other process
+    let _ = file.set_len(0);
+    let _ = write!(file, "{}", std::process::id());
+
+    Ok(DatabaseLock { _file: file })
+}
+
+/// Run schema migrations on the database at `database_path`, classifying failures
+async fn run_migrations(
+    pool: &SqlitePool,
+    migrator: &Migrator,
+    database_path: &Path,
+) -> Result<(), InitDbError> {
+    migrator.run(pool).await.map_err(|e| match e {
+        MigrateError::Execute(ref inner) if is_busy(inner) => {
+            InitDbError::Locked(database_path.to_path_buf())
+        }
+        e => InitDbError::Migration(e),
+    })
+}
+
 /// Initialize the database connection pool and run migrations
-pub async fn init_db(database_path: &Path) -> Result<SqlitePool, sqlx::Error> {
+///
+/// Takes the single-instance lock first, so a second server on the same
+/// database fails fast instead of hitting intermittent lock errors.
+///
+/// # Errors
+///
+/// Returns an error describing whether the database is locked, inaccessible,
+/// or failed to migrate.
+pub async fn init_db(database_path: &Path) -> Result<(SqlitePool, DatabaseLock), InitDbError> {
     // Create parent directories if they don't exist
     if let Some(parent) = database_path.parent() {
         std::fs::create_dir_all(parent).map_err(|e| {
-            eprintln!("{}{}", ERR_CREATE_DB_DIR, e);
-            sqlx::Error::Io(e)
+            if e.kind() == io::ErrorKind::PermissionDenied {
+                InitDbError::PermissionDenied(parent.to_path_buf())
+            } else {
+                InitDbError::CreateDir(e)
+            }
         })?;
     }
 
+    let lock = lock_database(database_path)?;
+
+    // Check access up front so a permission problem isn't reported as a generic SQLite error
+    if database_path.exists() {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(database_path)
+            .map_err(|e| io_error(database_path, e))?;
+    }
+
     let database_url = format!("sqlite://{}?mode=rwc", database_path.display());
 
     // Create connection pool
     let pool = SqlitePoolOptions::new()
         .max_connections(MAX_DB_CONNECTIONS)
         .connect(&database_url)
-        .await?;
+        .await
+        .map_err(|e| {
+            if is_busy(&e) {
+                InitDbError::Locked(database_path.to_path_buf())
+            } else {
+                InitDbError::Open(e)
+            }
+        })?;
 
     // Run migrations
-    sqlx::migrate!("./migrations").run(&pool).await?;
+    run_migrations(&pool, &MIGRATOR, database_path).await?;
+
+    Ok((pool, lock))
+}
 
-    Ok(pool)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a migrator from SQL files written to a temporary directory
+    async fn migrator_with(dir: &Path, migrations: &[(&str, &str)]) -> Migrator {
+        for (name, sql) in migrations {
+            std::fs::write(dir.join(name), sql).unwrap();
+        }
+        Migrator::new(dir).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_broken_migration_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let migrator = migrator_with(
+            dir.path(),
+            &[("20990101000000_broken.sql", "CREATE TABLE broken (;")],
+        )
+        .await;
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+
+        let err = run_migrations(&pool, &migrator, Path::new("nexus.db"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, InitDbError::Migration(_)), "{:?}", err);
+        assert!(err.to_string().starts_with(ERR_DB_MIGRATION));
+    }
+
+    #[tokio::test]
+    async fn test_database_from_newer_server_reported() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        run_migrations(&pool, &MIGRATOR, Path::new("nexus.db"))
+            .await
+            .unwrap();
+
+        // An older server only knows some of the applied migrations
+        let dir = tempfile::tempdir().unwrap();
+        let older = migrator_with(dir.path(), &[("20251130000000_initial.sql", "SELECT 1;")]).await;
+
+        let err = run_migrations(&pool, &older, Path::new("nexus.db"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().starts_with(ERR_DB_NEWER_VERSION), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_second_server_on_same_database_fails_fast() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data").join(DATABASE_FILENAME);
+
+        let (_pool, lock) = init_db(&path).await.unwrap();
+        let err = init_db(&path).await.unwrap_err();
+        assert!(matches!(&err, InitDbError::Locked(locked) if locked == &path));
+        assert!(err.to_string().starts_with(ERR_DB_LOCKED));
+
+        // The lock goes away with the server holding it
+        drop(lock);
+        assert!(lock_database(&path).is_ok());
+    }
 }
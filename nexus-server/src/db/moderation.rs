@@ -0,0 +1,181 @@
+//! Moderation log database operations
+
+use super::sql::{
+    SQL_CLEAR_MODERATION_LOG_TEXT_BEFORE, SQL_INSERT_MODERATION_LOG, SQL_SELECT_MODERATION_LOG,
+};
+use crate::constants::SECONDS_PER_DAY;
+use sqlx::SqlitePool;
+use std::io;
+
+/// A moderation action as recorded in the log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModerationEntry {
+    /// Username of the moderator who acted
+    pub moderator: String,
+    /// What was done (one of the `MODERATION_ACTION_*` constants)
+    pub action: String,
+    /// Username whose content was acted on
+    pub target_username: String,
+    /// Chat message the action applied to, if any
+    pub message_id: Option<u64>,
+    /// Text of the affected message, kept so the action can be reviewed later
+    /// (empty once it is past the chat retention age)
+    pub message: String,
+}
+
+/// Database interface for the moderation log
+///
+/// Entries are never removed, so moderator actions stay accountable after
+/// the affected messages are gone. The removed text itself follows the chat
+/// retention age, so it isn't kept longer than the chat it came from.
+#[derive(Clone)]
+pub struct ModerationDb {
+    pool: SqlitePool,
+}
+
+impl ModerationDb {
+    /// Create a new ModerationDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Append a moderation action to the log
+    pub async fn record(&self, entry: &ModerationEntry) -> io::Result<()> {
+        sqlx::query(SQL_INSERT_MODERATION_LOG)
+            .bind(&entry.moderator)
+            .bind(&entry.action)
+            .bind(&entry.target_username)
+            .bind(entry.message_id.map(|id| id as i64))
+            .bind(&entry.message)
+            .bind(chrono::Utc::now().timestamp())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Clear the message text of entries older than `max_age_days`
+    ///
+    /// The entries themselves are kept. A limit of 0 keeps all text.
+    ///
+    /// Returns the number of entries cleared.
+    pub async fn clear_text_older_than(&self, max_age_days: u32) -> io::Result<u64> {
+        if max_age_days == 0 {
+            return Ok(0);
+        }
+
+        let cutoff = chrono::Utc::now().timestamp() - i64::from(max_age_days) * SECONDS_PER_DAY;
+        let result = sqlx::query(SQL_CLEAR_MODERATION_LOG_TEXT_BEFORE)
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Get the newest `limit` log entries, oldest first
+    ///
+    /// Each entry comes with the Unix time it was recorded.
+    pub async fn recent(&self, limit: usize) -> io::Result<Vec<(i64, ModerationEntry)>> {
+        let rows = sqlx::query_as::<_, (String, String, String, Option<i64>, String, i64)>(
+            SQL_SELECT_MODERATION_LOG,
+        )
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .rev()
+            .map(
+                |(moderator, action, target_username, message_id, message, created_at)| {
+                    let entry = ModerationEntry {
+                        moderator,
+                        action,
+                        target_username,
+                        message_id: message_id.map(|id| id as u64),
+                        message,
+                    };
+                    (created_at, entry)
+                },
+            )
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MODERATION_ACTION_DELETE_MESSAGE;
+    use crate::db::testing::create_test_db;
+
+    fn entry(moderator: &str, message_id: u64) -> ModerationEntry {
+        ModerationEntry {
+            moderator: moderator.to_string(),
+            action: MODERATION_ACTION_DELETE_MESSAGE.to_string(),
+            target_username: "alice".to_string(),
+            message_id: Some(message_id),
+            message: format!("message {message_id}"),
+        }
+    }
+
+    async fn recent_entries(moderation_db: &ModerationDb, limit: usize) -> Vec<ModerationEntry> {
+        let recent = moderation_db.recent(limit).await.unwrap();
+        recent.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    #[tokio::test]
+    async fn test_moderation_log_round_trip() {
+        let pool = create_test_db().await;
+        let moderation_db = ModerationDb::new(pool);
+
+        assert!(moderation_db.recent(10).await.unwrap().is_empty());
+
+        moderation_db.record(&entry("mod", 1)).await.unwrap();
+        moderation_db.record(&entry("admin", 2)).await.unwrap();
+        moderation_db.record(&entry("mod", 3)).await.unwrap();
+
+        assert_eq!(
+            recent_entries(&moderation_db, 10).await,
+            vec![entry("mod", 1), entry("admin", 2), entry("mod", 3)]
+        );
+        assert_eq!(
+            recent_entries(&moderation_db, 2).await,
+            vec![entry("admin", 2), entry("mod", 3)]
+        );
+
+        // Entries carry the time they were recorded
+        let (created_at, _) = moderation_db.recent(1).await.unwrap().remove(0);
+        assert!(created_at > 0);
+    }
+
+    #[tokio::test]
+    async fn test_clear_text_older_than() {
+        let pool = create_test_db().await;
+        let moderation_db = ModerationDb::new(pool.clone());
+        moderation_db.record(&entry("mod", 1)).await.unwrap();
+        moderation_db.record(&entry("mod", 2)).await.unwrap();
+        sqlx::query("UPDATE moderation_log SET created_at = created_at - ? WHERE message_id = 1")
+            .bind(31 * SECONDS_PER_DAY)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(moderation_db.clear_text_older_than(0).await.unwrap(), 0);
+        assert_eq!(moderation_db.clear_text_older_than(30).await.unwrap(), 1);
+        assert_eq!(moderation_db.clear_text_older_than(30).await.unwrap(), 0);
+
+        // The action is still on record, only the old text is gone
+        let cleared = ModerationEntry {
+            message: String::new(),
+            ..entry("mod", 1)
+        };
+        assert_eq!(
+            recent_entries(&moderation_db, 10).await,
+            vec![cleared, entry("mod", 2)]
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! Info page database operations
+
+use super::sql::{SQL_DELETE_PAGE, SQL_SELECT_PAGE, SQL_SELECT_PAGE_NAMES, SQL_UPSERT_PAGE};
+use nexus_common::validators;
+use sqlx::SqlitePool;
+use std::io;
+
+/// An info page as stored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    /// Page name as the admin last saved it
+    pub name: String,
+    /// Page text
+    pub content: String,
+}
+
+/// Database interface for info pages
+#[derive(Clone)]
+pub struct PageDb {
+    pool: SqlitePool,
+}
+
+impl PageDb {
+    /// Create a new PageDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get a page by name (case-insensitive), or None if there is no such page
+    pub async fn get_page(&self, name: &str) -> io::Result<Option<Page>> {
+        let row = sqlx::query_as::<_, (String, String)>(SQL_SELECT_PAGE)
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(row.map(|(name, content)| Page { name, content }))
+    }
+
+    /// List page names in alphabetical order
+    pub async fn list_pages(&self) -> io::Result<Vec<String>> {
+        sqlx::query_scalar::<_, String>(SQL_SELECT_PAGE_NAMES)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))
+    }
+
+    /// Create a page or replace the content of an existing one
+    ///
+    /// Saving under a different capitalization renames the page to match.
+    pub async fn set_page(&self, name: &str, content: &str) -> io::Result<()> {
+        // Validate name and content (failsafe - handlers should also validate)
+        if let Err(e) = validators::validate_page_name(name) {
+            return Err(io::Error::other(format!("{e:?}")));
+        }
+        if let Err(e) = validators::validate_page_content(content) {
+            return Err(io::Error::other(format!("{e:?}")));
+        }
+
+        sqlx::query(SQL_UPSERT_PAGE)
+            .bind(name)
+            .bind(content)
+            .bind(chrono::Utc::now().timestamp())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Delete a page by name (case-insensitive)
+    ///
+    /// Returns false if there was no such page.
+    pub async fn delete_page(&self, name: &str) -> io::Result<bool> {
+        let result = sqlx::query(SQL_DELETE_PAGE)
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+
+    #[tokio::test]
+    async fn test_pages_round_trip() {
+        let pool = create_test_db().await;
+        let page_db = PageDb::new(pool);
+
+        assert!(page_db.list_pages().await.unwrap().is_empty());
+        assert!(page_db.get_page("help").await.unwrap().is_none());
+
+        page_db.set_page("news", "Game night Friday").await.unwrap();
+        page_db
+            .set_page("Help", "Type / for commands")
+            .await
+            .unwrap();
+        assert_eq!(
+            page_db.list_pages().await.unwrap(),
+            vec!["Help".to_string(), "news".to_string()]
+        );
+
+        let page = page_db.get_page("HELP").await.unwrap().unwrap();
+        assert_eq!(page.name, "Help");
+        assert_eq!(page.content, "Type / for commands");
+
+        assert!(page_db.delete_page("NEWS").await.unwrap());
+        assert!(!page_db.delete_page("news").await.unwrap());
+        assert_eq!(
+            page_db.list_pages().await.unwrap(),
+            vec!["Help".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_page_replaces_regardless_of_case() {
+        let pool = create_test_db().await;
+        let page_db = PageDb::new(pool);
+
+        page_db.set_page("rules", "Old").await.unwrap();
+        page_db.set_page("Rules", "New\nText").await.unwrap();
+
+        assert_eq!(
+            page_db.list_pages().await.unwrap(),
+            vec!["Rules".to_string()]
+        );
+        let page = page_db.get_page("rules").await.unwrap().unwrap();
+        assert_eq!(page.name, "Rules");
+        assert_eq!(page.content, "New\nText");
+    }
+
+    #[tokio::test]
+    async fn test_set_page_rejects_invalid_input() {
+        let pool = create_test_db().await;
+        let page_db = PageDb::new(pool);
+
+        assert!(page_db.set_page("two words", "Text").await.is_err());
+        assert!(page_db.set_page("help", "").await.is_err());
+        assert!(page_db.list_pages().await.unwrap().is_empty());
+    }
+}
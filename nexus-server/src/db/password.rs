@@ -98,6 +98,14 @@ pub fn verify_password(password: &str, password_hash: &str) -> Result<bool, Pass
     }
 }
 
+/// Whether a string is an Argon2 hash in PHC format that `verify_password` can check
+///
+/// Used to accept password hashes carried over from another Nexus server.
+pub fn is_password_hash(password_hash: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .is_ok_and(|hash| argon2::Algorithm::new(hash.algorithm.as_str()).is_ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +135,17 @@ mod tests {
         assert!(verify_password(password, &hash1).unwrap());
         assert!(verify_password(password, &hash2).unwrap());
     }
+
+    #[test]
+    fn test_is_password_hash() {
+        let hash = hash_password("password").unwrap();
+        assert!(is_password_hash(&hash));
+
+        assert!(!is_password_hash("password"));
+        assert!(!is_password_hash(""));
+        // PHC format, but not an algorithm verify_password understands
+        assert!(!is_password_hash(
+            "$pbkdf2-sha256$i=1000$c2FsdHNhbHQ$aGFzaGhhc2hoYXNoaGFzaA"
+        ));
+    }
 }
@@ -19,6 +19,10 @@ pub enum Permission {
     ChatTopic,
     /// Permission to edit chat topic
     ChatTopicEdit,
+    /// Permission to pin and unpin chat messages
+    ChatPin,
+    /// Permission to delete other users' chat messages
+    ChatModerate,
     /// Permission to send broadcast messages
     UserBroadcast,
     /// Permission to create users
@@ -57,6 +61,8 @@ impl Permission {
             "chat_receive" => Some(Permission::ChatReceive),
             "chat_topic" => Some(Permission::ChatTopic),
             "chat_topic_edit" => Some(Permission::ChatTopicEdit),
+            "chat_pin" => Some(Permission::ChatPin),
+            "chat_moderate" => Some(Permission::ChatModerate),
             "user_broadcast" => Some(Permission::UserBroadcast),
             "user_create" => Some(Permission::UserCreate),
             "user_delete" => Some(Permission::UserDelete),
@@ -66,8 +72,49 @@ impl Permission {
             _ => None,
         }
     }
+
+    /// Whether this permission gates a command the server can switch off
+    ///
+    /// `chat_receive` and `chat_topic` only control what a user is sent, so
+    /// there is no command to disable.
+    pub fn gates_command(self) -> bool {
+        !matches!(self, Permission::ChatReceive | Permission::ChatTopic)
+    }
 }
 
+/// Permissions that do nothing useful without another one, with the one each needs
+///
+/// Sending chat or acting on messages needs chat to be received, and managing
+/// users needs the user list to pick them from. Prerequisites have no
+/// prerequisites of their own.
+pub const PERMISSION_PREREQUISITES: &[(Permission, Permission)] = &[
+    (Permission::ChatSend, Permission::ChatReceive),
+    (Permission::ChatPin, Permission::ChatReceive),
+    (Permission::ChatModerate, Permission::ChatReceive),
+    (Permission::UserEdit, Permission::UserList),
+    (Permission::UserDelete, Permission::UserList),
+    (Permission::UserKick, Permission::UserList),
+];
+
+/// Permissions that show the chat topic (any one is enough)
+///
+/// Setting the topic implies seeing it, so `chat_topic_edit` alone shows the
+/// topic too; `chat_topic` alone shows it without allowing changes.
+pub const TOPIC_VIEW_PERMISSIONS: &[Permission] =
+    &[Permission::ChatTopic, Permission::ChatTopicEdit];
+
+/// Permissions every guest session holds
+///
+/// Guests can take part in public chat and see who is online, but nothing
+/// that reaches other users privately or changes the server.
+pub const GUEST_PERMISSIONS: &[Permission] = &[
+    Permission::UserList,
+    Permission::UserInfo,
+    Permission::ChatSend,
+    Permission::ChatReceive,
+    Permission::ChatTopic,
+];
+
 /// A set of permissions for a user
 ///
 /// This struct wraps a `HashSet<Permission>` to provide an efficient way to
@@ -113,15 +160,31 @@ impl Permissions {
     /// # Arguments
     ///
     /// * `permission` - The permission to add to the set
-    ///
-    /// # Note
-    ///
-    /// This method is primarily used in tests to build permission sets.
-    /// Production code typically uses `set_permissions()` from the database layer.
-    #[cfg_attr(not(test), allow(dead_code))]
     pub fn add(&mut self, permission: Permission) {
         self.permissions.insert(permission);
     }
+
+    /// Whether the set contains a permission
+    pub fn contains(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    /// Prerequisites of permissions in the set that the set lacks
+    ///
+    /// Each missing prerequisite is listed once, in the order of
+    /// `PERMISSION_PREREQUISITES`.
+    pub fn missing_prerequisites(&self) -> Vec<Permission> {
+        let mut missing = Vec::new();
+        for &(permission, prerequisite) in PERMISSION_PREREQUISITES {
+            if self.contains(permission)
+                && !self.contains(prerequisite)
+                && !missing.contains(&prerequisite)
+            {
+                missing.push(prerequisite);
+            }
+        }
+        missing
+    }
 }
 
 impl Default for Permissions {
@@ -144,6 +207,8 @@ mod tests {
         assert_eq!(Permission::ChatReceive.as_str(), "chat_receive");
         assert_eq!(Permission::ChatTopic.as_str(), "chat_topic");
         assert_eq!(Permission::ChatTopicEdit.as_str(), "chat_topic_edit");
+        assert_eq!(Permission::ChatPin.as_str(), "chat_pin");
+        assert_eq!(Permission::ChatModerate.as_str(), "chat_moderate");
         assert_eq!(Permission::UserBroadcast.as_str(), "user_broadcast");
         assert_eq!(Permission::UserCreate.as_str(), "user_create");
         assert_eq!(Permission::UserDelete.as_str(), "user_delete");
@@ -167,6 +232,11 @@ mod tests {
             Permission::parse("chat_topic_edit"),
             Some(Permission::ChatTopicEdit)
         );
+        assert_eq!(Permission::parse("chat_pin"), Some(Permission::ChatPin));
+        assert_eq!(
+            Permission::parse("chat_moderate"),
+            Some(Permission::ChatModerate)
+        );
         assert_eq!(
             Permission::parse("user_broadcast"),
             Some(Permission::UserBroadcast)
@@ -197,6 +267,14 @@ mod tests {
         assert_eq!(Permission::parse("admin"), None);
     }
 
+    #[test]
+    fn test_permission_gates_command() {
+        assert!(Permission::UserBroadcast.gates_command());
+        assert!(Permission::ChatSend.gates_command());
+        assert!(!Permission::ChatReceive.gates_command());
+        assert!(!Permission::ChatTopic.gates_command());
+    }
+
     #[test]
     fn test_permissions_new() {
         let perms = Permissions::new();
@@ -240,6 +318,43 @@ mod tests {
         assert!(vec.contains(&Permission::UserInfo));
     }
 
+    #[test]
+    fn test_missing_prerequisites() {
+        let mut perms = Permissions::new();
+        perms.add(Permission::ChatSend);
+        perms.add(Permission::ChatModerate);
+        perms.add(Permission::UserEdit);
+        assert_eq!(
+            perms.missing_prerequisites(),
+            vec![Permission::ChatReceive, Permission::UserList]
+        );
+
+        perms.add(Permission::ChatReceive);
+        perms.add(Permission::UserList);
+        assert!(perms.missing_prerequisites().is_empty());
+
+        // Prerequisites on their own need nothing, and editing the topic
+        // shows it without chat_topic
+        let mut perms = Permissions::new();
+        perms.add(Permission::ChatTopicEdit);
+        perms.add(Permission::UserList);
+        assert!(perms.missing_prerequisites().is_empty());
+    }
+
+    #[test]
+    fn test_prerequisites_are_one_level_deep() {
+        // Adding the missing prerequisites once must leave nothing missing
+        for &(_, prerequisite) in PERMISSION_PREREQUISITES {
+            assert!(
+                !PERMISSION_PREREQUISITES
+                    .iter()
+                    .any(|&(permission, _)| permission == prerequisite),
+                "{} is a prerequisite with prerequisites of its own",
+                prerequisite.as_str()
+            );
+        }
+    }
+
     #[test]
     fn test_permission_enum_matches_all_permissions() {
         // Verify that every permission in ALL_PERMISSIONS can be parsed
@@ -253,6 +368,8 @@ mod tests {
 
         // Verify that every Permission variant is in ALL_PERMISSIONS
         let all_variants = [
+            Permission::ChatModerate,
+            Permission::ChatPin,
             Permission::ChatReceive,
             Permission::ChatSend,
             Permission::ChatTopic,
@@ -45,6 +45,131 @@ pub const SQL_GET_CHAT_STATE: &str = "SELECT value FROM chat_state WHERE key = ?
 /// **Note:** Uses `INSERT OR REPLACE` to upsert the value.
 pub const SQL_SET_CHAT_STATE: &str = "INSERT OR REPLACE INTO chat_state (key, value) VALUES (?, ?)";
 
+// ========================================================================
+// Chat History Query Operations
+// ========================================================================
+
+/// Record a chat message in the history
+///
+/// **Parameters:**
+/// 1. `message_id: i64` - Server-assigned message id
+/// 2. `username: &str` - Sender's username
+/// 3. `message: &str` - Message text
+/// 4. `created_at: i64` - Unix timestamp (seconds)
+pub const SQL_INSERT_CHAT_HISTORY: &str =
+    "INSERT INTO chat_history (message_id, username, message, created_at) VALUES (?, ?, ?, ?)";
+
+/// Replace the text of a recorded chat message
+///
+/// **Parameters:**
+/// 1. `message: &str` - New message text
+/// 2. `message_id: i64` - Server-assigned message id
+pub const SQL_UPDATE_CHAT_HISTORY_MESSAGE: &str =
+    "UPDATE chat_history SET message = ? WHERE message_id = ?";
+
+/// Delete a recorded chat message
+///
+/// **Parameters:**
+/// 1. `message_id: i64` - Server-assigned message id
+pub const SQL_DELETE_CHAT_HISTORY_MESSAGE: &str = "DELETE FROM chat_history WHERE message_id = ?";
+
+/// Delete chat history older than a cutoff
+///
+/// **Parameters:**
+/// 1. `cutoff: i64` - Unix timestamp (seconds); older rows are removed
+pub const SQL_DELETE_CHAT_HISTORY_BEFORE: &str = "DELETE FROM chat_history WHERE created_at < ?";
+
+/// Select a page of chat history, newest first
+///
+/// **Parameters:**
+/// 1. `before_message_id: i64` - Only rows with a smaller message id
+/// 2. `limit: i64` - Maximum number of rows
+///
+/// **Returns:** `message_id`, `username`, `message`, `created_at`
+pub const SQL_SELECT_CHAT_HISTORY_BEFORE: &str =
+    "SELECT message_id, username, message, created_at FROM chat_history
+    WHERE message_id < ? ORDER BY message_id DESC LIMIT ?";
+
+/// Delete all but the newest chat history rows
+///
+/// **Parameters:**
+/// 1. `keep: i64` - Number of newest rows to keep
+///
+/// **Note:** Rows are ordered by `id`, which follows insertion order.
+pub const SQL_DELETE_CHAT_HISTORY_BEYOND_COUNT: &str = "DELETE FROM chat_history
+    WHERE id NOT IN (SELECT id FROM chat_history ORDER BY id DESC LIMIT ?)";
+
+// ========================================================================
+// Moderation Log Query Operations
+// ========================================================================
+
+/// Append a moderation action to the log
+///
+/// **Parameters:**
+/// 1. `moderator: &str` - Moderator's username
+/// 2. `action: &str` - Action name
+/// 3. `target_username: &str` - Username whose content was acted on
+/// 4. `message_id: Option<i64>` - Affected chat message id
+/// 5. `message: &str` - Affected message text
+/// 6. `created_at: i64` - Unix timestamp (seconds)
+pub const SQL_INSERT_MODERATION_LOG: &str = "INSERT INTO moderation_log
+    (moderator, action, target_username, message_id, message, created_at)
+    VALUES (?, ?, ?, ?, ?, ?)";
+
+/// Clear the message text of moderation log entries older than a cutoff
+///
+/// **Parameters:**
+/// 1. `cutoff: i64` - Unix timestamp (seconds); older rows lose their text
+pub const SQL_CLEAR_MODERATION_LOG_TEXT_BEFORE: &str =
+    "UPDATE moderation_log SET message = '' WHERE created_at < ? AND message != ''";
+
+/// Select the newest moderation log entries, newest first
+///
+/// **Parameters:**
+/// 1. `limit: i64` - Maximum number of rows
+///
+/// **Returns:** `moderator`, `action`, `target_username`, `message_id`, `message`, `created_at`
+pub const SQL_SELECT_MODERATION_LOG: &str =
+    "SELECT moderator, action, target_username, message_id, message, created_at FROM moderation_log
+    ORDER BY id DESC LIMIT ?";
+
+// ========================================================================
+// Info Page Query Operations
+// ========================================================================
+
+/// Get an info page by name (case-insensitive)
+///
+/// **Parameters:**
+/// 1. `name: &str` - Page name
+///
+/// **Returns:** `(name: String, content: String)`
+pub const SQL_SELECT_PAGE: &str = "SELECT name, content FROM pages WHERE name = ?";
+
+/// List info page names in alphabetical order
+///
+/// **Parameters:** None
+///
+/// **Returns:** `(name: String)` per page
+pub const SQL_SELECT_PAGE_NAMES: &str = "SELECT name FROM pages ORDER BY LOWER(name)";
+
+/// Create an info page or replace its content
+///
+/// **Parameters:**
+/// 1. `name: &str` - Page name (replaces the stored spelling on update)
+/// 2. `content: &str` - Page text
+/// 3. `updated_at: i64` - Unix timestamp (seconds)
+pub const SQL_UPSERT_PAGE: &str = "INSERT INTO pages (name, content, updated_at) VALUES (?, ?, ?)
+    ON CONFLICT(name) DO UPDATE SET
+        name = excluded.name, content = excluded.content, updated_at = excluded.updated_at";
+
+/// Delete an info page by name (case-insensitive)
+///
+/// **Parameters:**
+/// 1. `name: &str` - Page name
+///
+/// **Returns:** 0 rows affected if no such page exists
+pub const SQL_DELETE_PAGE: &str = "DELETE FROM pages WHERE name = ?";
+
 // ========================================================================
 // User Query Operations
 // ========================================================================
@@ -75,7 +200,6 @@ pub const SQL_SELECT_USER_BY_USERNAME: &str = "SELECT id, username, password_has
 /// 1. `user_id: i64` - User ID to look up
 ///
 /// **Returns:** `(id, username, password_hash, is_admin, enabled, created_at)`
-#[allow(dead_code)] // Used in tests
 pub const SQL_SELECT_USER_BY_ID: &str =
     "SELECT id, username, password_hash, is_admin, enabled, created_at FROM users WHERE id = ?";
 
@@ -142,6 +266,89 @@ pub const SQL_DELETE_PERMISSIONS: &str = "DELETE FROM user_permissions WHERE use
 pub const SQL_INSERT_PERMISSION: &str =
     "INSERT INTO user_permissions (user_id, permission) VALUES (?, ?)";
 
+// ========================================================================
+// Server Rules Operations
+// ========================================================================
+
+/// Check whether a user has accepted the current server rules
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - User ID
+///
+/// **Returns:** `(rules_accepted: bool)`
+pub const SQL_SELECT_RULES_ACCEPTED: &str = "SELECT rules_accepted FROM users WHERE id = ?";
+
+/// Record that a user accepted the current server rules
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - User ID
+pub const SQL_SET_RULES_ACCEPTED: &str = "UPDATE users SET rules_accepted = 1 WHERE id = ?";
+
+/// Clear every user's rules acceptance (used when the rules change)
+///
+/// **Parameters:** None
+pub const SQL_RESET_RULES_ACCEPTED: &str = "UPDATE users SET rules_accepted = 0";
+
+// ========================================================================
+// User Preference Operations
+// ========================================================================
+
+/// Get the stored preferences for a user
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - User ID
+///
+/// **Returns:** `(preferences: String)` - JSON object
+pub const SQL_SELECT_PREFERENCES: &str =
+    "SELECT preferences FROM user_preferences WHERE user_id = ?";
+
+/// Store preferences for a user (insert or replace)
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - User ID
+/// 2. `preferences: &str` - JSON object
+pub const SQL_SET_PREFERENCES: &str =
+    "INSERT OR REPLACE INTO user_preferences (user_id, preferences) VALUES (?, ?)";
+
+// ========================================================================
+// User Profile Operations
+// ========================================================================
+
+/// Get a user's public profile
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - User ID
+///
+/// **Returns:** `(bio: String, pronouns: String)` - empty when not set
+pub const SQL_SELECT_PROFILE: &str = "SELECT bio, pronouns FROM users WHERE id = ?";
+
+/// Replace a user's public profile
+///
+/// **Parameters:**
+/// 1. `bio: &str` - Bio (empty to clear)
+/// 2. `pronouns: &str` - Pronouns (empty to clear)
+/// 3. `user_id: i64` - User ID
+pub const SQL_SET_PROFILE: &str = "UPDATE users SET bio = ?, pronouns = ? WHERE id = ?";
+
+// ========================================================================
+// Last Seen Operations
+// ========================================================================
+
+/// Get when a user was last seen
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - User ID
+///
+/// **Returns:** `(last_seen: Option<i64>)` - Unix timestamp, NULL if never logged in
+pub const SQL_SELECT_LAST_SEEN: &str = "SELECT last_seen FROM users WHERE id = ?";
+
+/// Record when a user was last seen
+///
+/// **Parameters:**
+/// 1. `last_seen: i64` - Unix timestamp (seconds)
+/// 2. `user_id: i64` - User ID
+pub const SQL_SET_LAST_SEEN: &str = "UPDATE users SET last_seen = ? WHERE id = ?";
+
 // ========================================================================
 // User Mutation Operations
 // ========================================================================
@@ -209,10 +416,48 @@ pub const SQL_UPDATE_USER: &str = "UPDATE users
 /// simultaneously delete each other, leaving zero admins.
 ///
 /// **Cascade:** Foreign key constraints automatically delete associated
-/// permissions when the user is deleted.
+/// permissions and preferences when the user is deleted.
 pub const SQL_DELETE_USER_ATOMIC: &str = "DELETE FROM users
      WHERE id = ?
      AND (
          is_admin = 0
          OR (SELECT COUNT(*) FROM users WHERE is_admin = 1) > 1
      )";
+
+/// Promote an enabled user to admin
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - User ID to promote
+///
+/// **Returns:** 0 rows affected if the user is missing or disabled
+pub const SQL_PROMOTE_ENABLED_USER: &str =
+    "UPDATE users SET is_admin = 1 WHERE id = ? AND enabled = 1";
+
+/// Demote an admin unless they are the last enabled admin
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - Admin stepping down
+///
+/// **Atomic Protection:** Returns 0 rows affected when the user is not an
+/// admin or no other enabled admin exists, so a handoff can never leave the
+/// server without one.
+pub const SQL_DEMOTE_ADMIN_ATOMIC: &str = "UPDATE users
+     SET is_admin = 0
+     WHERE id = ?
+     AND is_admin = 1
+     AND (SELECT COUNT(*) FROM users WHERE is_admin = 1 AND enabled = 1) > 1";
+
+// ========================================================================
+// Maintenance Operations
+// ========================================================================
+
+/// Refresh query planner statistics for tables whose contents have shifted
+///
+/// Cheap when little has changed; SQLite decides which tables need it.
+pub const SQL_PRAGMA_OPTIMIZE: &str = "PRAGMA optimize";
+
+/// Rebuild the database file, returning free pages to the filesystem
+///
+/// Holds the write lock for the whole rewrite and needs free disk space
+/// about the size of the database.
+pub const SQL_VACUUM: &str = "VACUUM";
@@ -29,6 +29,51 @@ pub struct UserAccount {
     pub created_at: i64,
 }
 
+impl UserAccount {
+    /// Stand-in account for a guest, never stored in the database
+    ///
+    /// Guests have no row, so `id` is a placeholder no row uses. They are
+    /// never admins, and their "account" dates from their login.
+    pub fn guest(username: &str, created_at: i64) -> Self {
+        Self {
+            id: 0,
+            username: username.to_string(),
+            hashed_password: String::new(),
+            is_admin: false,
+            enabled: true,
+            created_at,
+        }
+    }
+}
+
+/// Public profile a user sets for themselves
+///
+/// Empty fields are unset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserProfile {
+    pub bio: String,
+    pub pronouns: String,
+}
+
+/// Outcome of [`UserDb::transfer_admin`]
+#[derive(Debug)]
+pub enum AdminTransfer {
+    /// Target is now an admin (and the caller stepped down if asked to)
+    Transferred(UserAccount),
+    /// Caller is no longer an admin
+    NotAdmin,
+    /// No account with that username
+    TargetNotFound,
+    /// Target is the caller's own account
+    TargetIsSelf,
+    /// Target account is disabled and could not use admin rights
+    TargetDisabled,
+    /// Target is already an admin and the caller is not stepping down
+    TargetAlreadyAdmin,
+    /// Caller is the last enabled admin and cannot step down
+    LastAdmin,
+}
+
 /// Database operations for user accounts
 ///
 /// Provides methods for creating, reading, updating, and deleting user accounts,
@@ -205,28 +250,17 @@ impl UserDb {
     ///
     /// This operation is atomic - uses a transaction to ensure either all permissions
     /// are updated or none are (prevents partial permission states on errors).
-    pub async fn set_permissions(
+    ///
+    /// Online sessions keep their own copy of the permissions, so callers
+    /// refresh it with `UserManager::reload_permissions` afterwards.
+    pub(crate) async fn set_permissions(
         &self,
         user_id: i64,
         permissions: &Permissions,
     ) -> Result<(), sqlx::Error> {
         // Use a transaction to make this atomic
         let mut tx = self.pool.begin().await?;
-
-        // Delete existing permissions
-        sqlx::query(SQL_DELETE_PERMISSIONS)
-            .bind(user_id)
-            .execute(&mut *tx)
-            .await?;
-
-        // Insert new permissions
-        for perm in permissions.to_vec() {
-            sqlx::query(SQL_INSERT_PERMISSION)
-                .bind(user_id)
-                .bind(perm.as_str())
-                .execute(&mut *tx)
-                .await?;
-        }
+        replace_permissions(&mut tx, user_id, permissions).await?;
 
         // Commit the transaction
         tx.commit().await?;
@@ -361,6 +395,12 @@ impl UserDb {
     /// Update a user account
     /// Returns Ok(true) if user was updated, Ok(false) if user didn't exist or update was blocked
     ///
+    /// `requested_permissions` replace the stored permissions in the same
+    /// transaction as the account update, so either both are written or
+    /// neither is (none are stored if the account ends up an admin). Online
+    /// sessions keep their own copy, so the caller reloads it afterwards with
+    /// `UserManager::reload_permissions`.
+    ///
     /// # Atomic Protection
     ///
     /// This method uses atomic SQL to prevent race conditions in two scenarios:
@@ -441,6 +481,7 @@ impl UserDb {
         // The SQL includes conditions to prevent:
         // 1. Disabling the last enabled admin
         // 2. Demoting the last admin
+        let mut tx = self.pool.begin().await?;
         let result = sqlx::query(SQL_UPDATE_USER)
             .bind(final_username)
             .bind(final_password)
@@ -449,37 +490,264 @@ impl UserDb {
             .bind(user.id)
             .bind(final_enabled) // Final enabled status for the "enabling" check
             .bind(final_is_admin) // Final admin status for the "promoting" check
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
 
         // Check if the update was blocked (0 rows affected means constraints prevented update)
         if result.rows_affected() == 0 {
-            // Update was blocked - could be last admin protection or user not found
-            // Check if user still exists to distinguish between the cases
-            if self.get_user_by_username(username).await?.is_some() {
-                // User exists but update was blocked - must be last admin protection
-                return Ok(false);
-            }
-            // User doesn't exist
+            // Update was blocked by last admin protection, or the user is gone
+            tx.rollback().await?;
             return Ok(false);
         }
 
-        // Update permissions if provided
-        if let Some(perms) = requested_permissions {
-            // Only set permissions for non-admin users
-            if !final_is_admin {
-                self.set_permissions(user.id, perms).await?;
+        if let Some(permissions) = requested_permissions {
+            // Admins get every permission, so none are stored for them
+            let permissions = if final_is_admin {
+                &Permissions::new()
             } else {
-                // Clear permissions for admin users (they get all automatically)
-                sqlx::query(SQL_DELETE_PERMISSIONS)
-                    .bind(user.id)
-                    .execute(&self.pool)
-                    .await?;
-            }
+                permissions
+            };
+            replace_permissions(&mut tx, user.id, permissions).await?;
         }
 
+        tx.commit().await?;
+
         Ok(true)
     }
+
+    /// Make another account an admin, optionally demoting the caller
+    ///
+    /// Runs in a single transaction: the target is promoted (and their stored
+    /// permissions cleared, since admins get all of them) before the caller
+    /// is demoted, and the demotion is guarded by [`SQL_DEMOTE_ADMIN_ATOMIC`].
+    /// Anything short of success rolls back, so the server always keeps at
+    /// least one enabled admin. A demoted caller keeps no stored permissions.
+    pub async fn transfer_admin(
+        &self,
+        from_user_id: i64,
+        to_username: &str,
+        demote_self: bool,
+    ) -> Result<AdminTransfer, sqlx::Error> {
+        // Validate username format (failsafe - handlers should also validate)
+        if let Err(e) = validators::validate_username(to_username) {
+            return Err(sqlx::Error::Protocol(format!("{:?}", e)));
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        let caller: Option<(i64, String, String, bool, bool, i64)> =
+            sqlx::query_as(SQL_SELECT_USER_BY_ID)
+                .bind(from_user_id)
+                .fetch_optional(&mut *tx)
+                .await?;
+        if !caller.is_some_and(|(_, _, _, is_admin, _, _)| is_admin) {
+            tx.rollback().await?;
+            return Ok(AdminTransfer::NotAdmin);
+        }
+
+        let target: Option<(i64, String, String, bool, bool, i64)> =
+            sqlx::query_as(SQL_SELECT_USER_BY_USERNAME)
+                .bind(to_username)
+                .fetch_optional(&mut *tx)
+                .await?;
+        let Some((id, username, hashed_password, is_admin, enabled, created_at)) = target else {
+            tx.rollback().await?;
+            return Ok(AdminTransfer::TargetNotFound);
+        };
+        if id == from_user_id {
+            tx.rollback().await?;
+            return Ok(AdminTransfer::TargetIsSelf);
+        }
+        if !enabled {
+            tx.rollback().await?;
+            return Ok(AdminTransfer::TargetDisabled);
+        }
+        if is_admin && !demote_self {
+            tx.rollback().await?;
+            return Ok(AdminTransfer::TargetAlreadyAdmin);
+        }
+
+        sqlx::query(SQL_PROMOTE_ENABLED_USER)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(SQL_DELETE_PERMISSIONS)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        if demote_self {
+            let result = sqlx::query(SQL_DEMOTE_ADMIN_ATOMIC)
+                .bind(from_user_id)
+                .execute(&mut *tx)
+                .await?;
+            if result.rows_affected() == 0 {
+                tx.rollback().await?;
+                return Ok(AdminTransfer::LastAdmin);
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(AdminTransfer::Transferred(UserAccount {
+            id,
+            username,
+            hashed_password,
+            is_admin: true,
+            enabled,
+            created_at,
+        }))
+    }
+
+    // ========================================================================
+    // Server Rules Methods
+    // ========================================================================
+
+    /// Check whether a user has accepted the current server rules
+    ///
+    /// Returns false for unknown users.
+    pub async fn has_accepted_rules(&self, user_id: i64) -> Result<bool, sqlx::Error> {
+        let accepted: Option<bool> = sqlx::query_scalar(SQL_SELECT_RULES_ACCEPTED)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(accepted.unwrap_or(false))
+    }
+
+    /// Record that a user accepted the current server rules
+    pub async fn accept_rules(&self, user_id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query(SQL_SET_RULES_ACCEPTED)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Clear every user's rules acceptance so new rules must be accepted again
+    pub async fn reset_rules_acceptance(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(SQL_RESET_RULES_ACCEPTED)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Preference Methods
+    // ========================================================================
+
+    /// Get a user's stored preferences (a JSON object), if any were set
+    pub async fn get_preferences(&self, user_id: i64) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar(SQL_SELECT_PREFERENCES)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    /// Store a user's preferences, replacing any previous value
+    ///
+    /// Callers validate the JSON with `validators::validate_preferences`.
+    pub async fn set_preferences(
+        &self,
+        user_id: i64,
+        preferences: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(SQL_SET_PREFERENCES)
+            .bind(user_id)
+            .bind(preferences)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Profile Methods
+    // ========================================================================
+
+    /// Get a user's public profile (None for unknown users)
+    pub async fn get_profile(&self, user_id: i64) -> Result<Option<UserProfile>, sqlx::Error> {
+        let profile: Option<(String, String)> = sqlx::query_as(SQL_SELECT_PROFILE)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(profile.map(|(bio, pronouns)| UserProfile { bio, pronouns }))
+    }
+
+    /// Replace a user's public profile
+    ///
+    /// Callers validate the fields with `validators::validate_bio` and
+    /// `validators::validate_pronouns`. Returns false if the user doesn't exist.
+    pub async fn set_profile(
+        &self,
+        user_id: i64,
+        profile: &UserProfile,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(SQL_SET_PROFILE)
+            .bind(&profile.bio)
+            .bind(&profile.pronouns)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // ========================================================================
+    // Last Seen Methods
+    // ========================================================================
+
+    /// Get when a user was last seen as a Unix timestamp
+    ///
+    /// Returns None for unknown users and Some(None) for accounts that have
+    /// never logged in.
+    pub async fn get_last_seen(&self, user_id: i64) -> Result<Option<Option<i64>>, sqlx::Error> {
+        let row: Option<(Option<i64>,)> = sqlx::query_as(SQL_SELECT_LAST_SEEN)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(|(last_seen,)| last_seen))
+    }
+
+    /// Record that a user was seen at the given Unix timestamp
+    ///
+    /// Updated on login and when a session disconnects. Deleted accounts are
+    /// silently ignored.
+    pub async fn set_last_seen(&self, user_id: i64, timestamp: i64) -> Result<(), sqlx::Error> {
+        sqlx::query(SQL_SET_LAST_SEEN)
+            .bind(timestamp)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Replace a user's stored permissions within a transaction
+async fn replace_permissions(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    user_id: i64,
+    permissions: &Permissions,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(SQL_DELETE_PERMISSIONS)
+        .bind(user_id)
+        .execute(&mut **tx)
+        .await?;
+
+    for perm in permissions.to_vec() {
+        sqlx::query(SQL_INSERT_PERMISSION)
+            .bind(user_id)
+            .bind(perm.as_str())
+            .execute(&mut **tx)
+            .await?;
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -741,6 +1009,51 @@ mod tests {
         assert!(!result);
     }
 
+    #[tokio::test]
+    async fn test_update_user_writes_permissions_with_account() {
+        let pool = create_test_db().await;
+        let db = UserDb::new(pool.clone());
+        let admin = db
+            .create_user("admin", "hash", true, true, &Permissions::new())
+            .await
+            .unwrap();
+        let alice = db
+            .create_user("alice", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+        let mut perms = Permissions::new();
+        perms.permissions.insert(Permission::ChatSend);
+
+        // A successful update stores the permissions too
+        let updated = db
+            .update_user("alice", None, None, None, Some(false), Some(&perms))
+            .await
+            .unwrap();
+        assert!(updated);
+        let stored = db.get_user_by_username("alice").await.unwrap().unwrap();
+        assert!(!stored.enabled);
+        assert_eq!(
+            db.get_user_permissions(alice.id).await.unwrap().permissions,
+            perms.permissions
+        );
+
+        // A blocked update (demoting the last admin) stores neither
+        let updated = db
+            .update_user("admin", None, None, Some(false), None, Some(&perms))
+            .await
+            .unwrap();
+        assert!(!updated);
+        let stored = db.get_user_by_username("admin").await.unwrap().unwrap();
+        assert!(stored.is_admin);
+        let stored_perms: Vec<(String,)> =
+            sqlx::query_as("SELECT permission FROM user_permissions WHERE user_id = ?")
+                .bind(admin.id)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert!(stored_perms.is_empty());
+    }
+
     #[tokio::test]
     async fn test_set_permissions_replaces_existing() {
         let pool = create_test_db().await;
@@ -891,6 +1204,86 @@ mod tests {
         assert_eq!(count_admins(&pool).await, 1);
     }
 
+    #[tokio::test]
+    async fn test_transfer_admin_single_admin_handoff() {
+        let pool = create_test_db().await;
+        let db = UserDb::new(pool.clone());
+
+        let owner = db
+            .create_user("owner", "hash", true, true, &Permissions::new())
+            .await
+            .unwrap();
+        let mut perms = Permissions::new();
+        perms.add(Permission::ChatSend);
+        let heir = db
+            .create_user("heir", "hash", false, true, &perms)
+            .await
+            .unwrap();
+
+        let outcome = db.transfer_admin(owner.id, "HEIR", true).await.unwrap();
+        let AdminTransfer::Transferred(account) = outcome else {
+            panic!("expected transfer, got {outcome:?}");
+        };
+        assert_eq!(account.id, heir.id);
+        assert_eq!(account.username, "heir");
+
+        assert!(db.get_user_by_id(heir.id).await.unwrap().unwrap().is_admin);
+        assert!(!db.get_user_by_id(owner.id).await.unwrap().unwrap().is_admin);
+        assert_eq!(count_admins(&pool).await, 1);
+
+        // Stored permissions are cleared once the heir is an admin
+        let (count,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM user_permissions WHERE user_id = ?")
+                .bind(heir.id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(count, 0);
+
+        // The former owner can no longer hand anything over
+        assert!(matches!(
+            db.transfer_admin(owner.id, "heir", true).await.unwrap(),
+            AdminTransfer::NotAdmin
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_admin_rejections_roll_back() {
+        let pool = create_test_db().await;
+        let db = UserDb::new(pool.clone());
+
+        let owner = db
+            .create_user("owner", "hash", true, true, &Permissions::new())
+            .await
+            .unwrap();
+        db.create_user("dormant", "hash", false, false, &Permissions::new())
+            .await
+            .unwrap();
+        db.create_user("peer", "hash", true, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            db.transfer_admin(owner.id, "nobody", true).await.unwrap(),
+            AdminTransfer::TargetNotFound
+        ));
+        assert!(matches!(
+            db.transfer_admin(owner.id, "dormant", true).await.unwrap(),
+            AdminTransfer::TargetDisabled
+        ));
+        assert!(matches!(
+            db.transfer_admin(owner.id, "Owner", true).await.unwrap(),
+            AdminTransfer::TargetIsSelf
+        ));
+        assert!(matches!(
+            db.transfer_admin(owner.id, "peer", false).await.unwrap(),
+            AdminTransfer::TargetAlreadyAdmin
+        ));
+
+        assert!(db.get_user_by_id(owner.id).await.unwrap().unwrap().is_admin);
+        assert_eq!(count_admins(&pool).await, 2);
+    }
+
     #[tokio::test]
     async fn test_can_delete_admin_when_multiple_exist() {
         let pool = create_test_db().await;
@@ -1170,4 +1563,133 @@ mod tests {
             "Admin permissions not stored in DB"
         );
     }
+
+    #[tokio::test]
+    async fn test_rules_acceptance() {
+        let pool = create_test_db().await;
+        let db = UserDb::new(pool.clone());
+
+        let alice = db
+            .create_user("alice", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+        let bob = db
+            .create_user("bob", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        // New accounts have not accepted anything yet
+        assert!(!db.has_accepted_rules(alice.id).await.unwrap());
+
+        db.accept_rules(alice.id).await.unwrap();
+        db.accept_rules(bob.id).await.unwrap();
+        assert!(db.has_accepted_rules(alice.id).await.unwrap());
+        assert!(db.has_accepted_rules(bob.id).await.unwrap());
+
+        // Changing the rules clears acceptance for everyone
+        db.reset_rules_acceptance().await.unwrap();
+        assert!(!db.has_accepted_rules(alice.id).await.unwrap());
+        assert!(!db.has_accepted_rules(bob.id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_preferences_round_trip() {
+        let pool = create_test_db().await;
+        let db = UserDb::new(pool.clone());
+
+        let user = db
+            .create_user("alice", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        // Nothing stored until the client sends preferences
+        assert_eq!(db.get_preferences(user.id).await.unwrap(), None);
+
+        db.set_preferences(user.id, r#"{"theme":"Dark"}"#)
+            .await
+            .unwrap();
+        db.set_preferences(user.id, r#"{"theme":"Light"}"#)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            db.get_preferences(user.id).await.unwrap().as_deref(),
+            Some(r#"{"theme":"Light"}"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preferences_deleted_with_user() {
+        let pool = create_test_db().await;
+        let db = UserDb::new(pool.clone());
+
+        // Keep an admin around so the user can be deleted
+        db.create_user("admin", "hash", true, true, &Permissions::new())
+            .await
+            .unwrap();
+        let user = db
+            .create_user("alice", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+        db.set_preferences(user.id, "{}").await.unwrap();
+
+        assert!(db.delete_user(user.id).await.unwrap());
+        assert_eq!(db.get_preferences(user.id).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_profile_round_trip() {
+        let pool = create_test_db().await;
+        let db = UserDb::new(pool.clone());
+
+        let user = db
+            .create_user("alice", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        // New accounts have an empty profile
+        assert_eq!(
+            db.get_profile(user.id).await.unwrap(),
+            Some(UserProfile::default())
+        );
+
+        let profile = UserProfile {
+            bio: "Retro computing fan".to_string(),
+            pronouns: "she/her".to_string(),
+        };
+        assert!(db.set_profile(user.id, &profile).await.unwrap());
+        assert_eq!(db.get_profile(user.id).await.unwrap(), Some(profile));
+
+        // Unknown users have no profile to read or write
+        assert_eq!(db.get_profile(user.id + 1).await.unwrap(), None);
+        assert!(
+            !db.set_profile(user.id + 1, &UserProfile::default())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_last_seen_round_trip() {
+        let pool = create_test_db().await;
+        let db = UserDb::new(pool.clone());
+
+        let user = db
+            .create_user("alice", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        // New accounts have never been seen
+        assert_eq!(db.get_last_seen(user.id).await.unwrap(), Some(None));
+
+        db.set_last_seen(user.id, 1_700_000_000).await.unwrap();
+        assert_eq!(
+            db.get_last_seen(user.id).await.unwrap(),
+            Some(Some(1_700_000_000))
+        );
+
+        // Unknown users have nothing to report
+        assert_eq!(db.get_last_seen(user.id + 1).await.unwrap(), None);
+        db.set_last_seen(user.id + 1, 1_700_000_000).await.unwrap();
+    }
 }
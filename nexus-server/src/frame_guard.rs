@@ -0,0 +1,318 @@
+//! Malformed frame tracking for DoS protection
+//!
+//! Scanners and broken clients send garbage that fails frame validation.
+//! This module counts those failures per IP address and temporarily blocks
+//! addresses that keep sending them, so persistent offenders stop costing a
+//! TLS handshake and a log line every few milliseconds.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use nexus_common::framing::FrameError;
+
+/// Number of tracked addresses above which stale entries are pruned
+const PRUNE_THRESHOLD: usize = 1024;
+
+/// Whether a frame error means the peer sent bytes that aren't valid Nexus
+/// frames (as opposed to the connection stalling or going away, or a
+/// well-formed request that was merely too large)
+#[must_use]
+pub fn is_malformed(error: &FrameError) -> bool {
+    !error.is_recoverable()
+        && !matches!(
+            error,
+            FrameError::Io(_) | FrameError::ConnectionClosed | FrameError::FrameTimeout
+        )
+}
+
+/// Inbound frame validation counters since startup
+#[derive(Debug, Default)]
+struct FrameMetrics {
+    /// Frames that failed validation
+    malformed_frames: AtomicU64,
+    /// Times an address was blocked
+    blocks: AtomicU64,
+    /// Connections refused because their address was blocked
+    refused_connections: AtomicU64,
+}
+
+/// Snapshot of the frame validation counters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameMetricsSnapshot {
+    pub malformed_frames: u64,
+    pub blocks: u64,
+    pub refused_connections: u64,
+}
+
+/// Malformed frame history for one address
+#[derive(Debug)]
+struct Offender {
+    /// Start of the current counting window
+    window_start: Instant,
+    /// Malformed frames seen in the current window
+    count: u32,
+    /// Block expiry, if the address is blocked
+    blocked_until: Option<Instant>,
+}
+
+/// Tracks malformed frames per IP address and blocks repeat offenders
+///
+/// Cheap to clone; clones share the same state.
+#[derive(Debug, Clone)]
+pub struct MalformedFrameTracker {
+    offenders: Arc<Mutex<HashMap<IpAddr, Offender>>>,
+    metrics: Arc<FrameMetrics>,
+    /// Malformed frames within `window` that trigger a block (0 disables blocking)
+    limit: u32,
+    window: Duration,
+    block_duration: Duration,
+}
+
+impl MalformedFrameTracker {
+    /// Create a tracker that blocks an address for `block_duration` once it
+    /// sends `limit` malformed frames within `window`
+    #[must_use]
+    pub fn new(limit: u32, window: Duration, block_duration: Duration) -> Self {
+        Self {
+            offenders: Arc::new(Mutex::new(HashMap::new())),
+            metrics: Arc::new(FrameMetrics::default()),
+            limit,
+            window,
+            block_duration,
+        }
+    }
+
+    /// How long a triggered block lasts
+    #[must_use]
+    pub fn block_duration(&self) -> Duration {
+        self.block_duration
+    }
+
+    /// Check whether connections from `ip` should be refused
+    ///
+    /// Counts the refusal when the address is blocked.
+    pub fn check_blocked(&self, ip: IpAddr) -> bool {
+        let blocked = self.is_blocked_at(ip, Instant::now());
+        if blocked {
+            self.metrics
+                .refused_connections
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        blocked
+    }
+
+    /// Count a malformed frame that doesn't count towards a block
+    ///
+    /// Used for sessions that already logged in: they are accountable users
+    /// rather than scanners, and may share an address with many others.
+    pub fn record_unattributed(&self) {
+        self.metrics
+            .malformed_frames
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count a malformed frame from `ip`
+    ///
+    /// Returns `true` if this frame triggered a block.
+    pub fn record(&self, ip: IpAddr) -> bool {
+        self.record_at(ip, Instant::now())
+    }
+
+    /// Get the counters since startup
+    #[must_use]
+    pub fn metrics(&self) -> FrameMetricsSnapshot {
+        FrameMetricsSnapshot {
+            malformed_frames: self.metrics.malformed_frames.load(Ordering::Relaxed),
+            blocks: self.metrics.blocks.load(Ordering::Relaxed),
+            refused_connections: self.metrics.refused_connections.load(Ordering::Relaxed),
+        }
+    }
+
+    fn is_blocked_at(&self, ip: IpAddr, now: Instant) -> bool {
+        let mut offenders = self.offenders.lock().expect("frame guard lock");
+        match offenders
+            .get(&ip)
+            .and_then(|offender| offender.blocked_until)
+        {
+            Some(until) if until > now => true,
+            Some(_) => {
+                // Block expired - start over with a clean slate
+                offenders.remove(&ip);
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn record_at(&self, ip: IpAddr, now: Instant) -> bool {
+        self.metrics
+            .malformed_frames
+            .fetch_add(1, Ordering::Relaxed);
+        if self.limit == 0 {
+            return false;
+        }
+
+        let mut offenders = self.offenders.lock().expect("frame guard lock");
+        if offenders.len() >= PRUNE_THRESHOLD {
+            let window = self.window;
+            offenders.retain(|_, offender| match offender.blocked_until {
+                Some(until) => until > now,
+                None => now.duration_since(offender.window_start) < window,
+            });
+        }
+
+        let offender = offenders.entry(ip).or_insert(Offender {
+            window_start: now,
+            count: 0,
+            blocked_until: None,
+        });
+
+        if offender.blocked_until.is_some_and(|until| until > now) {
+            return false;
+        }
+        if offender.blocked_until.is_some()
+            || now.duration_since(offender.window_start) >= self.window
+        {
+            offender.window_start = now;
+            offender.count = 0;
+            offender.blocked_until = None;
+        }
+
+        offender.count += 1;
+        if offender.count < self.limit {
+            return false;
+        }
+
+        offender.blocked_until = Some(now + self.block_duration);
+        self.metrics.blocks.fetch_add(1, Ordering::Relaxed);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    const WINDOW: Duration = Duration::from_secs(60);
+    const BLOCK: Duration = Duration::from_secs(300);
+
+    fn ip(last: u8) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(203, 0, 113, last))
+    }
+
+    #[test]
+    fn test_blocks_after_limit_within_window() {
+        let tracker = MalformedFrameTracker::new(3, WINDOW, BLOCK);
+        let now = Instant::now();
+
+        assert!(!tracker.record_at(ip(1), now));
+        assert!(!tracker.record_at(ip(1), now + Duration::from_secs(1)));
+        assert!(!tracker.is_blocked_at(ip(1), now + Duration::from_secs(2)));
+
+        assert!(tracker.record_at(ip(1), now + Duration::from_secs(2)));
+        assert!(tracker.is_blocked_at(ip(1), now + Duration::from_secs(3)));
+
+        // Other addresses are unaffected
+        assert!(!tracker.is_blocked_at(ip(2), now + Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn test_occasional_errors_never_block() {
+        let tracker = MalformedFrameTracker::new(3, WINDOW, BLOCK);
+        let now = Instant::now();
+
+        // Two errors per window, forever, stay under the limit
+        for minute in 0..10 {
+            let start = now + WINDOW * minute;
+            assert!(!tracker.record_at(ip(1), start));
+            assert!(!tracker.record_at(ip(1), start + Duration::from_secs(30)));
+        }
+        assert!(!tracker.is_blocked_at(ip(1), now + WINDOW * 10));
+    }
+
+    #[test]
+    fn test_block_expires() {
+        let tracker = MalformedFrameTracker::new(1, WINDOW, BLOCK);
+        let now = Instant::now();
+
+        assert!(tracker.record_at(ip(1), now));
+        assert!(tracker.is_blocked_at(ip(1), now + BLOCK - Duration::from_secs(1)));
+        assert!(!tracker.is_blocked_at(ip(1), now + BLOCK));
+
+        // A fresh offence after expiry starts a new count
+        assert!(tracker.record_at(ip(1), now + BLOCK + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_zero_limit_disables_blocking() {
+        let tracker = MalformedFrameTracker::new(0, WINDOW, BLOCK);
+        let now = Instant::now();
+
+        for _ in 0..100 {
+            assert!(!tracker.record_at(ip(1), now));
+        }
+        assert!(!tracker.is_blocked_at(ip(1), now));
+        assert_eq!(tracker.metrics().malformed_frames, 100);
+    }
+
+    #[test]
+    fn test_metrics() {
+        let tracker = MalformedFrameTracker::new(2, WINDOW, BLOCK);
+
+        tracker.record(ip(1));
+        tracker.record(ip(1));
+        tracker.record_unattributed();
+        assert!(tracker.check_blocked(ip(1)));
+        assert!(!tracker.check_blocked(ip(2)));
+
+        assert_eq!(
+            tracker.metrics(),
+            FrameMetricsSnapshot {
+                malformed_frames: 3,
+                blocks: 1,
+                refused_connections: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_is_malformed() {
+        assert!(is_malformed(&FrameError::InvalidMagic));
+        assert!(is_malformed(&FrameError::UnknownMessageType("X".into())));
+        assert!(is_malformed(&FrameError::InvalidJson("bad".into())));
+        assert!(!is_malformed(&FrameError::FrameTimeout));
+        assert!(!is_malformed(&FrameError::ConnectionClosed));
+        assert!(!is_malformed(&FrameError::Io("reset".into())));
+        assert!(!is_malformed(&FrameError::OversizedPayloadSkipped {
+            message_id: nexus_common::framing::MessageId::new(),
+            message_type: "ChatSend".into(),
+            length: 20000,
+            max: 16416,
+        }));
+        assert!(is_malformed(&FrameError::PayloadLengthExceedsTypeMax {
+            message_type: "ChatSend".into(),
+            length: 2_000_000,
+            max: 16416,
+        }));
+    }
+
+    #[test]
+    fn test_prunes_stale_entries() {
+        let tracker = MalformedFrameTracker::new(5, WINDOW, BLOCK);
+        let now = Instant::now();
+
+        for i in 0..PRUNE_THRESHOLD {
+            let addr = IpAddr::V4(Ipv4Addr::from(i as u32));
+            tracker.record_at(addr, now);
+        }
+
+        // Once the window has passed, the next record clears the old entries
+        tracker.record_at(ip(1), now + WINDOW);
+        let offenders = tracker.offenders.lock().expect("frame guard lock");
+        assert_eq!(offenders.len(), 1);
+    }
+}
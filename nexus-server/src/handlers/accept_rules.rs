@@ -0,0 +1,156 @@
+//! Handler for AcceptRules command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_not_logged_in, err_server_maintenance,
+};
+
+/// Handle AcceptRules command
+///
+/// Records that the logged-in account accepted the current server rules, which
+/// lifts the chat restriction and stops the rules being sent at login. A guest's
+/// acceptance only lasts for its session.
+pub async fn handle_accept_rules<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("AcceptRules from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("AcceptRules"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error(&err_authentication(ctx.locale), Some("AcceptRules"))
+                .await;
+        }
+    };
+
+    if ctx.maintenance_blocks(user.is_admin).await {
+        let response = ServerMessage::AcceptRulesResponse {
+            success: false,
+            error: Some(err_server_maintenance(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let accepted = if user.is_guest {
+        ctx.user_manager.accept_guest_rules(id).await;
+        Ok(())
+    } else {
+        ctx.db.users.accept_rules(user.db_user_id).await
+    };
+    let response = match accepted {
+        Ok(()) => ServerMessage::AcceptRulesResponse {
+            success: true,
+            error: None,
+        },
+        Err(e) => {
+            eprintln!("Database error accepting rules: {}", e);
+            ServerMessage::AcceptRulesResponse {
+                success: false,
+                error: Some(err_database(ctx.locale)),
+            }
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_accept_rules_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_accept_rules(None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(response, ServerMessage::Error { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_accept_rules_records_acceptance() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_accept_rules(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::AcceptRulesResponse {
+                success: true,
+                error: None
+            }
+        ));
+
+        let account = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(
+            test_ctx
+                .db
+                .users
+                .has_accepted_rules(account.id)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_accept_rules_blocked_during_maintenance() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        test_ctx.db.config.set_maintenance_mode(true).await.unwrap();
+
+        let result = handle_accept_rules(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AcceptRulesResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_server_maintenance(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected AcceptRulesResponse, got {:?}", other),
+        }
+
+        let account = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(
+            !test_ctx
+                .db
+                .users
+                .has_accepted_rules(account.id)
+                .await
+                .unwrap()
+        );
+    }
+}
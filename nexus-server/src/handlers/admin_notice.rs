@@ -0,0 +1,230 @@
+//! Handler for AdminNotice command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{AdminNoticeAction, ServerMessage};
+use nexus_common::validators::{self, MessageError, NoticeUrlError};
+
+use super::{
+    HandlerContext, err_admin_required, err_authentication, err_broadcast_too_long,
+    err_message_empty, err_message_invalid_characters, err_not_logged_in, err_notice_url_invalid,
+    err_notice_url_too_long,
+};
+
+/// Handle a request to send a notice to every connected user (admin only)
+///
+/// Unlike a broadcast, clients show the notice prominently rather than as a
+/// chat line. Nothing is stored, so only users connected now see it.
+pub async fn handle_admin_notice<W>(
+    action: AdminNoticeAction,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("AdminNotice from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("AdminNotice"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("AdminNotice"))
+                .await;
+        }
+    };
+
+    if !user.is_admin {
+        eprintln!(
+            "AdminNotice from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        return send_response(ctx, Some(err_admin_required(ctx.locale))).await;
+    }
+
+    let error = match &action {
+        AdminNoticeAction::Reconnect => None,
+        AdminNoticeAction::Update { url } => {
+            validators::validate_notice_url(url).err().map(|e| match e {
+                NoticeUrlError::TooLong => {
+                    err_notice_url_too_long(ctx.locale, validators::MAX_NOTICE_URL_LENGTH)
+                }
+                NoticeUrlError::Empty
+                | NoticeUrlError::InvalidScheme
+                | NoticeUrlError::InvalidCharacters => err_notice_url_invalid(ctx.locale),
+            })
+        }
+        // Notices are broadcast to everyone, so share the message limit
+        AdminNoticeAction::Notice { message } => {
+            let max_length = ctx.db.config.get_max_message_length().await;
+            validators::validate_message(message, max_length)
+                .err()
+                .map(|e| match e {
+                    MessageError::Empty => err_message_empty(ctx.locale),
+                    MessageError::TooLong => err_broadcast_too_long(ctx.locale, max_length),
+                    MessageError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
+                })
+        }
+    };
+    if error.is_some() {
+        return send_response(ctx, error).await;
+    }
+
+    println!("Admin notice from {}: {:?}", user.username, action);
+    ctx.user_manager
+        .broadcast_extended(ServerMessage::AdminNotice { action }, &ctx.db.users)
+        .await;
+
+    send_response(ctx, None).await
+}
+
+/// Send an AdminNoticeResponse, failed if `error` is set
+async fn send_response<W>(ctx: &mut HandlerContext<'_, W>, error: Option<String>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::AdminNoticeResponse {
+        success: error.is_none(),
+        error,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permission;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_admin_notice_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_admin_notice(
+            AdminNoticeAction::Reconnect,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_admin_notice_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserBroadcast],
+            false,
+        )
+        .await;
+
+        let result = handle_admin_notice(
+            AdminNoticeAction::Reconnect,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AdminNoticeResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_admin_required(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected AdminNoticeResponse, got {:?}", other),
+        }
+        assert!(test_ctx._rx.messages.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_admin_notice_rejects_invalid_url() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_admin_notice(
+            AdminNoticeAction::Update {
+                url: "javascript:alert(1)".to_string(),
+            },
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AdminNoticeResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_notice_url_invalid(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected AdminNoticeResponse, got {:?}", other),
+        }
+        assert!(test_ctx._rx.messages.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_admin_notice_rejects_empty_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_admin_notice(
+            AdminNoticeAction::Notice {
+                message: "   ".to_string(),
+            },
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AdminNoticeResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_message_empty(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected AdminNoticeResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_notice_broadcasts() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let action = AdminNoticeAction::Update {
+            url: "https://example.com/download".to_string(),
+        };
+
+        let result = handle_admin_notice(
+            action.clone(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AdminNoticeResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            other => panic!("Expected AdminNoticeResponse, got {:?}", other),
+        }
+        match test_ctx._rx.messages.try_recv() {
+            Ok((ServerMessage::AdminNotice { action: received }, _)) => {
+                assert_eq!(received, action);
+            }
+            other => panic!("Expected AdminNotice, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,402 @@
+//! Handlers for AnnouncementAdd, AnnouncementList, and AnnouncementRemove commands
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{MAX_ANNOUNCEMENTS, ServerMessage};
+use nexus_common::validators::{self, MessageError};
+
+use super::{
+    HandlerContext, err_admin_required, err_announcement_limit_reached, err_announcement_not_found,
+    err_authentication, err_broadcast_too_long, err_database, err_message_empty,
+    err_message_invalid_characters, err_not_logged_in, err_server_maintenance,
+};
+use crate::constants::ERR_ANNOUNCEMENTS;
+
+/// Handle a request to add a message to the announcement rotation (admin only)
+pub async fn handle_announcement_add<W>(
+    message: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("AnnouncementAdd from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("AnnouncementAdd"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("AnnouncementAdd"))
+                .await;
+        }
+    };
+
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return send_add_response(ctx, Some(err_server_maintenance(ctx.locale))).await;
+    }
+
+    if !user.is_admin {
+        eprintln!(
+            "AnnouncementAdd from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        return send_add_response(ctx, Some(err_admin_required(ctx.locale))).await;
+    }
+
+    // Announcements are broadcast like any other message, so share its limit
+    let max_length = ctx.db.config.get_max_message_length().await;
+    if let Err(e) = validators::validate_message(&message, max_length) {
+        let error = match e {
+            MessageError::Empty => err_message_empty(ctx.locale),
+            MessageError::TooLong => err_broadcast_too_long(ctx.locale, max_length),
+            MessageError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
+        };
+        return send_add_response(ctx, Some(error)).await;
+    }
+
+    let mut announcements = ctx.db.config.get_announcements().await;
+    if announcements.len() >= MAX_ANNOUNCEMENTS {
+        let error = err_announcement_limit_reached(ctx.locale, MAX_ANNOUNCEMENTS);
+        return send_add_response(ctx, Some(error)).await;
+    }
+
+    announcements.push(message);
+    if let Err(e) = ctx.db.config.set_announcements(&announcements).await {
+        eprintln!("{}{}", ERR_ANNOUNCEMENTS, e);
+        return send_add_response(ctx, Some(err_database(ctx.locale))).await;
+    }
+
+    send_add_response(ctx, None).await
+}
+
+/// Handle a request to list the announcement rotation (admin only)
+pub async fn handle_announcement_list<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("AnnouncementList from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("AnnouncementList"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(
+                    &err_authentication(ctx.locale),
+                    Some("AnnouncementList"),
+                )
+                .await;
+        }
+    };
+
+    if !user.is_admin {
+        eprintln!(
+            "AnnouncementList from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        let response = ServerMessage::AnnouncementListResponse {
+            success: false,
+            error: Some(err_admin_required(ctx.locale)),
+            announcements: None,
+            interval_minutes: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let response = ServerMessage::AnnouncementListResponse {
+        success: true,
+        error: None,
+        announcements: Some(ctx.db.config.get_announcements().await),
+        interval_minutes: Some(ctx.db.config.get_announcement_interval().await),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle a request to remove an announcement from the rotation (admin only)
+///
+/// `index` is the zero-based position in the rotation.
+pub async fn handle_announcement_remove<W>(
+    index: u32,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("AnnouncementRemove from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("AnnouncementRemove"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(
+                    &err_authentication(ctx.locale),
+                    Some("AnnouncementRemove"),
+                )
+                .await;
+        }
+    };
+
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return send_remove_response(ctx, Some(err_server_maintenance(ctx.locale))).await;
+    }
+
+    if !user.is_admin {
+        eprintln!(
+            "AnnouncementRemove from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        return send_remove_response(ctx, Some(err_admin_required(ctx.locale))).await;
+    }
+
+    let mut announcements = ctx.db.config.get_announcements().await;
+    if index as usize >= announcements.len() {
+        let error = err_announcement_not_found(ctx.locale, index.saturating_add(1));
+        return send_remove_response(ctx, Some(error)).await;
+    }
+
+    announcements.remove(index as usize);
+    if let Err(e) = ctx.db.config.set_announcements(&announcements).await {
+        eprintln!("{}{}", ERR_ANNOUNCEMENTS, e);
+        return send_remove_response(ctx, Some(err_database(ctx.locale))).await;
+    }
+
+    send_remove_response(ctx, None).await
+}
+
+/// Send an AnnouncementAddResponse (success when `error` is None)
+async fn send_add_response<W>(
+    ctx: &mut HandlerContext<'_, W>,
+    error: Option<String>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::AnnouncementAddResponse {
+        success: error.is_none(),
+        error,
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send an AnnouncementRemoveResponse (success when `error` is None)
+async fn send_remove_response<W>(
+    ctx: &mut HandlerContext<'_, W>,
+    error: Option<String>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::AnnouncementRemoveResponse {
+        success: error.is_none(),
+        error,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permission;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_announcement_add_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserBroadcast],
+            false,
+        )
+        .await;
+
+        let result = handle_announcement_add(
+            "Hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AnnouncementAddResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            other => panic!("Expected AnnouncementAddResponse, got {:?}", other),
+        }
+        assert!(test_ctx.db.config.get_announcements().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_announcement_add_list_and_remove() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        for message in ["Read the rules", "Game night Friday"] {
+            handle_announcement_add(
+                message.to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            match read_server_message(&mut test_ctx.client).await {
+                ServerMessage::AnnouncementAddResponse { success, .. } => assert!(success),
+                other => panic!("Expected AnnouncementAddResponse, got {:?}", other),
+            }
+        }
+
+        handle_announcement_remove(0, Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AnnouncementRemoveResponse { success, .. } => assert!(success),
+            other => panic!("Expected AnnouncementRemoveResponse, got {:?}", other),
+        }
+
+        handle_announcement_list(Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AnnouncementListResponse {
+                success,
+                announcements,
+                interval_minutes,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(announcements, Some(vec!["Game night Friday".to_string()]));
+                assert_eq!(interval_minutes, Some(30));
+            }
+            other => panic!("Expected AnnouncementListResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_announcement_add_rejects_empty_and_over_limit() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        handle_announcement_add(
+            String::new(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AnnouncementAddResponse { success, .. } => assert!(!success),
+            other => panic!("Expected AnnouncementAddResponse, got {:?}", other),
+        }
+
+        let full: Vec<String> = (0..MAX_ANNOUNCEMENTS).map(|i| i.to_string()).collect();
+        test_ctx.db.config.set_announcements(&full).await.unwrap();
+        handle_announcement_add(
+            "One too many".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AnnouncementAddResponse { success, .. } => assert!(!success),
+            other => panic!("Expected AnnouncementAddResponse, got {:?}", other),
+        }
+        assert_eq!(test_ctx.db.config.get_announcements().await, full);
+    }
+
+    #[tokio::test]
+    async fn test_announcement_remove_out_of_range() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        handle_announcement_remove(u32::MAX, Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AnnouncementRemoveResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            other => panic!("Expected AnnouncementRemoveResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_announcement_writes_blocked_during_maintenance() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserBroadcast],
+            false,
+        )
+        .await;
+        test_ctx
+            .db
+            .config
+            .set_announcements(&["Welcome".to_string()])
+            .await
+            .unwrap();
+        test_ctx.db.config.set_maintenance_mode(true).await.unwrap();
+
+        let result = handle_announcement_add(
+            "Hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AnnouncementAddResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_server_maintenance(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected AnnouncementAddResponse, got {:?}", other),
+        }
+
+        let result =
+            handle_announcement_remove(0, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::AnnouncementRemoveResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_server_maintenance(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected AnnouncementRemoveResponse, got {:?}", other),
+        }
+
+        assert_eq!(
+            test_ctx.db.config.get_announcements().await,
+            vec!["Welcome".to_string()]
+        );
+    }
+}
@@ -9,17 +9,21 @@ use nexus_common::protocol::ServerMessage;
 use nexus_common::validators::{self, MessageError};
 
 use super::{
-    HandlerContext, err_authentication, err_broadcast_too_long, err_message_contains_newlines,
+    HandlerContext, err_authentication, err_broadcast_too_long, err_feature_disabled,
     err_message_empty, err_message_invalid_characters, err_not_logged_in, err_permission_denied,
+    err_server_maintenance, err_unknown_permission,
 };
 use crate::db::Permission;
 
 /// Handle a broadcast request from the client
 ///
-/// Broadcasts a message to all connected users including the sender.
-/// Also sends a UserBroadcastResponse to the sender indicating success or failure.
+/// Broadcasts a message to all connected users including the sender, or only
+/// to users holding `target_permission` when one is given (the sender always
+/// sees their own broadcast). Also sends a UserBroadcastResponse to the sender
+/// indicating success or failure.
 pub async fn handle_user_broadcast<W>(
     message: String,
+    target_permission: Option<String>,
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
@@ -34,14 +38,12 @@ where
             .await;
     };
 
-    // Validate message content
-    if let Err(e) = validators::validate_message(&message) {
+    // Validate message content against the server's configured limit
+    let max_length = ctx.db.config.get_max_message_length().await;
+    if let Err(e) = validators::validate_message(&message, max_length) {
         let error_msg = match e {
             MessageError::Empty => err_message_empty(ctx.locale),
-            MessageError::TooLong => {
-                err_broadcast_too_long(ctx.locale, validators::MAX_MESSAGE_LENGTH)
-            }
-            MessageError::ContainsNewlines => err_message_contains_newlines(ctx.locale),
+            MessageError::TooLong => err_broadcast_too_long(ctx.locale, max_length),
             MessageError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
         };
         return ctx
@@ -59,6 +61,16 @@ where
         }
     };
 
+    // Check whether UserBroadcast is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserBroadcast, user.is_admin)
+        .await
+    {
+        return ctx
+            .send_error(&err_feature_disabled(ctx.locale), Some("UserBroadcast"))
+            .await;
+    }
+
     // Check permission (uses cached permissions, admin bypass built-in)
     if !user.has_permission(Permission::UserBroadcast) {
         eprintln!(
@@ -70,17 +82,50 @@ where
             .await;
     }
 
-    // Send broadcast to all users
-    ctx.user_manager
-        .broadcast(
-            ServerMessage::ServerBroadcast {
-                session_id: id,
-                username: user.username.clone(),
-                message,
-            },
-            &ctx.db.users,
-        )
-        .await;
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return ctx
+            .send_error(&err_server_maintenance(ctx.locale), Some("UserBroadcast"))
+            .await;
+    }
+
+    // Resolve the target audience before sending anything
+    let target = match target_permission.as_deref() {
+        Some(perm_str) => match Permission::parse(perm_str) {
+            Some(perm) => Some(perm),
+            None => {
+                let response = ServerMessage::UserBroadcastResponse {
+                    success: false,
+                    error: Some(err_unknown_permission(ctx.locale, perm_str)),
+                };
+                return ctx.send_message(&response).await;
+            }
+        },
+        None => None,
+    };
+
+    let broadcast = ServerMessage::ServerBroadcast {
+        session_id: id,
+        username: user.username.clone(),
+        message,
+    };
+
+    match target {
+        Some(perm) => {
+            ctx.user_manager
+                .broadcast_to_permission(broadcast.clone(), &ctx.db.users, perm)
+                .await;
+
+            // The sender may not hold the target permission themselves
+            if !user.has_permission(perm) {
+                ctx.send_message(&broadcast).await?;
+            }
+        }
+        None => {
+            // Send broadcast to all users
+            ctx.user_manager.broadcast(broadcast, &ctx.db.users).await;
+        }
+    }
 
     // Send success response to the sender
     ctx.send_message(&ServerMessage::UserBroadcastResponse {
@@ -94,7 +139,9 @@ where
 mod tests {
     use super::*;
     use crate::db;
-    use crate::handlers::testing::{create_test_context, login_user};
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+    };
 
     #[tokio::test]
     async fn test_broadcast_requires_login() {
@@ -104,6 +151,7 @@ mod tests {
         // Try to broadcast without login
         let result = handle_user_broadcast(
             "Hello everyone".to_string(),
+            None,
             session_id,
             &mut test_ctx.handler_context(),
         )
@@ -122,8 +170,13 @@ mod tests {
         let long_message = "a".repeat(validators::MAX_MESSAGE_LENGTH + 1);
 
         // Try to send too-long message
-        let result =
-            handle_user_broadcast(long_message, session_id, &mut test_ctx.handler_context()).await;
+        let result = handle_user_broadcast(
+            long_message,
+            None,
+            session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
 
         // Should fail
         assert!(
@@ -152,6 +205,7 @@ mod tests {
         // Should succeed
         let result = handle_user_broadcast(
             max_message,
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -179,6 +233,7 @@ mod tests {
         // Try to send empty message
         let result = handle_user_broadcast(
             "".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -190,6 +245,7 @@ mod tests {
         // Try to send whitespace-only message
         let result = handle_user_broadcast(
             "   ".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -212,6 +268,7 @@ mod tests {
         // Try to broadcast without permission
         let result = handle_user_broadcast(
             "Important announcement!".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -241,6 +298,7 @@ mod tests {
         // Send valid broadcast message
         let result = handle_user_broadcast(
             "Important announcement!".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -260,6 +318,7 @@ mod tests {
         // Try to broadcast with invalid session
         let result = handle_user_broadcast(
             "Hello everyone".to_string(),
+            None,
             invalid_session_id,
             &mut test_ctx.handler_context(),
         )
@@ -283,6 +342,7 @@ mod tests {
         // Admin should be able to broadcast
         let result = handle_user_broadcast(
             "Admin announcement!".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -294,4 +354,134 @@ mod tests {
             "Admin should be able to broadcast without explicit permission"
         );
     }
+
+    #[tokio::test]
+    async fn test_broadcast_disabled_blocks_admins() {
+        let mut test_ctx = create_test_context().await;
+        let disabled: db::DisabledPermissions = "user_broadcast".parse().unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions(&disabled)
+            .await
+            .unwrap();
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_user_broadcast(
+            "Admin announcement!".to_string(),
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_feature_disabled(DEFAULT_TEST_LOCALE));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_disabled_admin_exempt() {
+        let mut test_ctx = create_test_context().await;
+        let disabled: db::DisabledPermissions = "user_broadcast".parse().unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions(&disabled)
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions_admin_exempt(true)
+            .await
+            .unwrap();
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_user_broadcast(
+            "Admin announcement!".to_string(),
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserBroadcastResponse { success, .. } => assert!(success),
+            other => panic!("Expected UserBroadcastResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_unknown_target_permission() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::UserBroadcast],
+            false,
+        )
+        .await;
+
+        let result = handle_user_broadcast(
+            "Moderators only".to_string(),
+            Some("not_a_permission".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Unknown target should not disconnect");
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::UserBroadcastResponse { success, error } => {
+                assert!(!success);
+                assert!(error.unwrap().contains("not_a_permission"));
+            }
+            _ => panic!("Expected UserBroadcastResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_targeted_echoes_to_sender() {
+        let mut test_ctx = create_test_context().await;
+
+        // Sender can broadcast but doesn't hold the target permission
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::UserBroadcast],
+            false,
+        )
+        .await;
+
+        let result = handle_user_broadcast(
+            "Moderators only".to_string(),
+            Some("user_kick".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Targeted broadcast should succeed");
+
+        // The sender still sees their own broadcast
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ServerBroadcast {
+                username, message, ..
+            } => {
+                assert_eq!(username, "alice");
+                assert_eq!(message, "Moderators only");
+            }
+            _ => panic!("Expected ServerBroadcast"),
+        }
+    }
 }
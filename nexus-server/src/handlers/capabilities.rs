@@ -0,0 +1,81 @@
+//! Handler for Capabilities command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::framing::known_message_types;
+use nexus_common::protocol::ServerMessage;
+
+use super::{HandlerContext, err_not_logged_in};
+use crate::constants::SUPPORTED_FEATURES;
+
+/// Handle a capabilities request from the client
+///
+/// Reports every message type the server understands along with its supported
+/// features, so clients can hide UI for anything the server lacks.
+pub async fn handle_capabilities<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    if session_id.is_none() {
+        eprintln!("Capabilities request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("Capabilities"))
+            .await;
+    }
+
+    // Sorted so the response is stable across requests
+    let mut message_types: Vec<String> = known_message_types()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    message_types.sort_unstable();
+
+    let response = ServerMessage::CapabilitiesResponse {
+        message_types,
+        features: SUPPORTED_FEATURES.iter().map(|f| f.to_string()).collect(),
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_capabilities_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_capabilities(None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "Capabilities should require login");
+    }
+
+    #[tokio::test]
+    async fn test_capabilities_lists_message_types_and_features() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_capabilities(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::CapabilitiesResponse {
+                message_types,
+                features,
+            } => {
+                assert!(message_types.contains(&"Capabilities".to_string()));
+                assert!(message_types.contains(&"PinMessage".to_string()));
+                assert!(message_types.is_sorted());
+                assert_eq!(features, vec!["chat".to_string()]);
+            }
+            _ => panic!("Expected CapabilitiesResponse"),
+        }
+    }
+}
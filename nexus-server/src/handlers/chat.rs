@@ -1,20 +1,23 @@
 //! Chat message handler
 //! Handler for ChatSend command
 
+use std::collections::HashMap;
 use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::ServerMessage;
+use nexus_common::mentions::{MAX_MENTIONS, find_mentions};
+use nexus_common::protocol::{ERROR_CODE_RULES_NOT_ACCEPTED, ServerMessage};
 use nexus_common::validators::{self, MessageError};
 
 use super::{
-    HandlerContext, err_authentication, err_chat_feature_not_enabled, err_chat_too_long,
-    err_message_contains_newlines, err_message_empty, err_message_invalid_characters,
-    err_not_logged_in, err_permission_denied,
+    HandlerContext, err_authentication, err_chat_feature_not_enabled, err_chat_slow_mode,
+    err_chat_too_long, err_feature_disabled, err_message_empty, err_message_invalid_characters,
+    err_not_logged_in, err_permission_denied, err_rules_not_accepted, err_server_maintenance,
 };
-use crate::constants::FEATURE_CHAT;
+use crate::constants::{ERR_CHAT_HISTORY_RECORD, FEATURE_CHAT};
 use crate::db::Permission;
+use crate::users::UserManager;
 
 /// Handle a chat send request from the client
 pub async fn handle_chat_send<W>(
@@ -33,12 +36,12 @@ where
             .await;
     };
 
-    // Validate message content
-    if let Err(e) = validators::validate_message(&message) {
+    // Validate message content against the server's configured limit
+    let max_length = ctx.db.config.get_max_message_length().await;
+    if let Err(e) = validators::validate_message(&message, max_length) {
         let error_msg = match e {
             MessageError::Empty => err_message_empty(ctx.locale),
-            MessageError::TooLong => err_chat_too_long(ctx.locale, validators::MAX_MESSAGE_LENGTH),
-            MessageError::ContainsNewlines => err_message_contains_newlines(ctx.locale),
+            MessageError::TooLong => err_chat_too_long(ctx.locale, max_length),
             MessageError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
         };
         return ctx
@@ -63,6 +66,16 @@ where
             .await;
     }
 
+    // Check whether ChatSend is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::ChatSend, user.is_admin)
+        .await
+    {
+        return ctx
+            .send_error(&err_feature_disabled(ctx.locale), Some("ChatSend"))
+            .await;
+    }
+
     // Check permission (uses cached permissions, admin bypass built-in)
     if !user.has_permission(Permission::ChatSend) {
         eprintln!(
@@ -74,6 +87,77 @@ where
             .await;
     }
 
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return ctx
+            .send_error(&err_server_maintenance(ctx.locale), Some("ChatSend"))
+            .await;
+    }
+
+    // Require acceptance of the server rules, if any are configured
+    if !ctx.db.config.get_server_rules().await.is_empty() {
+        let accepted = if user.is_guest {
+            user.guest_rules_accepted
+        } else {
+            match ctx.db.users.has_accepted_rules(user.db_user_id).await {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    eprintln!("Database error checking rules acceptance: {}", e);
+                    false
+                }
+            }
+        };
+        if !accepted {
+            let error_msg = ServerMessage::Error {
+                message: err_rules_not_accepted(ctx.locale),
+                command: Some("ChatSend".to_string()),
+                code: Some(ERROR_CODE_RULES_NOT_ACCEPTED.to_string()),
+            };
+            return ctx.send_message(&error_msg).await;
+        }
+    }
+
+    // Enforce slow mode (admins are exempt)
+    if !user.is_admin {
+        let slow_mode_secs = ctx.db.config.get_chat_slow_mode_seconds().await;
+        if slow_mode_secs > 0
+            && let Some(remaining) = ctx
+                .user_manager
+                .check_chat_slow_mode(&user.username, slow_mode_secs)
+                .await
+        {
+            return ctx
+                .send_error(&err_chat_slow_mode(ctx.locale, remaining), Some("ChatSend"))
+                .await;
+        }
+    }
+
+    // Assign an id so the message can be pinned later
+    let message_id = ctx
+        .user_manager
+        .record_chat_message(user.db_user_id, &user.username, &message)
+        .await;
+
+    // Persist to history (if the operator opted in) off the hot path so chat
+    // isn't held up by the database; retention runs on its own schedule
+    if ctx.db.config.get_chat_history_enabled().await {
+        let db = ctx.db.clone();
+        let username = user.username.clone();
+        let history_message = message.clone();
+        tokio::spawn(async move {
+            if let Err(e) = db
+                .chat
+                .record_message(message_id, &username, &history_message)
+                .await
+            {
+                eprintln!("{}{}", ERR_CHAT_HISTORY_RECORD, e);
+            }
+        });
+    }
+
+    // Flag mentions of online users so every client highlights the same ones
+    let mentions = mentioned_users(&message, ctx.user_manager).await;
+
     // Broadcast to all users with chat feature and ChatReceive permission
     ctx.user_manager
         .broadcast_to_feature(
@@ -82,6 +166,8 @@ where
                 session_id: id,
                 username: user.username.clone(),
                 message,
+                message_id: Some(message_id),
+                mentions,
             },
             &ctx.db.users,
             Permission::ChatReceive,
@@ -91,11 +177,47 @@ where
     Ok(())
 }
 
+/// Online users mentioned in a chat message with `@username`
+///
+/// Each user is listed once, in order of first mention, up to
+/// `MAX_MENTIONS`. Mentions of unknown or offline users are left as plain
+/// text. Returns `None` if nobody is mentioned.
+pub(super) async fn mentioned_users(
+    message: &str,
+    user_manager: &UserManager,
+) -> Option<Vec<String>> {
+    if !message.contains('@') {
+        return None;
+    }
+
+    let online: HashMap<String, String> = user_manager
+        .get_all_users()
+        .await
+        .into_iter()
+        .map(|user| (user.username.to_lowercase(), user.username))
+        .collect();
+
+    let mut mentioned: Vec<String> = Vec::new();
+    for mention in find_mentions(message, |name| online.get(&name.to_lowercase()).cloned()) {
+        if mentioned.len() == MAX_MENTIONS {
+            break;
+        }
+        if !mentioned.contains(&mention.username) {
+            mentioned.push(mention.username);
+        }
+    }
+
+    (!mentioned.is_empty()).then_some(mentioned)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::db;
-    use crate::handlers::testing::{create_test_context, login_user_with_features};
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_guest, login_user,
+        login_user_with_features, read_server_message,
+    };
 
     #[tokio::test]
     async fn test_chat_requires_login() {
@@ -164,6 +286,104 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_chat_history_recorded_only_when_enabled() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatSend],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        // Off by default: nothing is stored
+        handle_chat_send(
+            "not kept".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        test_ctx
+            .db
+            .config
+            .set_chat_history_enabled(true)
+            .await
+            .unwrap();
+        handle_chat_send(
+            "kept".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        // The write runs in the background
+        let mut history = Vec::new();
+        for _ in 0..100 {
+            (history, _) = test_ctx.db.chat.history_page(None, 10).await.unwrap();
+            if !history.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let messages: Vec<&str> = history.iter().map(|m| m.message.as_str()).collect();
+        assert_eq!(messages, vec!["kept"]);
+    }
+
+    #[tokio::test]
+    async fn test_chat_message_configured_limit() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.db.config.set_max_message_length(16).await.unwrap();
+        let session_id = Some(1); // Fake session (length check happens first)
+
+        // Within the default limit, but over the configured one
+        let message = "a".repeat(17);
+        let result = handle_chat_send(message, session_id, &mut test_ctx.handler_context()).await;
+        assert!(
+            result.is_err(),
+            "Message over the configured limit should be rejected"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_message_raised_limit() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_max_message_length(validators::MAX_MESSAGE_LENGTH_CEILING)
+            .await
+            .unwrap();
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatSend],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        // Longer than the default limit, accepted once the server raises it
+        let long_message = "a".repeat(validators::MAX_MESSAGE_LENGTH_CEILING);
+        let result = handle_chat_send(
+            long_message,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(
+            result.is_ok(),
+            "Message within the raised limit should be accepted"
+        );
+    }
+
     #[tokio::test]
     async fn test_chat_empty_message() {
         let mut test_ctx = create_test_context().await;
@@ -220,18 +440,16 @@ mod tests {
         )
         .await;
 
-        // Try to send message with \n
+        // Multi-line messages are allowed
         let result = handle_chat_send(
             "Hello\nWorld".to_string(),
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
         .await;
+        assert!(result.is_ok(), "Message with newline should be accepted");
 
-        // Should fail
-        assert!(result.is_err(), "Message with newline should be rejected");
-
-        // Try to send message with \r
+        // Carriage returns are still rejected (clients send \n line breaks)
         let result = handle_chat_send(
             "Hello\rWorld".to_string(),
             Some(session_id),
@@ -341,6 +559,60 @@ mod tests {
         assert!(result.is_ok(), "Valid chat message should succeed");
     }
 
+    #[tokio::test]
+    async fn test_chat_requires_rules_acceptance() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_server_rules("Be nice")
+            .await
+            .unwrap();
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatSend],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let result = handle_chat_send(
+            "Hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Unaccepted rules should not disconnect");
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { code, .. } => {
+                assert_eq!(code.as_deref(), Some(ERROR_CODE_RULES_NOT_ACCEPTED));
+            }
+            _ => panic!("Expected Error"),
+        }
+
+        // After accepting, chat goes through
+        let account = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx.db.users.accept_rules(account.id).await.unwrap();
+
+        let result = handle_chat_send(
+            "Hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Chat should succeed after accepting rules");
+    }
+
     #[tokio::test]
     async fn test_chat_invalid_session() {
         let mut test_ctx = create_test_context().await;
@@ -393,4 +665,226 @@ mod tests {
             "Admin should be able to chat without explicit permission"
         );
     }
+
+    #[tokio::test]
+    async fn test_chat_slow_mode_blocks_rapid_messages() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_chat_slow_mode_seconds(60)
+            .await
+            .unwrap();
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatSend],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        // First message goes through
+        let result = handle_chat_send(
+            "First".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        // Second message within the interval is rejected without disconnecting
+        let result = handle_chat_send(
+            "Second".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Slow mode should not disconnect");
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
+                assert_eq!(message, err_chat_slow_mode(DEFAULT_TEST_LOCALE, 60));
+                assert_eq!(command, Some("ChatSend".to_string()));
+            }
+            _ => panic!("Expected Error message, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_blocked_during_maintenance() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.db.config.set_maintenance_mode(true).await.unwrap();
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatSend],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let result = handle_chat_send(
+            "Hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Maintenance should not disconnect");
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
+                assert_eq!(message, err_server_maintenance(DEFAULT_TEST_LOCALE));
+                assert_eq!(command, Some("ChatSend".to_string()));
+            }
+            _ => panic!("Expected Error message, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_slow_mode_admin_exempt() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_chat_slow_mode_seconds(60)
+            .await
+            .unwrap();
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "admin",
+            "password",
+            &[],
+            true,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        for message in ["First", "Second"] {
+            let result = handle_chat_send(
+                message.to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_ok());
+        }
+
+        // Admin was never rate limited, so no slow mode timestamp was recorded
+        assert!(
+            test_ctx
+                .user_manager
+                .check_chat_slow_mode("admin", 60)
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_flags_mentions_of_online_users() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatSend, db::Permission::ChatReceive],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+        login_user(&mut test_ctx, "Bob", "password", &[], false).await;
+
+        // Unknown users and email addresses are not mentions, repeats count once
+        let result = handle_chat_send(
+            "@bob: ask @carol or bob@example.com, @Alice @BOB".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let mut mentions = None;
+        while let Ok((msg, _)) = test_ctx._rx.messages.try_recv() {
+            if let ServerMessage::ChatMessage { mentions: m, .. } = msg {
+                mentions = Some(m);
+            }
+        }
+        assert_eq!(
+            mentions.expect("Expected ChatMessage broadcast"),
+            Some(vec!["Bob".to_string(), "alice".to_string()])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mentioned_users_without_mentions() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        assert_eq!(
+            mentioned_users("hello alice", &test_ctx.user_manager).await,
+            None
+        );
+        assert_eq!(
+            mentioned_users("@carol @ alice", &test_ctx.user_manager).await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_guest_chat_requires_rules_acceptance() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_server_rules("Be nice")
+            .await
+            .unwrap();
+        let session_id = login_guest(&mut test_ctx, "guest-alice").await;
+
+        let result = handle_chat_send(
+            "Hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { code, .. } => {
+                assert_eq!(code.as_deref(), Some(ERROR_CODE_RULES_NOT_ACCEPTED));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+
+        // A guest's acceptance is kept in its session
+        crate::handlers::handle_accept_rules(Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        assert!(matches!(
+            read_server_message(&mut test_ctx.client).await,
+            ServerMessage::AcceptRulesResponse { success: true, .. }
+        ));
+
+        let result = handle_chat_send(
+            "Hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert!(matches!(
+            test_ctx._rx.messages.try_recv(),
+            Ok((ServerMessage::ChatMessage { .. }, _))
+        ));
+    }
 }
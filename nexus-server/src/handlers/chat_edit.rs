@@ -0,0 +1,1012 @@
+//! Handlers for ChatEdit and ChatDelete commands
+
+use std::io;
+use std::time::Duration;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, MessageError};
+
+use super::chat::mentioned_users;
+use super::chat_pin::broadcast_pinned_messages;
+use super::{
+    HandlerContext, err_authentication, err_chat_edit_window_expired, err_chat_message_not_found,
+    err_chat_not_own_message, err_chat_too_long, err_database, err_feature_disabled,
+    err_message_empty, err_message_invalid_characters, err_not_logged_in, err_permission_denied,
+    err_server_maintenance,
+};
+use crate::constants::{FEATURE_CHAT, MODERATION_ACTION_DELETE_MESSAGE};
+use crate::db::{ModerationEntry, Permission};
+use crate::users::manager::RecentChatMessage;
+
+/// Handle ChatEdit command
+///
+/// Users can only edit their own messages, and only within the configured
+/// chat edit window of sending them.
+pub async fn handle_chat_edit<W>(
+    message_id: u64,
+    message: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("ChatEdit from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatEdit"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("ChatEdit"))
+                .await;
+        }
+    };
+
+    // Check whether ChatSend is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::ChatSend, user.is_admin)
+        .await
+    {
+        return send_edit_response(ctx, Some(err_feature_disabled(ctx.locale))).await;
+    }
+
+    // Editing is sending new text, so it needs the same permission
+    if !user.has_permission(Permission::ChatSend) {
+        eprintln!(
+            "ChatEdit from {} (user: {}) without permission",
+            ctx.peer_addr, user.username
+        );
+        return send_edit_response(ctx, Some(err_permission_denied(ctx.locale))).await;
+    }
+
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return send_edit_response(ctx, Some(err_server_maintenance(ctx.locale))).await;
+    }
+
+    let max_length = ctx.db.config.get_max_message_length().await;
+    if let Err(e) = validators::validate_message(&message, max_length) {
+        let error_msg = match e {
+            MessageError::Empty => err_message_empty(ctx.locale),
+            MessageError::TooLong => err_chat_too_long(ctx.locale, max_length),
+            MessageError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
+        };
+        return send_edit_response(ctx, Some(error_msg)).await;
+    }
+
+    let Some(recent) = ctx.user_manager.get_recent_chat_message(message_id).await else {
+        return send_edit_response(ctx, Some(err_chat_message_not_found(ctx.locale))).await;
+    };
+
+    let window_minutes = ctx.db.config.get_chat_edit_window_minutes().await;
+    if let Err(error) = check_own_message(&recent, user.db_user_id, window_minutes, ctx.locale) {
+        return send_edit_response(ctx, Some(error)).await;
+    }
+
+    if !ctx
+        .user_manager
+        .edit_recent_chat_message(message_id, &message)
+        .await
+    {
+        return send_edit_response(ctx, Some(err_chat_message_not_found(ctx.locale))).await;
+    }
+
+    if let Err(e) = ctx.db.chat.edit_message(message_id, &message).await {
+        eprintln!("Database error editing chat history: {}", e);
+        return send_edit_response(ctx, Some(err_database(ctx.locale))).await;
+    }
+
+    // Keep a pinned copy of the message in sync with the edit
+    let mut pinned = match ctx.db.chat.get_pinned_messages().await {
+        Ok(pinned) => pinned,
+        Err(e) => {
+            eprintln!("Database error getting pinned messages: {}", e);
+            return send_edit_response(ctx, Some(err_database(ctx.locale))).await;
+        }
+    };
+    if let Some(pin) = pinned.iter_mut().find(|p| p.message_id == message_id) {
+        pin.message = message.clone();
+        if let Err(e) = ctx.db.chat.set_pinned_messages(&pinned).await {
+            eprintln!("Database error saving pinned messages: {}", e);
+            return send_edit_response(ctx, Some(err_database(ctx.locale))).await;
+        }
+        broadcast_pinned_messages(ctx, pinned).await;
+    }
+
+    let mentions = mentioned_users(&message, ctx.user_manager).await;
+    ctx.user_manager
+        .broadcast_to_feature(
+            FEATURE_CHAT,
+            ServerMessage::ChatMessageEdited {
+                message_id,
+                message,
+                mentions,
+            },
+            &ctx.db.users,
+            Permission::ChatReceive,
+        )
+        .await;
+
+    send_edit_response(ctx, None).await
+}
+
+/// Handle ChatDelete command
+///
+/// Users can delete their own messages within the configured chat edit
+/// window of sending them. Admins and users with `chat_moderate` can delete
+/// any recent message; those deletions are recorded in the moderation log and
+/// shown to everyone as removed by a moderator.
+pub async fn handle_chat_delete<W>(
+    message_id: u64,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("ChatDelete from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatDelete"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("ChatDelete"))
+                .await;
+        }
+    };
+
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return send_delete_response(ctx, Some(err_server_maintenance(ctx.locale))).await;
+    }
+
+    let Some(recent) = ctx.user_manager.get_recent_chat_message(message_id).await else {
+        return send_delete_response(ctx, Some(err_chat_message_not_found(ctx.locale))).await;
+    };
+
+    // Moderators (admin bypass built-in) skip the ownership and window checks,
+    // unless the server has disabled moderation
+    let can_moderate = user.has_permission(Permission::ChatModerate)
+        && !ctx
+            .permission_disabled(Permission::ChatModerate, user.is_admin)
+            .await;
+    let window_minutes = ctx.db.config.get_chat_edit_window_minutes().await;
+    let moderated = match check_own_message(&recent, user.db_user_id, window_minutes, ctx.locale) {
+        Ok(()) => false,
+        Err(_) if can_moderate => true,
+        Err(error) => return send_delete_response(ctx, Some(error)).await,
+    };
+
+    // Log before deleting so a moderator can't remove a message unrecorded
+    if moderated {
+        let entry = ModerationEntry {
+            moderator: user.username.clone(),
+            action: MODERATION_ACTION_DELETE_MESSAGE.to_string(),
+            target_username: recent.username.clone(),
+            message_id: Some(message_id),
+            message: recent.message.clone(),
+        };
+        if let Err(e) = ctx.db.moderation.record(&entry).await {
+            eprintln!("Database error recording moderation action: {}", e);
+            return send_delete_response(ctx, Some(err_database(ctx.locale))).await;
+        }
+        println!(
+            "Moderator {} deleted message {} from {}",
+            user.username, message_id, recent.username
+        );
+    }
+
+    if !ctx
+        .user_manager
+        .remove_recent_chat_message(message_id)
+        .await
+    {
+        return send_delete_response(ctx, Some(err_chat_message_not_found(ctx.locale))).await;
+    }
+
+    if let Err(e) = ctx.db.chat.delete_message(message_id).await {
+        eprintln!("Database error deleting chat history: {}", e);
+        return send_delete_response(ctx, Some(err_database(ctx.locale))).await;
+    }
+
+    // A deleted message can't stay pinned
+    let mut pinned = match ctx.db.chat.get_pinned_messages().await {
+        Ok(pinned) => pinned,
+        Err(e) => {
+            eprintln!("Database error getting pinned messages: {}", e);
+            return send_delete_response(ctx, Some(err_database(ctx.locale))).await;
+        }
+    };
+    let before = pinned.len();
+    pinned.retain(|p| p.message_id != message_id);
+    if pinned.len() != before {
+        if let Err(e) = ctx.db.chat.set_pinned_messages(&pinned).await {
+            eprintln!("Database error saving pinned messages: {}", e);
+            return send_delete_response(ctx, Some(err_database(ctx.locale))).await;
+        }
+        broadcast_pinned_messages(ctx, pinned).await;
+    }
+
+    ctx.user_manager
+        .broadcast_to_feature(
+            FEATURE_CHAT,
+            ServerMessage::ChatMessageDeleted {
+                message_id,
+                by_moderator: recent.username != user.username,
+            },
+            &ctx.db.users,
+            Permission::ChatReceive,
+        )
+        .await;
+
+    send_delete_response(ctx, None).await
+}
+
+/// Check that account `db_user_id` sent `recent` and it is still within the
+/// edit window of `window_minutes` (0 for no limit)
+///
+/// Ownership follows the account rather than the name, so a rename doesn't
+/// lose it and whoever takes a freed name doesn't gain it.
+///
+/// Returns the translated error to send back otherwise.
+fn check_own_message(
+    recent: &RecentChatMessage,
+    db_user_id: i64,
+    window_minutes: u32,
+    locale: &str,
+) -> Result<(), String> {
+    if recent.db_user_id != db_user_id {
+        return Err(err_chat_not_own_message(locale));
+    }
+
+    if window_minutes > 0
+        && recent.sent_at.elapsed() > Duration::from_secs(u64::from(window_minutes) * 60)
+    {
+        return Err(err_chat_edit_window_expired(locale, window_minutes));
+    }
+
+    Ok(())
+}
+
+/// Send a ChatEditResponse (success when `error` is None)
+async fn send_edit_response<W>(
+    ctx: &mut HandlerContext<'_, W>,
+    error: Option<String>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    ctx.send_message(&ServerMessage::ChatEditResponse {
+        success: error.is_none(),
+        error,
+    })
+    .await
+}
+
+/// Send a ChatDeleteResponse (success when `error` is None)
+async fn send_delete_response<W>(
+    ctx: &mut HandlerContext<'_, W>,
+    error: Option<String>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    ctx.send_message(&ServerMessage::ChatDeleteResponse {
+        success: error.is_none(),
+        error,
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::DEFAULT_CHAT_EDIT_WINDOW_MINUTES;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user,
+        login_user_with_features, read_server_message,
+    };
+    use nexus_common::protocol::PinnedMessage;
+
+    /// Account id of a sender who isn't online
+    const OFFLINE_USER_ID: i64 = 999;
+
+    /// Remember a chat message from `username`, as sent by their online
+    /// session if they have one
+    async fn record_message(test_ctx: &TestContext, username: &str, message: &str) -> u64 {
+        let db_user_id = match test_ctx
+            .user_manager
+            .get_session_by_username(username)
+            .await
+        {
+            Some(session) => session.db_user_id,
+            None => OFFLINE_USER_ID,
+        };
+        test_ctx
+            .user_manager
+            .record_chat_message(db_user_id, username, message)
+            .await
+    }
+
+    /// Age past the default edit window
+    const EXPIRED_AGE: Duration =
+        Duration::from_secs(DEFAULT_CHAT_EDIT_WINDOW_MINUTES as u64 * 60 + 1);
+
+    #[tokio::test]
+    async fn test_chat_edit_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_chat_edit(
+            1,
+            "hello".to_string(),
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "ChatEdit should require login");
+    }
+
+    #[tokio::test]
+    async fn test_chat_edit_own_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+        let message_id = record_message(&test_ctx, "alice", "helo").await;
+
+        let result = handle_chat_edit(
+            message_id,
+            "hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatEditResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected ChatEditResponse, got {:?}", response),
+        }
+
+        let recent = test_ctx
+            .user_manager
+            .get_recent_chat_message(message_id)
+            .await
+            .unwrap();
+        assert_eq!(recent.message, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_chat_edit_updates_pinned_copy() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+        let message_id = record_message(&test_ctx, "alice", "Maintenance at 10om").await;
+        test_ctx
+            .db
+            .chat
+            .set_pinned_messages(&[PinnedMessage {
+                message_id,
+                username: "alice".to_string(),
+                message: "Maintenance at 10om".to_string(),
+                pinned_by: "admin".to_string(),
+            }])
+            .await
+            .unwrap();
+
+        handle_chat_edit(
+            message_id,
+            "Maintenance at 10pm".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        let pinned = test_ctx.db.chat.get_pinned_messages().await.unwrap();
+        assert_eq!(pinned[0].message, "Maintenance at 10pm");
+    }
+
+    #[tokio::test]
+    async fn test_chat_edit_rejects_other_users_message() {
+        let mut test_ctx = create_test_context().await;
+        // Even admins can only edit their own messages
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let message_id = record_message(&test_ctx, "bob", "hello").await;
+
+        let result = handle_chat_edit(
+            message_id,
+            "edited".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatEditResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_chat_not_own_message(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected ChatEditResponse, got {:?}", response),
+        }
+
+        let recent = test_ctx
+            .user_manager
+            .get_recent_chat_message(message_id)
+            .await
+            .unwrap();
+        assert_eq!(recent.message, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_chat_edit_ownership_follows_account() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+        // An earlier account named alice, since renamed or deleted
+        let message_id = test_ctx
+            .user_manager
+            .record_chat_message(OFFLINE_USER_ID, "alice", "hello")
+            .await;
+
+        handle_chat_edit(
+            message_id,
+            "edited".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatEditResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_chat_not_own_message(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected ChatEditResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_edit_rejects_expired_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+        let message_id = record_message(&test_ctx, "alice", "hello").await;
+        test_ctx
+            .user_manager
+            .backdate_recent_chat_message(message_id, EXPIRED_AGE)
+            .await;
+
+        let result = handle_chat_edit(
+            message_id,
+            "edited".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatEditResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_chat_edit_window_expired(
+                        DEFAULT_TEST_LOCALE,
+                        DEFAULT_CHAT_EDIT_WINDOW_MINUTES
+                    ))
+                );
+            }
+            _ => panic!("Expected ChatEditResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_edit_requires_chat_send_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let message_id = record_message(&test_ctx, "alice", "hello").await;
+
+        let result = handle_chat_edit(
+            message_id,
+            "edited".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatEditResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected ChatEditResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_edit_rejects_empty_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+        let message_id = record_message(&test_ctx, "alice", "hello").await;
+
+        let result = handle_chat_edit(
+            message_id,
+            String::new(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatEditResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_message_empty(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected ChatEditResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_delete_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_chat_delete(1, None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err(), "ChatDelete should require login");
+    }
+
+    #[tokio::test]
+    async fn test_chat_delete_own_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let message_id = record_message(&test_ctx, "alice", "oops").await;
+
+        let result = handle_chat_delete(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatDeleteResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected ChatDeleteResponse, got {:?}", response),
+        }
+
+        assert!(
+            test_ctx
+                .user_manager
+                .get_recent_chat_message(message_id)
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_delete_rejects_other_users_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let message_id = record_message(&test_ctx, "bob", "hello").await;
+
+        let result = handle_chat_delete(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatDeleteResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_chat_not_own_message(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected ChatDeleteResponse, got {:?}", response),
+        }
+
+        assert!(
+            test_ctx
+                .user_manager
+                .get_recent_chat_message(message_id)
+                .await
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_delete_rejects_expired_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let message_id = record_message(&test_ctx, "alice", "hello").await;
+        test_ctx
+            .user_manager
+            .backdate_recent_chat_message(message_id, EXPIRED_AGE)
+            .await;
+
+        let result = handle_chat_delete(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatDeleteResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_chat_edit_window_expired(
+                        DEFAULT_TEST_LOCALE,
+                        DEFAULT_CHAT_EDIT_WINDOW_MINUTES
+                    ))
+                );
+            }
+            _ => panic!("Expected ChatDeleteResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_delete_moderator_can_delete_any_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "mod",
+            "password",
+            &[Permission::ChatModerate, Permission::ChatReceive],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+        let message_id = record_message(&test_ctx, "bob", "spam").await;
+        // Moderators aren't limited by the edit window either
+        test_ctx
+            .user_manager
+            .backdate_recent_chat_message(message_id, EXPIRED_AGE)
+            .await;
+        test_ctx
+            .db
+            .chat
+            .set_pinned_messages(&[PinnedMessage {
+                message_id,
+                username: "bob".to_string(),
+                message: "spam".to_string(),
+                pinned_by: "bob".to_string(),
+            }])
+            .await
+            .unwrap();
+
+        let result = handle_chat_delete(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatDeleteResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected ChatDeleteResponse, got {:?}", response),
+        }
+
+        assert!(
+            test_ctx
+                .db
+                .chat
+                .get_pinned_messages()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // Everyone sees the message was removed by a moderator
+        let deleted = std::iter::from_fn(|| test_ctx._rx.messages.try_recv().ok()).find_map(
+            |(message, _)| match message {
+                ServerMessage::ChatMessageDeleted {
+                    message_id: id,
+                    by_moderator,
+                } => Some((id, by_moderator)),
+                _ => None,
+            },
+        );
+        assert_eq!(deleted, Some((message_id, true)));
+    }
+
+    #[tokio::test]
+    async fn test_chat_delete_by_moderator_is_logged() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "mod",
+            "password",
+            &[Permission::ChatModerate],
+            false,
+        )
+        .await;
+        let message_id = record_message(&test_ctx, "bob", "spam").await;
+        test_ctx
+            .user_manager
+            .backdate_recent_chat_message(message_id, EXPIRED_AGE)
+            .await;
+
+        let result = handle_chat_delete(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::ChatDeleteResponse { success: true, .. }
+        ));
+
+        assert_eq!(
+            test_ctx
+                .db
+                .moderation
+                .recent(10)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(_, entry)| entry)
+                .collect::<Vec<_>>(),
+            vec![ModerationEntry {
+                moderator: "mod".to_string(),
+                action: MODERATION_ACTION_DELETE_MESSAGE.to_string(),
+                target_username: "bob".to_string(),
+                message_id: Some(message_id),
+                message: "spam".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_delete_own_message_not_logged() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "mod",
+            "password",
+            &[Permission::ChatModerate, Permission::ChatReceive],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+        let message_id = record_message(&test_ctx, "mod", "typo").await;
+
+        let result = handle_chat_delete(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::ChatDeleteResponse { success: true, .. }
+        ));
+
+        // Deleting within the edit window needs no moderation
+        assert!(test_ctx.db.moderation.recent(10).await.unwrap().is_empty());
+        match test_ctx._rx.messages.try_recv() {
+            Ok((ServerMessage::ChatMessageDeleted { by_moderator, .. }, _)) => {
+                assert!(!by_moderator);
+            }
+            other => panic!("Expected ChatMessageDeleted, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_edit_window_is_configurable() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+        let message_id = record_message(&test_ctx, "alice", "helo").await;
+        test_ctx
+            .user_manager
+            .backdate_recent_chat_message(message_id, EXPIRED_AGE)
+            .await;
+
+        // Past the default window, but inside a longer configured one
+        test_ctx
+            .db
+            .config
+            .set_chat_edit_window_minutes(DEFAULT_CHAT_EDIT_WINDOW_MINUTES * 2)
+            .await
+            .unwrap();
+        let result = handle_chat_edit(
+            message_id,
+            "hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::ChatEditResponse { success: true, .. }
+        ));
+
+        // A shorter window closes it
+        test_ctx
+            .db
+            .config
+            .set_chat_edit_window_minutes(1)
+            .await
+            .unwrap();
+        let result = handle_chat_edit(
+            message_id,
+            "hello!".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::ChatEditResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_chat_edit_window_expired(DEFAULT_TEST_LOCALE, 1))
+                );
+            }
+            other => panic!("Expected ChatEditResponse, got {:?}", other),
+        }
+
+        // 0 removes the limit
+        test_ctx
+            .db
+            .config
+            .set_chat_edit_window_minutes(0)
+            .await
+            .unwrap();
+        let result = handle_chat_delete(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::ChatDeleteResponse { success: true, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_chat_delete_moderation_disabled() {
+        let mut test_ctx = create_test_context().await;
+        let disabled: crate::db::DisabledPermissions = "chat_moderate".parse().unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions(&disabled)
+            .await
+            .unwrap();
+        let session_id = login_user(
+            &mut test_ctx,
+            "mod",
+            "password",
+            &[Permission::ChatModerate],
+            false,
+        )
+        .await;
+        let message_id = record_message(&test_ctx, "bob", "spam").await;
+
+        let result = handle_chat_delete(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        // Without moderation the usual ownership check applies
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatDeleteResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_chat_not_own_message(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected ChatDeleteResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_delete_unknown_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result =
+            handle_chat_delete(12345, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatDeleteResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_chat_message_not_found(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected ChatDeleteResponse, got {:?}", response),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Handler for ChatHistoryPurge command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_admin_required, err_authentication, err_database, err_not_logged_in,
+};
+use crate::constants::ERR_CHAT_HISTORY_PURGE;
+
+/// Handle a request to apply the chat retention policy immediately (admin only)
+///
+/// Responds with the number of history messages removed.
+pub async fn handle_chat_history_purge<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("ChatHistoryPurge from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("ChatHistoryPurge"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error(&err_authentication(ctx.locale), Some("ChatHistoryPurge"))
+                .await;
+        }
+    };
+
+    if !user.is_admin {
+        eprintln!(
+            "ChatHistoryPurge from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        return ctx
+            .send_error(&err_admin_required(ctx.locale), Some("ChatHistoryPurge"))
+            .await;
+    }
+
+    let response = match ctx.db.purge_chat_history().await {
+        Ok(removed) => ServerMessage::ChatHistoryPurgeResponse {
+            success: true,
+            error: None,
+            removed: Some(removed),
+        },
+        Err(e) => {
+            eprintln!("{}{}", ERR_CHAT_HISTORY_PURGE, e);
+            ServerMessage::ChatHistoryPurgeResponse {
+                success: false,
+                error: Some(err_database(ctx.locale)),
+                removed: None,
+            }
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permission;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_chat_history_purge_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+
+        let result =
+            handle_chat_history_purge(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(
+            matches!(response, ServerMessage::Error { .. }),
+            "Non-admin purge should be rejected"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_history_purge_reports_removed() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        test_ctx
+            .db
+            .config
+            .set_chat_retention_count(2)
+            .await
+            .unwrap();
+        for id in 1..=5 {
+            test_ctx
+                .db
+                .chat
+                .record_message(id, "admin", "hello")
+                .await
+                .unwrap();
+        }
+
+        let result =
+            handle_chat_history_purge(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatHistoryPurgeResponse {
+                success,
+                error,
+                removed,
+            } => {
+                assert!(success);
+                assert!(error.is_none());
+                assert_eq!(removed, Some(3));
+            }
+            _ => panic!("Expected ChatHistoryPurgeResponse"),
+        }
+    }
+}
@@ -0,0 +1,486 @@
+//! Handlers for PinMessage and UnpinMessage commands
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{MAX_PINNED_MESSAGES, PinnedMessage, ServerMessage};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_feature_disabled, err_not_logged_in,
+    err_permission_denied, err_pin_already_pinned, err_pin_limit_reached,
+    err_pin_message_not_found, err_pin_not_pinned, err_server_maintenance,
+};
+use crate::constants::FEATURE_CHAT;
+use crate::db::Permission;
+
+/// Handle PinMessage command
+pub async fn handle_pin_message<W>(
+    message_id: u64,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("PinMessage from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("PinMessage"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("PinMessage"))
+                .await;
+        }
+    };
+
+    // Check whether ChatPin is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::ChatPin, user.is_admin)
+        .await
+    {
+        return send_pin_response(ctx, Some(err_feature_disabled(ctx.locale))).await;
+    }
+
+    // Check ChatPin permission (uses cached permissions, admin bypass built-in)
+    if !user.has_permission(Permission::ChatPin) {
+        eprintln!(
+            "PinMessage from {} (user: {}) without permission",
+            ctx.peer_addr, user.username
+        );
+        return send_pin_response(ctx, Some(err_permission_denied(ctx.locale))).await;
+    }
+
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return send_pin_response(ctx, Some(err_server_maintenance(ctx.locale))).await;
+    }
+
+    let mut pinned = match ctx.db.chat.get_pinned_messages().await {
+        Ok(pinned) => pinned,
+        Err(e) => {
+            eprintln!("Database error getting pinned messages: {}", e);
+            return send_pin_response(ctx, Some(err_database(ctx.locale))).await;
+        }
+    };
+
+    if pinned.iter().any(|p| p.message_id == message_id) {
+        return send_pin_response(ctx, Some(err_pin_already_pinned(ctx.locale))).await;
+    }
+
+    if pinned.len() >= MAX_PINNED_MESSAGES {
+        let error = err_pin_limit_reached(ctx.locale, MAX_PINNED_MESSAGES);
+        return send_pin_response(ctx, Some(error)).await;
+    }
+
+    // Only messages the server still remembers can be pinned
+    let Some(recent) = ctx.user_manager.get_recent_chat_message(message_id).await else {
+        return send_pin_response(ctx, Some(err_pin_message_not_found(ctx.locale))).await;
+    };
+
+    pinned.push(PinnedMessage {
+        message_id,
+        username: recent.username,
+        message: recent.message,
+        pinned_by: user.username.clone(),
+    });
+
+    if let Err(e) = ctx.db.chat.set_pinned_messages(&pinned).await {
+        eprintln!("Database error saving pinned messages: {}", e);
+        return send_pin_response(ctx, Some(err_database(ctx.locale))).await;
+    }
+
+    broadcast_pinned_messages(ctx, pinned).await;
+    send_pin_response(ctx, None).await
+}
+
+/// Handle UnpinMessage command
+pub async fn handle_unpin_message<W>(
+    message_id: u64,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("UnpinMessage from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UnpinMessage"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("UnpinMessage"))
+                .await;
+        }
+    };
+
+    // Check whether ChatPin is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::ChatPin, user.is_admin)
+        .await
+    {
+        return send_unpin_response(ctx, Some(err_feature_disabled(ctx.locale))).await;
+    }
+
+    // Check ChatPin permission (uses cached permissions, admin bypass built-in)
+    if !user.has_permission(Permission::ChatPin) {
+        eprintln!(
+            "UnpinMessage from {} (user: {}) without permission",
+            ctx.peer_addr, user.username
+        );
+        return send_unpin_response(ctx, Some(err_permission_denied(ctx.locale))).await;
+    }
+
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return send_unpin_response(ctx, Some(err_server_maintenance(ctx.locale))).await;
+    }
+
+    let mut pinned = match ctx.db.chat.get_pinned_messages().await {
+        Ok(pinned) => pinned,
+        Err(e) => {
+            eprintln!("Database error getting pinned messages: {}", e);
+            return send_unpin_response(ctx, Some(err_database(ctx.locale))).await;
+        }
+    };
+
+    let before = pinned.len();
+    pinned.retain(|p| p.message_id != message_id);
+    if pinned.len() == before {
+        return send_unpin_response(ctx, Some(err_pin_not_pinned(ctx.locale))).await;
+    }
+
+    if let Err(e) = ctx.db.chat.set_pinned_messages(&pinned).await {
+        eprintln!("Database error saving pinned messages: {}", e);
+        return send_unpin_response(ctx, Some(err_database(ctx.locale))).await;
+    }
+
+    broadcast_pinned_messages(ctx, pinned).await;
+    send_unpin_response(ctx, None).await
+}
+
+/// Broadcast the full set of pinned messages to everyone who can receive chat
+pub(super) async fn broadcast_pinned_messages<W>(
+    ctx: &HandlerContext<'_, W>,
+    pinned: Vec<PinnedMessage>,
+) {
+    ctx.user_manager
+        .broadcast_to_feature(
+            FEATURE_CHAT,
+            ServerMessage::PinnedMessagesUpdated {
+                pinned_messages: pinned,
+            },
+            &ctx.db.users,
+            Permission::ChatReceive,
+        )
+        .await;
+}
+
+/// Send a PinMessageResponse (success when `error` is None)
+async fn send_pin_response<W>(
+    ctx: &mut HandlerContext<'_, W>,
+    error: Option<String>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    ctx.send_message(&ServerMessage::PinMessageResponse {
+        success: error.is_none(),
+        error,
+    })
+    .await
+}
+
+/// Send an UnpinMessageResponse (success when `error` is None)
+async fn send_unpin_response<W>(
+    ctx: &mut HandlerContext<'_, W>,
+    error: Option<String>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    ctx.send_message(&ServerMessage::UnpinMessageResponse {
+        success: error.is_none(),
+        error,
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user, read_server_message,
+    };
+
+    /// Pin `count` messages so the pin list is in a known state
+    async fn pin_messages(test_ctx: &TestContext, count: usize) {
+        let pinned: Vec<PinnedMessage> = (0..count as u64)
+            .map(|i| PinnedMessage {
+                message_id: i,
+                username: "alice".to_string(),
+                message: format!("message {i}"),
+                pinned_by: "admin".to_string(),
+            })
+            .collect();
+        test_ctx.db.chat.set_pinned_messages(&pinned).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pin_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_pin_message(1, None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err(), "PinMessage should require login");
+    }
+
+    #[tokio::test]
+    async fn test_pin_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let message_id = test_ctx
+            .user_manager
+            .record_chat_message(1, "alice", "hello")
+            .await;
+
+        let result = handle_pin_message(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::PinMessageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected PinMessageResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pin_message_success() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatPin],
+            false,
+        )
+        .await;
+        let message_id = test_ctx
+            .user_manager
+            .record_chat_message(2, "bob", "Maintenance at 10pm")
+            .await;
+
+        let result = handle_pin_message(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::PinMessageResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected PinMessageResponse, got {:?}", response),
+        }
+
+        let pinned = test_ctx.db.chat.get_pinned_messages().await.unwrap();
+        assert_eq!(
+            pinned,
+            vec![PinnedMessage {
+                message_id,
+                username: "bob".to_string(),
+                message: "Maintenance at 10pm".to_string(),
+                pinned_by: "alice".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pin_unknown_message() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result =
+            handle_pin_message(12345, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::PinMessageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_pin_message_not_found(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected PinMessageResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pin_already_pinned() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let message_id = test_ctx
+            .user_manager
+            .record_chat_message(2, "bob", "hello")
+            .await;
+
+        // First pin succeeds
+        handle_pin_message(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(&mut test_ctx.client).await;
+
+        // Second pin reports the duplicate
+        handle_pin_message(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::PinMessageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_pin_already_pinned(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected PinMessageResponse, got {:?}", response),
+        }
+        assert_eq!(
+            test_ctx.db.chat.get_pinned_messages().await.unwrap().len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pin_limit_reached() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        pin_messages(&test_ctx, MAX_PINNED_MESSAGES).await;
+        let message_id = test_ctx
+            .user_manager
+            .record_chat_message(2, "bob", "one too many")
+            .await;
+
+        let result = handle_pin_message(
+            message_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::PinMessageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_pin_limit_reached(
+                        DEFAULT_TEST_LOCALE,
+                        MAX_PINNED_MESSAGES
+                    ))
+                );
+            }
+            _ => panic!("Expected PinMessageResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unpin_message_success() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatPin],
+            false,
+        )
+        .await;
+        pin_messages(&test_ctx, 2).await;
+
+        let result =
+            handle_unpin_message(0, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::UnpinMessageResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected UnpinMessageResponse, got {:?}", response),
+        }
+
+        let pinned = test_ctx.db.chat.get_pinned_messages().await.unwrap();
+        assert_eq!(pinned.len(), 1);
+        assert_eq!(pinned[0].message_id, 1);
+    }
+
+    #[tokio::test]
+    async fn test_unpin_not_pinned() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result =
+            handle_unpin_message(99, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::UnpinMessageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_pin_not_pinned(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected UnpinMessageResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unpin_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        pin_messages(&test_ctx, 1).await;
+
+        let result =
+            handle_unpin_message(0, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::UnpinMessageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected UnpinMessageResponse, got {:?}", response),
+        }
+        assert_eq!(
+            test_ctx.db.chat.get_pinned_messages().await.unwrap().len(),
+            1
+        );
+    }
+}
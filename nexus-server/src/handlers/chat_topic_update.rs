@@ -8,10 +8,11 @@ use nexus_common::protocol::ServerMessage;
 use nexus_common::validators::{self, ChatTopicError};
 
 use super::{
-    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
-    err_topic_contains_newlines, err_topic_invalid_characters, err_topic_too_long,
+    HandlerContext, err_authentication, err_database, err_feature_disabled, err_not_logged_in,
+    err_permission_denied, err_server_maintenance, err_topic_contains_newlines,
+    err_topic_invalid_characters, err_topic_too_long,
 };
-use crate::db::Permission;
+use crate::db::{Permission, TOPIC_VIEW_PERMISSIONS};
 
 /// Handle ChatTopicUpdate command
 pub async fn handle_chat_topic_update<W>(
@@ -30,12 +31,11 @@ where
             .await;
     };
 
-    // Validate topic format
-    if let Err(e) = validators::validate_chat_topic(&topic) {
+    // Validate topic format against the server's configured limit
+    let max_length = ctx.db.config.get_max_topic_length().await;
+    if let Err(e) = validators::validate_chat_topic(&topic, max_length) {
         let error_msg = match e {
-            ChatTopicError::TooLong => {
-                err_topic_too_long(ctx.locale, validators::MAX_CHAT_TOPIC_LENGTH)
-            }
+            ChatTopicError::TooLong => err_topic_too_long(ctx.locale, max_length),
             ChatTopicError::ContainsNewlines => err_topic_contains_newlines(ctx.locale),
             ChatTopicError::InvalidCharacters => err_topic_invalid_characters(ctx.locale),
         };
@@ -52,6 +52,16 @@ where
         }
     };
 
+    // Check whether ChatTopicEdit is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::ChatTopicEdit, user.is_admin)
+        .await
+    {
+        return ctx
+            .send_error(&err_feature_disabled(ctx.locale), Some("ChatTopicUpdate"))
+            .await;
+    }
+
     // Check ChatTopicEdit permission (uses cached permissions, admin bypass built-in)
     if !user.has_permission(Permission::ChatTopicEdit) {
         eprintln!(
@@ -63,6 +73,13 @@ where
             .await;
     }
 
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return ctx
+            .send_error(&err_server_maintenance(ctx.locale), Some("ChatTopicUpdate"))
+            .await;
+    }
+
     // Save topic to database (with username who set it)
     if let Err(e) = ctx.db.chat.set_topic(&topic, &user.username).await {
         eprintln!("Database error setting topic: {}", e);
@@ -71,15 +88,15 @@ where
             .await;
     }
 
-    // Broadcast ChatTopicUpdated to all users with ChatTopic permission
+    // Broadcast ChatTopicUpdated to everyone who can see the topic
     ctx.user_manager
-        .broadcast_to_permission(
+        .broadcast_to_any_permission(
             ServerMessage::ChatTopicUpdated {
                 topic,
                 username: user.username.clone(),
             },
             &ctx.db.users,
-            Permission::ChatTopic,
+            TOPIC_VIEW_PERMISSIONS,
         )
         .await;
 
@@ -94,10 +111,49 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::Permission;
+    use crate::constants::DEFAULT_OUTGOING_QUEUE_CAPACITY;
+    use crate::db::{Permission, Permissions};
     use crate::handlers::testing::{
-        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user, read_server_message,
     };
+    use crate::users::user::{NewSessionParams, SessionReceiver, session_channel};
+
+    /// Add an online user with their own channel so their broadcasts can be checked
+    async fn add_watcher(
+        test_ctx: &mut TestContext,
+        username: &str,
+        permissions: &[Permission],
+    ) -> SessionReceiver {
+        let mut perms = Permissions::new();
+        perms.permissions.extend(permissions.iter().copied());
+        let user = test_ctx
+            .db
+            .users
+            .create_user(username, "hash", false, true, &perms)
+            .await
+            .unwrap();
+        let (tx, rx) = session_channel(DEFAULT_OUTGOING_QUEUE_CAPACITY);
+        test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: user.id,
+                username: username.to_string(),
+                is_admin: false,
+                permissions: perms.permissions,
+                address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
+                created_at: user.created_at,
+                tx,
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
+            })
+            .await;
+        rx
+    }
 
     #[tokio::test]
     async fn test_chattopic_requires_login() {
@@ -114,7 +170,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_not_logged_in(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ChatTopicUpdate".to_string()));
             }
@@ -140,7 +198,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_permission_denied(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ChatTopicUpdate".to_string()));
             }
@@ -176,7 +236,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(
                     message.contains("256"),
                     "Error should mention max length: {}",
@@ -234,6 +296,62 @@ mod tests {
         assert_eq!(saved_topic.set_by, "testuser");
     }
 
+    #[tokio::test]
+    async fn test_chattopic_configured_limit() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.db.config.set_max_topic_length(512).await.unwrap();
+
+        let session_id = login_user(
+            &mut test_ctx,
+            "testuser",
+            "password",
+            &[Permission::ChatTopicEdit],
+            false,
+        )
+        .await;
+
+        // Longer than the default, but within the configured limit
+        let topic = "a".repeat(512);
+        handle_chat_topic_update(
+            topic.clone(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::ChatTopicUpdateResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected ChatTopicUpdateResponse, got {:?}", response),
+        }
+        assert_eq!(test_ctx.db.chat.get_topic().await.unwrap().topic, topic);
+
+        // One past the configured limit is rejected with that limit in the error
+        handle_chat_topic_update(
+            "a".repeat(513),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::Error { message, .. } => {
+                assert!(
+                    message.contains("512"),
+                    "Error should mention configured max length: {}",
+                    message
+                );
+            }
+            _ => panic!("Expected Error message, got {:?}", response),
+        }
+    }
+
     #[tokio::test]
     async fn test_chattopic_empty_allowed() {
         let mut test_ctx = create_test_context().await;
@@ -298,7 +416,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_topic_contains_newlines(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ChatTopicUpdate".to_string()));
             }
@@ -331,4 +451,83 @@ mod tests {
             _ => panic!("Expected ChatTopicUpdateResponse, got {:?}", response),
         }
     }
+
+    #[tokio::test]
+    async fn test_chattopic_view_only_cannot_set() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user(
+            &mut test_ctx,
+            "viewer",
+            "password",
+            &[Permission::ChatTopic],
+            false,
+        )
+        .await;
+
+        let result = handle_chat_topic_update(
+            "Not allowed".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_permission_denied(DEFAULT_TEST_LOCALE));
+            }
+            response => panic!("Expected Error message, got {:?}", response),
+        }
+        let topic = test_ctx.db.chat.get_topic().await.unwrap();
+        assert_ne!(topic.topic, "Not allowed");
+    }
+
+    #[tokio::test]
+    async fn test_chattopic_broadcast_follows_view_or_edit() {
+        let mut test_ctx = create_test_context().await;
+
+        // The setter can edit but not view; setting the topic implies seeing it
+        let session_id = login_user(
+            &mut test_ctx,
+            "editor",
+            "password",
+            &[Permission::ChatTopicEdit],
+            false,
+        )
+        .await;
+        let mut view_only = add_watcher(&mut test_ctx, "viewer", &[Permission::ChatTopic]).await;
+        let mut edit_only = add_watcher(&mut test_ctx, "other", &[Permission::ChatTopicEdit]).await;
+        let mut both = add_watcher(
+            &mut test_ctx,
+            "both",
+            &[Permission::ChatTopic, Permission::ChatTopicEdit],
+        )
+        .await;
+        let mut neither = add_watcher(&mut test_ctx, "neither", &[]).await;
+
+        let result = handle_chat_topic_update(
+            "New topic".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::ChatTopicUpdateResponse { success, .. } => assert!(success),
+            response => panic!("Expected ChatTopicUpdateResponse, got {:?}", response),
+        }
+
+        for rx in [&mut test_ctx._rx, &mut view_only, &mut edit_only, &mut both] {
+            match rx.messages.try_recv() {
+                Ok((ServerMessage::ChatTopicUpdated { topic, username }, _)) => {
+                    assert_eq!(topic, "New topic");
+                    assert_eq!(username, "editor");
+                }
+                other => panic!("Expected ChatTopicUpdated, got {:?}", other),
+            }
+        }
+        assert!(neither.messages.try_recv().is_err());
+    }
 }
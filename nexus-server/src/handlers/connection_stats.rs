@@ -0,0 +1,126 @@
+//! Handler for ConnectionStats command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{HandlerContext, err_admin_required, err_authentication, err_not_logged_in};
+
+/// Handle a request for the recent connection history (admin only)
+///
+/// Responds with the connections open now and the per-minute history. With
+/// `reset`, the history is cleared once it has been read, so the next
+/// request starts counting afresh.
+pub async fn handle_connection_stats<W>(
+    reset: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("ConnectionStats from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("ConnectionStats"))
+            .await;
+    };
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(id).await else {
+        return ctx
+            .send_error(&err_authentication(ctx.locale), Some("ConnectionStats"))
+            .await;
+    };
+
+    if !user.is_admin {
+        eprintln!(
+            "ConnectionStats from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        let response = ServerMessage::ConnectionStatsResponse {
+            success: false,
+            error: Some(err_admin_required(ctx.locale)),
+            current: None,
+            buckets: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let (current, buckets) = ctx.connection_history.snapshot();
+    if reset {
+        ctx.connection_history.reset();
+    }
+
+    let response = ServerMessage::ConnectionStatsResponse {
+        success: true,
+        error: None,
+        current: Some(current),
+        buckets: Some(buckets),
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_connection_stats_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result =
+            handle_connection_stats(false, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::ConnectionStatsResponse {
+                success,
+                error,
+                buckets,
+                ..
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(buckets.is_none());
+            }
+            other => panic!("Expected ConnectionStatsResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connection_stats_reports_and_resets() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        test_ctx.connection_history.record_opened(1);
+        test_ctx.connection_history.record_refused();
+
+        let result =
+            handle_connection_stats(true, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::ConnectionStatsResponse {
+                success,
+                current,
+                buckets,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(current, Some(1));
+                let buckets = buckets.expect("buckets");
+                assert_eq!(buckets.iter().map(|b| b.connections).sum::<u32>(), 1);
+                assert_eq!(buckets.iter().map(|b| b.refused).sum::<u32>(), 1);
+            }
+            other => panic!("Expected ConnectionStatsResponse, got {:?}", other),
+        }
+
+        // The history was cleared after being read
+        let (current, buckets) = test_ctx.connection_history.snapshot();
+        assert_eq!(current, 1);
+        assert!(buckets.is_empty());
+    }
+}
@@ -0,0 +1,341 @@
+//! Handlers for E2eeKeyAnnounce and E2eeKeysRequest commands
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, UsernameError};
+
+use super::{
+    HandlerContext, err_authentication, err_e2ee_invalid_key, err_feature_disabled,
+    err_not_logged_in, err_permission_denied, err_server_maintenance, err_username_empty,
+    err_username_invalid, err_username_too_long,
+};
+use crate::db::Permission;
+
+/// Handle E2eeKeyAnnounce command
+///
+/// Records the session's public key (replacing any earlier one). Every
+/// session watching the account, including this one, is sent the account's
+/// keys again; there is no other response. Refused during maintenance, so
+/// clients hold the announcement until it ends.
+pub async fn handle_e2ee_key_announce<W>(
+    public_key: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("E2eeKeyAnnounce from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("E2eeKeyAnnounce"))
+            .await;
+    };
+
+    if validators::validate_e2ee_public_key(&public_key).is_err() {
+        return ctx
+            .send_error(&err_e2ee_invalid_key(ctx.locale), Some("E2eeKeyAnnounce"))
+            .await;
+    }
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(id).await else {
+        return ctx
+            .send_error(&err_authentication(ctx.locale), Some("E2eeKeyAnnounce"))
+            .await;
+    };
+
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return ctx
+            .send_error(&err_server_maintenance(ctx.locale), Some("E2eeKeyAnnounce"))
+            .await;
+    }
+
+    if ctx
+        .user_manager
+        .set_e2ee_key(id, public_key)
+        .await
+        .is_none()
+    {
+        return ctx
+            .send_error(&err_authentication(ctx.locale), Some("E2eeKeyAnnounce"))
+            .await;
+    }
+    Ok(())
+}
+
+/// Handle E2eeKeysRequest command
+///
+/// Answers with the user's current keys and keeps the session updated as
+/// they change until the user goes offline. Gated by `user_message`, since
+/// the keys are only useful for private messages and reveal whether the user
+/// is online, as sending them a message would.
+pub async fn handle_e2ee_keys_request<W>(
+    username: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("E2eeKeysRequest from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("E2eeKeysRequest"))
+            .await;
+    };
+
+    if let Err(e) = validators::validate_username(&username) {
+        let error_msg = match e {
+            UsernameError::Empty => err_username_empty(ctx.locale),
+            UsernameError::TooLong => {
+                err_username_too_long(ctx.locale, validators::MAX_USERNAME_LENGTH)
+            }
+            UsernameError::InvalidCharacters => err_username_invalid(ctx.locale),
+        };
+        return ctx.send_error(&error_msg, Some("E2eeKeysRequest")).await;
+    }
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(id).await else {
+        return ctx
+            .send_error(&err_authentication(ctx.locale), Some("E2eeKeysRequest"))
+            .await;
+    };
+
+    // Check whether UserMessage is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserMessage, user.is_admin)
+        .await
+    {
+        return ctx
+            .send_error(&err_feature_disabled(ctx.locale), Some("E2eeKeysRequest"))
+            .await;
+    }
+
+    if !user.has_permission(Permission::UserMessage) {
+        eprintln!(
+            "E2eeKeysRequest from {} (user: {}) without permission",
+            ctx.peer_addr, user.username
+        );
+        return ctx
+            .send_error(&err_permission_denied(ctx.locale), Some("E2eeKeysRequest"))
+            .await;
+    }
+
+    // Keys are only sent while the session can be kept updated: offline
+    // users and a full watch set get none, so the client sends plain text
+    let keys = if ctx.user_manager.watch_e2ee_keys(id, &username).await {
+        ctx.user_manager.e2ee_keys(&username).await
+    } else {
+        Vec::new()
+    };
+    ctx.send_message(&ServerMessage::E2eeKeys { username, keys })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+    };
+
+    fn key(fill: char) -> String {
+        format!("{}=", fill.to_string().repeat(43))
+    }
+
+    #[tokio::test]
+    async fn test_key_announce_rejects_invalid_key() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_e2ee_key_announce(
+            "not a key".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_e2ee_invalid_key(DEFAULT_TEST_LOCALE));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+        assert!(test_ctx.user_manager.e2ee_keys("alice").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_key_announce_sends_own_keys() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result =
+            handle_e2ee_key_announce(key('A'), Some(session_id), &mut test_ctx.handler_context())
+                .await;
+        assert!(result.is_ok());
+
+        match test_ctx._rx.messages.try_recv() {
+            Ok((ServerMessage::E2eeKeys { username, keys }, _)) => {
+                assert_eq!(username, "alice");
+                assert_eq!(keys.len(), 1);
+                assert_eq!(keys[0].session_id, session_id);
+                assert_eq!(keys[0].public_key, key('A'));
+            }
+            other => panic!("Expected E2eeKeys, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_key_announce_blocked_during_maintenance() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        test_ctx.db.config.set_maintenance_mode(true).await.unwrap();
+
+        let result =
+            handle_e2ee_key_announce(key('A'), Some(session_id), &mut test_ctx.handler_context())
+                .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_server_maintenance(DEFAULT_TEST_LOCALE));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+        assert!(test_ctx.user_manager.e2ee_keys("alice").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_keys_request_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_e2ee_keys_request(
+            "bob".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_permission_denied(DEFAULT_TEST_LOCALE));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keys_request_returns_keys() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let bob_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        test_ctx.user_manager.set_e2ee_key(bob_id, key('B')).await;
+
+        let result = handle_e2ee_keys_request(
+            "BOB".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::E2eeKeys { username, keys } => {
+                assert_eq!(username, "BOB");
+                assert_eq!(keys.len(), 1);
+                assert_eq!(keys[0].session_id, bob_id);
+            }
+            other => panic!("Expected E2eeKeys, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keys_request_ignores_offline_users() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        // Offline accounts aren't watched; the client asks again later
+        let bob_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        test_ctx.user_manager.remove_user(bob_id).await;
+
+        for username in ["nobody", "bob"] {
+            let result = handle_e2ee_keys_request(
+                username.to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_ok());
+
+            match read_server_message(&mut test_ctx.client).await {
+                ServerMessage::E2eeKeys { keys, .. } => assert!(keys.is_empty()),
+                other => panic!("Expected E2eeKeys, got {:?}", other),
+            }
+        }
+
+        let user = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id)
+            .await
+            .unwrap();
+        assert!(!user.e2ee_watches.contains("nobody"));
+        assert!(!user.e2ee_watches.contains("bob"));
+    }
+
+    #[tokio::test]
+    async fn test_keys_request_when_user_message_disabled() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        let disabled: db::DisabledPermissions = "user_message".parse().unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions(&disabled)
+            .await
+            .unwrap();
+
+        let result = handle_e2ee_keys_request(
+            "bob".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_feature_disabled(DEFAULT_TEST_LOCALE));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! All user-facing error messages are translated via the i18n system.
 //! Functions are organized alphabetically for easy lookup.
 
+use crate::constants::GUEST_USERNAME_PREFIX;
 use crate::i18n::{t, t_args};
 
 /// Get translated "account deleted" error
@@ -30,6 +31,24 @@ pub fn err_authentication(locale: &str) -> String {
     t(locale, "err-authentication")
 }
 
+/// Get translated "avatar dimensions too large" error
+pub fn err_avatar_dimensions_too_large(locale: &str, max_dimension: u32) -> String {
+    t_args(
+        locale,
+        "err-avatar-dimensions-too-large",
+        &[("max_dimension", &max_dimension.to_string())],
+    )
+}
+
+/// Get translated "avatar dimensions too small" error
+pub fn err_avatar_dimensions_too_small(locale: &str, min_dimension: u32) -> String {
+    t_args(
+        locale,
+        "err-avatar-dimensions-too-small",
+        &[("min_dimension", &min_dimension.to_string())],
+    )
+}
+
 /// Get translated "avatar invalid format" error
 pub fn err_avatar_invalid_format(locale: &str) -> String {
     t(locale, "err-avatar-invalid-format")
@@ -44,11 +63,53 @@ pub fn err_avatar_too_large(locale: &str, max_length: usize) -> String {
     )
 }
 
+/// Get translated "avatar undecodable" error
+pub fn err_avatar_undecodable(locale: &str) -> String {
+    t(locale, "err-avatar-undecodable")
+}
+
 /// Get translated "avatar unsupported type" error
 pub fn err_avatar_unsupported_type(locale: &str) -> String {
     t(locale, "err-avatar-unsupported-type")
 }
 
+/// Get translated "announcement limit reached" error
+pub fn err_announcement_limit_reached(locale: &str, max_count: usize) -> String {
+    t_args(
+        locale,
+        "err-announcement-limit-reached",
+        &[("max_count", &max_count.to_string())],
+    )
+}
+
+/// Get translated "announcement not found" error
+pub fn err_announcement_not_found(locale: &str, number: u32) -> String {
+    t_args(
+        locale,
+        "err-announcement-not-found",
+        &[("number", &number.to_string())],
+    )
+}
+
+/// Get translated "bio too long" error
+pub fn err_bio_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-bio-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "bio contains newlines" error
+pub fn err_bio_contains_newlines(locale: &str) -> String {
+    t(locale, "err-bio-contains-newlines")
+}
+
+/// Get translated "bio invalid characters" error
+pub fn err_bio_invalid_characters(locale: &str) -> String {
+    t(locale, "err-bio-invalid-characters")
+}
+
 /// Get translated "broadcast too long" error
 pub fn err_broadcast_too_long(locale: &str, max_length: usize) -> String {
     t_args(
@@ -88,11 +149,21 @@ pub fn err_cannot_edit_self(locale: &str) -> String {
     t(locale, "err-cannot-edit-self")
 }
 
+/// Get translated "cannot transfer admin to self" error
+pub fn err_cannot_transfer_admin_to_self(locale: &str) -> String {
+    t(locale, "err-cannot-transfer-admin-to-self")
+}
+
 /// Get translated "cannot kick admin" error
 pub fn err_cannot_kick_admin(locale: &str) -> String {
     t(locale, "err-cannot-kick-admin")
 }
 
+/// Get translated "cannot kick own session" error
+pub fn err_cannot_kick_own_session(locale: &str) -> String {
+    t(locale, "err-cannot-kick-own-session")
+}
+
 /// Get translated "cannot kick self" error
 pub fn err_cannot_kick_self(locale: &str) -> String {
     t(locale, "err-cannot-kick-self")
@@ -117,11 +188,63 @@ pub fn err_chat_too_long(locale: &str, max_length: usize) -> String {
     )
 }
 
+/// Get translated "chat slow mode" error
+pub fn err_chat_slow_mode(locale: &str, seconds: u64) -> String {
+    t_args(
+        locale,
+        "err-chat-slow-mode",
+        &[("seconds", &seconds.to_string())],
+    )
+}
+
+/// Get translated "command cooldown" error
+pub fn err_command_cooldown(locale: &str, seconds: u64) -> String {
+    t_args(
+        locale,
+        "err-command-cooldown",
+        &[("seconds", &seconds.to_string())],
+    )
+}
+
+/// Get translated "chat edit window expired" error
+pub fn err_chat_edit_window_expired(locale: &str, minutes: u32) -> String {
+    t_args(
+        locale,
+        "err-chat-edit-window-expired",
+        &[("minutes", &minutes.to_string())],
+    )
+}
+
+/// Get translated "chat message not found" error
+pub fn err_chat_message_not_found(locale: &str) -> String {
+    t(locale, "err-chat-message-not-found")
+}
+
+/// Get translated "chat not own message" error
+pub fn err_chat_not_own_message(locale: &str) -> String {
+    t(locale, "err-chat-not-own-message")
+}
+
 /// Get translated "database" error
 pub fn err_database(locale: &str) -> String {
     t(locale, "err-database")
 }
 
+/// Get translated "invalid encryption key" error
+pub fn err_e2ee_invalid_key(locale: &str) -> String {
+    t(locale, "err-e2ee-invalid-key")
+}
+
+/// Get translated "invalid encrypted message" error
+pub fn err_e2ee_invalid_message(locale: &str) -> String {
+    t(locale, "err-e2ee-invalid-message")
+}
+
+/// Get translated "encryption keys changed" error
+pub fn err_e2ee_keys_changed(locale: &str, username: &str) -> String {
+    t_args(locale, "err-e2ee-keys-changed", &[("username", username)])
+}
+
 /// Get translated "failed to create user" error
 pub fn err_failed_to_create_user(locale: &str, username: &str) -> String {
     t_args(
@@ -159,6 +282,25 @@ pub fn err_features_too_many(locale: &str, max_count: usize) -> String {
     )
 }
 
+/// Get translated "guest name in use" error
+pub fn err_guest_name_in_use(locale: &str, username: &str) -> String {
+    t_args(locale, "err-guest-name-in-use", &[("username", username)])
+}
+
+/// Get translated "guest name reserved" error
+pub fn err_guest_name_reserved(locale: &str) -> String {
+    t_args(
+        locale,
+        "err-guest-name-reserved",
+        &[("prefix", GUEST_USERNAME_PREFIX)],
+    )
+}
+
+/// Get translated "guests not allowed" error
+pub fn err_guest_not_allowed(locale: &str) -> String {
+    t(locale, "err-guest-not-allowed")
+}
+
 /// Get translated "handshake already completed" error
 pub fn err_handshake_already_completed(locale: &str) -> String {
     t(locale, "err-handshake-already-completed")
@@ -179,6 +321,15 @@ pub fn err_invalid_message_format(locale: &str) -> String {
     t(locale, "err-invalid-message-format")
 }
 
+/// Get translated "payload too large" error (request skipped, connection kept)
+pub fn err_payload_too_large(locale: &str, max_length: u64) -> String {
+    t_args(
+        locale,
+        "err-payload-too-large",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
 /// Get translated "kicked by" message
 pub fn err_kicked_by(locale: &str, username: &str) -> String {
     t_args(locale, "err-kicked-by", &[("username", username)])
@@ -198,11 +349,6 @@ pub fn err_locale_too_long(locale: &str, max_length: usize) -> String {
     )
 }
 
-/// Get translated "message contains newlines" error
-pub fn err_message_contains_newlines(locale: &str) -> String {
-    t(locale, "err-message-contains-newlines")
-}
-
 /// Get translated "message empty" error
 pub fn err_message_empty(locale: &str) -> String {
     t(locale, "err-message-empty")
@@ -218,6 +364,67 @@ pub fn err_not_logged_in(locale: &str) -> String {
     t(locale, "err-not-logged-in")
 }
 
+/// Get translated "invalid notice link" error
+pub fn err_notice_url_invalid(locale: &str) -> String {
+    t(locale, "err-notice-url-invalid")
+}
+
+/// Get translated "notice link too long" error
+pub fn err_notice_url_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-notice-url-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "page content empty" error
+pub fn err_page_content_empty(locale: &str) -> String {
+    t(locale, "err-page-content-empty")
+}
+
+/// Get translated "page content invalid characters" error
+pub fn err_page_content_invalid_characters(locale: &str) -> String {
+    t(locale, "err-page-content-invalid-characters")
+}
+
+/// Get translated "page content too long" error
+pub fn err_page_content_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-page-content-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "page limit reached" error
+pub fn err_page_limit_reached(locale: &str, max_count: usize) -> String {
+    t_args(
+        locale,
+        "err-page-limit-reached",
+        &[("max_count", &max_count.to_string())],
+    )
+}
+
+/// Get translated "page name invalid" error (empty or bad characters)
+pub fn err_page_name_invalid(locale: &str) -> String {
+    t(locale, "err-page-name-invalid")
+}
+
+/// Get translated "page name too long" error
+pub fn err_page_name_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-page-name-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "page not found" error
+pub fn err_page_not_found(locale: &str, name: &str) -> String {
+    t_args(locale, "err-page-not-found", &[("name", name)])
+}
+
 /// Get translated "password empty" error
 pub fn err_password_empty(locale: &str) -> String {
     t(locale, "err-password-empty")
@@ -232,6 +439,16 @@ pub fn err_password_too_long(locale: &str, max_length: usize) -> String {
     )
 }
 
+/// Get translated "server in maintenance" error
+pub fn err_server_maintenance(locale: &str) -> String {
+    t(locale, "err-server-maintenance")
+}
+
+/// Get translated "feature disabled on this server" error
+pub fn err_feature_disabled(locale: &str) -> String {
+    t(locale, "err-feature-disabled")
+}
+
 /// Get translated "permission denied" error
 pub fn err_permission_denied(locale: &str) -> String {
     t(locale, "err-permission-denied")
@@ -270,6 +487,68 @@ pub fn err_permissions_too_many(locale: &str, max_count: usize) -> String {
     )
 }
 
+/// Get translated "pin already pinned" error
+pub fn err_pin_already_pinned(locale: &str) -> String {
+    t(locale, "err-pin-already-pinned")
+}
+
+/// Get translated "pin limit reached" error
+pub fn err_pin_limit_reached(locale: &str, max_count: usize) -> String {
+    t_args(
+        locale,
+        "err-pin-limit-reached",
+        &[("max_count", &max_count.to_string())],
+    )
+}
+
+/// Get translated "pin message not found" error
+pub fn err_pin_message_not_found(locale: &str) -> String {
+    t(locale, "err-pin-message-not-found")
+}
+
+/// Get translated "pin not pinned" error
+pub fn err_pin_not_pinned(locale: &str) -> String {
+    t(locale, "err-pin-not-pinned")
+}
+
+/// Get translated "preferences not object" error
+pub fn err_preferences_not_object(locale: &str) -> String {
+    t(locale, "err-preferences-not-object")
+}
+
+/// Get translated "preferences too large" error
+pub fn err_preferences_too_large(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-preferences-too-large",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "pronouns too long" error
+pub fn err_pronouns_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-pronouns-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "pronouns contain newlines" error
+pub fn err_pronouns_contains_newlines(locale: &str) -> String {
+    t(locale, "err-pronouns-contains-newlines")
+}
+
+/// Get translated "pronouns invalid characters" error
+pub fn err_pronouns_invalid_characters(locale: &str) -> String {
+    t(locale, "err-pronouns-invalid-characters")
+}
+
+/// Get translated "rules not accepted" error
+pub fn err_rules_not_accepted(locale: &str) -> String {
+    t(locale, "err-rules-not-accepted")
+}
+
 /// Get translated "topic contains newlines" error
 pub fn err_topic_contains_newlines(locale: &str) -> String {
     t(locale, "err-topic-contains-newlines")
@@ -308,6 +587,29 @@ pub fn err_user_not_found(locale: &str, username: &str) -> String {
     t_args(locale, "err-user-not-found", &[("username", username)])
 }
 
+/// Get translated "session not found" error
+pub fn err_session_not_found(locale: &str, session_id: u32) -> String {
+    t_args(
+        locale,
+        "err-session-not-found",
+        &[("session_id", &session_id.to_string())],
+    )
+}
+
+/// Get translated "session limit reached" error
+pub fn err_session_limit(locale: &str, max_sessions: u32) -> String {
+    t_args(
+        locale,
+        "err-session-limit",
+        &[("max_sessions", &max_sessions.to_string())],
+    )
+}
+
+/// Get translated "session replaced by a newer login" message
+pub fn err_session_replaced(locale: &str) -> String {
+    t(locale, "err-session-replaced")
+}
+
 /// Get translated "user not online" error
 pub fn err_user_not_online(locale: &str, username: &str) -> String {
     t_args(locale, "err-user-not-online", &[("username", username)])
@@ -375,6 +677,18 @@ pub fn err_version_client_too_new(
     )
 }
 
+/// Get translated "client version too old" error
+pub fn err_version_client_too_old(locale: &str, min_version: &str, client_version: &str) -> String {
+    t_args(
+        locale,
+        "err-version-client-too-old",
+        &[
+            ("min_version", min_version),
+            ("client_version", client_version),
+        ],
+    )
+}
+
 /// Get translated "version too long" error
 pub fn err_version_too_long(locale: &str, max_length: usize) -> String {
     t_args(
@@ -389,6 +703,11 @@ pub fn err_admin_required(locale: &str) -> String {
     t(locale, "err-admin-required")
 }
 
+/// Get translated "already an admin" error
+pub fn err_already_admin(locale: &str, username: &str) -> String {
+    t_args(locale, "err-already-admin", &[("username", username)])
+}
+
 /// Get translated "server name empty" error
 pub fn err_server_name_empty(locale: &str) -> String {
     t(locale, "err-server-name-empty")
@@ -452,7 +771,43 @@ pub fn err_max_connections_per_ip_invalid(locale: &str) -> String {
     t(locale, "err-max-connections-per-ip-invalid")
 }
 
+/// Get translated "max message length invalid" error
+pub fn err_max_message_length_invalid(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-max-message-length-invalid",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "max topic length invalid" error
+pub fn err_max_topic_length_invalid(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-max-topic-length-invalid",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
 /// Get translated "no fields to update" error
 pub fn err_no_fields_to_update(locale: &str) -> String {
     t(locale, "err-no-fields-to-update")
 }
+
+/// Get translated "prerequisite permissions added" warning
+pub fn warn_permission_prerequisites_added(locale: &str, permissions: &str) -> String {
+    t_args(
+        locale,
+        "warn-permission-prerequisites-added",
+        &[("permissions", permissions)],
+    )
+}
+
+/// Get translated "prerequisite permissions missing" warning
+pub fn warn_permission_prerequisites_missing(locale: &str, permissions: &str) -> String {
+    t_args(
+        locale,
+        "warn-permission-prerequisites-missing",
+        &[("permissions", permissions)],
+    )
+}
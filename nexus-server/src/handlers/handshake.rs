@@ -4,19 +4,23 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{ERROR_CODE_CLIENT_TOO_OLD, ServerMessage};
 use nexus_common::validators::{self, VersionError};
-use nexus_common::version::{self, CompatibilityResult};
+use nexus_common::version::{self, CompatibilityResult, Version};
 
 use super::{
-    HandlerContext, err_handshake_already_completed, err_version_client_too_new, err_version_empty,
-    err_version_invalid_semver, err_version_major_mismatch, err_version_too_long,
+    HandlerContext, err_handshake_already_completed, err_version_client_too_new,
+    err_version_client_too_old, err_version_empty, err_version_invalid_semver,
+    err_version_major_mismatch, err_version_too_long,
 };
 
 /// Handle a handshake request from the client
+///
+/// On success, `negotiated_version` is set to the client's protocol version,
+/// which also marks the handshake complete.
 pub async fn handle_handshake<W>(
     version: String,
-    handshake_complete: &mut bool,
+    negotiated_version: &mut Option<Version>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
 where
@@ -25,12 +29,14 @@ where
     let server_version_str = nexus_common::PROTOCOL_VERSION;
 
     // Check for duplicate handshake
-    if *handshake_complete {
+    if negotiated_version.is_some() {
         eprintln!("Duplicate handshake attempt from {}", ctx.peer_addr);
         let response = ServerMessage::HandshakeResponse {
             success: false,
             version: Some(server_version_str.to_string()),
             error: Some(err_handshake_already_completed(ctx.locale)),
+            banner: None,
+            code: None,
         };
         ctx.send_message(&response).await?;
         return Err(io::Error::other("Duplicate handshake"));
@@ -51,6 +57,8 @@ where
                 success: false,
                 version: Some(server_version_str.to_string()),
                 error: Some(error_msg),
+                banner: None,
+                code: None,
             };
             ctx.send_message(&response).await?;
             return Err(io::Error::other("Invalid version string"));
@@ -60,12 +68,48 @@ where
     // Check semver compatibility using the already-parsed version
     match version::check_compatibility(&client_version) {
         CompatibilityResult::Compatible => {
-            // Version is compatible - complete handshake
-            *handshake_complete = true;
+            // Operators can require a newer client than the protocol allows
+            // (pre-release tags are ignored, as in the compatibility check)
+            if let Some(min_version) = ctx.db.config.get_min_client_version().await
+                && (
+                    client_version.major,
+                    client_version.minor,
+                    client_version.patch,
+                ) < (min_version.major, min_version.minor, min_version.patch)
+            {
+                eprintln!(
+                    "Handshake from {} failed: client version {} is older than minimum {}",
+                    ctx.peer_addr, client_version, min_version
+                );
+                let response = ServerMessage::HandshakeResponse {
+                    success: false,
+                    version: Some(server_version_str.to_string()),
+                    error: Some(err_version_client_too_old(
+                        ctx.locale,
+                        &min_version.to_string(),
+                        &version,
+                    )),
+                    banner: None,
+                    code: Some(ERROR_CODE_CLIENT_TOO_OLD.to_string()),
+                };
+                ctx.send_message(&response).await?;
+                return Err(io::Error::other("Client version too old"));
+            }
+
+            // Version is compatible - complete handshake. Clients older than
+            // the extended protocol reject a response big enough for a banner.
+            let banner = if version::supports_extended_protocol(&client_version) {
+                Some(ctx.db.config.get_server_banner().await).filter(|b| !b.is_empty())
+            } else {
+                None
+            };
+            *negotiated_version = Some(client_version);
             let response = ServerMessage::HandshakeResponse {
                 success: true,
                 version: Some(server_version_str.to_string()),
                 error: None,
+                banner,
+                code: None,
             };
             ctx.send_message(&response).await
         }
@@ -85,6 +129,8 @@ where
                     server_major,
                     client_major,
                 )),
+                banner: None,
+                code: None,
             };
             ctx.send_message(&response).await?;
             Err(io::Error::other("Major version mismatch"))
@@ -105,6 +151,8 @@ where
                     server_version_str,
                     &version,
                 )),
+                banner: None,
+                code: None,
             };
             ctx.send_message(&response).await?;
             Err(io::Error::other("Client version too new"))
@@ -115,19 +163,19 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::handlers::testing::{create_test_context, read_server_message};
+    use crate::handlers::testing::{DEFAULT_TEST_LOCALE, create_test_context, read_server_message};
     use nexus_common::version;
 
     #[tokio::test]
     async fn test_successful_handshake() {
         let mut test_ctx = create_test_context().await;
-        let mut handshake_complete = false;
+        let mut negotiated_version = None;
 
         // Call handler with matching version
         let version = nexus_common::PROTOCOL_VERSION.to_string();
         let result = handle_handshake(
             version,
-            &mut handshake_complete,
+            &mut negotiated_version,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -137,7 +185,10 @@ mod tests {
             result.is_ok(),
             "Handshake should succeed with matching version"
         );
-        assert!(handshake_complete, "Handshake flag should be set to true");
+        assert!(
+            negotiated_version.is_some(),
+            "Handshake should record the client version"
+        );
 
         // Read response from client side using new framing format
         let response_msg = read_server_message(&mut test_ctx.client).await;
@@ -148,19 +199,145 @@ mod tests {
                 success,
                 version,
                 error,
+                banner,
+                code,
             } => {
                 assert!(success, "Response should indicate success");
                 assert_eq!(version, Some(nexus_common::PROTOCOL_VERSION.to_string()));
                 assert!(error.is_none(), "Error should be None on success");
+                assert!(banner.is_none(), "No banner is configured by default");
+                assert!(code.is_none());
+            }
+            _ => panic!("Expected HandshakeResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handshake_includes_banner() {
+        let mut test_ctx = create_test_context().await;
+        let mut negotiated_version = None;
+
+        let banner = "Authorized use only.\nActivity is logged.";
+        test_ctx.db.config.set_server_banner(banner).await.unwrap();
+
+        let version = nexus_common::PROTOCOL_VERSION.to_string();
+        handle_handshake(
+            version,
+            &mut negotiated_version,
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::HandshakeResponse {
+                success, banner: b, ..
+            } => {
+                assert!(success);
+                assert_eq!(b.as_deref(), Some(banner));
+            }
+            _ => panic!("Expected HandshakeResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handshake_omits_banner_for_legacy_client() {
+        let mut test_ctx = create_test_context().await;
+        let mut negotiated_version = None;
+
+        test_ctx
+            .db
+            .config
+            .set_server_banner("Authorized use only.")
+            .await
+            .unwrap();
+
+        // 0.5 clients are still compatible, but can't read a banner-sized response
+        handle_handshake(
+            "0.5.0".to_string(),
+            &mut negotiated_version,
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        assert!(negotiated_version.is_some());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::HandshakeResponse {
+                success, banner, ..
+            } => {
+                assert!(success);
+                assert!(banner.is_none());
             }
             _ => panic!("Expected HandshakeResponse"),
         }
     }
 
+    #[tokio::test]
+    async fn test_min_client_version_boundary() {
+        let mut test_ctx = create_test_context().await;
+        let server_ver = version::protocol_version();
+        test_ctx
+            .db
+            .config
+            .set_min_client_version(Some(&server_ver))
+            .await
+            .unwrap();
+
+        // Exactly the minimum is accepted, pre-release tag or not
+        for client_version in [server_ver.to_string(), format!("{}-beta.1", server_ver)] {
+            let mut negotiated_version = None;
+            let result = handle_handshake(
+                client_version,
+                &mut negotiated_version,
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_ok());
+            assert!(negotiated_version.is_some());
+            read_server_message(&mut test_ctx.client).await;
+        }
+
+        // Anything older is turned away with a code the client can act on
+        if server_ver.minor > 0 {
+            let mut negotiated_version = None;
+            let client_version = format!("{}.{}.9", server_ver.major, server_ver.minor - 1);
+            let result = handle_handshake(
+                client_version.clone(),
+                &mut negotiated_version,
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_err());
+            assert!(negotiated_version.is_none());
+
+            match read_server_message(&mut test_ctx.client).await {
+                ServerMessage::HandshakeResponse {
+                    success,
+                    error,
+                    code,
+                    ..
+                } => {
+                    assert!(!success);
+                    assert_eq!(
+                        error,
+                        Some(err_version_client_too_old(
+                            DEFAULT_TEST_LOCALE,
+                            &server_ver.to_string(),
+                            &client_version,
+                        ))
+                    );
+                    assert_eq!(code.as_deref(), Some(ERROR_CODE_CLIENT_TOO_OLD));
+                }
+                _ => panic!("Expected HandshakeResponse"),
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_compatible_older_minor_version() {
         let mut test_ctx = create_test_context().await;
-        let mut handshake_complete = false;
+        let mut negotiated_version = None;
 
         // Parse the server version to create a compatible older minor version
         let server_ver = version::protocol_version();
@@ -169,7 +346,7 @@ mod tests {
             let client_version = format!("{}.{}.0", server_ver.major, server_ver.minor - 1);
             let result = handle_handshake(
                 client_version,
-                &mut handshake_complete,
+                &mut negotiated_version,
                 &mut test_ctx.handler_context(),
             )
             .await;
@@ -178,21 +355,24 @@ mod tests {
                 result.is_ok(),
                 "Handshake should succeed with older compatible minor version"
             );
-            assert!(handshake_complete, "Handshake flag should be set to true");
+            assert!(
+                negotiated_version.is_some(),
+                "Handshake should record the client version"
+            );
         }
     }
 
     #[tokio::test]
     async fn test_compatible_different_patch_version() {
         let mut test_ctx = create_test_context().await;
-        let mut handshake_complete = false;
+        let mut negotiated_version = None;
 
         // Parse the server version to create a version with different patch
         let server_ver = version::protocol_version();
         let client_version = format!("{}.{}.99", server_ver.major, server_ver.minor);
         let result = handle_handshake(
             client_version,
-            &mut handshake_complete,
+            &mut negotiated_version,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -201,20 +381,23 @@ mod tests {
             result.is_ok(),
             "Handshake should succeed with different patch version"
         );
-        assert!(handshake_complete, "Handshake flag should be set to true");
+        assert!(
+            negotiated_version.is_some(),
+            "Handshake should record the client version"
+        );
     }
 
     #[tokio::test]
     async fn test_major_version_mismatch() {
         let mut test_ctx = create_test_context().await;
-        let mut handshake_complete = false;
+        let mut negotiated_version = None;
 
         // Use a different major version
         let server_ver = version::protocol_version();
         let client_version = format!("{}.0.0", server_ver.major + 1);
         let result = handle_handshake(
             client_version,
-            &mut handshake_complete,
+            &mut negotiated_version,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -223,7 +406,10 @@ mod tests {
             result.is_err(),
             "Handshake should fail with major version mismatch"
         );
-        assert!(!handshake_complete, "Handshake flag should remain false");
+        assert!(
+            negotiated_version.is_none(),
+            "Handshake should not record a version"
+        );
 
         // Error should be about major version mismatch
         let err = result.unwrap_err();
@@ -250,14 +436,14 @@ mod tests {
     #[tokio::test]
     async fn test_client_minor_version_too_new() {
         let mut test_ctx = create_test_context().await;
-        let mut handshake_complete = false;
+        let mut negotiated_version = None;
 
         // Use a newer minor version
         let server_ver = version::protocol_version();
         let client_version = format!("{}.{}.0", server_ver.major, server_ver.minor + 1);
         let result = handle_handshake(
             client_version.clone(),
-            &mut handshake_complete,
+            &mut negotiated_version,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -266,7 +452,10 @@ mod tests {
             result.is_err(),
             "Handshake should fail when client minor version is too new"
         );
-        assert!(!handshake_complete, "Handshake flag should remain false");
+        assert!(
+            negotiated_version.is_none(),
+            "Handshake should not record a version"
+        );
 
         // Read and verify response using new framing format
         let response_msg = read_server_message(&mut test_ctx.client).await;
@@ -288,12 +477,12 @@ mod tests {
     #[tokio::test]
     async fn test_invalid_semver_format() {
         let mut test_ctx = create_test_context().await;
-        let mut handshake_complete = false;
+        let mut negotiated_version = None;
 
         // Use an invalid semver format
         let result = handle_handshake(
             "not-valid-semver".to_string(),
-            &mut handshake_complete,
+            &mut negotiated_version,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -302,7 +491,10 @@ mod tests {
             result.is_err(),
             "Handshake should fail with invalid semver format"
         );
-        assert!(!handshake_complete, "Handshake flag should remain false");
+        assert!(
+            negotiated_version.is_none(),
+            "Handshake should not record a version"
+        );
 
         // Read and verify response using new framing format
         let response_msg = read_server_message(&mut test_ctx.client).await;
@@ -324,32 +516,32 @@ mod tests {
     #[tokio::test]
     async fn test_duplicate_handshake() {
         let mut test_ctx = create_test_context().await;
-        let mut handshake_complete = false;
+        let mut negotiated_version = None;
 
         let version = nexus_common::PROTOCOL_VERSION.to_string();
 
         // First handshake - should succeed
         let result1 = handle_handshake(
             version.clone(),
-            &mut handshake_complete,
+            &mut negotiated_version,
             &mut test_ctx.handler_context(),
         )
         .await;
         assert!(result1.is_ok(), "First handshake should succeed");
         assert!(
-            handshake_complete,
+            negotiated_version.is_some(),
             "Flag should be set after first handshake"
         );
 
         // Second handshake - should fail (duplicate)
         let result2 = handle_handshake(
             version,
-            &mut handshake_complete,
+            &mut negotiated_version,
             &mut test_ctx.handler_context(),
         )
         .await;
         assert!(result2.is_err(), "Duplicate handshake should fail");
-        assert!(handshake_complete, "Flag should remain true");
+        assert!(negotiated_version.is_some(), "Flag should remain true");
 
         // Error should be about duplicate
         let err = result2.unwrap_err();
@@ -360,7 +552,7 @@ mod tests {
     #[tokio::test]
     async fn test_prerelease_version_compatible() {
         let mut test_ctx = create_test_context().await;
-        let mut handshake_complete = false;
+        let mut negotiated_version = None;
 
         // Pre-release versions should be compatible based on their base version
         let server_ver = version::protocol_version();
@@ -370,7 +562,7 @@ mod tests {
         );
         let result = handle_handshake(
             client_version,
-            &mut handshake_complete,
+            &mut negotiated_version,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -379,6 +571,9 @@ mod tests {
             result.is_ok(),
             "Handshake should succeed with pre-release version of same base"
         );
-        assert!(handshake_complete, "Handshake flag should be set to true");
+        assert!(
+            negotiated_version.is_some(),
+            "Handshake should record the client version"
+        );
     }
 }
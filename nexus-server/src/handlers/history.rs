@@ -0,0 +1,219 @@
+//! Handler for HistoryRequest command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{MAX_HISTORY_PAGE_SIZE, ServerMessage};
+
+use super::{
+    HandlerContext, err_authentication, err_chat_feature_not_enabled, err_command_cooldown,
+    err_database, err_feature_disabled, err_not_logged_in, err_permission_denied,
+};
+use crate::constants::{FEATURE_CHAT, HISTORY_REQUEST_INTERVAL_SECONDS};
+use crate::db::Permission;
+
+/// Handle HistoryRequest command
+///
+/// Returns a page of persisted #server chat history older than
+/// `before_message_id`, so clients can load more as the user scrolls up.
+/// Pages hold at most `MAX_HISTORY_PAGE_SIZE` messages, and each session may
+/// ask at most once per `HISTORY_REQUEST_INTERVAL_SECONDS` (or the
+/// configured command cooldown, if longer, for non-admins).
+pub async fn handle_history_request<W>(
+    before_message_id: Option<u64>,
+    limit: u32,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("HistoryRequest from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("HistoryRequest"))
+            .await;
+    };
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(id).await else {
+        return ctx
+            .send_error(&err_authentication(ctx.locale), Some("HistoryRequest"))
+            .await;
+    };
+
+    if !user.has_feature(FEATURE_CHAT) {
+        return send_failure(err_chat_feature_not_enabled(ctx.locale), ctx).await;
+    }
+
+    if ctx
+        .permission_disabled(Permission::ChatReceive, user.is_admin)
+        .await
+    {
+        return send_failure(err_feature_disabled(ctx.locale), ctx).await;
+    }
+
+    if !user.has_permission(Permission::ChatReceive) {
+        eprintln!(
+            "HistoryRequest from {} (user: {}) without permission",
+            ctx.peer_addr, user.username
+        );
+        return send_failure(err_permission_denied(ctx.locale), ctx).await;
+    }
+
+    let interval = if user.is_admin {
+        HISTORY_REQUEST_INTERVAL_SECONDS
+    } else {
+        ctx.db
+            .config
+            .get_command_cooldown_seconds()
+            .await
+            .max(HISTORY_REQUEST_INTERVAL_SECONDS)
+    };
+    if let Some(remaining) = ctx
+        .user_manager
+        .check_command_cooldown(id, "HistoryRequest", interval)
+        .await
+    {
+        return send_failure(err_command_cooldown(ctx.locale, remaining), ctx).await;
+    }
+
+    let limit = (limit as usize).clamp(1, MAX_HISTORY_PAGE_SIZE);
+    let (messages, has_more) = match ctx.db.chat.history_page(before_message_id, limit).await {
+        Ok(page) => page,
+        Err(e) => {
+            eprintln!("Database error reading chat history: {}", e);
+            return send_failure(err_database(ctx.locale), ctx).await;
+        }
+    };
+
+    let response = ServerMessage::HistoryResponse {
+        success: true,
+        error: None,
+        messages: Some(messages),
+        has_more: Some(has_more),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send an unsuccessful `HistoryResponse`
+async fn send_failure<W>(error: String, ctx: &mut HandlerContext<'_, W>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::HistoryResponse {
+        success: false,
+        error: Some(error),
+        messages: None,
+        has_more: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        TestContext, create_test_context, login_user, login_user_with_features, read_server_message,
+    };
+
+    /// Log in a user who can read chat
+    async fn login_reader(test_ctx: &mut TestContext) -> u32 {
+        login_user_with_features(
+            test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatReceive],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_history_request_pages() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_reader(&mut test_ctx).await;
+        for id in 1..=3u64 {
+            test_ctx
+                .db
+                .chat
+                .record_message(id, "bob", &format!("message {id}"))
+                .await
+                .unwrap();
+        }
+
+        let result = handle_history_request(
+            Some(3),
+            1,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::HistoryResponse {
+                success,
+                messages,
+                has_more,
+                ..
+            } => {
+                assert!(success);
+                let messages = messages.expect("messages");
+                assert_eq!(messages.len(), 1);
+                assert_eq!(messages[0].message_id, 2);
+                assert_eq!(messages[0].message, "message 2");
+                assert_eq!(has_more, Some(true));
+            }
+            other => panic!("Expected HistoryResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_request_is_rate_limited() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_reader(&mut test_ctx).await;
+
+        for expect_success in [true, false] {
+            handle_history_request(None, 10, Some(session_id), &mut test_ctx.handler_context())
+                .await
+                .unwrap();
+            match read_server_message(&mut test_ctx.client).await {
+                ServerMessage::HistoryResponse {
+                    success,
+                    error,
+                    has_more,
+                    ..
+                } => {
+                    assert_eq!(success, expect_success);
+                    assert_eq!(error.is_some(), !expect_success);
+                    if expect_success {
+                        assert_eq!(has_more, Some(false));
+                    }
+                }
+                other => panic!("Expected HistoryResponse, got {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_request_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        handle_history_request(None, 10, Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::HistoryResponse {
+                success, messages, ..
+            } => {
+                assert!(!success);
+                assert!(messages.is_none());
+            }
+            other => panic!("Expected HistoryResponse, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! Handler for KickSession command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{ERROR_CODE_KICKED, ServerMessage};
+
+use super::{
+    HandlerContext, err_authentication, err_cannot_kick_admin, err_cannot_kick_own_session,
+    err_feature_disabled, err_kicked_by, err_not_logged_in, err_permission_denied,
+    err_session_not_found,
+};
+use crate::db::Permission;
+
+/// Handle KickSession command
+///
+/// Disconnects a single session (one device) and leaves the user's other
+/// sessions online. Unlike `UserKick` this doesn't touch the account, so
+/// admins may disconnect another admin's device; only the requester's own
+/// current session is off limits.
+pub async fn handle_kick_session<W>(
+    target_session_id: u32,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        eprintln!("KickSession request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("KickSession"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user_session = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("KickSession"))
+                .await;
+        }
+    };
+
+    // Check whether UserKick is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserKick, requesting_user_session.is_admin)
+        .await
+    {
+        return send_kick_session_response(ctx, Some(err_feature_disabled(ctx.locale))).await;
+    }
+
+    // Check UserKick permission (uses cached permissions, admin bypass built-in)
+    if !requesting_user_session.has_permission(Permission::UserKick) {
+        eprintln!(
+            "KickSession from {} (user: {}) without permission",
+            ctx.peer_addr, requesting_user_session.username
+        );
+        return send_kick_session_response(ctx, Some(err_permission_denied(ctx.locale))).await;
+    }
+
+    // Disconnecting the session that sent the request is never intended
+    if target_session_id == session_id {
+        return send_kick_session_response(ctx, Some(err_cannot_kick_own_session(ctx.locale)))
+            .await;
+    }
+
+    let Some(target) = ctx
+        .user_manager
+        .get_user_by_session_id(target_session_id)
+        .await
+    else {
+        let error = err_session_not_found(ctx.locale, target_session_id);
+        return send_kick_session_response(ctx, Some(error)).await;
+    };
+
+    // Only admins may disconnect an admin's devices
+    if target.is_admin && !requesting_user_session.is_admin {
+        return send_kick_session_response(ctx, Some(err_cannot_kick_admin(ctx.locale))).await;
+    }
+
+    // Tell the session why before disconnecting it
+    let kick_msg = ServerMessage::Error {
+        message: err_kicked_by(&target.locale, &requesting_user_session.username),
+        command: None,
+        code: Some(ERROR_CODE_KICKED.to_string()),
+    };
+    let _ = target.tx.send((kick_msg, None));
+
+    // Remove only this session and close its connection
+    if let Some(removed_user) = ctx.user_manager.disconnect_user(target_session_id).await {
+        ctx.user_manager
+            .broadcast_user_event(
+                ServerMessage::UserDisconnected {
+                    session_id: target_session_id,
+                    username: removed_user.username,
+                },
+                &ctx.db.users,
+                Some(target_session_id),
+            )
+            .await;
+    }
+
+    send_kick_session_response(ctx, None).await
+}
+
+/// Send a KickSessionResponse (success when there is no error)
+async fn send_kick_session_response<W>(
+    ctx: &mut HandlerContext<'_, W>,
+    error: Option<String>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::KickSessionResponse {
+        success: error.is_none(),
+        error,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user, read_server_message,
+    };
+    use crate::users::user::NewSessionParams;
+
+    /// Add another session for a user who is already logged in
+    async fn add_session(test_ctx: &mut TestContext, username: &str, is_admin: bool) -> u32 {
+        let user = test_ctx
+            .db
+            .users
+            .get_user_by_username(username)
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: user.id,
+                username: user.username.clone(),
+                is_admin,
+                permissions: std::collections::HashSet::new(),
+                address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
+                created_at: user.created_at,
+                tx: test_ctx.tx.clone(),
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
+            })
+            .await
+    }
+
+    async fn read_kick_session_response(test_ctx: &mut TestContext) -> Option<String> {
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::KickSessionResponse { success, error } => {
+                assert_eq!(success, error.is_none());
+                error
+            }
+            other => panic!("Expected KickSessionResponse, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_kick_session_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_kick_session(1, None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "KickSession should require login");
+    }
+
+    #[tokio::test]
+    async fn test_kick_session_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let target_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        let result =
+            handle_kick_session(target_id, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        assert_eq!(
+            read_kick_session_response(&mut test_ctx).await,
+            Some(err_permission_denied(DEFAULT_TEST_LOCALE))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kick_session_leaves_other_sessions_online() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserKick],
+            false,
+        )
+        .await;
+        let phone = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        let laptop = add_session(&mut test_ctx, "bob", false).await;
+
+        let result =
+            handle_kick_session(phone, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+        assert_eq!(read_kick_session_response(&mut test_ctx).await, None);
+
+        assert!(
+            test_ctx
+                .user_manager
+                .get_user_by_session_id(phone)
+                .await
+                .is_none()
+        );
+        assert!(
+            test_ctx
+                .user_manager
+                .get_user_by_session_id(laptop)
+                .await
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kick_session_own_other_device() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let other_device = add_session(&mut test_ctx, "admin", true).await;
+
+        let result = handle_kick_session(
+            other_device,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(read_kick_session_response(&mut test_ctx).await, None);
+
+        // The requesting session stays online
+        assert!(
+            test_ctx
+                .user_manager
+                .get_user_by_session_id(session_id)
+                .await
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kick_session_cannot_kick_current_session() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_kick_session(
+            session_id,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        assert_eq!(
+            read_kick_session_response(&mut test_ctx).await,
+            Some(err_cannot_kick_own_session(DEFAULT_TEST_LOCALE))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kick_session_unknown_session() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result =
+            handle_kick_session(999, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        assert_eq!(
+            read_kick_session_response(&mut test_ctx).await,
+            Some(err_session_not_found(DEFAULT_TEST_LOCALE, 999))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kick_session_admin_device() {
+        let mut test_ctx = create_test_context().await;
+        let kicker = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserKick],
+            false,
+        )
+        .await;
+        let admin_device = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let other_admin = login_user(&mut test_ctx, "root", "password", &[], true).await;
+
+        // Non-admins can't touch an admin's sessions
+        let result =
+            handle_kick_session(admin_device, Some(kicker), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            read_kick_session_response(&mut test_ctx).await,
+            Some(err_cannot_kick_admin(DEFAULT_TEST_LOCALE))
+        );
+
+        // Another admin can
+        let result = handle_kick_session(
+            admin_device,
+            Some(other_admin),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(read_kick_session_response(&mut test_ctx).await, None);
+    }
+}
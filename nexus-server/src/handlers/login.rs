@@ -4,24 +4,32 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::{ChatInfo, ServerInfo, ServerMessage, UserInfo};
+use nexus_common::protocol::{
+    ChatInfo, ERROR_CODE_SESSION_REPLACED, ServerInfo, ServerMessage, UserInfo,
+};
 use nexus_common::validators::{
     self, AvatarError, FeaturesError, LocaleError, PasswordError, UsernameError,
 };
 
 use super::{
     HandlerContext, current_timestamp, err_account_disabled, err_already_logged_in,
-    err_authentication, err_avatar_invalid_format, err_avatar_too_large,
+    err_authentication, err_avatar_dimensions_too_large, err_avatar_dimensions_too_small,
+    err_avatar_invalid_format, err_avatar_too_large, err_avatar_undecodable,
     err_avatar_unsupported_type, err_database, err_failed_to_create_user,
     err_features_empty_feature, err_features_feature_too_long, err_features_invalid_characters,
-    err_features_too_many, err_handshake_required, err_invalid_credentials,
+    err_features_too_many, err_guest_name_in_use, err_handshake_required, err_invalid_credentials,
     err_locale_invalid_characters, err_locale_too_long, err_password_empty, err_password_too_long,
-    err_username_empty, err_username_invalid, err_username_too_long,
+    err_session_limit, err_session_replaced, err_username_empty, err_username_invalid,
+    err_username_too_long,
 };
+use crate::avatar::{AvatarImageError, check_avatar_image};
 #[cfg(test)]
 use crate::constants::FEATURE_CHAT;
-use crate::db::{self, Permission};
-use crate::users::user::NewSessionParams;
+use crate::constants::GUEST_USERNAME_PREFIX;
+use crate::db::users::UserAccount;
+use crate::db::{self, GUEST_PERMISSIONS, Permission, SessionLimitPolicy, TOPIC_VIEW_PERMISSIONS};
+use crate::i18n::resolve_locale;
+use crate::users::user::{AddSessionError, NewSessionParams, notable_permissions};
 
 /// Login request parameters
 pub struct LoginRequest {
@@ -47,11 +55,14 @@ where
         username,
         password,
         features,
-        locale,
+        locale: requested_locale,
         avatar,
         handshake_complete,
     } = request;
 
+    // A client that doesn't name a supported locale is answered in the server's default
+    let locale = resolve_locale(&requested_locale, &ctx.db.config.get_default_locale().await);
+
     // Verify handshake completed
     if !handshake_complete {
         eprintln!("Login attempt from {} without handshake", ctx.peer_addr);
@@ -82,8 +93,15 @@ where
             .await;
     }
 
-    // Validate password
-    if let Err(e) = validators::validate_password(&password) {
+    // A guest name logs in as a guest while guest login is on (unless an
+    // account already has that name)
+    let guest_login = ctx.db.config.get_guest_login().await;
+    let guest_name = guest_login.enabled && is_guest_username(&username);
+
+    // Validate password (guests may leave it empty)
+    if let Err(e) = validators::validate_password(&password)
+        && !(guest_name && e == PasswordError::Empty)
+    {
         let error_msg = match e {
             PasswordError::Empty => err_password_empty(&locale),
             PasswordError::TooLong => {
@@ -96,7 +114,7 @@ where
     }
 
     // Validate locale
-    if let Err(e) = validators::validate_locale(&locale) {
+    if let Err(e) = validators::validate_locale(&requested_locale) {
         let error_msg = match e {
             LocaleError::TooLong => err_locale_too_long(&locale, validators::MAX_LOCALE_LENGTH),
             LocaleError::InvalidCharacters => err_locale_invalid_characters(&locale),
@@ -150,7 +168,8 @@ where
         }
     };
 
-    // Authenticate user or create first admin
+    // Authenticate user, log in a guest, or create first admin
+    let is_guest = guest_name && account.is_none();
     let authenticated_account = if let Some(account) = account {
         // User exists - verify password
         match db::verify_password(&password, &account.hashed_password) {
@@ -186,6 +205,20 @@ where
                     .await;
             }
         }
+    } else if is_guest {
+        if let Some(hash) = &guest_login.password_hash
+            && !db::verify_password(&password, hash).unwrap_or(false)
+        {
+            eprintln!(
+                "Guest login from {} failed: wrong guest password for {}",
+                ctx.peer_addr, username
+            );
+            return ctx
+                .send_error_and_disconnect(&err_invalid_credentials(&locale), Some("Login"))
+                .await;
+        }
+
+        UserAccount::guest(&username, current_timestamp())
     } else {
         // User doesn't exist - try to create as first user (atomic operation)
         let hashed_password = match db::hash_password(&password) {
@@ -234,8 +267,68 @@ where
         }
     };
 
+    // Check raster avatar dimensions, downscaling oversized ones if enabled.
+    // Decoding is expensive, so it waits until the login has authenticated
+    // and runs off the async workers.
+    let avatar = match avatar {
+        Some(avatar_data) => {
+            let limits = ctx.db.config.get_avatar_limits().await;
+            let checked = tokio::task::spawn_blocking(move || {
+                check_avatar_image(&avatar_data, limits).map(|scaled| scaled.unwrap_or(avatar_data))
+            })
+            .await
+            .unwrap_or_else(|e| {
+                eprintln!("Avatar check for {} failed: {}", username, e);
+                Err(AvatarImageError::Undecodable)
+            });
+            match checked {
+                Ok(avatar_data) => Some(avatar_data),
+                Err(e) => {
+                    let error_msg = match e {
+                        AvatarImageError::Undecodable => err_avatar_undecodable(&locale),
+                        AvatarImageError::TooSmall => {
+                            err_avatar_dimensions_too_small(&locale, limits.min_dimension)
+                        }
+                        AvatarImageError::TooLarge => {
+                            err_avatar_dimensions_too_large(&locale, limits.max_dimension)
+                        }
+                    };
+                    return ctx
+                        .send_error_and_disconnect(&error_msg, Some("Login"))
+                        .await;
+                }
+            }
+        }
+        None => None,
+    };
+
+    // Cap concurrent sessions per account (admins may be exempt; guest names
+    // are never shared). Replacing makes room here; the limit itself is
+    // enforced when the session is added, so simultaneous logins can't
+    // exceed it.
+    let session_limit = ctx.db.config.get_session_limit().await;
+    let max_sessions = (!is_guest && session_limit.applies_to(authenticated_account.is_admin))
+        .then_some(session_limit.max_sessions as usize);
+    if let Some(max_sessions) = max_sessions
+        && matches!(session_limit.policy, SessionLimitPolicy::ReplaceOldest)
+    {
+        let mut existing = ctx
+            .user_manager
+            .get_session_ids_for_user(&authenticated_account.username)
+            .await;
+        if existing.len() >= max_sessions {
+            // Session IDs increase with every login, so the lowest are the oldest
+            existing.sort_unstable();
+            for &oldest in &existing[..=existing.len() - max_sessions] {
+                replace_session(oldest, ctx).await;
+            }
+        }
+    }
+
     // Fetch user permissions from database (used for both caching and LoginResponse)
-    let cached_permissions = if authenticated_account.is_admin {
+    let cached_permissions = if is_guest {
+        GUEST_PERMISSIONS.iter().copied().collect()
+    } else if authenticated_account.is_admin {
         // Admins bypass permission checks, so we can use an empty set
         std::collections::HashSet::new()
     } else {
@@ -259,23 +352,61 @@ where
     // Create session in UserManager with cached permissions
     // Note: Features are client preferences (what they want to subscribe to)
     // Permissions are now cached in the User struct to avoid DB lookups during broadcasts
-    let id = ctx
-        .user_manager
-        .add_user(NewSessionParams {
-            session_id: 0, // Will be assigned by add_user
-            db_user_id: authenticated_account.id,
-            username: authenticated_account.username.clone(),
-            is_admin: authenticated_account.is_admin,
-            permissions: cached_permissions.clone(),
-            address: ctx.peer_addr,
-            created_at: authenticated_account.created_at,
-            tx: ctx.tx.clone(),
-            features,
-            locale: locale.clone(),
-            avatar: avatar.clone(),
-        })
-        .await;
+    let params = NewSessionParams {
+        session_id: 0,                        // Assigned when the session is added
+        db_user_id: authenticated_account.id, // Replaced by add_session for guests
+        username: authenticated_account.username.clone(),
+        is_admin: authenticated_account.is_admin,
+        permissions: cached_permissions.clone(),
+        address: ctx.peer_addr,
+        source: ctx.source.to_string(),
+        created_at: authenticated_account.created_at,
+        tx: ctx.tx.clone(),
+        features,
+        locale: locale.clone(),
+        avatar: avatar.clone(),
+        is_guest,
+        legacy_protocol: ctx.legacy_protocol,
+    };
+    let id = match ctx.user_manager.add_session(params, max_sessions).await {
+        Ok(id) => id,
+        Err(AddSessionError::SessionLimit) => {
+            eprintln!(
+                "Login from {} refused: {} already has {} sessions",
+                ctx.peer_addr, authenticated_account.username, session_limit.max_sessions
+            );
+            return ctx
+                .send_error_and_disconnect(
+                    &err_session_limit(&locale, session_limit.max_sessions),
+                    Some("Login"),
+                )
+                .await;
+        }
+        // Each guest name is one person, so it can't be shared
+        Err(AddSessionError::GuestNameInUse) => {
+            return ctx
+                .send_error_and_disconnect(
+                    &err_guest_name_in_use(&locale, &username),
+                    Some("Login"),
+                )
+                .await;
+        }
+    };
     *session_id = Some(id);
+    if is_guest {
+        println!("Guest '{}' logged in from {}", username, ctx.peer_addr);
+    }
+
+    // Record the login for /seen (the disconnect updates it again)
+    if !is_guest
+        && let Err(e) = ctx
+            .db
+            .users
+            .set_last_seen(authenticated_account.id, chrono::Utc::now().timestamp())
+            .await
+    {
+        eprintln!("Database error recording last seen: {}", e);
+    }
 
     // Convert cached permissions to strings for LoginResponse
     let user_permissions: Vec<String> = if authenticated_account.is_admin {
@@ -293,6 +424,9 @@ where
     let name = ctx.db.config.get_server_name().await;
     let description = ctx.db.config.get_server_description().await;
     let image = ctx.db.config.get_server_image().await;
+    let chat_slow_mode = ctx.db.config.get_chat_slow_mode_seconds().await;
+    let max_message_length = ctx.db.config.get_max_message_length().await as u32;
+    let max_topic_length = ctx.db.config.get_max_topic_length().await as u32;
 
     // Fetch max connections per IP (admin only)
     let max_connections_per_ip = if authenticated_account.is_admin {
@@ -301,33 +435,128 @@ where
         None
     };
 
+    // Online user count is only shared with users who can already see the user list
+    let user_count =
+        if authenticated_account.is_admin || cached_permissions.contains(&Permission::UserList) {
+            Some(ctx.user_manager.get_online_user_count().await as u32)
+        } else {
+            None
+        };
+
     let server_info = Some(ServerInfo {
         name: Some(name),
         description: Some(description),
         version: Some(env!("CARGO_PKG_VERSION").to_string()),
         max_connections_per_ip,
         image: Some(image),
+        chat_slow_mode: Some(chat_slow_mode),
+        user_count,
+        max_message_length: Some(max_message_length),
+        max_topic_length: Some(max_topic_length),
     });
 
-    // Fetch chat info only if user has ChatTopic permission
-    let chat_info =
-        if authenticated_account.is_admin || cached_permissions.contains(&Permission::ChatTopic) {
-            match ctx.db.chat.get_topic().await {
-                Ok(topic) => Some(ChatInfo {
-                    topic: topic.topic,
-                    topic_set_by: topic.set_by,
-                }),
-                Err(e) => {
-                    eprintln!(
-                        "Error fetching chat topic for {}: {}",
-                        authenticated_account.username, e
-                    );
-                    None
-                }
+    // Fetch chat info only if user can see the topic
+    let can_view_topic = authenticated_account.is_admin
+        || TOPIC_VIEW_PERMISSIONS
+            .iter()
+            .any(|p| cached_permissions.contains(p));
+    let chat_info = if can_view_topic {
+        match ctx.db.chat.get_topic().await {
+            Ok(topic) => Some(ChatInfo {
+                topic: topic.topic,
+                topic_set_by: topic.set_by,
+            }),
+            Err(e) => {
+                eprintln!(
+                    "Error fetching chat topic for {}: {}",
+                    authenticated_account.username, e
+                );
+                None
             }
-        } else {
-            None
-        };
+        }
+    } else {
+        None
+    };
+
+    // Pinned messages are chat content, so they follow the ChatReceive permission
+    let pinned_messages = if authenticated_account.is_admin
+        || cached_permissions.contains(&Permission::ChatReceive)
+    {
+        match ctx.db.chat.get_pinned_messages().await {
+            Ok(pinned) => Some(pinned),
+            Err(e) => {
+                eprintln!(
+                    "Error fetching pinned messages for {}: {}",
+                    authenticated_account.username, e
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Stored preferences are opt-in on the client; unparseable values are dropped
+    let preferences = if is_guest {
+        None
+    } else {
+        match ctx.db.users.get_preferences(authenticated_account.id).await {
+            Ok(stored) => stored.and_then(|json| serde_json::from_str(&json).ok()),
+            Err(e) => {
+                eprintln!(
+                    "Error fetching preferences for {}: {}",
+                    authenticated_account.username, e
+                );
+                None
+            }
+        }
+    };
+
+    // Rules are only sent until the user accepts them (empty rules disable the gate)
+    let server_rules = ctx.db.config.get_server_rules().await;
+    let rules = if server_rules.is_empty() {
+        None
+    } else if is_guest {
+        // A guest has never accepted them
+        Some(server_rules)
+    } else {
+        match ctx
+            .db
+            .users
+            .has_accepted_rules(authenticated_account.id)
+            .await
+        {
+            Ok(true) => None,
+            Ok(false) => Some(server_rules),
+            Err(e) => {
+                eprintln!(
+                    "Error checking rules acceptance for {}: {}",
+                    authenticated_account.username, e
+                );
+                Some(server_rules)
+            }
+        }
+    };
+
+    // Let the client show the maintenance banner right away
+    let maintenance = ctx.db.config.get_maintenance_mode().await;
+
+    // Let the client hide commands the server has switched off
+    let disabled_permissions = ctx
+        .db
+        .config
+        .get_disabled_permissions_for(authenticated_account.is_admin)
+        .await;
+    let disabled_permissions =
+        (!disabled_permissions.is_empty()).then(|| disabled_permissions.names());
+
+    // 0.5 clients ignore these, and they could push the response past the
+    // 0.5 size limit, which would drop it
+    let (pinned_messages, preferences, rules) = if ctx.legacy_protocol {
+        (None, None, None)
+    } else {
+        (pinned_messages, preferences, rules)
+    };
 
     let response = ServerMessage::LoginResponse {
         success: true,
@@ -337,6 +566,11 @@ where
         server_info,
         chat_info,
         locale: Some(locale.clone()),
+        pinned_messages,
+        preferences,
+        rules,
+        maintenance: Some(maintenance),
+        disabled_permissions,
         error: None,
     };
     ctx.send_message(&response).await?;
@@ -350,6 +584,12 @@ where
         username,
         login_time: current_timestamp(),
         is_admin: authenticated_account.is_admin,
+        is_moderator: !authenticated_account.is_admin
+            && cached_permissions.contains(&Permission::ChatModerate),
+        away: false, // A new session is never away
+        is_guest,
+        permissions: (!authenticated_account.is_admin)
+            .then(|| notable_permissions(&cached_permissions)),
         session_ids: vec![id],
         locale: locale.clone(),
         avatar,
@@ -362,13 +602,77 @@ where
         )
         .await;
 
+    // Let the account's other sessions spot a login they don't recognize
+    if !is_guest && ctx.db.config.get_login_notices().await {
+        let notice = ServerMessage::NewLogin {
+            session_id: id,
+            address: ctx.peer_addr.ip().to_string(),
+            source: ctx.source.to_string(),
+        };
+        ctx.user_manager
+            .broadcast_to_other_sessions(authenticated_account.id, id, &notice, &ctx.db.users)
+            .await;
+    }
+
     Ok(())
 }
 
+/// Whether a username is a guest name (the guest prefix and at least one more character)
+pub(super) fn is_guest_username(username: &str) -> bool {
+    username.len() > GUEST_USERNAME_PREFIX.len()
+        && username
+            .get(..GUEST_USERNAME_PREFIX.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(GUEST_USERNAME_PREFIX))
+}
+
+/// Disconnect a session to make room for a newer login on the same account
+async fn replace_session<W>(target_session_id: u32, ctx: &HandlerContext<'_, W>)
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(target) = ctx
+        .user_manager
+        .get_user_by_session_id(target_session_id)
+        .await
+    else {
+        return;
+    };
+
+    // Tell the session why before disconnecting it
+    let notice = ServerMessage::Error {
+        message: err_session_replaced(&target.locale),
+        command: None,
+        code: Some(ERROR_CODE_SESSION_REPLACED.to_string()),
+    };
+    let _ = target.tx.send((notice, None));
+
+    // Disconnect only this session
+    if let Some(removed_user) = ctx.user_manager.disconnect_user(target_session_id).await {
+        println!(
+            "Replaced session {} of {} from {} (session limit)",
+            target_session_id, removed_user.username, removed_user.address
+        );
+        ctx.user_manager
+            .broadcast_user_event(
+                ServerMessage::UserDisconnected {
+                    session_id: target_session_id,
+                    username: removed_user.username,
+                },
+                &ctx.db.users,
+                Some(target_session_id),
+            )
+            .await;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::handlers::testing::{DEFAULT_TEST_LOCALE, create_test_context, read_server_message};
+    use crate::constants::{DEFAULT_AVATAR_MAX_DIMENSION, DEFAULT_AVATAR_MIN_DIMENSION};
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_guest, login_user,
+        png_data_uri, read_server_message,
+    };
 
     #[tokio::test]
     async fn test_login_requires_handshake() {
@@ -887,6 +1191,10 @@ mod tests {
                     info.max_connections_per_ip.is_none(),
                     "Non-admin should not receive max_connections_per_ip"
                 );
+                assert!(
+                    info.user_count.is_none(),
+                    "Should NOT include user_count without user_list permission"
+                );
                 assert!(
                     chat_info.is_none(),
                     "Should NOT include chat_info without permission"
@@ -896,6 +1204,54 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_login_includes_topic_for_topic_editor() {
+        let mut test_ctx = create_test_context().await;
+
+        // Create user who can set the topic but lacks chat_topic
+        let password = "password";
+        let hashed = db::hash_password(password).unwrap();
+        let mut perms = db::Permissions::new();
+        perms.permissions.insert(Permission::ChatTopicEdit);
+        test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &perms)
+            .await
+            .unwrap();
+
+        test_ctx
+            .db
+            .chat
+            .set_topic("Editable topic", "admin")
+            .await
+            .unwrap();
+
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: password.to_string(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Login should succeed");
+
+        // Setting the topic implies seeing it
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::LoginResponse {
+                success, chat_info, ..
+            } => {
+                assert!(success, "Login should succeed");
+                let chat = chat_info.expect("Topic editor should receive chat_info");
+                assert_eq!(chat.topic, "Editable topic");
+            }
+            _ => panic!("Expected LoginResponse"),
+        }
+    }
+
     #[tokio::test]
     async fn test_login_admin_receives_server_info() {
         let mut test_ctx = create_test_context().await;
@@ -955,6 +1311,7 @@ mod tests {
                     Some(5),
                     "Admin should receive max_connections_per_ip"
                 );
+                assert_eq!(info.user_count, Some(1), "Admin should receive user_count");
                 assert!(chat_info.is_some(), "Admin should receive chat_info");
                 let chat = chat_info.unwrap();
                 assert_eq!(chat.topic, "Admin can see this");
@@ -964,135 +1321,516 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_login_disabled_account() {
+    async fn test_login_user_count_with_user_list_permission() {
         let mut test_ctx = create_test_context().await;
 
-        // Create a user first (so we're not the first user)
-        let password = "password";
-        let hashed = db::hash_password(password).unwrap();
-        test_ctx
+        // Another user already online with two sessions (counted once)
+        login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        let bob = test_ctx
             .db
             .users
-            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .get_user_by_username("bob")
             .await
+            .unwrap()
             .unwrap();
+        test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: bob.id,
+                username: bob.username.clone(),
+                is_admin: false,
+                permissions: std::collections::HashSet::new(),
+                address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
+                created_at: bob.created_at,
+                tx: test_ctx.tx.clone(),
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
+            })
+            .await;
 
-        // Create a disabled user
-        let bob_account = test_ctx
+        let password = "password";
+        let hashed = db::hash_password(password).unwrap();
+        let mut perms = db::Permissions::new();
+        perms.permissions.insert(Permission::UserList);
+        test_ctx
             .db
             .users
-            .create_user("bob", &hashed, false, false, &db::Permissions::new())
+            .create_user("alice", &hashed, false, true, &perms)
             .await
             .unwrap();
 
-        assert!(!bob_account.enabled, "Bob should be disabled");
-
         let mut session_id = None;
-        let handshake_complete = true;
-
-        // Attempt login with disabled account
         let request = LoginRequest {
-            username: "bob".to_string(),
+            username: "alice".to_string(),
             password: password.to_string(),
             features: vec![],
             locale: DEFAULT_TEST_LOCALE.to_string(),
             avatar: None,
-            handshake_complete,
+            handshake_complete: true,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Login should succeed");
 
-        // Should fail with disconnect
-        assert!(result.is_err(), "Login with disabled account should fail");
-        assert!(session_id.is_none(), "Session ID should remain None");
-
-        // Verify error message was sent
         let response_msg = read_server_message(&mut test_ctx.client).await;
         match response_msg {
-            ServerMessage::Error { message, .. } => {
-                assert!(
-                    message.contains("Account")
-                        && message.contains("bob")
-                        && message.contains("disabled"),
-                    "Should receive account disabled error with username, got: {}",
-                    message
-                );
+            ServerMessage::LoginResponse { server_info, .. } => {
+                let info = server_info.expect("Should include server_info");
+                // bob (2 sessions) + alice
+                assert_eq!(info.user_count, Some(2));
             }
-            _ => panic!("Expected Error message"),
+            _ => panic!("Expected LoginResponse"),
         }
     }
 
     #[tokio::test]
-    async fn test_login_error_uses_requested_locale() {
+    async fn test_login_includes_stored_preferences() {
         let mut test_ctx = create_test_context().await;
 
-        // Create a user first (so we're not the first user)
         let password = "password";
         let hashed = db::hash_password(password).unwrap();
-        test_ctx
+        let account = test_ctx
             .db
             .users
             .create_user("alice", &hashed, false, true, &db::Permissions::new())
             .await
             .unwrap();
+        test_ctx
+            .db
+            .users
+            .set_preferences(account.id, r#"{"theme":"Dark"}"#)
+            .await
+            .unwrap();
 
         let mut session_id = None;
-        let handshake_complete = true;
-
-        // Attempt login with wrong password using Spanish locale
         let request = LoginRequest {
             username: "alice".to_string(),
-            password: "wrong_password".to_string(),
+            password: password.to_string(),
             features: vec![],
-            locale: "es".to_string(), // Request Spanish locale
+            locale: DEFAULT_TEST_LOCALE.to_string(),
             avatar: None,
-            handshake_complete,
+            handshake_complete: true,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Login should succeed");
 
-        // Should fail with disconnect
-        assert!(result.is_err(), "Login with wrong password should fail");
-        assert!(session_id.is_none(), "Session ID should remain None");
-
-        // Verify error message was sent in Spanish
         let response_msg = read_server_message(&mut test_ctx.client).await;
         match response_msg {
-            ServerMessage::Error { message, .. } => {
-                // Spanish error message should contain "Usuario o contraseña" (not English "Invalid username or password")
-                assert!(
-                    message.contains("Usuario") || message.contains("contraseña"),
-                    "Error message should be in Spanish, got: {}",
-                    message
-                );
+            ServerMessage::LoginResponse { preferences, .. } => {
+                assert_eq!(preferences, Some(serde_json::json!({"theme": "Dark"})));
             }
-            _ => panic!("Expected Error message"),
+            _ => panic!("Expected LoginResponse"),
         }
     }
 
-    #[tokio::test]
-    async fn test_login_error_defaults_to_english() {
+    /// Log in as a fresh "alice" account on a server with rules, optionally
+    /// accepting them first, and return the rules sent in the LoginResponse
+    async fn login_with_rules(accepted: bool) -> Option<String> {
         let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_server_rules("Be nice")
+            .await
+            .unwrap();
 
-        // Create a user first (so we're not the first user)
         let password = "password";
         let hashed = db::hash_password(password).unwrap();
-        test_ctx
+        let account = test_ctx
             .db
             .users
             .create_user("alice", &hashed, false, true, &db::Permissions::new())
             .await
             .unwrap();
+        if accepted {
+            test_ctx.db.users.accept_rules(account.id).await.unwrap();
+        }
 
         let mut session_id = None;
-        let handshake_complete = true;
-
-        // Attempt login with wrong password using empty locale (should default to "en")
         let request = LoginRequest {
             username: "alice".to_string(),
-            password: "wrong_password".to_string(),
+            password: password.to_string(),
             features: vec![],
-            locale: "".to_string(), // Empty locale should default to English
+            locale: DEFAULT_TEST_LOCALE.to_string(),
             avatar: None,
-            handshake_complete,
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Login should succeed");
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::LoginResponse { rules, .. } => rules,
+            _ => panic!("Expected LoginResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_includes_rules_until_accepted() {
+        assert_eq!(login_with_rules(false).await.as_deref(), Some("Be nice"));
+        assert_eq!(login_with_rules(true).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_login_reports_maintenance() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.db.config.set_maintenance_mode(true).await.unwrap();
+
+        let password = "password";
+        let hashed = db::hash_password(password).unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: password.to_string(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Login should succeed");
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::LoginResponse { maintenance, .. } => {
+                assert_eq!(maintenance, Some(true));
+            }
+            _ => panic!("Expected LoginResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_reports_disabled_permissions() {
+        let mut test_ctx = create_test_context().await;
+        let disabled: db::DisabledPermissions = "user_broadcast".parse().unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions(&disabled)
+            .await
+            .unwrap();
+
+        let password = "password";
+        let hashed = db::hash_password(password).unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: password.to_string(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Login should succeed");
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::LoginResponse {
+                disabled_permissions,
+                ..
+            } => {
+                assert_eq!(
+                    disabled_permissions,
+                    Some(vec!["user_broadcast".to_string()])
+                );
+            }
+            _ => panic!("Expected LoginResponse"),
+        }
+    }
+
+    /// Log alice in through the handler (password "password")
+    async fn login_alice(test_ctx: &mut TestContext) -> u32 {
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: "password".to_string(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Login should succeed");
+        assert!(matches!(
+            read_server_message(&mut test_ctx.client).await,
+            ServerMessage::LoginResponse { success: true, .. }
+        ));
+        session_id.unwrap()
+    }
+
+    /// New login notices queued for online sessions
+    fn queued_new_logins(test_ctx: &mut TestContext) -> Vec<(u32, String, String)> {
+        let mut notices = Vec::new();
+        while let Ok((msg, _)) = test_ctx._rx.messages.try_recv() {
+            if let ServerMessage::NewLogin {
+                session_id,
+                address,
+                source,
+            } = msg
+            {
+                notices.push((session_id, address, source));
+            }
+        }
+        notices
+    }
+
+    #[tokio::test]
+    async fn test_login_notifies_other_sessions() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let new_session = login_alice(&mut test_ctx).await;
+
+        assert_eq!(
+            queued_new_logins(&mut test_ctx),
+            vec![(
+                new_session,
+                test_ctx.peer_addr.ip().to_string(),
+                test_ctx.source.clone()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_login_notice_skips_legacy_sessions() {
+        let mut test_ctx = create_test_context().await;
+        let hashed = db::hash_password("password").unwrap();
+        let account = test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+        test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: account.id,
+                username: "alice".to_string(),
+                is_admin: false,
+                permissions: std::collections::HashSet::new(),
+                address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
+                created_at: 0,
+                tx: test_ctx.tx.clone(),
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: None,
+                is_guest: false,
+                legacy_protocol: true,
+            })
+            .await;
+
+        login_alice(&mut test_ctx).await;
+
+        // A 0.5 client would disconnect on a message type it doesn't know
+        assert!(queued_new_logins(&mut test_ctx).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_login_notices_can_be_disabled() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.db.config.set_login_notices(false).await.unwrap();
+        login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        login_alice(&mut test_ctx).await;
+
+        assert!(queued_new_logins(&mut test_ctx).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_first_login_sends_no_notice() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        let hashed = db::hash_password("password").unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        login_alice(&mut test_ctx).await;
+
+        // Bob's session belongs to another account
+        assert!(queued_new_logins(&mut test_ctx).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_login_records_last_seen() {
+        let mut test_ctx = create_test_context().await;
+        let hashed = db::hash_password("password").unwrap();
+        let alice = test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            test_ctx.db.users.get_last_seen(alice.id).await.unwrap(),
+            Some(None)
+        );
+
+        let before = chrono::Utc::now().timestamp();
+        login_alice(&mut test_ctx).await;
+
+        let last_seen = test_ctx
+            .db
+            .users
+            .get_last_seen(alice.id)
+            .await
+            .unwrap()
+            .flatten()
+            .expect("login should record last seen");
+        assert!(last_seen >= before);
+    }
+
+    #[tokio::test]
+    async fn test_login_disabled_account() {
+        let mut test_ctx = create_test_context().await;
+
+        // Create a user first (so we're not the first user)
+        let password = "password";
+        let hashed = db::hash_password(password).unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        // Create a disabled user
+        let bob_account = test_ctx
+            .db
+            .users
+            .create_user("bob", &hashed, false, false, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        assert!(!bob_account.enabled, "Bob should be disabled");
+
+        let mut session_id = None;
+        let handshake_complete = true;
+
+        // Attempt login with disabled account
+        let request = LoginRequest {
+            username: "bob".to_string(),
+            password: password.to_string(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            handshake_complete,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+
+        // Should fail with disconnect
+        assert!(result.is_err(), "Login with disabled account should fail");
+        assert!(session_id.is_none(), "Session ID should remain None");
+
+        // Verify error message was sent
+        let response_msg = read_server_message(&mut test_ctx.client).await;
+        match response_msg {
+            ServerMessage::Error { message, .. } => {
+                assert!(
+                    message.contains("Account")
+                        && message.contains("bob")
+                        && message.contains("disabled"),
+                    "Should receive account disabled error with username, got: {}",
+                    message
+                );
+            }
+            _ => panic!("Expected Error message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_error_uses_requested_locale() {
+        let mut test_ctx = create_test_context().await;
+
+        // Create a user first (so we're not the first user)
+        let password = "password";
+        let hashed = db::hash_password(password).unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        let mut session_id = None;
+        let handshake_complete = true;
+
+        // Attempt login with wrong password using Spanish locale
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: "wrong_password".to_string(),
+            features: vec![],
+            locale: "es".to_string(), // Request Spanish locale
+            avatar: None,
+            handshake_complete,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+
+        // Should fail with disconnect
+        assert!(result.is_err(), "Login with wrong password should fail");
+        assert!(session_id.is_none(), "Session ID should remain None");
+
+        // Verify error message was sent in Spanish
+        let response_msg = read_server_message(&mut test_ctx.client).await;
+        match response_msg {
+            ServerMessage::Error { message, .. } => {
+                // Spanish error message should contain "Usuario o contraseña" (not English "Invalid username or password")
+                assert!(
+                    message.contains("Usuario") || message.contains("contraseña"),
+                    "Error message should be in Spanish, got: {}",
+                    message
+                );
+            }
+            _ => panic!("Expected Error message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_error_defaults_to_english() {
+        let mut test_ctx = create_test_context().await;
+
+        // Create a user first (so we're not the first user)
+        let password = "password";
+        let hashed = db::hash_password(password).unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        let mut session_id = None;
+        let handshake_complete = true;
+
+        // Attempt login with wrong password using empty locale (should default to "en")
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: "wrong_password".to_string(),
+            features: vec![],
+            locale: "".to_string(), // Empty locale should default to English
+            avatar: None,
+            handshake_complete,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1104,15 +1842,97 @@ mod tests {
         let response_msg = read_server_message(&mut test_ctx.client).await;
         match response_msg {
             ServerMessage::Error { message, .. } => {
-                // Should be English (contains "Invalid" or "username")
-                assert!(
-                    message.contains("Invalid") || message.contains("username"),
-                    "Error message should be in English (default), got: {}",
-                    message
-                );
+                // Should be English (contains "Invalid" or "username")
+                assert!(
+                    message.contains("Invalid") || message.contains("username"),
+                    "Error message should be in English (default), got: {}",
+                    message
+                );
+            }
+            _ => panic!("Expected Error message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_empty_locale_uses_configured_default() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.db.config.set_default_locale("es").await.unwrap();
+
+        let password = "password";
+        let hashed = db::hash_password(password).unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        // Errors for a client without a locale use the configured default
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: "wrong_password".to_string(),
+            features: vec![],
+            locale: String::new(),
+            avatar: None,
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_invalid_credentials("es"));
             }
             _ => panic!("Expected Error message"),
         }
+
+        // The session takes the default too
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: password.to_string(),
+            features: vec![],
+            locale: String::new(),
+            avatar: None,
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::LoginResponse { locale, .. } => {
+                assert_eq!(locale, Some("es".to_string()));
+            }
+            _ => panic!("Expected LoginResponse"),
+        }
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id.unwrap())
+            .await
+            .unwrap();
+        assert_eq!(session.locale, "es");
+    }
+
+    #[tokio::test]
+    async fn test_login_client_locale_overrides_default() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.db.config.set_default_locale("es").await.unwrap();
+
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: "password".to_string(),
+            features: vec![],
+            locale: "fr".to_string(),
+            avatar: None,
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::LoginResponse { locale, .. } => {
+                assert_eq!(locale, Some("fr".to_string()));
+            }
+            _ => panic!("Expected LoginResponse"),
+        }
     }
 
     // =========================================================================
@@ -1125,8 +1945,7 @@ mod tests {
         let mut session_id = None;
         let handshake_complete = true;
 
-        // Valid PNG data URI (minimal)
-        let valid_avatar = "data:image/png;base64,iVBORw0KGgo=".to_string();
+        let valid_avatar = png_data_uri(64, 64);
 
         let request = LoginRequest {
             username: "alice".to_string(),
@@ -1150,6 +1969,106 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_login_rejects_bad_avatar_images() {
+        let cases = [
+            (
+                "data:image/png;base64,iVBORw0KGgo=".to_string(),
+                err_avatar_undecodable(DEFAULT_TEST_LOCALE),
+            ),
+            (
+                png_data_uri(1, 400),
+                err_avatar_dimensions_too_small(DEFAULT_TEST_LOCALE, DEFAULT_AVATAR_MIN_DIMENSION),
+            ),
+            (
+                png_data_uri(2000, 2000),
+                err_avatar_dimensions_too_large(DEFAULT_TEST_LOCALE, DEFAULT_AVATAR_MAX_DIMENSION),
+            ),
+        ];
+
+        for (avatar, expected) in cases {
+            let mut test_ctx = create_test_context().await;
+            let mut session_id = None;
+
+            let request = LoginRequest {
+                username: "alice".to_string(),
+                password: "password123".to_string(),
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: Some(avatar),
+                handshake_complete: true,
+            };
+            let result =
+                handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+            assert!(result.is_err(), "Login with a bad avatar image should fail");
+            assert!(session_id.is_none());
+
+            match read_server_message(&mut test_ctx.client).await {
+                ServerMessage::Error { message, .. } => assert_eq!(message, expected),
+                other => panic!("Expected Error, got: {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_checks_credentials_before_avatar_image() {
+        let mut test_ctx = create_test_context().await;
+        let hashed = db::hash_password("password").unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("bob", &hashed, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+        let mut session_id = None;
+
+        let request = LoginRequest {
+            username: "bob".to_string(),
+            password: "wrongpassword".to_string(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: Some("data:image/png;base64,iVBORw0KGgo=".to_string()),
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_invalid_credentials(DEFAULT_TEST_LOCALE))
+            }
+            other => panic!("Expected Error, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_downscales_oversized_avatar() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.db.config.set_avatar_downscale(true).await.unwrap();
+        let mut session_id = None;
+
+        let original = png_data_uri(2000, 1000);
+        let request = LoginRequest {
+            username: "alice".to_string(),
+            password: "password123".to_string(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: Some(original.clone()),
+            handshake_complete: true,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id.unwrap())
+            .await
+            .unwrap();
+        let stored = session.avatar.expect("Avatar should be kept");
+        assert_ne!(stored, original);
+        assert!(stored.starts_with("data:image/png;base64,"));
+    }
+
     #[tokio::test]
     async fn test_login_with_avatar_too_large() {
         let mut test_ctx = create_test_context().await;
@@ -1297,4 +2216,393 @@ mod tests {
             _ => panic!("Expected LoginResponse"),
         }
     }
+
+    fn request_for(username: &str, password: &str) -> LoginRequest {
+        LoginRequest {
+            username: username.to_string(),
+            password: password.to_string(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            handshake_complete: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_session_limit_rejects() {
+        let mut test_ctx = create_test_context().await;
+        let first = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        test_ctx
+            .db
+            .config
+            .set_max_sessions_per_user(1)
+            .await
+            .unwrap();
+
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("bob", "password"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "Login over the limit should be refused");
+        assert!(session_id.is_none());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_session_limit(DEFAULT_TEST_LOCALE, 1));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+        assert_eq!(
+            test_ctx.user_manager.get_session_ids_for_user("bob").await,
+            vec![first]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_login_session_limit_replaces_oldest() {
+        let mut test_ctx = create_test_context().await;
+        let oldest = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        let config = &test_ctx.db.config;
+        config.set_max_sessions_per_user(1).await.unwrap();
+        config
+            .set_session_limit_policy(SessionLimitPolicy::ReplaceOldest)
+            .await
+            .unwrap();
+
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("bob", "password"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Login should replace the oldest session");
+        let newest = session_id.expect("Login should succeed");
+
+        assert_eq!(
+            test_ctx.user_manager.get_session_ids_for_user("bob").await,
+            vec![newest]
+        );
+        assert!(oldest < newest);
+
+        // The replaced session is told why before it is dropped
+        match test_ctx._rx.messages.try_recv() {
+            Ok((ServerMessage::Error { message, code, .. }, _)) => {
+                assert_eq!(message, err_session_replaced(DEFAULT_TEST_LOCALE));
+                assert_eq!(code.as_deref(), Some(ERROR_CODE_SESSION_REPLACED));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_session_limit_admin_exempt() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        test_ctx
+            .db
+            .config
+            .set_max_sessions_per_user(1)
+            .await
+            .unwrap();
+
+        // Admins are capped like everyone else by default
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("admin", "password"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "Admin should be capped unless exempt");
+        let _ = read_server_message(&mut test_ctx.client).await;
+
+        test_ctx
+            .db
+            .config
+            .set_session_limit_admin_exempt(true)
+            .await
+            .unwrap();
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("admin", "password"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Exempt admin should log in");
+        assert_eq!(
+            test_ctx
+                .user_manager
+                .get_session_ids_for_user("admin")
+                .await
+                .len(),
+            2
+        );
+    }
+
+    /// Turn guest login on, optionally with a shared password
+    async fn enable_guest_login(test_ctx: &TestContext, password: Option<&str>) {
+        test_ctx.db.config.set_guest_login(true).await.unwrap();
+        let hash = password.map(|p| db::hash_password(p).unwrap());
+        test_ctx
+            .db
+            .config
+            .set_guest_password_hash(hash.as_deref())
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_is_guest_username() {
+        assert!(is_guest_username("guest-alice"));
+        assert!(is_guest_username("Guest-Alice"));
+        assert!(!is_guest_username("guest-"));
+        assert!(!is_guest_username("guest"));
+        assert!(!is_guest_username("alice"));
+        assert!(!is_guest_username("alice-guest-"));
+    }
+
+    #[tokio::test]
+    async fn test_guest_login_without_password() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        enable_guest_login(&test_ctx, None).await;
+
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("guest-alice", ""),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Guest login should succeed");
+        let id = session_id.expect("guest should get a session");
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::LoginResponse {
+                success,
+                is_admin,
+                permissions,
+                preferences,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(is_admin, Some(false));
+                let mut permissions = permissions.unwrap();
+                permissions.sort();
+                let mut expected: Vec<String> = GUEST_PERMISSIONS
+                    .iter()
+                    .map(|p| p.as_str().to_string())
+                    .collect();
+                expected.sort();
+                assert_eq!(permissions, expected);
+                assert!(preferences.is_none());
+            }
+            other => panic!("Expected LoginResponse, got {:?}", other),
+        }
+
+        // The session is a guest with its own ID, and nothing was stored
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(id)
+            .await
+            .unwrap();
+        assert!(session.is_guest);
+        assert!(session.db_user_id < 0);
+        assert!(!session.is_admin);
+        assert!(
+            test_ctx
+                .db
+                .users
+                .get_user_by_username("guest-alice")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_guest_login_never_creates_first_admin() {
+        let mut test_ctx = create_test_context().await;
+        enable_guest_login(&test_ctx, None).await;
+
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("guest-alice", "password"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id.unwrap())
+            .await
+            .unwrap();
+        assert!(session.is_guest);
+        assert!(!session.is_admin);
+        assert!(test_ctx.db.users.get_all_users().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_guest_login_disabled_by_default() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("guest-alice", "password"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "Guest names need guest login turned on");
+        assert!(session_id.is_none());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_invalid_credentials(DEFAULT_TEST_LOCALE));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_guest_login_requires_prefix() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        enable_guest_login(&test_ctx, None).await;
+
+        // Without the prefix the name is treated as an account that doesn't exist
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("alice", "password"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(session_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_guest_login_shared_password() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        enable_guest_login(&test_ctx, Some("letmein")).await;
+
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("guest-alice", "wrong"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "Wrong guest password should be refused");
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_invalid_credentials(DEFAULT_TEST_LOCALE));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("guest-alice", "letmein"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Shared guest password should log in");
+        assert!(session_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_guest_name_in_use() {
+        let mut test_ctx = create_test_context().await;
+        enable_guest_login(&test_ctx, None).await;
+        let first = login_guest(&mut test_ctx, "guest-alice").await;
+
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("GUEST-ALICE", ""),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "A guest name can't be shared");
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(
+                    message,
+                    err_guest_name_in_use(DEFAULT_TEST_LOCALE, "GUEST-ALICE")
+                );
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+        assert_eq!(
+            test_ctx
+                .user_manager
+                .get_session_ids_for_user("guest-alice")
+                .await,
+            vec![first]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_guest_name_with_account_logs_into_account() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "guest-bob", "password", &[], false).await;
+        enable_guest_login(&test_ctx, None).await;
+
+        // The account's password is still required
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("guest-bob", "wrong"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err());
+        let _ = read_server_message(&mut test_ctx.client).await;
+
+        let mut session_id = None;
+        let result = handle_login(
+            request_for("guest-bob", "password"),
+            &mut session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id.unwrap())
+            .await
+            .unwrap();
+        assert!(!session.is_guest);
+    }
+
+    #[tokio::test]
+    async fn test_guest_sessions_get_distinct_ids() {
+        let mut test_ctx = create_test_context().await;
+        let alice = login_guest(&mut test_ctx, "guest-alice").await;
+        let bob = login_guest(&mut test_ctx, "guest-bob").await;
+
+        let alice = test_ctx
+            .user_manager
+            .get_user_by_session_id(alice)
+            .await
+            .unwrap();
+        let bob = test_ctx
+            .user_manager
+            .get_user_by_session_id(bob)
+            .await
+            .unwrap();
+        assert_ne!(alice.db_user_id, bob.db_user_id);
+        assert!(alice.db_user_id < 0 && bob.db_user_id < 0);
+    }
 }
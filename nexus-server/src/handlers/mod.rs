@@ -1,12 +1,32 @@
 //! Message handlers for client commands
 
+mod accept_rules;
+mod admin_notice;
+mod announcements;
 mod broadcast;
+mod capabilities;
 mod chat;
+mod chat_edit;
+mod chat_history_purge;
+mod chat_pin;
 mod chat_topic_update;
+mod connection_stats;
+mod e2ee;
 pub mod errors;
 mod handshake;
+mod history;
+mod kick_session;
 mod login;
+mod pages;
+mod ping;
+mod refresh_user_permissions;
+mod seen;
 mod server_info_update;
+mod set_away;
+mod set_maintenance;
+mod set_preferences;
+mod set_profile;
+mod transfer_admin;
 mod user_create;
 mod user_delete;
 mod user_edit;
@@ -15,56 +35,120 @@ mod user_kick;
 mod user_list;
 mod user_message;
 mod user_update;
+mod who_am_i;
 
 #[cfg(test)]
 pub mod testing;
 
+pub use accept_rules::handle_accept_rules;
+pub use admin_notice::handle_admin_notice;
+pub use announcements::{
+    handle_announcement_add, handle_announcement_list, handle_announcement_remove,
+};
 pub use broadcast::handle_user_broadcast;
+pub use capabilities::handle_capabilities;
 pub use chat::handle_chat_send;
+pub use chat_edit::{handle_chat_delete, handle_chat_edit};
+pub use chat_history_purge::handle_chat_history_purge;
+pub use chat_pin::{handle_pin_message, handle_unpin_message};
 pub use chat_topic_update::handle_chat_topic_update;
+pub use connection_stats::handle_connection_stats;
+pub use e2ee::{handle_e2ee_key_announce, handle_e2ee_keys_request};
 pub use errors::*;
 pub use handshake::handle_handshake;
+pub use history::handle_history_request;
+pub use kick_session::handle_kick_session;
 pub use login::{LoginRequest, handle_login};
-pub use server_info_update::handle_server_info_update;
+pub use pages::{handle_page_delete, handle_page_get, handle_page_list, handle_page_set};
+pub use ping::handle_ping;
+pub use refresh_user_permissions::handle_refresh_user_permissions;
+pub use seen::handle_seen;
+pub use server_info_update::{ServerInfoUpdateRequest, handle_server_info_update};
+pub use set_away::handle_set_away;
+pub use set_maintenance::handle_set_maintenance;
+pub use set_preferences::handle_set_preferences;
+pub use set_profile::handle_set_profile;
+pub use transfer_admin::handle_transfer_admin;
 pub use user_create::handle_user_create;
 pub use user_delete::handle_user_delete;
 pub use user_edit::handle_user_edit;
 pub use user_info::handle_user_info;
 pub use user_kick::handle_user_kick;
 pub use user_list::handle_user_list;
-pub use user_message::handle_user_message;
+pub use user_message::{handle_encrypted_user_message, handle_user_message};
 pub use user_update::{UserUpdateRequest, handle_user_update};
+pub use who_am_i::handle_who_am_i;
 
 use std::io;
 use std::net::SocketAddr;
 
 use tokio::io::AsyncWrite;
-use tokio::sync::mpsc;
 
-use nexus_common::framing::{FrameWriter, MessageId};
-use nexus_common::io::send_server_message_with_id;
+use nexus_common::framing::{FrameWriter, MessageId, RawFrame, legacy_max_payload_for_type};
+use nexus_common::io::{send_server_message_with_id, server_message_type};
 use nexus_common::protocol::ServerMessage;
 
-use crate::db::Database;
+use crate::connection_history::ConnectionHistory;
+use crate::db::{Database, Permission, PermissionPrerequisites, Permissions};
 use crate::users::UserManager;
+use crate::users::user::{SessionSender, UserSession};
 
 /// Context passed to all handlers with shared resources
 pub struct HandlerContext<'a, W> {
     pub writer: &'a mut FrameWriter<W>,
     pub peer_addr: SocketAddr,
+    /// Source tag of the connection (e.g. "loopback", "lan", "internet")
+    pub source: &'a str,
     pub user_manager: &'a UserManager,
     pub db: &'a Database,
-    pub tx: &'a mpsc::UnboundedSender<(ServerMessage, Option<MessageId>)>,
+    /// Recent connection counts recorded by the accept loop
+    pub connection_history: &'a ConnectionHistory,
+    pub tx: &'a SessionSender,
     pub debug: bool,
     pub locale: &'a str,
     /// Message ID from the incoming request (for response correlation)
     pub message_id: MessageId,
+    /// Client speaks protocol 0.5 (see [`send_server_message_to_peer`])
+    pub legacy_protocol: bool,
+}
+
+/// Send a server message to a client, within what its protocol can read
+///
+/// Clients older than the extended protocol disconnect on message types
+/// added since and on frames over the 0.5 size limits, so such messages are
+/// dropped for them instead of sent.
+pub async fn send_server_message_to_peer<W>(
+    writer: &mut FrameWriter<W>,
+    message: &ServerMessage,
+    message_id: MessageId,
+    legacy_protocol: bool,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    if !legacy_protocol {
+        return send_server_message_with_id(writer, message, message_id).await;
+    }
+
+    let message_type = server_message_type(message);
+    let Some(limit) = legacy_max_payload_for_type(message_type) else {
+        return Ok(());
+    };
+    let payload =
+        serde_json::to_vec(message).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    if limit != 0 && payload.len() as u64 > limit {
+        return Ok(());
+    }
+
+    let frame = RawFrame::new(message_id, message_type.to_string(), payload);
+    writer.write_frame(&frame).await.map_err(Into::into)
 }
 
 impl<'a, W: AsyncWrite + Unpin> HandlerContext<'a, W> {
     /// Send a message to the client, echoing the request's message ID
     pub async fn send_message(&mut self, message: &ServerMessage) -> io::Result<()> {
-        send_server_message_with_id(self.writer, message, self.message_id).await
+        send_server_message_to_peer(self.writer, message, self.message_id, self.legacy_protocol)
+            .await
     }
 
     /// Send an error message without disconnecting
@@ -72,10 +156,98 @@ impl<'a, W: AsyncWrite + Unpin> HandlerContext<'a, W> {
         let error_msg = ServerMessage::Error {
             message: message.to_string(),
             command: command.map(|s| s.to_string()),
+            code: None,
         };
         self.send_message(&error_msg).await
     }
 
+    /// Whether maintenance mode blocks this user from changing server state
+    ///
+    /// Admins are never blocked so they can fix whatever the maintenance is for.
+    pub async fn maintenance_blocks(&self, is_admin: bool) -> bool {
+        !is_admin && self.db.config.get_maintenance_mode().await
+    }
+
+    /// Whether the server has switched this permission off for this user
+    ///
+    /// Applies regardless of grants; admins are only spared when exempted.
+    pub async fn permission_disabled(&self, permission: Permission, is_admin: bool) -> bool {
+        self.db
+            .config
+            .get_disabled_permissions_for(is_admin)
+            .await
+            .contains(permission)
+    }
+
+    /// Seconds left on this session's cooldown for an expensive command
+    ///
+    /// Returns `None` (and starts a new cooldown) when the command may run.
+    /// Admins are exempt, and a cooldown of 0 turns the check off.
+    pub async fn command_cooldown(
+        &self,
+        session_id: u32,
+        is_admin: bool,
+        command: &'static str,
+    ) -> Option<u64> {
+        if is_admin {
+            return None;
+        }
+        let seconds = self.db.config.get_command_cooldown_seconds().await;
+        if seconds == 0 {
+            return None;
+        }
+        self.user_manager
+            .check_command_cooldown(session_id, command, seconds)
+            .await
+    }
+
+    /// Apply the server's prerequisite policy to permissions being saved
+    ///
+    /// In enforce mode, missing prerequisites the requester may grant are
+    /// added to `permissions`. Returns a warning naming what was added and
+    /// what is still missing, or `None` if there is nothing to report.
+    pub async fn permission_prerequisites_warning(
+        &self,
+        permissions: &mut Permissions,
+        requester: &UserSession,
+    ) -> Option<String> {
+        let mode = self.db.config.get_permission_prerequisites().await;
+        if mode == PermissionPrerequisites::Off {
+            return None;
+        }
+
+        let (added, missing): (Vec<Permission>, Vec<Permission>) = permissions
+            .missing_prerequisites()
+            .into_iter()
+            .partition(|&p| {
+                mode == PermissionPrerequisites::Enforce && requester.has_permission(p)
+            });
+        for &permission in &added {
+            permissions.add(permission);
+        }
+
+        let names = |list: &[Permission]| {
+            list.iter()
+                .map(|p| p.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut warnings = Vec::new();
+        if !added.is_empty() {
+            warnings.push(warn_permission_prerequisites_added(
+                self.locale,
+                &names(&added),
+            ));
+        }
+        if !missing.is_empty() {
+            warnings.push(warn_permission_prerequisites_missing(
+                self.locale,
+                &names(&missing),
+            ));
+        }
+        (!warnings.is_empty()).then(|| warnings.join("; "))
+    }
+
     /// Send an error message and disconnect
     pub async fn send_error_and_disconnect(
         &mut self,
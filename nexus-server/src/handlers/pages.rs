@@ -0,0 +1,485 @@
+//! Handlers for PageGet, PageList, PageSet, and PageDelete commands
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{MAX_PAGES, ServerMessage};
+use nexus_common::validators::{self, PageContentError, PageNameError};
+
+use super::{
+    HandlerContext, err_admin_required, err_authentication, err_database, err_not_logged_in,
+    err_page_content_empty, err_page_content_invalid_characters, err_page_content_too_long,
+    err_page_limit_reached, err_page_name_invalid, err_page_name_too_long, err_page_not_found,
+    err_server_maintenance,
+};
+use crate::constants::ERR_PAGES;
+use crate::users::user::UserSession;
+
+/// Handle a request for an info page by name (any logged-in user)
+pub async fn handle_page_get<W>(
+    name: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    if require_user(session_id, "PageGet", ctx).await?.is_none() {
+        return Ok(());
+    }
+
+    if let Err(e) = validators::validate_page_name(&name) {
+        let error = page_name_error(e, ctx.locale);
+        return send_get_response(ctx, Err(error)).await;
+    }
+
+    let result = match ctx.db.pages.get_page(&name).await {
+        Ok(Some(page)) => Ok((page.name, page.content)),
+        Ok(None) => Err(err_page_not_found(ctx.locale, &name)),
+        Err(e) => {
+            eprintln!("{}{}", ERR_PAGES, e);
+            Err(err_database(ctx.locale))
+        }
+    };
+    send_get_response(ctx, result).await
+}
+
+/// Handle a request to list the info pages (any logged-in user)
+pub async fn handle_page_list<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    if require_user(session_id, "PageList", ctx).await?.is_none() {
+        return Ok(());
+    }
+
+    let response = match ctx.db.pages.list_pages().await {
+        Ok(pages) => ServerMessage::PageListResponse {
+            success: true,
+            error: None,
+            pages: Some(pages),
+        },
+        Err(e) => {
+            eprintln!("{}{}", ERR_PAGES, e);
+            ServerMessage::PageListResponse {
+                success: false,
+                error: Some(err_database(ctx.locale)),
+                pages: None,
+            }
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle a request to create or replace an info page (admin only)
+pub async fn handle_page_set<W>(
+    name: String,
+    content: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(user) = require_user(session_id, "PageSet", ctx).await? else {
+        return Ok(());
+    };
+
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(user.is_admin).await {
+        let response = ServerMessage::PageSetResponse {
+            success: false,
+            error: Some(err_server_maintenance(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    if !user.is_admin {
+        eprintln!(
+            "PageSet from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        let response = ServerMessage::PageSetResponse {
+            success: false,
+            error: Some(err_admin_required(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let error = match page_set_error(&name, &content, ctx).await {
+        Some(error) => Some(error),
+        None => match ctx.db.pages.set_page(&name, &content).await {
+            Ok(()) => None,
+            Err(e) => {
+                eprintln!("{}{}", ERR_PAGES, e);
+                Some(err_database(ctx.locale))
+            }
+        },
+    };
+
+    let response = ServerMessage::PageSetResponse {
+        success: error.is_none(),
+        error,
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle a request to delete an info page (admin only)
+pub async fn handle_page_delete<W>(
+    name: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(user) = require_user(session_id, "PageDelete", ctx).await? else {
+        return Ok(());
+    };
+
+    // Maintenance mode keeps non-admins read-only
+    let error = if ctx.maintenance_blocks(user.is_admin).await {
+        Some(err_server_maintenance(ctx.locale))
+    } else if !user.is_admin {
+        eprintln!(
+            "PageDelete from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        Some(err_admin_required(ctx.locale))
+    } else if let Err(e) = validators::validate_page_name(&name) {
+        Some(page_name_error(e, ctx.locale))
+    } else {
+        match ctx.db.pages.delete_page(&name).await {
+            Ok(true) => None,
+            Ok(false) => Some(err_page_not_found(ctx.locale, &name)),
+            Err(e) => {
+                eprintln!("{}{}", ERR_PAGES, e);
+                Some(err_database(ctx.locale))
+            }
+        }
+    };
+
+    let response = ServerMessage::PageDeleteResponse {
+        success: error.is_none(),
+        error,
+    };
+    ctx.send_message(&response).await
+}
+
+/// Look up the logged-in user, disconnecting if there is none
+///
+/// Returns `Ok(None)` after the connection has been told why it is closing.
+async fn require_user<W>(
+    session_id: Option<u32>,
+    message_type: &str,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<Option<UserSession>>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("{} from {} without login", message_type, ctx.peer_addr);
+        ctx.send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some(message_type))
+            .await?;
+        return Ok(None);
+    };
+
+    match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(user) => Ok(Some(user)),
+        None => {
+            ctx.send_error_and_disconnect(&err_authentication(ctx.locale), Some(message_type))
+                .await?;
+            Ok(None)
+        }
+    }
+}
+
+/// Check a page about to be saved, returning the error to report if any
+async fn page_set_error<W>(name: &str, content: &str, ctx: &HandlerContext<'_, W>) -> Option<String>
+where
+    W: AsyncWrite + Unpin,
+{
+    if let Err(e) = validators::validate_page_name(name) {
+        return Some(page_name_error(e, ctx.locale));
+    }
+    if let Err(e) = validators::validate_page_content(content) {
+        return Some(match e {
+            PageContentError::Empty => err_page_content_empty(ctx.locale),
+            PageContentError::TooLong => {
+                err_page_content_too_long(ctx.locale, validators::MAX_PAGE_CONTENT_LENGTH)
+            }
+            PageContentError::InvalidCharacters => err_page_content_invalid_characters(ctx.locale),
+        });
+    }
+
+    // Replacing a page never counts against the limit
+    match ctx.db.pages.get_page(name).await {
+        Ok(Some(_)) => None,
+        Ok(None) => match ctx.db.pages.list_pages().await {
+            Ok(pages) if pages.len() >= MAX_PAGES => {
+                Some(err_page_limit_reached(ctx.locale, MAX_PAGES))
+            }
+            Ok(_) => None,
+            Err(e) => {
+                eprintln!("{}{}", ERR_PAGES, e);
+                Some(err_database(ctx.locale))
+            }
+        },
+        Err(e) => {
+            eprintln!("{}{}", ERR_PAGES, e);
+            Some(err_database(ctx.locale))
+        }
+    }
+}
+
+/// Translate a page name validation failure
+fn page_name_error(error: PageNameError, locale: &str) -> String {
+    match error {
+        PageNameError::Empty | PageNameError::InvalidCharacters => err_page_name_invalid(locale),
+        PageNameError::TooLong => err_page_name_too_long(locale, validators::MAX_PAGE_NAME_LENGTH),
+    }
+}
+
+/// Send a PageGetResponse with the page name and content, or an error
+async fn send_get_response<W>(
+    ctx: &mut HandlerContext<'_, W>,
+    result: Result<(String, String), String>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = match result {
+        Ok((name, content)) => ServerMessage::PageGetResponse {
+            success: true,
+            error: None,
+            name: Some(name),
+            content: Some(content),
+        },
+        Err(error) => ServerMessage::PageGetResponse {
+            success: false,
+            error: Some(error),
+            name: None,
+            content: None,
+        },
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_page_get_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result =
+            handle_page_get("help".to_string(), None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_page_set_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_page_set(
+            "help".to_string(),
+            "Hello".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::PageSetResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            other => panic!("Expected PageSetResponse, got {:?}", other),
+        }
+        assert!(test_ctx.db.pages.list_pages().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_page_set_get_list_and_delete() {
+        let mut test_ctx = create_test_context().await;
+        let admin_session = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let user_session = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_page_set(
+            "News".to_string(),
+            "Game night\nFriday".to_string(),
+            Some(admin_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::PageSetResponse { success, .. } => assert!(success),
+            other => panic!("Expected PageSetResponse, got {:?}", other),
+        }
+
+        // Regular users can read pages, by any capitalization
+        handle_page_get(
+            "news".to_string(),
+            Some(user_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::PageGetResponse {
+                success,
+                name,
+                content,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(name.as_deref(), Some("News"));
+                assert_eq!(content.as_deref(), Some("Game night\nFriday"));
+            }
+            other => panic!("Expected PageGetResponse, got {:?}", other),
+        }
+
+        handle_page_list(Some(user_session), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::PageListResponse { success, pages, .. } => {
+                assert!(success);
+                assert_eq!(pages, Some(vec!["News".to_string()]));
+            }
+            other => panic!("Expected PageListResponse, got {:?}", other),
+        }
+
+        handle_page_delete(
+            "NEWS".to_string(),
+            Some(admin_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::PageDeleteResponse { success, .. } => assert!(success),
+            other => panic!("Expected PageDeleteResponse, got {:?}", other),
+        }
+
+        handle_page_get(
+            "news".to_string(),
+            Some(user_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::PageGetResponse { success, error, .. } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            other => panic!("Expected PageGetResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_page_set_rejects_invalid_and_over_limit() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        for (name, content) in [("two words", "Text"), ("help", "   ")] {
+            handle_page_set(
+                name.to_string(),
+                content.to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            match read_server_message(&mut test_ctx.client).await {
+                ServerMessage::PageSetResponse { success, .. } => assert!(!success),
+                other => panic!("Expected PageSetResponse, got {:?}", other),
+            }
+        }
+
+        for i in 0..MAX_PAGES {
+            test_ctx
+                .db
+                .pages
+                .set_page(&format!("page{i}"), "Text")
+                .await
+                .unwrap();
+        }
+
+        // A new page is over the limit, but an existing one can still be edited
+        for (name, succeeds) in [("one-too-many", false), ("page0", true)] {
+            handle_page_set(
+                name.to_string(),
+                "Updated".to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            match read_server_message(&mut test_ctx.client).await {
+                ServerMessage::PageSetResponse { success, .. } => assert_eq!(success, succeeds),
+                other => panic!("Expected PageSetResponse, got {:?}", other),
+            }
+        }
+        assert_eq!(
+            test_ctx.db.pages.list_pages().await.unwrap().len(),
+            MAX_PAGES
+        );
+    }
+
+    #[tokio::test]
+    async fn test_page_writes_blocked_during_maintenance() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        test_ctx.db.pages.set_page("help", "Hello").await.unwrap();
+        test_ctx.db.config.set_maintenance_mode(true).await.unwrap();
+
+        let result = handle_page_set(
+            "rules".to_string(),
+            "Be nice".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::PageSetResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_server_maintenance(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected PageSetResponse, got {:?}", other),
+        }
+
+        let result = handle_page_delete(
+            "help".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::PageDeleteResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_server_maintenance(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected PageDeleteResponse, got {:?}", other),
+        }
+
+        assert_eq!(
+            test_ctx.db.pages.list_pages().await.unwrap(),
+            vec!["help".to_string()]
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Handler for Ping command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{HandlerContext, err_not_logged_in};
+
+/// Handle a keepalive ping from the client
+///
+/// Replies with `Pong` echoing the request's message ID so the client can
+/// measure round-trip time.
+pub async fn handle_ping<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    if session_id.is_none() {
+        eprintln!("Ping from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("Ping"))
+            .await;
+    }
+
+    ctx.send_message(&ServerMessage::Pong).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_ping_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_ping(None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err(), "Ping without login should disconnect");
+    }
+
+    #[tokio::test]
+    async fn test_ping_replies_pong() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_ping(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(response, ServerMessage::Pong));
+    }
+}
@@ -0,0 +1,248 @@
+//! Handler for RefreshUserPermissions command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, UsernameError};
+
+use super::user_update::push_permissions_update;
+use super::{
+    HandlerContext, err_admin_required, err_authentication, err_database, err_not_logged_in,
+    err_user_not_found, err_username_empty, err_username_invalid, err_username_too_long,
+};
+
+/// Handle RefreshUserPermissions command (admin only)
+///
+/// Re-reads the target account from the database and pushes its admin status
+/// and permissions to every online session, for changes made outside
+/// `UserUpdate`. Succeeds without sending anything if the user is offline.
+pub async fn handle_refresh_user_permissions<W>(
+    target_username: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first (before revealing validation errors to unauthenticated users)
+    let Some(id) = session_id else {
+        eprintln!(
+            "RefreshUserPermissions from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error(
+                &err_not_logged_in(ctx.locale),
+                Some("RefreshUserPermissions"),
+            )
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error(
+                    &err_authentication(ctx.locale),
+                    Some("RefreshUserPermissions"),
+                )
+                .await;
+        }
+    };
+
+    if !user.is_admin {
+        eprintln!(
+            "RefreshUserPermissions from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        return ctx
+            .send_error(
+                &err_admin_required(ctx.locale),
+                Some("RefreshUserPermissions"),
+            )
+            .await;
+    }
+
+    if let Err(e) = validators::validate_username(&target_username) {
+        let error_msg = match e {
+            UsernameError::Empty => err_username_empty(ctx.locale),
+            UsernameError::TooLong => {
+                err_username_too_long(ctx.locale, validators::MAX_USERNAME_LENGTH)
+            }
+            UsernameError::InvalidCharacters => err_username_invalid(ctx.locale),
+        };
+        let response = ServerMessage::RefreshUserPermissionsResponse {
+            success: false,
+            error: Some(error_msg),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let account = match ctx.db.users.get_user_by_username(&target_username).await {
+        Ok(Some(account)) => account,
+        Ok(None) => {
+            let response = ServerMessage::RefreshUserPermissionsResponse {
+                success: false,
+                error: Some(err_user_not_found(ctx.locale, &target_username)),
+            };
+            return ctx.send_message(&response).await;
+        }
+        Err(e) => {
+            eprintln!("Database error getting target user: {}", e);
+            let response = ServerMessage::RefreshUserPermissionsResponse {
+                success: false,
+                error: Some(err_database(ctx.locale)),
+            };
+            return ctx.send_message(&response).await;
+        }
+    };
+
+    // Admin status may also have changed outside the normal update flow
+    ctx.user_manager
+        .update_admin_status(account.id, account.is_admin)
+        .await;
+
+    let response = match push_permissions_update(&account, ctx).await {
+        Ok(()) => ServerMessage::RefreshUserPermissionsResponse {
+            success: true,
+            error: None,
+        },
+        Err(e) => {
+            eprintln!("Database error reading permissions: {}", e);
+            ServerMessage::RefreshUserPermissionsResponse {
+                success: false,
+                error: Some(err_database(ctx.locale)),
+            }
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{Permission, Permissions};
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_refresh_permissions_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_refresh_user_permissions(
+            "alice".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(response, ServerMessage::Error { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_permissions_unknown_user() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_refresh_user_permissions(
+            "nobody".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::RefreshUserPermissionsResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            _ => panic!("Expected RefreshUserPermissionsResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_permissions_offline_user() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        test_ctx
+            .db
+            .users
+            .create_user("bob", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        let result = handle_refresh_user_permissions(
+            "bob".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::RefreshUserPermissionsResponse {
+                success: true,
+                error: None
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_permissions_updates_cached_session() {
+        let mut test_ctx = create_test_context().await;
+        let admin_session = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let bob_session = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        // Grant a permission directly in the database; the session cache is now stale
+        let mut perms = Permissions::new();
+        perms.permissions.insert(Permission::ChatSend);
+        let bob_account = test_ctx
+            .db
+            .users
+            .get_user_by_username("bob")
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx
+            .db
+            .users
+            .set_permissions(bob_account.id, &perms)
+            .await
+            .unwrap();
+        let bob = test_ctx
+            .user_manager
+            .get_user_by_session_id(bob_session)
+            .await
+            .unwrap();
+        assert!(!bob.has_permission(Permission::ChatSend));
+
+        let result = handle_refresh_user_permissions(
+            "bob".to_string(),
+            Some(admin_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::RefreshUserPermissionsResponse { success: true, .. }
+        ));
+
+        let bob = test_ctx
+            .user_manager
+            .get_user_by_session_id(bob_session)
+            .await
+            .unwrap();
+        assert!(bob.has_permission(Permission::ChatSend));
+    }
+}
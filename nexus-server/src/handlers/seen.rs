@@ -0,0 +1,344 @@
+//! Handler for SeenRequest command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, UsernameError};
+
+use super::{
+    HandlerContext, err_authentication, err_command_cooldown, err_database, err_feature_disabled,
+    err_not_logged_in, err_permission_denied, err_user_not_found, err_username_empty,
+    err_username_invalid, err_username_too_long,
+};
+use crate::db::Permission;
+
+/// Handle SeenRequest command
+///
+/// Reports whether an account is online and, if not, when it last logged in
+/// or out. Gated by `user_info`, since it reveals the same presence details as
+/// a user info lookup.
+pub async fn handle_seen<W>(
+    requested_username: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first (before revealing validation errors to unauthenticated users)
+    let Some(id) = session_id else {
+        eprintln!("SeenRequest from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("SeenRequest"))
+            .await;
+    };
+
+    if let Err(e) = validators::validate_username(&requested_username) {
+        let error_msg = match e {
+            UsernameError::Empty => err_username_empty(ctx.locale),
+            UsernameError::TooLong => {
+                err_username_too_long(ctx.locale, validators::MAX_USERNAME_LENGTH)
+            }
+            UsernameError::InvalidCharacters => err_username_invalid(ctx.locale),
+        };
+        return send_failure(error_msg, ctx).await;
+    }
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error(&err_authentication(ctx.locale), Some("SeenRequest"))
+                .await;
+        }
+    };
+
+    if ctx
+        .permission_disabled(Permission::UserInfo, user.is_admin)
+        .await
+    {
+        return ctx
+            .send_error(&err_feature_disabled(ctx.locale), Some("SeenRequest"))
+            .await;
+    }
+
+    if !user.has_permission(Permission::UserInfo) {
+        eprintln!(
+            "SeenRequest from {} (user: {}) without permission",
+            ctx.peer_addr, user.username
+        );
+        return ctx
+            .send_error(&err_permission_denied(ctx.locale), Some("SeenRequest"))
+            .await;
+    }
+
+    if let Some(remaining) = ctx.command_cooldown(id, user.is_admin, "SeenRequest").await {
+        return send_failure(err_command_cooldown(ctx.locale, remaining), ctx).await;
+    }
+
+    // Look up the account (case-insensitive) for its stored username
+    let account = match ctx.db.users.get_user_by_username(&requested_username).await {
+        Ok(Some(account)) => account,
+        Ok(None) => {
+            return send_failure(err_user_not_found(ctx.locale, &requested_username), ctx).await;
+        }
+        Err(e) => {
+            eprintln!("Database error getting user: {}", e);
+            return send_failure(err_database(ctx.locale), ctx).await;
+        }
+    };
+
+    // Live sessions win over the stored timestamp
+    let online = !ctx
+        .user_manager
+        .get_sessions_by_db_user_id(account.id)
+        .await
+        .is_empty();
+
+    let last_seen = if online {
+        None
+    } else {
+        match ctx.db.users.get_last_seen(account.id).await {
+            Ok(last_seen) => last_seen.flatten(),
+            Err(e) => {
+                eprintln!("Database error reading last seen: {}", e);
+                return send_failure(err_database(ctx.locale), ctx).await;
+            }
+        }
+    };
+
+    let response = ServerMessage::SeenResponse {
+        success: true,
+        error: None,
+        username: Some(account.username),
+        online: Some(online),
+        last_seen,
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send an unsuccessful `SeenResponse`
+async fn send_failure<W>(error: String, ctx: &mut HandlerContext<'_, W>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::SeenResponse {
+        success: false,
+        error: Some(error),
+        username: None,
+        online: None,
+        last_seen: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{Permissions, hash_password};
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user, read_server_message,
+    };
+
+    /// Create an account that isn't logged in, returning its database id
+    async fn create_offline_user(test_ctx: &TestContext, username: &str) -> i64 {
+        let hashed = hash_password("password").unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user(username, &hashed, false, true, &Permissions::new())
+            .await
+            .unwrap()
+            .id
+    }
+
+    #[tokio::test]
+    async fn test_seen_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_seen("alice".to_string(), None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(response, ServerMessage::Error { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_seen_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_seen(
+            "alice".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_permission_denied(DEFAULT_TEST_LOCALE));
+            }
+            other => panic!("Expected Error, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_seen_unknown_user() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserInfo],
+            false,
+        )
+        .await;
+
+        let result = handle_seen(
+            "nobody".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::SeenResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_user_not_found(DEFAULT_TEST_LOCALE, "nobody"))
+                );
+            }
+            other => panic!("Expected SeenResponse, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_seen_online_user() {
+        let mut test_ctx = create_test_context().await;
+        login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserInfo],
+            false,
+        )
+        .await;
+
+        let result = handle_seen(
+            "BOB".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::SeenResponse {
+                success,
+                username,
+                online,
+                last_seen,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(username, Some("bob".to_string()));
+                assert_eq!(online, Some(true));
+                assert_eq!(last_seen, None);
+            }
+            other => panic!("Expected SeenResponse, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_seen_offline_user() {
+        let mut test_ctx = create_test_context().await;
+        let bob_id = create_offline_user(&test_ctx, "bob").await;
+        create_offline_user(&test_ctx, "carol").await;
+        test_ctx
+            .db
+            .users
+            .set_last_seen(bob_id, 1_700_000_000)
+            .await
+            .unwrap();
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserInfo],
+            false,
+        )
+        .await;
+
+        let expectations = [("bob", Some(1_700_000_000)), ("carol", None)];
+        for (username, expected) in expectations {
+            let result = handle_seen(
+                username.to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_ok());
+
+            let response = read_server_message(&mut test_ctx.client).await;
+            match response {
+                ServerMessage::SeenResponse {
+                    success,
+                    online,
+                    last_seen,
+                    ..
+                } => {
+                    assert!(success);
+                    assert_eq!(online, Some(false));
+                    assert_eq!(last_seen, expected);
+                }
+                other => panic!("Expected SeenResponse, got: {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_seen_cooldown() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_command_cooldown_seconds(60)
+            .await
+            .unwrap();
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserInfo],
+            false,
+        )
+        .await;
+
+        let expectations = [None, Some(err_command_cooldown(DEFAULT_TEST_LOCALE, 60))];
+        for expected in expectations {
+            let result = handle_seen(
+                "alice".to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_ok());
+
+            let response = read_server_message(&mut test_ctx.client).await;
+            match response {
+                ServerMessage::SeenResponse { error, .. } => assert_eq!(error, expected),
+                other => panic!("Expected SeenResponse, got: {:?}", other),
+            }
+        }
+    }
+}
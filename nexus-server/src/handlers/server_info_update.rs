@@ -12,25 +12,49 @@ use nexus_common::validators::{
 
 use super::{
     HandlerContext, err_admin_required, err_authentication, err_database,
-    err_max_connections_per_ip_invalid, err_no_fields_to_update, err_not_logged_in,
+    err_max_connections_per_ip_invalid, err_max_message_length_invalid,
+    err_max_topic_length_invalid, err_no_fields_to_update, err_not_logged_in,
     err_server_description_contains_newlines, err_server_description_invalid_characters,
     err_server_description_too_long, err_server_image_invalid_format, err_server_image_too_large,
     err_server_image_unsupported_type, err_server_name_contains_newlines, err_server_name_empty,
     err_server_name_invalid_characters, err_server_name_too_long,
 };
 
+/// Server info update request parameters
+#[derive(Default)]
+pub struct ServerInfoUpdateRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub max_connections_per_ip: Option<u32>,
+    pub image: Option<String>,
+    pub chat_slow_mode: Option<u32>,
+    pub max_message_length: Option<u32>,
+    pub max_topic_length: Option<u32>,
+    pub session_id: Option<u32>,
+}
+
 /// Handle ServerInfoUpdate command
+///
+/// When a chat limit is part of the update, every connected user also gets
+/// `LimitsUpdated` so their clients pick up the new limits without reconnecting.
 pub async fn handle_server_info_update<W>(
-    name: Option<String>,
-    description: Option<String>,
-    max_connections_per_ip: Option<u32>,
-    image: Option<String>,
-    session_id: Option<u32>,
+    request: ServerInfoUpdateRequest,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
 where
     W: AsyncWrite + Unpin,
 {
+    let ServerInfoUpdateRequest {
+        name,
+        description,
+        max_connections_per_ip,
+        image,
+        chat_slow_mode,
+        max_message_length,
+        max_topic_length,
+        session_id,
+    } = request;
+
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(id) = session_id else {
         eprintln!("ServerInfoUpdate from {} without login", ctx.peer_addr);
@@ -65,6 +89,9 @@ where
         && description.is_none()
         && max_connections_per_ip.is_none()
         && image.is_none()
+        && chat_slow_mode.is_none()
+        && max_message_length.is_none()
+        && max_topic_length.is_none()
     {
         return ctx
             .send_error(
@@ -120,6 +147,32 @@ where
             .await;
     }
 
+    // Validate length limits if provided (1 up to the protocol ceiling)
+    if let Some(length) = max_message_length
+        && (length == 0 || length as usize > validators::MAX_MESSAGE_LENGTH_CEILING)
+    {
+        return ctx
+            .send_error(
+                &err_max_message_length_invalid(ctx.locale, validators::MAX_MESSAGE_LENGTH_CEILING),
+                Some("ServerInfoUpdate"),
+            )
+            .await;
+    }
+
+    if let Some(length) = max_topic_length
+        && (length == 0 || length as usize > validators::MAX_CHAT_TOPIC_LENGTH_CEILING)
+    {
+        return ctx
+            .send_error(
+                &err_max_topic_length_invalid(
+                    ctx.locale,
+                    validators::MAX_CHAT_TOPIC_LENGTH_CEILING,
+                ),
+                Some("ServerInfoUpdate"),
+            )
+            .await;
+    }
+
     // Validate image if provided (empty string is allowed to clear image)
     if let Some(ref img) = image
         && !img.is_empty()
@@ -170,11 +223,39 @@ where
             .await;
     }
 
+    if let Some(seconds) = chat_slow_mode
+        && let Err(e) = ctx.db.config.set_chat_slow_mode_seconds(seconds).await
+    {
+        eprintln!("Database error setting chat slow mode: {}", e);
+        return ctx
+            .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
+            .await;
+    }
+
+    if let Some(length) = max_message_length
+        && let Err(e) = ctx.db.config.set_max_message_length(length as usize).await
+    {
+        eprintln!("Database error setting max_message_length: {}", e);
+        return ctx
+            .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
+            .await;
+    }
+
+    if let Some(length) = max_topic_length
+        && let Err(e) = ctx.db.config.set_max_topic_length(length as usize).await
+    {
+        eprintln!("Database error setting max_topic_length: {}", e);
+        return ctx
+            .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
+            .await;
+    }
+
     // Fetch current server info for broadcast
     let current_name = ctx.db.config.get_server_name().await;
     let current_description = ctx.db.config.get_server_description().await;
     let current_max_connections = ctx.db.config.get_max_connections_per_ip().await as u32;
     let current_image = ctx.db.config.get_server_image().await;
+    let current_chat_slow_mode = ctx.db.config.get_chat_slow_mode_seconds().await;
     let server_version = env!("CARGO_PKG_VERSION").to_string();
 
     // Broadcast ServerInfoUpdated to all connected users
@@ -186,9 +267,23 @@ where
             server_version,
             current_max_connections,
             current_image,
+            current_chat_slow_mode,
         )
         .await;
 
+    // Push the full set of limits when any of them changed (0.5 clients only
+    // learn limits at login)
+    if chat_slow_mode.is_some() || max_message_length.is_some() || max_topic_length.is_some() {
+        let limits = ServerMessage::LimitsUpdated {
+            max_message_length: ctx.db.config.get_max_message_length().await as u32,
+            max_topic_length: ctx.db.config.get_max_topic_length().await as u32,
+            chat_slow_mode: current_chat_slow_mode,
+        };
+        ctx.user_manager
+            .broadcast_extended(limits, &ctx.db.users)
+            .await;
+    }
+
     // Send success response to requester
     ctx.send_message(&ServerMessage::ServerInfoUpdateResponse {
         success: true,
@@ -209,11 +304,10 @@ mod tests {
         let mut test_ctx = create_test_context().await;
 
         let result = handle_server_info_update(
-            Some("New Name".to_string()),
-            None,
-            None,
-            None,
-            None,
+            ServerInfoUpdateRequest {
+                name: Some("New Name".to_string()),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -222,7 +316,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_not_logged_in(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -238,11 +334,11 @@ mod tests {
         let session_id = login_user(&mut test_ctx, "testuser", "password", &[], false).await;
 
         let result = handle_server_info_update(
-            Some("New Name".to_string()),
-            None,
-            None,
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                name: Some("New Name".to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -251,7 +347,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_admin_required(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -267,11 +365,10 @@ mod tests {
         let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
 
         let result = handle_server_info_update(
-            None,
-            None,
-            None,
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -280,7 +377,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_no_fields_to_update(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -296,11 +395,11 @@ mod tests {
         let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
 
         let result = handle_server_info_update(
-            Some("".to_string()),
-            None,
-            None,
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                name: Some("".to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -309,7 +408,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_server_name_empty(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -326,11 +427,11 @@ mod tests {
 
         let long_name = "a".repeat(validators::MAX_SERVER_NAME_LENGTH + 1);
         let result = handle_server_info_update(
-            Some(long_name),
-            None,
-            None,
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                name: Some(long_name),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -339,7 +440,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(message.contains(&validators::MAX_SERVER_NAME_LENGTH.to_string()));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -356,11 +459,11 @@ mod tests {
 
         let long_desc = "a".repeat(validators::MAX_SERVER_DESCRIPTION_LENGTH + 1);
         let result = handle_server_info_update(
-            None,
-            Some(long_desc),
-            None,
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                description: Some(long_desc),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -369,7 +472,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(message.contains(&validators::MAX_SERVER_DESCRIPTION_LENGTH.to_string()));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -385,11 +490,11 @@ mod tests {
         let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
 
         let result = handle_server_info_update(
-            None,
-            None,
-            Some(0),
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                max_connections_per_ip: Some(0),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -398,7 +503,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(
                     message,
                     err_max_connections_per_ip_invalid(DEFAULT_TEST_LOCALE)
@@ -409,6 +516,118 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_server_info_update_max_message_length_out_of_range_fails() {
+        let mut test_ctx = create_test_context().await;
+
+        // Login as admin
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        for length in [0, validators::MAX_MESSAGE_LENGTH_CEILING as u32 + 1] {
+            let result = handle_server_info_update(
+                ServerInfoUpdateRequest {
+                    max_message_length: Some(length),
+                    session_id: Some(session_id),
+                    ..Default::default()
+                },
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+
+            assert!(result.is_ok());
+
+            let response = read_server_message(&mut test_ctx.client).await;
+            match response {
+                ServerMessage::Error { message, .. } => {
+                    assert_eq!(
+                        message,
+                        err_max_message_length_invalid(
+                            DEFAULT_TEST_LOCALE,
+                            validators::MAX_MESSAGE_LENGTH_CEILING
+                        )
+                    );
+                }
+                _ => panic!("Expected Error message, got {:?}", response),
+            }
+        }
+
+        // Nothing was saved
+        assert_eq!(
+            test_ctx.db.config.get_max_message_length().await,
+            validators::MAX_MESSAGE_LENGTH
+        );
+    }
+
+    #[tokio::test]
+    async fn test_server_info_update_limits_reach_connected_users() {
+        let mut test_ctx = create_test_context().await;
+
+        // Login as admin, with another user connected
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_server_info_update(
+            ServerInfoUpdateRequest {
+                max_message_length: Some(500),
+                max_topic_length: Some(100),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::ServerInfoUpdateResponse { success: true, .. }
+        ));
+        assert_eq!(test_ctx.db.config.get_max_message_length().await, 500);
+        assert_eq!(test_ctx.db.config.get_max_topic_length().await, 100);
+
+        // Both sessions get the full set of limits
+        let mut updates = Vec::new();
+        while let Ok((msg, _)) = test_ctx._rx.messages.try_recv() {
+            if let ServerMessage::LimitsUpdated {
+                max_message_length,
+                max_topic_length,
+                chat_slow_mode,
+            } = msg
+            {
+                updates.push((max_message_length, max_topic_length, chat_slow_mode));
+            }
+        }
+        assert_eq!(updates, vec![(500, 100, 0), (500, 100, 0)]);
+    }
+
+    #[tokio::test]
+    async fn test_server_info_update_without_limits_sends_no_limits_update() {
+        let mut test_ctx = create_test_context().await;
+
+        // Login as admin
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_server_info_update(
+            ServerInfoUpdateRequest {
+                name: Some("Renamed".to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let mut saw_limits = false;
+        while let Ok((msg, _)) = test_ctx._rx.messages.try_recv() {
+            saw_limits |= matches!(msg, ServerMessage::LimitsUpdated { .. });
+        }
+        assert!(!saw_limits);
+    }
+
     #[tokio::test]
     async fn test_server_info_update_name_success() {
         let mut test_ctx = create_test_context().await;
@@ -417,11 +636,11 @@ mod tests {
         let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
 
         let result = handle_server_info_update(
-            Some("My New Server".to_string()),
-            None,
-            None,
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                name: Some("My New Server".to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -450,11 +669,11 @@ mod tests {
         let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
 
         let result = handle_server_info_update(
-            None,
-            Some("Welcome to my server!".to_string()),
-            None,
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                description: Some("Welcome to my server!".to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -483,11 +702,11 @@ mod tests {
         let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
 
         let result = handle_server_info_update(
-            None,
-            None,
-            Some(10),
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                max_connections_per_ip: Some(10),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -516,11 +735,13 @@ mod tests {
         let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
 
         let result = handle_server_info_update(
-            Some("Full Update Server".to_string()),
-            Some("All fields updated".to_string()),
-            Some(15),
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                name: Some("Full Update Server".to_string()),
+                description: Some("All fields updated".to_string()),
+                max_connections_per_ip: Some(15),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -564,11 +785,11 @@ mod tests {
 
         // Then clear it
         let result = handle_server_info_update(
-            None,
-            Some("".to_string()),
-            None,
-            None,
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                description: Some("".to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -602,11 +823,11 @@ mod tests {
 
         let image = "data:image/png;base64,iVBORw0KGgo=";
         let result = handle_server_info_update(
-            None,
-            None,
-            None,
-            Some(image.to_string()),
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                image: Some(image.to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -644,11 +865,11 @@ mod tests {
 
         // Then clear it
         let result = handle_server_info_update(
-            None,
-            None,
-            None,
-            Some("".to_string()),
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                image: Some("".to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -682,11 +903,11 @@ mod tests {
         let large_image = format!("{}{}", prefix, padding);
 
         let result = handle_server_info_update(
-            None,
-            None,
-            None,
-            Some(large_image),
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                image: Some(large_image),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -695,7 +916,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_server_image_too_large(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -711,11 +934,11 @@ mod tests {
         let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
 
         let result = handle_server_info_update(
-            None,
-            None,
-            None,
-            Some("not a data uri".to_string()),
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                image: Some("not a data uri".to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -724,7 +947,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(
                     message,
                     err_server_image_invalid_format(DEFAULT_TEST_LOCALE)
@@ -744,11 +969,11 @@ mod tests {
 
         // GIF is not supported
         let result = handle_server_info_update(
-            None,
-            None,
-            None,
-            Some("data:image/gif;base64,R0lGODlh".to_string()),
-            Some(session_id),
+            ServerInfoUpdateRequest {
+                image: Some("data:image/gif;base64,R0lGODlh".to_string()),
+                session_id: Some(session_id),
+                ..Default::default()
+            },
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -757,7 +982,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(
                     message,
                     err_server_image_unsupported_type(DEFAULT_TEST_LOCALE)
@@ -0,0 +1,104 @@
+//! Handler for SetAway command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use super::user_update::broadcast_user_updated;
+use super::{HandlerContext, err_authentication, err_not_logged_in};
+
+/// Handle SetAway command
+///
+/// Records whether the session is idle. Clients send this on their own, so
+/// there is no response; when the account's away status changes (every
+/// session away, or one of them back), user list holders get `UserUpdated`.
+pub async fn handle_set_away<W>(
+    away: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("SetAway from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("SetAway"))
+            .await;
+    };
+
+    let Some(changed) = ctx.user_manager.set_away(id, away).await else {
+        return ctx
+            .send_error(&err_authentication(ctx.locale), Some("SetAway"))
+            .await;
+    };
+    if !changed {
+        return Ok(());
+    }
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(id).await else {
+        return Ok(());
+    };
+    match ctx.db.users.get_user_by_username(&user.username).await {
+        Ok(Some(account)) => {
+            broadcast_user_updated(&account, account.username.clone(), ctx).await;
+        }
+        Ok(None) => {}
+        Err(e) => eprintln!("Database error reading {}: {}", user.username, e),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permission;
+    use crate::handlers::testing::{create_test_context, login_user};
+    use nexus_common::protocol::ServerMessage;
+
+    #[tokio::test]
+    async fn test_set_away_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_set_away(true, None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+        assert!(test_ctx.user_manager.get_all_users().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_set_away_broadcasts_status() {
+        let mut test_ctx = create_test_context().await;
+        login_user(
+            &mut test_ctx,
+            "watcher",
+            "password",
+            &[Permission::UserList],
+            false,
+        )
+        .await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        for away in [true, false] {
+            let result =
+                handle_set_away(away, Some(session_id), &mut test_ctx.handler_context()).await;
+            assert!(result.is_ok());
+
+            let mut updated = None;
+            while let Ok((msg, _)) = test_ctx._rx.messages.try_recv() {
+                if let ServerMessage::UserUpdated { user, .. } = msg {
+                    updated = Some(user);
+                }
+            }
+            let user = updated.expect("Expected UserUpdated broadcast");
+            assert_eq!(user.username, "alice");
+            assert_eq!(user.away, away);
+        }
+
+        // Repeating the current status broadcasts nothing
+        let result =
+            handle_set_away(false, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+        assert!(test_ctx._rx.messages.try_recv().is_err());
+    }
+}
@@ -0,0 +1,144 @@
+//! Handler for SetMaintenance command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_admin_required, err_authentication, err_database, err_not_logged_in,
+};
+use crate::constants::ERR_MAINTENANCE_MODE;
+
+/// Handle a request to turn maintenance (read-only) mode on or off (admin only)
+///
+/// The new state is persisted and broadcast to every connected user so clients
+/// can show a banner and disable input.
+pub async fn handle_set_maintenance<W>(
+    enabled: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("SetMaintenance from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("SetMaintenance"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("SetMaintenance"))
+                .await;
+        }
+    };
+
+    if !user.is_admin {
+        eprintln!(
+            "SetMaintenance from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        let response = ServerMessage::SetMaintenanceResponse {
+            success: false,
+            error: Some(err_admin_required(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    if let Err(e) = ctx.db.config.set_maintenance_mode(enabled).await {
+        eprintln!("{}{}", ERR_MAINTENANCE_MODE, e);
+        let response = ServerMessage::SetMaintenanceResponse {
+            success: false,
+            error: Some(err_database(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let response = ServerMessage::SetMaintenanceResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    ctx.user_manager
+        .broadcast(ServerMessage::MaintenanceChanged { enabled }, &ctx.db.users)
+        .await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permission;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_set_maintenance_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_set_maintenance(true, None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err(), "Should disconnect when not logged in");
+        assert!(!test_ctx.db.config.get_maintenance_mode().await);
+    }
+
+    #[tokio::test]
+    async fn test_set_maintenance_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+
+        let result =
+            handle_set_maintenance(true, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::SetMaintenanceResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            other => panic!("Expected SetMaintenanceResponse, got {:?}", other),
+        }
+        assert!(!test_ctx.db.config.get_maintenance_mode().await);
+    }
+
+    #[tokio::test]
+    async fn test_set_maintenance_persists_and_broadcasts() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result =
+            handle_set_maintenance(true, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::SetMaintenanceResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            other => panic!("Expected SetMaintenanceResponse, got {:?}", other),
+        }
+        assert!(test_ctx.db.config.get_maintenance_mode().await);
+
+        // The admin's own session receives the broadcast through its queue
+        let mut broadcast = None;
+        while let Ok((msg, _)) = test_ctx._rx.messages.try_recv() {
+            if let ServerMessage::MaintenanceChanged { enabled } = msg {
+                broadcast = Some(enabled);
+            }
+        }
+        assert_eq!(broadcast, Some(true));
+    }
+}
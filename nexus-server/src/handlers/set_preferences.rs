@@ -0,0 +1,241 @@
+//! Handler for SetPreferences command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, PreferencesError};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_guest_not_allowed, err_not_logged_in,
+    err_preferences_not_object, err_preferences_too_large, err_server_maintenance,
+};
+
+/// Handle SetPreferences command
+///
+/// Stores the preferences blob for the logged-in account so it can be returned
+/// in `LoginResponse` on the user's other devices.
+pub async fn handle_set_preferences<W>(
+    preferences: serde_json::Value,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first (before revealing validation errors to unauthenticated users)
+    let Some(id) = session_id else {
+        eprintln!("SetPreferences from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("SetPreferences"))
+            .await;
+    };
+
+    if let Err(e) = validators::validate_preferences(&preferences) {
+        let error_msg = match e {
+            PreferencesError::NotObject => err_preferences_not_object(ctx.locale),
+            PreferencesError::TooLarge => {
+                err_preferences_too_large(ctx.locale, validators::MAX_PREFERENCES_LENGTH)
+            }
+        };
+        let response = ServerMessage::SetPreferencesResponse {
+            success: false,
+            error: Some(error_msg),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error(&err_authentication(ctx.locale), Some("SetPreferences"))
+                .await;
+        }
+    };
+
+    // Guests have no account to store preferences in
+    if user.is_guest {
+        let response = ServerMessage::SetPreferencesResponse {
+            success: false,
+            error: Some(err_guest_not_allowed(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    if ctx.maintenance_blocks(user.is_admin).await {
+        let response = ServerMessage::SetPreferencesResponse {
+            success: false,
+            error: Some(err_server_maintenance(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    if let Err(e) = ctx
+        .db
+        .users
+        .set_preferences(user.db_user_id, &preferences.to_string())
+        .await
+    {
+        eprintln!("Database error setting preferences: {}", e);
+        let response = ServerMessage::SetPreferencesResponse {
+            success: false,
+            error: Some(err_database(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let response = ServerMessage::SetPreferencesResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_guest, login_user, read_server_message,
+    };
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_set_preferences_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_set_preferences(json!({}), None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(response, ServerMessage::Error { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_set_preferences_stores_value() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let preferences = json!({"theme": "Dark", "show_seconds": false});
+        let result = handle_set_preferences(
+            preferences.clone(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::SetPreferencesResponse {
+                success: true,
+                error: None
+            }
+        ));
+
+        let account = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        let stored = test_ctx
+            .db
+            .users
+            .get_preferences(account.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&stored).unwrap(),
+            preferences
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_preferences_rejects_invalid() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let oversized = json!({"k": "x".repeat(validators::MAX_PREFERENCES_LENGTH)});
+        let result =
+            handle_set_preferences(oversized, Some(session_id), &mut test_ctx.handler_context())
+                .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::SetPreferencesResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            _ => panic!("Expected SetPreferencesResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_preferences_blocked_during_maintenance() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        test_ctx.db.config.set_maintenance_mode(true).await.unwrap();
+
+        let result = handle_set_preferences(
+            json!({"theme": "Dark"}),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::SetPreferencesResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_server_maintenance(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected SetPreferencesResponse, got {:?}", other),
+        }
+
+        let account = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(
+            test_ctx
+                .db
+                .users
+                .get_preferences(account.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_preferences_refused_for_guests() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_guest(&mut test_ctx, "guest-alice").await;
+
+        let result = handle_set_preferences(
+            json!({"theme": "Dark"}),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::SetPreferencesResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_guest_not_allowed(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected SetPreferencesResponse, got {:?}", other),
+        }
+    }
+}
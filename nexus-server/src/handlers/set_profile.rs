@@ -0,0 +1,310 @@
+//! Handler for SetProfile command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, BioError, PronounsError};
+
+use super::{
+    HandlerContext, err_authentication, err_bio_contains_newlines, err_bio_invalid_characters,
+    err_bio_too_long, err_database, err_guest_not_allowed, err_not_logged_in,
+    err_pronouns_contains_newlines, err_pronouns_invalid_characters, err_pronouns_too_long,
+    err_server_maintenance,
+};
+use crate::db::UserProfile;
+
+/// Handle SetProfile command
+///
+/// Replaces the logged-in user's bio and pronouns, which anyone who can view
+/// their user info sees. Surrounding whitespace is trimmed, and empty fields
+/// clear the stored value.
+pub async fn handle_set_profile<W>(
+    bio: String,
+    pronouns: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first (before revealing validation errors to unauthenticated users)
+    let Some(id) = session_id else {
+        eprintln!("SetProfile from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("SetProfile"))
+            .await;
+    };
+
+    let profile = UserProfile {
+        bio: bio.trim().to_string(),
+        pronouns: pronouns.trim().to_string(),
+    };
+
+    if let Err(e) = validators::validate_bio(&profile.bio) {
+        let error_msg = match e {
+            BioError::TooLong => err_bio_too_long(ctx.locale, validators::MAX_BIO_LENGTH),
+            BioError::ContainsNewlines => err_bio_contains_newlines(ctx.locale),
+            BioError::InvalidCharacters => err_bio_invalid_characters(ctx.locale),
+        };
+        return send_failure(ctx, error_msg).await;
+    }
+
+    if let Err(e) = validators::validate_pronouns(&profile.pronouns) {
+        let error_msg = match e {
+            PronounsError::TooLong => {
+                err_pronouns_too_long(ctx.locale, validators::MAX_PRONOUNS_LENGTH)
+            }
+            PronounsError::ContainsNewlines => err_pronouns_contains_newlines(ctx.locale),
+            PronounsError::InvalidCharacters => err_pronouns_invalid_characters(ctx.locale),
+        };
+        return send_failure(ctx, error_msg).await;
+    }
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error(&err_authentication(ctx.locale), Some("SetProfile"))
+                .await;
+        }
+    };
+
+    // Guests have no account to keep a profile on
+    if user.is_guest {
+        return send_failure(ctx, err_guest_not_allowed(ctx.locale)).await;
+    }
+
+    if ctx.maintenance_blocks(user.is_admin).await {
+        return send_failure(ctx, err_server_maintenance(ctx.locale)).await;
+    }
+
+    match ctx.db.users.set_profile(user.db_user_id, &profile).await {
+        Ok(true) => {}
+        Ok(false) => {
+            // Account deleted while the session was still open
+            return ctx
+                .send_error(&err_authentication(ctx.locale), Some("SetProfile"))
+                .await;
+        }
+        Err(e) => {
+            eprintln!("Database error setting profile: {}", e);
+            return send_failure(ctx, err_database(ctx.locale)).await;
+        }
+    }
+
+    let response = ServerMessage::SetProfileResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send an unsuccessful `SetProfileResponse`
+async fn send_failure<W>(ctx: &mut HandlerContext<'_, W>, error: String) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::SetProfileResponse {
+        success: false,
+        error: Some(error),
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_guest, login_user,
+        read_server_message,
+    };
+
+    /// Stored profile of a test user
+    async fn stored_profile(test_ctx: &TestContext, username: &str) -> UserProfile {
+        let account = test_ctx
+            .db
+            .users
+            .get_user_by_username(username)
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx
+            .db
+            .users
+            .get_profile(account.id)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_set_profile_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_set_profile(
+            "bio".to_string(),
+            "they/them".to_string(),
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(response, ServerMessage::Error { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_set_profile_stores_trimmed_values() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_set_profile(
+            "  Retro computing fan ".to_string(),
+            " she/her ".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(
+            response,
+            ServerMessage::SetProfileResponse {
+                success: true,
+                error: None
+            }
+        ));
+        assert_eq!(
+            stored_profile(&test_ctx, "alice").await,
+            UserProfile {
+                bio: "Retro computing fan".to_string(),
+                pronouns: "she/her".to_string(),
+            }
+        );
+
+        // Empty fields clear the profile
+        let result = handle_set_profile(
+            String::new(),
+            "   ".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        read_server_message(&mut test_ctx.client).await;
+        assert_eq!(
+            stored_profile(&test_ctx, "alice").await,
+            UserProfile::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_profile_rejects_invalid() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let cases = [
+            (
+                "x".repeat(validators::MAX_BIO_LENGTH + 1),
+                String::new(),
+                err_bio_too_long(DEFAULT_TEST_LOCALE, validators::MAX_BIO_LENGTH),
+            ),
+            (
+                "line1\nline2".to_string(),
+                String::new(),
+                err_bio_contains_newlines(DEFAULT_TEST_LOCALE),
+            ),
+            (
+                String::new(),
+                "x".repeat(validators::MAX_PRONOUNS_LENGTH + 1),
+                err_pronouns_too_long(DEFAULT_TEST_LOCALE, validators::MAX_PRONOUNS_LENGTH),
+            ),
+            (
+                String::new(),
+                "she\0her".to_string(),
+                err_pronouns_invalid_characters(DEFAULT_TEST_LOCALE),
+            ),
+        ];
+
+        for (bio, pronouns, expected) in cases {
+            let result = handle_set_profile(
+                bio,
+                pronouns,
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_ok());
+
+            let response = read_server_message(&mut test_ctx.client).await;
+            match response {
+                ServerMessage::SetProfileResponse { success, error } => {
+                    assert!(!success);
+                    assert_eq!(error, Some(expected));
+                }
+                _ => panic!("Expected SetProfileResponse"),
+            }
+        }
+        assert_eq!(
+            stored_profile(&test_ctx, "alice").await,
+            UserProfile::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_profile_blocked_during_maintenance() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        test_ctx.db.config.set_maintenance_mode(true).await.unwrap();
+
+        let result = handle_set_profile(
+            "bio".to_string(),
+            String::new(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::SetProfileResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_server_maintenance(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected SetProfileResponse"),
+        }
+        assert_eq!(
+            stored_profile(&test_ctx, "alice").await,
+            UserProfile::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_profile_refused_for_guests() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_guest(&mut test_ctx, "guest-alice").await;
+
+        let result = handle_set_profile(
+            "bio".to_string(),
+            String::new(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::SetProfileResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_guest_not_allowed(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected SetProfileResponse, got {:?}", other),
+        }
+    }
+}
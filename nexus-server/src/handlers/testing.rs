@@ -7,16 +7,17 @@ use std::net::SocketAddr;
 
 use tokio::io::BufReader;
 use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::mpsc;
 
 use nexus_common::framing::{FrameReader, FrameWriter, MessageId};
 use nexus_common::io::read_server_message as io_read_server_message;
 use nexus_common::protocol::ServerMessage;
 
 use super::HandlerContext;
+use crate::connection_history::ConnectionHistory;
+use crate::constants::DEFAULT_OUTGOING_QUEUE_CAPACITY;
 use crate::db::Database;
 use crate::users::UserManager;
-use crate::users::user::NewSessionParams;
+use crate::users::user::{NewSessionParams, SessionReceiver, SessionSender, session_channel};
 
 /// Type alias for the write half used in tests
 type TestWriteHalf = tokio::net::tcp::OwnedWriteHalf;
@@ -27,9 +28,11 @@ pub struct TestContext {
     pub frame_writer: FrameWriter<TestWriteHalf>,
     pub user_manager: UserManager,
     pub db: Database,
-    pub tx: mpsc::UnboundedSender<(ServerMessage, Option<MessageId>)>,
+    pub connection_history: ConnectionHistory,
+    pub tx: SessionSender,
     pub peer_addr: SocketAddr,
-    pub _rx: mpsc::UnboundedReceiver<(ServerMessage, Option<MessageId>)>, // Keep receiver alive to prevent channel closure
+    pub source: String,
+    pub _rx: SessionReceiver, // Keep receiver alive to prevent channel closure
     pub message_id: MessageId,
 }
 
@@ -39,12 +42,15 @@ impl TestContext {
         HandlerContext {
             writer: &mut self.frame_writer,
             peer_addr: self.peer_addr,
+            source: &self.source,
             user_manager: &self.user_manager,
             db: &self.db,
+            connection_history: &self.connection_history,
             tx: &self.tx,
             debug: false, // Tests don't need debug logging
             locale: DEFAULT_TEST_LOCALE,
             message_id: self.message_id,
+            legacy_protocol: false,
         }
     }
 }
@@ -80,7 +86,7 @@ pub async fn create_test_context() -> TestContext {
     let client = client_handle.await.unwrap();
 
     // Create message channel (keep receiver alive to prevent channel closure)
-    let (tx, rx) = mpsc::unbounded_channel();
+    let (tx, rx) = session_channel(DEFAULT_OUTGOING_QUEUE_CAPACITY);
 
     // Create a default message ID for tests (must be valid hex characters)
     let message_id = MessageId::from_bytes(b"000000000000").unwrap();
@@ -90,8 +96,11 @@ pub async fn create_test_context() -> TestContext {
         frame_writer,
         user_manager,
         db,
+        connection_history: ConnectionHistory::new(),
         tx,
         peer_addr,
+        // Test clients connect over loopback
+        source: "loopback".to_string(),
         _rx: rx,
         message_id,
     }
@@ -146,11 +155,37 @@ pub async fn login_user_with_features(
             is_admin,
             permissions: perms.permissions.clone(),
             address: test_ctx.peer_addr,
+            source: test_ctx.source.clone(),
             created_at: user.created_at,
             tx: test_ctx.tx.clone(),
             features,
             locale: DEFAULT_TEST_LOCALE.to_string(),
             avatar: None,
+            is_guest: false,
+            legacy_protocol: false,
+        })
+        .await
+}
+
+/// Helper to add a chatting guest session to UserManager, returning its session_id
+pub async fn login_guest(test_ctx: &mut TestContext, username: &str) -> u32 {
+    test_ctx
+        .user_manager
+        .add_user(NewSessionParams {
+            session_id: 0, // Will be assigned by add_user
+            db_user_id: 0, // Will be assigned by add_user
+            username: username.to_string(),
+            is_admin: false,
+            permissions: crate::db::GUEST_PERMISSIONS.iter().copied().collect(),
+            address: test_ctx.peer_addr,
+            source: test_ctx.source.clone(),
+            created_at: super::current_timestamp(),
+            tx: test_ctx.tx.clone(),
+            features: vec![crate::constants::FEATURE_CHAT.to_string()],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            is_guest: true,
+            legacy_protocol: false,
         })
         .await
 }
@@ -167,3 +202,16 @@ pub async fn read_server_message(client: &mut TcpStream) -> ServerMessage {
         .expect("Connection closed unexpectedly")
         .message
 }
+
+/// Build a PNG avatar data URI of the given size (solid color)
+pub fn png_data_uri(width: u32, height: u32) -> String {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+
+    let image = image::RgbaImage::from_pixel(width, height, image::Rgba([40, 120, 200, 255]));
+    let mut png = Vec::new();
+    image
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .expect("Failed to encode PNG");
+    format!("data:image/png;base64,{}", STANDARD.encode(png))
+}
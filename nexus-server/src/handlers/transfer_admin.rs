@@ -0,0 +1,321 @@
+//! Handler for TransferAdmin command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, UsernameError};
+
+use super::user_update::{broadcast_user_updated, push_permissions_update};
+use super::{
+    HandlerContext, err_account_disabled, err_admin_required, err_already_admin,
+    err_authentication, err_cannot_demote_last_admin, err_cannot_transfer_admin_to_self,
+    err_database, err_not_logged_in, err_user_not_found, err_username_empty, err_username_invalid,
+    err_username_too_long,
+};
+use crate::db::AdminTransfer;
+
+/// Handle a request to make another account an admin (admin only)
+///
+/// With `demote_self`, the caller steps down in the same database transaction,
+/// so a server with a single admin can hand ownership over without ever
+/// having zero or two admins visible to other requests. Both accounts' online
+/// sessions receive their new permissions and a `UserUpdated` broadcast.
+pub async fn handle_transfer_admin<W>(
+    to_username: String,
+    demote_self: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first (before revealing validation errors to unauthenticated users)
+    let Some(id) = session_id else {
+        eprintln!("TransferAdmin from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("TransferAdmin"))
+            .await;
+    };
+
+    if let Err(e) = validators::validate_username(&to_username) {
+        let error_msg = match e {
+            UsernameError::Empty => err_username_empty(ctx.locale),
+            UsernameError::TooLong => {
+                err_username_too_long(ctx.locale, validators::MAX_USERNAME_LENGTH)
+            }
+            UsernameError::InvalidCharacters => err_username_invalid(ctx.locale),
+        };
+        return send_failure(error_msg, ctx).await;
+    }
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("TransferAdmin"))
+                .await;
+        }
+    };
+
+    if !user.is_admin {
+        eprintln!(
+            "TransferAdmin from {} (user: {}) without admin",
+            ctx.peer_addr, user.username
+        );
+        return send_failure(err_admin_required(ctx.locale), ctx).await;
+    }
+
+    let target = match ctx
+        .db
+        .users
+        .transfer_admin(user.db_user_id, &to_username, demote_self)
+        .await
+    {
+        Ok(AdminTransfer::Transferred(account)) => account,
+        Ok(outcome) => {
+            let error_msg = match outcome {
+                AdminTransfer::NotAdmin => err_admin_required(ctx.locale),
+                AdminTransfer::TargetNotFound => err_user_not_found(ctx.locale, &to_username),
+                AdminTransfer::TargetIsSelf => err_cannot_transfer_admin_to_self(ctx.locale),
+                AdminTransfer::TargetDisabled => err_account_disabled(ctx.locale, &to_username),
+                AdminTransfer::TargetAlreadyAdmin => err_already_admin(ctx.locale, &to_username),
+                AdminTransfer::LastAdmin | AdminTransfer::Transferred(_) => {
+                    err_cannot_demote_last_admin(ctx.locale)
+                }
+            };
+            return send_failure(error_msg, ctx).await;
+        }
+        Err(e) => {
+            eprintln!(
+                "Database error transferring admin to {}: {}",
+                to_username, e
+            );
+            return send_failure(err_database(ctx.locale), ctx).await;
+        }
+    };
+
+    let response = ServerMessage::TransferAdminResponse {
+        success: true,
+        error: None,
+        username: Some(target.username.clone()),
+    };
+    ctx.send_message(&response).await?;
+
+    // Refresh cached admin status, then tell both accounts and everyone else
+    let mut changed = vec![target];
+    if demote_self {
+        match ctx.db.users.get_user_by_username(&user.username).await {
+            Ok(Some(account)) => changed.push(account),
+            Ok(None) => {}
+            Err(e) => eprintln!("Database error reading {}: {}", user.username, e),
+        }
+    }
+    for account in &changed {
+        ctx.user_manager
+            .update_admin_status(account.id, account.is_admin)
+            .await;
+        if let Err(e) = push_permissions_update(account, ctx).await {
+            eprintln!("Database error reading permissions: {}", e);
+        }
+        broadcast_user_updated(account, account.username.clone(), ctx).await;
+    }
+
+    Ok(())
+}
+
+/// Reply with a failed TransferAdminResponse
+async fn send_failure<W>(error: String, ctx: &mut HandlerContext<'_, W>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::TransferAdminResponse {
+        success: false,
+        error: Some(error),
+        username: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permission;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    async fn is_admin(test_ctx: &crate::handlers::testing::TestContext, username: &str) -> bool {
+        test_ctx
+            .db
+            .users
+            .get_user_by_username(username)
+            .await
+            .unwrap()
+            .unwrap()
+            .is_admin
+    }
+
+    #[tokio::test]
+    async fn test_transfer_admin_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_transfer_admin(
+            "bob".to_string(),
+            true,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_transfer_admin_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserEdit],
+            false,
+        )
+        .await;
+        login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        let result = handle_transfer_admin(
+            "bob".to_string(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::TransferAdminResponse { success, error, .. } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            other => panic!("Expected TransferAdminResponse, got {:?}", other),
+        }
+        assert!(!is_admin(&test_ctx, "bob").await);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_admin_single_admin_handoff() {
+        let mut test_ctx = create_test_context().await;
+        let owner_session = login_user(&mut test_ctx, "owner", "password", &[], true).await;
+        let heir_session = login_user(
+            &mut test_ctx,
+            "heir",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+
+        let result = handle_transfer_admin(
+            "Heir".to_string(),
+            true,
+            Some(owner_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::TransferAdminResponse {
+                success,
+                error,
+                username,
+            } => {
+                assert!(success);
+                assert!(error.is_none());
+                assert_eq!(username.as_deref(), Some("heir"));
+            }
+            other => panic!("Expected TransferAdminResponse, got {:?}", other),
+        }
+
+        // Database and online sessions agree on the new owner
+        assert!(is_admin(&test_ctx, "heir").await);
+        assert!(!is_admin(&test_ctx, "owner").await);
+        let heir = test_ctx
+            .user_manager
+            .get_user_by_session_id(heir_session)
+            .await
+            .unwrap();
+        assert!(heir.is_admin);
+        let owner = test_ctx
+            .user_manager
+            .get_user_by_session_id(owner_session)
+            .await
+            .unwrap();
+        assert!(!owner.is_admin);
+
+        // The former owner can no longer transfer admin back
+        let result = handle_transfer_admin(
+            "heir".to_string(),
+            true,
+            Some(owner_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::TransferAdminResponse { success, .. } => assert!(!success),
+            other => panic!("Expected TransferAdminResponse, got {:?}", other),
+        }
+        assert!(is_admin(&test_ctx, "heir").await);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_admin_keep_own_admin() {
+        let mut test_ctx = create_test_context().await;
+        let owner_session = login_user(&mut test_ctx, "owner", "password", &[], true).await;
+        login_user(&mut test_ctx, "heir", "password", &[], false).await;
+
+        let result = handle_transfer_admin(
+            "heir".to_string(),
+            false,
+            Some(owner_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::TransferAdminResponse { success, .. } => assert!(success),
+            other => panic!("Expected TransferAdminResponse, got {:?}", other),
+        }
+        assert!(is_admin(&test_ctx, "heir").await);
+        assert!(is_admin(&test_ctx, "owner").await);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_admin_rejects_self_and_unknown() {
+        let mut test_ctx = create_test_context().await;
+        let owner_session = login_user(&mut test_ctx, "owner", "password", &[], true).await;
+
+        for target in ["OWNER", "nobody"] {
+            let result = handle_transfer_admin(
+                target.to_string(),
+                true,
+                Some(owner_session),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_ok());
+
+            match read_server_message(&mut test_ctx.client).await {
+                ServerMessage::TransferAdminResponse { success, error, .. } => {
+                    assert!(!success);
+                    assert!(error.is_some());
+                }
+                other => panic!("Expected TransferAdminResponse, got {:?}", other),
+            }
+        }
+
+        // The only admin is still an admin
+        assert!(is_admin(&test_ctx, "owner").await);
+    }
+}
@@ -7,15 +7,17 @@ use tokio::io::AsyncWrite;
 use nexus_common::protocol::ServerMessage;
 use nexus_common::validators::{self, PasswordError, PermissionsError, UsernameError};
 
+use super::login::is_guest_username;
 #[cfg(test)]
 use super::testing::DEFAULT_TEST_LOCALE;
 use super::{
-    HandlerContext, err_authentication, err_cannot_create_admin, err_database, err_not_logged_in,
-    err_password_empty, err_password_too_long, err_permission_denied,
-    err_permissions_contains_newlines, err_permissions_empty_permission,
-    err_permissions_invalid_characters, err_permissions_permission_too_long,
-    err_permissions_too_many, err_unknown_permission, err_username_empty, err_username_exists,
-    err_username_invalid, err_username_too_long,
+    HandlerContext, err_authentication, err_cannot_create_admin, err_database,
+    err_feature_disabled, err_guest_name_reserved, err_not_logged_in, err_password_empty,
+    err_password_too_long, err_permission_denied, err_permissions_contains_newlines,
+    err_permissions_empty_permission, err_permissions_invalid_characters,
+    err_permissions_permission_too_long, err_permissions_too_many, err_server_maintenance,
+    err_unknown_permission, err_username_empty, err_username_exists, err_username_invalid,
+    err_username_too_long,
 };
 use crate::db::{Permission, Permissions, hash_password};
 
@@ -52,6 +54,7 @@ where
         let response = ServerMessage::UserCreateResponse {
             success: false,
             error: Some(error_msg),
+            warning: None,
         };
         return ctx.send_message(&response).await;
     }
@@ -67,6 +70,7 @@ where
         let response = ServerMessage::UserCreateResponse {
             success: false,
             error: Some(error_msg),
+            warning: None,
         };
         return ctx.send_message(&response).await;
     }
@@ -87,6 +91,7 @@ where
         let response = ServerMessage::UserCreateResponse {
             success: false,
             error: Some(error_msg),
+            warning: None,
         };
         return ctx.send_message(&response).await;
     }
@@ -105,6 +110,16 @@ where
         }
     };
 
+    // Check whether UserCreate is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserCreate, requesting_user.is_admin)
+        .await
+    {
+        return ctx
+            .send_error(&err_feature_disabled(ctx.locale), Some("UserCreate"))
+            .await;
+    }
+
     // Check UserCreate permission (uses cached permissions, admin bypass built-in)
     if !requesting_user.has_permission(Permission::UserCreate) {
         eprintln!(
@@ -116,6 +131,16 @@ where
             .await;
     }
 
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(requesting_user.is_admin).await {
+        let response = ServerMessage::UserCreateResponse {
+            success: false,
+            error: Some(err_server_maintenance(ctx.locale)),
+            warning: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Verify admin creation privilege (use is_admin from UserManager)
     if is_admin && !requesting_user.is_admin {
         return ctx
@@ -133,6 +158,7 @@ where
                 let response = ServerMessage::UserCreateResponse {
                     success: false,
                     error: Some(err_unknown_permission(ctx.locale, perm_str)),
+                    warning: None,
                 };
                 return ctx.send_message(&response).await;
             }
@@ -153,6 +179,24 @@ where
         perms.permissions.insert(perm);
     }
 
+    // Admins have every permission, so prerequisites only matter for others
+    let warning = if is_admin {
+        None
+    } else {
+        ctx.permission_prerequisites_warning(&mut perms, &requesting_user)
+            .await
+    };
+
+    // Guest names belong to guests while guest login is on
+    if ctx.db.config.get_guest_login().await.enabled && is_guest_username(&username) {
+        let response = ServerMessage::UserCreateResponse {
+            success: false,
+            error: Some(err_guest_name_reserved(ctx.locale)),
+            warning: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Check for duplicate username
     match ctx.db.users.get_user_by_username(&username).await {
         Ok(Some(_)) => {
@@ -160,6 +204,7 @@ where
             let response = ServerMessage::UserCreateResponse {
                 success: false,
                 error: Some(err_username_exists(ctx.locale, &username)),
+                warning: None,
             };
             return ctx.send_message(&response).await;
         }
@@ -197,6 +242,7 @@ where
             let response = ServerMessage::UserCreateResponse {
                 success: true,
                 error: None,
+                warning,
             };
             ctx.send_message(&response).await
         }
@@ -290,7 +336,7 @@ mod tests {
         // Parse and verify response
         let response_msg = read_server_message(&mut test_ctx.client).await;
         match response_msg {
-            ServerMessage::UserCreateResponse { success, error } => {
+            ServerMessage::UserCreateResponse { success, error, .. } => {
                 assert!(success, "Response should indicate success");
                 assert!(error.is_none(), "Should have no error message");
             }
@@ -342,11 +388,14 @@ mod tests {
                 is_admin: true,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: admin.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -373,7 +422,7 @@ mod tests {
         // Parse and verify response
         let response_msg = read_server_message(&mut test_ctx.client).await;
         match response_msg {
-            ServerMessage::UserCreateResponse { success, error } => {
+            ServerMessage::UserCreateResponse { success, error, .. } => {
                 assert!(!success, "Response should indicate failure");
                 assert!(error.is_some(), "Should have error message");
                 let error_msg = error.unwrap();
@@ -387,6 +436,46 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_usercreate_guest_names_reserved_while_guests_allowed() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        for (guest_login, success) in [(true, false), (false, true)] {
+            test_ctx
+                .db
+                .config
+                .set_guest_login(guest_login)
+                .await
+                .unwrap();
+            let result = handle_user_create(
+                "guest-bob".to_string(),
+                "password".to_string(),
+                false,
+                true,
+                vec![],
+                Some(admin_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_ok());
+
+            match read_server_message(&mut test_ctx.client).await {
+                ServerMessage::UserCreateResponse {
+                    success: created,
+                    error,
+                    ..
+                } => {
+                    assert_eq!(created, success);
+                    if !success {
+                        assert_eq!(error, Some(err_guest_name_reserved(DEFAULT_TEST_LOCALE)));
+                    }
+                }
+                other => panic!("Expected UserCreateResponse, got {:?}", other),
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_usercreate_can_create_admin() {
         let mut test_ctx = create_test_context().await;
@@ -414,7 +503,7 @@ mod tests {
         // Parse and verify response
         let response_msg = read_server_message(&mut test_ctx.client).await;
         match response_msg {
-            ServerMessage::UserCreateResponse { success, error } => {
+            ServerMessage::UserCreateResponse { success, error, .. } => {
                 assert!(success, "Response should indicate success");
                 assert!(error.is_none(), "Should have no error message");
             }
@@ -471,7 +560,7 @@ mod tests {
         // Parse and verify response
         let response_msg = read_server_message(&mut test_ctx.client).await;
         match response_msg {
-            ServerMessage::UserCreateResponse { success, error } => {
+            ServerMessage::UserCreateResponse { success, error, .. } => {
                 assert!(success, "Response should indicate success");
                 assert!(error.is_none(), "Should have no error message");
             }
@@ -532,7 +621,7 @@ mod tests {
         // Parse and verify response
         let response_msg = read_server_message(&mut test_ctx.client).await;
         match response_msg {
-            ServerMessage::UserCreateResponse { success, error } => {
+            ServerMessage::UserCreateResponse { success, error, .. } => {
                 assert!(success, "Response should indicate success");
                 assert!(error.is_none(), "Should have no error message");
             }
@@ -636,11 +725,14 @@ mod tests {
                 is_admin: false,
                 permissions: perms.permissions.clone(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: creator.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -703,11 +795,14 @@ mod tests {
                 is_admin: false,
                 permissions: perms.permissions.clone(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: creator.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -815,7 +910,7 @@ mod tests {
         // Parse and verify response
         let response_msg = read_server_message(&mut test_ctx.client).await;
         match response_msg {
-            ServerMessage::UserCreateResponse { success, error } => {
+            ServerMessage::UserCreateResponse { success, error, .. } => {
                 assert!(success, "Response should indicate success");
                 assert!(error.is_none(), "Should have no error message");
             }
@@ -886,4 +981,149 @@ mod tests {
         let user = created_user.unwrap();
         assert!(!user.enabled, "User should be disabled");
     }
+
+    #[tokio::test]
+    async fn test_usercreate_warns_about_missing_prerequisites() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        // Default mode warns but saves permissions as given
+        handle_user_create(
+            "newuser".to_string(),
+            "password".to_string(),
+            false,
+            true,
+            vec!["chat_send".to_string()],
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserCreateResponse {
+                success, warning, ..
+            } => {
+                assert!(success);
+                assert!(warning.unwrap().contains("chat_receive"));
+            }
+            _ => panic!("Expected UserCreateResponse"),
+        }
+
+        let user = test_ctx
+            .db
+            .users
+            .get_user_by_username("newuser")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(
+            !test_ctx
+                .db
+                .users
+                .has_permission(user.id, db::Permission::ChatReceive)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_usercreate_enforces_grantable_prerequisites() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_permission_prerequisites(db::PermissionPrerequisites::Enforce)
+            .await
+            .unwrap();
+
+        // The requester can grant chat_receive but not user_list
+        let requester_id = login_user(
+            &mut test_ctx,
+            "creator",
+            "password",
+            &[
+                db::Permission::UserCreate,
+                db::Permission::ChatSend,
+                db::Permission::ChatReceive,
+                db::Permission::UserKick,
+            ],
+            false,
+        )
+        .await;
+
+        handle_user_create(
+            "newuser".to_string(),
+            "password".to_string(),
+            false,
+            true,
+            vec!["chat_send".to_string(), "user_kick".to_string()],
+            Some(requester_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserCreateResponse {
+                success, warning, ..
+            } => {
+                assert!(success);
+                let warning = warning.unwrap();
+                assert!(warning.contains("chat_receive"));
+                assert!(warning.contains("user_list"));
+            }
+            _ => panic!("Expected UserCreateResponse"),
+        }
+
+        let user = test_ctx
+            .db
+            .users
+            .get_user_by_username("newuser")
+            .await
+            .unwrap()
+            .unwrap();
+        let perms = test_ctx
+            .db
+            .users
+            .get_user_permissions(user.id)
+            .await
+            .unwrap();
+        assert!(perms.contains(db::Permission::ChatReceive));
+        assert!(!perms.contains(db::Permission::UserList));
+    }
+
+    #[tokio::test]
+    async fn test_usercreate_prerequisites_off() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_permission_prerequisites(db::PermissionPrerequisites::Off)
+            .await
+            .unwrap();
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        handle_user_create(
+            "newuser".to_string(),
+            "password".to_string(),
+            false,
+            true,
+            vec!["chat_send".to_string()],
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserCreateResponse {
+                success, warning, ..
+            } => {
+                assert!(success);
+                assert!(warning.is_none());
+            }
+            _ => panic!("Expected UserCreateResponse"),
+        }
+    }
 }
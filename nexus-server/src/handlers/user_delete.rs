@@ -11,8 +11,9 @@ use nexus_common::validators::{self, UsernameError};
 use super::testing::DEFAULT_TEST_LOCALE;
 use super::{
     HandlerContext, err_account_deleted, err_authentication, err_cannot_delete_last_admin,
-    err_cannot_delete_self, err_database, err_not_logged_in, err_permission_denied,
-    err_user_not_found, err_username_empty, err_username_invalid, err_username_too_long,
+    err_cannot_delete_self, err_database, err_feature_disabled, err_not_logged_in,
+    err_permission_denied, err_server_maintenance, err_user_not_found, err_username_empty,
+    err_username_invalid, err_username_too_long,
 };
 use crate::db::Permission;
 
@@ -68,6 +69,18 @@ where
         return ctx.send_message(&response).await;
     }
 
+    // Check whether UserDelete is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserDelete, requesting_user_session.is_admin)
+        .await
+    {
+        let response = ServerMessage::UserDeleteResponse {
+            success: false,
+            error: Some(err_feature_disabled(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Check UserDelete permission (uses cached permissions, admin bypass built-in)
     if !requesting_user_session.has_permission(Permission::UserDelete) {
         eprintln!(
@@ -81,6 +94,18 @@ where
         return ctx.send_message(&response).await;
     }
 
+    // Maintenance mode keeps non-admins read-only
+    if ctx
+        .maintenance_blocks(requesting_user_session.is_admin)
+        .await
+    {
+        let response = ServerMessage::UserDeleteResponse {
+            success: false,
+            error: Some(err_server_maintenance(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Look up target user in database
     let target_user = match ctx.db.users.get_user_by_username(&target_username).await {
         Ok(Some(user)) => user,
@@ -99,41 +124,15 @@ where
         }
     };
 
-    // Handle online user disconnection (all sessions)
-    let online_users = ctx
-        .user_manager
-        .get_sessions_by_username(&target_user.username)
-        .await;
-
-    for online_user in online_users {
-        // Send error message to the user being deleted (in their locale)
-        let disconnect_msg = ServerMessage::Error {
-            message: err_account_deleted(&online_user.locale),
-            command: None,
-        };
-        let _ = online_user.tx.send((disconnect_msg, None));
-
-        // Remove them from UserManager
-        let session_id = online_user.session_id;
-        if let Some(removed_user) = ctx.user_manager.remove_user(session_id).await {
-            // Broadcast disconnection to users with user_list permission
-            ctx.user_manager
-                .broadcast_user_event(
-                    ServerMessage::UserDisconnected {
-                        session_id,
-                        username: removed_user.username.clone(),
-                    },
-                    &ctx.db.users,
-                    Some(session_id), // Exclude the deleted user
-                )
-                .await;
-        }
-    }
-
     // Delete user from database (atomic last-admin protection)
     match ctx.db.users.delete_user(target_user.id).await {
         Ok(deleted) => {
             if deleted {
+                // Only disconnect once the account is really gone, so a blocked
+                // delete leaves the user online and a login that raced the
+                // delete can't leave a session behind for a missing account
+                disconnect_deleted_user(target_user.id, ctx).await;
+
                 // Send success response to the admin who deleted the user
                 let response = ServerMessage::UserDeleteResponse {
                     success: true,
@@ -157,14 +156,53 @@ where
     }
 }
 
+/// Disconnect every session of a deleted account
+///
+/// Sessions are matched by account ID rather than username, and each one is
+/// told why before it is removed and announced as disconnected.
+async fn disconnect_deleted_user<W>(db_user_id: i64, ctx: &HandlerContext<'_, W>)
+where
+    W: AsyncWrite + Unpin,
+{
+    let online_users = ctx
+        .user_manager
+        .get_sessions_by_db_user_id(db_user_id)
+        .await;
+
+    for online_user in online_users {
+        // Send error message to the user being deleted (in their locale)
+        let disconnect_msg = ServerMessage::Error {
+            message: err_account_deleted(&online_user.locale),
+            command: None,
+            code: None,
+        };
+        let _ = online_user.tx.send((disconnect_msg, None));
+
+        // Remove them from UserManager and close their connection
+        let session_id = online_user.session_id;
+        if let Some(removed_user) = ctx.user_manager.disconnect_user(session_id).await {
+            // Broadcast disconnection to users with user_list permission
+            ctx.user_manager
+                .broadcast_user_event(
+                    ServerMessage::UserDisconnected {
+                        session_id,
+                        username: removed_user.username,
+                    },
+                    &ctx.db.users,
+                    Some(session_id), // Exclude the deleted user
+                )
+                .await;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::DEFAULT_OUTGOING_QUEUE_CAPACITY;
     use crate::db;
     use crate::handlers::testing::{create_test_context, login_user, read_server_message};
-    use crate::users::user::NewSessionParams;
-
-    use tokio::sync::mpsc;
+    use crate::users::user::{NewSessionParams, session_channel};
 
     #[tokio::test]
     async fn test_userdelete_requires_login() {
@@ -409,7 +447,7 @@ mod tests {
             .unwrap();
 
         // Add online_user to UserManager (they're online)
-        let (online_tx, _online_rx) = mpsc::unbounded_channel();
+        let (online_tx, _online_rx) = session_channel(DEFAULT_OUTGOING_QUEUE_CAPACITY);
         let online_session_id = test_ctx
             .user_manager
             .add_user(NewSessionParams {
@@ -419,11 +457,14 @@ mod tests {
                 is_admin: false,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: online_user.created_at,
                 tx: online_tx,
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -486,6 +527,121 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_userdelete_disconnects_connected_user() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let target = test_ctx
+            .db
+            .users
+            .create_user("target", "hash", false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+        let (target_tx, mut target_rx) = session_channel(DEFAULT_OUTGOING_QUEUE_CAPACITY);
+        let target_session_id = test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: target.id,
+                username: "target".to_string(),
+                is_admin: false,
+                permissions: std::collections::HashSet::new(),
+                address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
+                created_at: target.created_at,
+                tx: target_tx,
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
+            })
+            .await;
+
+        let result = handle_user_delete(
+            "target".to_string(),
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        // The deleted user is told why, then their connection is closed
+        match target_rx.messages.recv().await {
+            Some((ServerMessage::Error { message, .. }, _)) => {
+                assert_eq!(message, err_account_deleted(DEFAULT_TEST_LOCALE));
+            }
+            other => panic!("Expected Error, got: {:?}", other),
+        }
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            target_rx.close.notified(),
+        )
+        .await
+        .expect("Deleted session should be told to disconnect");
+
+        // Everyone else sees the session go
+        match test_ctx._rx.messages.try_recv() {
+            Ok((
+                ServerMessage::UserDisconnected {
+                    session_id,
+                    username,
+                },
+                _,
+            )) => {
+                assert_eq!(session_id, target_session_id);
+                assert_eq!(username, "target");
+            }
+            other => panic!("Expected UserDisconnected, got: {:?}", other),
+        }
+
+        // Requests still arriving from the deleted session are rejected
+        let result = crate::handlers::handle_user_list(
+            false,
+            Some(target_session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "Deleted session should be rejected");
+    }
+
+    #[tokio::test]
+    async fn test_userdelete_blocked_keeps_sessions_online() {
+        let mut test_ctx = create_test_context().await;
+        let admin_session_id = login_user(&mut test_ctx, "only_admin", "password", &[], true).await;
+        let deleter_id = login_user(
+            &mut test_ctx,
+            "deleter",
+            "password",
+            &[db::Permission::UserDelete],
+            false,
+        )
+        .await;
+
+        let result = handle_user_delete(
+            "only_admin".to_string(),
+            Some(deleter_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserDeleteResponse { success, .. } => assert!(!success),
+            other => panic!("Expected UserDeleteResponse, got: {:?}", other),
+        }
+
+        // A delete that didn't happen must not disconnect anyone
+        assert!(
+            test_ctx
+                .user_manager
+                .get_user_by_session_id(admin_session_id)
+                .await
+                .is_some()
+        );
+    }
+
     #[tokio::test]
     async fn test_userdelete_with_permission() {
         let mut test_ctx = create_test_context().await;
@@ -538,4 +694,54 @@ mod tests {
         let deleted = test_ctx.db.users.get_user_by_id(target.id).await.unwrap();
         assert!(deleted.is_none(), "Target user should be deleted");
     }
+
+    #[tokio::test]
+    async fn test_userdelete_maintenance_blocks_non_admins() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.db.config.set_maintenance_mode(true).await.unwrap();
+
+        let deleter_id = login_user(
+            &mut test_ctx,
+            "deleter",
+            "password",
+            &[db::Permission::UserDelete],
+            false,
+        )
+        .await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        test_ctx
+            .db
+            .users
+            .create_user("target", "hash", false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        let result = handle_user_delete(
+            "target".to_string(),
+            Some(deleter_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserDeleteResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_server_maintenance(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected UserDeleteResponse"),
+        }
+
+        // Admins can still act during maintenance
+        let result = handle_user_delete(
+            "target".to_string(),
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserDeleteResponse { success, .. } => assert!(success),
+            _ => panic!("Expected UserDeleteResponse"),
+        }
+    }
 }
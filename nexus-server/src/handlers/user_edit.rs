@@ -10,9 +10,9 @@ use nexus_common::validators::{self, UsernameError};
 #[cfg(test)]
 use super::testing::DEFAULT_TEST_LOCALE;
 use super::{
-    HandlerContext, err_authentication, err_cannot_edit_self, err_database, err_not_logged_in,
-    err_permission_denied, err_user_not_found, err_username_empty, err_username_invalid,
-    err_username_too_long,
+    HandlerContext, err_authentication, err_cannot_edit_self, err_database, err_feature_disabled,
+    err_not_logged_in, err_permission_denied, err_user_not_found, err_username_empty,
+    err_username_invalid, err_username_too_long,
 };
 use crate::db::Permission;
 
@@ -80,6 +80,22 @@ where
         return ctx.send_message(&response).await;
     }
 
+    // Check whether UserEdit is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserEdit, requesting_user.is_admin)
+        .await
+    {
+        let response = ServerMessage::UserEditResponse {
+            success: false,
+            error: Some(err_feature_disabled(ctx.locale)),
+            username: None,
+            is_admin: None,
+            enabled: None,
+            permissions: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Check UserEdit permission (uses cached permissions, admin bypass built-in)
     if !requesting_user.has_permission(Permission::UserEdit) {
         eprintln!(
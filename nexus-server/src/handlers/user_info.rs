@@ -10,12 +10,14 @@ use nexus_common::validators::{self, UsernameError};
 #[cfg(test)]
 use super::testing::DEFAULT_TEST_LOCALE;
 use super::{
-    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
-    err_user_not_found, err_username_empty, err_username_invalid, err_username_too_long,
+    HandlerContext, err_authentication, err_command_cooldown, err_database, err_feature_disabled,
+    err_not_logged_in, err_permission_denied, err_user_not_found, err_username_empty,
+    err_username_invalid, err_username_too_long,
 };
 #[cfg(test)]
 use crate::constants::FEATURE_CHAT;
 use crate::db::Permission;
+use crate::db::users::UserAccount;
 
 /// Handle a userinfo request from the client
 pub async fn handle_user_info<W>(
@@ -61,6 +63,16 @@ where
         }
     };
 
+    // Check whether UserInfo is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserInfo, requesting_user.is_admin)
+        .await
+    {
+        return ctx
+            .send_error(&err_feature_disabled(ctx.locale), Some("UserInfo"))
+            .await;
+    }
+
     // Check UserInfo permission (uses cached permissions, admin bypass built-in)
     if !requesting_user.has_permission(Permission::UserInfo) {
         eprintln!(
@@ -72,6 +84,18 @@ where
             .await;
     }
 
+    if let Some(remaining) = ctx
+        .command_cooldown(id, requesting_user.is_admin, "UserInfo")
+        .await
+    {
+        let response = ServerMessage::UserInfoResponse {
+            success: false,
+            error: Some(err_command_cooldown(ctx.locale, remaining)),
+            user: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Look up all sessions for target username (case-insensitive)
     let target_sessions = ctx
         .user_manager
@@ -88,15 +112,35 @@ where
         return ctx.send_message(&response).await;
     }
 
-    // Fetch target user account for admin status and created_at
-    let target_account = match ctx.db.users.get_user_by_username(&requested_username).await {
-        Ok(Some(acc)) => acc,
-        _ => {
+    // Fetch target user account for admin status and created_at (a guest's
+    // session stands in for the account it doesn't have)
+    let is_guest = target_sessions.iter().any(|s| s.is_guest);
+    let target_account = if is_guest {
+        let guest = &target_sessions[0];
+        UserAccount::guest(&guest.username, guest.created_at)
+    } else {
+        match ctx.db.users.get_user_by_username(&requested_username).await {
+            Ok(Some(acc)) => acc,
+            _ => {
+                return ctx
+                    .send_error_and_disconnect(&err_database(ctx.locale), Some("UserInfo"))
+                    .await;
+            }
+        }
+    };
+
+    // Profile is public to everyone who can view user info
+    let profile = match ctx.db.users.get_profile(target_account.id).await {
+        Ok(profile) => profile.unwrap_or_default(),
+        Err(e) => {
+            eprintln!("Database error reading profile: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserInfo"))
                 .await;
         }
     };
+    let pronouns = Some(profile.pronouns).filter(|p| !p.is_empty());
+    let bio = Some(profile.bio).filter(|b| !b.is_empty());
 
     // Aggregate session data
     let session_ids: Vec<u32> = target_sessions.iter().map(|s| s.session_id).collect();
@@ -127,37 +171,48 @@ where
         .map(|s| s.address.to_string())
         .collect();
 
+    // Source tag of each session, in the same order (for admins only)
+    let sources: Vec<String> = target_sessions.iter().map(|s| s.source.clone()).collect();
+
     // Use the actual username from the database (preserves original casing)
     let actual_username = target_account.username.clone();
 
     // Build response with appropriate visibility level
     // is_admin is visible to everyone (same as in user list)
-    // addresses are only visible to admins
+    // addresses and sources are only visible to admins
     let user_info = if requesting_user.is_admin {
-        // Admin gets all fields including addresses
+        // Admin gets all fields including addresses and sources
         UserInfoDetailed {
             username: actual_username,
             login_time: earliest_login,
             session_ids,
             features,
             created_at: target_account.created_at,
+            is_guest,
             locale,
             avatar,
+            pronouns,
+            bio,
             is_admin: Some(target_account.is_admin),
             addresses: Some(addresses),
+            sources: Some(sources),
         }
     } else {
-        // Non-admin gets all fields except addresses
+        // Non-admin gets all fields except addresses and sources
         UserInfoDetailed {
             username: actual_username,
             login_time: earliest_login,
             session_ids,
             features,
             created_at: target_account.created_at,
+            is_guest,
             locale,
             avatar,
+            pronouns,
+            bio,
             is_admin: Some(target_account.is_admin),
             addresses: None,
+            sources: None,
         }
     };
 
@@ -173,7 +228,9 @@ where
 mod tests {
     use super::*;
     use crate::db;
-    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+    use crate::handlers::testing::{
+        create_test_context, login_guest, login_user, read_server_message,
+    };
     use crate::users::user::NewSessionParams;
 
     #[tokio::test]
@@ -212,11 +269,14 @@ mod tests {
                 is_admin: false,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: user.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -266,11 +326,14 @@ mod tests {
                 is_admin: false,
                 permissions: perms.permissions.clone(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: user.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -352,11 +415,14 @@ mod tests {
                 is_admin: false,
                 permissions: perms.permissions.clone(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: requester.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![FEATURE_CHAT.to_string()],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -370,11 +436,14 @@ mod tests {
                 is_admin: false,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: target.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![FEATURE_CHAT.to_string()],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -425,6 +494,10 @@ mod tests {
                     user_info.addresses.is_none(),
                     "Non-admin should not see addresses field"
                 );
+                assert!(
+                    user_info.sources.is_none(),
+                    "Non-admin should not see sources field"
+                );
             }
             _ => panic!("Expected UserInfoResponse, got: {:?}", response_msg),
         }
@@ -463,11 +536,14 @@ mod tests {
                 is_admin: true,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: admin.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![FEATURE_CHAT.to_string()],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -481,11 +557,14 @@ mod tests {
                 is_admin: false,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: target.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![FEATURE_CHAT.to_string()],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -541,6 +620,11 @@ mod tests {
                     "Address should not be empty, got: {}",
                     addresses[0]
                 );
+                assert_eq!(
+                    user_info.sources,
+                    Some(vec!["loopback".to_string()]),
+                    "Admin should see one source per session"
+                );
             }
             _ => panic!("Expected UserInfoResponse, got: {:?}", response_msg),
         }
@@ -577,11 +661,14 @@ mod tests {
                 is_admin: true,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: admin1.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -595,11 +682,14 @@ mod tests {
                 is_admin: true,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: admin2.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -727,6 +817,7 @@ mod tests {
                 db_user_id: account.id,
                 username: "alice".to_string(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: account.created_at,
                 is_admin: false,
                 permissions: std::collections::HashSet::new(),
@@ -734,6 +825,8 @@ mod tests {
                 features: vec![],
                 locale: "en".to_string(),
                 avatar: Some(avatar_data.clone()),
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -795,6 +888,7 @@ mod tests {
                 db_user_id: account.id,
                 username: "alice".to_string(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: account.created_at,
                 is_admin: false,
                 permissions: std::collections::HashSet::new(),
@@ -802,6 +896,8 @@ mod tests {
                 features: vec![],
                 locale: "en".to_string(),
                 avatar: Some(old_avatar),
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -816,6 +912,7 @@ mod tests {
                 db_user_id: account.id,
                 username: "alice".to_string(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: account.created_at,
                 is_admin: false,
                 permissions: std::collections::HashSet::new(),
@@ -823,6 +920,8 @@ mod tests {
                 features: vec![],
                 locale: "en".to_string(),
                 avatar: Some(new_avatar.clone()),
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -876,8 +975,90 @@ mod tests {
             ServerMessage::UserInfoResponse { user, .. } => {
                 let user_info = user.unwrap();
                 assert_eq!(user_info.avatar, None, "Avatar should be None");
+                assert_eq!(user_info.pronouns, None, "Unset pronouns are omitted");
+                assert_eq!(user_info.bio, None, "Unset bio is omitted");
             }
             _ => panic!("Expected UserInfoResponse"),
         }
     }
+
+    #[tokio::test]
+    async fn test_userinfo_profile_visible_to_non_admin() {
+        let mut test_ctx = create_test_context().await;
+
+        let requester_id = login_user(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &[db::Permission::UserInfo],
+            false,
+        )
+        .await;
+        let _target_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let alice = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        let profile = db::UserProfile {
+            bio: "Retro computing fan".to_string(),
+            pronouns: "she/her".to_string(),
+        };
+        test_ctx
+            .db
+            .users
+            .set_profile(alice.id, &profile)
+            .await
+            .unwrap();
+
+        let result = handle_user_info(
+            "alice".to_string(),
+            Some(requester_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response_msg = read_server_message(&mut test_ctx.client).await;
+        match response_msg {
+            ServerMessage::UserInfoResponse { user, .. } => {
+                let user_info = user.unwrap();
+                assert_eq!(user_info.pronouns.as_deref(), Some("she/her"));
+                assert_eq!(user_info.bio.as_deref(), Some("Retro computing fan"));
+            }
+            _ => panic!("Expected UserInfoResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_userinfo_for_guest() {
+        let mut test_ctx = create_test_context().await;
+        let requester_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        login_guest(&mut test_ctx, "guest-alice").await;
+
+        let result = handle_user_info(
+            "guest-alice".to_string(),
+            Some(requester_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(
+            result.is_ok(),
+            "A guest has no account but can be looked up"
+        );
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserInfoResponse { success, user, .. } => {
+                assert!(success);
+                let user = user.unwrap();
+                assert_eq!(user.username, "guest-alice");
+                assert!(user.is_guest);
+                assert_eq!(user.is_admin, Some(false));
+            }
+            other => panic!("Expected UserInfoResponse, got {:?}", other),
+        }
+    }
 }
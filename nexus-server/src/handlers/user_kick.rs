@@ -4,13 +4,13 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{ERROR_CODE_KICKED, ServerMessage};
 use nexus_common::validators::{self, UsernameError};
 
 use super::{
     HandlerContext, err_authentication, err_cannot_kick_admin, err_cannot_kick_self, err_database,
-    err_kicked_by, err_not_logged_in, err_permission_denied, err_user_not_online,
-    err_username_empty, err_username_invalid, err_username_too_long,
+    err_feature_disabled, err_kicked_by, err_not_logged_in, err_permission_denied,
+    err_user_not_online, err_username_empty, err_username_invalid, err_username_too_long,
 };
 use crate::db::Permission;
 
@@ -67,6 +67,18 @@ where
         return ctx.send_message(&response).await;
     }
 
+    // Check whether UserKick is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserKick, requesting_user_session.is_admin)
+        .await
+    {
+        let response = ServerMessage::UserKickResponse {
+            success: false,
+            error: Some(err_feature_disabled(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Check UserKick permission (uses cached permissions, admin bypass built-in)
     if !requesting_user_session.has_permission(Permission::UserKick) {
         eprintln!(
@@ -122,12 +134,13 @@ where
         let kick_msg = ServerMessage::Error {
             message: err_kicked_by(&user.locale, &requesting_user_session.username),
             command: None,
+            code: Some(ERROR_CODE_KICKED.to_string()),
         };
         let _ = user.tx.send((kick_msg, None));
 
-        // Remove user from UserManager (channel closes, connection breaks)
+        // Remove user from UserManager and close their connection
         let target_session_id = user.session_id;
-        if let Some(removed_user) = ctx.user_manager.remove_user(target_session_id).await {
+        if let Some(removed_user) = ctx.user_manager.disconnect_user(target_session_id).await {
             // Broadcast disconnection to users with user_list permission
             ctx.user_manager
                 .broadcast_user_event(
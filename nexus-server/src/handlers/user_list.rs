@@ -4,14 +4,28 @@ use std::collections::HashMap;
 use std::io;
 
 /// Aggregated user data for deduplication
-/// Fields: (login_time, is_admin, session_ids, locale, avatar, avatar_login_time)
-type UserAggregateData = (i64, bool, Vec<u32>, String, Option<String>, i64);
+/// Fields: (login_time, is_admin, is_moderator, away, is_guest, permissions, session_ids, locale, avatar, avatar_login_time)
+type UserAggregateData = (
+    i64,
+    bool,
+    bool,
+    bool,
+    bool,
+    Option<Vec<String>>,
+    Vec<u32>,
+    String,
+    Option<String>,
+    i64,
+);
 
 use tokio::io::AsyncWrite;
 
 use nexus_common::protocol::{ServerMessage, UserInfo};
 
-use super::{HandlerContext, err_authentication, err_not_logged_in, err_permission_denied};
+use super::{
+    HandlerContext, err_authentication, err_command_cooldown, err_feature_disabled,
+    err_not_logged_in, err_permission_denied,
+};
 use crate::db::Permission;
 
 /// Handle a userlist request from the client
@@ -45,6 +59,16 @@ where
         }
     };
 
+    // Check whether UserList is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserList, requesting_user.is_admin)
+        .await
+    {
+        return ctx
+            .send_error(&err_feature_disabled(ctx.locale), Some("UserList"))
+            .await;
+    }
+
     // Check UserList permission (uses cached permissions, admin bypass built-in)
     if !requesting_user.has_permission(Permission::UserList) {
         eprintln!(
@@ -70,6 +94,23 @@ where
             .await;
     }
 
+    // Listing every account reads the whole user table, so it has a cooldown
+    if all
+        && let Some(remaining) = ctx
+            .command_cooldown(id, requesting_user.is_admin, "UserList")
+            .await
+    {
+        return ctx
+            .send_error(
+                &err_command_cooldown(ctx.locale, remaining),
+                Some("UserList"),
+            )
+            .await;
+    }
+
+    // Notable permissions are only shared with those who could look them up anyway
+    let show_permissions = requesting_user.has_permission(Permission::UserInfo);
+
     // Fetch all connected users
     let online_users = ctx.user_manager.get_all_users().await;
 
@@ -82,9 +123,11 @@ where
         user_map
             .entry(user.username.clone())
             .and_modify(
-                |(login_time, _, session_ids, _, avatar, avatar_login_time)| {
+                |(login_time, _, _, away, _, _, session_ids, _, avatar, avatar_login_time)| {
                     // Keep earliest login time for display
                     *login_time = (*login_time).min(user.login_time);
+                    // Away only while every session is away
+                    *away &= user.away;
                     session_ids.push(user.session_id);
                     // Avatar: latest login wins
                     if user.login_time > *avatar_login_time {
@@ -96,6 +139,10 @@ where
             .or_insert((
                 user.login_time,
                 user.is_admin, // Use is_admin from UserManager
+                user.is_moderator(),
+                user.away,
+                user.is_guest,
+                user.notable_permissions().filter(|_| show_permissions),
                 vec![user.session_id],
                 user.locale.clone(),
                 user.avatar.clone(),
@@ -112,6 +159,10 @@ where
                     user_map.entry(db_user.username.clone()).or_insert((
                         db_user.created_at, // Use created_at as login_time for offline users
                         db_user.is_admin,
+                        false,         // Moderator badges are for online users
+                        false,         // Away is for online users
+                        false,         // Guests only exist while online
+                        None,          // Permissions are only listed for online users
                         vec![],        // No session IDs (offline)
                         String::new(), // No locale (offline)
                         None,          // No avatar (offline)
@@ -130,19 +181,39 @@ where
     let mut user_infos: Vec<UserInfo> = user_map
         .into_iter()
         .map(
-            |(username, (login_time, is_admin, session_ids, locale, avatar, _))| UserInfo {
+            |(
                 username,
-                login_time,
-                is_admin,
-                session_ids,
-                locale,
-                avatar,
+                (
+                    login_time,
+                    is_admin,
+                    is_moderator,
+                    away,
+                    is_guest,
+                    permissions,
+                    session_ids,
+                    locale,
+                    avatar,
+                    _,
+                ),
+            )| {
+                UserInfo {
+                    username,
+                    login_time,
+                    is_admin,
+                    is_moderator,
+                    away,
+                    is_guest,
+                    permissions,
+                    session_ids,
+                    locale,
+                    avatar,
+                }
             },
         )
         .collect();
 
     // Sort by username (case-insensitive) for consistent ordering
-    user_infos.sort_by(|a, b| a.username.to_lowercase().cmp(&b.username.to_lowercase()));
+    user_infos.sort_by_key(|u| u.username.to_lowercase());
 
     // Send user list response
     let response = ServerMessage::UserListResponse {
@@ -293,6 +364,90 @@ mod tests {
     // Avatar aggregation tests
     // =========================================================================
 
+    #[tokio::test]
+    async fn test_userlist_flags_moderators() {
+        let mut test_ctx = create_test_context().await;
+        login_user(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &[db::Permission::ChatModerate],
+            false,
+        )
+        .await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result =
+            handle_user_list(false, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        use crate::handlers::testing::read_server_message;
+        let response = read_server_message(&mut test_ctx.client).await;
+        let ServerMessage::UserListResponse {
+            users: Some(users), ..
+        } = response
+        else {
+            panic!("Expected UserListResponse with users");
+        };
+        let flags: Vec<(&str, bool, bool)> = users
+            .iter()
+            .map(|u| (u.username.as_str(), u.is_admin, u.is_moderator))
+            .collect();
+        // Admins hold chat_moderate implicitly but are badged as admins only
+        assert_eq!(flags, [("admin", true, false), ("bob", false, true)]);
+    }
+
+    #[tokio::test]
+    async fn test_userlist_permissions_require_user_info() {
+        use crate::handlers::testing::read_server_message;
+
+        let mut test_ctx = create_test_context().await;
+        login_user(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &[db::Permission::ChatModerate, db::Permission::UserList],
+            false,
+        )
+        .await;
+        let plain_id = login_user(
+            &mut test_ctx,
+            "carol",
+            "password",
+            &[db::Permission::UserList],
+            false,
+        )
+        .await;
+        let info_id = login_user(
+            &mut test_ctx,
+            "dave",
+            "password",
+            &[db::Permission::UserList, db::Permission::UserInfo],
+            false,
+        )
+        .await;
+
+        let expectations = [
+            (plain_id, None),
+            (info_id, Some(vec!["chat_moderate".to_string()])),
+        ];
+        for (session_id, expected) in expectations {
+            let result =
+                handle_user_list(false, Some(session_id), &mut test_ctx.handler_context()).await;
+            assert!(result.is_ok());
+
+            let response = read_server_message(&mut test_ctx.client).await;
+            let ServerMessage::UserListResponse {
+                users: Some(users), ..
+            } = response
+            else {
+                panic!("Expected UserListResponse with users");
+            };
+            let bob = users.iter().find(|u| u.username == "bob").unwrap();
+            assert_eq!(bob.permissions, expected);
+        }
+    }
+
     #[tokio::test]
     async fn test_userlist_includes_avatar() {
         use crate::handlers::testing::read_server_message;
@@ -322,6 +477,7 @@ mod tests {
                 db_user_id: account.id,
                 username: "alice".to_string(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: account.created_at,
                 is_admin: false,
                 permissions: perms.permissions.clone(),
@@ -329,6 +485,8 @@ mod tests {
                 features: vec![],
                 locale: "en".to_string(),
                 avatar: Some(avatar_data.clone()),
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -382,6 +540,7 @@ mod tests {
                 db_user_id: account.id,
                 username: "alice".to_string(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: account.created_at,
                 is_admin: false,
                 permissions: perms.permissions.clone(),
@@ -389,6 +548,8 @@ mod tests {
                 features: vec![],
                 locale: "en".to_string(),
                 avatar: Some(old_avatar.clone()),
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -403,6 +564,7 @@ mod tests {
                 db_user_id: account.id,
                 username: "alice".to_string(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: account.created_at,
                 is_admin: false,
                 permissions: perms.permissions.clone(),
@@ -410,6 +572,8 @@ mod tests {
                 features: vec![],
                 locale: "en".to_string(),
                 avatar: Some(new_avatar.clone()),
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -460,6 +624,7 @@ mod tests {
                 db_user_id: account.id,
                 username: "alice".to_string(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: account.created_at,
                 is_admin: false,
                 permissions: perms.permissions.clone(),
@@ -467,6 +632,8 @@ mod tests {
                 features: vec![],
                 locale: "en".to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
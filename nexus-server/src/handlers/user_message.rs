@@ -1,19 +1,22 @@
-//! Handler for UserMessage command
+//! Handlers for UserMessage and EncryptedUserMessage commands
 
+use std::collections::HashSet;
 use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{E2eeEnvelope, ServerMessage};
 use nexus_common::validators::{self, MessageError, UsernameError};
 
 use super::{
     HandlerContext, err_authentication, err_cannot_message_self, err_chat_too_long, err_database,
-    err_message_contains_newlines, err_message_empty, err_message_invalid_characters,
-    err_not_logged_in, err_permission_denied, err_user_not_found, err_user_not_online,
-    err_username_empty, err_username_invalid, err_username_too_long,
+    err_e2ee_invalid_message, err_e2ee_keys_changed, err_feature_disabled, err_message_empty,
+    err_message_invalid_characters, err_not_logged_in, err_permission_denied,
+    err_server_maintenance, err_user_not_found, err_user_not_online, err_username_empty,
+    err_username_invalid, err_username_too_long,
 };
 use crate::db::Permission;
+use crate::users::user::UserSession;
 
 /// Handle UserMessage command
 pub async fn handle_user_message<W>(
@@ -22,19 +25,161 @@ pub async fn handle_user_message<W>(
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Validate message content against the server's configured limit
+    let max_length = ctx.db.config.get_max_message_length().await;
+    let content_error = validators::validate_message(&message, max_length)
+        .err()
+        .map(|e| match e {
+            MessageError::Empty => err_message_empty(ctx.locale),
+            MessageError::TooLong => err_chat_too_long(ctx.locale, max_length),
+            MessageError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
+        });
+
+    let Some((sender, to_username)) =
+        route_private_message(&to_username, content_error, session_id, "UserMessage", ctx).await?
+    else {
+        return Ok(());
+    };
+
+    // Send success response to sender
+    let response = ServerMessage::UserMessageResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    // Broadcast message to all sessions of both sender and receiver, with
+    // one id so every copy refers to the same message
+    let broadcast = ServerMessage::UserMessage {
+        from_username: sender.username.clone(),
+        from_admin: sender.is_admin,
+        to_username: to_username.clone(),
+        message,
+        message_id: Some(ctx.user_manager.next_message_id().await),
+    };
+
+    // Send to all sender sessions
+    ctx.user_manager
+        .broadcast_to_username(&sender.username, &broadcast, &ctx.db.users)
+        .await;
+
+    // Send to all receiver sessions
+    ctx.user_manager
+        .broadcast_to_username(&to_username, &broadcast, &ctx.db.users)
+        .await;
+
+    Ok(())
+}
+
+/// Handle EncryptedUserMessage command
+///
+/// Goes through the same checks as a plain private message. The server can't
+/// read the envelopes, so it only checks there is exactly one for every
+/// session of the sender and recipient, then hands each session its own.
+/// If the sessions changed since the sender last heard their keys, the
+/// message is refused and the sender already has the new keys on the way.
+pub async fn handle_encrypted_user_message<W>(
+    to_username: String,
+    envelopes: Vec<E2eeEnvelope>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let content_error = validators::validate_e2ee_envelopes(&envelopes)
+        .err()
+        .map(|_| err_e2ee_invalid_message(ctx.locale));
+
+    let Some((sender, to_username)) = route_private_message(
+        &to_username,
+        content_error,
+        session_id,
+        "EncryptedUserMessage",
+        ctx,
+    )
+    .await?
+    else {
+        return Ok(());
+    };
+
+    // Every session of both accounts needs a key and an envelope
+    let sender_keys = ctx.user_manager.e2ee_keys(&sender.username).await;
+    let recipient_keys = ctx.user_manager.e2ee_keys(&to_username).await;
+    let expected: HashSet<u32> = sender_keys
+        .iter()
+        .chain(&recipient_keys)
+        .map(|key| key.session_id)
+        .collect();
+    let received: HashSet<u32> = envelopes.iter().map(|e| e.session_id).collect();
+    if sender_keys.is_empty() || recipient_keys.is_empty() || expected != received {
+        let response = ServerMessage::UserMessageResponse {
+            success: false,
+            error: Some(err_e2ee_keys_changed(ctx.locale, &to_username)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let response = ServerMessage::UserMessageResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    // One id for every copy, drawn from the same sequence as plain messages
+    let message_id = Some(ctx.user_manager.next_message_id().await);
+    let messages = envelopes
+        .into_iter()
+        .map(|envelope| {
+            (
+                envelope.session_id,
+                ServerMessage::EncryptedUserMessage {
+                    from_username: sender.username.clone(),
+                    from_admin: sender.is_admin,
+                    to_username: to_username.clone(),
+                    envelope,
+                    message_id,
+                },
+            )
+        })
+        .collect();
+    ctx.user_manager
+        .send_to_sessions(messages, &ctx.db.users)
+        .await;
+
+    Ok(())
+}
+
+/// Check that the session may send a private message to `to_username`
+///
+/// `content_error` is the caller's verdict on the message body, reported
+/// after the recipient's name is checked. Returns the sender's session and
+/// the recipient's username as stored, or `None` once a failure response has
+/// been sent.
+async fn route_private_message<W>(
+    to_username: &str,
+    content_error: Option<String>,
+    session_id: Option<u32>,
+    command: &str,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<Option<(UserSession, String)>>
 where
     W: AsyncWrite + Unpin,
 {
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(session_id) = session_id else {
-        eprintln!("UserMessage request from {} without login", ctx.peer_addr);
+        eprintln!("{} request from {} without login", command, ctx.peer_addr);
         return ctx
-            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserMessage"))
-            .await;
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some(command))
+            .await
+            .map(|()| None);
     };
 
     // Validate to_username format
-    if let Err(e) = validators::validate_username(&to_username) {
+    if let Err(e) = validators::validate_username(to_username) {
         let error_msg = match e {
             UsernameError::Empty => err_username_empty(ctx.locale),
             UsernameError::TooLong => {
@@ -42,26 +187,11 @@ where
             }
             UsernameError::InvalidCharacters => err_username_invalid(ctx.locale),
         };
-        let response = ServerMessage::UserMessageResponse {
-            success: false,
-            error: Some(error_msg),
-        };
-        return ctx.send_message(&response).await;
+        return refuse_private_message(error_msg, ctx).await;
     }
 
-    // Validate message content
-    if let Err(e) = validators::validate_message(&message) {
-        let error_msg = match e {
-            MessageError::Empty => err_message_empty(ctx.locale),
-            MessageError::TooLong => err_chat_too_long(ctx.locale, validators::MAX_MESSAGE_LENGTH),
-            MessageError::ContainsNewlines => err_message_contains_newlines(ctx.locale),
-            MessageError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
-        };
-        let response = ServerMessage::UserMessageResponse {
-            success: false,
-            error: Some(error_msg),
-        };
-        return ctx.send_message(&response).await;
+    if let Some(error_msg) = content_error {
+        return refuse_private_message(error_msg, ctx).await;
     }
 
     // Get requesting user from session
@@ -69,49 +199,54 @@ where
         Some(user) => user,
         None => {
             return ctx
-                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("UserMessage"))
-                .await;
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some(command))
+                .await
+                .map(|()| None);
         }
     };
 
     // Prevent self-messaging (cheap check before DB queries)
-    let to_username_lower = to_username.to_lowercase();
-    if to_username_lower == requesting_user_session.username.to_lowercase() {
-        let response = ServerMessage::UserMessageResponse {
-            success: false,
-            error: Some(err_cannot_message_self(ctx.locale)),
-        };
-        return ctx.send_message(&response).await;
+    if to_username.to_lowercase() == requesting_user_session.username.to_lowercase() {
+        return refuse_private_message(err_cannot_message_self(ctx.locale), ctx).await;
+    }
+
+    // Check whether UserMessage is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserMessage, requesting_user_session.is_admin)
+        .await
+    {
+        return refuse_private_message(err_feature_disabled(ctx.locale), ctx).await;
     }
 
     // Check UserMessage permission (uses cached permissions, admin bypass built-in)
     if !requesting_user_session.has_permission(Permission::UserMessage) {
         eprintln!(
-            "UserMessage from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user_session.username
+            "{} from {} (user: {}) without permission",
+            command, ctx.peer_addr, requesting_user_session.username
         );
-        let response = ServerMessage::UserMessageResponse {
-            success: false,
-            error: Some(err_permission_denied(ctx.locale)),
-        };
-        return ctx.send_message(&response).await;
+        return refuse_private_message(err_permission_denied(ctx.locale), ctx).await;
+    }
+
+    // Maintenance mode keeps non-admins read-only
+    if ctx
+        .maintenance_blocks(requesting_user_session.is_admin)
+        .await
+    {
+        return refuse_private_message(err_server_maintenance(ctx.locale), ctx).await;
     }
 
     // Look up target user in database
-    let target_user_db = match ctx.db.users.get_user_by_username(&to_username).await {
+    let target_user_db = match ctx.db.users.get_user_by_username(to_username).await {
         Ok(Some(user)) => user,
         Ok(None) => {
-            let response = ServerMessage::UserMessageResponse {
-                success: false,
-                error: Some(err_user_not_found(ctx.locale, &to_username)),
-            };
-            return ctx.send_message(&response).await;
+            return refuse_private_message(err_user_not_found(ctx.locale, to_username), ctx).await;
         }
         Err(e) => {
             eprintln!("Database error getting target user: {}", e);
             return ctx
-                .send_error_and_disconnect(&err_database(ctx.locale), Some("UserMessage"))
-                .await;
+                .send_error_and_disconnect(&err_database(ctx.locale), Some(command))
+                .await
+                .map(|()| None);
         }
     };
 
@@ -122,46 +257,36 @@ where
         .await;
 
     if target_sessions.is_empty() {
-        let response = ServerMessage::UserMessageResponse {
-            success: false,
-            error: Some(err_user_not_online(ctx.locale, &to_username)),
-        };
-        return ctx.send_message(&response).await;
+        return refuse_private_message(err_user_not_online(ctx.locale, to_username), ctx).await;
     }
 
-    // Send success response to sender
-    let response = ServerMessage::UserMessageResponse {
-        success: true,
-        error: None,
-    };
-    ctx.send_message(&response).await?;
+    Ok(Some((requesting_user_session, target_user_db.username)))
+}
 
-    // Broadcast message to all sessions of both sender and receiver
-    let broadcast = ServerMessage::UserMessage {
-        from_username: requesting_user_session.username.clone(),
-        from_admin: requesting_user_session.is_admin,
-        to_username: target_user_db.username.clone(),
-        message,
+/// Send a failed `UserMessageResponse`
+async fn refuse_private_message<W>(
+    error: String,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<Option<(UserSession, String)>>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::UserMessageResponse {
+        success: false,
+        error: Some(error),
     };
-
-    // Send to all sender sessions
-    ctx.user_manager
-        .broadcast_to_username(&requesting_user_session.username, &broadcast, &ctx.db.users)
-        .await;
-
-    // Send to all receiver sessions
-    ctx.user_manager
-        .broadcast_to_username(&target_user_db.username, &broadcast, &ctx.db.users)
-        .await;
-
-    Ok(())
+    ctx.send_message(&response).await.map(|()| None)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::Permission;
-    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+    use crate::db::{DisabledPermissions, Permission};
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_guest, login_user,
+        read_server_message,
+    };
+    use crate::users::user::NewSessionParams;
 
     #[tokio::test]
     async fn test_usermessage_requires_login() {
@@ -218,6 +343,96 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_usermessage_rejected_when_disabled() {
+        let mut test_ctx = create_test_context().await;
+        let disabled: DisabledPermissions = "user_message".parse().unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions(&disabled)
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions_admin_exempt(true)
+            .await
+            .unwrap();
+
+        let _sender_id = login_user(
+            &mut test_ctx,
+            "sender",
+            "pass123",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let _target_id = login_user(
+            &mut test_ctx,
+            "target",
+            "pass456",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+
+        // Disabling the permission overrides the grant
+        let result = handle_user_message(
+            "target".to_string(),
+            "hello".to_string(),
+            Some(1),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserMessageResponse { success, error } => {
+                assert!(!success);
+                assert!(error.unwrap().contains("disabled"));
+            }
+            _ => panic!("Expected UserMessageResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_usermessage_admin_exempt_when_disabled() {
+        let mut test_ctx = create_test_context().await;
+        let disabled: DisabledPermissions = "user_message".parse().unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions(&disabled)
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions_admin_exempt(true)
+            .await
+            .unwrap();
+
+        let _admin_id = login_user(&mut test_ctx, "admin", "pass123", &[], true).await;
+        let _target_id = login_user(&mut test_ctx, "target", "pass456", &[], false).await;
+
+        let result = handle_user_message(
+            "target".to_string(),
+            "hello".to_string(),
+            Some(1),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserMessageResponse { success, error } => {
+                assert!(success, "admin should be exempt: {:?}", error);
+            }
+            _ => panic!("Expected UserMessageResponse"),
+        }
+    }
+
     #[tokio::test]
     async fn test_usermessage_empty_message() {
         let mut test_ctx = create_test_context().await;
@@ -441,6 +656,57 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_usermessage_assigns_increasing_message_ids() {
+        let mut test_ctx = create_test_context().await;
+
+        login_user(
+            &mut test_ctx,
+            "sender",
+            "pass123",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        login_user(
+            &mut test_ctx,
+            "target",
+            "pass456",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+
+        for message in ["first", "second"] {
+            handle_user_message(
+                "target".to_string(),
+                message.to_string(),
+                Some(1),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+        }
+
+        // Sender and receiver copies of a message share its id
+        let mut ids = Vec::new();
+        while let Ok((msg, _)) = test_ctx._rx.messages.try_recv() {
+            if let ServerMessage::UserMessage {
+                message,
+                message_id,
+                ..
+            } = msg
+            {
+                ids.push((message, message_id.expect("message id assigned")));
+            }
+        }
+        assert_eq!(ids.len(), 4);
+        assert_eq!(ids[0], ids[1]);
+        assert_eq!(ids[2], ids[3]);
+        assert_eq!(ids[0].0, "first");
+        assert!(ids[2].1 > ids[0].1);
+    }
+
     #[tokio::test]
     async fn test_usermessage_admin_has_permission() {
         let mut test_ctx = create_test_context().await;
@@ -479,4 +745,184 @@ mod tests {
             _ => panic!("Expected UserMessageResponse, got: {:?}", response),
         }
     }
+
+    fn envelope(session_id: u32) -> E2eeEnvelope {
+        E2eeEnvelope {
+            session_id,
+            ephemeral_key: format!("{}=", "E".repeat(43)),
+            ciphertext: "c2VhbGVk".to_string(),
+        }
+    }
+
+    /// Log in a sender and recipient who have both announced keys
+    async fn login_e2ee_pair(test_ctx: &mut TestContext) -> (u32, u32) {
+        let sender_id = login_user(
+            test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let recipient_id = login_user(test_ctx, "bob", "password", &[], false).await;
+        for id in [sender_id, recipient_id] {
+            test_ctx
+                .user_manager
+                .set_e2ee_key(id, format!("{}=", "K".repeat(43)))
+                .await;
+        }
+        (sender_id, recipient_id)
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_message_delivers_each_envelope() {
+        let mut test_ctx = create_test_context().await;
+        let (sender_id, recipient_id) = login_e2ee_pair(&mut test_ctx).await;
+
+        let result = handle_encrypted_user_message(
+            "BOB".to_string(),
+            vec![envelope(sender_id), envelope(recipient_id)],
+            Some(sender_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserMessageResponse { success, error } => {
+                assert!(success, "unexpected error: {:?}", error);
+            }
+            other => panic!("Expected UserMessageResponse, got: {:?}", other),
+        }
+
+        // Each session gets only its own envelope, under one message id
+        let mut delivered = Vec::new();
+        while let Ok((msg, _)) = test_ctx._rx.messages.try_recv() {
+            if let ServerMessage::EncryptedUserMessage {
+                from_username,
+                to_username,
+                envelope,
+                message_id,
+                ..
+            } = msg
+            {
+                assert_eq!(from_username, "alice");
+                assert_eq!(to_username, "bob");
+                delivered.push((envelope.session_id, message_id.unwrap()));
+            }
+        }
+        delivered.sort();
+        assert_eq!(delivered.len(), 2);
+        assert_eq!(delivered[0].0, sender_id);
+        assert_eq!(delivered[1].0, recipient_id);
+        assert_eq!(delivered[0].1, delivered[1].1);
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_message_rejects_stale_keys() {
+        let mut test_ctx = create_test_context().await;
+        let (sender_id, recipient_id) = login_e2ee_pair(&mut test_ctx).await;
+
+        // Bob logs in again; his new session has no envelope
+        let bob = test_ctx
+            .db
+            .users
+            .get_user_by_username("bob")
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: bob.id,
+                username: bob.username,
+                is_admin: false,
+                permissions: Default::default(),
+                address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
+                created_at: bob.created_at,
+                tx: test_ctx.tx.clone(),
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
+            })
+            .await;
+
+        let result = handle_encrypted_user_message(
+            "bob".to_string(),
+            vec![envelope(sender_id), envelope(recipient_id)],
+            Some(sender_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserMessageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_e2ee_keys_changed(DEFAULT_TEST_LOCALE, "bob"))
+                );
+            }
+            other => panic!("Expected UserMessageResponse, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_message_rejects_invalid_envelopes() {
+        let mut test_ctx = create_test_context().await;
+        let (sender_id, _) = login_e2ee_pair(&mut test_ctx).await;
+
+        let result = handle_encrypted_user_message(
+            "bob".to_string(),
+            vec![],
+            Some(sender_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserMessageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_e2ee_invalid_message(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected UserMessageResponse, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_usermessage_refused_for_guests() {
+        let mut test_ctx = create_test_context().await;
+        login_user(
+            &mut test_ctx,
+            "target",
+            "pass456",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let guest_id = login_guest(&mut test_ctx, "guest-alice").await;
+
+        let result = handle_user_message(
+            "target".to_string(),
+            "hello".to_string(),
+            Some(guest_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserMessageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected UserMessageResponse, got {:?}", other),
+        }
+    }
 }
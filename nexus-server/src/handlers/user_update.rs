@@ -4,21 +4,26 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::{ChatInfo, ServerInfo, ServerMessage, UserInfo};
+use nexus_common::protocol::{
+    ChatInfo, ERROR_CODE_ACCOUNT_DISABLED, ServerInfo, ServerMessage, UserInfo,
+};
 use nexus_common::validators::{self, PasswordError, PermissionsError, UsernameError};
 
+use super::login::is_guest_username;
 #[cfg(test)]
 use super::testing::DEFAULT_TEST_LOCALE;
 use super::{
     HandlerContext, err_account_disabled_by_admin, err_authentication,
     err_cannot_demote_last_admin, err_cannot_disable_last_admin, err_cannot_edit_self,
-    err_database, err_not_logged_in, err_password_empty, err_password_too_long,
-    err_permission_denied, err_permissions_contains_newlines, err_permissions_empty_permission,
+    err_database, err_feature_disabled, err_guest_name_reserved, err_not_logged_in,
+    err_password_empty, err_password_too_long, err_permission_denied,
+    err_permissions_contains_newlines, err_permissions_empty_permission,
     err_permissions_invalid_characters, err_permissions_permission_too_long,
-    err_permissions_too_many, err_update_failed, err_user_not_found, err_username_empty,
-    err_username_exists, err_username_invalid, err_username_too_long,
+    err_permissions_too_many, err_server_maintenance, err_update_failed, err_user_not_found,
+    err_username_empty, err_username_exists, err_username_invalid, err_username_too_long,
 };
-use crate::db::{Permission, Permissions, hash_password};
+use crate::db::users::UserAccount;
+use crate::db::{Permission, Permissions, TOPIC_VIEW_PERMISSIONS, hash_password};
 
 /// User update request parameters
 pub struct UserUpdateRequest {
@@ -59,6 +64,7 @@ where
         let response = ServerMessage::UserUpdateResponse {
             success: false,
             error: Some(error_msg),
+            warning: None,
         };
         return ctx.send_message(&response).await;
     }
@@ -82,10 +88,21 @@ where
         let response = ServerMessage::UserUpdateResponse {
             success: false,
             error: Some(err_cannot_edit_self(ctx.locale)),
+            warning: None,
         };
         return ctx.send_message(&response).await;
     }
 
+    // Check whether UserEdit is disabled server-wide (admins too, unless exempt)
+    if ctx
+        .permission_disabled(Permission::UserEdit, requesting_user.is_admin)
+        .await
+    {
+        return ctx
+            .send_error(&err_feature_disabled(ctx.locale), Some("UserUpdate"))
+            .await;
+    }
+
     // Check UserEdit permission (uses cached permissions, admin bypass built-in)
     if !requesting_user.has_permission(Permission::UserEdit) {
         eprintln!(
@@ -97,6 +114,16 @@ where
             .await;
     }
 
+    // Maintenance mode keeps non-admins read-only
+    if ctx.maintenance_blocks(requesting_user.is_admin).await {
+        let response = ServerMessage::UserUpdateResponse {
+            success: false,
+            error: Some(err_server_maintenance(ctx.locale)),
+            warning: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Validate new username format if it's being changed
     if let Some(ref new_username) = request.requested_username
         && let Err(e) = validators::validate_username(new_username)
@@ -111,6 +138,22 @@ where
         let response = ServerMessage::UserUpdateResponse {
             success: false,
             error: Some(error_msg),
+            warning: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Guest names belong to guests while guest login is on (an account that
+    // already has one may keep it)
+    if let Some(ref new_username) = request.requested_username
+        && !new_username.eq_ignore_ascii_case(&request.username)
+        && is_guest_username(new_username)
+        && ctx.db.config.get_guest_login().await.enabled
+    {
+        let response = ServerMessage::UserUpdateResponse {
+            success: false,
+            error: Some(err_guest_name_reserved(ctx.locale)),
+            warning: None,
         };
         return ctx.send_message(&response).await;
     }
@@ -126,6 +169,7 @@ where
     }
 
     // Validate and parse requested permissions
+    let mut warning = None;
     let parsed_permissions = if let Some(ref perm_strings) = request.requested_permissions {
         // Validate permissions format first
         if let Err(e) = validators::validate_permissions(perm_strings) {
@@ -146,6 +190,7 @@ where
             let response = ServerMessage::UserUpdateResponse {
                 success: false,
                 error: Some(error_msg),
+                warning: None,
             };
             return ctx.send_message(&response).await;
         }
@@ -203,6 +248,13 @@ where
             }
         }
 
+        // Admins have every permission, so prerequisites only matter for others
+        if request.requested_is_admin != Some(true) {
+            warning = ctx
+                .permission_prerequisites_warning(&mut perms, &requesting_user)
+                .await;
+        }
+
         Some(perms)
     } else {
         None
@@ -225,6 +277,7 @@ where
                 let response = ServerMessage::UserUpdateResponse {
                     success: false,
                     error: Some(error_msg),
+                    warning: None,
                 };
                 return ctx.send_message(&response).await;
             }
@@ -244,18 +297,22 @@ where
 
     // Note: Username validation is already done earlier, so no need to check for empty here
 
-    // Get old username and admin status before update (to detect changes)
+    // Get old username, admin status and notable permissions before update (to detect changes)
     // Check if target user is online to use cached data, otherwise fall back to DB
     let old_account = if let Some(online_user) = ctx
         .user_manager
         .get_session_by_username(&request.username)
         .await
     {
-        Some((online_user.username.clone(), online_user.is_admin))
+        Some((
+            online_user.username.clone(),
+            online_user.is_admin,
+            online_user.notable_permissions(),
+        ))
     } else {
-        // User is offline - must check DB
+        // User is offline - must check DB (offline users never list permissions)
         match ctx.db.users.get_user_by_username(&request.username).await {
-            Ok(Some(acc)) => Some((acc.username.clone(), acc.is_admin)),
+            Ok(Some(acc)) => Some((acc.username.clone(), acc.is_admin, None)),
             _ => None,
         }
     };
@@ -275,10 +332,32 @@ where
         .await
     {
         Ok(true) => {
+            // The account and its permissions were written together; online
+            // sessions pick up the stored permissions now
+            if parsed_permissions.is_some() {
+                let final_username = request
+                    .requested_username
+                    .as_ref()
+                    .unwrap_or(&request.username);
+                let reloaded = match ctx.db.users.get_user_by_username(final_username).await {
+                    Ok(Some(account)) => ctx
+                        .user_manager
+                        .reload_permissions(&ctx.db.users, account.id)
+                        .await
+                        .map(|_| ()),
+                    Ok(None) => Ok(()),
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = reloaded {
+                    eprintln!("Database error reading permissions: {}", e);
+                }
+            }
+
             // Success - send response to requester
             let response = ServerMessage::UserUpdateResponse {
                 success: true,
                 error: None,
+                warning,
             };
             ctx.send_message(&response).await?;
 
@@ -292,90 +371,17 @@ where
             if let Ok(Some(updated_account)) =
                 ctx.db.users.get_user_by_username(final_username).await
             {
-                // Get the final permissions
-                if let Ok(final_permissions) =
-                    ctx.db.users.get_user_permissions(updated_account.id).await
-                {
-                    // Update cached permissions in UserManager for all sessions of this user
-                    ctx.user_manager
-                        .update_permissions(
-                            updated_account.id,
-                            final_permissions.permissions.clone(),
-                        )
-                        .await;
-
-                    let permission_strings: Vec<String> = final_permissions
-                        .permissions
-                        .iter()
-                        .map(|p| p.as_str().to_string())
-                        .collect();
-
-                    // Check if user now has chat topic permission
-                    let now_has_chat_topic = updated_account.is_admin
-                        || final_permissions
-                            .permissions
-                            .contains(&Permission::ChatTopic);
-
-                    // Only send max_connections_per_ip if user is now admin
-                    // (other server info fields like name/description/image don't change with permissions)
-                    let server_info = if updated_account.is_admin {
-                        Some(ServerInfo {
-                            max_connections_per_ip: Some(
-                                ctx.db.config.get_max_connections_per_ip().await as u32,
-                            ),
-                            ..Default::default()
-                        })
-                    } else {
-                        None
-                    };
-
-                    // Include chat info only if user has permission
-                    let chat_info = if now_has_chat_topic {
-                        match ctx.db.chat.get_topic().await {
-                            Ok(topic) => Some(ChatInfo {
-                                topic: topic.topic,
-                                topic_set_by: topic.set_by,
-                            }),
-                            Err(_) => None,
-                        }
-                    } else {
-                        None
-                    };
-
-                    let permissions_update = ServerMessage::PermissionsUpdated {
-                        is_admin: updated_account.is_admin,
-                        permissions: permission_strings,
-                        server_info,
-                        chat_info,
-                    };
-
-                    // Send to all sessions belonging to the updated user
-                    ctx.user_manager
-                        .broadcast_to_username(
-                            &updated_account.username,
-                            &permissions_update,
-                            &ctx.db.users,
-                        )
-                        .await;
+                // Push the final permissions to the user's online sessions
+                if let Err(e) = push_permissions_update(&updated_account, ctx).await {
+                    eprintln!("Database error reading permissions: {}", e);
                 }
 
                 // If user was disabled, disconnect all their active sessions
                 //
-                // Clean Disconnect Flow:
-                // 1. Send Error message to user ("Account disabled by admin")
-                // 2. Remove user from UserManager (drops the tx sender)
-                // 3. Connection handler's rx.recv() returns None (channel closed)
-                // 4. Connection loop breaks cleanly
-                // 5. TCP connection closes
-                //
-                // This approach avoids manual shutdown signals and relies on channel semantics:
-                // - User struct contains a tx (clone of the channel sender)
-                // - UserManager.remove_user() drops the User, which drops tx
-                // - When all senders are dropped, rx.recv() returns None
-                // - Connection handler detects None and breaks the loop
-                //
-                // Note: UserDisconnected is only broadcast once here (connection.rs cleanup
-                // doesn't re-broadcast because the user is already removed from manager)
+                // Each session is sent the reason, then removed and told to close;
+                // the connection sends what is queued before it disconnects. The
+                // connection's own cleanup finds the session already removed, so
+                // UserDisconnected is only broadcast once, here.
                 if let Some(false) = request.requested_enabled {
                     // Get all session IDs for this user
                     let session_ids = ctx
@@ -392,13 +398,15 @@ where
                             let disconnect_msg = ServerMessage::Error {
                                 message: err_account_disabled_by_admin(&user.locale),
                                 command: None,
+                                code: Some(ERROR_CODE_ACCOUNT_DISABLED.to_string()),
                             };
                             let _ = user.tx.send((disconnect_msg, None));
                         }
 
-                        // Remove user from manager - this drops tx, causing rx.recv() to return None,
-                        // which breaks the connection loop and triggers cleanup
-                        if let Some(removed_user) = ctx.user_manager.remove_user(session_id).await {
+                        // Remove the session and close its connection
+                        if let Some(removed_user) =
+                            ctx.user_manager.disconnect_user(session_id).await
+                        {
                             // Broadcast disconnect to users with user_list permission
                             ctx.user_manager
                                 .broadcast_user_event(
@@ -417,11 +425,11 @@ where
                 // Check if username or admin status changed
                 let username_changed = old_account
                     .as_ref()
-                    .map(|(old_name, _)| old_name != &updated_account.username)
+                    .map(|(old_name, _, _)| old_name != &updated_account.username)
                     .unwrap_or(false);
                 let admin_status_changed = old_account
                     .as_ref()
-                    .map(|(_, old_admin)| *old_admin != updated_account.is_admin)
+                    .map(|(_, old_admin, _)| *old_admin != updated_account.is_admin)
                     .unwrap_or(false);
 
                 // If username changed, update UserManager
@@ -438,62 +446,27 @@ where
                         .await;
                 }
 
-                // Only broadcast UserUpdated if username or admin status changed
-                if username_changed || admin_status_changed {
-                    let session_ids = ctx
-                        .user_manager
-                        .get_session_ids_for_user(&updated_account.username)
-                        .await;
+                // Sessions now hold the final admin status and permissions
+                let sessions = ctx
+                    .user_manager
+                    .get_sessions_by_db_user_id(updated_account.id)
+                    .await;
+                let permissions = sessions
+                    .first()
+                    .and_then(|session| session.notable_permissions());
+                let permissions_changed = old_account
+                    .as_ref()
+                    .map(|(_, _, old_permissions)| *old_permissions != permissions)
+                    .unwrap_or(false);
 
-                    // Get earliest login time, locale, and avatar from all sessions
-                    // Avatar uses "latest login wins"
-                    let (login_time, locale, avatar) = if !session_ids.is_empty() {
-                        let user_sessions = ctx
-                            .user_manager
-                            .get_sessions_by_username(&updated_account.username)
-                            .await;
-
-                        let login_time = user_sessions
-                            .iter()
-                            .map(|u| u.login_time)
-                            .min()
-                            .unwrap_or(0);
-
-                        let locale = user_sessions
-                            .first()
-                            .map(|u| u.locale.clone())
-                            .unwrap_or_else(|| "en".to_string());
-
-                        // Avatar from most recent login
-                        let avatar = user_sessions
-                            .iter()
-                            .max_by_key(|u| u.login_time)
-                            .and_then(|u| u.avatar.clone());
-
-                        (login_time, locale, avatar)
-                    } else {
-                        (0, "en".to_string(), None) // User not currently online
-                    };
-
-                    let user_info = UserInfo {
-                        username: updated_account.username.clone(),
-                        login_time,
-                        is_admin: updated_account.is_admin,
-                        session_ids,
-                        locale,
-                        avatar,
-                    };
-
-                    let user_updated = ServerMessage::UserUpdated {
-                        previous_username: old_account
-                            .as_ref()
-                            .map(|(name, _)| name.clone())
-                            .unwrap_or(updated_account.username.clone()),
-                        user: user_info,
-                    };
-                    ctx.user_manager
-                        .broadcast_to_permission(user_updated, &ctx.db.users, Permission::UserList)
-                        .await;
+                // Only broadcast UserUpdated if username, admin status or notable
+                // permissions (which include moderator status) changed
+                if username_changed || admin_status_changed || permissions_changed {
+                    let previous_username = old_account
+                        .as_ref()
+                        .map(|(name, _, _)| name.clone())
+                        .unwrap_or(updated_account.username.clone());
+                    broadcast_user_updated(&updated_account, previous_username, ctx).await;
                 }
             }
 
@@ -540,6 +513,7 @@ where
             let response = ServerMessage::UserUpdateResponse {
                 success: false,
                 error: Some(error_message.to_string()),
+                warning: None,
             };
             ctx.send_message(&response).await
         }
@@ -552,6 +526,148 @@ where
     }
 }
 
+/// Re-read an account's permissions and push them to its online sessions
+///
+/// Updates the cached permissions in every `UserSession` for the account and
+/// sends each one `PermissionsUpdated`. Offline accounts are a no-op beyond the
+/// database read.
+pub(super) async fn push_permissions_update<W>(
+    account: &UserAccount,
+    ctx: &HandlerContext<'_, W>,
+) -> Result<(), sqlx::Error>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Update cached permissions in UserManager for all sessions of this user
+    let final_permissions = ctx
+        .user_manager
+        .reload_permissions(&ctx.db.users, account.id)
+        .await?;
+
+    let permission_strings: Vec<String> = final_permissions
+        .permissions
+        .iter()
+        .map(|p| p.as_str().to_string())
+        .collect();
+
+    // Check if user can now see the chat topic
+    let now_has_chat_topic = account.is_admin
+        || TOPIC_VIEW_PERMISSIONS
+            .iter()
+            .any(|p| final_permissions.permissions.contains(p));
+
+    // Only send max_connections_per_ip if user is now admin
+    // (other server info fields like name/description/image don't change with permissions)
+    let server_info = if account.is_admin {
+        Some(ServerInfo {
+            max_connections_per_ip: Some(ctx.db.config.get_max_connections_per_ip().await as u32),
+            ..Default::default()
+        })
+    } else {
+        None
+    };
+
+    // Include chat info only if user has permission
+    let chat_info = if now_has_chat_topic {
+        match ctx.db.chat.get_topic().await {
+            Ok(topic) => Some(ChatInfo {
+                topic: topic.topic,
+                topic_set_by: topic.set_by,
+            }),
+            Err(_) => None,
+        }
+    } else {
+        None
+    };
+
+    let permissions_update = ServerMessage::PermissionsUpdated {
+        is_admin: account.is_admin,
+        permissions: permission_strings,
+        server_info,
+        chat_info,
+    };
+
+    // Send to all sessions belonging to the user
+    ctx.user_manager
+        .broadcast_to_username(&account.username, &permissions_update, &ctx.db.users)
+        .await;
+
+    Ok(())
+}
+
+/// Broadcast `UserUpdated` for an account to everyone who can see the user list
+///
+/// Online fields (sessions, login time, locale, avatar, moderator status and
+/// notable permissions) come from the account's current sessions, so call this
+/// after `UserManager` holds the final admin status and permissions.
+pub(super) async fn broadcast_user_updated<W>(
+    account: &UserAccount,
+    previous_username: String,
+    ctx: &HandlerContext<'_, W>,
+) where
+    W: AsyncWrite + Unpin,
+{
+    let user_sessions = ctx
+        .user_manager
+        .get_sessions_by_db_user_id(account.id)
+        .await;
+    let is_moderator = user_sessions.iter().any(|session| session.is_moderator());
+    let away = !user_sessions.is_empty() && user_sessions.iter().all(|session| session.away);
+    let permissions = user_sessions
+        .first()
+        .and_then(|session| session.notable_permissions());
+    let session_ids = ctx
+        .user_manager
+        .get_session_ids_for_user(&account.username)
+        .await;
+
+    // Get earliest login time, locale, and avatar from all sessions
+    // Avatar uses "latest login wins"
+    let (login_time, locale, avatar) = if !user_sessions.is_empty() {
+        let login_time = user_sessions
+            .iter()
+            .map(|u| u.login_time)
+            .min()
+            .unwrap_or(0);
+
+        let locale = user_sessions
+            .first()
+            .map(|u| u.locale.clone())
+            .unwrap_or_else(|| "en".to_string());
+
+        // Avatar from most recent login
+        let avatar = user_sessions
+            .iter()
+            .max_by_key(|u| u.login_time)
+            .and_then(|u| u.avatar.clone());
+
+        (login_time, locale, avatar)
+    } else {
+        (0, "en".to_string(), None) // User not currently online
+    };
+
+    let user_info = UserInfo {
+        username: account.username.clone(),
+        login_time,
+        is_admin: account.is_admin,
+        is_moderator,
+        away,
+        is_guest: false, // Only accounts can be updated
+        permissions,
+        session_ids,
+        locale,
+        avatar,
+    };
+
+    let user_updated = ServerMessage::UserUpdated {
+        previous_username,
+        user: user_info,
+    };
+    ctx.user_manager
+        .broadcast_user_event(user_updated, &ctx.db.users, None)
+        .await;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -633,7 +749,7 @@ mod tests {
         assert!(result.is_ok());
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::UserUpdateResponse { success, error } => {
+            ServerMessage::UserUpdateResponse { success, error, .. } => {
                 assert!(!success);
                 assert_eq!(error.unwrap(), err_cannot_edit_self(DEFAULT_TEST_LOCALE));
             }
@@ -670,7 +786,7 @@ mod tests {
         assert!(result.is_ok());
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::UserUpdateResponse { success, error } => {
+            ServerMessage::UserUpdateResponse { success, error, .. } => {
                 assert!(success);
                 assert!(error.is_none());
             }
@@ -689,6 +805,48 @@ mod tests {
         assert!(user.is_none());
     }
 
+    #[tokio::test]
+    async fn test_userupdate_rejects_guest_names_while_guests_allowed() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        test_ctx
+            .db
+            .users
+            .create_user("bob", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+        test_ctx.db.config.set_guest_login(true).await.unwrap();
+
+        let request = UserUpdateRequest {
+            username: "bob".to_string(),
+            requested_username: Some("Guest-bob".to_string()),
+            requested_password: None,
+            requested_is_admin: None,
+            requested_enabled: None,
+            requested_permissions: None,
+            session_id: Some(session_id),
+        };
+        let result = handle_user_update(request, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserUpdateResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_guest_name_reserved(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected UserUpdateResponse, got {:?}", other),
+        }
+        assert!(
+            test_ctx
+                .db
+                .users
+                .get_user_by_username("bob")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
     #[tokio::test]
     async fn test_userupdate_user_not_found() {
         let mut test_ctx = create_test_context().await;
@@ -710,7 +868,7 @@ mod tests {
         assert!(result.is_ok());
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::UserUpdateResponse { success, error } => {
+            ServerMessage::UserUpdateResponse { success, error, .. } => {
                 assert!(!success);
                 assert_eq!(
                     error.unwrap(),
@@ -895,7 +1053,7 @@ mod tests {
         assert!(result.is_ok());
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::UserUpdateResponse { success, error } => {
+            ServerMessage::UserUpdateResponse { success, error, .. } => {
                 assert!(!success);
                 assert!(error.is_some());
             }
@@ -1005,6 +1163,39 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_userupdate_granting_chat_moderate_broadcasts_moderator() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        let request = UserUpdateRequest {
+            username: "bob".to_string(),
+            requested_username: None,
+            requested_password: None,
+            requested_is_admin: None,
+            requested_enabled: None,
+            requested_permissions: Some(vec!["chat_moderate".to_string()]),
+            session_id: Some(session_id),
+        };
+        let result = handle_user_update(request, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+        read_server_message(&mut test_ctx.client).await;
+
+        // User list holders see bob's new badge
+        let mut updated = None;
+        while let Ok((msg, _)) = test_ctx._rx.messages.try_recv() {
+            if let ServerMessage::UserUpdated { user, .. } = msg {
+                updated = Some(user);
+            }
+        }
+        let user = updated.expect("Expected UserUpdated broadcast");
+        assert_eq!(user.username, "bob");
+        assert!(user.is_moderator);
+        assert!(!user.is_admin);
+        assert_eq!(user.permissions, Some(vec!["chat_moderate".to_string()]));
+    }
+
     #[tokio::test]
     async fn test_userupdate_empty_password_means_no_change() {
         let mut test_ctx = create_test_context().await;
@@ -1170,7 +1361,7 @@ mod tests {
         assert!(result.is_ok(), "Should send error response, not disconnect");
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::UserUpdateResponse { success, error } => {
+            ServerMessage::UserUpdateResponse { success, error, .. } => {
                 assert!(!success, "Should not allow self-edit");
                 assert_eq!(error, Some(err_cannot_edit_self(DEFAULT_TEST_LOCALE)));
             }
@@ -1205,11 +1396,14 @@ mod tests {
                 is_admin: false,
                 permissions: perms.permissions.clone(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: editor.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -1228,7 +1422,7 @@ mod tests {
         assert!(result.is_ok(), "Should send error response, not disconnect");
         let response = read_server_message(&mut test_ctx.client).await;
         match response {
-            ServerMessage::UserUpdateResponse { success, error } => {
+            ServerMessage::UserUpdateResponse { success, error, .. } => {
                 assert!(!success, "Should not allow disabling last admin");
                 assert_eq!(
                     error,
@@ -1393,11 +1587,14 @@ mod tests {
                 is_admin: true,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: admin1.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -1410,11 +1607,14 @@ mod tests {
                 is_admin: true,
                 permissions: std::collections::HashSet::new(),
                 address: test_ctx.peer_addr,
+                source: test_ctx.source.clone(),
                 created_at: admin2.created_at,
                 tx: test_ctx.tx.clone(),
                 features: vec![],
                 locale: DEFAULT_TEST_LOCALE.to_string(),
                 avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
             })
             .await;
 
@@ -1502,4 +1702,61 @@ mod tests {
             "Admin1 should still be admin (protected by atomic SQL)"
         );
     }
+
+    #[tokio::test]
+    async fn test_userupdate_enforces_prerequisites() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .config
+            .set_permission_prerequisites(crate::db::PermissionPrerequisites::Enforce)
+            .await
+            .unwrap();
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        test_ctx
+            .db
+            .users
+            .create_user("bob", "hash", false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        let request = UserUpdateRequest {
+            username: "bob".to_string(),
+            requested_username: None,
+            requested_password: None,
+            requested_is_admin: None,
+            requested_enabled: None,
+            requested_permissions: Some(vec!["user_kick".to_string()]),
+            session_id: Some(admin_id),
+        };
+        handle_user_update(request, &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::UserUpdateResponse {
+                success, warning, ..
+            } => {
+                assert!(success);
+                assert!(warning.unwrap().contains("user_list"));
+            }
+            _ => panic!("Expected UserUpdateResponse"),
+        }
+
+        let bob = test_ctx
+            .db
+            .users
+            .get_user_by_username("bob")
+            .await
+            .unwrap()
+            .unwrap();
+        let perms = test_ctx
+            .db
+            .users
+            .get_user_permissions(bob.id)
+            .await
+            .unwrap();
+        assert!(perms.contains(Permission::UserList));
+        assert!(perms.contains(Permission::UserKick));
+    }
 }
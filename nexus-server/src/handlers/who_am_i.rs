@@ -0,0 +1,280 @@
+//! Handler for WhoAmI command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::ALL_PERMISSIONS;
+use nexus_common::protocol::ServerMessage;
+
+use super::{HandlerContext, err_authentication, err_database, err_not_logged_in};
+use crate::db::Permission;
+
+/// Handle WhoAmI command
+///
+/// Reports the account behind this session as the server currently sees it.
+/// Admin status and permissions are re-read from the database (refreshing the
+/// session cache), and permissions switched off server-wide are left out.
+/// Guests, who have no account, get their session's fixed permissions. Needs
+/// no permission beyond being logged in.
+pub async fn handle_who_am_i<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(id) = session_id else {
+        eprintln!("WhoAmI from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error(&err_not_logged_in(ctx.locale), Some("WhoAmI"))
+            .await;
+    };
+
+    let user = match ctx.user_manager.get_user_by_session_id(id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error(&err_authentication(ctx.locale), Some("WhoAmI"))
+                .await;
+        }
+    };
+
+    let (username, is_admin, granted) = if user.is_guest {
+        // Guests have no account; their permissions are fixed for the session
+        let granted: Vec<Permission> = user.permissions.iter().copied().collect();
+        (user.username.clone(), false, granted)
+    } else {
+        let account = match ctx.db.users.get_user_by_id(user.db_user_id).await {
+            Ok(Some(account)) => account,
+            Ok(None) => {
+                return ctx
+                    .send_error(&err_authentication(ctx.locale), Some("WhoAmI"))
+                    .await;
+            }
+            Err(e) => {
+                eprintln!("Database error getting user: {}", e);
+                return send_failure(err_database(ctx.locale), ctx).await;
+            }
+        };
+
+        // Admins hold every permission without it being stored
+        let granted: Vec<Permission> = if account.is_admin {
+            ALL_PERMISSIONS
+                .iter()
+                .filter_map(|name| Permission::parse(name))
+                .collect()
+        } else {
+            match ctx
+                .user_manager
+                .reload_permissions(&ctx.db.users, account.id)
+                .await
+            {
+                Ok(permissions) => permissions.to_vec(),
+                Err(e) => {
+                    eprintln!("Database error reading permissions: {}", e);
+                    return send_failure(err_database(ctx.locale), ctx).await;
+                }
+            }
+        };
+        (account.username, account.is_admin, granted)
+    };
+
+    let disabled = ctx.db.config.get_disabled_permissions_for(is_admin).await;
+    let permissions = ALL_PERMISSIONS
+        .iter()
+        .filter_map(|name| Permission::parse(name))
+        .filter(|p| granted.contains(p) && !disabled.contains(*p))
+        .map(|p| p.as_str().to_string())
+        .collect();
+
+    let response = ServerMessage::WhoAmIResponse {
+        success: true,
+        error: None,
+        username: Some(username),
+        is_admin: Some(is_admin),
+        permissions: Some(permissions),
+        features: Some(user.features),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send a failed WhoAmI response
+async fn send_failure<W>(error: String, ctx: &mut HandlerContext<'_, W>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::WhoAmIResponse {
+        success: false,
+        error: Some(error),
+        username: None,
+        is_admin: None,
+        permissions: None,
+        features: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{DisabledPermissions, Permissions};
+    use crate::handlers::testing::{
+        create_test_context, login_guest, login_user, login_user_with_features, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_who_am_i_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_who_am_i(None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        assert!(matches!(response, ServerMessage::Error { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_who_am_i_reports_session() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserList, Permission::ChatSend],
+            false,
+            vec!["chat".to_string()],
+        )
+        .await;
+
+        let result = handle_who_am_i(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::WhoAmIResponse {
+                success,
+                error,
+                username,
+                is_admin,
+                permissions,
+                features,
+            } => {
+                assert!(success);
+                assert!(error.is_none());
+                assert_eq!(username.as_deref(), Some("alice"));
+                assert_eq!(is_admin, Some(false));
+                assert_eq!(
+                    permissions,
+                    Some(vec!["chat_send".to_string(), "user_list".to_string()])
+                );
+                assert_eq!(features, Some(vec!["chat".to_string()]));
+            }
+            _ => panic!("Expected WhoAmIResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_who_am_i_reads_permissions_fresh() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let user = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id)
+            .await
+            .unwrap();
+
+        // Grant a permission behind the session cache's back
+        let mut perms = Permissions::new();
+        perms.add(Permission::UserInfo);
+        test_ctx
+            .db
+            .users
+            .set_permissions(user.db_user_id, &perms)
+            .await
+            .unwrap();
+
+        let result = handle_who_am_i(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::WhoAmIResponse { permissions, .. } => {
+                assert_eq!(permissions, Some(vec!["user_info".to_string()]));
+            }
+            _ => panic!("Expected WhoAmIResponse"),
+        }
+
+        let user = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id)
+            .await
+            .unwrap();
+        assert!(user.permissions.contains(&Permission::UserInfo));
+    }
+
+    #[tokio::test]
+    async fn test_who_am_i_admin_omits_disabled_permissions() {
+        let mut test_ctx = create_test_context().await;
+        let disabled: DisabledPermissions = "user_broadcast".parse().unwrap();
+        test_ctx
+            .db
+            .config
+            .set_disabled_permissions(&disabled)
+            .await
+            .unwrap();
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_who_am_i(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx.client).await;
+        match response {
+            ServerMessage::WhoAmIResponse {
+                is_admin,
+                permissions,
+                ..
+            } => {
+                assert_eq!(is_admin, Some(true));
+                let permissions = permissions.unwrap();
+                assert_eq!(permissions.len(), ALL_PERMISSIONS.len() - 1);
+                assert!(!permissions.contains(&"user_broadcast".to_string()));
+            }
+            _ => panic!("Expected WhoAmIResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_who_am_i_reports_guest() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_guest(&mut test_ctx, "guest-alice").await;
+
+        let result = handle_who_am_i(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx.client).await {
+            ServerMessage::WhoAmIResponse {
+                success,
+                username,
+                is_admin,
+                permissions,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(username.as_deref(), Some("guest-alice"));
+                assert_eq!(is_admin, Some(false));
+                assert_eq!(
+                    permissions,
+                    Some(vec![
+                        "chat_receive".to_string(),
+                        "chat_send".to_string(),
+                        "chat_topic".to_string(),
+                        "user_info".to_string(),
+                        "user_list".to_string(),
+                    ])
+                );
+            }
+            other => panic!("Expected WhoAmIResponse, got {:?}", other),
+        }
+    }
+}
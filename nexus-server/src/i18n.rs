@@ -78,6 +78,23 @@ pub fn t_args(locale: &str, key: &str, args: &[(&str, &str)]) -> String {
     panic!("{} '{}'", ERR_I18N_MISSING_KEY_ENGLISH, key);
 }
 
+/// Whether a locale has a translation bundle
+pub fn is_supported_locale(locale: &str) -> bool {
+    SUPPORTED_LOCALES.contains(&locale)
+}
+
+/// Pick the locale to answer a client in
+///
+/// The client's own locale wins when it has a bundle; an empty or unsupported
+/// locale falls back to the server's configured default.
+pub fn resolve_locale(requested: &str, default_locale: &str) -> String {
+    if is_supported_locale(requested) {
+        requested.to_string()
+    } else {
+        default_locale.to_string()
+    }
+}
+
 /// Get a Fluent bundle for the specified locale
 ///
 /// Loads the appropriate .ftl file and creates a bundle.
@@ -192,6 +209,15 @@ mod tests {
         assert_eq!(result, "Not logged in");
     }
 
+    #[test]
+    fn test_resolve_locale() {
+        assert_eq!(resolve_locale("fr", "ja"), "fr");
+        assert_eq!(resolve_locale("zh", "ja"), "zh");
+        assert_eq!(resolve_locale("", "ja"), "ja");
+        assert_eq!(resolve_locale("xx", "ja"), "ja");
+        assert!(!is_supported_locale("en-US"));
+    }
+
     #[test]
     fn test_numeric_args() {
         let result = t_args("en", "err-broadcast-too-long", &[("max_length", "1024")]);
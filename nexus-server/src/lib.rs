@@ -2,6 +2,8 @@
 //!
 //! This library exposes the server's internal modules for integration testing.
 
+pub mod avatar;
+pub mod connection_history;
 pub mod constants;
 pub mod db;
 pub mod handlers;
@@ -1,27 +1,37 @@
 //! Nexus BBS Server
 
 mod args;
+mod avatar;
 mod connection;
+mod connection_history;
+mod connection_source;
 mod connection_tracker;
 mod constants;
 mod db;
+mod frame_guard;
 mod handlers;
 mod i18n;
+mod tls_policy;
 mod upnp;
+mod user_import;
 mod users;
 
-use args::Args;
+use args::{Args, Command, UserCommand};
 use clap::Parser;
+use connection_history::ConnectionHistory;
+use connection_source::SourceClassifier;
 use connection_tracker::ConnectionTracker;
 use constants::*;
+use frame_guard::MalformedFrameTracker;
+use nexus_common::protocol::ServerMessage;
 use sha2::{Digest, Sha256};
 use std::fs;
 use std::io::BufReader;
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::net::TcpListener;
+use tls_policy::TlsPolicy;
+use tokio::net::{TcpListener, TcpSocket};
 use tokio_rustls::TlsAcceptor;
-use tokio_rustls::rustls::ServerConfig;
 use tokio_rustls::rustls::pki_types::CertificateDer;
 use users::UserManager;
 
@@ -32,28 +42,160 @@ async fn main() {
     // Print banner first
     println!("{}{}", MSG_BANNER, env!("CARGO_PKG_VERSION"));
 
-    // Setup database
-    let (database, user_manager, db_path) = setup_db(args.database).await;
+    // Check the TLS policy before touching the database (flags can't cross-validate)
+    let tls_policy = match TlsPolicy::new(args.tls_min_version, args.tls_ciphers.clone()) {
+        Ok(policy) => policy,
+        Err(e) => {
+            eprintln!("{}{}", ERR_TLS_POLICY, e);
+            std::process::exit(1);
+        }
+    };
+
+    // Setup database (holding the lock until exit keeps a second server off it)
+    let (database, user_manager, db_path, _db_lock) = setup_db(args.database).await;
+
+    // Maintenance commands run against the database and exit
+    if let Some(command) = args.command {
+        run_command(&database, command).await;
+        return;
+    }
+
+    // Apply message limit overrides (persisted so they survive restarts)
+    apply_message_limits(&database, args.max_message_length, args.max_topic_length).await;
+
+    // Apply avatar dimension overrides (persisted so they survive restarts)
+    apply_avatar_limits(
+        &database,
+        args.avatar_min_dimension,
+        args.avatar_max_dimension,
+        args.avatar_downscale,
+    )
+    .await;
+
+    // Apply the expensive command cooldown (persisted so it survives restarts)
+    apply_command_cooldown(&database, args.command_cooldown).await;
+
+    // Apply the chat edit window (persisted so it survives restarts)
+    apply_chat_edit_window(&database, args.chat_edit_window).await;
+
+    // Apply chat retention overrides and purge history on a schedule
+    apply_chat_history(&database, args.chat_history).await;
+    apply_chat_retention(
+        &database,
+        args.chat_retention_count,
+        args.chat_retention_days,
+    )
+    .await;
+    spawn_chat_history_purge(database.clone());
+
+    // Apply the minimum client version (persisted so it survives restarts)
+    apply_min_client_version(&database, args.min_client_version).await;
+
+    // Apply maintenance mode (persisted so it survives restarts)
+    apply_maintenance_mode(&database, args.maintenance).await;
+
+    // Apply the default locale for server messages (persisted so it survives restarts)
+    apply_default_locale(&database, args.default_locale).await;
+
+    // Apply new login notices (persisted so they survive restarts)
+    apply_login_notices(&database, args.login_notices).await;
+
+    // Apply guest login (persisted so it survives restarts)
+    apply_guest_login(&database, args.guest_login, args.guest_password).await;
+
+    // Apply the per-account session limit (persisted so it survives restarts)
+    apply_session_limit(
+        &database,
+        args.max_sessions_per_user,
+        args.session_limit_policy,
+        args.session_limit_admin_exempt,
+    )
+    .await;
+
+    // Apply server-wide disabled permissions (persisted so they survive restarts)
+    apply_disabled_permissions(
+        &database,
+        args.disable_permissions,
+        args.disabled_permissions_admin_exempt,
+    )
+    .await;
+
+    // Apply the permission prerequisite policy (persisted so it survives restarts)
+    apply_permission_prerequisites(&database, args.permission_prerequisites).await;
+
+    // Load server rules (users must accept them again whenever they change)
+    apply_server_rules(&database, args.rules_file).await;
+
+    // Load the pre-login banner
+    apply_server_banner(&database, args.banner_file).await;
+
+    // Apply the announcement interval and start rotating announcements
+    apply_announcement_interval(&database, args.announcement_interval).await;
+    spawn_announcement_ticker(database.clone(), user_manager.clone());
+
+    // Schedule database maintenance for quiet moments
+    spawn_db_maintenance(
+        db_path.clone(),
+        user_manager.clone(),
+        args.db_maintenance_interval,
+        args.db_vacuum,
+        args.db_maintenance_max_users,
+    );
 
     // Setup network (TCP listener + TLS)
-    let (listener, tls_acceptor) = setup_network(args.bind, args.port, &db_path).await;
+    let (listener, tls_acceptor) = setup_network(
+        args.bind,
+        args.port,
+        args.backlog,
+        args.reuse_address,
+        &db_path,
+        &tls_policy,
+    )
+    .await;
 
     // Setup UPnP port forwarding if requested
     let upnp_handle = setup_upnp(args.upnp, args.bind, args.port).await;
 
     // Setup connection tracking for DoS protection (load limit from database)
     let max_connections_per_ip = database.config.get_max_connections_per_ip().await;
-    let connection_tracker = ConnectionTracker::new(max_connections_per_ip);
+    // The tracker also keeps the rolling history admins read with /stats
+    let connection_history = ConnectionHistory::new();
+    let connection_tracker =
+        ConnectionTracker::new(max_connections_per_ip, connection_history.clone());
+
+    // Temporarily block addresses that keep sending malformed frames
+    let frame_guard = MalformedFrameTracker::new(
+        args.malformed_frame_limit,
+        std::time::Duration::from_secs(args.malformed_frame_window),
+        std::time::Duration::from_secs(args.malformed_frame_block),
+    );
+
+    // Tag connections by source network (operator rules over built-in ranges)
+    let source_classifier = SourceClassifier::new(args.source_tags.clone());
 
     // Setup graceful shutdown handling
     let shutdown_signal = setup_shutdown_signal();
 
     // Main server loop - accept incoming connections
     let debug = args.debug;
+    let tcp_nodelay = !args.no_tcp_nodelay;
+    let outgoing_queue = args.outgoing_queue;
+    let login_timeout = std::time::Duration::from_secs(args.login_timeout);
     tokio::select! {
         _ = shutdown_signal => {
             println!("{}", MSG_SHUTDOWN_RECEIVED);
 
+            let metrics = frame_guard.metrics();
+            println!(
+                "{}{}{}{}{}{}",
+                MSG_FRAME_METRICS,
+                metrics.malformed_frames,
+                MSG_FRAME_METRICS_BLOCKS,
+                metrics.blocks,
+                MSG_FRAME_METRICS_REFUSED,
+                metrics.refused_connections
+            );
+
             // Cleanup UPnP port forwarding if enabled
             if let Some((gateway, renewal_task)) = upnp_handle {
                 renewal_task.abort();
@@ -68,6 +210,15 @@ async fn main() {
             loop {
                 match listener.accept().await {
                     Ok((socket, peer_addr)) => {
+                        // Refuse addresses blocked for sending malformed frames
+                        if frame_guard.check_blocked(peer_addr.ip()) {
+                            if debug {
+                                eprintln!("{}{}", ERR_MALFORMED_FRAME_BLOCKED, peer_addr.ip());
+                            }
+                            connection_history.record_refused();
+                            continue;
+                        }
+
                         // Check connection limit before accepting
                         let connection_guard = match connection_tracker.try_acquire(peer_addr.ip()) {
                             Some(guard) => guard,
@@ -80,9 +231,21 @@ async fn main() {
                             }
                         };
 
+                        // Chat traffic is many small frames; send them immediately
+                        // instead of letting Nagle's algorithm coalesce them
+                        if tcp_nodelay
+                            && let Err(e) = socket.set_nodelay(true)
+                            && debug
+                        {
+                            eprintln!("{}{}: {}", ERR_SET_NODELAY, peer_addr, e);
+                        }
+
+                        let source = source_classifier.classify(peer_addr.ip());
                         let user_manager = user_manager.clone();
                         let database = database.clone();
                         let tls_acceptor = tls_acceptor.clone();
+                        let frame_guard = frame_guard.clone();
+                        let connection_history = connection_history.clone();
 
                         // Spawn a new task to handle this connection
                         tokio::spawn(async move {
@@ -91,10 +254,15 @@ async fn main() {
                             if let Err(e) = connection::handle_connection(
                                 socket,
                                 peer_addr,
+                                source,
                                 user_manager,
                                 database,
                                 debug,
+                                outgoing_queue,
+                                login_timeout,
                                 tls_acceptor,
+                                frame_guard,
+                                connection_history,
                             )
                             .await
                             {
@@ -127,21 +295,24 @@ async fn main() {
 }
 
 /// Load existing TLS configuration or generate new self-signed certificate
-fn load_or_generate_tls_config(cert_dir: &std::path::Path) -> Result<TlsAcceptor, String> {
+fn load_or_generate_tls_config(
+    cert_dir: &std::path::Path,
+    policy: &TlsPolicy,
+) -> Result<TlsAcceptor, String> {
     let cert_path = cert_dir.join(CERT_FILENAME);
     let key_path = cert_dir.join(KEY_FILENAME);
 
     // Check if certificate and key already exist
     if cert_path.exists() && key_path.exists() {
         // Load existing certificate
-        let acceptor = load_tls_config(&cert_path, &key_path)?;
+        let acceptor = load_tls_config(&cert_path, &key_path, policy)?;
         display_certificate_fingerprint(&cert_path)?;
         Ok(acceptor)
     } else {
         // Generate new self-signed certificate
         println!("{}", MSG_GENERATING_CERT);
         generate_self_signed_cert(&cert_path, &key_path)?;
-        let acceptor = load_tls_config(&cert_path, &key_path)?;
+        let acceptor = load_tls_config(&cert_path, &key_path, policy)?;
         display_certificate_fingerprint(&cert_path)?;
         Ok(acceptor)
     }
@@ -187,10 +358,11 @@ fn generate_self_signed_cert(
     Ok(())
 }
 
-/// Load TLS configuration from certificate and key files
+/// Load TLS configuration from certificate and key files under the given policy
 fn load_tls_config(
     cert_path: &std::path::Path,
     key_path: &std::path::Path,
+    policy: &TlsPolicy,
 ) -> Result<TlsAcceptor, String> {
     // Load certificate chain
     let cert_file =
@@ -212,10 +384,7 @@ fn load_tls_config(
         .ok_or(ERR_NO_KEY_FOUND)?;
 
     // Create TLS server configuration
-    let config = ServerConfig::builder()
-        .with_no_client_auth()
-        .with_single_cert(certs, private_key)
-        .map_err(|e| format!("{}{}", ERR_CREATE_TLS_CONFIG, e))?;
+    let config = policy.server_config(certs, private_key)?;
 
     Ok(TlsAcceptor::from(Arc::new(config)))
 }
@@ -236,7 +405,12 @@ fn set_secure_permissions(path: &std::path::Path) -> Result<(), String> {
 /// Setup database connection and initialize user manager
 async fn setup_db(
     database_path: Option<std::path::PathBuf>,
-) -> (db::Database, UserManager, std::path::PathBuf) {
+) -> (
+    db::Database,
+    UserManager,
+    std::path::PathBuf,
+    db::DatabaseLock,
+) {
     // Determine database path (use provided path or platform default)
     let db_path = database_path.unwrap_or_else(|| match db::default_database_path() {
         Ok(path) => path,
@@ -246,9 +420,9 @@ async fn setup_db(
         }
     });
 
-    // Initialize database connection pool and run migrations
-    let pool = match db::init_db(&db_path).await {
-        Ok(pool) => pool,
+    // Lock the database, initialize the connection pool, and run migrations
+    let (pool, db_lock) = match db::init_db(&db_path).await {
+        Ok(opened) => opened,
         Err(e) => {
             eprintln!("{}{}", ERR_DATABASE_INIT, e);
             std::process::exit(1);
@@ -268,7 +442,640 @@ async fn setup_db(
     let database = db::Database::new(pool);
     let user_manager = UserManager::new();
 
-    (database, user_manager, db_path)
+    (database, user_manager, db_path, db_lock)
+}
+
+/// Run a maintenance command, exiting with an error status if it fails
+async fn run_command(database: &db::Database, command: Command) {
+    match command {
+        Command::User {
+            command: UserCommand::Import { file, dry_run },
+        } => import_users(database, &file, dry_run).await,
+        Command::ModerationLog { limit } => print_moderation_log(database, limit).await,
+    }
+}
+
+/// Print the newest moderation log entries with the time each was recorded
+async fn print_moderation_log(database: &db::Database, limit: usize) {
+    let entries = match database.moderation.recent(limit).await {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("{}{}", ERR_MODERATION_LOG_READ, e);
+            std::process::exit(1);
+        }
+    };
+
+    if entries.is_empty() {
+        println!("{}", MSG_MODERATION_LOG_EMPTY);
+        return;
+    }
+
+    for (created_at, entry) in entries {
+        let time = chrono::DateTime::from_timestamp(created_at, 0)
+            .map(|time| time.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            .unwrap_or_else(|| created_at.to_string());
+        let message_id = entry
+            .message_id
+            .map(|id| format!(" #{id}"))
+            .unwrap_or_default();
+        // Debug formatting quotes the text and escapes newlines and control characters
+        println!(
+            "{} {} {} {}{}: {:?}",
+            time, entry.moderator, entry.action, entry.target_username, message_id, entry.message
+        );
+    }
+}
+
+/// Import accounts from a file and print what was created and skipped
+///
+/// Exits with an error status if the file can't be read or any record is invalid.
+async fn import_users(database: &db::Database, file: &std::path::Path, dry_run: bool) {
+    let records = match user_import::read_records(file) {
+        Ok(records) => records,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if dry_run {
+        println!("{}", MSG_IMPORT_DRY_RUN);
+    }
+
+    let summary = user_import::import_users(&database.users, records, dry_run).await;
+
+    let created_label = if dry_run {
+        MSG_IMPORT_WOULD_CREATE
+    } else {
+        MSG_IMPORT_CREATED
+    };
+    println!("{}{}", created_label, summary.created);
+    println!("{}{}", MSG_IMPORT_SKIPPED, summary.skipped);
+    println!("{}{}", MSG_IMPORT_INVALID, summary.invalid);
+
+    if summary.invalid > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Save message and topic length limits passed on the command line
+async fn apply_message_limits(
+    database: &db::Database,
+    max_message_length: Option<usize>,
+    max_topic_length: Option<usize>,
+) {
+    if let Some(length) = max_message_length
+        && let Err(e) = database.config.set_max_message_length(length).await
+    {
+        eprintln!("{}{}", ERR_MESSAGE_LIMITS, e);
+        std::process::exit(1);
+    }
+
+    if let Some(length) = max_topic_length
+        && let Err(e) = database.config.set_max_topic_length(length).await
+    {
+        eprintln!("{}{}", ERR_MESSAGE_LIMITS, e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "{}{}{}{}",
+        MSG_MESSAGE_LIMITS,
+        database.config.get_max_message_length().await,
+        MSG_TOPIC_LIMIT,
+        database.config.get_max_topic_length().await
+    );
+}
+
+/// Persist avatar dimension overrides from the command line and print the active bounds
+///
+/// Either bound may be given alone; the other keeps its stored value.
+async fn apply_avatar_limits(
+    database: &db::Database,
+    min_dimension: Option<u32>,
+    max_dimension: Option<u32>,
+    downscale: Option<bool>,
+) {
+    let current = database.config.get_avatar_limits().await;
+    if (min_dimension.is_some() || max_dimension.is_some())
+        && let Err(e) = database
+            .config
+            .set_avatar_dimensions(
+                min_dimension.unwrap_or(current.min_dimension),
+                max_dimension.unwrap_or(current.max_dimension),
+            )
+            .await
+    {
+        eprintln!("{}{}", ERR_AVATAR_LIMITS, e);
+        std::process::exit(1);
+    }
+
+    if let Some(enabled) = downscale
+        && let Err(e) = database.config.set_avatar_downscale(enabled).await
+    {
+        eprintln!("{}{}", ERR_AVATAR_LIMITS, e);
+        std::process::exit(1);
+    }
+
+    let limits = database.config.get_avatar_limits().await;
+    println!(
+        "{}{}{}{}{}{}",
+        MSG_AVATAR_LIMITS,
+        limits.min_dimension,
+        MSG_AVATAR_LIMITS_TO,
+        limits.max_dimension,
+        MSG_AVATAR_LIMITS_SUFFIX,
+        if limits.downscale {
+            MSG_AVATAR_LIMITS_DOWNSCALE
+        } else {
+            ""
+        }
+    );
+}
+
+/// Persist the command cooldown from the command line and print the active value
+async fn apply_command_cooldown(database: &db::Database, cooldown: Option<u32>) {
+    if let Some(seconds) = cooldown
+        && let Err(e) = database.config.set_command_cooldown_seconds(seconds).await
+    {
+        eprintln!("{}{}", ERR_COMMAND_COOLDOWN, e);
+        std::process::exit(1);
+    }
+
+    match database.config.get_command_cooldown_seconds().await {
+        0 => println!("{}", MSG_COMMAND_COOLDOWN_OFF),
+        seconds => println!(
+            "{}{}{}",
+            MSG_COMMAND_COOLDOWN, seconds, MSG_COMMAND_COOLDOWN_SUFFIX
+        ),
+    }
+}
+
+/// Persist the chat edit window from the command line and print the active value
+async fn apply_chat_edit_window(database: &db::Database, window: Option<u32>) {
+    if let Some(minutes) = window
+        && let Err(e) = database.config.set_chat_edit_window_minutes(minutes).await
+    {
+        eprintln!("{}{}", ERR_CHAT_EDIT_WINDOW, e);
+        std::process::exit(1);
+    }
+
+    match database.config.get_chat_edit_window_minutes().await {
+        0 => println!("{}", MSG_CHAT_EDIT_WINDOW_UNLIMITED),
+        minutes => println!(
+            "{}{}{}",
+            MSG_CHAT_EDIT_WINDOW, minutes, MSG_CHAT_EDIT_WINDOW_SUFFIX
+        ),
+    }
+}
+
+/// Persist chat retention overrides from the command line and print the active policy
+async fn apply_chat_retention(
+    database: &db::Database,
+    retention_count: Option<u32>,
+    retention_days: Option<u32>,
+) {
+    if let Some(count) = retention_count
+        && let Err(e) = database.config.set_chat_retention_count(count).await
+    {
+        eprintln!("{}{}", ERR_CHAT_RETENTION, e);
+        std::process::exit(1);
+    }
+
+    if let Some(days) = retention_days
+        && let Err(e) = database.config.set_chat_retention_days(days).await
+    {
+        eprintln!("{}{}", ERR_CHAT_RETENTION, e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "{}{}{}{}{}",
+        MSG_CHAT_RETENTION,
+        database.config.get_chat_retention_count().await,
+        MSG_CHAT_RETENTION_DAYS,
+        database.config.get_chat_retention_days().await,
+        MSG_CHAT_RETENTION_DAYS_SUFFIX
+    );
+}
+
+/// Persist chat history recording from the command line and print the active state
+async fn apply_chat_history(database: &db::Database, enabled: Option<bool>) {
+    if let Some(enabled) = enabled
+        && let Err(e) = database.config.set_chat_history_enabled(enabled).await
+    {
+        eprintln!("{}{}", ERR_CHAT_HISTORY, e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "{}{}",
+        MSG_CHAT_HISTORY,
+        database.config.get_chat_history_enabled().await
+    );
+}
+
+/// Persist the minimum client version from the command line and print the active one
+///
+/// The argument was validated by the parser; an empty string clears it.
+async fn apply_min_client_version(database: &db::Database, version: Option<String>) {
+    if let Some(version) = version {
+        let version = version.parse::<nexus_common::version::Version>().ok();
+        if let Err(e) = database
+            .config
+            .set_min_client_version(version.as_ref())
+            .await
+        {
+            eprintln!("{}{}", ERR_MIN_CLIENT_VERSION, e);
+            std::process::exit(1);
+        }
+    }
+
+    match database.config.get_min_client_version().await {
+        Some(version) => println!("{}{}", MSG_MIN_CLIENT_VERSION, version),
+        None => println!("{}", MSG_MIN_CLIENT_VERSION_ANY),
+    }
+}
+
+/// Persist the permission prerequisite policy from the command line and print the active one
+async fn apply_permission_prerequisites(
+    database: &db::Database,
+    mode: Option<db::PermissionPrerequisites>,
+) {
+    if let Some(mode) = mode
+        && let Err(e) = database.config.set_permission_prerequisites(mode).await
+    {
+        eprintln!("{}{}", ERR_PERMISSION_PREREQUISITES, e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "{}{}",
+        MSG_PERMISSION_PREREQUISITES,
+        database.config.get_permission_prerequisites().await
+    );
+}
+
+/// Persist maintenance mode from the command line and print the active state
+async fn apply_maintenance_mode(database: &db::Database, enabled: Option<bool>) {
+    if let Some(enabled) = enabled
+        && let Err(e) = database.config.set_maintenance_mode(enabled).await
+    {
+        eprintln!("{}{}", ERR_MAINTENANCE_MODE, e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "{}{}",
+        MSG_MAINTENANCE_MODE,
+        database.config.get_maintenance_mode().await
+    );
+}
+
+/// Persist the default locale from the command line and print the active one
+async fn apply_default_locale(database: &db::Database, locale: Option<String>) {
+    if let Some(locale) = locale
+        && let Err(e) = database.config.set_default_locale(&locale).await
+    {
+        eprintln!("{}{}", ERR_DEFAULT_LOCALE, e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "{}{}",
+        MSG_DEFAULT_LOCALE,
+        database.config.get_default_locale().await
+    );
+}
+
+/// Persist new login notices from the command line and print the active state
+async fn apply_login_notices(database: &db::Database, enabled: Option<bool>) {
+    if let Some(enabled) = enabled
+        && let Err(e) = database.config.set_login_notices(enabled).await
+    {
+        eprintln!("{}{}", ERR_LOGIN_NOTICES, e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "{}{}",
+        MSG_LOGIN_NOTICES,
+        database.config.get_login_notices().await
+    );
+}
+
+/// Persist guest login from the command line and print the active state
+async fn apply_guest_login(
+    database: &db::Database,
+    enabled: Option<bool>,
+    password: Option<String>,
+) {
+    if let Some(enabled) = enabled
+        && let Err(e) = database.config.set_guest_login(enabled).await
+    {
+        eprintln!("{}{}", ERR_GUEST_LOGIN, e);
+        std::process::exit(1);
+    }
+
+    if let Some(password) = password {
+        // Only the hash is stored, like account passwords
+        let hash = if password.is_empty() {
+            None
+        } else {
+            match db::hash_password(&password) {
+                Ok(hash) => Some(hash),
+                Err(e) => {
+                    eprintln!("{}{}", ERR_GUEST_LOGIN, e);
+                    std::process::exit(1);
+                }
+            }
+        };
+        if let Err(e) = database
+            .config
+            .set_guest_password_hash(hash.as_deref())
+            .await
+        {
+            eprintln!("{}{}", ERR_GUEST_LOGIN, e);
+            std::process::exit(1);
+        }
+    }
+
+    let guest_login = database.config.get_guest_login().await;
+    let suffix = if guest_login.enabled && guest_login.password_hash.is_some() {
+        MSG_GUEST_LOGIN_PASSWORD
+    } else {
+        ""
+    };
+    println!("{}{}{}", MSG_GUEST_LOGIN, guest_login.enabled, suffix);
+}
+
+/// Persist the per-account session limit from the command line and print the active one
+async fn apply_session_limit(
+    database: &db::Database,
+    max_sessions: Option<u32>,
+    policy: Option<db::SessionLimitPolicy>,
+    admin_exempt: Option<bool>,
+) {
+    if let Some(max_sessions) = max_sessions
+        && let Err(e) = database
+            .config
+            .set_max_sessions_per_user(max_sessions)
+            .await
+    {
+        eprintln!("{}{}", ERR_SESSION_LIMIT, e);
+        std::process::exit(1);
+    }
+
+    if let Some(policy) = policy
+        && let Err(e) = database.config.set_session_limit_policy(policy).await
+    {
+        eprintln!("{}{}", ERR_SESSION_LIMIT, e);
+        std::process::exit(1);
+    }
+
+    if let Some(exempt) = admin_exempt
+        && let Err(e) = database.config.set_session_limit_admin_exempt(exempt).await
+    {
+        eprintln!("{}{}", ERR_SESSION_LIMIT, e);
+        std::process::exit(1);
+    }
+
+    let limit = database.config.get_session_limit().await;
+    if limit.max_sessions == 0 {
+        println!("{}", MSG_SESSION_LIMIT_UNLIMITED);
+        return;
+    }
+    let suffix = if limit.admin_exempt {
+        MSG_SESSION_LIMIT_ADMIN_EXEMPT
+    } else {
+        ""
+    };
+    println!(
+        "{}{} ({}){}",
+        MSG_SESSION_LIMIT, limit.max_sessions, limit.policy, suffix
+    );
+}
+
+/// Persist disabled permissions from the command line and print the active set
+async fn apply_disabled_permissions(
+    database: &db::Database,
+    disabled: Option<db::DisabledPermissions>,
+    admin_exempt: Option<bool>,
+) {
+    if let Some(disabled) = disabled
+        && let Err(e) = database.config.set_disabled_permissions(&disabled).await
+    {
+        eprintln!("{}{}", ERR_DISABLED_PERMISSIONS, e);
+        std::process::exit(1);
+    }
+
+    if let Some(exempt) = admin_exempt
+        && let Err(e) = database
+            .config
+            .set_disabled_permissions_admin_exempt(exempt)
+            .await
+    {
+        eprintln!("{}{}", ERR_DISABLED_PERMISSIONS, e);
+        std::process::exit(1);
+    }
+
+    let disabled = database.config.get_disabled_permissions().await;
+    let suffix = if !disabled.is_empty()
+        && database
+            .config
+            .get_disabled_permissions_admin_exempt()
+            .await
+    {
+        MSG_DISABLED_PERMISSIONS_ADMIN_EXEMPT
+    } else {
+        ""
+    };
+    println!("{}{}{}", MSG_DISABLED_PERMISSIONS, disabled, suffix);
+}
+
+/// Load server rules from a file passed on the command line
+///
+/// Changing the rules text resets every user's acceptance so they see the new
+/// rules on their next login.
+async fn apply_server_rules(database: &db::Database, rules_file: Option<std::path::PathBuf>) {
+    let Some(path) = rules_file else {
+        return;
+    };
+
+    let rules = match fs::read_to_string(&path) {
+        Ok(text) => text.trim().to_string(),
+        Err(e) => {
+            eprintln!("{}{}: {}", ERR_READ_RULES_FILE, path.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    let previous = database.config.get_server_rules().await;
+    if let Err(e) = database.config.set_server_rules(&rules).await {
+        eprintln!("{}{}", ERR_SERVER_RULES, e);
+        std::process::exit(1);
+    }
+
+    if previous != rules {
+        if let Err(e) = database.users.reset_rules_acceptance().await {
+            eprintln!("{}{}", ERR_SERVER_RULES, e);
+            std::process::exit(1);
+        }
+        if !rules.is_empty() {
+            println!("{}", MSG_SERVER_RULES_CHANGED);
+        }
+    }
+
+    println!("{}{}", MSG_SERVER_RULES, path.display());
+}
+
+/// Load the pre-login server banner from a file passed on the command line
+async fn apply_server_banner(database: &db::Database, banner_file: Option<std::path::PathBuf>) {
+    let Some(path) = banner_file else {
+        return;
+    };
+
+    let banner = match fs::read_to_string(&path) {
+        Ok(text) => text.trim().to_string(),
+        Err(e) => {
+            eprintln!("{}{}: {}", ERR_READ_BANNER_FILE, path.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(e) = database.config.set_server_banner(&banner).await {
+        eprintln!("{}{}", ERR_SERVER_BANNER, e);
+        std::process::exit(1);
+    }
+
+    println!("{}{}", MSG_SERVER_BANNER, path.display());
+}
+
+/// Persist the announcement interval from the command line and print the rotation
+async fn apply_announcement_interval(database: &db::Database, interval: Option<u32>) {
+    if let Some(minutes) = interval
+        && let Err(e) = database.config.set_announcement_interval(minutes).await
+    {
+        eprintln!("{}{}", ERR_ANNOUNCEMENT_INTERVAL, e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "{}{}{}{}{}",
+        MSG_ANNOUNCEMENTS,
+        database.config.get_announcements().await.len(),
+        MSG_ANNOUNCEMENTS_INTERVAL,
+        database.config.get_announcement_interval().await,
+        MSG_ANNOUNCEMENTS_INTERVAL_SUFFIX
+    );
+}
+
+/// Spawn a background task that broadcasts the next announcement each interval
+///
+/// The list and interval are re-read every cycle so admin changes apply
+/// without a restart. An empty list sends nothing.
+fn spawn_announcement_ticker(database: db::Database, user_manager: UserManager) {
+    tokio::spawn(async move {
+        let mut next = 0;
+        loop {
+            let minutes = database.config.get_announcement_interval().await;
+            tokio::time::sleep(std::time::Duration::from_secs(
+                u64::from(minutes) * SECONDS_PER_MINUTE,
+            ))
+            .await;
+
+            let announcements = database.config.get_announcements().await;
+            if announcements.is_empty() {
+                next = 0;
+                continue;
+            }
+
+            // Removals can shrink the list under us, so wrap before indexing
+            let index = next % announcements.len();
+            next = index + 1;
+            let message = announcements[index].clone();
+            user_manager
+                .broadcast_extended(
+                    ServerMessage::ServerAnnouncement { message },
+                    &database.users,
+                )
+                .await;
+        }
+    });
+}
+
+/// Spawn a background task that applies the chat retention policy periodically
+///
+/// The first tick fires immediately, so history left over from a previous run
+/// (or a tightened policy) is trimmed at startup.
+fn spawn_chat_history_purge(database: db::Database) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+            CHAT_HISTORY_PURGE_INTERVAL_SECS,
+        ));
+        loop {
+            interval.tick().await;
+            if let Err(e) = database.purge_chat_history().await {
+                eprintln!("{}{}", ERR_CHAT_HISTORY_PURGE, e);
+            }
+        }
+    });
+}
+
+/// Print the database maintenance schedule and spawn a task that runs it
+///
+/// The first run comes one interval after startup. A run that falls due
+/// while more than `max_users` are online is postponed until the server
+/// quiets down; the next interval counts from when it actually ran.
+fn spawn_db_maintenance(
+    db_path: std::path::PathBuf,
+    user_manager: UserManager,
+    interval_hours: u32,
+    vacuum: bool,
+    max_users: usize,
+) {
+    if interval_hours == 0 {
+        println!("{}", MSG_DB_MAINTENANCE_OFF);
+        return;
+    }
+
+    println!(
+        "{}{}{}{}{}{}{}",
+        MSG_DB_MAINTENANCE,
+        interval_hours,
+        MSG_DB_MAINTENANCE_HOURS,
+        if vacuum {
+            MSG_DB_MAINTENANCE_VACUUM
+        } else {
+            ""
+        },
+        MSG_DB_MAINTENANCE_MAX_USERS,
+        max_users,
+        MSG_DB_MAINTENANCE_MAX_USERS_SUFFIX
+    );
+
+    tokio::spawn(async move {
+        let interval = std::time::Duration::from_secs(u64::from(interval_hours) * SECONDS_PER_HOUR);
+        loop {
+            tokio::time::sleep(interval).await;
+
+            while user_manager.get_online_user_count().await > max_users {
+                tokio::time::sleep(std::time::Duration::from_secs(DB_MAINTENANCE_RETRY_SECS)).await;
+            }
+
+            match db::run_maintenance(&db_path, vacuum).await {
+                Ok(report) => println!(
+                    "{}{}{}{}{}",
+                    MSG_DB_MAINTENANCE_DONE,
+                    report.size_before,
+                    MSG_DB_MAINTENANCE_SIZE_TO,
+                    report.size_after,
+                    MSG_DB_MAINTENANCE_SIZE_SUFFIX
+                ),
+                Err(e) => eprintln!("{}{}", ERR_DB_MAINTENANCE, e),
+            }
+        }
+    });
 }
 
 /// Setup UPnP port forwarding if enabled
@@ -301,13 +1108,16 @@ async fn setup_upnp(
 async fn setup_network(
     bind: std::net::IpAddr,
     port: u16,
+    backlog: u32,
+    reuse_address: bool,
     db_path: &std::path::Path,
+    tls_policy: &TlsPolicy,
 ) -> (TcpListener, TlsAcceptor) {
     // Get certificate directory (same parent as database)
     let cert_dir = db_path.parent().expect(ERR_DB_PATH_NO_PARENT).to_path_buf();
 
     // Load or generate TLS certificate
-    let tls_acceptor = match load_or_generate_tls_config(&cert_dir) {
+    let tls_acceptor = match load_or_generate_tls_config(&cert_dir, tls_policy) {
         Ok(acceptor) => acceptor,
         Err(e) => {
             eprintln!("{}{}", ERR_TLS_INIT, e);
@@ -315,11 +1125,12 @@ async fn setup_network(
         }
     };
     println!("{}{}", MSG_CERTIFICATES, cert_dir.display());
+    println!("{}{}", MSG_TLS_POLICY, tls_policy);
 
     // Create socket address
     let addr = SocketAddr::new(bind, port);
 
-    let listener = match TcpListener::bind(addr).await {
+    let listener = match bind_listener(addr, backlog, reuse_address) {
         Ok(listener) => listener,
         Err(e) => {
             eprintln!("{}{}: {}", ERR_BIND_FAILED, addr, e);
@@ -332,6 +1143,29 @@ async fn setup_network(
     (listener, tls_acceptor)
 }
 
+/// Bind the TCP listener with the configured backlog and socket options
+///
+/// `reuse_address` is ignored on Windows, where SO_REUSEADDR lets another
+/// process take over a port that is in use.
+fn bind_listener(
+    addr: SocketAddr,
+    backlog: u32,
+    reuse_address: bool,
+) -> std::io::Result<TcpListener> {
+    let socket = if addr.is_ipv4() {
+        TcpSocket::new_v4()?
+    } else {
+        TcpSocket::new_v6()?
+    };
+
+    if reuse_address && cfg!(not(windows)) {
+        socket.set_reuseaddr(true)?;
+    }
+
+    socket.bind(addr)?;
+    socket.listen(backlog)
+}
+
 /// Calculate and display certificate fingerprint (SHA-256)
 fn display_certificate_fingerprint(cert_path: &std::path::Path) -> Result<(), String> {
     // Read certificate file
@@ -0,0 +1,282 @@
+//! TLS protocol version and cipher suite policy
+//!
+//! By default the server accepts TLS 1.2 and 1.3 with rustls' default cipher
+//! suites, which are all forward-secret AEAD suites. Operators with compliance
+//! requirements can raise the minimum version to TLS 1.3 and/or narrow the
+//! suites to a named subset. Only suites from rustls' default set can be
+//! named, so a policy can restrict the defaults but never weaken them.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use tokio_rustls::rustls::crypto::aws_lc_rs;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::rustls::{ServerConfig, SupportedCipherSuite, SupportedProtocolVersion, version};
+
+use crate::constants::{
+    ERR_CREATE_TLS_CONFIG, ERR_TLS_CIPHER_UNKNOWN, ERR_TLS_CIPHERS_EMPTY, ERR_TLS_CIPHERS_UNUSABLE,
+    ERR_TLS_VERSION_TOO_OLD, ERR_TLS_VERSION_UNKNOWN,
+};
+
+/// Versions enabled with a TLS 1.2 minimum
+static TLS12_AND_UP: &[&SupportedProtocolVersion] = &[&version::TLS13, &version::TLS12];
+
+/// Versions enabled with a TLS 1.3 minimum
+static TLS13_ONLY: &[&SupportedProtocolVersion] = &[&version::TLS13];
+
+/// Lowest TLS protocol version the server accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TlsMinVersion {
+    /// Accept TLS 1.2 and 1.3
+    #[default]
+    Tls12,
+    /// Accept TLS 1.3 only
+    Tls13,
+}
+
+impl TlsMinVersion {
+    /// Protocol versions enabled at this minimum
+    fn versions(self) -> &'static [&'static SupportedProtocolVersion] {
+        match self {
+            Self::Tls12 => TLS12_AND_UP,
+            Self::Tls13 => TLS13_ONLY,
+        }
+    }
+}
+
+impl fmt::Display for TlsMinVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tls12 => f.write_str("1.2"),
+            Self::Tls13 => f.write_str("1.3"),
+        }
+    }
+}
+
+impl FromStr for TlsMinVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1.2" => Ok(Self::Tls12),
+            "1.3" => Ok(Self::Tls13),
+            "1.0" | "1.1" => Err(format!("{}{}", ERR_TLS_VERSION_TOO_OLD, s)),
+            _ => Err(format!("{}{}", ERR_TLS_VERSION_UNKNOWN, s)),
+        }
+    }
+}
+
+/// Cipher suites the operator restricted the server to
+///
+/// Parsed from a comma-separated list of IANA suite names, e.g.
+/// `TLS13_AES_256_GCM_SHA384,TLS13_CHACHA20_POLY1305_SHA256`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsCipherSuites(Vec<SupportedCipherSuite>);
+
+impl TlsCipherSuites {
+    /// Whether any suite can be negotiated at one of these versions
+    fn usable_with(&self, versions: &[&SupportedProtocolVersion]) -> bool {
+        self.0
+            .iter()
+            .any(|suite| versions.contains(&suite.version()))
+    }
+}
+
+impl fmt::Display for TlsCipherSuites {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self.0.iter().map(|s| suite_name(*s)).collect();
+        f.write_str(&names.join(", "))
+    }
+}
+
+impl FromStr for TlsCipherSuites {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut suites = Vec::new();
+        for name in s.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let suite = aws_lc_rs::DEFAULT_CIPHER_SUITES
+                .iter()
+                .find(|suite| suite_name(**suite).eq_ignore_ascii_case(name))
+                .ok_or_else(|| format!("{}{}", ERR_TLS_CIPHER_UNKNOWN, name))?;
+            if !suites.contains(suite) {
+                suites.push(*suite);
+            }
+        }
+        if suites.is_empty() {
+            return Err(ERR_TLS_CIPHERS_EMPTY.to_string());
+        }
+        Ok(Self(suites))
+    }
+}
+
+/// Protocol versions and cipher suites used to build the TLS server config
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TlsPolicy {
+    min_version: TlsMinVersion,
+    /// `None` uses rustls' default suites
+    cipher_suites: Option<TlsCipherSuites>,
+}
+
+impl TlsPolicy {
+    /// Combine a minimum version and optional suite list
+    ///
+    /// Fails if none of the listed suites can be negotiated at the enabled
+    /// versions (e.g. only TLS 1.2 suites with a TLS 1.3 minimum).
+    pub fn new(
+        min_version: TlsMinVersion,
+        cipher_suites: Option<TlsCipherSuites>,
+    ) -> Result<Self, String> {
+        if let Some(suites) = &cipher_suites
+            && !suites.usable_with(min_version.versions())
+        {
+            return Err(format!("{}{}", ERR_TLS_CIPHERS_UNUSABLE, min_version));
+        }
+        Ok(Self {
+            min_version,
+            cipher_suites,
+        })
+    }
+
+    /// Build a server config for this certificate chain under this policy
+    pub fn server_config(
+        &self,
+        certs: Vec<CertificateDer<'static>>,
+        private_key: PrivateKeyDer<'static>,
+    ) -> Result<ServerConfig, String> {
+        let mut provider = aws_lc_rs::default_provider();
+        if let Some(suites) = &self.cipher_suites {
+            provider.cipher_suites = suites.0.clone();
+        }
+
+        ServerConfig::builder_with_provider(Arc::new(provider))
+            .with_protocol_versions(self.min_version.versions())
+            .map_err(|e| format!("{}{}", ERR_CREATE_TLS_CONFIG, e))?
+            .with_no_client_auth()
+            .with_single_cert(certs, private_key)
+            .map_err(|e| format!("{}{}", ERR_CREATE_TLS_CONFIG, e))
+    }
+}
+
+impl fmt::Display for TlsPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TLS {}+, ciphers: ", self.min_version)?;
+        match &self.cipher_suites {
+            Some(suites) => write!(f, "{}", suites),
+            None => f.write_str("default"),
+        }
+    }
+}
+
+/// IANA name of a cipher suite (e.g. `TLS13_AES_128_GCM_SHA256`)
+fn suite_name(suite: SupportedCipherSuite) -> &'static str {
+    suite.suite().as_str().unwrap_or("unknown")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio_rustls::rustls::pki_types::PrivatePkcs8KeyDer;
+
+    /// Self-signed test certificate and key
+    fn test_cert() -> (Vec<CertificateDer<'static>>, PrivateKeyDer<'static>) {
+        let key_pair = rcgen::KeyPair::generate().unwrap();
+        let cert = rcgen::CertificateParams::new(vec!["localhost".to_string()])
+            .unwrap()
+            .self_signed(&key_pair)
+            .unwrap();
+        let key = PrivatePkcs8KeyDer::from(key_pair.serialize_der()).into();
+        (vec![cert.der().clone()], key)
+    }
+
+    #[test]
+    fn test_parse_min_version() {
+        assert_eq!("1.2".parse::<TlsMinVersion>(), Ok(TlsMinVersion::Tls12));
+        assert_eq!("1.3".parse::<TlsMinVersion>(), Ok(TlsMinVersion::Tls13));
+        assert!("1.1".parse::<TlsMinVersion>().is_err());
+        assert!("1.0".parse::<TlsMinVersion>().is_err());
+        assert!("2".parse::<TlsMinVersion>().is_err());
+    }
+
+    #[test]
+    fn test_parse_cipher_suites() {
+        let suites: TlsCipherSuites = "TLS13_AES_256_GCM_SHA384, tls13_chacha20_poly1305_sha256"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            suites.to_string(),
+            "TLS13_AES_256_GCM_SHA384, TLS13_CHACHA20_POLY1305_SHA256"
+        );
+    }
+
+    #[test]
+    fn test_parse_cipher_suites_rejects_weak_and_unknown() {
+        assert!(
+            "TLS_RSA_WITH_AES_128_CBC_SHA"
+                .parse::<TlsCipherSuites>()
+                .is_err()
+        );
+        assert!(
+            "TLS13_AES_256_GCM_SHA384,RC4"
+                .parse::<TlsCipherSuites>()
+                .is_err()
+        );
+        assert!("".parse::<TlsCipherSuites>().is_err());
+        assert!(" , ".parse::<TlsCipherSuites>().is_err());
+    }
+
+    #[test]
+    fn test_policy_rejects_unusable_suites() {
+        let tls12_only: TlsCipherSuites =
+            "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384".parse().unwrap();
+        assert!(TlsPolicy::new(TlsMinVersion::Tls13, Some(tls12_only.clone())).is_err());
+        assert!(TlsPolicy::new(TlsMinVersion::Tls12, Some(tls12_only)).is_ok());
+    }
+
+    #[test]
+    fn test_policy_builds_server_config() {
+        let policies = [
+            TlsPolicy::default(),
+            TlsPolicy::new(TlsMinVersion::Tls13, None).unwrap(),
+            TlsPolicy::new(
+                TlsMinVersion::Tls13,
+                Some("TLS13_AES_256_GCM_SHA384".parse().unwrap()),
+            )
+            .unwrap(),
+            TlsPolicy::new(
+                TlsMinVersion::Tls12,
+                Some(
+                    "TLS13_AES_128_GCM_SHA256,TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256"
+                        .parse()
+                        .unwrap(),
+                ),
+            )
+            .unwrap(),
+        ];
+
+        for policy in policies {
+            let (certs, key) = test_cert();
+            let config = policy.server_config(certs, key);
+            assert!(config.is_ok(), "{}: {:?}", policy, config.err());
+        }
+    }
+
+    #[test]
+    fn test_policy_display() {
+        assert_eq!(
+            TlsPolicy::default().to_string(),
+            "TLS 1.2+, ciphers: default"
+        );
+        let policy = TlsPolicy::new(
+            TlsMinVersion::Tls13,
+            Some("TLS13_AES_256_GCM_SHA384".parse().unwrap()),
+        )
+        .unwrap();
+        assert_eq!(
+            policy.to_string(),
+            "TLS 1.3+, ciphers: TLS13_AES_256_GCM_SHA384"
+        );
+    }
+}
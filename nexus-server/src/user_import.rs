@@ -0,0 +1,423 @@
+//! Bulk user import from a CSV or JSON file
+//!
+//! `nexusd user import <file>` creates accounts when migrating from another
+//! system. Each record has a username and password, plus optional `hashed`,
+//! `admin`, and `permissions` fields. Plaintext passwords are hashed, while
+//! records marked `hashed` carry an Argon2 hash (e.g. from another Nexus
+//! server) that is stored as-is. Permissions are a comma-separated list such
+//! as `chat_send,chat_receive` and are ignored for admins.
+//!
+//! Files ending in `.json` hold an array of record objects; anything else is
+//! read as CSV with a header row naming the fields. Usernames that already
+//! exist (or repeat an earlier record) are skipped, and invalid records are
+//! reported without stopping the import.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use nexus_common::validators;
+use serde::Deserialize;
+
+use crate::constants::{
+    ERR_IMPORT_CREATE, ERR_IMPORT_HASH_INVALID, ERR_IMPORT_PARSE, ERR_IMPORT_PASSWORD_INVALID,
+    ERR_IMPORT_PERMISSION_UNKNOWN, ERR_IMPORT_READ, ERR_IMPORT_RECORD, ERR_IMPORT_USERNAME_EXISTS,
+    ERR_IMPORT_USERNAME_INVALID, ERR_IMPORT_USERNAME_REPEATED,
+};
+use crate::db::{Permission, Permissions, UserDb, hash_password, is_password_hash};
+
+/// One account as written in the import file
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportRecord {
+    pub username: String,
+    /// Plaintext password, or an Argon2 hash when `hashed` is set
+    pub password: String,
+    #[serde(default)]
+    pub hashed: Option<bool>,
+    #[serde(default)]
+    pub admin: Option<bool>,
+    /// Comma-separated permission names
+    #[serde(default)]
+    pub permissions: Option<String>,
+}
+
+/// Outcome counts of an import
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Accounts created (or that would be, on a dry run)
+    pub created: usize,
+    /// Records whose username already exists or repeats an earlier record
+    pub skipped: usize,
+    /// Records rejected by validation or a database error
+    pub invalid: usize,
+}
+
+/// Why a record wasn't imported
+#[derive(Debug, PartialEq, Eq)]
+enum RecordError {
+    /// The account exists already; not an error in the file
+    Skipped(String),
+    /// The record can't be imported as written
+    Invalid(String),
+}
+
+/// A validated record, ready to be created
+struct NewAccount {
+    username: String,
+    password: String,
+    hashed: bool,
+    is_admin: bool,
+    permissions: Permissions,
+}
+
+/// Read import records from a file (JSON if it ends in `.json`, CSV otherwise)
+pub fn read_records(path: &Path) -> Result<Vec<ImportRecord>, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}{}", ERR_IMPORT_READ, e))?;
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    parse_records(&contents, is_json)
+}
+
+/// Parse import records from JSON (an array of objects) or CSV (with a header row)
+fn parse_records(contents: &str, is_json: bool) -> Result<Vec<ImportRecord>, String> {
+    if is_json {
+        return serde_json::from_str(contents).map_err(|e| format!("{}{}", ERR_IMPORT_PARSE, e));
+    }
+
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::Headers)
+        .from_reader(contents.as_bytes())
+        .deserialize()
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("{}{}", ERR_IMPORT_PARSE, e))
+}
+
+/// Create an account for each record, reporting skipped and invalid records
+///
+/// With `dry_run` set every record is checked but nothing is written.
+pub async fn import_users(
+    user_db: &UserDb,
+    records: Vec<ImportRecord>,
+    dry_run: bool,
+) -> ImportSummary {
+    let mut summary = ImportSummary::default();
+    let mut seen = HashSet::new();
+
+    for (index, record) in records.into_iter().enumerate() {
+        match import_record(user_db, record, &mut seen, dry_run).await {
+            Ok(()) => summary.created += 1,
+            Err(error) => {
+                let reason = match error {
+                    RecordError::Skipped(reason) => {
+                        summary.skipped += 1;
+                        reason
+                    }
+                    RecordError::Invalid(reason) => {
+                        summary.invalid += 1;
+                        reason
+                    }
+                };
+                eprintln!("{}{}: {}", ERR_IMPORT_RECORD, index + 1, reason);
+            }
+        }
+    }
+
+    summary
+}
+
+/// Validate one record and create its account (unless this is a dry run)
+async fn import_record(
+    user_db: &UserDb,
+    record: ImportRecord,
+    seen: &mut HashSet<String>,
+    dry_run: bool,
+) -> Result<(), RecordError> {
+    let account = validate_record(record)?;
+
+    // Usernames are case-insensitive, so "Alice" repeats "alice"
+    if !seen.insert(account.username.to_lowercase()) {
+        return Err(RecordError::Skipped(format!(
+            "{}{}",
+            ERR_IMPORT_USERNAME_REPEATED, account.username
+        )));
+    }
+
+    match user_db.get_user_by_username(&account.username).await {
+        Ok(None) => {}
+        Ok(Some(_)) => {
+            return Err(RecordError::Skipped(format!(
+                "{}{}",
+                ERR_IMPORT_USERNAME_EXISTS, account.username
+            )));
+        }
+        Err(e) => return Err(RecordError::Invalid(format!("{}{}", ERR_IMPORT_CREATE, e))),
+    }
+
+    if dry_run {
+        return Ok(());
+    }
+
+    let hashed_password = if account.hashed {
+        account.password
+    } else {
+        hash_password(&account.password)
+            .map_err(|e| RecordError::Invalid(format!("{}{}", ERR_IMPORT_CREATE, e)))?
+    };
+
+    user_db
+        .create_user(
+            &account.username,
+            &hashed_password,
+            account.is_admin,
+            true,
+            &account.permissions,
+        )
+        .await
+        .map_err(|e| RecordError::Invalid(format!("{}{}", ERR_IMPORT_CREATE, e)))?;
+
+    Ok(())
+}
+
+/// Check a record's fields without touching the database
+fn validate_record(record: ImportRecord) -> Result<NewAccount, RecordError> {
+    let username = record.username.trim().to_string();
+    if validators::validate_username(&username).is_err() {
+        return Err(RecordError::Invalid(format!(
+            "{}{}",
+            ERR_IMPORT_USERNAME_INVALID, username
+        )));
+    }
+
+    let hashed = record.hashed.unwrap_or(false);
+    if hashed {
+        if !is_password_hash(&record.password) {
+            return Err(RecordError::Invalid(ERR_IMPORT_HASH_INVALID.to_string()));
+        }
+    } else if validators::validate_password(&record.password).is_err() {
+        return Err(RecordError::Invalid(
+            ERR_IMPORT_PASSWORD_INVALID.to_string(),
+        ));
+    }
+
+    let mut permissions = Permissions::new();
+    for name in record
+        .permissions
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+    {
+        let permission = Permission::parse(name).ok_or_else(|| {
+            RecordError::Invalid(format!("{}{}", ERR_IMPORT_PERMISSION_UNKNOWN, name))
+        })?;
+        permissions.add(permission);
+    }
+
+    Ok(NewAccount {
+        username,
+        password: record.password,
+        hashed,
+        is_admin: record.admin.unwrap_or(false),
+        permissions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::verify_password;
+
+    const CSV: &str = "\
+username,password,admin,permissions
+alice,alicepass,false,\"chat_send, chat_receive\"
+bob,bobpass,true,
+";
+
+    fn record(username: &str, password: &str) -> ImportRecord {
+        ImportRecord {
+            username: username.to_string(),
+            password: password.to_string(),
+            hashed: None,
+            admin: None,
+            permissions: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let records = parse_records(CSV, false).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].username, "alice");
+        assert_eq!(records[0].admin, Some(false));
+        assert_eq!(
+            records[0].permissions.as_deref(),
+            Some("chat_send, chat_receive")
+        );
+        assert_eq!(records[1].admin, Some(true));
+        assert_eq!(records[1].permissions, None);
+        assert_eq!(records[1].hashed, None);
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let json = r#"[
+            {"username": "alice", "password": "alicepass", "permissions": "chat_send"},
+            {"username": "bob", "password": "$argon2id$...", "hashed": true, "admin": true}
+        ]"#;
+        let records = parse_records(json, true).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].permissions.as_deref(), Some("chat_send"));
+        assert_eq!(records[1].hashed, Some(true));
+        assert_eq!(records[1].admin, Some(true));
+
+        assert!(parse_records("{not json", true).is_err());
+        assert!(parse_records("username\nalice\n", false).is_err());
+    }
+
+    #[test]
+    fn test_validate_record() {
+        assert!(validate_record(record("alice", "alicepass")).is_ok());
+
+        assert!(matches!(
+            validate_record(record("bad name!", "alicepass")),
+            Err(RecordError::Invalid(_))
+        ));
+        assert_eq!(
+            validate_record(record("alice", "")).err(),
+            Some(RecordError::Invalid(
+                ERR_IMPORT_PASSWORD_INVALID.to_string()
+            ))
+        );
+
+        let not_a_hash = ImportRecord {
+            hashed: Some(true),
+            ..record("alice", "plaintext")
+        };
+        assert_eq!(
+            validate_record(not_a_hash).err(),
+            Some(RecordError::Invalid(ERR_IMPORT_HASH_INVALID.to_string()))
+        );
+
+        let unknown_permission = ImportRecord {
+            permissions: Some("chat_send,fly".to_string()),
+            ..record("alice", "alicepass")
+        };
+        assert_eq!(
+            validate_record(unknown_permission).err(),
+            Some(RecordError::Invalid(format!(
+                "{}fly",
+                ERR_IMPORT_PERMISSION_UNKNOWN
+            )))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_creates_accounts() {
+        let user_db = UserDb::new(create_test_db().await);
+        let hash = hash_password("carolpass").unwrap();
+        let records = vec![
+            ImportRecord {
+                permissions: Some("chat_send".to_string()),
+                ..record("alice", "alicepass")
+            },
+            ImportRecord {
+                admin: Some(true),
+                ..record("bob", "bobpass")
+            },
+            ImportRecord {
+                hashed: Some(true),
+                ..record("carol", &hash)
+            },
+        ];
+
+        let summary = import_users(&user_db, records, false).await;
+        assert_eq!(
+            summary,
+            ImportSummary {
+                created: 3,
+                skipped: 0,
+                invalid: 0
+            }
+        );
+
+        let alice = user_db
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!alice.is_admin);
+        assert!(alice.enabled);
+        assert!(verify_password("alicepass", &alice.hashed_password).unwrap());
+        let permissions = user_db.get_user_permissions(alice.id).await.unwrap();
+        assert_eq!(permissions.to_vec(), vec![Permission::ChatSend]);
+
+        let bob = user_db.get_user_by_username("bob").await.unwrap().unwrap();
+        assert!(bob.is_admin);
+
+        // Pre-hashed passwords are stored as given
+        let carol = user_db
+            .get_user_by_username("carol")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(carol.hashed_password, hash);
+        assert!(verify_password("carolpass", &carol.hashed_password).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_import_skips_existing_and_reports_invalid() {
+        let user_db = UserDb::new(create_test_db().await);
+        import_users(&user_db, vec![record("alice", "alicepass")], false).await;
+
+        let records = vec![
+            record("Alice", "otherpass"),
+            record("bob", "bobpass"),
+            record("BOB", "bobpass"),
+            record("carol", ""),
+        ];
+        let summary = import_users(&user_db, records, false).await;
+        assert_eq!(
+            summary,
+            ImportSummary {
+                created: 1,
+                skipped: 2,
+                invalid: 1
+            }
+        );
+
+        // The existing account is untouched
+        let alice = user_db
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(verify_password("alicepass", &alice.hashed_password).unwrap());
+        assert!(
+            user_db
+                .get_user_by_username("carol")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_creates_nothing() {
+        let user_db = UserDb::new(create_test_db().await);
+        let records = vec![record("alice", "alicepass"), record("alice", "alicepass")];
+
+        let summary = import_users(&user_db, records, true).await;
+        assert_eq!(
+            summary,
+            ImportSummary {
+                created: 1,
+                skipped: 1,
+                invalid: 0
+            }
+        );
+        assert!(user_db.get_all_users().await.unwrap().is_empty());
+    }
+}
@@ -24,6 +24,28 @@ impl UserManager {
         self.remove_disconnected(disconnected, user_db).await;
     }
 
+    /// Broadcast a message type added after protocol 0.5 to every session that
+    /// understands it
+    ///
+    /// Sessions on older clients are skipped, since they disconnect on message
+    /// types they don't know.
+    ///
+    /// Automatically removes users whose channels have closed (disconnected connections).
+    pub async fn broadcast_extended(&self, message: ServerMessage, user_db: &UserDb) {
+        let mut disconnected = Vec::new();
+
+        {
+            let users = self.users.read().await;
+            for user in users.values() {
+                if !user.legacy_protocol && user.tx.send((message.clone(), None)).is_err() {
+                    disconnected.push(user.session_id);
+                }
+            }
+        }
+
+        self.remove_disconnected(disconnected, user_db).await;
+    }
+
     /// Broadcast a message to all users with a specific feature and permission
     ///
     /// This method checks both that the user has requested the feature (client preference)
@@ -92,10 +114,64 @@ impl UserManager {
         self.remove_disconnected(disconnected, user_db).await;
     }
 
+    /// Send a message to an account's other sessions (by database user ID)
+    ///
+    /// Skips `except_session_id` so the session that triggered the message
+    /// doesn't receive it. Sessions on clients older than the extended
+    /// protocol are skipped too, as the messages sent this way (e.g. new
+    /// login notices) were added after protocol 0.5.
+    ///
+    /// Automatically removes users whose channels have closed (disconnected connections).
+    pub async fn broadcast_to_other_sessions(
+        &self,
+        db_user_id: i64,
+        except_session_id: u32,
+        message: &ServerMessage,
+        user_db: &UserDb,
+    ) {
+        let mut disconnected = Vec::new();
+
+        {
+            let users = self.users.read().await;
+            for user in users.values() {
+                if user.db_user_id == db_user_id
+                    && user.session_id != except_session_id
+                    && !user.legacy_protocol
+                    && user.tx.send((message.clone(), None)).is_err()
+                {
+                    disconnected.push(user.session_id);
+                }
+            }
+        }
+
+        self.remove_disconnected(disconnected, user_db).await;
+    }
+
+    /// Send each listed session its own message (e.g. its envelope of an
+    /// encrypted private message), skipping sessions that no longer exist
+    ///
+    /// Automatically removes users whose channels have closed (disconnected connections).
+    pub async fn send_to_sessions(&self, messages: Vec<(u32, ServerMessage)>, user_db: &UserDb) {
+        let mut disconnected = Vec::new();
+
+        {
+            let users = self.users.read().await;
+            for (session_id, message) in messages {
+                if let Some(user) = users.get(&session_id)
+                    && user.tx.send((message, None)).is_err()
+                {
+                    disconnected.push(session_id);
+                }
+            }
+        }
+
+        self.remove_disconnected(disconnected, user_db).await;
+    }
+
     /// Broadcast a message to all users with a specific permission
     ///
     /// This method checks that users have the required permission (server enforcement).
-    /// Used for broadcasting events like topic updates to users who have permission to see them.
+    /// Used for broadcasting events like broadcasts limited to a permission.
     ///
     /// Automatically removes users whose channels have closed (disconnected connections).
     pub async fn broadcast_to_permission(
@@ -103,14 +179,29 @@ impl UserManager {
         message: ServerMessage,
         user_db: &UserDb,
         required_permission: Permission,
+    ) {
+        self.broadcast_to_any_permission(message, user_db, &[required_permission])
+            .await;
+    }
+
+    /// Broadcast a message to all users with at least one of several permissions
+    ///
+    /// Used for broadcasting events like topic updates to users who have permission to see them.
+    ///
+    /// Automatically removes users whose channels have closed (disconnected connections).
+    pub async fn broadcast_to_any_permission(
+        &self,
+        message: ServerMessage,
+        user_db: &UserDb,
+        permissions: &[Permission],
     ) {
         let mut disconnected = Vec::new();
 
         {
             let users = self.users.read().await;
             for user in users.values() {
-                // Check if user has the required permission (uses cached permissions, admin bypass)
-                if !user.has_permission(required_permission) {
+                // Check if user has one of the permissions (uses cached permissions, admin bypass)
+                if !permissions.iter().any(|&p| user.has_permission(p)) {
                     continue;
                 }
 
@@ -131,6 +222,9 @@ impl UserManager {
     ///
     /// Optionally excludes a specific session_id (e.g., to not send UserConnected to the connecting user).
     ///
+    /// Recipients without user_info permission get a copy with the user's
+    /// notable permissions removed.
+    ///
     /// Automatically removes users whose channels have closed (disconnected connections).
     pub async fn broadcast_user_event(
         &self,
@@ -139,6 +233,7 @@ impl UserManager {
         exclude_session_id: Option<u32>,
     ) {
         let mut disconnected = Vec::new();
+        let redacted = without_permissions(&message);
 
         {
             let users = self.users.read().await;
@@ -155,8 +250,14 @@ impl UserManager {
                     continue;
                 }
 
+                let outgoing = if user.has_permission(Permission::UserInfo) {
+                    message.clone()
+                } else {
+                    redacted.clone()
+                };
+
                 // Send message to this user
-                if user.tx.send((message.clone(), None)).is_err() {
+                if user.tx.send((outgoing, None)).is_err() {
                     disconnected.push(user.session_id);
                 }
             }
@@ -176,6 +277,7 @@ impl UserManager {
         version: String,
         max_connections_per_ip: u32,
         image: String,
+        chat_slow_mode: u32,
     ) {
         let users = self.users.read().await;
         for user in users.values() {
@@ -190,6 +292,12 @@ impl UserManager {
                     None
                 },
                 image: Some(image.clone()),
+                chat_slow_mode: Some(chat_slow_mode),
+                // Count only changes with connections, not configuration
+                user_count: None,
+                // Length limits are pushed separately with LimitsUpdated
+                max_message_length: None,
+                max_topic_length: None,
             };
 
             let message = ServerMessage::ServerInfoUpdated { server_info };
@@ -199,3 +307,99 @@ impl UserManager {
         }
     }
 }
+
+/// Copy of a user event with the user's notable permissions removed
+fn without_permissions(message: &ServerMessage) -> ServerMessage {
+    let mut message = message.clone();
+    if let ServerMessage::UserConnected { user } | ServerMessage::UserUpdated { user, .. } =
+        &mut message
+    {
+        user.permissions = None;
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::DEFAULT_OUTGOING_QUEUE_CAPACITY;
+    use crate::handlers::testing::create_test_context;
+    use crate::users::user::{NewSessionParams, session_channel};
+    use nexus_common::protocol::UserInfo;
+
+    #[test]
+    fn test_without_permissions_redacts_user_events() {
+        let user = UserInfo {
+            username: "bob".to_string(),
+            login_time: 0,
+            is_admin: false,
+            is_moderator: true,
+            away: false,
+            is_guest: false,
+            permissions: Some(vec!["chat_moderate".to_string()]),
+            session_ids: vec![1],
+            locale: "en".to_string(),
+            avatar: None,
+        };
+
+        let redacted = without_permissions(&ServerMessage::UserConnected { user: user.clone() });
+        let ServerMessage::UserConnected { user: redacted } = redacted else {
+            panic!("Expected UserConnected");
+        };
+        assert_eq!(redacted.permissions, None);
+        assert!(redacted.is_moderator);
+
+        let redacted = without_permissions(&ServerMessage::UserUpdated {
+            previous_username: "bob".to_string(),
+            user,
+        });
+        let ServerMessage::UserUpdated { user: redacted, .. } = redacted else {
+            panic!("Expected UserUpdated");
+        };
+        assert_eq!(redacted.permissions, None);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_extended_skips_legacy_sessions() {
+        let test_ctx = create_test_context().await;
+        let manager = &test_ctx.user_manager;
+        let mut receivers = Vec::new();
+        for legacy_protocol in [false, true] {
+            let (tx, rx) = session_channel(DEFAULT_OUTGOING_QUEUE_CAPACITY);
+            manager
+                .add_user(NewSessionParams {
+                    session_id: 0,
+                    db_user_id: 1,
+                    username: "alice".to_string(),
+                    is_admin: false,
+                    permissions: Default::default(),
+                    address: "127.0.0.1:1234".parse().unwrap(),
+                    source: "loopback".to_string(),
+                    created_at: 0,
+                    tx,
+                    features: vec![],
+                    locale: "en".to_string(),
+                    avatar: None,
+                    is_guest: false,
+                    legacy_protocol,
+                })
+                .await;
+            receivers.push(rx);
+        }
+
+        manager
+            .broadcast_extended(
+                ServerMessage::ServerAnnouncement {
+                    message: "Hello".to_string(),
+                },
+                &test_ctx.db.users,
+            )
+            .await;
+
+        assert!(matches!(
+            receivers[0].messages.try_recv(),
+            Ok((ServerMessage::ServerAnnouncement { .. }, _))
+        ));
+        assert!(receivers[1].messages.try_recv().is_err());
+    }
+}
@@ -0,0 +1,229 @@
+//! Recent chat message tracking for UserManager
+
+use std::collections::VecDeque;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use super::UserManager;
+use crate::constants::RECENT_CHAT_MESSAGES_CAPACITY;
+
+/// A chat message that was recently broadcast to #server
+#[derive(Debug, Clone)]
+pub struct RecentChatMessage {
+    /// Server-assigned message id
+    pub message_id: u64,
+    /// Account that sent the message (a guest's is unique to its session)
+    pub db_user_id: i64,
+    /// Username who sent the message
+    pub username: String,
+    /// The message text (replaced when the sender edits it)
+    pub message: String,
+    /// When the message was sent (used for the edit window)
+    pub sent_at: Instant,
+}
+
+/// Bounded buffer of recent chat messages plus the next message id
+///
+/// The id sequence is shared by chat and private messages.
+#[derive(Debug)]
+pub struct RecentChat {
+    next_id: u64,
+    messages: VecDeque<RecentChatMessage>,
+}
+
+impl RecentChat {
+    /// Create an empty buffer
+    ///
+    /// Ids start at the current time in microseconds so they keep increasing
+    /// across restarts and never collide with ids of already pinned messages.
+    pub fn new() -> Self {
+        let next_id = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(1);
+
+        Self {
+            next_id,
+            messages: VecDeque::with_capacity(RECENT_CHAT_MESSAGES_CAPACITY),
+        }
+    }
+}
+
+impl Default for RecentChat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecentChat {
+    /// Take the next message id
+    fn allocate_id(&mut self) -> u64 {
+        let message_id = self.next_id;
+        self.next_id += 1;
+        message_id
+    }
+}
+
+impl UserManager {
+    /// Assign an id to a message that isn't remembered (private messages)
+    pub async fn next_message_id(&self) -> u64 {
+        self.recent_chat.write().await.allocate_id()
+    }
+
+    /// Assign an id to a chat message and remember it so it can be pinned,
+    /// edited, or deleted later
+    ///
+    /// Returns the assigned message id. Only the most recent
+    /// `RECENT_CHAT_MESSAGES_CAPACITY` messages are kept.
+    pub async fn record_chat_message(&self, db_user_id: i64, username: &str, message: &str) -> u64 {
+        let mut recent = self.recent_chat.write().await;
+        let message_id = recent.allocate_id();
+
+        if recent.messages.len() >= RECENT_CHAT_MESSAGES_CAPACITY {
+            recent.messages.pop_front();
+        }
+        recent.messages.push_back(RecentChatMessage {
+            message_id,
+            db_user_id,
+            username: username.to_string(),
+            message: message.to_string(),
+            sent_at: Instant::now(),
+        });
+
+        message_id
+    }
+
+    /// Look up a recent chat message by id
+    pub async fn get_recent_chat_message(&self, message_id: u64) -> Option<RecentChatMessage> {
+        let recent = self.recent_chat.read().await;
+        recent
+            .messages
+            .iter()
+            .find(|m| m.message_id == message_id)
+            .cloned()
+    }
+
+    /// Replace the text of a recent chat message
+    ///
+    /// Returns false if the message is no longer remembered.
+    pub async fn edit_recent_chat_message(&self, message_id: u64, message: &str) -> bool {
+        let mut recent = self.recent_chat.write().await;
+        match recent
+            .messages
+            .iter_mut()
+            .find(|m| m.message_id == message_id)
+        {
+            Some(recent_message) => {
+                recent_message.message = message.to_string();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forget a recent chat message so it can no longer be pinned or edited
+    ///
+    /// Returns false if the message was not remembered.
+    pub async fn remove_recent_chat_message(&self, message_id: u64) -> bool {
+        let mut recent = self.recent_chat.write().await;
+        let before = recent.messages.len();
+        recent.messages.retain(|m| m.message_id != message_id);
+        recent.messages.len() != before
+    }
+
+    /// Pretend a recent chat message was sent `age` ago
+    #[cfg(test)]
+    pub async fn backdate_recent_chat_message(&self, message_id: u64, age: std::time::Duration) {
+        let mut recent = self.recent_chat.write().await;
+        if let Some(recent_message) = recent
+            .messages
+            .iter_mut()
+            .find(|m| m.message_id == message_id)
+        {
+            recent_message.sent_at = Instant::now()
+                .checked_sub(age)
+                .expect("monotonic clock should be older than the backdate");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_record_chat_message_assigns_sequential_ids() {
+        let manager = UserManager::new();
+
+        let first = manager.record_chat_message(1, "alice", "hello").await;
+        let second = manager.record_chat_message(2, "bob", "hi").await;
+        assert_eq!(second, first + 1);
+
+        let found = manager.get_recent_chat_message(first).await.unwrap();
+        assert_eq!(found.db_user_id, 1);
+        assert_eq!(found.username, "alice");
+        assert_eq!(found.message, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_message_ids_are_unique_and_increasing() {
+        let manager = Arc::new(UserManager::new());
+
+        // Chat and private messages share one sequence
+        let chat = manager.record_chat_message(1, "alice", "hello").await;
+        let private = manager.next_message_id().await;
+        let chat_again = manager.record_chat_message(2, "bob", "hi").await;
+        assert!(chat < private && private < chat_again);
+
+        let tasks: Vec<_> = (0..50)
+            .map(|i| {
+                let manager = manager.clone();
+                tokio::spawn(async move {
+                    if i % 2 == 0 {
+                        manager.next_message_id().await
+                    } else {
+                        manager.record_chat_message(1, "alice", "concurrent").await
+                    }
+                })
+            })
+            .collect();
+        let mut ids = vec![];
+        for task in tasks {
+            ids.push(task.await.unwrap());
+        }
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), 50);
+        assert!(ids[0] > chat_again);
+    }
+
+    #[tokio::test]
+    async fn test_recent_chat_drops_oldest_messages() {
+        let manager = UserManager::new();
+
+        let oldest = manager.record_chat_message(1, "alice", "first").await;
+        for i in 0..RECENT_CHAT_MESSAGES_CAPACITY {
+            manager
+                .record_chat_message(1, "alice", &i.to_string())
+                .await;
+        }
+
+        assert!(manager.get_recent_chat_message(oldest).await.is_none());
+        assert!(manager.get_recent_chat_message(oldest + 1).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_edit_and_remove_recent_chat_message() {
+        let manager = UserManager::new();
+
+        let message_id = manager.record_chat_message(1, "alice", "helo").await;
+        assert!(manager.edit_recent_chat_message(message_id, "hello").await);
+        let found = manager.get_recent_chat_message(message_id).await.unwrap();
+        assert_eq!(found.message, "hello");
+
+        assert!(manager.remove_recent_chat_message(message_id).await);
+        assert!(manager.get_recent_chat_message(message_id).await.is_none());
+        assert!(!manager.remove_recent_chat_message(message_id).await);
+        assert!(!manager.edit_recent_chat_message(message_id, "again").await);
+    }
+}
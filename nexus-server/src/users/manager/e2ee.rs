@@ -0,0 +1,264 @@
+//! End-to-end encryption key tracking for UserManager
+//!
+//! Each session that supports encrypted private messages announces an X25519
+//! public key. Sessions ask for the keys of the users they talk to and are
+//! sent those keys again whenever the users' sessions change, so senders can
+//! seal a copy of each message for every session that will receive it. Only
+//! online users are watched; once a user's last session ends their watchers
+//! are sent no keys and stop watching, and ask again when they next need them.
+
+use std::collections::HashMap;
+
+use nexus_common::protocol::{E2eeSessionKey, ServerMessage};
+use nexus_common::validators::MAX_E2EE_ENVELOPES;
+
+use super::UserManager;
+use crate::constants::MAX_E2EE_WATCHES_PER_SESSION;
+use crate::users::user::UserSession;
+
+impl UserManager {
+    /// Record a session's public key and watch its own account's keys
+    ///
+    /// Returns the session's username, or `None` if the session doesn't exist.
+    pub async fn set_e2ee_key(&self, session_id: u32, public_key: String) -> Option<String> {
+        let username = {
+            let mut users = self.users.write().await;
+            let user = users.get_mut(&session_id)?;
+            user.e2ee_public_key = Some(public_key);
+            user.e2ee_watches.insert(user.username.to_lowercase());
+            user.username.clone()
+        };
+        self.push_e2ee_keys(&username).await;
+        Some(username)
+    }
+
+    /// Have a session sent a user's keys whenever they change
+    ///
+    /// Returns `false` if the session doesn't exist, the user isn't online,
+    /// or the session already watches [`MAX_E2EE_WATCHES_PER_SESSION`] other
+    /// users.
+    pub async fn watch_e2ee_keys(&self, session_id: u32, username: &str) -> bool {
+        let mut users = self.users.write().await;
+        let username_lower = username.to_lowercase();
+        if !users
+            .values()
+            .any(|user| user.username.to_lowercase() == username_lower)
+        {
+            return false;
+        }
+        let Some(user) = users.get_mut(&session_id) else {
+            return false;
+        };
+        if user.e2ee_watches.contains(&username_lower) {
+            return true;
+        }
+        // The session's own account is watched on top of the limit
+        let own = user.username.to_lowercase();
+        let others = user.e2ee_watches.iter().filter(|w| **w != own).count();
+        if others >= MAX_E2EE_WATCHES_PER_SESSION {
+            return false;
+        }
+        user.e2ee_watches.insert(username_lower);
+        true
+    }
+
+    /// Current keys of a user's sessions (case-insensitive)
+    ///
+    /// Empty unless every session has announced a key and all of them fit
+    /// in one message alongside the sender's, since a session without an
+    /// envelope couldn't show the message.
+    pub async fn e2ee_keys(&self, username: &str) -> Vec<E2eeSessionKey> {
+        let users = self.users.read().await;
+        e2ee_keys_for(&users, &username.to_lowercase())
+    }
+
+    /// Send a user's current keys to every session watching them
+    ///
+    /// Called whenever the user's sessions change. When the user has gone
+    /// offline, the watchers are sent no keys and stop watching. Send failures
+    /// are left to the connections' own cleanup.
+    pub(super) async fn push_e2ee_keys(&self, username: &str) {
+        let username_lower = username.to_lowercase();
+        let mut users = self.users.write().await;
+        let offline = !users
+            .values()
+            .any(|user| user.username.to_lowercase() == username_lower);
+        let message = ServerMessage::E2eeKeys {
+            username: username.to_string(),
+            keys: e2ee_keys_for(&users, &username_lower),
+        };
+        for user in users.values_mut() {
+            if user.e2ee_watches.contains(&username_lower) {
+                let _ = user.tx.send((message.clone(), None));
+                if offline {
+                    user.e2ee_watches.remove(&username_lower);
+                }
+            }
+        }
+    }
+}
+
+/// Keys of the sessions of `username_lower`, or none if any session lacks one
+fn e2ee_keys_for(users: &HashMap<u32, UserSession>, username_lower: &str) -> Vec<E2eeSessionKey> {
+    let sessions: Vec<_> = users
+        .values()
+        .filter(|user| user.username.to_lowercase() == username_lower)
+        .collect();
+    if sessions.len() >= MAX_E2EE_ENVELOPES {
+        return Vec::new();
+    }
+
+    let mut keys = Vec::with_capacity(sessions.len());
+    for user in sessions {
+        let Some(public_key) = &user.e2ee_public_key else {
+            return Vec::new();
+        };
+        keys.push(E2eeSessionKey {
+            session_id: user.session_id,
+            public_key: public_key.clone(),
+        });
+    }
+    keys.sort_by_key(|key| key.session_id);
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::DEFAULT_OUTGOING_QUEUE_CAPACITY;
+    use crate::users::user::{NewSessionParams, SessionReceiver, session_channel};
+
+    fn key(fill: char) -> String {
+        format!("{}=", fill.to_string().repeat(43))
+    }
+
+    async fn add_session(manager: &UserManager, username: &str) -> (u32, SessionReceiver) {
+        let (tx, rx) = session_channel(DEFAULT_OUTGOING_QUEUE_CAPACITY);
+        let session_id = manager
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: 1,
+                username: username.to_string(),
+                is_admin: false,
+                permissions: Default::default(),
+                address: "127.0.0.1:1234".parse().unwrap(),
+                source: "loopback".to_string(),
+                created_at: 0,
+                tx,
+                features: vec![],
+                locale: "en".to_string(),
+                avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
+            })
+            .await;
+        (session_id, rx)
+    }
+
+    fn next_keys(rx: &mut SessionReceiver) -> (String, Vec<E2eeSessionKey>) {
+        match rx.messages.try_recv() {
+            Ok((ServerMessage::E2eeKeys { username, keys }, _)) => (username, keys),
+            other => panic!("Expected E2eeKeys, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keys_require_every_session() {
+        let manager = UserManager::new();
+        let (first, _rx1) = add_session(&manager, "alice").await;
+        let (second, _rx2) = add_session(&manager, "alice").await;
+
+        manager.set_e2ee_key(first, key('A')).await;
+        assert!(manager.e2ee_keys("alice").await.is_empty());
+
+        manager.set_e2ee_key(second, key('B')).await;
+        let keys = manager.e2ee_keys("ALICE").await;
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].session_id, first);
+        assert_eq!(keys[1].public_key, key('B'));
+    }
+
+    #[tokio::test]
+    async fn test_watchers_follow_session_changes() {
+        let manager = UserManager::new();
+        let (watcher, mut watcher_rx) = add_session(&manager, "bob").await;
+        let (alice, _alice_rx) = add_session(&manager, "alice").await;
+
+        // Announcing a key watches the session's own account
+        manager.set_e2ee_key(watcher, key('W')).await;
+        let (username, keys) = next_keys(&mut watcher_rx);
+        assert_eq!(username, "bob");
+        assert_eq!(keys.len(), 1);
+
+        assert!(manager.watch_e2ee_keys(watcher, "Alice").await);
+        manager.set_e2ee_key(alice, key('A')).await;
+        let (username, keys) = next_keys(&mut watcher_rx);
+        assert_eq!(username, "alice");
+        assert_eq!(keys.len(), 1);
+
+        // A second session without a key means alice can't be sent encrypted messages
+        let (_, _alice_rx2) = add_session(&manager, "alice").await;
+        assert!(next_keys(&mut watcher_rx).1.is_empty());
+
+        // Unwatched users' changes aren't sent
+        add_session(&manager, "carol").await;
+        assert!(watcher_rx.messages.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_watches_are_capped_per_session() {
+        let manager = UserManager::new();
+        let (watcher, _watcher_rx) = add_session(&manager, "bob").await;
+        manager.set_e2ee_key(watcher, key('W')).await;
+
+        for i in 0..MAX_E2EE_WATCHES_PER_SESSION {
+            add_session(&manager, &format!("user{i}")).await;
+            assert!(manager.watch_e2ee_keys(watcher, &format!("user{i}")).await);
+        }
+        add_session(&manager, "alice").await;
+        assert!(!manager.watch_e2ee_keys(watcher, "alice").await);
+
+        // Users already watched are still accepted
+        assert!(manager.watch_e2ee_keys(watcher, "USER0").await);
+        let user = manager.get_user_by_session_id(watcher).await.unwrap();
+        assert_eq!(user.e2ee_watches.len(), MAX_E2EE_WATCHES_PER_SESSION + 1);
+    }
+
+    #[tokio::test]
+    async fn test_removed_session_updates_watchers() {
+        let manager = UserManager::new();
+        let (watcher, mut watcher_rx) = add_session(&manager, "bob").await;
+        let (alice, _alice_rx) = add_session(&manager, "alice").await;
+        manager.set_e2ee_key(alice, key('A')).await;
+        manager.watch_e2ee_keys(watcher, "alice").await;
+
+        manager.remove_user(alice).await;
+        assert_eq!(next_keys(&mut watcher_rx), ("alice".to_string(), vec![]));
+    }
+
+    #[tokio::test]
+    async fn test_offline_users_are_unwatched() {
+        let manager = UserManager::new();
+        let (watcher, mut watcher_rx) = add_session(&manager, "bob").await;
+        assert!(!manager.watch_e2ee_keys(watcher, "alice").await);
+
+        let (alice, _alice_rx) = add_session(&manager, "alice").await;
+        let (alice2, _alice_rx2) = add_session(&manager, "alice").await;
+        assert!(manager.watch_e2ee_keys(watcher, "alice").await);
+
+        // Still watched while one of alice's sessions is online
+        manager.remove_user(alice).await;
+        next_keys(&mut watcher_rx);
+        let user = manager.get_user_by_session_id(watcher).await.unwrap();
+        assert!(user.e2ee_watches.contains("alice"));
+
+        manager.remove_user(alice2).await;
+        assert_eq!(next_keys(&mut watcher_rx), ("alice".to_string(), vec![]));
+        let user = manager.get_user_by_session_id(watcher).await.unwrap();
+        assert!(!user.e2ee_watches.contains("alice"));
+
+        // Logging back in isn't pushed; the watcher asks again
+        add_session(&manager, "alice").await;
+        assert!(watcher_rx.messages.try_recv().is_err());
+    }
+}
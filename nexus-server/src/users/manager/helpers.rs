@@ -40,6 +40,10 @@ impl UserManager {
             }
         }
 
+        for (_, username) in &users_to_remove {
+            self.push_e2ee_keys(username).await;
+        }
+
         // Broadcast disconnection to all remaining clients who have user_list permission
         // We send directly instead of using broadcast_user_event() to avoid infinite recursion
         // at the type level (even though runtime would be safe since users are already removed)
@@ -1,13 +1,18 @@
 //! User manager for tracking connected users
 
 mod broadcasts;
+mod chat;
+mod e2ee;
 mod helpers;
 mod mutations;
 mod queries;
 
 use crate::users::user::UserSession;
+use chat::RecentChat;
+pub use chat::RecentChatMessage;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::RwLock;
 
 /// Manages all connected users
@@ -15,6 +20,10 @@ use tokio::sync::RwLock;
 pub struct UserManager {
     pub(super) users: Arc<RwLock<HashMap<u32, UserSession>>>,
     pub(super) next_id: Arc<RwLock<u32>>,
+    pub(super) recent_chat: Arc<RwLock<RecentChat>>,
+    /// When each user (lowercased username) last sent a server chat message,
+    /// shared by all their sessions so slow mode can't be dodged by reconnecting
+    pub(super) last_chat_messages: Arc<RwLock<HashMap<String, Instant>>>,
 }
 
 impl UserManager {
@@ -23,6 +32,8 @@ impl UserManager {
         Self {
             users: Arc::new(RwLock::new(HashMap::new())),
             next_id: Arc::new(RwLock::new(1)),
+            recent_chat: Arc::new(RwLock::new(RecentChat::new())),
+            last_chat_messages: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
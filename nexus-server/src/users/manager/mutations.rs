@@ -1,46 +1,113 @@
 //! Mutation methods for UserManager
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 use super::UserManager;
-use crate::db::Permission;
-use crate::users::user::{NewSessionParams, UserSession};
+use crate::db::{Permission, Permissions, UserDb};
+use crate::users::user::{AddSessionError, NewSessionParams, UserSession};
 
 impl UserManager {
     /// Add a new user and return their assigned session ID
-    pub async fn add_user(&self, mut params: NewSessionParams) -> u32 {
+    #[allow(dead_code)] // Used in tests
+    pub async fn add_user(&self, params: NewSessionParams) -> u32 {
+        self.add_session(params, None)
+            .await
+            .expect("sessions without a limit are always added")
+    }
+
+    /// Add a new session and return its assigned session ID
+    ///
+    /// A guest is refused while its name is online, and a limited account
+    /// once it has `max_sessions`. Both are checked and the session inserted
+    /// under one lock, so concurrent logins can't slip past either check.
+    pub async fn add_session(
+        &self,
+        mut params: NewSessionParams,
+        max_sessions: Option<usize>,
+    ) -> Result<u32, AddSessionError> {
+        let mut users = self.users.write().await;
+        if params.is_guest {
+            let username_lower = params.username.to_lowercase();
+            if users
+                .values()
+                .any(|user| user.username.to_lowercase() == username_lower)
+            {
+                return Err(AddSessionError::GuestNameInUse);
+            }
+        }
+        if let Some(max_sessions) = max_sessions
+            && users
+                .values()
+                .filter(|user| user.db_user_id == params.db_user_id)
+                .count()
+                >= max_sessions
+        {
+            return Err(AddSessionError::SessionLimit);
+        }
+
         let mut next_id = self.next_id.write().await;
         let session_id = *next_id;
         *next_id += 1;
         drop(next_id);
 
         params.session_id = session_id;
+        if params.is_guest {
+            params.db_user_id = -i64::from(session_id);
+        }
         let user = UserSession::new(params);
-        let mut users = self.users.write().await;
+        let username = user.username.clone();
         users.insert(session_id, user);
+        drop(users);
 
-        session_id
+        // A new session has no key yet, so its account's keys change
+        self.push_e2ee_keys(&username).await;
+        Ok(session_id)
     }
 
     /// Remove a user by session ID
     pub async fn remove_user(&self, session_id: u32) -> Option<UserSession> {
-        let mut users = self.users.write().await;
-        users.remove(&session_id)
+        let removed = self.users.write().await.remove(&session_id);
+        if let Some(user) = &removed {
+            self.push_e2ee_keys(&user.username).await;
+        }
+        removed
+    }
+
+    /// Remove a user by session ID and disconnect its connection
+    ///
+    /// Anything already queued for the session (such as why it was kicked)
+    /// is still sent before the connection closes.
+    pub async fn disconnect_user(&self, session_id: u32) -> Option<UserSession> {
+        let removed = self.remove_user(session_id).await;
+        if let Some(user) = &removed {
+            user.tx.close();
+        }
+        removed
     }
 
     /// Update username for a user by database user ID
     /// Returns the number of sessions updated
     pub async fn update_username(&self, db_user_id: i64, new_username: String) -> usize {
-        let mut users = self.users.write().await;
+        let mut previous_username = None;
         let mut count = 0;
-
-        for user in users.values_mut() {
-            if user.db_user_id == db_user_id {
-                user.username = new_username.clone();
-                count += 1;
+        {
+            let mut users = self.users.write().await;
+            for user in users.values_mut() {
+                if user.db_user_id == db_user_id {
+                    let previous = std::mem::replace(&mut user.username, new_username.clone());
+                    user.e2ee_watches.insert(new_username.to_lowercase());
+                    previous_username = Some(previous);
+                    count += 1;
+                }
             }
         }
 
+        // Keys are looked up by name, so both names' watchers need to hear
+        if let Some(previous_username) = previous_username {
+            self.push_e2ee_keys(&previous_username).await;
+            self.push_e2ee_keys(&new_username).await;
+        }
         count
     }
 
@@ -60,13 +127,59 @@ impl UserManager {
         count
     }
 
-    /// Update cached permissions for a user by database user ID
-    /// Returns the number of sessions updated
-    pub async fn update_permissions(
+    /// Mark a session away or back
+    ///
+    /// An account counts as away only while every one of its sessions is.
+    /// Returns whether that changed, or `None` if the session doesn't exist.
+    pub async fn set_away(&self, session_id: u32, away: bool) -> Option<bool> {
+        let mut users = self.users.write().await;
+        let db_user_id = users.get(&session_id)?.db_user_id;
+        let account_away = |users: &HashMap<u32, UserSession>| {
+            users
+                .values()
+                .filter(|user| user.db_user_id == db_user_id)
+                .all(|user| user.away)
+        };
+
+        let was_away = account_away(&users);
+        users.get_mut(&session_id)?.away = away;
+        Some(account_away(&users) != was_away)
+    }
+
+    /// Record that a guest session accepted the server rules
+    ///
+    /// Returns false if the session doesn't exist or isn't a guest.
+    pub async fn accept_guest_rules(&self, session_id: u32) -> bool {
+        let mut users = self.users.write().await;
+        match users.get_mut(&session_id) {
+            Some(user) if user.is_guest => {
+                user.guest_rules_accepted = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Re-read an account's permissions from the database into its sessions
+    ///
+    /// Sessions authorize against the permissions cached at login, so this
+    /// follows every permission write: `UserDb::update_user`, or an edit
+    /// made to the database outside the server. Returns the permissions that
+    /// were read.
+    pub async fn reload_permissions(
         &self,
+        user_db: &UserDb,
         db_user_id: i64,
-        permissions: HashSet<Permission>,
-    ) -> usize {
+    ) -> Result<Permissions, sqlx::Error> {
+        let permissions = user_db.get_user_permissions(db_user_id).await?;
+        self.update_permissions(db_user_id, permissions.permissions.clone())
+            .await;
+        Ok(permissions)
+    }
+
+    /// Update cached permissions for a user by database user ID
+    /// Returns the number of sessions updated
+    async fn update_permissions(&self, db_user_id: i64, permissions: HashSet<Permission>) -> usize {
         let mut users = self.users.write().await;
         let mut count = 0;
 
@@ -79,4 +192,309 @@ impl UserManager {
 
         count
     }
+
+    /// Check chat slow mode for a user and record the message time if allowed
+    ///
+    /// Tracked per username rather than per session, so a second connection
+    /// or a reconnect doesn't reset the interval. Returns `Some(remaining_seconds)`
+    /// if the user must wait before sending another message, or `None` if the
+    /// message is allowed (in which case the user's last message time is updated).
+    pub async fn check_chat_slow_mode(&self, username: &str, interval_secs: u32) -> Option<u64> {
+        let mut last_chat_messages = self.last_chat_messages.write().await;
+        let username_lower = username.to_lowercase();
+        let now = Instant::now();
+        let interval = Duration::from_secs(u64::from(interval_secs));
+
+        if let Some(remaining) = last_chat_messages
+            .get(&username_lower)
+            .and_then(|last| remaining_wait(*last, interval, now))
+        {
+            return Some(remaining);
+        }
+
+        // Entries past the interval can't block anyone, so don't keep them
+        last_chat_messages.retain(|_, last| now.duration_since(*last) < interval);
+        last_chat_messages.insert(username_lower, now);
+        None
+    }
+
+    /// Check a command's per-session cooldown and record the call if allowed
+    ///
+    /// Works like `check_chat_slow_mode`, tracked separately for each command.
+    pub async fn check_command_cooldown(
+        &self,
+        session_id: u32,
+        command: &'static str,
+        interval_secs: u32,
+    ) -> Option<u64> {
+        let mut users = self.users.write().await;
+        let user = users.get_mut(&session_id)?;
+        let now = Instant::now();
+        let interval = Duration::from_secs(u64::from(interval_secs));
+
+        if let Some(remaining) = user
+            .last_cooldown_commands
+            .get(command)
+            .and_then(|last| remaining_wait(*last, interval, now))
+        {
+            return Some(remaining);
+        }
+
+        user.last_cooldown_commands.insert(command, now);
+        None
+    }
+}
+
+/// Whole seconds left before `interval` has passed since `last`, if any
+///
+/// Rounds up so the client never sees "0 seconds" while still blocked.
+fn remaining_wait(last: Instant, interval: Duration, now: Instant) -> Option<u64> {
+    let elapsed = now.duration_since(last);
+    if elapsed >= interval {
+        return None;
+    }
+    let remaining = interval - elapsed;
+    Some(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user};
+
+    #[tokio::test]
+    async fn test_reload_permissions_updates_online_sessions() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatSend],
+            false,
+        )
+        .await;
+        let account = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut perms = Permissions::new();
+        perms.permissions.insert(Permission::UserKick);
+        test_ctx
+            .db
+            .users
+            .set_permissions(account.id, &perms)
+            .await
+            .unwrap();
+        let reloaded = test_ctx
+            .user_manager
+            .reload_permissions(&test_ctx.db.users, account.id)
+            .await
+            .unwrap();
+        assert_eq!(reloaded.permissions, perms.permissions);
+
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id)
+            .await
+            .unwrap();
+        assert!(session.has_permission(Permission::UserKick));
+        assert!(!session.has_permission(Permission::ChatSend));
+        assert_eq!(
+            test_ctx
+                .db
+                .users
+                .get_user_permissions(account.id)
+                .await
+                .unwrap()
+                .permissions,
+            perms.permissions
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_away_needs_every_session() {
+        let mut test_ctx = create_test_context().await;
+        let first = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(first)
+            .await
+            .unwrap();
+        let second = test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: session.db_user_id,
+                username: session.username.clone(),
+                is_admin: false,
+                permissions: HashSet::new(),
+                address: session.address,
+                source: session.source.clone(),
+                created_at: session.created_at,
+                tx: session.tx.clone(),
+                features: vec![],
+                locale: session.locale.clone(),
+                avatar: None,
+                is_guest: false,
+                legacy_protocol: false,
+            })
+            .await;
+        let manager = &test_ctx.user_manager;
+
+        // One idle device doesn't make the account away
+        assert_eq!(manager.set_away(first, true).await, Some(false));
+        assert_eq!(manager.set_away(second, true).await, Some(true));
+        assert_eq!(manager.set_away(second, true).await, Some(false));
+        assert_eq!(manager.set_away(first, false).await, Some(true));
+        assert_eq!(manager.set_away(999, true).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_add_session_limit_is_atomic() {
+        let mut test_ctx = create_test_context().await;
+        let first = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(first)
+            .await
+            .unwrap();
+        let params = || NewSessionParams {
+            session_id: 0,
+            db_user_id: session.db_user_id,
+            username: session.username.clone(),
+            is_admin: false,
+            permissions: HashSet::new(),
+            address: session.address,
+            source: session.source.clone(),
+            created_at: session.created_at,
+            tx: session.tx.clone(),
+            features: vec![],
+            locale: session.locale.clone(),
+            avatar: None,
+            is_guest: false,
+            legacy_protocol: false,
+        };
+        let manager = &test_ctx.user_manager;
+
+        // Two logins racing for the last slot: only one gets it
+        let (a, b) = tokio::join!(
+            manager.add_session(params(), Some(2)),
+            manager.add_session(params(), Some(2))
+        );
+        assert!(a.is_ok() != b.is_ok());
+        assert_eq!(manager.get_session_ids_for_user("alice").await.len(), 2);
+        assert_eq!(
+            manager.add_session(params(), Some(2)).await,
+            Err(AddSessionError::SessionLimit)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_session_guest_name_is_atomic() {
+        let mut test_ctx = create_test_context().await;
+        let first = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(first)
+            .await
+            .unwrap();
+        let params = |username: &str| NewSessionParams {
+            session_id: 0,
+            db_user_id: 0,
+            username: username.to_string(),
+            is_admin: false,
+            permissions: HashSet::new(),
+            address: session.address,
+            source: session.source.clone(),
+            created_at: session.created_at,
+            tx: session.tx.clone(),
+            features: vec![],
+            locale: session.locale.clone(),
+            avatar: None,
+            is_guest: true,
+            legacy_protocol: false,
+        };
+        let manager = &test_ctx.user_manager;
+
+        // Two guests racing for one name: only one gets it
+        let (a, b) = tokio::join!(
+            manager.add_session(params("visitor"), None),
+            manager.add_session(params("Visitor"), None)
+        );
+        assert!(matches!(
+            (a, b),
+            (Ok(_), Err(AddSessionError::GuestNameInUse))
+                | (Err(AddSessionError::GuestNameInUse), Ok(_))
+        ));
+        assert_eq!(
+            manager.add_session(params("ALICE"), None).await,
+            Err(AddSessionError::GuestNameInUse)
+        );
+        assert!(manager.add_session(params("bob"), None).await.is_ok());
+    }
+
+    #[test]
+    fn test_remaining_wait_boundary() {
+        let last = Instant::now();
+        let interval = Duration::from_secs(5);
+
+        assert_eq!(remaining_wait(last, interval, last), Some(5));
+        assert_eq!(
+            remaining_wait(last, interval, last + Duration::from_millis(4_001)),
+            Some(1)
+        );
+        assert_eq!(
+            remaining_wait(last, interval, last + interval - Duration::from_nanos(1)),
+            Some(1)
+        );
+        assert_eq!(remaining_wait(last, interval, last + interval), None);
+    }
+
+    #[tokio::test]
+    async fn test_command_cooldown_per_command() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let manager = &test_ctx.user_manager;
+
+        assert_eq!(
+            manager
+                .check_command_cooldown(session_id, "UserInfo", 60)
+                .await,
+            None
+        );
+        assert_eq!(
+            manager
+                .check_command_cooldown(session_id, "UserInfo", 60)
+                .await,
+            Some(60)
+        );
+
+        // Other commands have their own timer
+        assert_eq!(
+            manager
+                .check_command_cooldown(session_id, "SeenRequest", 60)
+                .await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_slow_mode_is_shared_by_username() {
+        let user_manager = UserManager::new();
+
+        assert_eq!(user_manager.check_chat_slow_mode("alice", 60).await, None);
+
+        // Another session for the same account (any case) waits out the interval
+        assert_eq!(
+            user_manager.check_chat_slow_mode("Alice", 60).await,
+            Some(60)
+        );
+
+        // Other users are unaffected
+        assert_eq!(user_manager.check_chat_slow_mode("bob", 60).await, None);
+    }
 }
@@ -1,5 +1,7 @@
 //! Query methods for UserManager
 
+use std::collections::HashSet;
+
 use super::UserManager;
 use crate::users::user::UserSession;
 
@@ -10,6 +12,16 @@ impl UserManager {
         users.values().cloned().collect()
     }
 
+    /// Get the number of unique users online (multiple sessions count once)
+    pub async fn get_online_user_count(&self) -> usize {
+        let users = self.users.read().await;
+        users
+            .values()
+            .map(|u| u.username.to_lowercase())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
     /// Get a user by session ID
     pub async fn get_user_by_session_id(&self, session_id: u32) -> Option<UserSession> {
         let users = self.users.read().await;
@@ -42,6 +54,16 @@ impl UserManager {
             .collect()
     }
 
+    /// Get all sessions belonging to a database account
+    pub async fn get_sessions_by_db_user_id(&self, db_user_id: i64) -> Vec<UserSession> {
+        let users = self.users.read().await;
+        users
+            .values()
+            .filter(|u| u.db_user_id == db_user_id)
+            .cloned()
+            .collect()
+    }
+
     /// Get all session IDs for a given username (case-insensitive)
     pub async fn get_session_ids_for_user(&self, username: &str) -> Vec<u32> {
         let users = self.users.read().await;
@@ -1,14 +1,108 @@
 //! User session representation for logged-in users
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
 
+use nexus_common::NOTABLE_PERMISSIONS;
 use nexus_common::framing::MessageId;
 use nexus_common::protocol::ServerMessage;
-use tokio::sync::mpsc;
+use tokio::sync::Notify;
+use tokio::sync::mpsc::{self, error::TrySendError};
 
 use crate::db::Permission;
 
+/// A server message queued for a connection, with an optional message ID to reuse
+pub type OutgoingMessage = (ServerMessage, Option<MessageId>);
+
+/// Create the bounded outgoing queue for one connection
+///
+/// When a client stops reading and its queue fills, the client is
+/// disconnected rather than having messages dropped: a client that silently
+/// missed messages would show stale users, chat, and permissions.
+pub fn session_channel(capacity: usize) -> (SessionSender, SessionReceiver) {
+    let (tx, rx) = mpsc::channel(capacity);
+    let overflow = Arc::new(Notify::new());
+    let close = Arc::new(Notify::new());
+    (
+        SessionSender {
+            tx,
+            overflow: overflow.clone(),
+            close: close.clone(),
+        },
+        SessionReceiver {
+            messages: rx,
+            overflow,
+            close,
+        },
+    )
+}
+
+/// Sending half of a connection's outgoing queue (cheap to clone)
+#[derive(Debug, Clone)]
+pub struct SessionSender {
+    tx: mpsc::Sender<OutgoingMessage>,
+    /// Signalled when the queue is full so the connection can disconnect
+    overflow: Arc<Notify>,
+    /// Signalled when the session is kicked so the connection can disconnect
+    close: Arc<Notify>,
+}
+
+impl SessionSender {
+    /// Queue a message without waiting
+    ///
+    /// Fails if the connection has closed or its queue is full. A full queue
+    /// also tells the connection to disconnect the client, so callers treat
+    /// both failures as a disconnected session.
+    pub fn send(&self, message: OutgoingMessage) -> Result<(), SessionSendError> {
+        self.tx.try_send(message).map_err(|e| match e {
+            TrySendError::Full(_) => {
+                self.overflow.notify_one();
+                SessionSendError::Full
+            }
+            TrySendError::Closed(_) => SessionSendError::Closed,
+        })
+    }
+
+    /// Tell the connection to disconnect once it has sent what is queued
+    ///
+    /// The connection holds a sender of its own, so the queue never closes
+    /// by itself; this is the only way to end a connection from outside.
+    pub fn close(&self) {
+        self.close.notify_one();
+    }
+}
+
+/// Why a message couldn't be queued for a connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionSendError {
+    /// The client isn't reading fast enough (it is being disconnected)
+    Full,
+    /// The connection has closed
+    Closed,
+}
+
+/// Why a new session wasn't added
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddSessionError {
+    /// The account already has as many sessions as it may
+    SessionLimit,
+    /// A guest with this name is already online
+    GuestNameInUse,
+}
+
+/// Receiving half of a connection's outgoing queue
+#[derive(Debug)]
+pub struct SessionReceiver {
+    /// Queued messages, in order
+    pub messages: mpsc::Receiver<OutgoingMessage>,
+    /// Notified when a sender found the queue full
+    pub overflow: Arc<Notify>,
+    /// Notified when the session was kicked
+    pub close: Arc<Notify>,
+}
+
 /// Parameters for creating a new user session
 pub struct NewSessionParams {
     pub session_id: u32,
@@ -17,12 +111,18 @@ pub struct NewSessionParams {
     pub is_admin: bool,
     pub permissions: HashSet<Permission>,
     pub address: SocketAddr,
+    /// Source tag of the connection (e.g. "loopback", "lan", "internet")
+    pub source: String,
     pub created_at: i64,
-    pub tx: mpsc::UnboundedSender<(ServerMessage, Option<MessageId>)>,
+    pub tx: SessionSender,
     pub features: Vec<String>,
     pub locale: String,
     /// User's avatar as a data URI (ephemeral, not stored in DB)
     pub avatar: Option<String>,
+    /// Guest session with no account behind it
+    pub is_guest: bool,
+    /// Client speaks protocol 0.5, older than the extended protocol
+    pub legacy_protocol: bool,
 }
 
 /// Represents a logged-in user session
@@ -31,6 +131,9 @@ pub struct UserSession {
     /// Session ID (unique identifier for this connection)
     pub session_id: u32,
     /// Database user ID
+    ///
+    /// Guests have no account row, so each guest session gets its own
+    /// negative ID that no account or other session shares.
     pub db_user_id: i64,
     /// Username
     pub username: String,
@@ -40,22 +143,36 @@ pub struct UserSession {
     pub permissions: HashSet<Permission>,
     /// Remote address of the user's connection
     pub address: SocketAddr,
-    /// When the user account was created (Unix timestamp from database)
-    ///
-    /// This field is stored for potential future features like account age display,
-    /// statistics, or audit logging.
-    #[allow(dead_code)]
+    /// Source tag of the connection, derived from the remote address
+    pub source: String,
+    /// When the user account was created (Unix timestamp from database;
+    /// a guest's login time)
     pub created_at: i64,
     /// When the user logged in (Unix timestamp)
     pub login_time: i64,
     /// Channel sender for sending messages to this user
-    pub tx: mpsc::UnboundedSender<(ServerMessage, Option<MessageId>)>,
+    pub tx: SessionSender,
     /// Features enabled for this user
     pub features: Vec<String>,
     /// User's preferred locale (e.g., "en", "en-US", "zh-CN")
     pub locale: String,
     /// User's avatar as a data URI (ephemeral, not stored in DB)
     pub avatar: Option<String>,
+    /// Whether the client reported this session idle
+    pub away: bool,
+    /// When this session last ran each cooldown-limited command
+    pub last_cooldown_commands: HashMap<&'static str, Instant>,
+    /// X25519 public key for end-to-end encrypted private messages, once announced
+    pub e2ee_public_key: Option<String>,
+    /// Lowercased usernames whose keys this session is sent when they change
+    pub e2ee_watches: HashSet<String>,
+    /// Guest session with no account behind it (nothing is stored for it)
+    pub is_guest: bool,
+    /// Whether this guest accepted the server rules (accounts record it in the database)
+    pub guest_rules_accepted: bool,
+    /// Client speaks protocol 0.5, so it is never sent message types added
+    /// since (it would disconnect on them)
+    pub legacy_protocol: bool,
 }
 
 impl UserSession {
@@ -68,12 +185,20 @@ impl UserSession {
             is_admin: params.is_admin,
             permissions: params.permissions,
             address: params.address,
+            source: params.source,
             created_at: params.created_at,
             login_time: current_timestamp(),
             tx: params.tx,
             features: params.features,
             locale: params.locale,
             avatar: params.avatar,
+            away: false,
+            last_cooldown_commands: HashMap::new(),
+            e2ee_public_key: None,
+            e2ee_watches: HashSet::new(),
+            is_guest: params.is_guest,
+            guest_rules_accepted: false,
+            legacy_protocol: params.legacy_protocol,
         }
     }
 
@@ -90,6 +215,25 @@ impl UserSession {
             self.permissions.contains(&permission)
         }
     }
+
+    /// Check if user moderates chat without being an admin (badged in user lists)
+    pub fn is_moderator(&self) -> bool {
+        !self.is_admin && self.permissions.contains(&Permission::ChatModerate)
+    }
+
+    /// Notable permissions held, for admin-facing user listings (`None` for admins)
+    pub fn notable_permissions(&self) -> Option<Vec<String>> {
+        (!self.is_admin).then(|| notable_permissions(&self.permissions))
+    }
+}
+
+/// Names of the `NOTABLE_PERMISSIONS` contained in a permission set
+pub fn notable_permissions(permissions: &HashSet<Permission>) -> Vec<String> {
+    NOTABLE_PERMISSIONS
+        .iter()
+        .filter(|name| Permission::parse(name).is_some_and(|p| permissions.contains(&p)))
+        .map(|name| name.to_string())
+        .collect()
 }
 
 /// Get current Unix timestamp in seconds
@@ -104,3 +248,36 @@ fn current_timestamp() -> i64 {
         .expect("System time is before Unix epoch - check system clock configuration")
         .as_secs() as i64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_full_queue_signals_overflow() {
+        let (tx, mut rx) = session_channel(2);
+
+        assert!(tx.send((ServerMessage::Pong, None)).is_ok());
+        assert!(tx.send((ServerMessage::Pong, None)).is_ok());
+        assert_eq!(
+            tx.send((ServerMessage::Pong, None)),
+            Err(SessionSendError::Full)
+        );
+
+        // The connection learns about the overflow even if it wasn't waiting yet
+        tokio::time::timeout(Duration::from_secs(1), rx.overflow.notified())
+            .await
+            .expect("overflow should be signalled");
+
+        // Queued messages are kept (nothing is dropped), and the queue is usable again
+        assert!(rx.messages.try_recv().is_ok());
+        assert!(tx.send((ServerMessage::Pong, None)).is_ok());
+
+        drop(rx);
+        assert_eq!(
+            tx.send((ServerMessage::Pong, None)),
+            Err(SessionSendError::Closed)
+        );
+    }
+}
@@ -3,11 +3,10 @@
 use std::collections::HashSet;
 use std::net::SocketAddr;
 
-use nexus_common::framing::MessageId;
-use nexus_common::protocol::ServerMessage;
+use nexus_server::constants::DEFAULT_OUTGOING_QUEUE_CAPACITY;
 use nexus_server::db::{Database, Permission};
 use nexus_server::users::UserManager;
-use nexus_server::users::user::NewSessionParams;
+use nexus_server::users::user::{NewSessionParams, OutgoingMessage, session_channel};
 use tokio::sync::mpsc;
 
 /// Default locale for integration tests
@@ -45,11 +44,8 @@ pub async fn add_test_user(
     username: &str,
     is_admin: bool,
     permissions: HashSet<Permission>,
-) -> (
-    u32,
-    mpsc::UnboundedReceiver<(ServerMessage, Option<MessageId>)>,
-) {
-    let (tx, rx) = mpsc::unbounded_channel();
+) -> (u32, mpsc::Receiver<OutgoingMessage>) {
+    let (tx, rx) = session_channel(DEFAULT_OUTGOING_QUEUE_CAPACITY);
     let addr: SocketAddr = "127.0.0.1:8000".parse().unwrap();
     let created_at = chrono::Utc::now().timestamp();
 
@@ -62,13 +58,16 @@ pub async fn add_test_user(
             is_admin,
             permissions,
             address: addr,
+            source: "loopback".to_string(),
             created_at,
             tx,
             features: vec!["chat".to_string()],
             locale: DEFAULT_TEST_LOCALE.to_string(),
             avatar: None,
+            is_guest: false,
+            legacy_protocol: false,
         })
         .await;
 
-    (session_id, rx)
+    (session_id, rx.messages)
 }
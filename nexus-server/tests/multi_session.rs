@@ -6,6 +6,7 @@ use std::collections::HashSet;
 
 use common::{add_test_user, create_test_db};
 use nexus_common::protocol::{ServerMessage, UserInfo};
+use nexus_server::constants::DEFAULT_OUTGOING_QUEUE_CAPACITY;
 use nexus_server::db::{self, Permission, Permissions};
 use nexus_server::users::UserManager;
 
@@ -191,10 +192,14 @@ async fn test_broadcast_respects_user_list_permission() {
                 user: UserInfo {
                     username: "newuser".to_string(),
                     is_admin: false,
+                    is_moderator: false,
+                    away: false,
+                    is_guest: false,
                     login_time: chrono::Utc::now().timestamp(),
                     session_ids: vec![99],
                     locale: "en".to_string(),
                     avatar: None,
+                    permissions: None,
                 },
             },
             &db.users,
@@ -273,10 +278,14 @@ async fn test_broadcast_excludes_specified_session() {
                 user: UserInfo {
                     username: "newcomer".to_string(),
                     is_admin: false,
+                    is_moderator: false,
+                    away: false,
+                    is_guest: false,
                     login_time: chrono::Utc::now().timestamp(),
                     session_ids: vec![30],
                     locale: "en".to_string(),
                     avatar: None,
+                    permissions: None,
                 },
             },
             &db.users,
@@ -363,6 +372,8 @@ async fn test_broadcast_detects_closed_channels() {
                 session_id: 999,
                 username: "system".to_string(),
                 message: "test".to_string(),
+                message_id: None,
+                mentions: None,
             },
             &db.users,
             Permission::ChatReceive,
@@ -390,3 +401,61 @@ async fn test_broadcast_detects_closed_channels() {
     // Clean up
     drop(rx2);
 }
+
+#[tokio::test]
+async fn test_slow_session_disconnected_when_queue_full() {
+    let db = create_test_db().await;
+    let user_manager = UserManager::new();
+
+    let hashed_password = db::hash_password("password").unwrap();
+    let mut perms = Permissions::new();
+    perms.add(Permission::UserList);
+    let alice = db
+        .users
+        .create_user("alice", &hashed_password, false, true, &perms)
+        .await
+        .unwrap();
+    let bob = db
+        .users
+        .create_user("bob", &hashed_password, false, true, &perms)
+        .await
+        .unwrap();
+
+    let cached_perms: HashSet<Permission> = [Permission::UserList].into_iter().collect();
+    // Alice never reads her messages; Bob keeps up
+    let (alice_session, _alice_rx) = add_test_user(
+        &user_manager,
+        alice.id,
+        "alice",
+        false,
+        cached_perms.clone(),
+    )
+    .await;
+    let (_bob_session, mut bob_rx) =
+        add_test_user(&user_manager, bob.id, "bob", false, cached_perms).await;
+
+    for _ in 0..=DEFAULT_OUTGOING_QUEUE_CAPACITY {
+        while bob_rx.try_recv().is_ok() {}
+        user_manager.broadcast(ServerMessage::Pong, &db.users).await;
+    }
+
+    // Alice's full queue got her disconnected; Bob is unaffected
+    assert!(
+        user_manager
+            .get_user_by_session_id(alice_session)
+            .await
+            .is_none()
+    );
+    let remaining = user_manager.get_all_users().await;
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].username, "bob");
+
+    // Bob sees the last broadcast, then Alice leaving
+    assert!(matches!(bob_rx.try_recv(), Ok((ServerMessage::Pong, _))));
+    match bob_rx.try_recv().map(|(message, _)| message) {
+        Ok(ServerMessage::UserDisconnected { session_id, .. }) => {
+            assert_eq!(session_id, alice_session);
+        }
+        other => panic!("Expected UserDisconnected, got {:?}", other),
+    }
+}